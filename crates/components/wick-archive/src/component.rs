@@ -0,0 +1,217 @@
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::ArchiveComponentConfig;
+use wick_config::config::Metadata;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::limits::Limits;
+use crate::pack::pack;
+use crate::unpack::unpack;
+
+/// A component that unpacks tar and zip archives into entry streams and packs entry streams into archives.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ArchiveComponent {
+  signature: ComponentSignature,
+  config: ArchiveComponentConfig,
+  limits: Limits,
+}
+
+impl ArchiveComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: ArchiveComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/archive");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    Ok(Self {
+      signature: sig,
+      limits: Limits::new(&config),
+      config,
+    })
+  }
+}
+
+impl Component for ArchiveComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let format = *self.config.format();
+    let limits = self.limits;
+
+    Box::pin(async move {
+      match invocation.target().operation_id() {
+        "unpack" => Ok(unpack(invocation, limits)),
+        "pack" => Ok(pack(invocation, format, limits)),
+        name => Err(Error::OpNotFound(name.to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use futures::StreamExt;
+  use wick_config::config::components::{ArchiveComponentConfigBuilder, ArchiveFormat};
+  use wick_packet::{Base64Bytes, Entity, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<ArchiveComponent>();
+  }
+
+  fn component(format: ArchiveFormat, max_entry_size: Option<u64>) -> Result<ArchiveComponent> {
+    let config = ArchiveComponentConfigBuilder::default()
+      .format(format)
+      .max_entry_size(max_entry_size)
+      .build()?;
+    let component = ArchiveComponent::new(config, None, None)?;
+
+    Ok(component)
+  }
+
+  async fn invoke(
+    component: &ArchiveComponent,
+    op: &str,
+    packets: Vec<Packet>,
+  ) -> Result<Vec<Result<Packet, wick_packet::Error>>> {
+    let invocation = Invocation::test(file!(), Entity::local(op), packets, None)?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    Ok(stream.collect().await)
+  }
+
+  fn entries() -> Vec<Packet> {
+    vec![
+      Packet::encode("path", "hello.txt"),
+      Packet::encode("path", "nested/world.txt"),
+      Packet::done("path"),
+      Packet::open_bracket("data"),
+      Packet::encode("data", Base64Bytes::new(b"hello ".to_vec())),
+      Packet::encode("data", Base64Bytes::new(b"there".to_vec())),
+      Packet::close_bracket("data"),
+      Packet::open_bracket("data"),
+      Packet::encode("data", Base64Bytes::new(b"world".to_vec())),
+      Packet::close_bracket("data"),
+      Packet::done("data"),
+    ]
+  }
+
+  /// Turn the output of `pack` into the input of `unpack`.
+  fn packed(packets: Vec<Result<Packet, wick_packet::Error>>) -> Result<Vec<Packet>> {
+    let mut input = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      if packet.has_data() {
+        input.push(Packet::encode("input", packet.decode::<Base64Bytes>()?));
+      }
+    }
+    input.push(Packet::done("input"));
+    Ok(input)
+  }
+
+  /// Collect the output of `unpack` as `(path, data)` pairs.
+  fn unpacked(packets: Vec<Result<Packet, wick_packet::Error>>) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut data = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      match packet.port() {
+        "path" if packet.has_data() => entries.push((packet.decode()?, Vec::new())),
+        "data" if packet.has_data() => data.extend_from_slice(&packet.decode::<Base64Bytes>()?),
+        "data" if packet.is_close_bracket() => entries.last_mut().unwrap().1 = std::mem::take(&mut data),
+        _ => {}
+      }
+    }
+    Ok(entries)
+  }
+
+  #[tokio::test]
+  async fn test_roundtrip() -> Result<()> {
+    for format in [ArchiveFormat::Tar, ArchiveFormat::Zip] {
+      let component = component(format, None)?;
+      let archive = packed(invoke(&component, "pack", entries()).await?)?;
+      let packets = invoke(&component, "unpack", archive).await?;
+      assert_eq!(
+        unpacked(packets)?,
+        vec![
+          ("hello.txt".to_owned(), b"hello there".to_vec()),
+          ("nested/world.txt".to_owned(), b"world".to_vec()),
+        ],
+        "{}",
+        format
+      );
+    }
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_unsafe_path() -> Result<()> {
+    let component = component(ArchiveFormat::Tar, None)?;
+    let packets = invoke(
+      &component,
+      "pack",
+      vec![
+        Packet::encode("path", "../evil"),
+        Packet::done("path"),
+        Packet::encode("data", Base64Bytes::new(b"evil".to_vec())),
+        Packet::done("data"),
+      ],
+    )
+    .await?;
+    assert!(packets[0].is_err());
+
+    // `pack` refuses to write such an archive, so build it by hand.
+    let mut header = tar::Header::new_gnu();
+    header.set_size(4);
+    header.set_mode(0o644);
+    let name = b"../evil";
+    header.as_old_mut().name[..name.len()].copy_from_slice(name);
+    header.set_cksum();
+    let mut builder = tar::Builder::new(Vec::new());
+    builder.append(&header, b"evil".as_slice())?;
+    let archive = builder.into_inner()?;
+
+    let packets = invoke(
+      &component,
+      "unpack",
+      vec![
+        Packet::encode("input", Base64Bytes::new(archive)),
+        Packet::done("input"),
+      ],
+    )
+    .await?;
+    assert_eq!(packets.len(), 1);
+    assert!(packets[0].is_err());
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_entry_size_limit() -> Result<()> {
+    let limited = component(ArchiveFormat::Zip, Some(8))?;
+    let packets = invoke(&limited, "pack", entries()).await?;
+    assert!(packets[0].is_err());
+
+    let archive = packed(invoke(&component(ArchiveFormat::Zip, None)?, "pack", entries()).await?)?;
+    let packets = invoke(&limited, "unpack", archive).await?;
+    assert!(packets.last().unwrap().is_err());
+    assert!(!packets.iter().flatten().any(|p| p.port() == "data" && p.has_data()));
+    Ok(())
+  }
+}
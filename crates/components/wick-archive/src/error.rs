@@ -0,0 +1,39 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Refusing to process entry with unsafe path '{0}'")]
+  UnsafePath(String),
+
+  #[error("Entry '{0}' exceeds the maximum entry size of {1} bytes")]
+  EntryTooLarge(String, u64),
+
+  #[error("Archive exceeds the maximum total size of {0} bytes")]
+  ArchiveTooLarge(u64),
+
+  #[error("Archive exceeds the maximum of {0} entries")]
+  TooManyEntries(u32),
+
+  #[error("Received {0} paths for {1} entries")]
+  EntryMismatch(usize, usize),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+
+  #[error("Invalid archive: {0}")]
+  Format(String),
+}
+
+impl From<std::io::Error> for Error {
+  fn from(e: std::io::Error) -> Self {
+    Self::Format(e.to_string())
+  }
+}
+
+impl From<zip::result::ZipError> for Error {
+  fn from(e: zip::result::ZipError) -> Self {
+    Self::Format(e.to_string())
+  }
+}
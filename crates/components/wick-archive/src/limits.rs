@@ -0,0 +1,69 @@
+use wick_config::config::components::ArchiveComponentConfig;
+
+use crate::error::Error;
+
+/// The limits an archive component enforces on the archives it reads and writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Limits {
+  pub(crate) entry_size: u64,
+  pub(crate) total_size: u64,
+  pub(crate) entries: u32,
+}
+
+impl Limits {
+  pub(crate) fn new(config: &ArchiveComponentConfig) -> Self {
+    Self {
+      entry_size: config.entry_size_limit(),
+      total_size: config.total_size_limit(),
+      entries: config.entries_limit(),
+    }
+  }
+
+  pub(crate) const fn budget(self) -> Budget {
+    Budget {
+      limits: self,
+      entries: 0,
+      total: 0,
+    }
+  }
+}
+
+/// Tracks what an archive has used of its [Limits] so far. Sizes are counted from the bytes actually read rather
+/// than the sizes entries declare, which an archive can lie about.
+#[derive(Debug)]
+pub(crate) struct Budget {
+  limits: Limits,
+  entries: u32,
+  total: u64,
+}
+
+impl Budget {
+  /// Account for a new entry.
+  pub(crate) fn entry(&mut self) -> Result<(), Error> {
+    self.entries += 1;
+    if self.entries > self.limits.entries {
+      return Err(Error::TooManyEntries(self.limits.entries));
+    }
+    Ok(())
+  }
+
+  /// Account for `len` more bytes of the entry at `path`, which has `entry_size` bytes so far.
+  pub(crate) fn consume(&mut self, path: &str, entry_size: u64, len: u64) -> Result<(), Error> {
+    if entry_size.saturating_add(len) > self.limits.entry_size {
+      return Err(Error::EntryTooLarge(path.to_owned(), self.limits.entry_size));
+    }
+    self.total = self.total.saturating_add(len);
+    if self.total > self.limits.total_size {
+      return Err(Error::ArchiveTooLarge(self.limits.total_size));
+    }
+    Ok(())
+  }
+
+  /// Fail early when an entry declares a size over the entry limit.
+  pub(crate) fn check_declared(&self, path: &str, size: u64) -> Result<(), Error> {
+    if size > self.limits.entry_size {
+      return Err(Error::EntryTooLarge(path.to_owned(), self.limits.entry_size));
+    }
+    Ok(())
+  }
+}
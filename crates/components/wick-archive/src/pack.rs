@@ -0,0 +1,125 @@
+use std::io::{Cursor, Write};
+
+use futures::StreamExt;
+use wick_config::config::components::ArchiveFormat;
+use wick_packet::{Base64Bytes, Invocation, Observer, Packet, PacketExt, PacketStream};
+
+use crate::error::Error;
+use crate::limits::{Budget, Limits};
+use crate::path::sanitize;
+use crate::unpack::CHUNK_SIZE;
+
+/// Pack the entries arriving on the `path` and `data` ports into an archive emitted on `output`.
+///
+/// The nth `path` names the nth bracketed group of `data`. A single entry may send its `data` without brackets.
+pub(crate) fn pack(invocation: Invocation, format: ArchiveFormat, limits: Limits) -> PacketStream {
+  let (tx, rx) = invocation.make_response();
+  let span = invocation.span().clone();
+  let mtime = invocation.inherent().timestamp / 1000;
+  let mut stream = invocation.into_stream();
+
+  tokio::spawn(async move {
+    let result = match collect_entries(&mut stream, &mut limits.budget()).await {
+      Ok(entries) => tokio::task::spawn_blocking(move || write_archive(format, mtime, entries))
+        .await
+        .unwrap_or_else(|e| Err(Error::Format(e.to_string()))),
+      Err(e) => Err(e),
+    };
+    match result {
+      Ok(archive) => {
+        for chunk in archive.chunks(CHUNK_SIZE) {
+          let _ = tx.send(Packet::encode("output", Base64Bytes::new(chunk.to_vec())));
+        }
+        let _ = tx.send(Packet::done("output"));
+      }
+      Err(e) => {
+        span.in_scope(|| error!(error = %e, "archive:pack"));
+        let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+      }
+    }
+    tx.complete();
+  });
+
+  rx
+}
+
+async fn collect_entries(stream: &mut PacketStream, budget: &mut Budget) -> Result<Vec<(String, Vec<u8>)>, Error> {
+  let mut paths = Vec::new();
+  let mut groups = Vec::new();
+  let mut current: Option<Vec<u8>> = None;
+  let mut paths_done = false;
+  let mut data_done = false;
+
+  while let Some(next) = stream.next().await {
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    let port = packet.port().to_owned();
+    if port != "path" && port != "data" {
+      continue;
+    }
+    if packet.is_error() {
+      return Err(Error::Input(packet.unwrap_err().msg().to_owned()));
+    }
+    if port == "path" {
+      if packet.is_done() {
+        paths_done = true;
+      } else if packet.has_data() {
+        let path = packet.decode::<String>().map_err(|e| Error::Input(e.to_string()))?;
+        budget.entry()?;
+        paths.push(sanitize(&path)?);
+      }
+    } else if packet.is_open_bracket() {
+      current = Some(Vec::new());
+    } else if packet.is_close_bracket() {
+      groups.push(current.take().unwrap_or_default());
+    } else if packet.is_done() {
+      groups.extend(current.take());
+      data_done = true;
+    } else if packet.has_data() {
+      let bytes = packet
+        .decode::<Base64Bytes>()
+        .map_err(|e| Error::Input(e.to_string()))?;
+      let group = current.get_or_insert_with(Vec::new);
+      let name = paths
+        .get(groups.len())
+        .cloned()
+        .unwrap_or_else(|| format!("#{}", groups.len()));
+      budget.consume(&name, group.len() as u64, bytes.len() as u64)?;
+      group.extend_from_slice(&bytes);
+    }
+    if paths_done && data_done {
+      break;
+    }
+  }
+
+  if paths.len() != groups.len() {
+    return Err(Error::EntryMismatch(paths.len(), groups.len()));
+  }
+  Ok(paths.into_iter().zip(groups).collect())
+}
+
+fn write_archive(format: ArchiveFormat, mtime: u64, entries: Vec<(String, Vec<u8>)>) -> Result<Vec<u8>, Error> {
+  match format {
+    ArchiveFormat::Tar => {
+      let mut builder = tar::Builder::new(Vec::new());
+      for (path, data) in entries {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(mtime);
+        builder.append_data(&mut header, path, data.as_slice())?;
+      }
+      Ok(builder.into_inner()?)
+    }
+    ArchiveFormat::Zip => {
+      let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+      let options = zip::write::FileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .unix_permissions(0o644);
+      for (path, data) in entries {
+        writer.start_file(path, options)?;
+        writer.write_all(&data)?;
+      }
+      Ok(writer.finish()?.into_inner())
+    }
+  }
+}
@@ -0,0 +1,48 @@
+use crate::error::Error;
+
+/// Normalize an entry path to a relative, `/`-separated path that can't escape the directory an archive is
+/// extracted into. Absolute paths, drive prefixes, and `..` components are rejected rather than stripped so that
+/// a malicious archive fails loudly instead of silently landing somewhere unexpected.
+pub(crate) fn sanitize(path: &str) -> Result<String, Error> {
+  let normalized = path.replace('\\', "/");
+  if normalized.starts_with('/') || normalized.contains('\0') {
+    return Err(Error::UnsafePath(path.to_owned()));
+  }
+  let mut parts = Vec::new();
+  for part in normalized.split('/') {
+    match part {
+      "" | "." => {}
+      ".." => return Err(Error::UnsafePath(path.to_owned())),
+      part if parts.is_empty() && part.contains(':') => return Err(Error::UnsafePath(path.to_owned())),
+      part => parts.push(part),
+    }
+  }
+  if parts.is_empty() {
+    return Err(Error::UnsafePath(path.to_owned()));
+  }
+  Ok(parts.join("/"))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_sanitize() {
+    assert_eq!(sanitize("a/b.txt"), Ok("a/b.txt".to_owned()));
+    assert_eq!(sanitize("./a//b.txt"), Ok("a/b.txt".to_owned()));
+    assert_eq!(sanitize("a\\b.txt"), Ok("a/b.txt".to_owned()));
+    for path in [
+      "../evil",
+      "a/../../evil",
+      "/etc/passwd",
+      "\\evil",
+      "C:/evil",
+      "c:evil",
+      "",
+      "./",
+    ] {
+      assert_eq!(sanitize(path), Err(Error::UnsafePath(path.to_owned())), "{}", path);
+    }
+  }
+}
@@ -0,0 +1,231 @@
+use std::io::{Cursor, Read};
+
+use futures::StreamExt;
+use serde_json::json;
+use tokio::sync::mpsc;
+use wick_packet::{Base64Bytes, Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::error::Error;
+use crate::limits::{Budget, Limits};
+use crate::path::sanitize;
+
+pub(crate) const OUTPUTS: &[&str] = &["path", "metadata", "data"];
+
+/// The size of the `data` chunks emitted for each entry.
+pub(crate) const CHUNK_SIZE: usize = 64 * 1024;
+
+const S_IFMT: u32 = 0o170_000;
+const S_IFREG: u32 = 0o100_000;
+
+/// Unpack the archive arriving on the `input` port into a `path`, `metadata`, and bracketed `data` stream per file.
+///
+/// Tar archives are read as they arrive. Zip archives keep their index at the end, so they are buffered (up to the
+/// total size limit) before their entries are read.
+pub(crate) fn unpack(invocation: Invocation, limits: Limits) -> PacketStream {
+  let (tx, rx) = invocation.make_response();
+  let span = invocation.span().clone();
+  let mut stream = invocation.into_stream();
+  let (chunks, reader) = ChunkReader::new();
+
+  let blocking_tx = tx.clone();
+  let task = tokio::task::spawn_blocking(move || read_archive(reader, &blocking_tx, limits));
+
+  tokio::spawn(async move {
+    let mut failed = false;
+    while let Some(next) = stream.next().await {
+      let packet = match next {
+        Ok(packet) => packet,
+        Err(e) => {
+          failed = true;
+          let _ = tx.error(e);
+          break;
+        }
+      };
+      if packet.port() != "input" {
+        continue;
+      }
+      if packet.is_error() {
+        failed = true;
+        let err = packet.unwrap_err();
+        for port in OUTPUTS {
+          let _ = tx.send(Packet::raw_err(*port, err.clone()));
+        }
+        break;
+      }
+      if packet.is_done() {
+        break;
+      }
+      if packet.has_data() {
+        match packet.decode::<Base64Bytes>() {
+          Ok(bytes) => {
+            if chunks.send(bytes.into()).await.is_err() {
+              // The reader stopped early, it reports why below.
+              break;
+            }
+          }
+          Err(e) => {
+            failed = true;
+            let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+            break;
+          }
+        }
+      }
+    }
+    drop(chunks);
+
+    let result = task.await;
+    if !failed {
+      match result {
+        Ok(Ok(())) => {
+          for port in OUTPUTS {
+            let _ = tx.send(Packet::done(*port));
+          }
+        }
+        Ok(Err(e)) => {
+          span.in_scope(|| error!(error = %e, "archive:unpack"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+        Err(e) => {
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+    }
+    tx.complete();
+  });
+
+  rx
+}
+
+fn read_archive(mut reader: ChunkReader, tx: &PacketSender, limits: Limits) -> Result<(), Error> {
+  let mut budget = limits.budget();
+  if matches!(reader.peek(4), b"PK\x03\x04" | b"PK\x05\x06") {
+    let mut buffer = Vec::new();
+    (&mut reader).take(limits.total_size + 1).read_to_end(&mut buffer)?;
+    if buffer.len() as u64 > limits.total_size {
+      return Err(Error::ArchiveTooLarge(limits.total_size));
+    }
+    read_zip(Cursor::new(buffer), tx, &mut budget)
+  } else {
+    read_tar(reader, tx, &mut budget)
+  }
+}
+
+fn read_tar<R: Read>(reader: R, tx: &PacketSender, budget: &mut Budget) -> Result<(), Error> {
+  let mut archive = tar::Archive::new(reader);
+  for entry in archive.entries()? {
+    let entry = entry?;
+    budget.entry()?;
+    let header = entry.header();
+    if !header.entry_type().is_file() {
+      // Directories are implied by file paths and links could point outside of the extraction root.
+      trace!(kind = ?header.entry_type(), "archive:unpack:skipping non-file entry");
+      continue;
+    }
+    let path = String::from_utf8_lossy(&entry.path_bytes()).into_owned();
+    let mode = header.mode().ok();
+    let size = header.size()?;
+    emit_entry(tx, budget, &path, mode, size, entry)?;
+  }
+  Ok(())
+}
+
+fn read_zip<R: Read + std::io::Seek>(reader: R, tx: &PacketSender, budget: &mut Budget) -> Result<(), Error> {
+  let mut archive = zip::ZipArchive::new(reader)?;
+  for i in 0..archive.len() {
+    budget.entry()?;
+    let entry = archive.by_index(i)?;
+    let mode = entry.unix_mode();
+    if entry.is_dir() || mode.map_or(false, |mode| mode & S_IFMT != 0 && mode & S_IFMT != S_IFREG) {
+      trace!(name = entry.name(), "archive:unpack:skipping non-file entry");
+      continue;
+    }
+    let path = entry.name().to_owned();
+    let size = entry.size();
+    emit_entry(tx, budget, &path, mode.map(|mode| mode & !S_IFMT), size, entry)?;
+  }
+  Ok(())
+}
+
+fn emit_entry<R: Read>(
+  tx: &PacketSender,
+  budget: &mut Budget,
+  path: &str,
+  mode: Option<u32>,
+  size: u64,
+  mut reader: R,
+) -> Result<(), Error> {
+  let path = sanitize(path)?;
+  budget.check_declared(&path, size)?;
+
+  let _ = tx.send(Packet::encode("path", &path));
+  let _ = tx.send(Packet::encode("metadata", json!({ "size": size, "mode": mode })));
+  let _ = tx.send(Packet::open_bracket("data"));
+  let mut read = 0;
+  let mut chunk = vec![0; CHUNK_SIZE];
+  loop {
+    let len = reader.read(&mut chunk)?;
+    if len == 0 {
+      break;
+    }
+    budget.consume(&path, read, len as u64)?;
+    read += len as u64;
+    let _ = tx.send(Packet::encode("data", Base64Bytes::new(chunk[..len].to_vec())));
+  }
+  let _ = tx.send(Packet::close_bracket("data"));
+  Ok(())
+}
+
+/// A blocking [Read] over the chunks of an archive that an async task feeds it.
+struct ChunkReader {
+  rx: mpsc::Receiver<Vec<u8>>,
+  buffer: Vec<u8>,
+  pos: usize,
+}
+
+impl ChunkReader {
+  fn new() -> (mpsc::Sender<Vec<u8>>, Self) {
+    let (tx, rx) = mpsc::channel(16);
+    (
+      tx,
+      Self {
+        rx,
+        buffer: Vec::new(),
+        pos: 0,
+      },
+    )
+  }
+
+  /// Look at up to the next `len` bytes without consuming them. Returns fewer bytes only at the end of the stream.
+  fn peek(&mut self, len: usize) -> &[u8] {
+    while self.buffer.len() - self.pos < len {
+      match self.rx.blocking_recv() {
+        Some(chunk) => {
+          self.buffer.drain(..self.pos);
+          self.pos = 0;
+          self.buffer.extend(chunk);
+        }
+        None => break,
+      }
+    }
+    let end = self.buffer.len().min(self.pos + len);
+    &self.buffer[self.pos..end]
+  }
+}
+
+impl Read for ChunkReader {
+  fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+    while self.pos == self.buffer.len() {
+      match self.rx.blocking_recv() {
+        Some(chunk) => {
+          self.buffer = chunk;
+          self.pos = 0;
+        }
+        None => return Ok(0),
+      }
+    }
+    let len = out.len().min(self.buffer.len() - self.pos);
+    out[..len].copy_from_slice(&self.buffer[self.pos..self.pos + len]);
+    self.pos += len;
+    Ok(len)
+  }
+}
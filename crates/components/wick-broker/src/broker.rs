@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{Map, Value};
+use tokio::sync::broadcast;
+
+/// An event published to a topic, one field for each input of the operation that published it.
+pub type Event = Arc<Map<String, Value>>;
+
+/// How many events a topic holds for its slowest subscriber before that subscriber starts missing them.
+const TOPIC_CAPACITY: usize = 1024;
+
+/// Every broker in the process by name, so apps that bind a broker of the same name share it.
+static BROKERS: Lazy<Mutex<HashMap<String, Broker>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// An in-process broker that passes events published to a topic to every subscriber of that topic.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Broker {
+  name: Arc<str>,
+  topics: Arc<Mutex<HashMap<String, broadcast::Sender<Event>>>>,
+}
+
+impl Broker {
+  /// Get the broker named `name`, creating it if this is the first time it's been asked for.
+  pub fn named(name: &str) -> Self {
+    BROKERS
+      .lock()
+      .entry(name.to_owned())
+      .or_insert_with(|| Self {
+        name: name.into(),
+        topics: Arc::default(),
+      })
+      .clone()
+  }
+
+  #[must_use]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Publish `event` to `topic` and return how many subscribers it reached. Events published to a topic nobody is
+  /// subscribed to are dropped.
+  #[must_use]
+  pub fn publish(&self, topic: &str, event: Event) -> usize {
+    let topics = self.topics.lock();
+    topics.get(topic).and_then(|tx| tx.send(event).ok()).unwrap_or(0)
+  }
+
+  /// Subscribe to the events published to `topic` from now on.
+  #[must_use]
+  pub fn subscribe(&self, topic: &str) -> broadcast::Receiver<Event> {
+    let mut topics = self.topics.lock();
+    let tx = topics
+      .entry(topic.to_owned())
+      .or_insert_with(|| broadcast::channel(TOPIC_CAPACITY).0);
+    tx.subscribe()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  fn event(value: Value) -> Event {
+    match value {
+      Value::Object(map) => Arc::new(map),
+      _ => unreachable!(),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_publish() -> Result<()> {
+    let broker = Broker::named("test_publish");
+    assert_eq!(broker.publish("orders", event(json!({"id": 1}))), 0);
+
+    // brokers with the same name share their topics.
+    let mut first = Broker::named("test_publish").subscribe("orders");
+    let mut second = broker.subscribe("orders");
+    let _other = Broker::named("test_publish_other").subscribe("orders");
+    assert_eq!(broker.publish("orders", event(json!({"id": 2}))), 2);
+    assert_eq!(broker.publish("returns", event(json!({"id": 3}))), 0);
+
+    assert_eq!(first.recv().await?.get("id"), Some(&json!(2)));
+    assert_eq!(second.recv().await?.get("id"), Some(&json!(2)));
+    assert!(first.try_recv().is_err());
+    Ok(())
+  }
+}
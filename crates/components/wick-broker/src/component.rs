@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{BrokerComponentConfig, ComponentConfig, OperationConfig};
+use wick_config::config::{BrokerResource, Metadata};
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::broker::Broker;
+use crate::error::Error;
+use crate::operation::Operation;
+
+/// A component whose operations publish events to the topics of an in-process broker.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct BrokerComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl BrokerComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: BrokerComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/broker");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let resource: BrokerResource = resolver(config.resource())
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_broker())?;
+    let name = resource
+      .name()
+      .value()
+      .ok_or_else(|| Error::NoName(config.resource().to_string()))?;
+    let broker = Broker::named(name);
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| {
+        let operation = Operation::new(broker.clone(), op.topic().to_owned(), op.inputs().into_owned());
+        (op.name().to_owned(), Arc::new(operation))
+      })
+      .collect();
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for BrokerComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::json;
+  use wick_config::config::components::{BrokerComponentConfigBuilder, BrokerOperationDefinitionBuilder};
+  use wick_config::config::{AppConfiguration, ResourceDefinition};
+  use wick_interface_types::{Field, Type};
+  use wick_packet::{Entity, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<BrokerComponent>();
+  }
+
+  fn component(broker: &str) -> Result<BrokerComponent> {
+    let mut app_config = AppConfiguration::default();
+    app_config.add_resource("events", ResourceDefinition::Broker(BrokerResource::new(broker)));
+    let config = BrokerComponentConfigBuilder::default()
+      .resource("events")
+      .operations(vec![BrokerOperationDefinitionBuilder::default()
+        .name("order_placed")
+        .topic("orders")
+        .inputs(vec![Field::new("id", Type::U32), Field::new("item", Type::String)])
+        .build()?])
+      .build()?;
+    let component = BrokerComponent::new(config, None, None, &app_config.resolver())?;
+
+    Ok(component)
+  }
+
+  #[tokio::test]
+  async fn test_publish() -> Result<()> {
+    let component = component("test_component_publish")?;
+    let mut orders = Broker::named("test_component_publish").subscribe("orders");
+
+    let packets = vec![
+      Packet::encode("id", 1),
+      Packet::encode("item", "widget"),
+      Packet::encode("id", "two"),
+      Packet::encode("item", "gadget"),
+      Packet::done("id"),
+      Packet::done("item"),
+    ];
+    let invocation = Invocation::test(file!(), Entity::local("order_placed"), packets, None)?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let outputs: Vec<_> = packets
+      .into_iter()
+      .filter(|p| p.as_ref().map_or(true, |p| !p.is_done()))
+      .collect();
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[0].as_ref().unwrap().decode::<u32>()?, 1);
+    // a value that isn't of its field's type isn't published.
+    assert!(outputs[1].as_ref().unwrap().is_error());
+
+    let event = orders.recv().await?;
+    assert_eq!(*event, json!({"id": 1, "item": "widget"}).as_object().cloned().unwrap());
+    assert!(orders.try_recv().is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,12 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Broker {0} has no name")]
+  NoName(String),
+
+  #[error("Invalid input for '{0}': {1}")]
+  Input(String, String),
+}
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::Map;
+use wick_interface_types::Field;
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream, StreamMap};
+
+use crate::broker::{Broker, Event};
+use crate::error::Error;
+
+/// An operation that publishes an event to a topic for each set of inputs it receives.
+#[derive(Debug)]
+pub(crate) struct Operation {
+  broker: Broker,
+  topic: String,
+  inputs: Vec<Field>,
+}
+
+impl Operation {
+  pub(crate) const fn new(broker: Broker, topic: String, inputs: Vec<Field>) -> Self {
+    Self { broker, topic, inputs }
+  }
+
+  /// Process the invocation's stream, publishing one event for each packet received on every input and sending how
+  /// many subscribers it reached on the `delivered` port.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let ports = self.inputs.iter().map(|field| field.name.clone()).collect::<Vec<_>>();
+    let mut stream = StreamMap::from_stream(invocation.into_stream(), ports);
+
+    tokio::spawn(async move {
+      match self.each_set(&mut stream, &tx).await {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("delivered"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "broker"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+
+  async fn each_set(&self, stream: &mut StreamMap, tx: &PacketSender) -> Result<(), wick_packet::Error> {
+    while let Some(set) = stream.next_set().await? {
+      if set.values().any(Packet::is_done) {
+        break;
+      }
+      if let Some(packet) = set.values().find(|packet| packet.is_error()) {
+        let _ = tx.send(Packet::raw_err("delivered", packet.clone().unwrap_err()));
+        continue;
+      }
+      if !set.values().all(Packet::has_data) {
+        continue;
+      }
+      let packet = match self.event(set) {
+        Ok(event) => {
+          let delivered = self.broker.publish(&self.topic, event);
+          Packet::encode("delivered", u32::try_from(delivered).unwrap_or(u32::MAX))
+        }
+        Err(e) => Packet::err("delivered", e.to_string()),
+      };
+      let _ = tx.send(packet);
+    }
+    Ok(())
+  }
+
+  /// Build an event from a set of inputs, checking each value against the type of its field.
+  fn event(&self, mut set: HashMap<String, Packet>) -> Result<Event, Error> {
+    let mut event = Map::new();
+    for field in &self.inputs {
+      let Some(packet) = set.remove(&field.name) else {
+        continue;
+      };
+      let value = packet
+        .to_type_wrapper(field.ty.clone())
+        .map_err(|e| Error::Input(field.name.clone(), e.to_string()))?;
+      event.insert(field.name.clone(), value.into_inner());
+    }
+    Ok(Arc::new(event))
+  }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::{BoxFuture, Shared};
+use futures::FutureExt;
+use parking_lot::Mutex;
+use tokio::time::Instant;
+
+/// How many entries a cache holds before it first drops expired ones.
+const PRUNE_AT: usize = 1024;
+
+/// A computation of the value for a key that everyone who misses on the key while it runs waits on.
+type Flight<T> = Shared<BoxFuture<'static, T>>;
+
+/// Values stored by key for a while, computed on a miss by whoever misses first.
+#[derive(Debug)]
+pub(crate) struct Cache<T> {
+  ttl: Duration,
+  stale: Duration,
+  state: Arc<Mutex<State<T>>>,
+}
+
+struct State<T> {
+  entries: HashMap<String, Entry<T>>,
+  flights: HashMap<String, Flight<T>>,
+  prune_at: usize,
+}
+
+impl<T> std::fmt::Debug for State<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("State")
+      .field("entries", &self.entries.len())
+      .field("flights", &self.flights.len())
+      .finish()
+  }
+}
+
+#[derive(Debug)]
+struct Entry<T> {
+  value: T,
+  stored: Instant,
+}
+
+impl<T> Cache<T>
+where
+  T: Clone + Send + Sync + 'static,
+{
+  /// A cache whose values are fresh for `ttl`, and are still returned for `stale` after that while they're recomputed.
+  pub(crate) fn new(ttl: Duration, stale: Duration) -> Self {
+    Self {
+      ttl,
+      stale,
+      state: Arc::new(Mutex::new(State {
+        entries: HashMap::new(),
+        flights: HashMap::new(),
+        prune_at: PRUNE_AT,
+      })),
+    }
+  }
+
+  /// The value stored under `key`, or the value `compute` makes for it. `compute` is only called when no computation
+  /// for the key is already running, so concurrent misses share one. Values it returns as `Err` are returned but
+  /// not stored.
+  pub(crate) async fn get_or_compute<F>(&self, key: String, compute: F) -> T
+  where
+    F: FnOnce() -> BoxFuture<'static, Result<T, T>> + Send,
+  {
+    let flight = {
+      let mut state = self.state.lock();
+      if let Some(entry) = state.entries.get(&key) {
+        let age = entry.stored.elapsed();
+        if age < self.ttl {
+          return entry.value.clone();
+        }
+        if age < self.ttl + self.stale {
+          let value = entry.value.clone();
+          if !state.flights.contains_key(&key) {
+            // refreshed in the background; this caller is served the stale value.
+            let _refresh = self.start(&mut state, key, compute);
+          }
+          return value;
+        }
+      }
+      let existing = state.flights.get(&key).cloned();
+      existing.unwrap_or_else(|| self.start(&mut state, key, compute))
+    };
+    flight.await
+  }
+
+  /// Start computing the value for `key`. It's computed in its own task, so it's stored even if everyone waiting on
+  /// it gives up.
+  fn start<F>(&self, state: &mut State<T>, key: String, compute: F) -> Flight<T>
+  where
+    F: FnOnce() -> BoxFuture<'static, Result<T, T>>,
+  {
+    let shared = self.state.clone();
+    let lifetime = self.ttl + self.stale;
+    let computation = compute();
+    let flight_key = key.clone();
+    let flight = async move {
+      let result = computation.await;
+      let mut state = shared.lock();
+      state.flights.remove(&flight_key);
+      match result {
+        Ok(value) => {
+          state.store(flight_key, value.clone(), lifetime);
+          value
+        }
+        Err(value) => value,
+      }
+    }
+    .boxed()
+    .shared();
+    state.flights.insert(key, flight.clone());
+    tokio::spawn(flight.clone());
+    flight
+  }
+}
+
+impl<T> State<T> {
+  fn store(&mut self, key: String, value: T, lifetime: Duration) {
+    if self.entries.len() >= self.prune_at {
+      self.entries.retain(|_, entry| entry.stored.elapsed() < lifetime);
+      self.prune_at = (self.entries.len() * 2).max(PRUNE_AT);
+    }
+    self.entries.insert(
+      key,
+      Entry {
+        value,
+        stored: Instant::now(),
+      },
+    );
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::atomic::{AtomicU32, Ordering};
+
+  use super::*;
+
+  /// A computation that counts how many times it ran and returns the count.
+  fn counted(calls: &Arc<AtomicU32>, ok: bool) -> impl FnOnce() -> BoxFuture<'static, Result<u32, u32>> {
+    let calls = calls.clone();
+    move || {
+      async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+        if ok {
+          Ok(n)
+        } else {
+          Err(n)
+        }
+      }
+      .boxed()
+    }
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_ttl() {
+    let cache = Cache::new(Duration::from_secs(60), Duration::ZERO);
+    let calls = Arc::new(AtomicU32::new(0));
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 1);
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 1);
+    assert_eq!(cache.get_or_compute("b".to_owned(), counted(&calls, true)).await, 2);
+
+    tokio::time::advance(Duration::from_secs(61)).await;
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 3);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_single_flight() {
+    let cache = Cache::new(Duration::from_secs(60), Duration::ZERO);
+    let calls = Arc::new(AtomicU32::new(0));
+    let (a, b, c) = tokio::join!(
+      cache.get_or_compute("a".to_owned(), counted(&calls, true)),
+      cache.get_or_compute("a".to_owned(), counted(&calls, true)),
+      cache.get_or_compute("a".to_owned(), counted(&calls, true)),
+    );
+    assert_eq!((a, b, c), (1, 1, 1));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_stale_while_revalidate() {
+    let cache = Cache::new(Duration::from_secs(60), Duration::from_secs(30));
+    let calls = Arc::new(AtomicU32::new(0));
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 1);
+
+    // an expired value is still returned while it's recomputed in the background.
+    tokio::time::advance(Duration::from_secs(70)).await;
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 1);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 2);
+
+    // past the stale window, callers wait for the new value.
+    tokio::time::advance(Duration::from_secs(100)).await;
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 3);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_errors_not_stored() {
+    let cache = Cache::new(Duration::from_secs(60), Duration::ZERO);
+    let calls = Arc::new(AtomicU32::new(0));
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, false)).await, 1);
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 2);
+    assert_eq!(cache.get_or_compute("a".to_owned(), counted(&calls, true)).await, 2);
+  }
+}
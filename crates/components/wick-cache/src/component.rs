@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{CacheComponentConfig, ComponentConfig, OperationConfig};
+use wick_config::config::Metadata;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+
+/// A component whose operations store the outputs of other operations and only compute them on a miss.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CacheComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl CacheComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: CacheComponentConfig,
+    root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/cache");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let mut operations = HashMap::new();
+    for op in config.operations() {
+      let operation = Operation::new(
+        op.inputs().into_owned(),
+        &op.outputs(),
+        op.key().to_owned(),
+        op.compute(),
+        Duration::from_millis(op.ttl()),
+        Duration::from_millis(op.stale()),
+        root_config.clone(),
+      )?;
+      operations.insert(op.name().to_owned(), Arc::new(operation));
+    }
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for CacheComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    op_config: Option<RuntimeConfig>,
+    callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation, op_config, callback)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::atomic::{AtomicU32, Ordering};
+
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::{json, Value};
+  use wick_config::config::components::{CacheComponentConfigBuilder, CacheOperationDefinitionBuilder};
+  use wick_interface_types::{Field, Type};
+  use wick_packet::{Entity, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<CacheComponent>();
+  }
+
+  fn component(compute: &str) -> Result<CacheComponent> {
+    let config = CacheComponentConfigBuilder::default()
+      .operations(vec![CacheOperationDefinitionBuilder::default()
+        .name("get_user")
+        .inputs(vec![Field::new("id", Type::U32)])
+        .outputs(vec![Field::new("user", Type::Object)])
+        .key("user-{{ id }}")
+        .compute(compute)
+        .ttl(60000_u64)
+        .build()?])
+      .build()?;
+    let component = CacheComponent::new(config, None, None)?;
+
+    Ok(component)
+  }
+
+  /// A scope whose `users::lookup` operation counts how many times it was invoked.
+  fn users(calls: Arc<AtomicU32>) -> LocalScope {
+    LocalScope::new(Arc::new(move |compref, op, stream, _inherent, _config, _span| {
+      assert_eq!(compref.get_target_id(), "users");
+      assert_eq!(op, "lookup");
+      let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+      Box::pin(async move {
+        let packets: Vec<_> = stream.collect().await;
+        let id = packets
+          .into_iter()
+          .flatten()
+          .find(|p| p.port() == "id" && p.has_data())
+          .map(|p| p.decode_value().unwrap());
+        Ok::<_, ComponentError>(PacketStream::from(vec![
+          Packet::encode("user", json!({ "id": id, "n": n })),
+          Packet::done("user"),
+        ]))
+      })
+    }))
+  }
+
+  #[tokio::test]
+  async fn test_cache() -> Result<()> {
+    let component = component("users::lookup")?;
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let packets = vec![
+      Packet::encode("id", 1),
+      Packet::encode("id", 1),
+      Packet::encode("id", 2),
+      Packet::done("id"),
+    ];
+    let invocation = Invocation::test(file!(), Entity::local("get_user"), packets, None)?;
+    let stream = component.handle(invocation, None, users(calls.clone())).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let outputs = packets
+      .into_iter()
+      .filter(|p| p.as_ref().map_or(true, |p| !p.is_done()))
+      .map(|p| p?.decode::<Value>())
+      .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(
+      outputs,
+      vec![
+        json!({ "id": 1, "n": 1 }),
+        json!({ "id": 1, "n": 1 }),
+        json!({ "id": 2, "n": 2 })
+      ]
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid_compute() {
+    assert!(component("lookup").is_err());
+    assert!(component("users::").is_err());
+  }
+}
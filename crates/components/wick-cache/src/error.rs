@@ -0,0 +1,12 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Invalid operation '{0}' to compute misses with, expected 'component::operation'")]
+  Compute(String),
+
+  #[error("Could not render key: {0}")]
+  Key(String),
+}
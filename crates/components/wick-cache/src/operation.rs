@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use flow_component::LocalScope;
+use futures::{FutureExt, StreamExt};
+use serde_json::{Map, Value};
+use wick_config::config::LiquidJsonConfig;
+use wick_interface_types::Field;
+use wick_packet::{
+  ComponentReference,
+  Entity,
+  InherentData,
+  Invocation,
+  Observer,
+  Packet,
+  PacketExt,
+  PacketSender,
+  PacketStream,
+  RuntimeConfig,
+  StreamMap,
+};
+
+use crate::cache::Cache;
+use crate::error::Error;
+
+/// The outputs of one computation, in the order they were sent.
+type Outputs = Arc<Vec<Packet>>;
+
+/// An operation that sends the stored outputs for each set of inputs it receives, computing them with a linked
+/// operation on a miss.
+#[derive(Debug)]
+pub(crate) struct Operation {
+  inputs: Vec<Field>,
+  outputs: Vec<String>,
+  key: String,
+  component: String,
+  operation: String,
+  root_config: Option<RuntimeConfig>,
+  cache: Cache<Outputs>,
+}
+
+impl Operation {
+  pub(crate) fn new(
+    inputs: Vec<Field>,
+    outputs: &[Field],
+    key: String,
+    compute: &str,
+    ttl: Duration,
+    stale: Duration,
+    root_config: Option<RuntimeConfig>,
+  ) -> Result<Self, Error> {
+    let (component, operation) = compute
+      .split_once("::")
+      .filter(|(component, operation)| !component.is_empty() && !operation.is_empty())
+      .ok_or_else(|| Error::Compute(compute.to_owned()))?;
+    Ok(Self {
+      inputs,
+      outputs: outputs.iter().map(|field| field.name.clone()).collect(),
+      key,
+      component: component.to_owned(),
+      operation: operation.to_owned(),
+      root_config,
+      cache: Cache::new(ttl, stale),
+    })
+  }
+
+  /// Process the invocation's stream, sending the outputs for each set of packets received on every input.
+  pub(crate) fn run(
+    self: Arc<Self>,
+    invocation: Invocation,
+    op_config: Option<RuntimeConfig>,
+    callback: LocalScope,
+  ) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let ports = self.inputs.iter().map(|field| field.name.clone()).collect::<Vec<_>>();
+    let origin = invocation.target().clone();
+    let inherent = invocation.inherent().unsafe_clone();
+    let span = invocation.span().clone();
+    let mut stream = StreamMap::from_stream(invocation.into_stream(), ports);
+
+    tokio::spawn(async move {
+      let request = Request {
+        origin,
+        inherent,
+        op_config,
+        callback,
+        span: span.clone(),
+      };
+      if let Err(e) = self.each_set(&mut stream, &tx, &request).await {
+        span.in_scope(|| error!(error = %e, "cache"));
+        let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+      }
+      for port in &self.outputs {
+        let _ = tx.send(Packet::done(port));
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+
+  async fn each_set(
+    &self,
+    stream: &mut StreamMap,
+    tx: &PacketSender,
+    request: &Request,
+  ) -> Result<(), wick_packet::Error> {
+    while let Some(set) = stream.next_set().await? {
+      if set.values().any(Packet::is_done) {
+        break;
+      }
+      if let Some(packet) = set.values().find(|packet| packet.is_error()) {
+        let error = packet.clone().unwrap_err();
+        for port in &self.outputs {
+          let _ = tx.send(Packet::raw_err(port, error.clone()));
+        }
+        continue;
+      }
+      if !set.values().all(Packet::has_data) {
+        continue;
+      }
+      let packets = match self.render_key(&set, request) {
+        Ok(key) => self.cache.get_or_compute(key, || self.compute(set, request)).await,
+        Err(e) => Arc::new(self.errors(&e.to_string())),
+      };
+      for packet in &*packets {
+        let _ = tx.send(packet.clone());
+      }
+    }
+    Ok(())
+  }
+
+  /// Render the key for a set of inputs, with each input's value by its name.
+  fn render_key(&self, set: &HashMap<String, Packet>, request: &Request) -> Result<String, Error> {
+    let inputs: Map<String, Value> = set
+      .iter()
+      .map(|(port, packet)| (port.clone(), packet.decode_value().unwrap_or(Value::Null)))
+      .collect();
+    let ctx = LiquidJsonConfig::make_context(
+      Some(Value::Object(inputs)),
+      self.root_config.as_ref(),
+      request.op_config.as_ref(),
+      None,
+      Some(&request.inherent),
+    )
+    .map_err(|e| Error::Key(e.to_string()))?;
+    liquid_json::render_string(&self.key, &ctx).map_err(|e| Error::Key(e.to_string()))
+  }
+
+  /// Send a set of inputs to the linked operation and collect what it outputs. Outputs that include an error are
+  /// returned as `Err` so they aren't stored.
+  fn compute(
+    &self,
+    set: HashMap<String, Packet>,
+    request: &Request,
+  ) -> futures::future::BoxFuture<'static, Result<Outputs, Outputs>> {
+    let mut packets: Vec<Packet> = set.values().cloned().collect();
+    packets.extend(set.into_keys().map(Packet::done));
+    let compref = ComponentReference::new(request.origin.clone(), Entity::component(&self.component));
+    let invocation = request.callback.invoke(
+      compref,
+      self.operation.clone(),
+      PacketStream::from(packets),
+      request.inherent.next(),
+      None,
+      &request.span,
+    );
+    let outputs = self.outputs.clone();
+
+    async move {
+      let mut stream = match invocation.await {
+        Ok(stream) => stream,
+        Err(e) => return Err(Arc::new(errors_for(&outputs, &e.to_string()))),
+      };
+      let mut packets = Vec::new();
+      let mut ok = true;
+      while let Some(packet) = stream.next().await {
+        match packet {
+          Ok(packet) if packet.is_done() => {}
+          Ok(packet) => {
+            ok &= !packet.is_error();
+            packets.push(packet);
+          }
+          Err(e) => {
+            packets.extend(errors_for(&outputs, &e.to_string()));
+            ok = false;
+          }
+        }
+      }
+      if ok {
+        Ok(Arc::new(packets))
+      } else {
+        Err(Arc::new(packets))
+      }
+    }
+    .boxed()
+  }
+
+  fn errors(&self, message: &str) -> Vec<Packet> {
+    errors_for(&self.outputs, message)
+  }
+}
+
+/// An error packet on each of `outputs`.
+fn errors_for(outputs: &[String], message: &str) -> Vec<Packet> {
+  outputs.iter().map(|port| Packet::err(port, message)).collect()
+}
+
+/// What an invocation of the operation needs to compute misses with.
+struct Request {
+  origin: Entity,
+  inherent: InherentData,
+  op_config: Option<RuntimeConfig>,
+  callback: LocalScope,
+  span: tracing::Span,
+}
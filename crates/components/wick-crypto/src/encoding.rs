@@ -0,0 +1,72 @@
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use wick_config::config::components::CryptoEncoding;
+
+use crate::error::Error;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// `bytes` as text in `encoding`.
+pub(crate) fn encode(bytes: &[u8], encoding: CryptoEncoding) -> String {
+  match encoding {
+    CryptoEncoding::Hex => bytes
+      .iter()
+      .flat_map(|b| {
+        [
+          char::from(HEX[usize::from(b >> 4)]),
+          char::from(HEX[usize::from(b & 0xf)]),
+        ]
+      })
+      .collect(),
+    CryptoEncoding::Base64 => STANDARD.encode(bytes),
+    CryptoEncoding::Base64Url => URL_SAFE_NO_PAD.encode(bytes),
+  }
+}
+
+/// The bytes `text` encodes in `encoding`. Hex may be in either case.
+pub(crate) fn decode(text: &str, encoding: CryptoEncoding) -> Result<Vec<u8>, Error> {
+  let error = || Error::Encoding(encoding.to_string());
+  match encoding {
+    CryptoEncoding::Hex => {
+      if text.len() % 2 != 0 {
+        return Err(error());
+      }
+      text
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+          let digit = |c: u8| char::from(c).to_digit(16).ok_or_else(error);
+          u8::try_from(digit(pair[0])? << 4 | digit(pair[1])?).map_err(|_| error())
+        })
+        .collect()
+    }
+    CryptoEncoding::Base64 => STANDARD.decode(text).map_err(|_| error()),
+    CryptoEncoding::Base64Url => URL_SAFE_NO_PAD.decode(text).map_err(|_| error()),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_roundtrip() -> anyhow::Result<()> {
+    let bytes = [0x00, 0x0f, 0xa5, 0xff, 0xfb];
+    assert_eq!(encode(&bytes, CryptoEncoding::Hex), "000fa5fffb");
+    assert_eq!(encode(&bytes, CryptoEncoding::Base64), "AA+l//s=");
+    assert_eq!(encode(&bytes, CryptoEncoding::Base64Url), "AA-l__s");
+    for encoding in [CryptoEncoding::Hex, CryptoEncoding::Base64, CryptoEncoding::Base64Url] {
+      assert_eq!(decode(&encode(&bytes, encoding), encoding)?, bytes);
+    }
+    assert_eq!(decode("000FA5FFFB", CryptoEncoding::Hex)?, bytes);
+    assert_eq!(
+      decode("0f0", CryptoEncoding::Hex),
+      Err(Error::Encoding("hex".to_owned()))
+    );
+    assert_eq!(
+      decode("zz", CryptoEncoding::Hex),
+      Err(Error::Encoding("hex".to_owned()))
+    );
+    Ok(())
+  }
+}
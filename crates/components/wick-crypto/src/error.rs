@@ -0,0 +1,36 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Operation {0} can't {1} with {2}")]
+  Algorithm(String, String, String),
+
+  #[error("Operation {0} needs a key")]
+  NoKey(String),
+
+  #[error("Operation {0} doesn't use a key")]
+  UnusedKey(String),
+
+  #[error("Could not find the secret for key {0}")]
+  KeyNotFound(String),
+
+  #[error("Invalid key for operation {0}: {1}")]
+  Key(String, String),
+
+  #[error("Could not sign: {0}")]
+  Sign(String),
+
+  #[error("Invalid {0} encoding")]
+  Encoding(String),
+
+  #[error("Invalid token: {0}")]
+  Token(String),
+
+  #[error("Claims must be an object, found {0}")]
+  Claims(String),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
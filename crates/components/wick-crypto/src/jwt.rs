@@ -0,0 +1,203 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde_json::{json, Map, Value};
+use wick_config::config::components::CryptoAlgorithm;
+
+use crate::error::Error;
+use crate::keys::Key;
+
+/// The claims a JWT operation adds to tokens or checks of them.
+#[derive(Debug)]
+pub(crate) struct Jwt {
+  algorithm: &'static str,
+  issuer: Option<String>,
+  audience: Option<String>,
+  expires: Option<u64>,
+  leeway: u64,
+}
+
+impl Jwt {
+  /// The settings for tokens signed with `algorithm`, if JWTs can be.
+  pub(crate) fn new(
+    algorithm: CryptoAlgorithm,
+    issuer: Option<String>,
+    audience: Option<String>,
+    expires: Option<u64>,
+    leeway: u64,
+  ) -> Option<Self> {
+    let algorithm = match algorithm {
+      CryptoAlgorithm::Sha256 => "HS256",
+      CryptoAlgorithm::Sha384 => "HS384",
+      CryptoAlgorithm::Sha512 => "HS512",
+      CryptoAlgorithm::Ed25519 => "EdDSA",
+      CryptoAlgorithm::EcdsaP256 => "ES256",
+      CryptoAlgorithm::EcdsaP384 => "ES384",
+      CryptoAlgorithm::Blake3 => return None,
+    };
+    Some(Self {
+      algorithm,
+      issuer,
+      audience,
+      expires,
+      leeway,
+    })
+  }
+
+  /// A token of `claims` signed with `key`, with `iat` set to `now` and `exp`, `iss`, and `aud` set when they're
+  /// configured.
+  pub(crate) fn issue(&self, key: &Key, mut claims: Map<String, Value>, now: u64) -> Result<String, Error> {
+    claims.insert("iat".to_owned(), now.into());
+    if let Some(expires) = self.expires {
+      claims.insert("exp".to_owned(), now.saturating_add(expires).into());
+    }
+    if let Some(issuer) = &self.issuer {
+      claims.insert("iss".to_owned(), issuer.clone().into());
+    }
+    if let Some(audience) = &self.audience {
+      claims.insert("aud".to_owned(), audience.clone().into());
+    }
+
+    let header = json!({ "alg": self.algorithm, "typ": "JWT" });
+    let signed = format!("{}.{}", segment(&header), segment(&Value::Object(claims)));
+    let signature = key.sign(signed.as_bytes()).map_err(Error::Sign)?;
+    Ok(format!("{}.{}", signed, URL_SAFE_NO_PAD.encode(signature)))
+  }
+
+  /// The claims of `token` once its algorithm, signature, time, issuer, and audience claims are checked.
+  pub(crate) fn verify(&self, key: &Key, token: &str, now: u64) -> Result<Map<String, Value>, Error> {
+    let invalid = |reason: &str| Error::Token(reason.to_owned());
+    let (signed, signature) = token.rsplit_once('.').ok_or_else(|| invalid("malformed token"))?;
+    let (header, claims) = signed.split_once('.').ok_or_else(|| invalid("malformed token"))?;
+
+    let header = decode_segment(header)?;
+    if header.get("alg").and_then(Value::as_str) != Some(self.algorithm) {
+      return Err(invalid("unexpected token algorithm"));
+    }
+    let signature = URL_SAFE_NO_PAD
+      .decode(signature)
+      .map_err(|_| invalid("malformed token signature"))?;
+    if !key.verify(signed.as_bytes(), &signature) {
+      return Err(invalid("invalid token signature"));
+    }
+
+    let claims = match decode_segment(claims)? {
+      Value::Object(claims) => claims,
+      _ => return Err(invalid("token claims are not an object")),
+    };
+    if let Some(exp) = claims.get("exp") {
+      if exp.as_u64().map_or(true, |exp| exp.saturating_add(self.leeway) <= now) {
+        return Err(invalid("token has expired"));
+      }
+    }
+    if let Some(nbf) = claims.get("nbf") {
+      if nbf.as_u64().map_or(true, |nbf| nbf > now.saturating_add(self.leeway)) {
+        return Err(invalid("token is not yet valid"));
+      }
+    }
+    if let Some(issuer) = &self.issuer {
+      if claims.get("iss").and_then(Value::as_str) != Some(issuer) {
+        return Err(invalid("token issuer does not match"));
+      }
+    }
+    if let Some(audience) = &self.audience {
+      let valid = match claims.get("aud") {
+        Some(Value::String(aud)) => aud == audience,
+        Some(Value::Array(auds)) => auds.iter().any(|aud| aud.as_str() == Some(audience)),
+        _ => false,
+      };
+      if !valid {
+        return Err(invalid("token audience does not match"));
+      }
+    }
+    Ok(claims)
+  }
+}
+
+pub(crate) fn now() -> u64 {
+  SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+fn segment(value: &Value) -> String {
+  URL_SAFE_NO_PAD.encode(value.to_string())
+}
+
+fn decode_segment(segment: &str) -> Result<Value, Error> {
+  let bytes = URL_SAFE_NO_PAD
+    .decode(segment)
+    .map_err(|_| Error::Token("malformed token".to_owned()))?;
+  serde_json::from_slice(&bytes).map_err(|_| Error::Token("malformed token".to_owned()))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::keys::test::keypair;
+
+  fn claims() -> Map<String, Value> {
+    json!({ "sub": "ada" }).as_object().unwrap().clone()
+  }
+
+  #[test]
+  fn test_hmac() -> anyhow::Result<()> {
+    let key = Key::hmac(CryptoAlgorithm::Sha256, "secret").map_err(anyhow::Error::msg)?;
+    let jwt = Jwt::new(
+      CryptoAlgorithm::Sha256,
+      Some("wick".to_owned()),
+      Some("app".to_owned()),
+      Some(60),
+      5,
+    )
+    .unwrap();
+    let token = jwt.issue(&key, claims(), 1000)?;
+    let verified = jwt.verify(&key, &token, 1030)?;
+    assert_eq!(
+      Value::Object(verified),
+      json!({ "sub": "ada", "iat": 1000, "exp": 1060, "iss": "wick", "aud": "app" })
+    );
+    assert!(jwt.verify(&key, &token, 1064).is_ok());
+    assert_eq!(
+      jwt.verify(&key, &token, 1065),
+      Err(Error::Token("token has expired".to_owned()))
+    );
+
+    let other = Key::hmac(CryptoAlgorithm::Sha256, "other").map_err(anyhow::Error::msg)?;
+    assert_eq!(
+      jwt.verify(&other, &token, 1030),
+      Err(Error::Token("invalid token signature".to_owned()))
+    );
+    let strict = Jwt::new(CryptoAlgorithm::Sha256, None, Some("admin".to_owned()), None, 0).unwrap();
+    assert_eq!(
+      strict.verify(&key, &token, 1030),
+      Err(Error::Token("token audience does not match".to_owned()))
+    );
+    let hs512 = Jwt::new(CryptoAlgorithm::Sha512, None, None, None, 0).unwrap();
+    assert_eq!(
+      hs512.verify(&key, &token, 1030),
+      Err(Error::Token("unexpected token algorithm".to_owned()))
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_signature() -> anyhow::Result<()> {
+    for algorithm in [
+      CryptoAlgorithm::Ed25519,
+      CryptoAlgorithm::EcdsaP256,
+      CryptoAlgorithm::EcdsaP384,
+    ] {
+      let (private, public) = keypair(algorithm);
+      let signer = Key::private(algorithm, &private).map_err(anyhow::Error::msg)?;
+      let verifier = Key::public(algorithm, &public).map_err(anyhow::Error::msg)?;
+      let jwt = Jwt::new(algorithm, None, None, None, 0).unwrap();
+      let token = jwt.issue(&signer, claims(), 1000)?;
+      assert_eq!(
+        Value::Object(jwt.verify(&verifier, &token, 1000)?),
+        json!({ "sub": "ada", "iat": 1000 })
+      );
+      assert!(jwt.verify(&verifier, "a.b.c", 1000).is_err());
+    }
+    Ok(())
+  }
+}
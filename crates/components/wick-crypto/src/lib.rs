@@ -0,0 +1,17 @@
+// !!START_LINTS
+// Wick lints
+// Do not change anything between the START_LINTS and END_LINTS line.
+// Add exceptions here
+#![allow(missing_docs)]
+mod component;
+mod encoding;
+mod error;
+mod jwt;
+mod keys;
+mod operation;
+
+#[macro_use]
+extern crate tracing;
+
+pub use component::CryptoComponent;
+pub use error::Error;
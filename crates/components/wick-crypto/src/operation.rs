@@ -0,0 +1,417 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use futures::StreamExt;
+use ring::digest;
+use serde_json::Value;
+use wick_config::config::components::{
+  CryptoAction,
+  CryptoAlgorithm,
+  CryptoEncoding,
+  CryptoOperationDefinition,
+  OperationConfig,
+};
+use wick_config::config::Secret;
+use wick_packet::{Base64Bytes, Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::encoding;
+use crate::error::Error;
+use crate::jwt::{self, Jwt};
+use crate::keys::Key;
+
+/// What an operation does, with the key it does it with.
+#[derive(Debug)]
+enum Action {
+  Hash(CryptoAlgorithm),
+  Sign(Key),
+  Verify(Key),
+  JwtIssue(Key, Jwt),
+  JwtVerify(Key, Jwt),
+}
+
+/// A hash, HMAC, sign, verify, or JWT operation.
+#[derive(Debug)]
+pub(crate) struct Operation {
+  action: Action,
+  encoding: CryptoEncoding,
+}
+
+impl Operation {
+  /// Check that the operation's algorithm suits its action and read its key from `keys`, the secrets of the
+  /// component's key bindings.
+  pub(crate) fn new(config: &CryptoOperationDefinition, keys: &HashMap<String, Secret>) -> Result<Self, Error> {
+    let name = config.name();
+    let action = *config.action();
+    let algorithm = *config.algorithm();
+    let hmac = matches!(
+      algorithm,
+      CryptoAlgorithm::Sha256 | CryptoAlgorithm::Sha384 | CryptoAlgorithm::Sha512
+    );
+    let signature = matches!(
+      algorithm,
+      CryptoAlgorithm::Ed25519 | CryptoAlgorithm::EcdsaP256 | CryptoAlgorithm::EcdsaP384
+    );
+    let supported = match action {
+      CryptoAction::Hash => !signature,
+      CryptoAction::Hmac => hmac,
+      CryptoAction::Sign => signature,
+      CryptoAction::Verify | CryptoAction::JwtIssue | CryptoAction::JwtVerify => hmac || signature,
+    };
+    if !supported {
+      return Err(Error::Algorithm(
+        name.to_owned(),
+        action.to_string(),
+        algorithm.to_string(),
+      ));
+    }
+
+    let secret = match (action, config.key()) {
+      (CryptoAction::Hash, None) => None,
+      (CryptoAction::Hash, Some(_)) => return Err(Error::UnusedKey(name.to_owned())),
+      (_, None) => return Err(Error::NoKey(name.to_owned())),
+      (_, Some(binding)) => Some(
+        keys
+          .get(binding.id())
+          .ok_or_else(|| Error::KeyNotFound(binding.id().to_owned()))?,
+      ),
+    };
+    let key = secret
+      .map(|secret| {
+        if hmac {
+          Key::hmac(algorithm, secret.expose())
+        } else if matches!(action, CryptoAction::Sign | CryptoAction::JwtIssue) {
+          Key::private(algorithm, secret.expose())
+        } else {
+          Key::public(algorithm, secret.expose())
+        }
+      })
+      .transpose()
+      .map_err(|e| Error::Key(name.to_owned(), e))?;
+    let jwt = Jwt::new(
+      algorithm,
+      config.issuer().cloned(),
+      config.audience().cloned(),
+      config.expires(),
+      config.leeway().unwrap_or(0),
+    );
+
+    let action = match (action, key, jwt) {
+      (CryptoAction::Hash, _, _) => Action::Hash(algorithm),
+      (CryptoAction::Hmac | CryptoAction::Sign, Some(key), _) => Action::Sign(key),
+      (CryptoAction::Verify, Some(key), _) => Action::Verify(key),
+      (CryptoAction::JwtIssue, Some(key), Some(jwt)) => Action::JwtIssue(key, jwt),
+      (CryptoAction::JwtVerify, Some(key), Some(jwt)) => Action::JwtVerify(key, jwt),
+      _ => return Err(Error::NoKey(name.to_owned())),
+    };
+    Ok(Self {
+      action,
+      encoding: config.encoding().copied().unwrap_or(CryptoEncoding::Hex),
+    })
+  }
+
+  /// The ports the operation reads a packet from for each one it outputs.
+  const fn ports(&self) -> &'static [&'static str] {
+    match self.action {
+      Action::Hash(_) | Action::Sign(_) => &["input"],
+      Action::Verify(_) => &["input", "signature"],
+      Action::JwtIssue(..) => &["claims"],
+      Action::JwtVerify(..) => &["token"],
+    }
+  }
+
+  /// Process the invocation's stream, sending the result for each of its inputs on the `output` port.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      let mut inputs = Inputs::new(self.ports());
+      let result = async {
+        while let Some(packets) = inputs.next(&mut stream, &tx).await? {
+          let _ = match self.process(&packets) {
+            Ok(packet) => tx.send(packet),
+            Err(e) => tx.send(Packet::err("output", e.to_string())),
+          };
+        }
+        Ok::<_, Error>(())
+      }
+      .await;
+      match result {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("output"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "crypto"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+
+  /// The output for one packet from each of the operation's ports.
+  fn process(&self, packets: &[Packet]) -> Result<Packet, Error> {
+    let output = match &self.action {
+      Action::Hash(algorithm) => {
+        let digest = hash(*algorithm, &bytes(&packets[0])?);
+        Packet::encode("output", encoding::encode(&digest, self.encoding))
+      }
+      Action::Sign(key) => {
+        let signature = key.sign(&bytes(&packets[0])?).map_err(Error::Sign)?;
+        Packet::encode("output", encoding::encode(&signature, self.encoding))
+      }
+      Action::Verify(key) => {
+        let data = bytes(&packets[0])?;
+        let signature = packets[1].decode::<String>().map_err(|e| Error::Input(e.to_string()))?;
+        let valid = encoding::decode(signature.trim(), self.encoding).map_or(false, |s| key.verify(&data, &s));
+        Packet::encode("output", valid)
+      }
+      Action::JwtIssue(key, jwt) => {
+        let claims = match packets[0].decode_value().map_err(|e| Error::Input(e.to_string()))? {
+          Value::Object(claims) => claims,
+          value => return Err(Error::Claims(kind(&value).to_owned())),
+        };
+        Packet::encode("output", jwt.issue(key, claims, jwt::now())?)
+      }
+      Action::JwtVerify(key, jwt) => {
+        let token = packets[0].decode::<String>().map_err(|e| Error::Input(e.to_string()))?;
+        let claims = jwt.verify(key, token.trim(), jwt::now())?;
+        Packet::encode("output", Value::Object(claims))
+      }
+    };
+    Ok(output)
+  }
+}
+
+/// Packets from an operation's ports, queued until each port has one so they can be processed together.
+struct Inputs {
+  ports: &'static [&'static str],
+  queues: Vec<VecDeque<Packet>>,
+  done: Vec<bool>,
+}
+
+impl Inputs {
+  fn new(ports: &'static [&'static str]) -> Self {
+    Self {
+      ports,
+      queues: vec![VecDeque::new(); ports.len()],
+      done: vec![false; ports.len()],
+    }
+  }
+
+  /// The next packet from each port, forwarding errors from them to the `output` port. Once a port is done and its
+  /// queue is empty, nothing more can be processed.
+  async fn next(&mut self, stream: &mut PacketStream, tx: &PacketSender) -> Result<Option<Vec<Packet>>, Error> {
+    loop {
+      if self.queues.iter().all(|queue| !queue.is_empty()) {
+        return Ok(Some(self.queues.iter_mut().filter_map(VecDeque::pop_front).collect()));
+      }
+      if self
+        .queues
+        .iter()
+        .zip(&self.done)
+        .any(|(queue, done)| *done && queue.is_empty())
+      {
+        return Ok(None);
+      }
+      let Some(next) = stream.next().await else {
+        return Ok(None);
+      };
+      let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+      let Some(index) = self.ports.iter().position(|port| *port == packet.port()) else {
+        continue;
+      };
+      if packet.is_done() {
+        self.done[index] = true;
+      } else if packet.is_error() {
+        let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+      } else if packet.has_data() {
+        self.queues[index].push_back(packet);
+      }
+    }
+  }
+}
+
+fn bytes(packet: &Packet) -> Result<Base64Bytes, Error> {
+  packet.decode::<Base64Bytes>().map_err(|e| Error::Input(e.to_string()))
+}
+
+fn hash(algorithm: CryptoAlgorithm, data: &[u8]) -> Vec<u8> {
+  let algorithm = match algorithm {
+    CryptoAlgorithm::Blake3 => return blake3::hash(data).as_bytes().to_vec(),
+    CryptoAlgorithm::Sha384 => &digest::SHA384,
+    CryptoAlgorithm::Sha512 => &digest::SHA512,
+    _ => &digest::SHA256,
+  };
+  digest::digest(algorithm, data).as_ref().to_vec()
+}
+
+const fn kind(value: &Value) -> &'static str {
+  match value {
+    Value::Null => "null",
+    Value::Bool(_) => "a boolean",
+    Value::Number(_) => "a number",
+    Value::String(_) => "a string",
+    Value::Array(_) => "an array",
+    Value::Object(_) => "an object",
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use serde_json::json;
+  use wick_config::config::components::CryptoOperationDefinitionBuilder;
+  use wick_config::config::BoundIdentifier;
+
+  use super::*;
+  use crate::keys::test::keypair;
+
+  fn operation(action: CryptoAction, algorithm: CryptoAlgorithm) -> CryptoOperationDefinitionBuilder {
+    let mut builder = CryptoOperationDefinitionBuilder::default();
+    builder.name("op").action(action).algorithm(algorithm);
+    builder
+  }
+
+  fn keys(key: &str) -> HashMap<String, Secret> {
+    HashMap::from([("KEY".to_owned(), Secret::new(key))])
+  }
+
+  fn output(op: &Operation, packets: &[Packet]) -> Result<Value, Error> {
+    op.process(packets)?
+      .decode_value()
+      .map_err(|e| Error::Input(e.to_string()))
+  }
+
+  #[test]
+  fn test_new() -> anyhow::Result<()> {
+    let config = operation(CryptoAction::Hmac, CryptoAlgorithm::Ed25519).build()?;
+    assert_eq!(
+      Operation::new(&config, &HashMap::new()).unwrap_err(),
+      Error::Algorithm("op".to_owned(), "hmac".to_owned(), "ed25519".to_owned())
+    );
+    let config = operation(CryptoAction::JwtIssue, CryptoAlgorithm::Blake3).build()?;
+    assert!(matches!(
+      Operation::new(&config, &HashMap::new()),
+      Err(Error::Algorithm(..))
+    ));
+    let config = operation(CryptoAction::Sign, CryptoAlgorithm::Ed25519).build()?;
+    assert_eq!(
+      Operation::new(&config, &HashMap::new()).unwrap_err(),
+      Error::NoKey("op".to_owned())
+    );
+    let config = operation(CryptoAction::Hash, CryptoAlgorithm::Sha256)
+      .key(Some(BoundIdentifier::from("KEY")))
+      .build()?;
+    assert_eq!(
+      Operation::new(&config, &keys("secret")).unwrap_err(),
+      Error::UnusedKey("op".to_owned())
+    );
+    let config = operation(CryptoAction::Hmac, CryptoAlgorithm::Sha256)
+      .key(Some(BoundIdentifier::from("OTHER")))
+      .build()?;
+    assert_eq!(
+      Operation::new(&config, &keys("secret")).unwrap_err(),
+      Error::KeyNotFound("OTHER".to_owned())
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_hash() -> anyhow::Result<()> {
+    let input = Packet::encode("input", Base64Bytes::new(b"abc".to_vec()));
+    let sha256 = Operation::new(
+      &operation(CryptoAction::Hash, CryptoAlgorithm::Sha256).build()?,
+      &HashMap::new(),
+    )?;
+    assert_eq!(
+      output(&sha256, &[input.clone()])?,
+      json!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+    );
+    let blake3 = Operation::new(
+      &operation(CryptoAction::Hash, CryptoAlgorithm::Blake3)
+        .encoding(Some(CryptoEncoding::Base64Url))
+        .build()?,
+      &HashMap::new(),
+    )?;
+    assert_eq!(
+      output(&blake3, &[input])?,
+      json!(encoding::encode(
+        blake3::hash(b"abc").as_bytes(),
+        CryptoEncoding::Base64Url
+      ))
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_sign_verify() -> anyhow::Result<()> {
+    let (private, public) = keypair(CryptoAlgorithm::Ed25519);
+    let key = Some(BoundIdentifier::from("KEY"));
+    let sign = Operation::new(
+      &operation(CryptoAction::Sign, CryptoAlgorithm::Ed25519)
+        .key(key.clone())
+        .encoding(Some(CryptoEncoding::Base64))
+        .build()?,
+      &keys(&private),
+    )?;
+    let verify = Operation::new(
+      &operation(CryptoAction::Verify, CryptoAlgorithm::Ed25519)
+        .key(key)
+        .encoding(Some(CryptoEncoding::Base64))
+        .build()?,
+      &keys(&public),
+    )?;
+    let input = Packet::encode("input", Base64Bytes::new(b"hello".to_vec()));
+    let signature = output(&sign, &[input.clone()])?;
+    let signature = signature.as_str().unwrap();
+    assert_eq!(
+      output(&verify, &[input, Packet::encode("signature", signature)])?,
+      json!(true)
+    );
+    let other = Packet::encode("input", Base64Bytes::new(b"goodbye".to_vec()));
+    assert_eq!(
+      output(&verify, &[other.clone(), Packet::encode("signature", signature)])?,
+      json!(false)
+    );
+    assert_eq!(
+      output(&verify, &[other, Packet::encode("signature", "not base64!")])?,
+      json!(false)
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_jwt() -> anyhow::Result<()> {
+    let key = Some(BoundIdentifier::from("KEY"));
+    let issue = Operation::new(
+      &operation(CryptoAction::JwtIssue, CryptoAlgorithm::Sha384)
+        .key(key.clone())
+        .issuer(Some("wick".to_owned()))
+        .build()?,
+      &keys("secret"),
+    )?;
+    let verify = Operation::new(
+      &operation(CryptoAction::JwtVerify, CryptoAlgorithm::Sha384)
+        .key(key)
+        .issuer(Some("wick".to_owned()))
+        .build()?,
+      &keys("secret"),
+    )?;
+    let token = output(&issue, &[Packet::encode("claims", json!({ "sub": "ada" }))])?;
+    let claims = output(&verify, &[Packet::encode("token", token)])?;
+    assert_eq!(claims["sub"], json!("ada"));
+    assert_eq!(claims["iss"], json!("wick"));
+    assert_eq!(
+      output(&issue, &[Packet::encode("claims", json!(["ada"]))]),
+      Err(Error::Claims("an array".to_owned()))
+    );
+    assert!(matches!(
+      output(&verify, &[Packet::encode("token", "a.b.c")]),
+      Err(Error::Token(_))
+    ));
+    Ok(())
+  }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, DataComponentConfig, OperationConfig};
+use wick_config::config::Metadata;
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+
+/// A component whose operations parse CSV, NDJSON, and XML into objects and serialize objects into them.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct DataComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl DataComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: DataComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    _resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/data");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| Ok((op.name().to_owned(), Arc::new(Operation::new(op)?))))
+      .collect::<Result<_, Error>>()?;
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for DataComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<DataComponent>();
+  }
+}
@@ -0,0 +1,154 @@
+//! CSV as RFC 4180 describes it, read a record at a time from chunks split anywhere.
+
+use crate::error::Error;
+
+/// Splits CSV into records as its bytes arrive.
+#[derive(Debug)]
+pub(crate) struct CsvReader {
+  delimiter: char,
+  buffer: Vec<u8>,
+  /// How much of the buffer has been looked through for the end of a record.
+  scanned: usize,
+  /// Whether the scan has stopped inside a quoted field.
+  quoted: bool,
+}
+
+impl CsvReader {
+  pub(crate) const fn new(delimiter: char) -> Self {
+    Self {
+      delimiter,
+      buffer: Vec::new(),
+      scanned: 0,
+      quoted: false,
+    }
+  }
+
+  /// Add a chunk, returning the fields of every record it completes. Blank lines are skipped.
+  pub(crate) fn push(&mut self, chunk: &[u8]) -> Vec<Result<Vec<String>, Error>> {
+    self.buffer.extend_from_slice(chunk);
+    let mut records = Vec::new();
+    while let Some(record) = self.next_record() {
+      if !record.is_empty() {
+        records.push(self.fields(&record));
+      }
+    }
+    records
+  }
+
+  /// The fields of the last record, when the data doesn't end in a newline.
+  pub(crate) fn finish(&mut self) -> Option<Result<Vec<String>, Error>> {
+    if self.quoted {
+      return Some(Err(Error::Csv("the data ends inside a quoted field".to_owned())));
+    }
+    let record = std::mem::take(&mut self.buffer);
+    self.scanned = 0;
+    (!record.is_empty()).then(|| self.fields(&record))
+  }
+
+  /// The bytes of the next complete record, without its line ending. Quotes, delimiters, and line endings are ASCII
+  /// so they're never part of a multi-byte character.
+  fn next_record(&mut self) -> Option<Vec<u8>> {
+    while self.scanned < self.buffer.len() {
+      let byte = self.buffer[self.scanned];
+      self.scanned += 1;
+      if byte == b'"' {
+        // an escaped quote toggles this twice.
+        self.quoted = !self.quoted;
+      } else if byte == b'\n' && !self.quoted {
+        let mut record: Vec<u8> = self.buffer.drain(..self.scanned).collect();
+        self.scanned = 0;
+        record.pop();
+        if record.last() == Some(&b'\r') {
+          record.pop();
+        }
+        return Some(record);
+      }
+    }
+    None
+  }
+
+  fn fields(&self, record: &[u8]) -> Result<Vec<String>, Error> {
+    let record = std::str::from_utf8(record).map_err(|_| Error::Utf8)?;
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = record.chars().peekable();
+    while let Some(c) = chars.next() {
+      if quoted {
+        if c != '"' {
+          field.push(c);
+        } else if chars.peek() == Some(&'"') {
+          chars.next();
+          field.push('"');
+        } else {
+          quoted = false;
+        }
+      } else if c == '"' {
+        quoted = true;
+      } else if c == self.delimiter {
+        fields.push(std::mem::take(&mut field));
+      } else {
+        field.push(c);
+      }
+    }
+    fields.push(field);
+    Ok(fields)
+  }
+}
+
+/// A line of CSV with `fields` separated by `delimiter`, quoting those that need it.
+pub(crate) fn write_record(fields: &[String], delimiter: char) -> String {
+  let mut line = fields
+    .iter()
+    .map(|field| {
+      if field.contains(|c: char| c == delimiter || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+      } else {
+        field.clone()
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(&delimiter.to_string());
+  line.push('\n');
+  line
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn strings(fields: &[&str]) -> Vec<String> {
+    fields.iter().map(|f| (*f).to_owned()).collect()
+  }
+
+  #[test]
+  fn test_reader() {
+    let mut reader = CsvReader::new(',');
+    let data = "name,notes\r\nada,\"likes \"\"math\"\",\nand engines\"\n\nbob,é\ncarol,";
+    let mut records: Vec<_> = data.as_bytes().chunks(3).flat_map(|chunk| reader.push(chunk)).collect();
+    records.extend(reader.finish());
+    let records: Vec<_> = records.into_iter().map(Result::unwrap).collect();
+    assert_eq!(
+      records,
+      vec![
+        strings(&["name", "notes"]),
+        strings(&["ada", "likes \"math\",\nand engines"]),
+        strings(&["bob", "é"]),
+        strings(&["carol", ""]),
+      ]
+    );
+
+    let mut reader = CsvReader::new(';');
+    assert_eq!(reader.push(b"a;\"b"), vec![]);
+    assert!(reader.finish().unwrap().is_err());
+  }
+
+  #[test]
+  fn test_write_record() {
+    let fields = strings(&["plain", "with,comma", "with \"quotes\"", ""]);
+    let line = write_record(&fields, ',');
+    assert_eq!(line, "plain,\"with,comma\",\"with \"\"quotes\"\"\",\n");
+    let mut reader = CsvReader::new(',');
+    assert_eq!(reader.push(line.as_bytes()), vec![Ok(fields)]);
+  }
+}
@@ -0,0 +1,27 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("CSV delimiters must be a single ASCII character, found '{0}'")]
+  Delimiter(String),
+
+  #[error("Invalid CSV: {0}")]
+  Csv(String),
+
+  #[error("Invalid NDJSON on line {0}: {1}")]
+  Ndjson(usize, String),
+
+  #[error("Invalid XML: {0}")]
+  Xml(String),
+
+  #[error("Data must be UTF-8 text")]
+  Utf8,
+
+  #[error("Records must be objects, found {0}")]
+  NotAnObject(String),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
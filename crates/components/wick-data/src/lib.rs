@@ -0,0 +1,16 @@
+// !!START_LINTS
+// Wick lints
+// Do not change anything between the START_LINTS and END_LINTS line.
+// Add exceptions here
+#![allow(missing_docs)]
+mod component;
+mod csv;
+mod error;
+mod operation;
+mod xml;
+
+#[macro_use]
+extern crate tracing;
+
+pub use component::DataComponent;
+pub use error::Error;
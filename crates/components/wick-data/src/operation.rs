@@ -0,0 +1,551 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use serde_json::{Map, Value};
+use wick_config::config::components::{DataAction, DataFormat, DataOperationDefinition};
+use wick_packet::{Base64Bytes, Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::csv::{self, CsvReader};
+use crate::error::Error;
+use crate::xml;
+
+const DEFAULT_DELIMITER: char = ',';
+const DEFAULT_ELEMENT: &str = "item";
+const DEFAULT_ROOT: &str = "items";
+
+/// A parse or serialize operation.
+#[derive(Debug)]
+pub(crate) struct Operation {
+  action: DataAction,
+  format: DataFormat,
+  delimiter: char,
+  /// The columns of CSV without a header row.
+  columns: Option<Vec<String>>,
+  element: String,
+  root: String,
+  /// Field names in the data mapped to their names in the objects.
+  to_objects: HashMap<String, String>,
+  /// Field names in the objects mapped to their names in the data.
+  to_data: HashMap<String, String>,
+  typed: bool,
+}
+
+impl Operation {
+  pub(crate) fn new(config: &DataOperationDefinition) -> Result<Self, Error> {
+    let delimiter = match config.delimiter() {
+      None => DEFAULT_DELIMITER,
+      Some(delimiter) => {
+        let mut chars = delimiter.chars();
+        match (chars.next(), chars.next()) {
+          (Some(c), None) if c.is_ascii() && c != '"' && c != '\n' && c != '\r' => c,
+          _ => return Err(Error::Delimiter(delimiter.to_owned())),
+        }
+      }
+    };
+    Ok(Self {
+      action: *config.action(),
+      format: *config.format(),
+      delimiter,
+      columns: (!config.columns().is_empty()).then(|| config.columns().to_vec()),
+      element: config
+        .element()
+        .map_or_else(|| DEFAULT_ELEMENT.to_owned(), ToOwned::to_owned),
+      root: config.root().map_or_else(|| DEFAULT_ROOT.to_owned(), ToOwned::to_owned),
+      to_objects: config.mapping().clone(),
+      to_data: config.mapping().iter().map(|(k, v)| (v.clone(), k.clone())).collect(),
+      typed: config.typed(),
+    })
+  }
+
+  /// Process the invocation's stream, sending what the operation makes of it on the `output` port.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      let result = match self.action {
+        DataAction::Parse => self.parse(&mut stream, &tx).await,
+        DataAction::Serialize => self.serialize(&mut stream, &tx).await,
+      };
+      match result {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("output"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "data"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+
+  /// Send each record in the chunks of data from the `input` port as an object.
+  async fn parse(&self, stream: &mut PacketStream, tx: &PacketSender) -> Result<(), Error> {
+    let mut parser = Parser::new(self);
+    while let Some(packet) = next_input(stream, tx).await? {
+      match packet.decode::<Base64Bytes>() {
+        Ok(chunk) => self.send_records(parser.push(&chunk), tx),
+        Err(e) => {
+          let _ = tx.send(Packet::err("output", e.to_string()));
+        }
+      }
+    }
+    self.send_records(parser.finish(), tx);
+    Ok(())
+  }
+
+  fn send_records(&self, records: Vec<Result<Map<String, Value>, Error>>, tx: &PacketSender) {
+    for record in records {
+      let _ = match record {
+        Ok(record) => tx.send(Packet::encode(
+          "output",
+          Value::Object(rename(record, &self.to_objects)),
+        )),
+        Err(e) => tx.send(Packet::err("output", e.to_string())),
+      };
+    }
+  }
+
+  /// Send each object from the `input` port as a chunk of data.
+  async fn serialize(&self, stream: &mut PacketStream, tx: &PacketSender) -> Result<(), Error> {
+    let mut serializer = Serializer::new(self);
+    while let Some(packet) = next_input(stream, tx).await? {
+      let record = match packet.decode_value() {
+        Ok(Value::Object(record)) => record,
+        Ok(value) => {
+          let _ = tx.send(Packet::err(
+            "output",
+            Error::NotAnObject(kind(&value).to_owned()).to_string(),
+          ));
+          continue;
+        }
+        Err(e) => {
+          let _ = tx.send(Packet::err("output", e.to_string()));
+          continue;
+        }
+      };
+      let chunk = serializer.write(&rename(record, &self.to_data));
+      let _ = tx.send(Packet::encode("output", Base64Bytes::new(chunk.into_bytes())));
+    }
+    let chunk = serializer.finish();
+    if !chunk.is_empty() {
+      let _ = tx.send(Packet::encode("output", Base64Bytes::new(chunk.into_bytes())));
+    }
+    Ok(())
+  }
+
+  /// The value of a field read from CSV or XML.
+  fn value(&self, text: String) -> Value {
+    if self.typed {
+      typed(text)
+    } else {
+      Value::String(text)
+    }
+  }
+}
+
+/// The state of a parse operation between chunks.
+enum Parser<'a> {
+  Csv {
+    operation: &'a Operation,
+    reader: CsvReader,
+    columns: Option<Vec<String>>,
+    row: usize,
+  },
+  Ndjson {
+    buffer: Vec<u8>,
+    line: usize,
+  },
+  Xml {
+    operation: &'a Operation,
+    document: Vec<u8>,
+  },
+}
+
+impl<'a> Parser<'a> {
+  fn new(operation: &'a Operation) -> Self {
+    match operation.format {
+      DataFormat::Csv => Self::Csv {
+        operation,
+        reader: CsvReader::new(operation.delimiter),
+        columns: operation.columns.clone(),
+        row: 0,
+      },
+      DataFormat::Ndjson => Self::Ndjson {
+        buffer: Vec::new(),
+        line: 0,
+      },
+      DataFormat::Xml => Self::Xml {
+        operation,
+        document: Vec::new(),
+      },
+    }
+  }
+
+  /// The records completed by `chunk`.
+  fn push(&mut self, chunk: &[u8]) -> Vec<Result<Map<String, Value>, Error>> {
+    match self {
+      Self::Csv {
+        operation,
+        reader,
+        columns,
+        row,
+      } => reader
+        .push(chunk)
+        .into_iter()
+        .filter_map(|fields| csv_record(operation, columns, row, fields))
+        .collect(),
+      Self::Ndjson { buffer, line } => {
+        buffer.extend_from_slice(chunk);
+        let mut records = Vec::new();
+        while let Some(end) = buffer.iter().position(|b| *b == b'\n') {
+          let text: Vec<u8> = buffer.drain(..=end).collect();
+          *line += 1;
+          records.extend(ndjson_record(&text, *line));
+        }
+        records
+      }
+      Self::Xml { document, .. } => {
+        document.extend_from_slice(chunk);
+        Vec::new()
+      }
+    }
+  }
+
+  /// The records left once there's no more data.
+  fn finish(&mut self) -> Vec<Result<Map<String, Value>, Error>> {
+    match self {
+      Self::Csv {
+        operation,
+        reader,
+        columns,
+        row,
+      } => reader
+        .finish()
+        .and_then(|fields| csv_record(operation, columns, row, fields))
+        .into_iter()
+        .collect(),
+      Self::Ndjson { buffer, line } => ndjson_record(&std::mem::take(buffer), *line + 1).into_iter().collect(),
+      Self::Xml { operation, document } => {
+        let result = std::str::from_utf8(document)
+          .map_err(|_| Error::Utf8)
+          .and_then(|document| xml::records(document, &operation.element, |text| operation.value(text)));
+        match result {
+          Ok(records) => records.into_iter().map(Ok).collect(),
+          Err(e) => vec![Err(e)],
+        }
+      }
+    }
+  }
+}
+
+/// An object of a row of CSV, or nothing when the row is the header.
+fn csv_record(
+  operation: &Operation,
+  columns: &mut Option<Vec<String>>,
+  row: &mut usize,
+  fields: Result<Vec<String>, Error>,
+) -> Option<Result<Map<String, Value>, Error>> {
+  *row += 1;
+  let fields = match fields {
+    Ok(fields) => fields,
+    Err(e) => return Some(Err(e)),
+  };
+  let Some(columns) = columns else {
+    *columns = Some(fields);
+    return None;
+  };
+  if fields.len() != columns.len() {
+    return Some(Err(Error::Csv(format!(
+      "row {} has {} fields, expected {}",
+      row,
+      fields.len(),
+      columns.len()
+    ))));
+  }
+  Some(Ok(
+    columns
+      .iter()
+      .cloned()
+      .zip(fields.into_iter().map(|field| operation.value(field)))
+      .collect(),
+  ))
+}
+
+/// An object of a line of NDJSON, or nothing when the line is blank.
+fn ndjson_record(text: &[u8], line: usize) -> Option<Result<Map<String, Value>, Error>> {
+  if text.iter().all(u8::is_ascii_whitespace) {
+    return None;
+  }
+  Some(match serde_json::from_slice(text) {
+    Ok(Value::Object(record)) => Ok(record),
+    Ok(value) => Err(Error::NotAnObject(kind(&value).to_owned())),
+    Err(e) => Err(Error::Ndjson(line, e.to_string())),
+  })
+}
+
+/// The state of a serialize operation between objects.
+enum Serializer<'a> {
+  Csv {
+    operation: &'a Operation,
+    columns: Option<Vec<String>>,
+  },
+  Ndjson,
+  Xml {
+    operation: &'a Operation,
+    opened: bool,
+  },
+}
+
+impl<'a> Serializer<'a> {
+  fn new(operation: &'a Operation) -> Self {
+    match operation.format {
+      DataFormat::Csv => Self::Csv {
+        operation,
+        columns: operation.columns.clone(),
+      },
+      DataFormat::Ndjson => Self::Ndjson,
+      DataFormat::Xml => Self::Xml {
+        operation,
+        opened: false,
+      },
+    }
+  }
+
+  /// The data for `record`, after whatever has to come before the first.
+  fn write(&mut self, record: &Map<String, Value>) -> String {
+    match self {
+      Self::Csv { operation, columns } => {
+        let mut chunk = String::new();
+        if columns.is_none() {
+          let header: Vec<String> = record.keys().cloned().collect();
+          chunk.push_str(&csv::write_record(&header, operation.delimiter));
+          *columns = Some(header);
+        }
+        let fields: Vec<String> = columns
+          .iter()
+          .flatten()
+          .map(|column| record.get(column).map(csv_field).unwrap_or_default())
+          .collect();
+        chunk.push_str(&csv::write_record(&fields, operation.delimiter));
+        chunk
+      }
+      Self::Ndjson => {
+        let mut chunk = Value::Object(record.clone()).to_string();
+        chunk.push('\n');
+        chunk
+      }
+      Self::Xml { operation, opened } => {
+        let mut chunk = String::new();
+        if !*opened {
+          *opened = true;
+          xml::open_tag(&operation.root, &mut chunk);
+        }
+        chunk.push_str(&xml::write_record(&operation.element, record));
+        chunk
+      }
+    }
+  }
+
+  /// The data that has to come after the last record.
+  fn finish(&mut self) -> String {
+    match self {
+      Self::Csv { .. } | Self::Ndjson => String::new(),
+      Self::Xml { operation, opened } => {
+        let mut chunk = String::new();
+        if !*opened {
+          xml::open_tag(&operation.root, &mut chunk);
+        }
+        xml::close_tag(&operation.root, &mut chunk);
+        chunk
+      }
+    }
+  }
+}
+
+/// The next packet of data on the `input` port. Errors on it are passed on to the `output` port.
+async fn next_input(stream: &mut PacketStream, tx: &PacketSender) -> Result<Option<Packet>, Error> {
+  while let Some(next) = stream.next().await {
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    if packet.port() != "input" {
+      continue;
+    }
+    if packet.is_done() {
+      break;
+    }
+    if packet.is_error() {
+      let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+    } else if packet.has_data() {
+      return Ok(Some(packet));
+    }
+  }
+  Ok(None)
+}
+
+/// `record` with its fields renamed by `names`.
+fn rename(record: Map<String, Value>, names: &HashMap<String, String>) -> Map<String, Value> {
+  if names.is_empty() {
+    return record;
+  }
+  record
+    .into_iter()
+    .map(|(name, value)| (names.get(&name).cloned().unwrap_or(name), value))
+    .collect()
+}
+
+/// `text` as the number, boolean, or null it reads as, or as a string.
+fn typed(text: String) -> Value {
+  match text.as_str() {
+    "true" => return Value::Bool(true),
+    "false" => return Value::Bool(false),
+    "null" => return Value::Null,
+    _ => {}
+  }
+  if let Ok(n) = text.parse::<i64>() {
+    return Value::from(n);
+  }
+  // `inf` and `NaN` parse as floats but aren't numbers JSON can hold, so they stay strings.
+  match text.parse::<f64>().ok().and_then(serde_json::Number::from_f64) {
+    Some(n) => Value::Number(n),
+    None => Value::String(text),
+  }
+}
+
+/// The text of a CSV field holding `value`. Objects and arrays are written as JSON.
+fn csv_field(value: &Value) -> String {
+  match value {
+    Value::String(s) => s.clone(),
+    Value::Null => String::new(),
+    value => value.to_string(),
+  }
+}
+
+const fn kind(value: &Value) -> &'static str {
+  match value {
+    Value::Null => "null",
+    Value::Bool(_) => "a boolean",
+    Value::Number(_) => "a number",
+    Value::String(_) => "a string",
+    Value::Array(_) => "an array",
+    Value::Object(_) => "an object",
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use serde_json::json;
+  use wick_config::config::components::DataOperationDefinitionBuilder;
+
+  use super::*;
+
+  fn operation(format: DataFormat, action: DataAction) -> DataOperationDefinitionBuilder {
+    let mut builder = DataOperationDefinitionBuilder::default();
+    builder.name("op").format(format).action(action);
+    builder
+  }
+
+  fn parse(operation: &Operation, chunks: &[&str]) -> Vec<Result<Value, Error>> {
+    let mut parser = Parser::new(operation);
+    let mut records: Vec<_> = chunks.iter().flat_map(|chunk| parser.push(chunk.as_bytes())).collect();
+    records.extend(parser.finish());
+    records
+      .into_iter()
+      .map(|record| record.map(|r| Value::Object(rename(r, &operation.to_objects))))
+      .collect()
+  }
+
+  fn serialize(operation: &Operation, records: &[Value]) -> String {
+    let mut serializer = Serializer::new(operation);
+    let mut data: String = records
+      .iter()
+      .map(|record| serializer.write(&rename(record.as_object().unwrap().clone(), &operation.to_data)))
+      .collect();
+    data.push_str(&serializer.finish());
+    data
+  }
+
+  #[test]
+  fn test_csv() -> anyhow::Result<()> {
+    let config = operation(DataFormat::Csv, DataAction::Parse)
+      .typed(true)
+      .mapping(HashMap::from([("Full Name".to_owned(), "name".to_owned())]))
+      .build()?;
+    let op = Operation::new(&config)?;
+    let records = parse(&op, &["Full Name,age,active\nAda,3", "6,true\nBob,,false\nshort\n"]);
+    assert_eq!(records[0], Ok(json!({"name": "Ada", "age": 36, "active": true})));
+    assert_eq!(records[1], Ok(json!({"name": "Bob", "age": "", "active": false})));
+    assert_eq!(records[2], Err(Error::Csv("row 4 has 1 fields, expected 3".to_owned())));
+
+    let config = operation(DataFormat::Csv, DataAction::Serialize)
+      .delimiter(Some(";".to_owned()))
+      .columns(vec!["id".to_owned(), "tags".to_owned()])
+      .build()?;
+    let op = Operation::new(&config)?;
+    let data = serialize(&op, &[json!({"id": 1, "tags": ["a", "b"]}), json!({"id": "x;y"})]);
+    assert_eq!(data, "1;\"[\"\"a\"\",\"\"b\"\"]\"\n\"x;y\";\n");
+
+    let config = operation(DataFormat::Csv, DataAction::Parse)
+      .delimiter(Some("::".to_owned()))
+      .build()?;
+    assert_eq!(Operation::new(&config).unwrap_err(), Error::Delimiter("::".to_owned()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_ndjson() -> anyhow::Result<()> {
+    let config = operation(DataFormat::Ndjson, DataAction::Parse).build()?;
+    let op = Operation::new(&config)?;
+    let records = parse(&op, &["{\"a\":1}\n\n[1]\n{\"b\"", ":2}"]);
+    assert_eq!(
+      records,
+      vec![
+        Ok(json!({"a": 1})),
+        Err(Error::NotAnObject("an array".to_owned())),
+        Ok(json!({"b": 2})),
+      ]
+    );
+
+    let config = operation(DataFormat::Ndjson, DataAction::Serialize).build()?;
+    let op = Operation::new(&config)?;
+    assert_eq!(
+      serialize(&op, &[json!({"a": 1}), json!({"b": [2]})]),
+      "{\"a\":1}\n{\"b\":[2]}\n"
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_xml() -> anyhow::Result<()> {
+    let config = operation(DataFormat::Xml, DataAction::Parse)
+      .element(Some("row".to_owned()))
+      .typed(true)
+      .build()?;
+    let op = Operation::new(&config)?;
+    let records = parse(&op, &["<rows><row id=\"1\"><n>2.5</n></r", "ow><row id=\"2\"/></rows>"]);
+    assert_eq!(records, vec![Ok(json!({"id": 1, "n": 2.5})), Ok(json!({"id": 2}))]);
+
+    let config = operation(DataFormat::Xml, DataAction::Serialize).build()?;
+    let op = Operation::new(&config)?;
+    assert_eq!(serialize(&op, &[]), "<items></items>");
+    assert_eq!(
+      serialize(&op, &[json!({"n": 1})]),
+      "<items><item><n>1</n></item></items>"
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_typed() {
+    assert_eq!(typed("42".to_owned()), json!(42));
+    assert_eq!(typed("-0.5".to_owned()), json!(-0.5));
+    assert_eq!(typed("NaN".to_owned()), json!("NaN"));
+    assert_eq!(typed("inf".to_owned()), json!("inf"));
+    assert_eq!(typed("null".to_owned()), Value::Null);
+    assert_eq!(typed("Ada".to_owned()), json!("Ada"));
+  }
+}
@@ -0,0 +1,235 @@
+//! Records read from and written as the elements of an XML document.
+//!
+//! An element becomes an object of its attributes and child elements, with any text beside them in `$text`. Elements
+//! with only text become that text, and children that repeat become arrays.
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use serde_json::{Map, Value};
+
+use crate::error::Error;
+
+/// The field the text of an element with attributes or children is kept in.
+pub(crate) const TEXT: &str = "$text";
+
+/// An element being read.
+#[derive(Debug)]
+struct Node {
+  name: String,
+  fields: Map<String, Value>,
+  text: String,
+}
+
+/// Every element named `element` in `document` as an object, in the order they close. `convert` makes values of the
+/// text of attributes and elements.
+pub(crate) fn records<F>(document: &str, element: &str, convert: F) -> Result<Vec<Map<String, Value>>, Error>
+where
+  F: Fn(String) -> Value,
+{
+  let mut reader = Reader::from_str(document);
+  let mut stack: Vec<Node> = Vec::new();
+  let mut records = Vec::new();
+  loop {
+    let event = reader
+      .read_event()
+      .map_err(|e| Error::Xml(format!("at position {}: {}", reader.buffer_position(), e)))?;
+    match event {
+      Event::Start(start) => {
+        if let Some(node) = open(&start, element, !stack.is_empty(), &convert)? {
+          stack.push(node);
+        }
+      }
+      Event::Empty(start) => {
+        if let Some(node) = open(&start, element, !stack.is_empty(), &convert)? {
+          close(node, &mut stack, &mut records, &convert);
+        }
+      }
+      Event::Text(text) => {
+        if let Some(node) = stack.last_mut() {
+          let text = text.unescape().map_err(|e| Error::Xml(e.to_string()))?;
+          node.text.push_str(&text);
+        }
+      }
+      Event::CData(data) => {
+        if let Some(node) = stack.last_mut() {
+          node.text.push_str(&String::from_utf8_lossy(&data.into_inner()));
+        }
+      }
+      Event::End(_) => {
+        if let Some(node) = stack.pop() {
+          close(node, &mut stack, &mut records, &convert);
+        }
+      }
+      Event::Eof => break,
+      _ => {}
+    }
+  }
+  if let Some(node) = stack.last() {
+    return Err(Error::Xml(format!("the document ends inside <{}>", node.name)));
+  }
+  Ok(records)
+}
+
+/// A node for `start` if it's a record or inside one.
+fn open<F>(start: &BytesStart, element: &str, in_record: bool, convert: &F) -> Result<Option<Node>, Error>
+where
+  F: Fn(String) -> Value,
+{
+  let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+  if !in_record && name != element {
+    return Ok(None);
+  }
+  let mut fields = Map::new();
+  for attribute in start.attributes() {
+    let attribute = attribute.map_err(|e| Error::Xml(e.to_string()))?;
+    let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+    let value = attribute.unescape_value().map_err(|e| Error::Xml(e.to_string()))?;
+    fields.insert(key, convert(value.into_owned()));
+  }
+  Ok(Some(Node {
+    name,
+    fields,
+    text: String::new(),
+  }))
+}
+
+/// Add a finished node to its parent, or to the records when it has none.
+fn close<F>(node: Node, stack: &mut [Node], records: &mut Vec<Map<String, Value>>, convert: &F)
+where
+  F: Fn(String) -> Value,
+{
+  let text = node.text.trim();
+  let Some(parent) = stack.last_mut() else {
+    let mut record = node.fields;
+    if !text.is_empty() {
+      record.insert(TEXT.to_owned(), convert(text.to_owned()));
+    }
+    records.push(record);
+    return;
+  };
+  let value = if node.fields.is_empty() {
+    if text.is_empty() {
+      Value::Null
+    } else {
+      convert(text.to_owned())
+    }
+  } else {
+    let mut fields = node.fields;
+    if !text.is_empty() {
+      fields.insert(TEXT.to_owned(), convert(text.to_owned()));
+    }
+    Value::Object(fields)
+  };
+  match parent.fields.get_mut(&node.name) {
+    Some(Value::Array(values)) => values.push(value),
+    Some(existing) => {
+      let first = existing.take();
+      *existing = Value::Array(vec![first, value]);
+    }
+    None => {
+      parent.fields.insert(node.name, value);
+    }
+  }
+}
+
+/// `record` as an element named `name`. Arrays are written as repeated elements and `$text` as the element's text.
+pub(crate) fn write_record(name: &str, record: &Map<String, Value>) -> String {
+  let mut xml = String::new();
+  write_object(name, record, &mut xml);
+  xml
+}
+
+fn write_object(name: &str, fields: &Map<String, Value>, xml: &mut String) {
+  open_tag(name, xml);
+  for (key, value) in fields {
+    match value {
+      _ if key == TEXT => xml.push_str(&escape(&text(value))),
+      Value::Array(values) => {
+        for value in values {
+          write_value(key, value, xml);
+        }
+      }
+      value => write_value(key, value, xml),
+    }
+  }
+  close_tag(name, xml);
+}
+
+fn write_value(name: &str, value: &Value, xml: &mut String) {
+  match value {
+    Value::Null => {
+      xml.push('<');
+      xml.push_str(name);
+      xml.push_str("/>");
+    }
+    Value::Object(fields) => write_object(name, fields, xml),
+    value => {
+      open_tag(name, xml);
+      xml.push_str(&escape(&text(value)));
+      close_tag(name, xml);
+    }
+  }
+}
+
+pub(crate) fn open_tag(name: &str, xml: &mut String) {
+  xml.push('<');
+  xml.push_str(name);
+  xml.push('>');
+}
+
+pub(crate) fn close_tag(name: &str, xml: &mut String) {
+  xml.push_str("</");
+  xml.push_str(name);
+  xml.push('>');
+}
+
+/// The text of a value as XML holds it.
+fn text(value: &Value) -> String {
+  match value {
+    Value::String(s) => s.clone(),
+    Value::Null => String::new(),
+    value => value.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_records() {
+    let document = r#"<?xml version="1.0"?>
+      <catalog>
+        <book id="1"><title>Dune &amp; more</title><tag>scifi</tag><tag>classic</tag></book>
+        <book id="2"><title><![CDATA[<Emma>]]></title><note/></book>
+      </catalog>"#;
+    let records = records(document, "book", Value::String).unwrap();
+    assert_eq!(
+      records.into_iter().map(Value::Object).collect::<Vec<_>>(),
+      vec![
+        json!({"id": "1", "title": "Dune & more", "tag": ["scifi", "classic"]}),
+        json!({"id": "2", "title": "<Emma>", "note": null}),
+      ]
+    );
+
+    assert!(super::records("<book><title>", "book", Value::String).is_err());
+  }
+
+  #[test]
+  fn test_write_record() {
+    let record = json!({"id": 1, "title": "A & B", "tag": ["x", "y"], "note": null});
+    let xml = write_record("book", record.as_object().unwrap());
+    assert!(xml.starts_with("<book>") && xml.ends_with("</book>"), "{}", xml);
+    assert!(xml.contains("<tag>x</tag><tag>y</tag>"), "{}", xml);
+    assert!(xml.contains("<title>A &amp; B</title>"), "{}", xml);
+    assert!(xml.contains("<note/>"), "{}", xml);
+    let read = records(&xml, "book", Value::String).unwrap();
+    assert_eq!(
+      Value::Object(read[0].clone()),
+      json!({"id": "1", "title": "A & B", "tag": ["x", "y"], "note": null})
+    );
+  }
+}
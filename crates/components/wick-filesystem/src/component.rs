@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, FilesystemAction, FilesystemComponentConfig, OperationConfig};
+use wick_config::config::Metadata;
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::watch::{Watch, DEFAULT_INTERVAL};
+
+/// A component whose operations work with the files in a volume.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct FilesystemComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Watch>>,
+}
+
+impl FilesystemComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: FilesystemComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/filesystem");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let root = resolver(config.resource())
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_volume())?
+      .path()?;
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| {
+        let operation = match op.action() {
+          FilesystemAction::Watch => Watch::new(
+            root.clone(),
+            op.recursive(),
+            op.interval().map_or(DEFAULT_INTERVAL, Duration::from_millis),
+          ),
+        };
+        (op.name().to_owned(), Arc::new(operation))
+      })
+      .collect();
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for FilesystemComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<FilesystemComponent>();
+  }
+}
@@ -0,0 +1,15 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Paths must be relative to the volume and stay inside it, found '{0}'")]
+  Path(String),
+
+  #[error("'{0}' is not a directory in the volume")]
+  NotADirectory(String),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
@@ -0,0 +1,14 @@
+// !!START_LINTS
+// Wick lints
+// Do not change anything between the START_LINTS and END_LINTS line.
+// Add exceptions here
+#![allow(missing_docs)]
+mod component;
+mod error;
+mod watch;
+
+#[macro_use]
+extern crate tracing;
+
+pub use component::FilesystemComponent;
+pub use error::Error;
@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use futures::StreamExt;
+use serde_json::json;
+use walkdir::WalkDir;
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::error::Error;
+
+/// How often watched directories are checked for changes unless configured otherwise.
+pub(crate) const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification time and size of every file under a directory, by their paths relative to the volume.
+type Snapshot = BTreeMap<String, (Option<SystemTime>, u64)>;
+
+/// A change to a file in a watched directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChangeKind {
+  Create,
+  Modify,
+  Delete,
+}
+
+impl std::fmt::Display for ChangeKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Create => write!(f, "create"),
+      Self::Modify => write!(f, "modify"),
+      Self::Delete => write!(f, "delete"),
+    }
+  }
+}
+
+/// Watches directories in a volume by comparing what's in them every `interval`.
+#[derive(Debug)]
+pub(crate) struct Watch {
+  root: PathBuf,
+  recursive: bool,
+  interval: Duration,
+}
+
+impl Watch {
+  pub(crate) const fn new(root: PathBuf, recursive: bool, interval: Duration) -> Self {
+    Self {
+      root,
+      recursive,
+      interval,
+    }
+  }
+
+  /// Watch the directories from the `path` port, sending each change on the `output` port until the caller stops
+  /// listening.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      let directories = match self.directories(&mut stream, &tx).await {
+        Ok(directories) => directories,
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "filesystem"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+          tx.complete();
+          return;
+        }
+      };
+      if directories.is_empty() {
+        let _ = tx.send(Packet::done("output"));
+        tx.complete();
+        return;
+      }
+
+      let mut snapshots = Vec::new();
+      for dir in directories {
+        let snapshot = self.scan(&dir).await;
+        snapshots.push((dir, snapshot));
+      }
+      span.in_scope(|| trace!(directories = snapshots.len(), "filesystem:watching"));
+
+      while !tx.is_closed() {
+        tokio::time::sleep(self.interval).await;
+        for (dir, snapshot) in &mut snapshots {
+          let current = self.scan(dir).await;
+          for (kind, path) in changes(snapshot, &current) {
+            let event = json!({ "kind": kind.to_string(), "path": path });
+            if tx.send(Packet::encode("output", event)).is_err() {
+              return;
+            }
+          }
+          *snapshot = current;
+        }
+      }
+    });
+
+    rx
+  }
+
+  /// The directories to watch, checked to be inside the volume. Invalid ones are reported on the `output` port.
+  async fn directories(&self, stream: &mut PacketStream, tx: &PacketSender) -> Result<Vec<String>, Error> {
+    let mut directories = Vec::new();
+    while let Some(next) = stream.next().await {
+      let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+      if packet.port() != "path" {
+        continue;
+      }
+      if packet.is_done() {
+        break;
+      }
+      if packet.is_error() {
+        let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+      } else if packet.has_data() {
+        let dir = packet
+          .decode::<String>()
+          .map_err(|e| e.to_string())
+          .and_then(|dir| relative(&dir).map_err(|e| e.to_string()));
+        match dir {
+          Ok(dir) if self.root.join(&dir).is_dir() => directories.push(dir),
+          Ok(dir) => {
+            let _ = tx.send(Packet::err("output", Error::NotADirectory(dir).to_string()));
+          }
+          Err(e) => {
+            let _ = tx.send(Packet::err("output", e));
+          }
+        }
+      }
+    }
+    Ok(directories)
+  }
+
+  async fn scan(&self, dir: &str) -> Snapshot {
+    let root = self.root.clone();
+    let dir = dir.to_owned();
+    let recursive = self.recursive;
+    tokio::task::spawn_blocking(move || scan(&root, &dir, recursive))
+      .await
+      .unwrap_or_default()
+  }
+}
+
+/// `dir` with `.` segments removed, as long as it's relative and doesn't climb out of the volume.
+pub(crate) fn relative(dir: &str) -> Result<String, Error> {
+  let mut segments = Vec::new();
+  for component in Path::new(dir).components() {
+    match component {
+      Component::Normal(segment) => segments.push(segment.to_string_lossy().into_owned()),
+      Component::CurDir => {}
+      _ => return Err(Error::Path(dir.to_owned())),
+    }
+  }
+  Ok(segments.join("/"))
+}
+
+/// The files under `dir` in the volume at `root`, only those directly in it unless `recursive` is set.
+fn scan(root: &Path, dir: &str, recursive: bool) -> Snapshot {
+  let walk = WalkDir::new(root.join(dir)).min_depth(1);
+  let walk = if recursive { walk } else { walk.max_depth(1) };
+  walk
+    .into_iter()
+    .filter_map(Result::ok)
+    .filter(|entry| !entry.file_type().is_dir())
+    .filter_map(|entry| {
+      let metadata = entry.metadata().ok()?;
+      let path = entry.path().strip_prefix(root).ok()?;
+      let path = path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+      Some((path, (metadata.modified().ok(), metadata.len())))
+    })
+    .collect()
+}
+
+/// What changed between two snapshots of a directory, ordered by path.
+fn changes(before: &Snapshot, after: &Snapshot) -> Vec<(ChangeKind, String)> {
+  let mut changes: Vec<_> = after
+    .iter()
+    .filter_map(|(path, file)| match before.get(path) {
+      None => Some((ChangeKind::Create, path.clone())),
+      Some(previous) if previous != file => Some((ChangeKind::Modify, path.clone())),
+      Some(_) => None,
+    })
+    .chain(
+      before
+        .keys()
+        .filter(|path| !after.contains_key(*path))
+        .map(|path| (ChangeKind::Delete, path.clone())),
+    )
+    .collect();
+  changes.sort_by(|a, b| a.1.cmp(&b.1));
+  changes
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::Value;
+  use wick_packet::Entity;
+
+  use super::*;
+
+  fn volume(name: &str, files: &[&str]) -> Result<PathBuf> {
+    let root = std::env::temp_dir().join(format!("wick-filesystem-{}-{}", name, std::process::id()));
+    for path in files {
+      let path = root.join(path);
+      std::fs::create_dir_all(path.parent().unwrap())?;
+      std::fs::write(path, "")?;
+    }
+    Ok(root)
+  }
+
+  #[test]
+  fn test_relative() {
+    assert_eq!(relative("./inbox/./new"), Ok("inbox/new".to_owned()));
+    assert_eq!(relative(""), Ok(String::new()));
+    assert_eq!(relative("../etc"), Err(Error::Path("../etc".to_owned())));
+    assert_eq!(relative("/etc"), Err(Error::Path("/etc".to_owned())));
+  }
+
+  #[test]
+  fn test_scan() -> Result<()> {
+    let root = volume("scan", &["inbox/a.txt", "inbox/nested/b.txt", "other/c.txt"])?;
+    let shallow: Vec<_> = scan(&root, "inbox", false).into_keys().collect();
+    assert_eq!(shallow, vec!["inbox/a.txt"]);
+    let deep: Vec<_> = scan(&root, "inbox", true).into_keys().collect();
+    assert_eq!(deep, vec!["inbox/a.txt", "inbox/nested/b.txt"]);
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_changes() {
+    let before: Snapshot = [("a".to_owned(), (None, 1)), ("b".to_owned(), (None, 1))].into();
+    let after: Snapshot = [("b".to_owned(), (None, 2)), ("c".to_owned(), (None, 1))].into();
+    assert_eq!(
+      changes(&before, &after),
+      vec![
+        (ChangeKind::Delete, "a".to_owned()),
+        (ChangeKind::Modify, "b".to_owned()),
+        (ChangeKind::Create, "c".to_owned()),
+      ]
+    );
+    assert!(changes(&after, &after).is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_watch() -> Result<()> {
+    let root = volume("watch", &["inbox/old.txt"])?;
+    let watch = Arc::new(Watch::new(root.clone(), false, Duration::from_millis(10)));
+    let packets = vec![
+      Packet::encode("path", "inbox"),
+      Packet::encode("path", "../outside"),
+      Packet::done("path"),
+    ];
+    let invocation = Invocation::test(file!(), Entity::local("watch"), packets, None)?;
+    let mut stream = watch.run(invocation);
+
+    let rejected = stream.next().await.unwrap()?;
+    assert!(rejected.is_error(), "paths outside the volume should be rejected");
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    std::fs::write(root.join("inbox/new.txt"), "")?;
+    std::fs::remove_file(root.join("inbox/old.txt"))?;
+
+    let mut events: Vec<Value> = Vec::new();
+    while events.len() < 2 {
+      let packet = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await?
+        .unwrap()?;
+      events.push(packet.decode_value()?);
+    }
+    assert_eq!(
+      events,
+      vec![
+        json!({"kind": "create", "path": "inbox/new.txt"}),
+        json!({"kind": "delete", "path": "inbox/old.txt"}),
+      ]
+    );
+
+    drop(stream);
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+  }
+}
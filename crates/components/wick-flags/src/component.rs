@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, FlagsComponentConfig, OperationConfig};
+use wick_config::config::{Metadata, UrlResource};
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+use crate::rules::RulesFile;
+
+/// A component that evaluates feature flags from a local rules file.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct FlagsComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl FlagsComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub async fn new(
+    config: FlagsComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/flags");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let addr: UrlResource = resolver(config.resource())
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_url())?;
+    let url = addr.url().value().ok_or_else(|| Error::NotAFile(addr.to_string()))?;
+    let path = if url.scheme() == "file" {
+      url.to_file_path().map_err(|_| Error::NotAFile(url.to_string()))?
+    } else {
+      return Err(Error::NotAFile(url.to_string()).into());
+    };
+
+    let required = config
+      .operations()
+      .iter()
+      .map(|op| (op.name().to_owned(), op.flag().to_owned()))
+      .collect();
+    let rules = Arc::new(RulesFile::load(path, required).await?);
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| {
+        let operation = Operation::new(op.flag().to_owned(), rules.clone());
+        (op.name().to_owned(), Arc::new(operation))
+      })
+      .collect();
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for FlagsComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::{Path, PathBuf};
+  use std::time::Duration;
+
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::{json, Value};
+  use wick_config::config::components::{FlagsComponentConfigBuilder, FlagsOperationDefinitionBuilder};
+  use wick_config::config::{AppConfiguration, ResourceDefinition};
+  use wick_packet::{Entity, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<FlagsComponent>();
+  }
+
+  fn rules_file(name: &str, text: &str) -> Result<PathBuf> {
+    let path = std::env::temp_dir().join(format!("wick-flags-{}-{}.yaml", name, std::process::id()));
+    std::fs::write(&path, text)?;
+    Ok(path)
+  }
+
+  async fn component(path: &Path, flag: &str) -> Result<FlagsComponent> {
+    let mut app_config = AppConfiguration::default();
+    app_config.add_resource(
+      "rules",
+      ResourceDefinition::Url(format!("file://{}", path.display()).try_into()?),
+    );
+    let config = FlagsComponentConfigBuilder::default()
+      .resource("rules")
+      .operations(vec![FlagsOperationDefinitionBuilder::default()
+        .name("check")
+        .flag(flag)
+        .build()?])
+      .build()?;
+    let component = FlagsComponent::new(config, None, None, &app_config.resolver()).await?;
+
+    Ok(component)
+  }
+
+  async fn evaluate(component: &FlagsComponent, contexts: Vec<Value>) -> Result<Vec<(String, Value)>> {
+    let mut packets: Vec<_> = contexts.into_iter().map(|v| Packet::encode("context", v)).collect();
+    packets.push(Packet::done("context"));
+    let invocation = Invocation::test(file!(), Entity::local("check"), packets, None)?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let mut output = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      if packet.has_data() {
+        output.push((packet.port().to_owned(), packet.decode_value()?));
+      }
+    }
+    Ok(output)
+  }
+
+  #[tokio::test]
+  async fn test_evaluate() -> Result<()> {
+    let path = rules_file(
+      "evaluate",
+      "flags:\n  checkout:\n    rollout: 0\n    rules:\n      - when: { plan: pro }\n",
+    )?;
+    let component = component(&path, "checkout").await?;
+
+    let output = evaluate(&component, vec![json!({"plan": "pro"}), json!({"plan": "free"})]).await?;
+    assert_eq!(
+      output,
+      vec![
+        ("enabled".to_owned(), json!(true)),
+        ("reason".to_owned(), json!("rule 1")),
+        ("enabled".to_owned(), json!(false)),
+        ("reason".to_owned(), json!("default")),
+      ]
+    );
+
+    // the file's modification time has to change for it to be read again.
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    std::fs::write(&path, "flags:\n  checkout:\n    enabled: false\n")?;
+    let output = evaluate(&component, vec![json!({"plan": "pro"})]).await?;
+    assert_eq!(output[0], ("enabled".to_owned(), json!(false)));
+
+    // rules that drop the operation's flag are rejected and the previous rules are kept.
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    std::fs::write(&path, "flags: {}\n")?;
+    let output = evaluate(&component, vec![json!({"plan": "pro"})]).await?;
+    assert_eq!(output[1], ("reason".to_owned(), json!("disabled")));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_unknown_flag() -> Result<()> {
+    let path = rules_file("unknown", "flags:\n  checkout: {}\n")?;
+    let error = component(&path, "search").await.unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "Operation 'check' evaluates flag 'search', which the rules file does not define"
+    );
+    std::fs::remove_file(&path)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,24 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Flag rules must be read from a 'file://' URL, found '{0}'")]
+  NotAFile(String),
+
+  #[error("Could not read flag rules from {0}: {1}")]
+  Read(String, String),
+
+  #[error("Could not parse flag rules in {0}: {1}")]
+  Parse(String, String),
+
+  #[error("Flag '{0}' has a rollout of {1}, expected a percentage from 0 to 100")]
+  InvalidRollout(String, f64),
+
+  #[error("Operation '{0}' evaluates flag '{1}', which the rules file does not define")]
+  UnknownFlag(String, String),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+use serde_json::Value;
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::error::Error;
+use crate::rules::{Rules, RulesFile};
+
+/// An operation that evaluates one flag.
+#[derive(Debug)]
+pub(crate) struct Operation {
+  flag: String,
+  rules: Arc<RulesFile>,
+}
+
+impl Operation {
+  pub(crate) const fn new(flag: String, rules: Arc<RulesFile>) -> Self {
+    Self { flag, rules }
+  }
+
+  /// Process the invocation's stream, sending an evaluation on the `enabled` and `reason` ports for each context.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      // every context in an invocation is evaluated against the same rules, even if the file changes midway.
+      let rules = self.rules.current(&span).await;
+      match each_context(&mut stream, &tx, &self.flag, &rules).await {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("enabled"));
+          let _ = tx.send(Packet::done("reason"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "flags"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+}
+
+async fn each_context(stream: &mut PacketStream, tx: &PacketSender, flag: &str, rules: &Rules) -> Result<(), Error> {
+  while let Some(next) = stream.next().await {
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    if packet.port() != "context" {
+      continue;
+    }
+    if packet.is_done() {
+      break;
+    }
+    // every context has one evaluation, so the shape of the input stream is kept on both outputs.
+    if packet.is_error() {
+      let error = packet.unwrap_err();
+      let _ = tx.send(Packet::raw_err("enabled", error.clone()));
+      let _ = tx.send(Packet::raw_err("reason", error));
+    } else if packet.is_bracket() {
+      let _ = tx.send(packet.clone().to_port("enabled"));
+      let _ = tx.send(packet.to_port("reason"));
+    } else if packet.has_data() {
+      let errors = |message: String| (Packet::err("enabled", message.clone()), Packet::err("reason", message));
+      let (enabled, reason) = match packet.decode_value() {
+        Ok(Value::Object(context)) => {
+          let evaluation = rules.evaluate(flag, &context);
+          (
+            Packet::encode("enabled", evaluation.enabled),
+            Packet::encode("reason", evaluation.reason),
+          )
+        }
+        Ok(other) => errors(format!("expected an object of attributes, found {}", other)),
+        Err(e) => errors(e.to_string()),
+      };
+      let _ = tx.send(enabled);
+      let _ = tx.send(reason);
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,336 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use serde_json::{Map, Value};
+use tokio::sync::RwLock;
+use tracing::Span;
+
+use crate::error::Error;
+
+/// The attribute a flag's rollout is bucketed by when it doesn't name one.
+const DEFAULT_BUCKET_BY: &str = "key";
+
+/// The flags defined in a rules file, e.g.
+///
+/// ```yaml
+/// flags:
+///   new-checkout:
+///     rollout: 10
+///     rules:
+///       - when: { country: [US, CA], plan: pro }
+///       - when: { key: qa-team }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Rules {
+  #[serde(default)]
+  flags: HashMap<String, Flag>,
+}
+
+/// A feature flag. Rules are checked in order and the first whose `when` matches decides the evaluation, falling back
+/// to the flag's own `rollout` when none match.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Flag {
+  /// Set to false to turn the flag off for everyone, regardless of its rules.
+  #[serde(default = "enabled")]
+  enabled: bool,
+  /// The attribute that places a context in a rollout's percentage. The same value always lands in the same place.
+  #[serde(default = "bucket_by")]
+  bucket_by: String,
+  /// The percentage of contexts the flag is enabled for when no rule matches.
+  #[serde(default = "everyone")]
+  rollout: f64,
+  #[serde(default)]
+  rules: Vec<Rule>,
+}
+
+/// Attribute targeting for a flag.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Rule {
+  /// The attributes a context must have to match, each equal to the value or to one of a list of values.
+  #[serde(default)]
+  when: BTreeMap<String, Value>,
+  /// The percentage of matching contexts the flag is enabled for.
+  #[serde(default = "everyone")]
+  rollout: f64,
+}
+
+const fn enabled() -> bool {
+  true
+}
+
+fn bucket_by() -> String {
+  DEFAULT_BUCKET_BY.to_owned()
+}
+
+const fn everyone() -> f64 {
+  100.0
+}
+
+/// Whether a flag is enabled for a context, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Evaluation {
+  pub(crate) enabled: bool,
+  pub(crate) reason: String,
+}
+
+impl Rules {
+  pub(crate) fn parse(source: &str, text: &str) -> Result<Self, Error> {
+    // JSON is valid YAML, so one parser reads both.
+    let rules: Self = serde_yaml::from_str(text).map_err(|e| Error::Parse(source.to_owned(), e.to_string()))?;
+    for (name, flag) in &rules.flags {
+      let mut rollouts = std::iter::once(flag.rollout).chain(flag.rules.iter().map(|rule| rule.rollout));
+      if let Some(rollout) = rollouts.find(|r| !(0.0..=100.0).contains(r)) {
+        return Err(Error::InvalidRollout(name.clone(), rollout));
+      }
+    }
+    Ok(rules)
+  }
+
+  pub(crate) fn contains(&self, flag: &str) -> bool {
+    self.flags.contains_key(flag)
+  }
+
+  /// Evaluate `flag` for a context of attributes. Flags that aren't defined are disabled.
+  pub(crate) fn evaluate(&self, flag: &str, context: &Map<String, Value>) -> Evaluation {
+    let Some(def) = self.flags.get(flag) else {
+      return Evaluation::new(false, "not defined");
+    };
+    if !def.enabled {
+      return Evaluation::new(false, "disabled");
+    }
+    let (source, rollout) = def
+      .rules
+      .iter()
+      .position(|rule| rule.matches(context))
+      .map_or(("default".to_owned(), def.rollout), |i| {
+        (format!("rule {}", i + 1), def.rules[i].rollout)
+      });
+
+    if rollout >= 100.0 {
+      return Evaluation::new(true, source);
+    }
+    if rollout <= 0.0 {
+      return Evaluation::new(false, source);
+    }
+    let Some(value) = context.get(&def.bucket_by) else {
+      return Evaluation::new(
+        false,
+        format!("{} (no '{}' attribute to roll out by)", source, def.bucket_by),
+      );
+    };
+    let enabled = bucket(flag, value) < rollout;
+    let side = if enabled { "in" } else { "outside" };
+    Evaluation::new(enabled, format!("{} ({} {}% rollout)", source, side, rollout))
+  }
+}
+
+impl Rule {
+  fn matches(&self, context: &Map<String, Value>) -> bool {
+    self.when.iter().all(|(attribute, expected)| {
+      context.get(attribute).map_or(false, |actual| match expected {
+        Value::Array(values) => values.contains(actual),
+        value => value == actual,
+      })
+    })
+  }
+}
+
+impl Evaluation {
+  fn new<T: Into<String>>(enabled: bool, reason: T) -> Self {
+    Self {
+      enabled,
+      reason: reason.into(),
+    }
+  }
+}
+
+/// Place `value` in a bucket from 0 up to 100 for `flag`. Hashing the flag's name along with the value means each flag
+/// rolls out to a different slice of contexts.
+fn bucket(flag: &str, value: &Value) -> f64 {
+  let value = match value {
+    Value::String(s) => s.clone(),
+    other => other.to_string(),
+  };
+  // FNV-1a, which unlike std's hasher is stable across platforms and releases, so rollouts don't reshuffle.
+  let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+  for byte in flag.bytes().chain(std::iter::once(b'/')).chain(value.bytes()) {
+    hash ^= u64::from(byte);
+    hash = hash.wrapping_mul(0x0100_0000_01b3);
+  }
+  (hash % 10_000) as f64 / 100.0
+}
+
+/// A rules file, reloaded when it changes so rollouts can be adjusted without restarting.
+#[derive(Debug)]
+pub(crate) struct RulesFile {
+  path: PathBuf,
+  /// The operations of the component and the flags they evaluate, which every reload must still define.
+  required: Vec<(String, String)>,
+  loaded: RwLock<Loaded>,
+}
+
+#[derive(Debug)]
+struct Loaded {
+  modified: Option<SystemTime>,
+  rules: Arc<Rules>,
+}
+
+impl RulesFile {
+  pub(crate) async fn load(path: PathBuf, required: Vec<(String, String)>) -> Result<Self, Error> {
+    let modified = modified(&path).await;
+    let rules = read(&path).await?;
+    if let Some((operation, flag)) = missing(&required, &rules) {
+      return Err(Error::UnknownFlag(operation.clone(), flag.clone()));
+    }
+    Ok(Self {
+      path,
+      required,
+      loaded: RwLock::new(Loaded {
+        modified,
+        rules: Arc::new(rules),
+      }),
+    })
+  }
+
+  /// The current rules, reading the file again if it changed since it was last read. The last good rules are kept
+  /// when it can't be read.
+  pub(crate) async fn current(&self, span: &Span) -> Arc<Rules> {
+    let modified = modified(&self.path).await;
+    {
+      let loaded = self.loaded.read().await;
+      if modified.is_none() || modified == loaded.modified {
+        return loaded.rules.clone();
+      }
+    }
+
+    let mut loaded = self.loaded.write().await;
+    if modified != loaded.modified {
+      loaded.modified = modified;
+      match read(&self.path).await {
+        Ok(rules) => match missing(&self.required, &rules) {
+          Some((_, flag)) => span.in_scope(|| {
+            warn!(
+              path = %self.path.display(),
+              %flag,
+              "flags: reloaded rules are missing a flag, keeping the previous rules"
+            );
+          }),
+          None => {
+            span.in_scope(|| debug!(path = %self.path.display(), "flags: reloaded rules"));
+            loaded.rules = Arc::new(rules);
+          }
+        },
+        Err(error) => {
+          span.in_scope(|| warn!(%error, "flags: could not reload rules, keeping the previous rules"));
+        }
+      }
+    }
+    loaded.rules.clone()
+  }
+}
+
+/// The first operation whose flag the rules don't define, if any.
+fn missing<'a>(required: &'a [(String, String)], rules: &Rules) -> Option<&'a (String, String)> {
+  required.iter().find(|(_, flag)| !rules.contains(flag))
+}
+
+async fn modified(path: &Path) -> Option<SystemTime> {
+  tokio::fs::metadata(path).await.and_then(|m| m.modified()).ok()
+}
+
+async fn read(path: &Path) -> Result<Rules, Error> {
+  let source = path.display().to_string();
+  let text = tokio::fs::read_to_string(path)
+    .await
+    .map_err(|e| Error::Read(source.clone(), e.to_string()))?;
+  Rules::parse(&source, &text)
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  fn context(value: Value) -> Map<String, Value> {
+    match value {
+      Value::Object(map) => map,
+      _ => unreachable!(),
+    }
+  }
+
+  fn rules(text: &str) -> Result<Rules> {
+    Ok(Rules::parse("test", text)?)
+  }
+
+  #[test]
+  fn test_targeting() -> Result<()> {
+    let rules = rules(
+      r#"
+flags:
+  checkout:
+    rollout: 0
+    rules:
+      - when: { country: [US, CA], plan: pro }
+      - when: { key: qa }
+  off:
+    enabled: false
+"#,
+    )?;
+    let eval = |ctx| rules.evaluate("checkout", &context(ctx));
+
+    assert_eq!(
+      eval(json!({"key": "a", "country": "CA", "plan": "pro"})),
+      Evaluation::new(true, "rule 1")
+    );
+    assert_eq!(
+      eval(json!({"key": "a", "country": "FR", "plan": "pro"})),
+      Evaluation::new(false, "default")
+    );
+    assert_eq!(eval(json!({"key": "qa"})), Evaluation::new(true, "rule 2"));
+    assert_eq!(
+      rules.evaluate("off", &context(json!({"key": "qa"}))),
+      Evaluation::new(false, "disabled")
+    );
+    assert_eq!(
+      rules.evaluate("missing", &context(json!({}))),
+      Evaluation::new(false, "not defined")
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_rollout() -> Result<()> {
+    let rules = rules(r#"{"flags": {"beta": {"rollout": 25, "bucket_by": "user"}}}"#)?;
+
+    let enabled = (0..1000)
+      .filter(|i| rules.evaluate("beta", &context(json!({ "user": i }))).enabled)
+      .count();
+    assert!((200..300).contains(&enabled), "{} of 1000 enabled", enabled);
+
+    let first = rules.evaluate("beta", &context(json!({"user": "ada"})));
+    assert_eq!(rules.evaluate("beta", &context(json!({"user": "ada"}))), first);
+    assert!(first.reason.ends_with("25% rollout)"));
+
+    assert_eq!(
+      rules.evaluate("beta", &context(json!({"key": "ada"}))),
+      Evaluation::new(false, "default (no 'user' attribute to roll out by)")
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid() {
+    assert!(matches!(
+      rules("flags: { beta: { rollout: 150 } }").unwrap_err().downcast_ref::<Error>(),
+      Some(Error::InvalidRollout(flag, _)) if flag == "beta"
+    ));
+    assert!(rules("flags: { beta: { percent: 10 } }").is_err());
+  }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, GenerateComponentConfig, OperationConfig};
+use wick_config::config::Metadata;
+use wick_interface_types::{ComponentSignature, OperationSignatures, TypeDefinition};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+
+/// A component that generates records conforming to a type from the manifest's `types`.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct GenerateComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl GenerateComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: GenerateComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    types: &[TypeDefinition],
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/generate");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+    sig.types = types.to_vec();
+
+    let types: Arc<HashMap<_, _>> = Arc::new(types.iter().map(|t| (t.name().to_owned(), t.clone())).collect());
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| Ok((op.name().to_owned(), Arc::new(Operation::compile(op, types.clone())?))))
+      .collect::<Result<_, Error>>()?;
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for GenerateComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      operation.map_or_else(
+        || Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+        |operation| Ok(operation.run(&invocation)),
+      )
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::{json, Value};
+  use wick_config::config::components::{
+    GenerateComponentConfigBuilder,
+    GenerateField,
+    GenerateFieldBuilder,
+    GenerateOperationDefinitionBuilder,
+    GenerateStrategy,
+  };
+  use wick_interface_types::{EnumDefinition, EnumVariant, Field, StructDefinition, Type};
+  use wick_packet::{Entity, InherentData, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<GenerateComponent>();
+  }
+
+  fn types() -> Vec<TypeDefinition> {
+    vec![
+      TypeDefinition::Struct(StructDefinition::new(
+        "User",
+        vec![
+          Field::new("id", Type::U32),
+          Field::new("name", Type::String),
+          Field::new("email", Type::String),
+          Field::new("role", Type::Named("Role".to_owned())),
+          Field::new(
+            "tags",
+            Type::List {
+              ty: Box::new(Type::String),
+            },
+          ),
+        ],
+        None,
+      )),
+      TypeDefinition::Enum(EnumDefinition::new(
+        "Role",
+        vec![
+          EnumVariant::new("Admin", Some(0), Some("admin".to_owned()), None),
+          EnumVariant::new("Member", Some(1), Some("member".to_owned()), None),
+        ],
+        None,
+      )),
+    ]
+  }
+
+  fn field(name: &str, strategy: GenerateStrategy) -> GenerateFieldBuilder {
+    let mut builder = GenerateFieldBuilder::default();
+    builder.name(name).strategy(strategy);
+    builder
+  }
+
+  fn component(ty: &str, count: u32, seed: Option<u64>, fields: Vec<GenerateField>) -> Result<GenerateComponent> {
+    let op = GenerateOperationDefinitionBuilder::default()
+      .name("users")
+      .ty(ty)
+      .count(Some(count))
+      .seed(seed)
+      .fields(fields)
+      .build()?;
+    let config = GenerateComponentConfigBuilder::default().operations(vec![op]).build()?;
+    let component = GenerateComponent::new(config, None, None, &types())?;
+
+    Ok(component)
+  }
+
+  async fn generate(component: &GenerateComponent, invocation_seed: u64) -> Result<Vec<Value>> {
+    let inherent = InherentData::new(invocation_seed, 0);
+    let invocation = Invocation::test(file!(), Entity::local("users"), Vec::<Packet>::new(), Some(inherent))?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let mut output = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      if packet.has_data() {
+        output.push(packet.decode_value()?);
+      }
+    }
+    Ok(output)
+  }
+
+  #[tokio::test]
+  async fn test_records() -> Result<()> {
+    let component = component("User", 5, Some(42), Vec::new())?;
+
+    let records = generate(&component, 1).await?;
+    assert_eq!(records.len(), 5);
+    for record in &records {
+      assert!(record["id"].as_u64().map_or(false, |id| id <= 1000), "{}", record);
+      assert!(
+        record["name"].as_str().map_or(false, |name| name.contains(' ')),
+        "{}",
+        record
+      );
+      assert!(
+        record["email"]
+          .as_str()
+          .map_or(false, |email| email.contains("@example.")),
+        "{}",
+        record
+      );
+      assert!(
+        ["admin", "member"].contains(&record["role"].as_str().unwrap_or_default()),
+        "{}",
+        record
+      );
+      assert!(
+        record["tags"].as_array().map_or(false, |tags| !tags.is_empty()),
+        "{}",
+        record
+      );
+    }
+
+    // a configured seed generates the same records for every invocation.
+    assert_eq!(generate(&component, 2).await?, records);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_invocation_seed() -> Result<()> {
+    let component = component("User", 3, None, Vec::new())?;
+
+    assert_eq!(generate(&component, 7).await?, generate(&component, 7).await?);
+    assert_ne!(generate(&component, 7).await?, generate(&component, 8).await?);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_field_strategies() -> Result<()> {
+    let component = component(
+      "User",
+      4,
+      Some(1),
+      vec![
+        field("id", GenerateStrategy::Sequence).min(Some(100.0)).build()?,
+        field("name", GenerateStrategy::OneOf)
+          .values(vec![json!("Ada"), json!("Grace")])
+          .build()?,
+      ],
+    )?;
+
+    let records = generate(&component, 1).await?;
+    let ids: Vec<_> = records.iter().map(|r| r["id"].clone()).collect();
+    assert_eq!(ids, vec![json!(100), json!(101), json!(102), json!(103)]);
+    for record in &records {
+      assert!(
+        ["Ada", "Grace"].contains(&record["name"].as_str().unwrap_or_default()),
+        "{}",
+        record
+      );
+    }
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid_config() -> Result<()> {
+    assert!(component("Account", 1, None, Vec::new()).is_err());
+    assert!(component("Role", 1, None, vec![field("name", GenerateStrategy::Word).build()?]).is_err());
+    assert!(component("User", 1, None, vec![field("age", GenerateStrategy::Integer).build()?]).is_err());
+    assert!(component(
+      "User",
+      1,
+      None,
+      vec![field("id", GenerateStrategy::Integer)
+        .min(Some(10.0))
+        .max(Some(1.0))
+        .build()?]
+    )
+    .is_err());
+    assert!(component("User", 1, None, vec![field("name", GenerateStrategy::OneOf).build()?]).is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,24 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Operation '{0}' generates type '{1}', which is not defined in the manifest's types")]
+  TypeNotFound(String, String),
+
+  #[error("Operation '{0}' configures fields, but type '{1}' is not a struct")]
+  NotAStruct(String, String),
+
+  #[error("Operation '{0}' configures field '{2}', which type '{1}' does not have")]
+  UnknownField(String, String, String),
+
+  #[error("Operation '{0}' has a range for field '{1}' whose min is greater than its max")]
+  InvalidRange(String, String),
+
+  #[error("Operation '{0}' picks field '{1}' from a list of values, but the list is empty")]
+  MissingValues(String, String),
+
+  #[error("Type '{0}' nests too deeply to generate, it may refer to itself without an optional or list in between")]
+  TooDeep(String),
+}
@@ -0,0 +1,247 @@
+use chrono::{SecondsFormat, TimeZone, Utc};
+use seeded_random::Random;
+use wick_config::config::components::GenerateStrategy;
+
+const FIRST_NAMES: &[&str] = &[
+  "Maria", "James", "Aiko", "Mohammed", "Sofia", "Liam", "Priya", "Noah", "Amara", "Lucas", "Elena", "Kenji", "Fatima",
+  "Oliver", "Zara", "Mateo", "Ingrid", "Kwame", "Chloe", "Diego", "Hana", "Samuel", "Leila", "Arjun", "Nora", "Tomas",
+  "Yara", "Felix", "Mei", "Omar",
+];
+
+const LAST_NAMES: &[&str] = &[
+  "Okafor", "Smith", "Tanaka", "Haddad", "Rossi", "Murphy", "Patel", "Johnson", "Mensah", "Silva", "Ivanova", "Sato",
+  "Rahman", "Brown", "Khan", "Garcia", "Larsen", "Boateng", "Martin", "Lopez", "Kim", "Cohen", "Nasser", "Sharma",
+  "Novak", "Fischer", "Costa", "Weber", "Chen", "Ali",
+];
+
+const COMPANY_SUFFIXES: &[&str] = &[
+  "Inc.",
+  "LLC",
+  "Group",
+  "Labs",
+  "Systems",
+  "Partners",
+  "Industries",
+  "& Co.",
+];
+
+const CITIES: &[&str] = &[
+  "Lagos", "Toronto", "Osaka", "Beirut", "Milan", "Dublin", "Pune", "Denver", "Accra", "Porto", "Kazan", "Sapporo",
+  "Dhaka", "Leeds", "Karachi", "Seville", "Bergen", "Kumasi", "Lyon", "Quito", "Busan", "Haifa", "Amman", "Jaipur",
+  "Brno", "Graz", "Recife", "Bremen", "Xiamen", "Tunis",
+];
+
+const COUNTRIES: &[&str] = &[
+  "Nigeria",
+  "Canada",
+  "Japan",
+  "Lebanon",
+  "Italy",
+  "Ireland",
+  "India",
+  "United States",
+  "Ghana",
+  "Portugal",
+  "Russia",
+  "Bangladesh",
+  "United Kingdom",
+  "Pakistan",
+  "Spain",
+  "Norway",
+  "France",
+  "Ecuador",
+  "South Korea",
+  "Israel",
+  "Jordan",
+  "Czechia",
+  "Austria",
+  "Brazil",
+  "Germany",
+  "China",
+  "Tunisia",
+  "Kenya",
+  "Mexico",
+  "Sweden",
+];
+
+const WORDS: &[&str] = &[
+  "lorem",
+  "ipsum",
+  "dolor",
+  "sit",
+  "amet",
+  "consectetur",
+  "adipiscing",
+  "elit",
+  "sed",
+  "do",
+  "eiusmod",
+  "tempor",
+  "incididunt",
+  "ut",
+  "labore",
+  "et",
+  "dolore",
+  "magna",
+  "aliqua",
+  "enim",
+  "ad",
+  "minim",
+  "veniam",
+  "quis",
+  "nostrud",
+  "exercitation",
+  "ullamco",
+  "laboris",
+  "nisi",
+  "aliquip",
+  "ex",
+  "ea",
+  "commodo",
+  "consequat",
+  "duis",
+  "aute",
+  "irure",
+  "in",
+  "reprehenderit",
+  "voluptate",
+  "velit",
+  "esse",
+  "cillum",
+  "fugiat",
+  "nulla",
+  "pariatur",
+  "excepteur",
+  "sint",
+  "occaecat",
+  "cupidatat",
+  "non",
+  "proident",
+  "sunt",
+  "culpa",
+  "qui",
+  "officia",
+  "deserunt",
+  "mollit",
+  "anim",
+  "id",
+];
+
+/// Domains reserved for documentation by RFC 2606, so generated addresses never reach anyone.
+const EMAIL_DOMAINS: &[&str] = &["example.com", "example.org", "example.net"];
+
+/// 2000-01-01T00:00:00Z and 2030-01-01T00:00:00Z.
+const EARLIEST_DATETIME: i64 = 946_684_800;
+const LATEST_DATETIME: i64 = 1_893_456_000;
+
+/// Infer a strategy for a string field from its name, e.g. `email` or `first_name`.
+pub(crate) fn infer(name: &str) -> Option<GenerateStrategy> {
+  let compact = name.to_ascii_lowercase().replace(['-', '_'], "");
+  // `user_id` and `userId` are ids, `paid` is not.
+  let is_id = name.ends_with("Id") || name.ends_with("_id") || name.ends_with("-id");
+  Some(match compact.as_str() {
+    "firstname" | "givenname" | "forename" => GenerateStrategy::FirstName,
+    "lastname" | "surname" | "familyname" => GenerateStrategy::LastName,
+    "name" | "fullname" | "displayname" => GenerateStrategy::FullName,
+    "username" | "user" | "login" | "handle" => GenerateStrategy::Username,
+    "company" | "organization" | "organisation" | "employer" => GenerateStrategy::Company,
+    "city" | "town" => GenerateStrategy::City,
+    "country" => GenerateStrategy::Country,
+    "id" | "uuid" | "guid" => GenerateStrategy::Uuid,
+    "description" | "summary" | "bio" | "comment" | "message" | "title" => GenerateStrategy::Sentence,
+    _ if compact.contains("email") => GenerateStrategy::Email,
+    _ if compact.contains("phone") || compact.ends_with("mobile") => GenerateStrategy::Phone,
+    _ if is_id => GenerateStrategy::Uuid,
+    _ => return None,
+  })
+}
+
+pub(crate) fn pick<'a>(rng: &Random, list: &[&'a str]) -> &'a str {
+  list[rng.range(0, list.len() as u32) as usize]
+}
+
+/// A whole number from `min` to `max`, inclusive.
+pub(crate) fn integer(rng: &Random, min: f64, max: f64) -> i64 {
+  let (min, max) = (min.ceil(), max.floor());
+  (min + (rng.gen::<f64>() * (max - min + 1.0)).floor()).min(max) as i64
+}
+
+/// A number from `min` up to `max`.
+pub(crate) fn float(rng: &Random, min: f64, max: f64) -> f64 {
+  min + rng.gen::<f64>() * (max - min)
+}
+
+pub(crate) fn full_name(rng: &Random) -> String {
+  format!("{} {}", pick(rng, FIRST_NAMES), pick(rng, LAST_NAMES))
+}
+
+pub(crate) fn username(rng: &Random) -> String {
+  format!(
+    "{}.{}{}",
+    pick(rng, FIRST_NAMES),
+    pick(rng, LAST_NAMES),
+    rng.range(1, 100)
+  )
+  .to_ascii_lowercase()
+}
+
+pub(crate) fn email(rng: &Random) -> String {
+  format!(
+    "{}.{}@{}",
+    pick(rng, FIRST_NAMES),
+    pick(rng, LAST_NAMES),
+    pick(rng, EMAIL_DOMAINS)
+  )
+  .to_ascii_lowercase()
+}
+
+/// A North American number in the 555-0100 to 555-0199 range, which is reserved for fiction.
+pub(crate) fn phone(rng: &Random) -> String {
+  format!("+1{}55501{:02}", rng.range(200, 1000), rng.range(0, 100))
+}
+
+pub(crate) fn company(rng: &Random) -> String {
+  format!("{} {}", pick(rng, LAST_NAMES), pick(rng, COMPANY_SUFFIXES))
+}
+
+pub(crate) fn sentence(rng: &Random) -> String {
+  let words: Vec<_> = (0..rng.range(4, 13)).map(|_| pick(rng, WORDS)).collect();
+  let sentence = words.join(" ");
+  let mut chars = sentence.chars();
+  chars.next().map_or_else(String::new, |first| {
+    format!("{}{}.", first.to_ascii_uppercase(), chars.as_str())
+  })
+}
+
+pub(crate) fn uuid(rng: &Random) -> String {
+  uuid::Builder::from_random_bytes(rng.gen()).into_uuid().to_string()
+}
+
+pub(crate) fn datetime(rng: &Random) -> String {
+  let seconds = integer(rng, EARLIEST_DATETIME as f64, (LATEST_DATETIME - 1) as f64);
+  Utc
+    .timestamp_opt(seconds, 0)
+    .single()
+    .unwrap_or_default()
+    .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+pub(crate) fn word(rng: &Random) -> &'static str {
+  pick(rng, WORDS)
+}
+
+pub(crate) fn first_name(rng: &Random) -> &'static str {
+  pick(rng, FIRST_NAMES)
+}
+
+pub(crate) fn last_name(rng: &Random) -> &'static str {
+  pick(rng, LAST_NAMES)
+}
+
+pub(crate) fn city(rng: &Random) -> &'static str {
+  pick(rng, CITIES)
+}
+
+pub(crate) fn country(rng: &Random) -> &'static str {
+  pick(rng, COUNTRIES)
+}
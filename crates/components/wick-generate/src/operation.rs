@@ -0,0 +1,95 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use seeded_random::{Random, Seed};
+use wick_config::config::components::{GenerateOperationDefinition, OperationConfig};
+use wick_interface_types::{Type, TypeDefinition};
+use wick_packet::{Invocation, Observer, Packet, PacketStream};
+
+use crate::error::Error;
+use crate::record::{find_missing, Generator, Strategy};
+
+/// An operation with its type resolved and its field strategies checked.
+#[derive(Debug)]
+pub(crate) struct Operation {
+  ty: String,
+  count: u32,
+  seed: Option<u64>,
+  strategies: HashMap<String, Strategy>,
+  types: Arc<HashMap<String, TypeDefinition>>,
+}
+
+impl Operation {
+  pub(crate) fn compile(
+    def: &GenerateOperationDefinition,
+    types: Arc<HashMap<String, TypeDefinition>>,
+  ) -> Result<Self, Error> {
+    let name = def.name();
+    let ty = def.ty();
+
+    let record = Type::Named(ty.to_owned());
+    if let Some(missing) = find_missing(&record, &types, &mut HashSet::new()) {
+      return Err(Error::TypeNotFound(name.to_owned(), missing.to_owned()));
+    }
+
+    if !def.fields().is_empty() {
+      let Some(TypeDefinition::Struct(record)) = types.get(ty) else {
+        return Err(Error::NotAStruct(name.to_owned(), ty.to_owned()));
+      };
+      if let Some(field) = def
+        .fields()
+        .iter()
+        .find(|field| !record.fields.iter().any(|f| f.name == field.name()))
+      {
+        return Err(Error::UnknownField(
+          name.to_owned(),
+          ty.to_owned(),
+          field.name().to_owned(),
+        ));
+      }
+    }
+
+    let strategies = def
+      .fields()
+      .iter()
+      .map(|field| Ok((field.name().to_owned(), Strategy::compile(name, field)?)))
+      .collect::<Result<_, Error>>()?;
+
+    Ok(Self {
+      ty: ty.to_owned(),
+      count: def.count(),
+      seed: def.seed(),
+      strategies,
+      types,
+    })
+  }
+
+  /// Send `count` records on the `output` port. The invocation's seed is used when the operation has none.
+  pub(crate) fn run(self: Arc<Self>, invocation: &Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let seed = self.seed.unwrap_or(invocation.inherent().seed);
+
+    tokio::spawn(async move {
+      let rng = Random::from_seed(Seed::unsafe_new(seed));
+      let generator = Generator::new(&rng, &self.types);
+      for index in 0..u64::from(self.count) {
+        match generator.record(&self.ty, &self.strategies, index) {
+          Ok(record) => {
+            let _ = tx.send(Packet::encode("output", record));
+          }
+          Err(e) => {
+            span.in_scope(|| error!(error = %e, "generate"));
+            let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+            tx.complete();
+            return;
+          }
+        }
+      }
+      let _ = tx.send(Packet::done("output"));
+      tx.complete();
+    });
+
+    rx
+  }
+}
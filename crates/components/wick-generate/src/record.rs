@@ -0,0 +1,222 @@
+use std::collections::{HashMap, HashSet};
+
+use base64::Engine;
+use seeded_random::Random;
+use serde_json::{Map, Value};
+use wick_config::config::components::{GenerateField, GenerateStrategy};
+use wick_interface_types::{Field, Type, TypeDefinition};
+
+use crate::error::Error;
+use crate::fake;
+
+const DEFAULT_MIN: f64 = 0.0;
+const DEFAULT_MAX: f64 = 1000.0;
+
+/// How deep lists, maps, and optional values are generated before they're left empty.
+const MAX_DEPTH: usize = 8;
+
+/// How deep named types are followed before a type is assumed to contain itself.
+const MAX_NAMED_DEPTH: usize = 32;
+
+/// A field strategy with its settings checked.
+#[derive(Debug, Clone)]
+pub(crate) struct Strategy {
+  kind: GenerateStrategy,
+  min: f64,
+  max: f64,
+  values: Vec<Value>,
+}
+
+impl Strategy {
+  const fn new(kind: GenerateStrategy) -> Self {
+    Self {
+      kind,
+      min: DEFAULT_MIN,
+      max: DEFAULT_MAX,
+      values: Vec::new(),
+    }
+  }
+
+  pub(crate) fn compile(op: &str, field: &GenerateField) -> Result<Self, Error> {
+    let strategy = Self {
+      kind: field.strategy(),
+      min: field.min().unwrap_or(DEFAULT_MIN),
+      max: field.max().unwrap_or(DEFAULT_MAX),
+      values: field.values().to_vec(),
+    };
+    if strategy.min > strategy.max && matches!(strategy.kind, GenerateStrategy::Integer | GenerateStrategy::Float) {
+      return Err(Error::InvalidRange(op.to_owned(), field.name().to_owned()));
+    }
+    if strategy.values.is_empty() && strategy.kind == GenerateStrategy::OneOf {
+      return Err(Error::MissingValues(op.to_owned(), field.name().to_owned()));
+    }
+    Ok(strategy)
+  }
+
+  /// Generate a value for the record at `index`.
+  fn generate(&self, rng: &Random, index: u64) -> Value {
+    match self.kind {
+      GenerateStrategy::FirstName => fake::first_name(rng).into(),
+      GenerateStrategy::LastName => fake::last_name(rng).into(),
+      GenerateStrategy::FullName => fake::full_name(rng).into(),
+      GenerateStrategy::Username => fake::username(rng).into(),
+      GenerateStrategy::Email => fake::email(rng).into(),
+      GenerateStrategy::Phone => fake::phone(rng).into(),
+      GenerateStrategy::Company => fake::company(rng).into(),
+      GenerateStrategy::City => fake::city(rng).into(),
+      GenerateStrategy::Country => fake::country(rng).into(),
+      GenerateStrategy::Word => fake::word(rng).into(),
+      GenerateStrategy::Sentence => fake::sentence(rng).into(),
+      GenerateStrategy::Uuid => fake::uuid(rng).into(),
+      GenerateStrategy::Integer => fake::integer(rng, self.min, self.max).into(),
+      GenerateStrategy::Float => fake::float(rng, self.min, self.max).into(),
+      GenerateStrategy::Bool => rng.gen::<bool>().into(),
+      GenerateStrategy::Datetime => fake::datetime(rng).into(),
+      GenerateStrategy::Sequence => (self.min.floor() as i64 + index as i64).into(),
+      GenerateStrategy::OneOf => self.values[rng.range(0, self.values.len() as u32) as usize].clone(),
+    }
+  }
+}
+
+/// Find the first named type that `ty` refers to, directly or through other types, that isn't in `types`.
+pub(crate) fn find_missing<'a>(
+  ty: &'a Type,
+  types: &'a HashMap<String, TypeDefinition>,
+  seen: &mut HashSet<&'a str>,
+) -> Option<&'a str> {
+  match ty {
+    Type::Named(name) => {
+      if !seen.insert(name.as_str()) {
+        return None;
+      }
+      let Some(def) = types.get(name) else {
+        return Some(name.as_str());
+      };
+      match def {
+        TypeDefinition::Struct(def) => def.fields.iter().find_map(|f| find_missing(&f.ty, types, seen)),
+        TypeDefinition::Enum(_) => None,
+        TypeDefinition::Union(def) => def.types.iter().find_map(|ty| find_missing(ty, types, seen)),
+      }
+    }
+    Type::List { ty } | Type::Optional { ty } => find_missing(ty, types, seen),
+    Type::Map { key, value } => find_missing(key, types, seen).or_else(|| find_missing(value, types, seen)),
+    Type::AnonymousStruct(fields) => fields.iter().find_map(|f| find_missing(&f.ty, types, seen)),
+    _ => None,
+  }
+}
+
+/// Generates values of a type, following named types through the manifest's types.
+pub(crate) struct Generator<'a> {
+  rng: &'a Random,
+  types: &'a HashMap<String, TypeDefinition>,
+}
+
+impl<'a> Generator<'a> {
+  pub(crate) const fn new(rng: &'a Random, types: &'a HashMap<String, TypeDefinition>) -> Self {
+    Self { rng, types }
+  }
+
+  /// Generate the record at `index`, using `strategies` for the fields they name.
+  pub(crate) fn record(&self, ty: &str, strategies: &HashMap<String, Strategy>, index: u64) -> Result<Value, Error> {
+    let Some(TypeDefinition::Struct(def)) = self.types.get(ty) else {
+      return self.named(ty, 0);
+    };
+    let mut record = Map::new();
+    for field in &def.fields {
+      let value = strategies.get(&field.name).map_or_else(
+        || self.value(&field.ty, &field.name, 1),
+        |strategy| Ok(strategy.generate(self.rng, index)),
+      )?;
+      record.insert(field.name.clone(), value);
+    }
+    Ok(Value::Object(record))
+  }
+
+  fn value(&self, ty: &Type, name: &str, depth: usize) -> Result<Value, Error> {
+    let rng = self.rng;
+    Ok(match ty {
+      Type::I8 => fake::integer(rng, DEFAULT_MIN, f64::from(i8::MAX)).into(),
+      Type::U8 => fake::integer(rng, DEFAULT_MIN, f64::from(u8::MAX)).into(),
+      Type::I16 | Type::I32 | Type::I64 | Type::U16 | Type::U32 | Type::U64 => {
+        fake::integer(rng, DEFAULT_MIN, DEFAULT_MAX).into()
+      }
+      Type::F32 | Type::F64 => fake::float(rng, DEFAULT_MIN, DEFAULT_MAX).into(),
+      Type::Bool => rng.gen::<bool>().into(),
+      Type::String => fake::infer(name).map_or_else(
+        || fake::word(rng).into(),
+        |strategy| Strategy::new(strategy).generate(rng, 0),
+      ),
+      Type::Datetime => fake::datetime(rng).into(),
+      Type::Bytes => base64::engine::general_purpose::STANDARD.encode(rng.bytes(16)).into(),
+      Type::Named(name) => self.named(name, depth)?,
+      Type::List { ty } => {
+        let mut list = Vec::new();
+        if depth < MAX_DEPTH {
+          for _ in 0..rng.range(1, 4) {
+            list.push(self.value(ty, name, depth + 1)?);
+          }
+        }
+        Value::Array(list)
+      }
+      Type::Optional { ty } => {
+        // one in five optional values is left out.
+        if depth < MAX_DEPTH && rng.range(0, 5) != 0 {
+          self.value(ty, name, depth + 1)?
+        } else {
+          Value::Null
+        }
+      }
+      Type::Map { value, .. } => {
+        let mut map = Map::new();
+        if depth < MAX_DEPTH {
+          for _ in 0..rng.range(1, 4) {
+            map.insert(fake::word(rng).to_owned(), self.value(value, name, depth + 1)?);
+          }
+        }
+        Value::Object(map)
+      }
+      Type::Object => Value::Object(Map::new()),
+      Type::AnonymousStruct(fields) => self.fields(fields, depth + 1)?,
+      #[allow(deprecated)]
+      Type::Link { .. } => Value::Null,
+    })
+  }
+
+  fn named(&self, name: &str, depth: usize) -> Result<Value, Error> {
+    if depth >= MAX_NAMED_DEPTH {
+      return Err(Error::TooDeep(name.to_owned()));
+    }
+    let rng = self.rng;
+    // types are checked to exist when the operation is compiled.
+    let Some(def) = self.types.get(name) else {
+      return Ok(Value::Null);
+    };
+    Ok(match def {
+      TypeDefinition::Struct(def) => self.fields(&def.fields, depth + 1)?,
+      TypeDefinition::Enum(def) => {
+        let variants = def.variant_names();
+        if variants.is_empty() {
+          Value::Null
+        } else {
+          fake::pick(rng, &variants).into()
+        }
+      }
+      TypeDefinition::Union(def) => {
+        if def.types.is_empty() {
+          Value::Null
+        } else {
+          let ty = &def.types[rng.range(0, def.types.len() as u32) as usize];
+          self.value(ty, "", depth + 1)?
+        }
+      }
+    })
+  }
+
+  fn fields(&self, fields: &[Field], depth: usize) -> Result<Value, Error> {
+    let mut record = Map::new();
+    for field in fields {
+      record.insert(field.name.clone(), self.value(&field.ty, &field.name, depth)?);
+    }
+    Ok(Value::Object(record))
+  }
+}
@@ -1,12 +1,14 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use eventsource_stream::Eventsource;
 use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
-use futures::{Stream, StreamExt, TryStreamExt};
+use futures::{SinkExt, Stream, StreamExt, TryStreamExt};
 use reqwest::header::CONTENT_TYPE;
-use reqwest::{ClientBuilder, Method, Request, RequestBuilder};
+use reqwest::redirect::Policy;
+use reqwest::{Body, ClientBuilder, Method, Request, RequestBuilder};
 use serde_json::{Map, Value};
 use tracing::Span;
 use url::Url;
@@ -15,11 +17,13 @@ use wick_config::config::components::{
   HttpClientComponentConfig,
   HttpClientOperationDefinition,
   OperationConfig,
+  Proxy,
 };
 use wick_config::config::{Codec, HttpMethod, LiquidJsonConfig, Metadata, UrlResource};
 use wick_config::{ConfigValidation, Resolver};
 use wick_interface_http::types::HttpEvent;
 use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_logger::trace_context;
 use wick_packet::{
   Base64Bytes,
   FluxChannel,
@@ -33,6 +37,7 @@ use wick_packet::{
 };
 
 use crate::error::Error;
+use crate::pool::{self, PoolMetrics};
 static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
 
 #[derive(Debug, Clone)]
@@ -44,6 +49,8 @@ pub struct HttpClientComponent {
   root_config: Option<RuntimeConfig>,
   path_templates: HashMap<String, Arc<(String, String)>>,
   client: reqwest::Client,
+  op_clients: HashMap<String, reqwest::Client>,
+  metrics: PoolMetrics,
 }
 
 impl HttpClientComponent {
@@ -59,28 +66,6 @@ impl HttpClientComponent {
       .and_then(|r| r.try_resource())
       .and_then(|r| r.try_url())?;
 
-    let proxy_details = config.proxy().map(|proxy| {
-      let addr = resolver(proxy.resource())
-        .and_then(|r| r.try_resource())
-        .and_then(|r| r.try_url())
-        .ok();
-
-      let username = proxy.username();
-      let password = proxy.password();
-
-      (addr, username, password)
-    });
-
-    let (proxy_addr, proxy_username, proxy_password) = match proxy_details {
-      Some((addr, user, pass)) => (addr, Some(user).unwrap(), Some(pass).unwrap()),
-      None => (None, None, None),
-    };
-
-    let timeout = config.timeout().map_or_else(
-      || std::time::Duration::from_secs(5),
-      |timeout| std::time::Duration::from_secs(u64::from(timeout)),
-    );
-
     let mut sig = ComponentSignature::new_named("wick/component/http");
     sig.metadata.version = metadata.map(|v| v.version().to_owned());
     sig.operations = config.operation_signatures();
@@ -100,40 +85,81 @@ impl HttpClientComponent {
       );
     }
 
-    if proxy_addr == Some(addr.clone()) {
-      return Err(Error::ProxyLoop(addr.url().value().unwrap().clone()).into());
-    }
-
-    let client = match proxy_addr {
-      Some(proxy_addr) => {
-        let mut proxy = reqwest::Proxy::all(proxy_addr.url().value().unwrap().clone())?;
-
-        if let (Some(username), Some(password)) = (&proxy_username, &proxy_password) {
-          proxy = proxy.basic_auth(username.as_str(), password.as_str());
-        }
-
-        ClientBuilder::new()
-          .proxy(proxy)
-          .connect_timeout(timeout)
-          .user_agent(APP_USER_AGENT)
-          .build()?
+    let client = build_client(&config, &addr, config.proxy(), config.redirects(), resolver)?;
+    // operations that override the proxy or redirect policy need a client of their own.
+    let mut op_clients = HashMap::new();
+    for op in config.operations() {
+      if op.proxy().is_some() || op.redirects().is_some() {
+        let proxy = op.proxy().or(config.proxy());
+        let redirects = op.redirects().or(config.redirects());
+        let op_client = build_client(&config, &addr, proxy, redirects, resolver)?;
+        op_clients.insert(op.name().to_owned(), op_client);
       }
-      None => ClientBuilder::new()
-        .connect_timeout(timeout)
-        .user_agent(APP_USER_AGENT)
-        .build()
-        .unwrap(),
-    };
+    }
 
     Ok(Self {
       signature: sig,
       base: url,
       path_templates,
       client,
+      op_clients,
+      metrics: PoolMetrics::default(),
       root_config,
       config,
     })
   }
+
+  /// Count the requests this component sends in `metrics`, like the ones the runtime reports.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_metrics(mut self, metrics: PoolMetrics) -> Self {
+    self.metrics = metrics;
+    self
+  }
+}
+
+/// Build a client that connects through `proxy` and follows up to `redirects` redirects.
+fn build_client(
+  config: &HttpClientComponentConfig,
+  addr: &UrlResource,
+  proxy: Option<&Proxy>,
+  redirects: Option<u32>,
+  resolver: &Resolver,
+) -> Result<reqwest::Client, ComponentError> {
+  let timeout = config.timeout().map_or_else(
+    || Duration::from_secs(5),
+    |timeout| Duration::from_secs(u64::from(timeout)),
+  );
+  let mut builder = pool::configure(ClientBuilder::new(), config.pool())
+    .connect_timeout(timeout)
+    .user_agent(APP_USER_AGENT);
+
+  let proxy_addr = proxy.and_then(|proxy| {
+    resolver(proxy.resource())
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_url())
+      .ok()
+      .map(|addr| (proxy, addr))
+  });
+  if let Some((proxy, proxy_addr)) = proxy_addr {
+    if proxy_addr == *addr {
+      return Err(Error::ProxyLoop(addr.url().value().unwrap().clone()).into());
+    }
+    let mut reqwest_proxy = reqwest::Proxy::all(proxy_addr.url().value().unwrap().clone())?;
+    if let (Some(username), Some(password)) = (proxy.username(), proxy.password()) {
+      reqwest_proxy = reqwest_proxy.basic_auth(username.as_str(), password.as_str());
+    }
+    builder = builder.proxy(reqwest_proxy);
+  }
+
+  if let Some(max) = redirects {
+    builder = builder.redirect(if max == 0 {
+      Policy::none()
+    } else {
+      Policy::limited(max as usize)
+    });
+  }
+
+  Ok(builder.build()?)
 }
 
 impl Component for HttpClientComponent {
@@ -150,7 +176,12 @@ impl Component for HttpClientComponent {
     let path_template = opdef
       .as_ref()
       .and_then(|op| self.path_templates.get(op.name()).cloned());
-    let client = self.client.clone();
+    let client = opdef
+      .as_ref()
+      .and_then(|op| self.op_clients.get(op.name()))
+      .unwrap_or(&self.client)
+      .clone();
+    let metrics = self.metrics.clone();
 
     Box::pin(async move {
       let (tx, rx) = invocation.make_response();
@@ -165,6 +196,7 @@ impl Component for HttpClientComponent {
         path_template,
         baseurl,
         client,
+        metrics,
       );
       tokio::spawn(async move {
         if let Err(e) = fut.await {
@@ -196,6 +228,7 @@ async fn handle(
   path_template: Option<Arc<(String, String)>>,
   baseurl: Url,
   client: reqwest::Client,
+  metrics: PoolMetrics,
 ) -> anyhow::Result<()> {
   if baseurl.cannot_be_a_base() {
     return Err(Error::InvalidBaseUrl(baseurl).into());
@@ -210,11 +243,16 @@ async fn handle(
   let input_list: Vec<_> = opdef.inputs().iter().map(|i| i.name.clone()).collect();
   let (invocation, stream) = invocation.split();
   let mut inputs = wick_packet::StreamMap::from_stream(stream, input_list);
+  // a streamed body can only be read once, so operations that stream their body send a single request.
+  let streams_body = opdef.body_stream().is_some();
+  let mut body_stream = opdef.body_stream().map(|port| inputs.take(port)).transpose()?;
   let mut handles = Vec::new();
 
   'outer: loop {
     let inputs = match inputs.next_set().await {
       Ok(Some(inputs)) => inputs,
+      // the streamed body can be the only input.
+      Ok(None) if body_stream.is_some() => HashMap::new(),
       Ok(None) => break 'outer,
       Err(e) => {
         let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
@@ -222,7 +260,7 @@ async fn handle(
       }
     };
 
-    if inputs.values().all(|v| v.is_done()) {
+    if !inputs.is_empty() && inputs.values().all(|v| v.is_done()) {
       break 'outer;
     }
     let inputs: Map<String, Value> = inputs
@@ -285,7 +323,9 @@ async fn handle(
     };
 
     let request_builder = RequestBuilder::from_parts(client.clone(), request);
-    let mut request_builder = if let Some(body) = body {
+    let mut request_builder = if let Some(stream) = body_stream.take() {
+      request_builder.body(Body::wrap_stream(body_chunks(stream)))
+    } else if let Some(body) = body {
       match codec {
         Codec::Json => request_builder.json(&body),
         Codec::Raw => {
@@ -313,25 +353,43 @@ async fn handle(
       }
     }
 
+    if let Some(traceparent) = trace_context::traceparent(invocation.span()) {
+      request_builder = request_builder.header(trace_context::TRACEPARENT, traceparent);
+    }
+
+    if let Some(timeout) = opdef.timeout() {
+      request_builder = request_builder.timeout(Duration::from_millis(timeout));
+    }
+
     let (client, request) = request_builder.build_split();
     let request = request.unwrap();
 
     invocation.trace(|| debug!(request=?request, "http:client:request"));
 
+    let url = request.url();
+    let host = format!(
+      "{}:{}",
+      url.host_str().unwrap_or_default(),
+      url.port_or_known_default().unwrap_or_default()
+    );
+    let in_flight = metrics.start(&host);
     let response = match client.execute(request).await {
       Ok(r) => r,
       Err(e) => {
+        in_flight.failed();
         let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
         break 'outer;
       }
     };
+    invocation.trace(|| trace!(%host, stats = ?metrics.snapshot().get(&host), "http:client:pool"));
 
     invocation.trace(|| debug!(status=%response.status(), "http:client:response_status"));
 
     let content_type = response.headers().get(CONTENT_TYPE);
-    let event_stream = content_type.map_or(false, |t| t == "text/event-stream");
+    let stream_response = opdef.stream_response();
+    let event_stream = !stream_response && content_type.map_or(false, |t| t == "text/event-stream");
 
-    let codec = content_type.map_or(Codec::Raw, |value| {
+    let codec = content_type.filter(|_| !stream_response).map_or(Codec::Raw, |value| {
       let value = value.to_str().unwrap();
       let (value, _other) = value.split_once(';').unwrap_or((value, ""));
       if value.starts_with("text/") {
@@ -360,13 +418,15 @@ async fn handle(
     invocation.trace(|| debug!(response = ?our_response, "http:client:response"));
 
     let _ = tx.send(Packet::encode("response", our_response));
-    handles.push(tokio::spawn(output_task(
-      invocation.span.clone(),
-      codec,
-      body_stream,
-      event_stream,
-      tx.clone(),
-    )));
+    let output = output_task(invocation.span.clone(), codec, body_stream, event_stream, tx.clone());
+    handles.push(tokio::spawn(async move {
+      // the connection stays in use until the body has been read.
+      let _in_flight = in_flight;
+      output.await;
+    }));
+    if streams_body {
+      break 'outer;
+    }
   }
   let _ = tx.send(Packet::done("response"));
   let _ = futures::future::join_all(handles).await;
@@ -375,6 +435,30 @@ async fn handle(
   Ok(())
 }
 
+/// Forward the packets of a streamed request body as the chunks of the body, ending at the first error.
+fn body_chunks(mut stream: PacketStream) -> futures::channel::mpsc::Receiver<Result<Vec<u8>, String>> {
+  let (mut tx, rx) = futures::channel::mpsc::channel(16);
+  tokio::spawn(async move {
+    while let Some(packet) = stream.next().await {
+      let chunk = match packet {
+        Ok(packet) if packet.is_done() => break,
+        Ok(packet) if packet.is_error() => Err(packet.unwrap_err().msg().to_owned()),
+        Ok(packet) if !packet.has_data() => continue,
+        Ok(packet) => packet
+          .decode::<Base64Bytes>()
+          .map(Into::into)
+          .map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+      };
+      let failed = chunk.is_err();
+      if tx.send(chunk).await.is_err() || failed {
+        break;
+      }
+    }
+  });
+  rx
+}
+
 fn output_task<T: Stream<Item = Result<Base64Bytes, reqwest::Error>> + Send + Unpin + 'static>(
   span: Span,
   codec: Codec,
@@ -419,9 +503,9 @@ async fn output_task_inner<T: Stream<Item = Result<Base64Bytes, reqwest::Error>>
         }
       } else {
         let bytes: Vec<Base64Bytes> = body_stream.try_collect().await?;
-        let bytes = bytes.concat();
+        let mut bytes = bytes.concat();
 
-        let json: Value = serde_json::from_slice(&bytes)?;
+        let json: Value = wick_packet::json::from_slice_mut(&mut bytes)?;
         span.in_scope(|| trace!(%json, "http:client:response_body"));
         tx.send(Packet::encode("body", json))?;
       }
@@ -460,7 +544,18 @@ impl ConfigValidation for HttpClientComponent {
   }
 }
 
-fn validate(_config: &HttpClientComponentConfig, _resolver: &Resolver) -> Result<(), Error> {
+fn validate(config: &HttpClientComponentConfig, _resolver: &Resolver) -> Result<(), Error> {
+  for op in config.operations() {
+    if let Some(port) = op.body_stream() {
+      if !op.inputs().iter().any(|i| &i.name == port) {
+        return Err(Error::Validation(format!(
+          "operation '{}' streams its body from '{}', which is not one of its inputs",
+          op.name(),
+          port
+        )));
+      }
+    }
+  }
   Ok(())
 }
 
@@ -578,6 +673,24 @@ mod test {
     Ok(())
   }
 
+  #[test_logger::test(test)]
+  fn test_validate_body_stream() -> Result<()> {
+    let (app_config, mut component_config) = get_config();
+    let op =
+      HttpClientOperationDefinition::new_post("upload", "post", vec![Field::new("data", Type::Bytes)], None, None)
+        .body_stream("missing".to_owned())
+        .build()
+        .unwrap();
+    component_config.operations_mut().push(op);
+
+    let result = validate(&component_config, &app_config.resolver());
+    assert!(
+      result.is_err(),
+      "a streamed body must come from one of the operation's inputs"
+    );
+    Ok(())
+  }
+
   mod integration_test {
     use serde_json::json;
     use wick_interface_http::types::{HttpResponse, HttpVersion};
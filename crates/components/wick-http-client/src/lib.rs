@@ -114,9 +114,11 @@
 mod component;
 mod conversions;
 mod error;
+mod pool;
 
 #[macro_use]
 extern crate tracing;
 
 pub use component::HttpClientComponent;
 pub use error::Error;
+pub use pool::{HostPoolStats, PoolMetrics};
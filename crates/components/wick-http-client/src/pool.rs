@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::RwLock;
+use reqwest::ClientBuilder;
+use wick_config::config::components::ConnectionPool;
+
+/// Apply pool settings from configuration to a client that is being built.
+///
+/// Every client resolves names through the shared caching resolver.
+pub(crate) fn configure(builder: ClientBuilder, pool: Option<&ConnectionPool>) -> ClientBuilder {
+  let mut builder = builder.dns_resolver(Arc::new(cached_resolver::shared()));
+  let Some(pool) = pool else {
+    return builder;
+  };
+  if let Some(max) = pool.max_idle_per_host() {
+    builder = builder.pool_max_idle_per_host(max as usize);
+  }
+  if let Some(secs) = pool.idle_timeout() {
+    builder = builder.pool_idle_timeout(Duration::from_secs(secs));
+  }
+  if let Some(secs) = pool.keep_alive() {
+    builder = builder.tcp_keepalive(Duration::from_secs(secs));
+  }
+  // without prior knowledge, HTTPS connections still negotiate HTTP/2 with hosts that support it.
+  if pool.http2_prior_knowledge() {
+    builder = builder.http2_prior_knowledge().http2_adaptive_window(true);
+  }
+  builder
+}
+
+/// Usage statistics for the requests a component has made to a single host.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct HostPoolStats {
+  /// The total number of requests sent to the host.
+  pub requests: u64,
+  /// The number of requests whose connection is currently in use, including while the response body is read.
+  pub in_flight: u64,
+  /// The highest number of requests that were in flight at the same time.
+  pub peak_in_flight: u64,
+  /// The number of requests that failed before a response was received.
+  pub errors: u64,
+}
+
+#[derive(Debug, Default)]
+struct HostCounters {
+  requests: AtomicU64,
+  in_flight: AtomicU64,
+  peak_in_flight: AtomicU64,
+  errors: AtomicU64,
+}
+
+/// Per-host usage counters shared by every clone of a component, and by whatever reports them.
+#[derive(Debug, Clone, Default)]
+pub struct PoolMetrics {
+  hosts: Arc<RwLock<HashMap<String, Arc<HostCounters>>>>,
+}
+
+impl PoolMetrics {
+  /// Record the start of a request to `host`. The request is considered in flight until the guard is dropped.
+  pub(crate) fn start(&self, host: &str) -> InFlight {
+    let counters = self.hosts.read().get(host).cloned();
+    let counters = counters.unwrap_or_else(|| self.hosts.write().entry(host.to_owned()).or_default().clone());
+    counters.requests.fetch_add(1, Ordering::Relaxed);
+    let in_flight = counters.in_flight.fetch_add(1, Ordering::Relaxed) + 1;
+    counters.peak_in_flight.fetch_max(in_flight, Ordering::Relaxed);
+    InFlight { counters }
+  }
+
+  /// Usage statistics for each host requests were sent to, keyed by `host:port`.
+  #[must_use]
+  pub fn snapshot(&self) -> HashMap<String, HostPoolStats> {
+    self
+      .hosts
+      .read()
+      .iter()
+      .map(|(host, c)| {
+        (host.clone(), HostPoolStats {
+          requests: c.requests.load(Ordering::Relaxed),
+          in_flight: c.in_flight.load(Ordering::Relaxed),
+          peak_in_flight: c.peak_in_flight.load(Ordering::Relaxed),
+          errors: c.errors.load(Ordering::Relaxed),
+        })
+      })
+      .collect()
+  }
+}
+
+/// A request that is using a pooled connection.
+#[derive(Debug)]
+#[must_use]
+pub(crate) struct InFlight {
+  counters: Arc<HostCounters>,
+}
+
+impl InFlight {
+  /// Record that the request failed before a response was received.
+  pub(crate) fn failed(self) {
+    self.counters.errors.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+impl Drop for InFlight {
+  fn drop(&mut self) {
+    self.counters.in_flight.fetch_sub(1, Ordering::Relaxed);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_pool_metrics() -> Result<()> {
+    let metrics = PoolMetrics::default();
+    let first = metrics.start("example.com");
+    let second = metrics.start("example.com");
+    drop(first);
+    second.failed();
+    let _third = metrics.start("other.com");
+
+    let stats = metrics.snapshot();
+    assert_eq!(stats["example.com"], HostPoolStats {
+      requests: 2,
+      in_flight: 0,
+      peak_in_flight: 2,
+      errors: 1,
+    });
+    assert_eq!(stats["other.com"].in_flight, 1);
+    Ok(())
+  }
+}
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use serde_json::{Map, Value};
+use unic_langid::LanguageIdentifier;
+
+use crate::error::Error;
+use crate::gettext;
+
+/// The message catalogs of every locale in a volume, e.g.
+///
+/// ```text
+/// en-US/main.ftl
+/// fr/LC_MESSAGES/app.po
+/// ```
+#[derive(Debug)]
+pub(crate) struct Catalogs {
+  /// Each locale's catalogs, keyed by its normalized name.
+  locales: HashMap<String, Catalog>,
+  fallback: Option<String>,
+}
+
+/// The messages of one locale, from all of the Fluent and gettext files in its directory.
+struct Catalog {
+  fluent: FluentBundle<FluentResource>,
+  gettext: HashMap<String, String>,
+}
+
+impl std::fmt::Debug for Catalog {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Catalog")
+      .field("locales", &self.fluent.locales)
+      .field("gettext", &self.gettext.len())
+      .finish()
+  }
+}
+
+impl Catalogs {
+  /// Load the catalogs in each directory at the root of `root`, which is named after the locale it holds.
+  pub(crate) fn load(root: &Path, fallback: Option<&str>) -> Result<Self, Error> {
+    let read_error = |e: std::io::Error| Error::Read(root.display().to_string(), e.to_string());
+    let mut locales = HashMap::new();
+    for entry in std::fs::read_dir(root).map_err(read_error)? {
+      let entry = entry.map_err(read_error)?;
+      if !entry.file_type().map_err(read_error)?.is_dir() {
+        continue;
+      }
+      let name = entry.file_name().to_string_lossy().to_string();
+      let catalog = Catalog::load(&name, &entry.path())?;
+      debug!(locale = %name, "i18n: loaded catalogs");
+      locales.insert(normalize(&name), catalog);
+    }
+
+    let fallback = fallback.map(normalize);
+    if let Some(fallback) = &fallback {
+      if !locales.contains_key(fallback) {
+        return Err(Error::MissingFallback(fallback.clone()));
+      }
+    }
+    Ok(Self { locales, fallback })
+  }
+
+  /// Translate the message `key` to `locale`, falling back to the locale's language and then to the fallback locale
+  /// when it has no such message.
+  pub(crate) fn translate(&self, key: &str, locale: &str, args: &Map<String, Value>) -> Result<String, Error> {
+    let locale = normalize(locale);
+    let language = locale.split('-').next().map(ToOwned::to_owned);
+    let mut candidates = vec![locale.clone()];
+    for candidate in language.into_iter().chain(self.fallback.clone()) {
+      if !candidates.contains(&candidate) {
+        candidates.push(candidate);
+      }
+    }
+
+    for catalog in candidates.iter().filter_map(|candidate| self.locales.get(candidate)) {
+      if let Some(message) = catalog.format(key, args)? {
+        return Ok(message);
+      }
+    }
+    Err(Error::MissingMessage(key.to_owned(), locale))
+  }
+}
+
+impl Catalog {
+  fn load(name: &str, dir: &Path) -> Result<Self, Error> {
+    let language: LanguageIdentifier = normalize(name)
+      .parse()
+      .map_err(|_| Error::InvalidLocale(name.to_owned()))?;
+    let mut fluent = FluentBundle::new_concurrent(vec![language]);
+    // isolation marks keep bidirectional text intact in UIs, but would end up in every API response.
+    fluent.set_use_isolating(false);
+    let mut gettext = HashMap::new();
+
+    let mut files: Vec<_> = walkdir::WalkDir::new(dir)
+      .into_iter()
+      .collect::<Result<_, _>>()
+      .map_err(|e| Error::Read(dir.display().to_string(), e.to_string()))?;
+    files.sort_by(|a, b| a.path().cmp(b.path()));
+
+    for file in files {
+      let path = file.path();
+      let source = path.display().to_string();
+      let extension = path.extension().and_then(|e| e.to_str());
+      if !matches!(extension, Some("ftl" | "po")) {
+        continue;
+      }
+      let text = std::fs::read_to_string(path).map_err(|e| Error::Read(source.clone(), e.to_string()))?;
+      if extension == Some("ftl") {
+        let resource =
+          FluentResource::try_new(text).map_err(|(_, errors)| Error::Parse(source.clone(), join(&errors)))?;
+        fluent
+          .add_resource(resource)
+          .map_err(|errors| Error::Parse(source.clone(), join(&errors)))?;
+      } else {
+        gettext.extend(gettext::parse(&text).map_err(|e| Error::Parse(source.clone(), e))?);
+      }
+    }
+    Ok(Self { fluent, gettext })
+  }
+
+  /// Format the message `key`, if this locale has it.
+  fn format(&self, key: &str, args: &Map<String, Value>) -> Result<Option<String>, Error> {
+    let Some(pattern) = self.fluent.get_message(key).and_then(|message| message.value()) else {
+      return Ok(
+        self
+          .gettext
+          .get(key)
+          .map(|translation| gettext::interpolate(translation, args)),
+      );
+    };
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+      fluent_args.set(name.as_str(), fluent_value(value));
+    }
+    let mut errors = Vec::new();
+    let message = self.fluent.format_pattern(pattern, Some(&fluent_args), &mut errors);
+    if !errors.is_empty() {
+      return Err(Error::Format(key.to_owned(), join(&errors)));
+    }
+    Ok(Some(message.into_owned()))
+  }
+}
+
+fn fluent_value(value: &Value) -> FluentValue<'_> {
+  match value {
+    Value::String(s) => FluentValue::from(s.as_str()),
+    Value::Number(n) => n
+      .as_f64()
+      .map_or_else(|| FluentValue::from(n.to_string()), FluentValue::from),
+    other => FluentValue::from(other.to_string()),
+  }
+}
+
+/// Locales are matched without regard to case or whether they're written `pt_BR` or `pt-BR`, and any encoding or
+/// modifier, as in `de_DE.UTF-8@euro`, is ignored.
+fn normalize(locale: &str) -> String {
+  let locale = locale.split(|c| c == '.' || c == '@').next().unwrap_or_default();
+  locale.replace('_', "-").to_lowercase()
+}
+
+fn join<T: std::fmt::Display>(errors: &[T]) -> String {
+  errors.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::PathBuf;
+
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  fn catalogs(name: &str, files: &[(&str, &str)]) -> Result<PathBuf> {
+    let root = std::env::temp_dir().join(format!("wick-i18n-{}-{}", name, std::process::id()));
+    for (path, text) in files {
+      let path = root.join(path);
+      std::fs::create_dir_all(path.parent().unwrap())?;
+      std::fs::write(path, text)?;
+    }
+    Ok(root)
+  }
+
+  fn args(value: Value) -> Map<String, Value> {
+    match value {
+      Value::Object(map) => map,
+      _ => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn test_translate() -> Result<()> {
+    let root = catalogs(
+      "translate",
+      &[
+        (
+          "en-US/main.ftl",
+          "hello = Hello, { $name }!\nunread = { $count ->\n    [one] One message\n   *[other] { $count } messages\n}\n",
+        ),
+        ("fr/LC_MESSAGES/app.po", "msgid \"hello\"\nmsgstr \"Bonjour, {name} !\"\n"),
+        ("README.md", "not a catalog"),
+      ],
+    )?;
+    let catalogs = Catalogs::load(&root, Some("en-US"))?;
+
+    assert_eq!(
+      catalogs.translate("hello", "en_US", &args(json!({"name": "Ada"})))?,
+      "Hello, Ada!"
+    );
+    assert_eq!(
+      catalogs.translate("unread", "en-US", &args(json!({"count": 1})))?,
+      "One message"
+    );
+    assert_eq!(
+      catalogs.translate("unread", "en-US", &args(json!({"count": 5})))?,
+      "5 messages"
+    );
+    // fr-CA has no catalogs, so its language's are used.
+    assert_eq!(
+      catalogs.translate("hello", "fr-CA", &args(json!({"name": "Ada"})))?,
+      "Bonjour, Ada !"
+    );
+    // the French catalog has no `unread`, so the fallback's is used.
+    assert_eq!(
+      catalogs.translate("unread", "fr", &args(json!({"count": 2})))?,
+      "2 messages"
+    );
+    assert_eq!(
+      catalogs.translate("missing", "fr", &Map::new()),
+      Err(Error::MissingMessage("missing".to_owned(), "fr".to_owned()))
+    );
+    assert!(matches!(
+      catalogs.translate("hello", "en-US", &Map::new()),
+      Err(Error::Format(key, _)) if key == "hello"
+    ));
+
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_load_errors() -> Result<()> {
+    let root = catalogs("fallback", &[("en/main.ftl", "hello = Hello\n")])?;
+    assert_eq!(
+      Catalogs::load(&root, Some("de")).unwrap_err(),
+      Error::MissingFallback("de".to_owned())
+    );
+    std::fs::remove_dir_all(root)?;
+
+    let root = catalogs("invalid", &[("en/main.ftl", "hello Hello\n")])?;
+    assert!(matches!(Catalogs::load(&root, None), Err(Error::Parse(..))));
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, I18nComponentConfig, OperationConfig};
+use wick_config::config::Metadata;
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::catalog::Catalogs;
+use crate::error::Error;
+use crate::operation;
+
+/// A component that translates messages from the Fluent or gettext catalogs in a volume.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct I18nComponent {
+  signature: ComponentSignature,
+  operations: Vec<String>,
+  catalogs: Arc<Catalogs>,
+}
+
+impl I18nComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: I18nComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/i18n");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let path = resolver(config.resource())
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_volume())?
+      .path()?;
+    let catalogs = Catalogs::load(&path, config.fallback().map(String::as_str))?;
+
+    Ok(Self {
+      signature: sig,
+      operations: config.operations().iter().map(|op| op.name().to_owned()).collect(),
+      catalogs: Arc::new(catalogs),
+    })
+  }
+}
+
+impl Component for I18nComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let known = self
+      .operations
+      .iter()
+      .any(|op| op == invocation.target().operation_id());
+    let catalogs = self.catalogs.clone();
+
+    Box::pin(async move {
+      if known {
+        Ok(operation::translate(catalogs, invocation))
+      } else {
+        Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into())
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<I18nComponent>();
+  }
+}
@@ -0,0 +1,27 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Could not read message catalogs from {0}: {1}")]
+  Read(String, String),
+
+  #[error("Could not parse message catalog {0}: {1}")]
+  Parse(String, String),
+
+  #[error("Catalog directory '{0}' is not named after a valid locale")]
+  InvalidLocale(String),
+
+  #[error("The fallback locale '{0}' has no catalogs")]
+  MissingFallback(String),
+
+  #[error("No message '{0}' for locale '{1}'")]
+  MissingMessage(String, String),
+
+  #[error("Could not format message '{0}': {1}")]
+  Format(String, String),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
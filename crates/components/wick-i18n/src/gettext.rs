@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+/// Read the translations of a gettext `.po` file, keyed by their `msgid`.
+///
+/// Like gettext itself, untranslated and fuzzy entries are left out, as is the header. Plural entries are keyed by
+/// their singular `msgid` and translate to their first form. Entries with a `msgctxt` can't be looked up by key alone
+/// and are skipped.
+pub(crate) fn parse(text: &str) -> Result<HashMap<String, String>, String> {
+  let mut messages = HashMap::new();
+  let mut entry = Entry::default();
+  let mut field = None;
+
+  for (i, line) in text.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() {
+      entry.finish(&mut messages);
+      field = None;
+      continue;
+    }
+    if line.starts_with('#') {
+      // comments come before an entry's keywords, so one entry can follow another without a blank line between.
+      if entry.id.is_some() {
+        entry.finish(&mut messages);
+        field = None;
+      }
+      if let Some(flags) = line.strip_prefix("#,") {
+        entry.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+      }
+      continue;
+    }
+
+    let (keyword, rest) = if line.starts_with('"') {
+      (None, line)
+    } else {
+      let (keyword, rest) = line
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| format!("line {}: expected a keyword and a string", i + 1))?;
+      (Some(keyword), rest.trim_start())
+    };
+    let value = unquote(rest).ok_or_else(|| format!("line {}: expected a quoted string", i + 1))?;
+
+    if let Some(keyword) = keyword {
+      let next = match keyword {
+        "msgctxt" => Field::Context,
+        "msgid" => Field::Id,
+        "msgstr" | "msgstr[0]" => Field::Translation,
+        "msgid_plural" => Field::Ignored,
+        other if other.starts_with("msgstr[") => Field::Ignored,
+        other => return Err(format!("line {}: unknown keyword '{}'", i + 1, other)),
+      };
+      if matches!(next, Field::Context | Field::Id) && entry.id.is_some() {
+        entry.finish(&mut messages);
+      }
+      field = Some(next);
+    }
+    match field {
+      Some(Field::Context) => entry.context.get_or_insert_with(String::new).push_str(&value),
+      Some(Field::Id) => entry.id.get_or_insert_with(String::new).push_str(&value),
+      Some(Field::Translation) => entry.translation.push_str(&value),
+      Some(Field::Ignored) => {}
+      None => return Err(format!("line {}: string without a keyword", i + 1)),
+    }
+  }
+  entry.finish(&mut messages);
+  Ok(messages)
+}
+
+/// Replace each `{name}` in a translation with the value of the `name` argument. Placeholders without an argument are
+/// left as they are.
+pub(crate) fn interpolate(translation: &str, args: &Map<String, Value>) -> String {
+  let mut output = String::with_capacity(translation.len());
+  let mut rest = translation;
+  while let Some(start) = rest.find('{') {
+    output.push_str(&rest[..start]);
+    let placeholder = &rest[start..];
+    let value = placeholder
+      .find('}')
+      .and_then(|end| args.get(&placeholder[1..end]).map(|value| (end, value)));
+    let Some((end, value)) = value else {
+      output.push('{');
+      rest = &placeholder[1..];
+      continue;
+    };
+    match value {
+      Value::String(s) => output.push_str(s),
+      other => output.push_str(&other.to_string()),
+    }
+    rest = &placeholder[end + 1..];
+  }
+  output.push_str(rest);
+  output
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Field {
+  Context,
+  Id,
+  Translation,
+  Ignored,
+}
+
+#[derive(Debug, Default)]
+struct Entry {
+  context: Option<String>,
+  id: Option<String>,
+  translation: String,
+  fuzzy: bool,
+}
+
+impl Entry {
+  fn finish(&mut self, messages: &mut HashMap<String, String>) {
+    let entry = std::mem::take(self);
+    if let Some(id) = entry.id {
+      if !id.is_empty() && !entry.translation.is_empty() && !entry.fuzzy && entry.context.is_none() {
+        messages.insert(id, entry.translation);
+      }
+    }
+  }
+}
+
+fn unquote(s: &str) -> Option<String> {
+  let inner = s.strip_prefix('"')?.strip_suffix('"')?;
+  let mut output = String::with_capacity(inner.len());
+  let mut chars = inner.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      output.push(c);
+      continue;
+    }
+    match chars.next()? {
+      'n' => output.push('\n'),
+      't' => output.push('\t'),
+      'r' => output.push('\r'),
+      other => output.push(other),
+    }
+  }
+  Some(output)
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_parse() -> Result<()> {
+    let messages = parse(
+      r#"
+# header
+msgid ""
+msgstr "Content-Type: text/plain; charset=UTF-8\n"
+
+#: src/main.rs:10
+msgid "greeting"
+msgstr "Bonjour, {name} !"
+#, fuzzy
+msgid "farewell"
+msgstr "Au revoir"
+
+msgid "long"
+msgstr ""
+"Une ligne\n"
+"et \"une autre\""
+
+msgid "untranslated"
+msgstr ""
+
+msgctxt "menu"
+msgid "open"
+msgstr "Ouvrir"
+
+msgid "item"
+msgid_plural "items"
+msgstr[0] "article"
+msgstr[1] "articles"
+"#,
+    )
+    .map_err(anyhow::Error::msg)?;
+
+    let mut keys: Vec<_> = messages.keys().cloned().collect();
+    keys.sort();
+    assert_eq!(keys, vec!["greeting", "item", "long"]);
+    assert_eq!(messages["long"], "Une ligne\net \"une autre\"");
+    assert_eq!(messages["item"], "article");
+
+    assert!(parse("msgid greeting").is_err());
+    assert!(parse("msgfoo \"greeting\"").is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn test_interpolate() {
+    let args = json!({"name": "Ada", "count": 3});
+    let args = args.as_object().unwrap();
+    assert_eq!(
+      interpolate("Bonjour, {name} ! {count} messages, {missing} {", args),
+      "Bonjour, Ada ! 3 messages, {missing} {"
+    );
+  }
+}
@@ -0,0 +1,95 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use serde_json::{Map, Value};
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::catalog::Catalogs;
+use crate::error::Error;
+
+const PORTS: [&str; 3] = ["key", "locale", "args"];
+
+/// Process the invocation's stream, sending a translation on the `output` port for each `key`, `locale`, and `args`.
+pub(crate) fn translate(catalogs: Arc<Catalogs>, invocation: Invocation) -> PacketStream {
+  let (tx, rx) = invocation.make_response();
+  let span = invocation.span().clone();
+  let mut stream = invocation.into_stream();
+
+  tokio::spawn(async move {
+    match each_message(&mut stream, &tx, &catalogs).await {
+      Ok(()) => {
+        let _ = tx.send(Packet::done("output"));
+      }
+      Err(e) => {
+        span.in_scope(|| error!(error = %e, "i18n"));
+        let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+      }
+    }
+    tx.complete();
+  });
+
+  rx
+}
+
+async fn each_message(stream: &mut PacketStream, tx: &PacketSender, catalogs: &Catalogs) -> Result<(), Error> {
+  let mut queues: Vec<VecDeque<Value>> = vec![VecDeque::new(); PORTS.len()];
+  let mut done = [false; PORTS.len()];
+
+  while let Some(next) = stream.next().await {
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    let Some(index) = PORTS.iter().position(|port| *port == packet.port()) else {
+      continue;
+    };
+    if packet.is_error() {
+      let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+      continue;
+    }
+    if packet.is_done() {
+      done[index] = true;
+    } else if packet.has_data() {
+      queues[index].push_back(packet.decode_value().map_err(|e| Error::Input(e.to_string()))?);
+    }
+
+    while queues.iter().all(|queue| !queue.is_empty()) {
+      let mut values = queues.iter_mut().map(|queue| queue.pop_front().unwrap());
+      let (key, locale, args) = (values.next(), values.next(), values.next());
+      let packet = match message(key, locale, args) {
+        Ok((key, locale, args)) => match catalogs.translate(&key, &locale, &args) {
+          Ok(message) => Packet::encode("output", message),
+          Err(e) => Packet::err("output", e.to_string()),
+        },
+        Err(e) => Packet::err("output", e.to_string()),
+      };
+      let _ = tx.send(packet);
+    }
+
+    // Once an input is done and drained, no more messages can be completed.
+    if done.iter().zip(&queues).any(|(done, queue)| *done && queue.is_empty()) {
+      break;
+    }
+  }
+  Ok(())
+}
+
+/// Read the inputs of one message. A null `args` is treated as no arguments.
+fn message(
+  key: Option<Value>,
+  locale: Option<Value>,
+  args: Option<Value>,
+) -> Result<(String, String, Map<String, Value>), Error> {
+  let string = |port: &str, value: Option<Value>| match value {
+    Some(Value::String(s)) => Ok(s),
+    other => Err(Error::Input(format!(
+      "expected a string on '{}', found {}",
+      port,
+      other.unwrap_or_default()
+    ))),
+  };
+  let args = match args {
+    Some(Value::Object(args)) => args,
+    Some(Value::Null) | None => Map::new(),
+    Some(other) => return Err(Error::Input(format!("expected an object on 'args', found {}", other))),
+  };
+  Ok((string("key", key)?, string("locale", locale)?, args))
+}
@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use tokio::sync::mpsc;
+use wick_config::config::components::{ComponentConfig, MetricsComponentConfig, OperationConfig};
+use wick_config::config::{Metadata, UrlResource};
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+use crate::sink::Sink;
+
+const DEFAULT_BATCH_SIZE: u32 = 500;
+const DEFAULT_FLUSH_INTERVAL: u64 = 1000;
+
+/// A component that ships metric samples to a Prometheus remote-write endpoint or a statsd server.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct MetricsComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl MetricsComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub async fn new(
+    config: MetricsComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/metrics");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let addr: UrlResource = resolver(config.resource())
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_url())?;
+    let url = addr
+      .url()
+      .value()
+      .cloned()
+      .ok_or_else(|| Error::NoUrl(addr.to_string()))?;
+    let sink = Sink::new(*config.protocol(), url).await?;
+
+    let batch_size = config.batch_size().unwrap_or(DEFAULT_BATCH_SIZE).max(1) as usize;
+    let interval = Duration::from_millis(config.flush_interval().unwrap_or(DEFAULT_FLUSH_INTERVAL).max(1));
+    let (queue, samples) = mpsc::channel(batch_size * 2);
+    // the sink sends what's left and stops once the component and every operation it's running are dropped.
+    tokio::spawn(sink.run(samples, batch_size, interval));
+
+    let labels: Arc<BTreeMap<_, _>> = Arc::new(config.labels().clone().into_iter().collect());
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| {
+        let operation = Operation::new(*op.metric(), labels.clone(), queue.clone());
+        (op.name().to_owned(), Arc::new(operation))
+      })
+      .collect();
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for MetricsComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::{json, Value};
+  use tokio::net::UdpSocket;
+  use wick_config::config::components::{
+    MetricKind,
+    MetricsComponentConfigBuilder,
+    MetricsOperationDefinitionBuilder,
+    MetricsProtocol,
+  };
+  use wick_config::config::{AppConfiguration, ResourceDefinition};
+  use wick_packet::{Entity, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<MetricsComponent>();
+  }
+
+  async fn component(url: &str, protocol: MetricsProtocol) -> Result<MetricsComponent> {
+    let mut app_config = AppConfiguration::default();
+    app_config.add_resource("sink", ResourceDefinition::Url(url.to_owned().try_into()?));
+    let config = MetricsComponentConfigBuilder::default()
+      .resource("sink")
+      .protocol(protocol)
+      .labels(HashMap::from([("env".to_owned(), "test".to_owned())]))
+      .flush_interval(10_u64)
+      .operations(vec![MetricsOperationDefinitionBuilder::default()
+        .name("requests")
+        .metric(MetricKind::Counter)
+        .build()?])
+      .build()?;
+    let component = MetricsComponent::new(config, None, None, &app_config.resolver()).await?;
+
+    Ok(component)
+  }
+
+  #[tokio::test]
+  async fn test_statsd() -> Result<()> {
+    let server = UdpSocket::bind("127.0.0.1:0").await?;
+    let url = format!("udp://{}", server.local_addr()?);
+    let component = component(&url, MetricsProtocol::Statsd).await?;
+
+    let packets = vec![
+      Packet::encode(
+        "sample",
+        json!({"name": "requests_total", "value": 2, "labels": {"route": "/"}}),
+      ),
+      Packet::encode("sample", json!({"name": "requests_total", "value": -1})),
+      Packet::done("sample"),
+    ];
+    let invocation = Invocation::test(file!(), Entity::local("requests"), packets, None)?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let outputs: Vec<_> = packets
+      .into_iter()
+      .filter(|p| p.as_ref().map_or(true, |p| !p.is_done()))
+      .collect();
+    assert_eq!(outputs.len(), 2);
+    let sample: Value = outputs[0].as_ref().unwrap().decode_value()?;
+    assert_eq!(sample["labels"], json!({"env": "test", "route": "/"}));
+    assert!(outputs[1].as_ref().unwrap().is_error());
+
+    let mut buf = vec![0; 1500];
+    let len = tokio::time::timeout(Duration::from_secs(5), server.recv(&mut buf)).await??;
+    assert_eq!(
+      std::str::from_utf8(&buf[..len])?,
+      "requests_total:2|c|#env:test,route:/"
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_scheme() -> Result<()> {
+    let error = component("udp://127.0.0.1:8125", MetricsProtocol::RemoteWrite)
+      .await
+      .unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "The remote-write protocol sends samples to http(s) URLs, found 'udp://127.0.0.1:8125'"
+    );
+    Ok(())
+  }
+}
@@ -0,0 +1,27 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Resource {0} has no URL")]
+  NoUrl(String),
+
+  #[error("The {0} protocol sends samples to {1} URLs, found '{2}'")]
+  Scheme(String, String, String),
+
+  #[error("Could not resolve statsd server {0}: {1}")]
+  Resolve(String, String),
+
+  #[error("Could not send samples: {0}")]
+  Send(String),
+
+  #[error("The metrics sink has shut down")]
+  Closed,
+
+  #[error("Invalid sample: {0}")]
+  Sample(String),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
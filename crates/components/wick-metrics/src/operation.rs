@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use tokio::sync::mpsc;
+use wick_config::config::components::MetricKind;
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::error::Error;
+use crate::sample::Sample;
+
+/// An operation that queues the samples of one kind of metric.
+#[derive(Debug)]
+pub(crate) struct Operation {
+  kind: MetricKind,
+  labels: Arc<BTreeMap<String, String>>,
+  queue: mpsc::Sender<Sample>,
+}
+
+impl Operation {
+  pub(crate) const fn new(
+    kind: MetricKind,
+    labels: Arc<BTreeMap<String, String>>,
+    queue: mpsc::Sender<Sample>,
+  ) -> Self {
+    Self { kind, labels, queue }
+  }
+
+  /// Process the invocation's stream, queueing each `sample` and sending it back on the `output` port once it is.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      match self.each_sample(&mut stream, &tx).await {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("output"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "metrics"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+
+  async fn each_sample(&self, stream: &mut PacketStream, tx: &PacketSender) -> Result<(), Error> {
+    while let Some(next) = stream.next().await {
+      let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+      if packet.port() != "sample" {
+        continue;
+      }
+      if packet.is_done() {
+        break;
+      }
+      if packet.is_error() {
+        let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+      } else if packet.is_bracket() {
+        let _ = tx.send(packet.to_port("output"));
+      } else if packet.has_data() {
+        let sample = packet
+          .decode_value()
+          .map_err(|e| Error::Input(e.to_string()))
+          .and_then(|value| Sample::from_value(value, self.kind, &self.labels));
+        let packet = match sample {
+          Ok(sample) => {
+            let output = sample.to_value();
+            // waiting for room in the queue holds the flow back when samples come faster than they can be sent.
+            self.queue.send(sample).await.map_err(|_| Error::Closed)?;
+            Packet::encode("output", output)
+          }
+          Err(e) => Packet::err("output", e.to_string()),
+        };
+        let _ = tx.send(packet);
+      }
+    }
+    Ok(())
+  }
+}
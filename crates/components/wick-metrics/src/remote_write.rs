@@ -0,0 +1,133 @@
+use std::collections::{BTreeMap, HashMap};
+
+use prost::encoding::{self, WireType};
+use wick_config::config::components::MetricKind;
+
+use crate::sample::Sample;
+
+/// A series' labels, including its `__name__`, sorted by name.
+type SeriesKey = Vec<(String, String)>;
+
+/// The running totals of counter series. Remote-write receivers expect counters to be cumulative, but the samples a
+/// flow sends are increments.
+#[derive(Debug, Default)]
+pub(crate) struct Totals(HashMap<SeriesKey, f64>);
+
+/// Group samples into series, each with its values in the order of their timestamps. Samples are totaled in that order
+/// too, so a counter never appears to go down.
+fn series(samples: &[Sample], totals: &mut Totals) -> BTreeMap<SeriesKey, Vec<(f64, i64)>> {
+  let mut samples: Vec<_> = samples.iter().collect();
+  samples.sort_by_key(|sample| sample.timestamp);
+
+  let mut series: BTreeMap<SeriesKey, Vec<(f64, i64)>> = BTreeMap::new();
+  for sample in samples {
+    let mut labels = sample.labels.clone();
+    labels.insert("__name__".to_owned(), sample.name.clone());
+    let key: SeriesKey = labels.into_iter().collect();
+    let value = if sample.kind == MetricKind::Counter {
+      let total = totals.0.entry(key.clone()).or_default();
+      *total += sample.value;
+      *total
+    } else {
+      sample.value
+    };
+    series.entry(key).or_default().push((value, sample.timestamp));
+  }
+  series
+}
+
+/// Encode samples as a `prometheus.WriteRequest` protobuf message.
+pub(crate) fn encode(samples: &[Sample], totals: &mut Totals) -> Vec<u8> {
+  let mut request = Vec::new();
+  for (labels, values) in series(samples, totals) {
+    let mut series = Vec::new();
+    for (name, value) in &labels {
+      let mut label = Vec::new();
+      encoding::string::encode(1, name, &mut label);
+      encoding::string::encode(2, value, &mut label);
+      message(1, &label, &mut series);
+    }
+    for (value, timestamp) in &values {
+      let mut sample = Vec::new();
+      encoding::double::encode(1, value, &mut sample);
+      encoding::int64::encode(2, timestamp, &mut sample);
+      message(2, &sample, &mut series);
+    }
+    message(1, &series, &mut request);
+  }
+  request
+}
+
+fn message(tag: u32, inner: &[u8], buf: &mut Vec<u8>) {
+  encoding::encode_key(tag, WireType::LengthDelimited, buf);
+  encoding::encode_varint(inner.len() as u64, buf);
+  buf.extend_from_slice(inner);
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn sample(name: &str, kind: MetricKind, value: f64, timestamp: i64) -> Sample {
+    Sample {
+      name: name.to_owned(),
+      kind,
+      value,
+      labels: BTreeMap::from([("job".to_owned(), "a".to_owned())]),
+      timestamp,
+    }
+  }
+
+  #[test]
+  fn test_encode() {
+    let bytes = encode(&[sample("up", MetricKind::Gauge, 1.0, 1000)], &mut Totals::default());
+
+    let mut name = vec![0x0a, 0x08];
+    name.extend(b"__name__");
+    name.extend([0x12, 0x02]);
+    name.extend(b"up");
+    let mut job = vec![0x0a, 0x03];
+    job.extend(b"job");
+    job.extend([0x12, 0x01]);
+    job.extend(b"a");
+    let mut value = vec![0x09];
+    value.extend(1.0_f64.to_le_bytes());
+    value.extend([0x10, 0xe8, 0x07]);
+
+    let mut series = vec![0x0a, name.len() as u8];
+    series.extend(name);
+    series.extend([0x0a, job.len() as u8]);
+    series.extend(job);
+    series.extend([0x12, value.len() as u8]);
+    series.extend(value);
+    let mut expected = vec![0x0a, series.len() as u8];
+    expected.extend(series);
+
+    assert_eq!(bytes, expected);
+  }
+
+  #[test]
+  fn test_series() {
+    let mut totals = Totals::default();
+    let series = series(
+      &[
+        sample("rows_total", MetricKind::Counter, 2.0, 20),
+        sample("rows_total", MetricKind::Counter, 3.0, 10),
+        sample("lag", MetricKind::Gauge, 7.0, 10),
+      ],
+      &mut totals,
+    );
+    let key = |name: &str| {
+      vec![
+        ("__name__".to_owned(), name.to_owned()),
+        ("job".to_owned(), "a".to_owned()),
+      ]
+    };
+    // counters are sent as running totals, added up in order of time.
+    assert_eq!(series[&key("rows_total")], vec![(3.0, 10), (5.0, 20)]);
+    assert_eq!(series[&key("lag")], vec![(7.0, 10)]);
+
+    let series = super::series(&[sample("rows_total", MetricKind::Counter, 1.0, 30)], &mut totals);
+    assert_eq!(series[&key("rows_total")], vec![(6.0, 30)]);
+  }
+}
@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Map, Value};
+use wick_config::config::components::MetricKind;
+
+use crate::error::Error;
+
+/// One value of a metric series.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Sample {
+  pub(crate) name: String,
+  pub(crate) kind: MetricKind,
+  pub(crate) value: f64,
+  /// The sample's own labels over the component's, sorted by name.
+  pub(crate) labels: BTreeMap<String, String>,
+  /// Milliseconds since the Unix epoch.
+  pub(crate) timestamp: i64,
+}
+
+impl Sample {
+  /// Read a sample from an object of its `name`, `value`, and optionally its `labels` and `timestamp`.
+  pub(crate) fn from_value(value: Value, kind: MetricKind, defaults: &BTreeMap<String, String>) -> Result<Self, Error> {
+    let mut sample = match value {
+      Value::Object(sample) => sample,
+      other => return Err(Error::Sample(format!("expected an object, found {}", other))),
+    };
+
+    let name = match sample.remove("name") {
+      Some(Value::String(name)) if is_metric_name(&name) => name,
+      Some(Value::String(name)) => return Err(Error::Sample(format!("'{}' is not a valid metric name", name))),
+      other => {
+        return Err(Error::Sample(format!(
+          "expected a string name, found {}",
+          other.unwrap_or_default()
+        )))
+      }
+    };
+
+    let value = match sample.remove("value") {
+      Some(Value::Number(n)) => n.as_f64().unwrap_or_default(),
+      other => {
+        return Err(Error::Sample(format!(
+          "expected a numeric value, found {}",
+          other.unwrap_or_default()
+        )))
+      }
+    };
+    if kind == MetricKind::Counter && value < 0.0 {
+      return Err(Error::Sample(format!(
+        "counter '{}' can't be incremented by {}",
+        name, value
+      )));
+    }
+
+    let mut labels = defaults.clone();
+    match sample.remove("labels") {
+      Some(Value::Object(own)) => labels.extend(read_labels(own)?),
+      Some(Value::Null) | None => {}
+      Some(other) => return Err(Error::Sample(format!("expected an object of labels, found {}", other))),
+    }
+
+    let timestamp = match sample.remove("timestamp") {
+      Some(Value::Number(n)) => n
+        .as_i64()
+        .ok_or_else(|| Error::Sample(format!("expected a timestamp in milliseconds, found {}", n)))?,
+      Some(Value::Null) | None => now(),
+      Some(other) => {
+        return Err(Error::Sample(format!(
+          "expected a timestamp in milliseconds, found {}",
+          other
+        )))
+      }
+    };
+
+    Ok(Self {
+      name,
+      kind,
+      value,
+      labels,
+      timestamp,
+    })
+  }
+
+  pub(crate) fn to_value(&self) -> Value {
+    json!({
+      "name": self.name,
+      "value": self.value,
+      "labels": self.labels,
+      "timestamp": self.timestamp,
+    })
+  }
+}
+
+fn read_labels(labels: Map<String, Value>) -> Result<BTreeMap<String, String>, Error> {
+  labels
+    .into_iter()
+    .map(|(name, value)| {
+      if !is_label_name(&name) {
+        return Err(Error::Sample(format!("'{}' is not a valid label name", name)));
+      }
+      let value = match value {
+        Value::String(s) => s,
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        other => {
+          return Err(Error::Sample(format!(
+            "label '{}' has a non-scalar value {}",
+            name, other
+          )))
+        }
+      };
+      Ok((name, value))
+    })
+    .collect()
+}
+
+/// Metric names follow Prometheus' rules, `[a-zA-Z_:][a-zA-Z0-9_:]*`.
+fn is_metric_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars
+    .next()
+    .map_or(false, |c| c.is_ascii_alphabetic() || c == '_' || c == ':')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+}
+
+/// Label names are `[a-zA-Z_][a-zA-Z0-9_]*`, and those starting with `__` are reserved.
+fn is_label_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  !name.starts_with("__")
+    && chars.next().map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn now() -> i64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| i64::try_from(d.as_millis()).unwrap_or(i64::MAX))
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  fn defaults() -> BTreeMap<String, String> {
+    BTreeMap::from([
+      ("job".to_owned(), "ingest".to_owned()),
+      ("env".to_owned(), "prod".to_owned()),
+    ])
+  }
+
+  #[test]
+  fn test_from_value() -> Result<()> {
+    let sample = Sample::from_value(
+      json!({"name": "rows_total", "value": 3, "labels": {"env": "dev", "shard": 2}, "timestamp": 1_700_000_000_000_i64}),
+      MetricKind::Counter,
+      &defaults(),
+    )?;
+    assert_eq!(sample.name, "rows_total");
+    assert_eq!(sample.value, 3.0);
+    assert_eq!(sample.timestamp, 1_700_000_000_000);
+    // a sample's own labels take precedence over the component's.
+    assert_eq!(
+      sample.labels.into_iter().collect::<Vec<_>>(),
+      vec![
+        ("env".to_owned(), "dev".to_owned()),
+        ("job".to_owned(), "ingest".to_owned()),
+        ("shard".to_owned(), "2".to_owned()),
+      ]
+    );
+
+    let sample = Sample::from_value(
+      json!({"name": "lag", "value": -1.5}),
+      MetricKind::Gauge,
+      &BTreeMap::new(),
+    )?;
+    assert_eq!(sample.value, -1.5);
+    assert!(sample.timestamp > 0);
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid() {
+    let invalid = |value: Value, kind: MetricKind| Sample::from_value(value, kind, &BTreeMap::new()).is_err();
+    assert!(invalid(json!(5), MetricKind::Gauge));
+    assert!(invalid(json!({"name": "9lives", "value": 1}), MetricKind::Gauge));
+    assert!(invalid(json!({"name": "up", "value": "1"}), MetricKind::Gauge));
+    assert!(invalid(json!({"name": "up", "value": -1}), MetricKind::Counter));
+    assert!(invalid(
+      json!({"name": "up", "value": 1, "labels": {"__name__": "down"}}),
+      MetricKind::Gauge
+    ));
+    assert!(invalid(
+      json!({"name": "up", "value": 1, "labels": {"a": [1]}}),
+      MetricKind::Gauge
+    ));
+    assert!(invalid(
+      json!({"name": "up", "value": 1, "timestamp": 1.5}),
+      MetricKind::Gauge
+    ));
+  }
+}
@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use reqwest::header::{CONTENT_ENCODING, CONTENT_TYPE, USER_AGENT};
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc;
+use tokio::time::MissedTickBehavior;
+use url::Url;
+use wick_config::config::components::MetricsProtocol;
+
+use crate::error::Error;
+use crate::remote_write::{self, Totals};
+use crate::sample::Sample;
+use crate::statsd;
+
+const STATSD_PORT: u16 = 8125;
+
+/// Where samples are sent.
+#[derive(Debug)]
+pub(crate) enum Sink {
+  RemoteWrite {
+    client: reqwest::Client,
+    url: Url,
+    totals: Totals,
+  },
+  Statsd {
+    socket: UdpSocket,
+  },
+}
+
+impl Sink {
+  pub(crate) async fn new(protocol: MetricsProtocol, url: Url) -> Result<Self, Error> {
+    match protocol {
+      MetricsProtocol::RemoteWrite => {
+        if !matches!(url.scheme(), "http" | "https") {
+          return Err(Error::Scheme(
+            "remote-write".to_owned(),
+            "http(s)".to_owned(),
+            url.to_string(),
+          ));
+        }
+        Ok(Self::RemoteWrite {
+          client: reqwest::Client::new(),
+          url,
+          totals: Totals::default(),
+        })
+      }
+      MetricsProtocol::Statsd => {
+        let host = match (url.scheme(), url.host()) {
+          ("udp", Some(host)) => format!("{}:{}", host, url.port().unwrap_or(STATSD_PORT)),
+          _ => return Err(Error::Scheme("statsd".to_owned(), "udp".to_owned(), url.to_string())),
+        };
+        let resolve_error = |e: std::io::Error| Error::Resolve(host.clone(), e.to_string());
+        let addr = tokio::net::lookup_host(&host)
+          .await
+          .map_err(resolve_error)?
+          .next()
+          .ok_or_else(|| Error::Resolve(host.clone(), "no addresses found".to_owned()))?;
+        let local = if addr.is_ipv6() { "[::]:0" } else { "0.0.0.0:0" };
+        let socket = UdpSocket::bind(local).await.map_err(resolve_error)?;
+        socket.connect(addr).await.map_err(resolve_error)?;
+        Ok(Self::Statsd { socket })
+      }
+    }
+  }
+
+  async fn send(&mut self, samples: &[Sample]) -> Result<(), Error> {
+    match self {
+      Sink::RemoteWrite { client, url, totals } => {
+        let body = remote_write::encode(samples, totals);
+        let body = snap::raw::Encoder::new()
+          .compress_vec(&body)
+          .map_err(|e| Error::Send(e.to_string()))?;
+        let response = client
+          .post(url.clone())
+          .header(CONTENT_ENCODING, "snappy")
+          .header(CONTENT_TYPE, "application/x-protobuf")
+          .header(USER_AGENT, "wick")
+          .header("X-Prometheus-Remote-Write-Version", "0.1.0")
+          .body(body)
+          .send()
+          .await
+          .map_err(|e| Error::Send(e.to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+          let text = response.text().await.unwrap_or_default();
+          return Err(Error::Send(format!("{} responded {}: {}", url, status, text.trim())));
+        }
+        Ok(())
+      }
+      Sink::Statsd { socket } => {
+        for datagram in statsd::datagrams(samples) {
+          socket
+            .send(datagram.as_bytes())
+            .await
+            .map_err(|e| Error::Send(e.to_string()))?;
+        }
+        Ok(())
+      }
+    }
+  }
+
+  /// Send the samples from `queue` in batches of up to `batch_size`, and whatever has been collected every `interval`,
+  /// until every sender of the queue has been dropped. Samples that can't be sent are logged and dropped.
+  pub(crate) async fn run(mut self, mut queue: mpsc::Receiver<Sample>, batch_size: usize, interval: Duration) {
+    let mut batch = Vec::with_capacity(batch_size);
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+      let closed = tokio::select! {
+        sample = queue.recv() => match sample {
+          Some(sample) => {
+            batch.push(sample);
+            if batch.len() < batch_size {
+              continue;
+            }
+            false
+          }
+          None => true,
+        },
+        _ = ticker.tick() => false,
+      };
+      if !batch.is_empty() {
+        if let Err(e) = self.send(&batch).await {
+          error!(error = %e, samples = batch.len(), "metrics: dropped samples");
+        }
+        batch.clear();
+      }
+      if closed {
+        break;
+      }
+    }
+  }
+}
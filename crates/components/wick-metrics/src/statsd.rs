@@ -0,0 +1,101 @@
+use wick_config::config::components::MetricKind;
+
+use crate::sample::Sample;
+
+/// The most bytes to put in one datagram, so it fits in a packet on common networks without fragmenting.
+const MAX_DATAGRAM: usize = 1432;
+
+/// Format samples as statsd lines and pack them into as few datagrams as fit.
+pub(crate) fn datagrams(samples: &[Sample]) -> Vec<String> {
+  let mut datagrams = Vec::new();
+  let mut current = String::new();
+  for line in samples.iter().flat_map(lines) {
+    if !current.is_empty() && current.len() + 1 + line.len() > MAX_DATAGRAM {
+      datagrams.push(std::mem::take(&mut current));
+    }
+    if !current.is_empty() {
+      current.push('\n');
+    }
+    current.push_str(&line);
+  }
+  if !current.is_empty() {
+    datagrams.push(current);
+  }
+  datagrams
+}
+
+/// The lines of one sample, e.g. `rows_total:3|c|#job:ingest`. Labels are sent as DogStatsD tags.
+fn lines(sample: &Sample) -> Vec<String> {
+  let name = sample.name.replace(':', "_");
+  let kind = match sample.kind {
+    MetricKind::Gauge => "g",
+    MetricKind::Counter => "c",
+    MetricKind::Timing => "ms",
+  };
+  let tags = if sample.labels.is_empty() {
+    String::new()
+  } else {
+    let tags: Vec<_> = sample
+      .labels
+      .iter()
+      .map(|(name, value)| format!("{}:{}", name, value.replace([',', '|', '\n'], "_")))
+      .collect();
+    format!("|#{}", tags.join(","))
+  };
+  let line = |value: f64| format!("{}:{}|{}{}", name, value, kind, tags);
+
+  // a signed gauge value changes the gauge by that much, so a negative gauge is set by zeroing it first.
+  if sample.kind == MetricKind::Gauge && sample.value < 0.0 {
+    vec![line(0.0), line(sample.value)]
+  } else {
+    vec![line(sample.value)]
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::BTreeMap;
+
+  use super::*;
+
+  fn sample(name: &str, kind: MetricKind, value: f64, labels: &[(&str, &str)]) -> Sample {
+    Sample {
+      name: name.to_owned(),
+      kind,
+      value,
+      labels: labels
+        .iter()
+        .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+        .collect::<BTreeMap<_, _>>(),
+      timestamp: 0,
+    }
+  }
+
+  #[test]
+  fn test_lines() {
+    assert_eq!(
+      datagrams(&[
+        sample(
+          "rows_total",
+          MetricKind::Counter,
+          3.0,
+          &[("job", "ingest"), ("route", "a,b")]
+        ),
+        sample("job:lag", MetricKind::Gauge, -1.5, &[]),
+        sample("latency", MetricKind::Timing, 12.25, &[]),
+      ]),
+      vec!["rows_total:3|c|#job:ingest,route:a_b\njob_lag:0|g\njob_lag:-1.5|g\nlatency:12.25|ms".to_owned()]
+    );
+  }
+
+  #[test]
+  fn test_datagrams() {
+    let samples: Vec<_> = (0..200)
+      .map(|i| sample(&format!("metric_{}", i), MetricKind::Gauge, 1.0, &[]))
+      .collect();
+    let datagrams = datagrams(&samples);
+    assert!(datagrams.len() > 1);
+    assert!(datagrams.iter().all(|d| d.len() <= MAX_DATAGRAM));
+    assert_eq!(datagrams.iter().map(|d| d.lines().count()).sum::<usize>(), 200);
+  }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, ObjectStoreComponentConfig, OperationConfig};
+use wick_config::config::{Metadata, UrlResource};
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+use crate::sign::Credentials;
+use crate::store::Store;
+
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// A component whose operations work on the objects of a bucket in an S3-compatible store.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ObjectStoreComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl ObjectStoreComponent {
+  /// Create a component that signs its requests with `credentials`, read by the host from the component's secret
+  /// resources.
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: ObjectStoreComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+    credentials: Credentials,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/object-store");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let addr: UrlResource = resolver(config.resource())
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_url())?;
+    let url = addr
+      .url()
+      .value()
+      .cloned()
+      .ok_or_else(|| Error::NoUrl(addr.to_string()))?;
+    let region = config
+      .region()
+      .map_or_else(|| DEFAULT_REGION.to_owned(), ToOwned::to_owned);
+    let store = Arc::new(Store::new(
+      url,
+      config.bucket(),
+      region,
+      config.path_style(),
+      credentials,
+    )?);
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| {
+        let operation = Operation::new(store.clone(), op)?;
+        Ok((op.name().to_owned(), Arc::new(operation)))
+      })
+      .collect::<Result<_, Error>>()?;
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for ObjectStoreComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::Value;
+  use wick_config::config::components::{
+    ObjectStoreAction,
+    ObjectStoreComponentConfigBuilder,
+    ObjectStoreOperationDefinitionBuilder,
+  };
+  use wick_config::config::{AppConfiguration, ResourceDefinition, Secret};
+  use wick_packet::{Entity, Packet};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<ObjectStoreComponent>();
+  }
+
+  fn component(path_style: bool) -> Result<ObjectStoreComponent> {
+    let mut app_config = AppConfiguration::default();
+    app_config.add_resource(
+      "store",
+      ResourceDefinition::Url("http://localhost:9000".to_owned().try_into()?),
+    );
+    let config = ObjectStoreComponentConfigBuilder::default()
+      .resource("store")
+      .bucket("photos")
+      .access_key_id("access_key_id")
+      .secret_access_key("secret_access_key")
+      .path_style(path_style)
+      .operations(vec![ObjectStoreOperationDefinitionBuilder::default()
+        .name("share")
+        .action(ObjectStoreAction::Presign)
+        .expires(60_u32)
+        .build()?])
+      .build()?;
+    let credentials = Credentials::new(Secret::new("AKIDEXAMPLE"), Secret::new("secret"), None);
+    let component = ObjectStoreComponent::new(config, None, None, &app_config.resolver(), credentials)?;
+
+    Ok(component)
+  }
+
+  async fn presign(component: &ObjectStoreComponent, key: &str) -> Result<String> {
+    let packets = vec![Packet::encode("key", key), Packet::done("key")];
+    let invocation = Invocation::test(file!(), Entity::local("share"), packets, None)?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let url: Value = packets[0].as_ref().unwrap().decode_value()?;
+    Ok(url.as_str().unwrap().to_owned())
+  }
+
+  #[tokio::test]
+  async fn test_presign() -> Result<()> {
+    let url = presign(&component(true)?, "2023/summer trip.jpg").await?;
+    assert!(
+      url.starts_with("http://localhost:9000/photos/2023/summer%20trip.jpg?X-Amz-Algorithm=AWS4-HMAC-SHA256&"),
+      "unexpected url: {}",
+      url
+    );
+    assert!(url.contains("&X-Amz-Expires=60&"), "unexpected url: {}", url);
+    assert!(url.contains("&X-Amz-Signature="), "unexpected url: {}", url);
+
+    let url = presign(&component(false)?, "a.jpg").await?;
+    assert!(
+      url.starts_with("http://photos.localhost:9000/a.jpg?"),
+      "buckets should be subdomains unless addressed by path, got {}",
+      url
+    );
+    Ok(())
+  }
+}
@@ -0,0 +1,36 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Resource {0} has no URL")]
+  NoUrl(String),
+
+  #[error("Object stores are reached over http(s), found '{0}'")]
+  Scheme(String),
+
+  #[error("Could not address bucket {0} as a subdomain of the endpoint: {1}")]
+  Bucket(String, String),
+
+  #[error("Could not reach the object store: {0}")]
+  Request(String),
+
+  #[error("The object store responded to {0} with {1}: {2}")]
+  Status(String, u16, String),
+
+  #[error("The object store sent a response without {0}")]
+  Response(String),
+
+  #[error("Presigned URLs can only be made for GET, PUT, and DELETE requests, found {0}")]
+  PresignMethod(String),
+
+  #[error("Presigned URLs expire after at most {0} seconds, found {1}")]
+  Expires(u32, u32),
+
+  #[error("No key was given for the object to store")]
+  NoKey,
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
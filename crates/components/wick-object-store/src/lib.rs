@@ -0,0 +1,18 @@
+// !!START_LINTS
+// Wick lints
+// Do not change anything between the START_LINTS and END_LINTS line.
+// Add exceptions here
+#![allow(missing_docs)]
+mod component;
+mod error;
+mod operation;
+mod sign;
+mod store;
+mod xml;
+
+#[macro_use]
+extern crate tracing;
+
+pub use component::ObjectStoreComponent;
+pub use error::Error;
+pub use sign::Credentials;
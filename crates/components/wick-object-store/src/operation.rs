@@ -0,0 +1,234 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+use wick_config::config::components::{ObjectStoreAction, ObjectStoreOperationDefinition};
+use wick_config::config::HttpMethod;
+use wick_packet::{Base64Bytes, Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::error::Error;
+use crate::store::{Store, Upload};
+
+const DEFAULT_PAGE_SIZE: u32 = 1000;
+const DEFAULT_EXPIRES: u32 = 3600;
+/// The longest S3 lets presigned URLs last, a week.
+const MAX_EXPIRES: u32 = 604_800;
+
+/// An operation on the objects of a bucket.
+#[derive(Debug)]
+pub(crate) enum Operation {
+  Get(Arc<Store>),
+  Put(Arc<Store>),
+  List {
+    store: Arc<Store>,
+    page_size: u32,
+  },
+  Presign {
+    store: Arc<Store>,
+    method: &'static str,
+    expires: u32,
+  },
+}
+
+impl Operation {
+  pub(crate) fn new(store: Arc<Store>, config: &ObjectStoreOperationDefinition) -> Result<Self, Error> {
+    Ok(match config.action() {
+      ObjectStoreAction::Get => Self::Get(store),
+      ObjectStoreAction::Put => Self::Put(store),
+      ObjectStoreAction::List => Self::List {
+        store,
+        page_size: config.page_size().unwrap_or(DEFAULT_PAGE_SIZE).max(1),
+      },
+      ObjectStoreAction::Presign => {
+        let method = match config.method() {
+          None | Some(HttpMethod::Get) => "GET",
+          Some(HttpMethod::Put) => "PUT",
+          Some(HttpMethod::Delete) => "DELETE",
+          Some(HttpMethod::Post) => return Err(Error::PresignMethod("POST".to_owned())),
+        };
+        let expires = config.expires().unwrap_or(DEFAULT_EXPIRES);
+        if expires > MAX_EXPIRES {
+          return Err(Error::Expires(MAX_EXPIRES, expires));
+        }
+        Self::Presign { store, method, expires }
+      }
+    })
+  }
+
+  /// Process the invocation's stream, sending what the operation makes of it on the `output` port.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      let result = match self.as_ref() {
+        Operation::Get(store) => get(store, &mut stream, &tx).await,
+        Operation::Put(store) => put(store, &mut stream, &tx).await,
+        Operation::List { store, page_size } => list(store, *page_size, &mut stream, &tx).await,
+        Operation::Presign { store, method, expires } => presign(store, method, *expires, &mut stream, &tx).await,
+      };
+      match result {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("output"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "object-store"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+}
+
+/// Send each object, as the chunks it's downloaded in, between brackets.
+async fn get(store: &Store, stream: &mut PacketStream, tx: &PacketSender) -> Result<(), Error> {
+  // each object is bracketed, brackets around the keys would be ambiguous.
+  while let Some(key) = next_value(stream, "key", tx, false).await? {
+    let mut body = match store.get(&key).await {
+      Ok(body) => body,
+      Err(e) => {
+        let _ = tx.send(Packet::err("output", e.to_string()));
+        continue;
+      }
+    };
+    let _ = tx.send(Packet::open_bracket("output"));
+    while let Some(chunk) = body.next().await {
+      match chunk {
+        Ok(bytes) => {
+          let _ = tx.send(Packet::encode("output", Base64Bytes::new(bytes)));
+        }
+        Err(e) => {
+          let _ = tx.send(Packet::err("output", e.to_string()));
+          break;
+        }
+      }
+    }
+    let _ = tx.send(Packet::close_bracket("output"));
+  }
+  Ok(())
+}
+
+/// Store the chunks from the `body` port as the object named on the `key` port.
+async fn put(store: &Store, stream: &mut PacketStream, tx: &PacketSender) -> Result<(), Error> {
+  let mut upload = Upload::new(store);
+  let stored = match read_body(&mut upload, stream).await {
+    Ok(()) => upload.finish().await,
+    Err(e) => Err(e),
+  };
+  match stored {
+    Ok(stored) => {
+      let _ = tx.send(Packet::encode("output", stored));
+    }
+    Err(e) => {
+      upload.abort().await;
+      let _ = tx.send(Packet::err("output", e.to_string()));
+    }
+  }
+  Ok(())
+}
+
+async fn read_body(upload: &mut Upload<'_>, stream: &mut PacketStream) -> Result<(), Error> {
+  let (mut key_done, mut body_done) = (false, false);
+  while !(key_done && body_done) {
+    let Some(next) = stream.next().await else {
+      break;
+    };
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    let port = packet.port().to_owned();
+    if packet.is_error() {
+      return Err(Error::Input(packet.unwrap_err().msg().to_owned()));
+    }
+    match port.as_str() {
+      "key" if packet.is_done() => key_done = true,
+      "body" if packet.is_done() => body_done = true,
+      "key" if packet.has_data() => {
+        let key: String = packet.decode().map_err(|e| Error::Input(e.to_string()))?;
+        upload.set_key(key).await?;
+      }
+      "body" if packet.has_data() => {
+        let chunk: Base64Bytes = packet.decode().map_err(|e| Error::Input(e.to_string()))?;
+        upload.write(&chunk).await?;
+      }
+      _ => {}
+    }
+  }
+  Ok(())
+}
+
+/// Send every object under each prefix, a page at a time.
+async fn list(store: &Store, page_size: u32, stream: &mut PacketStream, tx: &PacketSender) -> Result<(), Error> {
+  while let Some(prefix) = next_value(stream, "prefix", tx, true).await? {
+    let mut token = None;
+    loop {
+      match store.list(&prefix, page_size, token.as_deref()).await {
+        Ok(page) => {
+          for object in page.objects {
+            let _ = tx.send(Packet::encode("output", object));
+          }
+          match page.next {
+            Some(next) => token = Some(next),
+            None => break,
+          }
+        }
+        Err(e) => {
+          let _ = tx.send(Packet::err("output", e.to_string()));
+          break;
+        }
+      }
+    }
+  }
+  Ok(())
+}
+
+/// Send a presigned URL for each key.
+async fn presign(
+  store: &Store,
+  method: &str,
+  expires: u32,
+  stream: &mut PacketStream,
+  tx: &PacketSender,
+) -> Result<(), Error> {
+  while let Some(key) = next_value(stream, "key", tx, true).await? {
+    let _ = tx.send(Packet::encode(
+      "output",
+      store.presign(method, &key, expires).to_string(),
+    ));
+  }
+  Ok(())
+}
+
+/// The next string on `port`. Errors on it, and brackets when `brackets` is set, are passed on to the `output` port.
+async fn next_value(
+  stream: &mut PacketStream,
+  port: &str,
+  tx: &PacketSender,
+  brackets: bool,
+) -> Result<Option<String>, Error> {
+  while let Some(next) = stream.next().await {
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    if packet.port() != port {
+      continue;
+    }
+    if packet.is_done() {
+      break;
+    }
+    if packet.is_error() {
+      let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+    } else if packet.is_bracket() {
+      if brackets {
+        let _ = tx.send(packet.to_port("output"));
+      }
+    } else if packet.has_data() {
+      match packet.decode::<String>() {
+        Ok(value) => return Ok(Some(value)),
+        Err(e) => {
+          let _ = tx.send(Packet::err("output", e.to_string()));
+        }
+      }
+    }
+  }
+  Ok(None)
+}
@@ -0,0 +1,302 @@
+use bytes::Bytes;
+use chrono::Utc;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use reqwest::header::ETAG;
+use reqwest::{Method, Response};
+use serde_json::{json, Value};
+use url::Url;
+
+use crate::error::Error;
+use crate::sign::{self, Credentials, Signer};
+use crate::xml;
+
+/// The size large objects are uploaded in parts of. S3 needs every part but the last to be at least 5 MiB.
+pub(crate) const PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// A bucket in an S3-compatible store.
+#[derive(Debug)]
+pub(crate) struct Store {
+  client: reqwest::Client,
+  /// The endpoint, with the bucket as a subdomain unless it's addressed by path.
+  base: Url,
+  /// What object keys are appended to to make their path.
+  prefix: String,
+  signer: Signer,
+}
+
+/// A page of the objects under a prefix.
+#[derive(Debug)]
+pub(crate) struct Page {
+  pub(crate) objects: Vec<Value>,
+  /// The token to get the next page with, if there is one.
+  pub(crate) next: Option<String>,
+}
+
+impl Store {
+  pub(crate) fn new(
+    endpoint: Url,
+    bucket: &str,
+    region: String,
+    path_style: bool,
+    credentials: Credentials,
+  ) -> Result<Self, Error> {
+    if !matches!(endpoint.scheme(), "http" | "https") {
+      return Err(Error::Scheme(endpoint.to_string()));
+    }
+    let root = endpoint.path().trim_end_matches('/').to_owned();
+    let mut base = endpoint;
+    let prefix = if path_style {
+      format!("{}/{}/", root, sign::path(bucket))
+    } else {
+      let host = format!("{}.{}", bucket, base.host_str().unwrap_or_default());
+      base
+        .set_host(Some(&host))
+        .map_err(|e| Error::Bucket(bucket.to_owned(), e.to_string()))?;
+      format!("{}/", root)
+    };
+    Ok(Self {
+      client: reqwest::Client::new(),
+      base,
+      prefix,
+      signer: Signer::new(credentials, region),
+    })
+  }
+
+  /// The URL of the object `key`, or of the bucket when `key` is empty, with the query `params`.
+  fn url(&self, key: &str, params: &[(&str, String)]) -> Url {
+    let mut url = self.base.clone();
+    url.set_path(&format!("{}{}", self.prefix, sign::path(key)));
+    url.set_query((!params.is_empty()).then(|| sign::query(params)).as_deref());
+    url
+  }
+
+  /// Send a signed request, turning the error responses of the store into errors.
+  async fn send(&self, method: Method, url: Url, body: Vec<u8>) -> Result<Response, Error> {
+    let described = format!("{} {}", method, url.path());
+    let mut request = self.client.request(method.clone(), url.clone());
+    for (name, value) in self.signer.headers(method.as_str(), &url, &body, Utc::now()) {
+      request = request.header(name, value);
+    }
+    let response = request
+      .body(body)
+      .send()
+      .await
+      .map_err(|e| Error::Request(e.to_string()))?;
+    if response.status().is_success() {
+      return Ok(response);
+    }
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let reason = xml::text(&body, "Message")
+      .or_else(|| xml::text(&body, "Code"))
+      .unwrap_or_else(|| status.canonical_reason().unwrap_or_default().to_owned());
+    Err(Error::Status(described, status.as_u16(), reason))
+  }
+
+  /// Stream the bytes of the object `key` as they're downloaded.
+  pub(crate) async fn get(&self, key: &str) -> Result<BoxStream<'static, Result<Bytes, Error>>, Error> {
+    let response = self.send(Method::GET, self.url(key, &[]), Vec::new()).await?;
+    Ok(
+      response
+        .bytes_stream()
+        .map(|chunk| chunk.map_err(|e| Error::Request(e.to_string())))
+        .boxed(),
+    )
+  }
+
+  /// A page of at most `page_size` objects under `prefix`, following the page `token` came with.
+  pub(crate) async fn list(&self, prefix: &str, page_size: u32, token: Option<&str>) -> Result<Page, Error> {
+    let mut params = vec![
+      ("list-type", "2".to_owned()),
+      ("max-keys", page_size.to_string()),
+      ("prefix", prefix.to_owned()),
+    ];
+    if let Some(token) = token {
+      params.push(("continuation-token", token.to_owned()));
+    }
+    let body = text(self.send(Method::GET, self.url("", &params), Vec::new()).await?).await?;
+
+    let objects = xml::elements(&body, "Contents")
+      .into_iter()
+      .map(|object| {
+        json!({
+          "key": xml::text(object, "Key").unwrap_or_default(),
+          "size": xml::text(object, "Size").and_then(|size| size.parse::<u64>().ok()).unwrap_or_default(),
+          "etag": xml::text(object, "ETag").map(|etag| etag.trim_matches('"').to_owned()).unwrap_or_default(),
+          "last_modified": xml::text(object, "LastModified").unwrap_or_default(),
+        })
+      })
+      .collect();
+    let truncated = xml::text(&body, "IsTruncated").map_or(false, |truncated| truncated == "true");
+    let next = xml::text(&body, "NextContinuationToken").filter(|_| truncated);
+    Ok(Page { objects, next })
+  }
+
+  /// Store `body` as the object `key` in one request, returning its ETag.
+  pub(crate) async fn put(&self, key: &str, body: Vec<u8>) -> Result<String, Error> {
+    etag(&self.send(Method::PUT, self.url(key, &[]), body).await?)
+  }
+
+  /// Start a multipart upload of the object `key`, returning the upload's ID.
+  pub(crate) async fn create_upload(&self, key: &str) -> Result<String, Error> {
+    let url = self.url(key, &[("uploads", String::new())]);
+    let body = text(self.send(Method::POST, url, Vec::new()).await?).await?;
+    xml::text(&body, "UploadId").ok_or_else(|| Error::Response("an upload ID".to_owned()))
+  }
+
+  /// Upload part `number`, counting from 1, of a multipart upload, returning the part's ETag.
+  pub(crate) async fn upload_part(
+    &self,
+    key: &str,
+    upload: &str,
+    number: usize,
+    body: Vec<u8>,
+  ) -> Result<String, Error> {
+    let url = self.url(
+      key,
+      &[("partNumber", number.to_string()), ("uploadId", upload.to_owned())],
+    );
+    etag(&self.send(Method::PUT, url, body).await?)
+  }
+
+  /// Assemble the uploaded parts, with the ETags `parts`, into the object, returning its ETag.
+  pub(crate) async fn complete_upload(&self, key: &str, upload: &str, parts: &[String]) -> Result<String, Error> {
+    let parts: String = parts
+      .iter()
+      .enumerate()
+      .map(|(i, etag)| {
+        format!(
+          "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+          i + 1,
+          xml::escape(etag)
+        )
+      })
+      .collect();
+    let body = format!("<CompleteMultipartUpload>{}</CompleteMultipartUpload>", parts);
+    let url = self.url(key, &[("uploadId", upload.to_owned())]);
+    let described = format!("POST {}", url.path());
+    let body = text(self.send(Method::POST, url, body.into_bytes()).await?).await?;
+    // completing an upload can fail after the store has started responding with a success.
+    if body.contains("<Error>") {
+      let reason = xml::text(&body, "Message").unwrap_or_default();
+      return Err(Error::Status(described, 200, reason));
+    }
+    xml::text(&body, "ETag")
+      .map(|etag| etag.trim_matches('"').to_owned())
+      .ok_or_else(|| Error::Response("an ETag".to_owned()))
+  }
+
+  /// Abandon a multipart upload, deleting the parts uploaded so far.
+  pub(crate) async fn abort_upload(&self, key: &str, upload: &str) -> Result<(), Error> {
+    let url = self.url(key, &[("uploadId", upload.to_owned())]);
+    self.send(Method::DELETE, url, Vec::new()).await?;
+    Ok(())
+  }
+
+  /// A URL that lets anyone with it make a `method` request for the object `key` for `expires` seconds.
+  pub(crate) fn presign(&self, method: &str, key: &str, expires: u32) -> Url {
+    self.signer.presign(method, &self.url(key, &[]), expires, Utc::now())
+  }
+}
+
+async fn text(response: Response) -> Result<String, Error> {
+  response.text().await.map_err(|e| Error::Request(e.to_string()))
+}
+
+fn etag(response: &Response) -> Result<String, Error> {
+  response
+    .headers()
+    .get(ETAG)
+    .and_then(|etag| etag.to_str().ok())
+    .map(|etag| etag.trim_matches('"').to_owned())
+    .ok_or_else(|| Error::Response("an ETag".to_owned()))
+}
+
+/// An object being stored from a stream of chunks, in one request or, once it outgrows a part, in a multipart upload.
+#[derive(Debug)]
+pub(crate) struct Upload<'a> {
+  store: &'a Store,
+  key: Option<String>,
+  buffer: Vec<u8>,
+  /// The ID of the multipart upload, once one is started.
+  id: Option<String>,
+  /// The ETags of the parts uploaded so far.
+  parts: Vec<String>,
+  size: u64,
+}
+
+impl<'a> Upload<'a> {
+  pub(crate) const fn new(store: &'a Store) -> Self {
+    Self {
+      store,
+      key: None,
+      buffer: Vec::new(),
+      id: None,
+      parts: Vec::new(),
+      size: 0,
+    }
+  }
+
+  pub(crate) async fn set_key(&mut self, key: String) -> Result<(), Error> {
+    self.key = Some(key);
+    self.flush().await
+  }
+
+  pub(crate) async fn write(&mut self, chunk: &[u8]) -> Result<(), Error> {
+    self.buffer.extend_from_slice(chunk);
+    self.size += chunk.len() as u64;
+    self.flush().await
+  }
+
+  /// Upload what's buffered as a part once there's enough of it and the key is known.
+  async fn flush(&mut self) -> Result<(), Error> {
+    let Some(key) = &self.key else {
+      return Ok(());
+    };
+    if self.buffer.len() < PART_SIZE {
+      return Ok(());
+    }
+    let id = match &self.id {
+      Some(id) => id.clone(),
+      None => {
+        let id = self.store.create_upload(key).await?;
+        self.id = Some(id.clone());
+        id
+      }
+    };
+    let part = std::mem::take(&mut self.buffer);
+    let etag = self.store.upload_part(key, &id, self.parts.len() + 1, part).await?;
+    self.parts.push(etag);
+    Ok(())
+  }
+
+  /// Store what's left, returning the stored object's `key`, `etag`, and `size`.
+  pub(crate) async fn finish(&mut self) -> Result<Value, Error> {
+    let key = self.key.clone().ok_or(Error::NoKey)?;
+    let buffer = std::mem::take(&mut self.buffer);
+    let etag = match self.id.clone() {
+      None => self.store.put(&key, buffer).await?,
+      Some(id) => {
+        if !buffer.is_empty() {
+          let etag = self.store.upload_part(&key, &id, self.parts.len() + 1, buffer).await?;
+          self.parts.push(etag);
+        }
+        let etag = self.store.complete_upload(&key, &id, &self.parts).await?;
+        self.id = None;
+        etag
+      }
+    };
+    Ok(json!({ "key": key, "etag": etag, "size": self.size }))
+  }
+
+  /// Abandon the multipart upload, if one was started, so its parts don't linger in the store.
+  pub(crate) async fn abort(&mut self) {
+    if let (Some(key), Some(id)) = (&self.key, self.id.take()) {
+      if let Err(error) = self.store.abort_upload(key, &id).await {
+        warn!(%error, "could not abort multipart upload");
+      }
+    }
+  }
+}
@@ -0,0 +1,67 @@
+//! Just enough XML to read S3's responses, which are flat and predictable enough that a parser isn't worth the
+//! dependency.
+
+/// The contents of every `<name>` element in `xml`, in order. Elements named `name` mustn't nest.
+pub(crate) fn elements<'a>(xml: &'a str, name: &str) -> Vec<&'a str> {
+  let open = format!("<{}>", name);
+  let close = format!("</{}>", name);
+  let mut found = Vec::new();
+  let mut rest = xml;
+  while let Some(start) = rest.find(&open) {
+    let after = &rest[start + open.len()..];
+    let Some(end) = after.find(&close) else {
+      break;
+    };
+    found.push(&after[..end]);
+    rest = &after[end + close.len()..];
+  }
+  found
+}
+
+/// The text of the first `<name>` element in `xml`, unescaped.
+pub(crate) fn text(xml: &str, name: &str) -> Option<String> {
+  elements(xml, name).first().map(|text| unescape(text))
+}
+
+/// `text` with XML's predefined entities replaced by the characters they stand for.
+pub(crate) fn unescape(text: &str) -> String {
+  text
+    .replace("&quot;", "\"")
+    .replace("&apos;", "'")
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&#34;", "\"")
+    .replace("&#39;", "'")
+    .replace("&amp;", "&")
+}
+
+/// `text` with the characters XML reserves escaped.
+pub(crate) fn escape(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_elements() {
+    let xml = "<ListBucketResult><KeyCount>2</KeyCount><Contents><Key>a&amp;b</Key><ETag>&quot;1&quot;</ETag>\
+               </Contents><Contents><Key>c</Key></Contents></ListBucketResult>";
+    let contents = elements(xml, "Contents");
+    assert_eq!(contents.len(), 2);
+    assert_eq!(text(contents[0], "Key").as_deref(), Some("a&b"));
+    assert_eq!(text(contents[0], "ETag").as_deref(), Some("\"1\""));
+    assert_eq!(text(xml, "KeyCount").as_deref(), Some("2"));
+    assert_eq!(text(xml, "NextContinuationToken"), None);
+  }
+
+  #[test]
+  fn test_escape() {
+    assert_eq!(unescape(&escape("<\"a\" & 'b'>")), "<\"a\" & 'b'>");
+  }
+}
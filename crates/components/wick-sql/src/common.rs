@@ -135,13 +135,11 @@ mod test {
 #[async_trait::async_trait]
 pub(crate) trait DatabaseProvider {
   fn get_statement<'a>(&'a self, id: &'a str) -> Option<&'a str>;
-  async fn get_connection<'a, 'b>(&'a self) -> Result<Connection<'b>>
-  where
-    'a: 'b;
+  async fn get_connection(&self) -> Result<Connection<'static>>;
 }
 
 #[async_trait::async_trait]
-pub(crate) trait ClientConnection: Send + Sync {
+pub(crate) trait ClientConnection: Send {
   async fn query<'a, 'b>(
     &'a mut self,
     stmt: &'a str,
@@ -151,17 +149,30 @@ pub(crate) trait ClientConnection: Send + Sync {
     'a: 'b;
 
   async fn exec(&mut self, stmt: String, bound_args: Vec<ConvertedType>) -> Result<u64>;
-  async fn finish(&mut self, behavior: ErrorBehavior) -> Result<()>;
-  async fn handle_error(&mut self, e: Error, behavior: ErrorBehavior) -> Result<()>;
-  async fn start(&mut self, behavior: ErrorBehavior) -> Result<()>;
+  async fn run_script(&mut self, sql: &str) -> Result<()>;
+  async fn begin(&mut self) -> Result<()>;
+  async fn commit(&mut self) -> Result<()>;
+  async fn rollback(&mut self) -> Result<()>;
 }
 
-#[derive()]
-pub(crate) struct Connection<'a>(Box<dyn ClientConnection + Sync + Send + 'a>);
+/// What opened the transaction a [Connection] is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TxScope {
+  /// A single operation call, to honor its `on_error` behavior.
+  Operation,
+  /// An explicit [Connection::begin], e.g. by a `begin` operation for every operation called in the same flow
+  /// transaction.
+  Explicit,
+}
+
+pub(crate) struct Connection<'a> {
+  conn: Box<dyn ClientConnection + Send + 'a>,
+  tx: Option<TxScope>,
+}
 
 impl<'conn> Connection<'conn> {
-  pub(crate) fn new(conn: Box<dyn ClientConnection + Sync + Send + 'conn>) -> Self {
-    Self(conn)
+  pub(crate) fn new(conn: Box<dyn ClientConnection + Send + 'conn>) -> Self {
+    Self { conn, tx: None }
   }
 
   pub(crate) async fn query<'a, 'b>(
@@ -172,25 +183,100 @@ impl<'conn> Connection<'conn> {
   where
     'a: 'b,
   {
-    let stream = self.0.query(stmt, bound_args).await?;
+    let stream = self.conn.query(stmt, bound_args).await?;
 
     Ok(stream)
   }
   pub(crate) async fn exec(&mut self, stmt: String, bound_args: Vec<ConvertedType>) -> Result<u64> {
-    self.0.exec(stmt, bound_args).await
+    self.conn.exec(stmt, bound_args).await
+  }
+
+  /// Execute `stmt` once for each set of arguments, all or none of them taking effect.
+  pub(crate) async fn exec_batch(&mut self, stmt: &str, batch: Vec<Vec<ConvertedType>>) -> Result<u64> {
+    let owned = self.tx.is_none();
+    if owned {
+      self.conn.begin().await?;
+    }
+    let mut rows = 0;
+    for bound_args in batch {
+      match self.conn.exec(stmt.to_owned(), bound_args).await {
+        Ok(num) => rows += num,
+        Err(e) => {
+          if owned {
+            self.conn.rollback().await?;
+          }
+          return Err(e);
+        }
+      }
+    }
+    if owned {
+      self.conn.commit().await?;
+    }
+    Ok(rows)
+  }
+
+  pub(crate) const fn in_transaction(&self) -> bool {
+    self.tx.is_some()
+  }
+
+  /// Run one or more SQL statements that take no arguments.
+  pub(crate) async fn run_script(&mut self, sql: &str) -> Result<()> {
+    self.conn.run_script(sql).await
+  }
+
+  /// Open a transaction that lasts until it's committed or rolled back.
+  pub(crate) async fn begin(&mut self) -> Result<()> {
+    if self.tx.is_some() {
+      return Err(Error::TxStart);
+    }
+    self.conn.begin().await?;
+    self.tx = Some(TxScope::Explicit);
+    Ok(())
+  }
+
+  pub(crate) async fn commit(&mut self) -> Result<()> {
+    if self.tx.take().is_some() {
+      self.conn.commit().await?;
+    }
+    Ok(())
+  }
+
+  pub(crate) async fn rollback(&mut self) -> Result<()> {
+    if self.tx.take().is_some() {
+      self.conn.rollback().await?;
+    }
+    Ok(())
   }
 
   pub(crate) async fn handle_error(&mut self, e: Error, behavior: ErrorBehavior) -> Result<()> {
-    self.0.handle_error(e, behavior).await
+    if self.tx.is_none() {
+      return Ok(());
+    }
+    match behavior {
+      ErrorBehavior::Commit => {
+        error!(error=%e, on_error=?behavior, "error in sql operation, committing transaction");
+        self.commit().await
+      }
+      ErrorBehavior::Rollback => {
+        error!(error=%e, on_error=?behavior, "error in sql operation, rolling back transaction");
+        self.rollback().await
+      }
+      ErrorBehavior::Ignore => Ok(()),
+    }
   }
 
   pub(crate) async fn start(&mut self, behavior: ErrorBehavior) -> Result<()> {
-    self.0.start(behavior).await
+    if self.tx.is_none() && matches!(behavior, ErrorBehavior::Commit | ErrorBehavior::Rollback) {
+      self.conn.begin().await?;
+      self.tx = Some(TxScope::Operation);
+    }
+    Ok(())
   }
 
-  #[allow(clippy::unused_async)]
   pub(crate) async fn finish(&mut self) -> Result<()> {
-    // todo
+    if self.tx == Some(TxScope::Operation) {
+      self.commit().await?;
+    }
     Ok(())
   }
 }
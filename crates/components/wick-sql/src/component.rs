@@ -1,20 +1,33 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Weak};
 use std::time::{Duration, SystemTime};
 
 use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
 use futures::StreamExt;
 use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 use regex::{Captures, Regex};
+use serde_json::Value;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
 use tracing::Span;
 use url::Url;
-use wick_config::config::components::{ComponentConfig, OperationConfig, SqlComponentConfig, SqlOperationDefinition};
+use uuid::Uuid;
+use wick_config::config::components::{
+  ComponentConfig,
+  OperationConfig,
+  SqlComponentConfig,
+  SqlMigration,
+  SqlOperationDefinition,
+  SqlTransaction,
+};
 use wick_config::config::{ErrorBehavior, Metadata};
 use wick_config::Resolver;
 use wick_interface_types::{ComponentSignature, Field, OperationSignatures, Type};
 use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream, RuntimeConfig};
 
+use crate::common::sql_wrapper::ConvertedType;
 use crate::common::{Connection, DatabaseProvider};
 use crate::{common, Error};
 
@@ -37,23 +50,21 @@ impl Client {
     _root_config: Option<RuntimeConfig>, // TODO use this
     resolver: &Resolver,
   ) -> Result<Self, Error> {
-    let client: Arc<dyn DatabaseProvider + Send + Sync> = match url.scheme() {
-      "mssql" => {
-        normalize_operations(config.operations_mut(), DbKind::Mssql);
-        Arc::new(crate::mssql_tiberius::AzureSqlComponent::new(config.clone(), resolver).await?)
-      }
-      "postgres" => {
-        normalize_operations(config.operations_mut(), DbKind::Postgres);
-        Arc::new(crate::sqlx::SqlXComponent::new(config.clone(), resolver).await?)
-      }
-      "file" | "sqlite" => {
-        normalize_operations(config.operations_mut(), DbKind::Sqlite);
-        Arc::new(crate::sqlx::SqlXComponent::new(config.clone(), resolver).await?)
-      }
+    let kind = match url.scheme() {
+      "mssql" => DbKind::Mssql,
+      "postgres" => DbKind::Postgres,
+      "file" | "sqlite" => DbKind::Sqlite,
       _ => return Err(Error::InvalidScheme(url.scheme().to_owned())),
     };
+    normalize_operations(config.operations_mut(), kind);
+    let client: Arc<dyn DatabaseProvider + Send + Sync> = match kind {
+      DbKind::Mssql => Arc::new(crate::mssql_tiberius::AzureSqlComponent::new(config.clone(), resolver).await?),
+      DbKind::Postgres | DbKind::Sqlite => Arc::new(crate::sqlx::SqlXComponent::new(config.clone(), resolver).await?),
+    };
 
-    Ok(Self { inner: client })
+    let client = Self { inner: client };
+    migrate(&client, kind, config.migrations()).await?;
+    Ok(client)
   }
 
   fn inner(&self) -> &Arc<dyn DatabaseProvider + Sync + Send> {
@@ -67,14 +78,20 @@ impl DatabaseProvider for Client {
     self.inner().get_statement(id)
   }
 
-  async fn get_connection<'a, 'b>(&'a self) -> Result<Connection<'b>, Error>
-  where
-    'a: 'b,
-  {
+  async fn get_connection(&self) -> Result<Connection<'static>, Error> {
     self.inner().get_connection().await
   }
 }
 
+/// A connection shared by the operations of a flow transaction, empty until its `begin` operation has acquired it.
+type SharedConnection = Arc<AsyncMutex<Option<Connection<'static>>>>;
+
+/// How long a flow transaction's database transaction may stay open before it is rolled back.
+///
+/// Flows that fail or are cancelled between their `begin` and `commit` operations would otherwise hold onto their
+/// connection forever.
+const TX_TIMEOUT: Duration = Duration::from_secs(60);
+
 /// The Azure SQL Wick component.
 #[derive(Clone)]
 #[must_use]
@@ -84,6 +101,8 @@ pub struct SqlComponent {
   url: Url,
   config: SqlComponentConfig,
   root_config: Option<RuntimeConfig>,
+  /// Database transactions opened by `begin` operations, by the flow transaction they're shared in.
+  transactions: Arc<Mutex<HashMap<Uuid, SharedConnection>>>,
 }
 
 impl std::fmt::Debug for SqlComponent {
@@ -124,6 +143,7 @@ impl SqlComponent {
       url,
       root_config,
       config,
+      transactions: Default::default(),
     })
   }
 }
@@ -136,11 +156,17 @@ impl Component for SqlComponent {
     _callback: LocalScope,
   ) -> BoxFuture<Result<PacketStream, ComponentError>> {
     let client = self.provider.clone();
+    let transactions = self.transactions.clone();
     let opdef = self
       .config
       .get_operation(invocation.target().operation_id())
       .ok_or_else(|| Error::MissingOperation(invocation.target().operation_id().to_owned()))
       .cloned();
+    // Claim the flow transaction before returning so operations invoked after a `begin` always join it.
+    let reserved = match &opdef {
+      Ok(opdef) if opdef.transaction() == SqlTransaction::Begin => Some(reserve(&transactions, invocation.tx_id())),
+      _ => None,
+    };
 
     Box::pin(async move {
       let opdef = opdef?;
@@ -153,13 +179,30 @@ impl Component for SqlComponent {
       tokio::spawn(async move {
         let start = SystemTime::now();
         let span = invocation.span.clone();
-        let Ok(mut connection) = client.get_connection().await else {
-          invocation.trace(|| {
-            error!("could not get connection to database");
-          });
-          return;
+        let tx_id = invocation.tx_id;
+        let mode = opdef.transaction();
+        let (shared, mut guard) = match get_connection(&client, &transactions, tx_id, mode, reserved).await {
+          Ok(connection) => connection,
+          Err(e) => {
+            invocation.trace(|| {
+              error!(error = %e, "could not get connection to database");
+            });
+            let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+            return;
+          }
         };
-        if let Err(e) = handle_call(&mut connection, opdef, input_streams, tx.clone(), &stmt, span).await {
+        // `get_connection` only hands out guards that hold a connection.
+        let connection = guard.as_mut().unwrap();
+        let result = handle_transaction(connection, mode, opdef, input_streams, tx.clone(), &stmt, span).await;
+        if !connection.in_transaction() {
+          evict(&transactions, tx_id, &shared);
+          // Operations still waiting on an ended transaction must not run outside of it.
+          guard.take();
+        } else if mode == SqlTransaction::Begin {
+          tokio::spawn(expire(transactions.clone(), tx_id, Arc::downgrade(&shared)));
+        }
+        drop(guard);
+        if let Err(e) = result {
           invocation.trace(|| {
             error!(error = %e, "error handling sql operation");
           });
@@ -199,6 +242,123 @@ fn validate(config: &SqlComponentConfig, _resolver: &Resolver) -> Result<(), Err
   Ok(())
 }
 
+type Reservation = (SharedConnection, OwnedMutexGuard<Option<Connection<'static>>>);
+
+/// Claim the slot for a flow transaction's connection, locked until its `begin` operation has filled it.
+fn reserve(transactions: &Mutex<HashMap<Uuid, SharedConnection>>, tx_id: Uuid) -> Result<Reservation, Error> {
+  match transactions.lock().entry(tx_id) {
+    Entry::Occupied(_) => Err(Error::TxAlreadyOpen(tx_id)),
+    Entry::Vacant(entry) => {
+      let shared = entry.insert(Arc::new(AsyncMutex::new(None))).clone();
+      // A mutex nobody else has seen yet is always free.
+      let guard = shared
+        .clone()
+        .try_lock_owned()
+        .map_err(|_| Error::TxAlreadyOpen(tx_id))?;
+      Ok((shared, guard))
+    }
+  }
+}
+
+/// Get the connection to run an operation on: the flow transaction's open database transaction, if the operation
+/// takes part in it, or a connection of its own.
+async fn get_connection(
+  client: &Client,
+  transactions: &Mutex<HashMap<Uuid, SharedConnection>>,
+  tx_id: Uuid,
+  mode: SqlTransaction,
+  reserved: Option<Result<Reservation, Error>>,
+) -> Result<Reservation, Error> {
+  if let Some(reserved) = reserved {
+    let (shared, mut guard) = reserved?;
+    match client.get_connection().await {
+      Ok(connection) => guard.replace(connection),
+      Err(e) => {
+        evict(transactions, tx_id, &shared);
+        return Err(e);
+      }
+    };
+    return Ok((shared, guard));
+  }
+  let open = transactions.lock().get(&tx_id).cloned();
+  match (mode, open) {
+    (SqlTransaction::Commit | SqlTransaction::Rollback, None) => Err(Error::NoTransaction(tx_id)),
+    (_, Some(shared)) => {
+      let guard = shared.clone().lock_owned().await;
+      // The transaction was abandoned while this operation waited for it.
+      if guard.is_none() {
+        return Err(Error::NoTransaction(tx_id));
+      }
+      Ok((shared, guard))
+    }
+    (_, None) => {
+      let shared = Arc::new(AsyncMutex::new(Some(client.get_connection().await?)));
+      let guard = shared.clone().lock_owned().await;
+      Ok((shared, guard))
+    }
+  }
+}
+
+/// Forget a flow transaction's connection, unless the transaction has since been replaced.
+fn evict(transactions: &Mutex<HashMap<Uuid, SharedConnection>>, tx_id: Uuid, shared: &SharedConnection) -> bool {
+  let mut transactions = transactions.lock();
+  if transactions.get(&tx_id).map_or(false, |open| Arc::ptr_eq(open, shared)) {
+    transactions.remove(&tx_id);
+    true
+  } else {
+    false
+  }
+}
+
+/// Roll back a flow transaction that is still open after [TX_TIMEOUT].
+async fn expire(
+  transactions: Arc<Mutex<HashMap<Uuid, SharedConnection>>>,
+  tx_id: Uuid,
+  shared: Weak<AsyncMutex<Option<Connection<'static>>>>,
+) {
+  tokio::time::sleep(TX_TIMEOUT).await;
+  let Some(shared) = shared.upgrade() else {
+    return;
+  };
+  if !evict(&transactions, tx_id, &shared) {
+    return;
+  }
+  warn!(%tx_id, timeout = ?TX_TIMEOUT, "flow transaction was not committed in time, rolling back");
+  let connection = shared.lock().await.take();
+  if let Some(mut connection) = connection {
+    if let Err(e) = connection.rollback().await {
+      error!(%tx_id, error = %e, "could not roll back expired transaction");
+    }
+  }
+}
+
+async fn handle_transaction<'a, 'b, 'c>(
+  connection: &'a mut Connection<'c>,
+  mode: SqlTransaction,
+  opdef: SqlOperationDefinition,
+  input_streams: Vec<PacketStream>,
+  tx: PacketSender,
+  stmt: &'b str,
+  span: Span,
+) -> Result<(), Error>
+where
+  'b: 'a,
+{
+  if mode == SqlTransaction::Begin {
+    connection.begin().await?;
+  }
+
+  let result = handle_call(connection, opdef, input_streams, tx, stmt, span).await;
+
+  // The last operation of a flow transaction ends its database transaction whether or not it succeeded.
+  let ended = match mode {
+    SqlTransaction::Commit => connection.commit().await,
+    SqlTransaction::Rollback => connection.rollback().await,
+    SqlTransaction::Join | SqlTransaction::Begin => Ok(()),
+  };
+  result.and(ended)
+}
+
 async fn handle_call<'a, 'b, 'c>(
   connection: &'a mut Connection<'c>,
   opdef: SqlOperationDefinition,
@@ -238,6 +398,8 @@ where
   'b: 'a,
 {
   span.in_scope(|| debug!(stmt = %stmt, "preparing query for stream"));
+  let batch_size = opdef.batch() as usize;
+  let mut batch = Vec::new();
   'outer: loop {
     let mut incoming_packets = Vec::new();
 
@@ -268,6 +430,7 @@ where
         break 'outer;
       }
       if packet.is_open_bracket() || packet.is_close_bracket() {
+        flush_batch(connection, &tx, &opdef, &mut batch, stmt, &span).await?;
         let _ = tx.send(packet.to_port("output"));
         continue 'outer;
       }
@@ -280,6 +443,21 @@ where
       SqlOperationDefinition::Query(_) => {
         query(connection, tx.clone(), opdef.clone(), type_wrappers, stmt, span.clone()).await
       }
+      SqlOperationDefinition::Exec(_) if batch_size > 1 && !opdef.inputs().is_empty() => {
+        batch.push(type_wrappers);
+        if batch.len() < batch_size {
+          continue 'outer;
+        }
+        exec_batch(
+          connection,
+          tx.clone(),
+          opdef.clone(),
+          std::mem::take(&mut batch),
+          stmt,
+          span.clone(),
+        )
+        .await
+      }
       SqlOperationDefinition::Exec(_) => {
         exec(connection, tx.clone(), opdef.clone(), type_wrappers, stmt, span.clone()).await
       }
@@ -288,21 +466,58 @@ where
 
     span.in_scope(|| debug!(μs = duration.as_micros(), "executed query"));
 
-    if let Err(e) = result {
-      if opdef.on_error() == ErrorBehavior::Ignore {
-        let _ = tx.send(Packet::err("output", e.to_string()));
-      } else {
-        return Err(Error::ErrorInStream(e.to_string()));
-      }
-    };
+    report_error(&opdef, &tx, result)?;
 
     if opdef.inputs().len() == 0 {
       break 'outer;
     }
   }
+  flush_batch(connection, &tx, &opdef, &mut batch, stmt, &span).await?;
+  Ok(())
+}
+
+fn report_error(
+  opdef: &SqlOperationDefinition,
+  tx: &PacketSender,
+  result: Result<Duration, Error>,
+) -> Result<(), Error> {
+  if let Err(e) = result {
+    if opdef.on_error() == ErrorBehavior::Ignore {
+      let _ = tx.send(Packet::err("output", e.to_string()));
+    } else {
+      return Err(Error::ErrorInStream(e.to_string()));
+    }
+  };
   Ok(())
 }
 
+/// Execute the inputs batched so far, if any.
+async fn flush_batch<'a, 'b, 'c>(
+  connection: &'a mut Connection<'c>,
+  tx: &PacketSender,
+  opdef: &SqlOperationDefinition,
+  batch: &mut Vec<Vec<(Type, Packet)>>,
+  stmt: &'b str,
+  span: &Span,
+) -> Result<(), Error>
+where
+  'b: 'a,
+{
+  if batch.is_empty() {
+    return Ok(());
+  }
+  let result = exec_batch(
+    connection,
+    tx.clone(),
+    opdef.clone(),
+    std::mem::take(batch),
+    stmt,
+    span.clone(),
+  )
+  .await;
+  report_error(opdef, tx, result)
+}
+
 async fn query<'a, 'b, 'c>(
   client: &'a mut Connection<'c>,
   tx: PacketSender,
@@ -358,6 +573,88 @@ where
   Ok(duration)
 }
 
+async fn exec_batch<'a, 'b, 'c>(
+  connection: &'a mut Connection<'c>,
+  tx: PacketSender,
+  def: SqlOperationDefinition,
+  batch: Vec<Vec<(Type, Packet)>>,
+  stmt: &'b str,
+  span: Span,
+) -> Result<Duration, Error>
+where
+  'b: 'a,
+{
+  let start = SystemTime::now();
+
+  let bound_args = batch
+    .iter()
+    .map(|args| common::bind_args(def.arguments(), args))
+    .collect::<Result<Vec<_>, _>>()?;
+  span.in_scope(|| debug!(size = bound_args.len(), "executing batch"));
+  let packet = match connection.exec_batch(stmt, bound_args).await {
+    Ok(num) => Packet::encode("output", num),
+    Err(err) => Packet::err("output", err.to_string()),
+  };
+
+  let _ = tx.send(packet);
+
+  let duration = SystemTime::now().duration_since(start).unwrap();
+
+  Ok(duration)
+}
+
+/// Apply the migrations that haven't been applied to the DB yet, each in its own transaction.
+async fn migrate(client: &Client, kind: DbKind, migrations: &[SqlMigration]) -> Result<(), Error> {
+  if migrations.is_empty() {
+    return Ok(());
+  }
+  let (create, insert) = match kind {
+    DbKind::Mssql => (
+      "IF OBJECT_ID('_wick_migrations') IS NULL CREATE TABLE _wick_migrations (name NVARCHAR(255) NOT NULL PRIMARY KEY);",
+      "INSERT INTO _wick_migrations (name) VALUES (@p1);",
+    ),
+    DbKind::Postgres | DbKind::Sqlite => (
+      "CREATE TABLE IF NOT EXISTS _wick_migrations (name TEXT NOT NULL PRIMARY KEY);",
+      "INSERT INTO _wick_migrations (name) VALUES ($1);",
+    ),
+  };
+
+  let mut connection = client.get_connection().await?;
+  connection.run_script(create).await?;
+  let mut applied = HashSet::new();
+  let mut rows = connection
+    .query("SELECT name FROM _wick_migrations;", Vec::new())
+    .await?;
+  while let Some(row) = rows.next().await {
+    if let Some(name) = row?.get("name").and_then(Value::as_str) {
+      applied.insert(name.to_owned());
+    }
+  }
+  drop(rows);
+
+  for migration in migrations.iter().filter(|m| !applied.contains(m.name())) {
+    debug!(name = %migration.name(), "sql:migration:apply");
+    connection.begin().await?;
+    if let Err(e) = apply_migration(&mut connection, migration, insert).await {
+      connection.rollback().await?;
+      return Err(Error::Migration(migration.name().to_owned(), e.to_string()));
+    }
+    connection.commit().await?;
+  }
+  Ok(())
+}
+
+async fn apply_migration(connection: &mut Connection<'_>, migration: &SqlMigration, insert: &str) -> Result<(), Error> {
+  connection.run_script(migration.sql()).await?;
+  connection
+    .exec(
+      insert.to_owned(),
+      vec![ConvertedType::String(Some(migration.name().to_owned()))],
+    )
+    .await?;
+  Ok(())
+}
+
 static POSITIONAL_ARGS: Lazy<Regex> = Lazy::new(|| Regex::new(r"\$(?<id>\d+)\b").unwrap());
 static WICK_ID_ARGS: Lazy<Regex> = Lazy::new(|| Regex::new(r"\$\{(?<id>\w+)\}").unwrap());
 
@@ -432,6 +729,12 @@ fn normalize_mssql_query(original: Cow<str>) -> Cow<str> {
 #[cfg(test)]
 mod test {
   use anyhow::Result;
+  use wick_config::config::components::{
+    SqlComponentConfigBuilder,
+    SqlMigrationBuilder,
+    SqlQueryOperationDefinitionBuilder,
+  };
+  use wick_config::config::ResourceDefinition;
 
   use super::*;
 
@@ -464,6 +767,97 @@ mod test {
 
     Ok(())
   }
+
+  async fn init_migrated_sqlite(url: &str) -> Result<SqlComponent> {
+    let migrations = [
+      (
+        "create_users",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);",
+      ),
+      ("seed_users", "INSERT INTO users (name) VALUES ('Migrated User');"),
+    ];
+    let mut config = SqlComponentConfigBuilder::default()
+      .resource("db")
+      .migrations(
+        migrations
+          .into_iter()
+          .map(|(name, sql)| SqlMigrationBuilder::default().name(name).sql(sql).build())
+          .collect::<Result<Vec<_>, _>>()?,
+      )
+      .build()?;
+    let op = SqlQueryOperationDefinitionBuilder::default()
+      .name("users")
+      .query("select name from users;")
+      .outputs([Field::new("output", Type::Object)])
+      .build()?;
+    config.operations_mut().push(SqlOperationDefinition::Query(op));
+    let mut app_config = wick_config::config::AppConfiguration::default();
+    app_config.add_resource("db", ResourceDefinition::Url(url.to_owned().try_into()?));
+
+    let component = SqlComponent::new(config, None, None, &app_config.resolver()).await?;
+
+    Ok(component)
+  }
+
+  async fn get_users(component: &SqlComponent) -> Result<Vec<Packet>> {
+    let inv = Invocation::test("sqlite", "wick://__local__/users", PacketStream::empty(), None)?;
+    let response = component.handle(inv, Default::default(), Default::default()).await?;
+    Ok(
+      response
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<_, _>>()?,
+    )
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_sqlite_memory_migrations() -> Result<()> {
+    let component = init_migrated_sqlite("sqlite://memory").await?;
+    let packets = get_users(&component).await?;
+    assert_eq!(
+      packets,
+      vec![
+        Packet::encode("output", serde_json::json!({"name":"Migrated User"})),
+        Packet::done("output")
+      ]
+    );
+
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test(flavor = "multi_thread"))]
+  async fn test_sqlite_memory_concurrent_queries() -> Result<()> {
+    let component = init_migrated_sqlite("sqlite://memory").await?;
+    // Enough queries at once to spread over every connection in the pool, which must all see the same DB.
+    let results = futures::future::join_all((0..20).map(|_| get_users(&component))).await;
+    for packets in results {
+      assert_eq!(
+        packets?,
+        vec![
+          Packet::encode("output", serde_json::json!({"name":"Migrated User"})),
+          Packet::done("output")
+        ]
+      );
+    }
+
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_sqlite_file_migrations() -> Result<()> {
+    let db = std::env::temp_dir().join("wick_sql_test_file_migrations.db");
+    let _ = std::fs::remove_file(&db);
+    let url = format!("file://{}", db.display());
+
+    let _component = init_migrated_sqlite(&url).await?;
+    let component = init_migrated_sqlite(&url).await?;
+    let packets = get_users(&component).await?;
+    assert_eq!(packets.len(), 2, "migrations should only be applied once");
+
+    std::fs::remove_file(&db)?;
+    Ok(())
+  }
 }
 
 #[cfg(test)]
@@ -472,15 +866,19 @@ mod integration_test {
   use flow_component::Component;
   use futures::StreamExt;
   use serde_json::json;
+  use tracing::Span;
+  use uuid::Uuid;
   use wick_config::config::components::{
     ComponentConfig,
     SqlComponentConfigBuilder,
+    SqlExecOperationDefinitionBuilder,
     SqlOperationDefinition,
     SqlQueryOperationDefinitionBuilder,
+    SqlTransaction,
   };
   use wick_config::config::ResourceDefinition;
   use wick_interface_types::{Field, Type};
-  use wick_packet::{packet_stream, Invocation, Packet};
+  use wick_packet::{packet_stream, Entity, InherentData, Invocation, Packet, PacketStream};
 
   use super::SqlComponent;
 
@@ -642,4 +1040,111 @@ mod integration_test {
     );
     Ok(())
   }
+
+  async fn init_sqlite_tx_component() -> Result<SqlComponent> {
+    let db = std::env::var("SQLITE_DB").unwrap();
+
+    let mut config = SqlComponentConfigBuilder::default()
+      .resource("db")
+      .tls(false)
+      .build()
+      .unwrap();
+    let insert = SqlExecOperationDefinitionBuilder::default()
+      .name("insert")
+      .exec("insert into users(id, name, email) values ($1, $2, $3);")
+      .inputs([
+        Field::new("id", Type::I32),
+        Field::new("name", Type::String),
+        Field::new("email", Type::String),
+      ])
+      .arguments(["id".to_owned(), "name".to_owned(), "email".to_owned()])
+      .transaction(SqlTransaction::Begin)
+      .batch(Some(2))
+      .build()
+      .unwrap();
+    config.operations_mut().push(SqlOperationDefinition::Exec(insert));
+    for (name, transaction) in [("count", SqlTransaction::Join), ("rollback", SqlTransaction::Rollback)] {
+      let op = SqlQueryOperationDefinitionBuilder::default()
+        .name(name)
+        .query("select count(*) as count from users where id >= 1000;")
+        .outputs([Field::new("output", Type::Object)])
+        .transaction(transaction)
+        .build()
+        .unwrap();
+      config.operations_mut().push(SqlOperationDefinition::Query(op));
+    }
+    let mut app_config = wick_config::config::AppConfiguration::default();
+    app_config.add_resource(
+      "db",
+      ResourceDefinition::Url(format!("file://{}", db).try_into().unwrap()),
+    );
+
+    let component = SqlComponent::new(config, None, None, &app_config.resolver()).await?;
+
+    Ok(component)
+  }
+
+  async fn call(component: &SqlComponent, tx_id: Uuid, op: &str, input: PacketStream) -> Result<Vec<Packet>> {
+    let inv = Invocation::new_with_id(
+      tx_id,
+      Entity::test("sqlite"),
+      Entity::local(op),
+      input,
+      InherentData::unsafe_default(),
+      &Span::current(),
+    );
+    let response = component.handle(inv, Default::default(), Default::default()).await?;
+    Ok(
+      response
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<_, _>>()?,
+    )
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_sqlite_transaction() -> Result<()> {
+    let component = init_sqlite_tx_component().await?;
+    let tx_id = Invocation::new_tx_id();
+
+    let input = packet_stream!(
+      ("id", 1000_i32),
+      ("name", "Batch User 1"),
+      ("email", "batch1@example.com"),
+      ("id", 1001_i32),
+      ("name", "Batch User 2"),
+      ("email", "batch2@example.com"),
+      ("id", 1002_i32),
+      ("name", "Batch User 3"),
+      ("email", "batch3@example.com")
+    );
+    let packets = call(&component, tx_id, "insert", input).await?;
+    assert_eq!(
+      packets,
+      vec![
+        Packet::encode("output", 2_u64),
+        Packet::encode("output", 1_u64),
+        Packet::done("output")
+      ],
+      "inserts should be executed in batches of two"
+    );
+
+    let packets = call(&component, tx_id, "count", PacketStream::empty()).await?;
+    assert_eq!(packets[0], Packet::encode("output", json!({"count":3})));
+
+    let packets = call(&component, tx_id, "rollback", PacketStream::empty()).await?;
+    assert_eq!(packets[0], Packet::encode("output", json!({"count":3})));
+
+    let packets = call(&component, Invocation::new_tx_id(), "count", PacketStream::empty()).await?;
+    assert_eq!(
+      packets[0],
+      Packet::encode("output", json!({"count":0})),
+      "the inserts should have been rolled back"
+    );
+
+    let error = call(&component, tx_id, "rollback", PacketStream::empty()).await;
+    assert!(error.is_err(), "the transaction should be closed after a rollback");
+    Ok(())
+  }
 }
@@ -1,3 +1,4 @@
+use uuid::Uuid;
 use wick_config::error::ManifestError;
 use wick_packet::TypeWrapper;
 
@@ -49,6 +50,15 @@ pub enum Error {
   #[error("Failed to rollback DB transaction")]
   TxRollback,
 
+  #[error("A DB transaction is already open for transaction {0}")]
+  TxAlreadyOpen(Uuid),
+
+  #[error("No DB transaction is open for transaction {0}, a 'begin' operation must run first")]
+  NoTransaction(Uuid),
+
+  #[error("Failed to apply migration '{0}': {1}")]
+  Migration(String, String),
+
   #[error("Operation failed: {0}")]
   OperationFailed(String),
 
@@ -9,7 +9,6 @@ use serde_json::{Map, Value};
 use tiberius::{Query, Row};
 use url::Url;
 use wick_config::config::components::{ComponentConfig, OperationConfig, SqlComponentConfig};
-use wick_config::config::ErrorBehavior;
 use wick_config::{ConfigValidation, Resolver};
 use wick_interface_types::{Field, Type};
 
@@ -30,44 +29,41 @@ impl std::fmt::Debug for Context {
 }
 
 impl Context {
-  async fn get<'a, 'b>(&'a self) -> Result<Connection<'b>, Error>
-  where
-    'a: 'b,
-  {
-    let conn = self.db.get().await.map_err(|e| Error::PoolConnection(e.to_string()))?;
+  async fn get(&self) -> Result<Connection<'static>, Error> {
+    let conn = self
+      .db
+      .get_owned()
+      .await
+      .map_err(|e| Error::PoolConnection(e.to_string()))?;
     Ok(Connection::new(Box::new(conn)))
   }
 }
 
 #[async_trait::async_trait]
 impl<'a> ClientConnection for PooledConnection<'a, ConnectionManager> {
-  async fn finish(&mut self, _behavior: ErrorBehavior) -> Result<(), Error> {
-    // todo
+  async fn run_script(&mut self, sql: &str) -> Result<(), Error> {
+    self
+      .simple_query(sql)
+      .await
+      .map_err(|e| Error::Failed(e.to_string()))?
+      .into_results()
+      .await
+      .map_err(|e| Error::Failed(e.to_string()))?;
     Ok(())
   }
 
-  async fn start(&mut self, behavior: ErrorBehavior) -> Result<(), Error> {
-    match behavior {
-      ErrorBehavior::Commit | ErrorBehavior::Rollback => {
-        self.simple_query("BEGIN TRAN").await.map_err(|_| Error::TxStart)?;
-      }
-      _ => {}
-    }
+  async fn begin(&mut self) -> Result<(), Error> {
+    self.simple_query("BEGIN TRAN").await.map_err(|_| Error::TxStart)?;
     Ok(())
   }
 
-  async fn handle_error(&mut self, e: Error, behavior: ErrorBehavior) -> Result<(), Error> {
-    match behavior {
-      ErrorBehavior::Commit => {
-        error!(error=%e, on_error=?behavior, "error in sql operation, committing transaction");
-        self.simple_query("COMMIT").await.map_err(|_| Error::TxCommit)?;
-      }
-      ErrorBehavior::Rollback => {
-        error!(error=%e, on_error=?behavior, "error in sql operation, rolling back transaction");
-        self.simple_query("ROLLBACK").await.map_err(|_| Error::TxCommit)?;
-      }
-      _ => {}
-    }
+  async fn commit(&mut self) -> Result<(), Error> {
+    self.simple_query("COMMIT").await.map_err(|_| Error::TxCommit)?;
+    Ok(())
+  }
+
+  async fn rollback(&mut self) -> Result<(), Error> {
+    self.simple_query("ROLLBACK").await.map_err(|_| Error::TxRollback)?;
     Ok(())
   }
 
@@ -155,10 +151,7 @@ impl DatabaseProvider for AzureSqlComponent {
     self.prepared_queries.get(id).map(|e| e.as_str())
   }
 
-  async fn get_connection<'a, 'b>(&'a self) -> Result<Connection<'b>, Error>
-  where
-    'a: 'b,
-  {
+  async fn get_connection(&self) -> Result<Connection<'static>, Error> {
     self.context.get().await
   }
 }
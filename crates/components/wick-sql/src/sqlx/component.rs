@@ -4,10 +4,10 @@ use flow_component::ComponentError;
 use futures::stream::BoxStream;
 use futures::StreamExt;
 use serde_json::Value;
-use sqlx::{PgPool, SqlitePool};
+use sqlx::pool::PoolConnection;
+use sqlx::{Executor, PgPool, Postgres, Sqlite, SqlitePool};
 use url::Url;
 use wick_config::config::components::{ComponentConfig, OperationConfig, SqlComponentConfig};
-use wick_config::config::ErrorBehavior;
 use wick_config::{ConfigValidation, Resolver};
 use wick_interface_types::{Field, Type};
 
@@ -23,50 +23,79 @@ enum CtxPool {
 }
 
 impl CtxPool {
-  fn run_query<'a, 'b>(&'a self, querystr: &'b str, args: Vec<ConvertedType>) -> BoxStream<'a, Result<Value, Error>>
+  async fn acquire(&self) -> Result<CtxConnection, Error> {
+    match self {
+      CtxPool::Postgres(c) => c.acquire().await.map(CtxConnection::Postgres),
+      CtxPool::SqlLite(c) => c.acquire().await.map(CtxConnection::SqlLite),
+    }
+    .map_err(|e| Error::PoolConnection(e.to_string()))
+  }
+}
+
+#[derive(Debug)]
+enum CtxConnection {
+  Postgres(PoolConnection<Postgres>),
+  SqlLite(PoolConnection<Sqlite>),
+}
+
+impl CtxConnection {
+  fn run_query<'a, 'b>(&'a mut self, querystr: &'b str, args: Vec<ConvertedType>) -> BoxStream<'a, Result<Value, Error>>
   where
     'b: 'a,
   {
     match self {
-      CtxPool::Postgres(c) => {
+      CtxConnection::Postgres(c) => {
         let query = postgres::make_query(querystr, args);
-        let stream = query.fetch(c).map(|res| res.map(postgres::SerMapRow::from)).map(|res| {
-          res
-            .map(|el| serde_json::to_value(el).unwrap_or(Value::Null))
-            .map_err(|e| Error::Fetch(e.to_string()))
-        });
+        let stream = query
+          .fetch(&mut **c)
+          .map(|res| res.map(postgres::SerMapRow::from))
+          .map(|res| {
+            res
+              .map(|el| serde_json::to_value(el).unwrap_or(Value::Null))
+              .map_err(|e| Error::Fetch(e.to_string()))
+          });
 
         stream.boxed()
       }
-      CtxPool::SqlLite(c) => {
+      CtxConnection::SqlLite(c) => {
         let query = sqlite::make_query(querystr, args);
-        let stream = query.fetch(c).map(|res| res.map(sqlite::SerMapRow::from)).map(|res| {
-          res
-            .map(|el| serde_json::to_value(el).unwrap_or(Value::Null))
-            .map_err(|e| Error::Fetch(e.to_string()))
-        });
+        let stream = query
+          .fetch(&mut **c)
+          .map(|res| res.map(sqlite::SerMapRow::from))
+          .map(|res| {
+            res
+              .map(|el| serde_json::to_value(el).unwrap_or(Value::Null))
+              .map_err(|e| Error::Fetch(e.to_string()))
+          });
 
         stream.boxed()
       }
     }
   }
 
-  async fn run_exec<'a, 'q>(&'a self, query: &'q str, args: Vec<ConvertedType>) -> Result<u64, Error>
+  async fn run_exec<'a, 'q>(&'a mut self, query: &'q str, args: Vec<ConvertedType>) -> Result<u64, Error>
   where
     'q: 'a,
   {
     let result = match self {
-      CtxPool::Postgres(c) => {
+      CtxConnection::Postgres(c) => {
         let query = postgres::make_query(query, args);
-        query.execute(c).await.map(|r| r.rows_affected())
+        query.execute(&mut **c).await.map(|r| r.rows_affected())
       }
-      CtxPool::SqlLite(c) => {
+      CtxConnection::SqlLite(c) => {
         let query = sqlite::make_query(query, args);
-        query.execute(c).await.map(|r| r.rows_affected())
+        query.execute(&mut **c).await.map(|r| r.rows_affected())
       }
     };
     result.map_err(|e| Error::Exec(e.to_string()))
   }
+
+  async fn run_raw(&mut self, stmt: &str) -> Result<(), sqlx::Error> {
+    match self {
+      CtxConnection::Postgres(c) => c.execute(stmt).await.map(|_| ()),
+      CtxConnection::SqlLite(c) => c.execute(stmt).await.map(|_| ()),
+    }
+  }
 }
 
 #[derive(Clone)]
@@ -114,29 +143,27 @@ impl DatabaseProvider for SqlXComponent {
     self.prepared_queries.get(id).map(|e| e.as_str())
   }
 
-  async fn get_connection<'a, 'b>(&'a self) -> Result<Connection<'b>, Error>
-  where
-    'a: 'b,
-  {
-    Ok(Connection::new(Box::new(self.context.db.clone())))
+  async fn get_connection(&self) -> Result<Connection<'static>, Error> {
+    Ok(Connection::new(Box::new(self.context.db.acquire().await?)))
   }
 }
 
 #[async_trait::async_trait]
-impl ClientConnection for CtxPool {
-  async fn finish(&mut self, _behavior: ErrorBehavior) -> Result<(), Error> {
-    // todo
-    Ok(())
+impl ClientConnection for CtxConnection {
+  async fn run_script(&mut self, sql: &str) -> Result<(), Error> {
+    self.run_raw(sql).await.map_err(|e| Error::Exec(e.to_string()))
   }
 
-  async fn start(&mut self, _behavior: ErrorBehavior) -> Result<(), Error> {
-    // todo
-    Ok(())
+  async fn begin(&mut self) -> Result<(), Error> {
+    self.run_raw("BEGIN").await.map_err(|_| Error::TxStart)
   }
 
-  async fn handle_error(&mut self, _e: Error, _behavior: ErrorBehavior) -> Result<(), Error> {
-    // todo
-    Ok(())
+  async fn commit(&mut self) -> Result<(), Error> {
+    self.run_raw("COMMIT").await.map_err(|_| Error::TxCommit)
+  }
+
+  async fn rollback(&mut self) -> Result<(), Error> {
+    self.run_raw("ROLLBACK").await.map_err(|_| Error::TxRollback)
   }
 
   async fn exec(&mut self, stmt: String, bound_args: Vec<ConvertedType>) -> Result<u64, Error> {
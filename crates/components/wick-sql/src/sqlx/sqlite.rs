@@ -1,5 +1,8 @@
 mod serialize;
-use sqlx::sqlite::SqlitePoolOptions;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
 use sqlx::{Sqlite, SqlitePool};
 use wick_config::config::components::SqlComponentConfig;
 
@@ -7,16 +10,31 @@ pub(crate) use self::serialize::*;
 use crate::common::sql_wrapper::ConvertedType;
 use crate::Error;
 
-pub(crate) async fn connect(_config: &SqlComponentConfig, addr: Option<&str>) -> Result<SqlitePool, Error> {
-  let addr = addr.unwrap_or(":memory:");
-  debug!(%addr, "connecting to sqlite");
+/// Numbers the in-memory DBs of this process so each component gets its own.
+static IN_MEMORY_DB_SEQ: AtomicUsize = AtomicUsize::new(0);
 
-  let pool = SqlitePoolOptions::new()
-    .max_connections(5)
-    .connect(addr)
-    .await
-    .map_err(|e| Error::SqliteConnect(e.to_string()))?;
-  Ok(pool)
+/// Connect to the SQLite DB file at `addr`, creating it if it doesn't exist, or to a new in-memory DB.
+pub(crate) async fn connect(_config: &SqlComponentConfig, addr: Option<&str>) -> Result<SqlitePool, Error> {
+  let pool = if let Some(path) = addr {
+    debug!(%path, "connecting to sqlite");
+    let options = SqliteConnectOptions::new().filename(path).create_if_missing(true);
+    SqlitePoolOptions::new().max_connections(5).connect_with(options).await
+  } else {
+    let name = format!("wick-in-memory-{}", IN_MEMORY_DB_SEQ.fetch_add(1, Ordering::Relaxed));
+    debug!(%name, "connecting to in-memory sqlite");
+    // Every connection to a plain `:memory:` DB gets a DB of its own, so name it and share its cache instead.
+    let options = SqliteConnectOptions::from_str(&format!("sqlite:file:{}?mode=memory&cache=shared", name))
+      .map_err(|e| Error::SqliteConnect(e.to_string()))?;
+    // An in-memory DB is dropped with its last connection, so keep one open for the life of the pool.
+    SqlitePoolOptions::new()
+      .max_connections(5)
+      .min_connections(1)
+      .idle_timeout(None)
+      .max_lifetime(None)
+      .connect_with(options)
+      .await
+  };
+  pool.map_err(|e| Error::SqliteConnect(e.to_string()))
 }
 
 #[allow(clippy::cast_lossless)]
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, OperationConfig, TextComponentConfig};
+use wick_config::config::Metadata;
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+use crate::templates::Templates;
+
+/// A component that matches, replaces, splits, joins, templates, and diffs strings.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct TextComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl TextComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: TextComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/text");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let templates = match config.templates() {
+      Some(templates) => {
+        let root = resolver(templates)
+          .and_then(|r| r.try_resource())
+          .and_then(|r| r.try_volume())?
+          .path()?;
+        Some(Templates::load(&root)?)
+      }
+      None => None,
+    };
+    let parser = templates
+      .as_ref()
+      .map_or_else(|| liquid::ParserBuilder::with_stdlib().build(), Templates::parser)
+      .map_err(|e| Error::Templates(e.to_string()))?;
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| {
+        let operation = Operation::compile(op, &parser, templates.as_ref())?;
+        Ok((op.name().to_owned(), Arc::new(operation)))
+      })
+      .collect::<Result<_, Error>>()?;
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for TextComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::{json, Value};
+  use wick_config::config::components::{
+    TextComponentConfigBuilder,
+    TextOperationDefinition,
+    TextOperationDefinitionBuilder,
+    TextOperationKind,
+  };
+  use wick_config::config::{BoundIdentifier, OwnedConfigurationItem};
+  use wick_config::error::ManifestError;
+  use wick_interface_types::{Field, Type};
+  use wick_packet::{Entity, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<TextComponent>();
+  }
+
+  fn op(name: &str, kind: TextOperationKind) -> TextOperationDefinitionBuilder {
+    let mut builder = TextOperationDefinitionBuilder::default();
+    builder.name(name).kind(kind);
+    builder
+  }
+
+  fn resolver(id: &BoundIdentifier) -> Result<OwnedConfigurationItem, ManifestError> {
+    Err(ManifestError::IdNotFound {
+      id: id.id().to_owned(),
+      ids: Vec::new(),
+    })
+  }
+
+  fn component(operations: Vec<TextOperationDefinition>) -> Result<TextComponent> {
+    let config = TextComponentConfigBuilder::default().operations(operations).build()?;
+    let component = TextComponent::new(config, None, None, &resolver)?;
+
+    Ok(component)
+  }
+
+  /// Invoke `op` and collect its output, with brackets as `"["` and `"]"`.
+  async fn invoke(component: &TextComponent, op: &str, packets: Vec<Packet>) -> Result<Vec<Value>> {
+    let invocation = Invocation::test(file!(), Entity::local(op), packets, None)?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let mut output = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      if packet.is_open_bracket() {
+        output.push(json!("["));
+      } else if packet.is_close_bracket() {
+        output.push(json!("]"));
+      } else if packet.has_data() {
+        output.push(packet.decode_value()?);
+      }
+    }
+    Ok(output)
+  }
+
+  fn strings(port: &str, values: &[&str]) -> Vec<Packet> {
+    let mut packets: Vec<_> = values.iter().map(|v| Packet::encode(port, v)).collect();
+    packets.push(Packet::done(port));
+    packets
+  }
+
+  #[tokio::test]
+  async fn test_regex() -> Result<()> {
+    let component = component(vec![
+      op("match", TextOperationKind::Match)
+        .pattern("^a+$".to_owned())
+        .build()?,
+      op("replace", TextOperationKind::Replace)
+        .pattern(r"(?P<word>\w+)@".to_owned())
+        .replacement("<$word>".to_owned())
+        .build()?,
+      op("captures", TextOperationKind::Captures)
+        .pattern(r"(?P<key>\w+)=(\d+)".to_owned())
+        .build()?,
+    ])?;
+
+    let output = invoke(&component, "match", strings("input", &["aaa", "ab"])).await?;
+    assert_eq!(output, vec![json!(true), json!(false)]);
+
+    let output = invoke(&component, "replace", strings("input", &["hi@ there@"])).await?;
+    assert_eq!(output, vec![json!("<hi> <there>")]);
+
+    let output = invoke(&component, "captures", strings("input", &["a=1 b=2"])).await?;
+    assert_eq!(
+      output,
+      vec![
+        json!("["),
+        json!({"0": "a=1", "1": "a", "key": "a", "2": "1"}),
+        json!({"0": "b=2", "1": "b", "key": "b", "2": "2"}),
+        json!("]"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_split_join() -> Result<()> {
+    let component = component(vec![
+      op("lines", TextOperationKind::Split).build()?,
+      op("commas", TextOperationKind::Split)
+        .pattern(r"\s*,\s*".to_owned())
+        .build()?,
+      op("join", TextOperationKind::Join).separator(" ".to_owned()).build()?,
+    ])?;
+
+    let output = invoke(&component, "lines", strings("input", &["one\ntwo", "three"])).await?;
+    assert_eq!(
+      output,
+      vec![
+        json!("["),
+        json!("one"),
+        json!("two"),
+        json!("]"),
+        json!("["),
+        json!("three"),
+        json!("]")
+      ]
+    );
+
+    let output = invoke(&component, "commas", strings("input", &["a , b,c"])).await?;
+    assert_eq!(output, vec![json!("["), json!("a"), json!("b"), json!("c"), json!("]")]);
+
+    let mut packets = vec![Packet::open_bracket("input")];
+    packets.extend(strings("input", &["a", "b"]));
+    packets.insert(3, Packet::close_bracket("input"));
+    let output = invoke(&component, "join", packets).await?;
+    assert_eq!(output, vec![json!("a b")]);
+
+    let output = invoke(&component, "join", strings("input", &["c", "d"])).await?;
+    assert_eq!(output, vec![json!("c d")]);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_template() -> Result<()> {
+    let component = component(vec![op("greet", TextOperationKind::Template)
+      .template("{{ greeting }}, {{ name | upcase }}!".to_owned())
+      .inputs(vec![
+        Field::new("greeting", Type::String),
+        Field::new("name", Type::String),
+      ])
+      .build()?])?;
+
+    let mut packets = strings("greeting", &["Hello", "Bye"]);
+    packets.extend(strings("name", &["world", "moon"]));
+    let output = invoke(&component, "greet", packets).await?;
+    assert_eq!(output, vec![json!("Hello, WORLD!"), json!("Bye, MOON!")]);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_diff() -> Result<()> {
+    let component = component(vec![op("diff", TextOperationKind::Diff).context(Some(0)).build()?])?;
+
+    let mut packets = strings("left", &["a\nb\nc\n"]);
+    packets.extend(strings("right", &["a\nB\nc\n"]));
+    let output = invoke(&component, "diff", packets).await?;
+    assert_eq!(output.len(), 1);
+    let diff = output[0].as_str().unwrap();
+    assert!(diff.starts_with("--- left\n+++ right\n"), "{}", diff);
+    assert!(diff.ends_with("\n-b\n+B\n"), "{}", diff);
+    assert!(!diff.contains("\n a\n"), "{}", diff);
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid_config() -> Result<()> {
+    let missing = component(vec![op("match", TextOperationKind::Match).build()?]);
+    assert!(missing.is_err());
+    let invalid = component(vec![op("match", TextOperationKind::Match)
+      .pattern("(".to_owned())
+      .build()?]);
+    assert!(invalid.is_err());
+    let no_templates = component(vec![op("page", TextOperationKind::Template)
+      .file(Some("page.liquid".to_owned()))
+      .build()?]);
+    assert!(no_templates.is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,36 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Operation '{0}' needs a {1}")]
+  MissingField(String, &'static str),
+
+  #[error("Invalid pattern for operation '{0}': {1}")]
+  Pattern(String, String),
+
+  #[error("Invalid template for operation '{0}': {1}")]
+  Template(String, String),
+
+  #[error("Operation '{0}' sets both a template and a template file")]
+  TemplateAndFile(String),
+
+  #[error("Operation '{0}' renders a template file but the component has no templates volume")]
+  NoTemplates(String),
+
+  #[error("Operation '{0}' renders '{1}', which is not a file in the templates volume")]
+  TemplateNotFound(String, String),
+
+  #[error("Could not read templates: {0}")]
+  Templates(String),
+
+  #[error("Could not render template: {0}")]
+  Render(String),
+
+  #[error("Received {0} `left` and {1} `right` strings, diff needs them in pairs")]
+  Unpaired(usize, usize),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
@@ -0,0 +1,332 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use regex::Regex;
+use serde_json::{Map, Value};
+use similar::TextDiff;
+use wick_config::config::components::{OperationConfig, TextOperationDefinition, TextOperationKind};
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::error::Error;
+use crate::templates::Templates;
+
+/// An operation with its pattern or template compiled.
+#[derive(Debug)]
+pub(crate) enum Operation {
+  Match(Regex),
+  Replace(Regex, String),
+  Captures(Regex),
+  Split(Splitter),
+  Join(String),
+  Template(Template),
+  Diff(usize),
+}
+
+#[derive(Debug)]
+pub(crate) enum Splitter {
+  Lines,
+  Separator(String),
+  Pattern(Regex),
+}
+
+pub(crate) struct Template {
+  source: String,
+  template: liquid::Template,
+  inputs: Vec<String>,
+}
+
+impl std::fmt::Debug for Template {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Template")
+      .field("source", &self.source)
+      .field("inputs", &self.inputs)
+      .finish()
+  }
+}
+
+impl Operation {
+  /// Compile `def`. Templates are parsed with `parser` and template files are read from `templates`.
+  pub(crate) fn compile(
+    def: &TextOperationDefinition,
+    parser: &liquid::Parser,
+    templates: Option<&Templates>,
+  ) -> Result<Self, Error> {
+    let name = def.name();
+    let pattern = || -> Result<Regex, Error> {
+      let pattern = def
+        .pattern()
+        .ok_or_else(|| Error::MissingField(name.to_owned(), "pattern"))?;
+      Regex::new(pattern).map_err(|e| Error::Pattern(name.to_owned(), e.to_string()))
+    };
+
+    Ok(match def.kind() {
+      TextOperationKind::Match => Self::Match(pattern()?),
+      TextOperationKind::Replace => {
+        let replacement = def
+          .replacement()
+          .ok_or_else(|| Error::MissingField(name.to_owned(), "replacement"))?;
+        Self::Replace(pattern()?, replacement.to_owned())
+      }
+      TextOperationKind::Captures => Self::Captures(pattern()?),
+      TextOperationKind::Split => match (def.pattern(), def.separator()) {
+        (Some(_), _) => Self::Split(Splitter::Pattern(pattern()?)),
+        (None, Some(separator)) => Self::Split(Splitter::Separator(separator.to_owned())),
+        (None, None) => Self::Split(Splitter::Lines),
+      },
+      TextOperationKind::Join => Self::Join(def.separator().unwrap_or("\n").to_owned()),
+      TextOperationKind::Template => {
+        let source = match (def.template(), def.file()) {
+          (Some(_), Some(_)) => return Err(Error::TemplateAndFile(name.to_owned())),
+          (Some(source), None) => source,
+          (None, Some(file)) => templates
+            .ok_or_else(|| Error::NoTemplates(name.to_owned()))?
+            .get(file)
+            .ok_or_else(|| Error::TemplateNotFound(name.to_owned(), file.to_owned()))?,
+          (None, None) => return Err(Error::MissingField(name.to_owned(), "template")),
+        };
+        let template = parser
+          .parse(source)
+          .map_err(|e| Error::Template(name.to_owned(), e.to_string()))?;
+        Self::Template(Template {
+          source: source.to_owned(),
+          template,
+          inputs: def.inputs().iter().map(|field| field.name().to_owned()).collect(),
+        })
+      }
+      TextOperationKind::Diff => Self::Diff(def.context_lines() as usize),
+    })
+  }
+
+  /// Process the invocation's stream, sending each result on the `output` port.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      let result = match self.as_ref() {
+        Self::Join(separator) => join(&mut stream, &tx, separator).await,
+        Self::Template(template) => render(&mut stream, &tx, template).await,
+        Self::Diff(context) => diff(&mut stream, &tx, *context).await,
+        op => each_input(&mut stream, &tx, op).await,
+      };
+      match result {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("output"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "text"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+
+  /// Send the result of an operation that works on one string at a time.
+  fn apply(&self, input: &str, tx: &PacketSender) {
+    match self {
+      Self::Match(pattern) => {
+        let _ = tx.send(Packet::encode("output", pattern.is_match(input)));
+      }
+      Self::Replace(pattern, replacement) => {
+        let _ = tx.send(Packet::encode(
+          "output",
+          pattern.replace_all(input, replacement.as_str()),
+        ));
+      }
+      Self::Captures(pattern) => {
+        let _ = tx.send(Packet::open_bracket("output"));
+        for captures in pattern.captures_iter(input) {
+          let mut groups = Map::new();
+          for (i, name) in pattern.capture_names().enumerate() {
+            let value = captures
+              .get(i)
+              .map_or(Value::Null, |m| Value::String(m.as_str().to_owned()));
+            if let Some(name) = name {
+              groups.insert(name.to_owned(), value.clone());
+            }
+            groups.insert(i.to_string(), value);
+          }
+          let _ = tx.send(Packet::encode("output", groups));
+        }
+        let _ = tx.send(Packet::close_bracket("output"));
+      }
+      Self::Split(splitter) => {
+        let pieces: Box<dyn Iterator<Item = &str>> = match splitter {
+          Splitter::Lines => Box::new(input.lines()),
+          Splitter::Separator(separator) => Box::new(input.split(separator.as_str())),
+          Splitter::Pattern(pattern) => Box::new(pattern.split(input)),
+        };
+        let _ = tx.send(Packet::open_bracket("output"));
+        for piece in pieces {
+          let _ = tx.send(Packet::encode("output", piece));
+        }
+        let _ = tx.send(Packet::close_bracket("output"));
+      }
+      Self::Join(_) | Self::Template(_) | Self::Diff(_) => unreachable!("not a per-string operation"),
+    }
+  }
+}
+
+/// The next packet on one of `ports`, passing errors on to `output`. Returns `None` at the end of the stream.
+async fn next_packet(stream: &mut PacketStream, tx: &PacketSender, ports: &[&str]) -> Result<Option<Packet>, Error> {
+  while let Some(next) = stream.next().await {
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    if !ports.contains(&packet.port()) {
+      continue;
+    }
+    if packet.is_error() {
+      let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+      continue;
+    }
+    return Ok(Some(packet));
+  }
+  Ok(None)
+}
+
+fn decode_string(packet: &Packet) -> Result<String, Error> {
+  packet.decode().map_err(|e| Error::Input(e.to_string()))
+}
+
+async fn each_input(stream: &mut PacketStream, tx: &PacketSender, op: &Operation) -> Result<(), Error> {
+  while let Some(packet) = next_packet(stream, tx, &["input"]).await? {
+    if packet.is_done() {
+      break;
+    }
+    // Only operations with one output per input keep the shape of their input stream.
+    if packet.is_bracket() && matches!(op, Operation::Match(_) | Operation::Replace(..)) {
+      let _ = tx.send(packet.to_port("output"));
+    } else if packet.has_data() {
+      op.apply(&decode_string(&packet)?, tx);
+    }
+  }
+  Ok(())
+}
+
+async fn join(stream: &mut PacketStream, tx: &PacketSender, separator: &str) -> Result<(), Error> {
+  let mut group: Option<Vec<String>> = None;
+  let mut ungrouped = Vec::new();
+  let mut joined_groups = false;
+
+  while let Some(packet) = next_packet(stream, tx, &["input"]).await? {
+    if packet.is_done() {
+      break;
+    }
+    if packet.is_open_bracket() {
+      group = Some(Vec::new());
+    } else if packet.is_close_bracket() {
+      let pieces = group.take().unwrap_or_default();
+      let _ = tx.send(Packet::encode("output", pieces.join(separator)));
+      joined_groups = true;
+    } else if packet.has_data() {
+      let piece = decode_string(&packet)?;
+      match group.as_mut() {
+        Some(group) => group.push(piece),
+        None => ungrouped.push(piece),
+      }
+    }
+  }
+
+  if !ungrouped.is_empty() || !joined_groups {
+    let _ = tx.send(Packet::encode("output", ungrouped.join(separator)));
+  }
+  Ok(())
+}
+
+async fn render(stream: &mut PacketStream, tx: &PacketSender, template: &Template) -> Result<(), Error> {
+  let send = |values: Map<String, Value>| -> Result<(), Error> {
+    let globals = liquid::model::to_object(&values).map_err(|e| Error::Render(e.to_string()))?;
+    let output = template
+      .template
+      .render(&globals)
+      .map_err(|e| Error::Render(e.to_string()))?;
+    let _ = tx.send(Packet::encode("output", output));
+    Ok(())
+  };
+
+  if template.inputs.is_empty() {
+    return send(Map::new());
+  }
+
+  let ports: Vec<&str> = template.inputs.iter().map(String::as_str).collect();
+  let mut queues: Vec<VecDeque<Value>> = vec![VecDeque::new(); ports.len()];
+  let mut done = vec![false; ports.len()];
+
+  while let Some(packet) = next_packet(stream, tx, &ports).await? {
+    let index = ports.iter().position(|port| *port == packet.port()).unwrap();
+    if packet.is_done() {
+      done[index] = true;
+    } else if packet.has_data() {
+      let value = packet.decode_value().map_err(|e| Error::Input(e.to_string()))?;
+      queues[index].push_back(value);
+    }
+
+    while queues.iter().all(|queue| !queue.is_empty()) {
+      let values = ports
+        .iter()
+        .zip(queues.iter_mut())
+        .map(|(port, queue)| ((*port).to_owned(), queue.pop_front().unwrap()))
+        .collect();
+      send(values)?;
+    }
+
+    // Once an input is done and drained, no more sets of values can be completed.
+    if done.iter().zip(&queues).any(|(done, queue)| *done && queue.is_empty()) {
+      break;
+    }
+  }
+  Ok(())
+}
+
+async fn diff(stream: &mut PacketStream, tx: &PacketSender, context: usize) -> Result<(), Error> {
+  let mut left = VecDeque::new();
+  let mut right = VecDeque::new();
+  let mut counts = (0, 0);
+  let mut done = (false, false);
+
+  while let Some(packet) = next_packet(stream, tx, &["left", "right"]).await? {
+    let is_left = packet.port() == "left";
+    if packet.is_done() {
+      if is_left {
+        done.0 = true;
+      } else {
+        done.1 = true;
+      }
+      if done.0 && done.1 {
+        break;
+      }
+      continue;
+    }
+    if !packet.has_data() {
+      continue;
+    }
+    let text = decode_string(&packet)?;
+    if is_left {
+      counts.0 += 1;
+      left.push_back(text);
+    } else {
+      counts.1 += 1;
+      right.push_back(text);
+    }
+
+    while let (Some(old), Some(new)) = (left.front(), right.front()) {
+      let output = TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(context)
+        .header("left", "right")
+        .to_string();
+      let _ = tx.send(Packet::encode("output", output));
+      left.pop_front();
+      right.pop_front();
+    }
+  }
+
+  if !left.is_empty() || !right.is_empty() {
+    return Err(Error::Unpaired(counts.0, counts.1));
+  }
+  Ok(())
+}
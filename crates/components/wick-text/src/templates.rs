@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use liquid::partials::{InMemorySource, LazyCompiler};
+use walkdir::WalkDir;
+
+use crate::error::Error;
+
+/// The templates in a volume, by their paths relative to it.
+#[derive(Debug)]
+pub(crate) struct Templates {
+  files: BTreeMap<String, String>,
+}
+
+impl Templates {
+  /// Read every file under `root`. Files that aren't UTF-8 can't be templates, so they're left out.
+  pub(crate) fn load(root: &Path) -> Result<Self, Error> {
+    let mut files = BTreeMap::new();
+    for entry in WalkDir::new(root).min_depth(1) {
+      let entry = entry.map_err(|e| Error::Templates(e.to_string()))?;
+      if entry.file_type().is_dir() {
+        continue;
+      }
+      let Ok(path) = entry.path().strip_prefix(root) else {
+        continue;
+      };
+      let path = path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+      let bytes = std::fs::read(entry.path()).map_err(|e| Error::Templates(format!("{}: {}", path, e)))?;
+      match String::from_utf8(bytes) {
+        Ok(source) => {
+          files.insert(path, source);
+        }
+        Err(_) => trace!(%path, "text:skipping non-utf8 file"),
+      }
+    }
+    Ok(Self { files })
+  }
+
+  /// The source of the template at `path`.
+  pub(crate) fn get(&self, path: &str) -> Option<&str> {
+    self.files.get(path.trim_start_matches("./")).map(String::as_str)
+  }
+
+  /// A parser that resolves `include` and `render` tags to these templates. Partials are compiled the first time
+  /// they're used, so files that are never included don't have to be valid templates.
+  pub(crate) fn parser(&self) -> Result<liquid::Parser, liquid::Error> {
+    let mut source = InMemorySource::new();
+    for (path, template) in &self.files {
+      source.add(path.clone(), template.clone());
+    }
+    liquid::ParserBuilder::with_stdlib()
+      .partials(LazyCompiler::new(source))
+      .build()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_load() -> anyhow::Result<()> {
+    let root = std::env::temp_dir().join(format!("wick-text-templates-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("partials"))?;
+    std::fs::write(
+      root.join("page.liquid"),
+      "{% include 'partials/title.liquid' %}: {{ body }}",
+    )?;
+    std::fs::write(root.join("partials/title.liquid"), "<h1>{{ title }}</h1>")?;
+    std::fs::write(root.join("image.bin"), [0xff, 0xfe, 0x00])?;
+
+    let templates = Templates::load(&root)?;
+    std::fs::remove_dir_all(&root)?;
+    assert_eq!(templates.files.len(), 2);
+    assert_eq!(templates.get("./partials/title.liquid"), Some("<h1>{{ title }}</h1>"));
+
+    let template = templates.parser()?.parse(templates.get("page.liquid").unwrap())?;
+    let globals = liquid::object!({ "title": "Hi", "body": "there" });
+    assert_eq!(template.render(&globals)?, "<h1>Hi</h1>: there");
+    Ok(())
+  }
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, OperationConfig, UnitsComponentConfig};
+use wick_config::config::Metadata;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+
+/// A component that does exact money arithmetic and unit conversions.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct UnitsComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl UnitsComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: UnitsComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/units");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| Ok((op.name().to_owned(), Arc::new(Operation::compile(op)?))))
+      .collect::<Result<_, Error>>()?;
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for UnitsComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::{json, Value};
+  use wick_config::config::components::{
+    RoundingMode,
+    UnitsComponentConfigBuilder,
+    UnitsOperationDefinition,
+    UnitsOperationDefinitionBuilder,
+    UnitsOperationKind,
+  };
+  use wick_packet::{Entity, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<UnitsComponent>();
+  }
+
+  fn op(name: &str, kind: UnitsOperationKind) -> UnitsOperationDefinitionBuilder {
+    let mut builder = UnitsOperationDefinitionBuilder::default();
+    builder.name(name).kind(kind);
+    builder
+  }
+
+  fn component(operations: Vec<UnitsOperationDefinition>) -> Result<UnitsComponent> {
+    let config = UnitsComponentConfigBuilder::default().operations(operations).build()?;
+    let component = UnitsComponent::new(config, None, None)?;
+
+    Ok(component)
+  }
+
+  /// Invoke `op` and collect its output, with errors as `{"error": message}`.
+  async fn invoke(component: &UnitsComponent, op: &str, packets: Vec<Packet>) -> Result<Vec<Value>> {
+    let invocation = Invocation::test(file!(), Entity::local(op), packets, None)?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let mut output = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      if packet.is_error() {
+        output.push(json!({ "error": packet.unwrap_err().msg() }));
+      } else if packet.has_data() {
+        output.push(packet.decode_value()?);
+      }
+    }
+    Ok(output)
+  }
+
+  fn values(port: &str, values: &[Value]) -> Vec<Packet> {
+    let mut packets: Vec<_> = values.iter().map(|v| Packet::encode(port, v)).collect();
+    packets.push(Packet::done(port));
+    packets
+  }
+
+  fn usd(amount: i64) -> Value {
+    json!({ "amount": amount, "currency": "USD" })
+  }
+
+  #[tokio::test]
+  async fn test_arithmetic() -> Result<()> {
+    let component = component(vec![
+      op("add", UnitsOperationKind::Add).build()?,
+      op("subtract", UnitsOperationKind::Subtract).build()?,
+      op("tax", UnitsOperationKind::Multiply).build()?,
+      op("tax_up", UnitsOperationKind::Multiply)
+        .rounding(Some(RoundingMode::HalfUp))
+        .build()?,
+    ])?;
+
+    let mut packets = values("left", &[usd(1010), usd(5)]);
+    packets.extend(values("right", &[usd(20), json!({"amount": 1, "currency": "EUR"})]));
+    let output = invoke(&component, "add", packets).await?;
+    assert_eq!(output[0], usd(1030));
+    assert_eq!(output[1], json!({"error": "Can not combine amounts in USD and EUR"}));
+
+    let mut packets = values("left", &[usd(10)]);
+    packets.extend(values("right", &[usd(25)]));
+    assert_eq!(invoke(&component, "subtract", packets).await?, vec![usd(-15)]);
+
+    // 250 * 0.1 is exactly 25, and 125 * 0.5 = 62.5 is halfway.
+    let mut packets = values("money", &[usd(250), usd(125)]);
+    packets.extend(values("factor", &[json!(0.1), json!("0.5")]));
+    assert_eq!(
+      invoke(&component, "tax", packets.clone()).await?,
+      vec![usd(25), usd(62)]
+    );
+    assert_eq!(invoke(&component, "tax_up", packets).await?, vec![usd(25), usd(63)]);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_allocate() -> Result<()> {
+    let component = component(vec![op("split", UnitsOperationKind::Allocate)
+      .ratios(vec![1, 1, 1])
+      .build()?])?;
+
+    let output = invoke(&component, "split", values("input", &[usd(100), usd(-100)])).await?;
+    assert_eq!(
+      output,
+      vec![
+        json!([usd(34), usd(33), usd(33)]),
+        json!([usd(-34), usd(-33), usd(-33)])
+      ]
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_parse_format() -> Result<()> {
+    let component = component(vec![
+      op("usd", UnitsOperationKind::Parse)
+        .currency(Some("USD".to_owned()))
+        .build()?,
+      op("jpy", UnitsOperationKind::Parse)
+        .currency(Some("JPY".to_owned()))
+        .build()?,
+      op("format", UnitsOperationKind::Format).build()?,
+    ])?;
+
+    let output = invoke(
+      &component,
+      "usd",
+      values("input", &[json!("12.34"), json!(0.1), json!("1.005")]),
+    )
+    .await?;
+    assert_eq!(output, vec![usd(1234), usd(10), usd(100)]);
+
+    let output = invoke(&component, "jpy", values("input", &[json!("500")])).await?;
+    assert_eq!(output, vec![json!({"amount": 500, "currency": "JPY"})]);
+
+    let inputs = [usd(1234), usd(-5), json!({"amount": 1234, "currency": "KWD"})];
+    let output = invoke(&component, "format", values("input", &inputs)).await?;
+    assert_eq!(output, vec![json!("12.34"), json!("-0.05"), json!("1.234")]);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_convert() -> Result<()> {
+    let convert = |name: &str, from: &str, to: &str| {
+      op(name, UnitsOperationKind::Convert)
+        .from(Some(from.to_owned()))
+        .to(Some(to.to_owned()))
+        .build()
+    };
+    let component = component(vec![
+      convert("mi_km", "mi", "km")?,
+      convert("f_c", "f", "c")?,
+      convert("c_f", "c", "f")?,
+      op("in_cm", UnitsOperationKind::Convert)
+        .from(Some("in".to_owned()))
+        .to(Some("cm".to_owned()))
+        .precision(Some(1))
+        .build()?,
+    ])?;
+
+    let output = invoke(&component, "mi_km", values("input", &[json!(1), json!(0.1)])).await?;
+    assert_eq!(output, vec![json!(1.609344), json!(0.1609344)]);
+
+    let output = invoke(&component, "f_c", values("input", &[json!(212), json!(-40)])).await?;
+    assert_eq!(output, vec![json!(100.0), json!(-40.0)]);
+
+    let output = invoke(&component, "c_f", values("input", &[json!(37)])).await?;
+    assert_eq!(output, vec![json!(98.6)]);
+
+    let output = invoke(&component, "in_cm", values("input", &[json!("1.75")])).await?;
+    assert_eq!(output, vec![json!(4.4)]);
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid_config() -> Result<()> {
+    assert!(component(vec![op("parse", UnitsOperationKind::Parse).build()?]).is_err());
+    assert!(component(vec![op("parse", UnitsOperationKind::Parse)
+      .currency(Some("dollars".to_owned()))
+      .build()?])
+    .is_err());
+    assert!(component(vec![op("split", UnitsOperationKind::Allocate).build()?]).is_err());
+    assert!(component(vec![op("convert", UnitsOperationKind::Convert)
+      .from(Some("kg".to_owned()))
+      .to(Some("km".to_owned()))
+      .build()?])
+    .is_err());
+    assert!(component(vec![op("convert", UnitsOperationKind::Convert)
+      .from(Some("furlong".to_owned()))
+      .to(Some("km".to_owned()))
+      .build()?])
+    .is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,30 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Operation '{0}' needs a {1}")]
+  MissingField(String, &'static str),
+
+  #[error("Unknown currency '{0}', expected an ISO 4217 code like 'USD'")]
+  UnknownCurrency(String),
+
+  #[error("Unknown unit '{0}'")]
+  UnknownUnit(String),
+
+  #[error("Can not convert between '{0}' and '{1}', they measure different things")]
+  IncompatibleUnits(String, String),
+
+  #[error("Operation '{0}' needs at least one non-zero ratio")]
+  InvalidRatios(String),
+
+  #[error("Can not combine amounts in {0} and {1}")]
+  CurrencyMismatch(String, String),
+
+  #[error("Result is too large to represent")]
+  Overflow,
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
@@ -0,0 +1,152 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde_json::Value;
+use wick_config::config::components::RoundingMode;
+
+use crate::error::Error;
+
+/// An amount of money as a whole number of its currency's minor units, e.g. cents.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Money {
+  pub(crate) amount: i64,
+  pub(crate) currency: String,
+}
+
+/// The number of decimal places in a currency's minor unit, per ISO 4217.
+pub(crate) fn minor_units(currency: &str) -> Result<u32, Error> {
+  if currency.len() != 3 || !currency.bytes().all(|b| b.is_ascii_uppercase()) {
+    return Err(Error::UnknownCurrency(currency.to_owned()));
+  }
+  Ok(match currency {
+    "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX" | "UYI" | "VND" | "VUV"
+    | "XAF" | "XOF" | "XPF" => 0,
+    "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+    "CLF" | "UYW" => 4,
+    _ => 2,
+  })
+}
+
+pub(crate) const fn strategy(mode: RoundingMode) -> RoundingStrategy {
+  match mode {
+    RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+    RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+    RoundingMode::HalfDown => RoundingStrategy::MidpointTowardZero,
+    RoundingMode::Up => RoundingStrategy::AwayFromZero,
+    RoundingMode::Down => RoundingStrategy::ToZero,
+    RoundingMode::Ceiling => RoundingStrategy::ToPositiveInfinity,
+    RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+  }
+}
+
+/// Read an exact decimal from a string or a number. Numbers are read from their shortest representation, so `0.1`
+/// stays `0.1`.
+pub(crate) fn decimal(value: &Value) -> Result<Decimal, Error> {
+  let text = match value {
+    Value::String(s) => s.trim().to_owned(),
+    Value::Number(n) => n.to_string(),
+    _ => {
+      return Err(Error::Input(format!(
+        "expected a number or a decimal string, got {}",
+        value
+      )))
+    }
+  };
+  text
+    .parse::<Decimal>()
+    .or_else(|_| Decimal::from_scientific(&text))
+    .map_err(|_| Error::Input(format!("'{}' is not a decimal number", text)))
+}
+
+fn to_amount(value: Decimal, rounding: RoundingStrategy) -> Result<i64, Error> {
+  value
+    .round_dp_with_strategy(0, rounding)
+    .to_i64()
+    .ok_or(Error::Overflow)
+}
+
+impl Money {
+  pub(crate) fn from_value(value: Value) -> Result<Self, Error> {
+    let money: Self = serde_json::from_value(value).map_err(|e| {
+      Error::Input(format!(
+        "expected an object with an integer amount and a currency: {}",
+        e
+      ))
+    })?;
+    minor_units(&money.currency)?;
+    Ok(money)
+  }
+
+  fn same_currency(&self, other: &Self) -> Result<(), Error> {
+    if self.currency == other.currency {
+      Ok(())
+    } else {
+      Err(Error::CurrencyMismatch(self.currency.clone(), other.currency.clone()))
+    }
+  }
+
+  pub(crate) fn add(&self, other: &Self) -> Result<Self, Error> {
+    self.same_currency(other)?;
+    let amount = self.amount.checked_add(other.amount).ok_or(Error::Overflow)?;
+    Ok(Self {
+      amount,
+      currency: self.currency.clone(),
+    })
+  }
+
+  pub(crate) fn subtract(&self, other: &Self) -> Result<Self, Error> {
+    self.same_currency(other)?;
+    let amount = self.amount.checked_sub(other.amount).ok_or(Error::Overflow)?;
+    Ok(Self {
+      amount,
+      currency: self.currency.clone(),
+    })
+  }
+
+  pub(crate) fn multiply(&self, factor: Decimal, rounding: RoundingStrategy) -> Result<Self, Error> {
+    let product = Decimal::from(self.amount).checked_mul(factor).ok_or(Error::Overflow)?;
+    Ok(Self {
+      amount: to_amount(product, rounding)?,
+      currency: self.currency.clone(),
+    })
+  }
+
+  /// Split the amount by `ratios`. Every share is rounded toward zero, then the minor units left over are handed out
+  /// one at a time from the first share on, so the shares always add up to the original amount.
+  pub(crate) fn allocate(&self, ratios: &[u32]) -> Vec<Self> {
+    let total: i128 = ratios.iter().map(|r| i128::from(*r)).sum();
+    let amount = i128::from(self.amount);
+    let mut shares: Vec<i128> = ratios.iter().map(|r| amount * i128::from(*r) / total).collect();
+    let mut remainder = amount - shares.iter().sum::<i128>();
+    for (share, ratio) in shares.iter_mut().zip(ratios) {
+      if remainder == 0 {
+        break;
+      }
+      if *ratio > 0 {
+        *share += remainder.signum();
+        remainder -= remainder.signum();
+      }
+    }
+    shares
+      .into_iter()
+      .map(|share| Self {
+        // each share lies between zero and the original amount, so it always fits.
+        amount: share as i64,
+        currency: self.currency.clone(),
+      })
+      .collect()
+  }
+
+  pub(crate) fn parse(value: Decimal, currency: &str, rounding: RoundingStrategy) -> Result<Self, Error> {
+    let scale = Decimal::from(10_i64.pow(minor_units(currency)?));
+    let minor = value.checked_mul(scale).ok_or(Error::Overflow)?;
+    Ok(Self {
+      amount: to_amount(minor, rounding)?,
+      currency: currency.to_owned(),
+    })
+  }
+
+  pub(crate) fn format(&self) -> Result<String, Error> {
+    let places = minor_units(&self.currency)?;
+    Ok(Decimal::from_i128_with_scale(i128::from(self.amount), places).to_string())
+  }
+}
@@ -0,0 +1,187 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::RoundingStrategy;
+use serde_json::Value;
+use wick_config::config::components::{OperationConfig, UnitsOperationDefinition, UnitsOperationKind};
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::error::Error;
+use crate::money::{decimal, minor_units, strategy, Money};
+use crate::units::Conversion;
+
+/// An operation with its settings checked.
+#[derive(Debug)]
+pub(crate) enum Operation {
+  Add,
+  Subtract,
+  Multiply(RoundingStrategy),
+  Allocate(Vec<u32>),
+  Parse(String, RoundingStrategy),
+  Format,
+  Convert(Conversion),
+}
+
+impl Operation {
+  pub(crate) fn compile(def: &UnitsOperationDefinition) -> Result<Self, Error> {
+    let name = def.name();
+    let rounding = strategy(def.rounding());
+
+    Ok(match def.kind() {
+      UnitsOperationKind::Add => Self::Add,
+      UnitsOperationKind::Subtract => Self::Subtract,
+      UnitsOperationKind::Multiply => Self::Multiply(rounding),
+      UnitsOperationKind::Allocate => {
+        if def.ratios().iter().all(|ratio| *ratio == 0) {
+          return Err(Error::InvalidRatios(name.to_owned()));
+        }
+        Self::Allocate(def.ratios().to_vec())
+      }
+      UnitsOperationKind::Parse => {
+        let currency = def
+          .currency()
+          .ok_or_else(|| Error::MissingField(name.to_owned(), "currency"))?;
+        minor_units(currency)?;
+        Self::Parse(currency.to_owned(), rounding)
+      }
+      UnitsOperationKind::Format => Self::Format,
+      UnitsOperationKind::Convert => {
+        let from = def
+          .from_unit()
+          .ok_or_else(|| Error::MissingField(name.to_owned(), "from"))?;
+        let to = def
+          .to_unit()
+          .ok_or_else(|| Error::MissingField(name.to_owned(), "to"))?;
+        Self::Convert(Conversion::new(from, to, def.precision_places(), rounding)?)
+      }
+    })
+  }
+
+  /// Process the invocation's stream, sending each result on the `output` port.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      let result = match self.as_ref() {
+        Self::Add | Self::Subtract => each_pair(&mut stream, &tx, ["left", "right"], &self).await,
+        Self::Multiply(_) => each_pair(&mut stream, &tx, ["money", "factor"], &self).await,
+        op => each_input(&mut stream, &tx, op).await,
+      };
+      match result {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("output"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "units"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+
+  /// The result of an operation that works on one value at a time.
+  fn apply(&self, input: Value) -> Result<Packet, Error> {
+    Ok(match self {
+      Self::Allocate(ratios) => Packet::encode("output", Money::from_value(input)?.allocate(ratios)),
+      Self::Parse(currency, rounding) => Packet::encode("output", Money::parse(decimal(&input)?, currency, *rounding)?),
+      Self::Format => Packet::encode("output", Money::from_value(input)?.format()?),
+      Self::Convert(conversion) => {
+        let converted = conversion.convert(decimal(&input)?)?;
+        Packet::encode("output", converted.to_f64().ok_or(Error::Overflow)?)
+      }
+      Self::Add | Self::Subtract | Self::Multiply(_) => unreachable!("not a single-input operation"),
+    })
+  }
+
+  /// The result of an operation that works on a pair of values.
+  fn apply_pair(&self, first: Value, second: Value) -> Result<Packet, Error> {
+    let result = match self {
+      Self::Add => Money::from_value(first)?.add(&Money::from_value(second)?)?,
+      Self::Subtract => Money::from_value(first)?.subtract(&Money::from_value(second)?)?,
+      Self::Multiply(rounding) => Money::from_value(first)?.multiply(decimal(&second)?, *rounding)?,
+      _ => unreachable!("not a paired operation"),
+    };
+    Ok(Packet::encode("output", result))
+  }
+}
+
+/// Send a result, or the error that stopped this value from producing one.
+fn send(tx: &PacketSender, result: Result<Packet, Error>) {
+  let packet = result.unwrap_or_else(|e| Packet::err("output", e.to_string()));
+  let _ = tx.send(packet);
+}
+
+/// The next packet on one of `ports`, passing errors on to `output`. Returns `None` at the end of the stream.
+async fn next_packet(stream: &mut PacketStream, tx: &PacketSender, ports: &[&str]) -> Result<Option<Packet>, Error> {
+  while let Some(next) = stream.next().await {
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    if !ports.contains(&packet.port()) {
+      continue;
+    }
+    if packet.is_error() {
+      let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+      continue;
+    }
+    return Ok(Some(packet));
+  }
+  Ok(None)
+}
+
+fn decode_value(packet: &Packet) -> Result<Value, Error> {
+  packet.decode_value().map_err(|e| Error::Input(e.to_string()))
+}
+
+async fn each_input(stream: &mut PacketStream, tx: &PacketSender, op: &Operation) -> Result<(), Error> {
+  while let Some(packet) = next_packet(stream, tx, &["input"]).await? {
+    if packet.is_done() {
+      break;
+    }
+    // every input has one output, so the shape of the input stream is kept.
+    if packet.is_bracket() {
+      let _ = tx.send(packet.to_port("output"));
+    } else if packet.has_data() {
+      send(tx, decode_value(&packet).and_then(|value| op.apply(value)));
+    }
+  }
+  Ok(())
+}
+
+async fn each_pair(
+  stream: &mut PacketStream,
+  tx: &PacketSender,
+  ports: [&str; 2],
+  op: &Operation,
+) -> Result<(), Error> {
+  let mut queues: [VecDeque<Value>; 2] = Default::default();
+  let mut done = [false; 2];
+
+  while let Some(packet) = next_packet(stream, tx, &ports).await? {
+    let index = usize::from(packet.port() == ports[1]);
+    if packet.is_done() {
+      done[index] = true;
+    } else if packet.has_data() {
+      queues[index].push_back(decode_value(&packet)?);
+    }
+
+    while queues.iter().all(|queue| !queue.is_empty()) {
+      let [first, second] = &mut queues;
+      send(
+        tx,
+        op.apply_pair(first.pop_front().unwrap(), second.pop_front().unwrap()),
+      );
+    }
+
+    // Once an input is done and drained, no more pairs can be completed.
+    if done.iter().zip(&queues).any(|(done, queue)| *done && queue.is_empty()) {
+      break;
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,165 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimension {
+  Length,
+  Mass,
+  Volume,
+  Time,
+  Temperature,
+  Pressure,
+  Energy,
+  Speed,
+  Data,
+}
+
+/// A unit, defined exactly in terms of its dimension's base unit: `base = (value + offset) * numerator / denominator`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Unit {
+  dimension: Dimension,
+  offset: Decimal,
+  numerator: Decimal,
+  denominator: Decimal,
+}
+
+fn dec(mantissa: i64, scale: u32) -> Decimal {
+  Decimal::new(mantissa, scale)
+}
+
+impl Unit {
+  const fn scaled(dimension: Dimension, numerator: Decimal) -> Self {
+    Self::ratio(dimension, numerator, Decimal::ONE)
+  }
+
+  const fn ratio(dimension: Dimension, numerator: Decimal, denominator: Decimal) -> Self {
+    Self {
+      dimension,
+      offset: Decimal::ZERO,
+      numerator,
+      denominator,
+    }
+  }
+
+  pub(crate) fn lookup(name: &str) -> Result<Self, Error> {
+    use Dimension::*;
+    let unit = match name {
+      "m" => Self::scaled(Length, Decimal::ONE),
+      "km" => Self::scaled(Length, dec(1000, 0)),
+      "cm" => Self::scaled(Length, dec(1, 2)),
+      "mm" => Self::scaled(Length, dec(1, 3)),
+      "in" => Self::scaled(Length, dec(254, 4)),
+      "ft" => Self::scaled(Length, dec(3048, 4)),
+      "yd" => Self::scaled(Length, dec(9144, 4)),
+      "mi" => Self::scaled(Length, dec(1_609_344, 3)),
+      "nmi" => Self::scaled(Length, dec(1852, 0)),
+
+      "kg" => Self::scaled(Mass, Decimal::ONE),
+      "g" => Self::scaled(Mass, dec(1, 3)),
+      "mg" => Self::scaled(Mass, dec(1, 6)),
+      "t" => Self::scaled(Mass, dec(1000, 0)),
+      "lb" => Self::scaled(Mass, dec(45_359_237, 8)),
+      "oz" => Self::scaled(Mass, dec(28_349_523_125, 12)),
+
+      "l" => Self::scaled(Volume, Decimal::ONE),
+      "ml" => Self::scaled(Volume, dec(1, 3)),
+      "m3" => Self::scaled(Volume, dec(1000, 0)),
+      "gal" => Self::scaled(Volume, dec(3_785_411_784, 9)),
+      "qt" => Self::scaled(Volume, dec(946_352_946, 9)),
+      "pt" => Self::scaled(Volume, dec(473_176_473, 9)),
+      "cup" => Self::scaled(Volume, dec(2_365_882_365, 10)),
+      "floz" => Self::scaled(Volume, dec(295_735_295_625, 13)),
+
+      "s" => Self::scaled(Time, Decimal::ONE),
+      "ms" => Self::scaled(Time, dec(1, 3)),
+      "min" => Self::scaled(Time, dec(60, 0)),
+      "h" => Self::scaled(Time, dec(3600, 0)),
+      "d" => Self::scaled(Time, dec(86_400, 0)),
+      "wk" => Self::scaled(Time, dec(604_800, 0)),
+
+      "k" => Self::scaled(Temperature, Decimal::ONE),
+      "c" => Self {
+        offset: dec(27315, 2),
+        ..Self::scaled(Temperature, Decimal::ONE)
+      },
+      "f" => Self {
+        offset: dec(45967, 2),
+        ..Self::ratio(Temperature, dec(5, 0), dec(9, 0))
+      },
+
+      "pa" => Self::scaled(Pressure, Decimal::ONE),
+      "kpa" => Self::scaled(Pressure, dec(1000, 0)),
+      "bar" => Self::scaled(Pressure, dec(100_000, 0)),
+      "atm" => Self::scaled(Pressure, dec(101_325, 0)),
+      // a pound-force per square inch.
+      "psi" => Self::ratio(Pressure, dec(44_482_216_152_605, 13), dec(64_516, 8)),
+
+      "j" => Self::scaled(Energy, Decimal::ONE),
+      "kj" => Self::scaled(Energy, dec(1000, 0)),
+      "wh" => Self::scaled(Energy, dec(3600, 0)),
+      "kwh" => Self::scaled(Energy, dec(3_600_000, 0)),
+      "cal" => Self::scaled(Energy, dec(4184, 3)),
+      "kcal" => Self::scaled(Energy, dec(4184, 0)),
+
+      "m/s" => Self::scaled(Speed, Decimal::ONE),
+      "km/h" => Self::ratio(Speed, dec(1000, 0), dec(3600, 0)),
+      "mph" => Self::ratio(Speed, dec(1_609_344, 3), dec(3600, 0)),
+      "kn" => Self::ratio(Speed, dec(1852, 0), dec(3600, 0)),
+
+      "b" => Self::scaled(Data, Decimal::ONE),
+      "bit" => Self::ratio(Data, Decimal::ONE, dec(8, 0)),
+      "kb" => Self::scaled(Data, dec(1000, 0)),
+      "mb" => Self::scaled(Data, dec(1_000_000, 0)),
+      "gb" => Self::scaled(Data, dec(1_000_000_000, 0)),
+      "kib" => Self::scaled(Data, dec(1024, 0)),
+      "mib" => Self::scaled(Data, dec(1_048_576, 0)),
+      "gib" => Self::scaled(Data, dec(1_073_741_824, 0)),
+      _ => return Err(Error::UnknownUnit(name.to_owned())),
+    };
+    Ok(unit)
+  }
+}
+
+/// Converts values between two units of the same dimension.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Conversion {
+  from: Unit,
+  to: Unit,
+  precision: u32,
+  rounding: RoundingStrategy,
+}
+
+impl Conversion {
+  pub(crate) fn new(from: &str, to: &str, precision: u32, rounding: RoundingStrategy) -> Result<Self, Error> {
+    let (from_unit, to_unit) = (Unit::lookup(from)?, Unit::lookup(to)?);
+    if from_unit.dimension != to_unit.dimension {
+      return Err(Error::IncompatibleUnits(from.to_owned(), to.to_owned()));
+    }
+    Ok(Self {
+      from: from_unit,
+      to: to_unit,
+      precision,
+      rounding,
+    })
+  }
+
+  /// Convert `value`, dividing only once so exact conversions stay exact.
+  pub(crate) fn convert(&self, value: Decimal) -> Result<Decimal, Error> {
+    let numerator = value
+      .checked_add(self.from.offset)
+      .and_then(|v| v.checked_mul(self.from.numerator))
+      .and_then(|v| v.checked_mul(self.to.denominator))
+      .ok_or(Error::Overflow)?;
+    let denominator = self.from.denominator * self.to.numerator;
+    let converted = numerator
+      .checked_div(denominator)
+      .and_then(|v| v.checked_sub(self.to.offset))
+      .ok_or(Error::Overflow)?;
+    Ok(
+      converted
+        .round_dp_with_strategy(self.precision, self.rounding)
+        .normalize(),
+    )
+  }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use wick_config::config::components::{ComponentConfig, OperationConfig, ValidateComponentConfig};
+use wick_config::config::Metadata;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, PacketStream, RuntimeConfig};
+
+use crate::error::Error;
+use crate::operation::Operation;
+
+/// A component that validates and normalizes emails, URLs, and phone numbers.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ValidateComponent {
+  signature: ComponentSignature,
+  operations: HashMap<String, Arc<Operation>>,
+}
+
+impl ValidateComponent {
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: ValidateComponentConfig,
+    _root_config: Option<RuntimeConfig>,
+    metadata: Option<Metadata>,
+  ) -> Result<Self, ComponentError> {
+    let mut sig = ComponentSignature::new_named("wick/component/validate");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+    sig.config = config.config().to_vec();
+
+    let operations = config
+      .operations()
+      .iter()
+      .map(|op| Ok((op.name().to_owned(), Arc::new(Operation::compile(op)?))))
+      .collect::<Result<_, Error>>()?;
+
+    Ok(Self {
+      signature: sig,
+      operations,
+    })
+  }
+}
+
+impl Component for ValidateComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let operation = self.operations.get(invocation.target().operation_id()).cloned();
+
+    Box::pin(async move {
+      match operation {
+        Some(operation) => Ok(operation.run(invocation)),
+        None => Err(Error::OpNotFound(invocation.target().operation_id().to_owned()).into()),
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use futures::StreamExt;
+  use serde_json::{json, Value};
+  use wick_config::config::components::{
+    ValidateComponentConfigBuilder,
+    ValidateOperationDefinition,
+    ValidateOperationDefinitionBuilder,
+    ValidateOperationKind,
+  };
+  use wick_packet::{Entity, Packet, PacketExt};
+
+  use super::*;
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<ValidateComponent>();
+  }
+
+  fn op(name: &str, kind: ValidateOperationKind) -> ValidateOperationDefinitionBuilder {
+    let mut builder = ValidateOperationDefinitionBuilder::default();
+    builder.name(name).kind(kind);
+    builder
+  }
+
+  fn component(operations: Vec<ValidateOperationDefinition>) -> Result<ValidateComponent> {
+    let config = ValidateComponentConfigBuilder::default()
+      .operations(operations)
+      .build()?;
+    let component = ValidateComponent::new(config, None, None)?;
+
+    Ok(component)
+  }
+
+  async fn validate(component: &ValidateComponent, op: &str, inputs: &[&str]) -> Result<Vec<Value>> {
+    let mut packets: Vec<_> = inputs.iter().map(|v| Packet::encode("input", v)).collect();
+    packets.push(Packet::done("input"));
+    let invocation = Invocation::test(file!(), Entity::local(op), packets, None)?;
+    let stream = component.handle(invocation, None, LocalScope::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let mut output = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      if packet.has_data() {
+        output.push(packet.decode_value()?);
+      }
+    }
+    Ok(output)
+  }
+
+  fn valid(normalized: &str) -> Value {
+    json!({ "valid": true, "normalized": normalized })
+  }
+
+  fn invalid(reason: &str) -> Value {
+    json!({ "valid": false, "reason": reason })
+  }
+
+  #[tokio::test]
+  async fn test_email() -> Result<()> {
+    let component = component(vec![op("email", ValidateOperationKind::Email).build()?])?;
+
+    let output = validate(
+      &component,
+      "email",
+      &[
+        " Jane.Doe@Example.COM ",
+        "user@b\u{fc}cher.de",
+        "jane.doe",
+        "jane..doe@example.com",
+        "jane@localhost",
+      ],
+    )
+    .await?;
+    assert_eq!(
+      output,
+      vec![
+        valid("Jane.Doe@example.com"),
+        valid("user@xn--bcher-kva.de"),
+        invalid("missing '@'"),
+        invalid("the part before '@' has a misplaced '.'"),
+        invalid("'localhost' is not a fully qualified domain"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_url() -> Result<()> {
+    let component = component(vec![
+      op("web", ValidateOperationKind::Url).build()?,
+      op("ftp", ValidateOperationKind::Url)
+        .schemes(vec!["FTP".to_owned()])
+        .build()?,
+    ])?;
+
+    let output = validate(
+      &component,
+      "web",
+      &["HTTPS://Example.COM:443/a?b=c", "ftp://example.com/file", "example.com"],
+    )
+    .await?;
+    assert_eq!(
+      output,
+      vec![
+        valid("https://example.com/a?b=c"),
+        invalid("the 'ftp' scheme is not allowed"),
+        invalid("relative URL without a base"),
+      ]
+    );
+
+    let output = validate(&component, "ftp", &["ftp://example.com/file"]).await?;
+    assert_eq!(output, vec![valid("ftp://example.com/file")]);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_phone() -> Result<()> {
+    let component = component(vec![
+      op("us", ValidateOperationKind::Phone)
+        .country_code(Some("1".to_owned()))
+        .build()?,
+      op("any", ValidateOperationKind::Phone).build()?,
+    ])?;
+
+    let output = validate(
+      &component,
+      "us",
+      &["+1 (415) 555-2671", "415.555.2671", "0044 20 7946 0018", "555-CALL"],
+    )
+    .await?;
+    assert_eq!(
+      output,
+      vec![
+        valid("+14155552671"),
+        valid("+14155552671"),
+        valid("+442079460018"),
+        invalid("'C' is not allowed in a phone number"),
+      ]
+    );
+
+    let output = validate(&component, "any", &["+44 20 7946 0018", "020 7946 0018", "+1 555"]).await?;
+    assert_eq!(
+      output,
+      vec![
+        valid("+442079460018"),
+        invalid("missing a country code"),
+        invalid("expected 7 to 15 digits, found 4"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid_config() -> Result<()> {
+    assert!(component(vec![op("phone", ValidateOperationKind::Phone)
+      .country_code(Some("+44".to_owned()))
+      .build()?])
+    .is_err());
+    assert!(component(vec![op("url", ValidateOperationKind::Url)
+      .schemes(vec!["http://".to_owned()])
+      .build()?])
+    .is_err());
+    Ok(())
+  }
+}
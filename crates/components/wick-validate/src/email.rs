@@ -0,0 +1,71 @@
+/// The longest address that fits in the SMTP `MAIL FROM` and `RCPT TO` commands.
+const MAX_ADDRESS: usize = 254;
+const MAX_LOCAL_PART: usize = 64;
+const MAX_DOMAIN: usize = 253;
+const MAX_LABEL: usize = 63;
+
+/// Validate an email address, returning it with its domain lowercased and converted to punycode.
+///
+/// The local part is case-sensitive per RFC 5321, so it's kept as written. Quoted local parts and IP address literals
+/// are valid on paper but almost never intended in a form, so they're rejected.
+pub(crate) fn normalize(input: &str) -> Result<String, String> {
+  let input = input.trim();
+  let (local, domain) = input.rsplit_once('@').ok_or("missing '@'")?;
+  check_local_part(local)?;
+
+  let ascii_domain = idna::domain_to_ascii(domain).map_err(|_| format!("'{}' is not a valid domain", domain))?;
+  check_domain(&ascii_domain)?;
+
+  let address = format!("{}@{}", local, ascii_domain);
+  if address.len() > MAX_ADDRESS {
+    return Err(format!("longer than {} characters", MAX_ADDRESS));
+  }
+  Ok(address)
+}
+
+fn check_local_part(local: &str) -> Result<(), String> {
+  if local.is_empty() {
+    return Err("missing the part before '@'".to_owned());
+  }
+  if local.len() > MAX_LOCAL_PART {
+    return Err(format!(
+      "the part before '@' is longer than {} characters",
+      MAX_LOCAL_PART
+    ));
+  }
+  if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+    return Err("the part before '@' has a misplaced '.'".to_owned());
+  }
+  // RFC 5322 atext, plus any non-ASCII character per RFC 6531.
+  if let Some(c) = local
+    .chars()
+    .find(|c| !(c.is_ascii_alphanumeric() || !c.is_ascii() || "!#$%&'*+-/=?^_`{|}~.".contains(*c)))
+  {
+    return Err(format!("'{}' is not allowed before '@'", c));
+  }
+  Ok(())
+}
+
+fn check_domain(domain: &str) -> Result<(), String> {
+  if domain.len() > MAX_DOMAIN {
+    return Err(format!("the domain is longer than {} characters", MAX_DOMAIN));
+  }
+  let labels: Vec<_> = domain.split('.').collect();
+  if labels.len() < 2 {
+    return Err(format!("'{}' is not a fully qualified domain", domain));
+  }
+  let valid_label = |label: &&str| {
+    (1..=MAX_LABEL).contains(&label.len())
+      && !label.starts_with('-')
+      && !label.ends_with('-')
+      && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+  };
+  // a top-level domain is never all digits, which also rules out IP addresses.
+  let numeric_tld = labels
+    .last()
+    .map_or(false, |tld| tld.chars().all(|c| c.is_ascii_digit()));
+  if !labels.iter().all(valid_label) || numeric_tld {
+    return Err(format!("'{}' is not a valid domain", domain));
+  }
+  Ok(())
+}
@@ -0,0 +1,15 @@
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  #[error("Operation '{0}' has an invalid country code '{1}', expected one to three digits like '1' or '44'")]
+  InvalidCountryCode(String, String),
+
+  #[error("Operation '{0}' has an invalid URL scheme '{1}'")]
+  InvalidScheme(String, String),
+
+  #[error("Invalid input: {0}")]
+  Input(String),
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+use url::Url;
+use wick_config::config::components::{OperationConfig, ValidateOperationDefinition, ValidateOperationKind};
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream};
+
+use crate::error::Error;
+use crate::{email, phone};
+
+/// An operation with its settings checked.
+#[derive(Debug)]
+pub(crate) enum Operation {
+  Email,
+  Url(Vec<String>),
+  Phone(Option<String>),
+}
+
+/// What an operation outputs for each input.
+#[derive(Debug, serde::Serialize)]
+struct Validation {
+  valid: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  normalized: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  reason: Option<String>,
+}
+
+impl From<Result<String, String>> for Validation {
+  fn from(result: Result<String, String>) -> Self {
+    result.map_or_else(
+      |reason| Self {
+        valid: false,
+        normalized: None,
+        reason: Some(reason),
+      },
+      |normalized| Self {
+        valid: true,
+        normalized: Some(normalized),
+        reason: None,
+      },
+    )
+  }
+}
+
+impl Operation {
+  pub(crate) fn compile(def: &ValidateOperationDefinition) -> Result<Self, Error> {
+    let name = def.name();
+
+    Ok(match def.kind() {
+      ValidateOperationKind::Email => Self::Email,
+      ValidateOperationKind::Url => {
+        let schemes = def.allowed_schemes();
+        if let Some(scheme) = schemes.iter().find(|scheme| !is_scheme(scheme)) {
+          return Err(Error::InvalidScheme(name.to_owned(), scheme.clone()));
+        }
+        Self::Url(schemes.iter().map(|s| s.to_ascii_lowercase()).collect())
+      }
+      ValidateOperationKind::Phone => {
+        if let Some(code) = def.country_code().filter(|code| !phone::is_country_code(code)) {
+          return Err(Error::InvalidCountryCode(name.to_owned(), code.to_owned()));
+        }
+        Self::Phone(def.country_code().map(ToOwned::to_owned))
+      }
+    })
+  }
+
+  /// Process the invocation's stream, sending a [Validation] for each input on the `output` port.
+  pub(crate) fn run(self: Arc<Self>, invocation: Invocation) -> PacketStream {
+    let (tx, rx) = invocation.make_response();
+    let span = invocation.span().clone();
+    let mut stream = invocation.into_stream();
+
+    tokio::spawn(async move {
+      match each_input(&mut stream, &tx, &self).await {
+        Ok(()) => {
+          let _ = tx.send(Packet::done("output"));
+        }
+        Err(e) => {
+          span.in_scope(|| error!(error = %e, "validate"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+      }
+      tx.complete();
+    });
+
+    rx
+  }
+
+  fn validate(&self, input: &str) -> Validation {
+    match self {
+      Self::Email => email::normalize(input),
+      Self::Url(schemes) => normalize_url(input, schemes),
+      Self::Phone(country_code) => phone::normalize(input, country_code.as_deref()),
+    }
+    .into()
+  }
+}
+
+/// Validate an absolute URL, returning it with its scheme and host lowercased and any default port removed.
+fn normalize_url(input: &str, schemes: &[String]) -> Result<String, String> {
+  let url = Url::parse(input.trim()).map_err(|e| e.to_string())?;
+  if !schemes.iter().any(|scheme| scheme == url.scheme()) {
+    return Err(format!("the '{}' scheme is not allowed", url.scheme()));
+  }
+  Ok(url.into())
+}
+
+/// Whether `scheme` is a valid URL scheme per RFC 3986.
+fn is_scheme(scheme: &str) -> bool {
+  scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+    && scheme
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+async fn each_input(stream: &mut PacketStream, tx: &PacketSender, op: &Operation) -> Result<(), Error> {
+  while let Some(next) = stream.next().await {
+    let packet = next.map_err(|e| Error::Input(e.to_string()))?;
+    if packet.port() != "input" {
+      continue;
+    }
+    if packet.is_done() {
+      break;
+    }
+    // every input has one output, so the shape of the input stream is kept.
+    if packet.is_error() {
+      let _ = tx.send(Packet::raw_err("output", packet.unwrap_err()));
+    } else if packet.is_bracket() {
+      let _ = tx.send(packet.to_port("output"));
+    } else if packet.has_data() {
+      let output = packet.decode::<String>().map_or_else(
+        |e| Packet::err("output", e.to_string()),
+        |input| Packet::encode("output", op.validate(&input)),
+      );
+      let _ = tx.send(output);
+    }
+  }
+  Ok(())
+}
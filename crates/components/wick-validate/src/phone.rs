@@ -0,0 +1,49 @@
+/// E.164 numbers are at most 15 digits, country code included. The shortest numbers in use are 7.
+const MIN_DIGITS: usize = 7;
+const MAX_DIGITS: usize = 15;
+
+/// Validate a phone number, returning it in E.164 form, e.g. `+14155552671`.
+///
+/// Numbers that start with `+` or the `00` international prefix carry their own country code. Anything else is a
+/// national number: a single leading trunk `0` is dropped and `country_code` is prepended.
+pub(crate) fn normalize(input: &str, country_code: Option<&str>) -> Result<String, String> {
+  let input = input.trim();
+  let (international, rest) = input
+    .strip_prefix('+')
+    .or_else(|| input.strip_prefix("00"))
+    .map_or((false, input), |rest| (true, rest));
+
+  let mut digits = String::with_capacity(rest.len());
+  for c in rest.chars() {
+    match c {
+      '0'..='9' => digits.push(c),
+      ' ' | '-' | '.' | '(' | ')' | '/' => {}
+      _ => return Err(format!("'{}' is not allowed in a phone number", c)),
+    }
+  }
+
+  let number = if international {
+    digits
+  } else {
+    let code = country_code.ok_or("missing a country code")?;
+    format!("{}{}", code, digits.strip_prefix('0').unwrap_or(&digits))
+  };
+
+  if number.starts_with('0') {
+    return Err("country codes can not start with 0".to_owned());
+  }
+  if !(MIN_DIGITS..=MAX_DIGITS).contains(&number.len()) {
+    return Err(format!(
+      "expected {} to {} digits, found {}",
+      MIN_DIGITS,
+      MAX_DIGITS,
+      number.len()
+    ));
+  }
+  Ok(format!("+{}", number))
+}
+
+/// Whether `code` looks like a calling code: one to three digits, not starting with 0.
+pub(crate) fn is_country_code(code: &str) -> bool {
+  (1..=3).contains(&code.len()) && !code.starts_with('0') && code.chars().all(|c| c.is_ascii_digit())
+}
@@ -67,3 +67,26 @@ impl main::Operation for Component {
     Ok(())
   }
 }
+
+#[cfg_attr(target_family = "wasm",async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait::async_trait)]
+impl wait::Operation for Component {
+  type Error = anyhow::Error;
+  type Inputs = wait::Inputs;
+  type Outputs = wait::Outputs;
+  type Config = wait::Config;
+
+  async fn wait(_inputs: Self::Inputs, _outputs: Self::Outputs, ctx: Context<Self::Config>) -> Result<(), Self::Error> {
+    println!("waiting for cancellation");
+    while !ctx.cancel.is_cancelled() {
+      // each call goes out to the host, which gives the cancellation a chance to arrive.
+      let mut result = ctx
+        .provided()
+        .baseline
+        .power(power::Config { exponent: 1 }, power::Request { input: 1 })?;
+      let _ = result.output.next().await;
+    }
+    println!("cancelled");
+    Ok(())
+  }
+}
@@ -0,0 +1,29 @@
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use hyper::client::connect::dns::Name;
+use hyper::service::Service;
+
+use crate::CachingResolver;
+
+/// Lets the resolver be used with `hyper::client::HttpConnector::new_with_resolver`.
+///
+/// The connector takes care of happy eyeballs: it attempts the first address family returned and, if that
+/// hasn't connected within its happy eyeballs timeout, races it against the other family.
+impl Service<Name> for CachingResolver {
+  type Response = std::vec::IntoIter<SocketAddr>;
+  type Error = io::Error;
+  type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+  fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    Poll::Ready(Ok(()))
+  }
+
+  fn call(&mut self, name: Name) -> Self::Future {
+    let resolver = self.clone();
+    Box::pin(async move { Ok(resolver.resolve_all(name.as_str()).await?.into_iter()) })
+  }
+}
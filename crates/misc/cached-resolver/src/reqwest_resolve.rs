@@ -0,0 +1,15 @@
+use hyper::client::connect::dns::Name;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+
+use crate::CachingResolver;
+
+/// Lets the resolver be used with `reqwest::ClientBuilder::dns_resolver`.
+impl Resolve for CachingResolver {
+  fn resolve(&self, name: Name) -> Resolving {
+    let resolver = self.clone();
+    Box::pin(async move {
+      let addrs: Addrs = Box::new(resolver.resolve_all(name.as_str()).await?.into_iter());
+      Ok(addrs)
+    })
+  }
+}
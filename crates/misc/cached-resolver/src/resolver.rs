@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::RwLock;
+
+type LookupFuture = Pin<Box<dyn Future<Output = io::Result<Vec<SocketAddr>>> + Send>>;
+type LookupFn = dyn Fn(String) -> LookupFuture + Send + Sync;
+
+/// Settings for a [CachingResolver].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResolverConfig {
+  /// How long a successful lookup is reused.
+  pub positive_ttl: Duration,
+  /// How long a failed lookup is reused before the name is looked up again.
+  pub negative_ttl: Duration,
+  /// The number of names to cache before expired entries are evicted.
+  pub max_entries: usize,
+}
+
+impl Default for ResolverConfig {
+  fn default() -> Self {
+    Self {
+      positive_ttl: Duration::from_secs(60),
+      negative_ttl: Duration::from_secs(5),
+      max_entries: 1024,
+    }
+  }
+}
+
+impl ResolverConfig {
+  /// Set how long a successful lookup is reused.
+  #[must_use]
+  pub const fn positive_ttl(mut self, ttl: Duration) -> Self {
+    self.positive_ttl = ttl;
+    self
+  }
+
+  /// Set how long a failed lookup is reused.
+  #[must_use]
+  pub const fn negative_ttl(mut self, ttl: Duration) -> Self {
+    self.negative_ttl = ttl;
+    self
+  }
+
+  /// Set the number of names to cache before expired entries are evicted.
+  #[must_use]
+  pub const fn max_entries(mut self, max: usize) -> Self {
+    self.max_entries = max;
+    self
+  }
+}
+
+/// Counters describing how often lookups were answered from the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CacheStats {
+  /// Lookups answered with cached addresses.
+  pub hits: u64,
+  /// Lookups answered with a cached failure.
+  pub negative_hits: u64,
+  /// Lookups that went to the system resolver.
+  pub misses: u64,
+}
+
+#[derive(Debug, Clone)]
+enum Cached {
+  Found(Arc<[SocketAddr]>),
+  Failed(io::ErrorKind, Arc<str>),
+}
+
+#[derive(Debug)]
+struct Entry {
+  value: Cached,
+  expires: Instant,
+}
+
+#[derive(Default)]
+struct Counters {
+  hits: AtomicU64,
+  negative_hits: AtomicU64,
+  misses: AtomicU64,
+}
+
+struct Inner {
+  config: ResolverConfig,
+  cache: RwLock<HashMap<String, Entry>>,
+  counters: Counters,
+  lookup: Box<LookupFn>,
+}
+
+/// A DNS resolver that caches successful and failed lookups.
+///
+/// Clones share the same cache. Resolved addresses have a port of `0`; connectors set the port of the URI they
+/// are connecting to.
+#[derive(Clone)]
+#[must_use]
+pub struct CachingResolver {
+  inner: Arc<Inner>,
+}
+
+impl std::fmt::Debug for CachingResolver {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CachingResolver")
+      .field("config", &self.inner.config)
+      .field("entries", &self.inner.cache.read().len())
+      .finish()
+  }
+}
+
+impl Default for CachingResolver {
+  fn default() -> Self {
+    Self::new(ResolverConfig::default())
+  }
+}
+
+impl CachingResolver {
+  /// Create a resolver that looks names up with the system resolver.
+  pub fn new(config: ResolverConfig) -> Self {
+    Self::with_lookup(config, |name| {
+      Box::pin(async move { Ok(tokio::net::lookup_host((name.as_str(), 0)).await?.collect()) })
+    })
+  }
+
+  /// Create a resolver that uses `lookup` in place of the system resolver.
+  pub fn with_lookup<F>(config: ResolverConfig, lookup: F) -> Self
+  where
+    F: Fn(String) -> LookupFuture + Send + Sync + 'static,
+  {
+    Self {
+      inner: Arc::new(Inner {
+        config,
+        cache: RwLock::new(HashMap::new()),
+        counters: Counters::default(),
+        lookup: Box::new(lookup),
+      }),
+    }
+  }
+
+  /// Resolve `name` to every address it has, IPv4 and IPv6 alike.
+  pub async fn resolve_all(&self, name: &str) -> io::Result<Vec<SocketAddr>> {
+    let name = name.to_ascii_lowercase();
+    if let Some(cached) = self.cached(&name) {
+      return match cached {
+        Cached::Found(addrs) => {
+          self.inner.counters.hits.fetch_add(1, Ordering::Relaxed);
+          Ok(addrs.to_vec())
+        }
+        Cached::Failed(kind, msg) => {
+          self.inner.counters.negative_hits.fetch_add(1, Ordering::Relaxed);
+          Err(io::Error::new(kind, msg.to_string()))
+        }
+      };
+    }
+
+    self.inner.counters.misses.fetch_add(1, Ordering::Relaxed);
+    let result = (self.inner.lookup)(name.clone()).await;
+    let (value, ttl) = match &result {
+      Ok(addrs) if !addrs.is_empty() => (Cached::Found(addrs.as_slice().into()), self.inner.config.positive_ttl),
+      Ok(_) => (
+        Cached::Failed(
+          io::ErrorKind::NotFound,
+          format!("no addresses found for {}", name).into(),
+        ),
+        self.inner.config.negative_ttl,
+      ),
+      Err(e) => (
+        Cached::Failed(e.kind(), e.to_string().into()),
+        self.inner.config.negative_ttl,
+      ),
+    };
+    trace!(%name, ?value, "dns:resolve");
+    let result = match &value {
+      Cached::Found(_) => result,
+      Cached::Failed(kind, msg) => Err(io::Error::new(*kind, msg.to_string())),
+    };
+    self.insert(name, value, ttl);
+    result
+  }
+
+  /// Get the cache's hit and miss counters.
+  #[must_use]
+  pub fn stats(&self) -> CacheStats {
+    let counters = &self.inner.counters;
+    CacheStats {
+      hits: counters.hits.load(Ordering::Relaxed),
+      negative_hits: counters.negative_hits.load(Ordering::Relaxed),
+      misses: counters.misses.load(Ordering::Relaxed),
+    }
+  }
+
+  /// Forget every cached lookup.
+  pub fn clear(&self) {
+    self.inner.cache.write().clear();
+  }
+
+  fn cached(&self, name: &str) -> Option<Cached> {
+    self
+      .inner
+      .cache
+      .read()
+      .get(name)
+      .filter(|entry| entry.expires > Instant::now())
+      .map(|entry| entry.value.clone())
+  }
+
+  fn insert(&self, name: String, value: Cached, ttl: Duration) {
+    if ttl.is_zero() {
+      return;
+    }
+    let now = Instant::now();
+    let mut cache = self.inner.cache.write();
+    if cache.len() >= self.inner.config.max_entries && !cache.contains_key(&name) {
+      cache.retain(|_, entry| entry.expires > now);
+      if cache.len() >= self.inner.config.max_entries {
+        return;
+      }
+    }
+    cache.insert(
+      name,
+      Entry {
+        value,
+        expires: now + ttl,
+      },
+    );
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::net::{Ipv4Addr, Ipv6Addr};
+  use std::sync::atomic::AtomicUsize;
+
+  use anyhow::Result;
+
+  use super::*;
+
+  fn counting_resolver(config: ResolverConfig, calls: Arc<AtomicUsize>) -> CachingResolver {
+    CachingResolver::with_lookup(config, move |name| {
+      calls.fetch_add(1, Ordering::SeqCst);
+      Box::pin(async move {
+        match name.as_str() {
+          "dual.example.com" => Ok(vec![
+            SocketAddr::new(Ipv6Addr::LOCALHOST.into(), 0),
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+          ]),
+          "empty.example.com" => Ok(vec![]),
+          _ => Err(io::Error::new(io::ErrorKind::TimedOut, "lookup timed out")),
+        }
+      })
+    })
+  }
+
+  #[tokio::test]
+  async fn test_positive_cache() -> Result<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let resolver = counting_resolver(ResolverConfig::default(), calls.clone());
+
+    let first = resolver.resolve_all("dual.example.com").await?;
+    let second = resolver.clone().resolve_all("DUAL.example.com").await?;
+    assert_eq!(first, second);
+    assert_eq!(first.len(), 2, "both address families are returned");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(
+      resolver.stats(),
+      CacheStats {
+        hits: 1,
+        negative_hits: 0,
+        misses: 1,
+      }
+    );
+
+    resolver.clear();
+    resolver.resolve_all("dual.example.com").await?;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_negative_cache() -> Result<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let resolver = counting_resolver(ResolverConfig::default(), calls.clone());
+
+    assert!(resolver.resolve_all("missing.example.com").await.is_err());
+    let err = resolver.resolve_all("missing.example.com").await.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    assert_eq!(err.to_string(), "lookup timed out");
+    let err = resolver.resolve_all("empty.example.com").await.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(resolver.stats().negative_hits, 1);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_expiry() -> Result<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let config = ResolverConfig::default()
+      .positive_ttl(Duration::ZERO)
+      .negative_ttl(Duration::ZERO);
+    let resolver = counting_resolver(config, calls.clone());
+
+    resolver.resolve_all("dual.example.com").await?;
+    resolver.resolve_all("dual.example.com").await?;
+    assert!(resolver.resolve_all("missing.example.com").await.is_err());
+    assert!(resolver.resolve_all("missing.example.com").await.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_system_lookup() -> Result<()> {
+    let resolver = CachingResolver::default();
+    let addrs = resolver.resolve_all("127.0.0.1").await?;
+    assert_eq!(addrs, vec![SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0)]);
+    Ok(())
+  }
+}
@@ -0,0 +1,77 @@
+use bytes::Bytes;
+use uuid::Uuid;
+use wick_packet::{BlobRef, BlobStore};
+
+use crate::ComponentError;
+
+/// A component's handle to the blobs of the transaction it's handling.
+///
+/// Blobs put through the handle are held until the transaction's last flow finishes, so a [BlobRef] can be sent
+/// downstream in place of a large payload and resolved by any operation in the same transaction.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Blobs {
+  store: BlobStore,
+  tx_id: Option<Uuid>,
+}
+
+impl Default for Blobs {
+  fn default() -> Self {
+    Self::new(BlobStore::global().clone())
+  }
+}
+
+impl Blobs {
+  /// A handle to the blobs in `store`.
+  pub const fn new(store: BlobStore) -> Self {
+    Self { store, tx_id: None }
+  }
+
+  /// This handle bound to the transaction `tx_id`.
+  pub const fn bind(mut self, tx_id: Uuid) -> Self {
+    self.tx_id = Some(tx_id);
+    self
+  }
+
+  /// Store `bytes` for the current transaction and return a reference to send in their place.
+  pub fn put(&self, bytes: impl Into<Bytes>) -> Result<BlobRef, ComponentError> {
+    let tx_id = self
+      .tx_id
+      .ok_or_else(|| ComponentError::msg("blobs can only be put while handling an invocation"))?;
+    Ok(self.store.put(tx_id, bytes)?)
+  }
+
+  /// The bytes `blob` refers to.
+  pub fn get(&self, blob: &BlobRef) -> Result<Bytes, ComponentError> {
+    self
+      .store
+      .get(blob)
+      .ok_or_else(|| ComponentError::msg(format!("{} is no longer held by any transaction", blob)))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_blobs() -> Result<()> {
+    let store = BlobStore::new();
+    let tx = Uuid::new_v4();
+    assert!(
+      Blobs::new(store.clone()).put("unbound").is_err(),
+      "unbound handles can't put blobs"
+    );
+
+    let scope = store.scope(tx);
+    let blobs = Blobs::new(store.clone()).bind(tx);
+    let blob = blobs.put("payload")?;
+    assert_eq!(blobs.get(&blob)?, "payload");
+    drop(scope);
+    assert!(blobs.get(&blob).is_err(), "blobs should be gone once the transaction is");
+    assert!(blobs.put("late").is_err(), "finished transactions can't hold blobs");
+    Ok(())
+  }
+}
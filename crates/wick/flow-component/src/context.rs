@@ -1,3 +1,4 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use seeded_random::{Random, Seed};
@@ -30,6 +31,8 @@ where
   pub config: Arc<T>,
   /// Inherent data passed to the operation.
   pub inherent: InherentContext,
+  /// Raised when the host cancels the invocation.
+  pub cancel: CancelSignal,
   #[cfg(feature = "invocation")]
   /// A callback to invoke other components within the executing runtime.
   pub callback: LocalScope,
@@ -55,8 +58,11 @@ where
       inherent: InherentContext {
         rng: Random::from_seed(Seed::unsafe_new(value.inherent.seed)),
         timestamp: date_from_millis(value.inherent.timestamp).unwrap(),
+        deadline: value.inherent.deadline,
+        tx_id: value.inherent.tx_id,
       },
       config: Arc::new(value.config),
+      cancel: Default::default(),
       #[cfg(feature = "invocation")]
       callback: Default::default(),
     }
@@ -71,6 +77,12 @@ pub struct InherentContext {
   pub rng: Random,
   /// The timestamp of the invocation.
   pub timestamp: DateTime,
+  /// The time, in milliseconds since the UNIX epoch, by which the invocation must finish.
+  ///
+  /// Invocations made through the context's callback inherit this deadline.
+  pub deadline: Option<u64>,
+  /// The transaction being handled, which link calls carry back to the host.
+  pub tx_id: Option<String>,
 }
 
 impl Clone for InherentContext {
@@ -78,6 +90,8 @@ impl Clone for InherentContext {
     Self {
       rng: Random::from_seed(self.rng.seed()),
       timestamp: self.timestamp,
+      deadline: self.deadline,
+      tx_id: self.tx_id.clone(),
     }
   }
 }
@@ -85,6 +99,8 @@ impl Clone for InherentContext {
 impl From<InherentContext> for InherentData {
   fn from(value: InherentContext) -> Self {
     Self::new(value.rng.gen(), value.timestamp.timestamp_millis() as _)
+      .with_deadline(value.deadline)
+      .with_tx_id(value.tx_id)
   }
 }
 
@@ -93,6 +109,8 @@ impl From<InherentData> for InherentContext {
     Self {
       rng: Random::from_seed(Seed::unsafe_new(value.seed)),
       timestamp: date_from_millis(value.timestamp).unwrap(),
+      deadline: value.deadline,
+      tx_id: value.tx_id,
     }
   }
 }
@@ -109,12 +127,32 @@ where
       inherent: InherentContext {
         rng: Random::from_seed(Seed::unsafe_new(inherent.seed)),
         timestamp: date_from_millis(inherent.timestamp).unwrap(),
+        deadline: inherent.deadline,
+        tx_id: inherent.tx_id.clone(),
       },
       config: Arc::new(config),
+      cancel: Default::default(),
       callback,
     }
   }
 
+  /// The state the runtime keeps for the invoked component.
+  ///
+  /// This is for native components. WebAssembly guests reach their app and component state through link calls the
+  /// host answers instead.
+  #[cfg(feature = "invocation")]
+  pub fn kv(&self) -> crate::KeyValue {
+    self.callback.kv()
+  }
+
+  /// The blobs of the transaction being handled.
+  ///
+  /// WebAssembly guests put and resolve blobs through link calls the host answers instead.
+  #[cfg(feature = "invocation")]
+  pub fn blobs(&self) -> crate::Blobs {
+    self.callback.blobs()
+  }
+
   /// Create a new context.
   #[cfg(not(feature = "invocation"))]
   pub fn new(config: T, inherent: &InherentData) -> Self {
@@ -122,12 +160,36 @@ where
       inherent: InherentContext {
         rng: Random::from_seed(Seed::unsafe_new(inherent.seed)),
         timestamp: date_from_millis(inherent.timestamp).unwrap(),
+        deadline: inherent.deadline,
+        tx_id: inherent.tx_id.clone(),
       },
       config: Arc::new(config),
+      cancel: Default::default(),
     }
   }
 }
 
+#[derive(Debug, Default, Clone)]
+#[must_use]
+/// A signal the host raises when it cancels the invocation an operation is working on.
+///
+/// Long-running operations should check [CancelSignal::is_cancelled] between units of work and stop once it returns
+/// `true`; the host has stopped listening for their output.
+pub struct CancelSignal(Arc<AtomicBool>);
+
+impl CancelSignal {
+  /// Whether the invocation has been cancelled.
+  #[must_use]
+  pub fn is_cancelled(&self) -> bool {
+    self.0.load(Ordering::Relaxed)
+  }
+
+  /// Mark the invocation as cancelled.
+  pub fn cancel(&self) {
+    self.0.store(true, Ordering::Relaxed);
+  }
+}
+
 #[allow(missing_debug_implementations, missing_copy_implementations)]
 #[derive(Default, Clone)]
 #[non_exhaustive]
@@ -136,6 +198,10 @@ pub struct LocalScope {
   #[allow(unused)]
   #[cfg(feature = "invocation")]
   invocation: Option<Arc<crate::ScopeInvokeFn>>,
+  #[cfg(feature = "invocation")]
+  kv: crate::KeyValue,
+  #[cfg(feature = "invocation")]
+  blobs: crate::Blobs,
 }
 
 impl LocalScope {
@@ -145,6 +211,42 @@ impl LocalScope {
   pub fn new(invocation: Arc<crate::ScopeInvokeFn>) -> Self {
     Self {
       invocation: Some(invocation),
+      kv: Default::default(),
+      blobs: Default::default(),
+    }
+  }
+
+  #[cfg(feature = "invocation")]
+  #[must_use]
+  #[allow(clippy::missing_const_for_fn)]
+  /// Give operations invoked with this scope access to the state in `kv`.
+  pub fn with_kv(mut self, kv: crate::KeyValue) -> Self {
+    self.kv = kv;
+    self
+  }
+
+  #[cfg(feature = "invocation")]
+  /// The state operations invoked with this scope can read and write.
+  pub fn kv(&self) -> crate::KeyValue {
+    self.kv.clone()
+  }
+
+  #[cfg(feature = "invocation")]
+  /// The blobs of the transaction operations invoked with this scope are handling.
+  pub fn blobs(&self) -> crate::Blobs {
+    self.blobs.clone()
+  }
+
+  #[cfg(feature = "invocation")]
+  /// This scope with its state and blobs bound to the component and transaction `invocation` targets.
+  pub fn for_invocation(&self, invocation: &wick_packet::Invocation) -> Self {
+    Self {
+      invocation: self.invocation.clone(),
+      kv: self
+        .kv
+        .clone()
+        .bind(invocation.target().component_id(), invocation.tx_id().to_string()),
+      blobs: self.blobs.clone().bind(invocation.tx_id()),
     }
   }
   #[cfg(feature = "invocation")]
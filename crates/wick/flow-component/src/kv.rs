@@ -0,0 +1,233 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{BoxFuture, ComponentError};
+
+/// How long keys in a transaction's scope are kept after they're last set, so state a transaction left behind doesn't
+/// pile up in the store.
+pub const TRANSACTION_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// A backend for the state the runtime keeps for components.
+///
+/// Keys are already scoped by the time they reach the backend, so a backend can be shared by every component in an
+/// app, or by several apps.
+pub trait KeyValueStore: std::fmt::Debug + Send + Sync {
+  /// The value stored under `key`, if it's set and hasn't expired.
+  fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Value>, ComponentError>>;
+
+  /// Store `value` under `key`, expiring it after `ttl` if given.
+  fn set<'a>(&'a self, key: &'a str, value: Value, ttl: Option<Duration>) -> BoxFuture<'a, Result<(), ComponentError>>;
+
+  /// Remove `key`, returning whether it was set.
+  fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool, ComponentError>>;
+}
+
+/// Which keys a [KeyValue] handle reads and writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KvScope {
+  /// Keys shared by every component in the app.
+  App,
+  /// Keys private to one of the app's components.
+  Component,
+  /// Keys private to one transaction, which expire after [TRANSACTION_TTL].
+  Transaction,
+}
+
+/// A component's handle to the state the runtime keeps for it.
+///
+/// Handles start out scoped to the component that was invoked; [KeyValue::app] and [KeyValue::transaction] switch to
+/// the keys shared by the whole app or kept for the current transaction.
+#[derive(Clone, Default)]
+#[must_use]
+pub struct KeyValue {
+  store: Option<Arc<dyn KeyValueStore>>,
+  app: String,
+  component: String,
+  tx: String,
+  scope: Option<KvScope>,
+}
+
+impl std::fmt::Debug for KeyValue {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("KeyValue")
+      .field("store", &self.store)
+      .field("app", &self.app)
+      .field("component", &self.component)
+      .field("tx", &self.tx)
+      .field("scope", &self.scope())
+      .finish()
+  }
+}
+
+impl KeyValue {
+  /// A handle to the keys `app` keeps in `store`.
+  pub fn new<T: Into<String>>(store: Arc<dyn KeyValueStore>, app: T) -> Self {
+    Self {
+      store: Some(store),
+      app: app.into(),
+      component: String::new(),
+      tx: String::new(),
+      scope: None,
+    }
+  }
+
+  /// This handle bound to the invocation of `component` in the transaction `tx`.
+  pub fn bind<C: Into<String>, T: Into<String>>(mut self, component: C, tx: T) -> Self {
+    self.component = component.into();
+    self.tx = tx.into();
+    self
+  }
+
+  /// The keys shared by every component in the app.
+  pub const fn app(mut self) -> Self {
+    self.scope = Some(KvScope::App);
+    self
+  }
+
+  /// The keys private to the invoked component. This is the scope handles start out with.
+  pub const fn component(mut self) -> Self {
+    self.scope = Some(KvScope::Component);
+    self
+  }
+
+  /// The keys private to the current transaction.
+  pub const fn transaction(mut self) -> Self {
+    self.scope = Some(KvScope::Transaction);
+    self
+  }
+
+  /// The scope this handle reads and writes.
+  #[must_use]
+  pub fn scope(&self) -> KvScope {
+    self.scope.unwrap_or(KvScope::Component)
+  }
+
+  /// The key `key` is stored under in the backend.
+  fn key(&self, key: &str) -> Result<String, ComponentError> {
+    match self.scope() {
+      KvScope::App => Ok(format!("{}/app/{}", self.app, key)),
+      KvScope::Component if !self.component.is_empty() => {
+        Ok(format!("{}/component/{}/{}", self.app, self.component, key))
+      }
+      KvScope::Transaction if !self.tx.is_empty() => Ok(format!("{}/tx/{}/{}", self.app, self.tx, key)),
+      scope => Err(ComponentError::msg(format!(
+        "{:?} state is only available while handling an invocation",
+        scope
+      ))),
+    }
+  }
+
+  fn store(&self) -> Result<&dyn KeyValueStore, ComponentError> {
+    self
+      .store
+      .as_deref()
+      .ok_or_else(|| ComponentError::msg("the runtime did not provide a state store"))
+  }
+
+  /// The value stored under `key`.
+  pub async fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, ComponentError> {
+    let key = self.key(key)?;
+    self
+      .store()?
+      .get(&key)
+      .await?
+      .map(serde_json::from_value)
+      .transpose()
+      .map_err(ComponentError::new)
+  }
+
+  /// Store `value` under `key`.
+  pub async fn set<T: Serialize + Sync>(&self, key: &str, value: &T) -> Result<(), ComponentError> {
+    let ttl = (self.scope() == KvScope::Transaction).then_some(TRANSACTION_TTL);
+    self.set_value(key, value, ttl).await
+  }
+
+  /// Store `value` under `key` until `ttl` has passed.
+  pub async fn set_with_ttl<T: Serialize + Sync>(
+    &self,
+    key: &str,
+    value: &T,
+    ttl: Duration,
+  ) -> Result<(), ComponentError> {
+    self.set_value(key, value, Some(ttl)).await
+  }
+
+  async fn set_value<T: Serialize + Sync>(
+    &self,
+    key: &str,
+    value: &T,
+    ttl: Option<Duration>,
+  ) -> Result<(), ComponentError> {
+    let key = self.key(key)?;
+    let value = serde_json::to_value(value)?;
+    self.store()?.set(&key, value, ttl).await
+  }
+
+  /// Remove `key`, returning whether it was set.
+  pub async fn delete(&self, key: &str) -> Result<bool, ComponentError> {
+    let key = self.key(key)?;
+    self.store()?.delete(&key).await
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+  use std::sync::Mutex;
+
+  use anyhow::Result;
+
+  use super::*;
+
+  #[derive(Debug, Default)]
+  struct Store(Mutex<HashMap<String, Value>>);
+
+  impl KeyValueStore for Store {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Value>, ComponentError>> {
+      Box::pin(async move { Ok(self.0.lock().unwrap().get(key).cloned()) })
+    }
+
+    fn set<'a>(
+      &'a self,
+      key: &'a str,
+      value: Value,
+      _ttl: Option<Duration>,
+    ) -> BoxFuture<'a, Result<(), ComponentError>> {
+      Box::pin(async move {
+        self.0.lock().unwrap().insert(key.to_owned(), value);
+        Ok(())
+      })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool, ComponentError>> {
+      Box::pin(async move { Ok(self.0.lock().unwrap().remove(key).is_some()) })
+    }
+  }
+
+  #[tokio::test]
+  async fn test_scopes() -> Result<()> {
+    let store = Arc::new(Store::default());
+    let kv = KeyValue::new(store.clone(), "app").bind("cache", "tx1");
+    kv.set("hits", &1).await?;
+    kv.clone().app().set("hits", &2).await?;
+    kv.clone().transaction().set("hits", &3).await?;
+    assert_eq!(kv.get::<u32>("hits").await?, Some(1));
+    assert_eq!(kv.clone().app().get::<u32>("hits").await?, Some(2));
+    assert_eq!(kv.clone().transaction().get::<u32>("hits").await?, Some(3));
+    assert!(kv.delete("hits").await?);
+    assert_eq!(kv.get::<u32>("hits").await?, None);
+    let keys: Vec<_> = store.0.lock().unwrap().keys().cloned().collect();
+    assert!(keys.contains(&"app/app/hits".to_owned()), "{:?}", keys);
+    assert!(keys.contains(&"app/tx/tx1/hits".to_owned()), "{:?}", keys);
+
+    let unbound = KeyValue::new(store, "app");
+    assert!(unbound.get::<u32>("hits").await.is_err());
+    assert!(KeyValue::default().app().get::<u32>("hits").await.is_err());
+    Ok(())
+  }
+}
@@ -114,8 +114,16 @@
 // Add exceptions here
 #![allow()]
 
+#[cfg(feature = "invocation")]
+mod blobs;
+#[cfg(feature = "invocation")]
+pub use blobs::Blobs;
 mod context;
 pub use context::*;
+mod kv;
+pub use kv::*;
+mod observability;
+pub use observability::ObservabilityLevel;
 #[cfg(feature = "invocation")]
 mod traits;
 
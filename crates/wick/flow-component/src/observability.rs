@@ -0,0 +1,118 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use tracing::Span;
+
+use crate::ComponentError;
+
+static LEVEL: AtomicU8 = AtomicU8::new(ObservabilityLevel::Packets as u8);
+
+/// How much detail the runtime records in tracing spans and events.
+///
+/// Each level includes everything recorded by the levels below it. The level is process-wide and can be changed
+/// while the runtime is serving requests.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(clippy::exhaustive_enums)]
+pub enum ObservabilityLevel {
+  /// Don't create any spans.
+  Off = 0,
+  /// Create a span per transaction and log packets that carry errors.
+  Errors = 1,
+  /// Also create a span per operation instance.
+  Ops = 2,
+  /// Also log every packet as it moves through a flow.
+  #[default]
+  Packets = 3,
+}
+
+impl ObservabilityLevel {
+  /// Get the current observability level.
+  #[must_use]
+  pub fn current() -> Self {
+    match LEVEL.load(Ordering::Relaxed) {
+      0 => Self::Off,
+      1 => Self::Errors,
+      2 => Self::Ops,
+      _ => Self::Packets,
+    }
+  }
+
+  /// Set the observability level, returning the previous one.
+  pub fn set(level: Self) -> Self {
+    let previous = Self::current();
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    previous
+  }
+
+  /// Whether the current level records details at this level.
+  #[must_use]
+  pub fn enabled(self) -> bool {
+    self != Self::Off && Self::current() >= self
+  }
+
+  /// Create a span with `f` when this level is enabled, otherwise return a disabled span.
+  #[must_use]
+  pub fn span<F: FnOnce() -> Span>(self, f: F) -> Span {
+    if self.enabled() {
+      f()
+    } else {
+      Span::none()
+    }
+  }
+}
+
+impl std::fmt::Display for ObservabilityLevel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(match self {
+      Self::Off => "off",
+      Self::Errors => "errors",
+      Self::Ops => "ops",
+      Self::Packets => "packets",
+    })
+  }
+}
+
+impl FromStr for ObservabilityLevel {
+  type Err = ComponentError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "off" => Ok(Self::Off),
+      "errors" => Ok(Self::Errors),
+      "ops" => Ok(Self::Ops),
+      "packets" => Ok(Self::Packets),
+      _ => Err(anyhow::anyhow!(
+        "invalid observability level '{}', expected one of off, errors, ops, or packets",
+        s
+      )),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_levels() -> Result<()> {
+    let previous = ObservabilityLevel::set(ObservabilityLevel::Ops);
+    assert!(ObservabilityLevel::Errors.enabled());
+    assert!(ObservabilityLevel::Ops.enabled());
+    assert!(!ObservabilityLevel::Packets.enabled());
+    assert!(ObservabilityLevel::Packets
+      .span(|| tracing::info_span!("packet"))
+      .is_none());
+
+    ObservabilityLevel::set(ObservabilityLevel::Off);
+    assert!(!ObservabilityLevel::Errors.enabled());
+    assert!(!ObservabilityLevel::Off.enabled());
+
+    ObservabilityLevel::set(previous);
+    assert_eq!("OPS".parse::<ObservabilityLevel>()?, ObservabilityLevel::Ops);
+    assert!("verbose".parse::<ObservabilityLevel>().is_err());
+    Ok(())
+  }
+}
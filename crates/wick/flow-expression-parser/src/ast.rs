@@ -201,12 +201,42 @@ impl From<TargetId> for Option<String> {
   }
 }
 
+/// How the packets of several connections into the same input port are merged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[allow(clippy::exhaustive_enums)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStrategy {
+  /// Every packet from the first connection, then every packet from the next, in the order the connections are declared.
+  Ordered,
+  /// One packet from each connection in turn, skipping connections that have finished.
+  RoundRobin,
+  /// The nth packet from each connection together, stopping when any connection finishes.
+  Zip,
+  /// Waiting packets from earlier-declared connections before those from later ones.
+  Priority,
+}
+
+impl std::fmt::Display for MergeStrategy {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(match self {
+      MergeStrategy::Ordered => "ordered",
+      MergeStrategy::RoundRobin => "round-robin",
+      MergeStrategy::Zip => "zip",
+      MergeStrategy::Priority => "priority",
+    })
+  }
+}
+
 /// A connection between two targets.
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
 #[must_use]
 pub struct ConnectionExpression {
   from: ConnectionTargetExpression,
   to: ConnectionTargetExpression,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  merge: Option<MergeStrategy>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  transform: Option<LiquidJsonValue>,
 }
 
 impl std::fmt::Display for ConnectionExpression {
@@ -221,7 +251,38 @@ impl ConnectionExpression {
     from.instance = from.instance.or(InstanceTarget::Input);
     to.instance = to.instance.or(InstanceTarget::Output);
 
-    Self { from, to }
+    Self {
+      from,
+      to,
+      merge: None,
+      transform: None,
+    }
+  }
+
+  /// Set how packets from this and other connections into the same input port are merged.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_merge(mut self, merge: Option<MergeStrategy>) -> Self {
+    self.merge = merge;
+    self
+  }
+
+  /// How packets from this and other connections into the same input port are merged, if set.
+  #[must_use]
+  pub const fn merge(&self) -> Option<MergeStrategy> {
+    self.merge
+  }
+
+  /// Set the template that reshapes each packet's value on its way across this connection.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_transform(mut self, transform: Option<LiquidJsonValue>) -> Self {
+    self.transform = transform;
+    self
+  }
+
+  /// The template that reshapes each packet's value on its way across this connection, if set.
+  #[must_use]
+  pub const fn transform(&self) -> Option<&LiquidJsonValue> {
+    self.transform.as_ref()
   }
 
   /// Get the owned parts of the connection.
@@ -1,4 +1,5 @@
 mod error;
+mod hash;
 mod helpers;
 mod operation_settings;
 pub(crate) mod types;
@@ -12,8 +13,11 @@ use flow_expression_parser::ast::{
   FlowExpression,
   InstancePort,
   InstanceTarget,
+  MergeStrategy,
 };
 use flow_graph::NodeReference;
+pub(crate) use hash::content_hash;
+use liquid_json::LiquidJsonValue;
 use serde_json::Value;
 use types::*;
 use wick_config::config::components::{ComponentConfig, OperationConfig};
@@ -62,7 +66,16 @@ fn register_operation(
     register_operation(scope, network, flow, handlers, op_config_base)?;
   }
   let name = scope.join("::");
-  let mut schematic = Schematic::new(name, Default::default(), Default::default());
+  let input_settings = OperationSettings {
+    defaults: flow
+      .inputs()
+      .iter()
+      .filter(|f| f.default().is_some())
+      .cloned()
+      .collect(),
+    ..Default::default()
+  };
+  let mut schematic = Schematic::new(name, input_settings, Default::default());
   let mut ids = flow.instances().keys().cloned().collect::<Vec<_>>();
   ids.sort();
 
@@ -86,6 +99,17 @@ fn register_operation(
     process_flow_expression(&mut schematic, expression, handlers)?;
   }
 
+  // an input port can only be fed by several connections when it says how to merge them.
+  for node in schematic.nodes() {
+    if let Some(port) = node
+      .inputs()
+      .iter()
+      .find(|port| port.connections().len() > 1 && node.data().merge(port.name()).is_none())
+    {
+      return Err(flow_graph::error::Error::MultipleInputConnections(port.to_string()).into());
+    }
+  }
+
   network.add_schematic(schematic);
   Ok(())
 }
@@ -118,15 +142,18 @@ fn process_connection_expression(
     "Missing downstream port for expr: {:?}",
     expr
   );
-  let to_port = schematic
-    .find_mut(to.instance().id().unwrap())
-    .map(|component| component.add_input(to.port().name().unwrap()));
-
-  if to_port.is_none() {
+  let Some(downstream) = schematic.find_mut(to.instance().id().unwrap()) else {
     error!("missing downstream: instance {:?}", to);
     return Err(GraphError::missing_downstream(to.instance().id().unwrap()));
+  };
+  let port_name = to.port().name().unwrap();
+  let to_port = downstream.add_input(port_name);
+  if let Some(merge) = expr.merge() {
+    downstream
+      .data_mut()
+      .set_merge(port_name, merge)
+      .map_err(|existing| GraphError::merge_conflict(to.instance(), port_name, existing, merge))?;
   }
-  let to_port = to_port.unwrap();
 
   if let Some(component) = schematic.find_mut(from.instance().id().unwrap()) {
     let from_port = component.add_output(from.port().name().unwrap());
@@ -207,6 +234,7 @@ fn expand_expressions(
     .collect::<Result<HashMap<_, _>, _>>()?;
   add_nodes_to_schematic(schematic, flow.expressions_mut(), handlers, &config_map, inline_id)?;
   let result = result.update(expand_port_paths(schematic, flow.expressions_mut())?);
+  let result = result.update(expand_transforms(schematic, flow.expressions_mut(), inline_id)?);
   let result = result.update(expand_defaulted_ports(schematic, flow.expressions_mut())?);
   Ok(result)
 }
@@ -248,14 +276,35 @@ fn add_nodes_to_schematic(
   Ok(())
 }
 
+/// What a connection keeps when it's rewritten, e.g. when its ports are inferred.
+#[derive(Debug, Clone, Default)]
+struct ConnectionSettings {
+  merge: Option<MergeStrategy>,
+  transform: Option<LiquidJsonValue>,
+}
+
+impl ConnectionSettings {
+  fn of(expr: &ConnectionExpression) -> Self {
+    Self {
+      merge: expr.merge(),
+      transform: expr.transform().cloned(),
+    }
+  }
+}
+
 fn connection(
   from: (InstanceTarget, impl Into<InstancePort>),
   to: (InstanceTarget, impl Into<InstancePort>),
+  settings: ConnectionSettings,
 ) -> FlowExpression {
-  FlowExpression::connection(ConnectionExpression::new(
-    ConnectionTargetExpression::new(from.0, from.1),
-    ConnectionTargetExpression::new(to.0, to.1),
-  ))
+  FlowExpression::connection(
+    ConnectionExpression::new(
+      ConnectionTargetExpression::new(from.0, from.1),
+      ConnectionTargetExpression::new(to.0, to.1),
+    )
+    .with_merge(settings.merge)
+    .with_transform(settings.transform),
+  )
 }
 
 #[allow(clippy::option_if_let_else, clippy::too_many_lines, clippy::cognitive_complexity)]
@@ -267,6 +316,7 @@ fn expand_defaulted_ports(
   for (_i, expression) in expressions.iter_mut().enumerate() {
     match expression {
       FlowExpression::ConnectionExpression(expr) => {
+        let settings = ConnectionSettings::of(expr);
         let (from, to) = expr.clone().into_parts();
         let (from_inst, from_port, _) = from.into_parts();
         let (to_inst, to_port, _) = to.into_parts();
@@ -290,7 +340,7 @@ fn expand_defaulted_ports(
               let from_port = from_node_ports[0].name();
               let to_port = to_node_ports[0].name();
               debug!(from = %from_inst, from_port,to = %to_inst, to_port, reason="unary", "graph:inferred ports");
-              expression.replace(connection((from_inst, from_port), (to_inst, to_port)));
+              expression.replace(connection((from_inst, from_port), (to_inst, to_port), settings.clone()));
               result = ExpandResult::Continue;
               continue;
             }
@@ -301,13 +351,21 @@ fn expand_defaulted_ports(
               for port in to_node_ports {
                 let port_name = port.name();
                 debug!(from = %from_inst, from_port=port_name,to = %to_inst, to_port=port_name, reason="upstream_default", "graph:inferred ports");
-                new_connections.push(connection((from_inst.clone(), port_name), (to_inst.clone(), port_name)));
+                new_connections.push(connection(
+                  (from_inst.clone(), port_name),
+                  (to_inst.clone(), port_name),
+                  settings.clone(),
+                ));
               }
             } else if matches!(to_inst, InstanceTarget::Output | InstanceTarget::Default) {
               for port in from_node_ports {
                 let port_name = port.name();
                 debug!(from = %from_inst, from_port=port_name,to = %to_inst, to_port=port_name, reason="downstream_default", "graph:inferred ports");
-                new_connections.push(connection((from_inst.clone(), port_name), (to_inst.clone(), port_name)));
+                new_connections.push(connection(
+                  (from_inst.clone(), port_name),
+                  (to_inst.clone(), port_name),
+                  settings.clone(),
+                ));
               }
             } else {
               for port in from_node_ports {
@@ -325,6 +383,7 @@ fn expand_defaulted_ports(
                 new_connections.push(connection(
                   (from_inst.clone(), port.name()),
                   (to_inst.clone(), port.name()),
+                  settings.clone(),
                 ));
               }
             }
@@ -343,12 +402,20 @@ fn expand_defaulted_ports(
             );
             // if we're at a schematic input node, adopt the name of what we're pointing to.
             if matches!(from_inst, InstanceTarget::Input | InstanceTarget::Default) {
-              expression.replace(connection((from_inst, port_name), (to_inst, to_port.clone())));
+              expression.replace(connection(
+                (from_inst, port_name),
+                (to_inst, to_port.clone()),
+                settings.clone(),
+              ));
               result = ExpandResult::Continue;
               continue;
             }
             if ports.len() == 1 {
-              expression.replace(connection((from_inst, ports[0].name()), (to_inst, to_port.clone())));
+              expression.replace(connection(
+                (from_inst, ports[0].name()),
+                (to_inst, to_port.clone()),
+                settings.clone(),
+              ));
               result = ExpandResult::Continue;
               continue;
             }
@@ -358,7 +425,11 @@ fn expand_defaulted_ports(
             }
 
             result = ExpandResult::Continue;
-            expression.replace(connection((from_inst, port_name), (to_inst, to_port.clone())));
+            expression.replace(connection(
+              (from_inst, port_name),
+              (to_inst, to_port.clone()),
+              settings.clone(),
+            ));
           }
           (from_port, InstancePort::None) => {
             let port_name = from_port.name().unwrap();
@@ -371,13 +442,21 @@ fn expand_defaulted_ports(
 
             // if we're at a schematic input node, adopt the name of what we're pointing to.
             if matches!(to_inst, InstanceTarget::Output | InstanceTarget::Default) {
-              expression.replace(connection((from_inst, from_port.clone()), (to_inst, port_name)));
+              expression.replace(connection(
+                (from_inst, from_port.clone()),
+                (to_inst, port_name),
+                settings.clone(),
+              ));
               result = ExpandResult::Continue;
               continue;
             }
 
             if ports.len() == 1 {
-              expression.replace(connection((from_inst, from_port.clone()), (to_inst, ports[0].name())));
+              expression.replace(connection(
+                (from_inst, from_port.clone()),
+                (to_inst, ports[0].name()),
+                settings.clone(),
+              ));
               result = ExpandResult::Continue;
               continue;
             }
@@ -387,7 +466,11 @@ fn expand_defaulted_ports(
             }
 
             result = ExpandResult::Continue;
-            expression.replace(connection((from_inst, from_port.clone()), (to_inst, port_name)));
+            expression.replace(connection(
+              (from_inst, from_port.clone()),
+              (to_inst, port_name),
+              settings.clone(),
+            ));
           }
           _ => continue,
         }
@@ -409,6 +492,7 @@ fn expand_port_paths(
   for (i, expression) in expressions.iter_mut().enumerate() {
     match expression {
       FlowExpression::ConnectionExpression(expr) => {
+        let settings = ConnectionSettings::of(expr);
         let (from, to) = expr.clone().into_parts();
         let (from_inst, from_port, _) = from.into_parts();
         let (to_inst, to_port, _) = to.into_parts();
@@ -427,8 +511,16 @@ fn expand_port_paths(
           core::pluck::Op::decorate(node).map_err(|e| GraphError::config(id.clone(), e))?;
 
           expression.replace(FlowExpression::block(BlockExpression::new(vec![
-            connection((from_inst, &name), (InstanceTarget::named(&id), InstancePort::None)),
-            connection((InstanceTarget::named(&id), InstancePort::None), (to_inst, to_port)),
+            connection(
+              (from_inst, &name),
+              (InstanceTarget::named(&id), InstancePort::None),
+              ConnectionSettings::default(),
+            ),
+            connection(
+              (InstanceTarget::named(&id), InstancePort::None),
+              (to_inst, to_port),
+              settings,
+            ),
           ])));
           result = ExpandResult::Continue;
         }
@@ -441,6 +533,61 @@ fn expand_port_paths(
   Ok(result)
 }
 
+/// Replace connections that transform their packets with a connection into a `core::transform` operation and one
+/// out of it.
+fn expand_transforms(
+  schematic: &mut Schematic,
+  expressions: &mut [FlowExpression],
+  inline_id: &mut usize,
+) -> Result<ExpandResult, GraphError> {
+  let mut result = ExpandResult::Done;
+  for expression in expressions.iter_mut() {
+    match expression {
+      FlowExpression::ConnectionExpression(expr) => {
+        let Some(transform) = expr.transform() else {
+          continue;
+        };
+        *inline_id += 1;
+        let id = format!("{}_transform_{}", schematic.name(), inline_id);
+        let template = serde_json::to_value(transform).map_err(|e| GraphError::config(id.clone(), e.to_string()))?;
+        let config = HashMap::from([("template".to_owned(), template)]);
+
+        let node = schematic.add_and_get_mut(
+          &id,
+          NodeReference::new("core", "transform"),
+          OperationSettings::new(Some(RuntimeConfig::from(config)).into(), None),
+        );
+        core::transform::Op::decorate(node).map_err(|e| GraphError::config(id.clone(), e))?;
+
+        let settings = ConnectionSettings {
+          merge: expr.merge(),
+          transform: None,
+        };
+        let (from, to) = expr.clone().into_parts();
+        let (from_inst, from_port, _) = from.into_parts();
+        let (to_inst, to_port, _) = to.into_parts();
+        expression.replace(FlowExpression::block(BlockExpression::new(vec![
+          connection(
+            (from_inst, from_port),
+            (InstanceTarget::named(&id), InstancePort::None),
+            ConnectionSettings::default(),
+          ),
+          connection(
+            (InstanceTarget::named(&id), InstancePort::None),
+            (to_inst, to_port),
+            settings,
+          ),
+        ])));
+        result = ExpandResult::Continue;
+      }
+      FlowExpression::BlockExpression(expressions) => {
+        result = result.update(expand_transforms(schematic, expressions.inner_mut(), inline_id)?);
+      }
+    }
+  }
+  Ok(result)
+}
+
 pub fn from_def(
   manifest: &mut wick_config::config::ComponentConfiguration,
   handlers: &HandlerMap,
@@ -1,4 +1,4 @@
-use flow_expression_parser::ast::InstanceTarget;
+use flow_expression_parser::ast::{InstanceTarget, MergeStrategy};
 use flow_graph::NodePort;
 
 #[derive(thiserror::Error, Debug, Clone, PartialEq)]
@@ -32,6 +32,12 @@ pub enum Error {
   Config(String, String),
   #[error("Invalid config for core operation '{0}': {1}")]
   CoreOperation(String, String),
+  #[error("Connections into '{port}' set conflicting merge strategies '{first}' and '{second}'")]
+  MergeConflict {
+    port: String,
+    first: MergeStrategy,
+    second: MergeStrategy,
+  },
 }
 
 impl Error {
@@ -84,4 +90,16 @@ impl Error {
   pub(crate) fn core_operation<T: Into<String>, J: Into<String>>(id: T, err: J) -> Self {
     Error::CoreOperation(id.into(), err.into())
   }
+  pub(crate) fn merge_conflict<T: std::fmt::Display>(
+    to: &InstanceTarget,
+    to_port: T,
+    first: MergeStrategy,
+    second: MergeStrategy,
+  ) -> Self {
+    Error::MergeConflict {
+      port: format!("{}.{}", to, to_port),
+      first,
+      second,
+    }
+  }
 }
@@ -0,0 +1,168 @@
+use serde_json::{json, Value};
+
+use super::types::*;
+use super::OperationSettings;
+
+/// Compute a stable hash of a schematic's nodes, connections, and configuration.
+///
+/// Nodes and connections are identified by name rather than by index so that the hash only changes when the
+/// schematic's behavior could change, not when the same flow is written in a different order.
+pub(crate) fn content_hash(schematic: &Schematic) -> String {
+  sha256::digest(canonicalize(describe(schematic)).to_string())
+}
+
+fn describe(schematic: &Schematic) -> Value {
+  let mut nodes: Vec<_> = schematic
+    .nodes()
+    .iter()
+    .map(|node| {
+      let mut inputs: Vec<_> = node.inputs().iter().map(|p| p.name()).collect();
+      inputs.sort_unstable();
+      let mut outputs: Vec<_> = node.outputs().iter().map(|p| p.name()).collect();
+      outputs.sort_unstable();
+      // The order of the connections into a merged input is part of its behavior.
+      let merge: serde_json::Map<String, Value> = node
+        .inputs()
+        .iter()
+        .filter_map(|port| {
+          let strategy = node.data().merge(port.name())?;
+          let upstreams: Vec<_> = port
+            .connections()
+            .iter()
+            .map(|index| {
+              let from = schematic.connections()[*index].from();
+              format!(
+                "{}.{}",
+                schematic.nodes()[from.node_index()].id(),
+                schematic.get_port_name(from)
+              )
+            })
+            .collect();
+          Some((port.name().to_owned(), json!([strategy, upstreams])))
+        })
+        .collect();
+      let mut description = json!({
+        "name": node.id(),
+        "kind": node.kind().to_string(),
+        "inputs": inputs,
+        "outputs": outputs,
+        "data": describe_data(node.data()),
+      });
+      if !merge.is_empty() {
+        description["merge"] = Value::Object(merge);
+      }
+      (node.id(), description)
+    })
+    .collect();
+  nodes.sort_by(|a, b| a.0.cmp(b.0));
+
+  let mut connections: Vec<_> = schematic
+    .connections()
+    .iter()
+    .map(|c| {
+      format!(
+        "{}.{}->{}.{}",
+        schematic.nodes()[c.from().node_index()].id(),
+        schematic.get_port_name(c.from()),
+        schematic.nodes()[c.to().node_index()].id(),
+        schematic.get_port_name(c.to()),
+      )
+    })
+    .collect();
+  connections.sort_unstable();
+
+  json!({
+    "name": schematic.name(),
+    "nodes": nodes.into_iter().map(|(_, node)| node).collect::<Vec<_>>(),
+    "connections": connections,
+  })
+}
+
+fn describe_data(data: &OperationSettings) -> Value {
+  json!({
+    "root": data.config.root(),
+    "config": data.config.op_config(),
+    "template": data.config.template(),
+    "settings": data.settings.as_ref().and_then(|s| s.timeout_millis()),
+    "retry": data.settings.as_ref().and_then(|s| s.retry.as_ref()),
+  })
+}
+
+/// Rebuild a JSON value with object keys in sorted order, regardless of how the map was populated.
+fn canonicalize(value: Value) -> Value {
+  match value {
+    Value::Object(map) => {
+      let mut entries: Vec<_> = map.into_iter().collect();
+      entries.sort_by(|a, b| a.0.cmp(&b.0));
+      Value::Object(entries.into_iter().map(|(k, v)| (k, canonicalize(v))).collect())
+    }
+    Value::Array(list) => Value::Array(list.into_iter().map(canonicalize).collect()),
+    value => value,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use flow_expression_parser::ast::MergeStrategy;
+  use flow_graph::{NodeReference, SCHEMATIC_INPUT, SCHEMATIC_OUTPUT};
+  use wick_packet::RuntimeConfig;
+
+  use super::*;
+
+  fn schematic(reverse: bool, config: Option<&RuntimeConfig>) -> Result<Schematic> {
+    let mut schematic = Schematic::new("test", Default::default(), Default::default());
+    let mut names = vec!["first", "second"];
+    if reverse {
+      names.reverse();
+    }
+    for name in &names {
+      schematic.add_external(
+        *name,
+        NodeReference::new("test", "op"),
+        OperationSettings::new(config.cloned().into(), None),
+      );
+    }
+    let input = schematic.find_mut(SCHEMATIC_INPUT).unwrap().add_output("input");
+    let output = schematic.find_mut(SCHEMATIC_OUTPUT).unwrap().add_input("output");
+    for name in names {
+      let node = schematic.find_mut(name).unwrap();
+      let node_in = node.add_input("input");
+      let node_out = node.add_output("output");
+      schematic.connect(input, node_in, Default::default())?;
+      schematic.connect(node_out, output, Default::default())?;
+    }
+    Ok(schematic)
+  }
+
+  #[test]
+  fn test_content_hash_is_stable() -> Result<()> {
+    let config = Some(RuntimeConfig::from(HashMap::from([("a".to_owned(), json!(1))])));
+    let hash = content_hash(&schematic(false, config.as_ref())?);
+    assert_eq!(hash, content_hash(&schematic(false, config.as_ref())?));
+    assert_eq!(hash, content_hash(&schematic(true, config.as_ref())?));
+
+    let changed = Some(RuntimeConfig::from(HashMap::from([("a".to_owned(), json!(2))])));
+    assert_ne!(hash, content_hash(&schematic(false, changed.as_ref())?));
+    assert_ne!(hash, content_hash(&schematic(false, None)?));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_content_hash_merge_order() -> Result<()> {
+    let merged = |reverse| -> Result<String> {
+      let mut schematic = schematic(reverse, None)?;
+      let output = schematic.find_mut(SCHEMATIC_OUTPUT).unwrap();
+      output.data_mut().set_merge("output", MergeStrategy::Ordered).unwrap();
+      Ok(content_hash(&schematic))
+    };
+    assert_ne!(merged(false)?, content_hash(&schematic(false, None)?));
+    // with a merge strategy, the order the connections were declared in matters.
+    assert_ne!(merged(false)?, merged(true)?);
+
+    Ok(())
+  }
+}
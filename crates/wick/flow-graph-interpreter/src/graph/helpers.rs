@@ -101,6 +101,11 @@ pub(super) fn decorate(
       components::core::log::Op::ID => components::core::log::Op::decorate(node),
       components::core::sender::Op::ID => components::core::sender::Op::decorate(node),
       components::core::switch::Op::ID => components::core::switch::Op::decorate(node),
+      components::core::digest::Op::ID => components::core::digest::Op::decorate(node),
+      components::core::verify::Op::ID => components::core::verify::Op::decorate(node),
+      components::core::filter::Op::ID => components::core::filter::Op::decorate(node),
+      components::core::for_each::Op::ID => components::core::for_each::Op::decorate(node),
+      components::core::transform::Op::ID => components::core::transform::Op::decorate(node),
       _ => {
         panic!("unhandled core component operation: {}", operation);
       }
@@ -110,6 +115,9 @@ pub(super) fn decorate(
   } else if component == components::self_component::SelfComponent::ID {
     debug!("skipping {} component at this stage", component);
     return Ok(());
+  } else if component == components::flow::FlowComponent::ID {
+    debug!("skipping {} component at this stage", component);
+    return Ok(());
   } else if component == components::null::NullComponent::ID {
     debug!("skipping {} component at this stage", component);
     return Ok(());
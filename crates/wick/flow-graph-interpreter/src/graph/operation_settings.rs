@@ -1,4 +1,9 @@
+use std::collections::HashMap;
+
+use flow_expression_parser::ast::MergeStrategy;
+use serde_json::Value;
 use wick_config::config::{ExecutionSettings, LiquidJsonConfig};
+use wick_interface_types::Field;
 use wick_packet::{InherentData, RuntimeConfig};
 
 use crate::error::InterpreterError;
@@ -7,12 +12,38 @@ use crate::error::InterpreterError;
 pub struct OperationSettings {
   pub(crate) config: LiquidOperationConfig,
   pub(crate) settings: Option<ExecutionSettings>,
+  pub(crate) merge: HashMap<String, MergeStrategy>,
+  /// The declared inputs with a default value. Only set on a schematic's input node.
+  pub(crate) defaults: Vec<Field>,
 }
 
 impl OperationSettings {
   /// Initialize a new OperationSettings with the specified config and settings.
-  pub(crate) const fn new(config: LiquidOperationConfig, settings: Option<ExecutionSettings>) -> Self {
-    Self { config, settings }
+  pub(crate) fn new(config: LiquidOperationConfig, settings: Option<ExecutionSettings>) -> Self {
+    Self {
+      config,
+      settings,
+      merge: HashMap::new(),
+      defaults: Vec::new(),
+    }
+  }
+
+  /// The value the input `port` takes when its upstream finishes without sending any data, if one was declared.
+  pub(crate) fn port_default(&self, port: &str) -> Option<&Value> {
+    self.defaults.iter().find(|f| f.name == port).and_then(|f| f.default())
+  }
+
+  /// The strategy for merging the connections into the input `port`, if one was set.
+  pub(crate) fn merge(&self, port: &str) -> Option<MergeStrategy> {
+    self.merge.get(port).copied()
+  }
+
+  /// Set how the connections into the input `port` are merged, returning the strategy already set if it differs.
+  pub(crate) fn set_merge(&mut self, port: &str, strategy: MergeStrategy) -> Result<(), MergeStrategy> {
+    match self.merge.insert(port.to_owned(), strategy) {
+      Some(existing) if existing != strategy => Err(existing),
+      _ => Ok(()),
+    }
   }
 }
 
@@ -4,6 +4,7 @@ pub(crate) mod error;
 pub(crate) mod event_loop;
 pub(crate) mod executor;
 pub(crate) mod program;
+pub(crate) mod replay;
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -13,21 +14,27 @@ use flow_component::{Component, ComponentError, LocalScope};
 use futures::{FutureExt, TryFutureExt};
 use parking_lot::Mutex;
 use tracing::{info_span, Span};
+use uuid::Uuid;
 use wick_interface_types::ComponentSignature;
-use wick_packet::{Entity, Invocation, PacketStream, RuntimeConfig};
+use wick_packet::{Entity, InherentData, Invocation, Packet, PacketStream, RuntimeConfig};
 
 use self::channel::InterpreterDispatchChannel;
 use self::components::HandlerMap;
 use self::error::Error;
 use self::event_loop::EventLoop;
+use self::program::validator::coercion::CoercionRules;
 use self::program::Program;
 use crate::graph::types::*;
 use crate::interpreter::channel::InterpreterChannel;
 use crate::interpreter::components::component::ComponentComponent;
 use crate::interpreter::components::null::NullComponent;
 use crate::interpreter::components::self_component::SelfComponent;
+use crate::interpreter::executor::context::chaos::ChaosPolicy;
+use crate::interpreter::executor::context::checkpoint::Durability;
+use crate::interpreter::executor::context::dead_letter::DeadLetterPolicy;
+use crate::interpreter::executor::context::{LineageStore, Resumptions, TransactionLineage};
 use crate::interpreter::executor::error::ExecutionError;
-use crate::{NamespaceHandler, Observer};
+use crate::{EventListener, NamespaceHandler, Observer};
 
 #[must_use]
 #[derive()]
@@ -41,6 +48,9 @@ pub struct Interpreter {
   namespace: Option<String>,
   callback: LocalScope,
   exposed_ops: HashMap<String, NamespaceHandler>, // A map from op name to the ns of the handler that exposes it.
+  lineage: LineageStore,
+  durability: Option<Durability>,
+  resumptions: Resumptions,
   span: Span,
 }
 
@@ -63,6 +73,7 @@ impl Interpreter {
     components: Option<HandlerMap>,
     callback: LocalScope,
     root_config: Option<&RuntimeConfig>,
+    coercions: Option<CoercionRules>,
     parent_span: &Span,
   ) -> Result<Self, Error> {
     let span = info_span!(parent: parent_span, "interpreter");
@@ -96,12 +107,13 @@ impl Interpreter {
     ))?;
 
     handlers.add_core(&network)?;
+    handlers.add_flow(&network)?;
 
     let mut signatures = handlers.component_signatures();
     program::generate_self_signature(&network, &mut signatures).map_err(Error::EarlyError)?;
     let program = Program::new(network, signatures)?;
 
-    program.validate()?;
+    program.validate(&coercions.unwrap_or_default())?;
 
     let channel = InterpreterChannel::new();
     let dispatcher = channel.dispatcher(Some(span.clone()));
@@ -137,12 +149,16 @@ impl Interpreter {
       namespace,
       exposed_ops,
       callback,
+      lineage: LineageStore::new(LineageStore::DEFAULT_CAPACITY),
+      durability: None,
+      resumptions: Resumptions::default(),
       span,
     })
   }
 
   fn get_callback(&self) -> LocalScope {
     let outside_callback = self.callback.clone();
+    let kv = outside_callback.kv();
     let internal_components = self.components.clone();
     let self_component = self.self_component.clone();
 
@@ -157,6 +173,13 @@ impl Interpreter {
       let span = span.clone();
       Box::pin(async move {
         span.in_scope(|| trace!(op, %compref, "invoke:component reference"));
+        if inherent.remaining() == Some(Duration::ZERO) {
+          return Err(ComponentError::new(wick_packet::Error::BudgetExceeded(format!(
+            "{}::{} was not invoked, the caller's deadline has passed",
+            compref.get_target_id(),
+            op
+          ))));
+        }
         if compref.get_target_id() == SelfComponent::ID {
           span.in_scope(|| trace!(op, %compref, "handling component invocation for self"));
           let cb = inner_cb.lock().clone().unwrap();
@@ -166,12 +189,14 @@ impl Interpreter {
           span.in_scope(|| trace!(op, %compref, "handling component invocation internal to this interpreter"));
           let cb = inner_cb.lock().clone().unwrap();
           let invocation = compref.to_invocation(&op, stream, inherent, &span);
+          let cb = cb.for_invocation(&invocation);
           handler.component().handle(invocation, config, cb).await
         } else {
           outer_scope.invoke(compref, op, stream, inherent, config, &span).await
         }
       })
-    }));
+    }))
+    .with_kv(kv);
     scope_hack.lock().replace(scope_proxy.clone());
     scope_proxy
   }
@@ -191,7 +216,81 @@ impl Interpreter {
     options: Option<InterpreterOptions>,
     observer: Option<Box<dyn Observer + Send + Sync>>,
   ) {
-    self.event_loop.start(options.unwrap_or_default(), observer).await;
+    let options = options.unwrap_or_default();
+    self.durability = options.durability.clone();
+    self
+      .event_loop
+      .start(options, observer, self.lineage.clone(), self.resumptions.clone())
+      .await;
+  }
+
+  /// Resume the transactions left unfinished in the [InterpreterOptions::durability] store, e.g. by a runtime that
+  /// crashed, returning the id and output stream of each.
+  ///
+  /// A resumed transaction gets the origin and inputs it received before, with any input that hadn't finished closed.
+  /// The operations that had finished emit their recorded output again, and the rest are called from the start.
+  pub async fn resume(&self) -> Result<Vec<(Uuid, PacketStream)>, Error> {
+    let Some(durability) = &self.durability else {
+      return Ok(Vec::new());
+    };
+    let checkpoints = durability
+      .store
+      .load()
+      .await
+      .map_err(|e| Error::Checkpoint(e.to_string()))?;
+    let mut resumed = Vec::with_capacity(checkpoints.len());
+    for checkpoint in checkpoints {
+      let tx_id = checkpoint.tx_id;
+      self
+        .span
+        .in_scope(|| debug!(%tx_id, operation = %checkpoint.operation, "resuming transaction"));
+      // The caller that was still streaming inputs is gone, so close the inputs it hadn't finished.
+      let mut inputs = checkpoint.inputs.clone();
+      if let Some(schematic) = self.program.operations().iter().find(|s| s.name() == checkpoint.operation) {
+        inputs.extend(
+          schematic
+            .input()
+            .outputs()
+            .iter()
+            .filter(|port| !checkpoint.done.contains(port.name()))
+            .map(|port| Packet::done(port.name())),
+        );
+      }
+      let invocation = Invocation::new_with_id(
+        tx_id,
+        checkpoint.origin.clone(),
+        Entity::local(&checkpoint.operation),
+        PacketStream::from(inputs),
+        InherentData::new(checkpoint.seed, checkpoint.timestamp),
+        &self.span,
+      );
+      let config = checkpoint.config.clone();
+      self.resumptions.insert(checkpoint);
+      resumed.push((tx_id, self.invoke(invocation, config).await?));
+    }
+    Ok(resumed)
+  }
+
+  /// Get the packet lineage recorded for a recent transaction.
+  ///
+  /// Lineage is only recorded when the interpreter is started with [InterpreterOptions::track_lineage].
+  #[must_use]
+  pub fn lineage(&self, tx_id: Uuid) -> Option<Arc<TransactionLineage>> {
+    self.lineage.get(tx_id)
+  }
+
+  /// Cancel the transaction `tx_id`, including any nested flows it invoked on this interpreter.
+  ///
+  /// Operations still running are aborted, their inputs are closed, and every output of the transaction ends with a
+  /// [wick_packet::PacketError::CANCELLED] error. Cancelling a transaction that already finished does nothing.
+  pub fn cancel(&self, tx_id: Uuid) {
+    self.dispatcher.dispatch_cancel(tx_id);
+  }
+
+  /// The number of events waiting to be processed by the event loop.
+  #[must_use]
+  pub fn backlog(&self) -> usize {
+    self.dispatcher.backlog()
   }
 
   pub async fn stop(&self) -> Result<(), Error> {
@@ -223,6 +322,13 @@ impl Interpreter {
   pub fn render_dotviz(&self, op: &str) -> Result<String, Error> {
     self.program.dotviz(op)
   }
+
+  /// Get a stable hash of the compiled graph for an operation, including its nodes, connections, and configuration.
+  ///
+  /// The hash only changes when the operation's definition does, so it can be used to detect configuration drift.
+  pub fn content_hash(&self, op: &str) -> Result<String, Error> {
+    self.program.content_hash(op)
+  }
 }
 
 impl Component for Interpreter {
@@ -253,6 +359,7 @@ impl Component for Interpreter {
               let new_target = Entity::operation(handler.namespace(), invocation.target().operation_id());
               span.in_scope(|| trace!(origin=%invocation.origin(),original_target=%invocation.target(), %new_target, "invoke::exposed::operation"));
               invocation = invocation.redirect(new_target);
+              let cb = cb.for_invocation(&invocation);
               return handler.component.handle(invocation, config, cb).await;
             }
             span.in_scope(
@@ -266,6 +373,7 @@ impl Component for Interpreter {
             span.in_scope(
               || trace!(origin=%invocation.origin(),target=%invocation.target(), "invoke::handler::operation"),
             );
+            let cb = cb.for_invocation(&invocation);
             handler.component.handle(invocation, config, cb).await?
           } else {
             return Err(ComponentError::new(Error::TargetNotFound(
@@ -296,12 +404,28 @@ impl Component for Interpreter {
 pub struct InterpreterOptions {
   /// Timeout after which a component that has received no output is considered dead.
   pub output_timeout: Duration,
+  /// Record the lineage of packets in recent transactions for debugging.
+  pub track_lineage: bool,
+  /// Receive a structured event for every packet accepted, operation started or finished, error, and completed
+  /// transaction, e.g. to persist an audit trail.
+  pub event_listener: Option<EventListener>,
+  /// What to do with packets sent to an input that a flow doesn't connect to anything.
+  pub dead_letter: DeadLetterPolicy,
+  /// Inject randomized faults into operation calls to test how flows cope with failing components.
+  pub chaos: Option<ChaosPolicy>,
+  /// Checkpoint transactions so the ones a crash interrupts can be finished with [Interpreter::resume].
+  pub durability: Option<Durability>,
 }
 
 impl Default for InterpreterOptions {
   fn default() -> Self {
     Self {
       output_timeout: Duration::from_secs(500),
+      track_lineage: false,
+      event_listener: None,
+      dead_letter: DeadLetterPolicy::default(),
+      chaos: None,
+      durability: None,
     }
   }
 }
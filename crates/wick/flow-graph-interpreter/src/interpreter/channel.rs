@@ -50,6 +50,8 @@ pub enum EventKind {
   PortData(PortReference),
   Invocation(NodeIndex, Box<Invocation>),
   CallComplete(CallComplete),
+  /// Cancel every execution of the transaction identified by the event's `ctx_id`.
+  Cancel,
   Close(Option<ExecutionError>),
 }
 
@@ -62,6 +64,7 @@ impl EventKind {
       EventKind::PortData(_) => "port_data",
       EventKind::Invocation(_, _) => "invocation",
       EventKind::CallComplete(_) => "call_complete",
+      EventKind::Cancel => "cancel",
       EventKind::Close(_) => "close",
     }
   }
@@ -144,6 +147,11 @@ impl InterpreterDispatchChannel {
     }
   }
 
+  /// The number of events queued for the event loop that it hasn't picked up yet.
+  pub(crate) fn backlog(&self) -> usize {
+    self.sender.max_capacity() - self.sender.capacity()
+  }
+
   pub(crate) fn dispatch(&self, event: Event) {
     let tx = self.sender.clone();
 
@@ -162,6 +170,10 @@ impl InterpreterDispatchChannel {
     self.dispatch(Event::new(ctx_id, EventKind::PortData(port), self.span.clone()));
   }
 
+  pub(crate) fn dispatch_cancel(&self, tx_id: Uuid) {
+    self.dispatch(Event::new(tx_id, EventKind::Cancel, self.span.clone()));
+  }
+
   pub(crate) fn dispatch_close(&self, error: Option<ExecutionError>) {
     self.dispatch(Event::new(CHANNEL_UUID, EventKind::Close(error), self.span.clone()));
   }
@@ -271,4 +283,25 @@ mod test {
 
     Ok(())
   }
+
+  #[tokio::test]
+  async fn test_backlog() -> anyhow::Result<()> {
+    let mut channel = InterpreterChannel::new();
+    let dispatcher = channel.dispatcher(None);
+    assert_eq!(dispatcher.backlog(), 0);
+
+    assert!(dispatcher
+      .sender
+      .try_send(Event::new(Uuid::new_v4(), EventKind::Ping(1), None))
+      .is_ok());
+    assert!(dispatcher
+      .sender
+      .try_send(Event::new(Uuid::new_v4(), EventKind::Ping(2), None))
+      .is_ok());
+    assert_eq!(dispatcher.backlog(), 2);
+
+    channel.accept().await;
+    assert_eq!(dispatcher.backlog(), 1);
+    Ok(())
+  }
 }
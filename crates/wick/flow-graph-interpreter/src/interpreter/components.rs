@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 pub(crate) mod component;
 pub(crate) mod core;
+pub(crate) mod flow;
 pub(crate) mod internal;
 pub(crate) mod null;
 pub(crate) mod self_component;
@@ -13,6 +14,7 @@ use flow_component::Component;
 use wick_interface_types::{ComponentSignature, OperationSignature};
 
 use self::core::CoreComponent;
+use self::flow::FlowComponent;
 use self::internal::InternalComponent;
 use crate::error::InterpreterError;
 use crate::graph::types::Network;
@@ -56,6 +58,13 @@ impl HandlerMap {
     ))
   }
 
+  pub(crate) fn add_flow(&mut self, network: &Network) -> Result<(), InterpreterError> {
+    self.add(NamespaceHandler::new(
+      FlowComponent::ID,
+      Box::new(FlowComponent::new(network)?),
+    ))
+  }
+
   #[must_use]
   pub const fn inner(&self) -> &HashMap<String, NamespaceHandler> {
     &self.components
@@ -125,7 +134,9 @@ pub(crate) fn dyn_component_id(name: &str, schematic: &str, instance: &str) -> S
 }
 
 pub(crate) fn reconcile_op_id(ns: &str, name: &str, schematic: &str, instance: &str) -> String {
-  if ns == CoreComponent::ID && core::DYNAMIC_OPERATIONS.contains(&name) {
+  if (ns == CoreComponent::ID && core::DYNAMIC_OPERATIONS.contains(&name))
+    || (ns == FlowComponent::ID && name == FlowComponent::CALL)
+  {
     dyn_component_id(name, schematic, instance)
   } else {
     name.to_owned()
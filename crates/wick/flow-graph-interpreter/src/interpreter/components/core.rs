@@ -7,11 +7,16 @@ use crate::interpreter::components::dyn_component_id;
 use crate::{BoxFuture, HandlerMap};
 
 pub(crate) mod collect;
+pub(crate) mod digest;
+pub(crate) mod filter;
+pub(crate) mod for_each;
 pub(crate) mod log;
 pub(crate) mod merge;
 pub(crate) mod pluck;
 pub(crate) mod sender;
 pub(crate) mod switch;
+pub(crate) mod transform;
+pub(crate) mod verify;
 
 pub(crate) static DYNAMIC_OPERATIONS: &[&str] = &[collect::Op::ID, merge::Op::ID, switch::Op::ID];
 
@@ -24,6 +29,11 @@ pub(crate) struct CoreComponent {
   switch: switch::Op,
   collect: collect::Op,
   log: log::Op,
+  digest: digest::Op,
+  verify: verify::Op,
+  filter: filter::Op,
+  for_each: for_each::Op,
+  transform: transform::Op,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -77,11 +87,21 @@ impl CoreComponent {
       switch: switch::Op::new(),
       log: log::Op::new(),
       collect: collect::Op::new(),
+      digest: digest::Op::new(),
+      verify: verify::Op::new(),
+      filter: filter::Op::new(),
+      for_each: for_each::Op::new(),
+      transform: transform::Op::new(),
     };
 
     this.signature.operations.push(this.pluck.get_signature(None).clone());
     this.signature.operations.push(this.sender.get_signature(None).clone());
     this.signature.operations.push(this.log.get_signature(None).clone());
+    this.signature.operations.push(this.digest.get_signature(None).clone());
+    this.signature.operations.push(this.verify.get_signature(None).clone());
+    this.signature.operations.push(this.filter.get_signature(None).clone());
+    this.signature.operations.push(this.for_each.get_signature(None).clone());
+    this.signature.operations.push(this.transform.get_signature(None).clone());
 
     // scour program for dynamic components
     for schematic in graph.schematics() {
@@ -179,6 +199,11 @@ impl Component for CoreComponent {
         merge::Op::ID => core_op! {merge::Op, invocation, self.merge, callback, data},
         switch::Op::ID => core_op! {switch::Op, invocation, self.switch, callback, data},
         collect::Op::ID => core_op! {collect::Op, invocation, self.collect, callback, data},
+        digest::Op::ID => core_op! {digest::Op, invocation, self.digest, callback, data},
+        verify::Op::ID => core_op! {verify::Op, invocation, self.verify, callback, data},
+        filter::Op::ID => core_op! {filter::Op, invocation, self.filter, callback, data},
+        for_each::Op::ID => core_op! {for_each::Op, invocation, self.for_each, callback, data},
+        transform::Op::ID => core_op! {transform::Op, invocation, self.transform, callback, data},
         _ => {
           panic!("Core operation {} not handled.", invocation.target().operation_id());
         }
@@ -0,0 +1,317 @@
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::FutureExt;
+use sha2::Digest;
+use tokio_stream::StreamExt;
+use wasmrs_rx::Observer;
+use wick_interface_types::{operation, OperationSignature};
+use wick_packet::{Base64Bytes, Invocation, Packet, PacketExt, PacketSender, PacketStream, RuntimeConfig};
+
+use crate::BoxFuture;
+
+#[derive()]
+pub(crate) struct Op {
+  signature: OperationSignature,
+}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).field("signature", &self.signature).finish()
+  }
+}
+
+impl Op {
+  pub(crate) fn new() -> Self {
+    Self {
+      signature: operation!(Op::ID=>{
+        config: {
+          "algorithm" => "string?"
+        },
+        inputs: {
+          "input" => "bytes"
+        },
+        outputs: {
+          "output" => "string"
+        },
+      }),
+    }
+  }
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    node.add_input("input");
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+/// The digest algorithms supported by the `digest` and `verify` operations.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Algorithm {
+  #[default]
+  Sha256,
+  Blake3,
+  Crc32c,
+}
+
+impl Algorithm {
+  pub(crate) fn from_config(config: Option<&RuntimeConfig>) -> Result<Self, ComponentError> {
+    config.and_then(|c| c.get("algorithm")).map_or_else(
+      || Ok(Self::default()),
+      |value| {
+        serde_json::from_value(value.clone()).map_err(|_| {
+          anyhow!(
+            "unsupported digest algorithm {}, expected sha256, blake3, or crc32c",
+            value
+          )
+        })
+      },
+    )
+  }
+}
+
+/// A rolling digest over the bytes seen so far.
+enum Hasher {
+  Sha256(sha2::Sha256),
+  Blake3(Box<blake3::Hasher>),
+  Crc32c(u32),
+}
+
+impl Hasher {
+  fn new(algorithm: Algorithm) -> Self {
+    match algorithm {
+      Algorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
+      Algorithm::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+      Algorithm::Crc32c => Self::Crc32c(0),
+    }
+  }
+
+  fn update(&mut self, bytes: &[u8]) {
+    match self {
+      Self::Sha256(hasher) => hasher.update(bytes),
+      Self::Blake3(hasher) => {
+        hasher.update(bytes);
+      }
+      Self::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, bytes),
+    }
+  }
+
+  /// The digest as a lowercase hex string.
+  fn finish(self) -> String {
+    match self {
+      Self::Sha256(hasher) => to_hex(&hasher.finalize()),
+      Self::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+      Self::Crc32c(crc) => format!("{:08x}", crc),
+    }
+  }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hash the bytes arriving on the `input` port without buffering them, calling `emit` with the digest of each
+/// bracketed group (or of the whole stream when it isn't bracketed). Signals and errors are forwarded to `outputs`.
+pub(crate) fn digest_stream<F>(
+  mut stream: PacketStream,
+  tx: PacketSender,
+  algorithm: Algorithm,
+  outputs: &'static [&'static str],
+  emit: F,
+) where
+  F: Fn(String) -> Vec<Packet> + Send + 'static,
+{
+  tokio::spawn(async move {
+    let mut hasher: Option<Hasher> = None;
+    let mut emitted = false;
+    while let Some(next) = stream.next().await {
+      let packet = match next {
+        Ok(packet) => packet,
+        Err(e) => {
+          let _ = tx.send_result(Err(e));
+          continue;
+        }
+      };
+      if packet.port() != "input" {
+        continue;
+      }
+      if packet.is_error() {
+        let err = packet.unwrap_err();
+        for port in outputs {
+          let _ = tx.send(Packet::raw_err(*port, err.clone()));
+        }
+      } else if packet.is_open_bracket() {
+        hasher = Some(Hasher::new(algorithm));
+        for port in outputs {
+          let _ = tx.send(Packet::open_bracket(*port));
+        }
+      } else if packet.is_close_bracket() {
+        let digest = hasher.take().unwrap_or_else(|| Hasher::new(algorithm)).finish();
+        emitted = true;
+        for packet in emit(digest) {
+          let _ = tx.send(packet);
+        }
+        for port in outputs {
+          let _ = tx.send(Packet::close_bracket(*port));
+        }
+      } else if packet.is_done() {
+        if hasher.is_some() || !emitted {
+          let digest = hasher.take().unwrap_or_else(|| Hasher::new(algorithm)).finish();
+          for packet in emit(digest) {
+            let _ = tx.send(packet);
+          }
+        }
+        break;
+      } else if packet.has_data() {
+        match packet.decode::<Base64Bytes>() {
+          Ok(bytes) => hasher.get_or_insert_with(|| Hasher::new(algorithm)).update(&bytes),
+          Err(e) => {
+            for port in outputs {
+              let _ = tx.send(Packet::err(*port, e.to_string()));
+            }
+          }
+        }
+      }
+    }
+    for port in outputs {
+      let _ = tx.send(Packet::done(*port));
+    }
+    tx.complete();
+  });
+}
+
+impl Operation for Op {
+  const ID: &'static str = "digest";
+  type Config = Algorithm;
+
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+    let stream = invocation.into_stream();
+    digest_stream(stream, tx, *context.config, &["output"], |digest| {
+      vec![Packet::encode("output", digest)]
+    });
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    &self.signature
+  }
+
+  fn input_names(&self, _config: &Self::Config) -> Vec<String> {
+    self.signature.inputs.iter().map(|n| n.name.clone()).collect()
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Algorithm;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    Algorithm::from_config(data.as_ref())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use wick_packet::{Entity, InherentData};
+
+  use super::*;
+
+  async fn digest(algorithm: Algorithm, packets: Vec<Packet>) -> Result<Vec<Packet>> {
+    let op = Op::new();
+    let inv = Invocation::test(file!(), Entity::test("noop"), packets, None)?;
+    let packets = op
+      .handle(
+        inv,
+        Context::new(algorithm, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Result<Vec<_>, _>>()
+      .await?;
+    Ok(packets)
+  }
+
+  fn chunks() -> Vec<Packet> {
+    vec![
+      Packet::encode("input", Base64Bytes::new(b"hello ".to_vec())),
+      Packet::encode("input", Base64Bytes::new(b"world".to_vec())),
+      Packet::done("input"),
+    ]
+  }
+
+  #[tokio::test]
+  async fn test_digest() -> Result<()> {
+    let cases = [
+      (
+        Algorithm::Sha256,
+        "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+      ),
+      (
+        Algorithm::Blake3,
+        "d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24",
+      ),
+      (Algorithm::Crc32c, "c99465aa"),
+    ];
+    for (algorithm, expected) in cases {
+      let packets = digest(algorithm, chunks()).await?;
+      assert_eq!(
+        packets,
+        vec![Packet::encode("output", expected), Packet::done("output")]
+      );
+    }
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_brackets() -> Result<()> {
+    let packets = digest(
+      Algorithm::Crc32c,
+      vec![
+        Packet::open_bracket("input"),
+        Packet::encode("input", Base64Bytes::new(b"hello world".to_vec())),
+        Packet::close_bracket("input"),
+        Packet::open_bracket("input"),
+        Packet::close_bracket("input"),
+        Packet::done("input"),
+      ],
+    )
+    .await?;
+    assert_eq!(
+      packets,
+      vec![
+        Packet::open_bracket("output"),
+        Packet::encode("output", "c99465aa"),
+        Packet::close_bracket("output"),
+        Packet::open_bracket("output"),
+        Packet::encode("output", "00000000"),
+        Packet::close_bracket("output"),
+        Packet::done("output"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_config() -> Result<()> {
+    assert_eq!(Op::decode_config(None)?, Algorithm::Sha256);
+    let config = RuntimeConfig::from(std::collections::HashMap::from([(
+      "algorithm".to_owned(),
+      serde_json::json!("blake3"),
+    )]));
+    assert_eq!(Op::decode_config(Some(config))?, Algorithm::Blake3);
+    let config = RuntimeConfig::from(std::collections::HashMap::from([(
+      "algorithm".to_owned(),
+      serde_json::json!("md5"),
+    )]));
+    assert!(Op::decode_config(Some(config)).is_err());
+    Ok(())
+  }
+}
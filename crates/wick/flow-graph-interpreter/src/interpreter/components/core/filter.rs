@@ -0,0 +1,181 @@
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::{FutureExt, StreamExt};
+use serde_json::{json, Value};
+use wick_interface_types::{operation, OperationSignature};
+use wick_packet::{Invocation, Packet, PacketExt, PacketStream, RuntimeConfig};
+
+use crate::BoxFuture;
+pub(crate) struct Op {
+  signature: OperationSignature,
+}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).field("signature", &self.signature).finish()
+  }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub(crate) struct Config {
+  /// A liquid condition, e.g. `input.age >= 18`, evaluated with each packet's value as `input`.
+  condition: String,
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    node.add_input("input");
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+impl Op {
+  pub(crate) fn new() -> Self {
+    Self {
+      signature: operation!(Op::ID=>{
+        config: {
+          "condition" => "string"
+        },
+        inputs: {
+          "input" => "object"
+        },
+        outputs: {
+          "output" => "object"
+        },
+      }),
+    }
+  }
+}
+
+/// Compile a condition into a template that renders `true` when the condition holds.
+fn compile(condition: &str) -> Result<liquid::Template, ComponentError> {
+  liquid::ParserBuilder::with_stdlib()
+    .build()
+    .and_then(|parser| parser.parse(&format!("{{% if {} %}}true{{% endif %}}", condition)))
+    .map_err(|e| anyhow!("invalid filter condition '{}': {}", condition, e))
+}
+
+fn matches(template: &liquid::Template, value: &Value) -> Result<bool, String> {
+  let globals = liquid::model::to_object(&json!({ "input": value })).map_err(|e| e.to_string())?;
+  let rendered = template.render(&globals).map_err(|e| e.to_string())?;
+  Ok(rendered == "true")
+}
+
+impl Operation for Op {
+  const ID: &'static str = "filter";
+  type Config = Config;
+
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let template = compile(&context.config.condition);
+    let stream = invocation.into_stream();
+
+    async move {
+      let template = template?;
+      let mapped = stream.filter_map(move |next| {
+        let result = match next {
+          Ok(packet) if packet.port() != "input" => None,
+          Ok(packet) if packet.has_data() => match packet.decode_value() {
+            Ok(value) => match matches(&template, &value) {
+              Ok(true) => Some(Ok(packet.to_port("output"))),
+              Ok(false) => None,
+              Err(e) => Some(Ok(Packet::err(
+                "output",
+                format!("could not evaluate filter condition: {}", e),
+              ))),
+            },
+            Err(e) => Some(Ok(Packet::err("output", e.to_string()))),
+          },
+          // brackets, errors, and done are passed through untouched.
+          Ok(packet) => Some(Ok(packet.to_port("output"))),
+          Err(e) => Some(Err(e)),
+        };
+        futures::future::ready(result)
+      });
+      Ok(PacketStream::new(mapped))
+    }
+    .boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    &self.signature
+  }
+
+  fn input_names(&self, _config: &Self::Config) -> Vec<String> {
+    self.signature.inputs.iter().map(|n| n.name.clone()).collect()
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config =
+      data.ok_or_else(|| anyhow!("Filter component requires configuration, please specify configuration."))?;
+
+    Ok(Self::Config {
+      condition: config.coerce_key("condition")?,
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use tokio_stream::StreamExt;
+  use wick_packet::{Entity, InherentData};
+
+  use super::*;
+
+  async fn filter(condition: &str, packets: Vec<Packet>) -> Result<Vec<Packet>> {
+    let op = Op::new();
+    let config = HashMap::from([("condition".to_owned(), json!(condition))]);
+    let config = Op::decode_config(Some(config.into()))?;
+    let inv = Invocation::test(file!(), Entity::test("noop"), packets, None)?;
+    let packets = op
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Result<Vec<_>, _>>()
+      .await?;
+    Ok(packets)
+  }
+
+  #[tokio::test]
+  async fn test_filter() -> Result<()> {
+    let packets = filter(
+      "input.age >= 18 and input.name != 'eve'",
+      vec![
+        Packet::encode("input", json!({"name": "alice", "age": 30})),
+        Packet::encode("input", json!({"name": "bob", "age": 12})),
+        Packet::encode("input", json!({"name": "eve", "age": 40})),
+        Packet::done("input"),
+      ],
+    )
+    .await?;
+    assert_eq!(
+      packets,
+      vec![
+        Packet::encode("output", json!({"name": "alice", "age": 30})),
+        Packet::done("output"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_invalid_condition() -> Result<()> {
+    let result = filter("input ==", vec![Packet::done("input")]).await;
+    assert!(result.is_err());
+    Ok(())
+  }
+}
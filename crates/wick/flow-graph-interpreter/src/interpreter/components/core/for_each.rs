@@ -0,0 +1,272 @@
+use anyhow::{anyhow, bail};
+use flow_component::{ComponentError, Context, LocalScope, Operation, RenderConfiguration};
+use futures::{FutureExt, StreamExt};
+use seeded_random::Seed;
+use serde_json::Value;
+use wasmrs_rx::Observer;
+use wick_interface_types::{operation, OperationSignature};
+use wick_packet::{
+  ComponentReference,
+  InherentData,
+  Invocation,
+  InvocationData,
+  Packet,
+  PacketExt,
+  PacketStream,
+  RuntimeConfig,
+};
+
+use crate::utils::path_to_entity;
+use crate::BoxFuture;
+pub(crate) struct Op {
+  signature: OperationSignature,
+}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).field("signature", &self.signature).finish()
+  }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub(crate) struct Config {
+  /// The operation to invoke for each element, e.g. `self::double` or `test::uppercase`.
+  #[serde(rename = "do")]
+  operation: String,
+  /// Configuration passed to every invocation of the operation.
+  with: Option<RuntimeConfig>,
+  /// The operation's input that receives each element.
+  input: String,
+  /// The operation's output that each result is taken from.
+  output: String,
+  /// How many elements may be in flight at once. Results keep the order of their elements regardless.
+  concurrency: usize,
+  /// Whether results are collected back into a list or re-emitted one packet at a time.
+  collect: bool,
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    node.add_input("input");
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+impl Op {
+  pub(crate) fn new() -> Self {
+    Self {
+      signature: operation!(Op::ID=>{
+        config: {
+          "do" => "string",
+          "with" => "object?",
+          "input" => "string?",
+          "output" => "string?",
+          "concurrency" => "u32?",
+          "collect" => "bool?"
+        },
+        inputs: {
+          "input" => "object[]"
+        },
+        outputs: {
+          "output" => "object"
+        },
+      }),
+    }
+  }
+}
+
+/// Invoke the configured operation with a single element and wait for its first value.
+async fn invoke(
+  element: Value,
+  config: &Config,
+  invocation: &InvocationData,
+  inherent: InherentData,
+  callback: LocalScope,
+) -> Result<Value, String> {
+  let target = path_to_entity(&config.operation);
+  let op_id = target.operation_id().to_owned();
+  let compref = ComponentReference::new(invocation.target.clone(), target);
+  let stream = PacketStream::from(vec![
+    Packet::encode(&config.input, element),
+    Packet::done(&config.input),
+  ]);
+
+  let mut results = callback
+    .invoke(compref, op_id, stream, inherent, config.with.clone(), &invocation.span)
+    .await
+    .map_err(|e| e.to_string())?;
+
+  while let Some(packet) = results.next().await {
+    let packet = packet.map_err(|e| e.to_string())?;
+    if packet.port() != config.output {
+      continue;
+    }
+    if packet.is_error() {
+      return Err(packet.unwrap_err().msg().to_owned());
+    }
+    if packet.has_data() {
+      return packet.decode_value().map_err(|e| e.to_string());
+    }
+  }
+  Err(format!(
+    "{} finished without a value on its '{}' output",
+    config.operation, config.output
+  ))
+}
+
+fn to_packet(result: Result<Value, String>) -> Packet {
+  result.map_or_else(|e| Packet::err("output", e), |value| Packet::encode("output", value))
+}
+
+impl Operation for Op {
+  const ID: &'static str = "for_each";
+  type Config = Config;
+
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+    let config = context.config;
+    let callback = context.callback;
+
+    tokio::spawn(async move {
+      let (invocation, mut stream) = invocation.split();
+      let rng = seeded_random::Random::from_seed(Seed::unsafe_new(invocation.seed()));
+
+      while let Some(next) = stream.next().await {
+        let packet = match next {
+          Ok(packet) => packet,
+          Err(e) => {
+            let _ = tx.error(e);
+            continue;
+          }
+        };
+        if packet.port() != "input" {
+          continue;
+        }
+        if packet.is_done() {
+          break;
+        }
+        // brackets and errors are passed through so the shape of the input stream is kept.
+        if !packet.has_data() {
+          let _ = tx.send(packet.to_port("output"));
+          continue;
+        }
+
+        let elements = match packet.decode::<Vec<Value>>() {
+          Ok(elements) => elements,
+          Err(e) => {
+            let _ = tx.send(Packet::err("output", format!("for_each expects a list: {}", e)));
+            continue;
+          }
+        };
+
+        let mut results = futures::stream::iter(elements)
+          .map(|element| {
+            let inherent = InherentData::new(rng.gen(), invocation.timestamp())
+              .with_deadline(invocation.inherent.deadline)
+              .with_call_depth(invocation.inherent.call_depth);
+            invoke(element, &config, &invocation, inherent, callback.clone())
+          })
+          .buffered(config.concurrency);
+
+        if config.collect {
+          let results: Result<Vec<_>, _> = results.collect::<Vec<_>>().await.into_iter().collect();
+          let _ = tx.send(to_packet(results.map(Value::Array)));
+        } else {
+          while let Some(result) = results.next().await {
+            let _ = tx.send(to_packet(result));
+          }
+        }
+      }
+
+      let _ = tx.send(Packet::done("output"));
+    });
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    &self.signature
+  }
+
+  fn input_names(&self, _config: &Self::Config) -> Vec<String> {
+    self.signature.inputs.iter().map(|n| n.name.clone()).collect()
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config =
+      data.ok_or_else(|| anyhow!("For-each component requires configuration, please specify configuration."))?;
+
+    let concurrency = if config.has("concurrency") {
+      config.coerce_key("concurrency")?
+    } else {
+      1
+    };
+    if concurrency == 0 {
+      bail!("For-each concurrency must be at least 1.");
+    }
+
+    Ok(Self::Config {
+      operation: config.coerce_key("do")?,
+      with: config.has("with").then(|| config.coerce_key("with")).transpose()?,
+      input: if config.has("input") {
+        config.coerce_key("input")?
+      } else {
+        "input".to_owned()
+      },
+      output: if config.has("output") {
+        config.coerce_key("output")?
+      } else {
+        "output".to_owned()
+      },
+      concurrency,
+      collect: if config.has("collect") {
+        config.coerce_key("collect")?
+      } else {
+        true
+      },
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  fn decode(config: Value) -> Result<Config, ComponentError> {
+    let config: HashMap<String, Value> = serde_json::from_value(config)?;
+    Op::decode_config(Some(config.into()))
+  }
+
+  #[test]
+  fn test_config_defaults() -> Result<()> {
+    let config = decode(json!({"do": "self::double"}))?;
+    assert_eq!(config.operation, "self::double");
+    assert_eq!(config.input, "input");
+    assert_eq!(config.output, "output");
+    assert_eq!(config.concurrency, 1);
+    assert!(config.collect);
+    assert!(config.with.is_none());
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid_config() {
+    assert!(decode(json!({})).is_err());
+    assert!(decode(json!({"do": "self::double", "concurrency": 0})).is_err());
+  }
+}
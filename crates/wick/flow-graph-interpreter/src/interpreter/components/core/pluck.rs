@@ -1,9 +1,8 @@
 use anyhow::anyhow;
 use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
 use futures::{FutureExt, StreamExt};
-use serde_json::Value;
 use wick_interface_types::{operation, OperationSignature};
-use wick_packet::{Invocation, Packet, PacketExt, PacketStream, RuntimeConfig};
+use wick_packet::{Invocation, Packet, PacketExt, PacketPayload, PacketStream, RuntimeConfig};
 
 use crate::BoxFuture;
 pub(crate) struct Op {
@@ -48,24 +47,9 @@ impl Op {
   }
 }
 
-fn _pluck<'a>(val: &'a Value, path: &[String], idx: usize) -> Option<&'a Value> {
-  if idx == path.len() {
-    Some(val)
-  } else {
-    let part = &path[idx];
-    match val {
-      Value::Object(map) => map.get(part).and_then(|next| _pluck(next, path, idx + 1)),
-      Value::Array(list) => {
-        let i: Result<usize, _> = part.parse();
-        i.map_or(None, |i| list.get(i).and_then(|next| _pluck(next, path, idx + 1)))
-      }
-      _ => None,
-    }
-  }
-}
-
-fn pluck<'a>(val: &'a Value, path: &[String]) -> Option<&'a Value> {
-  _pluck(val, path, 0)
+fn pluck(packet: &Packet, path: &[String]) -> Result<Option<Packet>, wick_packet::Error> {
+  let plucked = packet.lazy()?.path(path)?;
+  Ok(plucked.map(|value| Packet::new_for_port("output", PacketPayload::Ok(Some(value.as_bytes().to_vec().into())), 0)))
 }
 
 impl Operation for Op {
@@ -88,16 +72,13 @@ impl Operation for Op {
             return None;
           }
           if packet.has_data() {
-            Some(packet.decode_value().map(|obj| {
-              pluck(&obj, &field).map_or_else(
-                || {
-                  Packet::err(
-                    "output",
-                    format!("could not retrieve data from object path [{}]", field.join(",")),
-                  )
-                },
-                |value| Packet::encode("output", value),
-              )
+            Some(pluck(&packet, &field).map(|value| {
+              value.unwrap_or_else(|| {
+                Packet::err(
+                  "output",
+                  format!("could not retrieve data from object path [{}]", field.join(",")),
+                )
+              })
             }))
           } else {
             Some(Ok(packet.to_port("output")))
@@ -227,7 +208,7 @@ mod test {
     });
 
     let val = pluck(
-      &json,
+      &Packet::encode("input", json),
       &[
         "first".to_owned(),
         "second".to_owned(),
@@ -236,7 +217,7 @@ mod test {
         "fourth".to_owned(),
       ],
     );
-    assert_eq!(val, Some(&serde_json::json!("first element")));
+    assert_eq!(val?.unwrap().decode::<String>()?, "first element");
 
     Ok(())
   }
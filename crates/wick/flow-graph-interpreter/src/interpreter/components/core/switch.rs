@@ -29,7 +29,7 @@ use wick_packet::{
 use crate::graph::types::{Network, Schematic};
 use crate::interpreter::components::self_component::SelfComponent;
 use crate::utils::path_to_entity;
-use crate::{BoxFuture, HandlerMap};
+use crate::{BoxFuture, HandlerMap, ObservabilityLevel};
 pub(crate) struct Op {
   signature: Arc<Mutex<Option<OperationSignature>>>,
 }
@@ -81,6 +81,28 @@ pub(crate) struct SwitchCase {
   with: Option<RuntimeConfig>,
 }
 
+/// Find the case a discriminant selects, along with the discriminant's value.
+///
+/// Discriminants are compared to the encoded cases before they're decoded, so a large record that matches a case
+/// is never decoded at all. Equal values can be encoded differently, so one that matches no case's bytes is decoded
+/// and compared again.
+fn select_case<'a>(
+  cases: &'a [SwitchCase],
+  encoded: &[Option<Vec<u8>>],
+  packet: &Packet,
+) -> Result<(Option<&'a SwitchCase>, Value), wick_packet::Error> {
+  let payload = packet.lazy()?;
+  let matched = cases
+    .iter()
+    .zip(encoded)
+    .find(|(_, bytes)| bytes.as_deref() == Some(payload.as_bytes()));
+  if let Some((case, _)) = matched {
+    return Ok((Some(case), case.case.clone()));
+  }
+  let condition = payload.decode_value()?;
+  Ok((cases.iter().find(|case| case.case == condition), condition))
+}
+
 #[allow(clippy::option_if_let_else)]
 fn get_op_signature(
   op_path: &str,
@@ -465,6 +487,12 @@ impl Operation for Op {
 
     let default = context.config.default.clone();
     let callback = context.callback;
+    let encoded_cases: Vec<_> = context
+      .config
+      .cases
+      .iter()
+      .map(|case| wasmrs_codec::messagepack::serialize(&case.case).ok())
+      .collect();
 
     tokio::spawn(async move {
       // the substream level the condition was found at.
@@ -509,8 +537,13 @@ impl Operation for Op {
         // if this is a packet on the DISCRIMINANT port, decode it and set the condition.
         if packet.port() == DISCRIMINANT {
           if packet.has_data() {
-            let condition = packet.decode_value().unwrap();
-            let case = context.config.cases.iter().find(|case| case.case == condition);
+            let (case, condition) = match select_case(&context.config.cases, &encoded_cases, &packet) {
+              Ok(selected) => selected,
+              Err(e) => {
+                let _ = root_tx.error(e);
+                continue;
+              }
+            };
             let (_case, op, op_config) = case.map_or_else(
               || {
                 invocation
@@ -524,14 +557,16 @@ impl Operation for Op {
                 (CaseId::Match(CaseValue(&case.case)), &case.case_do, case.with.clone())
               },
             );
-            let span = info_span!(parent:&invocation.span,"switch:case:handler",otel.name=format!("case:{}",condition),%condition);
+            let span = ObservabilityLevel::Ops.span(|| info_span!(parent:&invocation.span,"switch:case:handler",otel.name=format!("case:{}",condition),%condition));
             router.push(Condition::new(
               condition,
               condition_level,
               new_route_handler(
                 path_to_entity(op),
                 &invocation,
-                InherentData::new(rng.gen(), invocation.timestamp()),
+                InherentData::new(rng.gen(), invocation.timestamp())
+                  .with_deadline(invocation.inherent.deadline)
+                  .with_call_depth(invocation.inherent.call_depth),
                 callback.clone(),
                 op_config,
                 span,
@@ -0,0 +1,183 @@
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::{FutureExt, StreamExt};
+use liquid_json::LiquidJsonValue;
+use serde_json::{json, Value};
+use wick_interface_types::{operation, OperationSignature};
+use wick_packet::{Invocation, Packet, PacketExt, PacketStream, RuntimeConfig};
+
+use crate::BoxFuture;
+pub(crate) struct Op {
+  signature: OperationSignature,
+}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).field("signature", &self.signature).finish()
+  }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub(crate) struct Config {
+  /// A structured JSON liquid template, e.g. `{"name": "{{ input.first_name }}"}`, rendered with each packet's value
+  /// as `input`.
+  template: Value,
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    node.add_input("input");
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+impl Op {
+  pub(crate) fn new() -> Self {
+    Self {
+      signature: operation!(Op::ID=>{
+        config: {
+          "template" => "object"
+        },
+        inputs: {
+          "input" => "object"
+        },
+        outputs: {
+          "output" => "object"
+        },
+      }),
+    }
+  }
+}
+
+fn transform(template: &LiquidJsonValue, value: &Value) -> Result<Value, String> {
+  template.render(&json!({ "input": value })).map_err(|e| e.to_string())
+}
+
+impl Operation for Op {
+  const ID: &'static str = "transform";
+  type Config = Config;
+
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let template = LiquidJsonValue::new(context.config.template.clone());
+    let stream = invocation.into_stream();
+
+    async move {
+      let mapped = stream.filter_map(move |next| {
+        let result = match next {
+          Ok(packet) if packet.port() != "input" => None,
+          Ok(packet) if packet.has_data() => match packet.decode_value() {
+            Ok(value) => match transform(&template, &value) {
+              Ok(value) => Some(Ok(Packet::encode("output", value))),
+              Err(e) => Some(Ok(Packet::err("output", format!("could not transform packet: {}", e)))),
+            },
+            Err(e) => Some(Ok(Packet::err("output", e.to_string()))),
+          },
+          // brackets, errors, and done are passed through untouched.
+          Ok(packet) => Some(Ok(packet.to_port("output"))),
+          Err(e) => Some(Err(e)),
+        };
+        futures::future::ready(result)
+      });
+      Ok(PacketStream::new(mapped))
+    }
+    .boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    &self.signature
+  }
+
+  fn input_names(&self, _config: &Self::Config) -> Vec<String> {
+    self.signature.inputs.iter().map(|n| n.name.clone()).collect()
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config =
+      data.ok_or_else(|| anyhow!("Transform component requires configuration, please specify configuration."))?;
+
+    Ok(Self::Config {
+      template: config.coerce_key("template")?,
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use tokio_stream::StreamExt;
+  use wick_packet::{Entity, InherentData};
+
+  use super::*;
+
+  async fn transform(template: Value, packets: Vec<Packet>) -> Result<Vec<Packet>> {
+    let op = Op::new();
+    let config = HashMap::from([("template".to_owned(), template)]);
+    let config = Op::decode_config(Some(config.into()))?;
+    let inv = Invocation::test(file!(), Entity::test("noop"), packets, None)?;
+    let packets = op
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Result<Vec<_>, _>>()
+      .await?;
+    Ok(packets)
+  }
+
+  #[tokio::test]
+  async fn test_transform() -> Result<()> {
+    let packets = transform(
+      json!({"name": "{{ input.first_name }} {{ input.last_name }}", "greeting": "Hi {{ input.first_name }}"}),
+      vec![
+        Packet::encode("input", json!({"first_name": "Ada", "last_name": "Lovelace"})),
+        Packet::done("input"),
+      ],
+    )
+    .await?;
+    assert_eq!(
+      packets,
+      vec![
+        Packet::encode("output", json!({"name": "Ada Lovelace", "greeting": "Hi Ada"})),
+        Packet::done("output"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_signals_pass_through() -> Result<()> {
+    let packets = transform(
+      json!("{{ input | upcase }}"),
+      vec![
+        Packet::open_bracket("input"),
+        Packet::encode("input", "hello"),
+        Packet::close_bracket("input"),
+        Packet::done("input"),
+      ],
+    )
+    .await?;
+    assert_eq!(
+      packets,
+      vec![
+        Packet::open_bracket("output"),
+        Packet::encode("output", "HELLO"),
+        Packet::close_bracket("output"),
+        Packet::done("output"),
+      ]
+    );
+    Ok(())
+  }
+}
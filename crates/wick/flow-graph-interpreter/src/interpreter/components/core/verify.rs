@@ -0,0 +1,158 @@
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::FutureExt;
+use wick_interface_types::{operation, OperationSignature};
+use wick_packet::{Invocation, Packet, PacketStream, RuntimeConfig};
+
+use super::digest::{digest_stream, Algorithm};
+use crate::BoxFuture;
+
+#[derive()]
+pub(crate) struct Op {
+  signature: OperationSignature,
+}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).field("signature", &self.signature).finish()
+  }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub(crate) struct Config {
+  algorithm: Algorithm,
+  expected: String,
+}
+
+impl Op {
+  pub(crate) fn new() -> Self {
+    Self {
+      signature: operation!(Op::ID=>{
+        config: {
+          "algorithm" => "string?",
+          "expected" => "string"
+        },
+        inputs: {
+          "input" => "bytes"
+        },
+        outputs: {
+          "digest" => "string",
+          "valid" => "bool"
+        },
+      }),
+    }
+  }
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    node.add_input("input");
+    node.add_output("digest");
+    node.add_output("valid");
+    Ok(())
+  }
+}
+
+impl Operation for Op {
+  const ID: &'static str = "verify";
+  type Config = Config;
+
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+    let stream = invocation.into_stream();
+    let expected = context.config.expected.clone();
+    digest_stream(
+      stream,
+      tx,
+      context.config.algorithm,
+      &["digest", "valid"],
+      move |digest| {
+        let valid = digest.eq_ignore_ascii_case(&expected);
+        vec![Packet::encode("valid", valid), Packet::encode("digest", digest)]
+      },
+    );
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    &self.signature
+  }
+
+  fn input_names(&self, _config: &Self::Config) -> Vec<String> {
+    self.signature.inputs.iter().map(|n| n.name.clone()).collect()
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config =
+      data.ok_or_else(|| anyhow!("Verify component requires configuration, please specify configuration."))?;
+
+    Ok(Self::Config {
+      algorithm: Algorithm::from_config(Some(&config))?,
+      expected: config.coerce_key("expected")?,
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+  use wick_packet::{Base64Bytes, Entity, InherentData};
+
+  use super::*;
+
+  async fn verify(expected: &str) -> Result<Vec<Packet>> {
+    let op = Op::new();
+    let config = HashMap::from([
+      ("algorithm".to_owned(), json!("crc32c")),
+      ("expected".to_owned(), json!(expected)),
+    ]);
+    let config = Op::decode_config(Some(config.into()))?;
+    let packets = vec![
+      Packet::encode("input", Base64Bytes::new(b"hello world".to_vec())),
+      Packet::done("input"),
+    ];
+    let inv = Invocation::test(file!(), Entity::test("noop"), packets, None)?;
+    let packets = op
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Result<Vec<_>, _>>()
+      .await?;
+    Ok(packets)
+  }
+
+  #[tokio::test]
+  async fn test_verify() -> Result<()> {
+    let packets = verify("C99465AA").await?;
+    assert_eq!(
+      packets,
+      vec![
+        Packet::encode("valid", true),
+        Packet::encode("digest", "c99465aa"),
+        Packet::done("digest"),
+        Packet::done("valid"),
+      ]
+    );
+
+    let packets = verify("00000000").await?;
+    assert_eq!(packets[0], Packet::encode("valid", false));
+    assert_eq!(packets[1], Packet::encode("digest", "c99465aa"));
+    Ok(())
+  }
+}
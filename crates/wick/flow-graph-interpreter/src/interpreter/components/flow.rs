@@ -0,0 +1,177 @@
+use anyhow::{anyhow, bail};
+use flow_component::{Component, ComponentError, LocalScope};
+use wick_interface_types::{ComponentSignature, OperationSignature, Type};
+use wick_packet::{ComponentReference, Entity, InherentData, Invocation, PacketStream, RuntimeConfig};
+
+use super::dyn_component_id;
+use super::self_component::SelfComponent;
+use crate::error::{InterpreterError, ValidationError};
+use crate::graph::types::{Network, Node, Schematic};
+use crate::BoxFuture;
+
+/// How many calls deep flows may nest when a call doesn't set its own `max_depth`.
+pub(crate) const DEFAULT_MAX_DEPTH: u32 = 16;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Error {
+  #[error("Operation '{0}' not found on this component")]
+  OperationNotFound(String),
+
+  #[error("Flow '{0}' was not called, calls can only be nested {1} deep")]
+  TooDeep(String, u32),
+}
+
+#[derive(Debug)]
+pub(crate) struct Config {
+  /// The flow to call, e.g. `double` or `parent::child`.
+  flow: String,
+  /// Configuration for the called flow. The caller's configuration is not passed on.
+  with: Option<RuntimeConfig>,
+  /// How many calls deep flows may nest before this call fails.
+  max_depth: u32,
+}
+
+fn decode_config(data: Option<RuntimeConfig>) -> Result<Config, ComponentError> {
+  let config = data.ok_or_else(|| anyhow!("Flow calls require configuration, please specify the flow to call."))?;
+
+  let max_depth = if config.has("max_depth") {
+    config.coerce_key("max_depth")?
+  } else {
+    DEFAULT_MAX_DEPTH
+  };
+  if max_depth == 0 {
+    bail!("Flow call max_depth must be at least 1.");
+  }
+
+  Ok(Config {
+    flow: config.coerce_key("flow")?,
+    with: config.has("with").then(|| config.coerce_key("with")).transpose()?,
+    max_depth,
+  })
+}
+
+/// The flow that a `flow::call` node calls.
+pub(crate) fn call_target(node: &Node) -> Result<String, ComponentError> {
+  let config = node
+    .data()
+    .config
+    .render(&InherentData::unsafe_default()) // this is a first pass render to find the flow so using unsafe_default should be OK.
+    .map_err(ComponentError::new)?;
+  Ok(decode_config(config)?.flow)
+}
+
+/// The signature of a call to `target`: the flow's own ports, untyped so flows can call each other in any order.
+fn gen_signature(id: String, target: &Schematic) -> OperationSignature {
+  let mut signature = OperationSignature::new_named(id);
+  for port in target.input().outputs() {
+    signature = signature.add_input(port.name(), Type::Object);
+  }
+  for port in target.output().inputs() {
+    signature = signature.add_output(port.name(), Type::Object);
+  }
+  signature
+}
+
+#[derive(Debug)]
+pub(crate) struct FlowComponent {
+  signature: ComponentSignature,
+}
+
+impl FlowComponent {
+  pub(crate) const ID: &str = "flow";
+  pub(crate) const CALL: &str = "call";
+
+  pub(crate) fn new(graph: &Network) -> Result<Self, InterpreterError> {
+    let mut signature = ComponentSignature::new_named(Self::ID).set_version("0.0.0");
+
+    for schematic in graph.schematics() {
+      for node in schematic.nodes().iter().filter(|node| Self::is_call(node)) {
+        let flow = call_target(node).map_err(|e| InterpreterError::Configuration(format!("{}: {}", node.id(), e)))?;
+        let target = graph.schematic(&flow).ok_or_else(|| {
+          InterpreterError::EarlyError(ValidationError::MissingFlow {
+            id: node.id().to_owned(),
+            flow: flow.clone(),
+          })
+        })?;
+        let id = dyn_component_id(Self::CALL, schematic.name(), node.id());
+        debug!(%id, %flow, "adding type signature for flow call");
+        signature.operations.push(gen_signature(id, target));
+      }
+    }
+
+    Ok(Self { signature })
+  }
+
+  pub(crate) fn is_call(node: &Node) -> bool {
+    node.cref().component_id() == Self::ID && node.cref().name() == Self::CALL
+  }
+}
+
+impl Component for FlowComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    data: Option<RuntimeConfig>,
+    callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    invocation.trace(|| debug!(target = %invocation.target(), namespace = Self::ID));
+
+    Box::pin(async move {
+      if invocation.target().operation_id() != Self::CALL {
+        return Err(ComponentError::new(Error::OperationNotFound(
+          invocation.target().operation_id().to_owned(),
+        )));
+      }
+      let config = decode_config(data)?;
+      let (invocation, stream) = invocation.split();
+
+      let depth = invocation.inherent.call_depth + 1;
+      if depth > config.max_depth {
+        return Err(ComponentError::new(Error::TooDeep(config.flow, config.max_depth)));
+      }
+      let inherent = invocation.inherent.unsafe_clone().with_call_depth(depth);
+
+      // invoking through the callback starts a new transaction, so the called flow shares no state with its caller.
+      let target = Entity::operation(SelfComponent::ID, &config.flow);
+      let compref = ComponentReference::new(invocation.target.clone(), target);
+      callback
+        .invoke(compref, config.flow, stream, inherent, config.with, &invocation.span)
+        .await
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use serde_json::{json, Value};
+
+  use super::*;
+
+  fn decode(config: Value) -> Result<Config, ComponentError> {
+    let config: HashMap<String, Value> = serde_json::from_value(config)?;
+    decode_config(Some(config.into()))
+  }
+
+  #[test]
+  fn test_config_defaults() -> Result<()> {
+    let config = decode(json!({"flow": "parent::child"}))?;
+    assert_eq!(config.flow, "parent::child");
+    assert_eq!(config.max_depth, DEFAULT_MAX_DEPTH);
+    assert!(config.with.is_none());
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid_config() {
+    assert!(decode_config(None).is_err());
+    assert!(decode(json!({})).is_err());
+    assert!(decode(json!({"flow": "child", "max_depth": 0})).is_err());
+  }
+}
@@ -48,6 +48,12 @@ pub enum Error {
 
   #[error("Could not render operation configuration: {0}")]
   Configuration(String),
+
+  #[error("Can not replay transaction {0}, the trace does not include its start")]
+  IncompleteTrace(uuid::Uuid),
+
+  #[error("Could not read transaction checkpoints: {0}")]
+  Checkpoint(String),
 }
 
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
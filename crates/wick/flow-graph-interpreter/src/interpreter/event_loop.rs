@@ -11,6 +11,7 @@ use super::channel::{Event, EventKind, InterpreterChannel, InterpreterDispatchCh
 use super::error::Error;
 use super::InterpreterOptions;
 use crate::interpreter::event_loop::state::State;
+use crate::interpreter::executor::context::{Checkpointer, LineageStore, Resumptions, TransactionStatistics};
 use crate::interpreter::executor::error::ExecutionError;
 
 #[derive(Debug)]
@@ -39,11 +40,17 @@ impl EventLoop {
     }
   }
 
-  pub(super) async fn start(&mut self, options: InterpreterOptions, observer: Option<Box<dyn Observer + Send + Sync>>) {
+  pub(super) async fn start(
+    &mut self,
+    options: InterpreterOptions,
+    observer: Option<Box<dyn Observer + Send + Sync>>,
+    lineage: LineageStore,
+    resumptions: Resumptions,
+  ) {
     let channel = self.channel.take().unwrap();
 
     let span = self.span.clone();
-    let handle = tokio::spawn(async move { event_loop(channel, options, observer, span).await });
+    let handle = tokio::spawn(async move { event_loop(channel, options, observer, lineage, resumptions, span).await });
     let mut lock = self.task.lock();
     lock.replace(handle);
   }
@@ -97,16 +104,26 @@ pub trait Observer {
   fn on_event(&self, index: usize, event: &Event);
   fn on_after_event(&self, index: usize, state: &State);
   fn on_close(&self);
+  /// Called after the event loop has waited for events without receiving any.
+  fn on_idle(&self, _state: &State) {}
+  /// Called when a transaction has finished delivering its output.
+  fn on_transaction_done(&self, _stats: &TransactionStatistics) {}
 }
 
 async fn event_loop(
   mut channel: InterpreterChannel,
   options: InterpreterOptions,
   observer: Option<Box<dyn Observer + Send + Sync>>,
+  lineage: LineageStore,
+  resumptions: Resumptions,
   span: Span,
 ) -> Result<(), ExecutionError> {
   debug!(?options, "started");
-  let mut state = State::new(channel.dispatcher(None));
+  let checkpointer = options
+    .durability
+    .as_ref()
+    .map(|durability| Checkpointer::start(durability, span.clone()));
+  let mut state = State::new(channel.dispatcher(None), lineage, checkpointer, resumptions);
 
   let mut num: usize = 0;
 
@@ -132,7 +149,12 @@ async fn event_loop(
           }
           EventKind::CallComplete(data) => state.handle_call_complete(ctx_id, data).instrument(tx_span).await,
           EventKind::PortData(data) => state.handle_port_data(ctx_id, data, &tx_span).await,
-          EventKind::ExecutionDone => state.handle_exec_done(ctx_id).instrument(tx_span).await,
+          EventKind::ExecutionDone => state.handle_exec_done(ctx_id).instrument(tx_span).await.map(|stats| {
+            if let (Some(observer), Some(stats)) = (&observer, stats) {
+              observer.on_transaction_done(&stats);
+            }
+          }),
+          EventKind::Cancel => state.handle_cancel(ctx_id).instrument(tx_span).await,
           EventKind::ExecutionStart(context, stream) => {
             state
               .handle_exec_start(*context, stream, &options)
@@ -178,6 +200,9 @@ async fn event_loop(
             channel.dispatcher(None).dispatch_close(Some(error));
           };
         });
+        if let Some(observer) = &observer {
+          observer.on_idle(&state);
+        }
       }
     }
   };
@@ -4,25 +4,45 @@ use std::sync::atomic::AtomicBool;
 use flow_graph::{PortDirection, PortReference};
 use tracing::Span;
 use uuid::Uuid;
-use wick_packet::{PacketPayload, PacketStream};
+use wick_packet::{Lineage, PacketPayload, PacketStream};
 
 use super::EventLoop;
 use crate::interpreter::channel::{CallComplete, InterpreterDispatchChannel};
-use crate::interpreter::executor::context::{ExecutionContext, TxState};
+use crate::interpreter::executor::context::{
+  Checkpointer,
+  ExecutionContext,
+  LineageStore,
+  Resumptions,
+  TransactionEventKind,
+  TransactionSnapshot,
+  TransactionStatistics,
+  TxState,
+};
 use crate::interpreter::executor::error::ExecutionError;
-use crate::InterpreterOptions;
+use crate::{InterpreterOptions, ObservabilityLevel};
 
 #[derive(Debug)]
 pub struct State {
   context_map: ContextMap,
   channel: InterpreterDispatchChannel,
+  lineage: LineageStore,
+  checkpointer: Option<Checkpointer>,
+  resumptions: Resumptions,
 }
 
 impl State {
-  pub(super) fn new(channel: InterpreterDispatchChannel) -> Self {
+  pub(super) fn new(
+    channel: InterpreterDispatchChannel,
+    lineage: LineageStore,
+    checkpointer: Option<Checkpointer>,
+    resumptions: Resumptions,
+  ) -> Self {
     Self {
       context_map: ContextMap::default(),
       channel,
+      lineage,
+      checkpointer,
+      resumptions,
     }
   }
 
@@ -92,6 +112,19 @@ impl State {
     stream: PacketStream,
     options: &InterpreterOptions,
   ) -> Result<(), ExecutionError> {
+    if options.track_lineage {
+      ctx.track_lineage(self.lineage.track(ctx.id()));
+    }
+    if let Some(checkpointer) = &self.checkpointer {
+      // nested flows share their caller's transaction and are checkpointed as part of it.
+      let nested = self
+        .context_map
+        .iter()
+        .any(|(_, (other, _))| other.tx_id() == ctx.tx_id());
+      if !nested {
+        ctx.make_durable(checkpointer.clone(), self.resumptions.take(ctx.tx_id()));
+      }
+    }
     match ctx.start(options, stream).await {
       Ok(_) => {
         self.context_map.init_tx(ctx.id(), ctx);
@@ -102,24 +135,40 @@ impl State {
   }
 
   #[allow(clippy::unused_async)]
-  pub(super) async fn handle_exec_done(&mut self, ctx_id: Uuid) -> Result<(), ExecutionError> {
-    let is_done = if let Some(ctx) = self.get_mut(&ctx_id) {
-      let _ = ctx.finish()?;
-
-      ctx.in_scope(|| {
-        if ctx.active_instances().is_empty() {
-          debug!(%ctx_id,"execution:done");
-          true
-        } else {
-          false
-        }
-      })
-    } else {
-      false
+  pub(super) async fn handle_exec_done(
+    &mut self,
+    ctx_id: Uuid,
+  ) -> Result<Option<TransactionStatistics>, ExecutionError> {
+    let Some(ctx) = self.get_mut(&ctx_id) else {
+      return Ok(None);
     };
+    let stats = ctx.finish()?;
+
+    let is_done = ctx.in_scope(|| {
+      if ctx.active_instances().is_empty() {
+        debug!(%ctx_id,"execution:done");
+        true
+      } else {
+        false
+      }
+    });
     if is_done {
       self.context_map.remove(&ctx_id);
     }
+    Ok(Some(stats))
+  }
+
+  #[allow(clippy::unused_async)]
+  pub(super) async fn handle_cancel(&self, tx_id: Uuid) -> Result<(), ExecutionError> {
+    let mut cancelled = false;
+    for (_, (ctx, _)) in self.context_map.iter().filter(|(_, (ctx, _))| ctx.tx_id() == tx_id) {
+      ctx.in_scope(|| debug!(%tx_id, ctx_id = %ctx.id(), "cancelling invocation"));
+      ctx.cancel()?;
+      cancelled = true;
+    }
+    if !cancelled {
+      debug!(%tx_id, "tried to cancel missing transaction, it may have already completed");
+    }
     Ok(())
   }
 
@@ -152,26 +201,46 @@ impl State {
       });
 
       ctx.handle_schematic_output()?;
-    } else if let Some(packet) = ctx.take_instance_input(&port) {
-      span.in_scope(|| {
-        if packet.is_error() {
-          warn!(
-            operation = %instance,
-            port = port_name,
-            payload = ?packet,
-            "handling port input"
-          );
-        } else {
-          debug!(
-            operation = %instance,
-            port = port_name,
-            payload = ?packet,
-            "handling port input"
-          );
+    } else {
+      // a merged port may release several packets at once, or hold this one back for later. Other ports take one
+      // packet per event so they stay interleaved with the operation's other inputs.
+      let merged = ctx.is_merged_input(&port);
+      while let Some(packet) = ctx.take_instance_input(&port) {
+        if let Some(lineage) = ctx.lineage() {
+          // packets without lineage have just entered the transaction on a schematic input.
+          let hop = packet.lineage().map_or_else(|| lineage.input(port_name), |l| l.hop);
+          lineage.receive(port.node_index(), hop);
         }
-      });
-      let fut = ctx.push_packets(port.node_index(), vec![packet]);
-      fut.await?;
+        ctx.emit_event(TransactionEventKind::PacketAccepted {
+          operation: instance.id().to_owned(),
+          port: port_name.to_owned(),
+          packet: packet.clone(),
+        });
+        span.in_scope(|| {
+          if packet.is_error() {
+            if ObservabilityLevel::Errors.enabled() {
+              warn!(
+                operation = %instance,
+                port = port_name,
+                payload = ?packet,
+                "handling port input"
+              );
+            }
+          } else if ObservabilityLevel::Packets.enabled() {
+            debug!(
+              operation = %instance,
+              port = port_name,
+              payload = ?packet,
+              "handling port input"
+            );
+          }
+        });
+        let fut = ctx.push_packets(port.node_index(), vec![packet]);
+        fut.await?;
+        if !merged {
+          break;
+        }
+      }
     }
     Ok(())
   }
@@ -196,18 +265,30 @@ impl State {
       .mark(format!("output:{}:{}:ready", port.node_index(), port.port_index()));
 
     let Some(packet) = ctx.take_instance_output(&port) else {
+      if instance.is_cancelled() {
+        // buffered output is dropped when an operation is cancelled.
+        return Ok(());
+      }
       panic!("got port_data message with no payload to act on, port: {:?}", port);
     };
+    if let Some(durable) = ctx.durable() {
+      if port.node_index() != graph.input().index() {
+        durable.output(instance.id(), &packet);
+      }
+    }
+    ctx.stats.packet(packet.is_error());
 
     let connections = span.in_scope(|| {
       if packet.is_error() {
-        warn!(
-          operation = %instance,
-          port = port_name,
-          payload = ?packet,
-          "handling port output"
-        );
-      } else {
+        if ObservabilityLevel::Errors.enabled() {
+          warn!(
+            operation = %instance,
+            port = port_name,
+            payload = ?packet,
+            "handling port output"
+          );
+        }
+      } else if ObservabilityLevel::Packets.enabled() {
         debug!(
           operation = %instance,
           port = port_name,
@@ -217,6 +298,10 @@ impl State {
       }
       graph.get_port(&port).connections()
     });
+    let lineage = ctx.lineage().map(|lineage| {
+      let hop = lineage.emit(port.node_index(), port_name);
+      Lineage::new(port.node_index(), port.port_index(), hop)
+    });
     for index in connections {
       span.in_scope(|| {
         let connection = &graph.connections()[*index];
@@ -225,9 +310,14 @@ impl State {
 
         let channel = self.channel.clone();
         let downstream_instance = ctx.instance(downport.node_index()).clone();
-        let message = packet.clone().to_port(name);
-        trace!(%connection, "delivering packet to downstream",);
-        downstream_instance.buffer_in(&downport, message);
+        let mut message = packet.clone().to_port(name);
+        if let Some(lineage) = lineage {
+          message.set_lineage(lineage);
+        }
+        if ObservabilityLevel::Packets.enabled() {
+          trace!(%connection, lineage = ?lineage, "delivering packet to downstream",);
+        }
+        downstream_instance.buffer_in(&downport, Some(*index), message);
         channel.dispatch_data(ctx_id, downport);
       });
     }
@@ -268,7 +358,16 @@ impl State {
       ctx.handle_op_err(data.index, &err)?;
       // ...and clean up the call.
       // instance.handle_stream_complete(CompletionStatus::Error)?;
+      ctx.emit_event(TransactionEventKind::OperationError {
+        operation: instance.id().to_owned(),
+        error: err.msg().to_owned(),
+      });
+    } else if let Some(durable) = ctx.durable() {
+      durable.completed(instance.id());
     }
+    ctx.emit_event(TransactionEventKind::OperationFinished {
+      operation: instance.id().to_owned(),
+    });
 
     Ok(())
   }
@@ -304,6 +403,24 @@ impl Metadata {
 pub struct ContextMap(HashMap<Uuid, (ExecutionContext, Metadata)>);
 
 impl ContextMap {
+  /// The number of transactions in progress.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Whether no transactions are in progress.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Take a [TransactionSnapshot] of every transaction in progress.
+  #[must_use]
+  pub fn snapshot(&self) -> Vec<TransactionSnapshot> {
+    self.0.values().map(|(ctx, _)| ctx.snapshot()).collect()
+  }
+
   pub(crate) fn init_tx(&mut self, uuid: Uuid, ctx: ExecutionContext) {
     self.0.insert(uuid, (ctx, Metadata::default()));
   }
@@ -1,3 +1,4 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Instant, SystemTime};
@@ -7,9 +8,22 @@ use flow_graph::{NodeIndex, PortReference, SCHEMATIC_OUTPUT_INDEX};
 use futures::StreamExt;
 use parking_lot::Mutex;
 use seeded_random::{Random, Seed};
+use serde_json::Value;
 use uuid::Uuid;
 use wasmrs_rx::Observer;
-use wick_packet::{Entity, InvocationData, Packet, PacketError, PacketExt, PacketSender, PacketStream, RuntimeConfig};
+use wick_packet::{
+  BlobScope,
+  BlobStore,
+  Entity,
+  InherentData,
+  InvocationData,
+  Packet,
+  PacketError,
+  PacketExt,
+  PacketSender,
+  PacketStream,
+  RuntimeConfig,
+};
 
 use self::operation::{FutureInvocation, InstanceHandler};
 use super::error::ExecutionError;
@@ -18,12 +32,29 @@ use crate::interpreter::channel::InterpreterDispatchChannel;
 use crate::interpreter::components::self_component::SelfComponent;
 use crate::interpreter::error::StateError;
 use crate::interpreter::executor::context::operation::port::PortStatus;
-use crate::{HandlerMap, InterpreterOptions};
+use crate::{HandlerMap, InterpreterOptions, ObservabilityLevel};
+
+pub(crate) mod chaos;
+
+pub(crate) mod checkpoint;
+pub(crate) use checkpoint::{Checkpoint, Checkpointer, DurableTransaction, Resumptions};
+
+pub(crate) mod dead_letter;
+pub(crate) use dead_letter::DeadLetters;
+
+pub(crate) mod events;
+pub(crate) use events::TransactionEventKind;
+
+pub(crate) mod lineage;
+pub(crate) use lineage::{LineageStore, TransactionLineage};
 
 pub(crate) mod operation;
 
+pub(crate) mod snapshot;
+pub(crate) use snapshot::{InstanceSnapshot, TransactionSnapshot};
+
 pub(crate) mod statistics;
-pub(crate) use statistics::ExecutionStatistics;
+pub(crate) use statistics::{ExecutionStatistics, TransactionStatistics};
 
 type Result<T> = std::result::Result<T, ExecutionError>;
 
@@ -42,17 +73,36 @@ pub struct ExecutionContext {
   channel: InterpreterDispatchChannel,
   instances: Vec<Arc<InstanceHandler>>,
   id: Uuid,
+  tx_id: Uuid,
+  origin: Entity,
   start_time: Instant,
   finished: AtomicBool,
+  /// Whether the transaction has reported that it finished or was cancelled.
+  ended: AtomicBool,
   span: tracing::Span,
   callback: LocalScope,
   root_config: Option<RuntimeConfig>,
   op_config: Option<RuntimeConfig>,
+  inherent: InherentData,
   options: Option<InterpreterOptions>,
+  lineage: Option<Arc<TransactionLineage>>,
+  durable: Option<Arc<DurableTransaction>>,
+  /// Holds the blobs operations put in the [BlobStore] for this transaction until its last flow is dropped.
+  _blobs: BlobScope,
   pub(crate) last_access_time: Mutex<SystemTime>,
   pub(crate) stats: ExecutionStatistics,
 }
 
+impl Drop for ExecutionContext {
+  fn drop(&mut self) {
+    if !self.ended.load(Ordering::Relaxed) {
+      self.emit_event(TransactionEventKind::Abandoned {
+        operation: self.schematic_name().to_owned(),
+      });
+    }
+  }
+}
+
 impl std::fmt::Debug for ExecutionContext {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.debug_struct("ExecutionContext").field("id", &self.id).finish()
@@ -93,9 +143,9 @@ impl ExecutionContext {
       })
       .collect();
 
-    let stats = ExecutionStatistics::new(invocation.id);
+    let stats = ExecutionStatistics::new(invocation.id, &invocation.origin);
     stats.mark("new");
-    let span = info_span!(parent:&invocation.span,"execution_flow",ctx_id=%id);
+    let span = ObservabilityLevel::Errors.span(|| info_span!(parent:&invocation.span,"execution_flow",ctx_id=%id));
     let channel = channel.with_span(span.clone());
 
     let (tx, rx) = invocation.make_response();
@@ -103,17 +153,24 @@ impl ExecutionContext {
     let this = Self {
       channel,
       options: None,
+      lineage: None,
+      durable: None,
+      _blobs: BlobStore::global().scope(invocation.tx_id),
       schematic,
       root_config,
       op_config,
+      inherent: invocation.inherent.unsafe_clone(),
       output: Some(tx),
       instances,
       start_time: Instant::now(),
       stats,
       last_access_time: Mutex::new(SystemTime::now()),
       id,
+      tx_id: invocation.tx_id,
+      origin: invocation.origin.clone(),
       span,
       finished: AtomicBool::new(false),
+      ended: AtomicBool::new(false),
       callback,
     };
 
@@ -133,10 +190,55 @@ impl ExecutionContext {
     self.id
   }
 
+  /// The id of the transaction this execution belongs to, shared by the executions of nested operations.
+  pub const fn tx_id(&self) -> Uuid {
+    self.tx_id
+  }
+
   pub fn schematic_name(&self) -> &str {
     self.schematic.name()
   }
 
+  pub(crate) fn track_lineage(&mut self, lineage: Arc<TransactionLineage>) {
+    self.lineage = Some(lineage);
+  }
+
+  pub(crate) fn lineage(&self) -> Option<&TransactionLineage> {
+    self.lineage.as_deref()
+  }
+
+  /// Checkpoint this transaction with `writer`, resuming it from `resumed` if it was checkpointed before.
+  pub(crate) fn make_durable(&mut self, writer: Checkpointer, resumed: Option<Checkpoint>) {
+    let is_resumed = resumed.is_some();
+    let checkpoint = resumed.unwrap_or_else(|| Checkpoint {
+      tx_id: self.tx_id,
+      operation: self.schematic_name().to_owned(),
+      origin: self.origin.clone(),
+      seed: self.inherent.seed,
+      timestamp: self.inherent.timestamp,
+      config: self.op_config.clone(),
+      inputs: Vec::new(),
+      done: BTreeSet::new(),
+      completed: HashMap::new(),
+    });
+    self.durable = Some(Arc::new(DurableTransaction::new(checkpoint, is_resumed, writer)));
+  }
+
+  pub(crate) fn durable(&self) -> Option<&DurableTransaction> {
+    self.durable.as_deref()
+  }
+
+  fn replay(&self, instance: &InstanceHandler) -> Option<Vec<Packet>> {
+    self.durable.as_ref().and_then(|d| d.replay(instance.id()))
+  }
+
+  /// Report an event to the [crate::EventListener] configured in [InterpreterOptions], if any.
+  pub(crate) fn emit_event(&self, kind: TransactionEventKind) {
+    if let Some(listener) = self.options.as_ref().and_then(|o| o.event_listener.as_ref()) {
+      listener.emit(self.id, kind);
+    }
+  }
+
   pub(crate) fn schematic(&self) -> &Schematic {
     &self.schematic
   }
@@ -153,6 +255,23 @@ impl ExecutionContext {
     self.instances.iter().filter(|i| i.is_running()).collect()
   }
 
+  /// Take a [TransactionSnapshot] of the operations that are still running or holding packets.
+  pub(crate) fn snapshot(&self) -> TransactionSnapshot {
+    let mut snapshot = TransactionSnapshot::new(self.id, self.schematic_name(), self.start_time.elapsed());
+    snapshot.done = self.finished.load(Ordering::Relaxed);
+    snapshot.instances = self
+      .instances
+      .iter()
+      .map(|i| {
+        let entity = i.entity();
+        let entity = format!("{}::{}", entity.component_id(), entity.operation_id());
+        InstanceSnapshot::new(i.id(), entity, i.is_running(), i.buffered())
+      })
+      .filter(|i| i.running || i.buffered > 0)
+      .collect();
+    snapshot
+  }
+
   pub(crate) fn done(&self) -> bool {
     let output_handler = self.output_handler();
     let outputs_done = output_handler
@@ -169,6 +288,12 @@ impl ExecutionContext {
     self.span.in_scope(|| trace!("starting execution"));
 
     self.options = Some(options.clone());
+    self.emit_event(TransactionEventKind::Started {
+      operation: self.schematic_name().to_owned(),
+      seed: self.inherent.seed,
+      timestamp: self.inherent.timestamp,
+      config: self.op_config.clone(),
+    });
 
     self.start_time = Instant::now();
 
@@ -187,8 +312,14 @@ impl ExecutionContext {
             self.callback.clone(),
             self.root_config.clone(),
             self.op_config.clone(),
+            self.replay(instance),
           )
           .await?;
+        self.stats.operation_started(&instance.entity());
+        self.emit_event(TransactionEventKind::OperationStarted {
+          operation: instance.id().to_owned(),
+          entity: instance.entity().to_string(),
+        });
       }
     }
 
@@ -202,15 +333,86 @@ impl ExecutionContext {
     let input = self.instance(index).clone();
     let channel = self.channel.clone();
     let ctx_id = self.id();
+    let settings = self.schematic.input().data();
+    let defaults: HashMap<String, Value> = settings
+      .defaults
+      .iter()
+      .filter_map(|f| f.default().map(|v| (f.name.clone(), v.clone())))
+      .collect();
+    let dead_letters = DeadLetters {
+      policy: self.options.as_ref().map(|o| o.dead_letter.clone()).unwrap_or_default(),
+      listener: self.options.as_ref().and_then(|o| o.event_listener.clone()),
+      operation: self.schematic_name().to_owned(),
+      callback: self.callback.clone(),
+      inherent: self.inherent.unsafe_clone(),
+      span: self.span.clone(),
+    };
+    let durable = self.durable.clone();
+    let ports: Vec<String> = self
+      .schematic
+      .input()
+      .inputs()
+      .iter()
+      .map(|port| port.name().to_owned())
+      .collect();
 
     tokio::spawn(async move {
-      while let Some(Ok(packet)) = payloads.next().await {
+      // defaulted ports that have received data or finished.
+      let mut sent = HashSet::new();
+      let mut finished = HashSet::new();
+      // every port the caller finished.
+      let mut closed = HashSet::new();
+      let mut completed = true;
+      while let Some(packet) = payloads.next().await {
+        let Ok(packet) = packet else {
+          completed = false;
+          break;
+        };
+        if let Some(durable) = &durable {
+          durable.input(&packet);
+        }
         if let Ok(port) = input.find_input(packet.port()) {
+          if packet.is_done() {
+            closed.insert(packet.port().to_owned());
+          }
+          if let Some(default) = defaults.get(packet.port()) {
+            if packet.has_data() {
+              sent.insert(packet.port().to_owned());
+            } else if packet.is_done() && finished.insert(packet.port().to_owned()) && !sent.contains(packet.port()) {
+              accept_input(ctx_id, port, &input, &channel, Packet::encode(packet.port(), default));
+            }
+          }
           accept_input(ctx_id, port, &input, &channel, packet);
         } else if packet.is_noop() {
           // TODO: propagate this and/or its context if it becomes an issue.
-        } else {
-          debug!(port = packet.port(), "dropping packet for unconnected port");
+        } else if !packet.has_data() && !packet.is_error() {
+          // done and bracket signals carry no data to lose.
+          debug!(port = packet.port(), "dropping signal for unconnected port");
+        } else if let Err(error) = dead_letters.deliver(ctx_id, packet).await {
+          // end the open inputs the same way data arrives so the error can't overtake packets already accepted.
+          for name in ports.iter().filter(|name| !closed.contains(*name)) {
+            if let Ok(port) = input.find_input(name) {
+              accept_input(ctx_id, port, &input, &channel, Packet::raw_err(name, error.clone()));
+              accept_input(ctx_id, port, &input, &channel, Packet::done(name));
+            }
+          }
+          completed = false;
+          break;
+        }
+      }
+      if !completed {
+        return;
+      }
+      // ports the caller never finished get their default as if they had been sent nothing.
+      for (name, default) in &defaults {
+        if finished.contains(name) {
+          continue;
+        }
+        if let Ok(port) = input.find_input(name) {
+          if !sent.contains(name) {
+            accept_input(ctx_id, port, &input, &channel, Packet::encode(name, default));
+          }
+          accept_input(ctx_id, port, &input, &channel, Packet::done(name));
         }
       }
     });
@@ -229,12 +431,9 @@ impl ExecutionContext {
   // Run when the execution context has finished delivering output to its output ports.
   //
   // A context may still be executing operations with side effects after this point.
-  pub(crate) fn finish(&mut self) -> Result<&ExecutionStatistics> {
+  pub(crate) fn finish(&mut self) -> Result<TransactionStatistics> {
     self.span.in_scope(|| trace!("finishing execution output"));
 
-    // drop our output sender;
-    drop(self.output.take());
-
     // mark our end of execution
     self.stats.end("execution");
 
@@ -244,12 +443,32 @@ impl ExecutionContext {
 
     self.span.in_scope(|| trace!(statistics=?self.stats));
 
-    Ok(&self.stats)
+    let stats = self.stats.summarize(self.schematic_name(), self.start_time.elapsed());
+    self.emit_event(TransactionEventKind::Finished {
+      operation: stats.operation.clone(),
+      duration: stats.duration,
+      packets: stats.packets,
+      errors: stats.errors,
+    });
+    self.ended.store(true, Ordering::Relaxed);
+
+    if let Some(durable) = &self.durable {
+      durable.finish();
+    }
+
+    // drop our output sender;
+    drop(self.output.take());
+
+    Ok(stats)
   }
 
   pub(crate) fn emit_output_message(&self, packets: Vec<Packet>) -> Result<()> {
     if let Some(ref output) = self.output {
       for packet in packets {
+        self.emit_event(TransactionEventKind::Output {
+          port: packet.port().to_owned(),
+          packet: packet.clone(),
+        });
         output.send(packet).map_err(|_e| ExecutionError::ChannelSend)?;
       }
     } else if packets.iter().any(|p| !p.is_done()) {
@@ -290,6 +509,11 @@ impl ExecutionContext {
     instance.take_input(port)
   }
 
+  pub(crate) fn is_merged_input(&self, port: &PortReference) -> bool {
+    let instance = self.instance(port.node_index());
+    instance.is_merged_input(port)
+  }
+
   pub(crate) fn check_stalled(&self) -> Result<TxState> {
     if self.done() {
       let active_instances = self.active_instances();
@@ -316,8 +540,14 @@ impl ExecutionContext {
         self.callback.clone(),
         self.root_config.clone(),
         self.op_config.clone(),
+        self.replay(&instance),
       )
       .await?;
+      self.stats.operation_started(&instance.entity());
+      self.emit_event(TransactionEventKind::OperationStarted {
+        operation: instance.id().to_owned(),
+        entity: instance.entity().to_string(),
+      });
     }
 
     let _ = instance.accept_packets(packets);
@@ -333,6 +563,7 @@ impl ExecutionContext {
 
   pub(crate) fn handle_op_err(&self, index: NodeIndex, err: &PacketError) -> Result<()> {
     self.stats.mark(format!("component:{}:op_err", index));
+    self.stats.error();
     let instance = self.instance(index);
 
     let graph = self.schematic();
@@ -350,6 +581,36 @@ impl ExecutionContext {
     }
     Ok(())
   }
+
+  /// Stop the transaction: abort every operation still running and end each output with a cancellation error.
+  pub(crate) fn cancel(&self) -> Result<()> {
+    if self.finished.load(Ordering::Relaxed) {
+      return Ok(());
+    }
+    self.span.in_scope(|| debug!("cancelling execution"));
+    self.stats.mark("cancel");
+    self.stats.error();
+
+    for instance in &self.instances {
+      instance.abort();
+    }
+    self.emit_event(TransactionEventKind::Cancelled {
+      operation: self.schematic_name().to_owned(),
+    });
+    self.ended.store(true, Ordering::Relaxed);
+    if let Some(durable) = &self.durable {
+      durable.finish();
+    }
+
+    if let Some(ref output) = self.output {
+      for port in self.output_handler().inputs().iter() {
+        // the receiver may be gone already, e.g. when the caller of a nested flow was cancelled first.
+        let _ = output.send(Packet::raw_err(port.name(), PacketError::cancelled()));
+        let _ = output.send(Packet::done(port.name()));
+      }
+    }
+    self.emit_done()
+  }
 }
 
 pub(crate) fn accept_input(
@@ -359,7 +620,7 @@ pub(crate) fn accept_input(
   channel: &InterpreterDispatchChannel,
   payload: Packet,
 ) {
-  instance.buffer_in(&port, payload);
+  instance.buffer_in(&port, None, payload);
   channel.dispatch_data(ctx_id, port);
 }
 
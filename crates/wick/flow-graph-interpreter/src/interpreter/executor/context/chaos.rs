@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use flow_component::ComponentError;
+use seeded_random::{Random, Seed};
+use tokio_stream::StreamExt;
+use tracing::Span;
+use wick_packet::{Entity, Observer, PacketExt, PacketStream};
+
+use crate::interpreter::components::component::ComponentComponent;
+use crate::interpreter::components::core::CoreComponent;
+use crate::interpreter::components::flow::FlowComponent;
+use crate::interpreter::components::internal::InternalComponent;
+use crate::interpreter::components::null::NullComponent;
+use crate::interpreter::components::self_component::SelfComponent;
+
+/// Namespaces the interpreter provides itself, which only get faults when a policy targets them by name.
+const BUILTIN: &[&str] = &[
+  SelfComponent::ID,
+  CoreComponent::ID,
+  FlowComponent::ID,
+  InternalComponent::ID,
+  ComponentComponent::ID,
+  NullComponent::ID,
+];
+
+/// Faults to inject into calls to an operation. Every chance is a probability from `0.0` (never) to `1.0` (always).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ChaosFaults {
+  /// The chance a call is delayed before the operation is invoked.
+  pub latency: f64,
+  /// The shortest delay added to a delayed call.
+  pub min_latency: Duration,
+  /// The longest delay added to a delayed call.
+  pub max_latency: Duration,
+  /// The chance each packet with data the operation sends is dropped. Signals like `done` are never dropped.
+  pub drop: f64,
+  /// The chance a call fails with a component error instead of invoking the operation.
+  pub error: f64,
+  /// The chance, before each packet the operation sends, that its output ends with an error as if its provider
+  /// disconnected.
+  pub disconnect: f64,
+}
+
+/// Randomized faults to inject into operation calls so retry, timeout, and dead letter configurations can be checked
+/// against slow, lossy, and failing components before they meet real ones. Not meant for production.
+///
+/// Faults are picked from the seed of each invocation, so a transaction started with the same seed meets the same
+/// faults, while every retry of a call gets its own.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ChaosPolicy {
+  /// Mixed into every invocation's seed so that different policies pick different faults.
+  pub seed: u64,
+  /// Faults for the operations of imported components that no target matches.
+  pub default: Option<ChaosFaults>,
+  /// Faults by component namespace, e.g. `db`, or operation, e.g. `db::query`. An operation's own entry takes
+  /// precedence over its namespace's.
+  pub targets: HashMap<String, ChaosFaults>,
+}
+
+impl ChaosPolicy {
+  fn faults(&self, entity: &Entity) -> Option<&ChaosFaults> {
+    let namespace = entity.component_id();
+    self
+      .targets
+      .get(&format!("{}::{}", namespace, entity.operation_id()))
+      .or_else(|| self.targets.get(namespace))
+      .or_else(|| {
+        if BUILTIN.contains(&namespace) {
+          None
+        } else {
+          self.default.as_ref()
+        }
+      })
+  }
+
+  /// Pick the faults for the `attempt`th call to `entity` by an invocation seeded with `seed`.
+  pub(crate) fn call(&self, entity: &Entity, seed: u64, attempt: u32) -> Option<Chaos> {
+    let faults = self.faults(entity)?;
+    let mixed = self.seed ^ seed ^ u64::from(attempt).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let rng = Random::from_seed(Seed::unsafe_new(mixed));
+
+    let delay = (rng.gen::<f64>() < faults.latency).then(|| {
+      let spread = faults.max_latency.saturating_sub(faults.min_latency);
+      faults.min_latency + spread.mul_f64(rng.gen::<f64>())
+    });
+    let fail = rng.gen::<f64>() < faults.error;
+
+    Some(Chaos {
+      entity: entity.clone(),
+      delay,
+      fail,
+      drop: faults.drop,
+      disconnect: faults.disconnect,
+      rng,
+    })
+  }
+}
+
+/// The faults picked for one call to an operation.
+pub(crate) struct Chaos {
+  entity: Entity,
+  delay: Option<Duration>,
+  fail: bool,
+  drop: f64,
+  disconnect: f64,
+  rng: Random,
+}
+
+impl Chaos {
+  /// Wait out the injected latency, then return the injected error if there is one.
+  pub(crate) async fn before_call(&self, span: &Span) -> Result<(), ComponentError> {
+    let entity = &self.entity;
+    if let Some(delay) = self.delay {
+      span.in_scope(|| debug!(%entity, delay_ms = delay.as_millis(), "chaos: delaying call"));
+      tokio::time::sleep(delay).await;
+    }
+    if self.fail {
+      span.in_scope(|| debug!(%entity, "chaos: failing call"));
+      return Err(anyhow!("chaos: injected error calling {}", entity));
+    }
+    Ok(())
+  }
+
+  /// Drop packets from and cut off the operation's output.
+  pub(crate) fn wrap(self, mut stream: PacketStream, span: Span) -> PacketStream {
+    if self.drop <= 0.0 && self.disconnect <= 0.0 {
+      return stream;
+    }
+    let (tx, rx) = PacketStream::new_channels();
+    tokio::spawn(async move {
+      let entity = &self.entity;
+      while let Some(packet) = stream.next().await {
+        if self.rng.gen::<f64>() < self.disconnect {
+          span.in_scope(|| debug!(%entity, "chaos: disconnecting"));
+          let _ = tx.error(wick_packet::Error::component_error(format!(
+            "chaos: injected disconnect from {}",
+            entity
+          )));
+          break;
+        }
+        if matches!(&packet, Ok(packet) if packet.has_data()) && self.rng.gen::<f64>() < self.drop {
+          span.in_scope(|| debug!(%entity, "chaos: dropping packet"));
+          continue;
+        }
+        let _ = tx.send_result(packet);
+      }
+      tx.complete();
+    });
+    rx
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  fn faults(error: f64) -> ChaosFaults {
+    ChaosFaults {
+      error,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_targets() {
+    let mut policy = ChaosPolicy {
+      default: Some(faults(0.1)),
+      ..Default::default()
+    };
+    policy.targets.insert("db".to_owned(), faults(0.2));
+    policy.targets.insert("db::query".to_owned(), faults(0.3));
+
+    assert_eq!(policy.faults(&Entity::operation("db", "query")), Some(&faults(0.3)));
+    assert_eq!(policy.faults(&Entity::operation("db", "insert")), Some(&faults(0.2)));
+    assert_eq!(policy.faults(&Entity::operation("http", "get")), Some(&faults(0.1)));
+    assert_eq!(policy.faults(&Entity::operation("core", "merge")), None);
+    assert_eq!(policy.faults(&Entity::operation("self", "child")), None);
+  }
+
+  #[tokio::test]
+  async fn test_error() -> Result<()> {
+    let mut policy = ChaosPolicy::default();
+    policy.targets.insert("db".to_owned(), faults(1.0));
+    let entity = Entity::operation("db", "query");
+
+    let chaos = policy.call(&entity, 0, 1).unwrap();
+    assert!(chaos.before_call(&Span::current()).await.is_err());
+    assert!(policy.call(&Entity::operation("http", "get"), 0, 1).is_none());
+    Ok(())
+  }
+
+  #[test]
+  fn test_deterministic() {
+    let mut policy = ChaosPolicy::default();
+    policy.targets.insert("db".to_owned(), faults(0.5));
+    let entity = Entity::operation("db", "query");
+
+    let picks = |attempt| {
+      (0..64)
+        .map(|seed| policy.call(&entity, seed, attempt).unwrap().fail)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(picks(1), picks(1));
+    assert_ne!(picks(1), picks(2));
+    assert!(picks(1).contains(&true) && picks(1).contains(&false));
+  }
+
+  #[tokio::test]
+  async fn test_disconnect() -> Result<()> {
+    let mut policy = ChaosPolicy::default();
+    policy.targets.insert(
+      "db".to_owned(),
+      ChaosFaults {
+        disconnect: 1.0,
+        ..Default::default()
+      },
+    );
+    let chaos = policy.call(&Entity::operation("db", "query"), 0, 1).unwrap();
+
+    let stream = PacketStream::from(vec![
+      wick_packet::Packet::encode("output", "row"),
+      wick_packet::Packet::done("output"),
+    ]);
+    let packets: Vec<_> = chaos.wrap(stream, Span::current()).collect().await;
+    assert_eq!(packets.len(), 1);
+    assert!(packets[0].is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,269 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use flow_component::{BoxFuture, ComponentError};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::Span;
+use uuid::Uuid;
+use wick_packet::{Entity, Packet, PacketExt, RuntimeConfig};
+
+/// The state of a transaction an interpreter needs to resume it after a restart: who invoked it, the packets it
+/// received on its inputs, and the output of every operation that finished.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Checkpoint {
+  /// The id of the transaction.
+  pub tx_id: Uuid,
+  /// The name of the operation the transaction executes.
+  pub operation: String,
+  /// The entity that invoked the transaction.
+  #[serde(default)]
+  pub origin: Entity,
+  /// The seed the transaction was invoked with.
+  pub seed: u64,
+  /// The timestamp the transaction was invoked with.
+  pub timestamp: u64,
+  /// The configuration the operation was invoked with.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub config: Option<RuntimeConfig>,
+  /// The packets the transaction received on its inputs, in the order they arrived.
+  pub inputs: Vec<Packet>,
+  /// The inputs that received their done packet.
+  #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+  pub done: BTreeSet<String>,
+  /// The packets each finished operation emitted, keyed by the id of the operation instance.
+  pub completed: HashMap<String, Vec<Packet>>,
+}
+
+/// A store for the [Checkpoint]s of the transactions an interpreter hasn't finished.
+///
+/// Checkpoints are written from a background task, so a slow store delays how current the checkpoints are, not the
+/// transactions themselves.
+pub trait CheckpointStore: std::fmt::Debug + Send + Sync {
+  /// Save `checkpoint`, replacing the previous checkpoint of its transaction.
+  fn save<'a>(&'a self, checkpoint: &'a Checkpoint) -> BoxFuture<'a, Result<(), ComponentError>>;
+
+  /// Remove the checkpoint of the transaction `tx_id`, which has finished.
+  fn remove(&self, tx_id: Uuid) -> BoxFuture<'_, Result<(), ComponentError>>;
+
+  /// Every checkpoint in the store.
+  fn load(&self) -> BoxFuture<'_, Result<Vec<Checkpoint>, ComponentError>>;
+}
+
+/// Keeps checkpoints in memory, which lets an interpreter that's rebuilt in the same process resume the
+/// transactions of the one it replaces.
+#[derive(Debug, Default)]
+#[must_use]
+pub struct MemoryCheckpoints(Mutex<HashMap<Uuid, Checkpoint>>);
+
+impl CheckpointStore for MemoryCheckpoints {
+  fn save<'a>(&'a self, checkpoint: &'a Checkpoint) -> BoxFuture<'a, Result<(), ComponentError>> {
+    self.0.lock().insert(checkpoint.tx_id, checkpoint.clone());
+    Box::pin(async { Ok(()) })
+  }
+
+  fn remove(&self, tx_id: Uuid) -> BoxFuture<'_, Result<(), ComponentError>> {
+    self.0.lock().remove(&tx_id);
+    Box::pin(async { Ok(()) })
+  }
+
+  fn load(&self) -> BoxFuture<'_, Result<Vec<Checkpoint>, ComponentError>> {
+    let checkpoints = self.0.lock().values().cloned().collect();
+    Box::pin(async { Ok(checkpoints) })
+  }
+}
+
+/// Checkpoint transactions to a [CheckpointStore] so they can be resumed with [crate::Interpreter::resume] after a
+/// crash.
+///
+/// Only the transactions invoked on the interpreter are checkpointed, the flows they call are part of them. On resume,
+/// operations that had finished have their recorded output replayed instead of being called again; operations that
+/// were still running are called again from the start.
+#[derive(Clone)]
+#[must_use]
+pub struct Durability {
+  pub(crate) store: Arc<dyn CheckpointStore>,
+}
+
+impl Durability {
+  /// Checkpoint transactions to `store`.
+  pub fn new(store: Arc<dyn CheckpointStore>) -> Self {
+    Self { store }
+  }
+}
+
+impl std::fmt::Debug for Durability {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Durability").field("store", &self.store).finish()
+  }
+}
+
+/// Checkpoints waiting to be resumed, keyed by transaction id, until the transaction starts again.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Resumptions(Arc<Mutex<HashMap<Uuid, Checkpoint>>>);
+
+impl Resumptions {
+  pub(crate) fn insert(&self, checkpoint: Checkpoint) {
+    self.0.lock().insert(checkpoint.tx_id, checkpoint);
+  }
+
+  pub(crate) fn take(&self, tx_id: Uuid) -> Option<Checkpoint> {
+    self.0.lock().remove(&tx_id)
+  }
+}
+
+/// How long the background task gathers changes before writing the checkpoints they touched.
+const CHECKPOINT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The checkpoint of a transaction in progress and whether it's changed since it was last written.
+#[derive(Debug)]
+struct LiveCheckpoint {
+  checkpoint: Mutex<Checkpoint>,
+  dirty: AtomicBool,
+}
+
+impl LiveCheckpoint {
+  /// Change the checkpoint with `f`, returning true if it wasn't already waiting to be written.
+  fn update(&self, f: impl FnOnce(&mut Checkpoint)) -> bool {
+    f(&mut self.checkpoint.lock());
+    !self.dirty.swap(true, Ordering::AcqRel)
+  }
+
+  /// A copy of the checkpoint to write.
+  fn snapshot(&self) -> Checkpoint {
+    let checkpoint = self.checkpoint.lock();
+    self.dirty.store(false, Ordering::Release);
+    checkpoint.clone()
+  }
+}
+
+/// Writes checkpoints to a [CheckpointStore] from a background task. Changes are gathered for
+/// [CHECKPOINT_INTERVAL] and each checkpoint they touched is written once, so the cost of a write doesn't grow with
+/// every packet a transaction records.
+#[derive(Debug, Clone)]
+pub(crate) struct Checkpointer {
+  tx: mpsc::UnboundedSender<(Uuid, Option<Arc<LiveCheckpoint>>)>,
+}
+
+impl Checkpointer {
+  pub(crate) fn start(durability: &Durability, span: Span) -> Self {
+    let (tx, mut rx) = mpsc::unbounded_channel::<(Uuid, Option<Arc<LiveCheckpoint>>)>();
+    let store = durability.store.clone();
+    tokio::spawn(async move {
+      while let Some((tx_id, checkpoint)) = rx.recv().await {
+        let mut pending = HashMap::from([(tx_id, checkpoint)]);
+        tokio::time::sleep(CHECKPOINT_INTERVAL).await;
+        while let Ok((tx_id, checkpoint)) = rx.try_recv() {
+          pending.insert(tx_id, checkpoint);
+        }
+        for (tx_id, checkpoint) in pending {
+          let result = match checkpoint {
+            Some(checkpoint) => store.save(&checkpoint.snapshot()).await,
+            None => store.remove(tx_id).await,
+          };
+          if let Err(error) = result {
+            span.in_scope(|| warn!(%tx_id, %error, "could not write transaction checkpoint"));
+          }
+        }
+      }
+    });
+    Self { tx }
+  }
+
+  fn save(&self, tx_id: Uuid, checkpoint: &Arc<LiveCheckpoint>) {
+    let _ = self.tx.send((tx_id, Some(checkpoint.clone())));
+  }
+
+  fn remove(&self, tx_id: Uuid) {
+    let _ = self.tx.send((tx_id, None));
+  }
+}
+
+/// The checkpoint of a transaction in progress.
+#[derive(Debug)]
+pub(crate) struct DurableTransaction {
+  tx_id: Uuid,
+  checkpoint: Arc<LiveCheckpoint>,
+  /// The packets emitted so far by operations that haven't finished.
+  running: Mutex<HashMap<String, Vec<Packet>>>,
+  /// The output of the operations that finished before the transaction was resumed.
+  replay: HashMap<String, Vec<Packet>>,
+  /// Whether the transaction was resumed, its inputs are already in the checkpoint.
+  resumed: bool,
+  writer: Checkpointer,
+}
+
+impl DurableTransaction {
+  pub(crate) fn new(checkpoint: Checkpoint, resumed: bool, writer: Checkpointer) -> Self {
+    let replay = if resumed {
+      checkpoint.completed.clone()
+    } else {
+      HashMap::new()
+    };
+    let tx_id = checkpoint.tx_id;
+    let checkpoint = Arc::new(LiveCheckpoint {
+      checkpoint: Mutex::new(checkpoint),
+      dirty: AtomicBool::new(true),
+    });
+    writer.save(tx_id, &checkpoint);
+    Self {
+      tx_id,
+      checkpoint,
+      running: Mutex::new(HashMap::new()),
+      replay,
+      resumed,
+      writer,
+    }
+  }
+
+  /// Record a packet the transaction received on one of its inputs.
+  pub(crate) fn input(&self, packet: &Packet) {
+    if self.resumed {
+      return;
+    }
+    let changed = self.checkpoint.update(|checkpoint| {
+      if packet.is_done() {
+        checkpoint.done.insert(packet.port().to_owned());
+      }
+      checkpoint.inputs.push(packet.clone());
+    });
+    if changed {
+      self.writer.save(self.tx_id, &self.checkpoint);
+    }
+  }
+
+  /// Record a packet the operation instance `id` emitted.
+  pub(crate) fn output(&self, id: &str, packet: &Packet) {
+    self
+      .running
+      .lock()
+      .entry(id.to_owned())
+      .or_default()
+      .push(packet.clone());
+  }
+
+  /// Record that the operation instance `id` finished, so it's replayed rather than called again on resume.
+  pub(crate) fn completed(&self, id: &str) {
+    let packets = self.running.lock().remove(id).unwrap_or_default();
+    let changed = self.checkpoint.update(|checkpoint| {
+      checkpoint.completed.insert(id.to_owned(), packets);
+    });
+    if changed {
+      self.writer.save(self.tx_id, &self.checkpoint);
+    }
+  }
+
+  /// The output to replay for the operation instance `id`, if it finished before the transaction was resumed.
+  pub(crate) fn replay(&self, id: &str) -> Option<Vec<Packet>> {
+    self.replay.get(id).cloned()
+  }
+
+  /// Drop the checkpoint of the finished transaction.
+  pub(crate) fn finish(&self) {
+    self.writer.remove(self.tx_id);
+  }
+}
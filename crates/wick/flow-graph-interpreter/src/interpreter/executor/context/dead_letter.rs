@@ -0,0 +1,117 @@
+use flow_component::LocalScope;
+use futures::StreamExt;
+use serde_json::json;
+use tracing::Span;
+use uuid::Uuid;
+use wick_packet::{ComponentReference, Entity, InherentData, Packet, PacketError, PacketExt, PacketStream};
+
+use super::events::{EventListener, TransactionEventKind};
+use crate::interpreter::components::self_component::SelfComponent;
+
+/// What the interpreter does with a packet sent to an input that a flow doesn't connect to anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeadLetterPolicy {
+  /// Drop the packet.
+  #[default]
+  Drop,
+  /// Fail the transaction: the flow's inputs end with an error that names the unconnected port.
+  Error,
+  /// Invoke the named operation on the same component with the packet, e.g. `deadletter`.
+  ///
+  /// The operation receives an object with the flow's name as `operation` and the packet as `packet` on its `input`
+  /// port. Packets the dead letter operation can't deliver itself are dropped.
+  Route(String),
+}
+
+/// Handles the packets a single transaction couldn't deliver.
+pub(crate) struct DeadLetters {
+  pub(crate) policy: DeadLetterPolicy,
+  pub(crate) listener: Option<EventListener>,
+  pub(crate) operation: String,
+  pub(crate) callback: LocalScope,
+  pub(crate) inherent: InherentData,
+  pub(crate) span: Span,
+}
+
+impl DeadLetters {
+  /// Handle `packet` according to the policy, returning the error to fail the transaction with if the policy says to.
+  pub(crate) async fn deliver(&self, ctx_id: Uuid, packet: Packet) -> Result<(), PacketError> {
+    let port = packet.port().to_owned();
+    if let Some(listener) = &self.listener {
+      listener.emit(
+        ctx_id,
+        TransactionEventKind::DeadLetter {
+          port: port.clone(),
+          packet: packet.clone(),
+        },
+      );
+    }
+
+    match &self.policy {
+      DeadLetterPolicy::Drop => {
+        self
+          .span
+          .in_scope(|| debug!(%port, "dropping packet for unconnected port"));
+        Ok(())
+      }
+      DeadLetterPolicy::Error => Err(PacketError::new(format!(
+        "operation '{}' received a packet on input '{}', which is not connected to anything",
+        self.operation, port
+      ))),
+      // routing the dead letter operation's own undeliverable packets back to it would never end.
+      DeadLetterPolicy::Route(target) if *target == self.operation => {
+        self
+          .span
+          .in_scope(|| warn!(%port, "dropping packet for unconnected port of the dead letter operation"));
+        Ok(())
+      }
+      DeadLetterPolicy::Route(target) => {
+        self.route(target, packet).await;
+        Ok(())
+      }
+    }
+  }
+
+  async fn route(&self, target: &str, packet: Packet) {
+    let letter = json!({ "operation": self.operation, "packet": packet.to_json() });
+    let stream = PacketStream::from(vec![Packet::encode("input", letter), Packet::done("input")]);
+    let compref = ComponentReference::new(
+      Entity::operation(SelfComponent::ID, &self.operation),
+      Entity::operation(SelfComponent::ID, target),
+    );
+
+    let result = self
+      .callback
+      .invoke(
+        compref,
+        target.to_owned(),
+        stream,
+        self.inherent.unsafe_clone(),
+        None,
+        &self.span,
+      )
+      .await;
+
+    match result {
+      Ok(mut outputs) => {
+        let span = self.span.clone();
+        tokio::spawn(async move {
+          while let Some(packet) = outputs.next().await {
+            match packet {
+              Ok(packet) if packet.is_error() => {
+                let error = packet.unwrap_err();
+                span.in_scope(|| warn!(error = error.msg(), "dead letter operation failed"));
+              }
+              Err(error) => span.in_scope(|| warn!(%error, "dead letter operation failed")),
+              Ok(_) => {}
+            }
+          }
+        });
+      }
+      Err(error) => self
+        .span
+        .in_scope(|| warn!(%error, operation = target, "could not invoke dead letter operation")),
+    }
+  }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use wick_packet::{Packet, RuntimeConfig};
+
+/// A structured record of something that happened during a transaction, delivered to an [EventListener].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TransactionEvent {
+  /// The transaction the event belongs to.
+  pub tx_id: Uuid,
+  /// When the event occurred.
+  pub time: SystemTime,
+  /// What happened.
+  #[serde(flatten)]
+  pub kind: TransactionEventKind,
+}
+
+/// The kinds of [TransactionEvent] the interpreter emits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TransactionEventKind {
+  /// The transaction started executing `operation`.
+  Started {
+    /// The name of the operation the transaction executes.
+    operation: String,
+    /// The seed the transaction was invoked with.
+    seed: u64,
+    /// The timestamp the transaction was invoked with.
+    timestamp: u64,
+    /// The configuration the operation was invoked with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    config: Option<RuntimeConfig>,
+  },
+  /// An operation within the transaction accepted a packet on one of its inputs.
+  PacketAccepted {
+    /// The id of the operation instance that accepted the packet.
+    operation: String,
+    /// The input port the packet arrived on.
+    port: String,
+    /// The packet itself.
+    packet: Packet,
+  },
+  /// A packet arrived on an input the flow doesn't connect to anything and was handled by the
+  /// [crate::DeadLetterPolicy].
+  DeadLetter {
+    /// The input port the packet arrived on.
+    port: String,
+    /// The packet itself.
+    packet: Packet,
+  },
+  /// An operation within the transaction was invoked.
+  OperationStarted {
+    /// The id of the operation instance.
+    operation: String,
+    /// The entity the instance invokes.
+    entity: String,
+  },
+  /// An operation within the transaction failed.
+  OperationError {
+    /// The id of the operation instance.
+    operation: String,
+    /// The error the operation failed with.
+    error: String,
+  },
+  /// An operation within the transaction completed its call.
+  OperationFinished {
+    /// The id of the operation instance.
+    operation: String,
+  },
+  /// The transaction emitted a packet on one of its outputs.
+  Output {
+    /// The output port the packet was sent from.
+    port: String,
+    /// The packet itself.
+    packet: Packet,
+  },
+  /// The transaction was cancelled before it finished.
+  Cancelled {
+    /// The name of the operation the transaction executed.
+    operation: String,
+  },
+  /// The transaction was dropped without finishing or being cancelled, e.g. because it stalled and timed out.
+  Abandoned {
+    /// The name of the operation the transaction executed.
+    operation: String,
+  },
+  /// The transaction finished delivering its output.
+  Finished {
+    /// The name of the operation the transaction executed.
+    operation: String,
+    /// The time from the start of the transaction until its output finished.
+    duration: Duration,
+    /// The number of packets emitted by operations within the transaction.
+    packets: u64,
+    /// The number of error packets and operation failures within the transaction.
+    errors: u64,
+  },
+}
+
+/// A sink for the [TransactionEvent]s of every transaction an interpreter runs.
+///
+/// The listener is called from the interpreter's event loop and should hand events off (e.g. to a channel or a
+/// buffered writer) rather than block.
+#[derive(Clone)]
+#[must_use]
+pub struct EventListener(Arc<dyn Fn(&TransactionEvent) + Send + Sync>);
+
+impl EventListener {
+  /// Create a listener that calls `f` for every event.
+  pub fn new<F>(f: F) -> Self
+  where
+    F: Fn(&TransactionEvent) + Send + Sync + 'static,
+  {
+    Self(Arc::new(f))
+  }
+
+  pub(crate) fn emit(&self, tx_id: Uuid, kind: TransactionEventKind) {
+    (self.0)(&TransactionEvent {
+      tx_id,
+      time: SystemTime::now(),
+      kind,
+    });
+  }
+}
+
+impl std::fmt::Debug for EventListener {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("EventListener").finish()
+  }
+}
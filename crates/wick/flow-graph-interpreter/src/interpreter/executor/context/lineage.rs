@@ -0,0 +1,202 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use flow_graph::NodeIndex;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+/// A schematic input that contributed to a packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PacketOrigin {
+  /// The name of the schematic input port the packet arrived on.
+  pub port: String,
+  /// The hop id assigned to the packet when it arrived.
+  pub hop: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HopSource {
+  /// A packet that entered the transaction on a schematic input port.
+  Input,
+  /// A packet emitted from a node after it had received the given number of hops.
+  Node(NodeIndex, usize),
+}
+
+#[derive(Debug)]
+struct Hop {
+  source: HopSource,
+  port: String,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+  /// Every hop in the transaction, indexed by hop id.
+  hops: Vec<Hop>,
+  /// The hops each node has received, in order.
+  received: HashMap<NodeIndex, Vec<u64>>,
+}
+
+/// The record of every hop a packet took during a single transaction.
+///
+/// Operations are opaque to the interpreter, so every output is considered to depend on all of the inputs its node
+/// had received at the time the output was delivered.
+#[derive(Debug, Default)]
+#[must_use]
+pub struct TransactionLineage {
+  inner: Mutex<Inner>,
+}
+
+impl TransactionLineage {
+  /// Record a packet arriving on a schematic input port and return its hop id.
+  pub(crate) fn input<T: Into<String>>(&self, port: T) -> u64 {
+    let mut inner = self.inner.lock();
+    let id = inner.hops.len() as u64;
+    inner.hops.push(Hop {
+      source: HopSource::Input,
+      port: port.into(),
+    });
+    id
+  }
+
+  /// Record a packet emitted from `node` on `port` and return its hop id.
+  pub(crate) fn emit<T: Into<String>>(&self, node: NodeIndex, port: T) -> u64 {
+    let mut inner = self.inner.lock();
+    let id = inner.hops.len() as u64;
+    let seen = inner.received.get(&node).map_or(0, |r| r.len());
+    inner.hops.push(Hop {
+      source: HopSource::Node(node, seen),
+      port: port.into(),
+    });
+    id
+  }
+
+  /// Record that `node` received the packet with the given hop id.
+  pub(crate) fn receive(&self, node: NodeIndex, hop: u64) {
+    self.inner.lock().received.entry(node).or_default().push(hop);
+  }
+
+  /// Return the schematic inputs that contributed to the packet with the given hop id.
+  #[must_use]
+  pub fn contributing_inputs(&self, hop: u64) -> Vec<PacketOrigin> {
+    let inner = self.inner.lock();
+    let mut origins = Vec::new();
+    let mut visited = HashSet::new();
+    let mut pending = vec![hop];
+
+    while let Some(id) = pending.pop() {
+      if !visited.insert(id) {
+        continue;
+      }
+      let Some(hop) = inner.hops.get(id as usize) else {
+        continue;
+      };
+      match hop.source {
+        HopSource::Input => origins.push(PacketOrigin {
+          port: hop.port.clone(),
+          hop: id,
+        }),
+        HopSource::Node(node, seen) => {
+          if let Some(received) = inner.received.get(&node) {
+            pending.extend(received.iter().take(seen));
+          }
+        }
+      }
+    }
+    origins.sort_by_key(|o| o.hop);
+    origins
+  }
+}
+
+type Entries = VecDeque<(Uuid, Arc<TransactionLineage>)>;
+
+/// A bounded store of the lineage of recent transactions.
+#[derive(Debug, Clone)]
+#[must_use]
+pub(crate) struct LineageStore {
+  capacity: usize,
+  transactions: Arc<Mutex<Entries>>,
+}
+
+impl LineageStore {
+  pub(crate) const DEFAULT_CAPACITY: usize = 100;
+
+  pub(crate) fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      transactions: Default::default(),
+    }
+  }
+
+  /// Start tracking a new transaction, evicting the oldest one if the store is full.
+  pub(crate) fn track(&self, id: Uuid) -> Arc<TransactionLineage> {
+    let lineage = Arc::new(TransactionLineage::default());
+    let mut transactions = self.transactions.lock();
+    if transactions.len() >= self.capacity {
+      transactions.pop_front();
+    }
+    transactions.push_back((id, lineage.clone()));
+    lineage
+  }
+
+  pub(crate) fn get(&self, id: Uuid) -> Option<Arc<TransactionLineage>> {
+    self
+      .transactions
+      .lock()
+      .iter()
+      .find(|(tx, _)| *tx == id)
+      .map(|(_, lineage)| lineage.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_contributing_inputs() -> Result<()> {
+    let lineage = TransactionLineage::default();
+    let left = lineage.input("left");
+    let right = lineage.input("right");
+    let unused = lineage.input("unused");
+
+    // node 2 sees both `left` and `right` before emitting.
+    lineage.receive(2, left);
+    lineage.receive(2, right);
+    let out = lineage.emit(2, "output");
+    // node 3 only sees `unused` after node 2 has emitted.
+    lineage.receive(3, out);
+    let early = lineage.emit(3, "output");
+    lineage.receive(3, unused);
+    let late = lineage.emit(3, "output");
+
+    let ports = |hop| {
+      lineage
+        .contributing_inputs(hop)
+        .into_iter()
+        .map(|o| o.port)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(ports(early), vec!["left", "right"]);
+    assert_eq!(ports(late), vec!["left", "right", "unused"]);
+    assert_eq!(ports(left), vec!["left"]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_store_capacity() -> Result<()> {
+    let store = LineageStore::new(2);
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    for id in ids {
+      store.track(id);
+    }
+    assert!(store.get(ids[0]).is_none());
+    assert!(store.get(ids[1]).is_some());
+    assert!(store.get(ids[2]).is_some());
+
+    Ok(())
+  }
+}
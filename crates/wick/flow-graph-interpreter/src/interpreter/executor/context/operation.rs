@@ -1,10 +1,10 @@
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use flow_component::{Component, ComponentError, LocalScope};
-use flow_graph::{NodeIndex, PortReference};
+use flow_graph::{ConnectionIndex, NodeIndex, PortReference};
 use parking_lot::Mutex;
 use tokio::task::JoinHandle;
 use tokio_stream::StreamExt;
@@ -12,12 +12,15 @@ use tracing::Span;
 use tracing_futures::Instrument;
 use uuid::Uuid;
 use wasmrs_rx::{FluxChannel, Observer};
+use wick_config::config::RetryCondition;
 use wick_packet::{
   Entity,
   InherentData,
+  Invocation,
   InvocationData,
   Packet,
   PacketError,
+  PacketErrorKind,
   PacketExt,
   PacketPayload,
   PacketSender,
@@ -26,6 +29,8 @@ use wick_packet::{
 };
 
 use self::port::{InputPorts, OutputPorts, PortStatus};
+use self::retry::{AttemptError, Retry};
+use super::chaos::Chaos;
 use crate::graph::types::*;
 use crate::graph::Reference;
 use crate::interpreter::channel::InterpreterDispatchChannel;
@@ -33,10 +38,11 @@ use crate::interpreter::components::self_component::SelfComponent;
 use crate::interpreter::error::StateError;
 use crate::interpreter::executor::error::ExecutionError;
 use crate::utils::Bucket;
-use crate::{HandlerMap, InterpreterOptions};
+use crate::{HandlerMap, InterpreterOptions, ObservabilityLevel};
 type Result<T> = std::result::Result<T, ExecutionError>;
 
 pub(crate) mod port;
+mod retry;
 
 pub(crate) struct FutureInvocation {
   origin: Entity,
@@ -66,7 +72,15 @@ impl FutureInvocation {
         .as_millis() as u64,
     );
 
-    Self::new(value.tx_id, value.target.clone(), target, inherent, value.span.clone())
+    Self::new(
+      value.tx_id,
+      value.target.clone(),
+      target,
+      inherent
+        .with_deadline(value.inherent.deadline)
+        .with_call_depth(value.inherent.call_depth),
+      value.span.clone(),
+    )
   }
 }
 
@@ -88,6 +102,7 @@ pub(crate) struct InstanceHandler {
   outputs: OutputPorts,
   schematic: Arc<Schematic>,
   pending: AtomicU32,
+  cancelled: AtomicBool,
   components: Arc<HandlerMap>,
   task: InstanceTask,
   self_component: SelfComponent,
@@ -127,7 +142,7 @@ impl InstanceHandler {
 
     Self {
       schematic,
-      inputs: InputPorts::new(op_node.id(), inputs),
+      inputs: InputPorts::new(op_node.id(), inputs, op_node.data()),
       outputs: OutputPorts::new(op_node.id(), outputs),
       invocation: Bucket::new(invocation),
       reference,
@@ -136,6 +151,7 @@ impl InstanceHandler {
       components,
       sender: FluxChannel::new(),
       pending: AtomicU32::new(0),
+      cancelled: AtomicBool::new(false),
       self_component,
       task: Default::default(),
     }
@@ -169,8 +185,17 @@ impl InstanceHandler {
     self.inputs.take(port)
   }
 
-  pub(crate) fn buffer_in(&self, port: &PortReference, value: Packet) {
-    self.inputs.receive(port, value);
+  pub(super) fn is_merged_input(&self, port: &PortReference) -> bool {
+    self.inputs.is_merged(port)
+  }
+
+  /// Buffer a packet for one of this operation's inputs, noting the `connection` it arrived over, if any.
+  pub(crate) fn buffer_in(&self, port: &PortReference, connection: Option<ConnectionIndex>, value: Packet) {
+    if self.is_cancelled() {
+      trace!(op = self.id(), "dropping input for cancelled operation");
+      return;
+    }
+    self.inputs.receive(port, connection, value);
   }
 
   pub(crate) fn buffer_out(&self, port: &PortReference, value: Packet) {
@@ -211,6 +236,39 @@ impl InstanceHandler {
     Ok(())
   }
 
+  /// Stop feeding the operation's invocation: close its input stream and free any packets still buffered for it.
+  pub(crate) fn cancel(&self) {
+    if self.cancelled.swap(true, Ordering::SeqCst) {
+      return;
+    }
+    self.sender.complete();
+    let _ = self.inputs.drain_packets();
+    let _ = self.outputs.drain_packets();
+    for port in self.inputs.iter() {
+      port.set_status(PortStatus::DoneClosed);
+    }
+  }
+
+  /// Cancel the operation and abort its outstanding call, dropping the stream the component is producing.
+  pub(crate) fn abort(&self) {
+    self.cancel();
+    self.task.abort();
+  }
+
+  pub(crate) fn is_cancelled(&self) -> bool {
+    self.cancelled.load(Ordering::SeqCst)
+  }
+
+  /// The number of packets waiting in the buffers of this operation's input and output ports.
+  pub(super) fn buffered(&self) -> usize {
+    self
+      .inputs
+      .iter()
+      .chain(self.outputs.iter())
+      .map(|port| port.buffered())
+      .sum()
+  }
+
   pub(super) fn is_running(&self) -> bool {
     self.task.has_started() && !self.task.is_done()
   }
@@ -243,6 +301,9 @@ impl InstanceHandler {
   }
 
   pub(crate) fn accept_packets(self: Arc<Self>, packets: Vec<Packet>) -> Result<()> {
+    if self.is_cancelled() {
+      return Ok(());
+    }
     for packet in packets {
       self.sender.send(packet)?;
     }
@@ -253,6 +314,9 @@ impl InstanceHandler {
     Ok(())
   }
 
+  /// Start the operation. If `replay` is given, the operation finished before its transaction was resumed and the
+  /// packets it emitted are forwarded instead of calling it again.
+  #[allow(clippy::too_many_arguments)]
   pub(crate) async fn start(
     self: Arc<Self>,
     ctx_id: Uuid,
@@ -261,6 +325,7 @@ impl InstanceHandler {
     callback: LocalScope,
     root_config: Option<RuntimeConfig>,
     op_config: Option<RuntimeConfig>,
+    replay: Option<Vec<Packet>>,
   ) -> Result<()> {
     if self.task.has_started() {
       #[cfg(debug_assertions)]
@@ -273,12 +338,12 @@ impl InstanceHandler {
     let Some(invocation) = self.invocation.take() else {
       return Err(StateError::InvocationMissing(identifier).into());
     };
-    let invocation: InvocationData = invocation.into();
-    let span =
-      info_span!(parent:&invocation.span,"interpreter:op:instance", otel.name=format!("starting:{}",invocation.target));
+    let mut invocation: InvocationData = invocation.into();
+    let span = ObservabilityLevel::Ops.span(|| {
+      info_span!(parent:&invocation.span,"interpreter:op:instance", otel.name=format!("starting:{}",invocation.target))
+    });
 
     let entity = self.entity();
-    let namespace = self.namespace().to_owned();
 
     let mut associated_data = self.schematic.nodes()[self.index()].data().clone();
 
@@ -295,75 +360,227 @@ impl InstanceHandler {
       .render(&invocation.inherent)
       .map_err(|e| ExecutionError::ComponentError(ComponentError::new(e)))?;
 
-    let timeout = associated_data
-      .settings
-      .as_ref()
-      .and_then(|v| v.timeout)
-      .unwrap_or(options.output_timeout);
+    let own_timeout = associated_data.settings.as_ref().and_then(|v| v.timeout);
+    let inherited = invocation.inherent.remaining();
+    let remaining = budget(inherited, own_timeout);
+    let timeout = own_timeout.unwrap_or(options.output_timeout);
+    // A timeout of 0 waits indefinitely.
+    let timeout = if timeout.is_zero() { Duration::MAX } else { timeout };
+    let retry_policy = associated_data.settings.and_then(|v| v.retry);
 
     self.increment_pending();
-    let stream = if self.inputs.is_empty() {
+
+    if let Some(packets) = replay {
+      self.replay(ctx_id, channel, timeout, packets, &span);
+      return Ok(());
+    }
+
+    if remaining == Some(Duration::ZERO) {
+      self.reject_expired(ctx_id, &channel, &span);
+      return Ok(());
+    }
+    // Only the invocation's own deadline counts as an exceeded budget, the operation's timeout is reported as a
+    // timeout.
+    let deadline = inherited.map(|remaining| Instant::now() + remaining);
+    // Everything the operation invokes inherits its deadline.
+    if let Some(remaining) = remaining {
+      invocation.inherent = invocation.inherent.with_remaining(remaining);
+    }
+
+    let input = if self.inputs.is_empty() {
       invocation.trace(|| debug!(%entity, "operation has no inputs, starting with noop packet"));
-      PacketStream::noop()
+      None
     } else {
-      PacketStream::new(Box::new(self.sender.take_rx().unwrap()))
+      Some(PacketStream::new(Box::new(self.sender.take_rx().unwrap())))
+    };
+    let (stream, retry) = match retry_policy {
+      Some(policy) => {
+        let retry = Retry::new(
+          policy,
+          &invocation,
+          config.clone(),
+          callback.clone(),
+          options.chaos.clone(),
+          input,
+        );
+        (retry.input(), Some(retry))
+      }
+      None => (input.unwrap_or_else(PacketStream::noop), None),
     };
+    let chaos = options
+      .chaos
+      .as_ref()
+      .and_then(|policy| policy.call(&entity, invocation.inherent.seed, 1));
     let invocation = invocation.with_stream(stream);
-    let cb = callback.clone();
 
+    let fut = self.invoke(invocation, config, callback, chaos)?;
+    let first = await_stream(&self, fut, wait_for(timeout, deadline), deadline, &span).await;
+
+    let first = match first {
+      Err(failure) if !retry.as_ref().map_or(false, |retry| retry.retries(&failure)) => {
+        if failure.condition == RetryCondition::Timeout {
+          self.cancel();
+        }
+        channel.dispatch_op_err(ctx_id, self.index(), failure.payload);
+        return Ok(());
+      }
+      first => first,
+    };
+
+    self.task.start(
+      ctx_id,
+      self.clone(),
+      first,
+      retry,
+      channel,
+      timeout,
+      deadline,
+      span.clone(),
+    );
+
+    Ok(())
+  }
+
+  /// Forward the packets an operation emitted before its transaction was resumed instead of calling it again.
+  fn replay(
+    self: &Arc<Self>,
+    ctx_id: Uuid,
+    channel: InterpreterDispatchChannel,
+    timeout: Duration,
+    packets: Vec<Packet>,
+    span: &Span,
+  ) {
+    let entity = self.entity();
+    span.in_scope(|| debug!(%entity, "replaying output of operation that finished before resume"));
+    self.task.start(
+      ctx_id,
+      self.clone(),
+      Ok(PacketStream::from(packets)),
+      None,
+      channel,
+      timeout,
+      None,
+      span.clone(),
+    );
+  }
+
+  /// Report that the operation wasn't started because the invocation's deadline has passed.
+  fn reject_expired(&self, ctx_id: Uuid, channel: &InterpreterDispatchChannel, span: &Span) {
+    let msg = format!(
+      "Operation {} was not started, the invocation's deadline has passed",
+      self.entity()
+    );
+    span.in_scope(|| warn!(%msg, "budget exceeded"));
+    channel.dispatch_op_err(
+      ctx_id,
+      self.index(),
+      PacketPayload::Err(PacketError::budget_exceeded(msg)),
+    );
+  }
+
+  /// Call the operation's component with `invocation`, returning the task that resolves to its output stream.
+  fn invoke(
+    &self,
+    invocation: Invocation,
+    config: Option<RuntimeConfig>,
+    callback: LocalScope,
+    chaos: Option<Chaos>,
+  ) -> Result<JoinHandle<Result<PacketStream>>> {
+    let namespace = self.namespace();
     let fut = if namespace == SelfComponent::ID {
       let clone = self.self_component.clone();
-      tokio::spawn(async move {
-        clone
-          .handle(invocation, config, cb)
-          .await
-          .map_err(ExecutionError::ComponentError)
-      })
+      tokio::spawn(async move { call(&clone, invocation, config, callback, chaos).await })
     } else {
       let clone = self
         .components
-        .get(&namespace)
-        .ok_or_else(|| ExecutionError::InvalidState(StateError::MissingComponent(self.namespace().to_owned())))?
+        .get(namespace)
+        .ok_or_else(|| ExecutionError::InvalidState(StateError::MissingComponent(namespace.to_owned())))?
         .component
         .clone();
-      tokio::spawn(async move {
-        clone
-          .handle(invocation, config, cb)
-          .await
-          .map_err(ExecutionError::ComponentError)
-      })
+      tokio::spawn(async move { call(&**clone, invocation, config, callback, chaos).await })
     };
+    Ok(fut)
+  }
+}
 
-    let outer_result = fut
-      .instrument(span.clone())
+/// Call `component`, injecting the faults chaos testing picked for this call.
+async fn call<C: Component + Sync + ?Sized>(
+  component: &C,
+  invocation: Invocation,
+  config: Option<RuntimeConfig>,
+  callback: LocalScope,
+  chaos: Option<Chaos>,
+) -> Result<PacketStream> {
+  let callback = callback.for_invocation(&invocation);
+  let Some(chaos) = chaos else {
+    return component
+      .handle(invocation, config, callback)
       .await
-      .map_err(ExecutionError::OperationFailure);
-
-    let stream = match outer_result {
-      Ok(Ok(result)) => result,
-      Ok(Err(error)) | Err(error) => {
-        let msg = if let ExecutionError::OperationFailure(e) = error {
-          if e.is_panic() {
-            format!("Operation {} panicked", entity)
-          } else {
-            format!("Operation {} cancelled", entity)
-          }
-        } else {
-          format!("Operation {} failed: {}", entity, error)
-        };
+      .map_err(ExecutionError::ComponentError);
+  };
+  let span = invocation.span().clone();
+  chaos.before_call(&span).await.map_err(ExecutionError::ComponentError)?;
+  let stream = component
+    .handle(invocation, config, callback)
+    .await
+    .map_err(ExecutionError::ComponentError)?;
+  Ok(chaos.wrap(stream, span))
+}
 
-        span.in_scope(|| warn!(%msg, "component error"));
+/// How long to wait on an operation: its own timeout, cut short by the invocation's deadline.
+fn wait_for(timeout: Duration, deadline: Option<Instant>) -> Duration {
+  deadline.map_or(timeout, |deadline| {
+    timeout.min(deadline.saturating_duration_since(Instant::now()))
+  })
+}
 
-        channel.dispatch_op_err(ctx_id, self.index(), PacketPayload::Err(PacketError::new(msg)));
-        return Ok(());
-      }
-    };
+/// Wait up to `wait` for a call to an operation to return the operation's output stream.
+async fn await_stream(
+  instance: &InstanceHandler,
+  mut fut: JoinHandle<Result<PacketStream>>,
+  wait: Duration,
+  deadline: Option<Instant>,
+  span: &Span,
+) -> std::result::Result<PacketStream, AttemptError> {
+  let entity = instance.entity();
+  let outer_result = match tokio::time::timeout(wait, (&mut fut).instrument(span.clone())).await {
+    Ok(result) => result.map_err(ExecutionError::OperationFailure),
+    Err(_) => {
+      fut.abort();
+      span.in_scope(|| warn!(%entity, "timed out starting operation"));
+      return Err(AttemptError::new(
+        RetryCondition::Timeout,
+        timeout_payload(instance, deadline),
+      ));
+    }
+  };
 
-    self
-      .task
-      .start(ctx_id, self.clone(), stream, channel, timeout, span.clone());
+  match outer_result {
+    Ok(Ok(result)) => Ok(result),
+    Ok(Err(error)) | Err(error) => {
+      let budget_exceeded = is_budget_exceeded(&error);
+      let (condition, msg) = if let ExecutionError::OperationFailure(e) = error {
+        if e.is_panic() {
+          (RetryCondition::Panic, format!("Operation {} panicked", entity))
+        } else {
+          (RetryCondition::Error, format!("Operation {} cancelled", entity))
+        }
+      } else {
+        (RetryCondition::Error, format!("Operation {} failed: {}", entity, error))
+      };
 
-    Ok(())
+      span.in_scope(|| warn!(%msg, "component error"));
+
+      let kind = if budget_exceeded {
+        PacketErrorKind::BudgetExceeded
+      } else {
+        PacketErrorKind::Other
+      };
+      Err(AttemptError::new(
+        condition,
+        PacketPayload::Err(PacketError::new(msg).with_kind(kind)),
+      ))
+    }
   }
 }
 
@@ -375,13 +592,16 @@ struct InstanceTask {
 }
 
 impl InstanceTask {
+  #[allow(clippy::too_many_arguments)]
   fn start(
     &self,
     ctx_id: Uuid,
     instance: Arc<InstanceHandler>,
-    stream: PacketStream,
+    first: std::result::Result<PacketStream, AttemptError>,
+    retry: Option<Retry>,
     channel: InterpreterDispatchChannel,
     timeout: Duration,
+    deadline: Option<Instant>,
     span: Span,
   ) {
     if self.has_started() {
@@ -395,7 +615,17 @@ impl InstanceTask {
 
     span.in_scope(|| debug!(instance = instance.id(), "task:start"));
     let task = tokio::spawn(async move {
-      let result = output_handler(ctx_id, &instance, stream, channel, timeout, span.clone()).await;
+      let result = output_handler(
+        ctx_id,
+        &instance,
+        first,
+        retry,
+        channel,
+        timeout,
+        deadline,
+        span.clone(),
+      )
+      .await;
       if let Err(error) = &result {
         span.in_scope(|| error!(%error, "error in output handler"));
       }
@@ -415,6 +645,15 @@ impl InstanceTask {
     self.task.lock().is_some()
   }
 
+  fn abort(&self) {
+    if let Some(task) = self.task.lock().as_ref() {
+      if !self.is_done() {
+        task.abort();
+        self.end_time.lock().replace(Instant::now());
+      }
+    }
+  }
+
   #[allow(unused)]
   fn is_done(&self) -> bool {
     self.end_time.lock().is_some()
@@ -430,19 +669,95 @@ impl InstanceTask {
   }
 }
 
+/// Forward the output of an operation, invoking it again when an attempt fails before it produced any output
+/// and its retry policy allows it.
+#[allow(clippy::too_many_arguments)]
 async fn output_handler(
   ctx_id: Uuid,
   instance: &InstanceHandler,
-  mut stream: PacketStream,
+  first: std::result::Result<PacketStream, AttemptError>,
+  mut retry: Option<Retry>,
   channel: InterpreterDispatchChannel,
   timeout: Duration,
+  deadline: Option<Instant>,
   span: Span,
 ) -> Result<()> {
   span.in_scope(|| trace!("starting output task"));
 
-  let mut num_received = 0;
+  let mut attempt = first;
   let reason = loop {
-    let response = tokio::time::timeout(timeout, stream.next());
+    let (failure, forwarded) = match attempt {
+      Ok(stream) => match forward_output(ctx_id, instance, stream, &channel, timeout, deadline, &span).await? {
+        StreamEnd::Finished => break CompletionStatus::Finished,
+        StreamEnd::Failed { failure, forwarded } => (failure, forwarded),
+      },
+      Err(failure) => (failure, false),
+    };
+
+    // Output that already went downstream can't be taken back, so only clean failures are retried.
+    let delay = match retry.as_mut() {
+      Some(retry) if !forwarded => retry.next_delay(&failure, deadline),
+      _ => None,
+    };
+    match (delay, retry.as_ref()) {
+      (Some(delay), Some(retry)) => {
+        span.in_scope(|| {
+          warn!(
+            attempt = retry.attempts(),
+            delay_ms = delay.as_millis(),
+            error = ?failure.payload,
+            "retrying operation"
+          );
+        });
+        tokio::time::sleep(delay).await;
+        attempt = retry
+          .attempt(instance, wait_for(timeout, deadline), deadline, &span)
+          .await;
+      }
+      _ => {
+        if failure.condition == RetryCondition::Timeout {
+          instance.cancel();
+        }
+        let status = failure.status();
+        channel.dispatch_op_err(ctx_id, instance.index(), failure.payload);
+        break status;
+      }
+    }
+  };
+  instance.handle_stream_complete(reason)?;
+  channel.dispatch_call_complete(ctx_id, instance.index());
+  Ok(())
+}
+
+/// How forwarding an operation's output stream ended.
+enum StreamEnd {
+  Finished,
+  Failed {
+    failure: AttemptError,
+    /// Whether any of the stream's packets were passed downstream before it failed.
+    forwarded: bool,
+  },
+}
+
+impl StreamEnd {
+  const fn failed(forwarded: bool, failure: AttemptError) -> Self {
+    Self::Failed { failure, forwarded }
+  }
+}
+
+async fn forward_output(
+  ctx_id: Uuid,
+  instance: &InstanceHandler,
+  mut stream: PacketStream,
+  channel: &InterpreterDispatchChannel,
+  timeout: Duration,
+  deadline: Option<Instant>,
+  span: &Span,
+) -> Result<StreamEnd> {
+  let mut num_received = 0;
+  let mut forwarded = false;
+  loop {
+    let response = tokio::time::timeout(wait_for(timeout, deadline), stream.next());
     let mut hanging = HashMap::new();
     let next = response.await;
     match next {
@@ -450,8 +765,10 @@ async fn output_handler(
         num_received += 1;
         if let Err(e) = message {
           span.in_scope(|| warn!(error=?e,"component-wide error"));
-          channel.dispatch_op_err(ctx_id, instance.index(), PacketPayload::fatal_error(e.to_string()));
-          break CompletionStatus::Error;
+          return Ok(StreamEnd::failed(
+            forwarded,
+            AttemptError::new(RetryCondition::Error, PacketPayload::fatal_error(e.to_string())),
+          ));
         }
         let message = message.unwrap();
 
@@ -461,8 +778,10 @@ async fn output_handler(
 
         if message.is_fatal_error() {
           span.in_scope(|| warn!(error=?message,"component-wide error"));
-          channel.dispatch_op_err(ctx_id, instance.index(), message.payload);
-          break CompletionStatus::Error;
+          return Ok(StreamEnd::failed(
+            forwarded,
+            AttemptError::new(RetryCondition::Error, message.payload),
+          ));
         }
 
         if message.is_noop() {
@@ -485,36 +804,51 @@ async fn output_handler(
 
         instance.buffer_out(&port, message);
         channel.dispatch_data(ctx_id, port);
+        forwarded = true;
       }
       Err(error) => {
         span.in_scope(|| warn!(%error,"timeout"));
-        let msg = format!(
-          "Execution timed out waiting for output from operation {} ({})",
-          instance.id(),
-          instance.entity()
-        );
-        channel.dispatch_op_err(ctx_id, instance.index(), PacketPayload::fatal_error(msg));
-        break CompletionStatus::Timeout;
+        return Ok(StreamEnd::failed(
+          forwarded,
+          AttemptError::new(RetryCondition::Timeout, timeout_payload(instance, deadline)),
+        ));
       }
       Ok(None) => {
         if num_received == 0 && instance.outputs().len() > 0 {
           let err = "operation produced no output, likely due to a panic or misconfiguration";
           span.in_scope(|| warn!(error = err, "stream complete"));
-          channel.dispatch_op_err(ctx_id, instance.index(), PacketPayload::fatal_error(err));
-          break CompletionStatus::Error;
+          return Ok(StreamEnd::failed(
+            forwarded,
+            AttemptError::new(RetryCondition::Panic, PacketPayload::fatal_error(err)),
+          ));
         }
         for (portref, port) in hanging {
           span.in_scope(|| debug!(%port,"auto-closing port"));
           instance.buffer_out(&portref, Packet::done(port));
         }
         span.in_scope(|| trace!("stream complete"));
-        break CompletionStatus::Finished;
+        return Ok(StreamEnd::Finished);
       }
     }
-  };
-  instance.handle_stream_complete(reason)?;
-  channel.dispatch_call_complete(ctx_id, instance.index());
-  Ok(())
+  }
+}
+
+/// The error reported when `instance` times out, distinguishing an exceeded invocation deadline from the
+/// operation's own timeout.
+fn timeout_payload(instance: &InstanceHandler, deadline: Option<Instant>) -> PacketPayload {
+  if deadline.is_some_and(|deadline| deadline <= Instant::now()) {
+    PacketPayload::Err(PacketError::budget_exceeded(format!(
+      "operation {} ({}) did not finish before the invocation's deadline",
+      instance.id(),
+      instance.entity()
+    )))
+  } else {
+    PacketPayload::fatal_error(format!(
+      "Execution timed out waiting for output from operation {} ({})",
+      instance.id(),
+      instance.entity()
+    ))
+  }
 }
 
 #[derive(Clone, Copy)]
@@ -524,6 +858,29 @@ pub(crate) enum CompletionStatus {
   Error,
 }
 
+/// The time an operation has to finish: what's left of its invocation's budget, cut short by its own timeout. A
+/// timeout of 0 places no limit.
+fn budget(remaining: Option<Duration>, timeout: Option<Duration>) -> Option<Duration> {
+  match (remaining, timeout.filter(|timeout| !timeout.is_zero())) {
+    (Some(remaining), Some(timeout)) => Some(remaining.min(timeout)),
+    (remaining, timeout) => remaining.or(timeout),
+  }
+}
+
+/// Whether a call failed because it, or something it invoked, exceeded its time budget.
+fn is_budget_exceeded(error: &ExecutionError) -> bool {
+  let ExecutionError::ComponentError(error) = error else {
+    return false;
+  };
+  error.chain().any(|e| {
+    e.downcast_ref::<wick_packet::Error>()
+      .map_or(false, wick_packet::Error::is_budget_exceeded)
+      || e
+        .downcast_ref::<PacketError>()
+        .map_or(false, PacketError::is_budget_exceeded)
+  })
+}
+
 #[cfg(test)]
 mod test {
   use anyhow::Result;
@@ -538,4 +895,15 @@ mod test {
     assert_sync::<InstanceHandler>();
     Ok(())
   }
+
+  #[test]
+  fn test_budget() {
+    let secs = Duration::from_secs;
+    assert_eq!(budget(None, None), None);
+    assert_eq!(budget(Some(secs(5)), None), Some(secs(5)));
+    assert_eq!(budget(None, Some(secs(5))), Some(secs(5)));
+    assert_eq!(budget(Some(secs(5)), Some(secs(2))), Some(secs(2)));
+    assert_eq!(budget(Some(secs(2)), Some(secs(5))), Some(secs(2)));
+    assert_eq!(budget(Some(secs(5)), Some(Duration::ZERO)), Some(secs(5)));
+  }
 }
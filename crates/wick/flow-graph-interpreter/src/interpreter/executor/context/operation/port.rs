@@ -1,11 +1,13 @@
-use flow_graph::PortReference;
+use flow_graph::{ConnectionIndex, PortReference};
 use wick_packet::Packet;
 
+mod merge_buffer;
 mod port_buffer;
 pub(crate) mod port_handler;
 
 use self::port_handler::PortHandler;
 use crate::graph::types::OperationPort;
+use crate::graph::OperationSettings;
 use crate::interpreter::executor::error::ExecutionError;
 type Result<T> = std::result::Result<T, ExecutionError>;
 type PacketType = Packet;
@@ -64,7 +66,7 @@ impl PortList {
     let instance = operation_instance.into();
     let ports = ports
       .into_iter()
-      .map(|p| PortHandler::new(instance.clone(), p))
+      .map(|p| PortHandler::new(instance.clone(), p, None))
       .collect();
     Self { inner: ports }
   }
@@ -82,8 +84,8 @@ impl PortList {
     self.inner.is_empty()
   }
 
-  pub(super) fn receive(&self, port: &PortReference, value: PacketType) {
-    self.inner[port.port_index()].buffer(value);
+  pub(super) fn receive(&self, port: &PortReference, connection: Option<ConnectionIndex>, value: PacketType) {
+    self.inner[port.port_index()].buffer(connection, value);
   }
 
   pub(super) fn take(&self, port: &PortReference) -> Option<PacketType> {
@@ -127,7 +129,7 @@ impl OutputPorts {
   }
 
   pub(super) fn receive(&self, port: &PortReference, value: PacketType) {
-    self.inner.receive(port, value);
+    self.inner.receive(port, None, value);
   }
 
   pub(super) fn take(&self, port: &PortReference) -> Option<PacketType> {
@@ -145,6 +147,16 @@ impl OutputPorts {
   pub(crate) fn find_ref(&self, name: &str) -> Option<PortReference> {
     self.inner.find_ref(name)
   }
+
+  pub(super) fn drain_packets(&self) -> Result<Vec<Packet>> {
+    let mut vec = Vec::new();
+
+    for handler in &self.inner.inner {
+      let mut drain = handler.drain();
+      vec.append(&mut drain);
+    }
+    Ok(vec)
+  }
 }
 
 #[derive(Debug)]
@@ -155,9 +167,22 @@ pub(crate) struct InputPorts {
 
 #[allow(unused)]
 impl InputPorts {
-  pub(super) fn new<T: Into<String>>(operation_instance: T, ports: Vec<OperationPort>) -> Self {
+  /// Create the input ports of an operation, merging each port's connections as its `settings` specify.
+  pub(super) fn new<T: Into<String>>(
+    operation_instance: T,
+    ports: Vec<OperationPort>,
+    settings: &OperationSettings,
+  ) -> Self {
+    let instance = operation_instance.into();
+    let inner = ports
+      .into_iter()
+      .map(|p| {
+        let merge = settings.merge(p.name());
+        PortHandler::new(instance.clone(), p, merge)
+      })
+      .collect();
     Self {
-      inner: PortList::new(operation_instance, ports),
+      inner: PortList { inner },
     }
   }
 
@@ -165,8 +190,8 @@ impl InputPorts {
     self.inner.is_empty()
   }
 
-  pub(super) fn receive(&self, port: &PortReference, value: PacketType) {
-    self.inner.receive(port, value);
+  pub(super) fn receive(&self, port: &PortReference, connection: Option<ConnectionIndex>, value: PacketType) {
+    self.inner.receive(port, connection, value);
   }
 
   pub(super) fn take(&self, port: &PortReference) -> Option<PacketType> {
@@ -177,6 +202,11 @@ impl InputPorts {
     self.inner.inner.iter()
   }
 
+  /// Whether `port` merges several connections rather than keeping packets in arrival order.
+  pub(super) fn is_merged(&self, port: &PortReference) -> bool {
+    self.inner.get_handler(port).is_merged()
+  }
+
   #[allow(unused)]
   pub(crate) fn len(&self) -> usize {
     self.inner.inner.len()
@@ -194,7 +224,7 @@ impl InputPorts {
     let mut vec = Vec::new();
 
     for handler in &self.inner.inner {
-      let mut drain = handler.drain(0..);
+      let mut drain = handler.drain();
       vec.append(&mut drain);
     }
     Ok(vec)
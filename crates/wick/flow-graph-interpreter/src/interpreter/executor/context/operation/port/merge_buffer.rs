@@ -0,0 +1,236 @@
+use std::collections::VecDeque;
+
+use flow_expression_parser::ast::MergeStrategy;
+use flow_graph::ConnectionIndex;
+use wick_packet::{Packet, PacketExt};
+
+type PacketType = Packet;
+
+/// Buffers the packets of every connection into an input port and releases them according to a [MergeStrategy].
+///
+/// Each connection's `done` is held back so the port only sees one, after every connection has finished.
+#[derive(Debug)]
+pub(super) struct MergeBuffer {
+  strategy: MergeStrategy,
+  /// The connections into the port, in the order they were declared.
+  connections: Vec<ConnectionIndex>,
+  queues: Vec<VecDeque<PacketType>>,
+  finished: Vec<bool>,
+  /// Packets released but not taken yet.
+  ready: VecDeque<PacketType>,
+  /// The connection whose turn it is for [MergeStrategy::RoundRobin].
+  turn: usize,
+  /// Set once a [MergeStrategy::Zip] can't make any more complete sets of packets.
+  exhausted: bool,
+  done: Option<PacketType>,
+}
+
+impl MergeBuffer {
+  pub(super) fn new(strategy: MergeStrategy, connections: &[ConnectionIndex]) -> Self {
+    Self {
+      strategy,
+      connections: connections.to_vec(),
+      queues: vec![VecDeque::new(); connections.len()],
+      finished: vec![false; connections.len()],
+      ready: VecDeque::new(),
+      turn: 0,
+      exhausted: false,
+      done: None,
+    }
+  }
+
+  /// Buffer a packet from `connection`. Returns true when it was the last connection's `done`.
+  pub(super) fn push(&mut self, connection: Option<ConnectionIndex>, value: PacketType) -> bool {
+    let Some(index) = connection.and_then(|c| self.connections.iter().position(|i| *i == c)) else {
+      warn!(
+        port = value.port(),
+        "packet for merged port did not come from one of its connections"
+      );
+      let is_done = value.is_done();
+      self.ready.push_back(value);
+      return is_done;
+    };
+
+    if value.is_done() {
+      self.finished[index] = true;
+      self.done = Some(value);
+      return self.finished.iter().all(|finished| *finished);
+    }
+    if self.exhausted {
+      trace!(port = value.port(), "dropping packet after zip finished");
+    } else {
+      self.queues[index].push_back(value);
+    }
+    false
+  }
+
+  /// Whether connection `index` has finished and had all of its packets released.
+  fn drained(&self, index: usize) -> bool {
+    self.finished[index] && self.queues[index].is_empty()
+  }
+
+  pub(super) fn is_empty(&self) -> bool {
+    self.ready.is_empty() && self.done.is_none() && self.queues.iter().all(VecDeque::is_empty)
+  }
+
+  /// The number of packets held, whether they are ready to be taken or not.
+  pub(super) fn len(&self) -> usize {
+    self.ready.len() + usize::from(self.done.is_some()) + self.queues.iter().map(VecDeque::len).sum::<usize>()
+  }
+
+  /// The next packet to deliver, or `None` when the strategy is waiting on a connection.
+  pub(super) fn take(&mut self) -> Option<PacketType> {
+    if let Some(packet) = self.ready.pop_front() {
+      return Some(packet);
+    }
+    let next = match self.strategy {
+      MergeStrategy::Ordered => {
+        let index = (0..self.queues.len()).find(|i| !self.drained(*i));
+        index.and_then(|index| self.queues[index].pop_front())
+      }
+      MergeStrategy::RoundRobin => self.take_turn(),
+      MergeStrategy::Zip => self.take_set(),
+      MergeStrategy::Priority => self.queues.iter_mut().find_map(VecDeque::pop_front),
+    };
+
+    next.or_else(|| {
+      if (0..self.queues.len()).all(|i| self.drained(i)) {
+        self.done.take()
+      } else {
+        None
+      }
+    })
+  }
+
+  fn take_turn(&mut self) -> Option<PacketType> {
+    let len = self.queues.len();
+    let index = (0..len)
+      .map(|offset| (self.turn + offset) % len)
+      .find(|i| !self.drained(*i))?;
+    // wait for the connection whose turn it is rather than skipping ahead.
+    let packet = self.queues[index].pop_front()?;
+    self.turn = (index + 1) % len;
+    Some(packet)
+  }
+
+  fn take_set(&mut self) -> Option<PacketType> {
+    if self.exhausted {
+      return None;
+    }
+    if self.queues.iter().all(|queue| !queue.is_empty()) {
+      let set = self.queues.iter_mut().filter_map(VecDeque::pop_front);
+      self.ready.extend(set);
+      return self.ready.pop_front();
+    }
+    if (0..self.queues.len()).any(|i| self.drained(i)) {
+      self.exhausted = true;
+      let dropped: usize = self.queues.iter_mut().map(|queue| queue.drain(..).count()).sum();
+      if dropped > 0 {
+        debug!(dropped, "zip finished, dropping unmatched packets");
+      }
+    }
+    None
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn merge(strategy: MergeStrategy, packets: &[(usize, Option<i32>)]) -> Vec<Option<i32>> {
+    let mut buffer = MergeBuffer::new(strategy, &[10, 11, 12]);
+    for (connection, value) in packets {
+      let packet = value.map_or_else(|| Packet::done("in"), |v| Packet::encode("in", v));
+      buffer.push(Some(*connection), packet);
+    }
+    std::iter::from_fn(|| buffer.take())
+      .map(|packet| (!packet.is_done()).then(|| packet.decode().unwrap()))
+      .collect()
+  }
+
+  fn all_done() -> Vec<(usize, Option<i32>)> {
+    vec![(10, None), (11, None), (12, None)]
+  }
+
+  #[test]
+  fn test_ordered() {
+    let mut packets = vec![(12, Some(3)), (11, Some(2)), (10, Some(1)), (11, Some(22))];
+    packets.extend(all_done());
+    assert_eq!(
+      merge(MergeStrategy::Ordered, &packets),
+      vec![Some(1), Some(2), Some(22), Some(3), None]
+    );
+  }
+
+  #[test]
+  fn test_ordered_waits_for_earlier_connections() {
+    let packets = vec![(11, Some(2)), (11, None), (12, None)];
+    assert!(merge(MergeStrategy::Ordered, &packets).is_empty());
+  }
+
+  #[test]
+  fn test_round_robin() {
+    let mut packets = vec![
+      (10, Some(1)),
+      (10, Some(11)),
+      (10, Some(111)),
+      (11, Some(2)),
+      (12, Some(3)),
+      (12, Some(33)),
+    ];
+    packets.extend(all_done());
+    assert_eq!(
+      merge(MergeStrategy::RoundRobin, &packets),
+      vec![Some(1), Some(2), Some(3), Some(11), Some(33), Some(111), None]
+    );
+  }
+
+  #[test]
+  fn test_zip() {
+    let mut packets = vec![
+      (10, Some(1)),
+      (10, Some(11)),
+      (11, Some(2)),
+      (11, Some(22)),
+      (12, Some(3)),
+      (10, Some(111)),
+    ];
+    packets.extend(all_done());
+    assert_eq!(
+      merge(MergeStrategy::Zip, &packets),
+      vec![Some(1), Some(2), Some(3), None]
+    );
+  }
+
+  #[test]
+  fn test_priority() {
+    let mut packets = vec![(12, Some(3)), (11, Some(2)), (12, Some(33)), (10, Some(1))];
+    packets.extend(all_done());
+    assert_eq!(
+      merge(MergeStrategy::Priority, &packets),
+      vec![Some(1), Some(2), Some(3), Some(33), None]
+    );
+  }
+
+  #[test]
+  fn test_single_done() {
+    let mut buffer = MergeBuffer::new(MergeStrategy::Priority, &[0, 1]);
+    assert!(!buffer.push(Some(0), Packet::done("in")));
+    assert!(buffer.take().is_none());
+    assert!(buffer.push(Some(1), Packet::done("in")));
+    assert!(buffer.take().unwrap().is_done());
+    assert!(buffer.take().is_none());
+    assert!(buffer.is_empty());
+  }
+
+  #[test]
+  fn test_len() {
+    let mut buffer = MergeBuffer::new(MergeStrategy::Ordered, &[0, 1]);
+    buffer.push(Some(1), Packet::encode("in", 2));
+    buffer.push(Some(1), Packet::done("in"));
+    buffer.push(Some(0), Packet::encode("in", 1));
+    assert_eq!(buffer.len(), 3);
+    assert!(buffer.take().is_some());
+    assert_eq!(buffer.len(), 2);
+  }
+}
@@ -1,56 +1,92 @@
 use std::collections::VecDeque;
 use std::fmt::Debug;
-use std::ops::RangeBounds;
 
+use flow_expression_parser::ast::MergeStrategy;
+use flow_graph::ConnectionIndex;
 use parking_lot::Mutex;
-use wick_packet::Packet;
+use wick_packet::{Packet, PacketExt};
+
+use super::merge_buffer::MergeBuffer;
 
 type PacketType = Packet;
 
 pub(super) struct PortBuffer {
-  buffer: Mutex<VecDeque<PacketType>>,
+  buffer: Mutex<Buffer>,
+}
+
+enum Buffer {
+  /// Packets in the order they arrived.
+  Fifo(VecDeque<PacketType>),
+  Merged(MergeBuffer),
 }
 
 impl Debug for PortBuffer {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let len = self.buffer.lock().len();
-    f.debug_struct("Buffer").field("len", &len).finish()
+    match &*self.buffer.lock() {
+      Buffer::Fifo(buffer) => f.debug_struct("Buffer").field("len", &buffer.len()).finish(),
+      Buffer::Merged(buffer) => f.debug_struct("Buffer").field("merged", buffer).finish(),
+    }
   }
 }
 
 impl Default for PortBuffer {
   fn default() -> Self {
     Self {
-      buffer: Mutex::new(Default::default()),
+      buffer: Mutex::new(Buffer::Fifo(Default::default())),
     }
   }
 }
 
 impl PortBuffer {
-  pub(super) fn push(&self, value: PacketType) {
-    self.buffer.lock().push_back(value);
+  /// A buffer that merges the packets of `connections` with `strategy`, or keeps them in arrival order if there is none.
+  pub(super) fn new(strategy: Option<MergeStrategy>, connections: &[ConnectionIndex]) -> Self {
+    strategy.map_or_else(Self::default, |strategy| Self {
+      buffer: Mutex::new(Buffer::Merged(MergeBuffer::new(strategy, connections))),
+    })
+  }
+
+  /// Buffer a packet that arrived over `connection`. Returns true when the port has received its final `done`.
+  pub(super) fn push(&self, connection: Option<ConnectionIndex>, value: PacketType) -> bool {
+    match &mut *self.buffer.lock() {
+      Buffer::Fifo(buffer) => {
+        let is_done = value.is_done();
+        buffer.push_back(value);
+        is_done
+      }
+      Buffer::Merged(buffer) => buffer.push(connection, value),
+    }
+  }
+
+  pub(super) fn is_merged(&self) -> bool {
+    matches!(&*self.buffer.lock(), Buffer::Merged(_))
   }
 
   pub(super) fn is_empty(&self) -> bool {
-    self.buffer.lock().is_empty()
+    match &*self.buffer.lock() {
+      Buffer::Fifo(buffer) => buffer.is_empty(),
+      Buffer::Merged(buffer) => buffer.is_empty(),
+    }
   }
 
-  // pub(super) fn len(&self) -> usize {
-  //   self.buffer.lock().len()
-  // }
+  pub(super) fn len(&self) -> usize {
+    match &*self.buffer.lock() {
+      Buffer::Fifo(buffer) => buffer.len(),
+      Buffer::Merged(buffer) => buffer.len(),
+    }
+  }
 
   pub(super) fn take(&self) -> Option<PacketType> {
-    self.buffer.lock().pop_front()
+    match &mut *self.buffer.lock() {
+      Buffer::Fifo(buffer) => buffer.pop_front(),
+      Buffer::Merged(buffer) => buffer.take(),
+    }
   }
 
-  pub(super) fn drain<R>(&self, range: R) -> Vec<PacketType>
-  where
-    R: RangeBounds<usize>,
-  {
-    self.buffer.lock().drain(range).collect()
+  /// Take every packet that is ready to be delivered.
+  pub(super) fn drain(&self) -> Vec<PacketType> {
+    match &mut *self.buffer.lock() {
+      Buffer::Fifo(buffer) => buffer.drain(..).collect(),
+      Buffer::Merged(buffer) => std::iter::from_fn(|| buffer.take()).collect(),
+    }
   }
-
-  // pub(super) fn clone_buffer(&self) -> Vec<PacketType> {
-  //   self.buffer.lock().iter().cloned().collect()
-  // }
 }
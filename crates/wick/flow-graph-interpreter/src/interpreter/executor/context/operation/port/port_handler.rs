@@ -1,9 +1,8 @@
-use std::ops::RangeBounds;
-
-use flow_graph::PortReference;
+use flow_expression_parser::ast::MergeStrategy;
+use flow_graph::{ConnectionIndex, PortReference};
 use parking_lot::Mutex;
 use tracing::Span;
-use wick_packet::{Packet, PacketExt};
+use wick_packet::Packet;
 
 use super::port_buffer::PortBuffer;
 use super::PortStatus;
@@ -44,9 +43,9 @@ impl std::fmt::Debug for PortHandler {
 }
 
 impl PortHandler {
-  pub(super) fn new<T: Into<String>>(operation_instance: T, port: OperationPort) -> Self {
+  pub(super) fn new<T: Into<String>>(operation_instance: T, port: OperationPort, merge: Option<MergeStrategy>) -> Self {
     Self {
-      buffer: Default::default(),
+      buffer: PortBuffer::new(merge, port.connections()),
       operation_instance: operation_instance.into(),
       port,
       status: Mutex::new(PortStatus::Open),
@@ -105,14 +104,17 @@ impl PortHandler {
     *self.status.lock()
   }
 
-  pub(super) fn buffer(&self, value: PacketType) {
+  pub(super) fn buffer(&self, connection: Option<ConnectionIndex>, value: PacketType) {
     if self.get_status() == PortStatus::DoneClosed {
       warn!(port=%self, "trying to buffer on closed port");
     }
-    if value.is_done() {
+    if self.buffer.push(connection, value) {
       self.set_status(PortStatus::DoneClosing);
     }
-    self.buffer.push(value);
+  }
+
+  pub(super) fn is_merged(&self) -> bool {
+    self.buffer.is_merged()
   }
 
   pub(super) fn take(&self) -> Option<PacketType> {
@@ -125,14 +127,11 @@ impl PortHandler {
     result
   }
 
-  pub(super) fn drain<R>(&self, range: R) -> Vec<PacketType>
-  where
-    R: RangeBounds<usize>,
-  {
+  pub(super) fn drain(&self) -> Vec<PacketType> {
     if self.buffer.is_empty() {
       return vec![];
     }
-    let packets = self.buffer.drain(range);
+    let packets = self.buffer.drain();
     trace!(port=%self,packets=?packets, "draining buffer");
 
     let status = self.get_status();
@@ -145,4 +144,9 @@ impl PortHandler {
   pub(crate) fn is_empty(&self) -> bool {
     self.buffer.is_empty()
   }
+
+  /// The number of packets waiting in the port's buffer.
+  pub(crate) fn buffered(&self) -> usize {
+    self.buffer.len()
+  }
 }
@@ -0,0 +1,232 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use flow_component::LocalScope;
+use parking_lot::Mutex;
+use seeded_random::{Random, Seed};
+use tokio::sync::Notify;
+use tokio_stream::StreamExt;
+use tracing::Span;
+use uuid::Uuid;
+use wick_config::config::{RetryCondition, RetryPolicy};
+use wick_packet::{Entity, InherentData, InvocationData, Packet, PacketPayload, PacketStream, RuntimeConfig};
+
+use super::{await_stream, CompletionStatus, InstanceHandler};
+use crate::interpreter::executor::context::chaos::ChaosPolicy;
+
+/// Why an attempt at invoking an operation failed.
+#[derive(Debug)]
+pub(crate) struct AttemptError {
+  pub(crate) condition: RetryCondition,
+  pub(crate) payload: PacketPayload,
+}
+
+impl AttemptError {
+  pub(crate) const fn new(condition: RetryCondition, payload: PacketPayload) -> Self {
+    Self { condition, payload }
+  }
+
+  pub(crate) const fn status(&self) -> CompletionStatus {
+    match self.condition {
+      RetryCondition::Timeout => CompletionStatus::Timeout,
+      _ => CompletionStatus::Error,
+    }
+  }
+}
+
+/// The state needed to invoke an operation again after a failed attempt.
+pub(crate) struct Retry {
+  policy: RetryPolicy,
+  attempts: u32,
+  rng: Random,
+  origin: Entity,
+  target: Entity,
+  id: Uuid,
+  tx_id: Uuid,
+  seed: u64,
+  timestamp: u64,
+  deadline: Option<u64>,
+  call_depth: u32,
+  span: Span,
+  config: Option<RuntimeConfig>,
+  callback: LocalScope,
+  chaos: Option<ChaosPolicy>,
+  input: Option<ReplayBuffer>,
+}
+
+impl Retry {
+  pub(crate) fn new(
+    policy: RetryPolicy,
+    invocation: &InvocationData,
+    config: Option<RuntimeConfig>,
+    callback: LocalScope,
+    chaos: Option<ChaosPolicy>,
+    input: Option<PacketStream>,
+  ) -> Self {
+    Self {
+      policy,
+      attempts: 1,
+      rng: Random::from_seed(Seed::unsafe_new(invocation.inherent.seed)),
+      origin: invocation.origin.clone(),
+      target: invocation.target.clone(),
+      id: invocation.id,
+      tx_id: invocation.tx_id,
+      seed: invocation.inherent.seed,
+      timestamp: invocation.inherent.timestamp,
+      deadline: invocation.inherent.deadline,
+      call_depth: invocation.inherent.call_depth,
+      span: invocation.span.clone(),
+      config,
+      callback,
+      chaos,
+      input: input.map(ReplayBuffer::record),
+    }
+  }
+
+  /// A stream of the operation's input from its first packet on.
+  pub(crate) fn input(&self) -> PacketStream {
+    self
+      .input
+      .as_ref()
+      .map_or_else(PacketStream::noop, ReplayBuffer::replay)
+  }
+
+  /// Whether the policy allows another attempt after `failure`.
+  pub(crate) fn retries(&self, failure: &AttemptError) -> bool {
+    self.policy.should_retry(self.attempts, failure.condition)
+  }
+
+  /// How long to wait before the next attempt, or `None` when the policy doesn't retry `failure` or the wait
+  /// would run past `deadline`. The delay is the policy's backoff with up to half of it taken off at random.
+  pub(crate) fn next_delay(&mut self, failure: &AttemptError, deadline: Option<Instant>) -> Option<Duration> {
+    if !self.retries(failure) {
+      return None;
+    }
+    let ceiling = self.policy.backoff(self.attempts);
+    let delay = ceiling / 2 + (ceiling / 2).mul_f64(self.rng.gen::<f64>());
+    if deadline.is_some_and(|deadline| Instant::now() + delay >= deadline) {
+      return None;
+    }
+    self.attempts += 1;
+    Some(delay)
+  }
+
+  pub(crate) const fn attempts(&self) -> u32 {
+    self.attempts
+  }
+
+  /// Invoke the operation again with a replay of its input.
+  pub(crate) async fn attempt(
+    &self,
+    instance: &InstanceHandler,
+    wait: Duration,
+    deadline: Option<Instant>,
+    span: &Span,
+  ) -> Result<PacketStream, AttemptError> {
+    let inherent = InherentData::new(self.seed, self.timestamp)
+      .with_deadline(self.deadline)
+      .with_call_depth(self.call_depth);
+    let invocation = InvocationData::new_raw(
+      self.origin.clone(),
+      self.target.clone(),
+      self.id,
+      self.tx_id,
+      inherent,
+      self.span.clone(),
+    )
+    .with_stream(self.input());
+    let chaos = self
+      .chaos
+      .as_ref()
+      .and_then(|policy| policy.call(&instance.entity(), self.seed, self.attempts));
+    match instance.invoke(invocation, self.config.clone(), self.callback.clone(), chaos) {
+      Ok(fut) => await_stream(instance, fut, wait, deadline, span).await,
+      Err(e) => Err(AttemptError::new(
+        RetryCondition::Error,
+        PacketPayload::fatal_error(e.to_string()),
+      )),
+    }
+  }
+}
+
+/// Records the input of an operation so that a retried invocation receives the same packets as the first one.
+#[derive(Clone)]
+struct ReplayBuffer {
+  state: Arc<Mutex<ReplayState>>,
+  notify: Arc<Notify>,
+}
+
+#[derive(Default)]
+struct ReplayState {
+  packets: Vec<Result<Packet, wick_packet::Error>>,
+  done: bool,
+}
+
+impl ReplayBuffer {
+  fn record(mut input: PacketStream) -> Self {
+    let buffer = Self {
+      state: Default::default(),
+      notify: Default::default(),
+    };
+    let recorder = buffer.clone();
+    tokio::spawn(async move {
+      while let Some(packet) = input.next().await {
+        recorder.state.lock().packets.push(packet);
+        recorder.notify.notify_waiters();
+      }
+      recorder.state.lock().done = true;
+      recorder.notify.notify_waiters();
+    });
+    buffer
+  }
+
+  /// A stream of every packet recorded so far, followed by the packets that arrive later.
+  fn replay(&self) -> PacketStream {
+    let stream = futures::stream::unfold((self.clone(), 0), |(buffer, index)| async move {
+      loop {
+        // Created before checking the state so a packet recorded in between still wakes us.
+        let notify = buffer.notify.clone();
+        let notified = notify.notified();
+        let next = {
+          let state = buffer.state.lock();
+          match state.packets.get(index) {
+            Some(packet) => Some(Some(packet.clone())),
+            None if state.done => Some(None),
+            None => None,
+          }
+        };
+        match next {
+          Some(Some(packet)) => return Some((packet, (buffer, index + 1))),
+          Some(None) => return None,
+          None => notified.await,
+        }
+      }
+    });
+    PacketStream::new(Box::pin(stream))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use wasmrs_rx::Observer;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_replay() -> Result<()> {
+    let (tx, rx) = wick_packet::PacketStream::new_channels();
+    let buffer = ReplayBuffer::record(rx);
+    tx.send(Packet::encode("in", 1))?;
+    let first = buffer.replay();
+    tx.send(Packet::encode("in", 2))?;
+    tx.send(Packet::done("in"))?;
+    tx.complete();
+
+    let first: Vec<_> = first.collect::<Result<_, _>>().await?;
+    let second: Vec<_> = buffer.replay().collect::<Result<_, _>>().await?;
+    assert_eq!(first.len(), 3);
+    assert_eq!(first, second);
+    Ok(())
+  }
+}
@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+/// A point-in-time view of a transaction in progress, taken from [crate::State] to find transactions and operations
+/// that hold on to memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransactionSnapshot {
+  /// The transaction's id.
+  pub id: Uuid,
+  /// The name of the operation the transaction executes.
+  pub operation: String,
+  /// The time since the transaction started.
+  pub age: Duration,
+  /// Whether the transaction has finished delivering its output, even if some of its operations are still running.
+  pub done: bool,
+  /// The operations that are still running or have packets waiting in their port buffers.
+  pub instances: Vec<InstanceSnapshot>,
+}
+
+impl TransactionSnapshot {
+  /// Create a snapshot of a transaction of `operation` with no pending operations.
+  #[must_use]
+  pub fn new<T: Into<String>>(id: Uuid, operation: T, age: Duration) -> Self {
+    Self {
+      id,
+      operation: operation.into(),
+      age,
+      done: false,
+      instances: Vec::new(),
+    }
+  }
+
+  /// The number of packets waiting in the port buffers of every operation in the transaction.
+  #[must_use]
+  pub fn buffered(&self) -> usize {
+    self.instances.iter().map(|i| i.buffered).sum()
+  }
+}
+
+/// The state of one operation within a [TransactionSnapshot].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InstanceSnapshot {
+  /// The id of the operation instance in its flow.
+  pub id: String,
+  /// The operation the instance calls, e.g. `core::merge`.
+  pub entity: String,
+  /// Whether the operation has been called and its output hasn't finished.
+  pub running: bool,
+  /// The number of packets waiting in the instance's input and output port buffers.
+  pub buffered: usize,
+}
+
+impl InstanceSnapshot {
+  /// Create a snapshot of the instance `id` calling `entity`.
+  #[must_use]
+  pub fn new<T: Into<String>, E: Into<String>>(id: T, entity: E, running: bool, buffered: usize) -> Self {
+    Self {
+      id: id.into(),
+      entity: entity.into(),
+      running,
+      buffered,
+    }
+  }
+}
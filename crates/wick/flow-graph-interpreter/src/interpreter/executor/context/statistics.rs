@@ -1,22 +1,95 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
 use parking_lot::Mutex;
 use performance_mark::Performance;
 use uuid::Uuid;
+use wick_packet::Entity;
+
+/// A summary of a transaction whose output has finished, reported to [crate::Observer::on_transaction_done].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransactionStatistics {
+  /// The transaction's id.
+  pub id: Uuid,
+  /// The name of the operation the transaction executed.
+  pub operation: String,
+  /// The time from the start of the transaction until its output finished.
+  pub duration: Duration,
+  /// The number of packets emitted by operations within the transaction.
+  pub packets: u64,
+  /// The number of error packets and operation failures within the transaction.
+  pub errors: u64,
+  /// The id of the entity that started the transaction, like the trigger that invoked it.
+  pub origin: String,
+  /// The number of times each operation was started within the transaction, keyed by namespace and operation name.
+  pub operations: BTreeMap<(String, String), u64>,
+}
+
+impl TransactionStatistics {
+  /// Create statistics for a transaction of `operation` with every measurement at zero.
+  #[must_use]
+  pub fn new<T: Into<String>>(id: Uuid, operation: T) -> Self {
+    Self {
+      id,
+      operation: operation.into(),
+      duration: Duration::ZERO,
+      packets: 0,
+      errors: 0,
+      origin: String::new(),
+      operations: BTreeMap::new(),
+    }
+  }
+}
 
 #[derive(Debug)]
 #[must_use]
 pub(crate) struct ExecutionStatistics {
-  #[allow(unused)]
   pub(crate) id: Uuid,
   pub(crate) performance: Mutex<Performance>,
+  packets: AtomicU64,
+  errors: AtomicU64,
+  origin: String,
+  operations: Mutex<BTreeMap<(String, String), u64>>,
 }
 
 impl ExecutionStatistics {
-  pub(crate) fn new(uuid: Uuid) -> Self {
+  pub(crate) fn new(uuid: Uuid, origin: &Entity) -> Self {
     Self {
       id: uuid,
       performance: Mutex::new(Default::default()),
+      packets: AtomicU64::new(0),
+      errors: AtomicU64::new(0),
+      origin: origin.component_id().to_owned(),
+      operations: Mutex::new(BTreeMap::new()),
+    }
+  }
+  pub(crate) fn packet(&self, is_error: bool) {
+    self.packets.fetch_add(1, Ordering::Relaxed);
+    if is_error {
+      self.error();
     }
   }
+  pub(crate) fn error(&self) {
+    self.errors.fetch_add(1, Ordering::Relaxed);
+  }
+  pub(crate) fn operation_started(&self, entity: &Entity) {
+    *self
+      .operations
+      .lock()
+      .entry((entity.component_id().to_owned(), entity.operation_id().to_owned()))
+      .or_default() += 1;
+  }
+  pub(crate) fn summarize(&self, operation: &str, duration: Duration) -> TransactionStatistics {
+    let mut stats = TransactionStatistics::new(self.id, operation);
+    stats.duration = duration;
+    stats.packets = self.packets.load(Ordering::Relaxed);
+    stats.errors = self.errors.load(Ordering::Relaxed);
+    stats.origin = self.origin.clone();
+    stats.operations = self.operations.lock().clone();
+    stats
+  }
   pub(crate) fn mark<T: Into<String>>(&self, label: T) {
     self.performance.lock().mark(label);
   }
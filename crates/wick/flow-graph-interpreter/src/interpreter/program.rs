@@ -10,6 +10,7 @@ use crate::graph::types::*;
 use crate::interpreter::components::self_component::SelfComponent;
 
 pub(crate) mod validator;
+use self::validator::coercion::CoercionRules;
 use super::components::{reconcile_op_id, ComponentMap};
 use super::error::Error;
 
@@ -35,8 +36,8 @@ impl Program {
     self.state.network.schematics()
   }
 
-  pub(crate) fn validate(&self) -> Result<(), Error> {
-    self::validator::validate(self)?;
+  pub(crate) fn validate(&self, coercions: &CoercionRules) -> Result<(), Error> {
+    self::validator::validate(self, coercions)?;
     Ok(())
   }
 
@@ -44,6 +45,11 @@ impl Program {
     let schematic = self.state.get_schematic(op)?;
     Ok(schematic.render_dot())
   }
+
+  pub(crate) fn content_hash(&self, op: &str) -> Result<String, Error> {
+    let schematic = self.state.get_schematic(op)?;
+    Ok(crate::graph::content_hash(schematic))
+  }
 }
 
 fn get_resolution_order(network: &Network) -> Result<Vec<Vec<&Schematic>>, ValidationError> {
@@ -138,7 +144,10 @@ fn get_schematic_signature(
         }
         _ => continue,
       };
-      schematic_signature.inputs.push(Field::new(port.name(), signature));
+      let default = schematic.input().data().port_default(port.name()).cloned();
+      schematic_signature
+        .inputs
+        .push(Field::new(port.name(), signature).with_default(default));
       break;
     }
   }
@@ -1,10 +1,23 @@
+use std::collections::{HashMap, HashSet};
+
 use flow_graph::iterators::{SchematicWalker, WalkDirection};
 use flow_graph::NodeKind;
+use tracing::warn;
+use wick_interface_types::Type;
+use wick_packet::PacketPayload;
 
+use self::coercion::CoercionRules;
 use self::error::{OperationInvalid, ValidationError};
 use super::Program;
-use crate::interpreter::components::reconcile_op_id;
+use crate::graph::types::Schematic;
+use crate::interpreter::components::component::ComponentComponent;
+use crate::interpreter::components::core::CoreComponent;
+use crate::interpreter::components::flow::{call_target, FlowComponent};
+use crate::interpreter::components::internal::InternalComponent;
+use crate::interpreter::components::null::NullComponent;
+use crate::interpreter::components::{reconcile_op_id, ComponentMap};
 
+pub(crate) mod coercion;
 pub(crate) mod error;
 
 type Result = std::result::Result<(), Vec<OperationInvalid>>;
@@ -140,10 +153,203 @@ impl Validator {
       Err(errors)
     }
   }
+
+  /// Reject flows that reach themselves through `flow::call`, which would recurse until the depth limit.
+  fn validate_flow_calls(&self, program: &Program) -> Result {
+    let network = &program.state().network;
+    let calls: HashMap<&str, Vec<String>> = network
+      .schematics()
+      .iter()
+      .map(|schematic| {
+        let targets = schematic
+          .nodes()
+          .iter()
+          .filter(|node| FlowComponent::is_call(node))
+          .filter_map(|node| call_target(node).ok())
+          .collect();
+        (schematic.name(), targets)
+      })
+      .collect();
+
+    let mut errors = Vec::new();
+    let mut checked = HashSet::new();
+    for schematic in network.schematics() {
+      if let Some(cycle) = find_cycle(schematic.name(), &calls, &mut Vec::new(), &mut checked) {
+        errors.push(OperationInvalid::new(
+          schematic.name().to_owned(),
+          vec![ValidationError::CallCycle(cycle)],
+        ));
+      }
+    }
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  /// Reject input defaults that can't be read as their port's declared type.
+  fn validate_defaults(&self, program: &Program) -> Result {
+    let mut errors = Vec::new();
+    for schematic in program.state().network.schematics() {
+      let mut validation_errors = Vec::new();
+      for field in &schematic.input().data().defaults {
+        let Some(default) = field.default() else {
+          continue;
+        };
+        if !is_resolvable(&field.ty) {
+          debug!(port = %field.name, ty = %field.ty, "skipping validation of default for named type");
+          continue;
+        }
+        if let Err(e) = PacketPayload::encode(default).type_wrapper(field.ty.clone()) {
+          validation_errors.push(ValidationError::InvalidDefault {
+            port: field.name.clone(),
+            ty: field.ty.to_string(),
+            error: e.to_string(),
+          });
+        }
+      }
+      if !validation_errors.is_empty() {
+        errors.push(OperationInvalid::new(schematic.name().to_owned(), validation_errors));
+      }
+    }
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  /// Reject connections between operations whose output type can't be sent to the input it's connected to.
+  fn validate_types(&self, program: &Program, rules: &CoercionRules) -> Result {
+    let components = &program.state().components;
+    let mut errors = Vec::new();
+    for schematic in program.state().network.schematics() {
+      let mut validation_errors = Vec::new();
+      for connection in schematic.connections() {
+        let from = port_type(schematic, components, connection.from());
+        let to = port_type(schematic, components, connection.to());
+        let (Some(from_ty), Some(to_ty)) = (from, to) else {
+          continue;
+        };
+        if !rules.allows(from_ty, to_ty) {
+          validation_errors.push(ValidationError::TypeMismatch {
+            connection: format!(
+              "{}.{} -> {}.{}",
+              schematic.nodes()[connection.from().node_index()].name,
+              schematic.get_port_name(connection.from()),
+              schematic.nodes()[connection.to().node_index()].name,
+              schematic.get_port_name(connection.to())
+            ),
+            from: from_ty.to_string(),
+            to: to_ty.to_string(),
+          });
+        }
+      }
+      if !validation_errors.is_empty() {
+        errors.push(OperationInvalid::new(schematic.name().to_owned(), validation_errors));
+      }
+    }
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+}
+
+/// The declared type of an operation's port, if it has one that can be checked. The interpreter's own operations
+/// adapt their ports to whatever they're connected to, so they don't have one.
+fn port_type<'a>(
+  schematic: &Schematic,
+  components: &'a ComponentMap,
+  port: &flow_graph::PortReference,
+) -> Option<&'a Type> {
+  let node = &schematic.nodes()[port.node_index()];
+  let NodeKind::External(reference) = node.kind() else {
+    return None;
+  };
+  let dynamic = [
+    CoreComponent::ID,
+    FlowComponent::ID,
+    ComponentComponent::ID,
+    NullComponent::ID,
+    InternalComponent::ID,
+  ];
+  if dynamic.contains(&reference.component_id()) {
+    return None;
+  }
+  let id = reconcile_op_id(reference.component_id(), reference.name(), schematic.name(), node.id());
+  let signature = components.get(reference.component_id())?.get_operation(&id)?;
+  let name = schematic.get_port_name(port);
+  let fields = match port.direction() {
+    flow_graph::PortDirection::In => &signature.inputs,
+    flow_graph::PortDirection::Out => &signature.outputs,
+  };
+  fields.iter().find(|f| f.name == name).map(|f| &f.ty)
+}
+
+/// Whether `ty` can be checked without resolving named types, which aren't known to the interpreter.
+fn is_resolvable(ty: &Type) -> bool {
+  match ty {
+    Type::Named(_) => false,
+    Type::List { ty } | Type::Optional { ty } => is_resolvable(ty),
+    Type::Map { key, value } => is_resolvable(key) && is_resolvable(value),
+    Type::AnonymousStruct(fields) => fields.iter().all(|f| is_resolvable(&f.ty)),
+    _ => true,
+  }
 }
 
-pub(crate) fn validate(program: &Program) -> Result {
+/// Walk the flows `flow` calls, returning the first path that leads back to a flow already on `path`.
+fn find_cycle<'a>(
+  flow: &'a str,
+  calls: &'a HashMap<&'a str, Vec<String>>,
+  path: &mut Vec<&'a str>,
+  checked: &mut HashSet<&'a str>,
+) -> Option<Vec<String>> {
+  if let Some(start) = path.iter().position(|f| *f == flow) {
+    let mut cycle: Vec<_> = path[start..].iter().map(|f| (*f).to_owned()).collect();
+    cycle.push(flow.to_owned());
+    return Some(cycle);
+  }
+  if checked.contains(flow) {
+    return None;
+  }
+  path.push(flow);
+  let cycle = calls.get(flow).and_then(|targets| {
+    targets
+      .iter()
+      .find_map(|target| find_cycle(target, calls, path, checked))
+  });
+  path.pop();
+  checked.insert(flow);
+  cycle
+}
+
+pub(crate) fn validate(program: &Program, rules: &CoercionRules) -> Result {
   let validator = Validator {};
-  validator.validate_external_components(program)?;
-  Ok(())
+  // every pass runs, even after one fails, so all of a program's problems are reported at once.
+  let mut errors: Vec<_> = [
+    validator.validate_external_components(program),
+    validator.validate_flow_calls(program),
+    validator.validate_defaults(program),
+  ]
+  .into_iter()
+  .filter_map(std::result::Result::err)
+  .flatten()
+  .collect();
+  if let Err(mismatches) = validator.validate_types(program, rules) {
+    if rules.is_strict() {
+      errors.extend(mismatches);
+    } else {
+      for operation in mismatches {
+        warn!(%operation, "connected ports have incompatible types");
+      }
+    }
+  }
+  if errors.is_empty() {
+    Ok(())
+  } else {
+    Err(errors)
+  }
 }
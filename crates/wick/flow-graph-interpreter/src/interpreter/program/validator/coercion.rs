@@ -0,0 +1,143 @@
+use wick_interface_types::Type;
+
+use super::is_resolvable;
+
+/// The conversions allowed when an operation's output is connected to an input of a different type.
+///
+/// The defaults only include conversions that can't lose data: widening integers and integers to floats that can hold
+/// every value. Values are sent unchanged, so a conversion should only be allowed when the input can decode the
+/// output's values as they are.
+///
+/// Connections the rules don't allow are logged as warnings unless the rules are [strict](CoercionRules::strict).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct CoercionRules {
+  allowed: Vec<(Type, Type)>,
+  strict: bool,
+}
+
+impl Default for CoercionRules {
+  fn default() -> Self {
+    let widening = [
+      (Type::I8, vec![Type::I16, Type::I32, Type::I64, Type::F32, Type::F64]),
+      (Type::I16, vec![Type::I32, Type::I64, Type::F32, Type::F64]),
+      (Type::I32, vec![Type::I64, Type::F64]),
+      (
+        Type::U8,
+        vec![
+          Type::U16,
+          Type::U32,
+          Type::U64,
+          Type::I16,
+          Type::I32,
+          Type::I64,
+          Type::F32,
+          Type::F64,
+        ],
+      ),
+      (
+        Type::U16,
+        vec![Type::U32, Type::U64, Type::I32, Type::I64, Type::F32, Type::F64],
+      ),
+      (Type::U32, vec![Type::U64, Type::I64, Type::F64]),
+      (Type::F32, vec![Type::F64]),
+    ];
+    Self {
+      allowed: widening
+        .into_iter()
+        .flat_map(|(from, to)| to.into_iter().map(move |to| (from.clone(), to)))
+        .collect(),
+      strict: false,
+    }
+  }
+}
+
+impl CoercionRules {
+  /// Rules that only allow connecting ports of the same type.
+  pub const fn none() -> Self {
+    Self {
+      allowed: Vec::new(),
+      strict: false,
+    }
+  }
+
+  /// Whether connections the rules don't allow fail validation instead of being logged as warnings.
+  pub const fn strict(mut self, strict: bool) -> Self {
+    self.strict = strict;
+    self
+  }
+
+  /// Whether connections the rules don't allow fail validation.
+  #[must_use]
+  pub const fn is_strict(&self) -> bool {
+    self.strict
+  }
+
+  /// Also allow values of type `from` to be sent to inputs of type `to`.
+  pub fn allow(mut self, from: Type, to: Type) -> Self {
+    if !self.allows(&from, &to) {
+      self.allowed.push((from, to));
+    }
+    self
+  }
+
+  /// Whether a value of type `from` can be sent to an input of type `to`.
+  ///
+  /// Objects and named types can't be checked before running and are always allowed.
+  #[must_use]
+  pub fn allows(&self, from: &Type, to: &Type) -> bool {
+    if from == to || !is_resolvable(from) || !is_resolvable(to) {
+      return true;
+    }
+    match (from, to) {
+      (Type::Object, _) | (_, Type::Object) => true,
+      (Type::Optional { ty: from }, Type::Optional { ty: to }) | (Type::List { ty: from }, Type::List { ty: to }) => {
+        self.allows(from, to)
+      }
+      (from, Type::Optional { ty: to }) => self.allows(from, to),
+      (
+        Type::Map {
+          key: from_key,
+          value: from_value,
+        },
+        Type::Map {
+          key: to_key,
+          value: to_value,
+        },
+      ) => self.allows(from_key, to_key) && self.allows(from_value, to_value),
+      (from, to) => self.allowed.iter().any(|(f, t)| f == from && t == to),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_default_rules() {
+    let rules = CoercionRules::default();
+    assert!(rules.allows(&Type::I32, &Type::I64));
+    assert!(!rules.allows(&Type::I64, &Type::I32));
+    assert!(!rules.allows(&Type::String, &Type::Datetime));
+    assert!(!rules.is_strict());
+    let list = |ty| Type::List { ty: Box::new(ty) };
+    assert!(rules.allows(&list(Type::U8), &list(Type::U32)));
+    assert!(!rules.allows(&list(Type::U32), &list(Type::U8)));
+    let optional = Type::Optional {
+      ty: Box::new(Type::U64),
+    };
+    assert!(rules.allows(&Type::U16, &optional));
+    assert!(rules.allows(&Type::Named("User".to_owned()), &Type::String));
+    assert!(rules.allows(&Type::Bool, &Type::Object));
+  }
+
+  #[test]
+  fn test_configured_rules() {
+    let rules = CoercionRules::none();
+    assert!(!rules.allows(&Type::I32, &Type::I64));
+    let rules = rules.allow(Type::I64, Type::String);
+    assert!(rules.allows(&Type::I64, &Type::String));
+    assert!(!rules.allows(&Type::String, &Type::I64));
+  }
+}
@@ -13,6 +13,12 @@ pub enum ValidationError {
   #[error("Could not find component referenced by id '{0}'")]
   ComponentIdNotFound(String),
 
+  #[error("Operation '{id}' calls flow '{flow}', which does not exist")]
+  MissingFlow { id: String, flow: String },
+
+  #[error("Flows call each other in a cycle: {}", .0.join(" -> "))]
+  CallCycle(Vec<String>),
+
   #[error("Missing operation '{name}' on component '{component}'")]
   MissingOperation { component: String, name: String },
 
@@ -62,6 +68,16 @@ pub enum ValidationError {
     operation: String,
   },
 
+  #[error("Default for input '{port}' does not match its type '{ty}': {error}")]
+  InvalidDefault { port: String, ty: String, error: String },
+
+  #[error("Connection '{connection}' sends {from} to an input that expects {to}")]
+  TypeMismatch {
+    connection: String,
+    from: String,
+    to: String,
+  },
+
   #[error("Unused output port '{port}' on operation '{id}' ('{component}::{operation}')")]
   UnusedOutput {
     port: String,
@@ -82,6 +98,18 @@ impl OperationInvalid {
   pub fn new(schematic: String, errors: Vec<ValidationError>) -> Self {
     Self { schematic, errors }
   }
+
+  /// The name of the flow that failed validation.
+  #[must_use]
+  pub fn schematic(&self) -> &str {
+    &self.schematic
+  }
+
+  /// Everything wrong with the flow.
+  #[must_use]
+  pub fn errors(&self) -> &[ValidationError] {
+    &self.errors
+  }
 }
 
 impl std::fmt::Display for OperationInvalid {
@@ -0,0 +1,83 @@
+use flow_graph::SCHEMATIC_INPUT;
+use serde::{Deserialize, Serialize};
+use tracing::Span;
+use uuid::Uuid;
+use wick_packet::{Entity, InherentData, Invocation, Packet, PacketStream, RuntimeConfig};
+
+use super::error::Error;
+use crate::{Interpreter, TransactionEvent, TransactionEventKind};
+
+/// A transaction reconstructed from the [TransactionEvent]s recorded by an [crate::EventListener].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Transaction {
+  /// The id of the recorded transaction.
+  pub tx_id: Uuid,
+  /// The name of the operation the transaction executed.
+  pub operation: String,
+  /// The seed the transaction was invoked with.
+  pub seed: u64,
+  /// The timestamp the transaction was invoked with.
+  pub timestamp: u64,
+  /// The configuration the operation was invoked with.
+  pub config: Option<RuntimeConfig>,
+  /// The packets the transaction received on its inputs, in the order they arrived.
+  pub inputs: Vec<Packet>,
+}
+
+impl Transaction {
+  /// Reconstruct the transaction `tx_id` from a recorded trace. Events of other transactions are ignored.
+  pub fn from_events<'a, I>(tx_id: Uuid, events: I) -> Result<Self, Error>
+  where
+    I: IntoIterator<Item = &'a TransactionEvent>,
+  {
+    let mut tx = None;
+    let mut inputs = Vec::new();
+    for event in events.into_iter().filter(|e| e.tx_id == tx_id) {
+      match &event.kind {
+        TransactionEventKind::Started {
+          operation,
+          seed,
+          timestamp,
+          config,
+        } => {
+          tx = Some(Self {
+            tx_id,
+            operation: operation.clone(),
+            seed: *seed,
+            timestamp: *timestamp,
+            config: config.clone(),
+            inputs: Vec::new(),
+          });
+        }
+        TransactionEventKind::PacketAccepted { operation, packet, .. } if operation == SCHEMATIC_INPUT => {
+          inputs.push(packet.clone());
+        }
+        // packets for unconnected inputs are part of the input too, the dead letter policy may act on them.
+        TransactionEventKind::DeadLetter { packet, .. } => {
+          inputs.push(packet.clone());
+        }
+        _ => {}
+      }
+    }
+    let mut tx = tx.ok_or(Error::IncompleteTrace(tx_id))?;
+    tx.inputs = inputs;
+    Ok(tx)
+  }
+
+  /// Execute the transaction again on `interpreter` with its recorded inputs, seed, timestamp, and configuration.
+  ///
+  /// The replay runs as a new transaction. Operations with side effects outside of the interpreter (e.g. HTTP or SQL
+  /// calls) are executed again rather than replayed from the trace.
+  pub async fn replay(&self, interpreter: &Interpreter) -> Result<PacketStream, Error> {
+    let stream = PacketStream::new(Box::new(futures::stream::iter(self.inputs.clone().into_iter().map(Ok))));
+    let invocation = Invocation::new(
+      Entity::test(format!("replay:{}", self.tx_id)),
+      Entity::local(&self.operation),
+      stream,
+      InherentData::new(self.seed, self.timestamp),
+      &Span::current(),
+    );
+    interpreter.invoke(invocation, self.config.clone()).await
+  }
+}
@@ -129,4 +129,14 @@ pub use interpreter::channel::{Event, EventKind};
 pub use interpreter::components::{HandlerMap, NamespaceHandler};
 pub use interpreter::event_loop::state::State;
 pub use interpreter::event_loop::Observer;
+pub use interpreter::executor::context::chaos::{ChaosFaults, ChaosPolicy};
+pub use interpreter::executor::context::checkpoint::{Checkpoint, CheckpointStore, Durability, MemoryCheckpoints};
+pub use interpreter::executor::context::dead_letter::DeadLetterPolicy;
+pub use interpreter::executor::context::events::{EventListener, TransactionEvent, TransactionEventKind};
+pub use interpreter::executor::context::lineage::{PacketOrigin, TransactionLineage};
+pub use interpreter::executor::context::snapshot::{InstanceSnapshot, TransactionSnapshot};
+pub use interpreter::executor::context::statistics::TransactionStatistics;
+pub use interpreter::program::validator::coercion::CoercionRules;
+pub use interpreter::replay::Transaction;
 pub use interpreter::{Interpreter, InterpreterOptions};
+pub use flow_component::ObservabilityLevel;
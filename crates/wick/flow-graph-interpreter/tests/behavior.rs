@@ -3,7 +3,7 @@ mod test;
 use anyhow::Result;
 use flow_component::Component;
 use pretty_assertions::assert_eq;
-use wick_packet::{packets, Packet};
+use wick_packet::{packets, Observer, Packet};
 
 #[test_logger::test(tokio::test)]
 async fn test_forked_input() -> Result<()> {
@@ -43,3 +43,508 @@ async fn test_unused_instances() -> Result<()> {
 
   Ok(())
 }
+
+#[test_logger::test(tokio::test)]
+async fn test_merge_strategy() -> Result<()> {
+  let (interpreter, outputs) =
+    test::common_setup("./tests/manifests/v1/behavior-merge-ordered.yaml", "test", Vec::new()).await?;
+
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+  assert_eq!(
+    outputs,
+    vec![
+      Packet::encode("output", "second"),
+      Packet::encode("output", "first"),
+      Packet::done("output")
+    ]
+  );
+
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_connection_transform() -> Result<()> {
+  use serde_json::json;
+
+  let (interpreter, outputs) = test::common_setup(
+    "./tests/manifests/v1/behavior-transform.yaml",
+    "test",
+    packets!(("input", json!({ "first_name": "Ada", "last_name": "Lovelace" }))),
+  )
+  .await?;
+
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+  assert_eq!(
+    outputs,
+    vec![
+      Packet::encode("output", json!({ "name": "Ada Lovelace", "greeting": "Hello Ada!" })),
+      Packet::done("output")
+    ]
+  );
+
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_input_default() -> Result<()> {
+  use serde_json::json;
+
+  // a port that finishes without data and a port that's never sent anything both get the default.
+  for packets in [
+    vec![
+      Packet::encode("name", "Ada"),
+      Packet::done("name"),
+      Packet::done("greeting"),
+    ],
+    vec![Packet::encode("name", "Ada"), Packet::done("name")],
+  ] {
+    let (interpreter, outputs) =
+      test::common_setup("./tests/manifests/v1/behavior-input-default.yaml", "test", packets).await?;
+
+    let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(
+      outputs[0].decode_value()?,
+      json!({ "name": "Ada", "greeting": "Hello" })
+    );
+    assert_eq!(outputs[1], Packet::done("output"));
+    interpreter.shutdown().await?;
+  }
+
+  let (interpreter, outputs) = test::common_setup(
+    "./tests/manifests/v1/behavior-input-default.yaml",
+    "test",
+    packets!(("name", "Ada"), ("greeting", "Hi")),
+  )
+  .await?;
+
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+  assert_eq!(outputs.len(), 2);
+  assert_eq!(outputs[0].decode_value()?, json!({ "name": "Ada", "greeting": "Hi" }));
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_event_listener() -> Result<()> {
+  use std::sync::{Arc, Mutex};
+
+  use flow_graph_interpreter::{EventListener, InterpreterOptions, TransactionEventKind};
+  use serde_json::json;
+  use wick_packet::PacketExt;
+
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let mut options = InterpreterOptions::default();
+  let sink = events.clone();
+  options.event_listener = Some(EventListener::new(move |event| {
+    sink.lock().unwrap().push(event.clone());
+  }));
+
+  let (interpreter, outputs) = test::options_setup(
+    "./tests/manifests/v1/core-pluck.yaml",
+    wick_packet::Entity::local("test"),
+    packets!(("input", json!({ "to_pluck": "Hello world!" }))),
+    None,
+    None,
+    options,
+  )
+  .await?;
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+  assert_eq!(outputs[0], Packet::encode("output", "Hello world!"));
+  interpreter.shutdown().await?;
+
+  let events = events.lock().unwrap().clone();
+  let tx_id = events[0].tx_id;
+  assert!(events.iter().all(|e| e.tx_id == tx_id));
+  assert!(matches!(&events[0].kind, TransactionEventKind::Started { operation, .. } if operation == "test"));
+  assert!(events.iter().any(|e| matches!(
+    &e.kind,
+    TransactionEventKind::OperationStarted { operation, entity } if operation == "p" && entity.contains("pluck")
+  )));
+  assert!(events.iter().any(|e| matches!(
+    &e.kind,
+    TransactionEventKind::PacketAccepted { operation, port, .. } if operation == "p" && port == "input"
+  )));
+  assert!(events.iter().any(|e| matches!(
+    &e.kind,
+    TransactionEventKind::Output { port, packet } if port == "output" && packet.has_data()
+  )));
+  assert!(events.iter().any(|e| matches!(
+    &e.kind,
+    TransactionEventKind::Finished { operation, errors: 0, .. } if operation == "test"
+  )));
+
+  let record = serde_json::to_value(&events[0])?;
+  assert_eq!(record["event"], "started");
+  assert_eq!(record["operation"], "test");
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_replay() -> Result<()> {
+  use std::sync::{Arc, Mutex};
+
+  use flow_graph_interpreter::{EventListener, InterpreterOptions, Transaction, TransactionEvent};
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+
+  let log = Arc::new(Mutex::new(Vec::new()));
+  let mut options = InterpreterOptions::default();
+  let sink = log.clone();
+  options.event_listener = Some(EventListener::new(move |event| {
+    sink.lock().unwrap().push(serde_json::to_string(event).unwrap());
+  }));
+
+  let (interpreter, outputs) = test::options_setup(
+    "./tests/manifests/v1/core-pluck.yaml",
+    wick_packet::Entity::local("test"),
+    packets!(("input", json!({ "to_pluck": "Hello world!" }))),
+    None,
+    None,
+    options,
+  )
+  .await?;
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+  let events = log
+    .lock()
+    .unwrap()
+    .iter()
+    .map(|line| serde_json::from_str(line))
+    .collect::<Result<Vec<TransactionEvent>, _>>()?;
+  let tx = Transaction::from_events(events[0].tx_id, &events)?;
+  assert_eq!(tx.operation, "test");
+  assert!(Transaction::from_events(uuid::Uuid::new_v4(), &events).is_err());
+
+  let replayed = tx.replay(&interpreter).await?;
+  let replayed = replayed.collect::<Result<Vec<_>, _>>().await?;
+  assert_eq!(replayed, outputs);
+
+  interpreter.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_expired_deadline() -> Result<()> {
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+  use wick_packet::{InherentData, Invocation, PacketPayload};
+
+  let (interpreter, _) = test::common_setup(
+    "./tests/manifests/v1/core-pluck.yaml",
+    "test",
+    packets!(("input", json!({ "to_pluck": "Hello world!" }))),
+  )
+  .await?;
+
+  let inherent = InherentData::unsafe_default().with_deadline(Some(1));
+  let stream = packets!(("input", json!({ "to_pluck": "Hello world!" })));
+  let invocation = Invocation::test("test", wick_packet::Entity::local("test"), stream, Some(inherent))?;
+  let outputs = interpreter.invoke(invocation, None).await?;
+  let outputs = outputs.collect::<Result<Vec<_>, _>>().await?;
+
+  assert!(outputs
+    .iter()
+    .any(|p| matches!(p.payload(), PacketPayload::Err(e) if e.is_budget_exceeded())));
+
+  interpreter.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_cancel() -> Result<()> {
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+  use wick_packet::{Invocation, PacketPayload, PacketStream};
+
+  let (interpreter, _) = test::common_setup(
+    "./tests/manifests/v1/core-pluck.yaml",
+    "test",
+    packets!(("input", json!({ "to_pluck": "Hello world!" }))),
+  )
+  .await?;
+
+  // leave the input open so the transaction can't finish on its own.
+  let (tx, stream) = PacketStream::new_channels();
+  let _ = tx.send(Packet::encode("input", json!({ "to_pluck": "Hello world!" })));
+  let invocation = Invocation::test("test", wick_packet::Entity::local("test"), stream, None)?;
+  let tx_id = invocation.tx_id();
+  let mut outputs = interpreter.invoke(invocation, None).await?;
+  let first = outputs.next().await.unwrap()?;
+  assert_eq!(first, Packet::encode("output", "Hello world!"));
+
+  interpreter.cancel(tx_id);
+  let rest = outputs.collect::<Result<Vec<_>, _>>().await?;
+
+  assert!(matches!(rest[0].payload(), PacketPayload::Err(e) if e.is_cancelled()));
+  assert_eq!(rest[1], Packet::done("output"));
+
+  drop(tx);
+  interpreter.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_flow_call() -> Result<()> {
+  use std::collections::HashMap;
+
+  use serde_json::json;
+  use wick_packet::{Entity, RuntimeConfig};
+
+  // the called flow only sees the configuration its call passes, not the caller's.
+  let (interpreter, outputs) = test::base_setup(
+    "./tests/manifests/v1/behavior-flow-call.yaml",
+    Entity::local("test"),
+    packets!(("input", "World")),
+    None,
+    Some(RuntimeConfig::from(HashMap::from([(
+      "greeting".to_owned(),
+      json!("Goodbye, "),
+    )]))),
+  )
+  .await?;
+
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+  assert_eq!(
+    outputs,
+    vec![Packet::encode("output", "Hello, World"), Packet::done("output")]
+  );
+
+  interpreter.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_flow_call_depth() -> Result<()> {
+  use wick_packet::PacketPayload;
+
+  let (interpreter, outputs) = test::common_setup(
+    "./tests/manifests/v1/behavior-flow-call.yaml",
+    "nested",
+    packets!(("input", "World")),
+  )
+  .await?;
+
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+  assert!(outputs
+    .iter()
+    .any(|p| matches!(p.payload(), PacketPayload::Err(e) if e.msg().contains("nested 1 deep"))));
+
+  interpreter.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_dead_letter_error() -> Result<()> {
+  use flow_graph_interpreter::{DeadLetterPolicy, InterpreterOptions};
+  use wick_packet::PacketPayload;
+
+  let mut options = InterpreterOptions::default();
+  options.dead_letter = DeadLetterPolicy::Error;
+
+  let (interpreter, outputs) = test::options_setup(
+    "./tests/manifests/v1/behavior-dead-letter.yaml",
+    wick_packet::Entity::local("test"),
+    packets!(("input", "hello"), ("extra", 42)),
+    None,
+    None,
+    options,
+  )
+  .await?;
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+  assert!(outputs
+    .iter()
+    .any(|p| matches!(p.payload(), PacketPayload::Err(e) if e.msg().contains("input 'extra'"))));
+  assert_eq!(outputs.last(), Some(&Packet::done("output")));
+
+  interpreter.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_dead_letter_route() -> Result<()> {
+  use std::sync::{Arc, Mutex};
+  use std::time::Duration;
+
+  use flow_graph_interpreter::{DeadLetterPolicy, EventListener, InterpreterOptions, TransactionEventKind};
+  use serde_json::Value;
+
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let mut options = InterpreterOptions::default();
+  options.dead_letter = DeadLetterPolicy::Route("deadletter".to_owned());
+  let sink = events.clone();
+  options.event_listener = Some(EventListener::new(move |event| {
+    sink.lock().unwrap().push(event.clone());
+  }));
+
+  let (interpreter, outputs) = test::options_setup(
+    "./tests/manifests/v1/behavior-dead-letter.yaml",
+    wick_packet::Entity::local("test"),
+    packets!(("input", "hello"), ("extra", 42)),
+    None,
+    None,
+    options,
+  )
+  .await?;
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+  assert_eq!(outputs, vec![Packet::encode("output", "hello"), Packet::done("output")]);
+
+  // the dead letter operation runs on its own, so wait for it to receive the letter.
+  let mut letter = None;
+  for _ in 0..100 {
+    letter = events.lock().unwrap().iter().find_map(|e| match &e.kind {
+      TransactionEventKind::PacketAccepted { operation, packet, .. } if operation == "<input>" => {
+        packet.decode::<Value>().ok().filter(Value::is_object)
+      }
+      _ => None,
+    });
+    if letter.is_some() {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(10)).await;
+  }
+  let letter = letter.expect("dead letter operation never received the packet");
+  assert_eq!(letter["operation"], "test");
+  assert_eq!(letter["packet"]["port"], "extra");
+  assert_eq!(letter["packet"]["payload"]["value"], 42);
+
+  assert!(events.lock().unwrap().iter().any(|e| matches!(
+    &e.kind,
+    TransactionEventKind::DeadLetter { port, .. } if port == "extra"
+  )));
+
+  interpreter.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_durable_resume() -> Result<()> {
+  use std::sync::atomic::Ordering;
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use flow_graph_interpreter::{CheckpointStore, Durability, InterpreterOptions, MemoryCheckpoints};
+  use tokio_stream::StreamExt;
+  use wick_packet::{Entity, InherentData, Invocation};
+
+  let manifest = "./tests/manifests/v1/behavior-durable.yaml";
+  let store = Arc::new(MemoryCheckpoints::default());
+  let mut options = InterpreterOptions::default();
+  options.durability = Some(Durability::new(store.clone()));
+
+  // `first` finishes, `second` never does, and the interpreter goes away mid-transaction.
+  let interpreter = test::start_interpreter(manifest, None, options.clone()).await?;
+  let invocation = Invocation::new(
+    Entity::component("caller"),
+    Entity::local("test"),
+    packets!(("input", "hello")),
+    InherentData::unsafe_default(),
+    &tracing::Span::current(),
+  );
+  let tx_id = invocation.tx_id();
+  let _abandoned = interpreter.invoke(invocation, None).await?;
+  let mut checkpoint = None;
+  for _ in 0..100 {
+    checkpoint = store
+      .load()
+      .await?
+      .into_iter()
+      .find(|c| c.completed.contains_key("first"));
+    if checkpoint.is_some() {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(10)).await;
+  }
+  let checkpoint = checkpoint.expect("transaction was never checkpointed after `first` finished");
+  assert_eq!(checkpoint.tx_id, tx_id);
+  assert_eq!(checkpoint.operation, "test");
+  assert_eq!(checkpoint.origin, Entity::component("caller"));
+  assert!(checkpoint.done.contains("input"));
+  assert_eq!(
+    checkpoint.inputs,
+    vec![Packet::encode("input", "hello"), Packet::done("input")]
+  );
+  interpreter.shutdown().await?;
+
+  let interpreter = test::start_interpreter(manifest, None, options).await?;
+  let mut resumed = interpreter.resume().await?;
+  assert_eq!(resumed.len(), 1);
+  let (resumed_id, stream) = resumed.pop().unwrap();
+  assert_eq!(resumed_id, tx_id);
+  let outputs = stream.collect::<Result<Vec<_>, _>>().await?;
+  assert_eq!(outputs, vec![Packet::encode("output", "hello"), Packet::done("output")]);
+  assert_eq!(
+    test::TALLY_CALLS.load(Ordering::SeqCst),
+    1,
+    "`first` should be replayed, not called again"
+  );
+
+  // the finished transaction's checkpoint is dropped.
+  for _ in 0..100 {
+    if store.load().await?.is_empty() {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(10)).await;
+  }
+  assert!(store.load().await?.is_empty());
+
+  interpreter.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_durable_resume_open_input() -> Result<()> {
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use flow_graph_interpreter::{CheckpointStore, Durability, InterpreterOptions, MemoryCheckpoints};
+  use tokio_stream::StreamExt;
+  use wick_packet::{Entity, Invocation, PacketStream};
+
+  let manifest = "./tests/manifests/v1/behavior-durable.yaml";
+  let store = Arc::new(MemoryCheckpoints::default());
+  let mut options = InterpreterOptions::default();
+  options.durability = Some(Durability::new(store.clone()));
+
+  // the caller goes away before it finished sending its input.
+  let interpreter = test::start_interpreter(manifest, None, options.clone()).await?;
+  let (tx, stream) = PacketStream::new_channels();
+  let _ = tx.send(Packet::encode("input", "hello"));
+  let invocation = Invocation::test("partial", Entity::local("partial"), stream, None)?;
+  let _abandoned = interpreter.invoke(invocation, None).await?;
+  let mut checkpoint = None;
+  for _ in 0..100 {
+    checkpoint = store.load().await?.into_iter().find(|c| !c.inputs.is_empty());
+    if checkpoint.is_some() {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(10)).await;
+  }
+  let checkpoint = checkpoint.expect("transaction was never checkpointed after receiving input");
+  assert!(checkpoint.done.is_empty());
+  interpreter.shutdown().await?;
+  drop(tx);
+
+  let interpreter = test::start_interpreter(manifest, None, options).await?;
+  let mut resumed = interpreter.resume().await?;
+  assert_eq!(resumed.len(), 1);
+  let (_, stream) = resumed.pop().unwrap();
+  let outputs = tokio::time::timeout(Duration::from_secs(5), stream.collect::<Result<Vec<_>, _>>())
+    .await
+    .expect("resumed transaction never finished")?;
+  assert_eq!(outputs, vec![Packet::encode("output", "hello"), Packet::done("output")]);
+
+  interpreter.shutdown().await?;
+  Ok(())
+}
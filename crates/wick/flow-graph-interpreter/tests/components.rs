@@ -8,7 +8,7 @@ use flow_component::Component;
 use pretty_assertions::assert_eq;
 use serde_json::json;
 use test::*;
-use wick_packet::{packets, ComponentReference, Entity, Packet, PacketExt, RuntimeConfig};
+use wick_packet::{packets, BlobStore, ComponentReference, Entity, Packet, PacketExt, RuntimeConfig};
 
 #[test_logger::test(tokio::test)]
 async fn test_echo() -> Result<()> {
@@ -105,6 +105,136 @@ async fn test_timeout_fail() -> Result<()> {
   Ok(())
 }
 
+#[test_logger::test(tokio::test)]
+async fn test_timeout_start() -> Result<()> {
+  let start = SystemTime::now();
+  let (interpreter, mut outputs) = test::base_setup(
+    "./tests/manifests/v1/component-slow-start.yaml",
+    Entity::local("test"),
+    packets!(("input", "hello world")),
+    None,
+    None,
+  )
+  .await?;
+  let elapsed = SystemTime::now().duration_since(start)?;
+
+  assert_eq!(outputs.len(), 2);
+
+  let _wrapper = outputs.pop().unwrap(); //done signal
+  let packet = outputs.pop().unwrap()?;
+  assert!(packet.unwrap_err().msg().contains("timed out"));
+  assert!(elapsed.as_millis() < 1000);
+
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_retry() -> Result<()> {
+  let (interpreter, mut outputs) = test::base_setup(
+    "./tests/manifests/v1/component-retry.yaml",
+    Entity::local("test"),
+    packets!(("input", "hello world")),
+    None,
+    None,
+  )
+  .await?;
+
+  assert_eq!(outputs.len(), 2);
+
+  let _wrapper = outputs.pop().unwrap(); //done signal
+  let wrapper = outputs.pop().unwrap();
+  assert_eq!(wrapper.unwrap(), Packet::encode("output", "hello world"));
+
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_retry_exhausted() -> Result<()> {
+  let (interpreter, mut outputs) = test::base_setup(
+    "./tests/manifests/v1/component-retry-exhausted.yaml",
+    Entity::local("test"),
+    packets!(("input", "hello world")),
+    None,
+    None,
+  )
+  .await?;
+
+  assert_eq!(outputs.len(), 2);
+
+  let _wrapper = outputs.pop().unwrap(); //done signal
+  let packet = outputs.pop().unwrap()?;
+  assert!(packet.unwrap_err().msg().contains("always errors"));
+
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_chaos_error() -> Result<()> {
+  use flow_graph_interpreter::{ChaosFaults, ChaosPolicy, InterpreterOptions};
+
+  let mut faults = ChaosFaults::default();
+  faults.error = 1.0;
+  let mut chaos = ChaosPolicy::default();
+  chaos.targets.insert("test::echo".to_owned(), faults);
+  let mut options = InterpreterOptions::default();
+  options.chaos = Some(chaos);
+
+  let (interpreter, mut outputs) = test::options_setup(
+    "./tests/manifests/v1/component-chaos.yaml",
+    Entity::local("test"),
+    packets!(("input", "hello world")),
+    None,
+    None,
+    options,
+  )
+  .await?;
+
+  assert_eq!(outputs.len(), 2);
+
+  let _wrapper = outputs.pop().unwrap(); //done signal
+  let packet = outputs.pop().unwrap()?;
+  assert!(packet.unwrap_err().msg().contains("chaos: injected error"));
+
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_chaos_drop() -> Result<()> {
+  use flow_graph_interpreter::{ChaosFaults, ChaosPolicy, InterpreterOptions};
+
+  let mut faults = ChaosFaults::default();
+  faults.drop = 1.0;
+  let mut chaos = ChaosPolicy::default();
+  chaos.default = Some(faults);
+  let mut options = InterpreterOptions::default();
+  options.chaos = Some(chaos);
+
+  let (interpreter, outputs) = test::options_setup(
+    "./tests/manifests/v1/component-chaos.yaml",
+    Entity::local("test"),
+    packets!(("input", "hello world")),
+    None,
+    None,
+    options,
+  )
+  .await?;
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+  assert_eq!(outputs, vec![Packet::done("output")]);
+
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
 #[test_logger::test(tokio::test)]
 async fn test_context_passing() -> Result<()> {
   let (interpreter, mut outputs) = test::base_setup(
@@ -439,3 +569,27 @@ async fn test_subflows() -> Result<()> {
   )
   .await
 }
+
+#[test_logger::test(tokio::test)]
+async fn test_blobs() -> Result<()> {
+  // `put_blob` puts its input in the transaction's blobs and sends a reference that `get_blob` resolves.
+  let (interpreter, mut outputs) = test::common_setup(
+    "./tests/manifests/v1/component-blobs.yaml",
+    "test",
+    packets!(("input", "a large payload")),
+  )
+  .await?;
+
+  assert_eq!(outputs.len(), 2);
+  let _ = outputs.pop();
+  assert_eq!(outputs.pop().unwrap()?, Packet::encode("output", "a large payload"));
+
+  let blob = LAST_BLOB.lock().unwrap().clone().unwrap();
+  interpreter.shutdown().await?;
+  assert!(
+    BlobStore::global().get(&blob).is_none(),
+    "the blob should be released with its transaction"
+  );
+
+  Ok(())
+}
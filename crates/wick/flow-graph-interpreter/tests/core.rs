@@ -51,6 +51,72 @@ async fn test_pluck_substreams() -> Result<()> {
   .await
 }
 
+#[test_logger::test(tokio::test)]
+async fn test_filter() -> Result<()> {
+  test_config(
+    "./tests/manifests/v1/core-filter.yaml",
+    None,
+    None,
+    vec![
+      Packet::open_bracket("input"),
+      Packet::encode("input", json!({"age": 12})),
+      Packet::encode("input", json!({"age": 30})),
+      Packet::close_bracket("input"),
+      Packet::done("input"),
+    ],
+    vec![
+      Packet::open_bracket("output"),
+      Packet::encode("output", json!({"age": 30})),
+      Packet::close_bracket("output"),
+      Packet::done("output"),
+    ],
+  )
+  .await
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_for_each() -> Result<()> {
+  test_config(
+    "./tests/manifests/v1/core-for-each.yaml",
+    None,
+    None,
+    vec![
+      Packet::encode("input", json!(["alpha", "beta", "gamma"])),
+      Packet::encode("input", json!([])),
+      Packet::done("input"),
+    ],
+    vec![
+      Packet::encode("output", json!(["ALPHA", "BETA", "GAMMA"])),
+      Packet::encode("output", json!([])),
+      Packet::done("output"),
+    ],
+  )
+  .await
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_for_each_stream() -> Result<()> {
+  test_config(
+    "./tests/manifests/v1/core-for-each-stream.yaml",
+    None,
+    None,
+    vec![
+      Packet::open_bracket("input"),
+      Packet::encode("input", json!(["alpha", "beta"])),
+      Packet::close_bracket("input"),
+      Packet::done("input"),
+    ],
+    vec![
+      Packet::open_bracket("output"),
+      Packet::encode("output", "ALPHA"),
+      Packet::encode("output", "BETA"),
+      Packet::close_bracket("output"),
+      Packet::done("output"),
+    ],
+  )
+  .await
+}
+
 #[test_logger::test(tokio::test)]
 async fn test_pluck_shorthand() -> Result<()> {
   first_packet_test(
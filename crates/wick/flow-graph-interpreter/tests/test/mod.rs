@@ -9,7 +9,7 @@ use flow_graph_interpreter::Interpreter;
 pub use observer::JsonWriter;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-pub use test_component::TestComponent;
+pub use test_component::{TestComponent, LAST_BLOB, TALLY_CALLS};
 use wick_packet::{Entity, Packet, PacketExt, RuntimeConfig};
 
 use crate::test;
@@ -36,10 +36,35 @@ pub async fn base_setup(
   component_config: Option<RuntimeConfig>,
   config: Option<RuntimeConfig>,
 ) -> anyhow::Result<(Interpreter, Vec<Result<Packet, wick_packet::Error>>)> {
-  use flow_graph_interpreter::{HandlerMap, InterpreterOptions, NamespaceHandler};
+  options_setup(manifest, entity, packets, component_config, config, Default::default()).await
+}
+
+pub async fn options_setup(
+  manifest: &str,
+  entity: Entity,
+  packets: Vec<Packet>,
+  component_config: Option<RuntimeConfig>,
+  config: Option<RuntimeConfig>,
+  options: flow_graph_interpreter::InterpreterOptions,
+) -> anyhow::Result<(Interpreter, Vec<Result<Packet, wick_packet::Error>>)> {
   use tokio_stream::StreamExt;
   use wick_packet::Invocation;
-  let options = Some(InterpreterOptions::default());
+  let interpreter = start_interpreter(manifest, component_config, options).await?;
+  let stream = wick_packet::PacketStream::new(Box::new(futures::stream::iter(packets.into_iter().map(Ok))));
+  let invocation = Invocation::test("test", entity, stream, None)?;
+  let stream = interpreter.invoke(invocation, config).await?;
+  let outputs: Vec<_> = stream.collect().await;
+  println!("{:#?}", outputs);
+  Ok((interpreter, outputs))
+}
+
+pub async fn start_interpreter(
+  manifest: &str,
+  component_config: Option<RuntimeConfig>,
+  options: flow_graph_interpreter::InterpreterOptions,
+) -> anyhow::Result<Interpreter> {
+  use flow_graph_interpreter::{HandlerMap, NamespaceHandler};
+  let options = Some(options);
   let mut def = wick_config::WickConfiguration::fetch(manifest, Default::default()).await?;
   def.set_root_config(component_config);
   let mut def = def.finish()?.try_component_config()?;
@@ -57,16 +82,12 @@ pub async fn base_setup(
     Some(collections),
     Default::default(),
     None,
+    None,
     &tracing::Span::current(),
   )?;
 
   interpreter.start(options, None).await;
-  let stream = wick_packet::PacketStream::new(Box::new(futures::stream::iter(packets.into_iter().map(Ok))));
-  let invocation = Invocation::test("test", entity, stream, None)?;
-  let stream = interpreter.invoke(invocation, config).await?;
-  let outputs: Vec<_> = stream.collect().await;
-  println!("{:#?}", outputs);
-  Ok((interpreter, outputs))
+  Ok(interpreter)
 }
 
 pub fn from_packet_file(file: &str) -> Result<Vec<Packet>> {
@@ -19,7 +19,7 @@ impl Observer for JsonWriter {
           "ctx_id": ctx_id.to_string(),
           "name" : tx.schematic_name()})
       }
-      EventKind::ExecutionDone => {
+      EventKind::ExecutionDone | EventKind::Cancel => {
         serde_json::json!({
           "type":event.name(),
           "index": index,
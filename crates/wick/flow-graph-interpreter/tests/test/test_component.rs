@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
 use std::time::{Duration, SystemTime};
 
 use anyhow::{anyhow, Result};
@@ -13,6 +15,7 @@ use wick_interface_types::{ComponentSignature, OperationSignature, Type};
 use wick_packet::{
   fan_out,
   packet_stream,
+  BlobRef,
   ComponentReference,
   InherentData,
   Invocation,
@@ -34,6 +37,26 @@ impl TestComponent {
           .add_input("input", Type::String)
           .add_output("output", Type::String),
       )
+      .add_operation(
+        OperationSignature::new_named("slow_start")
+          .add_input("input", Type::String)
+          .add_output("output", Type::String),
+      )
+      .add_operation(
+        OperationSignature::new_named("flaky")
+          .add_input("input", Type::String)
+          .add_output("output", Type::String),
+      )
+      .add_operation(
+        OperationSignature::new_named("tally")
+          .add_input("input", Type::String)
+          .add_output("output", Type::String),
+      )
+      .add_operation(
+        OperationSignature::new_named("hang_once")
+          .add_input("input", Type::String)
+          .add_output("output", Type::String),
+      )
       .add_operation(OperationSignature::new_named("empty_stream").add_output("output", Type::String))
       .add_operation(
         OperationSignature::new_named("wait")
@@ -141,6 +164,16 @@ impl TestComponent {
         OperationSignature::new_named("render")
           .add_input("input", Type::String)
           .add_output("output", Type::String),
+      )
+      .add_operation(
+        OperationSignature::new_named("put_blob")
+          .add_input("input", Type::String)
+          .add_output("output", Type::Object),
+      )
+      .add_operation(
+        OperationSignature::new_named("get_blob")
+          .add_input("input", Type::Object)
+          .add_output("output", Type::String),
       );
 
     Self(signature)
@@ -181,6 +214,13 @@ fn defer(futs: Vec<impl Future<Output = Result<(), impl std::error::Error + Send
   });
 }
 
+/// The number of times `flaky` has been called. It fails until its third call.
+static FLAKY_CALLS: AtomicU32 = AtomicU32::new(0);
+pub static TALLY_CALLS: AtomicU32 = AtomicU32::new(0);
+/// The last blob `put_blob` put.
+pub static LAST_BLOB: Mutex<Option<BlobRef>> = Mutex::new(None);
+static HUNG: AtomicBool = AtomicBool::new(false);
+
 impl Component for TestComponent {
   fn handle(
     &self,
@@ -190,7 +230,25 @@ impl Component for TestComponent {
   ) -> BoxFuture<Result<PacketStream, ComponentError>> {
     let operation = invocation.target().operation_id();
     println!("got op {} in test collection", operation);
-    Box::pin(async move { handler(invocation, callback) })
+    let slow_start = operation == "slow_start";
+    let flaky_failure = operation == "flaky" && FLAKY_CALLS.fetch_add(1, Ordering::SeqCst) < 2;
+    if operation == "tally" {
+      TALLY_CALLS.fetch_add(1, Ordering::SeqCst);
+    }
+    let hang = operation == "hang_once" && !HUNG.swap(true, Ordering::SeqCst);
+    Box::pin(async move {
+      if slow_start {
+        tokio::time::sleep(Duration::from_millis(1000)).await;
+      }
+      if hang {
+        // Accept the call but never emit anything, like an operation that's still running.
+        return Ok(PacketStream::new(Box::new(futures::stream::pending())));
+      }
+      if flaky_failure {
+        return Err(anyhow!("flaky failure"));
+      }
+      handler(invocation, callback)
+    })
   }
 
   fn signature(&self) -> &ComponentSignature {
@@ -227,7 +285,7 @@ fn handler(invocation: Invocation, callback: LocalScope) -> anyhow::Result<Packe
   }
 
   match operation.as_str() {
-    "echo" => {
+    "echo" | "slow_start" | "flaky" | "tally" | "hang_once" => {
       spawn(async move {
         let mut input = fan_out!(payload_stream, "input");
         while let Some(Ok(payload)) = input.next().await {
@@ -378,6 +436,37 @@ fn handler(invocation: Invocation, callback: LocalScope) -> anyhow::Result<Packe
       });
       Ok(stream)
     }
+    "put_blob" => {
+      let blobs = callback.blobs();
+      spawn(async move {
+        let mut input = fan_out!(payload_stream, "input");
+        while let Some(Ok(payload)) = input.next().await {
+          break_if_done!(payload);
+          continue_if_bracket!(payload, "output");
+
+          let blob = blobs.put(payload.decode::<String>().unwrap()).unwrap();
+          LAST_BLOB.lock().unwrap().replace(blob.clone());
+          defer(vec![send(Packet::encode("output", blob))]);
+        }
+        defer(vec![send(Packet::done("output"))]);
+      });
+      Ok(stream)
+    }
+    "get_blob" => {
+      let blobs = callback.blobs();
+      spawn(async move {
+        let mut input = fan_out!(payload_stream, "input");
+        while let Some(Ok(payload)) = input.next().await {
+          break_if_done!(payload);
+          continue_if_bracket!(payload, "output");
+
+          let bytes = blobs.get(&payload.decode::<BlobRef>().unwrap()).unwrap();
+          let _ = send(Packet::encode("output", String::from_utf8(bytes.to_vec()).unwrap())).await;
+        }
+        defer(vec![send(Packet::done("output"))]);
+      });
+      Ok(stream)
+    }
     "copy" => {
       let (mut input, mut times) = fan_out!(payload_stream, "input", "times");
 
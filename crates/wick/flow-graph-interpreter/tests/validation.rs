@@ -5,7 +5,7 @@ use anyhow::Result;
 use flow_component::{Component, ComponentError};
 use flow_graph_interpreter::error::{InterpreterError, OperationInvalid, ValidationError};
 use flow_graph_interpreter::graph::{from_def, GraphError};
-use flow_graph_interpreter::{HandlerMap, Interpreter, NamespaceHandler};
+use flow_graph_interpreter::{CoercionRules, HandlerMap, Interpreter, NamespaceHandler};
 use pretty_assertions::assert_eq;
 type BoxFuture<'a, T> = std::pin::Pin<Box<dyn futures::Future<Output = T> + Send + 'a>>;
 
@@ -56,6 +56,7 @@ async fn interp(path: &str, sig: ComponentSignature) -> std::result::Result<Inte
     Some(components),
     Default::default(),
     None,
+    None,
     &Span::current(),
   )
 }
@@ -137,3 +138,153 @@ async fn test_missing_port() -> Result<()> {
 
   Ok(())
 }
+
+#[test_logger::test(tokio::test)]
+async fn test_flow_call_cycle() -> Result<()> {
+  let result = interp(
+    "./tests/manifests/v1/flow-call-cycle.yaml",
+    ComponentSignature::new_named("test"),
+  )
+  .await;
+
+  let errors = vec![ValidationError::CallCycle(vec!["test".to_owned(), "test".to_owned()])];
+
+  if let Err(InterpreterError::ValidationError(e)) = result {
+    assert_eq!(e, vec![OperationInvalid::new("test".to_owned(), errors)]);
+  } else {
+    panic!("{:?}", result);
+  }
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_flow_call_missing() -> Result<()> {
+  let result = interp(
+    "./tests/manifests/v1/flow-call-missing.yaml",
+    ComponentSignature::new_named("test"),
+  )
+  .await;
+
+  if let Err(InterpreterError::EarlyError(e)) = result {
+    assert_eq!(
+      e,
+      ValidationError::MissingFlow {
+        id: "CALL".to_owned(),
+        flow: "missing".to_owned(),
+      }
+    );
+  } else {
+    panic!("{:?}", result);
+  }
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_invalid_input_default() -> Result<()> {
+  let result = interp(
+    "./tests/manifests/v1/input-default-invalid.yaml",
+    ComponentSignature::new_named("test"),
+  )
+  .await;
+
+  let errors = vec![ValidationError::InvalidDefault {
+    port: "count".to_owned(),
+    ty: "u32".to_owned(),
+    error: "Could not coerce value \"many\" to a u32".to_owned(),
+  }];
+
+  if let Err(InterpreterError::ValidationError(e)) = result {
+    assert_eq!(e, vec![OperationInvalid::new("test".to_owned(), errors)]);
+  } else {
+    panic!("{:?}", result);
+  }
+
+  Ok(())
+}
+
+fn typed_signature() -> ComponentSignature {
+  ComponentSignature::new_named("test")
+    .add_operation(
+      OperationSignature::new_named("count")
+        .add_input("input", Type::String)
+        .add_output("output", Type::U32),
+    )
+    .add_operation(
+      OperationSignature::new_named("widen")
+        .add_input("input", Type::I64)
+        .add_output("output", Type::I64),
+    )
+    .add_operation(
+      OperationSignature::new_named("greet")
+        .add_input("input", Type::String)
+        .add_output("output", Type::String),
+    )
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_connection_type_mismatch() -> Result<()> {
+  let components = collections(typed_signature());
+  let network = from_def(
+    &mut load("./tests/manifests/v1/connection-type-mismatch.yaml").await?,
+    &components,
+  )?;
+
+  let result = Interpreter::new(
+    network,
+    None,
+    Some(components),
+    Default::default(),
+    None,
+    Some(CoercionRules::default().strict(true)),
+    &Span::current(),
+  );
+
+  // u32 -> i64 is a safe coercion, u32 -> string is not.
+  let errors = vec![ValidationError::TypeMismatch {
+    connection: "COUNT.output -> GREET.input".to_owned(),
+    from: "u32".to_owned(),
+    to: "string".to_owned(),
+  }];
+
+  if let Err(InterpreterError::ValidationError(e)) = result {
+    assert_eq!(e, vec![OperationInvalid::new("test".to_owned(), errors)]);
+  } else {
+    panic!("{:?}", result);
+  }
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_connection_type_coercion() -> Result<()> {
+  let components = collections(typed_signature());
+  let network = from_def(
+    &mut load("./tests/manifests/v1/connection-type-mismatch.yaml").await?,
+    &components,
+  )?;
+  let rules = CoercionRules::default().strict(true).allow(Type::U32, Type::String);
+
+  let result = Interpreter::new(
+    network,
+    None,
+    Some(components),
+    Default::default(),
+    None,
+    Some(rules),
+    &Span::current(),
+  );
+  assert!(result.is_ok());
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_connection_type_mismatch_warns() -> Result<()> {
+  // mismatches only fail validation when the rules are strict.
+  let result = interp("./tests/manifests/v1/connection-type-mismatch.yaml", typed_signature()).await;
+  assert!(result.is_ok());
+
+  Ok(())
+}
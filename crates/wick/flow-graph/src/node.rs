@@ -139,6 +139,10 @@ where
     &self.data
   }
 
+  pub fn data_mut(&mut self) -> &mut DATA {
+    &mut self.data
+  }
+
   pub fn inputs(&self) -> &[NodePort] {
     self.inputs.inner()
   }
@@ -278,9 +282,6 @@ impl PortList {
   fn add_connection(&mut self, port: PortIndex, connection: ConnectionIndex) -> Result<(), Error> {
     let node_port = self.list.get_mut(port).ok_or(Error::InvalidPortIndex(port))?;
 
-    if node_port.direction() == &PortDirection::In && !node_port.connections.is_empty() {
-      return Err(Error::MultipleInputConnections(node_port.to_string()));
-    }
     node_port.connections.push(connection);
     Ok(())
   }
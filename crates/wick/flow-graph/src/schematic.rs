@@ -147,7 +147,9 @@ where
       })
       .collect();
     nodes_connected_to_input.extend(nodes_connected_to_output);
-    nodes_connected_to_input.into_values().collect()
+    let mut nodes: Vec<_> = nodes_connected_to_input.into_values().collect();
+    nodes.sort_by_key(|n| n.index());
+    nodes
   }
 
   #[must_use]
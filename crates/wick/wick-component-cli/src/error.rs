@@ -58,6 +58,18 @@ pub enum CliError {
   #[error("Found argument '{0}' which requires a value but no value was supplied")]
   /// Dangling arguments (e.g. --arg instead of --arg value or --arg=value)
   MissingArgumentValue(String),
+
+  #[error("Operation '{1}' has no input named '{0}', its inputs are: {2}")]
+  /// Thrown when an argument names an input the target operation doesn't have.
+  UnknownInput(String, String, String),
+
+  #[error("Could not read input from '{0}': {1}")]
+  /// Could not read an input's value from a file.
+  ReadInput(String, #[source] std::io::Error),
+
+  #[error("Invalid JSON in {0}: {1}")]
+  /// Input data passed as JSON was not an object of input names to values.
+  InvalidJson(String, String),
 }
 
 impl CliError {
@@ -128,7 +128,7 @@ pub mod options;
 
 /// Utility functions.
 mod utils;
-pub use utils::parse_args;
+pub use utils::{parse_args, Query};
 
 /// The crate's error type;.
 pub type Error = error::CliError;
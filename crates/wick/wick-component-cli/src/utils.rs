@@ -1,95 +1,303 @@
+use std::io::Read;
+
 use serde_json::Value;
-use wick_interface_types::{OperationSignature, Type};
+use wick_interface_types::{Field, OperationSignature, Type};
 use wick_packet::Packet;
 
 use crate::Error;
 
 /// Parse CLI arguments into a [wick_packet::PacketStream]
 pub fn parse_args(args: &[String], sig: &OperationSignature) -> Result<Vec<Packet>, Error> {
-  let mut packets = Vec::new();
-  let mut iter = args.iter();
-  while let Some(next) = iter.next() {
-    if !next.starts_with("--") {
-      return Err(Error::InvalidArgument(next.clone()));
+  let mut query = Query::new(sig);
+  query.args(args)?;
+  Ok(query.packets())
+}
+
+/// Input for an ad-hoc invocation of an operation, parsed from loosely typed arguments and coerced to the types of the
+/// operation's inputs.
+///
+/// Inputs can be passed as `--name value`, `--name=value`, or `name=value`. A value of `@path` reads the value from a
+/// file and an argument of `@path.json` reads a JSON object of inputs, like [Query::reader] does for any reader, e.g.
+/// STDIN.
+///
+/// ```
+/// # use wick_interface_types::{Field, OperationSignature, Type};
+/// # use wick_component_cli::Query;
+/// let sig = OperationSignature::new(
+///   "greet".to_owned(),
+///   vec![Field::new("name", Type::String), Field::new("times", Type::U32)],
+///   Vec::new(),
+///   Vec::new(),
+/// );
+/// let mut query = Query::new(&sig);
+/// query.arg("name=Samuel")?.arg("times=3")?;
+/// assert_eq!(query.packets().len(), 2);
+/// assert!(query.missing_inputs().is_empty());
+/// # Ok::<_, wick_component_cli::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Query<'a> {
+  sig: &'a OperationSignature,
+  values: Vec<(String, Value)>,
+}
+
+impl<'a> Query<'a> {
+  /// Start a query for the operation with the signature `sig`.
+  pub const fn new(sig: &'a OperationSignature) -> Self {
+    Self {
+      sig,
+      values: Vec::new(),
     }
-    let next = next.trim_start_matches("--");
-    let (name, value) = split_arg(next);
-    let value = match value {
-      Some(value) => value,
-      None => {
-        let value = iter.next();
-        if value.is_none() {
-          return Err(Error::MissingArgumentValue(name.to_owned()));
-        }
-        value.unwrap()
-      }
-    };
-    let input = sig.inputs.iter().find(|i| i.name == name);
-    if input.is_none() {
-      return Err(Error::InvalidInput(name.to_owned()));
+  }
+
+  /// Add every input in `args`, either `--name value`, `--name=value`, `name=value`, or `@path.json`.
+  pub fn args(&mut self, args: &[String]) -> Result<&mut Self, Error> {
+    let mut iter = args.iter();
+    while let Some(next) = iter.next() {
+      let Some(flag) = next.strip_prefix("--") else {
+        self.arg(next)?;
+        continue;
+      };
+      let (name, value) = match flag.split_once('=') {
+        Some((name, value)) => (name, value),
+        None => (
+          flag,
+          iter
+            .next()
+            .ok_or_else(|| Error::MissingArgumentValue(flag.to_owned()))?
+            .as_str(),
+        ),
+      };
+      self.input(name, value)?;
+    }
+    Ok(self)
+  }
+
+  /// Add the input in `arg`, either `name=value` or `@path.json`.
+  pub fn arg(&mut self, arg: &str) -> Result<&mut Self, Error> {
+    if let Some(path) = arg.strip_prefix('@') {
+      let file = std::fs::File::open(path).map_err(|e| Error::ReadInput(path.to_owned(), e))?;
+      return self.read_json(path, file);
     }
-    let input = input.unwrap();
-
-    let value = if value.starts_with('@') {
-      let path = value.trim_start_matches('@');
-
-      match input.ty() {
-        Type::String => Value::String(std::fs::read_to_string(path)?),
-        Type::Bytes => {
-          let bytes: wick_packet::Base64Bytes = std::fs::read(path)?.into();
-          serde_json::to_value(bytes).unwrap()
-        }
-        _ => encode(&std::fs::read_to_string(path)?),
-      }
-    } else {
-      match input.ty() {
-        // Datetime can be parsed from a string or a number but numbers need to be stringified.
-        // Strings must be explicit because a bare number will be parsed as a number.
-        Type::Datetime | Type::String => {
-          if is_valid(value) {
-            coerce_string(name, value, input.ty())?
-          } else {
-            // if it's not valid JSON then it's a bare string.
-            value.into()
-          }
-        }
-        // serde_json does an adequate job on the rest.
-        _ => encode(value),
-      }
+    let (name, value) = arg
+      .split_once('=')
+      .ok_or_else(|| Error::InvalidArgument(format!("{}, expected name=value or @file.json", arg)))?;
+    self.input(name, value)
+  }
+
+  /// Add `value` to the input `name`, coercing it to the input's type. A value of `@path` is read from a file.
+  pub fn input(&mut self, name: &str, value: &str) -> Result<&mut Self, Error> {
+    let field = self.field(name)?;
+    let value = match value.strip_prefix('@') {
+      Some(path) => read_value(path, field)?,
+      None => parse_value(value, &field.ty),
     };
+    let value = coerce(name, value, &field.ty)?;
+    self.values.push((name.to_owned(), value));
+    Ok(self)
+  }
 
-    // Note on above: complex objects with embedded Datetime/Strings
-    // may not be parsed correctly but that's an edge case we're ignoring for now.
+  /// Add the inputs in `json`, an object of input names to values.
+  pub fn json(&mut self, json: Value) -> Result<&mut Self, Error> {
+    let Value::Object(inputs) = json else {
+      return Err(Error::InvalidJson(
+        "input".to_owned(),
+        "expected an object of input names to values".to_owned(),
+      ));
+    };
+    for (name, value) in inputs {
+      let field = self.field(&name)?;
+      let value = coerce(&name, value, &field.ty)?;
+      self.values.push((name, value));
+    }
+    Ok(self)
+  }
 
-    let payload = Packet::encode(name, value);
+  /// Add the inputs in the JSON object read from `reader`.
+  pub fn reader<R: Read>(&mut self, reader: R) -> Result<&mut Self, Error> {
+    self.read_json("input", reader)
+  }
 
-    packets.push(payload);
+  fn read_json<R: Read>(&mut self, source: &str, reader: R) -> Result<&mut Self, Error> {
+    let json = serde_json::from_reader(reader).map_err(|e| Error::InvalidJson(source.to_owned(), e.to_string()))?;
+    self.json(json)
   }
 
-  Ok(packets)
+  fn field(&self, name: &str) -> Result<&'a Field, Error> {
+    self.sig.inputs.iter().find(|i| i.name == name).ok_or_else(|| {
+      let inputs: Vec<_> = self
+        .sig
+        .inputs
+        .iter()
+        .map(|i| format!("{}: {}", i.name, i.ty))
+        .collect();
+      Error::UnknownInput(name.to_owned(), self.sig.name.clone(), inputs.join(", "))
+    })
+  }
+
+  /// The inputs of the operation that aren't optional and haven't been given a value.
+  #[must_use]
+  pub fn missing_inputs(&self) -> Vec<&'a str> {
+    self
+      .sig
+      .inputs
+      .iter()
+      .filter(|i| i.required)
+      .filter(|i| !self.values.iter().any(|(name, _)| name == &i.name))
+      .map(|i| i.name.as_str())
+      .collect()
+  }
+
+  /// The packets for the inputs, in the order they were added.
+  #[must_use]
+  pub fn packets(&self) -> Vec<Packet> {
+    self
+      .values
+      .iter()
+      .map(|(name, value)| Packet::encode(name, value))
+      .collect()
+  }
 }
 
-fn encode(value: &str) -> Value {
-  serde_json::from_str::<Value>(value).unwrap_or_else(|_| Value::String(value.to_owned()))
+/// Read the value for `field` from the file at `path`. Strings and bytes are the file's contents, anything else is
+/// parsed from them.
+fn read_value(path: &str, field: &Field) -> Result<Value, Error> {
+  let read_err = |e: std::io::Error| Error::ReadInput(path.to_owned(), e);
+  Ok(match &field.ty {
+    Type::String => Value::String(std::fs::read_to_string(path).map_err(read_err)?),
+    Type::Bytes => {
+      let bytes: wick_packet::Base64Bytes = std::fs::read(path).map_err(read_err)?.into();
+      serde_json::to_value(bytes).unwrap()
+    }
+    ty => parse_value(&std::fs::read_to_string(path).map_err(read_err)?, ty),
+  })
 }
 
-fn coerce_string(name: &str, value: &str, ty: &Type) -> Result<Value, Error> {
-  let val = serde_json::from_str::<Value>(value).unwrap_or_else(|_| Value::String(value.to_owned()));
+/// Parse a raw argument for an input of type `ty`. Arguments that aren't JSON are strings, and arguments for string-like
+/// inputs are taken verbatim unless they're a quoted JSON string.
+fn parse_value(raw: &str, ty: &Type) -> Value {
+  if !is_valid(raw) {
+    return Value::String(raw.to_owned());
+  }
+  let value: Value = serde_json::from_str(raw).unwrap();
+  let ty = match ty {
+    Type::Optional { ty } => ty,
+    ty => ty,
+  };
+  match (ty, value) {
+    (Type::String | Type::Datetime | Type::Bytes, value @ (Value::String(_) | Value::Array(_) | Value::Object(_))) => {
+      value
+    }
+    (Type::String | Type::Datetime | Type::Bytes, _) => Value::String(raw.to_owned()),
+    (_, value) => value,
+  }
+}
 
-  Ok(match val {
-    serde_json::Value::Null => Value::String("null".to_owned()),
-    serde_json::Value::Bool(v) => Value::String(v.to_string()),
-    serde_json::Value::Number(v) => Value::String(v.to_string()),
-    serde_json::Value::String(v) => Value::String(v),
-    serde_json::Value::Array(_v) => return Err(Error::encoding(name, value, ty.clone())),
-    serde_json::Value::Object(_v) => return Err(Error::encoding(name, value, ty.clone())),
-  })
+/// Coerce `value` into the type `ty` of the input `name`, e.g. numeric strings into numbers, failing if it can't be.
+fn coerce(name: &str, value: Value, ty: &Type) -> Result<Value, Error> {
+  let invalid = |value: &Value| Error::encoding(name, value.to_string(), ty.clone());
+  let coerced = match ty {
+    Type::I8 => signed(&value, i8::MIN.into(), i8::MAX.into()),
+    Type::I16 => signed(&value, i16::MIN.into(), i16::MAX.into()),
+    Type::I32 => signed(&value, i32::MIN.into(), i32::MAX.into()),
+    Type::I64 => signed(&value, i64::MIN, i64::MAX),
+    Type::U8 => unsigned(&value, u8::MAX.into()),
+    Type::U16 => unsigned(&value, u16::MAX.into()),
+    Type::U32 => unsigned(&value, u32::MAX.into()),
+    Type::U64 => unsigned(&value, u64::MAX),
+    Type::F32 | Type::F64 => match &value {
+      Value::Number(_) => Some(value.clone()),
+      Value::String(v) => v
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+        .map(Value::Number),
+      _ => None,
+    },
+    Type::Bool => match &value {
+      Value::Bool(_) => Some(value.clone()),
+      Value::String(v) => v.trim().parse::<bool>().ok().map(Value::Bool),
+      _ => None,
+    },
+    Type::String => match &value {
+      Value::String(_) => Some(value.clone()),
+      Value::Null => Some(Value::String("null".to_owned())),
+      Value::Bool(v) => Some(Value::String(v.to_string())),
+      Value::Number(v) => Some(Value::String(v.to_string())),
+      _ => None,
+    },
+    // Datetimes can be passed as a string or a number but numbers need to be stringified.
+    Type::Datetime => match &value {
+      Value::String(v) => wick_packet::parse_date(v).ok().map(|_| value.clone()),
+      Value::Number(v) => Some(Value::String(v.to_string())),
+      _ => None,
+    },
+    Type::Bytes => value.is_string().then(|| value.clone()),
+    Type::List { ty } => {
+      return match value {
+        Value::Array(items) => items
+          .into_iter()
+          .map(|item| coerce(name, item, ty))
+          .collect::<Result<_, _>>()
+          .map(Value::Array),
+        value => Ok(Value::Array(vec![coerce(name, value, ty)?])),
+      };
+    }
+    Type::Optional { ty } => {
+      return match value {
+        Value::Null => Ok(Value::Null),
+        value => coerce(name, value, ty),
+      };
+    }
+    Type::Map { value: ty, .. } => {
+      return match value {
+        Value::Object(map) => map
+          .into_iter()
+          .map(|(k, v)| Ok((k, coerce(name, v, ty)?)))
+          .collect::<Result<_, _>>()
+          .map(Value::Object),
+        value => Err(invalid(&value)),
+      };
+    }
+    Type::AnonymousStruct(fields) => {
+      return match value {
+        Value::Object(map) => map
+          .into_iter()
+          .map(|(k, v)| match fields.iter().find(|f| f.name == k) {
+            Some(field) => Ok((k, coerce(name, v, &field.ty)?)),
+            None => Ok((k, v)),
+          })
+          .collect::<Result<_, _>>()
+          .map(Value::Object),
+        value => Err(invalid(&value)),
+      };
+    }
+    // Named types aren't part of an operation's signature so they're passed along as they are.
+    Type::Named(_) | Type::Object => Some(value.clone()),
+    #[allow(deprecated)]
+    Type::Link { .. } => Some(value.clone()),
+  };
+  coerced.ok_or_else(|| invalid(&value))
 }
 
-#[must_use]
-fn split_arg(arg: &str) -> (&str, Option<&str>) {
-  let mut parts = arg.split('=');
-  (parts.next().unwrap(), parts.next())
+fn signed(value: &Value, min: i64, max: i64) -> Option<Value> {
+  let number = match value {
+    Value::Number(v) => v.as_i64(),
+    Value::String(v) => v.trim().parse().ok(),
+    _ => None,
+  }?;
+  (min..=max).contains(&number).then(|| Value::from(number))
+}
+
+fn unsigned(value: &Value, max: u64) -> Option<Value> {
+  let number = match value {
+    Value::Number(v) => v.as_u64(),
+    Value::String(v) => v.trim().parse().ok(),
+    _ => None,
+  }?;
+  (number <= max).then(|| Value::from(number))
 }
 
 fn is_valid(string: &str) -> bool {
@@ -200,6 +408,63 @@ mod tests {
     Ok(())
   }
 
+  #[test_logger::test]
+  fn query_kv_args() -> Result<()> {
+    let sig = sig(&[
+      ("name", Type::String),
+      ("times", Type::U8),
+      ("loud", Type::Bool),
+      (
+        "tags",
+        Type::List {
+          ty: Box::new(Type::String),
+        },
+      ),
+    ]);
+    let mut query = Query::new(&sig);
+    query.arg("times=3")?.arg("loud=true")?;
+    assert_eq!(query.missing_inputs(), vec!["name", "tags"]);
+    query.arg("name=123")?.arg("tags=solo")?;
+    assert!(query.missing_inputs().is_empty());
+    let packets = query.packets();
+    assert_eq!(packets[0], Packet::encode("times", 3));
+    assert_eq!(packets[1], Packet::encode("loud", true));
+    assert_eq!(packets[2], Packet::encode("name", "123"));
+    assert_eq!(packets[3], Packet::encode("tags", vec!["solo"]));
+    Ok(())
+  }
+
+  #[test_logger::test]
+  fn query_json() -> Result<()> {
+    let sig = sig(&[("times", Type::U8), ("ratio", Type::F64)]);
+    let mut query = Query::new(&sig);
+    query.reader(r#"{"times": "4", "ratio": 0.5}"#.as_bytes())?;
+    let packets = query.packets();
+    assert!(packets.contains(&Packet::encode("times", 4)));
+    assert!(packets.contains(&Packet::encode("ratio", 0.5)));
+    assert!(Query::new(&sig).reader("[1]".as_bytes()).is_err());
+    Ok(())
+  }
+
+  #[test_logger::test]
+  fn query_errors() -> Result<()> {
+    let sig = sig(&[("times", Type::U8), ("when", Type::Datetime)]);
+    let err = Query::new(&sig).arg("time=3").unwrap_err();
+    assert!(
+      err.to_string().contains("times: u8"),
+      "unknown inputs should list the real ones: {}",
+      err
+    );
+    assert!(
+      Query::new(&sig).arg("times=300").is_err(),
+      "300 is out of range of a u8"
+    );
+    assert!(Query::new(&sig).arg("times=three").is_err());
+    assert!(Query::new(&sig).arg("when=yesterday").is_err());
+    assert!(Query::new(&sig).arg("times").is_err());
+    Ok(())
+  }
+
   #[test_logger::test]
   fn test_is_valid() -> Result<()> {
     let int = "1234567890";
@@ -49,6 +49,15 @@ fn gen_types<'a>(
     put_impl_in_path(&root, mod_parts, implementation);
   }
 
+  // Generating a struct can register more inline structs, so keep going until every one has been emitted.
+  let mut generated = 0;
+  while generated < config.anonymous_structs.len() {
+    let ty = config.anonymous_structs[generated].clone();
+    let (_, implementation) = templates::gen_struct(config, &ty, TypeOptions::empty());
+    root.borrow_mut().add(implementation);
+    generated += 1;
+  }
+
   let borrowed = root.borrow();
   borrowed.codegen()
 }
@@ -114,16 +123,20 @@ fn codegen(wick_config: WickConfiguration, gen_config: &mut config::Config) -> R
     _ => panic!("Code generation only supports `wick/component` and `wick/types` configurations"),
   };
 
+  gen_config.recursive_fields = wick_interface_types::recursive_fields(&types).into_iter().collect();
+
   let component_name = id("Component");
   let wrapper_fns = gen_wrapper_fns(gen_config, &component_name, ops.iter());
   let trait_defs = gen_trait_fns(gen_config, ops.iter());
-  let typedefs = gen_types("types", gen_config, types.iter());
 
   let init = (!ops.is_empty())
     .then(|| templates::gen_component_impls(gen_config, &component_name, ops.iter(), &required, &imported));
 
   let root_config = templates::component_config(gen_config, root_config);
 
+  // Types are generated last so they include the inline structs referenced by everything above.
+  let typedefs = gen_types("types", gen_config, types.iter());
+
   let imports = gen_config.deps.iter().map(|dep| quote! { #dep }).collect_vec();
   let imports = quote! { #( #imports )* };
 
@@ -215,4 +228,25 @@ mod test {
 
     Ok(())
   }
+
+  #[tokio::test]
+  async fn test_enum_helpers() -> Result<()> {
+    let mut config = ConfigBuilder::new()
+      .spec("./tests/testdata/enum-types.yaml")
+      .enum_helpers(true)
+      .build()?;
+    let wick_config = WickConfiguration::fetch(&config.spec, Default::default())
+      .await
+      .unwrap()
+      .finish()?;
+
+    let src = codegen(wick_config, &mut config)?;
+
+    assert!(src.contains("pub const ALL: [Self; 2usize] = [Self::Red, Self::DarkGreen];"));
+    assert!(src.contains("pub const fn is_dark_green(&self) -> bool"));
+    assert!(src.contains("0u32 => Self::Red"));
+    assert!(src.contains("is not a valid Color, expected one of: red, DarkGreen"));
+
+    Ok(())
+  }
 }
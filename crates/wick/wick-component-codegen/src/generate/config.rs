@@ -2,8 +2,10 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 use derive_builder::Builder;
+use wick_interface_types::{Field, StructDefinition};
 
 use super::dependency::Dependency;
+use super::ids::anonymous_struct_name;
 
 #[derive(Debug, Default, Builder, Clone)]
 #[must_use]
@@ -21,8 +23,15 @@ pub struct Config {
   pub(crate) components: bool,
   #[builder(default = "true")]
   pub(crate) output_structs: bool,
+  /// Generate an `ALL` constant and `is_<variant>()` predicates for every enum.
+  #[builder(default)]
+  pub(crate) enum_helpers: bool,
   #[builder(setter(skip))]
   pub(crate) deps: HashSet<Dependency>,
+  #[builder(setter(skip))]
+  pub(crate) anonymous_structs: Vec<StructDefinition>,
+  #[builder(setter(skip))]
+  pub(crate) recursive_fields: HashSet<(String, String)>,
 }
 
 impl Config {
@@ -34,6 +43,35 @@ impl Config {
     self.deps.insert(dep);
   }
 
+  /// Whether a struct field refers back to its own struct and must be boxed.
+  pub(crate) fn is_recursive_field(&self, parent: &str, field: &str) -> bool {
+    self
+      .recursive_fields
+      .iter()
+      .any(|(ty, name)| ty == parent && name == field)
+  }
+
+  /// Register an inline struct so it can be generated alongside the named types, returning its generated name.
+  pub(crate) fn add_anonymous_struct(&mut self, fields: &[Field]) -> String {
+    let base = anonymous_struct_name(fields);
+    let mut name = base.clone();
+    let mut suffix = 1;
+    loop {
+      match self.anonymous_structs.iter().find(|s| s.name == name) {
+        Some(existing) if existing.fields == fields => return name,
+        Some(_) => {
+          suffix += 1;
+          name = format!("{}{}", base, suffix);
+        }
+        None => break,
+      }
+    }
+    self
+      .anonymous_structs
+      .push(StructDefinition::new(&name, fields.to_vec(), None));
+    name
+  }
+
   pub fn exec(self) -> anyhow::Result<()> {
     super::build(self)?;
     Ok(())
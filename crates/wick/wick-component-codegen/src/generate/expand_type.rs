@@ -69,6 +69,9 @@ pub(super) fn expand_type(
       config.add_dep(Dependency::SerdeJson);
       quote! { wick_component::Value }
     }
-    wick_interface_types::Type::AnonymousStruct(_) => todo!("implement anonymous struct in new codegen"),
+    wick_interface_types::Type::AnonymousStruct(fields) => {
+      let name = config.add_anonymous_struct(fields);
+      expand_type(config, dir, imported, raw, &wick_interface_types::Type::Named(name))
+    }
   }
 }
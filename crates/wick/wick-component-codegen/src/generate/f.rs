@@ -14,17 +14,32 @@ const fn is_defaultable(ty: &Type) -> bool {
   )
 }
 
-pub(crate) fn field_pair(
-  config: &mut config::Config,
+pub(crate) fn field_pair<'a>(
+  config: &'a mut config::Config,
+  parent: &'a str,
   imported: bool,
   serde: bool,
   dir: Direction,
-) -> impl FnMut(&Field) -> TokenStream + '_ {
+) -> impl FnMut(&Field) -> TokenStream + 'a {
   move |field: &Field| {
     let name = &field.name;
     let id = id(&snake(name));
 
-    let ty = expand_type(config, dir, imported, config.raw, &field.ty);
+    let ty = if config.is_recursive_field(parent, name) {
+      // Recursive fields need indirection or the struct would have an infinite size.
+      match &field.ty {
+        Type::Optional { ty } => {
+          let ty = expand_type(config, dir, imported, config.raw, ty);
+          quote! { Option<Box<#ty>> }
+        }
+        ty => {
+          let ty = expand_type(config, dir, imported, config.raw, ty);
+          quote! { Box<#ty> }
+        }
+      }
+    } else {
+      expand_type(config, dir, imported, config.raw, &field.ty)
+    };
     let desc = field
       .description
       .as_ref()
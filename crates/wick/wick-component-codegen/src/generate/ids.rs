@@ -3,7 +3,7 @@ use heck::{AsPascalCase, AsSnakeCase};
 use itertools::Itertools;
 use proc_macro2::{Ident, Span};
 use wick_config::config::Binding;
-use wick_interface_types::{EnumVariant, OperationSignature};
+use wick_interface_types::{EnumVariant, Field, OperationSignature};
 
 pub(crate) fn id(name: &str) -> Ident {
   if name.is_keyword() {
@@ -29,6 +29,10 @@ pub(crate) fn pascal(s: &str) -> String {
   AsPascalCase(s).to_string()
 }
 
+pub(crate) fn anonymous_struct_name(fields: &[Field]) -> String {
+  format!("Anon{}", fields.iter().map(|f| pascal(&f.name)).join(""))
+}
+
 pub(crate) fn op_wrapper_name(op: &OperationSignature) -> String {
   snake(&format!("{}_wrapper", op.name()))
 }
@@ -21,14 +21,18 @@ pub(crate) fn type_def<'a>(
   options: TypeOptions,
 ) -> (Vec<&'a str>, TokenStream) {
   match ty {
-    TypeDefinition::Enum(ty) => gen_enum(ty, options),
+    TypeDefinition::Enum(ty) => gen_enum(config, ty, options),
     TypeDefinition::Struct(ty) => gen_struct(config, ty, options),
     TypeDefinition::Union(ty) => gen_union(config, ty, options),
   }
 }
 
 #[allow(clippy::too_many_lines)]
-pub(crate) fn gen_enum(ty: &EnumDefinition, _options: TypeOptions) -> (Vec<&str>, TokenStream) {
+pub(crate) fn gen_enum<'a>(
+  config: &config::Config,
+  ty: &'a EnumDefinition,
+  _options: TypeOptions,
+) -> (Vec<&'a str>, TokenStream) {
   let (path_parts, item_part) = get_typename_parts(&ty.name);
   let name = id(item_part);
   let variants = ty
@@ -53,7 +57,7 @@ pub(crate) fn gen_enum(ty: &EnumDefinition, _options: TypeOptions) -> (Vec<&str>
     .iter()
     .filter_map(|v| {
       let identname = id(&enumvariant_name(v));
-      v.index.map(|_| quote! {i => Self::#identname})
+      v.index.map(|i| quote! {#i => Self::#identname})
     })
     .collect_vec();
 
@@ -113,6 +117,12 @@ pub(crate) fn gen_enum(ty: &EnumDefinition, _options: TypeOptions) -> (Vec<&str>
     .as_ref()
     .map_or_else(|| quote! {}, |desc| quote! {#[doc = #desc]});
 
+  let invalid_variant = format!(
+    "is not a valid {}, expected one of: {}",
+    item_part,
+    ty.variant_names().join(", ")
+  );
+
   let try_from_strnum_impl = quote! {
     impl TryFrom<wick_component::serde_util::enum_repr::StringOrNum> for #name {
       type Error = String;
@@ -121,7 +131,8 @@ pub(crate) fn gen_enum(ty: &EnumDefinition, _options: TypeOptions) -> (Vec<&str>
         use std::str::FromStr;
         match value {
           wick_component::serde_util::enum_repr::StringOrNum::String(v) => Self::from_str(&v),
-          wick_component::serde_util::enum_repr::StringOrNum::Int(v) => Self::from_str(&v.to_string()),
+          wick_component::serde_util::enum_repr::StringOrNum::Int(v) => Self::from_str(&v.to_string())
+            .or_else(|e| u32::try_from(v).ok().and_then(|i| Self::try_from(i).ok()).ok_or(e)),
           wick_component::serde_util::enum_repr::StringOrNum::Float(v) => Self::from_str(&v.to_string()),
         }
       }
@@ -134,6 +145,32 @@ pub(crate) fn gen_enum(ty: &EnumDefinition, _options: TypeOptions) -> (Vec<&str>
     }
   };
 
+  let helpers = config.enum_helpers.then(|| {
+    let count = ty.variants.len();
+    let idents = ty.variants.iter().map(|v| id(&enumvariant_name(v))).collect_vec();
+    let predicates = ty.variants.iter().map(|v| {
+      let variant = enumvariant_name(v);
+      let identname = id(&variant);
+      let fn_name = id(&format!("is_{}", snake(&variant)));
+      let doc = format!("Returns `true` if this is the [`{}::{}`] variant.", item_part, variant);
+      quote! {
+        #[doc = #doc]
+        #[must_use]
+        pub const fn #fn_name(&self) -> bool {
+          matches!(self, Self::#identname)
+        }
+      }
+    });
+    quote! {
+      impl #name {
+        #[doc = "Every variant of the enum, in the order they were defined."]
+        pub const ALL: [Self; #count] = [#(Self::#idents),*];
+
+        #(#predicates)*
+      }
+    }
+  });
+
   let enum_impl = quote! {
     #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
     #description
@@ -157,6 +194,8 @@ pub(crate) fn gen_enum(ty: &EnumDefinition, _options: TypeOptions) -> (Vec<&str>
       }
     }
 
+    #helpers
+
     impl TryFrom<u32> for #name {
       type Error = u32;
       fn try_from(i: u32) -> Result<Self, Self::Error> {
@@ -171,11 +210,12 @@ pub(crate) fn gen_enum(ty: &EnumDefinition, _options: TypeOptions) -> (Vec<&str>
     impl std::str::FromStr for #name {
       type Err = String;
 
+      #[allow(clippy::too_many_lines)]
       fn from_str(s: &str) -> Result<Self, Self::Err> {
         #[allow(clippy::match_single_binding)]
         match s {
           #(#fromstr_match_arms,)*
-          _ => Err(s.to_owned())
+          _ => Err(format!("'{}' {}", s, #invalid_variant))
         }
       }
     }
@@ -205,7 +245,7 @@ pub(crate) fn gen_struct<'a>(
   let fields = ty
     .fields
     .iter()
-    .map(f::field_pair(config, imported, true, Direction::Out))
+    .map(f::field_pair(config, &ty.name, imported, true, Direction::Out))
     .collect_vec();
 
   let (derive, default_impl) = if ty.fields.is_empty() {
@@ -252,7 +292,7 @@ pub(crate) fn gen_struct<'a>(
   (module_parts, item)
 }
 
-fn generic_type_name(ty: &Type) -> String {
+fn generic_type_name(config: &mut config::Config, ty: &Type) -> String {
   match ty {
     Type::I8 | Type::I16 | Type::I32 | Type::I64 => pascal(&ty.to_string()),
     Type::U8 | Type::U16 | Type::U32 | Type::U64 => pascal(&ty.to_string()),
@@ -265,7 +305,7 @@ fn generic_type_name(ty: &Type) -> String {
     Type::Object => "Any".to_owned(),
     #[allow(deprecated)]
     Type::Link { .. } => unimplemented!(),
-    Type::AnonymousStruct(_) => unimplemented!(),
+    Type::AnonymousStruct(fields) => config.add_anonymous_struct(fields),
   }
 }
 
@@ -283,7 +323,7 @@ pub(crate) fn gen_union<'a>(
     .types
     .iter()
     .map(|ty| {
-      let name = id(&generic_type_name(ty));
+      let name = id(&generic_type_name(config, ty));
       let description = format!("A {} value.", ty);
       let ty = expand_type(config, Direction::In, imported, config.raw, ty);
       quote! {
@@ -4,10 +4,13 @@ use std::sync::Arc;
 use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
 use tracing::Span;
 use wasmrs_host::WasiParams;
+use wick_config::config::components::{WasmLimits, WasmPool};
 use wick_config::config::Permissions;
 use wick_config::FetchableAssetReference;
 use wick_packet::{Entity, Invocation, PacketStream, RuntimeConfig};
 
+use crate::limits::ViolationCallback;
+use crate::pool::PoolStats;
 use crate::wasmrs_host::{SetupPayload, WasmHost, WasmHostBuilder};
 use crate::Error;
 
@@ -29,6 +32,14 @@ pub struct ComponentSetup {
   pub imported: HashMap<String, String>,
   #[builder(setter(), default)]
   pub permissions: Option<Permissions>,
+  #[builder(setter(), default)]
+  pub limits: Option<WasmLimits>,
+  #[builder(setter(), default)]
+  pub on_violation: Option<ViolationCallback>,
+  #[builder(setter(), default)]
+  pub pool: Option<WasmPool>,
+  #[builder(setter(), default)]
+  pub pool_stats: Option<Arc<PoolStats>>,
 }
 
 impl std::fmt::Debug for ComponentSetup {
@@ -38,6 +49,8 @@ impl std::fmt::Debug for ComponentSetup {
       .field("buffer_size", &self.buffer_size)
       .field("provided", &self.provided)
       .field("imported", &self.provided)
+      .field("limits", &self.limits)
+      .field("pool", &self.pool)
       .finish()
   }
 }
@@ -86,6 +99,18 @@ impl WasmrsComponent {
       builder = builder.buffer_size(value);
     }
 
+    if let Some(limits) = options.limits {
+      builder = builder.limits(limits);
+    }
+
+    if let Some(callback) = options.on_violation {
+      builder = builder.on_violation(callback);
+    }
+
+    if let Some(pool) = options.pool {
+      builder = builder.pool(pool, options.pool_stats.unwrap_or_default());
+    }
+
     let host = builder.build(&asset).await?;
 
     let sig = host.signature();
@@ -116,9 +141,9 @@ impl Component for WasmrsComponent {
   ) -> BoxFuture<Result<PacketStream, ComponentError>> {
     invocation.trace(|| trace!(target = %invocation.target(), config=?data, "wasm invoke"));
 
-    let outputs = self.host.call(invocation, data);
+    let host = self.host.clone();
 
-    Box::pin(async move { outputs.map_err(ComponentError::new) })
+    Box::pin(async move { host.call(invocation, data).await.map_err(ComponentError::new) })
   }
 
   fn signature(&self) -> &wick_interface_types::ComponentSignature {
@@ -139,6 +164,10 @@ mod tests {
   use super::*;
 
   async fn load_component() -> Result<WasmrsComponent> {
+    load_pooled_component(None, None).await
+  }
+
+  async fn load_pooled_component(pool: Option<WasmPool>, stats: Option<Arc<PoolStats>>) -> Result<WasmrsComponent> {
     let file = AssetReference::from_str("../../integration/test-baseline-component/build/baseline.signed.wasm")?;
     let file = file.with_options(Default::default());
 
@@ -147,6 +176,8 @@ mod tests {
       .callback(Some(LocalScope::new(Arc::new(|_, _, _, _, _, _| {
         Box::pin(async { Ok(packet_stream!(("test", "test"))) })
       }))))
+      .pool(pool)
+      .pool_stats(stats)
       .build()?;
 
     let c = WasmrsComponent::try_load("test", file, setup, Span::current()).await?;
@@ -219,4 +250,45 @@ mod tests {
     assert_eq!(output, Packet::encode("output", 1936));
     Ok(())
   }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_component_pool() -> Result<()> {
+    let stats = Arc::new(PoolStats::default());
+    let pool = wick_config::config::components::WasmPoolBuilder::default()
+      .min_instances(2_u32)
+      .max_instances(3_u32)
+      .build()?;
+    let component = load_pooled_component(Some(pool), Some(stats.clone())).await?;
+    assert_eq!(stats.instances(), 2);
+    assert_eq!(stats.idle(), 2);
+
+    let mut outputs = Vec::new();
+    for _ in 0..3 {
+      let invocation = Invocation::test(
+        file!(),
+        Entity::local("add"),
+        packets!(("left", 10), ("right", 20)),
+        None,
+      )?;
+      let output = component
+        .handle(invocation, Some(json!({}).try_into()?), Default::default())
+        .await?;
+      outputs.push(output);
+    }
+    assert_eq!(
+      stats.instances(),
+      3,
+      "the third concurrent invocation should start an instance"
+    );
+    assert_eq!(stats.cold_starts(), 1);
+    assert_eq!(stats.idle(), 0);
+
+    for output in outputs {
+      let packets: Vec<_> = output.collect().await;
+      assert!(packets.contains(&Ok(Packet::encode("output", 30))));
+    }
+    assert_eq!(stats.checkouts(), 3);
+    assert_eq!(stats.idle(), 3);
+    Ok(())
+  }
 }
@@ -46,6 +46,15 @@ pub enum WasmComponentError {
 
   #[error("Operation '__setup' not exported by the wasm module.")]
   SetupOperation,
+
+  #[error("Component has reached its limit of {0} instances")]
+  InstanceLimit(u32),
+
+  #[error("Component needs more memory than its limit of {0} bytes")]
+  MemoryLimit(u64),
+
+  #[error("Operation '{0}' exceeded its time limit of {1}ms")]
+  Timeout(String, u64),
 }
 
 impl From<serde_json::error::Error> for WasmComponentError {
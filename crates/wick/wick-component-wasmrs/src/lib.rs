@@ -114,6 +114,8 @@
 
 pub mod component;
 pub mod error;
+pub mod limits;
+pub mod pool;
 pub mod wasmrs_host;
 mod wasmrs_module;
 
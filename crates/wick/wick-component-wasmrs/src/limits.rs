@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::Poll;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use wick_config::config::components::WasmLimits;
+use wick_packet::{Packet, PacketPayload, PacketStream};
+
+use crate::error::WasmComponentError;
+
+/// The size of a page of WebAssembly linear memory.
+const WASM_PAGE_SIZE: u64 = 65536;
+
+/// The most pages a 32-bit linear memory can have, used when only the instance count is limited.
+const MAX_WASM_PAGES: u64 = 65536;
+
+/// Engines that enforce memory and instance limits, keyed by the module and limits they were made for. Modules are
+/// compiled for one engine, so each module and set of limits keeps the engine its cached module was compiled with.
+static ENGINES: Lazy<Mutex<HashMap<String, wasmtime::Engine>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The limit a component exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Limit {
+  Memory,
+  Instances,
+  Timeout,
+}
+
+impl std::fmt::Display for Limit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Memory => write!(f, "memory"),
+      Self::Instances => write!(f, "instances"),
+      Self::Timeout => write!(f, "timeout"),
+    }
+  }
+}
+
+/// Called whenever a component exceeds one of its limits.
+pub type ViolationCallback = Arc<dyn Fn(Limit) + Send + Sync>;
+
+/// The engine and module cache key to load the module at `path` with, when `limits` restrict memory or instances.
+pub(crate) fn engine(
+  path: &str,
+  limits: &WasmLimits,
+) -> Result<Option<(wasmtime::Engine, String)>, WasmComponentError> {
+  if limits.max_memory().is_none() && limits.max_instances().is_none() {
+    return Ok(None);
+  }
+  let key = format!(
+    "{}?max_memory={}&max_instances={}",
+    path,
+    limits.max_memory().unwrap_or_default(),
+    limits.max_instances().unwrap_or_default()
+  );
+  let mut engines = ENGINES.lock();
+  if let Some(engine) = engines.get(&key) {
+    return Ok(Some((engine.clone(), key)));
+  }
+
+  let mut pool = wasmtime::PoolingAllocationConfig::default();
+  pool.memory_pages(
+    limits
+      .max_memory()
+      .map_or(MAX_WASM_PAGES, |bytes| bytes / WASM_PAGE_SIZE),
+  );
+  if let Some(count) = limits.max_instances() {
+    // each instance of a module has a single linear memory.
+    pool.total_core_instances(count).total_memories(count);
+  }
+  let mut config = wasmtime::Config::default();
+  config.strategy(wasmtime::Strategy::Cranelift);
+  config.allocation_strategy(wasmtime::InstanceAllocationStrategy::Pooling(pool));
+  if let Err(e) = config.cache_config_load_default() {
+    warn!(error = %e, "could not load wasmtime cache config");
+  }
+  let engine = wasmtime::Engine::new(&config).map_err(|e| WasmComponentError::EngineFailure(e.to_string()))?;
+  engines.insert(key.clone(), engine.clone());
+  Ok(Some((engine, key)))
+}
+
+/// The limit an error from the engine reports was exceeded, if any. Wasmtime only describes these in its messages.
+pub(crate) fn exceeded(error: &str) -> Option<Limit> {
+  if error.contains("concurrent instance limit") {
+    Some(Limit::Instances)
+  } else if error.contains("exceeds the limit") || error.contains("memory allocation of") {
+    Some(Limit::Memory)
+  } else {
+    None
+  }
+}
+
+/// The limit `error` reports was exceeded, if any.
+pub(crate) const fn violated(error: &WasmComponentError) -> Option<Limit> {
+  match error {
+    WasmComponentError::InstanceLimit(_) => Some(Limit::Instances),
+    WasmComponentError::MemoryLimit(_) => Some(Limit::Memory),
+    WasmComponentError::Timeout(..) => Some(Limit::Timeout),
+    _ => None,
+  }
+}
+
+/// The time an invocation can run: the component's time limit, cut short by the invocation's own deadline.
+pub(crate) fn timeout(limits: &WasmLimits, remaining: Option<Duration>) -> Option<Duration> {
+  match (limits.timeout().map(Duration::from_millis), remaining) {
+    (Some(limit), Some(remaining)) => Some(limit.min(remaining)),
+    (limit, remaining) => limit.or(remaining),
+  }
+}
+
+/// A watchdog that fails an invocation that outlives its `timeout` and reports errors in its output that come from
+/// running out of memory.
+///
+/// The wasmrs engine owns the wasmtime store, so a runaway guest can't be interrupted with epochs. Instead the
+/// watchdog preempts the invocation: it cancels it, ends its output with an error packet so the transaction moves
+/// on, and retires the instance it ran on, when it has one of its own, so nothing else is sent to it.
+pub(crate) struct Limited {
+  output: Option<PacketStream>,
+  deadline: Option<Pin<Box<tokio::time::Sleep>>>,
+  timeout: Duration,
+  operation: String,
+  limits: WasmLimits,
+  on_violation: Option<ViolationCallback>,
+  retire: Option<Arc<AtomicBool>>,
+}
+
+impl Limited {
+  pub(crate) fn new(
+    output: PacketStream,
+    operation: String,
+    limits: WasmLimits,
+    timeout: Option<Duration>,
+    on_violation: Option<ViolationCallback>,
+  ) -> Self {
+    Self {
+      output: Some(output),
+      deadline: timeout.map(|timeout| Box::pin(tokio::time::sleep(timeout))),
+      timeout: timeout.unwrap_or_default(),
+      operation,
+      limits,
+      on_violation,
+      retire: None,
+    }
+  }
+
+  /// Set `retire` when the invocation times out.
+  pub(crate) fn retiring(mut self, retire: Arc<AtomicBool>) -> Self {
+    self.retire = Some(retire);
+    self
+  }
+
+  fn violation(&self, limit: Limit) {
+    if let Some(callback) = &self.on_violation {
+      callback(limit);
+    }
+  }
+}
+
+impl tokio_stream::Stream for Limited {
+  type Item = Result<Packet, wick_packet::Error>;
+
+  fn poll_next(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+    let Some(output) = self.output.as_mut() else {
+      return Poll::Ready(None);
+    };
+    if let Poll::Ready(next) = Pin::new(output).poll_next(cx) {
+      if let Some(Ok(packet)) = &next {
+        if let PacketPayload::Err(e) = packet.payload() {
+          if self.limits.max_memory().is_some() && exceeded(e.msg()) == Some(Limit::Memory) {
+            self.violation(Limit::Memory);
+          }
+        }
+      }
+      return Poll::Ready(next);
+    }
+    let timed_out = self
+      .deadline
+      .as_mut()
+      .map_or(false, |deadline| deadline.as_mut().poll(cx).is_ready());
+    if !timed_out {
+      return Poll::Pending;
+    }
+
+    if let Some(retire) = &self.retire {
+      retire.store(true, Ordering::Relaxed);
+    }
+    // Dropping the output cancels the invocation.
+    self.output = None;
+    self.deadline = None;
+    self.violation(Limit::Timeout);
+    let error = WasmComponentError::Timeout(
+      self.operation.clone(),
+      u64::try_from(self.timeout.as_millis()).unwrap_or(u64::MAX),
+    );
+    warn!(error = %error, "wasm invocation timed out");
+    Poll::Ready(Some(Ok(Packet::component_error(error.to_string()))))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use tokio_stream::StreamExt;
+  use wick_config::config::components::WasmLimitsBuilder;
+
+  use super::*;
+
+  #[test]
+  fn test_exceeded() {
+    assert_eq!(
+      exceeded("maximum concurrent instance limit of 2 reached"),
+      Some(Limit::Instances)
+    );
+    assert_eq!(
+      exceeded("memory index 0 has a minimum page size of 17 which exceeds the limit of 16"),
+      Some(Limit::Memory)
+    );
+    assert_eq!(
+      exceeded("memory allocation of 1048576 bytes failed"),
+      Some(Limit::Memory)
+    );
+    assert_eq!(exceeded("operation failed"), None);
+  }
+
+  #[tokio::test]
+  async fn test_timeout() -> Result<()> {
+    let (_tx, output) = PacketStream::new_channels();
+    let limits = WasmLimitsBuilder::default().timeout(10_u64).build()?;
+    let violations = Arc::new(Mutex::new(Vec::new()));
+    let recorded = violations.clone();
+    let retire = Arc::new(AtomicBool::new(false));
+    let limited = Limited::new(
+      output,
+      "add".to_owned(),
+      limits,
+      timeout(&limits, None),
+      Some(Arc::new(move |limit| recorded.lock().push(limit))),
+    )
+    .retiring(retire.clone());
+    let packets: Vec<_> = limited.collect().await;
+    assert_eq!(
+      packets,
+      vec![Ok(Packet::component_error(
+        "Operation 'add' exceeded its time limit of 10ms"
+      ))]
+    );
+    assert_eq!(*violations.lock(), vec![Limit::Timeout]);
+    assert!(retire.load(Ordering::Relaxed), "a timed out instance should be retired");
+    Ok(())
+  }
+
+  #[test]
+  fn test_effective_timeout() -> Result<()> {
+    let limits = WasmLimitsBuilder::default().timeout(100_u64).build()?;
+    assert_eq!(timeout(&limits, None), Some(Duration::from_millis(100)));
+    assert_eq!(
+      timeout(&limits, Some(Duration::from_millis(40))),
+      Some(Duration::from_millis(40))
+    );
+    assert_eq!(
+      timeout(&WasmLimits::default(), Some(Duration::from_millis(40))),
+      Some(Duration::from_millis(40))
+    );
+    assert_eq!(timeout(&WasmLimits::default(), None), None);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_no_limit() -> Result<()> {
+    let output: PacketStream = vec![Packet::encode("output", 1), Packet::done("output")].into();
+    let limited = Limited::new(output, "add".to_owned(), WasmLimits::default(), None, None);
+    let packets: Vec<_> = limited.collect().await;
+    assert_eq!(packets.len(), 2);
+    Ok(())
+  }
+}
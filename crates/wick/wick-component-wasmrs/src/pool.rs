@@ -0,0 +1,245 @@
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::Poll;
+
+use parking_lot::Mutex;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::Span;
+use wasmrs_host::{CallContext, Host};
+use wick_config::config::components::{WasmLimits, WasmPool};
+use wick_packet::{Packet, PacketStream};
+
+use crate::error::WasmComponentError;
+use crate::wasmrs_host::{context_error, setup_context, SetupPayload};
+use crate::Result;
+
+/// Counts of a pool's instances and checkouts, shared with whatever reports them.
+#[derive(Debug, Default)]
+pub struct PoolStats {
+  instances: AtomicU32,
+  idle: AtomicU32,
+  checkouts: AtomicU64,
+  cold_starts: AtomicU64,
+  waits: AtomicU64,
+  retired: AtomicU64,
+}
+
+impl PoolStats {
+  /// The instances the pool has created.
+  #[must_use]
+  pub fn instances(&self) -> u32 {
+    self.instances.load(Ordering::Relaxed)
+  }
+
+  /// The instances ready to be checked out.
+  #[must_use]
+  pub fn idle(&self) -> u32 {
+    self.idle.load(Ordering::Relaxed)
+  }
+
+  /// The invocations that have checked out an instance.
+  #[must_use]
+  pub fn checkouts(&self) -> u64 {
+    self.checkouts.load(Ordering::Relaxed)
+  }
+
+  /// The checkouts that had to create an instance because none were idle.
+  #[must_use]
+  pub fn cold_starts(&self) -> u64 {
+    self.cold_starts.load(Ordering::Relaxed)
+  }
+
+  /// The checkouts that waited for an instance because the pool was at its limit.
+  #[must_use]
+  pub fn waits(&self) -> u64 {
+    self.waits.load(Ordering::Relaxed)
+  }
+
+  /// The instances taken out of the pool because an invocation running on them timed out.
+  #[must_use]
+  pub fn retired(&self) -> u64 {
+    self.retired.load(Ordering::Relaxed)
+  }
+}
+
+/// Instances of a component that invocations check out one at a time.
+pub(crate) struct InstancePool {
+  host: Arc<Host>,
+  buffer_size: u32,
+  min: u32,
+  limits: WasmLimits,
+  setup: Mutex<Option<SetupPayload>>,
+  idle: Mutex<Vec<Arc<CallContext>>>,
+  permits: Arc<Semaphore>,
+  stats: Arc<PoolStats>,
+  span: Span,
+}
+
+impl std::fmt::Debug for InstancePool {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("InstancePool")
+      .field("min", &self.min)
+      .field("stats", &self.stats)
+      .finish()
+  }
+}
+
+impl InstancePool {
+  /// A pool that starts with the already created instance `first`.
+  pub(crate) fn new(
+    host: Host,
+    first: Arc<CallContext>,
+    buffer_size: u32,
+    config: WasmPool,
+    limits: WasmLimits,
+    stats: Arc<PoolStats>,
+    span: Span,
+  ) -> Self {
+    let min = config.min_instances().unwrap_or(1).max(1);
+    let max = config.max_instances().unwrap_or(min).max(min);
+    stats.instances.store(1, Ordering::Relaxed);
+    stats.idle.store(1, Ordering::Relaxed);
+    Self {
+      host: Arc::new(host),
+      buffer_size,
+      min,
+      limits,
+      setup: Mutex::new(None),
+      idle: Mutex::new(vec![first]),
+      permits: Arc::new(Semaphore::new(usize::try_from(max).unwrap_or(usize::MAX))),
+      stats,
+      span,
+    }
+  }
+
+  /// Create and set up instances until the pool has its minimum, once the first instance is set up with `payload`.
+  pub(crate) async fn warm(&self, payload: SetupPayload) -> Result<()> {
+    self.setup.lock().replace(payload);
+    for _ in 1..self.min {
+      let ctx = self.instantiate().await?;
+      self.idle.lock().push(ctx);
+      self.stats.idle.fetch_add(1, Ordering::Relaxed);
+    }
+    self
+      .span
+      .in_scope(|| debug!(instances = self.min, "wasm instance pool warmed"));
+    Ok(())
+  }
+
+  /// An idle instance, or a new one if there are none, waiting for one to be returned when the pool is full.
+  pub(crate) async fn checkout(self: &Arc<Self>) -> Result<PooledInstance> {
+    let permit = if let Ok(permit) = self.permits.clone().try_acquire_owned() {
+      permit
+    } else {
+      self.stats.waits.fetch_add(1, Ordering::Relaxed);
+      self
+        .permits
+        .clone()
+        .acquire_owned()
+        .await
+        .map_err(|e| WasmComponentError::EngineFailure(e.to_string()))?
+    };
+    self.stats.checkouts.fetch_add(1, Ordering::Relaxed);
+
+    let idle = self.idle.lock().pop();
+    let ctx = if let Some(ctx) = idle {
+      self.stats.idle.fetch_sub(1, Ordering::Relaxed);
+      ctx
+    } else {
+      self.stats.cold_starts.fetch_add(1, Ordering::Relaxed);
+      self.instantiate().await?
+    };
+    Ok(PooledInstance {
+      ctx,
+      pool: self.clone(),
+      retired: Arc::new(AtomicBool::new(false)),
+      _permit: permit,
+    })
+  }
+
+  async fn instantiate(&self) -> Result<Arc<CallContext>> {
+    let ctx = self
+      .host
+      .new_context(self.buffer_size, self.buffer_size)
+      .await
+      .map_err(|e| context_error(e, &self.limits))?;
+    let payload = self.setup.lock().clone();
+    if let Some(payload) = payload {
+      setup_context(&ctx, &payload, &self.span).await?;
+    }
+    self.stats.instances.fetch_add(1, Ordering::Relaxed);
+    Ok(Arc::new(ctx))
+  }
+
+  fn release(&self, ctx: Arc<CallContext>) {
+    self.idle.lock().push(ctx);
+    self.stats.idle.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Drop an instance that may still be running a runaway invocation. A later checkout replaces it.
+  fn retire(&self) {
+    self
+      .span
+      .in_scope(|| warn!("retiring wasm instance after an invocation timed out"));
+    self.stats.instances.fetch_sub(1, Ordering::Relaxed);
+    self.stats.retired.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+/// An instance checked out of an [InstancePool], returned to it when dropped unless it was retired.
+pub(crate) struct PooledInstance {
+  ctx: Arc<CallContext>,
+  pool: Arc<InstancePool>,
+  retired: Arc<AtomicBool>,
+  _permit: OwnedSemaphorePermit,
+}
+
+impl PooledInstance {
+  pub(crate) fn context(&self) -> Arc<CallContext> {
+    self.ctx.clone()
+  }
+
+  /// The flag that retires the instance instead of returning it to the pool.
+  pub(crate) fn retirement(&self) -> Arc<AtomicBool> {
+    self.retired.clone()
+  }
+}
+
+impl Drop for PooledInstance {
+  fn drop(&mut self) {
+    // The permit is released after the instance is back in the pool, so the next checkout finds it idle.
+    if self.retired.load(Ordering::Relaxed) {
+      self.pool.retire();
+    } else {
+      self.pool.release(self.ctx.clone());
+    }
+  }
+}
+
+/// Keeps an instance checked out until the output of the invocation it's running finishes.
+pub(crate) struct Checkout {
+  output: PacketStream,
+  instance: Option<PooledInstance>,
+}
+
+impl Checkout {
+  pub(crate) const fn new(output: PacketStream, instance: PooledInstance) -> Self {
+    Self {
+      output,
+      instance: Some(instance),
+    }
+  }
+}
+
+impl tokio_stream::Stream for Checkout {
+  type Item = std::result::Result<Packet, wick_packet::Error>;
+
+  fn poll_next(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+    let next = Pin::new(&mut self.output).poll_next(cx);
+    if matches!(next, Poll::Ready(None)) {
+      self.instance.take();
+    }
+    next
+  }
+}
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::pin::Pin;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::task::Poll;
 use std::time::{Duration, Instant};
 
 use flow_component::LocalScope;
@@ -15,6 +17,7 @@ use wasmrs_codec::messagepack::serialize;
 use wasmrs_host::{CallContext, Host, WasiParams};
 use wasmrs_rx::{FluxChannel, Observer};
 use wasmrs_wasmtime::WasmtimeBuilder;
+use wick_config::config::components::{WasmLimits, WasmPool};
 use wick_config::FetchableAssetReference;
 use wick_interface_types::ComponentSignature;
 use wick_packet::{
@@ -24,14 +27,17 @@ use wick_packet::{
   ComponentReference,
   ContextTransport,
   Entity,
-  InherentData,
   Invocation,
+  Packet,
+  PacketSender,
   PacketStream,
   RuntimeConfig,
 };
 use wick_wascap::{Claims, WickComponent};
 
 use crate::error::WasmComponentError;
+use crate::limits::{self, Limit, Limited, ViolationCallback};
+use crate::pool::{Checkout, InstancePool, PoolStats, PooledInstance};
 use crate::wasmrs_module::WickWasmModule;
 use crate::{Error, Result};
 
@@ -45,12 +51,17 @@ pub struct WasmHostBuilder {
   engine: Option<wasmtime::Engine>,
   span: Span,
   buffer_size: Option<u32>,
+  limits: Option<WasmLimits>,
+  on_violation: Option<ViolationCallback>,
+  pool: Option<(WasmPool, Arc<PoolStats>)>,
 }
 
 impl std::fmt::Debug for WasmHostBuilder {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.debug_struct("WasmHostBuilder")
       .field("wasi_params", &self.wasi_params)
+      .field("limits", &self.limits)
+      .field("pool", &self.pool)
       .finish()
   }
 }
@@ -62,6 +73,9 @@ impl WasmHostBuilder {
       callback: None,
       engine: None,
       buffer_size: None,
+      limits: None,
+      on_violation: None,
+      pool: None,
       span,
     }
   }
@@ -90,6 +104,23 @@ impl WasmHostBuilder {
     self
   }
 
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn limits(mut self, limits: WasmLimits) -> Self {
+    self.limits = Some(limits);
+    self
+  }
+
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn on_violation(mut self, callback: ViolationCallback) -> Self {
+    self.on_violation = Some(callback);
+    self
+  }
+
+  pub fn pool(mut self, config: WasmPool, stats: Arc<PoolStats>) -> Self {
+    self.pool = Some((config, stats));
+    self
+  }
+
   pub fn preopened_dirs(mut self, dirs: Vec<PathBuf>) -> Self {
     let mut params = self.wasi_params.take().unwrap_or_default();
     params.preopened_dirs = dirs;
@@ -98,15 +129,29 @@ impl WasmHostBuilder {
   }
 
   pub async fn build(self, reference: &FetchableAssetReference<'_>) -> Result<WasmHost> {
-    WasmHost::try_load(
+    let result = WasmHost::try_load(
       reference,
       self.engine,
       self.wasi_params,
       self.buffer_size.unwrap_or(DEFAULT_BUFFER_SIZE),
       &self.callback,
+      self.limits.unwrap_or_default(),
+      self.pool,
       self.span,
     )
-    .await
+    .await;
+    match result {
+      Ok(mut host) => {
+        host.on_violation = self.on_violation;
+        Ok(host)
+      }
+      Err(e) => {
+        if let (Some(limit), Some(callback)) = (limits::violated(&e), &self.on_violation) {
+          callback(limit);
+        }
+        Err(e)
+      }
+    }
   }
 }
 
@@ -114,30 +159,49 @@ impl WasmHostBuilder {
 pub struct WasmHost {
   claims: Claims<WickComponent>,
   ctx: Arc<CallContext>,
+  pool: Option<Arc<InstancePool>>,
   _rng: seeded_random::Random,
+  limits: WasmLimits,
+  on_violation: Option<ViolationCallback>,
   span: Span,
 }
 
 impl std::fmt::Debug for WasmHost {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    f.debug_struct("WasmHost").field("claims", &self.claims).finish()
+    f.debug_struct("WasmHost")
+      .field("claims", &self.claims)
+      .field("limits", &self.limits)
+      .field("pool", &self.pool)
+      .finish()
   }
 }
 
 impl WasmHost {
+  #[allow(clippy::too_many_arguments)]
   pub async fn try_load(
     asset: &FetchableAssetReference<'_>,
     engine: Option<wasmtime::Engine>,
     wasi_options: Option<WasiParams>,
     buffer_size: u32,
     callback: &Option<LocalScope>,
+    limits: WasmLimits,
+    pool: Option<(WasmPool, Arc<PoolStats>)>,
     span: Span,
   ) -> Result<Self> {
     let _span = span.enter();
 
     let time = Instant::now();
 
-    let path = asset.path()?.to_string_lossy().to_string();
+    let mut path = asset.path()?.to_string_lossy().to_string();
+
+    // Limited modules are compiled for an engine of their own, so they're cached apart from unlimited ones.
+    let engine = match limits::engine(&path, &limits)? {
+      Some((limited, key)) => {
+        path = key;
+        Some(limited)
+      }
+      None => engine,
+    };
 
     let mut builder = WasmtimeBuilder::new();
     builder = if let Some(engine) = engine {
@@ -186,45 +250,75 @@ impl WasmHost {
       trace!(index, "wasmrs callback index");
     }
 
-    let ctx = match host.new_context(buffer_size, buffer_size).await {
-      Ok(ctx) => ctx,
-      Err(e) => {
-        // wasmtime has junk errors so we need to parse the string to provide useful information.
-
-        let errstr = e.to_string();
-
-        if errstr.contains("wasi_snapshot") {
-          error!("wasi error initializing component, this may be because a component was compiled with wasi extensions that are not enabled in wick");
-        }
-        return Err(Error::ContextInit(e));
-      }
-    };
+    let ctx = Arc::new(
+      host
+        .new_context(buffer_size, buffer_size)
+        .await
+        .map_err(|e| context_error(e, &limits))?,
+    );
+    let pool = pool.map(|(config, stats)| {
+      Arc::new(InstancePool::new(
+        host,
+        ctx.clone(),
+        buffer_size,
+        config,
+        limits,
+        stats,
+        span.clone(),
+      ))
+    });
 
     drop(_span);
     Ok(Self {
       claims,
-      ctx: Arc::new(ctx),
+      ctx,
+      pool,
       _rng: seeded_random::Random::new(),
+      limits,
+      on_violation: None,
       span,
     })
   }
 
   #[allow(clippy::needless_pass_by_value)]
-  pub fn call(&self, invocation: Invocation, config: Option<RuntimeConfig>) -> Result<PacketStream> {
+  pub async fn call(&self, invocation: Invocation, config: Option<RuntimeConfig>) -> Result<PacketStream> {
+    // Pooled components run each invocation on an instance of its own.
+    let instance = match &self.pool {
+      Some(pool) => match pool.checkout().await {
+        Ok(instance) => Some(instance),
+        Err(e) => {
+          if let (Some(limit), Some(callback)) = (limits::violated(&e), &self.on_violation) {
+            callback(limit);
+          }
+          return Err(e);
+        }
+      },
+      None => None,
+    };
+    let ctx = instance.as_ref().map_or_else(|| self.ctx.clone(), |i| i.context());
+
     let _span = self.span.enter();
     let (invocation, mut stream) = invocation.split();
     let component_name = invocation.target.operation_id();
     let now = Instant::now();
-    let ctx = self.ctx.clone();
     let index = ctx
       .get_export("wick", component_name)
       .ok_or(crate::Error::OperationNotFound(
         component_name.to_owned(),
         ctx.get_exports(),
       ))?;
-    let inherent = InherentData::new(invocation.inherent.seed, invocation.inherent.timestamp);
+    let timeout = limits::timeout(&self.limits, invocation.inherent.remaining());
+    // guests send the transaction back with their link calls, so blobs they put are held for it.
+    let inherent = invocation
+      .inherent
+      .unsafe_clone()
+      .with_tx_id(Some(invocation.tx_id.to_string()));
     stream.set_context(config.unwrap_or_default(), inherent);
 
+    // Keep the component's input open until its output finishes so a cancellation signal can still be delivered.
+    let (cancel_tx, cancel_rx) = PacketStream::new_channels();
+    let stream = PacketStream::new(Box::new(tokio_stream::StreamExt::merge(stream, cancel_rx)));
+
     let wasmrs_stream = packetstream_to_wasmrs(index, stream);
     let out = ctx.request_channel(Box::pin(wasmrs_stream));
     trace!(
@@ -232,41 +326,37 @@ impl WasmHost {
       duration_μs = ?now.elapsed().as_micros(),
       "received stream"
     );
-    Ok(from_raw_wasmrs(out))
+    let mut output = PacketStream::new(Box::new(CancelOnDrop {
+      output: from_raw_wasmrs(out),
+      cancel: Some(cancel_tx),
+    }));
+    let retire = instance.as_ref().map(PooledInstance::retirement);
+    if let Some(instance) = instance {
+      output = PacketStream::new(Box::new(Checkout::new(output, instance)));
+    }
+    if timeout.is_none() && self.limits.max_memory().is_none() {
+      return Ok(output);
+    }
+    let mut limited = Limited::new(
+      output,
+      component_name.to_owned(),
+      self.limits,
+      timeout,
+      self.on_violation.clone(),
+    );
+    if let Some(retire) = retire {
+      limited = limited.retiring(retire);
+    }
+    Ok(PacketStream::new(Box::new(limited)))
   }
 
+  /// Set up the component and, when it's pooled, warm the pool's instances up with the same setup.
   pub async fn setup(&self, provided: SetupPayload) -> Result<()> {
-    let ctx = self.ctx.clone();
-    let payload = self.span.in_scope(|| {
-      debug!("wasm setup");
-
-      let index = ctx.get_export("wick", "__setup").ok_or(crate::Error::SetupOperation)?;
-      let metadata = wasmrs::Metadata::new(index);
-      let data = serialize(&provided).unwrap();
-      Ok::<_, WasmComponentError>(RawPayload::new(metadata.encode(), data.into()))
-    })?;
-
-    // this should never take more than a second.
-    let result = timeout(Duration::from_millis(1000), ctx.request_response(payload)).await;
-
-    self.span.in_scope(|| {
-      match result {
-        Ok(Ok(_)) => {
-          debug!("setup finished");
-        }
-        Ok(Err(e)) => {
-          error!("setup failed: {}", e);
-          return Err(Error::Setup(e));
-        }
-        Err(e) => {
-          error!("setup failed with timeout: {}", e);
-          return Err(Error::SetupTimeout);
-        }
-      }
-
-      trace!("wasm setup finished");
-      Ok(())
-    })
+    setup_context(&self.ctx, &provided, &self.span).await?;
+    if let Some(pool) = &self.pool {
+      pool.warm(provided).await?;
+    }
+    Ok(())
   }
 
   pub fn signature(&self) -> &ComponentSignature {
@@ -275,6 +365,55 @@ impl WasmHost {
   }
 }
 
+/// The error to report for an instance that couldn't be created.
+pub(crate) fn context_error(e: wasmrs_host::errors::Error, limits: &WasmLimits) -> Error {
+  // wasmtime has junk errors so we need to parse the string to provide useful information.
+  let errstr = e.to_string();
+
+  if errstr.contains("wasi_snapshot") {
+    error!("wasi error initializing component, this may be because a component was compiled with wasi extensions that are not enabled in wick");
+  }
+  match limits::exceeded(&errstr) {
+    Some(Limit::Instances) => Error::InstanceLimit(limits.max_instances().unwrap_or_default()),
+    Some(Limit::Memory) => Error::MemoryLimit(limits.max_memory().unwrap_or_default()),
+    _ => Error::ContextInit(e),
+  }
+}
+
+/// Run the component's `__setup` operation on the instance `ctx`.
+pub(crate) async fn setup_context(ctx: &CallContext, provided: &SetupPayload, span: &Span) -> Result<()> {
+  let payload = span.in_scope(|| {
+    debug!("wasm setup");
+
+    let index = ctx.get_export("wick", "__setup").ok_or(crate::Error::SetupOperation)?;
+    let metadata = wasmrs::Metadata::new(index);
+    let data = serialize(provided).unwrap();
+    Ok::<_, WasmComponentError>(RawPayload::new(metadata.encode(), data.into()))
+  })?;
+
+  // this should never take more than a second.
+  let result = timeout(Duration::from_millis(1000), ctx.request_response(payload)).await;
+
+  span.in_scope(|| {
+    match result {
+      Ok(Ok(_)) => {
+        debug!("setup finished");
+      }
+      Ok(Err(e)) => {
+        error!("setup failed: {}", e);
+        return Err(Error::Setup(e));
+      }
+      Err(e) => {
+        error!("setup failed with timeout: {}", e);
+        return Err(Error::SetupTimeout);
+      }
+    }
+
+    trace!("wasm setup finished");
+    Ok(())
+  })
+}
+
 fn make_event_callback(span: Span) -> OperationHandler<wasmrs::IncomingMono, ()> {
   let func = move |incoming: wasmrs::IncomingMono| {
     let span = span.clone();
@@ -388,3 +527,71 @@ impl SetupPayload {
     }
   }
 }
+
+/// Sends a [Packet::cancel] signal to the component if its output is dropped before it finishes.
+struct CancelOnDrop {
+  output: PacketStream,
+  cancel: Option<PacketSender>,
+}
+
+impl tokio_stream::Stream for CancelOnDrop {
+  type Item = std::result::Result<Packet, wick_packet::Error>;
+
+  fn poll_next(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+    let next = Pin::new(&mut self.output).poll_next(cx);
+    if matches!(next, Poll::Ready(None)) {
+      if let Some(cancel) = self.cancel.take() {
+        cancel.complete();
+      }
+    }
+    next
+  }
+}
+
+impl Drop for CancelOnDrop {
+  fn drop(&mut self) {
+    if let Some(cancel) = self.cancel.take() {
+      trace!("output dropped before completion, cancelling component invocation");
+      let _ = cancel.send(Packet::cancel());
+      cancel.complete();
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use tokio_stream::StreamExt;
+  use wick_packet::PacketExt;
+
+  use super::*;
+
+  fn guard(packets: Vec<Packet>) -> (CancelOnDrop, PacketStream) {
+    let (cancel_tx, cancel_rx) = PacketStream::new_channels();
+    let guard = CancelOnDrop {
+      output: packets.into(),
+      cancel: Some(cancel_tx),
+    };
+    (guard, cancel_rx)
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_cancel_on_drop() -> Result<()> {
+    let (mut output, signals) = guard(vec![Packet::encode("output", "a"), Packet::done("output")]);
+    let _ = output.next().await.unwrap()?;
+    drop(output);
+    let signals = signals.collect::<std::result::Result<Vec<_>, _>>().await?;
+    assert_eq!(signals.len(), 1);
+    assert!(signals[0].is_cancel());
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_no_cancel_on_completion() -> Result<()> {
+    let (output, signals) = guard(vec![Packet::encode("output", "a"), Packet::done("output")]);
+    let _ = output.collect::<Vec<_>>().await;
+    let signals = signals.collect::<Vec<_>>().await;
+    assert!(signals.is_empty());
+    Ok(())
+  }
+}
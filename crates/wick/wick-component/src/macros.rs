@@ -98,14 +98,18 @@ macro_rules! stream_receivers {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! payload_fan_out {
-    (@handle_packet $payload: ident, $sender:ident, $config:ty) => {
+    (@handle_packet $payload: ident, $sender:ident, $cancel:ident, $config:ty) => {
       {
         let packet: $crate::wick_packet::Packet = $payload.into();
 
         if let Some(config_tx) = $sender.take() {
           if let Some(context) = packet.context() {
             let config: Result<$crate::wick_packet::ContextTransport<$config>, _> = $crate::wasmrs_codec::messagepack::deserialize(&context).map_err(|e|format!("Cound not deserialize context: {}", e));
-            let _ = config_tx.send(config.map($crate::flow_component::Context::from));
+            let _ = config_tx.send(config.map(|config| {
+              let mut ctx = $crate::flow_component::Context::from(config);
+              ctx.cancel = $cancel.clone();
+              ctx
+            }));
           } else {
             // packet = $crate::wick_packet::Packet::component_error("No context attached to first invocation packet");
           }
@@ -180,6 +184,7 @@ macro_rules! payload_fan_out {
 
         let (config_tx,config_rx) = $crate::runtime::oneshot();
         let mut config_tx = Some(config_tx);
+        let cancel = $crate::flow_component::CancelSignal::default();
         let config_mono = Box::pin(async move {config_rx.await.unwrap()});
         let output_streams = (config_mono, channels.receivers().unwrap());
 
@@ -188,7 +193,11 @@ macro_rules! payload_fan_out {
           use $crate::StreamExt;
           loop {
             if let Some(Ok(payload)) = $stream.next().await {
-              let packet = $crate::payload_fan_out!(@handle_packet payload, config_tx, $config);
+              let packet = $crate::payload_fan_out!(@handle_packet payload, config_tx, cancel, $config);
+              if $crate::wick_packet::PacketExt::is_cancel(&packet) {
+                cancel.cancel();
+                continue;
+              }
               $crate::payload_fan_out!(@route_packet packet, channels, [ $(($port, $($ty)+)),* ]);
             } else {
               break;
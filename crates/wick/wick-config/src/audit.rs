@@ -5,9 +5,12 @@ use url::Url;
 
 use crate::config::{
   Binding,
+  BrokerResource,
   ConfigOrDefinition,
   ConfigurationTreeNode,
   ResourceDefinition,
+  SecretProvider,
+  SecretResource,
   TcpPort,
   UdpPort,
   UrlResource,
@@ -76,6 +79,13 @@ impl From<&ResourceDefinition> for AuditedResource {
       ResourceDefinition::Volume(v) => Self::Volume(AuditedVolume {
         path: v.path().unwrap(),
       }),
+      ResourceDefinition::Broker(v) => Self::Broker(AuditedBroker {
+        name: v.name.value_unchecked().clone(),
+      }),
+      ResourceDefinition::Secret(v) => Self::Secret(AuditedSecret {
+        provider: v.provider,
+        source: v.source.value_unchecked().clone(),
+      }),
     }
   }
 }
@@ -94,6 +104,8 @@ impl From<AuditedResourceBinding> for ResourceDefinition {
       AuditedResource::UdpPort(v) => Self::UdpPort(UdpPort::new(v.address, v.port)),
       AuditedResource::Url(v) => Self::Url(UrlResource::new(v.url)),
       AuditedResource::Volume(v) => Self::Volume(Volume::new(v.path.to_string_lossy().to_string())),
+      AuditedResource::Broker(v) => Self::Broker(BrokerResource::new(v.name)),
+      AuditedResource::Secret(v) => Self::Secret(SecretResource::new(v.provider, v.source)),
     }
   }
 }
@@ -130,6 +142,12 @@ pub enum AuditedResource {
   /// A variant representing a [crate::config::Volume] type.
   #[serde(rename = "wick/resource/volume@v1")]
   Volume(AuditedVolume),
+  /// A variant representing a [crate::config::BrokerResource] type.
+  #[serde(rename = "wick/resource/broker@v1")]
+  Broker(AuditedBroker),
+  /// A variant representing a [crate::config::SecretResource] type.
+  #[serde(rename = "wick/resource/secret@v1")]
+  Secret(AuditedSecret),
 }
 
 impl std::fmt::Display for AuditedResource {
@@ -139,6 +157,8 @@ impl std::fmt::Display for AuditedResource {
       AuditedResource::UdpPort(v) => v.fmt(f),
       AuditedResource::Url(v) => v.fmt(f),
       AuditedResource::Volume(v) => v.fmt(f),
+      AuditedResource::Broker(v) => v.fmt(f),
+      AuditedResource::Secret(v) => v.fmt(f),
     }
   }
 }
@@ -168,6 +188,41 @@ impl std::fmt::Display for AuditedVolume {
   }
 }
 
+/// A summary of a broker resource.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct AuditedBroker {
+  pub(crate) name: String,
+}
+
+impl std::fmt::Display for AuditedBroker {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "broker {}", self.name)
+  }
+}
+
+/// A summary of a secret resource. It names where the secret is read from, never the secret itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct AuditedSecret {
+  pub(crate) provider: SecretProvider,
+  pub(crate) source: String,
+}
+
+impl AuditedSecret {
+  /// The directory a file secret is read from, which lockdown restricts like a volume.
+  pub(crate) fn file_dir(&self) -> Option<PathBuf> {
+    match self.provider {
+      SecretProvider::File => PathBuf::from(&self.source).parent().map(PathBuf::from),
+      _ => None,
+    }
+  }
+}
+
+impl std::fmt::Display for AuditedSecret {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "secret from {} {}", self.provider, self.source)
+  }
+}
+
 /// A summary of a URL resource.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
 pub struct AuditedUrl {
@@ -0,0 +1,113 @@
+use serde::Serialize;
+
+use crate::error::ManifestError;
+use crate::WickConfiguration;
+
+/// The name manifests use in `metadata.requires` to constrain the version of the Wick runtime.
+pub const WICK: &str = "wick";
+
+/// A description of a runtime that loads Wick manifests: its version, the manifest formats it understands, and the
+/// optional features it was built with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct RuntimeInfo {
+  /// The runtime's version.
+  pub version: String,
+  /// The manifest format versions this runtime can load.
+  pub formats: Vec<String>,
+  /// The optional features enabled in this runtime.
+  pub features: Vec<String>,
+}
+
+impl RuntimeInfo {
+  /// Describe a runtime at `version` that supports every manifest format compiled into this crate.
+  pub fn new<T: Into<String>>(version: T) -> Self {
+    Self {
+      version: version.into(),
+      formats: supported_formats(),
+      features: Vec::new(),
+    }
+  }
+
+  /// Set the optional features enabled in this runtime.
+  #[must_use]
+  pub fn with_features<T: Into<String>>(mut self, features: impl IntoIterator<Item = T>) -> Self {
+    self.features = features.into_iter().map(Into::into).collect();
+    self
+  }
+
+  /// Check that this runtime satisfies the `wick` requirement in a manifest's `metadata.requires`.
+  ///
+  /// Manifests without metadata or without a `wick` requirement are always compatible.
+  pub fn check(&self, config: &WickConfiguration) -> Result<(), ManifestError> {
+    let Some(requirement) = config.metadata().and_then(|m| m.requires().get(WICK)) else {
+      return Ok(());
+    };
+    let manifest = config
+      .source()
+      .map_or_else(|| "<raw>".to_owned(), |p| p.display().to_string());
+    let req = semver::VersionReq::parse(requirement)
+      .map_err(|e| ManifestError::InvalidRequirement(manifest.clone(), requirement.clone(), e.to_string()))?;
+    let version = semver::Version::parse(&self.version)
+      .map_err(|e| ManifestError::InvalidRequirement(manifest.clone(), self.version.clone(), e.to_string()))?;
+    if req.matches(&version) {
+      Ok(())
+    } else {
+      Err(ManifestError::IncompatibleRuntime {
+        manifest,
+        requirement: requirement.clone(),
+        version: self.version.clone(),
+      })
+    }
+  }
+}
+
+/// The manifest format versions enabled in this build of the crate.
+#[must_use]
+pub fn supported_formats() -> Vec<String> {
+  let mut formats = Vec::new();
+  if cfg!(feature = "v0") {
+    formats.push("v0".to_owned());
+  }
+  if cfg!(feature = "v1") {
+    formats.push("v1".to_owned());
+  }
+  formats
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  fn manifest(requires: &str) -> Result<WickConfiguration> {
+    let yaml = format!(
+      "kind: wick/component@v1\nname: test\nmetadata:\n  version: 0.0.1\n  requires:\n    wick: '{}'\ncomponent:\n  kind: wick/component/composite@v1\n",
+      requires
+    );
+    Ok(WickConfiguration::from_yaml(&yaml, &None)?.into_inner())
+  }
+
+  #[test]
+  fn test_check() -> Result<()> {
+    let runtime = RuntimeInfo::new("0.17.1");
+    runtime.check(&manifest(">=0.17")?)?;
+    runtime.check(&manifest("~0.17.0")?)?;
+
+    let err = runtime.check(&manifest(">=0.18")?).unwrap_err();
+    assert!(matches!(err, ManifestError::IncompatibleRuntime { .. }));
+    assert!(err.to_string().contains(">=0.18"));
+
+    assert!(matches!(
+      runtime.check(&manifest("not a version")?),
+      Err(ManifestError::InvalidRequirement(..))
+    ));
+    Ok(())
+  }
+
+  #[test]
+  fn test_formats() {
+    assert!(RuntimeInfo::new("0.17.0").formats.contains(&"v1".to_owned()));
+  }
+}
@@ -36,6 +36,7 @@ use wick_packet::{Entity, RuntimeConfig};
 use self::template_config::Renderable;
 use crate::load::resolve_configuration;
 use crate::lockdown::Lockdown;
+use crate::span::SourceText;
 use crate::{Error, Imports, RootConfig};
 
 #[derive(Debug, Clone, property::Property)]
@@ -84,10 +85,16 @@ impl UninitializedConfiguration {
       self.manifest.config(),
     )
     .map_err(Error::ConfigurationInvalid)?;
+    let source = self.manifest.source().map(ToOwned::to_owned);
+    // these errors only name what they're about, so find where that is in the manifest.
+    let locate = |e: Error| match source.as_deref().and_then(SourceText::read) {
+      Some(text) => e.locate(&text),
+      None => e,
+    };
     self.manifest.set_env(self.env);
     self.manifest.set_root_config(self.root_config);
-    self.manifest.initialize()?;
-    self.manifest.validate()?;
+    self.manifest.initialize().map_err(locate)?;
+    self.manifest.validate().map_err(locate)?;
     Ok(self.manifest)
   }
 }
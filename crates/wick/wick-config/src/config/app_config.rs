@@ -1,6 +1,7 @@
 #![allow(missing_docs)] // delete when we move away from the `property` crate.
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+mod admin;
 pub(super) mod triggers;
 
 use asset_container::{AssetManager, Assets};
@@ -9,6 +10,7 @@ use wick_asset_reference::{AssetReference, FetchOptions};
 use wick_interface_types::TypeDefinition;
 use wick_packet::{Entity, RuntimeConfig};
 
+pub use self::admin::{AdminConfig, AdminConfigBuilder, AdminConfigBuilderError};
 pub use self::triggers::*;
 use super::common::component_definition::ComponentDefinition;
 use super::common::package_definition::PackageConfig;
@@ -79,6 +81,12 @@ pub struct AppConfiguration {
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub(crate) triggers: Vec<TriggerDefinition>,
 
+  #[asset(skip)]
+  #[builder(setter(strip_option), default)]
+  /// The admin listener that serves health and readiness probes.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) admin: Option<AdminConfig>,
+
   #[asset(skip)]
   #[doc(hidden)]
   #[builder(default)]
@@ -374,6 +382,8 @@ mod test {
             .response(vec![op.clone()])
             .build()?,
         ),
+        auth: None,
+        cors: None,
         codec: Some(Codec::Json),
         operation: op,
       })])
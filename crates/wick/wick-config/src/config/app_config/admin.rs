@@ -0,0 +1,15 @@
+use crate::config::{AlertRule, BoundIdentifier};
+
+#[derive(Debug, Clone, PartialEq, property::Property, serde::Serialize, derive_builder::Builder)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+#[must_use]
+/// Settings for the built-in admin listener that serves health and readiness probes.
+pub struct AdminConfig {
+  /// The TcpPort resource to listen on.
+  pub(crate) resource: BoundIdentifier,
+  /// Rules checked against the app's operation metrics that invoke an operation when they fire.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) alerts: Vec<AlertRule>,
+}
@@ -1,14 +1,25 @@
+mod broker;
 mod cli;
+mod grpc;
 mod http;
+mod log;
 mod time;
 mod wasm_command;
 
 use std::collections::HashMap;
 use std::path::Path;
 
-pub use cli::{CliConfig, CliConfigBuilder, CliConfigBuilderError};
+pub use broker::{BrokerTriggerConfig, BrokerTriggerConfigBuilder, BrokerTriggerConfigBuilderError};
+pub use cli::{CliConfig, CliConfigBuilder, CliConfigBuilderError, StdinMode};
+pub use grpc::{GrpcTriggerConfig, GrpcTriggerConfigBuilder, GrpcTriggerConfigBuilderError};
 pub use http::{
+  ApiKeyAuth,
+  AuthConfig,
+  BasicAuth,
   Contact,
+  CorsConfig,
+  CorsConfigBuilder,
+  CorsConfigBuilderError,
   Documentation,
   HttpRouterConfig,
   HttpRouterKind,
@@ -16,6 +27,7 @@ pub use http::{
   HttpTriggerConfigBuilder,
   HttpTriggerConfigBuilderError,
   Info,
+  JwtAuth,
   License,
   Middleware,
   MiddlewareBuilder,
@@ -26,6 +38,7 @@ pub use http::{
   RawRouterConfig,
   RawRouterConfigBuilder,
   RawRouterConfigBuilderError,
+  ResponseMode,
   RestRoute,
   RestRouterConfig,
   RestRouterConfigBuilder,
@@ -36,6 +49,15 @@ pub use http::{
   Tools,
   WickRouter,
 };
+pub use log::{
+  LogFormat,
+  LogSourceConfig,
+  LogSourceConfigBuilder,
+  LogSourceConfigBuilderError,
+  LogTriggerConfig,
+  LogTriggerConfigBuilder,
+  LogTriggerConfigBuilderError,
+};
 use serde_json::Value;
 pub use time::{
   ScheduleConfig,
@@ -70,6 +92,12 @@ pub enum TriggerDefinition {
   Http(HttpTriggerConfig),
   /// A time trigger.
   Time(TimeTriggerConfig),
+  /// A gRPC trigger.
+  Grpc(GrpcTriggerConfig),
+  /// A log trigger.
+  Log(LogTriggerConfig),
+  /// A broker trigger.
+  Broker(BrokerTriggerConfig),
 }
 
 impl TriggerDefinition {
@@ -80,6 +108,9 @@ impl TriggerDefinition {
       TriggerDefinition::Cli(_) => TriggerKind::Cli,
       TriggerDefinition::Http(_) => TriggerKind::Http,
       TriggerDefinition::Time(_) => TriggerKind::Time,
+      TriggerDefinition::Grpc(_) => TriggerKind::Grpc,
+      TriggerDefinition::Log(_) => TriggerKind::Log,
+      TriggerDefinition::Broker(_) => TriggerKind::Broker,
     }
   }
 }
@@ -96,6 +127,9 @@ impl Renderable for TriggerDefinition {
       TriggerDefinition::Cli(v) => v.render_config(source, root_config, env),
       TriggerDefinition::Http(v) => v.render_config(source, root_config, env),
       TriggerDefinition::Time(v) => v.render_config(source, root_config, env),
+      TriggerDefinition::Grpc(v) => v.render_config(source, root_config, env),
+      TriggerDefinition::Log(v) => v.render_config(source, root_config, env),
+      TriggerDefinition::Broker(v) => v.render_config(source, root_config, env),
     }
   }
 }
@@ -108,6 +142,9 @@ impl ExpandImports for TriggerDefinition {
       TriggerDefinition::Cli(c) => c.expand_imports(bindings, index),
       TriggerDefinition::Http(c) => c.expand_imports(bindings, index),
       TriggerDefinition::Time(c) => c.expand_imports(bindings, index),
+      TriggerDefinition::Grpc(c) => c.expand_imports(bindings, index),
+      TriggerDefinition::Log(c) => c.expand_imports(bindings, index),
+      TriggerDefinition::Broker(c) => c.expand_imports(bindings, index),
     }
   }
 }
@@ -123,6 +160,12 @@ pub enum TriggerKind {
   Http,
   /// A time trigger.
   Time,
+  /// A gRPC trigger.
+  Grpc,
+  /// A log trigger.
+  Log,
+  /// A broker trigger.
+  Broker,
   /// An external WebAssembly command component.
   WasmCommand,
 }
@@ -133,6 +176,9 @@ impl std::fmt::Display for TriggerKind {
       TriggerKind::Cli => f.write_str("CLI"),
       TriggerKind::Http => f.write_str("HTTP"),
       TriggerKind::Time => f.write_str("TIME"),
+      TriggerKind::Grpc => f.write_str("GRPC"),
+      TriggerKind::Log => f.write_str("LOG"),
+      TriggerKind::Broker => f.write_str("BROKER"),
       TriggerKind::WasmCommand => f.write_str("WASM_COMMAND"),
     }
   }
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use wick_asset_reference::AssetReference;
+use wick_packet::RuntimeConfig;
+
+use crate::config::bindings::BoundIdentifier;
+use crate::config::template_config::Renderable;
+use crate::config::{Binding, ComponentOperationExpression, ImportDefinition};
+use crate::error::ManifestError;
+use crate::ExpandImports;
+
+#[derive(
+  Debug,
+  Clone,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+  derive_builder::Builder,
+)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[asset(asset(AssetReference))]
+#[must_use]
+/// Normalized representation of a broker trigger configuration.
+pub struct BrokerTriggerConfig {
+  /// The Broker resource to subscribe to.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+  /// The topic to subscribe to.
+  #[asset(skip)]
+  pub(crate) topic: String,
+  /// The operation to send each event to.
+  pub(crate) operation: ComponentOperationExpression,
+}
+
+impl Renderable for BrokerTriggerConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.topic.render_config(source, root_config, env)?;
+    self.operation.render_config(source, root_config, env)
+  }
+}
+
+impl ExpandImports for BrokerTriggerConfig {
+  type Error = ManifestError;
+  fn expand_imports(
+    &mut self,
+    bindings: &mut Vec<Binding<ImportDefinition>>,
+    trigger_index: usize,
+  ) -> Result<(), Self::Error> {
+    let id = format!("trigger_{}", trigger_index);
+    self.operation_mut().maybe_import(&id, bindings);
+    Ok(())
+  }
+}
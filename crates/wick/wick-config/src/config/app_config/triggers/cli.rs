@@ -25,6 +25,35 @@ use crate::ExpandImports;
 /// Normalized representation of a CLI trigger configuration.
 pub struct CliConfig {
   pub(crate) operation: ComponentOperationExpression,
+  /// Connect stdin and stdout to the operation.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) stdin: Option<StdinMode>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+/// How the CLI trigger connects stdin and stdout to its operation.
+#[serde(rename_all = "kebab-case")]
+pub enum StdinMode {
+  /// Each line of stdin is a JSON value sent as its own packet, and each output packet is written as a line of JSON.
+  #[default]
+  Lines = 0,
+  /// Stdin is sent in chunks of bytes, and output is written as-is: bytes raw, strings as text, anything else as JSON.
+  Raw = 1,
+  /// Text the operation sends on `stdout` and `stderr` is written there, and each `prompt` it sends is answered with
+  /// the next line of stdin on its `line` input.
+  Interactive = 2,
+}
+
+impl std::fmt::Display for StdinMode {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Lines => write!(f, "lines"),
+      Self::Raw => write!(f, "raw"),
+      Self::Interactive => write!(f, "interactive"),
+    }
+  }
 }
 
 impl ExpandImports for CliConfig {
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use wick_asset_reference::AssetReference;
+use wick_packet::RuntimeConfig;
+
+use crate::config::bindings::BoundIdentifier;
+use crate::config::template_config::Renderable;
+use crate::config::{Binding, ComponentOperationExpression, ImportDefinition};
+use crate::error::ManifestError;
+use crate::ExpandImports;
+
+#[derive(
+  Debug,
+  Clone,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+  derive_builder::Builder,
+)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[asset(asset(AssetReference))]
+#[must_use]
+/// Normalized representation of a gRPC trigger configuration.
+pub struct GrpcTriggerConfig {
+  /// The TcpPort resource to listen on for connections.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+  /// The protobuf package the generated service belongs to.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) package: Option<String>,
+  /// The name of the generated gRPC service.
+  #[asset(skip)]
+  pub(crate) service: String,
+  /// The operations to expose as methods on the service.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<ComponentOperationExpression>,
+}
+
+impl Renderable for GrpcTriggerConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.package.render_config(source, root_config, env)?;
+    self.service.render_config(source, root_config, env)?;
+    self.operations.render_config(source, root_config, env)
+  }
+}
+
+impl ExpandImports for GrpcTriggerConfig {
+  type Error = ManifestError;
+  fn expand_imports(
+    &mut self,
+    bindings: &mut Vec<Binding<ImportDefinition>>,
+    trigger_index: usize,
+  ) -> Result<(), Self::Error> {
+    for (i, operation) in self.operations_mut().iter_mut().enumerate() {
+      let id = format!("trigger_{}_operation_{}", trigger_index, i);
+      operation.maybe_import(&id, bindings);
+    }
+    Ok(())
+  }
+}
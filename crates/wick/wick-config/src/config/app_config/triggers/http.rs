@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::path::Path;
 
+pub use auth::{ApiKeyAuth, AuthConfig, BasicAuth, JwtAuth};
+pub use cors::{CorsConfig, CorsConfigBuilder, CorsConfigBuilderError};
 pub use middleware::{Middleware, MiddlewareBuilder, MiddlewareBuilderError};
 use wick_asset_reference::AssetReference;
 use wick_packet::RuntimeConfig;
@@ -12,6 +14,7 @@ pub use self::rest_router::{
   Documentation,
   Info,
   License,
+  ResponseMode,
   RestRoute,
   RestRouterConfig,
   RestRouterConfigBuilder,
@@ -25,6 +28,8 @@ use crate::config::{Binding, ImportDefinition};
 use crate::error::ManifestError;
 use crate::ExpandImports;
 
+mod auth;
+mod cors;
 mod middleware;
 mod proxy_router;
 mod raw_router;
@@ -140,6 +145,16 @@ pub trait WickRouter {
   fn middleware(&self) -> Option<&Middleware>;
   fn middleware_mut(&mut self) -> Option<&mut Middleware>;
   fn path(&self) -> &str;
+
+  /// The built-in authentication requests to this router must pass.
+  fn auth(&self) -> Option<&AuthConfig> {
+    None
+  }
+
+  /// The cross-origin resource sharing (CORS) settings for this router.
+  fn cors(&self) -> Option<&CorsConfig> {
+    None
+  }
 }
 
 #[derive(Debug, Clone, Copy, serde::Serialize)]
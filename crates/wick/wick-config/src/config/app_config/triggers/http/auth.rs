@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use wick_packet::RuntimeConfig;
+
+use crate::config::template_config::Renderable;
+use crate::config::{TemplateConfig, REDACTED};
+use crate::error::ManifestError;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+/// Built-in authentication that requests must pass before they reach a router's middleware or operations.
+pub enum AuthConfig {
+  /// Require a JSON Web Token signed with a shared secret.
+  Jwt(JwtAuth),
+  /// Require a known API key in a request header.
+  ApiKey(ApiKeyAuth),
+  /// Require HTTP Basic credentials.
+  Basic(BasicAuth),
+}
+
+impl Renderable for AuthConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    match self {
+      AuthConfig::Jwt(v) => v.render_config(source, root_config, env),
+      AuthConfig::ApiKey(v) => v.render_config(source, root_config, env),
+      AuthConfig::Basic(v) => v.render_config(source, root_config, env),
+    }
+  }
+}
+
+#[derive(Clone, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+/// Require a JSON Web Token in the Authorization header, signed with a shared secret using HS256, HS384, or HS512.
+pub struct JwtAuth {
+  /// The shared secret used to verify token signatures.
+  pub(crate) secret: TemplateConfig<String>,
+  /// The issuer that the token's `iss` claim must match.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) issuer: Option<String>,
+  /// The audience that the token's `aud` claim must contain.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) audience: Option<String>,
+}
+
+impl std::fmt::Debug for JwtAuth {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("JwtAuth")
+      .field("secret", &REDACTED)
+      .field("issuer", &self.issuer)
+      .field("audience", &self.audience)
+      .finish()
+  }
+}
+
+impl Renderable for JwtAuth {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.secret.set_value(self.secret.render(source, root_config, env)?);
+    self.issuer.render_config(source, root_config, env)?;
+    self.audience.render_config(source, root_config, env)
+  }
+}
+
+#[derive(Clone, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+/// Require one of the configured API keys in a request header.
+pub struct ApiKeyAuth {
+  /// The header to read the API key from.
+  pub(crate) header: String,
+  /// The API keys that are allowed access.
+  pub(crate) keys: Vec<TemplateConfig<String>>,
+}
+
+impl std::fmt::Debug for ApiKeyAuth {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ApiKeyAuth")
+      .field("header", &self.header)
+      .field("keys", &vec![REDACTED; self.keys.len()])
+      .finish()
+  }
+}
+
+impl Renderable for ApiKeyAuth {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.header.render_config(source, root_config, env)?;
+    for key in &mut self.keys {
+      key.set_value(key.render(source, root_config, env)?);
+    }
+    Ok(())
+  }
+}
+
+#[derive(Clone, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+/// Require HTTP Basic credentials that match one of the configured users.
+pub struct BasicAuth {
+  /// The realm to report in the WWW-Authenticate header.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) realm: Option<String>,
+  /// A map of usernames to the passwords that are allowed access.
+  pub(crate) users: HashMap<String, TemplateConfig<String>>,
+}
+
+impl std::fmt::Debug for BasicAuth {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let users: HashMap<_, _> = self.users.keys().map(|user| (user, REDACTED)).collect();
+    f.debug_struct("BasicAuth")
+      .field("realm", &self.realm)
+      .field("users", &users)
+      .finish()
+  }
+}
+
+impl Renderable for BasicAuth {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.realm.render_config(source, root_config, env)?;
+    for password in self.users.values_mut() {
+      password.set_value(password.render(source, root_config, env)?);
+    }
+    Ok(())
+  }
+}
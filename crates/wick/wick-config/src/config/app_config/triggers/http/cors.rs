@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use wick_packet::RuntimeConfig;
+
+use crate::config::template_config::Renderable;
+use crate::error::ManifestError;
+
+#[derive(Debug, Clone, Default, PartialEq, derive_builder::Builder, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into), default, build_fn(validate = "Self::validate"))]
+#[must_use]
+/// Cross-origin resource sharing (CORS) settings that let browsers call a router from other origins.
+pub struct CorsConfig {
+  /// The origins allowed to make cross-origin requests. `*` allows any origin.
+  pub(crate) allowed_origins: Vec<String>,
+  /// The methods allowed in cross-origin requests. When empty, GET, POST, PUT, PATCH, and DELETE are allowed.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) allowed_methods: Vec<String>,
+  /// The request headers allowed in cross-origin requests. When empty, the headers a browser asks for are allowed.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) allowed_headers: Vec<String>,
+  /// How long in seconds browsers may cache the result of a preflight request.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_age: Option<u64>,
+  /// Whether browsers may send credentials such as cookies with cross-origin requests.
+  pub(crate) allow_credentials: bool,
+}
+
+impl CorsConfig {
+  /// Make sure browsers can honor these settings.
+  pub(crate) fn validate(&self) -> Result<(), ManifestError> {
+    if allows_credentials_from_any_origin(&self.allowed_origins, self.allow_credentials) {
+      return Err(ManifestError::CorsWildcardCredentials);
+    }
+    Ok(())
+  }
+}
+
+impl CorsConfigBuilder {
+  fn validate(&self) -> Result<(), String> {
+    let origins = self.allowed_origins.as_deref().unwrap_or_default();
+    if allows_credentials_from_any_origin(origins, self.allow_credentials.unwrap_or_default()) {
+      return Err(ManifestError::CorsWildcardCredentials.to_string());
+    }
+    Ok(())
+  }
+}
+
+// Browsers reject `Access-Control-Allow-Origin: *` on credentialed requests, and echoing every origin back instead
+// would let any site make requests with a user's cookies.
+fn allows_credentials_from_any_origin(origins: &[String], credentials: bool) -> bool {
+  credentials && origins.iter().any(|o| o == "*")
+}
+
+impl Renderable for CorsConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.allowed_origins.render_config(source, root_config, env)?;
+    self.allowed_methods.render_config(source, root_config, env)?;
+    self.allowed_headers.render_config(source, root_config, env)?;
+    self.validate()
+  }
+}
@@ -26,6 +26,12 @@ pub struct ProxyRouterConfig {
   #[property(get(disable))]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) middleware: Option<super::middleware::Middleware>,
+  /// Cross-origin resource sharing (CORS) settings for this router.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(get(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cors: Option<super::CorsConfig>,
   /// The URL resource to proxy to.
   #[asset(skip)]
   pub(crate) url: BoundIdentifier,
@@ -41,7 +47,9 @@ impl Renderable for ProxyRouterConfig {
     root_config: Option<&RuntimeConfig>,
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
-    self.middleware.render_config(source, root_config, env)
+    self.path.render_config(source, root_config, env)?;
+    self.middleware.render_config(source, root_config, env)?;
+    self.cors.render_config(source, root_config, env)
   }
 }
 
@@ -57,6 +65,10 @@ impl super::WickRouter for ProxyRouterConfig {
   fn path(&self) -> &str {
     &self.path
   }
+
+  fn cors(&self) -> Option<&super::CorsConfig> {
+    self.cors.as_ref()
+  }
 }
 
 pub(crate) fn process_runtime_config(
@@ -24,6 +24,18 @@ pub struct RawRouterConfig {
   #[property(get(disable), mut(disable))]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) middleware: Option<super::middleware::Middleware>,
+  /// Built-in authentication that requests must pass before reaching middleware or operations.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) auth: Option<super::AuthConfig>,
+  /// Cross-origin resource sharing (CORS) settings for this router.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cors: Option<super::CorsConfig>,
   #[asset(skip)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) codec: Option<config::common::Codec>,
@@ -42,6 +54,14 @@ impl super::WickRouter for RawRouterConfig {
   fn path(&self) -> &str {
     &self.path
   }
+
+  fn auth(&self) -> Option<&super::AuthConfig> {
+    self.auth.as_ref()
+  }
+
+  fn cors(&self) -> Option<&super::CorsConfig> {
+    self.cors.as_ref()
+  }
 }
 
 impl Renderable for RawRouterConfig {
@@ -51,7 +71,10 @@ impl Renderable for RawRouterConfig {
     root_config: Option<&RuntimeConfig>,
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
+    self.path.render_config(source, root_config, env)?;
     self.operation.render_config(source, root_config, env)?;
+    self.auth.render_config(source, root_config, env)?;
+    self.cors.render_config(source, root_config, env)?;
     self.middleware.render_config(source, root_config, env)
   }
 }
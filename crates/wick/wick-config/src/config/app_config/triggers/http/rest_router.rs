@@ -31,6 +31,18 @@ pub struct RestRouterConfig {
   #[property(get(disable), mut(disable))]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) middleware: Option<super::middleware::Middleware>,
+  /// Built-in authentication that requests must pass before reaching middleware or operations.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) auth: Option<super::AuthConfig>,
+  /// Cross-origin resource sharing (CORS) settings for this router.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cors: Option<super::CorsConfig>,
   /// Additional tools and services to enable.
   #[asset(skip)]
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,7 +63,11 @@ impl Renderable for RestRouterConfig {
     root_config: Option<&RuntimeConfig>,
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
+    self.path.render_config(source, root_config, env)?;
     self.middleware.render_config(source, root_config, env)?;
+    self.auth.render_config(source, root_config, env)?;
+    self.cors.render_config(source, root_config, env)?;
+    self.info.render_config(source, root_config, env)?;
     self.routes.render_config(source, root_config, env)
   }
 }
@@ -63,10 +79,68 @@ impl Renderable for RestRoute {
     root_config: Option<&RuntimeConfig>,
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
+    self.id.render_config(source, root_config, env)?;
+    self.sub_path.render_config(source, root_config, env)?;
+    self.description.render_config(source, root_config, env)?;
+    self.summary.render_config(source, root_config, env)?;
     self.operation.render_config(source, root_config, env)
   }
 }
 
+impl Renderable for Info {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.title.render_config(source, root_config, env)?;
+    self.description.render_config(source, root_config, env)?;
+    self.tos.render_config(source, root_config, env)?;
+    self.contact.render_config(source, root_config, env)?;
+    self.license.render_config(source, root_config, env)?;
+    self.version.render_config(source, root_config, env)?;
+    self.documentation.render_config(source, root_config, env)
+  }
+}
+
+impl Renderable for Documentation {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.url.render_config(source, root_config, env)?;
+    self.description.render_config(source, root_config, env)
+  }
+}
+
+impl Renderable for License {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.name.render_config(source, root_config, env)?;
+    self.url.render_config(source, root_config, env)
+  }
+}
+
+impl Renderable for Contact {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.name.render_config(source, root_config, env)?;
+    self.url.render_config(source, root_config, env)?;
+    self.email.render_config(source, root_config, env)
+  }
+}
+
 impl super::WickRouter for RestRouterConfig {
   fn middleware(&self) -> Option<&super::Middleware> {
     self.middleware.as_ref()
@@ -78,6 +152,14 @@ impl super::WickRouter for RestRouterConfig {
   fn path(&self) -> &str {
     &self.path
   }
+
+  fn auth(&self) -> Option<&super::AuthConfig> {
+    self.auth.as_ref()
+  }
+
+  fn cors(&self) -> Option<&super::CorsConfig> {
+    self.cors.as_ref()
+  }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, property::Property, serde::Serialize)]
@@ -186,6 +268,42 @@ pub struct RestRoute {
   #[asset(skip)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) summary: Option<String>,
+  /// How the operation's output is sent back to the client.
+  #[asset(skip)]
+  #[builder(default)]
+  pub(crate) response: ResponseMode,
+  /// The maximum size in bytes of a request body.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_body_size: Option<u64>,
+  /// The maximum size in bytes of a single field or file in a multipart/form-data body.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_part_size: Option<u64>,
+  /// Whether to validate requests against the operation's inputs.
+  #[asset(skip)]
+  #[builder(default)]
+  pub(crate) validate: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// How a REST route sends an operation's output back to the client.
+#[serde(rename_all = "kebab-case")]
+pub enum ResponseMode {
+  /// Wait for the operation to complete and respond with a single JSON object.
+  Buffered = 0,
+  /// Stream each output packet as a line of JSON using chunked transfer encoding.
+  Stream = 1,
+  /// Stream each output packet as a Server-Sent Event.
+  Sse = 2,
+}
+
+impl Default for ResponseMode {
+  fn default() -> Self {
+    Self::Buffered
+  }
 }
 
 pub(crate) fn process_runtime_config(
@@ -25,6 +25,12 @@ pub struct StaticRouterConfig {
   #[property(get(disable))]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) middleware: Option<super::middleware::Middleware>,
+  /// Cross-origin resource sharing (CORS) settings for this router.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(get(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cors: Option<super::CorsConfig>,
   #[asset(skip)]
   pub(crate) volume: BoundIdentifier,
   #[asset(skip)]
@@ -32,6 +38,8 @@ pub struct StaticRouterConfig {
   pub(crate) fallback: Option<String>,
   #[asset(skip)]
   pub(crate) indexes: bool,
+  #[asset(skip)]
+  pub(crate) precompressed: bool,
 }
 
 impl super::WickRouter for StaticRouterConfig {
@@ -46,6 +54,10 @@ impl super::WickRouter for StaticRouterConfig {
   fn path(&self) -> &str {
     &self.path
   }
+
+  fn cors(&self) -> Option<&super::CorsConfig> {
+    self.cors.as_ref()
+  }
 }
 
 impl Renderable for StaticRouterConfig {
@@ -55,7 +67,10 @@ impl Renderable for StaticRouterConfig {
     root_config: Option<&RuntimeConfig>,
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
-    self.middleware.render_config(source, root_config, env)
+    self.path.render_config(source, root_config, env)?;
+    self.middleware.render_config(source, root_config, env)?;
+    self.cors.render_config(source, root_config, env)?;
+    self.fallback.render_config(source, root_config, env)
   }
 }
 
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use wick_asset_reference::AssetReference;
+use wick_packet::RuntimeConfig;
+
+use crate::config::bindings::BoundIdentifier;
+use crate::config::template_config::Renderable;
+use crate::config::{Binding, ComponentOperationExpression, ImportDefinition};
+use crate::error::ManifestError;
+use crate::ExpandImports;
+
+#[derive(
+  Debug,
+  Clone,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+  derive_builder::Builder,
+)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[asset(asset(AssetReference))]
+#[must_use]
+/// Normalized representation of a log trigger configuration.
+pub struct LogTriggerConfig {
+  /// The operation to send each record to.
+  pub(crate) operation: ComponentOperationExpression,
+  /// The files to tail and how to parse them.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) sources: Vec<LogSourceConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, property::Property, serde::Serialize, derive_builder::Builder)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(disable))]
+#[must_use]
+/// A set of log files and the format their records are written in.
+pub struct LogSourceConfig {
+  /// The Volume resource the log files are in.
+  pub(crate) resource: BoundIdentifier,
+  /// Glob patterns, relative to the volume, of the files to tail.
+  pub(crate) paths: Vec<String>,
+  /// The format records are written in.
+  #[builder(default)]
+  pub(crate) format: LogFormat,
+  /// The regular expression to parse each line with when the format is [LogFormat::Regex].
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) pattern: Option<String>,
+  /// The names of the fields of each CSV record.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) columns: Vec<String>,
+  /// The character CSV fields are separated by.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) delimiter: Option<String>,
+  /// Whether to read files that exist when the trigger starts from their beginning.
+  #[builder(default)]
+  pub(crate) from_start: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// The format records are written to a log file in.
+#[serde(rename_all = "kebab-case")]
+pub enum LogFormat {
+  /// One JSON object per line.
+  Json = 0,
+  /// Comma-separated (or otherwise delimited) values.
+  Csv = 1,
+  /// Lines parsed with a regular expression.
+  Regex = 2,
+}
+
+impl Default for LogFormat {
+  fn default() -> Self {
+    Self::Json
+  }
+}
+
+impl Renderable for LogTriggerConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.sources.render_config(source, root_config, env)?;
+    self.operation.render_config(source, root_config, env)
+  }
+}
+
+impl Renderable for LogSourceConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.paths.render_config(source, root_config, env)?;
+    self.pattern.render_config(source, root_config, env)?;
+    self.columns.render_config(source, root_config, env)?;
+    self.delimiter.render_config(source, root_config, env)
+  }
+}
+
+impl ExpandImports for LogTriggerConfig {
+  type Error = ManifestError;
+  fn expand_imports(
+    &mut self,
+    bindings: &mut Vec<Binding<ImportDefinition>>,
+    trigger_index: usize,
+  ) -> Result<(), Self::Error> {
+    let id = format!("trigger_{}", trigger_index);
+    self.operation_mut().maybe_import(&id, bindings);
+    Ok(())
+  }
+}
@@ -39,6 +39,7 @@ impl Renderable for TimeTriggerConfig {
     root_config: Option<&RuntimeConfig>,
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
+    self.schedule.cron.render_config(source, root_config, env)?;
     self.operation.render_config(source, root_config, env)
   }
 }
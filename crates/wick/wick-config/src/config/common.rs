@@ -28,7 +28,28 @@ pub use self::component_implementation::{ComponentImplementation, ComponentKind}
 pub use self::error_behavior::ErrorBehavior;
 pub use self::exposed_resources::{ExposedVolume, ExposedVolumeBuilder};
 pub use self::glob::Glob;
-pub use self::host_definition::{HostConfig, HostConfigBuilder, HttpConfig, HttpConfigBuilder};
+pub use self::host_definition::{
+  AlertMetric,
+  AlertRule,
+  AlertRuleBuilder,
+  ComponentPolicy,
+  ComponentPolicyBuilder,
+  HostConfig,
+  HostConfigBuilder,
+  HttpConfig,
+  HttpConfigBuilder,
+  MetricsConfig,
+  MetricsConfigBuilder,
+  OtlpConfig,
+  OtlpConfigBuilder,
+  PolicyAction,
+  PolicyRule,
+  PolicyRuleBuilder,
+  TypeCheckConfig,
+  TypeCheckConfigBuilder,
+  TypeCoercion,
+  TypeCoercionBuilder,
+};
 pub use self::http::{Codec, HttpMethod};
 pub use self::import_definition::ImportDefinition;
 pub use self::interface::InterfaceDefinition;
@@ -36,6 +57,18 @@ pub use self::liquid_json_config::LiquidJsonConfig;
 pub use self::metadata::{Metadata, MetadataBuilder};
 pub use self::operation_definition::{OperationDefinition, OperationDefinitionBuilder};
 pub use self::package_definition::{PackageConfig, PackageConfigBuilder, RegistryConfig, RegistryConfigBuilder};
-pub use self::resources::{ResourceDefinition, TcpPort, UdpPort, UrlResource, Volume};
-pub use self::settings::ExecutionSettings;
+pub use self::resources::{
+  BrokerResource,
+  Redacted,
+  ResourceDefinition,
+  Secret,
+  SecretProvider,
+  SecretResource,
+  TcpPort,
+  UdpPort,
+  UrlResource,
+  Volume,
+  REDACTED,
+};
+pub use self::settings::{ExecutionSettings, RetryCondition, RetryPolicy};
 pub use self::template_config::TemplateConfig;
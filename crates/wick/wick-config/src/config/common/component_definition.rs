@@ -154,6 +154,48 @@ pub enum HighLevelComponent {
   #[asset(skip)]
   /// An HTTP Client Component.
   HttpClient(config::components::HttpClientComponentConfig),
+  #[asset(skip)]
+  /// An archive component.
+  Archive(config::components::ArchiveComponentConfig),
+  #[asset(skip)]
+  /// A text component.
+  Text(config::components::TextComponentConfig),
+  #[asset(skip)]
+  /// A units component.
+  Units(config::components::UnitsComponentConfig),
+  #[asset(skip)]
+  /// A validation component.
+  Validate(config::components::ValidateComponentConfig),
+  #[asset(skip)]
+  /// A test data generator component.
+  Generate(config::components::GenerateComponentConfig),
+  #[asset(skip)]
+  /// A feature flag component.
+  Flags(config::components::FlagsComponentConfig),
+  #[asset(skip)]
+  /// A message translation component.
+  I18n(config::components::I18nComponentConfig),
+  #[asset(skip)]
+  /// A metrics sink component.
+  Metrics(config::components::MetricsComponentConfig),
+  #[asset(skip)]
+  /// A component that publishes events to an in-process broker.
+  Broker(config::components::BrokerComponentConfig),
+  #[asset(skip)]
+  /// A component that caches the outputs of other operations.
+  Cache(config::components::CacheComponentConfig),
+  #[asset(skip)]
+  /// An S3-compatible object store component.
+  ObjectStore(config::components::ObjectStoreComponentConfig),
+  #[asset(skip)]
+  /// A component that works with the files in a volume.
+  Filesystem(config::components::FilesystemComponentConfig),
+  #[asset(skip)]
+  /// A component that parses and serializes CSV, NDJSON, and XML.
+  Data(config::components::DataComponentConfig),
+  #[asset(skip)]
+  /// A component that hashes, signs, and verifies data and JWTs.
+  Crypto(config::components::CryptoComponentConfig),
 }
 
 impl OperationSignatures for HighLevelComponent {
@@ -161,6 +203,20 @@ impl OperationSignatures for HighLevelComponent {
     match self {
       HighLevelComponent::Sql(c) => c.operation_signatures(),
       HighLevelComponent::HttpClient(c) => c.operation_signatures(),
+      HighLevelComponent::Archive(c) => c.operation_signatures(),
+      HighLevelComponent::Text(c) => c.operation_signatures(),
+      HighLevelComponent::Units(c) => c.operation_signatures(),
+      HighLevelComponent::Validate(c) => c.operation_signatures(),
+      HighLevelComponent::Generate(c) => c.operation_signatures(),
+      HighLevelComponent::Flags(c) => c.operation_signatures(),
+      HighLevelComponent::I18n(c) => c.operation_signatures(),
+      HighLevelComponent::Metrics(c) => c.operation_signatures(),
+      HighLevelComponent::Broker(c) => c.operation_signatures(),
+      HighLevelComponent::Cache(c) => c.operation_signatures(),
+      HighLevelComponent::ObjectStore(c) => c.operation_signatures(),
+      HighLevelComponent::Filesystem(c) => c.operation_signatures(),
+      HighLevelComponent::Data(c) => c.operation_signatures(),
+      HighLevelComponent::Crypto(c) => c.operation_signatures(),
     }
   }
 }
@@ -181,7 +237,6 @@ pub enum ComponentDefinition {
   #[asset(skip)]
   Reference(config::components::ComponentReference),
   /// Separate microservices that Wick can connect to.
-  #[asset(skip)]
   GrpcUrl(config::components::GrpcUrlComponent),
   /// External manifests.
   Manifest(config::components::ManifestComponent),
@@ -313,7 +368,8 @@ impl OperationSignatures for &ComponentDefinition {
       ComponentDefinition::HighLevelComponent(c) => c.operation_signatures(),
       ComponentDefinition::Native(_) => unreachable!(),
       ComponentDefinition::Reference(_) => unreachable!(),
-      ComponentDefinition::GrpcUrl(_) => unreachable!(),
+      // A remote component's operations are only known once the runtime connects to it.
+      ComponentDefinition::GrpcUrl(_) => Vec::new(),
       #[allow(deprecated)]
       ComponentDefinition::Wasm(_) => unreachable!(),
     }
@@ -18,6 +18,34 @@ pub enum ComponentImplementation {
   Sql(config::components::SqlComponentConfig),
   /// An http client component.
   HttpClient(config::components::HttpClientComponentConfig),
+  /// An archive component.
+  Archive(config::components::ArchiveComponentConfig),
+  /// A text component.
+  Text(config::components::TextComponentConfig),
+  /// A units component.
+  Units(config::components::UnitsComponentConfig),
+  /// A validation component.
+  Validate(config::components::ValidateComponentConfig),
+  /// A test data generator component.
+  Generate(config::components::GenerateComponentConfig),
+  /// A feature flag component.
+  Flags(config::components::FlagsComponentConfig),
+  /// A message translation component.
+  I18n(config::components::I18nComponentConfig),
+  /// A metrics sink component.
+  Metrics(config::components::MetricsComponentConfig),
+  /// A component that publishes events to an in-process broker.
+  Broker(config::components::BrokerComponentConfig),
+  /// A component that caches the outputs of other operations.
+  Cache(config::components::CacheComponentConfig),
+  /// An S3-compatible object store component.
+  ObjectStore(config::components::ObjectStoreComponentConfig),
+  /// A component that works with the files in a volume.
+  Filesystem(config::components::FilesystemComponentConfig),
+  /// A component that parses and serializes CSV, NDJSON, and XML.
+  Data(config::components::DataComponentConfig),
+  /// A component that hashes, signs, and verifies data and JWTs.
+  Crypto(config::components::CryptoComponentConfig),
 }
 
 impl ComponentImplementation {
@@ -29,6 +57,20 @@ impl ComponentImplementation {
       ComponentImplementation::Composite(_) => ComponentKind::Composite,
       ComponentImplementation::Sql(_) => ComponentKind::Sql,
       ComponentImplementation::HttpClient(_) => ComponentKind::HttpClient,
+      ComponentImplementation::Archive(_) => ComponentKind::Archive,
+      ComponentImplementation::Text(_) => ComponentKind::Text,
+      ComponentImplementation::Units(_) => ComponentKind::Units,
+      ComponentImplementation::Validate(_) => ComponentKind::Validate,
+      ComponentImplementation::Generate(_) => ComponentKind::Generate,
+      ComponentImplementation::Flags(_) => ComponentKind::Flags,
+      ComponentImplementation::I18n(_) => ComponentKind::I18n,
+      ComponentImplementation::Metrics(_) => ComponentKind::Metrics,
+      ComponentImplementation::Broker(_) => ComponentKind::Broker,
+      ComponentImplementation::Cache(_) => ComponentKind::Cache,
+      ComponentImplementation::ObjectStore(_) => ComponentKind::ObjectStore,
+      ComponentImplementation::Filesystem(_) => ComponentKind::Filesystem,
+      ComponentImplementation::Data(_) => ComponentKind::Data,
+      ComponentImplementation::Crypto(_) => ComponentKind::Crypto,
     }
   }
 
@@ -41,6 +83,20 @@ impl ComponentImplementation {
       ComponentImplementation::Composite(c) => c.operation_signatures(),
       ComponentImplementation::Sql(c) => c.operation_signatures(),
       ComponentImplementation::HttpClient(c) => c.operation_signatures(),
+      ComponentImplementation::Archive(c) => c.operation_signatures(),
+      ComponentImplementation::Text(c) => c.operation_signatures(),
+      ComponentImplementation::Units(c) => c.operation_signatures(),
+      ComponentImplementation::Validate(c) => c.operation_signatures(),
+      ComponentImplementation::Generate(c) => c.operation_signatures(),
+      ComponentImplementation::Flags(c) => c.operation_signatures(),
+      ComponentImplementation::I18n(c) => c.operation_signatures(),
+      ComponentImplementation::Metrics(c) => c.operation_signatures(),
+      ComponentImplementation::Broker(c) => c.operation_signatures(),
+      ComponentImplementation::Cache(c) => c.operation_signatures(),
+      ComponentImplementation::ObjectStore(c) => c.operation_signatures(),
+      ComponentImplementation::Filesystem(c) => c.operation_signatures(),
+      ComponentImplementation::Data(c) => c.operation_signatures(),
+      ComponentImplementation::Crypto(c) => c.operation_signatures(),
     }
   }
 
@@ -53,6 +109,20 @@ impl ComponentImplementation {
       ComponentImplementation::Composite(_) => panic!("Composite components must be named"),
       ComponentImplementation::Sql(_) => "wick/component/sql",
       ComponentImplementation::HttpClient(_) => "wick/component/http",
+      ComponentImplementation::Archive(_) => "wick/component/archive",
+      ComponentImplementation::Text(_) => "wick/component/text",
+      ComponentImplementation::Units(_) => "wick/component/units",
+      ComponentImplementation::Validate(_) => "wick/component/validate",
+      ComponentImplementation::Generate(_) => "wick/component/generate",
+      ComponentImplementation::Flags(_) => "wick/component/flags",
+      ComponentImplementation::I18n(_) => "wick/component/i18n",
+      ComponentImplementation::Metrics(_) => "wick/component/metrics",
+      ComponentImplementation::Broker(_) => "wick/component/broker",
+      ComponentImplementation::Cache(_) => "wick/component/cache",
+      ComponentImplementation::ObjectStore(_) => "wick/component/object-store",
+      ComponentImplementation::Filesystem(_) => "wick/component/filesystem",
+      ComponentImplementation::Data(_) => "wick/component/data",
+      ComponentImplementation::Crypto(_) => "wick/component/crypto",
     }
   }
 }
@@ -78,6 +148,34 @@ pub enum ComponentKind {
   Sql,
   /// An http client component.
   HttpClient,
+  /// An archive component.
+  Archive,
+  /// A text component.
+  Text,
+  /// A units component.
+  Units,
+  /// A validation component.
+  Validate,
+  /// A test data generator component.
+  Generate,
+  /// A feature flag component.
+  Flags,
+  /// A message translation component.
+  I18n,
+  /// A metrics sink component.
+  Metrics,
+  /// A component that publishes events to an in-process broker.
+  Broker,
+  /// A component that caches the outputs of other operations.
+  Cache,
+  /// An S3-compatible object store component.
+  ObjectStore,
+  /// A component that works with the files in a volume.
+  Filesystem,
+  /// A component that parses and serializes CSV, NDJSON, and XML.
+  Data,
+  /// A component that hashes, signs, and verifies data and JWTs.
+  Crypto,
 }
 
 impl std::fmt::Display for ComponentKind {
@@ -88,6 +186,20 @@ impl std::fmt::Display for ComponentKind {
       ComponentKind::Composite => write!(f, "wick/component/composite"),
       ComponentKind::Sql => write!(f, "wick/component/sql"),
       ComponentKind::HttpClient => write!(f, "wick/component/http"),
+      ComponentKind::Archive => write!(f, "wick/component/archive"),
+      ComponentKind::Text => write!(f, "wick/component/text"),
+      ComponentKind::Units => write!(f, "wick/component/units"),
+      ComponentKind::Validate => write!(f, "wick/component/validate"),
+      ComponentKind::Generate => write!(f, "wick/component/generate"),
+      ComponentKind::Flags => write!(f, "wick/component/flags"),
+      ComponentKind::I18n => write!(f, "wick/component/i18n"),
+      ComponentKind::Metrics => write!(f, "wick/component/metrics"),
+      ComponentKind::Broker => write!(f, "wick/component/broker"),
+      ComponentKind::Cache => write!(f, "wick/component/cache"),
+      ComponentKind::ObjectStore => write!(f, "wick/component/object-store"),
+      ComponentKind::Filesystem => write!(f, "wick/component/filesystem"),
+      ComponentKind::Data => write!(f, "wick/component/data"),
+      ComponentKind::Crypto => write!(f, "wick/component/crypto"),
     }
   }
 }
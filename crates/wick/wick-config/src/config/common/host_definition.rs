@@ -1,5 +1,6 @@
 #![allow(missing_docs)] // delete when we move away from the `property` crate.
 use std::net::Ipv4Addr;
+use std::time::Duration;
 
 use crate::config;
 
@@ -22,6 +23,188 @@ pub struct HostConfig {
   #[builder(setter(strip_option), default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) rpc: Option<HttpConfig>,
+
+  /// Configuration for the Prometheus metrics endpoint.
+  #[builder(setter(strip_option), default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) metrics: Option<MetricsConfig>,
+
+  /// Configuration for exporting trace spans over OTLP.
+  #[builder(setter(strip_option), default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) otlp: Option<OtlpConfig>,
+
+  /// Rules that decide which imported components may be instantiated.
+  #[builder(setter(strip_option), default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) policy: Option<ComponentPolicy>,
+
+  /// How the types of connected ports are checked when flows are loaded.
+  #[builder(setter(strip_option), default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) type_check: Option<TypeCheckConfig>,
+}
+
+#[derive(Debug, Default, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// Configuration for the endpoint that serves runtime metrics in the Prometheus format.
+pub struct MetricsConfig {
+  /// Enable/disable the endpoint.
+  #[builder(default)]
+  pub(crate) enabled: bool,
+
+  /// The port to bind to.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) port: Option<u16>,
+
+  /// The address to bind to.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) address: Option<Ipv4Addr>,
+
+  /// Rules checked against operation metrics that invoke an operation when they fire.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) alerts: Vec<AlertRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// A rule that invokes an operation when a metric of the operations it watches crosses a threshold.
+pub struct AlertRule {
+  /// The name of the rule.
+  pub(crate) name: String,
+
+  /// The operation to watch, or every operation if `None`.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) operation: Option<String>,
+
+  /// The metric to compare against the threshold.
+  pub(crate) metric: AlertMetric,
+
+  /// The value the metric must exceed for the rule to fire.
+  pub(crate) threshold: f64,
+
+  /// The window the metric is computed over.
+  pub(crate) window: Duration,
+
+  /// The operation to invoke when the rule fires, e.g. `mailer::send`.
+  pub(crate) invoke: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+/// A metric an [AlertRule] can check.
+#[non_exhaustive]
+pub enum AlertMetric {
+  /// The percentage of transactions that had an error.
+  ErrorRate,
+  /// The 99th percentile of transaction latency in milliseconds.
+  P99,
+}
+
+#[derive(Debug, Default, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// Rules that decide which imported components may be instantiated. The first rule that matches a component decides.
+pub struct ComponentPolicy {
+  /// Whether to refuse components no rule matches.
+  #[builder(default)]
+  pub(crate) deny_by_default: bool,
+
+  /// The rules to check, in order.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) rules: Vec<PolicyRule>,
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// A rule that allows or denies the components it matches. Criteria that are `None` match every component.
+pub struct PolicyRule {
+  /// Whether to instantiate the components the rule matches.
+  pub(crate) action: PolicyAction,
+
+  /// The OCI registry the component is pulled from.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) registry: Option<String>,
+
+  /// The digest of the component's artifact, e.g. `sha256:4f6a...`.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) digest: Option<String>,
+
+  /// The public key of the account that signed the component's WebAssembly module.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) signer: Option<String>,
+
+  /// The namespace the component is imported as, where a trailing `*` matches a prefix.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) namespace: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+/// What a [PolicyRule] does with the components it matches.
+#[non_exhaustive]
+pub enum PolicyAction {
+  /// Instantiate the component.
+  Allow,
+  /// Refuse to instantiate the component.
+  Deny,
+}
+
+impl std::fmt::Display for PolicyAction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Allow => f.write_str("allow"),
+      Self::Deny => f.write_str("deny"),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// How the types of connected ports are checked when flows are loaded.
+pub struct TypeCheckConfig {
+  /// Whether connections between ports of incompatible types stop flows from loading instead of being logged.
+  #[builder(default)]
+  pub(crate) strict: bool,
+
+  /// More conversions to allow between connected ports, on top of the defaults.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) coercions: Vec<TypeCoercion>,
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// A conversion to allow from an output of one type to an input of another.
+pub struct TypeCoercion {
+  /// The type of the output.
+  pub(crate) from: wick_interface_types::Type,
+
+  /// The type of the input.
+  pub(crate) to: wick_interface_types::Type,
+}
+
+#[derive(Debug, Default, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// Configuration for exporting trace spans to an OpenTelemetry collector.
+pub struct OtlpConfig {
+  /// The OTLP/gRPC endpoint of the collector.
+  pub(crate) endpoint: String,
 }
 
 #[derive(Debug, Default, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
@@ -1,4 +1,6 @@
 #![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::collections::HashMap;
+
 use crate::config::AssetReference;
 
 #[derive(
@@ -48,6 +50,11 @@ pub struct Metadata {
   #[builder(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) icon: Option<AssetReference>,
+  /// Version requirements the runtime must satisfy to load the component or application, keyed by runtime name.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  pub(crate) requires: HashMap<String, String>,
 }
 
 impl Metadata {
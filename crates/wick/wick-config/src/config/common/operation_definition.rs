@@ -28,6 +28,11 @@ pub struct OperationDefinition {
   #[builder(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub(crate) outputs: Vec<Field>,
+
+  /// The relative cost of one invocation of the operation.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cost: Option<u64>,
 }
 
 impl OperationConfig for OperationDefinition {
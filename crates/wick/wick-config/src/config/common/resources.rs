@@ -14,6 +14,8 @@ crate::impl_from_for!(ResourceDefinition, TcpPort);
 crate::impl_from_for!(ResourceDefinition, UdpPort);
 crate::impl_from_for!(ResourceDefinition, Volume);
 crate::impl_from_for!(ResourceDefinition, Url, UrlResource);
+crate::impl_from_for!(ResourceDefinition, Broker, BrokerResource);
+crate::impl_from_for!(ResourceDefinition, Secret, SecretResource);
 
 /// A resource type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -22,6 +24,8 @@ pub enum ResourceKind {
   UdpPort,
   Url,
   Volume,
+  Broker,
+  Secret,
 }
 
 impl std::fmt::Display for ResourceKind {
@@ -31,6 +35,8 @@ impl std::fmt::Display for ResourceKind {
       Self::UdpPort => write!(f, "UdpPort"),
       Self::Url => write!(f, "Url"),
       Self::Volume => write!(f, "Volume"),
+      Self::Broker => write!(f, "Broker"),
+      Self::Secret => write!(f, "Secret"),
     }
   }
 }
@@ -51,6 +57,12 @@ pub enum ResourceDefinition {
   Url(UrlResource),
   /// A filesystem or network volume.
   Volume(Volume),
+  /// An in-process broker for passing events between apps.
+  #[asset(skip)]
+  Broker(BrokerResource),
+  /// A secret read from the host.
+  #[asset(skip)]
+  Secret(SecretResource),
 }
 
 impl Renderable for ResourceDefinition {
@@ -65,6 +77,8 @@ impl Renderable for ResourceDefinition {
       ResourceDefinition::UdpPort(v) => v.render_config(source, root_config, env),
       ResourceDefinition::Url(v) => v.render_config(source, root_config, env),
       ResourceDefinition::Volume(v) => v.render_config(source, root_config, env),
+      ResourceDefinition::Broker(v) => v.render_config(source, root_config, env),
+      ResourceDefinition::Secret(v) => v.render_config(source, root_config, env),
     }
   }
 }
@@ -77,6 +91,8 @@ impl ResourceDefinition {
       ResourceDefinition::UdpPort(_) => ResourceKind::UdpPort,
       ResourceDefinition::Url(_) => ResourceKind::Url,
       ResourceDefinition::Volume(_) => ResourceKind::Volume,
+      ResourceDefinition::Broker(_) => ResourceKind::Broker,
+      ResourceDefinition::Secret(_) => ResourceKind::Secret,
     }
   }
 
@@ -95,6 +111,14 @@ impl ResourceDefinition {
   pub fn try_volume(self) -> Result<Volume, ManifestError> {
     self.try_into()
   }
+
+  pub fn try_broker(self) -> Result<BrokerResource, ManifestError> {
+    self.try_into()
+  }
+
+  pub fn try_secret(self) -> Result<SecretResource, ManifestError> {
+    self.try_into()
+  }
 }
 
 impl TryFrom<String> for UrlResource {
@@ -305,6 +329,176 @@ impl Renderable for UdpPort {
   }
 }
 
+#[derive(Debug, Clone, PartialEq, Hash, Eq, property::Property, serde::Serialize)]
+/// Normalized representation of an in-process broker.
+#[property(get(public), set(private), mut(disable))]
+pub struct BrokerResource {
+  /// The name of the broker. Brokers with the same name share their topics.
+  pub(crate) name: TemplateConfig<String>,
+}
+
+impl BrokerResource {
+  /// Create a new broker configuration.
+  pub fn new<T: Into<String>>(name: T) -> Self {
+    Self {
+      name: TemplateConfig::new_value(name.into()),
+    }
+  }
+}
+
+impl Renderable for BrokerResource {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.name.set_value(self.name.render(source, root_config, env)?);
+    Ok(())
+  }
+}
+
+/// The places a secret can be read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+pub enum SecretProvider {
+  /// An environment variable of the host.
+  Env,
+  /// A file, with surrounding whitespace trimmed.
+  File,
+  /// What a command prints to stdout, with surrounding whitespace trimmed.
+  Command,
+  /// A secrets backend of the runtime, referenced like `secret://vault/path#key`.
+  Backend,
+}
+
+impl std::fmt::Display for SecretProvider {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Env => write!(f, "env"),
+      Self::File => write!(f, "file"),
+      Self::Command => write!(f, "command"),
+      Self::Backend => write!(f, "backend"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Hash, Eq, property::Property, serde::Serialize)]
+/// Normalized representation of a secret read from the host.
+#[property(get(public), set(private), mut(disable))]
+pub struct SecretResource {
+  /// Where to read the secret from.
+  pub(crate) provider: SecretProvider,
+  /// The environment variable, file, command, or backend reference to read the secret from.
+  pub(crate) source: TemplateConfig<String>,
+  /// How often, in seconds, to read the secret again.
+  pub(crate) refresh: Option<u64>,
+}
+
+impl SecretResource {
+  /// Create a new secret configuration.
+  pub fn new<T: Into<String>>(provider: SecretProvider, source: T) -> Self {
+    Self {
+      provider,
+      source: TemplateConfig::new_value(source.into()),
+      refresh: None,
+    }
+  }
+
+  /// Read the secret again every `refresh` seconds.
+  #[must_use]
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_refresh(self, refresh: Option<u64>) -> Self {
+    Self { refresh, ..self }
+  }
+
+  /// Read the secret from its provider. It's read again every time this is called so rotated secrets are picked up
+  /// by whatever starts next. Secrets from a backend or a command can only be read by the runtime, which knows its
+  /// backends and can run commands without blocking.
+  pub fn reveal(&self) -> Result<Secret, ManifestError> {
+    let source = self
+      .source
+      .value()
+      .ok_or_else(|| ManifestError::UnrenderedConfiguration(format!("{:?}", self.source.template)))?;
+    let error = |reason: String| ManifestError::SecretUnavailable {
+      provider: self.provider,
+      name: source.clone(),
+      reason,
+    };
+
+    let value = match self.provider {
+      SecretProvider::Env => std::env::var(source).map_err(|e| error(e.to_string()))?,
+      SecretProvider::File => std::fs::read_to_string(source)
+        .map_err(|e| error(e.to_string()))?
+        .trim()
+        .to_owned(),
+      SecretProvider::Command => return Err(error("secrets from a command are read by the runtime".to_owned())),
+      SecretProvider::Backend => return Err(error("secrets from a backend are read by the runtime".to_owned())),
+    };
+    Ok(Secret::new(value))
+  }
+}
+
+impl Renderable for SecretResource {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.source.set_value(self.source.render(source, root_config, env)?);
+    Ok(())
+  }
+}
+
+/// What secrets are printed as.
+pub const REDACTED: Redacted = Redacted;
+
+/// A placeholder that prints in place of a secret.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Redacted;
+
+impl std::fmt::Debug for Redacted {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("[REDACTED]")
+  }
+}
+
+impl std::fmt::Display for Redacted {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("[REDACTED]")
+  }
+}
+
+/// A secret value. It's redacted when it's printed, so it can be held by things that are logged or put in errors.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+  /// Wrap a secret value.
+  pub fn new<T: Into<String>>(value: T) -> Self {
+    Self(value.into())
+  }
+
+  /// The secret value itself. Take care not to log it.
+  #[must_use]
+  pub fn expose(&self) -> &str {
+    &self.0
+  }
+}
+
+impl std::fmt::Debug for Secret {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("Secret").field(&REDACTED).finish()
+  }
+}
+
+impl std::fmt::Display for Secret {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    REDACTED.fmt(f)
+  }
+}
+
 #[cfg(test)]
 mod test {
   use anyhow::Result;
@@ -322,4 +516,30 @@ mod test {
 
     Ok(())
   }
+
+  #[test]
+  fn secret_is_redacted() {
+    let secret = Secret::new("hunter2");
+    assert_eq!(secret.expose(), "hunter2");
+    assert!(!format!("{:?} {}", secret, secret).contains("hunter2"));
+  }
+
+  #[test]
+  fn reveal_secret() -> Result<()> {
+    let dir = std::env::temp_dir().join("wick_config_reveal_secret");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("token");
+    std::fs::write(&path, "s3cret\n")?;
+
+    let file = SecretResource::new(SecretProvider::File, path.to_string_lossy());
+    assert_eq!(file.reveal()?.expose(), "s3cret");
+
+    let env = SecretResource::new(SecretProvider::Env, "WICK_CONFIG_TEST_UNSET_SECRET");
+    let err = env.reveal().unwrap_err();
+    assert!(err.to_string().contains("WICK_CONFIG_TEST_UNSET_SECRET"));
+
+    let command = SecretResource::new(SecretProvider::Command, "echo s3cret");
+    assert!(command.reveal().is_err(), "commands should be left to the runtime");
+    Ok(())
+  }
 }
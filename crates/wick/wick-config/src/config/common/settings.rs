@@ -9,13 +9,15 @@ use std::time::Duration;
 pub struct ExecutionSettings {
   /// The timeout for the execution.
   pub timeout: Option<Duration>,
+  /// How to retry failed invocations.
+  pub retry: Option<RetryPolicy>,
 }
 
 impl ExecutionSettings {
   /// Create a new settings object.
   #[must_use]
   pub const fn new(timeout: Option<Duration>) -> Self {
-    Self { timeout }
+    Self { timeout, retry: None }
   }
 
   /// Create a new settings object with a timeout from milliseconds.
@@ -23,12 +25,108 @@ impl ExecutionSettings {
   pub const fn from_timeout_millis(millis: u64) -> Self {
     Self {
       timeout: Some(Duration::from_millis(millis)),
+      retry: None,
     }
   }
 
+  /// Set the retry policy for failed invocations.
+  #[must_use]
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_retry(mut self, retry: Option<RetryPolicy>) -> Self {
+    self.retry = retry;
+    self
+  }
+
   /// Get the timeout duration as milliseconds if set.
   #[must_use]
   pub fn timeout_millis(&self) -> Option<u64> {
     self.timeout.map(|d| d.as_millis() as _)
   }
 }
+
+#[derive(Debug, Clone, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+/// How to retry operation invocations that fail before producing any output.
+#[non_exhaustive]
+pub struct RetryPolicy {
+  /// The maximum number of attempts, including the first one.
+  pub max_attempts: u32,
+  /// The delay before the first retry.
+  pub initial_backoff: Duration,
+  /// The longest delay between two attempts.
+  pub max_backoff: Duration,
+  /// The kinds of failure to retry. Every kind of failure is retried when empty.
+  pub retry_on: Vec<RetryCondition>,
+}
+
+impl RetryPolicy {
+  /// Create a new retry policy that retries every kind of failure.
+  #[must_use]
+  pub const fn new(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+    Self {
+      max_attempts,
+      initial_backoff,
+      max_backoff,
+      retry_on: Vec::new(),
+    }
+  }
+
+  /// Only retry the passed kinds of failure.
+  #[must_use]
+  pub fn retry_only(mut self, conditions: Vec<RetryCondition>) -> Self {
+    self.retry_on = conditions;
+    self
+  }
+
+  /// Whether a failure of kind `condition` should be retried after `attempt` attempts.
+  #[must_use]
+  pub fn should_retry(&self, attempt: u32, condition: RetryCondition) -> bool {
+    attempt < self.max_attempts && (self.retry_on.is_empty() || self.retry_on.contains(&condition))
+  }
+
+  /// The longest delay before the attempt that follows `attempt` attempts, before jitter is applied. The delay
+  /// doubles with every attempt, up to [RetryPolicy::max_backoff].
+  #[must_use]
+  pub fn backoff(&self, attempt: u32) -> Duration {
+    let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+    self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+/// A kind of failure a [RetryPolicy] can retry.
+#[non_exhaustive]
+pub enum RetryCondition {
+  /// The operation failed with an error.
+  Error,
+  /// The operation timed out.
+  Timeout,
+  /// The operation panicked or ended without producing output.
+  Panic,
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_backoff() {
+    let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_millis(350));
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    assert_eq!(policy.backoff(40), Duration::from_millis(350));
+  }
+
+  #[test]
+  fn test_should_retry() {
+    let policy = RetryPolicy::new(3, Duration::ZERO, Duration::ZERO);
+    assert!(policy.should_retry(1, RetryCondition::Panic));
+    assert!(policy.should_retry(2, RetryCondition::Error));
+    assert!(!policy.should_retry(3, RetryCondition::Error));
+    let policy = policy.retry_only(vec![RetryCondition::Timeout]);
+    assert!(policy.should_retry(1, RetryCondition::Timeout));
+    assert!(!policy.should_retry(1, RetryCondition::Error));
+  }
+}
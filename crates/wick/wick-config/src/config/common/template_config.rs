@@ -4,7 +4,7 @@ use std::str::FromStr;
 
 use liquid_json::LiquidJsonValue;
 use serde_json::Value;
-use wick_packet::RuntimeConfig;
+use wick_packet::{InherentData, RuntimeConfig};
 
 use crate::config::LiquidJsonConfig;
 use crate::error::ManifestError;
@@ -132,12 +132,7 @@ where
       return Ok(value.clone());
     }
 
-    let base = source.map(|source| {
-      let dirname = source.parent().unwrap_or_else(|| Path::new("<unavailable>"));
-      serde_json::json!({ "__dirname": dirname })
-    });
-
-    let ctx = LiquidJsonConfig::make_context(base, root, None, env, None)?;
+    let ctx = load_context(source, root, env)?;
 
     if let Some(template) = &self.template {
       let rendered = template
@@ -160,6 +155,27 @@ where
   }
 }
 
+/// The context templates are rendered with when a configuration is loaded: `__dirname` is the manifest's directory,
+/// and `ctx.root_config`, `ctx.env`, and `ctx.inherent` hold the root configuration, the environment, and the time the
+/// configuration was loaded.
+fn load_context(
+  source: Option<&Path>,
+  root: Option<&RuntimeConfig>,
+  env: Option<&HashMap<String, String>>,
+) -> Result<Value, ManifestError> {
+  let base = source.map(|source| {
+    let dirname = source.parent().unwrap_or_else(|| Path::new("<unavailable>"));
+    serde_json::json!({ "__dirname": dirname })
+  });
+  let now = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .unwrap_or_default()
+    .as_millis();
+  let inherent = InherentData::new(0, now as u64);
+
+  LiquidJsonConfig::make_context(base, root, None, env, Some(&inherent))
+}
+
 fn value_to_string(value: &Value) -> Result<String, ManifestError> {
   match value {
     serde_json::Value::String(v) => Ok(v.clone()),
@@ -180,6 +196,27 @@ pub(crate) trait Renderable {
   ) -> Result<(), ManifestError>;
 }
 
+/// Plain string fields are rendered in place if they contain Liquid syntax (`{{` or `{%`). Text that should be kept
+/// as written can be wrapped in `{% raw %}...{% endraw %}`. A template that fails to render fails the load.
+impl Renderable for String {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    if !self.contains("{{") && !self.contains("{%") {
+      return Ok(());
+    }
+    let ctx = load_context(source, root_config, env)?;
+    let rendered = LiquidJsonValue::new(Value::String(self.clone()))
+      .render(&ctx)
+      .map_err(|e| ManifestError::ConfigurationTemplate(format!("could not render '{}': {}", self, e)))?;
+    *self = value_to_string(&rendered)?;
+    Ok(())
+  }
+}
+
 impl<T> Renderable for Option<T>
 where
   T: Renderable,
@@ -213,3 +250,31 @@ where
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  fn render(template: &str) -> Result<String, ManifestError> {
+    let env = HashMap::from([("HOST".to_owned(), "example.com".to_owned())]);
+    let root = RuntimeConfig::from([("port", 8080.into())]);
+    let mut value = template.to_owned();
+    value.render_config(None, Some(&root), Some(&env))?;
+    Ok(value)
+  }
+
+  #[test]
+  fn test_render_string() -> Result<()> {
+    assert_eq!(render("/static")?, "/static");
+    assert_eq!(
+      render("{{ ctx.env.HOST }}:{{ ctx.root_config.port }}")?,
+      "example.com:8080"
+    );
+    assert_eq!(render("{% raw %}{{ name }}{% endraw %}")?, "{{ name }}");
+    assert!(!render("{{ ctx.inherent.timestamp }}")?.is_empty());
+    assert!(render("{{ ctx.env.HOST | nope }}").is_err());
+    Ok(())
+  }
+}
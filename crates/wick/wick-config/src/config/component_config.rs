@@ -202,6 +202,18 @@ impl ComponentConfiguration {
     self.host.as_ref().map(|v| v.insecure_registries.as_ref())
   }
 
+  /// Return the policy that decides which imported components may be instantiated, if the manifest defines one.
+  #[must_use]
+  pub fn policy(&self) -> Option<&config::ComponentPolicy> {
+    self.host.as_ref().and_then(|v| v.policy.as_ref())
+  }
+
+  /// Return how the types of connected ports are checked, if the manifest configures it.
+  #[must_use]
+  pub fn type_check(&self) -> Option<&config::TypeCheckConfig> {
+    self.host.as_ref().and_then(|v| v.type_check.as_ref())
+  }
+
   /// Return the version of the component.
   #[must_use]
   pub fn version(&self) -> Option<&str> {
@@ -253,6 +265,20 @@ impl ComponentConfiguration {
       ComponentImplementation::WasmRs(c) => &c.config,
       ComponentImplementation::Sql(c) => &c.config,
       ComponentImplementation::HttpClient(c) => &c.config,
+      ComponentImplementation::Archive(c) => &c.config,
+      ComponentImplementation::Text(c) => &c.config,
+      ComponentImplementation::Units(c) => &c.config,
+      ComponentImplementation::Validate(c) => &c.config,
+      ComponentImplementation::Generate(c) => &c.config,
+      ComponentImplementation::Flags(c) => &c.config,
+      ComponentImplementation::I18n(c) => &c.config,
+      ComponentImplementation::Metrics(c) => &c.config,
+      ComponentImplementation::Broker(c) => &c.config,
+      ComponentImplementation::Cache(c) => &c.config,
+      ComponentImplementation::ObjectStore(c) => &c.config,
+      ComponentImplementation::Filesystem(c) => &c.config,
+      ComponentImplementation::Data(c) => &c.config,
+      ComponentImplementation::Crypto(c) => &c.config,
     }
   }
 
@@ -301,6 +327,14 @@ impl ComponentConfiguration {
       self.config(),
     )
     .map_err(Error::ConfigurationInvalid)?;
+    if let Some(cycle) = wick_interface_types::find_infinite_cycle(&self.types) {
+      return Err(Error::InfiniteType(cycle));
+    }
+    for def in &self.types {
+      if let TypeDefinition::Enum(def) = def {
+        def.validate().map_err(|e| Error::InvalidEnum(def.name.clone(), e))?;
+      }
+    }
     Ok(())
   }
 }
@@ -409,6 +443,6 @@ impl From<config::Metadata> for ComponentMetadata {
 
 impl From<config::OperationDefinition> for OperationSignature {
   fn from(value: config::OperationDefinition) -> Self {
-    Self::new(value.name, value.inputs, value.outputs, value.config)
+    Self::new(value.name, value.inputs, value.outputs, value.config).with_cost(value.cost)
   }
 }
@@ -84,7 +84,7 @@ impl OperationConfig for FlowOperation {
 
 impl From<FlowOperation> for wick_interface_types::OperationSignature {
   fn from(operation: FlowOperation) -> Self {
-    Self::new(operation.name, operation.inputs, operation.outputs, operation.config)
+    Self::new(operation.name, operation.inputs, operation.outputs, operation.config).with_cost(operation.cost)
   }
 }
 
@@ -130,6 +130,12 @@ pub struct FlowOperation {
   #[builder(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub(crate) flows: Vec<FlowOperation>,
+
+  /// The relative cost of one invocation of the operation.
+  #[builder(default)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cost: Option<u64>,
 }
 
 impl From<FlowOperation> for config::OperationDefinition {
@@ -139,6 +145,7 @@ impl From<FlowOperation> for config::OperationDefinition {
       inputs: value.inputs,
       outputs: value.outputs,
       config: value.config,
+      cost: value.cost,
     }
   }
 }
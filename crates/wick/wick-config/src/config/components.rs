@@ -1,20 +1,48 @@
+mod archive;
+mod broker;
+mod cache;
+mod crypto;
+mod data;
+mod filesystem;
+mod flags;
+mod generate;
 mod grpcurl;
 mod http_client;
+mod i18n;
 mod manifest;
+mod metrics;
 mod native;
+mod object_store;
 mod reference;
 mod sql;
+mod text;
+mod units;
+mod validate;
 mod types;
 mod wasm;
 
 use std::borrow::Cow;
 
+pub use archive::*;
+pub use broker::*;
+pub use cache::*;
+pub use crypto::*;
+pub use data::*;
+pub use filesystem::*;
+pub use flags::*;
+pub use generate::*;
 pub use grpcurl::*;
 pub use http_client::*;
+pub use i18n::*;
 pub use manifest::*;
+pub use metrics::*;
 pub use native::*;
+pub use object_store::*;
 pub use reference::*;
 pub use sql::*;
+pub use text::*;
+pub use units::*;
+pub use validate::*;
 pub use types::*;
 pub use wasm::*;
 use wick_interface_types::{Field, OperationSignatures};
@@ -0,0 +1,121 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+
+use wick_interface_types::{operation, OperationSignature, OperationSignatures};
+
+use crate::config;
+
+/// The default value of [ArchiveComponentConfig::max_entry_size].
+pub const DEFAULT_MAX_ENTRY_SIZE: u64 = 64 * 1024 * 1024;
+
+/// The default value of [ArchiveComponentConfig::max_total_size].
+pub const DEFAULT_MAX_TOTAL_SIZE: u64 = 256 * 1024 * 1024;
+
+/// The default value of [ArchiveComponentConfig::max_entries].
+pub const DEFAULT_MAX_ENTRIES: u32 = 10_000;
+
+#[derive(
+  Debug,
+  Default,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into), default)]
+#[must_use]
+/// A component that unpacks archives into entries and packs entries into archives.
+pub struct ArchiveComponentConfig {
+  /// The format `pack` writes archives in. `unpack` detects the format of its input.
+  #[asset(skip)]
+  pub(crate) format: ArchiveFormat,
+
+  /// The largest uncompressed size in bytes of a single entry.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_entry_size: Option<u64>,
+
+  /// The largest uncompressed size in bytes of all entries of an archive together.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_total_size: Option<u64>,
+
+  /// The largest number of entries an archive may contain.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_entries: Option<u32>,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<wick_interface_types::Field>,
+}
+
+impl ArchiveComponentConfig {
+  /// The entry size limit, falling back to [DEFAULT_MAX_ENTRY_SIZE].
+  #[must_use]
+  pub fn entry_size_limit(&self) -> u64 {
+    self.max_entry_size.unwrap_or(DEFAULT_MAX_ENTRY_SIZE)
+  }
+
+  /// The total size limit, falling back to [DEFAULT_MAX_TOTAL_SIZE].
+  #[must_use]
+  pub fn total_size_limit(&self) -> u64 {
+    self.max_total_size.unwrap_or(DEFAULT_MAX_TOTAL_SIZE)
+  }
+
+  /// The entry count limit, falling back to [DEFAULT_MAX_ENTRIES].
+  #[must_use]
+  pub fn entries_limit(&self) -> u32 {
+    self.max_entries.unwrap_or(DEFAULT_MAX_ENTRIES)
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// Archive formats supported by the archive component.
+#[serde(rename_all = "kebab-case")]
+pub enum ArchiveFormat {
+  /// An uncompressed tar archive.
+  #[default]
+  Tar = 0,
+  /// A zip archive with deflate-compressed entries.
+  Zip = 1,
+}
+
+impl std::fmt::Display for ArchiveFormat {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ArchiveFormat::Tar => write!(f, "tar"),
+      ArchiveFormat::Zip => write!(f, "zip"),
+    }
+  }
+}
+
+impl OperationSignatures for ArchiveComponentConfig {
+  fn operation_signatures(&self) -> Vec<OperationSignature> {
+    vec![
+      operation!("unpack"=>{
+        inputs: {
+          "input" => "bytes"
+        },
+        outputs: {
+          "path" => "string",
+          "metadata" => "object",
+          "data" => "bytes"
+        },
+      }),
+      operation!("pack"=>{
+        inputs: {
+          "path" => "string",
+          "data" => "bytes"
+        },
+        outputs: {
+          "output" => "bytes"
+        },
+      }),
+    ]
+  }
+}
@@ -0,0 +1,105 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+#[derive(
+  Debug,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component whose operations publish events to the topics of an in-process broker.
+pub struct BrokerComponentConfig {
+  /// The Broker resource to publish to.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<BrokerOperationDefinition>,
+}
+
+impl OperationSignatures for BrokerComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for BrokerComponentConfig {
+  type Operation = BrokerOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation that publishes an event to a broker topic for each set of inputs it receives.
+pub struct BrokerOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// The topic to publish events to.
+  pub(crate) topic: String,
+
+  /// The fields of the events the operation publishes.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) inputs: Vec<Field>,
+}
+
+impl OperationConfig for BrokerOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Borrowed(&self.inputs)
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![Field::new("delivered", Type::U32)])
+  }
+}
+
+impl From<BrokerOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: BrokerOperationDefinition) -> Self {
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, operation.inputs, outputs, operation.config)
+  }
+}
@@ -0,0 +1,117 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+
+#[derive(
+  Debug,
+  Clone,
+  Default,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component that stores the outputs of other operations and only computes them when they're missing or expired.
+pub struct CacheComponentConfig {
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<CacheOperationDefinition>,
+}
+
+impl OperationSignatures for CacheComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for CacheComponentConfig {
+  type Operation = CacheOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation that looks up the outputs for each set of inputs it receives, computing them with a linked operation
+/// on a miss.
+pub struct CacheOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// The inputs of the operation.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) inputs: Vec<Field>,
+
+  /// The outputs of the operation.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) outputs: Vec<Field>,
+
+  /// The template the key outputs are stored under is rendered from.
+  pub(crate) key: String,
+
+  /// The operation to compute missing outputs with, as `component::operation`.
+  pub(crate) compute: String,
+
+  /// How long, in milliseconds, stored outputs are fresh.
+  pub(crate) ttl: u64,
+
+  /// How long, in milliseconds, expired outputs are still sent while they're recomputed.
+  #[builder(default)]
+  pub(crate) stale: u64,
+}
+
+impl OperationConfig for CacheOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Borrowed(&self.inputs)
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    Cow::Borrowed(&self.outputs)
+  }
+}
+
+impl From<CacheOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: CacheOperationDefinition) -> Self {
+    Self::new(operation.name, operation.inputs, operation.outputs, operation.config)
+  }
+}
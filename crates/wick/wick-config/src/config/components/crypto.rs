@@ -0,0 +1,226 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+#[derive(
+  Debug,
+  Default,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into), default)]
+#[must_use]
+/// A component whose operations hash, authenticate, sign, and verify data, and issue and verify JWTs.
+pub struct CryptoComponentConfig {
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<CryptoOperationDefinition>,
+}
+
+impl OperationSignatures for CryptoComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for CryptoComponentConfig {
+  type Operation = CryptoOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// What a crypto operation does.
+#[serde(rename_all = "kebab-case")]
+pub enum CryptoAction {
+  /// Output the digest of data.
+  Hash = 0,
+  /// Output the HMAC of data.
+  Hmac = 1,
+  /// Output the signature of data.
+  Sign = 2,
+  /// Output whether a signature or HMAC of data is valid.
+  Verify = 3,
+  /// Output a JWT of claims.
+  JwtIssue = 4,
+  /// Output the claims of a valid JWT.
+  JwtVerify = 5,
+}
+
+impl std::fmt::Display for CryptoAction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Hash => write!(f, "hash"),
+      Self::Hmac => write!(f, "hmac"),
+      Self::Sign => write!(f, "sign"),
+      Self::Verify => write!(f, "verify"),
+      Self::JwtIssue => write!(f, "jwt-issue"),
+      Self::JwtVerify => write!(f, "jwt-verify"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// The algorithms crypto operations use.
+#[serde(rename_all = "kebab-case")]
+pub enum CryptoAlgorithm {
+  /// SHA-256, or HMAC with it.
+  Sha256 = 0,
+  /// SHA-384, or HMAC with it.
+  Sha384 = 1,
+  /// SHA-512, or HMAC with it.
+  Sha512 = 2,
+  /// BLAKE3.
+  Blake3 = 3,
+  /// Ed25519 signatures.
+  Ed25519 = 4,
+  /// ECDSA signatures on the P-256 curve with SHA-256.
+  EcdsaP256 = 5,
+  /// ECDSA signatures on the P-384 curve with SHA-384.
+  EcdsaP384 = 6,
+}
+
+impl std::fmt::Display for CryptoAlgorithm {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Sha256 => write!(f, "sha256"),
+      Self::Sha384 => write!(f, "sha384"),
+      Self::Sha512 => write!(f, "sha512"),
+      Self::Blake3 => write!(f, "blake3"),
+      Self::Ed25519 => write!(f, "ed25519"),
+      Self::EcdsaP256 => write!(f, "ecdsa-p256"),
+      Self::EcdsaP384 => write!(f, "ecdsa-p384"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// How crypto operations encode digests, HMACs, and signatures.
+#[serde(rename_all = "kebab-case")]
+pub enum CryptoEncoding {
+  /// Lowercase hexadecimal.
+  Hex = 0,
+  /// Standard base64, with padding.
+  Base64 = 1,
+  /// URL-safe base64, without padding.
+  Base64Url = 2,
+}
+
+impl std::fmt::Display for CryptoEncoding {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Hex => write!(f, "hex"),
+      Self::Base64 => write!(f, "base64"),
+      Self::Base64Url => write!(f, "base64-url"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a crypto component.
+pub struct CryptoOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// What the operation does.
+  pub(crate) action: CryptoAction,
+
+  /// The algorithm the operation uses.
+  pub(crate) algorithm: CryptoAlgorithm,
+
+  /// The Secret resource holding the key.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) key: Option<BoundIdentifier>,
+
+  /// How digests, HMACs, and signatures are encoded.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) encoding: Option<CryptoEncoding>,
+
+  /// The `iss` claim of tokens.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) issuer: Option<String>,
+
+  /// The `aud` claim of tokens.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) audience: Option<String>,
+
+  /// How many seconds issued tokens are valid for.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) expires: Option<u64>,
+
+  /// How many seconds of clock difference are allowed when verifying tokens.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) leeway: Option<u64>,
+}
+
+impl OperationConfig for CryptoOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    let fields = match self.action {
+      CryptoAction::Hash | CryptoAction::Hmac | CryptoAction::Sign => vec![Field::new("input", Type::Bytes)],
+      CryptoAction::Verify => vec![Field::new("input", Type::Bytes), Field::new("signature", Type::String)],
+      CryptoAction::JwtIssue => vec![Field::new("claims", Type::Object)],
+      CryptoAction::JwtVerify => vec![Field::new("token", Type::String)],
+    };
+    Cow::Owned(fields)
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    let ty = match self.action {
+      CryptoAction::Hash | CryptoAction::Hmac | CryptoAction::Sign | CryptoAction::JwtIssue => Type::String,
+      CryptoAction::Verify => Type::Bool,
+      CryptoAction::JwtVerify => Type::Object,
+    };
+    Cow::Owned(vec![Field::new("output", ty)])
+  }
+}
+
+impl From<CryptoOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: CryptoOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
@@ -0,0 +1,176 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+
+#[derive(
+  Debug,
+  Default,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into), default)]
+#[must_use]
+/// A component whose operations parse CSV, NDJSON, and XML into objects and serialize objects into them.
+pub struct DataComponentConfig {
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<DataOperationDefinition>,
+}
+
+impl OperationSignatures for DataComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for DataComponentConfig {
+  type Operation = DataOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// Whether a data operation parses or serializes.
+#[serde(rename_all = "kebab-case")]
+pub enum DataAction {
+  /// Parse chunks of data into objects.
+  Parse = 0,
+  /// Serialize objects into chunks of data.
+  Serialize = 1,
+}
+
+impl std::fmt::Display for DataAction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Parse => write!(f, "parse"),
+      Self::Serialize => write!(f, "serialize"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// The formats a data component reads and writes.
+#[serde(rename_all = "kebab-case")]
+pub enum DataFormat {
+  /// Comma-separated values.
+  Csv = 0,
+  /// Newline-delimited JSON.
+  Ndjson = 1,
+  /// XML.
+  Xml = 2,
+}
+
+impl std::fmt::Display for DataFormat {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Csv => write!(f, "csv"),
+      Self::Ndjson => write!(f, "ndjson"),
+      Self::Xml => write!(f, "xml"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a data component.
+pub struct DataOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// Whether the operation parses or serializes.
+  pub(crate) action: DataAction,
+
+  /// The format of the data.
+  pub(crate) format: DataFormat,
+
+  /// For CSV, the character that separates fields.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) delimiter: Option<String>,
+
+  /// For CSV, the names of the columns of data without a header row.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) columns: Vec<String>,
+
+  /// For XML, the name of the elements records are read from or written as.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) element: Option<String>,
+
+  /// For serializing XML, the name of the element records are written inside.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) root: Option<String>,
+
+  /// The names of fields in the data mapped to their names in the objects.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  pub(crate) mapping: HashMap<String, String>,
+
+  /// For parsing CSV and XML, whether to turn values that read as numbers, booleans, or null into them.
+  #[builder(default)]
+  pub(crate) typed: bool,
+}
+
+impl OperationConfig for DataOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    let ty = match self.action {
+      DataAction::Parse => Type::Bytes,
+      DataAction::Serialize => Type::Object,
+    };
+    Cow::Owned(vec![Field::new("input", ty)])
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    let ty = match self.action {
+      DataAction::Parse => Type::Object,
+      DataAction::Serialize => Type::Bytes,
+    };
+    Cow::Owned(vec![Field::new("output", ty)])
+  }
+}
+
+impl From<DataOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: DataOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
@@ -0,0 +1,129 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+#[derive(
+  Debug,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component whose operations work with the files in a volume.
+pub struct FilesystemComponentConfig {
+  /// The Volume resource the operations work in.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<FilesystemOperationDefinition>,
+}
+
+impl OperationSignatures for FilesystemComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for FilesystemComponentConfig {
+  type Operation = FilesystemOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// What a filesystem operation does.
+#[serde(rename_all = "kebab-case")]
+pub enum FilesystemAction {
+  /// Stream the changes to the files in directories.
+  Watch = 0,
+}
+
+impl std::fmt::Display for FilesystemAction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Watch => write!(f, "watch"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a filesystem component.
+pub struct FilesystemOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// What the operation does.
+  pub(crate) action: FilesystemAction,
+
+  /// For watch operations, whether to watch the files in subdirectories too.
+  #[builder(default)]
+  pub(crate) recursive: bool,
+
+  /// For watch operations, how often in milliseconds to check for changes.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) interval: Option<u64>,
+}
+
+impl OperationConfig for FilesystemOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(match self.action {
+      FilesystemAction::Watch => vec![Field::new("path", Type::String)],
+    })
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(match self.action {
+      FilesystemAction::Watch => vec![Field::new("output", Type::Object)],
+    })
+  }
+}
+
+impl From<FilesystemOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: FilesystemOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
@@ -0,0 +1,103 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+#[derive(
+  Debug,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component that evaluates feature flags from a local rules file.
+pub struct FlagsComponentConfig {
+  /// The URL resource of the rules file.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<FlagsOperationDefinition>,
+}
+
+impl OperationSignatures for FlagsComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for FlagsComponentConfig {
+  type Operation = FlagsOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a flags component.
+pub struct FlagsOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// The name of the flag the operation evaluates.
+  pub(crate) flag: String,
+}
+
+impl OperationConfig for FlagsOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![Field::new("context", Type::Object)])
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![
+      Field::new("enabled", Type::Bool),
+      Field::new("reason", Type::String),
+    ])
+  }
+}
+
+impl From<FlagsOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: FlagsOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
@@ -0,0 +1,226 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+
+/// How many records an operation generates when no count is configured.
+pub const DEFAULT_COUNT: u32 = 10;
+
+#[derive(
+  Debug,
+  Default,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into), default)]
+#[must_use]
+/// A component that generates records conforming to a type from the manifest's `types`.
+pub struct GenerateComponentConfig {
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<GenerateOperationDefinition>,
+}
+
+impl OperationSignatures for GenerateComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for GenerateComponentConfig {
+  type Operation = GenerateOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a generate component.
+pub struct GenerateOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// The name of the type each record conforms to.
+  #[serde(rename = "type")]
+  pub(crate) ty: String,
+
+  /// How many records to generate.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) count: Option<u32>,
+
+  /// The seed for generating records.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) seed: Option<u64>,
+
+  /// How to generate specific fields.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) fields: Vec<GenerateField>,
+}
+
+impl GenerateOperationDefinition {
+  /// How many records to generate, falling back to [DEFAULT_COUNT].
+  #[must_use]
+  pub fn count(&self) -> u32 {
+    self.count.unwrap_or(DEFAULT_COUNT)
+  }
+}
+
+impl OperationConfig for GenerateOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(Vec::new())
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![Field::new("output", Type::Named(self.ty.clone()))])
+  }
+}
+
+impl From<GenerateOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: GenerateOperationDefinition) -> Self {
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, Vec::new(), outputs, operation.config)
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// How to generate the values of one field of a record.
+pub struct GenerateField {
+  /// The name of the field.
+  pub(crate) name: String,
+
+  /// How the field's values are generated.
+  #[property(skip)]
+  pub(crate) strategy: GenerateStrategy,
+
+  /// The smallest value an `Integer`, `Float`, or `Sequence` field generates.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) min: Option<f64>,
+
+  /// The largest value an `Integer` or `Float` field generates.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max: Option<f64>,
+
+  /// The values a `OneOf` field picks from.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) values: Vec<serde_json::Value>,
+}
+
+impl GenerateField {
+  /// How the field's values are generated.
+  #[must_use]
+  pub const fn strategy(&self) -> GenerateStrategy {
+    self.strategy
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// The ways a generated field can get its values.
+#[serde(rename_all = "kebab-case")]
+pub enum GenerateStrategy {
+  /// A first name.
+  #[default]
+  FirstName = 0,
+  /// A last name.
+  LastName = 1,
+  /// A first and last name.
+  FullName = 2,
+  /// A lowercase username.
+  Username = 3,
+  /// An email address at a reserved example domain.
+  Email = 4,
+  /// A phone number from the fictional 555-01XX range.
+  Phone = 5,
+  /// A company name.
+  Company = 6,
+  /// A city name.
+  City = 7,
+  /// A country name.
+  Country = 8,
+  /// A single lorem ipsum word.
+  Word = 9,
+  /// A lorem ipsum sentence.
+  Sentence = 10,
+  /// A version 4 UUID.
+  Uuid = 11,
+  /// A whole number in a range.
+  Integer = 12,
+  /// A number in a range.
+  Float = 13,
+  /// true or false.
+  Bool = 14,
+  /// An RFC 3339 timestamp.
+  Datetime = 15,
+  /// Consecutive whole numbers.
+  Sequence = 16,
+  /// One of a list of values.
+  OneOf = 17,
+}
+
+impl std::fmt::Display for GenerateStrategy {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      GenerateStrategy::FirstName => write!(f, "first-name"),
+      GenerateStrategy::LastName => write!(f, "last-name"),
+      GenerateStrategy::FullName => write!(f, "full-name"),
+      GenerateStrategy::Username => write!(f, "username"),
+      GenerateStrategy::Email => write!(f, "email"),
+      GenerateStrategy::Phone => write!(f, "phone"),
+      GenerateStrategy::Company => write!(f, "company"),
+      GenerateStrategy::City => write!(f, "city"),
+      GenerateStrategy::Country => write!(f, "country"),
+      GenerateStrategy::Word => write!(f, "word"),
+      GenerateStrategy::Sentence => write!(f, "sentence"),
+      GenerateStrategy::Uuid => write!(f, "uuid"),
+      GenerateStrategy::Integer => write!(f, "integer"),
+      GenerateStrategy::Float => write!(f, "float"),
+      GenerateStrategy::Bool => write!(f, "bool"),
+      GenerateStrategy::Datetime => write!(f, "datetime"),
+      GenerateStrategy::Sequence => write!(f, "sequence"),
+      GenerateStrategy::OneOf => write!(f, "one-of"),
+    }
+  }
+}
@@ -1,14 +1,45 @@
 #![allow(missing_docs)] // delete when we move away from the `property` crate.
 
-use crate::config::LiquidJsonConfig;
+use crate::config::{self, LiquidJsonConfig};
 
-/// A component exposed as an external microservice.
-#[derive(Debug, Clone, PartialEq, property::Property, serde::Serialize)]
+/// A component exposed as an external microservice, e.g. a component served by another wick host.
+#[derive(
+  Debug,
+  Clone,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+  derive_builder::Builder,
+)]
+#[builder(setter(into))]
 #[property(get(public), set(private), mut(disable))]
+#[asset(asset(config::AssetReference))]
 pub struct GrpcUrlComponent {
-  /// The URL to connect to.
+  /// The URL to connect to, a `unix://` or `npipe://` local socket, or a `dns+srv://` or `mdns://` name to discover
+  /// hosts with.
+  #[asset(skip)]
   pub(crate) url: String,
   /// The configuration for the component
+  #[asset(skip)]
+  #[builder(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) config: Option<LiquidJsonConfig>,
+  /// The certificate to identify this runtime to the remote host with, for mutual TLS.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) pem: Option<config::AssetReference>,
+  /// The private key for `pem`.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) key: Option<config::AssetReference>,
+  /// The certificate authority to verify the remote host with.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) ca: Option<config::AssetReference>,
+  /// The domain name to verify the remote host's certificate against.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) domain: Option<String>,
 }
@@ -51,6 +51,18 @@ pub struct HttpClientComponentConfig {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) timeout: Option<u16>,
 
+  /// The maximum number of redirects to follow, 0 disables following redirects.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) redirects: Option<u32>,
+
+  /// Connection pooling and keep-alive settings for outbound requests.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) pool: Option<ConnectionPool>,
+
   /// A list of operations to expose on this component.
   #[asset(skip)]
   #[builder(default)]
@@ -81,6 +93,29 @@ pub struct Proxy {
 
 impl Proxy {}
 
+#[derive(Debug, Clone, Copy, Default, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into), default)]
+#[must_use]
+/// Connection pooling and keep-alive settings for outbound HTTP requests.
+pub struct ConnectionPool {
+  /// The maximum number of idle connections to keep open to each host.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_idle_per_host: Option<u32>,
+
+  /// How long in seconds an idle connection is kept open before it is closed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) idle_timeout: Option<u64>,
+
+  /// The interval in seconds between TCP keep-alive probes on open connections.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) keep_alive: Option<u64>,
+
+  /// Send every request with HTTP/2 without negotiating it first, so concurrent requests to the same host share one
+  /// connection. Hosts that only speak HTTP/1.1 will fail. HTTPS hosts that support HTTP/2 negotiate it without this.
+  pub(crate) http2_prior_knowledge: bool,
+}
+
 impl OperationSignatures for HttpClientComponentConfig {
   fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
     let codec = self.codec;
@@ -122,16 +157,7 @@ impl OperationConfig for HttpClientOperationDefinition {
     Cow::Owned(vec![
       // TODO: support actual HTTP Response type.
       wick_interface_types::Field::new("response", wick_interface_types::Type::Object),
-      wick_interface_types::Field::new(
-        "body",
-        match self.codec {
-          Some(Codec::Json) => wick_interface_types::Type::Object,
-          Some(Codec::Raw) => wick_interface_types::Type::Bytes,
-          Some(Codec::FormData) => wick_interface_types::Type::Object,
-          Some(Codec::Text) => wick_interface_types::Type::Object,
-          None => wick_interface_types::Type::Object,
-        },
-      ),
+      wick_interface_types::Field::new("body", body_type(self.codec, self.stream_response)),
     ])
   }
 }
@@ -144,22 +170,22 @@ impl From<HttpClientOperationDefinition> for wick_interface_types::OperationSign
       vec![
         // TODO: support actual HTTP Response type.
         wick_interface_types::Field::new("response", wick_interface_types::Type::Object),
-        wick_interface_types::Field::new(
-          "body",
-          match operation.codec {
-            Some(Codec::Json) => wick_interface_types::Type::Object,
-            Some(Codec::Raw) => wick_interface_types::Type::Bytes,
-            Some(Codec::FormData) => wick_interface_types::Type::Object,
-            Some(Codec::Text) => wick_interface_types::Type::Object,
-            None => wick_interface_types::Type::Object,
-          },
-        ),
+        wick_interface_types::Field::new("body", body_type(operation.codec, operation.stream_response)),
       ],
       operation.config,
     )
   }
 }
 
+/// The type of an operation's `body` output. Streamed responses are always raw bytes.
+const fn body_type(codec: Option<Codec>, stream_response: bool) -> wick_interface_types::Type {
+  if stream_response || matches!(codec, Some(Codec::Raw)) {
+    wick_interface_types::Type::Bytes
+  } else {
+    wick_interface_types::Type::Object
+  }
+}
+
 #[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
 #[property(get(public), set(private), mut(disable))]
 #[builder(setter(into))]
@@ -200,6 +226,30 @@ pub struct HttpClientOperationDefinition {
 
   /// The HTTP method to use.
   pub(crate) method: HttpMethod,
+
+  /// The timeout in milliseconds for the whole request, including reading the response body.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) timeout: Option<u64>,
+
+  /// The maximum number of redirects to follow, overriding the component's.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) redirects: Option<u32>,
+
+  /// The proxy to use, overriding the component's.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) proxy: Option<Proxy>,
+
+  /// The input whose packets are streamed as the request body in place of `body`.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) body_stream: Option<String>,
+
+  /// Whether the response body is sent as bytes packets as it arrives instead of being decoded with the codec.
+  #[builder(default)]
+  pub(crate) stream_response: bool,
 }
 
 impl HttpClientOperationDefinition {
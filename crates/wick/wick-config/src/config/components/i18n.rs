@@ -0,0 +1,107 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+#[derive(
+  Debug,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component that translates messages from the Fluent or gettext catalogs in a volume.
+pub struct I18nComponentConfig {
+  /// The volume resource holding the catalogs.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+
+  /// The locale to translate to when a message is missing from the requested locale and its language.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) fallback: Option<String>,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<I18nOperationDefinition>,
+}
+
+impl OperationSignatures for I18nComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for I18nComponentConfig {
+  type Operation = I18nOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of an i18n component.
+pub struct I18nOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+}
+
+impl OperationConfig for I18nOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![
+      Field::new("key", Type::String),
+      Field::new("locale", Type::String),
+      Field::new("args", Type::Object),
+    ])
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![Field::new("output", Type::String)])
+  }
+}
+
+impl From<I18nOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: I18nOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
@@ -37,6 +37,105 @@ pub struct ManifestComponent {
   #[builder(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) max_packet_size: Option<u32>,
+  /// Resource limits for the instances of the component, when it's a WebAssembly component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) limits: Option<WasmLimits>,
+  /// Pool instances of the component, when it's a WebAssembly component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) pool: Option<WasmPool>,
+  /// Run the component in a separate, supervised process instead of in the runtime's own.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) process: Option<ProcessHost>,
+}
+
+#[derive(
+  Debug, Clone, Copy, Default, derive_builder::Builder, PartialEq, Eq, Hash, property::Property, serde::Serialize,
+)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into), default)]
+#[must_use]
+/// Resource limits enforced on the instances of a WebAssembly component.
+pub struct WasmLimits {
+  /// The most linear memory, in bytes, each instance of the component can grow to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_memory: Option<u64>,
+
+  /// The most instances of the component that can exist at once.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_instances: Option<u32>,
+
+  /// How long, in milliseconds, an invocation of the component can run before it's cancelled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) timeout: Option<u64>,
+}
+
+#[derive(
+  Debug, Clone, Copy, Default, derive_builder::Builder, PartialEq, Eq, Hash, property::Property, serde::Serialize,
+)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into), default)]
+#[must_use]
+/// A pool of ready instances of a WebAssembly component, each checked out by one invocation at a time.
+pub struct WasmPool {
+  /// The instances to create when the application starts and keep ready. Defaults to one.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) min_instances: Option<u32>,
+
+  /// The most instances to pool. Defaults to `min_instances`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_instances: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, derive_builder::Builder, PartialEq, Eq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into), default)]
+#[must_use]
+/// Settings for running a component in a separate process that the runtime starts, talks to over gRPC, and restarts
+/// when it exits.
+pub struct ProcessHost {
+  /// When to restart the process after it exits.
+  pub(crate) restart: RestartPolicy,
+
+  /// The most times to restart the process. Defaults to five.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_restarts: Option<u32>,
+
+  /// How long, in milliseconds, to wait for the process to start serving the component. Defaults to ten seconds.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) startup_timeout: Option<u64>,
+
+  /// The wick executable to run the component with. Defaults to the executable running the application.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) command: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+/// When the runtime restarts a component's process after it exits.
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+  /// Restart the process when it exits with an error or is killed.
+  #[default]
+  OnFailure = 0,
+  /// Restart the process whenever it exits.
+  Always = 1,
+  /// Leave the process stopped after it exits.
+  Never = 2,
+}
+
+impl std::fmt::Display for RestartPolicy {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::OnFailure => write!(f, "on-failure"),
+      Self::Always => write!(f, "always"),
+      Self::Never => write!(f, "never"),
+    }
+  }
 }
 
 impl OperationSignatures for ManifestComponent {
@@ -0,0 +1,159 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+#[derive(
+  Debug,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component that ships metric samples to a Prometheus remote-write endpoint or a statsd server.
+pub struct MetricsComponentConfig {
+  /// The URL resource to send samples to.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+
+  /// The protocol to send samples with.
+  #[asset(skip)]
+  #[builder(default)]
+  pub(crate) protocol: MetricsProtocol,
+
+  /// Labels added to every sample.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  pub(crate) labels: HashMap<String, String>,
+
+  /// The most samples to send at once.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) batch_size: Option<u32>,
+
+  /// How often, in milliseconds, to send the samples collected so far.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) flush_interval: Option<u64>,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<MetricsOperationDefinition>,
+}
+
+impl OperationSignatures for MetricsComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for MetricsComponentConfig {
+  type Operation = MetricsOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// The protocol a metrics component sends samples with.
+#[serde(rename_all = "kebab-case")]
+pub enum MetricsProtocol {
+  /// Snappy-compressed protobuf write requests, POSTed to a Prometheus remote-write endpoint.
+  RemoteWrite = 0,
+  /// Plain-text statsd lines sent over UDP.
+  Statsd = 1,
+}
+
+impl Default for MetricsProtocol {
+  fn default() -> Self {
+    Self::RemoteWrite
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+/// The kind of metric a sample is.
+#[serde(rename_all = "kebab-case")]
+pub enum MetricKind {
+  /// A value reported as it is.
+  Gauge = 0,
+  /// A count added to the running total of its series.
+  Counter = 1,
+  /// A duration in milliseconds.
+  Timing = 2,
+}
+
+impl Default for MetricKind {
+  fn default() -> Self {
+    Self::Gauge
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a metrics component.
+pub struct MetricsOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// The kind of metric the operation's samples are.
+  #[builder(default)]
+  pub(crate) metric: MetricKind,
+}
+
+impl OperationConfig for MetricsOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![Field::new("sample", Type::Object)])
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![Field::new("output", Type::Object)])
+  }
+}
+
+impl From<MetricsOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: MetricsOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
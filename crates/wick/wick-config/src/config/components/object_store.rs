@@ -0,0 +1,179 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+use crate::config::HttpMethod;
+
+#[derive(
+  Debug,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component whose operations work on the objects of a bucket in an S3-compatible store.
+pub struct ObjectStoreComponentConfig {
+  /// The URL resource of the store's endpoint.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+
+  /// The bucket the operations work on.
+  #[asset(skip)]
+  pub(crate) bucket: String,
+
+  /// The region requests are signed for.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) region: Option<String>,
+
+  /// The Secret resource holding the access key ID.
+  #[asset(skip)]
+  pub(crate) access_key_id: BoundIdentifier,
+
+  /// The Secret resource holding the secret access key.
+  #[asset(skip)]
+  pub(crate) secret_access_key: BoundIdentifier,
+
+  /// The Secret resource holding a session token.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) session_token: Option<BoundIdentifier>,
+
+  /// Whether to address the bucket as the first segment of the path instead of as a subdomain.
+  #[asset(skip)]
+  #[builder(default)]
+  pub(crate) path_style: bool,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<ObjectStoreOperationDefinition>,
+}
+
+impl OperationSignatures for ObjectStoreComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for ObjectStoreComponentConfig {
+  type Operation = ObjectStoreOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// What an object store operation does.
+#[serde(rename_all = "kebab-case")]
+pub enum ObjectStoreAction {
+  /// Stream the bytes of objects.
+  Get = 0,
+  /// Store a stream of bytes as an object.
+  Put = 1,
+  /// List the objects under prefixes.
+  List = 2,
+  /// Make presigned URLs for objects.
+  Presign = 3,
+}
+
+impl std::fmt::Display for ObjectStoreAction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Get => write!(f, "get"),
+      Self::Put => write!(f, "put"),
+      Self::List => write!(f, "list"),
+      Self::Presign => write!(f, "presign"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of an object store component.
+pub struct ObjectStoreOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// What the operation does.
+  pub(crate) action: ObjectStoreAction,
+
+  /// For presign operations, the method the URL is signed for.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) method: Option<HttpMethod>,
+
+  /// For presign operations, how long in seconds the URL is valid for.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) expires: Option<u32>,
+
+  /// For list operations, the most objects to ask the store for at a time.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) page_size: Option<u32>,
+}
+
+impl OperationConfig for ObjectStoreOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(match self.action {
+      ObjectStoreAction::Get | ObjectStoreAction::Presign => vec![Field::new("key", Type::String)],
+      ObjectStoreAction::Put => vec![Field::new("key", Type::String), Field::new("body", Type::Bytes)],
+      ObjectStoreAction::List => vec![Field::new("prefix", Type::String)],
+    })
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    let output = match self.action {
+      ObjectStoreAction::Get => Type::Bytes,
+      ObjectStoreAction::Put | ObjectStoreAction::List => Type::Object,
+      ObjectStoreAction::Presign => Type::String,
+    };
+    Cow::Owned(vec![Field::new("output", output)])
+  }
+}
+
+impl From<ObjectStoreOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: ObjectStoreOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
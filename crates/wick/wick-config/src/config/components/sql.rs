@@ -44,10 +44,28 @@ pub struct SqlComponentConfig {
   #[property(skip)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub(crate) operations: Vec<SqlOperationDefinition>,
+
+  /// Schema changes to apply when the component starts, in order.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) migrations: Vec<SqlMigration>,
 }
 
 impl SqlComponentConfig {}
 
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+/// A named schema change to apply to a SQL database.
+pub struct SqlMigration {
+  /// A unique name for the migration, recorded in the database once it's applied.
+  pub(crate) name: String,
+
+  /// The SQL statements to run.
+  pub(crate) sql: String,
+}
+
 impl OperationSignatures for SqlComponentConfig {
   fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
     self.operations.clone().into_iter().map(Into::into).collect()
@@ -85,6 +103,32 @@ pub enum SqlOperationDefinition {
   Exec(SqlExecOperationDefinition),
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+/// How an operation takes part in a database transaction shared by the operations called in the same flow transaction.
+pub enum SqlTransaction {
+  /// Run in the shared transaction if one is open, otherwise run on its own.
+  #[default]
+  Join,
+  /// Open the shared transaction and run in it.
+  Begin,
+  /// Run in the shared transaction and commit it.
+  Commit,
+  /// Run in the shared transaction and roll it back.
+  Rollback,
+}
+
+impl std::fmt::Display for SqlTransaction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Join => write!(f, "join"),
+      Self::Begin => write!(f, "begin"),
+      Self::Commit => write!(f, "commit"),
+      Self::Rollback => write!(f, "rollback"),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum SqlOperationKind {
   Query,
@@ -109,6 +153,23 @@ impl SqlOperationDefinition {
     }
   }
 
+  #[must_use]
+  pub const fn transaction(&self) -> SqlTransaction {
+    match self {
+      SqlOperationDefinition::Query(v) => v.transaction,
+      SqlOperationDefinition::Exec(v) => v.transaction,
+    }
+  }
+
+  /// The number of inputs to execute together, only exec operations are batched.
+  #[must_use]
+  pub fn batch(&self) -> u32 {
+    match self {
+      SqlOperationDefinition::Query(_) => 1,
+      SqlOperationDefinition::Exec(v) => v.batch.unwrap_or(1).max(1),
+    }
+  }
+
   #[must_use]
   pub fn arguments(&self) -> &[String] {
     match self {
@@ -256,6 +317,11 @@ pub struct SqlQueryOperationDefinition {
   #[asset(skip)]
   #[builder(default)]
   pub(crate) on_error: ErrorBehavior,
+
+  /// How the operation takes part in a database transaction shared by the operations called in the same flow transaction.
+  #[asset(skip)]
+  #[builder(default)]
+  pub(crate) transaction: SqlTransaction,
 }
 
 #[derive(
@@ -311,4 +377,14 @@ pub struct SqlExecOperationDefinition {
   #[asset(skip)]
   #[builder(default)]
   pub(crate) on_error: ErrorBehavior,
+
+  /// How the operation takes part in a database transaction shared by the operations called in the same flow transaction.
+  #[asset(skip)]
+  #[builder(default)]
+  pub(crate) transaction: SqlTransaction,
+
+  /// The number of streamed inputs to execute the statement for together in one database transaction.
+  #[asset(skip)]
+  #[builder(default)]
+  pub(crate) batch: Option<u32>,
 }
@@ -0,0 +1,236 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+/// The default value of [TextOperationDefinition::context].
+pub const DEFAULT_DIFF_CONTEXT: u32 = 3;
+
+#[derive(
+  Debug,
+  Default,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into), default)]
+#[must_use]
+/// A component whose operations match, replace, split, join, template, and diff strings.
+pub struct TextComponentConfig {
+  /// The Volume resource templates and their partials are read from.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) templates: Option<BoundIdentifier>,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<TextOperationDefinition>,
+}
+
+impl OperationSignatures for TextComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for TextComponentConfig {
+  type Operation = TextOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a text component.
+pub struct TextOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// What the operation does with its input.
+  #[property(skip)]
+  pub(crate) kind: TextOperationKind,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// Types of the inputs to a `template` operation.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) inputs: Vec<Field>,
+
+  /// The regular expression the operation uses.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) pattern: Option<String>,
+
+  /// What `replace` substitutes for each match.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) replacement: Option<String>,
+
+  /// The string `split` splits on and `join` joins with when no pattern is set.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) separator: Option<String>,
+
+  /// The liquid template a `template` operation renders.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) template: Option<String>,
+
+  /// The path of the template in the component's `templates` volume a `template` operation renders.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) file: Option<String>,
+
+  /// The number of unchanged lines `diff` shows around each change.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) context: Option<u32>,
+}
+
+impl TextOperationDefinition {
+  /// What the operation does with its input.
+  #[must_use]
+  pub const fn kind(&self) -> TextOperationKind {
+    self.kind
+  }
+
+  /// The regular expression the operation uses.
+  #[must_use]
+  pub fn pattern(&self) -> Option<&str> {
+    self.pattern.as_deref()
+  }
+
+  /// What `replace` substitutes for each match.
+  #[must_use]
+  pub fn replacement(&self) -> Option<&str> {
+    self.replacement.as_deref()
+  }
+
+  /// The string `split` splits on and `join` joins with when no pattern is set.
+  #[must_use]
+  pub fn separator(&self) -> Option<&str> {
+    self.separator.as_deref()
+  }
+
+  /// The liquid template a `template` operation renders.
+  #[must_use]
+  pub fn template(&self) -> Option<&str> {
+    self.template.as_deref()
+  }
+
+  /// The path of the template in the component's `templates` volume a `template` operation renders.
+  #[must_use]
+  pub fn file(&self) -> Option<&str> {
+    self.file.as_deref()
+  }
+
+  /// The number of context lines for `diff`, falling back to [DEFAULT_DIFF_CONTEXT].
+  #[must_use]
+  pub fn context_lines(&self) -> u32 {
+    self.context.unwrap_or(DEFAULT_DIFF_CONTEXT)
+  }
+}
+
+impl OperationConfig for TextOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    match self.kind {
+      TextOperationKind::Template => Cow::Borrowed(&self.inputs),
+      TextOperationKind::Diff => Cow::Owned(vec![
+        Field::new("left", Type::String),
+        Field::new("right", Type::String),
+      ]),
+      _ => Cow::Owned(vec![Field::new("input", Type::String)]),
+    }
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    let ty = match self.kind {
+      TextOperationKind::Match => Type::Bool,
+      TextOperationKind::Captures => Type::Object,
+      _ => Type::String,
+    };
+    Cow::Owned(vec![Field::new("output", ty)])
+  }
+}
+
+impl From<TextOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: TextOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// The kinds of operation a text component can expose.
+#[serde(rename_all = "kebab-case")]
+pub enum TextOperationKind {
+  /// Output whether the pattern matches each input string.
+  #[default]
+  Match = 0,
+  /// Output each input string with every match of the pattern replaced.
+  Replace = 1,
+  /// Output the capture groups of every match of the pattern in each input string.
+  Captures = 2,
+  /// Output the pieces of each input string.
+  Split = 3,
+  /// Output groups of input strings joined together.
+  Join = 4,
+  /// Output a template rendered with one value from each input.
+  Template = 5,
+  /// Output a unified diff of each pair of `left` and `right` strings.
+  Diff = 6,
+}
+
+impl std::fmt::Display for TextOperationKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      TextOperationKind::Match => write!(f, "match"),
+      TextOperationKind::Replace => write!(f, "replace"),
+      TextOperationKind::Captures => write!(f, "captures"),
+      TextOperationKind::Split => write!(f, "split"),
+      TextOperationKind::Join => write!(f, "join"),
+      TextOperationKind::Template => write!(f, "template"),
+      TextOperationKind::Diff => write!(f, "diff"),
+    }
+  }
+}
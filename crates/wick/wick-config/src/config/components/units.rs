@@ -0,0 +1,256 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+
+/// The default value of [UnitsOperationDefinition::precision].
+pub const DEFAULT_CONVERT_PRECISION: u32 = 10;
+
+#[derive(
+  Debug,
+  Default,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into), default)]
+#[must_use]
+/// A component for currency-safe money arithmetic and exact unit conversions.
+pub struct UnitsComponentConfig {
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<UnitsOperationDefinition>,
+}
+
+impl OperationSignatures for UnitsComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for UnitsComponentConfig {
+  type Operation = UnitsOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a units component.
+pub struct UnitsOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// What the operation does with its input.
+  #[property(skip)]
+  pub(crate) kind: UnitsOperationKind,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// How results between two representable values are rounded.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) rounding: Option<RoundingMode>,
+
+  /// The shares an `allocate` operation splits each amount into.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) ratios: Vec<u32>,
+
+  /// The currency a `parse` operation produces.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) currency: Option<String>,
+
+  /// The unit a `convert` operation converts from.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) from: Option<String>,
+
+  /// The unit a `convert` operation converts to.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) to: Option<String>,
+
+  /// The number of decimal places `convert` rounds its results to.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) precision: Option<u32>,
+}
+
+impl UnitsOperationDefinition {
+  /// What the operation does with its input.
+  #[must_use]
+  pub const fn kind(&self) -> UnitsOperationKind {
+    self.kind
+  }
+
+  /// How results are rounded, falling back to [RoundingMode::HalfEven].
+  #[must_use]
+  pub fn rounding(&self) -> RoundingMode {
+    self.rounding.unwrap_or_default()
+  }
+
+  /// The currency a `parse` operation produces.
+  #[must_use]
+  pub fn currency(&self) -> Option<&str> {
+    self.currency.as_deref()
+  }
+
+  /// The unit a `convert` operation converts from.
+  #[must_use]
+  pub fn from_unit(&self) -> Option<&str> {
+    self.from.as_deref()
+  }
+
+  /// The unit a `convert` operation converts to.
+  #[must_use]
+  pub fn to_unit(&self) -> Option<&str> {
+    self.to.as_deref()
+  }
+
+  /// The number of decimal places for `convert`, falling back to [DEFAULT_CONVERT_PRECISION].
+  #[must_use]
+  pub fn precision_places(&self) -> u32 {
+    self.precision.unwrap_or(DEFAULT_CONVERT_PRECISION)
+  }
+}
+
+impl OperationConfig for UnitsOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(match self.kind {
+      UnitsOperationKind::Add | UnitsOperationKind::Subtract => {
+        vec![Field::new("left", Type::Object), Field::new("right", Type::Object)]
+      }
+      UnitsOperationKind::Multiply => vec![Field::new("money", Type::Object), Field::new("factor", Type::String)],
+      UnitsOperationKind::Parse => vec![Field::new("input", Type::String)],
+      UnitsOperationKind::Convert => vec![Field::new("input", Type::F64)],
+      UnitsOperationKind::Allocate | UnitsOperationKind::Format => vec![Field::new("input", Type::Object)],
+    })
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    let ty = match self.kind {
+      UnitsOperationKind::Allocate => Type::List {
+        ty: Box::new(Type::Object),
+      },
+      UnitsOperationKind::Format => Type::String,
+      UnitsOperationKind::Convert => Type::F64,
+      _ => Type::Object,
+    };
+    Cow::Owned(vec![Field::new("output", ty)])
+  }
+}
+
+impl From<UnitsOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: UnitsOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// The kinds of operation a units component can expose.
+#[serde(rename_all = "kebab-case")]
+pub enum UnitsOperationKind {
+  /// Output the sum of each pair of `left` and `right` amounts.
+  #[default]
+  Add = 0,
+  /// Output each `left` amount minus its paired `right` amount.
+  Subtract = 1,
+  /// Output each `money` amount multiplied by its paired `factor`.
+  Multiply = 2,
+  /// Output each amount split by `ratios`.
+  Allocate = 3,
+  /// Output the amount for each decimal string or number.
+  Parse = 4,
+  /// Output each amount as a decimal string.
+  Format = 5,
+  /// Output each number converted between units.
+  Convert = 6,
+}
+
+impl std::fmt::Display for UnitsOperationKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      UnitsOperationKind::Add => write!(f, "add"),
+      UnitsOperationKind::Subtract => write!(f, "subtract"),
+      UnitsOperationKind::Multiply => write!(f, "multiply"),
+      UnitsOperationKind::Allocate => write!(f, "allocate"),
+      UnitsOperationKind::Parse => write!(f, "parse"),
+      UnitsOperationKind::Format => write!(f, "format"),
+      UnitsOperationKind::Convert => write!(f, "convert"),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// How a value between two representable values is rounded.
+#[serde(rename_all = "kebab-case")]
+pub enum RoundingMode {
+  /// To the nearest value, and to the even one when exactly halfway.
+  #[default]
+  HalfEven = 0,
+  /// To the nearest value, and away from zero when exactly halfway.
+  HalfUp = 1,
+  /// To the nearest value, and toward zero when exactly halfway.
+  HalfDown = 2,
+  /// Away from zero.
+  Up = 3,
+  /// Toward zero.
+  Down = 4,
+  /// Toward positive infinity.
+  Ceiling = 5,
+  /// Toward negative infinity.
+  Floor = 6,
+}
+
+impl std::fmt::Display for RoundingMode {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      RoundingMode::HalfEven => write!(f, "half-even"),
+      RoundingMode::HalfUp => write!(f, "half-up"),
+      RoundingMode::HalfDown => write!(f, "half-down"),
+      RoundingMode::Up => write!(f, "up"),
+      RoundingMode::Down => write!(f, "down"),
+      RoundingMode::Ceiling => write!(f, "ceiling"),
+      RoundingMode::Floor => write!(f, "floor"),
+    }
+  }
+}
@@ -0,0 +1,156 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::borrow::Cow;
+
+use wick_interface_types::{Field, OperationSignatures, Type};
+
+use super::{ComponentConfig, OperationConfig};
+use crate::config;
+
+/// The schemes a `url` operation accepts when none are configured.
+pub const DEFAULT_URL_SCHEMES: [&str; 2] = ["http", "https"];
+
+#[derive(
+  Debug,
+  Default,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into), default)]
+#[must_use]
+/// A component that validates and normalizes emails, URLs, and phone numbers.
+pub struct ValidateComponentConfig {
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// A list of operations to expose on this component.
+  #[asset(skip)]
+  #[property(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<ValidateOperationDefinition>,
+}
+
+impl OperationSignatures for ValidateComponentConfig {
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    self.operations.clone().into_iter().map(Into::into).collect()
+  }
+}
+
+impl ComponentConfig for ValidateComponentConfig {
+  type Operation = ValidateOperationDefinition;
+
+  fn operations(&self) -> &[Self::Operation] {
+    &self.operations
+  }
+
+  fn operations_mut(&mut self) -> &mut Vec<Self::Operation> {
+    &mut self.operations
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// An operation of a validation component.
+pub struct ValidateOperationDefinition {
+  /// The name of the operation.
+  #[property(skip)]
+  pub(crate) name: String,
+
+  /// What the operation validates.
+  #[property(skip)]
+  pub(crate) kind: ValidateOperationKind,
+
+  /// The configuration the operation needs.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<Field>,
+
+  /// The schemes a `url` operation accepts.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) schemes: Vec<String>,
+
+  /// The calling code a `phone` operation assumes for national numbers.
+  #[property(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) country_code: Option<String>,
+}
+
+impl ValidateOperationDefinition {
+  /// What the operation validates.
+  #[must_use]
+  pub const fn kind(&self) -> ValidateOperationKind {
+    self.kind
+  }
+
+  /// The schemes a `url` operation accepts, falling back to [DEFAULT_URL_SCHEMES].
+  #[must_use]
+  pub fn allowed_schemes(&self) -> Vec<String> {
+    if self.schemes.is_empty() {
+      DEFAULT_URL_SCHEMES.iter().map(|s| (*s).to_owned()).collect()
+    } else {
+      self.schemes.clone()
+    }
+  }
+
+  /// The calling code a `phone` operation assumes for national numbers.
+  #[must_use]
+  pub fn country_code(&self) -> Option<&str> {
+    self.country_code.as_deref()
+  }
+}
+
+impl OperationConfig for ValidateOperationDefinition {
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn inputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![Field::new("input", Type::String)])
+  }
+
+  fn outputs(&self) -> Cow<Vec<Field>> {
+    Cow::Owned(vec![Field::new("output", Type::Object)])
+  }
+}
+
+impl From<ValidateOperationDefinition> for wick_interface_types::OperationSignature {
+  fn from(operation: ValidateOperationDefinition) -> Self {
+    let inputs = operation.inputs().into_owned();
+    let outputs = operation.outputs().into_owned();
+    Self::new(operation.name, inputs, outputs, operation.config)
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// The kinds of operation a validation component can expose.
+#[serde(rename_all = "kebab-case")]
+pub enum ValidateOperationKind {
+  /// Validate and normalize email addresses.
+  #[default]
+  Email = 0,
+  /// Validate and normalize absolute URLs.
+  Url = 1,
+  /// Validate phone numbers and normalize them to E.164.
+  Phone = 2,
+}
+
+impl std::fmt::Display for ValidateOperationKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ValidateOperationKind::Email => write!(f, "email"),
+      ValidateOperationKind::Url => write!(f, "url"),
+      ValidateOperationKind::Phone => write!(f, "phone"),
+    }
+  }
+}
@@ -116,6 +116,7 @@ impl From<Vec<Audit>> for LockdownConfiguration {
     let mut volume_restrictions: Vec<VolumeRestriction> = Vec::new();
     let mut tcpport_restrictions: Vec<PortRestriction> = Vec::new();
     let mut udpport_restrictions: Vec<PortRestriction> = Vec::new();
+    let mut secret_restrictions: Vec<SecretRestriction> = Vec::new();
     let mut restrictions: Vec<ResourceRestriction> = Vec::new();
     let mut reverse_map: HashMap<&crate::audit::AuditedResource, HashSet<&String>> = HashMap::new();
 
@@ -146,6 +147,17 @@ impl From<Vec<Audit>> for LockdownConfiguration {
           components,
           v.path.to_string_lossy(),
         )),
+        crate::audit::AuditedResource::Broker(_) => {}
+        crate::audit::AuditedResource::Secret(v) => match v.provider {
+          config::SecretProvider::Env | config::SecretProvider::Command => {
+            secret_restrictions.push(SecretRestriction::new_from_template(components, v.provider, &v.source));
+          }
+          _ => {
+            if let Some(dir) = v.file_dir() {
+              volume_restrictions.push(VolumeRestriction::new_from_template(components, dir.to_string_lossy()));
+            }
+          }
+        },
       }
     }
 
@@ -153,6 +165,7 @@ impl From<Vec<Audit>> for LockdownConfiguration {
     restrictions.extend(udpport_restrictions.into_iter().map(ResourceRestriction::UdpPort));
     restrictions.extend(url_restrictions.into_iter().map(ResourceRestriction::Url));
     restrictions.extend(volume_restrictions.into_iter().map(ResourceRestriction::Volume));
+    restrictions.extend(secret_restrictions.into_iter().map(ResourceRestriction::Secret));
 
     LockdownConfiguration {
       source: None,
@@ -6,7 +6,7 @@ use std::path::Path;
 use wick_packet::RuntimeConfig;
 
 use crate::config::template_config::Renderable;
-use crate::config::TemplateConfig;
+use crate::config::{SecretProvider, TemplateConfig};
 use crate::error::ManifestError;
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -16,6 +16,7 @@ pub enum ResourceRestriction {
   Url(UrlRestriction),
   TcpPort(PortRestriction),
   UdpPort(PortRestriction),
+  Secret(SecretRestriction),
 }
 
 impl Renderable for ResourceRestriction {
@@ -29,6 +30,7 @@ impl Renderable for ResourceRestriction {
       Self::Volume(restriction) => restriction.render_config(source, root_config, env),
       Self::Url(restriction) => restriction.render_config(source, root_config, env),
       Self::TcpPort(restriction) | Self::UdpPort(restriction) => restriction.render_config(source, root_config, env),
+      Self::Secret(restriction) => restriction.render_config(source, root_config, env),
     }
   }
 }
@@ -133,3 +135,39 @@ impl Renderable for PortRestriction {
     Ok(())
   }
 }
+
+#[derive(Debug, Clone, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+/// Settings that define what environment variables and commands secrets can be read from.
+pub struct SecretRestriction {
+  /// The components that apply to this restriction.
+  pub(crate) components: Vec<String>,
+  /// The provider this restriction applies to.
+  pub(crate) provider: SecretProvider,
+  /// The environment variables or commands this restriction allows.
+  pub(crate) allow: TemplateConfig<String>,
+}
+
+impl SecretRestriction {
+  /// Create a new [SecretRestriction] for the passed components.
+  #[must_use]
+  pub fn new_from_template(components: Vec<String>, provider: SecretProvider, allow: impl Into<String>) -> Self {
+    Self {
+      components,
+      provider,
+      allow: TemplateConfig::new_template(allow.into()),
+    }
+  }
+}
+
+impl Renderable for SecretRestriction {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.allow.set_value(self.allow.render(source, root_config, env)?);
+    Ok(())
+  }
+}
@@ -103,9 +103,15 @@ impl TypesConfiguration {
   }
 
   /// Validate this configuration is good.
-  #[allow(clippy::missing_const_for_fn)]
   pub fn validate(&self) -> Result<(), ManifestError> {
-    /* placeholder */
+    if let Some(cycle) = wick_interface_types::find_infinite_cycle(&self.types) {
+      return Err(ManifestError::InfiniteType(cycle));
+    }
+    for def in &self.types {
+      if let TypeDefinition::Enum(def) = def {
+        def.validate().map_err(|e| ManifestError::InvalidEnum(def.name.clone(), e))?;
+      }
+    }
     Ok(())
   }
 }
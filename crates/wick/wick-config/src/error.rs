@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use thiserror::Error;
 
+use crate::span::{SourceSpan, SourceText};
+
 /// Wick Manifest's Errors.
 #[derive(Error, Debug)]
 #[non_exhaustive]
@@ -32,10 +34,22 @@ pub enum ManifestError {
   #[error("Attempted to import a type that was not found in the manifest: {0}")]
   TypeNotFound(String),
 
+  /// A type requires a value of itself through required fields and can never be constructed.
+  #[error("Type '{}' can never be constructed because it contains itself through required fields ({}); make at least one of them optional or a list", .0[0], .0.join(" -> "))]
+  InfiniteType(Vec<String>),
+
+  /// Two or more variants of an enum can not be told apart.
+  #[error("Invalid enum '{0}': {1}")]
+  InvalidEnum(String, wick_interface_types::EnumError),
+
   /// No format version or kind found in the parsed manifest.
   #[error("Manifest {} needs a format version (v0) or kind (v1+)", .0.as_ref().map_or("<raw>".to_owned(), |v|v.display().to_string()))]
   NoFormat(Option<PathBuf>),
 
+  /// A manifest written for a newer revision of the format uses constructs this runtime can't safely ignore.
+  #[error("Manifest {} targets format {1}, newer than the {2} this runtime supports, and uses constructs it can't ignore: {3}; upgrade wick to load it", .0.as_ref().map_or("<raw>".to_owned(), |v|v.display().to_string()))]
+  NewerFormat(Option<PathBuf>, String, String, String),
+
   /// Manifest not found at the specified path.
   #[error("File not found {0}")]
   FileNotFound(String),
@@ -59,7 +73,7 @@ pub enum ManifestError {
 
   /// Error deserializing YAML manifest.
   #[error("Could not parse manifest {} as YAML: {1} at line {}, column {}", .0.as_ref().map_or("<raw>".to_owned(), |v|v.display().to_string()), .2.as_ref().map_or("unknown".to_owned(),|l|l.line().to_string()), .2.as_ref().map_or("unknown".to_owned(),|l|l.column().to_string()))]
-  YamlError(Option<PathBuf>, String, Option<serde_yaml::Location>),
+  YamlError(Option<PathBuf>, String, Option<SourceSpan>),
 
   /// IP address in manifest is invalid.
   #[error("Invalid IP Address: {0}")]
@@ -144,6 +158,106 @@ pub enum ManifestError {
   /// Error when trying to use a component as a bound reference when it wasn't bound.
   #[error("Tried to use a component as a bound reference when it wasn't bound")]
   InvalidReference,
+
+  /// A version or version requirement could not be parsed.
+  #[error("Invalid version requirement '{1}' in {0}: {2}")]
+  InvalidRequirement(String, String, String),
+
+  /// A manifest requires a different version of the runtime than the one loading it.
+  #[error("{manifest} requires wick {requirement} but this is wick {version}; install a release that satisfies {requirement} or relax `metadata.requires` in the manifest")]
+  IncompatibleRuntime {
+    /// The manifest declaring the requirement.
+    manifest: String,
+    /// The declared version requirement.
+    requirement: String,
+    /// The version of the running runtime.
+    version: String,
+  },
+
+  /// A secret could not be read from its provider. The reason never includes the secret.
+  #[error("could not read secret from {provider} '{name}': {reason}")]
+  SecretUnavailable {
+    /// The provider the secret was read from.
+    provider: crate::config::SecretProvider,
+    /// The environment variable, file, or command the secret was read from.
+    name: String,
+    /// Why the secret could not be read.
+    reason: String,
+  },
+
+  /// CORS settings allowed credentials from any origin, which browsers refuse.
+  #[error("CORS settings can't allow credentials from any origin ('*'), list the origins to allow instead")]
+  CorsWildcardCredentials,
+
+  /// An error and the place in the manifest it's about.
+  #[error("{0}\n{1}")]
+  Spanned(Box<ManifestError>, SourceSpan),
+}
+
+impl ManifestError {
+  pub(crate) fn yaml(src: &str, path: &Option<PathBuf>, error: &serde_yaml::Error) -> Self {
+    Self::YamlError(
+      path.clone(),
+      error.to_string(),
+      SourceSpan::from_yaml(src, path, error.location().as_ref()),
+    )
+  }
+
+  /// The place in the manifest this error is about, when it's known.
+  #[must_use]
+  pub const fn span(&self) -> Option<&SourceSpan> {
+    match self {
+      Self::YamlError(_, _, span) => span.as_ref(),
+      Self::Spanned(_, span) => Some(span),
+      _ => None,
+    }
+  }
+
+  /// This error without the place in the manifest it's about.
+  #[must_use]
+  pub fn unspanned(&self) -> &Self {
+    match self {
+      Self::Spanned(error, _) => error.unspanned(),
+      error => error,
+    }
+  }
+
+  /// Point this error at the first place in `source` that mentions what it names, unless it already points somewhere.
+  #[must_use]
+  pub fn locate(self, source: &SourceText) -> Self {
+    if self.span().is_some() {
+      return self;
+    }
+    match source.locate(None, &self.needles()) {
+      Some(span) => Self::Spanned(Box::new(self), span),
+      None => self,
+    }
+  }
+
+  /// What to look for in a manifest to find the place this error is about, most specific first.
+  fn needles(&self) -> Vec<String> {
+    match self {
+      Self::IdNotFound { id, .. } => vec![id.clone()],
+      Self::TypeNotFound(name) | Self::InvalidOperationExpression(name) | Self::InvalidUrl(name) => vec![name.clone()],
+      // the problem is with the type's definition rather than wherever else it's used.
+      Self::InvalidEnum(name, _) => vec![format!("name: {}", name), name.clone()],
+      Self::InfiniteType(names) => names
+        .first()
+        .map_or_else(Vec::new, |name| vec![format!("name: {}", name), name.clone()]),
+      Self::InvalidRequirement(_, requirement, _) => vec![requirement.clone()],
+      Self::CorsWildcardCredentials => vec!["allow_credentials".to_owned()],
+      Self::Parser(
+        flow_expression_parser::Error::ComponentIdError(text)
+        | flow_expression_parser::Error::ConnectionTargetSyntax(text, _)
+        | flow_expression_parser::Error::ConnectionDefinitionSyntax(text)
+        | flow_expression_parser::Error::PortSyntax(text)
+        | flow_expression_parser::Error::NoDefaultPort(text)
+        | flow_expression_parser::Error::NoDefaultReference(text)
+        | flow_expression_parser::Error::FlowExpressionParse(text),
+      ) => vec![text.clone()],
+      _ => Vec::new(),
+    }
+  }
 }
 
 #[cfg(feature = "config")]
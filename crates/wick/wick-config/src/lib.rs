@@ -122,6 +122,9 @@
 /// Structures and functions for auditing Wick Manifests.
 #[cfg(feature = "config")]
 pub mod audit;
+/// Runtime version information and manifest compatibility checks.
+#[cfg(feature = "config")]
+pub mod compat;
 mod helpers;
 mod traits;
 
@@ -134,6 +137,11 @@ pub mod lockdown;
 pub mod config;
 /// Wick Manifest error.
 pub mod error;
+/// JSON Schema for the manifest formats.
+#[cfg(any(feature = "v0", feature = "v1"))]
+pub mod schema;
+/// Locations in manifests that errors point to.
+pub mod span;
 mod utils;
 
 #[allow(unused)]
@@ -4,6 +4,10 @@ use serde::de::DeserializeOwned;
 
 use crate::{Error, Result};
 
+/// Best-effort loading of manifests written for newer minor revisions of the v1 format.
+#[cfg(feature = "v1")]
+pub mod forward_compat;
+
 /// A raw configuration, before it's been converted into a `WickConfiguration`.
 #[allow(missing_copy_implementations)]
 #[derive(Debug)]
@@ -27,8 +31,13 @@ pub fn load_raw_config(src: &str, source: &Option<PathBuf>) -> Result<RawConfig>
 
   let raw_version = raw.get("format");
   let raw_kind = raw.get("kind");
-  let version = if raw_kind.is_some() {
-    1
+  let version = if let Some(kind) = raw_kind {
+    // Manifests with a kind are v1 unless the kind spells out another major version.
+    kind
+      .as_str()
+      .and_then(|kind| kind.rsplit_once("@v"))
+      .and_then(|(_, version)| version.split('.').next()?.parse::<i64>().ok())
+      .unwrap_or(1)
   } else {
     let raw_version = raw_version.ok_or(Error::NoFormat(source.clone()))?;
     raw_version
@@ -39,14 +48,13 @@ pub fn load_raw_config(src: &str, source: &Option<PathBuf>) -> Result<RawConfig>
   match version {
     #[cfg(feature = "v0")]
     0 => {
-      let host_config = serde_yaml::from_str::<crate::v0::HostManifest>(src)
-        .map_err(|e| Error::YamlError(source.clone(), e.to_string(), e.location()))?;
+      let host_config =
+        serde_yaml::from_str::<crate::v0::HostManifest>(src).map_err(|e| Error::yaml(src, source, &e))?;
       Ok(RawConfig::V0(Box::new(host_config)))
     }
     #[cfg(feature = "v1")]
     1 => {
-      let base_config = serde_yaml::from_str::<crate::v1::WickConfig>(src)
-        .map_err(|e| Error::YamlError(source.clone(), e.to_string(), e.location()))?;
+      let base_config = forward_compat::load(src, source, raw)?;
       Ok(RawConfig::V1(Box::new(base_config)))
     }
     -1 => Err(Error::NoFormat(source.clone())),
@@ -60,11 +68,14 @@ pub(crate) fn resolve_configuration(
   source: &Option<PathBuf>,
 ) -> Result<crate::config::UninitializedConfiguration> {
   let raw_config = load_raw_config(src, source)?;
+  // conversion errors only name what they're about, so find where that is in the manifest.
+  #[cfg(any(feature = "v0", feature = "v1"))]
+  let locate = |e: Error| e.locate(&crate::span::SourceText::new(src.to_owned(), source.clone()));
 
   match raw_config {
     #[cfg(feature = "v0")]
     RawConfig::V0(config) => {
-      let mut config = crate::config::WickConfiguration::Component((*config).try_into()?);
+      let mut config = crate::config::WickConfiguration::Component((*config).try_into().map_err(locate)?);
       if let Some(src) = source {
         config.set_source(src);
       }
@@ -72,7 +83,7 @@ pub(crate) fn resolve_configuration(
     }
     #[cfg(feature = "v1")]
     RawConfig::V1(config) => {
-      let mut config: crate::config::WickConfiguration = (*config).try_into()?;
+      let mut config: crate::config::WickConfiguration = (*config).try_into().map_err(locate)?;
       if let Some(src) = source {
         config.set_source(src);
       }
@@ -89,7 +100,6 @@ pub(crate) fn from_yaml<T>(src: &str, path: &Option<PathBuf>) -> Result<T>
 where
   T: DeserializeOwned,
 {
-  let result =
-    serde_yaml::from_str(src).map_err(|e| Error::YamlError(path.as_ref().cloned(), e.to_string(), e.location()))?;
+  let result = serde_yaml::from_str(src).map_err(|e| Error::yaml(src, path, &e))?;
   Ok(result)
 }
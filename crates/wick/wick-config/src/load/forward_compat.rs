@@ -0,0 +1,207 @@
+//! Best-effort loading of v1 manifests written for a newer minor revision of the format.
+//!
+//! A manifest declares its revision in its `kind`, e.g. `wick/app@v1.2`; a bare `@v1` is revision 0. Newer revisions
+//! only add constructs, so when a runtime meets a revision it doesn't know yet it loads the manifest anyway, dropping
+//! unknown fields in the places the [COMPATIBILITY] table marks as safe to ignore and warning about each one.
+
+use std::path::PathBuf;
+
+use serde_yaml::{Mapping, Value};
+use tracing::warn;
+
+use crate::{Error, Result};
+
+/// The newest minor revision of the v1 format this crate understands.
+pub const V1_MINOR: u32 = 0;
+
+/// Where newer revisions of the v1 format may add fields that older runtimes can safely ignore, and why.
+///
+/// Paths are dotted keys from the manifest root; a key ending in `[]` matches every item in that list. Unknown fields
+/// anywhere else still fail the load.
+pub const COMPATIBILITY: &[(&str, &str)] = &[
+  ("metadata", "metadata is descriptive"),
+  ("package", "packaging settings only apply when publishing"),
+  ("package.registry", "packaging settings only apply when publishing"),
+  ("host", "host settings fall back to their defaults"),
+  ("host.rpc", "host settings fall back to their defaults"),
+  ("host.metrics", "host settings fall back to their defaults"),
+  ("import[]", "import options fall back to their defaults"),
+  ("resources[]", "resource options fall back to their defaults"),
+];
+
+/// The format revision a `kind` declares, e.g. `(1, 2)` for `wick/app@v1.2`.
+pub(crate) fn format_of(kind: &str) -> Option<(u32, u32)> {
+  let (_, version) = kind.rsplit_once("@v")?;
+  match version.split_once('.') {
+    Some((major, minor)) => Some((major.parse().ok()?, minor.parse().ok()?)),
+    None => Some((version.parse().ok()?, 0)),
+  }
+}
+
+/// Load a v1 manifest, tolerating newer minor revisions of the format.
+// `V1_MINOR` is still 0, which makes the version comparison look absurd until it's bumped.
+#[allow(clippy::absurd_extreme_comparisons)]
+pub(crate) fn load(src: &str, source: &Option<PathBuf>, mut raw: Value) -> Result<crate::v1::WickConfig> {
+  let Some(minor) = declared_minor(&raw) else {
+    // Re-parse from the source so errors keep their location.
+    return serde_yaml::from_str(src).map_err(|e| Error::yaml(src, source, &e));
+  };
+  normalize_kinds(&mut raw);
+  if minor <= V1_MINOR {
+    return serde_yaml::from_value(raw).map_err(|e| Error::yaml(src, source, &e));
+  }
+
+  let manifest = source
+    .as_ref()
+    .map_or_else(|| "<raw>".to_owned(), |p| p.display().to_string());
+  warn!(
+    %manifest,
+    format = %format!("v1.{}", minor),
+    supported = %format!("v1.{}", V1_MINOR),
+    "manifest targets a newer format revision than this runtime supports, loading it best-effort"
+  );
+  loop {
+    let error = match serde_yaml::from_value(raw.clone()) {
+      Ok(config) => return Ok(config),
+      Err(e) => e.to_string(),
+    };
+    let stripped = unknown_field(&error).map_or(false, |field| strip(&mut raw, &field, &manifest));
+    if !stripped {
+      return Err(Error::NewerFormat(
+        source.clone(),
+        format!("v1.{}", minor),
+        format!("v1.{}", V1_MINOR),
+        error,
+      ));
+    }
+  }
+}
+
+/// The minor revision in the root `kind`, if it spells one out.
+fn declared_minor(raw: &Value) -> Option<u32> {
+  let kind = raw.get("kind")?.as_str()?;
+  // kinds without a minor revision are loaded as they are.
+  if !kind.rsplit_once("@v")?.1.contains('.') {
+    return None;
+  }
+  format_of(kind).map(|(_, minor)| minor)
+}
+
+/// Rewrite every `kind: …@v1.N` in `value` to the `…@v1` the v1 types are tagged with.
+fn normalize_kinds(value: &mut Value) {
+  match value {
+    Value::Mapping(map) => {
+      for (key, value) in map.iter_mut() {
+        match value {
+          Value::String(kind) if key.as_str() == Some("kind") => {
+            if let Some((prefix, version)) = kind.rsplit_once("@v") {
+              if let Some((major, _)) = version.split_once('.') {
+                *kind = format!("{}@v{}", prefix, major);
+              }
+            }
+          }
+          _ => normalize_kinds(value),
+        }
+      }
+    }
+    Value::Sequence(items) => items.iter_mut().for_each(normalize_kinds),
+    Value::Tagged(tagged) => normalize_kinds(&mut tagged.value),
+    _ => {}
+  }
+}
+
+/// The field named in a serde "unknown field" error.
+fn unknown_field(error: &str) -> Option<String> {
+  let (_, rest) = error.split_once("unknown field `")?;
+  let (field, _) = rest.split_once('`')?;
+  Some(field.to_owned())
+}
+
+/// Remove `field` from every location the [COMPATIBILITY] table marks as ignorable, returning whether any was removed.
+fn strip(raw: &mut Value, field: &str, manifest: &str) -> bool {
+  let mut stripped = false;
+  for (path, reason) in COMPATIBILITY {
+    let segments: Vec<_> = path.split('.').collect();
+    visit(raw, &segments, &mut |map| {
+      if map.remove(field).is_some() {
+        warn!(manifest, field = %format!("{}.{}", path, field), reason, "ignoring field unknown to this runtime");
+        stripped = true;
+      }
+    });
+  }
+  stripped
+}
+
+fn visit(value: &mut Value, segments: &[&str], f: &mut dyn FnMut(&mut Mapping)) {
+  let Value::Mapping(map) = value else {
+    return;
+  };
+  let Some((first, rest)) = segments.split_first() else {
+    f(map);
+    return;
+  };
+  let (key, each) = first.strip_suffix("[]").map_or((*first, false), |key| (key, true));
+  let Some(child) = map.get_mut(key) else {
+    return;
+  };
+  if !each {
+    visit(child, rest, f);
+  } else if let Value::Sequence(items) = child {
+    for item in items {
+      visit(item, rest, f);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  fn load_str(src: &str) -> crate::Result<crate::v1::WickConfig> {
+    load(src, &None, crate::load::from_yaml(src, &None)?)
+  }
+
+  #[test]
+  fn test_format_of() {
+    assert_eq!(format_of("wick/app@v1"), Some((1, 0)));
+    assert_eq!(format_of("wick/app@v1.3"), Some((1, 3)));
+    assert_eq!(format_of("wick/app@v2"), Some((2, 0)));
+    assert_eq!(format_of("wick/app"), None);
+  }
+
+  #[test]
+  fn test_newer_minor() -> Result<()> {
+    let src = r#"
+kind: wick/app@v1.9
+name: app
+metadata:
+  version: 0.0.1
+  mascot: crab
+resources:
+  - name: port
+    resource:
+      kind: wick/resource/tcpport@v1.9
+      port: "8999"
+      address: 0.0.0.0
+    labels:
+      tier: edge
+"#;
+    let config = load_str(src)?;
+    assert!(matches!(config, crate::v1::WickConfig::AppConfiguration(_)));
+    Ok(())
+  }
+
+  #[test]
+  fn test_newer_minor_unignorable() {
+    let src = "kind: wick/app@v1.9\nname: app\nlaunch_mode: eager\n";
+    assert!(matches!(load_str(src), Err(Error::NewerFormat(..))));
+  }
+
+  #[test]
+  fn test_current_minor_is_strict() {
+    let src = "kind: wick/app@v1.0\nname: app\nmetadata:\n  version: 0.0.1\n  mascot: crab\n";
+    assert!(matches!(load_str(src), Err(Error::YamlError(..))));
+  }
+}
@@ -1,12 +1,13 @@
 mod error;
 mod port;
+mod secret;
 mod url;
 mod volume;
 
 pub use error::*;
 
 use crate::audit::{Audit, AuditedResource, AuditedResourceBinding, AuditedVolume};
-use crate::config::{ConfigOrDefinition, LockdownConfiguration, ResourceRestriction};
+use crate::config::{ConfigOrDefinition, LockdownConfiguration, ResourceRestriction, SecretProvider};
 use crate::WickConfiguration;
 
 pub(crate) fn validate_resource(
@@ -77,6 +78,31 @@ pub(crate) fn validate_resource(
         _ => None,
       }),
     ),
+    // Brokers only pass events between apps in the same process and grant no access to the system.
+    AuditedResource::Broker(_) => Ok(()),
+    AuditedResource::Secret(v) => match v.provider {
+      SecretProvider::Env | SecretProvider::Command => self::secret::validate(
+        component_id,
+        &resource.name,
+        v,
+        resource_restrictions.iter().filter_map(|r| match r {
+          ResourceRestriction::Secret(v) => Some(v),
+          _ => None,
+        }),
+      ),
+      // Files are restricted like volumes. Backends are configured by the runtime and grant no access to the host.
+      SecretProvider::File | SecretProvider::Backend => v.file_dir().map_or(Ok(()), |dir| {
+        self::volume::validate(
+          component_id,
+          &resource.name,
+          &AuditedVolume { path: dir },
+          resource_restrictions.iter().filter_map(|r| match r {
+            ResourceRestriction::Volume(v) => Some(v),
+            _ => None,
+          }),
+        )
+      }),
+    },
   }
 }
 
@@ -109,11 +135,13 @@ mod test {
   use normpath::PathExt;
 
   use super::*;
-  use crate::audit::AuditedUrl;
+  use crate::audit::{AuditedSecret, AuditedUrl};
+  use crate::config::resources::ResourceKind;
   use crate::config::{
     ComponentConfiguration,
     LockdownConfigurationBuilder,
     ResourceRestriction,
+    SecretRestriction,
     UrlRestriction,
     VolumeRestriction,
   };
@@ -231,6 +259,78 @@ mod test {
     Ok(())
   }
 
+  fn secret(provider: SecretProvider, source: &str) -> AuditedResourceBinding {
+    AuditedResourceBinding {
+      name: "secret".to_owned(),
+      resource: AuditedResource::Secret(AuditedSecret {
+        provider,
+        source: source.to_owned(),
+      }),
+    }
+  }
+
+  #[rstest::rstest]
+  #[case(SecretProvider::Env, "API_KEY")]
+  #[case(SecretProvider::Command, "pass show api-key")]
+  fn test_secret_denied_by_default(#[case] provider: SecretProvider, #[case] source: &str) -> Result<()> {
+    // Allowing every URL and volume grants no access to secrets from the environment or commands.
+    let lockdown = new_lockdown_config(vec![
+      ResourceRestriction::Url(UrlRestriction::new_from_template(vec!["*".into()], "*")),
+      ResourceRestriction::Volume(VolumeRestriction::new_from_template(vec!["*".into()], "/")),
+    ]);
+
+    let error = validate_resource("test_component", &secret(provider, source), &lockdown).unwrap_err();
+    assert_eq!(
+      error.failures(),
+      [FailureKind::NotExpresslyAllowed(
+        "test_component".into(),
+        ResourceKind::Secret
+      )]
+    );
+
+    Ok(())
+  }
+
+  #[rstest::rstest]
+  #[case(SecretProvider::Env, "API_KEY", SecretProvider::Env, "DB_*")]
+  #[case(SecretProvider::Command, "cat /etc/shadow", SecretProvider::Command, "pass show *")]
+  #[case(SecretProvider::Command, "API_KEY", SecretProvider::Env, "*")]
+  fn test_secret_restriction_fails(
+    #[case] provider: SecretProvider,
+    #[case] source: &str,
+    #[case] allowed_provider: SecretProvider,
+    #[case] allowed: &str,
+  ) -> Result<()> {
+    let lockdown = new_lockdown_config(vec![ResourceRestriction::Secret(SecretRestriction::new_from_template(
+      vec!["test_component".into()],
+      allowed_provider,
+      allowed,
+    ))]);
+
+    assert!(validate_resource("test_component", &secret(provider, source), &lockdown).is_err());
+
+    Ok(())
+  }
+
+  #[rstest::rstest]
+  #[case(SecretProvider::Env, "API_KEY", "API_KEY")]
+  #[case(SecretProvider::Command, "pass show api-key", "pass show *")]
+  fn test_secret_restriction_passes(
+    #[case] provider: SecretProvider,
+    #[case] source: &str,
+    #[case] allowed: &str,
+  ) -> Result<()> {
+    let lockdown = new_lockdown_config(vec![ResourceRestriction::Secret(SecretRestriction::new_from_template(
+      vec!["test_component".into()],
+      provider,
+      allowed,
+    ))]);
+
+    validate_resource("test_component", &secret(provider, source), &lockdown)?;
+
+    Ok(())
+  }
+
   #[test_logger::test(tokio::test)]
   async fn test_lockdown_fail() -> Result<()> {
     let config = load_component("./tests/manifests/v1/component-resources.yaml").await?;
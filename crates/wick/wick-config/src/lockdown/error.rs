@@ -62,6 +62,8 @@ pub enum FailureKind {
   FileUrlInvalid(url::Url),
   /// A file:// URL does not point to a concrete file.
   FileUrlNotFound(url::Url),
+  /// A component is not allowed to read a secret from the given environment variable or command.
+  Secret(String, String),
 }
 
 impl std::fmt::Display for FailureKind {
@@ -86,6 +88,7 @@ impl std::fmt::Display for FailureKind {
         FailureKind::Url(id, url) =>  write!(f, "component {} is not allowed to access {}", id, url),
         FailureKind::FileUrlInvalid(url) =>  write!(f, "could not create a file path out of {}", url),
         FailureKind::FileUrlNotFound(url) =>  write!(f, "file URL '{}' does not point to a valid file", url),
+        FailureKind::Secret(id, secret) =>  write!(f, "component {} is not allowed to read a {}", id, secret),
     }
   }
 }
@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+
+use wildmatch::WildMatch;
+
+use super::{FailureKind, LockdownError};
+use crate::audit::AuditedSecret;
+use crate::config::resources::ResourceKind;
+use crate::config::SecretRestriction;
+
+pub(crate) fn validate<'a>(
+  component_id: &str,
+  resource_id: &str,
+  resource: &AuditedSecret,
+  restrictions: impl Iterator<Item = &'a SecretRestriction>,
+) -> Result<(), LockdownError> {
+  let mut failures = HashSet::new();
+  for restriction in restrictions.filter(|r| *r.provider() == resource.provider) {
+    match is_allowed(component_id, resource_id, resource, restriction) {
+      Ok(_) => return Ok(()),
+      Err(e) => {
+        failures.insert(e);
+      }
+    }
+  }
+
+  // Unlike other resources, secrets from the environment or commands are denied unless a restriction allows them.
+  if failures.is_empty() {
+    failures.insert(FailureKind::NotExpresslyAllowed(
+      component_id.to_owned(),
+      ResourceKind::Secret,
+    ));
+  }
+
+  Err(LockdownError::new(failures.into_iter().collect()))
+}
+
+pub(crate) fn is_allowed(
+  component_id: &str,
+  _resource_id: &str,
+  resource: &AuditedSecret,
+  restriction: &SecretRestriction,
+) -> Result<(), FailureKind> {
+  // Does this restriction include this component ID? If not, then we don't have access.
+  if !restriction
+    .components()
+    .iter()
+    .any(|c| WildMatch::new(c).matches(component_id))
+  {
+    return Err(FailureKind::NotExpresslyAllowed(
+      component_id.to_owned(),
+      ResourceKind::Secret,
+    ));
+  }
+
+  // If our template configuration is unrendered, there's a bug. Panic.
+  let Some(secret_restriction) = restriction.allow.value() else {
+    panic!("secret restriction's allow template is unrendered");
+  };
+
+  if !WildMatch::new(secret_restriction).matches(&resource.source) {
+    return Err(FailureKind::Secret(component_id.to_owned(), resource.to_string()));
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+  use crate::config::template_config::Renderable;
+  use crate::config::SecretProvider;
+
+  fn secret(provider: SecretProvider, source: &str) -> AuditedSecret {
+    AuditedSecret {
+      provider,
+      source: source.to_owned(),
+    }
+  }
+
+  fn restriction<const K: usize>(
+    components: [&str; K],
+    provider: SecretProvider,
+    allow: &str,
+  ) -> Result<SecretRestriction> {
+    let mut restriction = SecretRestriction::new_from_template(
+      components.into_iter().map(Into::into).collect::<Vec<_>>(),
+      provider,
+      allow,
+    );
+    restriction.render_config(None, None, None)?;
+    Ok(restriction)
+  }
+
+  #[rstest::rstest]
+  #[case("test", SecretProvider::Env, "API_KEY", (["test"], SecretProvider::Env, "API_KEY"))]
+  #[case("test", SecretProvider::Env, "API_KEY", (["*"], SecretProvider::Env, "API_*"))]
+  #[case("test", SecretProvider::Command, "pass show db", (["test"], SecretProvider::Command, "pass show *"))]
+  fn test_allowed<const K: usize>(
+    #[case] component_id: &str,
+    #[case] provider: SecretProvider,
+    #[case] source: &str,
+    #[case] allowed: ([&str; K], SecretProvider, &str),
+  ) -> Result<()> {
+    let restriction = restriction(allowed.0, allowed.1, allowed.2)?;
+    validate(component_id, "ID", &secret(provider, source), [restriction].iter())?;
+
+    Ok(())
+  }
+
+  #[rstest::rstest]
+  #[case("test", SecretProvider::Env, "API_KEY", (["test"], SecretProvider::Env, "DB_*"), FailureKind::Secret("test".into(), "secret from env API_KEY".into()))]
+  #[case("test", SecretProvider::Env, "API_KEY", (["other"], SecretProvider::Env, "*"), FailureKind::NotExpresslyAllowed("test".into(), ResourceKind::Secret))]
+  #[case("test", SecretProvider::Command, "cat /etc/shadow", (["test"], SecretProvider::Env, "*"), FailureKind::NotExpresslyAllowed("test".into(), ResourceKind::Secret))]
+  fn test_restricted<const K: usize>(
+    #[case] component_id: &str,
+    #[case] provider: SecretProvider,
+    #[case] source: &str,
+    #[case] allowed: ([&str; K], SecretProvider, &str),
+    #[case] failure: FailureKind,
+  ) -> Result<()> {
+    let restriction = restriction(allowed.0, allowed.1, allowed.2)?;
+    let error = validate(component_id, "ID", &secret(provider, source), [restriction].iter()).unwrap_err();
+    assert_eq!(error.failures(), [failure]);
+
+    Ok(())
+  }
+
+  #[rstest::rstest]
+  #[case(SecretProvider::Env, "API_KEY")]
+  #[case(SecretProvider::Command, "pass show db")]
+  fn test_denied_by_default(#[case] provider: SecretProvider, #[case] source: &str) -> Result<()> {
+    let error = validate("test", "ID", &secret(provider, source), std::iter::empty()).unwrap_err();
+    assert_eq!(
+      error.failures(),
+      [FailureKind::NotExpresslyAllowed("test".into(), ResourceKind::Secret)]
+    );
+
+    Ok(())
+  }
+}
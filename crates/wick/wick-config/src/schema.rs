@@ -0,0 +1,104 @@
+use serde_json::{json, Map, Value};
+
+use crate::{Error, Result};
+
+/// The `$defs` of each manifest format, generated from the same definitions as the format's Rust types.
+#[cfg(feature = "v0")]
+const V0_DEFS: &str = include_str!("../json-schema/v0/manifest.json");
+#[cfg(feature = "v1")]
+const V1_DEFS: &str = include_str!("../json-schema/v1/manifest.json");
+
+/// The JSON Schema for every manifest format enabled in this build, for editors to complete and validate `.wick` files
+/// with.
+pub fn json_schema() -> Result<Value> {
+  let versions = [
+    #[cfg(feature = "v1")]
+    1,
+    #[cfg(feature = "v0")]
+    0,
+  ];
+  build(&versions)
+}
+
+/// The JSON Schema for one major version of the manifest format.
+pub fn json_schema_for(version: u32) -> Result<Value> {
+  build(&[version])
+}
+
+fn build(versions: &[u32]) -> Result<Value> {
+  let mut defs = Map::new();
+  let mut roots = Vec::new();
+  for version in versions {
+    let (src, root) = match version {
+      #[cfg(feature = "v0")]
+      0 => (V0_DEFS, "v0.HostManifest"),
+      #[cfg(feature = "v1")]
+      1 => (V1_DEFS, "v1.WickConfig"),
+      _ => return Err(Error::VersionError(version.to_string())),
+    };
+    let version_defs: Map<String, Value> = serde_json::from_str(src).map_err(Error::Invalid)?;
+    // v1's root is a union of each kind of configuration, which editors match better when it's flattened.
+    match version_defs
+      .get(root)
+      .and_then(|def| def.get("oneOf"))
+      .and_then(Value::as_array)
+    {
+      Some(kinds) => roots.extend(kinds.iter().cloned()),
+      None => roots.push(json!({ "$ref": format!("#/$defs/{}", root) })),
+    }
+    defs.extend(version_defs);
+  }
+
+  Ok(json!({
+    "title": "Wick configuration",
+    "$schema": "https://json-schema.org/draft/2020-12/schema",
+    "type": "object",
+    "$defs": defs,
+    "oneOf": roots,
+  }))
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  /// Every `$ref` in `value`.
+  fn refs(value: &Value) -> Vec<String> {
+    match value {
+      Value::Object(map) => map
+        .iter()
+        .flat_map(|(k, v)| match (k.as_str(), v) {
+          ("$ref", Value::String(r)) => vec![r.clone()],
+          _ => refs(v),
+        })
+        .collect(),
+      Value::Array(list) => list.iter().flat_map(refs).collect(),
+      _ => Vec::new(),
+    }
+  }
+
+  #[test]
+  fn test_json_schema() -> Result<()> {
+    let schema = json_schema()?;
+    let defs = schema["$defs"].as_object().unwrap();
+    assert!(defs.contains_key("v1.AppConfiguration"));
+    assert!(defs.contains_key("v0.HostManifest"));
+    assert_eq!(schema["oneOf"].as_array().unwrap().len(), 6);
+    for r in refs(&schema) {
+      let name = r.strip_prefix("#/$defs/").unwrap();
+      assert!(defs.contains_key(name), "dangling reference {}", r);
+    }
+    Ok(())
+  }
+
+  #[test]
+  fn test_json_schema_for() -> Result<()> {
+    let schema = json_schema_for(0)?;
+    assert_eq!(schema["oneOf"], json!([{ "$ref": "#/$defs/v0.HostManifest" }]));
+    assert!(!schema["$defs"].as_object().unwrap().contains_key("v1.AppConfiguration"));
+    assert!(json_schema_for(2).is_err());
+    Ok(())
+  }
+}
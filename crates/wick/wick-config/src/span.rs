@@ -0,0 +1,207 @@
+use std::path::{Path, PathBuf};
+
+/// A place in a manifest an error points to, with the line it's on. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SourceSpan {
+  path: Option<PathBuf>,
+  line: usize,
+  column: usize,
+  snippet: String,
+}
+
+impl SourceSpan {
+  /// The manifest the span is in, if it was loaded from a file.
+  #[must_use]
+  pub fn path(&self) -> Option<&Path> {
+    self.path.as_deref()
+  }
+
+  /// The line the span starts on.
+  #[must_use]
+  pub const fn line(&self) -> usize {
+    self.line
+  }
+
+  /// The column the span starts at.
+  #[must_use]
+  pub const fn column(&self) -> usize {
+    self.column
+  }
+
+  /// The text of the line the span starts on.
+  #[must_use]
+  pub fn snippet(&self) -> &str {
+    &self.snippet
+  }
+
+  pub(crate) fn from_yaml(src: &str, path: &Option<PathBuf>, location: Option<&serde_yaml::Location>) -> Option<Self> {
+    location.map(|location| Self {
+      path: path.clone(),
+      line: location.line(),
+      column: location.column(),
+      snippet: line_of(src, location.line()),
+    })
+  }
+}
+
+impl std::fmt::Display for SourceSpan {
+  /// Renders the span the way rustc does, e.g.
+  ///
+  /// ```text
+  ///   --> app.wick:12:11
+  ///    |
+  /// 12 |         - <>.input -> GREET.input
+  ///    |           ^
+  /// ```
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let gutter = " ".repeat(self.line.to_string().len());
+    let path = self
+      .path
+      .as_ref()
+      .map_or_else(|| "<raw>".to_owned(), |p| p.display().to_string());
+    writeln!(f, "{}--> {}:{}:{}", gutter, path, self.line, self.column)?;
+    writeln!(f, "{} |", gutter)?;
+    writeln!(f, "{} | {}", self.line, self.snippet)?;
+    write!(f, "{} | {}^", gutter, " ".repeat(self.column.saturating_sub(1)))
+  }
+}
+
+/// The text of a manifest, for finding where in it the things errors name are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct SourceText {
+  path: Option<PathBuf>,
+  text: String,
+}
+
+impl SourceText {
+  /// Wrap the `text` of the manifest at `path`.
+  pub const fn new(text: String, path: Option<PathBuf>) -> Self {
+    Self { path, text }
+  }
+
+  /// Read the manifest at `path`, if it can be read.
+  #[must_use]
+  pub fn read(path: &Path) -> Option<Self> {
+    std::fs::read_to_string(path)
+      .ok()
+      .map(|text| Self::new(text, Some(path.to_path_buf())))
+  }
+
+  /// The span starting at `line` and `column`, both starting at 1.
+  #[must_use]
+  pub fn span(&self, line: usize, column: usize) -> SourceSpan {
+    SourceSpan {
+      path: self.path.clone(),
+      line,
+      column,
+      snippet: line_of(&self.text, line),
+    }
+  }
+
+  /// Find the first of `needles` in the definition named `scope`, or in the whole manifest when there's no scope. Falls
+  /// back to the line the scope is named on.
+  #[must_use]
+  pub fn locate(&self, scope: Option<&str>, needles: &[String]) -> Option<SourceSpan> {
+    let start = scope.and_then(|name| self.name_line(name));
+    let (line, column) = needles
+      .iter()
+      .find_map(|needle| self.find(needle, start.unwrap_or_default()))
+      .or_else(|| start.map(|line| (line, self.indent(line))))?;
+    Some(self.span(line + 1, column + 1))
+  }
+
+  /// The index of the line that names `name`, e.g. `- name: greet`.
+  fn name_line(&self, name: &str) -> Option<usize> {
+    self.text.lines().position(|line| {
+      let line = line.trim().trim_start_matches("- ");
+      line.strip_prefix("name:").map_or(false, |value| {
+        let value = value.trim();
+        value == name || value.trim_matches(|c| c == '"' || c == '\'') == name
+      })
+    })
+  }
+
+  /// The index of the first line from `from` that contains `needle`, and the column in characters it starts at.
+  fn find(&self, needle: &str, from: usize) -> Option<(usize, usize)> {
+    if needle.is_empty() {
+      return None;
+    }
+    self
+      .text
+      .lines()
+      .enumerate()
+      .skip(from)
+      .find_map(|(i, line)| line.find(needle).map(|byte| (i, line[..byte].chars().count())))
+  }
+
+  fn indent(&self, line: usize) -> usize {
+    self
+      .text
+      .lines()
+      .nth(line)
+      .map_or(0, |l| l.chars().count() - l.trim_start().chars().count())
+  }
+}
+
+/// The text of the `line`th line of `text`, starting at 1.
+fn line_of(text: &str, line: usize) -> String {
+  text.lines().nth(line.saturating_sub(1)).unwrap_or_default().to_owned()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const MANIFEST: &str = r#"kind: wick/component@v1
+name: test
+component:
+  kind: wick/component/composite@v1
+  operations:
+    - name: "count"
+      flow:
+        - <>.input -> test::count.input
+    - name: greet
+      flow:
+        - <>.input -> COUNT.input
+        - COUNT.output -> GREET.input
+"#;
+
+  fn source() -> SourceText {
+    SourceText::new(MANIFEST.to_owned(), Some(PathBuf::from("component.wick")))
+  }
+
+  fn at(span: Option<SourceSpan>) -> Option<(usize, usize)> {
+    span.map(|span| (span.line(), span.column()))
+  }
+
+  #[test]
+  fn test_locate() {
+    let source = source();
+    // `<>.input` appears in both flows, only the one in `greet` is the one it's about.
+    assert_eq!(
+      at(source.locate(Some("greet"), &["<>.input".to_owned()])),
+      Some((11, 11))
+    );
+    assert_eq!(at(source.locate(Some("count"), &[])), Some((6, 5)));
+    assert_eq!(at(source.locate(None, &["test::count".to_owned()])), Some((8, 23)));
+    assert_eq!(source.locate(Some("missing"), &["nothing".to_owned()]), None);
+  }
+
+  #[test]
+  fn test_display() {
+    let span = source().span(12, 11);
+    assert_eq!(span.snippet(), "        - COUNT.output -> GREET.input");
+    assert_eq!(
+      span.to_string(),
+      [
+        "  --> component.wick:12:11",
+        "   |",
+        "12 |         - COUNT.output -> GREET.input",
+        "   |           ^",
+      ]
+      .join("\n")
+    );
+  }
+}
@@ -65,6 +65,10 @@ impl TryFrom<crate::v0::CollectionDefinition> for config::ComponentDefinition {
         config::ComponentDefinition::GrpcUrl(config::components::GrpcUrlComponent {
           url: def.reference.clone(),
           config: def.data.map(Into::into),
+          pem: None,
+          key: None,
+          ca: None,
+          domain: None,
         })
       }
       #[allow(deprecated)]
@@ -79,6 +83,9 @@ impl TryFrom<crate::v0::CollectionDefinition> for config::ComponentDefinition {
           config: def.data.map(Into::into),
           provide: Default::default(),
           max_packet_size: None,
+          limits: None,
+          pool: None,
+          process: None,
         })
       }
     };
@@ -108,6 +115,7 @@ impl TryFrom<crate::v0::SchematicManifest> for config::FlowOperation {
       outputs: Default::default(),
       config: Default::default(),
       flows: Default::default(),
+      cost: Default::default(),
     })
   }
 }
@@ -156,6 +164,10 @@ impl TryFrom<crate::v0::HostConfig> for config::HostConfig {
       allow_latest: def.allow_latest,
       insecure_registries: def.insecure_registries,
       rpc: def.rpc.and_then(|v| v.try_into().ok()),
+      metrics: None,
+      otlp: None,
+      policy: None,
+      type_check: None,
     })
   }
 }
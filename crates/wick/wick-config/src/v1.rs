@@ -101,6 +101,24 @@ pub struct AppConfiguration {
   #[serde(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub triggers: Vec<TriggerDefinition>,
+  /// An admin listener that serves health and readiness endpoints for orchestrators.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub admin: Option<AdminConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Configuration for the built-in admin listener that serves health and readiness probes.
+pub struct AdminConfig {
+  /// The TcpPort resource to serve &#x60;/healthz&#x60;, &#x60;/readyz&#x60;, and &#x60;/metrics&#x60; on.
+  pub resource: BoundIdentifier,
+  /// Rules checked against the app&#x27;s operation metrics that invoke an operation when they fire.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub alerts: Vec<AlertRule>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -141,6 +159,12 @@ pub struct Metadata {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub icon: Option<crate::v1::helpers::LocationReference>,
+  /// Version requirements the runtime must satisfy to load this artifact, e.g. &#x60;wick: >=0.17&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  #[serde(deserialize_with = "crate::helpers::kv_deserializer")]
+  pub requires: HashMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
@@ -215,6 +239,12 @@ pub enum ResourceDefinition {
   /// A variant representing a [Volume] type.
   #[serde(rename = "wick/resource/volume@v1")]
   Volume(Volume),
+  /// A variant representing a [Broker] type.
+  #[serde(rename = "wick/resource/broker@v1")]
+  Broker(Broker),
+  /// A variant representing a [Secret] type.
+  #[serde(rename = "wick/resource/secret@v1")]
+  Secret(Secret),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -253,17 +283,95 @@ pub struct Url {
   pub url: LiquidTemplate,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An in-process message broker. Flows publish events to its topics with a broker component and broker triggers subscribe to them, so apps running in the same process can be chained together without external messaging infrastructure.
+pub struct Broker {
+  /// The name of the broker. Apps in the same process that bind brokers with the same name share their topics.
+  pub name: LiquidTemplate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A secret, like a password or an API key, read from the host when the component or trigger that binds it starts so it never has to be written in a manifest. Secrets are redacted wherever they&#x27;re printed.
+pub struct Secret {
+  /// Where to read the secret from.
+  pub provider: SecretProvider,
+  /// The name of the environment variable, the path of the file, the command to run, or, for a backend, a reference like `secret://vault/path#key`, depending on the provider. Commands are split on whitespace and run without a shell.
+  pub source: LiquidTemplate,
+  /// How often, in seconds, to read the secret again while the app runs. The secret is only read at startup when this is omitted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub refresh: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The places a secret can be read from.
+pub enum SecretProvider {
+  /// An environment variable of the host.
+  Env = 0,
+  /// A file, with surrounding whitespace trimmed.
+  File = 1,
+  /// What a command prints to stdout, with surrounding whitespace trimmed.
+  Command = 2,
+  /// A secrets backend of the runtime, like HashiCorp Vault (`vault`) or AWS Secrets Manager (`aws`).
+  Backend = 3,
+}
+
+impl Default for SecretProvider {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for SecretProvider {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Env,
+      1 => Self::File,
+      2 => Self::Command,
+      3 => Self::Backend,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Env,
+      1 => Self::File,
+      2 => Self::Command,
+      3 => Self::Backend,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 #[serde(tag = "kind")]
 /// Triggers that operate off events and translate environment data to components. Triggers are the way that Wick handles standard use cases and translates them into the component world.
 pub enum TriggerDefinition {
+  /// A variant representing a [BrokerTrigger] type.
+  #[serde(rename = "wick/trigger/broker@v1")]
+  BrokerTrigger(BrokerTrigger),
   /// A variant representing a [CliTrigger] type.
   #[serde(rename = "wick/trigger/cli@v1")]
   CliTrigger(CliTrigger),
+  /// A variant representing a [GrpcTrigger] type.
+  #[serde(rename = "wick/trigger/grpc@v1")]
+  GrpcTrigger(GrpcTrigger),
   /// A variant representing a [HttpTrigger] type.
   #[serde(rename = "wick/trigger/http@v1")]
   HttpTrigger(HttpTrigger),
+  /// A variant representing a [LogTrigger] type.
+  #[serde(rename = "wick/trigger/log@v1")]
+  LogTrigger(LogTrigger),
   /// A variant representing a [TimeTrigger] type.
   #[serde(rename = "wick/trigger/time@v1")]
   TimeTrigger(TimeTrigger),
@@ -294,6 +402,53 @@ pub struct CliTrigger {
   #[serde(serialize_with = "crate::v1::helpers::serialize_component_expression")]
   #[serde(deserialize_with = "crate::v1::parse::component_operation_syntax")]
   pub operation: ComponentOperationExpression,
+  /// Connect stdin and stdout to the operation, either streaming them through its &#x60;stdin&#x60; input and its outputs or bridging them for an interactive session.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub stdin: Option<StdinMode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// How the CLI trigger connects stdin and stdout to its operation.
+pub enum StdinMode {
+  /// Each line of stdin is parsed as JSON and sent as a packet, and each packet the operation outputs is written as a line of JSON.
+  Lines = 0,
+  /// Stdin is sent in chunks of bytes, and output is written as-is: bytes raw, strings as text, and anything else as JSON.
+  Raw = 1,
+  /// The operation drives a terminal session: text it sends on &#x60;stdout&#x60; and &#x60;stderr&#x60; is written there, and each &#x60;prompt&#x60; it sends is shown before the next line of stdin is sent back on its &#x60;line&#x60; input.
+  Interactive = 2,
+}
+
+impl Default for StdinMode {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for StdinMode {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Lines,
+      1 => Self::Raw,
+      2 => Self::Interactive,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Lines,
+      1 => Self::Raw,
+      2 => Self::Interactive,
+      _ => {
+        return None;
+      }
+    })
+  }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -340,6 +495,71 @@ pub struct Schedule {
   pub repeat: u16,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A trigger that tails log files and sends each record parsed from them to an operation.
+pub struct LogTrigger {
+  /// The operation to send each record to. Records are sent one at a time, in the order they were written, on the operation&#x27;s &#x60;record&#x60; input.
+
+  #[serde(serialize_with = "crate::v1::helpers::serialize_component_expression")]
+  #[serde(deserialize_with = "crate::v1::parse::component_operation_syntax")]
+  pub operation: ComponentOperationExpression,
+  /// The files to tail and how to parse them.
+
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub sources: Vec<LogSource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A set of log files and the format their records are written in.
+pub struct LogSource {
+  /// The Volume resource the log files are in.
+  pub resource: BoundIdentifier,
+  /// Glob patterns, relative to the volume, of the files to tail. Files that match after the trigger starts are read from their beginning.
+
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub paths: Vec<String>,
+  /// The format records are written in. Each line is one record.
+
+  #[serde(default)]
+  pub format: LogFormat,
+  /// The regular expression to parse each line with when the format is &#x60;Regex&#x60;. Each named capture group becomes a field of the record.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pattern: Option<String>,
+  /// The names of the fields of each CSV record. When empty, the first line of each file is read as its header.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub columns: Vec<String>,
+  /// The character CSV fields are separated by. Defaults to a comma.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub delimiter: Option<String>,
+  /// Whether to read files that exist when the trigger starts from their beginning instead of only what&#x27;s written to them afterwards.
+
+  #[serde(default)]
+  pub from_start: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A trigger that subscribes to a topic of a broker resource and sends each event published on it to an operation, with each field of the event on the input of the same name.
+pub struct BrokerTrigger {
+  /// The Broker resource to subscribe to.
+  pub resource: BoundIdentifier,
+  /// The topic to subscribe to.
+  pub topic: String,
+  /// The operation to send each event to. Events are sent one at a time, in the order they were published.
+
+  #[serde(serialize_with = "crate::v1::helpers::serialize_component_expression")]
+  #[serde(deserialize_with = "crate::v1::parse::component_operation_syntax")]
+  pub operation: ComponentOperationExpression,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 /// A reference to an operation. This type can be shortened to <code>component_id::operation_name</code> with the short-form syntax.
@@ -360,6 +580,111 @@ pub struct ComponentOperationExpression {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub timeout: Option<u64>,
+  /// How to retry the operation when an invocation fails.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub retry: Option<RetryPolicy>,
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn RETRY_POLICY_MAX_ATTEMPTS() -> u32 {
+  3
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn RETRY_POLICY_INITIAL_BACKOFF() -> u64 {
+  100
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn RETRY_POLICY_MAX_BACKOFF() -> u64 {
+  10000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Retry behavior for operation invocations that fail before producing any output. Retries wait with a jittered, exponentially increasing backoff.
+pub struct RetryPolicy {
+  /// The maximum number of attempts, including the first one.
+
+  #[serde(default = "RETRY_POLICY_MAX_ATTEMPTS")]
+  pub max_attempts: u32,
+  /// The delay (in milliseconds) before the first retry. The delay doubles with every further retry.
+
+  #[serde(default = "RETRY_POLICY_INITIAL_BACKOFF")]
+  pub initial_backoff: u64,
+  /// The longest delay (in milliseconds) between two attempts.
+
+  #[serde(default = "RETRY_POLICY_MAX_BACKOFF")]
+  pub max_backoff: u64,
+  /// The kinds of failure to retry. Retries every kind of failure when empty.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub retry_on: Vec<RetryCondition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A kind of failure a retry policy can retry.
+pub enum RetryCondition {
+  /// The operation failed with an error.
+  Error = 0,
+  /// The operation timed out.
+  Timeout = 1,
+  /// The operation panicked or ended without producing output.
+  Panic = 2,
+}
+
+impl Default for RetryCondition {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for RetryCondition {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Error,
+      1 => Self::Timeout,
+      2 => Self::Panic,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Error,
+      1 => Self::Timeout,
+      2 => Self::Panic,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A gRPC server that exposes component operations as methods on a generated service.
+pub struct GrpcTrigger {
+  /// The TcpPort resource to listen on for connections.
+  pub resource: BoundIdentifier,
+  /// The protobuf package the generated service belongs to.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub package: Option<String>,
+  /// The name of the generated gRPC service.
+  pub service: String,
+  /// The operations to expose as methods on the service.
+
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  #[serde(deserialize_with = "crate::v1::parse::vec_component_operation")]
+  pub operations: Vec<ComponentOperationExpression>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -405,6 +730,11 @@ pub struct ProxyRouter {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub middleware: Option<Middleware>,
+  /// Cross-origin resource sharing (CORS) settings for this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cors: Option<Cors>,
   /// The URL resource to proxy to.
   pub url: BoundIdentifier,
   /// Whether or not to strip the router&#x27;s path from the proxied request.
@@ -429,6 +759,16 @@ pub struct RestRouter {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub middleware: Option<Middleware>,
+  /// Built-in authentication that requests must pass before they reach the router&#x27;s middleware or operations.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub auth: Option<Auth>,
+  /// Cross-origin resource sharing (CORS) settings for this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cors: Option<Cors>,
   /// The routes to serve and operations that handle them.
 
   #[serde(default)]
@@ -474,6 +814,24 @@ pub struct Route {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub summary: Option<String>,
+  /// How the operation&#x27;s output is sent back to the client.
+
+  #[serde(default)]
+  pub response: ResponseMode,
+  /// The maximum size in bytes of a request body. Larger requests are rejected with 413 Payload Too Large.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_body_size: Option<u64>,
+  /// The maximum size in bytes of a single field or file in a multipart/form-data body.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_part_size: Option<u64>,
+  /// Whether to validate path parameters, query parameters, and the request body against the operation&#x27;s inputs. Invalid requests are rejected with 400 Bad Request.
+
+  #[serde(default)]
+  pub validate: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -587,6 +945,11 @@ pub struct StaticRouter {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub middleware: Option<Middleware>,
+  /// Cross-origin resource sharing (CORS) settings for this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cors: Option<Cors>,
   /// The volume to serve static files from.
   pub volume: String,
   /// Fallback path (relative to volume &#x60;resource&#x60;) for files to serve in case of a 404. Useful for SPA&#x27;s. if volume resource is: /www and fallback: index.html, then a 404 will serve /www/index.html
@@ -598,6 +961,10 @@ pub struct StaticRouter {
 
   #[serde(default)]
   pub indexes: bool,
+  /// Whether to serve precompressed &#x60;.br&#x60; or &#x60;.gz&#x60; variants of requested files when they exist and the client accepts them.
+
+  #[serde(default)]
+  pub precompressed: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -611,6 +978,16 @@ pub struct RawRouter {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub middleware: Option<Middleware>,
+  /// Built-in authentication that requests must pass before they reach the router&#x27;s middleware or operations.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub auth: Option<Auth>,
+  /// Cross-origin resource sharing (CORS) settings for this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cors: Option<Cors>,
   /// The codec to use when encoding/decoding data.
 
   #[serde(default)]
@@ -643,65 +1020,163 @@ pub struct Middleware {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
-/// A type definition for a Wick Components and Operations
-pub struct TypesConfiguration {
-  /// The name of this type.
+/// Cross-origin resource sharing (CORS) settings that let browsers call a router from other origins.
+pub struct Cors {
+  /// The origins allowed to make cross-origin requests. Use * to allow any origin.
 
-  #[serde(default)]
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub name: Option<String>,
-  /// Associated metadata for this type.
-
-  #[serde(default)]
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub metadata: Option<Metadata>,
-  /// Additional types to export and make available to the type.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub allowed_origins: Vec<String>,
+  /// The methods allowed in cross-origin requests. Defaults to GET, POST, PUT, PATCH, and DELETE.
 
   #[serde(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
-  pub types: Vec<TypeDefinition>,
-  /// A list of operation signatures.
+  pub allowed_methods: Vec<String>,
+  /// The request headers allowed in cross-origin requests. Defaults to the headers the browser asks for.
 
   #[serde(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
-  pub operations: Vec<OperationDefinition>,
-  /// Details about the package for this types.
+  pub allowed_headers: Vec<String>,
+  /// How long in seconds browsers may cache the result of a preflight request.
 
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub package: Option<PackageDefinition>,
+  pub max_age: Option<u64>,
+  /// Whether browsers may send credentials such as cookies with cross-origin requests. Credentials can&#x27;t be allowed from any origin (`*`).
+
+  #[serde(default)]
+  pub allow_credentials: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
-/// A configuration for a Wick Component
-pub struct TestConfiguration {
-  /// The name of this component.
+#[serde(tag = "kind")]
+/// Built-in authentication schemes for HTTP routers.
+pub enum Auth {
+  /// A variant representing a [JwtAuth] type.
+  #[serde(rename = "wick/auth/jwt@v1")]
+  JwtAuth(JwtAuth),
+  /// A variant representing a [ApiKeyAuth] type.
+  #[serde(rename = "wick/auth/api-key@v1")]
+  ApiKeyAuth(ApiKeyAuth),
+  /// A variant representing a [BasicAuth] type.
+  #[serde(rename = "wick/auth/basic@v1")]
+  BasicAuth(BasicAuth),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Require a JSON Web Token in the Authorization header, signed with a shared secret using HS256, HS384, or HS512.
+pub struct JwtAuth {
+  /// The shared secret used to verify token signatures.
+  pub secret: String,
+  /// The issuer that the token&#x27;s &#x60;iss&#x60; claim must match.
 
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub name: Option<String>,
-  /// Configuration used to instantiate this component.
+  pub issuer: Option<String>,
+  /// The audience that the token&#x27;s &#x60;aud&#x60; claim must contain.
 
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub with: Option<HashMap<String, liquid_json::LiquidJsonValue>>,
-  /// Unit tests to run against components and operations.
+  pub audience: Option<String>,
+}
 
-  #[serde(default)]
-  #[serde(skip_serializing_if = "Vec::is_empty")]
-  pub cases: Vec<TestDefinition>,
+#[allow(non_snake_case)]
+pub(crate) fn API_KEY_AUTH_HEADER() -> String {
+  "x-api-key".to_owned()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
-/// A lockdown configuration used to secure Wick components and applications
-pub struct LockdownConfiguration {
-  /// Associated metadata for this configuration.
+/// Require one of the configured API keys in a request header.
+pub struct ApiKeyAuth {
+  /// The header to read the API key from.
 
-  #[serde(default)]
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub metadata: Option<Metadata>,
+  #[serde(default = "API_KEY_AUTH_HEADER")]
+  pub header: String,
+  /// The API keys that are allowed access.
+
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Require HTTP Basic credentials that match one of the configured users.
+pub struct BasicAuth {
+  /// The realm to report in the WWW-Authenticate header.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub realm: Option<String>,
+  /// A map of usernames to the passwords that are allowed access.
+
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  #[serde(deserialize_with = "crate::helpers::kv_deserializer")]
+  pub users: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A type definition for a Wick Components and Operations
+pub struct TypesConfiguration {
+  /// The name of this type.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// Associated metadata for this type.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<Metadata>,
+  /// Additional types to export and make available to the type.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub types: Vec<TypeDefinition>,
+  /// A list of operation signatures.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<OperationDefinition>,
+  /// Details about the package for this types.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub package: Option<PackageDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A configuration for a Wick Component
+pub struct TestConfiguration {
+  /// The name of this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// Configuration used to instantiate this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub with: Option<HashMap<String, liquid_json::LiquidJsonValue>>,
+  /// Unit tests to run against components and operations.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub cases: Vec<TestDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A lockdown configuration used to secure Wick components and applications
+pub struct LockdownConfiguration {
+  /// Associated metadata for this configuration.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<Metadata>,
   /// Restrictions to apply to resources before an application or component can be run.
 
   #[serde(default)]
@@ -726,6 +1201,9 @@ pub enum ResourceRestriction {
   /// A variant representing a [UdpPortRestriction] type.
   #[serde(rename = "wick/resource/udpport@v1")]
   UdpPortRestriction(UdpPortRestriction),
+  /// A variant representing a [SecretRestriction] type.
+  #[serde(rename = "wick/resource/secret@v1")]
+  SecretRestriction(SecretRestriction),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -784,6 +1262,21 @@ pub struct UdpPortRestriction {
   pub port: LiquidTemplate,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Restrictions to apply against Secret resources read from environment variables or commands. Secrets read from files are restricted by volume restrictions.
+pub struct SecretRestriction {
+  /// The components this restriction applies to
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub components: Vec<String>,
+  /// The provider to allow
+  pub provider: SecretProvider,
+  /// The environment variables or commands to allow
+  pub allow: LiquidTemplate,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 /// A configuration for a Wick Component
@@ -969,6 +1462,48 @@ pub enum ComponentKind {
   /// A variant representing a [HttpClientComponent] type.
   #[serde(rename = "wick/component/http@v1")]
   HttpClientComponent(HttpClientComponent),
+  /// A variant representing a [ArchiveComponent] type.
+  #[serde(rename = "wick/component/archive@v1")]
+  ArchiveComponent(ArchiveComponent),
+  /// A variant representing a [TextComponent] type.
+  #[serde(rename = "wick/component/text@v1")]
+  TextComponent(TextComponent),
+  /// A variant representing a [UnitsComponent] type.
+  #[serde(rename = "wick/component/units@v1")]
+  UnitsComponent(UnitsComponent),
+  /// A variant representing a [ValidateComponent] type.
+  #[serde(rename = "wick/component/validate@v1")]
+  ValidateComponent(ValidateComponent),
+  /// A variant representing a [GenerateComponent] type.
+  #[serde(rename = "wick/component/generate@v1")]
+  GenerateComponent(GenerateComponent),
+  /// A variant representing a [FlagsComponent] type.
+  #[serde(rename = "wick/component/flags@v1")]
+  FlagsComponent(FlagsComponent),
+  /// A variant representing a [I18nComponent] type.
+  #[serde(rename = "wick/component/i18n@v1")]
+  I18nComponent(I18nComponent),
+  /// A variant representing a [MetricsComponent] type.
+  #[serde(rename = "wick/component/metrics@v1")]
+  MetricsComponent(MetricsComponent),
+  /// A variant representing a [BrokerComponent] type.
+  #[serde(rename = "wick/component/broker@v1")]
+  BrokerComponent(BrokerComponent),
+  /// A variant representing a [CacheComponent] type.
+  #[serde(rename = "wick/component/cache@v1")]
+  CacheComponent(CacheComponent),
+  /// A variant representing a [ObjectStoreComponent] type.
+  #[serde(rename = "wick/component/object-store@v1")]
+  ObjectStoreComponent(ObjectStoreComponent),
+  /// A variant representing a [FilesystemComponent] type.
+  #[serde(rename = "wick/component/filesystem@v1")]
+  FilesystemComponent(FilesystemComponent),
+  /// A variant representing a [DataComponent] type.
+  #[serde(rename = "wick/component/data@v1")]
+  DataComponent(DataComponent),
+  /// A variant representing a [CryptoComponent] type.
+  #[serde(rename = "wick/component/crypto@v1")]
+  CryptoComponent(CryptoComponent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -982,12 +1517,57 @@ pub enum ImportDefinition {
   /// A variant representing a [ManifestComponent] type.
   #[serde(rename = "wick/component/manifest@v1")]
   ManifestComponent(ManifestComponent),
+  /// A variant representing a [GrpcUrlComponent] type.
+  #[serde(rename = "wick/component/grpc@v1")]
+  GrpcUrlComponent(GrpcUrlComponent),
   /// A variant representing a [SqlComponent] type.
   #[serde(rename = "wick/component/sql@v1")]
   SqlComponent(SqlComponent),
   /// A variant representing a [HttpClientComponent] type.
   #[serde(rename = "wick/component/http@v1")]
   HttpClientComponent(HttpClientComponent),
+  /// A variant representing a [ArchiveComponent] type.
+  #[serde(rename = "wick/component/archive@v1")]
+  ArchiveComponent(ArchiveComponent),
+  /// A variant representing a [TextComponent] type.
+  #[serde(rename = "wick/component/text@v1")]
+  TextComponent(TextComponent),
+  /// A variant representing a [UnitsComponent] type.
+  #[serde(rename = "wick/component/units@v1")]
+  UnitsComponent(UnitsComponent),
+  /// A variant representing a [ValidateComponent] type.
+  #[serde(rename = "wick/component/validate@v1")]
+  ValidateComponent(ValidateComponent),
+  /// A variant representing a [GenerateComponent] type.
+  #[serde(rename = "wick/component/generate@v1")]
+  GenerateComponent(GenerateComponent),
+  /// A variant representing a [FlagsComponent] type.
+  #[serde(rename = "wick/component/flags@v1")]
+  FlagsComponent(FlagsComponent),
+  /// A variant representing a [I18nComponent] type.
+  #[serde(rename = "wick/component/i18n@v1")]
+  I18nComponent(I18nComponent),
+  /// A variant representing a [MetricsComponent] type.
+  #[serde(rename = "wick/component/metrics@v1")]
+  MetricsComponent(MetricsComponent),
+  /// A variant representing a [BrokerComponent] type.
+  #[serde(rename = "wick/component/broker@v1")]
+  BrokerComponent(BrokerComponent),
+  /// A variant representing a [CacheComponent] type.
+  #[serde(rename = "wick/component/cache@v1")]
+  CacheComponent(CacheComponent),
+  /// A variant representing a [ObjectStoreComponent] type.
+  #[serde(rename = "wick/component/object-store@v1")]
+  ObjectStoreComponent(ObjectStoreComponent),
+  /// A variant representing a [FilesystemComponent] type.
+  #[serde(rename = "wick/component/filesystem@v1")]
+  FilesystemComponent(FilesystemComponent),
+  /// A variant representing a [DataComponent] type.
+  #[serde(rename = "wick/component/data@v1")]
+  DataComponent(DataComponent),
+  /// A variant representing a [CryptoComponent] type.
+  #[serde(rename = "wick/component/crypto@v1")]
+  CryptoComponent(CryptoComponent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1010,6 +1590,48 @@ pub enum ComponentDefinition {
   /// A variant representing a [HttpClientComponent] type.
   #[serde(rename = "wick/component/http@v1")]
   HttpClientComponent(HttpClientComponent),
+  /// A variant representing a [ArchiveComponent] type.
+  #[serde(rename = "wick/component/archive@v1")]
+  ArchiveComponent(ArchiveComponent),
+  /// A variant representing a [TextComponent] type.
+  #[serde(rename = "wick/component/text@v1")]
+  TextComponent(TextComponent),
+  /// A variant representing a [UnitsComponent] type.
+  #[serde(rename = "wick/component/units@v1")]
+  UnitsComponent(UnitsComponent),
+  /// A variant representing a [ValidateComponent] type.
+  #[serde(rename = "wick/component/validate@v1")]
+  ValidateComponent(ValidateComponent),
+  /// A variant representing a [GenerateComponent] type.
+  #[serde(rename = "wick/component/generate@v1")]
+  GenerateComponent(GenerateComponent),
+  /// A variant representing a [FlagsComponent] type.
+  #[serde(rename = "wick/component/flags@v1")]
+  FlagsComponent(FlagsComponent),
+  /// A variant representing a [I18nComponent] type.
+  #[serde(rename = "wick/component/i18n@v1")]
+  I18nComponent(I18nComponent),
+  /// A variant representing a [MetricsComponent] type.
+  #[serde(rename = "wick/component/metrics@v1")]
+  MetricsComponent(MetricsComponent),
+  /// A variant representing a [BrokerComponent] type.
+  #[serde(rename = "wick/component/broker@v1")]
+  BrokerComponent(BrokerComponent),
+  /// A variant representing a [CacheComponent] type.
+  #[serde(rename = "wick/component/cache@v1")]
+  CacheComponent(CacheComponent),
+  /// A variant representing a [ObjectStoreComponent] type.
+  #[serde(rename = "wick/component/object-store@v1")]
+  ObjectStoreComponent(ObjectStoreComponent),
+  /// A variant representing a [FilesystemComponent] type.
+  #[serde(rename = "wick/component/filesystem@v1")]
+  FilesystemComponent(FilesystemComponent),
+  /// A variant representing a [DataComponent] type.
+  #[serde(rename = "wick/component/data@v1")]
+  DataComponent(DataComponent),
+  /// A variant representing a [CryptoComponent] type.
+  #[serde(rename = "wick/component/crypto@v1")]
+  CryptoComponent(CryptoComponent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1053,6 +1675,231 @@ pub struct HostConfig {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub rpc: Option<HttpConfig>,
+  /// Configuration for the Prometheus metrics endpoint.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metrics: Option<MetricsConfig>,
+  /// Configuration for exporting trace spans over OTLP.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub otlp: Option<OtlpConfig>,
+  /// Rules that decide which imported components the host may instantiate.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub policy: Option<ComponentPolicy>,
+  /// How the types of connected ports are checked when flows are loaded.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub type_check: Option<TypeCheckConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Configuration for the Prometheus metrics endpoint.
+pub struct MetricsConfig {
+  /// Enable/disable the &#x60;/metrics&#x60; endpoint.
+
+  #[serde(default)]
+  pub enabled: bool,
+  /// The port to bind to.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub port: Option<u16>,
+  /// The address to bind to.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub address: Option<String>,
+  /// Rules checked against operation metrics that invoke an operation when they fire. Rules are checked even when the endpoint is disabled.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub alerts: Vec<AlertRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A rule checked against the metrics of operations over a sliding window that invokes an operation when the metric crosses a threshold.
+pub struct AlertRule {
+  /// The name of the rule, passed to the alert operation.
+  pub name: String,
+  /// The operation to watch, e.g. &#x60;greet&#x60;. Watches every operation when omitted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub operation: Option<String>,
+  /// The metric to compare against the threshold.
+  pub metric: AlertMetric,
+  /// The value the metric must exceed for the rule to fire: a percentage for &#x60;ErrorRate&#x60;, milliseconds for &#x60;P99&#x60;.
+  pub threshold: f64,
+  /// The length (in seconds) of the window the metric is computed over.
+
+  #[serde(default = "ALERT_RULE_WINDOW")]
+  pub window: u64,
+  /// The operation to invoke when the rule fires, e.g. &#x60;notify&#x60; or &#x60;mailer::send&#x60;. It receives the alert as an object on its &#x60;alert&#x60; input.
+  pub invoke: String,
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn ALERT_RULE_WINDOW() -> u64 {
+  60
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A metric an alert rule can check.
+pub enum AlertMetric {
+  /// The percentage of transactions that had an error.
+  ErrorRate = 0,
+  /// The 99th percentile of transaction latency in milliseconds, estimated from the latency histogram.
+  P99 = 1,
+}
+
+impl Default for AlertMetric {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for AlertMetric {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::ErrorRate,
+      1 => Self::P99,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::ErrorRate,
+      1 => Self::P99,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Rules that decide which imported components the host may instantiate. Rules are checked in order and the first one that matches a component decides.
+pub struct ComponentPolicy {
+  /// Whether to refuse components no rule matches. Components no rule matches are instantiated when this is false.
+
+  #[serde(default)]
+  pub deny_by_default: bool,
+  /// The rules to check, in order.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub rules: Vec<PolicyRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A rule that allows or denies the imported components it matches. A rule matches a component when every criterion it sets matches, and a rule that sets none matches every component.
+pub struct PolicyRule {
+  /// Whether to instantiate the components the rule matches.
+  pub action: PolicyAction,
+  /// The OCI registry the component is pulled from, e.g. `registry.candle.dev`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub registry: Option<String>,
+  /// The digest of the component&#x27;s artifact, e.g. `sha256:4f6a...`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub digest: Option<String>,
+  /// The public key of the account that signed the component&#x27;s WebAssembly module.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub signer: Option<String>,
+  /// The namespace the component is imported as. A trailing `*` matches any namespace with that prefix.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub namespace: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// What a policy rule does with the components it matches.
+pub enum PolicyAction {
+  /// Instantiate the component.
+  Allow = 0,
+  /// Refuse to instantiate the component.
+  Deny = 1,
+}
+
+impl Default for PolicyAction {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for PolicyAction {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Allow,
+      1 => Self::Deny,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Allow,
+      1 => Self::Deny,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// How the types of connected ports are checked when flows are loaded. Integers can always be sent to wider integer and float inputs.
+pub struct TypeCheckConfig {
+  /// Whether connections between ports of incompatible types stop flows from loading. They are logged as warnings when this is false.
+
+  #[serde(default)]
+  pub strict: bool,
+  /// More conversions to allow between connected ports. Values are sent unchanged, so only allow conversions the input can decode.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub coercions: Vec<TypeCoercion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A conversion to allow from an output of one type to an input of another.
+pub struct TypeCoercion {
+  /// The type of the output.
+  pub from: TypeSignature,
+  /// The type of the input.
+  pub to: TypeSignature,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Configuration for exporting trace spans to an OpenTelemetry collector over OTLP.
+pub struct OtlpConfig {
+  /// The OTLP/gRPC endpoint of the collector, e.g. http://localhost:4317.
+  pub endpoint: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1092,15 +1939,35 @@ pub struct HttpConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
-/// A component hosted as an independent microservice.
+/// A component hosted as an independent microservice, e.g. a component served by another wick host.
 pub struct GrpcUrlComponent {
-  /// The GRPC URL to connect to.
+  /// The GRPC URL to connect to, a &#x60;unix://<path>&#x60; or &#x60;npipe://<name>&#x60; URL for a host on the same machine, or &#x60;dns+srv://<name>&#x60; or &#x60;mdns://<service>&#x60; to balance invocations across the hosts found in the SRV records of a name or advertised over multicast DNS.
   pub url: String,
   /// Any configuration necessary for the component.
 
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub with: Option<HashMap<String, liquid_json::LiquidJsonValue>>,
+  /// Path to the certificate (PEM) to identify this runtime to the remote host with, for mutual TLS.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pem: Option<crate::v1::helpers::LocationReference>,
+  /// Path to the private key (PEM) for &#x60;pem&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub key: Option<crate::v1::helpers::LocationReference>,
+  /// Path to the certificate authority (PEM) to verify the remote host with.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub ca: Option<crate::v1::helpers::LocationReference>,
+  /// The domain name to verify the remote host&#x27;s certificate against. Defaults to the host in &#x60;url&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub domain: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1127,20 +1994,139 @@ pub struct ManifestComponent {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub max_packet_size: Option<u32>,
+  /// Resource limits for the instances of the component, when it&#x27;s a WebAssembly component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limits: Option<WasmLimits>,
+  /// Pool instances of the component, when it&#x27;s a WebAssembly component, so concurrent invocations don&#x27;t wait on one instance.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pool: Option<WasmPool>,
+  /// Run the component in a separate, supervised process instead of in the runtime&#x27;s own.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub process: Option<ProcessHost>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
-/// Composite operations are operations whose implementations come from connecting other operations into a flow or series of pipelines.
-pub struct CompositeOperationDefinition {
-  /// The name of the operation.
+/// Resource limits enforced on the instances of a WebAssembly component.
+pub struct WasmLimits {
+  /// The most linear memory, in bytes, each instance of the component can grow to.
 
   #[serde(default)]
-  pub name: String,
-  /// Any configuration required by the operation.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_memory: Option<u64>,
+  /// The most instances of the component that can exist at once.
 
   #[serde(default)]
-  #[serde(skip_serializing_if = "Vec::is_empty")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_instances: Option<u32>,
+  /// How long, in milliseconds, an invocation of the component can run before it&#x27;s cancelled.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub timeout: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A pool of ready instances of a WebAssembly component, each checked out by one invocation at a time.
+pub struct WasmPool {
+  /// The instances of the component to create when the application starts and keep ready. Defaults to one.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub min_instances: Option<u32>,
+  /// The most instances of the component to pool. Invocations wait for an instance to free up once this many are busy. Defaults to &#x60;min_instances&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_instances: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Settings for running a component in a separate process that the runtime starts, talks to over gRPC, and restarts when it exits.
+pub struct ProcessHost {
+  /// When to restart the process after it exits.
+
+  #[serde(default)]
+  pub restart: RestartPolicy,
+  /// The most times to restart the process. Invocations of the component fail once it has exited more often than this. Defaults to five.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_restarts: Option<u32>,
+  /// How long, in milliseconds, to wait for the process to start serving the component. Defaults to ten seconds.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub startup_timeout: Option<u64>,
+  /// The wick executable to run the component with. Defaults to the executable running the application.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub command: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// When the runtime restarts a component's process after it exits.
+pub enum RestartPolicy {
+  /// Restart the process when it exits with an error or is killed.
+  OnFailure = 0,
+  /// Restart the process whenever it exits.
+  Always = 1,
+  /// Leave the process stopped after it exits. Invocations of the component fail from then on.
+  Never = 2,
+}
+
+impl Default for RestartPolicy {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for RestartPolicy {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::OnFailure,
+      1 => Self::Always,
+      2 => Self::Never,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::OnFailure,
+      1 => Self::Always,
+      2 => Self::Never,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Composite operations are operations whose implementations come from connecting other operations into a flow or series of pipelines.
+pub struct CompositeOperationDefinition {
+  /// The name of the operation.
+
+  #[serde(default)]
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
   pub with: Vec<Field>,
   /// Types of the inputs to the operation.
 
@@ -1168,6 +2154,11 @@ pub struct CompositeOperationDefinition {
   #[serde(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub operations: Vec<CompositeOperationDefinition>,
+  /// The relative cost of one invocation of the operation, in whatever units the platform running it charges by. The runtime adds up the costs of the operations each transaction invokes.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cost: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1192,8 +2183,7 @@ pub struct BlockExpression {
   pub expressions: Vec<FlowExpression>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(into = "String")]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 /// A connection between Operations and their ports. This can be specified in short-form syntax.
 pub struct ConnectionDefinition {
@@ -1201,6 +2191,16 @@ pub struct ConnectionDefinition {
   pub from: ConnectionTargetDefinition,
   /// A downstream operation&#x27;s input.
   pub to: ConnectionTargetDefinition,
+  /// How packets from this and other connections into the same input are merged. Connections into one input must not set different strategies.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub merge: Option<MergeStrategy>,
+  /// A structured JSON liquid template that reshapes each packet&#x27;s value before it&#x27;s delivered, with the value available as `input`, e.g. `{ name: &quot;{{ input.first_name }}&quot; }`. Signals and errors pass through untouched.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub transform: Option<liquid_json::LiquidJsonValue>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1221,6 +2221,52 @@ pub struct ConnectionTargetDefinition {
   pub data: Option<HashMap<String, liquid_json::LiquidJsonValue>>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// How the packets of several connections into the same input are merged. Without a strategy, packets are delivered in the order they arrive.
+pub enum MergeStrategy {
+  /// Every packet from the first connection, then every packet from the next, in the order the connections are declared.
+  Ordered = 0,
+  /// One packet from each connection in turn, skipping connections that have finished.
+  RoundRobin = 1,
+  /// The nth packet from each connection together, in declaration order. Stops when any connection finishes and drops the unmatched packets of the others.
+  Zip = 2,
+  /// Packets waiting to be delivered are taken from earlier-declared connections first. Nothing is held back for a connection that hasn&#x27;t sent anything.
+  Priority = 3,
+}
+
+impl Default for MergeStrategy {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for MergeStrategy {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Ordered,
+      1 => Self::RoundRobin,
+      2 => Self::Zip,
+      3 => Self::Priority,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Ordered,
+      1 => Self::RoundRobin,
+      2 => Self::Zip,
+      3 => Self::Priority,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 /// An operation name and its input and output signatures
@@ -1244,6 +2290,11 @@ pub struct OperationDefinition {
   #[serde(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub outputs: Vec<Field>,
+  /// The relative cost of one invocation of the operation, in whatever units the platform running it charges by. The runtime adds up the costs of the operations each transaction invokes.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cost: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1261,6 +2312,16 @@ pub struct Field {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub description: Option<String>,
+  /// The value an input receives when its upstream finishes without sending any data.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default: Option<Value>,
+  /// The media type of the field&#x27;s data when it isn&#x27;t JSON, like `text/html` or `image/png`. HTTP routers send an output with a media type as the response body with that Content-Type.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub content_type: Option<String>,
 }
 
 #[derive(Debug, Clone, serde_with::DeserializeFromStr, Serialize, PartialEq)]
@@ -1325,6 +2386,9 @@ pub enum TypeSignature {
   /// A variant representing a [Object] type.
   #[serde(rename = "Object")]
   Object(Object),
+  /// A variant representing a [AnonymousStruct] type.
+  #[serde(rename = "AnonymousStruct")]
+  AnonymousStruct(AnonymousStruct),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1418,6 +2482,17 @@ pub struct Map {
 #[serde(deny_unknown_fields)]
 pub struct Object;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An inline struct of named fields.
+pub struct AnonymousStruct {
+  /// The fields in this struct.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub fields: Vec<Field>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 #[serde(tag = "kind")]
@@ -1540,6 +2615,11 @@ pub struct OperationInstance {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub timeout: Option<u64>,
+  /// How to retry the operation when an invocation fails.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub retry: Option<RetryPolicy>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1832,6 +2912,21 @@ pub struct SqlComponent {
   #[serde(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub operations: Vec<SqlQueryKind>,
+  /// Schema changes to apply when the component starts, in order. Each is applied once per database.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub migrations: Vec<SqlMigration>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A named schema change to apply to a SQL database.
+pub struct SqlMigration {
+  /// A unique name for the migration, recorded in the database once it&#x27;s applied.
+  pub name: String,
+  /// The SQL statements to run.
+  pub sql: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1879,6 +2974,11 @@ pub struct SqlQueryOperationDefinition {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub on_error: Option<ErrorBehavior>,
+  /// How the query takes part in a database transaction shared by the operations called in the same flow transaction.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub transaction: Option<SqlTransaction>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1914,6 +3014,62 @@ pub struct SqlExecOperationDefinition {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub on_error: Option<ErrorBehavior>,
+  /// How the query takes part in a database transaction shared by the operations called in the same flow transaction.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub transaction: Option<SqlTransaction>,
+  /// The number of streamed inputs to execute the prepared statement for together in one database transaction. Defaults to 1.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub batch: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// How an operation takes part in a database transaction shared by the operations called in the same flow transaction.
+pub enum SqlTransaction {
+  /// Run in the shared transaction if one is open, otherwise run on its own.
+  Join = 0,
+  /// Open the shared transaction and run in it.
+  Begin = 1,
+  /// Run in the shared transaction and commit it.
+  Commit = 2,
+  /// Run in the shared transaction and roll it back.
+  Rollback = 3,
+}
+
+impl Default for SqlTransaction {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for SqlTransaction {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Join,
+      1 => Self::Begin,
+      2 => Self::Commit,
+      3 => Self::Rollback,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Join,
+      1 => Self::Begin,
+      2 => Self::Commit,
+      3 => Self::Rollback,
+      _ => {
+        return None;
+      }
+    })
+  }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
@@ -1979,6 +3135,16 @@ pub struct HttpClientComponent {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub timeout: Option<u16>,
+  /// The maximum number of redirects to follow. Use 0 to not follow redirects. Defaults to 10.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub redirects: Option<u32>,
+  /// Connection pooling and keep-alive settings for outbound requests.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pool: Option<ConnectionPool>,
   /// Configuration necessary to provide when instantiating the component.
 
   #[serde(default)]
@@ -2010,6 +3176,31 @@ pub struct Proxy {
   pub password: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Connection pooling and keep-alive settings for outbound HTTP requests.
+pub struct ConnectionPool {
+  /// The maximum number of idle connections to keep open to each host.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_idle_per_host: Option<u32>,
+  /// How long in seconds an idle connection is kept open before it is closed.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub idle_timeout: Option<u64>,
+  /// The interval in seconds between TCP keep-alive probes on open connections.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub keep_alive: Option<u64>,
+  /// Send every request with HTTP/2 without negotiating it first, so concurrent requests to the same host share one connection. Hosts that only speak HTTP/1.1 will fail. HTTPS hosts that support HTTP/2 negotiate it without this.
+
+  #[serde(default)]
+  pub http2_prior_knowledge: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 /// A dynamic operation whose implementation is an HTTP request. The outputs of HttpClientOperationDefinition are always `response` & `body`
@@ -2047,35 +3238,83 @@ pub struct HttpClientOperationDefinition {
 
   #[serde(default)]
   pub path: String,
+  /// Timeout (in milliseconds) for the whole request, including reading the response body.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub timeout: Option<u64>,
+  /// The maximum number of redirects to follow, overriding the component's.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub redirects: Option<u32>,
+  /// The proxy HTTP / HTTPS to use, overriding the component's.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub proxy: Option<Proxy>,
+  /// An input whose packets are sent as the request body as they arrive, in place of `body`. Operations with a streamed body send one request per invocation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub body_stream: Option<String>,
+  /// Send the response body as `bytes` packets as it arrives instead of decoding it with the codec.
+
+  #[serde(default)]
+  pub stream_response: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component whose operations unpack tar and zip archives into entries and pack entries into archives.
+pub struct ArchiveComponent {
+  /// The format `pack` writes archives in. `unpack` detects the format of its input.
+
+  #[serde(default)]
+  pub format: ArchiveFormat,
+  /// The largest uncompressed size in bytes of a single entry. Defaults to 64 MiB.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_entry_size: Option<u64>,
+  /// The largest uncompressed size in bytes of all entries of an archive together. Defaults to 256 MiB.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_total_size: Option<u64>,
+  /// The largest number of entries an archive may contain. Defaults to 10000.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_entries: Option<u32>,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
 #[serde(deny_unknown_fields)]
-/// Codec to use when encoding/decoding data.
-pub enum Codec {
-  /// JSON data
-  Json = 0,
-  /// Raw bytes
-  Raw = 1,
-  /// Form Data
-  FormData = 2,
-  /// Raw text
-  Text = 3,
+/// Archive formats supported by the archive component.
+pub enum ArchiveFormat {
+  /// An uncompressed tar archive.
+  Tar = 0,
+  /// A zip archive with deflate-compressed entries.
+  Zip = 1,
 }
 
-impl Default for Codec {
+impl Default for ArchiveFormat {
   fn default() -> Self {
     Self::from_u16(0).unwrap()
   }
 }
 
-impl FromPrimitive for Codec {
+impl FromPrimitive for ArchiveFormat {
   fn from_i64(n: i64) -> Option<Self> {
     Some(match n {
-      0 => Self::Json,
-      1 => Self::Raw,
-      2 => Self::FormData,
-      3 => Self::Text,
+      0 => Self::Tar,
+      1 => Self::Zip,
       _ => {
         return None;
       }
@@ -2084,10 +3323,1550 @@ impl FromPrimitive for Codec {
 
   fn from_u64(n: u64) -> Option<Self> {
     Some(match n {
-      0 => Self::Json,
-      1 => Self::Raw,
-      2 => Self::FormData,
-      3 => Self::Text,
+      0 => Self::Tar,
+      1 => Self::Zip,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component whose operations match, replace, split, join, template, and diff strings.
+pub struct TextComponent {
+  /// The Volume resource `template` operations read template files from. Every file in it can be included in a template as a partial by its path, e.g. `{% include &#x27;partials/header.liquid&#x27; %}`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub templates: Option<BoundIdentifier>,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<TextOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a text component. Its patterns and templates are compiled once, when the component is created.
+pub struct TextOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// What the operation does with its input.
+  pub kind: TextOperationKind,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// Types of the inputs to a `template` operation. Each input is available to the template by name.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub inputs: Vec<Field>,
+  /// The regular expression `match`, `replace`, and `captures` operations use. `split` splits on it when set.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pattern: Option<String>,
+  /// What `replace` substitutes for each match. It may refer to capture groups as `$1` or `$name`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub replacement: Option<String>,
+  /// The string `split` splits on and `join` joins with when no pattern is set. Defaults to a newline.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub separator: Option<String>,
+  /// The liquid template a `template` operation renders.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template: Option<String>,
+  /// The path of a liquid template in the component&#x27;s `templates` volume for a `template` operation to render instead of `template`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<String>,
+  /// The number of unchanged lines `diff` shows around each change. Defaults to 3.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub context: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The kinds of operation a text component can expose.
+pub enum TextOperationKind {
+  /// Output whether the pattern matches each input string.
+  Match = 0,
+  /// Output each input string with every match of the pattern replaced.
+  Replace = 1,
+  /// Output the capture groups of every match of the pattern in each input string, bracketed per input.
+  Captures = 2,
+  /// Output the pieces of each input string, bracketed per input. Splits into lines by default.
+  Split = 3,
+  /// Output each bracketed group of input strings, or every string when there are no brackets, joined together.
+  Join = 4,
+  /// Output the template rendered with one value from each input.
+  Template = 5,
+  /// Output a unified diff of each pair of `left` and `right` strings.
+  Diff = 6,
+}
+
+impl Default for TextOperationKind {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for TextOperationKind {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Match,
+      1 => Self::Replace,
+      2 => Self::Captures,
+      3 => Self::Split,
+      4 => Self::Join,
+      5 => Self::Template,
+      6 => Self::Diff,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Match,
+      1 => Self::Replace,
+      2 => Self::Captures,
+      3 => Self::Split,
+      4 => Self::Join,
+      5 => Self::Template,
+      6 => Self::Diff,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component for currency-safe money arithmetic and exact unit conversions. Money is an object with an integer `amount` in the currency&#x27;s minor units (e.g. cents) and an ISO 4217 `currency` code.
+pub struct UnitsComponent {
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<UnitsOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a units component. Its settings are checked once, when the component is created.
+pub struct UnitsOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// What the operation does with its input.
+  pub kind: UnitsOperationKind,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// How results that fall between two minor units (or between two values at `precision`) are rounded. Defaults to `HalfEven`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub rounding: Option<RoundingMode>,
+  /// The shares an `allocate` operation splits each amount into, e.g. `[1, 1, 1]` for thirds.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub ratios: Vec<u32>,
+  /// The ISO 4217 code of the currency a `parse` operation produces.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  /// The unit a `convert` operation converts from, e.g. `mi`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub from: Option<String>,
+  /// The unit a `convert` operation converts to, e.g. `km`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub to: Option<String>,
+  /// The number of decimal places `convert` rounds its results to. Defaults to 10.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub precision: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The kinds of operation a units component can expose.
+pub enum UnitsOperationKind {
+  /// Output the sum of each pair of `left` and `right` amounts, which must share a currency.
+  Add = 0,
+  /// Output each `left` amount minus its paired `right` amount, which must share a currency.
+  Subtract = 1,
+  /// Output each `money` amount multiplied by its paired decimal `factor`, rounded to a whole minor unit.
+  Multiply = 2,
+  /// Output a list of amounts that split each input amount by `ratios` without losing or creating a minor unit.
+  Allocate = 3,
+  /// Output the amount in `currency` for each decimal string or number, e.g. `12.34`.
+  Parse = 4,
+  /// Output each amount as a decimal string in its currency&#x27;s major units, e.g. `12.34`.
+  Format = 5,
+  /// Output each number converted from the `from` unit to the `to` unit.
+  Convert = 6,
+}
+
+impl Default for UnitsOperationKind {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for UnitsOperationKind {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Add,
+      1 => Self::Subtract,
+      2 => Self::Multiply,
+      3 => Self::Allocate,
+      4 => Self::Parse,
+      5 => Self::Format,
+      6 => Self::Convert,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Add,
+      1 => Self::Subtract,
+      2 => Self::Multiply,
+      3 => Self::Allocate,
+      4 => Self::Parse,
+      5 => Self::Format,
+      6 => Self::Convert,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// How a value between two representable values is rounded.
+pub enum RoundingMode {
+  /// To the nearest value, and to the even one when exactly halfway (banker&#x27;s rounding).
+  HalfEven = 0,
+  /// To the nearest value, and away from zero when exactly halfway.
+  HalfUp = 1,
+  /// To the nearest value, and toward zero when exactly halfway.
+  HalfDown = 2,
+  /// Away from zero.
+  Up = 3,
+  /// Toward zero.
+  Down = 4,
+  /// Toward positive infinity.
+  Ceiling = 5,
+  /// Toward negative infinity.
+  Floor = 6,
+}
+
+impl Default for RoundingMode {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for RoundingMode {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::HalfEven,
+      1 => Self::HalfUp,
+      2 => Self::HalfDown,
+      3 => Self::Up,
+      4 => Self::Down,
+      5 => Self::Ceiling,
+      6 => Self::Floor,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::HalfEven,
+      1 => Self::HalfUp,
+      2 => Self::HalfDown,
+      3 => Self::Up,
+      4 => Self::Down,
+      5 => Self::Ceiling,
+      6 => Self::Floor,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component that validates and normalizes emails, URLs, and phone numbers. Each operation outputs an object with `valid`, the `normalized` value when valid, and a `reason` when not.
+pub struct ValidateComponent {
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<ValidateOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a validation component. It takes strings on its `input` port.
+pub struct ValidateOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// What the operation validates.
+  pub kind: ValidateOperationKind,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// The schemes a `url` operation accepts. Defaults to `http` and `https`.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub schemes: Vec<String>,
+  /// The calling code, e.g. `1` or `44`, that a `phone` operation assumes for numbers without a leading `+` or `00`. Without it, such numbers are invalid.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub country_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The kinds of operation a validation component can expose.
+pub enum ValidateOperationKind {
+  /// Validate email addresses. The domain is lowercased and converted to punycode; the local part is kept as written.
+  Email = 0,
+  /// Validate absolute URLs against the allowed `schemes`. The scheme and host are lowercased and default ports removed.
+  Url = 1,
+  /// Validate phone numbers and normalize them to E.164, e.g. `+14155552671`.
+  Phone = 2,
+}
+
+impl Default for ValidateOperationKind {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for ValidateOperationKind {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Email,
+      1 => Self::Url,
+      2 => Self::Phone,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Email,
+      1 => Self::Url,
+      2 => Self::Phone,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component that generates records conforming to a type from the manifest&#x27;s `types`, for benchmarks, demos, and tests that need realistic inputs.
+pub struct GenerateComponent {
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<GenerateOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a generate component. It takes no inputs and emits `count` records on its `output` port.
+pub struct GenerateOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// The name of the type, from the manifest&#x27;s `types`, that each record conforms to.
+
+  #[serde(rename = "type")]
+  pub ty: String,
+  /// How many records to generate. Defaults to 10.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub count: Option<u32>,
+  /// The seed for generating records. The same seed always generates the same records. Without it, records differ between invocations.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub seed: Option<u64>,
+  /// How to generate specific fields. Fields not listed here are generated from their type and name, e.g. a string field named `email` gets email addresses.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub fields: Vec<GenerateField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// How to generate the values of one field of a record.
+pub struct GenerateField {
+  /// The name of the field.
+  pub name: String,
+  /// How the field&#x27;s values are generated.
+  pub strategy: GenerateStrategy,
+  /// The smallest value an `Integer`, `Float`, or `Sequence` field generates. Defaults to 0.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub min: Option<f64>,
+  /// The largest value an `Integer` or `Float` field generates. Defaults to 1000.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max: Option<f64>,
+  /// The values a `OneOf` field picks from.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub values: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The ways a generated field can get its values.
+pub enum GenerateStrategy {
+  /// A first name, e.g. `Maria`.
+  FirstName = 0,
+  /// A last name, e.g. `Okafor`.
+  LastName = 1,
+  /// A first and last name.
+  FullName = 2,
+  /// A lowercase username, e.g. `maria.okafor42`.
+  Username = 3,
+  /// An email address at a reserved example domain.
+  Email = 4,
+  /// A phone number in E.164 form from the fictional 555-01XX range.
+  Phone = 5,
+  /// A company name.
+  Company = 6,
+  /// A city name.
+  City = 7,
+  /// A country name.
+  Country = 8,
+  /// A single lorem ipsum word.
+  Word = 9,
+  /// A lorem ipsum sentence.
+  Sentence = 10,
+  /// A version 4 UUID.
+  Uuid = 11,
+  /// A whole number between `min` and `max`.
+  Integer = 12,
+  /// A number between `min` and `max`.
+  Float = 13,
+  /// true or false.
+  Bool = 14,
+  /// An RFC 3339 timestamp between 2000 and 2030.
+  Datetime = 15,
+  /// Consecutive whole numbers starting at `min`, e.g. for ids.
+  Sequence = 16,
+  /// One of the configured `values`.
+  OneOf = 17,
+}
+
+impl Default for GenerateStrategy {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for GenerateStrategy {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::FirstName,
+      1 => Self::LastName,
+      2 => Self::FullName,
+      3 => Self::Username,
+      4 => Self::Email,
+      5 => Self::Phone,
+      6 => Self::Company,
+      7 => Self::City,
+      8 => Self::Country,
+      9 => Self::Word,
+      10 => Self::Sentence,
+      11 => Self::Uuid,
+      12 => Self::Integer,
+      13 => Self::Float,
+      14 => Self::Bool,
+      15 => Self::Datetime,
+      16 => Self::Sequence,
+      17 => Self::OneOf,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::FirstName,
+      1 => Self::LastName,
+      2 => Self::FullName,
+      3 => Self::Username,
+      4 => Self::Email,
+      5 => Self::Phone,
+      6 => Self::Company,
+      7 => Self::City,
+      8 => Self::Country,
+      9 => Self::Word,
+      10 => Self::Sentence,
+      11 => Self::Uuid,
+      12 => Self::Integer,
+      13 => Self::Float,
+      14 => Self::Bool,
+      15 => Self::Datetime,
+      16 => Self::Sequence,
+      17 => Self::OneOf,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component that evaluates feature flags from a local JSON or YAML rules file, with percentage rollouts and targeting on attributes of each invocation. Operations output whether their flag is `enabled`, which flows can route on with `core::switch`.
+pub struct FlagsComponent {
+  /// The URL resource of the rules file. Only &#x60;file://&#x60; URLs are supported.
+  pub resource: BoundIdentifier,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<FlagsOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a flags component. It takes an object of attributes to target, like a user&#x27;s `key` and `country`, on its `context` port and outputs the evaluation of its flag on its `enabled` and `reason` ports.
+pub struct FlagsOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// The name of the flag, from the rules file, that the operation evaluates.
+  pub flag: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component that translates messages from Fluent (`.ftl`) or gettext (`.po`) catalogs in a volume. Each directory at the root of the volume holds the catalogs of one locale, e.g. `en-US/main.ftl` or `fr/LC_MESSAGES/app.po`.
+pub struct I18nComponent {
+  /// The volume resource holding the catalogs.
+  pub resource: BoundIdentifier,
+  /// The locale to translate to when a message is missing from the requested locale and its language.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fallback: Option<String>,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<I18nOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of an i18n component. It takes the `key` of a message, the `locale` to translate it to, and an object of `args` to format it with, and outputs the translated message on its `output` port.
+pub struct I18nOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component that ships metric samples to a Prometheus remote-write endpoint or a statsd server, so flows can forward the metrics they derive without a custom exporter.
+pub struct MetricsComponent {
+  /// The URL resource to send samples to: an &#x60;http(s)&#x60; remote-write endpoint or a &#x60;udp://host:port&#x60; statsd server.
+  pub resource: BoundIdentifier,
+  /// The protocol to send samples with.
+
+  #[serde(default)]
+  pub protocol: MetricsProtocol,
+  /// Labels added to every sample, e.g. &#x60;job: ingest&#x60;. A sample&#x27;s own labels take precedence.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  #[serde(deserialize_with = "crate::helpers::kv_deserializer")]
+  pub labels: HashMap<String, String>,
+  /// The most samples to send at once. Defaults to 500.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub batch_size: Option<u32>,
+  /// How often, in milliseconds, to send the samples collected so far. Defaults to 1000.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub flush_interval: Option<u64>,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<MetricsOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a metrics component. It takes a `sample` object of a `name`, a numeric `value`, and optionally `labels` and a `timestamp` in milliseconds, and outputs the sample as it was queued on its `output` port.
+pub struct MetricsOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// The kind of metric the operation&#x27;s samples are.
+
+  #[serde(default)]
+  pub metric: MetricKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component whose operations publish events to the topics of a broker resource, for broker triggers in the same process to pick up.
+pub struct BrokerComponent {
+  /// The Broker resource to publish to.
+  pub resource: BoundIdentifier,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<BrokerOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a broker component. Each set of values it receives, one on each of its inputs, is published as an event with a field for each input. It outputs how many subscribers each event reached on its `delivered` port.
+pub struct BrokerOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// The topic to publish events to.
+  pub topic: String,
+  /// The fields of the events the operation publishes. Each value is checked against its field&#x27;s type before it&#x27;s published.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub inputs: Vec<Field>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component that stores the outputs of other operations under keys made from their inputs, and computes them with those operations only when they&#x27;re missing or expired. Concurrent misses for the same key share one computation.
+pub struct CacheComponent {
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<CacheOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a cache component. Each set of values it receives, one on each of its inputs, is looked up by its key. Stored outputs are sent as they were stored; missing ones are computed by sending the inputs to the linked operation.
+pub struct CacheOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// The inputs of the operation, passed on to the linked operation on a miss.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub inputs: Vec<Field>,
+  /// The outputs of the operation, the same as the linked operation&#x27;s.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub outputs: Vec<Field>,
+  /// A liquid template rendered with the inputs to make the key outputs are stored under, e.g. &#x60;user:{{ id }}&#x60;.
+  pub key: String,
+  /// The operation to compute missing outputs with, e.g. &#x60;users::lookup&#x60;. The component must be in the cache&#x27;s scope: one its manifest requires or, when the cache is imported inline, one imported alongside it. Errors are passed on and never stored.
+  pub compute: String,
+  /// How long (in milliseconds) stored outputs are fresh.
+
+  #[serde(default = "CACHE_OPERATION_DEFINITION_TTL")]
+  pub ttl: u64,
+  /// How long (in milliseconds) after they expire stored outputs are still sent while they&#x27;re recomputed in the background.
+
+  #[serde(default)]
+  pub stale: u64,
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn CACHE_OPERATION_DEFINITION_TTL() -> u64 {
+  60000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component whose operations read, write, list, and sign URLs for the objects of a bucket in Amazon S3 or an S3-compatible store like MinIO or Cloudflare R2. Requests are signed with AWS Signature Version 4.
+pub struct ObjectStoreComponent {
+  /// The URL resource of the store&#x27;s endpoint, e.g. &#x60;https://s3.us-east-1.amazonaws.com&#x60; or &#x60;http://localhost:9000&#x60;.
+  pub resource: BoundIdentifier,
+  /// The bucket the operations work on.
+  pub bucket: String,
+  /// The region requests are signed for. Defaults to &#x60;us-east-1&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub region: Option<String>,
+  /// The Secret resource holding the access key ID.
+  pub access_key_id: BoundIdentifier,
+  /// The Secret resource holding the secret access key.
+  pub secret_access_key: BoundIdentifier,
+  /// The Secret resource holding a session token, for temporary credentials.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub session_token: Option<BoundIdentifier>,
+  /// Address the bucket as the first segment of the path, e.g. &#x60;http://localhost:9000/bucket/key&#x60;, instead of as a subdomain of the endpoint. Most S3-compatible stores need this.
+
+  #[serde(default)]
+  pub path_style: bool,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<ObjectStoreOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of an object store component. What it takes and outputs depends on its action.
+pub struct ObjectStoreOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// What the operation does with the objects it&#x27;s given.
+  pub action: ObjectStoreAction,
+  /// For &#x60;presign&#x60;, the method the URL is signed for. Defaults to &#x60;Get&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub method: Option<HttpMethod>,
+  /// For &#x60;presign&#x60;, how long (in seconds) the URL is valid for. Defaults to 3600, at most 604800 (a week).
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires: Option<u32>,
+  /// For &#x60;list&#x60;, the most objects to ask the store for at a time. Defaults to 1000.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// What an object store operation does.
+pub enum ObjectStoreAction {
+  /// Take object keys on the `key` port and stream the bytes of each object to the `output` port as it's downloaded, wrapped in brackets.
+  Get = 0,
+  /// Take an object key on the `key` port and stream its bytes from the `body` port to the store, in parts for large objects. Outputs the `key`, `etag`, and `size` of the stored object on the `output` port.
+  Put = 1,
+  /// Take prefixes on the `prefix` port and output the `key`, `size`, `etag`, and `last_modified` of every object under each on the `output` port, following the store's pages until there are no more.
+  List = 2,
+  /// Take object keys on the `key` port and output a presigned URL for each on the `output` port, which lets anyone with it make a request for the object without credentials until it expires.
+  Presign = 3,
+}
+
+impl Default for ObjectStoreAction {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for ObjectStoreAction {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Get,
+      1 => Self::Put,
+      2 => Self::List,
+      3 => Self::Presign,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Get,
+      1 => Self::Put,
+      2 => Self::List,
+      3 => Self::Presign,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component whose operations work with the files in a Volume resource.
+pub struct FilesystemComponent {
+  /// The Volume resource the operations work in.
+  pub resource: BoundIdentifier,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<FilesystemOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a filesystem component. What it takes and outputs depends on its action.
+pub struct FilesystemOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// What the operation does with the files it&#x27;s given.
+  pub action: FilesystemAction,
+  /// For &#x60;watch&#x60;, whether to watch the files in subdirectories too.
+
+  #[serde(default)]
+  pub recursive: bool,
+  /// For &#x60;watch&#x60;, how often (in milliseconds) to check for changes. Defaults to 500.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub interval: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// What a filesystem operation does.
+pub enum FilesystemAction {
+  /// Take directories, relative to the volume, on the `path` port and output the `kind` (`create`, `modify`, or `delete`) and `path` of each change to the files in them on the `output` port, until the caller stops listening.
+  Watch = 0,
+}
+
+impl Default for FilesystemAction {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for FilesystemAction {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Watch,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Watch,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component whose operations parse CSV, NDJSON, and XML into objects and serialize objects into them, a record at a time.
+pub struct DataComponent {
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<DataOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a data component. Parse operations take chunks of data on the `input` port and output an object per record on the `output` port, serialize operations do the reverse.
+pub struct DataOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// Whether the operation parses data into objects or serializes objects into data.
+  pub action: DataAction,
+  /// The format of the data.
+  pub format: DataFormat,
+  /// For &#x60;csv&#x60;, the character that separates fields. Defaults to &#x60;,&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub delimiter: Option<String>,
+  /// For &#x60;csv&#x60;, the names of the columns of data without a header row. When omitted, parsing reads the columns from the first row and serializing writes a header row from the fields of the first object.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub columns: Vec<String>,
+  /// For &#x60;xml&#x60;, the name of the elements records are read from or written as. Defaults to &#x60;item&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub element: Option<String>,
+  /// For serializing &#x60;xml&#x60;, the name of the element records are written inside. Defaults to &#x60;items&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub root: Option<String>,
+  /// Renames fields, from their names in the data (CSV columns, NDJSON keys, or XML attributes and child elements) to their names in the objects. Serializing renames them back.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  #[serde(deserialize_with = "crate::helpers::kv_deserializer")]
+  pub mapping: HashMap<String, String>,
+  /// For parsing &#x60;csv&#x60; and &#x60;xml&#x60;, turn values that read as numbers, booleans, or &#x60;null&#x60; into them instead of keeping every value a string.
+
+  #[serde(default)]
+  pub typed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Whether a data operation parses or serializes.
+pub enum DataAction {
+  /// Take chunks of data on the `input` port and output each record in them as an object on the `output` port.
+  Parse = 0,
+  /// Take objects on the `input` port and output each as a chunk of data on the `output` port.
+  Serialize = 1,
+}
+
+impl Default for DataAction {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for DataAction {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Parse,
+      1 => Self::Serialize,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Parse,
+      1 => Self::Serialize,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The formats a data component reads and writes.
+pub enum DataFormat {
+  /// Comma-separated values, with fields quoted as RFC 4180 describes.
+  Csv = 0,
+  /// Newline-delimited JSON, an object per line.
+  Ndjson = 1,
+  /// XML, with a record per element of a name. Documents are read whole before their records are output.
+  Xml = 2,
+}
+
+impl Default for DataFormat {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for DataFormat {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Csv,
+      1 => Self::Ndjson,
+      2 => Self::Xml,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Csv,
+      1 => Self::Ndjson,
+      2 => Self::Xml,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component whose operations hash, authenticate, sign, and verify data, and issue and verify JWTs, with keys read from Secret resources.
+pub struct CryptoComponent {
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// A list of operations to expose on this component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<CryptoOperationDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An operation of a crypto component. What it takes and outputs depends on its action.
+pub struct CryptoOperationDefinition {
+  /// The name of the operation.
+  pub name: String,
+  /// Any configuration required by the operation.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+  /// What the operation does.
+  pub action: CryptoAction,
+  /// The algorithm the operation uses. &#x60;Hash&#x60; takes a SHA-2 algorithm or &#x60;Blake3&#x60;, &#x60;Hmac&#x60; takes a SHA-2 algorithm, and &#x60;Sign&#x60; takes &#x60;Ed25519&#x60;, &#x60;EcdsaP256&#x60;, or &#x60;EcdsaP384&#x60;. &#x60;Verify&#x60; and the JWT actions take any but &#x60;Blake3&#x60;, with SHA-2 algorithms meaning HMAC.
+  pub algorithm: CryptoAlgorithm,
+  /// The Secret resource holding the key: the shared secret for HMAC, a PKCS#8 private key in PEM to sign and issue tokens, or a public key in PEM to verify signatures and tokens. Every action but &#x60;Hash&#x60; needs one.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub key: Option<BoundIdentifier>,
+  /// How digests, HMACs, and signatures are encoded, both those the operation outputs and those &#x60;Verify&#x60; takes. Defaults to &#x60;Hex&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub encoding: Option<CryptoEncoding>,
+  /// The &#x60;iss&#x60; claim &#x60;JwtIssue&#x60; adds to tokens and &#x60;JwtVerify&#x60; requires of them.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub issuer: Option<String>,
+  /// The &#x60;aud&#x60; claim &#x60;JwtIssue&#x60; adds to tokens and &#x60;JwtVerify&#x60; requires of them.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub audience: Option<String>,
+  /// How many seconds tokens from &#x60;JwtIssue&#x60; are valid for. They don&#x27;t expire when this is omitted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires: Option<u64>,
+  /// How many seconds of difference between clocks &#x60;JwtVerify&#x60; allows when it checks &#x60;exp&#x60; and &#x60;nbf&#x60;. Defaults to 0.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub leeway: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// What a crypto operation does.
+pub enum CryptoAction {
+  /// Take bytes on the `input` port and output their digest on the `output` port.
+  Hash = 0,
+  /// Take bytes on the `input` port and output their HMAC with the key on the `output` port.
+  Hmac = 1,
+  /// Take bytes on the `input` port and output their signature by the private key on the `output` port.
+  Sign = 2,
+  /// Take bytes on the `input` port and a signature or HMAC of them on the `signature` port, and output whether it's valid for the key on the `output` port.
+  Verify = 3,
+  /// Take objects of claims on the `claims` port and output a JWT of each, signed with the key, on the `output` port.
+  JwtIssue = 4,
+  /// Take JWTs on the `token` port and output the claims of each on the `output` port once its signature, `exp`, `nbf`, issuer, and audience are checked. Invalid tokens are output as errors.
+  JwtVerify = 5,
+}
+
+impl Default for CryptoAction {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for CryptoAction {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Hash,
+      1 => Self::Hmac,
+      2 => Self::Sign,
+      3 => Self::Verify,
+      4 => Self::JwtIssue,
+      5 => Self::JwtVerify,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Hash,
+      1 => Self::Hmac,
+      2 => Self::Sign,
+      3 => Self::Verify,
+      4 => Self::JwtIssue,
+      5 => Self::JwtVerify,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The algorithms crypto operations use.
+pub enum CryptoAlgorithm {
+  /// SHA-256, or HMAC with it (`HS256` for JWTs).
+  Sha256 = 0,
+  /// SHA-384, or HMAC with it (`HS384` for JWTs).
+  Sha384 = 1,
+  /// SHA-512, or HMAC with it (`HS512` for JWTs).
+  Sha512 = 2,
+  /// BLAKE3, for hashing.
+  Blake3 = 3,
+  /// Ed25519 signatures (`EdDSA` for JWTs).
+  Ed25519 = 4,
+  /// ECDSA signatures on the P-256 curve with SHA-256 (`ES256` for JWTs), as the 64 bytes of `r` and `s`.
+  EcdsaP256 = 5,
+  /// ECDSA signatures on the P-384 curve with SHA-384 (`ES384` for JWTs), as the 96 bytes of `r` and `s`.
+  EcdsaP384 = 6,
+}
+
+impl Default for CryptoAlgorithm {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for CryptoAlgorithm {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Sha256,
+      1 => Self::Sha384,
+      2 => Self::Sha512,
+      3 => Self::Blake3,
+      4 => Self::Ed25519,
+      5 => Self::EcdsaP256,
+      6 => Self::EcdsaP384,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Sha256,
+      1 => Self::Sha384,
+      2 => Self::Sha512,
+      3 => Self::Blake3,
+      4 => Self::Ed25519,
+      5 => Self::EcdsaP256,
+      6 => Self::EcdsaP384,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// How crypto operations encode digests, HMACs, and signatures.
+pub enum CryptoEncoding {
+  /// Lowercase hexadecimal.
+  Hex = 0,
+  /// Standard base64, with padding.
+  Base64 = 1,
+  /// URL-safe base64, without padding.
+  Base64Url = 2,
+}
+
+impl Default for CryptoEncoding {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for CryptoEncoding {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Hex,
+      1 => Self::Base64,
+      2 => Self::Base64Url,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Hex,
+      1 => Self::Base64,
+      2 => Self::Base64Url,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Codec to use when encoding/decoding data.
+pub enum Codec {
+  /// JSON data
+  Json = 0,
+  /// Raw bytes
+  Raw = 1,
+  /// Form Data
+  FormData = 2,
+  /// Raw text
+  Text = 3,
+}
+
+impl Default for Codec {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for Codec {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Json,
+      1 => Self::Raw,
+      2 => Self::FormData,
+      3 => Self::Text,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Json,
+      1 => Self::Raw,
+      2 => Self::FormData,
+      3 => Self::Text,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// How a REST route sends an operation&#x27;s output back to the client.
+pub enum ResponseMode {
+  /// Wait for the operation to complete and respond with a single JSON object.
+  Buffered = 0,
+  /// Stream each output packet as a line of JSON using chunked transfer encoding.
+  Stream = 1,
+  /// Stream each output packet as a Server-Sent Event.
+  Sse = 2,
+}
+
+impl Default for ResponseMode {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for ResponseMode {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Buffered,
+      1 => Self::Stream,
+      2 => Self::Sse,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Buffered,
+      1 => Self::Stream,
+      2 => Self::Sse,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The format records are written to a log file in.
+pub enum LogFormat {
+  /// One JSON object per line.
+  Json = 0,
+  /// Comma-separated (or otherwise delimited) values.
+  Csv = 1,
+  /// Lines parsed with a regular expression.
+  Regex = 2,
+}
+
+impl Default for LogFormat {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for LogFormat {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Json,
+      1 => Self::Csv,
+      2 => Self::Regex,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Json,
+      1 => Self::Csv,
+      2 => Self::Regex,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The protocol a metrics component sends samples with.
+pub enum MetricsProtocol {
+  /// Snappy-compressed protobuf write requests, POSTed to a Prometheus remote-write endpoint.
+  RemoteWrite = 0,
+  /// Plain-text statsd lines sent over UDP, with labels as DogStatsD tags.
+  Statsd = 1,
+}
+
+impl Default for MetricsProtocol {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for MetricsProtocol {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::RemoteWrite,
+      1 => Self::Statsd,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::RemoteWrite,
+      1 => Self::Statsd,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The kind of metric a sample is.
+pub enum MetricKind {
+  /// A value reported as it is.
+  Gauge = 0,
+  /// A count added to the running total of its series.
+  Counter = 1,
+  /// A duration in milliseconds.
+  Timing = 2,
+}
+
+impl Default for MetricKind {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for MetricKind {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Gauge,
+      1 => Self::Counter,
+      2 => Self::Timing,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Gauge,
+      1 => Self::Counter,
+      2 => Self::Timing,
       _ => {
         return None;
       }
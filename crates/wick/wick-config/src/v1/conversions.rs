@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::Duration;
 mod impls;
 mod root_configs;
 mod triggers;
@@ -26,9 +27,12 @@ use crate::config::{
 use crate::config::{
   AppConfiguration,
   Binding,
+  BrokerTriggerConfig,
   CliConfig,
+  GrpcTriggerConfig,
   HttpRouterConfig,
   HttpTriggerConfig,
+  LogTriggerConfig,
   ProxyRouterConfig,
   RawRouterConfig,
   RestRouterConfig,
@@ -39,7 +43,7 @@ use crate::config::{
   UdpPort,
 };
 use crate::error::ManifestError;
-use crate::utils::{opt_str_to_ipv4addr, VecTryMapInto};
+use crate::utils::{opt_str_to_ipv4addr, VecMapInto, VecTryMapInto};
 use crate::{v1, Result};
 
 impl TryFrom<v1::PackageDefinition> for PackageConfig {
@@ -257,7 +261,7 @@ impl TryFrom<v1::ComponentOperationExpression> for ComponentOperationExpression
       name: literal.name,
       component: literal.component.try_into()?,
       config: literal.with.map_into(),
-      settings: literal.timeout.map(ExecutionSettings::from_timeout_millis),
+      settings: execution_settings(literal.timeout, literal.retry),
     })
   }
 }
@@ -274,6 +278,7 @@ impl TryFrom<v1::AppConfiguration> for AppConfiguration {
       import: def.import.try_map_into()?,
       resources: def.resources.try_map_into()?,
       triggers: def.triggers.into_iter().map(|v| v.try_into()).collect::<Result<_>>()?,
+      admin: def.admin.map_into(),
       cached_types: Default::default(),
       type_cache: Default::default(),
       package: def.package.try_map_into()?,
@@ -294,10 +299,29 @@ impl TryFrom<AppConfiguration> for v1::AppConfiguration {
       resources: value.resources.try_map_into()?,
       triggers: value.triggers.try_map_into()?,
       package: value.package.try_map_into()?,
+      admin: value.admin.map_into(),
     })
   }
 }
 
+impl From<v1::AdminConfig> for config::AdminConfig {
+  fn from(value: v1::AdminConfig) -> Self {
+    Self {
+      resource: value.resource.into(),
+      alerts: value.alerts.map_into(),
+    }
+  }
+}
+
+impl From<config::AdminConfig> for v1::AdminConfig {
+  fn from(value: config::AdminConfig) -> Self {
+    Self {
+      resource: value.resource.id().to_owned(),
+      alerts: value.alerts.map_into(),
+    }
+  }
+}
+
 impl TryFrom<ComponentOperationExpression> for v1::ComponentOperationExpression {
   type Error = ManifestError;
   fn try_from(value: ComponentOperationExpression) -> Result<Self> {
@@ -305,7 +329,8 @@ impl TryFrom<ComponentOperationExpression> for v1::ComponentOperationExpression
       name: value.name,
       component: value.component.try_into()?,
       with: value.config.map_into(),
-      timeout: value.settings.and_then(|t| t.timeout_millis()),
+      timeout: value.settings.as_ref().and_then(|t| t.timeout_millis()),
+      retry: value.settings.and_then(|t| t.retry).map_into(),
     })
   }
 }
@@ -318,6 +343,8 @@ impl TryFrom<config::ResourceDefinition> for v1::ResourceDefinition {
       config::ResourceDefinition::UdpPort(v) => v1::ResourceDefinition::UdpPort(v.try_into()?),
       config::ResourceDefinition::Url(v) => v1::ResourceDefinition::Url(v.try_into()?),
       config::ResourceDefinition::Volume(v) => v1::ResourceDefinition::Volume(v.try_into()?),
+      config::ResourceDefinition::Broker(v) => v1::ResourceDefinition::Broker(v.try_into()?),
+      config::ResourceDefinition::Secret(v) => v1::ResourceDefinition::Secret(v.try_into()?),
     })
   }
 }
@@ -338,6 +365,37 @@ impl TryFrom<config::Volume> for v1::Volume {
   }
 }
 
+impl TryFrom<config::BrokerResource> for v1::Broker {
+  type Error = ManifestError;
+  fn try_from(value: config::BrokerResource) -> Result<Self> {
+    Ok(Self {
+      name: value.name.unrender()?,
+    })
+  }
+}
+
+impl TryFrom<config::SecretResource> for v1::Secret {
+  type Error = ManifestError;
+  fn try_from(value: config::SecretResource) -> Result<Self> {
+    Ok(Self {
+      provider: value.provider.into(),
+      source: value.source.unrender()?,
+      refresh: value.refresh,
+    })
+  }
+}
+
+impl From<config::SecretProvider> for v1::SecretProvider {
+  fn from(value: config::SecretProvider) -> Self {
+    match value {
+      config::SecretProvider::Env => Self::Env,
+      config::SecretProvider::File => Self::File,
+      config::SecretProvider::Command => Self::Command,
+      config::SecretProvider::Backend => Self::Backend,
+    }
+  }
+}
+
 impl TryFrom<UdpPort> for v1::UdpPort {
   type Error = ManifestError;
   fn try_from(value: UdpPort) -> Result<Self> {
@@ -377,6 +435,7 @@ impl TryFrom<crate::v1::CompositeOperationDefinition> for config::FlowOperation
       expressions: expressions?,
       config: op.with.try_map_into()?,
       flows: op.operations.try_map_into()?,
+      cost: op.cost,
     })
   }
 }
@@ -390,6 +449,20 @@ impl TryFrom<v1::ComponentKind> for ComponentImplementation {
       v1::ComponentKind::HttpClientComponent(v) => ComponentImplementation::HttpClient(v.try_into()?),
       v1::ComponentKind::SqlComponent(v) => ComponentImplementation::Sql(v.try_into()?),
       v1::ComponentKind::WasmComponentModel(v) => ComponentImplementation::Wasm(v.try_into()?),
+      v1::ComponentKind::ArchiveComponent(v) => ComponentImplementation::Archive(v.try_into()?),
+      v1::ComponentKind::TextComponent(v) => ComponentImplementation::Text(v.try_into()?),
+      v1::ComponentKind::UnitsComponent(v) => ComponentImplementation::Units(v.try_into()?),
+      v1::ComponentKind::ValidateComponent(v) => ComponentImplementation::Validate(v.try_into()?),
+      v1::ComponentKind::GenerateComponent(v) => ComponentImplementation::Generate(v.try_into()?),
+      v1::ComponentKind::FlagsComponent(v) => ComponentImplementation::Flags(v.try_into()?),
+      v1::ComponentKind::I18nComponent(v) => ComponentImplementation::I18n(v.try_into()?),
+      v1::ComponentKind::MetricsComponent(v) => ComponentImplementation::Metrics(v.try_into()?),
+      v1::ComponentKind::BrokerComponent(v) => ComponentImplementation::Broker(v.try_into()?),
+      v1::ComponentKind::CacheComponent(v) => ComponentImplementation::Cache(v.try_into()?),
+      v1::ComponentKind::ObjectStoreComponent(v) => ComponentImplementation::ObjectStore(v.try_into()?),
+      v1::ComponentKind::FilesystemComponent(v) => ComponentImplementation::Filesystem(v.try_into()?),
+      v1::ComponentKind::DataComponent(v) => ComponentImplementation::Data(v.try_into()?),
+      v1::ComponentKind::CryptoComponent(v) => ComponentImplementation::Crypto(v.try_into()?),
     })
   }
 }
@@ -403,6 +476,20 @@ impl TryFrom<ComponentImplementation> for v1::ComponentKind {
       ComponentImplementation::WasmRs(v) => v1::ComponentKind::WasmComponentConfiguration(v.try_into()?),
       ComponentImplementation::Sql(v) => v1::ComponentKind::SqlComponent(v.try_into()?),
       ComponentImplementation::HttpClient(v) => v1::ComponentKind::HttpClientComponent(v.try_into()?),
+      ComponentImplementation::Archive(v) => v1::ComponentKind::ArchiveComponent(v.try_into()?),
+      ComponentImplementation::Text(v) => v1::ComponentKind::TextComponent(v.try_into()?),
+      ComponentImplementation::Units(v) => v1::ComponentKind::UnitsComponent(v.try_into()?),
+      ComponentImplementation::Validate(v) => v1::ComponentKind::ValidateComponent(v.try_into()?),
+      ComponentImplementation::Generate(v) => v1::ComponentKind::GenerateComponent(v.try_into()?),
+      ComponentImplementation::Flags(v) => v1::ComponentKind::FlagsComponent(v.try_into()?),
+      ComponentImplementation::I18n(v) => v1::ComponentKind::I18nComponent(v.try_into()?),
+      ComponentImplementation::Metrics(v) => v1::ComponentKind::MetricsComponent(v.try_into()?),
+      ComponentImplementation::Broker(v) => v1::ComponentKind::BrokerComponent(v.try_into()?),
+      ComponentImplementation::Cache(v) => v1::ComponentKind::CacheComponent(v.try_into()?),
+      ComponentImplementation::ObjectStore(v) => v1::ComponentKind::ObjectStoreComponent(v.try_into()?),
+      ComponentImplementation::Filesystem(v) => v1::ComponentKind::FilesystemComponent(v.try_into()?),
+      ComponentImplementation::Data(v) => v1::ComponentKind::DataComponent(v.try_into()?),
+      ComponentImplementation::Crypto(v) => v1::ComponentKind::CryptoComponent(v.try_into()?),
     })
   }
 }
@@ -416,6 +503,7 @@ impl TryFrom<crate::v1::OperationDefinition> for OperationDefinition {
       config: op.with.try_map_into()?,
       inputs: op.inputs.try_map_into()?,
       outputs: op.outputs.try_map_into()?,
+      cost: op.cost,
     })
   }
 }
@@ -429,6 +517,7 @@ impl TryFrom<OperationDefinition> for crate::v1::OperationDefinition {
       with: op.config.try_map_into()?,
       inputs: op.inputs.try_map_into()?,
       outputs: op.outputs.try_map_into()?,
+      cost: op.cost,
     })
   }
 }
@@ -452,11 +541,25 @@ impl TryFrom<config::ImportDefinition> for v1::ImportDefinition {
         #[allow(deprecated)]
         ComponentDefinition::Wasm(_) => unreachable!("Wasm components are not allowed in v1 imports"),
         ComponentDefinition::Reference(_) => unreachable!("Component references can't exist in v1 imports"),
-        ComponentDefinition::GrpcUrl(_) => unreachable!("GrpcUrl components are not allowed in v1 imports"),
+        ComponentDefinition::GrpcUrl(c) => v1::ImportDefinition::GrpcUrlComponent(c.try_into()?),
         ComponentDefinition::Manifest(c) => v1::ImportDefinition::ManifestComponent(c.try_into()?),
         ComponentDefinition::HighLevelComponent(c) => match c {
           HighLevelComponent::Sql(c) => v1::ImportDefinition::SqlComponent(c.try_into()?),
           HighLevelComponent::HttpClient(c) => v1::ImportDefinition::HttpClientComponent(c.try_into()?),
+          HighLevelComponent::Archive(c) => v1::ImportDefinition::ArchiveComponent(c.try_into()?),
+          HighLevelComponent::Text(c) => v1::ImportDefinition::TextComponent(c.try_into()?),
+          HighLevelComponent::Units(c) => v1::ImportDefinition::UnitsComponent(c.try_into()?),
+          HighLevelComponent::Validate(c) => v1::ImportDefinition::ValidateComponent(c.try_into()?),
+          HighLevelComponent::Generate(c) => v1::ImportDefinition::GenerateComponent(c.try_into()?),
+          HighLevelComponent::Flags(c) => v1::ImportDefinition::FlagsComponent(c.try_into()?),
+          HighLevelComponent::I18n(c) => v1::ImportDefinition::I18nComponent(c.try_into()?),
+          HighLevelComponent::Metrics(c) => v1::ImportDefinition::MetricsComponent(c.try_into()?),
+          HighLevelComponent::Broker(c) => v1::ImportDefinition::BrokerComponent(c.try_into()?),
+          HighLevelComponent::Cache(c) => v1::ImportDefinition::CacheComponent(c.try_into()?),
+          HighLevelComponent::ObjectStore(c) => v1::ImportDefinition::ObjectStoreComponent(c.try_into()?),
+          HighLevelComponent::Filesystem(c) => v1::ImportDefinition::FilesystemComponent(c.try_into()?),
+          HighLevelComponent::Data(c) => v1::ImportDefinition::DataComponent(c.try_into()?),
+          HighLevelComponent::Crypto(c) => v1::ImportDefinition::CryptoComponent(c.try_into()?),
         },
       },
       crate::config::ImportDefinition::Types(c) => v1::ImportDefinition::TypesComponent(c.try_into()?),
@@ -492,13 +595,27 @@ impl TryFrom<ComponentDefinition> for v1::ComponentDefinition {
       ComponentDefinition::Wasm(_) => unimplemented!(
         "Wasm component definition is no longer supported in v1 manifests. Use ManifestComponent instead."
       ),
-      ComponentDefinition::GrpcUrl(grpc) => Self::GrpcUrlComponent(grpc.into()),
+      ComponentDefinition::GrpcUrl(grpc) => Self::GrpcUrlComponent(grpc.try_into()?),
       ComponentDefinition::Native(_) => todo!(),
       ComponentDefinition::Reference(v) => Self::ComponentReference(v.into()),
       ComponentDefinition::Manifest(v) => Self::ManifestComponent(v.try_into()?),
       ComponentDefinition::HighLevelComponent(v) => match v {
         config::HighLevelComponent::Sql(v) => Self::SqlComponent(v.try_into()?),
         config::HighLevelComponent::HttpClient(v) => Self::HttpClientComponent(v.try_into()?),
+        config::HighLevelComponent::Archive(v) => Self::ArchiveComponent(v.try_into()?),
+        config::HighLevelComponent::Text(v) => Self::TextComponent(v.try_into()?),
+        config::HighLevelComponent::Units(v) => Self::UnitsComponent(v.try_into()?),
+        config::HighLevelComponent::Validate(v) => Self::ValidateComponent(v.try_into()?),
+        config::HighLevelComponent::Generate(v) => Self::GenerateComponent(v.try_into()?),
+        config::HighLevelComponent::Flags(v) => Self::FlagsComponent(v.try_into()?),
+        config::HighLevelComponent::I18n(v) => Self::I18nComponent(v.try_into()?),
+        config::HighLevelComponent::Metrics(v) => Self::MetricsComponent(v.try_into()?),
+        config::HighLevelComponent::Broker(v) => Self::BrokerComponent(v.try_into()?),
+        config::HighLevelComponent::Cache(v) => Self::CacheComponent(v.try_into()?),
+        config::HighLevelComponent::ObjectStore(v) => Self::ObjectStoreComponent(v.try_into()?),
+        config::HighLevelComponent::Filesystem(v) => Self::FilesystemComponent(v.try_into()?),
+        config::HighLevelComponent::Data(v) => Self::DataComponent(v.try_into()?),
+        config::HighLevelComponent::Crypto(v) => Self::CryptoComponent(v.try_into()?),
       },
     };
     Ok(def)
@@ -513,22 +630,110 @@ impl TryFrom<ManifestComponent> for v1::ManifestComponent {
       with: def.config.map_into(),
       provide: def.provide,
       max_packet_size: def.max_packet_size,
+      limits: def.limits.map(Into::into),
+      pool: def.pool.map(Into::into),
+      process: def.process.map(Into::into),
     })
   }
 }
 
+impl From<components::WasmLimits> for v1::WasmLimits {
+  fn from(value: components::WasmLimits) -> Self {
+    Self {
+      max_memory: value.max_memory,
+      max_instances: value.max_instances,
+      timeout: value.timeout,
+    }
+  }
+}
+
+impl From<v1::WasmLimits> for components::WasmLimits {
+  fn from(value: v1::WasmLimits) -> Self {
+    Self {
+      max_memory: value.max_memory,
+      max_instances: value.max_instances,
+      timeout: value.timeout,
+    }
+  }
+}
+
+impl From<components::WasmPool> for v1::WasmPool {
+  fn from(value: components::WasmPool) -> Self {
+    Self {
+      min_instances: value.min_instances,
+      max_instances: value.max_instances,
+    }
+  }
+}
+
+impl From<v1::WasmPool> for components::WasmPool {
+  fn from(value: v1::WasmPool) -> Self {
+    Self {
+      min_instances: value.min_instances,
+      max_instances: value.max_instances,
+    }
+  }
+}
+
+impl From<components::ProcessHost> for v1::ProcessHost {
+  fn from(value: components::ProcessHost) -> Self {
+    Self {
+      restart: value.restart.into(),
+      max_restarts: value.max_restarts,
+      startup_timeout: value.startup_timeout,
+      command: value.command,
+    }
+  }
+}
+
+impl From<v1::ProcessHost> for components::ProcessHost {
+  fn from(value: v1::ProcessHost) -> Self {
+    Self {
+      restart: value.restart.into(),
+      max_restarts: value.max_restarts,
+      startup_timeout: value.startup_timeout,
+      command: value.command,
+    }
+  }
+}
+
+impl From<components::RestartPolicy> for v1::RestartPolicy {
+  fn from(value: components::RestartPolicy) -> Self {
+    match value {
+      components::RestartPolicy::OnFailure => Self::OnFailure,
+      components::RestartPolicy::Always => Self::Always,
+      components::RestartPolicy::Never => Self::Never,
+    }
+  }
+}
+
+impl From<v1::RestartPolicy> for components::RestartPolicy {
+  fn from(value: v1::RestartPolicy) -> Self {
+    match value {
+      v1::RestartPolicy::OnFailure => Self::OnFailure,
+      v1::RestartPolicy::Always => Self::Always,
+      v1::RestartPolicy::Never => Self::Never,
+    }
+  }
+}
+
 impl From<ComponentReference> for v1::ComponentReference {
   fn from(value: ComponentReference) -> Self {
     Self { id: value.id }
   }
 }
 
-impl From<GrpcUrlComponent> for v1::GrpcUrlComponent {
-  fn from(def: GrpcUrlComponent) -> Self {
-    Self {
+impl TryFrom<GrpcUrlComponent> for v1::GrpcUrlComponent {
+  type Error = ManifestError;
+  fn try_from(def: GrpcUrlComponent) -> Result<Self> {
+    Ok(Self {
       url: def.url,
       with: def.config.map_into(),
-    }
+      pem: def.pem.try_map_into()?,
+      key: def.key.try_map_into()?,
+      ca: def.ca.try_map_into()?,
+      domain: def.domain,
+    })
   }
 }
 
@@ -541,485 +746,2045 @@ impl TryFrom<config::components::HttpClientComponentConfig> for v1::HttpClientCo
       codec: value.codec.map_into(),
       proxy: value.proxy.try_map_into()?,
       timeout: value.timeout,
+      redirects: value.redirects,
+      pool: value.pool.map_into(),
       operations: value.operations.try_map_into()?,
     })
   }
 }
 
-impl TryFrom<config::components::Proxy> for v1::Proxy {
+impl TryFrom<config::components::ArchiveComponentConfig> for v1::ArchiveComponent {
   type Error = ManifestError;
-  fn try_from(value: config::components::Proxy) -> std::result::Result<Self, Self::Error> {
+  fn try_from(value: config::components::ArchiveComponentConfig) -> Result<Self> {
     Ok(Self {
-      resource: value.resource.id().to_owned(),
-      username: value.username,
-      password: value.password,
+      format: value.format.into(),
+      max_entry_size: value.max_entry_size,
+      max_total_size: value.max_total_size,
+      max_entries: value.max_entries,
+      with: value.config.try_map_into()?,
     })
   }
 }
 
-impl From<config::common::Codec> for v1::Codec {
-  fn from(value: config::common::Codec) -> Self {
+impl From<config::components::ArchiveFormat> for v1::ArchiveFormat {
+  fn from(value: config::components::ArchiveFormat) -> Self {
     match value {
-      config::common::Codec::Json => Self::Json,
-      config::common::Codec::Raw => Self::Raw,
-      config::common::Codec::FormData => Self::FormData,
-      config::common::Codec::Text => Self::Text,
+      config::components::ArchiveFormat::Tar => Self::Tar,
+      config::components::ArchiveFormat::Zip => Self::Zip,
     }
   }
 }
 
-impl From<v1::Codec> for config::common::Codec {
-  fn from(value: v1::Codec) -> Self {
-    match value {
-      v1::Codec::Json => Self::Json,
-      v1::Codec::Raw => Self::Raw,
-      v1::Codec::FormData => Self::FormData,
-      v1::Codec::Text => Self::Text,
-    }
+impl TryFrom<config::components::TextComponentConfig> for v1::TextComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::TextComponentConfig) -> Result<Self> {
+    Ok(Self {
+      templates: value.templates.map(|v| v.id().to_owned()),
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
   }
 }
 
-impl TryFrom<config::FlowOperation> for v1::CompositeOperationDefinition {
+impl TryFrom<config::components::TextOperationDefinition> for v1::TextOperationDefinition {
   type Error = ManifestError;
-
-  fn try_from(value: config::FlowOperation) -> std::result::Result<Self, Self::Error> {
-    let instances: Vec<v1::OperationInstance> = value
-      .instances
-      .into_iter()
-      .map(|(n, v)| new_operation_instance(n, v))
-      .collect();
-    let connections: Result<Vec<v1::FlowExpression>> = value.expressions.try_map_into();
+  fn try_from(value: config::components::TextOperationDefinition) -> Result<Self> {
     Ok(Self {
       name: value.name,
-      inputs: value.inputs.try_map_into()?,
-      outputs: value.outputs.try_map_into()?,
+      kind: value.kind.into(),
       with: value.config.try_map_into()?,
-      uses: instances,
-      flow: connections?,
-      operations: value.flows.try_map_into()?,
+      inputs: value.inputs.try_map_into()?,
+      pattern: value.pattern,
+      replacement: value.replacement,
+      separator: value.separator,
+      template: value.template,
+      file: value.file,
+      context: value.context,
     })
   }
 }
 
-fn new_operation_instance(id: String, value: config::InstanceReference) -> v1::OperationInstance {
-  v1::OperationInstance {
-    name: id,
-    operation: v1::ComponentOperationExpression {
-      name: value.name,
-      component: v1::ComponentDefinition::ComponentReference(v1::ComponentReference { id: value.component_id }),
-      with: None,
-      timeout: None,
-    },
-    timeout: value.settings.and_then(|v| v.timeout.map(|v| v.as_millis() as _)),
-    with: value.data.map_into(),
+impl From<config::components::TextOperationKind> for v1::TextOperationKind {
+  fn from(value: config::components::TextOperationKind) -> Self {
+    match value {
+      config::components::TextOperationKind::Match => Self::Match,
+      config::components::TextOperationKind::Replace => Self::Replace,
+      config::components::TextOperationKind::Captures => Self::Captures,
+      config::components::TextOperationKind::Split => Self::Split,
+      config::components::TextOperationKind::Join => Self::Join,
+      config::components::TextOperationKind::Template => Self::Template,
+      config::components::TextOperationKind::Diff => Self::Diff,
+    }
   }
 }
 
-impl TryFrom<crate::v1::ComponentDefinition> for ComponentDefinition {
+impl TryFrom<config::components::UnitsComponentConfig> for v1::UnitsComponent {
   type Error = ManifestError;
-  fn try_from(def: crate::v1::ComponentDefinition) -> Result<Self> {
-    let res = match def {
-      v1::ComponentDefinition::GrpcUrlComponent(v) => ComponentDefinition::GrpcUrl(GrpcUrlComponent {
-        url: v.url,
-        config: v.with.map_into(),
-      }),
-      v1::ComponentDefinition::ManifestComponent(v) => ComponentDefinition::Manifest(ManifestComponent {
-        reference: v.reference.try_into()?,
-        config: v.with.map_into(),
-        provide: v.provide,
-        max_packet_size: v.max_packet_size,
-      }),
-      v1::ComponentDefinition::ComponentReference(v) => ComponentDefinition::Reference(ComponentReference { id: v.id }),
-      v1::ComponentDefinition::SqlComponent(v) => {
-        ComponentDefinition::HighLevelComponent(HighLevelComponent::Sql(v.try_into()?))
-      }
-      v1::ComponentDefinition::HttpClientComponent(v) => {
-        ComponentDefinition::HighLevelComponent(HighLevelComponent::HttpClient(v.try_into()?))
-      }
-    };
-    Ok(res)
+  fn try_from(value: config::components::UnitsComponentConfig) -> Result<Self> {
+    Ok(Self {
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
   }
 }
 
-impl TryFrom<crate::v1::OperationInstance> for config::InstanceReference {
+impl TryFrom<config::components::UnitsOperationDefinition> for v1::UnitsOperationDefinition {
   type Error = ManifestError;
-  fn try_from(def: crate::v1::OperationInstance) -> Result<Self> {
-    let ns = def.operation.component.component_id().unwrap_or("<anonymous>");
-    let name = def.operation.name;
-    Ok(config::InstanceReference {
-      component_id: ns.to_owned(),
-      name,
-      data: def.with.map_into(),
-      settings: def.timeout.map(ExecutionSettings::from_timeout_millis),
+  fn try_from(value: config::components::UnitsOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      kind: value.kind.into(),
+      with: value.config.try_map_into()?,
+      rounding: value.rounding.map(Into::into),
+      ratios: value.ratios,
+      currency: value.currency,
+      from: value.from,
+      to: value.to,
+      precision: value.precision,
     })
   }
 }
 
-impl TryFrom<crate::v1::HostConfig> for HostConfig {
-  type Error = ManifestError;
-  fn try_from(def: crate::v1::HostConfig) -> Result<Self> {
-    Ok(Self {
-      allow_latest: def.allow_latest,
-      insecure_registries: def.insecure_registries,
-      rpc: def.rpc.try_map_into()?,
-    })
+impl From<config::components::UnitsOperationKind> for v1::UnitsOperationKind {
+  fn from(value: config::components::UnitsOperationKind) -> Self {
+    match value {
+      config::components::UnitsOperationKind::Add => Self::Add,
+      config::components::UnitsOperationKind::Subtract => Self::Subtract,
+      config::components::UnitsOperationKind::Multiply => Self::Multiply,
+      config::components::UnitsOperationKind::Allocate => Self::Allocate,
+      config::components::UnitsOperationKind::Parse => Self::Parse,
+      config::components::UnitsOperationKind::Format => Self::Format,
+      config::components::UnitsOperationKind::Convert => Self::Convert,
+    }
   }
 }
 
-impl TryFrom<HostConfig> for crate::v1::HostConfig {
-  type Error = ManifestError;
-  fn try_from(def: HostConfig) -> Result<Self> {
-    Ok(Self {
-      allow_latest: def.allow_latest,
-      insecure_registries: def.insecure_registries,
-      rpc: def.rpc.try_map_into()?,
-    })
+impl From<config::components::RoundingMode> for v1::RoundingMode {
+  fn from(value: config::components::RoundingMode) -> Self {
+    match value {
+      config::components::RoundingMode::HalfEven => Self::HalfEven,
+      config::components::RoundingMode::HalfUp => Self::HalfUp,
+      config::components::RoundingMode::HalfDown => Self::HalfDown,
+      config::components::RoundingMode::Up => Self::Up,
+      config::components::RoundingMode::Down => Self::Down,
+      config::components::RoundingMode::Ceiling => Self::Ceiling,
+      config::components::RoundingMode::Floor => Self::Floor,
+    }
   }
 }
 
-impl TryFrom<crate::v1::HttpConfig> for config::HttpConfig {
+impl TryFrom<config::components::ValidateComponentConfig> for v1::ValidateComponent {
   type Error = ManifestError;
-  fn try_from(def: crate::v1::HttpConfig) -> Result<Self> {
+  fn try_from(value: config::components::ValidateComponentConfig) -> Result<Self> {
     Ok(Self {
-      enabled: def.enabled,
-      port: def.port,
-      address: opt_str_to_ipv4addr(&def.address)?,
-      pem: match def.pem {
-        Some(v) => Some(v.try_into()?),
-        None => None,
-      },
-      key: match def.key {
-        Some(v) => Some(v.try_into()?),
-        None => None,
-      },
-      ca: match def.ca {
-        Some(v) => Some(v.try_into()?),
-        None => None,
-      },
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
     })
   }
 }
 
-impl TryFrom<config::HttpConfig> for crate::v1::HttpConfig {
+impl TryFrom<config::components::ValidateOperationDefinition> for v1::ValidateOperationDefinition {
   type Error = ManifestError;
-  fn try_from(def: config::HttpConfig) -> Result<Self> {
+  fn try_from(value: config::components::ValidateOperationDefinition) -> Result<Self> {
     Ok(Self {
-      enabled: def.enabled,
-      port: def.port,
-      address: def.address.map(|v| v.to_string()),
-      pem: def.pem.try_map_into()?,
-      key: def.key.try_map_into()?,
-      ca: def.ca.try_map_into()?,
+      name: value.name,
+      kind: value.kind.into(),
+      with: value.config.try_map_into()?,
+      schemes: value.schemes,
+      country_code: value.country_code,
     })
   }
 }
 
-impl TryFrom<v1::ResourceDefinition> for config::ResourceDefinition {
+impl From<config::components::ValidateOperationKind> for v1::ValidateOperationKind {
+  fn from(value: config::components::ValidateOperationKind) -> Self {
+    match value {
+      config::components::ValidateOperationKind::Email => Self::Email,
+      config::components::ValidateOperationKind::Url => Self::Url,
+      config::components::ValidateOperationKind::Phone => Self::Phone,
+    }
+  }
+}
+
+impl TryFrom<config::components::GenerateComponentConfig> for v1::GenerateComponent {
   type Error = ManifestError;
-  fn try_from(value: v1::ResourceDefinition) -> Result<Self> {
-    Ok(match value {
-      v1::ResourceDefinition::TcpPort(v) => Self::TcpPort(v.into()),
-      v1::ResourceDefinition::UdpPort(v) => Self::UdpPort(v.into()),
-      v1::ResourceDefinition::Url(v) => Self::Url(v.into()),
-      v1::ResourceDefinition::Volume(v) => Self::Volume(v.into()),
+  fn try_from(value: config::components::GenerateComponentConfig) -> Result<Self> {
+    Ok(Self {
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
     })
   }
 }
 
-impl From<v1::Volume> for config::Volume {
-  fn from(value: v1::Volume) -> Self {
-    config::Volume::new(value.path)
+impl TryFrom<config::components::GenerateOperationDefinition> for v1::GenerateOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::GenerateOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      ty: value.ty,
+      count: value.count,
+      seed: value.seed,
+      fields: value.fields.map_into(),
+    })
   }
 }
 
-impl From<v1::Url> for config::UrlResource {
-  fn from(value: v1::Url) -> Self {
+impl From<config::components::GenerateField> for v1::GenerateField {
+  fn from(value: config::components::GenerateField) -> Self {
     Self {
-      url: TemplateConfig::new_template(value.url),
+      name: value.name,
+      strategy: value.strategy.into(),
+      min: value.min,
+      max: value.max,
+      values: value.values,
     }
   }
 }
 
-impl From<v1::TcpPort> for TcpPort {
-  fn from(value: v1::TcpPort) -> Self {
-    Self {
-      port: TemplateConfig::new_template(value.port),
-      host: TemplateConfig::new_template(value.address),
+impl From<config::components::GenerateStrategy> for v1::GenerateStrategy {
+  fn from(value: config::components::GenerateStrategy) -> Self {
+    match value {
+      config::components::GenerateStrategy::FirstName => Self::FirstName,
+      config::components::GenerateStrategy::LastName => Self::LastName,
+      config::components::GenerateStrategy::FullName => Self::FullName,
+      config::components::GenerateStrategy::Username => Self::Username,
+      config::components::GenerateStrategy::Email => Self::Email,
+      config::components::GenerateStrategy::Phone => Self::Phone,
+      config::components::GenerateStrategy::Company => Self::Company,
+      config::components::GenerateStrategy::City => Self::City,
+      config::components::GenerateStrategy::Country => Self::Country,
+      config::components::GenerateStrategy::Word => Self::Word,
+      config::components::GenerateStrategy::Sentence => Self::Sentence,
+      config::components::GenerateStrategy::Uuid => Self::Uuid,
+      config::components::GenerateStrategy::Integer => Self::Integer,
+      config::components::GenerateStrategy::Float => Self::Float,
+      config::components::GenerateStrategy::Bool => Self::Bool,
+      config::components::GenerateStrategy::Datetime => Self::Datetime,
+      config::components::GenerateStrategy::Sequence => Self::Sequence,
+      config::components::GenerateStrategy::OneOf => Self::OneOf,
     }
   }
 }
 
-impl From<v1::UdpPort> for UdpPort {
-  fn from(value: v1::UdpPort) -> Self {
-    Self {
-      port: TemplateConfig::new_template(value.port),
-      host: TemplateConfig::new_template(value.address),
-    }
+impl TryFrom<config::components::FlagsComponentConfig> for v1::FlagsComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::FlagsComponentConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
   }
 }
 
-impl TryFrom<v1::TriggerDefinition> for TriggerDefinition {
+impl TryFrom<config::components::FlagsOperationDefinition> for v1::FlagsOperationDefinition {
   type Error = ManifestError;
-  fn try_from(trigger: v1::TriggerDefinition) -> Result<Self> {
-    let rv = match trigger {
-      v1::TriggerDefinition::CliTrigger(cli) => Self::Cli(CliConfig {
-        operation: cli.operation.try_into()?,
-      }),
-      v1::TriggerDefinition::HttpTrigger(v) => Self::Http(HttpTriggerConfig {
-        resource: v.resource.into(),
-        routers: v.routers.try_map_into()?,
-      }),
-      v1::TriggerDefinition::TimeTrigger(time) => Self::Time(TimeTriggerConfig {
-        schedule: time.schedule.try_into()?,
-        operation: time.operation.try_into()?,
-        payload: time.payload.try_map_into()?,
-      }),
-      v1::TriggerDefinition::WasmCommandTrigger(v) => Self::WasmCommand(WasmCommandConfig {
-        reference: v.reference.try_into()?,
-        volumes: v.volumes.try_map_into()?,
-      }),
-    };
-    Ok(rv)
+  fn try_from(value: config::components::FlagsOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      flag: value.flag,
+    })
   }
 }
 
-impl TryFrom<v1::HttpRouter> for HttpRouterConfig {
+impl TryFrom<config::components::I18nComponentConfig> for v1::I18nComponent {
   type Error = ManifestError;
-  fn try_from(router: v1::HttpRouter) -> Result<Self> {
-    let rv = match router {
-      v1::HttpRouter::RawRouter(v) => Self::RawRouter(RawRouterConfig {
-        path: v.path,
-        codec: v.codec.map_into(),
-        operation: v.operation.try_into()?,
-        middleware: v.middleware.try_map_into()?,
-      }),
-      v1::HttpRouter::RestRouter(v) => Self::RestRouter(RestRouterConfig {
-        path: v.path,
-        tools: v.tools.try_map_into()?,
-        routes: v.routes.try_map_into()?,
-        info: v.info.try_map_into()?,
-        middleware: v.middleware.try_map_into()?,
-      }),
-      v1::HttpRouter::StaticRouter(v) => Self::StaticRouter(StaticRouterConfig {
-        path: v.path,
-        volume: v.volume.into(),
-        fallback: v.fallback,
-        middleware: v.middleware.try_map_into()?,
-        indexes: v.indexes,
-      }),
-      v1::HttpRouter::ProxyRouter(v) => Self::ProxyRouter(ProxyRouterConfig {
-        path: v.path,
-        url: v.url.into(),
-        strip_path: v.strip_path,
-        middleware: v.middleware.try_map_into()?,
-      }),
-    };
-    Ok(rv)
+  fn try_from(value: config::components::I18nComponentConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      fallback: value.fallback,
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::I18nOperationDefinition> for v1::I18nOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::I18nOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::MetricsComponentConfig> for v1::MetricsComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::MetricsComponentConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      protocol: value.protocol.into(),
+      labels: value.labels,
+      batch_size: value.batch_size,
+      flush_interval: value.flush_interval,
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::MetricsOperationDefinition> for v1::MetricsOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::MetricsOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      metric: value.metric.into(),
+    })
+  }
+}
+
+impl From<config::components::MetricsProtocol> for v1::MetricsProtocol {
+  fn from(value: config::components::MetricsProtocol) -> Self {
+    match value {
+      config::components::MetricsProtocol::RemoteWrite => Self::RemoteWrite,
+      config::components::MetricsProtocol::Statsd => Self::Statsd,
+    }
+  }
+}
+
+impl From<config::components::MetricKind> for v1::MetricKind {
+  fn from(value: config::components::MetricKind) -> Self {
+    match value {
+      config::components::MetricKind::Gauge => Self::Gauge,
+      config::components::MetricKind::Counter => Self::Counter,
+      config::components::MetricKind::Timing => Self::Timing,
+    }
+  }
+}
+
+impl TryFrom<config::components::BrokerComponentConfig> for v1::BrokerComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::BrokerComponentConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::BrokerOperationDefinition> for v1::BrokerOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::BrokerOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      topic: value.topic,
+      inputs: value.inputs.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::CacheComponentConfig> for v1::CacheComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::CacheComponentConfig) -> Result<Self> {
+    Ok(Self {
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::CacheOperationDefinition> for v1::CacheOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::CacheOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      inputs: value.inputs.try_map_into()?,
+      outputs: value.outputs.try_map_into()?,
+      key: value.key,
+      compute: value.compute,
+      ttl: value.ttl,
+      stale: value.stale,
+    })
+  }
+}
+
+impl TryFrom<config::components::ObjectStoreComponentConfig> for v1::ObjectStoreComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::ObjectStoreComponentConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      bucket: value.bucket,
+      region: value.region,
+      access_key_id: value.access_key_id.id().to_owned(),
+      secret_access_key: value.secret_access_key.id().to_owned(),
+      session_token: value.session_token.map(|v| v.id().to_owned()),
+      path_style: value.path_style,
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::ObjectStoreOperationDefinition> for v1::ObjectStoreOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::ObjectStoreOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      action: value.action.into(),
+      method: value.method.map(Into::into),
+      expires: value.expires,
+      page_size: value.page_size,
+    })
+  }
+}
+
+impl From<config::components::ObjectStoreAction> for v1::ObjectStoreAction {
+  fn from(value: config::components::ObjectStoreAction) -> Self {
+    match value {
+      config::components::ObjectStoreAction::Get => Self::Get,
+      config::components::ObjectStoreAction::Put => Self::Put,
+      config::components::ObjectStoreAction::List => Self::List,
+      config::components::ObjectStoreAction::Presign => Self::Presign,
+    }
+  }
+}
+
+impl TryFrom<config::components::FilesystemComponentConfig> for v1::FilesystemComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::FilesystemComponentConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::FilesystemOperationDefinition> for v1::FilesystemOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::FilesystemOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      action: value.action.into(),
+      recursive: value.recursive,
+      interval: value.interval,
+    })
+  }
+}
+
+impl From<config::components::FilesystemAction> for v1::FilesystemAction {
+  fn from(value: config::components::FilesystemAction) -> Self {
+    match value {
+      config::components::FilesystemAction::Watch => Self::Watch,
+    }
+  }
+}
+
+impl TryFrom<config::components::DataComponentConfig> for v1::DataComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::DataComponentConfig) -> Result<Self> {
+    Ok(Self {
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::DataOperationDefinition> for v1::DataOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::DataOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      action: value.action.into(),
+      format: value.format.into(),
+      delimiter: value.delimiter,
+      columns: value.columns,
+      element: value.element,
+      root: value.root,
+      mapping: value.mapping,
+      typed: value.typed,
+    })
+  }
+}
+
+impl From<config::components::DataAction> for v1::DataAction {
+  fn from(value: config::components::DataAction) -> Self {
+    match value {
+      config::components::DataAction::Parse => Self::Parse,
+      config::components::DataAction::Serialize => Self::Serialize,
+    }
+  }
+}
+
+impl From<config::components::DataFormat> for v1::DataFormat {
+  fn from(value: config::components::DataFormat) -> Self {
+    match value {
+      config::components::DataFormat::Csv => Self::Csv,
+      config::components::DataFormat::Ndjson => Self::Ndjson,
+      config::components::DataFormat::Xml => Self::Xml,
+    }
+  }
+}
+
+impl TryFrom<config::components::CryptoComponentConfig> for v1::CryptoComponent {
+  type Error = ManifestError;
+  fn try_from(value: config::components::CryptoComponentConfig) -> Result<Self> {
+    Ok(Self {
+      with: value.config.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::components::CryptoOperationDefinition> for v1::CryptoOperationDefinition {
+  type Error = ManifestError;
+  fn try_from(value: config::components::CryptoOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      with: value.config.try_map_into()?,
+      action: value.action.into(),
+      algorithm: value.algorithm.into(),
+      key: value.key.map(|v| v.id().to_owned()),
+      encoding: value.encoding.map(Into::into),
+      issuer: value.issuer,
+      audience: value.audience,
+      expires: value.expires,
+      leeway: value.leeway,
+    })
+  }
+}
+
+impl From<config::components::CryptoAction> for v1::CryptoAction {
+  fn from(value: config::components::CryptoAction) -> Self {
+    match value {
+      config::components::CryptoAction::Hash => Self::Hash,
+      config::components::CryptoAction::Hmac => Self::Hmac,
+      config::components::CryptoAction::Sign => Self::Sign,
+      config::components::CryptoAction::Verify => Self::Verify,
+      config::components::CryptoAction::JwtIssue => Self::JwtIssue,
+      config::components::CryptoAction::JwtVerify => Self::JwtVerify,
+    }
+  }
+}
+
+impl From<config::components::CryptoAlgorithm> for v1::CryptoAlgorithm {
+  fn from(value: config::components::CryptoAlgorithm) -> Self {
+    match value {
+      config::components::CryptoAlgorithm::Sha256 => Self::Sha256,
+      config::components::CryptoAlgorithm::Sha384 => Self::Sha384,
+      config::components::CryptoAlgorithm::Sha512 => Self::Sha512,
+      config::components::CryptoAlgorithm::Blake3 => Self::Blake3,
+      config::components::CryptoAlgorithm::Ed25519 => Self::Ed25519,
+      config::components::CryptoAlgorithm::EcdsaP256 => Self::EcdsaP256,
+      config::components::CryptoAlgorithm::EcdsaP384 => Self::EcdsaP384,
+    }
+  }
+}
+
+impl From<config::components::CryptoEncoding> for v1::CryptoEncoding {
+  fn from(value: config::components::CryptoEncoding) -> Self {
+    match value {
+      config::components::CryptoEncoding::Hex => Self::Hex,
+      config::components::CryptoEncoding::Base64 => Self::Base64,
+      config::components::CryptoEncoding::Base64Url => Self::Base64Url,
+    }
+  }
+}
+
+impl From<config::components::ConnectionPool> for v1::ConnectionPool {
+  fn from(value: config::components::ConnectionPool) -> Self {
+    Self {
+      max_idle_per_host: value.max_idle_per_host,
+      idle_timeout: value.idle_timeout,
+      keep_alive: value.keep_alive,
+      http2_prior_knowledge: value.http2_prior_knowledge,
+    }
+  }
+}
+
+impl TryFrom<config::components::Proxy> for v1::Proxy {
+  type Error = ManifestError;
+  fn try_from(value: config::components::Proxy) -> std::result::Result<Self, Self::Error> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      username: value.username,
+      password: value.password,
+    })
+  }
+}
+
+impl From<config::common::Codec> for v1::Codec {
+  fn from(value: config::common::Codec) -> Self {
+    match value {
+      config::common::Codec::Json => Self::Json,
+      config::common::Codec::Raw => Self::Raw,
+      config::common::Codec::FormData => Self::FormData,
+      config::common::Codec::Text => Self::Text,
+    }
+  }
+}
+
+impl From<v1::Codec> for config::common::Codec {
+  fn from(value: v1::Codec) -> Self {
+    match value {
+      v1::Codec::Json => Self::Json,
+      v1::Codec::Raw => Self::Raw,
+      v1::Codec::FormData => Self::FormData,
+      v1::Codec::Text => Self::Text,
+    }
+  }
+}
+
+impl TryFrom<config::FlowOperation> for v1::CompositeOperationDefinition {
+  type Error = ManifestError;
+
+  fn try_from(value: config::FlowOperation) -> std::result::Result<Self, Self::Error> {
+    let instances: Vec<v1::OperationInstance> = value
+      .instances
+      .into_iter()
+      .map(|(n, v)| new_operation_instance(n, v))
+      .collect();
+    let connections: Result<Vec<v1::FlowExpression>> = value.expressions.try_map_into();
+    Ok(Self {
+      name: value.name,
+      inputs: value.inputs.try_map_into()?,
+      outputs: value.outputs.try_map_into()?,
+      with: value.config.try_map_into()?,
+      uses: instances,
+      flow: connections?,
+      operations: value.flows.try_map_into()?,
+      cost: value.cost,
+    })
+  }
+}
+
+fn new_operation_instance(id: String, value: config::InstanceReference) -> v1::OperationInstance {
+  v1::OperationInstance {
+    name: id,
+    operation: v1::ComponentOperationExpression {
+      name: value.name,
+      component: v1::ComponentDefinition::ComponentReference(v1::ComponentReference { id: value.component_id }),
+      with: None,
+      timeout: None,
+      retry: None,
+    },
+    timeout: value.settings.as_ref().and_then(|v| v.timeout.map(|v| v.as_millis() as _)),
+    retry: value.settings.and_then(|v| v.retry).map_into(),
+    with: value.data.map_into(),
+  }
+}
+
+impl TryFrom<crate::v1::ComponentDefinition> for ComponentDefinition {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::ComponentDefinition) -> Result<Self> {
+    let res = match def {
+      v1::ComponentDefinition::GrpcUrlComponent(v) => ComponentDefinition::GrpcUrl(GrpcUrlComponent {
+        url: v.url,
+        config: v.with.map_into(),
+        pem: v.pem.try_map_into()?,
+        key: v.key.try_map_into()?,
+        ca: v.ca.try_map_into()?,
+        domain: v.domain,
+      }),
+      v1::ComponentDefinition::ManifestComponent(v) => ComponentDefinition::Manifest(ManifestComponent {
+        reference: v.reference.try_into()?,
+        config: v.with.map_into(),
+        provide: v.provide,
+        max_packet_size: v.max_packet_size,
+        limits: v.limits.map(Into::into),
+        pool: v.pool.map(Into::into),
+        process: v.process.map(Into::into),
+      }),
+      v1::ComponentDefinition::ComponentReference(v) => ComponentDefinition::Reference(ComponentReference { id: v.id }),
+      v1::ComponentDefinition::SqlComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Sql(v.try_into()?))
+      }
+      v1::ComponentDefinition::HttpClientComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::HttpClient(v.try_into()?))
+      }
+      v1::ComponentDefinition::ArchiveComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Archive(v.try_into()?))
+      }
+      v1::ComponentDefinition::TextComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Text(v.try_into()?))
+      }
+      v1::ComponentDefinition::UnitsComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Units(v.try_into()?))
+      }
+      v1::ComponentDefinition::ValidateComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Validate(v.try_into()?))
+      }
+      v1::ComponentDefinition::GenerateComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Generate(v.try_into()?))
+      }
+      v1::ComponentDefinition::FlagsComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Flags(v.try_into()?))
+      }
+      v1::ComponentDefinition::I18nComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::I18n(v.try_into()?))
+      }
+      v1::ComponentDefinition::MetricsComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Metrics(v.try_into()?))
+      }
+      v1::ComponentDefinition::BrokerComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Broker(v.try_into()?))
+      }
+      v1::ComponentDefinition::CacheComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Cache(v.try_into()?))
+      }
+      v1::ComponentDefinition::ObjectStoreComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::ObjectStore(v.try_into()?))
+      }
+      v1::ComponentDefinition::FilesystemComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Filesystem(v.try_into()?))
+      }
+      v1::ComponentDefinition::DataComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Data(v.try_into()?))
+      }
+      v1::ComponentDefinition::CryptoComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::Crypto(v.try_into()?))
+      }
+    };
+    Ok(res)
+  }
+}
+
+impl TryFrom<crate::v1::OperationInstance> for config::InstanceReference {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::OperationInstance) -> Result<Self> {
+    let ns = def.operation.component.component_id().unwrap_or("<anonymous>");
+    let name = def.operation.name;
+    Ok(config::InstanceReference {
+      component_id: ns.to_owned(),
+      name,
+      data: def.with.map_into(),
+      settings: execution_settings(def.timeout, def.retry),
+    })
+  }
+}
+
+impl TryFrom<crate::v1::HostConfig> for HostConfig {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::HostConfig) -> Result<Self> {
+    Ok(Self {
+      allow_latest: def.allow_latest,
+      insecure_registries: def.insecure_registries,
+      rpc: def.rpc.try_map_into()?,
+      metrics: def.metrics.try_map_into()?,
+      otlp: def.otlp.map_into(),
+      policy: def.policy.map_into(),
+      type_check: def.type_check.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<HostConfig> for crate::v1::HostConfig {
+  type Error = ManifestError;
+  fn try_from(def: HostConfig) -> Result<Self> {
+    Ok(Self {
+      allow_latest: def.allow_latest,
+      insecure_registries: def.insecure_registries,
+      rpc: def.rpc.try_map_into()?,
+      metrics: def.metrics.map_into(),
+      otlp: def.otlp.map_into(),
+      policy: def.policy.map_into(),
+      type_check: def.type_check.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<crate::v1::MetricsConfig> for config::MetricsConfig {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::MetricsConfig) -> Result<Self> {
+    Ok(Self {
+      enabled: def.enabled,
+      port: def.port,
+      address: opt_str_to_ipv4addr(&def.address)?,
+      alerts: def.alerts.map_into(),
+    })
+  }
+}
+
+impl From<config::MetricsConfig> for crate::v1::MetricsConfig {
+  fn from(def: config::MetricsConfig) -> Self {
+    Self {
+      enabled: def.enabled,
+      port: def.port,
+      address: def.address.map(|v| v.to_string()),
+      alerts: def.alerts.map_into(),
+    }
+  }
+}
+
+impl From<crate::v1::AlertRule> for config::AlertRule {
+  fn from(def: crate::v1::AlertRule) -> Self {
+    Self {
+      name: def.name,
+      operation: def.operation,
+      metric: def.metric.into(),
+      threshold: def.threshold,
+      window: Duration::from_secs(def.window),
+      invoke: def.invoke,
+    }
+  }
+}
+
+impl From<config::AlertRule> for crate::v1::AlertRule {
+  fn from(def: config::AlertRule) -> Self {
+    Self {
+      name: def.name,
+      operation: def.operation,
+      metric: def.metric.into(),
+      threshold: def.threshold,
+      window: def.window.as_secs(),
+      invoke: def.invoke,
+    }
+  }
+}
+
+impl From<crate::v1::AlertMetric> for config::AlertMetric {
+  fn from(value: crate::v1::AlertMetric) -> Self {
+    match value {
+      crate::v1::AlertMetric::ErrorRate => Self::ErrorRate,
+      crate::v1::AlertMetric::P99 => Self::P99,
+    }
+  }
+}
+
+impl From<config::AlertMetric> for crate::v1::AlertMetric {
+  fn from(value: config::AlertMetric) -> Self {
+    match value {
+      config::AlertMetric::ErrorRate => Self::ErrorRate,
+      config::AlertMetric::P99 => Self::P99,
+    }
+  }
+}
+
+impl From<crate::v1::ComponentPolicy> for config::ComponentPolicy {
+  fn from(def: crate::v1::ComponentPolicy) -> Self {
+    Self {
+      deny_by_default: def.deny_by_default,
+      rules: def.rules.map_into(),
+    }
+  }
+}
+
+impl From<config::ComponentPolicy> for crate::v1::ComponentPolicy {
+  fn from(def: config::ComponentPolicy) -> Self {
+    Self {
+      deny_by_default: def.deny_by_default,
+      rules: def.rules.map_into(),
+    }
+  }
+}
+
+impl From<crate::v1::PolicyRule> for config::PolicyRule {
+  fn from(def: crate::v1::PolicyRule) -> Self {
+    Self {
+      action: def.action.into(),
+      registry: def.registry,
+      digest: def.digest,
+      signer: def.signer,
+      namespace: def.namespace,
+    }
+  }
+}
+
+impl From<config::PolicyRule> for crate::v1::PolicyRule {
+  fn from(def: config::PolicyRule) -> Self {
+    Self {
+      action: def.action.into(),
+      registry: def.registry,
+      digest: def.digest,
+      signer: def.signer,
+      namespace: def.namespace,
+    }
+  }
+}
+
+impl From<crate::v1::PolicyAction> for config::PolicyAction {
+  fn from(value: crate::v1::PolicyAction) -> Self {
+    match value {
+      crate::v1::PolicyAction::Allow => Self::Allow,
+      crate::v1::PolicyAction::Deny => Self::Deny,
+    }
+  }
+}
+
+impl From<config::PolicyAction> for crate::v1::PolicyAction {
+  fn from(value: config::PolicyAction) -> Self {
+    match value {
+      config::PolicyAction::Allow => Self::Allow,
+      config::PolicyAction::Deny => Self::Deny,
+    }
+  }
+}
+
+impl TryFrom<crate::v1::TypeCheckConfig> for config::TypeCheckConfig {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::TypeCheckConfig) -> Result<Self> {
+    Ok(Self {
+      strict: def.strict,
+      coercions: def.coercions.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::TypeCheckConfig> for crate::v1::TypeCheckConfig {
+  type Error = ManifestError;
+  fn try_from(def: config::TypeCheckConfig) -> Result<Self> {
+    Ok(Self {
+      strict: def.strict,
+      coercions: def.coercions.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<crate::v1::TypeCoercion> for config::TypeCoercion {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::TypeCoercion) -> Result<Self> {
+    Ok(Self {
+      from: def.from.try_into()?,
+      to: def.to.try_into()?,
+    })
+  }
+}
+
+impl TryFrom<config::TypeCoercion> for crate::v1::TypeCoercion {
+  type Error = ManifestError;
+  fn try_from(def: config::TypeCoercion) -> Result<Self> {
+    Ok(Self {
+      from: def.from.try_into()?,
+      to: def.to.try_into()?,
+    })
+  }
+}
+
+impl From<crate::v1::OtlpConfig> for config::OtlpConfig {
+  fn from(def: crate::v1::OtlpConfig) -> Self {
+    Self { endpoint: def.endpoint }
+  }
+}
+
+impl From<config::OtlpConfig> for crate::v1::OtlpConfig {
+  fn from(def: config::OtlpConfig) -> Self {
+    Self { endpoint: def.endpoint }
+  }
+}
+
+impl TryFrom<crate::v1::HttpConfig> for config::HttpConfig {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::HttpConfig) -> Result<Self> {
+    Ok(Self {
+      enabled: def.enabled,
+      port: def.port,
+      address: opt_str_to_ipv4addr(&def.address)?,
+      pem: match def.pem {
+        Some(v) => Some(v.try_into()?),
+        None => None,
+      },
+      key: match def.key {
+        Some(v) => Some(v.try_into()?),
+        None => None,
+      },
+      ca: match def.ca {
+        Some(v) => Some(v.try_into()?),
+        None => None,
+      },
+    })
+  }
+}
+
+impl TryFrom<config::HttpConfig> for crate::v1::HttpConfig {
+  type Error = ManifestError;
+  fn try_from(def: config::HttpConfig) -> Result<Self> {
+    Ok(Self {
+      enabled: def.enabled,
+      port: def.port,
+      address: def.address.map(|v| v.to_string()),
+      pem: def.pem.try_map_into()?,
+      key: def.key.try_map_into()?,
+      ca: def.ca.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::ResourceDefinition> for config::ResourceDefinition {
+  type Error = ManifestError;
+  fn try_from(value: v1::ResourceDefinition) -> Result<Self> {
+    Ok(match value {
+      v1::ResourceDefinition::TcpPort(v) => Self::TcpPort(v.into()),
+      v1::ResourceDefinition::UdpPort(v) => Self::UdpPort(v.into()),
+      v1::ResourceDefinition::Url(v) => Self::Url(v.into()),
+      v1::ResourceDefinition::Volume(v) => Self::Volume(v.into()),
+      v1::ResourceDefinition::Broker(v) => Self::Broker(v.into()),
+      v1::ResourceDefinition::Secret(v) => Self::Secret(v.into()),
+    })
+  }
+}
+
+impl From<v1::Volume> for config::Volume {
+  fn from(value: v1::Volume) -> Self {
+    config::Volume::new(value.path)
+  }
+}
+
+impl From<v1::Url> for config::UrlResource {
+  fn from(value: v1::Url) -> Self {
+    Self {
+      url: TemplateConfig::new_template(value.url),
+    }
+  }
+}
+
+impl From<v1::Broker> for config::BrokerResource {
+  fn from(value: v1::Broker) -> Self {
+    Self {
+      name: TemplateConfig::new_template(value.name),
+    }
+  }
+}
+
+impl From<v1::Secret> for config::SecretResource {
+  fn from(value: v1::Secret) -> Self {
+    Self {
+      provider: value.provider.into(),
+      source: TemplateConfig::new_template(value.source),
+      refresh: value.refresh,
+    }
+  }
+}
+
+impl From<v1::SecretProvider> for config::SecretProvider {
+  fn from(value: v1::SecretProvider) -> Self {
+    match value {
+      v1::SecretProvider::Env => Self::Env,
+      v1::SecretProvider::File => Self::File,
+      v1::SecretProvider::Command => Self::Command,
+      v1::SecretProvider::Backend => Self::Backend,
+    }
+  }
+}
+
+impl From<v1::TcpPort> for TcpPort {
+  fn from(value: v1::TcpPort) -> Self {
+    Self {
+      port: TemplateConfig::new_template(value.port),
+      host: TemplateConfig::new_template(value.address),
+    }
+  }
+}
+
+impl From<v1::UdpPort> for UdpPort {
+  fn from(value: v1::UdpPort) -> Self {
+    Self {
+      port: TemplateConfig::new_template(value.port),
+      host: TemplateConfig::new_template(value.address),
+    }
+  }
+}
+
+impl TryFrom<v1::TriggerDefinition> for TriggerDefinition {
+  type Error = ManifestError;
+  fn try_from(trigger: v1::TriggerDefinition) -> Result<Self> {
+    let rv = match trigger {
+      v1::TriggerDefinition::CliTrigger(cli) => Self::Cli(CliConfig {
+        operation: cli.operation.try_into()?,
+        stdin: cli.stdin.map(Into::into),
+      }),
+      v1::TriggerDefinition::HttpTrigger(v) => Self::Http(HttpTriggerConfig {
+        resource: v.resource.into(),
+        routers: v.routers.try_map_into()?,
+      }),
+      v1::TriggerDefinition::TimeTrigger(time) => Self::Time(TimeTriggerConfig {
+        schedule: time.schedule.try_into()?,
+        operation: time.operation.try_into()?,
+        payload: time.payload.try_map_into()?,
+      }),
+      v1::TriggerDefinition::GrpcTrigger(v) => Self::Grpc(GrpcTriggerConfig {
+        resource: v.resource.into(),
+        package: v.package,
+        service: v.service,
+        operations: v.operations.try_map_into()?,
+      }),
+      v1::TriggerDefinition::LogTrigger(v) => Self::Log(LogTriggerConfig {
+        operation: v.operation.try_into()?,
+        sources: v.sources.map_into(),
+      }),
+      v1::TriggerDefinition::BrokerTrigger(v) => Self::Broker(BrokerTriggerConfig {
+        resource: v.resource.into(),
+        topic: v.topic,
+        operation: v.operation.try_into()?,
+      }),
+      v1::TriggerDefinition::WasmCommandTrigger(v) => Self::WasmCommand(WasmCommandConfig {
+        reference: v.reference.try_into()?,
+        volumes: v.volumes.try_map_into()?,
+      }),
+    };
+    Ok(rv)
+  }
+}
+
+impl TryFrom<v1::HttpRouter> for HttpRouterConfig {
+  type Error = ManifestError;
+  fn try_from(router: v1::HttpRouter) -> Result<Self> {
+    let rv = match router {
+      v1::HttpRouter::RawRouter(v) => Self::RawRouter(RawRouterConfig {
+        path: v.path,
+        codec: v.codec.map_into(),
+        operation: v.operation.try_into()?,
+        middleware: v.middleware.try_map_into()?,
+        auth: v.auth.try_map_into()?,
+        cors: v.cors.try_map_into()?,
+      }),
+      v1::HttpRouter::RestRouter(v) => Self::RestRouter(RestRouterConfig {
+        path: v.path,
+        tools: v.tools.try_map_into()?,
+        routes: v.routes.try_map_into()?,
+        info: v.info.try_map_into()?,
+        middleware: v.middleware.try_map_into()?,
+        auth: v.auth.try_map_into()?,
+        cors: v.cors.try_map_into()?,
+      }),
+      v1::HttpRouter::StaticRouter(v) => Self::StaticRouter(StaticRouterConfig {
+        path: v.path,
+        volume: v.volume.into(),
+        fallback: v.fallback,
+        middleware: v.middleware.try_map_into()?,
+        cors: v.cors.try_map_into()?,
+        indexes: v.indexes,
+        precompressed: v.precompressed,
+      }),
+      v1::HttpRouter::ProxyRouter(v) => Self::ProxyRouter(ProxyRouterConfig {
+        path: v.path,
+        url: v.url.into(),
+        strip_path: v.strip_path,
+        middleware: v.middleware.try_map_into()?,
+        cors: v.cors.try_map_into()?,
+      }),
+    };
+    Ok(rv)
+  }
+}
+
+impl TryFrom<v1::Cors> for config::CorsConfig {
+  type Error = ManifestError;
+  fn try_from(value: v1::Cors) -> Result<Self> {
+    let config = Self {
+      allowed_origins: value.allowed_origins,
+      allowed_methods: value.allowed_methods,
+      allowed_headers: value.allowed_headers,
+      max_age: value.max_age,
+      allow_credentials: value.allow_credentials,
+    };
+    config.validate()?;
+    Ok(config)
+  }
+}
+
+impl TryFrom<v1::Auth> for config::AuthConfig {
+  type Error = ManifestError;
+  fn try_from(value: v1::Auth) -> Result<Self> {
+    Ok(match value {
+      v1::Auth::JwtAuth(v) => Self::Jwt(config::JwtAuth {
+        secret: TemplateConfig::new_template(v.secret),
+        issuer: v.issuer,
+        audience: v.audience,
+      }),
+      v1::Auth::ApiKeyAuth(v) => Self::ApiKey(config::ApiKeyAuth {
+        header: v.header,
+        keys: v.keys.into_iter().map(TemplateConfig::new_template).collect(),
+      }),
+      v1::Auth::BasicAuth(v) => Self::Basic(config::BasicAuth {
+        realm: v.realm,
+        users: v
+          .users
+          .into_iter()
+          .map(|(user, password)| (user, TemplateConfig::new_template(password)))
+          .collect(),
+      }),
+    })
+  }
+}
+
+impl TryFrom<v1::ImportBinding> for Binding<config::ImportDefinition> {
+  type Error = ManifestError;
+  fn try_from(value: v1::ImportBinding) -> Result<Self> {
+    Ok(Self {
+      id: value.name.into(),
+      kind: value.component.try_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::ImportDefinition> for config::ImportDefinition {
+  type Error = ManifestError;
+  fn try_from(value: v1::ImportDefinition) -> Result<Self> {
+    Ok(match value {
+      v1::ImportDefinition::TypesComponent(c) => config::ImportDefinition::Types(c.try_into()?),
+      v1::ImportDefinition::ManifestComponent(c) => {
+        let c = v1::ComponentDefinition::ManifestComponent(c);
+        config::ImportDefinition::Component(c.try_into()?)
+      }
+      v1::ImportDefinition::GrpcUrlComponent(c) => {
+        let c = v1::ComponentDefinition::GrpcUrlComponent(c);
+        config::ImportDefinition::Component(c.try_into()?)
+      }
+      v1::ImportDefinition::SqlComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Sql(c.try_into()?)),
+      ),
+      v1::ImportDefinition::HttpClientComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::HttpClient(c.try_into()?)),
+      ),
+      v1::ImportDefinition::ArchiveComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Archive(c.try_into()?)),
+      ),
+      v1::ImportDefinition::TextComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Text(c.try_into()?)),
+      ),
+      v1::ImportDefinition::UnitsComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Units(c.try_into()?)),
+      ),
+      v1::ImportDefinition::ValidateComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Validate(c.try_into()?)),
+      ),
+      v1::ImportDefinition::GenerateComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Generate(c.try_into()?)),
+      ),
+      v1::ImportDefinition::FlagsComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Flags(c.try_into()?)),
+      ),
+      v1::ImportDefinition::I18nComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::I18n(c.try_into()?)),
+      ),
+      v1::ImportDefinition::MetricsComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Metrics(c.try_into()?)),
+      ),
+      v1::ImportDefinition::BrokerComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Broker(c.try_into()?)),
+      ),
+      v1::ImportDefinition::CacheComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Cache(c.try_into()?)),
+      ),
+      v1::ImportDefinition::ObjectStoreComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::ObjectStore(c.try_into()?)),
+      ),
+      v1::ImportDefinition::FilesystemComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Filesystem(c.try_into()?)),
+      ),
+      v1::ImportDefinition::DataComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Data(c.try_into()?)),
+      ),
+      v1::ImportDefinition::CryptoComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Crypto(c.try_into()?)),
+      ),
+    })
+  }
+}
+
+impl TryFrom<v1::TypesComponent> for config::components::TypesComponent {
+  type Error = ManifestError;
+
+  fn try_from(value: v1::TypesComponent) -> std::result::Result<Self, Self::Error> {
+    Ok(Self {
+      reference: value.reference.try_into()?,
+      types: value.types,
+    })
+  }
+}
+
+impl TryFrom<v1::ResourceBinding> for Binding<config::ResourceDefinition> {
+  type Error = ManifestError;
+  fn try_from(value: v1::ResourceBinding) -> Result<Self> {
+    Ok(Self {
+      id: value.name.into(),
+      kind: value.resource.try_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::helpers::LocationReference> for AssetReference {
+  type Error = crate::Error;
+  fn try_from(value: v1::helpers::LocationReference) -> Result<Self> {
+    Ok(value.0.try_into()?)
+  }
+}
+
+impl TryFrom<AssetReference> for v1::helpers::LocationReference {
+  type Error = crate::Error;
+  fn try_from(value: AssetReference) -> Result<Self> {
+    Ok(Self(value.location().to_owned()))
+  }
+}
+
+impl TryFrom<v1::Metadata> for config::Metadata {
+  type Error = crate::Error;
+  fn try_from(value: v1::Metadata) -> Result<Self> {
+    Ok(Self {
+      version: value.version,
+      authors: value.authors,
+      vendors: value.vendors,
+      description: value.description,
+      documentation: value.documentation,
+      licenses: value.licenses,
+      icon: value.icon.try_map_into()?,
+      requires: value.requires,
+    })
+  }
+}
+
+impl TryFrom<config::Metadata> for v1::Metadata {
+  type Error = crate::Error;
+  fn try_from(value: config::Metadata) -> Result<Self> {
+    Ok(Self {
+      version: value.version,
+      authors: value.authors,
+      vendors: value.vendors,
+      description: value.description,
+      documentation: value.documentation,
+      licenses: value.licenses,
+      icon: value.icon.try_map_into()?,
+      requires: value.requires,
+    })
+  }
+}
+
+impl TryFrom<v1::SqlComponent> for components::SqlComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::SqlComponent) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.into(),
+      tls: value.tls,
+      config: value.with.try_map_into()?,
+      operations: value
+        .operations
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<_>>()?,
+      migrations: value.migrations.into_iter().map(Into::into).collect(),
+    })
+  }
+}
+
+impl From<v1::SqlMigration> for components::SqlMigration {
+  fn from(value: v1::SqlMigration) -> Self {
+    Self {
+      name: value.name,
+      sql: value.sql,
+    }
+  }
+}
+
+impl From<components::SqlMigration> for v1::SqlMigration {
+  fn from(value: components::SqlMigration) -> Self {
+    Self {
+      name: value.name,
+      sql: value.sql,
+    }
+  }
+}
+
+impl TryFrom<v1::SqlQueryKind> for components::SqlOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::SqlQueryKind) -> Result<Self> {
+    Ok(match value {
+      v1::SqlQueryKind::SqlQueryOperationDefinition(v) => Self::Query(v.try_into()?),
+      v1::SqlQueryKind::SqlExecOperationDefinition(v) => Self::Exec(v.try_into()?),
+    })
+  }
+}
+
+impl TryFrom<v1::SqlQueryOperationDefinition> for components::SqlQueryOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::SqlQueryOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      inputs: value.inputs.try_map_into()?,
+      outputs: value.outputs.try_map_into()?,
+      query: value.query,
+      arguments: value.arguments,
+      config: value.with.try_map_into()?,
+      on_error: value.on_error.unwrap_or_default().try_into()?,
+      transaction: value.transaction.unwrap_or_default().into(),
+    })
+  }
+}
+
+impl TryFrom<v1::SqlExecOperationDefinition> for components::SqlExecOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::SqlExecOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      inputs: value.inputs.try_map_into()?,
+      outputs: value.outputs.try_map_into()?,
+      exec: value.exec,
+      arguments: value.arguments,
+      config: value.with.try_map_into()?,
+      on_error: value.on_error.unwrap_or_default().try_into()?,
+      transaction: value.transaction.unwrap_or_default().into(),
+      batch: value.batch,
+    })
+  }
+}
+
+impl TryFrom<v1::HttpClientOperationDefinition> for components::HttpClientOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::HttpClientOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      codec: value.codec.map_into(),
+      inputs: value.inputs.try_map_into()?,
+      path: value.path,
+      body: value.body,
+      method: value.method.into(),
+      config: value.with.try_map_into()?,
+      headers: value.headers,
+      timeout: value.timeout,
+      redirects: value.redirects,
+      proxy: value.proxy.try_map_into()?,
+      body_stream: value.body_stream,
+      stream_response: value.stream_response,
+    })
+  }
+}
+
+impl From<v1::HttpMethod> for config::HttpMethod {
+  fn from(value: v1::HttpMethod) -> Self {
+    match value {
+      v1::HttpMethod::Get => Self::Get,
+      v1::HttpMethod::Post => Self::Post,
+      v1::HttpMethod::Put => Self::Put,
+      v1::HttpMethod::Delete => Self::Delete,
+    }
+  }
+}
+
+impl From<config::HttpMethod> for v1::HttpMethod {
+  fn from(value: config::HttpMethod) -> Self {
+    match value {
+      config::HttpMethod::Get => Self::Get,
+      config::HttpMethod::Post => Self::Post,
+      config::HttpMethod::Put => Self::Put,
+      config::HttpMethod::Delete => Self::Delete,
+    }
+  }
+}
+
+impl TryFrom<v1::HttpClientComponent> for components::HttpClientComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::HttpClientComponent) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.into(),
+      config: value.with.try_map_into()?,
+      codec: value.codec.map_into(),
+      proxy: value.proxy.try_map_into()?,
+      timeout: value.timeout,
+      redirects: value.redirects,
+      pool: value.pool.map_into(),
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::ArchiveComponent> for components::ArchiveComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::ArchiveComponent) -> Result<Self> {
+    Ok(Self {
+      format: value.format.into(),
+      max_entry_size: value.max_entry_size,
+      max_total_size: value.max_total_size,
+      max_entries: value.max_entries,
+      config: value.with.try_map_into()?,
+    })
+  }
+}
+
+/// Combine the timeout and retry policy of an operation reference into its [ExecutionSettings].
+fn execution_settings(timeout: Option<u64>, retry: Option<v1::RetryPolicy>) -> Option<ExecutionSettings> {
+  if timeout.is_none() && retry.is_none() {
+    return None;
+  }
+  Some(ExecutionSettings::new(timeout.map(Duration::from_millis)).with_retry(retry.map_into()))
+}
+
+impl From<v1::RetryPolicy> for config::RetryPolicy {
+  fn from(value: v1::RetryPolicy) -> Self {
+    Self::new(
+      value.max_attempts,
+      Duration::from_millis(value.initial_backoff),
+      Duration::from_millis(value.max_backoff),
+    )
+    .retry_only(value.retry_on.map_into())
+  }
+}
+
+impl From<config::RetryPolicy> for v1::RetryPolicy {
+  fn from(value: config::RetryPolicy) -> Self {
+    Self {
+      max_attempts: value.max_attempts,
+      initial_backoff: value.initial_backoff.as_millis() as _,
+      max_backoff: value.max_backoff.as_millis() as _,
+      retry_on: value.retry_on.map_into(),
+    }
+  }
+}
+
+impl From<v1::RetryCondition> for config::RetryCondition {
+  fn from(value: v1::RetryCondition) -> Self {
+    match value {
+      v1::RetryCondition::Error => Self::Error,
+      v1::RetryCondition::Timeout => Self::Timeout,
+      v1::RetryCondition::Panic => Self::Panic,
+    }
+  }
+}
+
+impl From<config::RetryCondition> for v1::RetryCondition {
+  fn from(value: config::RetryCondition) -> Self {
+    match value {
+      config::RetryCondition::Error => Self::Error,
+      config::RetryCondition::Timeout => Self::Timeout,
+      config::RetryCondition::Panic => Self::Panic,
+    }
+  }
+}
+
+impl From<v1::ArchiveFormat> for components::ArchiveFormat {
+  fn from(value: v1::ArchiveFormat) -> Self {
+    match value {
+      v1::ArchiveFormat::Tar => Self::Tar,
+      v1::ArchiveFormat::Zip => Self::Zip,
+    }
+  }
+}
+
+impl TryFrom<v1::TextComponent> for components::TextComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::TextComponent) -> Result<Self> {
+    Ok(Self {
+      templates: value.templates.map(Into::into),
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::TextOperationDefinition> for components::TextOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::TextOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      kind: value.kind.into(),
+      config: value.with.try_map_into()?,
+      inputs: value.inputs.try_map_into()?,
+      pattern: value.pattern,
+      replacement: value.replacement,
+      separator: value.separator,
+      template: value.template,
+      file: value.file,
+      context: value.context,
+    })
+  }
+}
+
+impl From<v1::TextOperationKind> for components::TextOperationKind {
+  fn from(value: v1::TextOperationKind) -> Self {
+    match value {
+      v1::TextOperationKind::Match => Self::Match,
+      v1::TextOperationKind::Replace => Self::Replace,
+      v1::TextOperationKind::Captures => Self::Captures,
+      v1::TextOperationKind::Split => Self::Split,
+      v1::TextOperationKind::Join => Self::Join,
+      v1::TextOperationKind::Template => Self::Template,
+      v1::TextOperationKind::Diff => Self::Diff,
+    }
+  }
+}
+
+impl TryFrom<v1::UnitsComponent> for components::UnitsComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::UnitsComponent) -> Result<Self> {
+    Ok(Self {
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::UnitsOperationDefinition> for components::UnitsOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::UnitsOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      kind: value.kind.into(),
+      config: value.with.try_map_into()?,
+      rounding: value.rounding.map(Into::into),
+      ratios: value.ratios,
+      currency: value.currency,
+      from: value.from,
+      to: value.to,
+      precision: value.precision,
+    })
+  }
+}
+
+impl From<v1::UnitsOperationKind> for components::UnitsOperationKind {
+  fn from(value: v1::UnitsOperationKind) -> Self {
+    match value {
+      v1::UnitsOperationKind::Add => Self::Add,
+      v1::UnitsOperationKind::Subtract => Self::Subtract,
+      v1::UnitsOperationKind::Multiply => Self::Multiply,
+      v1::UnitsOperationKind::Allocate => Self::Allocate,
+      v1::UnitsOperationKind::Parse => Self::Parse,
+      v1::UnitsOperationKind::Format => Self::Format,
+      v1::UnitsOperationKind::Convert => Self::Convert,
+    }
+  }
+}
+
+impl From<v1::RoundingMode> for components::RoundingMode {
+  fn from(value: v1::RoundingMode) -> Self {
+    match value {
+      v1::RoundingMode::HalfEven => Self::HalfEven,
+      v1::RoundingMode::HalfUp => Self::HalfUp,
+      v1::RoundingMode::HalfDown => Self::HalfDown,
+      v1::RoundingMode::Up => Self::Up,
+      v1::RoundingMode::Down => Self::Down,
+      v1::RoundingMode::Ceiling => Self::Ceiling,
+      v1::RoundingMode::Floor => Self::Floor,
+    }
+  }
+}
+
+impl TryFrom<v1::ValidateComponent> for components::ValidateComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::ValidateComponent) -> Result<Self> {
+    Ok(Self {
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::ValidateOperationDefinition> for components::ValidateOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::ValidateOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      kind: value.kind.into(),
+      config: value.with.try_map_into()?,
+      schemes: value.schemes,
+      country_code: value.country_code,
+    })
   }
 }
 
-impl TryFrom<v1::ImportBinding> for Binding<config::ImportDefinition> {
-  type Error = ManifestError;
-  fn try_from(value: v1::ImportBinding) -> Result<Self> {
+impl From<v1::ValidateOperationKind> for components::ValidateOperationKind {
+  fn from(value: v1::ValidateOperationKind) -> Self {
+    match value {
+      v1::ValidateOperationKind::Email => Self::Email,
+      v1::ValidateOperationKind::Url => Self::Url,
+      v1::ValidateOperationKind::Phone => Self::Phone,
+    }
+  }
+}
+
+impl TryFrom<v1::GenerateComponent> for components::GenerateComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::GenerateComponent) -> Result<Self> {
     Ok(Self {
-      id: value.name.into(),
-      kind: value.component.try_into()?,
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
     })
   }
 }
 
-impl TryFrom<v1::ImportDefinition> for config::ImportDefinition {
-  type Error = ManifestError;
-  fn try_from(value: v1::ImportDefinition) -> Result<Self> {
-    Ok(match value {
-      v1::ImportDefinition::TypesComponent(c) => config::ImportDefinition::Types(c.try_into()?),
-      v1::ImportDefinition::ManifestComponent(c) => {
-        let c = v1::ComponentDefinition::ManifestComponent(c);
-        config::ImportDefinition::Component(c.try_into()?)
-      }
-      v1::ImportDefinition::SqlComponent(c) => config::ImportDefinition::Component(
-        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::Sql(c.try_into()?)),
-      ),
-      v1::ImportDefinition::HttpClientComponent(c) => config::ImportDefinition::Component(
-        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::HttpClient(c.try_into()?)),
-      ),
+impl TryFrom<v1::GenerateOperationDefinition> for components::GenerateOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::GenerateOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      config: value.with.try_map_into()?,
+      ty: value.ty,
+      count: value.count,
+      seed: value.seed,
+      fields: value.fields.map_into(),
     })
   }
 }
 
-impl TryFrom<v1::TypesComponent> for config::components::TypesComponent {
-  type Error = ManifestError;
+impl From<v1::GenerateField> for components::GenerateField {
+  fn from(value: v1::GenerateField) -> Self {
+    Self {
+      name: value.name,
+      strategy: value.strategy.into(),
+      min: value.min,
+      max: value.max,
+      values: value.values,
+    }
+  }
+}
 
-  fn try_from(value: v1::TypesComponent) -> std::result::Result<Self, Self::Error> {
+impl From<v1::GenerateStrategy> for components::GenerateStrategy {
+  fn from(value: v1::GenerateStrategy) -> Self {
+    match value {
+      v1::GenerateStrategy::FirstName => Self::FirstName,
+      v1::GenerateStrategy::LastName => Self::LastName,
+      v1::GenerateStrategy::FullName => Self::FullName,
+      v1::GenerateStrategy::Username => Self::Username,
+      v1::GenerateStrategy::Email => Self::Email,
+      v1::GenerateStrategy::Phone => Self::Phone,
+      v1::GenerateStrategy::Company => Self::Company,
+      v1::GenerateStrategy::City => Self::City,
+      v1::GenerateStrategy::Country => Self::Country,
+      v1::GenerateStrategy::Word => Self::Word,
+      v1::GenerateStrategy::Sentence => Self::Sentence,
+      v1::GenerateStrategy::Uuid => Self::Uuid,
+      v1::GenerateStrategy::Integer => Self::Integer,
+      v1::GenerateStrategy::Float => Self::Float,
+      v1::GenerateStrategy::Bool => Self::Bool,
+      v1::GenerateStrategy::Datetime => Self::Datetime,
+      v1::GenerateStrategy::Sequence => Self::Sequence,
+      v1::GenerateStrategy::OneOf => Self::OneOf,
+    }
+  }
+}
+
+impl TryFrom<v1::FlagsComponent> for components::FlagsComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::FlagsComponent) -> Result<Self> {
     Ok(Self {
-      reference: value.reference.try_into()?,
-      types: value.types,
+      resource: value.resource.into(),
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
     })
   }
 }
 
-impl TryFrom<v1::ResourceBinding> for Binding<config::ResourceDefinition> {
-  type Error = ManifestError;
-  fn try_from(value: v1::ResourceBinding) -> Result<Self> {
+impl TryFrom<v1::FlagsOperationDefinition> for components::FlagsOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::FlagsOperationDefinition) -> Result<Self> {
     Ok(Self {
-      id: value.name.into(),
-      kind: value.resource.try_into()?,
+      name: value.name,
+      config: value.with.try_map_into()?,
+      flag: value.flag,
     })
   }
 }
 
-impl TryFrom<v1::helpers::LocationReference> for AssetReference {
+impl TryFrom<v1::I18nComponent> for components::I18nComponentConfig {
   type Error = crate::Error;
-  fn try_from(value: v1::helpers::LocationReference) -> Result<Self> {
-    Ok(value.0.try_into()?)
+  fn try_from(value: v1::I18nComponent) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.into(),
+      fallback: value.fallback,
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
   }
 }
 
-impl TryFrom<AssetReference> for v1::helpers::LocationReference {
+impl TryFrom<v1::I18nOperationDefinition> for components::I18nOperationDefinition {
   type Error = crate::Error;
-  fn try_from(value: AssetReference) -> Result<Self> {
-    Ok(Self(value.location().to_owned()))
+  fn try_from(value: v1::I18nOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      config: value.with.try_map_into()?,
+    })
   }
 }
 
-impl TryFrom<v1::Metadata> for config::Metadata {
+impl TryFrom<v1::MetricsComponent> for components::MetricsComponentConfig {
   type Error = crate::Error;
-  fn try_from(value: v1::Metadata) -> Result<Self> {
+  fn try_from(value: v1::MetricsComponent) -> Result<Self> {
     Ok(Self {
-      version: value.version,
-      authors: value.authors,
-      vendors: value.vendors,
-      description: value.description,
-      documentation: value.documentation,
-      licenses: value.licenses,
-      icon: value.icon.try_map_into()?,
+      resource: value.resource.into(),
+      protocol: value.protocol.into(),
+      labels: value.labels,
+      batch_size: value.batch_size,
+      flush_interval: value.flush_interval,
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
     })
   }
 }
 
-impl TryFrom<config::Metadata> for v1::Metadata {
+impl TryFrom<v1::MetricsOperationDefinition> for components::MetricsOperationDefinition {
   type Error = crate::Error;
-  fn try_from(value: config::Metadata) -> Result<Self> {
+  fn try_from(value: v1::MetricsOperationDefinition) -> Result<Self> {
     Ok(Self {
-      version: value.version,
-      authors: value.authors,
-      vendors: value.vendors,
-      description: value.description,
-      documentation: value.documentation,
-      licenses: value.licenses,
-      icon: value.icon.try_map_into()?,
+      name: value.name,
+      config: value.with.try_map_into()?,
+      metric: value.metric.into(),
     })
   }
 }
 
-impl TryFrom<v1::SqlComponent> for components::SqlComponentConfig {
+impl From<v1::MetricsProtocol> for components::MetricsProtocol {
+  fn from(value: v1::MetricsProtocol) -> Self {
+    match value {
+      v1::MetricsProtocol::RemoteWrite => Self::RemoteWrite,
+      v1::MetricsProtocol::Statsd => Self::Statsd,
+    }
+  }
+}
+
+impl From<v1::MetricKind> for components::MetricKind {
+  fn from(value: v1::MetricKind) -> Self {
+    match value {
+      v1::MetricKind::Gauge => Self::Gauge,
+      v1::MetricKind::Counter => Self::Counter,
+      v1::MetricKind::Timing => Self::Timing,
+    }
+  }
+}
+
+impl TryFrom<v1::BrokerComponent> for components::BrokerComponentConfig {
   type Error = crate::Error;
-  fn try_from(value: v1::SqlComponent) -> Result<Self> {
+  fn try_from(value: v1::BrokerComponent) -> Result<Self> {
     Ok(Self {
       resource: value.resource.into(),
-      tls: value.tls,
       config: value.with.try_map_into()?,
-      operations: value
-        .operations
-        .into_iter()
-        .map(TryInto::try_into)
-        .collect::<Result<_>>()?,
+      operations: value.operations.try_map_into()?,
     })
   }
 }
 
-impl TryFrom<v1::SqlQueryKind> for components::SqlOperationDefinition {
+impl TryFrom<v1::BrokerOperationDefinition> for components::BrokerOperationDefinition {
   type Error = crate::Error;
-  fn try_from(value: v1::SqlQueryKind) -> Result<Self> {
-    Ok(match value {
-      v1::SqlQueryKind::SqlQueryOperationDefinition(v) => Self::Query(v.try_into()?),
-      v1::SqlQueryKind::SqlExecOperationDefinition(v) => Self::Exec(v.try_into()?),
+  fn try_from(value: v1::BrokerOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      config: value.with.try_map_into()?,
+      topic: value.topic,
+      inputs: value.inputs.try_map_into()?,
     })
   }
 }
 
-impl TryFrom<v1::SqlQueryOperationDefinition> for components::SqlQueryOperationDefinition {
+impl TryFrom<v1::CacheComponent> for components::CacheComponentConfig {
   type Error = crate::Error;
-  fn try_from(value: v1::SqlQueryOperationDefinition) -> Result<Self> {
+  fn try_from(value: v1::CacheComponent) -> Result<Self> {
+    Ok(Self {
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::CacheOperationDefinition> for components::CacheOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::CacheOperationDefinition) -> Result<Self> {
     Ok(Self {
       name: value.name,
+      config: value.with.try_map_into()?,
       inputs: value.inputs.try_map_into()?,
       outputs: value.outputs.try_map_into()?,
-      query: value.query,
-      arguments: value.arguments,
+      key: value.key,
+      compute: value.compute,
+      ttl: value.ttl,
+      stale: value.stale,
+    })
+  }
+}
+
+impl TryFrom<v1::ObjectStoreComponent> for components::ObjectStoreComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::ObjectStoreComponent) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.into(),
+      bucket: value.bucket,
+      region: value.region,
+      access_key_id: value.access_key_id.into(),
+      secret_access_key: value.secret_access_key.into(),
+      session_token: value.session_token.map(Into::into),
+      path_style: value.path_style,
       config: value.with.try_map_into()?,
-      on_error: value.on_error.unwrap_or_default().try_into()?,
+      operations: value.operations.try_map_into()?,
     })
   }
 }
 
-impl TryFrom<v1::SqlExecOperationDefinition> for components::SqlExecOperationDefinition {
+impl TryFrom<v1::ObjectStoreOperationDefinition> for components::ObjectStoreOperationDefinition {
   type Error = crate::Error;
-  fn try_from(value: v1::SqlExecOperationDefinition) -> Result<Self> {
+  fn try_from(value: v1::ObjectStoreOperationDefinition) -> Result<Self> {
     Ok(Self {
       name: value.name,
-      inputs: value.inputs.try_map_into()?,
-      outputs: value.outputs.try_map_into()?,
-      exec: value.exec,
-      arguments: value.arguments,
       config: value.with.try_map_into()?,
-      on_error: value.on_error.unwrap_or_default().try_into()?,
+      action: value.action.into(),
+      method: value.method.map(Into::into),
+      expires: value.expires,
+      page_size: value.page_size,
     })
   }
 }
 
-impl TryFrom<v1::HttpClientOperationDefinition> for components::HttpClientOperationDefinition {
+impl From<v1::ObjectStoreAction> for components::ObjectStoreAction {
+  fn from(value: v1::ObjectStoreAction) -> Self {
+    match value {
+      v1::ObjectStoreAction::Get => Self::Get,
+      v1::ObjectStoreAction::Put => Self::Put,
+      v1::ObjectStoreAction::List => Self::List,
+      v1::ObjectStoreAction::Presign => Self::Presign,
+    }
+  }
+}
+
+impl TryFrom<v1::FilesystemComponent> for components::FilesystemComponentConfig {
   type Error = crate::Error;
-  fn try_from(value: v1::HttpClientOperationDefinition) -> Result<Self> {
+  fn try_from(value: v1::FilesystemComponent) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.into(),
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::FilesystemOperationDefinition> for components::FilesystemOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::FilesystemOperationDefinition) -> Result<Self> {
     Ok(Self {
       name: value.name,
-      codec: value.codec.map_into(),
-      inputs: value.inputs.try_map_into()?,
-      path: value.path,
-      body: value.body,
-      method: value.method.into(),
       config: value.with.try_map_into()?,
-      headers: value.headers,
+      action: value.action.into(),
+      recursive: value.recursive,
+      interval: value.interval,
     })
   }
 }
 
-impl From<v1::HttpMethod> for config::HttpMethod {
-  fn from(value: v1::HttpMethod) -> Self {
+impl From<v1::FilesystemAction> for components::FilesystemAction {
+  fn from(value: v1::FilesystemAction) -> Self {
     match value {
-      v1::HttpMethod::Get => Self::Get,
-      v1::HttpMethod::Post => Self::Post,
-      v1::HttpMethod::Put => Self::Put,
-      v1::HttpMethod::Delete => Self::Delete,
+      v1::FilesystemAction::Watch => Self::Watch,
     }
   }
 }
 
-impl From<config::HttpMethod> for v1::HttpMethod {
-  fn from(value: config::HttpMethod) -> Self {
+impl TryFrom<v1::DataComponent> for components::DataComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::DataComponent) -> Result<Self> {
+    Ok(Self {
+      config: value.with.try_map_into()?,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
+impl TryFrom<v1::DataOperationDefinition> for components::DataOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::DataOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      config: value.with.try_map_into()?,
+      action: value.action.into(),
+      format: value.format.into(),
+      delimiter: value.delimiter,
+      columns: value.columns,
+      element: value.element,
+      root: value.root,
+      mapping: value.mapping,
+      typed: value.typed,
+    })
+  }
+}
+
+impl From<v1::DataAction> for components::DataAction {
+  fn from(value: v1::DataAction) -> Self {
     match value {
-      config::HttpMethod::Get => Self::Get,
-      config::HttpMethod::Post => Self::Post,
-      config::HttpMethod::Put => Self::Put,
-      config::HttpMethod::Delete => Self::Delete,
+      v1::DataAction::Parse => Self::Parse,
+      v1::DataAction::Serialize => Self::Serialize,
     }
   }
 }
 
-impl TryFrom<v1::HttpClientComponent> for components::HttpClientComponentConfig {
+impl From<v1::DataFormat> for components::DataFormat {
+  fn from(value: v1::DataFormat) -> Self {
+    match value {
+      v1::DataFormat::Csv => Self::Csv,
+      v1::DataFormat::Ndjson => Self::Ndjson,
+      v1::DataFormat::Xml => Self::Xml,
+    }
+  }
+}
+
+impl TryFrom<v1::CryptoComponent> for components::CryptoComponentConfig {
   type Error = crate::Error;
-  fn try_from(value: v1::HttpClientComponent) -> Result<Self> {
+  fn try_from(value: v1::CryptoComponent) -> Result<Self> {
     Ok(Self {
-      resource: value.resource.into(),
       config: value.with.try_map_into()?,
-      codec: value.codec.map_into(),
-      proxy: value.proxy.try_map_into()?,
-      timeout: value.timeout,
       operations: value.operations.try_map_into()?,
     })
   }
 }
 
+impl TryFrom<v1::CryptoOperationDefinition> for components::CryptoOperationDefinition {
+  type Error = crate::Error;
+  fn try_from(value: v1::CryptoOperationDefinition) -> Result<Self> {
+    Ok(Self {
+      name: value.name,
+      config: value.with.try_map_into()?,
+      action: value.action.into(),
+      algorithm: value.algorithm.into(),
+      key: value.key.map(Into::into),
+      encoding: value.encoding.map(Into::into),
+      issuer: value.issuer,
+      audience: value.audience,
+      expires: value.expires,
+      leeway: value.leeway,
+    })
+  }
+}
+
+impl From<v1::CryptoAction> for components::CryptoAction {
+  fn from(value: v1::CryptoAction) -> Self {
+    match value {
+      v1::CryptoAction::Hash => Self::Hash,
+      v1::CryptoAction::Hmac => Self::Hmac,
+      v1::CryptoAction::Sign => Self::Sign,
+      v1::CryptoAction::Verify => Self::Verify,
+      v1::CryptoAction::JwtIssue => Self::JwtIssue,
+      v1::CryptoAction::JwtVerify => Self::JwtVerify,
+    }
+  }
+}
+
+impl From<v1::CryptoAlgorithm> for components::CryptoAlgorithm {
+  fn from(value: v1::CryptoAlgorithm) -> Self {
+    match value {
+      v1::CryptoAlgorithm::Sha256 => Self::Sha256,
+      v1::CryptoAlgorithm::Sha384 => Self::Sha384,
+      v1::CryptoAlgorithm::Sha512 => Self::Sha512,
+      v1::CryptoAlgorithm::Blake3 => Self::Blake3,
+      v1::CryptoAlgorithm::Ed25519 => Self::Ed25519,
+      v1::CryptoAlgorithm::EcdsaP256 => Self::EcdsaP256,
+      v1::CryptoAlgorithm::EcdsaP384 => Self::EcdsaP384,
+    }
+  }
+}
+
+impl From<v1::CryptoEncoding> for components::CryptoEncoding {
+  fn from(value: v1::CryptoEncoding) -> Self {
+    match value {
+      v1::CryptoEncoding::Hex => Self::Hex,
+      v1::CryptoEncoding::Base64 => Self::Base64,
+      v1::CryptoEncoding::Base64Url => Self::Base64Url,
+    }
+  }
+}
+
+impl From<v1::ConnectionPool> for components::ConnectionPool {
+  fn from(value: v1::ConnectionPool) -> Self {
+    Self {
+      max_idle_per_host: value.max_idle_per_host,
+      idle_timeout: value.idle_timeout,
+      keep_alive: value.keep_alive,
+      http2_prior_knowledge: value.http2_prior_knowledge,
+    }
+  }
+}
+
 impl TryFrom<v1::Proxy> for components::Proxy {
   type Error = crate::Error;
   fn try_from(value: v1::Proxy) -> Result<Self> {
@@ -1039,6 +2804,7 @@ impl TryFrom<components::SqlComponentConfig> for v1::SqlComponent {
       with: value.config.try_map_into()?,
       tls: value.tls,
       operations: value.operations.try_map_into()?,
+      migrations: value.migrations.into_iter().map(Into::into).collect(),
     })
   }
 }
@@ -1064,6 +2830,7 @@ impl TryFrom<components::SqlQueryOperationDefinition> for v1::SqlQueryOperationD
       query: value.query,
       arguments: value.arguments,
       on_error: Some(value.on_error.try_into()?),
+      transaction: Some(value.transaction.into()),
       with: value.config.try_map_into()?,
     })
   }
@@ -1079,6 +2846,8 @@ impl TryFrom<components::SqlExecOperationDefinition> for v1::SqlExecOperationDef
       exec: value.exec,
       arguments: value.arguments,
       on_error: Some(value.on_error.try_into()?),
+      transaction: Some(value.transaction.into()),
+      batch: value.batch,
       with: value.config.try_map_into()?,
     })
   }
@@ -1106,6 +2875,28 @@ impl TryFrom<v1::ErrorBehavior> for config::ErrorBehavior {
   }
 }
 
+impl From<components::SqlTransaction> for v1::SqlTransaction {
+  fn from(value: components::SqlTransaction) -> Self {
+    match value {
+      components::SqlTransaction::Join => Self::Join,
+      components::SqlTransaction::Begin => Self::Begin,
+      components::SqlTransaction::Commit => Self::Commit,
+      components::SqlTransaction::Rollback => Self::Rollback,
+    }
+  }
+}
+
+impl From<v1::SqlTransaction> for components::SqlTransaction {
+  fn from(value: v1::SqlTransaction) -> Self {
+    match value {
+      v1::SqlTransaction::Join => Self::Join,
+      v1::SqlTransaction::Begin => Self::Begin,
+      v1::SqlTransaction::Commit => Self::Commit,
+      v1::SqlTransaction::Rollback => Self::Rollback,
+    }
+  }
+}
+
 impl TryFrom<components::HttpClientOperationDefinition> for v1::HttpClientOperationDefinition {
   type Error = crate::Error;
   fn try_from(value: components::HttpClientOperationDefinition) -> Result<Self> {
@@ -1118,6 +2909,11 @@ impl TryFrom<components::HttpClientOperationDefinition> for v1::HttpClientOperat
       method: value.method.into(),
       with: value.config.try_map_into()?,
       headers: value.headers,
+      timeout: value.timeout,
+      redirects: value.redirects,
+      proxy: value.proxy.try_map_into()?,
+      body_stream: value.body_stream,
+      stream_response: value.stream_response,
     })
   }
 }
@@ -8,6 +8,20 @@ impl ComponentDefinition {
       ComponentDefinition::ComponentReference(v) => Some(&v.id),
       ComponentDefinition::SqlComponent(_) => todo!(),
       ComponentDefinition::HttpClientComponent(_) => todo!(),
+      ComponentDefinition::ArchiveComponent(_) => None,
+      ComponentDefinition::TextComponent(_) => None,
+      ComponentDefinition::UnitsComponent(_) => None,
+      ComponentDefinition::ValidateComponent(_) => None,
+      ComponentDefinition::GenerateComponent(_) => None,
+      ComponentDefinition::FlagsComponent(_) => None,
+      ComponentDefinition::I18nComponent(_) => None,
+      ComponentDefinition::MetricsComponent(_) => None,
+      ComponentDefinition::BrokerComponent(_) => None,
+      ComponentDefinition::CacheComponent(_) => None,
+      ComponentDefinition::ObjectStoreComponent(_) => None,
+      ComponentDefinition::FilesystemComponent(_) => None,
+      ComponentDefinition::DataComponent(_) => None,
+      ComponentDefinition::CryptoComponent(_) => None,
     }
   }
 }
@@ -13,6 +13,7 @@ impl TryFrom<v1::ResourceRestriction> for config::ResourceRestriction {
       v1::ResourceRestriction::UrlRestriction(v) => Self::Url(v.try_into()?),
       v1::ResourceRestriction::TcpPortRestriction(v) => Self::TcpPort(v.try_into()?),
       v1::ResourceRestriction::UdpPortRestriction(v) => Self::UdpPort(v.try_into()?),
+      v1::ResourceRestriction::SecretRestriction(v) => Self::Secret(v.try_into()?),
     })
   }
 }
@@ -26,6 +27,7 @@ impl TryFrom<config::ResourceRestriction> for v1::ResourceRestriction {
       config::ResourceRestriction::Url(v) => v1::ResourceRestriction::UrlRestriction(v.try_into()?),
       config::ResourceRestriction::TcpPort(v) => v1::ResourceRestriction::TcpPortRestriction(v.try_into()?),
       config::ResourceRestriction::UdpPort(v) => v1::ResourceRestriction::UdpPortRestriction(v.try_into()?),
+      config::ResourceRestriction::Secret(v) => v1::ResourceRestriction::SecretRestriction(v.try_into()?),
     })
   }
 }
@@ -121,3 +123,27 @@ impl TryFrom<config::PortRestriction> for v1::UdpPortRestriction {
     })
   }
 }
+
+impl TryFrom<v1::SecretRestriction> for config::SecretRestriction {
+  type Error = ManifestError;
+
+  fn try_from(value: v1::SecretRestriction) -> Result<Self> {
+    Ok(Self {
+      components: value.components,
+      provider: value.provider.into(),
+      allow: TemplateConfig::new_template(value.allow),
+    })
+  }
+}
+
+impl TryFrom<config::SecretRestriction> for v1::SecretRestriction {
+  type Error = ManifestError;
+
+  fn try_from(value: config::SecretRestriction) -> Result<Self> {
+    Ok(Self {
+      components: value.components,
+      provider: value.provider.into(),
+      allow: value.allow.unrender()?,
+    })
+  }
+}
@@ -14,6 +14,9 @@ impl TryFrom<config::TriggerDefinition> for v1::TriggerDefinition {
       config::TriggerDefinition::Cli(v) => v1::TriggerDefinition::CliTrigger(v.try_into()?),
       config::TriggerDefinition::Time(v) => v1::TriggerDefinition::TimeTrigger(v.try_into()?),
       config::TriggerDefinition::WasmCommand(v) => v1::TriggerDefinition::WasmCommandTrigger(v.try_into()?),
+      config::TriggerDefinition::Grpc(v) => v1::TriggerDefinition::GrpcTrigger(v.try_into()?),
+      config::TriggerDefinition::Log(v) => v1::TriggerDefinition::LogTrigger(v.try_into()?),
+      config::TriggerDefinition::Broker(v) => v1::TriggerDefinition::BrokerTrigger(v.try_into()?),
     })
   }
 }
@@ -61,6 +64,75 @@ impl TryFrom<v1::Schedule> for config::ScheduleConfig {
   }
 }
 
+impl TryFrom<config::LogTriggerConfig> for v1::LogTrigger {
+  type Error = ManifestError;
+  fn try_from(value: config::LogTriggerConfig) -> Result<Self> {
+    Ok(Self {
+      operation: value.operation.try_into()?,
+      sources: value.sources.map_into(),
+    })
+  }
+}
+
+impl TryFrom<config::BrokerTriggerConfig> for v1::BrokerTrigger {
+  type Error = ManifestError;
+  fn try_from(value: config::BrokerTriggerConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      topic: value.topic,
+      operation: value.operation.try_into()?,
+    })
+  }
+}
+
+impl From<config::LogSourceConfig> for v1::LogSource {
+  fn from(value: config::LogSourceConfig) -> Self {
+    Self {
+      resource: value.resource.id().to_owned(),
+      paths: value.paths,
+      format: value.format.into(),
+      pattern: value.pattern,
+      columns: value.columns,
+      delimiter: value.delimiter,
+      from_start: value.from_start,
+    }
+  }
+}
+
+impl From<v1::LogSource> for config::LogSourceConfig {
+  fn from(value: v1::LogSource) -> Self {
+    Self {
+      resource: value.resource.into(),
+      paths: value.paths,
+      format: value.format.into(),
+      pattern: value.pattern,
+      columns: value.columns,
+      delimiter: value.delimiter,
+      from_start: value.from_start,
+    }
+  }
+}
+
+impl From<v1::LogFormat> for config::LogFormat {
+  fn from(value: v1::LogFormat) -> Self {
+    match value {
+      v1::LogFormat::Json => Self::Json,
+      v1::LogFormat::Csv => Self::Csv,
+      v1::LogFormat::Regex => Self::Regex,
+    }
+  }
+}
+
+impl From<config::LogFormat> for v1::LogFormat {
+  fn from(value: config::LogFormat) -> Self {
+    match value {
+      config::LogFormat::Json => Self::Json,
+      config::LogFormat::Csv => Self::Csv,
+      config::LogFormat::Regex => Self::Regex,
+    }
+  }
+}
+
 // Implement conversion from OperationInputConfig to v1::OperationInput
 impl TryFrom<config::OperationInputConfig> for v1::OperationInput {
   type Error = ManifestError;
@@ -90,10 +162,31 @@ impl TryFrom<config::CliConfig> for v1::CliTrigger {
   fn try_from(value: config::CliConfig) -> Result<Self> {
     Ok(Self {
       operation: value.operation.try_into()?,
+      stdin: value.stdin.map(Into::into),
     })
   }
 }
 
+impl From<config::StdinMode> for v1::StdinMode {
+  fn from(value: config::StdinMode) -> Self {
+    match value {
+      config::StdinMode::Lines => Self::Lines,
+      config::StdinMode::Raw => Self::Raw,
+      config::StdinMode::Interactive => Self::Interactive,
+    }
+  }
+}
+
+impl From<v1::StdinMode> for config::StdinMode {
+  fn from(value: v1::StdinMode) -> Self {
+    match value {
+      v1::StdinMode::Lines => Self::Lines,
+      v1::StdinMode::Raw => Self::Raw,
+      v1::StdinMode::Interactive => Self::Interactive,
+    }
+  }
+}
+
 impl TryFrom<config::HttpTriggerConfig> for v1::HttpTrigger {
   type Error = ManifestError;
   fn try_from(value: config::HttpTriggerConfig) -> Result<Self> {
@@ -104,6 +197,18 @@ impl TryFrom<config::HttpTriggerConfig> for v1::HttpTrigger {
   }
 }
 
+impl TryFrom<config::GrpcTriggerConfig> for v1::GrpcTrigger {
+  type Error = ManifestError;
+  fn try_from(value: config::GrpcTriggerConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      package: value.package,
+      service: value.service,
+      operations: value.operations.try_map_into()?,
+    })
+  }
+}
+
 impl TryFrom<config::HttpRouterConfig> for v1::HttpRouter {
   type Error = ManifestError;
   fn try_from(value: config::HttpRouterConfig) -> Result<Self> {
@@ -124,6 +229,7 @@ impl TryFrom<config::ProxyRouterConfig> for v1::ProxyRouter {
       url: value.url.id().to_owned(),
       strip_path: value.strip_path,
       middleware: value.middleware.try_map_into()?,
+      cors: value.cors.map_into(),
     })
   }
 }
@@ -136,7 +242,9 @@ impl TryFrom<config::StaticRouterConfig> for v1::StaticRouter {
       volume: value.volume.id().to_owned(),
       fallback: value.fallback,
       middleware: value.middleware.try_map_into()?,
+      cors: value.cors.map_into(),
       indexes: value.indexes,
+      precompressed: value.precompressed,
     })
   }
 }
@@ -149,6 +257,8 @@ impl TryFrom<config::RawRouterConfig> for v1::RawRouter {
       codec: value.codec.map_into(),
       operation: value.operation.try_into()?,
       middleware: value.middleware.try_map_into()?,
+      auth: value.auth.try_map_into()?,
+      cors: value.cors.map_into(),
     })
   }
 }
@@ -162,6 +272,45 @@ impl TryFrom<config::RestRouterConfig> for v1::RestRouter {
       routes: value.routes.try_map_into()?,
       middleware: value.middleware.try_map_into()?,
       info: value.info.try_map_into()?,
+      auth: value.auth.try_map_into()?,
+      cors: value.cors.map_into(),
+    })
+  }
+}
+
+impl From<config::CorsConfig> for v1::Cors {
+  fn from(value: config::CorsConfig) -> Self {
+    Self {
+      allowed_origins: value.allowed_origins,
+      allowed_methods: value.allowed_methods,
+      allowed_headers: value.allowed_headers,
+      max_age: value.max_age,
+      allow_credentials: value.allow_credentials,
+    }
+  }
+}
+
+impl TryFrom<config::AuthConfig> for v1::Auth {
+  type Error = ManifestError;
+  fn try_from(value: config::AuthConfig) -> Result<Self> {
+    Ok(match value {
+      config::AuthConfig::Jwt(v) => v1::Auth::JwtAuth(v1::JwtAuth {
+        secret: v.secret.unrender()?,
+        issuer: v.issuer,
+        audience: v.audience,
+      }),
+      config::AuthConfig::ApiKey(v) => v1::Auth::ApiKeyAuth(v1::ApiKeyAuth {
+        header: v.header,
+        keys: v.keys.into_iter().map(|k| k.unrender()).collect::<Result<_>>()?,
+      }),
+      config::AuthConfig::Basic(v) => v1::Auth::BasicAuth(v1::BasicAuth {
+        realm: v.realm,
+        users: v
+          .users
+          .into_iter()
+          .map(|(user, password)| Ok((user, password.unrender()?)))
+          .collect::<Result<_>>()?,
+      }),
     })
   }
 }
@@ -215,6 +364,10 @@ impl TryFrom<v1::Route> for config::RestRoute {
       operation: value.operation.try_into()?,
       description: value.description,
       summary: value.summary,
+      response: value.response.into(),
+      max_body_size: value.max_body_size,
+      max_part_size: value.max_part_size,
+      validate: value.validate,
     })
   }
 }
@@ -230,10 +383,34 @@ impl TryFrom<config::RestRoute> for v1::Route {
       operation: value.operation.try_into()?,
       description: value.description,
       summary: value.summary,
+      response: value.response.into(),
+      max_body_size: value.max_body_size,
+      max_part_size: value.max_part_size,
+      validate: value.validate,
     })
   }
 }
 
+impl From<v1::ResponseMode> for config::ResponseMode {
+  fn from(value: v1::ResponseMode) -> Self {
+    match value {
+      v1::ResponseMode::Buffered => Self::Buffered,
+      v1::ResponseMode::Stream => Self::Stream,
+      v1::ResponseMode::Sse => Self::Sse,
+    }
+  }
+}
+
+impl From<config::ResponseMode> for v1::ResponseMode {
+  fn from(value: config::ResponseMode) -> Self {
+    match value {
+      config::ResponseMode::Buffered => Self::Buffered,
+      config::ResponseMode::Stream => Self::Stream,
+      config::ResponseMode::Sse => Self::Sse,
+    }
+  }
+}
+
 impl TryFrom<v1::Info> for config::Info {
   type Error = ManifestError;
 
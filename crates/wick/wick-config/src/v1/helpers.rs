@@ -29,6 +29,28 @@ impl Into<String> for super::ConnectionDefinition {
   }
 }
 
+/// Connections serialize to their short-form syntax unless they set something that syntax can't express.
+impl Serialize for super::ConnectionDefinition {
+  fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    if self.merge.is_none() && self.transform.is_none() {
+      return s.serialize_str(&Into::<String>::into(self.clone()));
+    }
+    let mut m = s.serialize_map(None)?;
+    m.serialize_entry("from", &self.from)?;
+    m.serialize_entry("to", &self.to)?;
+    if let Some(merge) = &self.merge {
+      m.serialize_entry("merge", merge)?;
+    }
+    if let Some(transform) = &self.transform {
+      m.serialize_entry("transform", transform)?;
+    }
+    m.end()
+  }
+}
+
 #[allow(clippy::from_over_into)]
 impl Into<String> for super::ComponentReference {
   fn into(self) -> String {
@@ -34,10 +34,14 @@ impl TryFrom<ast::ConnectionExpression> for v1::ConnectionDefinition {
   type Error = ManifestError;
 
   fn try_from(value: ast::ConnectionExpression) -> Result<Self> {
+    let merge = value.merge().map(Into::into);
+    let transform = value.transform().cloned();
     let (from, to) = value.into_parts();
     Ok(Self {
       from: from.try_into()?,
       to: to.try_into()?,
+      merge,
+      transform,
     })
   }
 }
@@ -55,6 +59,17 @@ impl TryFrom<ast::ConnectionTargetExpression> for v1::ConnectionTargetDefinition
   }
 }
 
+impl From<ast::MergeStrategy> for v1::MergeStrategy {
+  fn from(value: ast::MergeStrategy) -> Self {
+    match value {
+      ast::MergeStrategy::Ordered => Self::Ordered,
+      ast::MergeStrategy::RoundRobin => Self::RoundRobin,
+      ast::MergeStrategy::Zip => Self::Zip,
+      ast::MergeStrategy::Priority => Self::Priority,
+    }
+  }
+}
+
 impl TryFrom<v1::FlowExpression> for ast::FlowExpression {
   type Error = ManifestError;
 
@@ -70,7 +85,22 @@ impl TryFrom<v1::ConnectionDefinition> for ast::ConnectionExpression {
   type Error = ManifestError;
 
   fn try_from(expr: v1::ConnectionDefinition) -> Result<Self> {
-    Ok(Self::new(expr.from.try_into()?, expr.to.try_into()?))
+    Ok(
+      Self::new(expr.from.try_into()?, expr.to.try_into()?)
+        .with_merge(expr.merge.map(Into::into))
+        .with_transform(expr.transform),
+    )
+  }
+}
+
+impl From<v1::MergeStrategy> for ast::MergeStrategy {
+  fn from(value: v1::MergeStrategy) -> Self {
+    match value {
+      v1::MergeStrategy::Ordered => Self::Ordered,
+      v1::MergeStrategy::RoundRobin => Self::RoundRobin,
+      v1::MergeStrategy::Zip => Self::Zip,
+      v1::MergeStrategy::Priority => Self::Priority,
+    }
   }
 }
 
@@ -88,7 +118,11 @@ impl TryFrom<&crate::v1::ConnectionDefinition> for ast::ConnectionExpression {
   fn try_from(def: &crate::v1::ConnectionDefinition) -> Result<Self> {
     let from: ast::ConnectionTargetExpression = def.from.clone().try_into()?;
     let to: ast::ConnectionTargetExpression = def.to.clone().try_into()?;
-    Ok(ast::ConnectionExpression::new(from, to))
+    Ok(
+      ast::ConnectionExpression::new(from, to)
+        .with_merge(def.merge.map(Into::into))
+        .with_transform(def.transform.clone()),
+    )
   }
 }
 
@@ -114,11 +114,11 @@ impl TryFrom<v1::Field> for wick::Field {
   type Error = ManifestError;
 
   fn try_from(value: v1::Field) -> Result<Self, Self::Error> {
-    Ok(Self::new_with_description(
-      value.name,
-      value.ty.try_into()?,
-      value.description,
-    ))
+    Ok(
+      Self::new_with_description(value.name, value.ty.try_into()?, value.description)
+        .with_default(value.default)
+        .with_content_type(value.content_type),
+    )
   }
 }
 
@@ -129,6 +129,8 @@ impl TryFrom<wick::Field> for v1::Field {
     Ok(Self {
       name: value.name,
       description: value.description,
+      default: value.default,
+      content_type: value.content_type,
       ty: value.ty.try_into()?,
     })
   }
@@ -174,6 +176,7 @@ impl TryFrom<v1::TypeSignature> for wick::Type {
         value: Box::new((*t.value).try_into()?),
       },
       v1::TypeSignature::Object(_) => TS::Object,
+      v1::TypeSignature::AnonymousStruct(t) => TS::AnonymousStruct(t.fields.try_map_into()?),
     };
     Ok(v)
   }
@@ -213,7 +216,9 @@ impl TryFrom<wick::Type> for v1::TypeSignature {
       #[allow(deprecated)]
       wick::Type::Link { .. } => unimplemented!(),
       wick::Type::Object => TS::Object(v1::Object {}),
-      wick::Type::AnonymousStruct(_) => unimplemented!(),
+      wick::Type::AnonymousStruct(fields) => TS::AnonymousStruct(v1::AnonymousStruct {
+        fields: fields.try_map_into()?,
+      }),
     };
     Ok(v)
   }
@@ -143,6 +143,7 @@ impl FromStr for v1::ComponentOperationExpression {
       component: crate::v1::ComponentDefinition::ComponentReference(crate::v1::ComponentReference { id }),
       with: None,
       timeout: None,
+      retry: None,
     })
   }
 }
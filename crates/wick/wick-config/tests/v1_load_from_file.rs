@@ -30,6 +30,61 @@ async fn test_types() -> Result<(), ManifestError> {
   Ok(())
 }
 
+#[test_logger::test(tokio::test)]
+async fn test_anonymous_struct_types() -> Result<(), ManifestError> {
+  let config = load("./tests/manifests/v1/anonymous-types.yaml").await?;
+  let types = config.clone().try_types_config()?;
+  let wick_interface_types::TypeDefinition::Struct(shape) = &types.types()[0] else {
+    panic!("expected a struct");
+  };
+  assert_eq!(
+    shape.fields[0].ty,
+    wick_interface_types::Type::AnonymousStruct(vec![
+      wick_interface_types::Field::new("x", wick_interface_types::Type::I32),
+      wick_interface_types::Field::new("y", wick_interface_types::Type::I32),
+    ])
+  );
+
+  let yaml = config.into_v1_yaml()?;
+  assert!(yaml.contains("type: '{x:i32,y:i32}'"));
+  assert!(yaml.contains("type: '{name:string,value:string?}[]'"));
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_recursive_types() -> Result<(), ManifestError> {
+  let types = load("./tests/manifests/v1/recursive-types.yaml")
+    .await?
+    .try_types_config()?;
+  assert_eq!(
+    wick_interface_types::recursive_fields(types.types()),
+    vec![("TreeNode".to_owned(), "parent".to_owned())]
+  );
+
+  let error = load("./tests/manifests/v1/infinite-types.yaml").await.unwrap_err();
+  assert!(matches!(error.unspanned(), ManifestError::InfiniteType(cycle) if cycle == &["Chicken", "Egg", "Chicken"]));
+  assert_eq!(error.span().map(|s| (s.line(), s.column())), Some((5, 5)));
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_duplicate_enum_values() -> Result<(), ManifestError> {
+  let error = load("./tests/manifests/v1/duplicate-enum.yaml").await.unwrap_err();
+  assert!(matches!(
+    error.unspanned(),
+    ManifestError::InvalidEnum(name, wick_interface_types::EnumError::DuplicateValue(value))
+      if name == "HttpVersion" && value == "1.1"
+  ));
+  let span = error.span().unwrap();
+  assert!(span.path().unwrap().ends_with("duplicate-enum.yaml"));
+  assert_eq!((span.line(), span.column()), (5, 5));
+  assert_eq!(span.snippet(), "  - name: HttpVersion");
+
+  Ok(())
+}
+
 #[test_logger::test(tokio::test)]
 async fn test_tests() -> Result<(), ManifestError> {
   let tests = load("./tests/manifests/v1/tests.yaml").await?.try_test_config()?;
@@ -54,6 +109,8 @@ async fn test_component() -> Result<(), ManifestError> {
     .try_component_config()?;
 
   assert!(matches!(component.component().kind(), config::ComponentKind::WasmRs));
+  let signature = component.signature()?;
+  assert_eq!(signature.get_operation("render").and_then(|op| op.cost()), Some(3));
 
   Ok(())
 }
@@ -91,6 +148,35 @@ async fn test_component_extended() -> Result<(), ManifestError> {
   Ok(())
 }
 
+#[test_logger::test(tokio::test)]
+async fn test_host_type_check() -> Result<(), ManifestError> {
+  let component = load("./tests/manifests/v1/host-type-check.yaml")
+    .await?
+    .try_component_config()?;
+
+  let type_check = component.type_check().unwrap();
+  assert!(type_check.strict());
+  assert_eq!(type_check.coercions().len(), 1);
+  assert_eq!(type_check.coercions()[0].from(), &wick_interface_types::Type::U32);
+  assert_eq!(type_check.coercions()[0].to(), &wick_interface_types::Type::String);
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_app_admin_alerts() -> Result<(), ManifestError> {
+  let app = load_app("./tests/manifests/v1/app-admin-alerts.yaml").await?;
+
+  let alerts = app.admin().unwrap().alerts();
+  assert_eq!(alerts.len(), 1);
+  assert_eq!(alerts[0].name(), "slow-greetings");
+  assert_eq!(alerts[0].operation().map(String::as_str), Some("greet"));
+  assert_eq!(alerts[0].window(), &std::time::Duration::from_secs(60));
+  assert_eq!(alerts[0].invoke(), "mailer::send");
+
+  Ok(())
+}
+
 #[test_logger::test(tokio::test)]
 async fn regression_issue_42() -> Result<(), ManifestError> {
   let component = load_app("./tests/manifests/v1/template-expansion.yaml").await?;
@@ -73,6 +73,8 @@ export class AppConfiguration implements HasKind {
       _import : ImportBinding[] =  [];
  // Triggers to load and instantiate to drive the application&#x27;s behavior. 
       _triggers : TriggerDefinition[] =  [];
+ // An admin listener that serves health and readiness endpoints for orchestrators. 
+      _admin : AdminConfig| undefined =  undefined;
     constructor (
 name:
  string,
@@ -128,6 +130,14 @@ triggers(value: TriggerDefinition[]) : AppConfiguration {
       return this._triggers;
 
     }
+admin(value: AdminConfig| undefined) : AppConfiguration {
+      this._admin = value;
+      return this;
+    }
+    getAdmin() : AdminConfig| undefined {
+      return this._admin;
+
+    }
 
     getKind() : string {
       return "wick/app@v1";
@@ -136,7 +146,54 @@ triggers(value: TriggerDefinition[]) : AppConfiguration {
     toJSON() : any {
       return {
         kind : "wick/app@v1",
-name: this._name,metadata: this._metadata,package: this._package,resources: this._resources,import: this._import,triggers: this._triggers,      }
+name: this._name,metadata: this._metadata,package: this._package,resources: this._resources,import: this._import,triggers: this._triggers,admin: this._admin,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class AdminConfig implements HasKind {
+ // The TcpPort resource to serve `/healthz`, `/readyz`, and `/metrics` on. 
+      _resource : BoundIdentifier ;
+ // Rules checked against the app's operation metrics that invoke an operation when they fire. 
+      _alerts : AlertRule[] =  [];
+    constructor (
+resource:
+ BoundIdentifier,
+      ) {
+          this._resource = resource;
+    }
+
+resource(value: BoundIdentifier) : AdminConfig {
+      this._resource = value;
+      return this;
+    }
+    getResource() : BoundIdentifier {
+      return this._resource;
+
+    }
+alerts(value: AlertRule[]) : AdminConfig {
+      this._alerts = value;
+      return this;
+    }
+    getAlerts() : AlertRule[] {
+      return this._alerts;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+resource: this._resource,alerts: this._alerts,      }
 
     }
 }
@@ -163,6 +220,8 @@ export class Metadata implements HasKind {
       _licenses : string[] =  [];
  // An icon to associate with the artifact. 
       _icon : string| undefined =  undefined;
+ // Version requirements the runtime must satisfy to load this artifact, e.g. `wick: >=0.17`. 
+      _requires :   Record<string,string> =  {};
     constructor (
       ) {
     }
@@ -223,6 +282,14 @@ icon(value: string| undefined) : Metadata {
       return this._icon;
 
     }
+requires(value:   Record<string,string>) : Metadata {
+      this._requires = value;
+      return this;
+    }
+    getRequires() :   Record<string,string> {
+      return this._requires;
+
+    }
 
     getKind() : string {
       return "";
@@ -230,7 +297,7 @@ icon(value: string| undefined) : Metadata {
 
     toJSON() : any {
       return {
-version: this._version,authors: this._authors,vendors: this._vendors,description: this._description,documentation: this._documentation,licenses: this._licenses,icon: this._icon,      }
+version: this._version,authors: this._authors,vendors: this._vendors,description: this._description,documentation: this._documentation,licenses: this._licenses,icon: this._icon,requires: this._requires,      }
 
     }
 }
@@ -432,7 +499,7 @@ name: this._name,component: this._component,      }
     
     
 export type ResourceDefinition =
-      TcpPort|UdpPort|Url|Volume
+      TcpPort|UdpPort|Url|Volume|Broker|Secret
     ;
     
 
@@ -614,11 +681,114 @@ url: this._url,      }
     
     
 
+
+
+export class Broker implements HasKind {
+ // The name of the broker. Apps in the same process that bind brokers with the same name share their topics. 
+      _name : LiquidTemplate ;
+    constructor (
+name:
+ LiquidTemplate,
+      ) {
+          this._name = name;
+    }
+
+name(value: LiquidTemplate) : Broker {
+      this._name = value;
+      return this;
+    }
+    getName() : LiquidTemplate {
+      return this._name;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/broker@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/broker@v1",
+name: this._name,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+export class Secret implements HasKind {
+ // Where to read the secret from. 
+      _provider : SecretProvider ;
+ // The name of the environment variable, the path of the file, the command to run, or, for a backend, a reference like `secret://vault/path#key`, depending on the provider. Commands are split on whitespace and run without a shell. 
+      _source : LiquidTemplate ;
+ // How often, in seconds, to read the secret again while the app runs. The secret is only read at startup when this is omitted. 
+      _refresh : number| undefined =  undefined;
+    constructor (
+provider:
+ SecretProvider,
+source:
+ LiquidTemplate,
+      ) {
+          this._provider = provider;
+          this._source = source;
+    }
+
+provider(value: SecretProvider) : Secret {
+      this._provider = value;
+      return this;
+    }
+    getProvider() : SecretProvider {
+      return this._provider;
+
+    }
+source(value: LiquidTemplate) : Secret {
+      this._source = value;
+      return this;
+    }
+    getSource() : LiquidTemplate {
+      return this._source;
+
+    }
+refresh(value: number| undefined) : Secret {
+      this._refresh = value;
+      return this;
+    }
+    getRefresh() : number| undefined {
+      return this._refresh;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/secret@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/secret@v1",
+provider: this._provider,source: this._source,refresh: this._refresh,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+export enum SecretProvider {
+Env = "Env",File = "File",Command = "Command",Backend = "Backend",}
+    
+    
+
     
     
     
 export type TriggerDefinition =
-      CliTrigger|HttpTrigger|TimeTrigger|WasmCommandTrigger
+      BrokerTrigger|CliTrigger|GrpcTrigger|HttpTrigger|LogTrigger|TimeTrigger|WasmCommandTrigger
     ;
     
 
@@ -675,6 +845,8 @@ reference: this._reference,volumes: this._volumes,      }
 export class CliTrigger implements HasKind {
  // The operation that will act as the main entrypoint for this trigger. 
       _operation :string | ComponentOperationExpression ;
+ // Connect stdin and stdout to the operation, either streaming them through its &#x60;stdin&#x60; input and its outputs or bridging them for an interactive session. 
+      _stdin : StdinMode| undefined =  undefined;
     constructor (
 operation:
 string | ComponentOperationExpression,
@@ -690,6 +862,14 @@ operation(value: ComponentOperationExpression) : CliTrigger {
       return this._operation;
 
     }
+stdin(value: StdinMode| undefined) : CliTrigger {
+      this._stdin = value;
+      return this;
+    }
+    getStdin() : StdinMode| undefined {
+      return this._stdin;
+
+    }
 
     getKind() : string {
       return "wick/trigger/cli@v1";
@@ -698,7 +878,7 @@ operation(value: ComponentOperationExpression) : CliTrigger {
     toJSON() : any {
       return {
         kind : "wick/trigger/cli@v1",
-operation: this._operation,      }
+operation: this._operation,stdin: this._stdin,      }
 
     }
 }
@@ -707,6 +887,14 @@ operation: this._operation,      }
     
     
     
+    
+export enum StdinMode {
+Lines = "Lines",Raw = "Raw",Interactive = "Interactive",}
+
+    
+    
+    
+    
 
 
 
@@ -871,65 +1059,46 @@ cron: this._cron,repeat: this._repeat,      }
 
 
 
-export class ComponentOperationExpression implements HasKind {
- // The component that exports the operation. 
-      _component :string | ComponentDefinition ;
- // The operation name. 
-      _name : string ;
- // Configuration to pass to this operation on invocation. 
-      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
- // Timeout (in milliseconds) to wait for the operation to complete. Use 0 to wait indefinitely. 
-      _timeout : number| undefined =  undefined;
+export class LogTrigger implements HasKind {
+ // The operation to send each record to. Records are sent one at a time, in the order they were written, on the operation&#x27;s &#x60;record&#x60; input. 
+      _operation :string | ComponentOperationExpression ;
+ // The files to tail and how to parse them. 
+      _sources : LogSource[] ;
     constructor (
-component:
-string | ComponentDefinition,
-name:
- string,
+operation:
+string | ComponentOperationExpression,
+sources:
+ LogSource[],
       ) {
-          this._component = component;
-          this._name = name;
-    }
-
-component(value: ComponentDefinition) : ComponentOperationExpression {
-      this._component = value;
-      return this;
-    }
-    getComponent() :string | ComponentDefinition {
-      return this._component;
-
-    }
-name(value: string) : ComponentOperationExpression {
-      this._name = value;
-      return this;
+          this._operation = operation;
+          this._sources = sources;
     }
-    getName() : string {
-      return this._name;
 
-    }
-with(value:   Record<string,LiquidJsonValue>| undefined) : ComponentOperationExpression {
-      this._with = value;
+operation(value: ComponentOperationExpression) : LogTrigger {
+      this._operation = value;
       return this;
     }
-    getWith() :   Record<string,LiquidJsonValue>| undefined {
-      return this._with;
+    getOperation() :string | ComponentOperationExpression {
+      return this._operation;
 
     }
-timeout(value: number| undefined) : ComponentOperationExpression {
-      this._timeout = value;
+sources(value: LogSource[]) : LogTrigger {
+      this._sources = value;
       return this;
     }
-    getTimeout() : number| undefined {
-      return this._timeout;
+    getSources() : LogSource[] {
+      return this._sources;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/trigger/log@v1";
     }
 
     toJSON() : any {
       return {
-component: this._component,name: this._name,with: this._with,timeout: this._timeout,      }
+        kind : "wick/trigger/log@v1",
+operation: this._operation,sources: this._sources,      }
 
     }
 }
@@ -941,19 +1110,32 @@ component: this._component,name: this._name,with: this._with,timeout: this._time
 
 
 
-export class HttpTrigger implements HasKind {
- // The TcpPort resource to listen on for connections. 
+export class LogSource implements HasKind {
+ // The Volume resource the log files are in. 
       _resource : BoundIdentifier ;
- // The router to handle incoming requests 
-      _routers : HttpRouter[] =  [];
+ // Glob patterns, relative to the volume, of the files to tail. Files that match after the trigger starts are read from their beginning. 
+      _paths : string[] ;
+ // The format records are written in. Each line is one record. 
+      _format : LogFormat = LogFormat.Json;
+ // The regular expression to parse each line with when the format is &#x60;Regex&#x60;. Each named capture group becomes a field of the record. 
+      _pattern : string| undefined =  undefined;
+ // The names of the fields of each CSV record. When empty, the first line of each file is read as its header. 
+      _columns : string[] =  [];
+ // The character CSV fields are separated by. Defaults to a comma. 
+      _delimiter : string| undefined =  undefined;
+ // Whether to read files that exist when the trigger starts from their beginning instead of only what&#x27;s written to them afterwards. 
+      _fromStart : boolean =false;
     constructor (
 resource:
  BoundIdentifier,
+paths:
+ string[],
       ) {
           this._resource = resource;
+          this._paths = paths;
     }
 
-resource(value: BoundIdentifier) : HttpTrigger {
+resource(value: BoundIdentifier) : LogSource {
       this._resource = value;
       return this;
     }
@@ -961,102 +1143,62 @@ resource(value: BoundIdentifier) : HttpTrigger {
       return this._resource;
 
     }
-routers(value: HttpRouter[]) : HttpTrigger {
-      this._routers = value;
+paths(value: string[]) : LogSource {
+      this._paths = value;
       return this;
     }
-    getRouters() : HttpRouter[] {
-      return this._routers;
-
-    }
+    getPaths() : string[] {
+      return this._paths;
 
-    getKind() : string {
-      return "wick/trigger/http@v1";
     }
-
-    toJSON() : any {
-      return {
-        kind : "wick/trigger/http@v1",
-resource: this._resource,routers: this._routers,      }
-
+format(value: LogFormat) : LogSource {
+      this._format = value;
+      return this;
     }
-}
+    getFormat() : LogFormat {
+      return this._format;
 
-    
-    
-    
-    
-
-    
-    
-    
-export type HttpRouter =
-      RawRouter|RestRouter|StaticRouter|ProxyRouter
-    ;
-    
-
-
-
-export class ProxyRouter implements HasKind {
- // The path that this router will trigger for. 
-      _path : string ;
- // Middleware operations for this router. 
-      _middleware : Middleware| undefined =  undefined;
- // The URL resource to proxy to. 
-      _url : BoundIdentifier ;
- // Whether or not to strip the router&#x27;s path from the proxied request. 
-      _stripPath : boolean =false;
-    constructor (
-path:
- string,
-url:
- BoundIdentifier,
-      ) {
-          this._path = path;
-          this._url = url;
     }
-
-path(value: string) : ProxyRouter {
-      this._path = value;
+pattern(value: string| undefined) : LogSource {
+      this._pattern = value;
       return this;
     }
-    getPath() : string {
-      return this._path;
+    getPattern() : string| undefined {
+      return this._pattern;
 
     }
-middleware(value: Middleware| undefined) : ProxyRouter {
-      this._middleware = value;
+columns(value: string[]) : LogSource {
+      this._columns = value;
       return this;
     }
-    getMiddleware() : Middleware| undefined {
-      return this._middleware;
+    getColumns() : string[] {
+      return this._columns;
 
     }
-url(value: BoundIdentifier) : ProxyRouter {
-      this._url = value;
+delimiter(value: string| undefined) : LogSource {
+      this._delimiter = value;
       return this;
     }
-    getUrl() : BoundIdentifier {
-      return this._url;
+    getDelimiter() : string| undefined {
+      return this._delimiter;
 
     }
-stripPath(value: boolean) : ProxyRouter {
-      this._stripPath = value;
+fromStart(value: boolean) : LogSource {
+      this._fromStart = value;
       return this;
     }
-    getStripPath() : boolean {
-      return this._stripPath;
+    getFromStart() : boolean {
+      return this._fromStart;
 
     }
 
     getKind() : string {
-      return "wick/router/proxy@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/router/proxy@v1",
-path: this._path,middleware: this._middleware,url: this._url,strip_path: this._stripPath,      }
+resource: this._resource,paths: this._paths,format: this._format,pattern: this._pattern,columns: this._columns,delimiter: this._delimiter,from_start: this._fromStart,      }
 
     }
 }
@@ -1068,73 +1210,59 @@ path: this._path,middleware: this._middleware,url: this._url,strip_path: this._s
 
 
 
-export class RestRouter implements HasKind {
- // The path that this router will trigger for. 
-      _path : string ;
- // Additional tools and services to enable. 
-      _tools : Tools| undefined =  undefined;
- // Middleware operations for this router. 
-      _middleware : Middleware| undefined =  undefined;
- // The routes to serve and operations that handle them. 
-      _routes : Route[] =  [];
- // Information about the router to use when generating documentation and other tools. 
-      _info : Info| undefined =  undefined;
-    constructor (
-path:
+export class BrokerTrigger implements HasKind {
+ // The Broker resource to subscribe to. 
+      _resource : BoundIdentifier ;
+ // The topic to subscribe to. 
+      _topic : string ;
+ // The operation to send each event to. Events are sent one at a time, in the order they were published. 
+      _operation :string | ComponentOperationExpression ;
+    constructor (
+resource:
+ BoundIdentifier,
+topic:
  string,
+operation:
+string | ComponentOperationExpression,
       ) {
-          this._path = path;
-    }
-
-path(value: string) : RestRouter {
-      this._path = value;
-      return this;
-    }
-    getPath() : string {
-      return this._path;
-
-    }
-tools(value: Tools| undefined) : RestRouter {
-      this._tools = value;
-      return this;
+          this._resource = resource;
+          this._topic = topic;
+          this._operation = operation;
     }
-    getTools() : Tools| undefined {
-      return this._tools;
 
-    }
-middleware(value: Middleware| undefined) : RestRouter {
-      this._middleware = value;
+resource(value: BoundIdentifier) : BrokerTrigger {
+      this._resource = value;
       return this;
     }
-    getMiddleware() : Middleware| undefined {
-      return this._middleware;
+    getResource() : BoundIdentifier {
+      return this._resource;
 
     }
-routes(value: Route[]) : RestRouter {
-      this._routes = value;
+topic(value: string) : BrokerTrigger {
+      this._topic = value;
       return this;
     }
-    getRoutes() : Route[] {
-      return this._routes;
+    getTopic() : string {
+      return this._topic;
 
     }
-info(value: Info| undefined) : RestRouter {
-      this._info = value;
+operation(value: ComponentOperationExpression) : BrokerTrigger {
+      this._operation = value;
       return this;
     }
-    getInfo() : Info| undefined {
-      return this._info;
+    getOperation() :string | ComponentOperationExpression {
+      return this._operation;
 
     }
 
     getKind() : string {
-      return "wick/router/rest@v1";
+      return "wick/trigger/broker@v1";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/router/rest@v1",
-path: this._path,tools: this._tools,middleware: this._middleware,routes: this._routes,info: this._info,      }
+        kind : "wick/trigger/broker@v1",
+resource: this._resource,topic: this._topic,operation: this._operation,      }
 
     }
 }
@@ -1146,75 +1274,65 @@ path: this._path,tools: this._tools,middleware: this._middleware,routes: this._r
 
 
 
-export class Route implements HasKind {
- // The path to serve this route from. See [URI documentation](/docs/configuration/uri) for more information on specifying query and path parameters. 
-      _subPath : string ;
- // The operation that will act as the main entrypoint for this route. 
-      _operation :string | ComponentOperationExpression ;
- // The HTTP methods to serve this route for. 
-      _methods : HttpMethod[] =  [];
- // The unique ID of the route, used for documentation and tooling. 
-      _id : string| undefined =  undefined;
- // A short description of the route. 
-      _description : string| undefined =  undefined;
- // A longer description of the route. 
-      _summary : string| undefined =  undefined;
+export class ComponentOperationExpression implements HasKind {
+ // The component that exports the operation. 
+      _component :string | ComponentDefinition ;
+ // The operation name. 
+      _name : string ;
+ // Configuration to pass to this operation on invocation. 
+      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
+ // Timeout (in milliseconds) to wait for the operation to complete. Use 0 to wait indefinitely. 
+      _timeout : number| undefined =  undefined;
+ // How to retry the operation when an invocation fails. 
+      _retry : RetryPolicy| undefined =  undefined;
     constructor (
-sub_path:
+component:
+string | ComponentDefinition,
+name:
  string,
-operation:
-string | ComponentOperationExpression,
       ) {
-          this._subPath = sub_path;
-          this._operation = operation;
-    }
-
-subPath(value: string) : Route {
-      this._subPath = value;
-      return this;
+          this._component = component;
+          this._name = name;
     }
-    getSubPath() : string {
-      return this._subPath;
 
-    }
-operation(value: ComponentOperationExpression) : Route {
-      this._operation = value;
+component(value: ComponentDefinition) : ComponentOperationExpression {
+      this._component = value;
       return this;
     }
-    getOperation() :string | ComponentOperationExpression {
-      return this._operation;
+    getComponent() :string | ComponentDefinition {
+      return this._component;
 
     }
-methods(value: HttpMethod[]) : Route {
-      this._methods = value;
+name(value: string) : ComponentOperationExpression {
+      this._name = value;
       return this;
     }
-    getMethods() : HttpMethod[] {
-      return this._methods;
+    getName() : string {
+      return this._name;
 
     }
-id(value: string| undefined) : Route {
-      this._id = value;
+with(value:   Record<string,LiquidJsonValue>| undefined) : ComponentOperationExpression {
+      this._with = value;
       return this;
     }
-    getId() : string| undefined {
-      return this._id;
+    getWith() :   Record<string,LiquidJsonValue>| undefined {
+      return this._with;
 
     }
-description(value: string| undefined) : Route {
-      this._description = value;
+timeout(value: number| undefined) : ComponentOperationExpression {
+      this._timeout = value;
       return this;
     }
-    getDescription() : string| undefined {
-      return this._description;
+    getTimeout() : number| undefined {
+      return this._timeout;
 
     }
-summary(value: string| undefined) : Route {
-      this._summary = value;
+retry(value: RetryPolicy| undefined) : ComponentOperationExpression {
+      this._retry = value;
       return this;
     }
-    getSummary() : string| undefined {
-      return this._summary;
+    getRetry() : RetryPolicy| undefined {
+      return this._retry;
 
     }
 
@@ -1224,7 +1342,7 @@ summary(value: string| undefined) : Route {
 
     toJSON() : any {
       return {
-sub_path: this._subPath,operation: this._operation,methods: this._methods,id: this._id,description: this._description,summary: this._summary,      }
+component: this._component,name: this._name,with: this._with,timeout: this._timeout,retry: this._retry,      }
 
     }
 }
@@ -1236,19 +1354,49 @@ sub_path: this._subPath,operation: this._operation,methods: this._methods,id: th
 
 
 
-export class Tools implements HasKind {
- // Set to true to generate an OpenAPI specification and serve it at *router_path*/openapi.json 
-      _openapi : boolean =false;
+export class RetryPolicy implements HasKind {
+ // The maximum number of attempts, including the first one. 
+      _maxAttempts : number =3;
+ // The delay (in milliseconds) before the first retry. The delay doubles with every further retry. 
+      _initialBackoff : number =100;
+ // The longest delay (in milliseconds) between two attempts. 
+      _maxBackoff : number =10000;
+ // The kinds of failure to retry. Retries every kind of failure when empty. 
+      _retryOn : RetryCondition[] =  [];
     constructor (
       ) {
     }
 
-openapi(value: boolean) : Tools {
-      this._openapi = value;
+maxAttempts(value: number) : RetryPolicy {
+      this._maxAttempts = value;
       return this;
     }
-    getOpenapi() : boolean {
-      return this._openapi;
+    getMaxAttempts() : number {
+      return this._maxAttempts;
+
+    }
+initialBackoff(value: number) : RetryPolicy {
+      this._initialBackoff = value;
+      return this;
+    }
+    getInitialBackoff() : number {
+      return this._initialBackoff;
+
+    }
+maxBackoff(value: number) : RetryPolicy {
+      this._maxBackoff = value;
+      return this;
+    }
+    getMaxBackoff() : number {
+      return this._maxBackoff;
+
+    }
+retryOn(value: RetryCondition[]) : RetryPolicy {
+      this._retryOn = value;
+      return this;
+    }
+    getRetryOn() : RetryCondition[] {
+      return this._retryOn;
 
     }
 
@@ -1258,7 +1406,7 @@ openapi(value: boolean) : Tools {
 
     toJSON() : any {
       return {
-openapi: this._openapi,      }
+max_attempts: this._maxAttempts,initial_backoff: this._initialBackoff,max_backoff: this._maxBackoff,retry_on: this._retryOn,      }
 
     }
 }
@@ -1270,89 +1418,79 @@ openapi: this._openapi,      }
 
 
 
-export class Info implements HasKind {
- // The title of the API. 
-      _title : string| undefined =  undefined;
- // A short description of the API. 
-      _description : string| undefined =  undefined;
- // The terms of service for the API. 
-      _tos : string| undefined =  undefined;
- // The contact information for the API. 
-      _contact : Contact| undefined =  undefined;
- // The license information for the API. 
-      _license : License| undefined =  undefined;
- // The version of the API. 
-      _version : string ="";
- // The URL to the API&#x27;s terms of service. 
-      _documentation : Documentation| undefined =  undefined;
-    constructor (
-      ) {
-    }
+export enum RetryCondition {
+Error = "Error",Timeout = "Timeout",Panic = "Panic",}
 
-title(value: string| undefined) : Info {
-      this._title = value;
-      return this;
-    }
-    getTitle() : string| undefined {
-      return this._title;
+    
+    
+    
+    
 
-    }
-description(value: string| undefined) : Info {
-      this._description = value;
-      return this;
-    }
-    getDescription() : string| undefined {
-      return this._description;
 
-    }
-tos(value: string| undefined) : Info {
-      this._tos = value;
-      return this;
-    }
-    getTos() : string| undefined {
-      return this._tos;
 
+export class GrpcTrigger implements HasKind {
+ // The TcpPort resource to listen on for connections. 
+      _resource : BoundIdentifier ;
+ // The protobuf package the generated service belongs to. 
+      _package : string| undefined =  undefined;
+ // The name of the generated gRPC service. 
+      _service : string ;
+ // The operations to expose as methods on the service. 
+      _operations : ComponentOperationExpression[] ;
+    constructor (
+resource:
+ BoundIdentifier,
+service:
+ string,
+operations:
+ ComponentOperationExpression[],
+      ) {
+          this._resource = resource;
+          this._service = service;
+          this._operations = operations;
     }
-contact(value: Contact| undefined) : Info {
-      this._contact = value;
+
+resource(value: BoundIdentifier) : GrpcTrigger {
+      this._resource = value;
       return this;
     }
-    getContact() : Contact| undefined {
-      return this._contact;
+    getResource() : BoundIdentifier {
+      return this._resource;
 
     }
-license(value: License| undefined) : Info {
-      this._license = value;
+package(value: string| undefined) : GrpcTrigger {
+      this._package = value;
       return this;
     }
-    getLicense() : License| undefined {
-      return this._license;
+    getPackage() : string| undefined {
+      return this._package;
 
     }
-version(value: string) : Info {
-      this._version = value;
+service(value: string) : GrpcTrigger {
+      this._service = value;
       return this;
     }
-    getVersion() : string {
-      return this._version;
+    getService() : string {
+      return this._service;
 
     }
-documentation(value: Documentation| undefined) : Info {
-      this._documentation = value;
+operations(value: ComponentOperationExpression[]) : GrpcTrigger {
+      this._operations = value;
       return this;
     }
-    getDocumentation() : Documentation| undefined {
-      return this._documentation;
+    getOperations() : ComponentOperationExpression[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/trigger/grpc@v1";
     }
 
     toJSON() : any {
       return {
-title: this._title,description: this._description,tos: this._tos,contact: this._contact,license: this._license,version: this._version,documentation: this._documentation,      }
+        kind : "wick/trigger/grpc@v1",
+resource: this._resource,package: this._package,service: this._service,operations: this._operations,      }
 
     }
 }
@@ -1364,39 +1502,43 @@ title: this._title,description: this._description,tos: this._tos,contact: this._
 
 
 
-export class Documentation implements HasKind {
- // The URL to the API&#x27;s documentation. 
-      _url : string| undefined =  undefined;
- // A short description of the documentation. 
-      _description : string| undefined =  undefined;
+export class HttpTrigger implements HasKind {
+ // The TcpPort resource to listen on for connections. 
+      _resource : BoundIdentifier ;
+ // The router to handle incoming requests 
+      _routers : HttpRouter[] =  [];
     constructor (
+resource:
+ BoundIdentifier,
       ) {
+          this._resource = resource;
     }
 
-url(value: string| undefined) : Documentation {
-      this._url = value;
+resource(value: BoundIdentifier) : HttpTrigger {
+      this._resource = value;
       return this;
     }
-    getUrl() : string| undefined {
-      return this._url;
+    getResource() : BoundIdentifier {
+      return this._resource;
 
     }
-description(value: string| undefined) : Documentation {
-      this._description = value;
+routers(value: HttpRouter[]) : HttpTrigger {
+      this._routers = value;
       return this;
     }
-    getDescription() : string| undefined {
-      return this._description;
+    getRouters() : HttpRouter[] {
+      return this._routers;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/trigger/http@v1";
     }
 
     toJSON() : any {
       return {
-url: this._url,description: this._description,      }
+        kind : "wick/trigger/http@v1",
+resource: this._resource,routers: this._routers,      }
 
     }
 }
@@ -1406,98 +1548,86 @@ url: this._url,description: this._description,      }
     
     
 
-
-
-export class License implements HasKind {
- // The name of the license. 
-      _name : string ;
- // The URL to the license. 
-      _url : string| undefined =  undefined;
+    
+    
+    
+export type HttpRouter =
+      RawRouter|RestRouter|StaticRouter|ProxyRouter
+    ;
+    
+
+
+
+export class ProxyRouter implements HasKind {
+ // The path that this router will trigger for. 
+      _path : string ;
+ // Middleware operations for this router. 
+      _middleware : Middleware| undefined =  undefined;
+ // Cross-origin resource sharing (CORS) settings for this router. 
+      _cors : Cors| undefined =  undefined;
+ // The URL resource to proxy to. 
+      _url : BoundIdentifier ;
+ // Whether or not to strip the router&#x27;s path from the proxied request. 
+      _stripPath : boolean =false;
     constructor (
-name:
+path:
  string,
+url:
+ BoundIdentifier,
       ) {
-          this._name = name;
+          this._path = path;
+          this._url = url;
     }
 
-name(value: string) : License {
-      this._name = value;
+path(value: string) : ProxyRouter {
+      this._path = value;
       return this;
     }
-    getName() : string {
-      return this._name;
+    getPath() : string {
+      return this._path;
 
     }
-url(value: string| undefined) : License {
-      this._url = value;
+middleware(value: Middleware| undefined) : ProxyRouter {
+      this._middleware = value;
       return this;
     }
-    getUrl() : string| undefined {
-      return this._url;
-
-    }
-
-    getKind() : string {
-      return "";
-    }
-
-    toJSON() : any {
-      return {
-name: this._name,url: this._url,      }
-
-    }
-}
-
-    
-    
-    
-    
-
-
+    getMiddleware() : Middleware| undefined {
+      return this._middleware;
 
-export class Contact implements HasKind {
- // The name of the contact. 
-      _name : string| undefined =  undefined;
- // The URL to the contact. 
-      _url : string| undefined =  undefined;
- // The email address of the contact. 
-      _email : string| undefined =  undefined;
-    constructor (
-      ) {
     }
-
-name(value: string| undefined) : Contact {
-      this._name = value;
+cors(value: Cors| undefined) : ProxyRouter {
+      this._cors = value;
       return this;
     }
-    getName() : string| undefined {
-      return this._name;
+    getCors() : Cors| undefined {
+      return this._cors;
 
     }
-url(value: string| undefined) : Contact {
+url(value: BoundIdentifier) : ProxyRouter {
       this._url = value;
       return this;
     }
-    getUrl() : string| undefined {
+    getUrl() : BoundIdentifier {
       return this._url;
 
     }
-email(value: string| undefined) : Contact {
-      this._email = value;
+stripPath(value: boolean) : ProxyRouter {
+      this._stripPath = value;
       return this;
     }
-    getEmail() : string| undefined {
-      return this._email;
+    getStripPath() : boolean {
+      return this._stripPath;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/router/proxy@v1";
     }
 
     toJSON() : any {
       return {
-name: this._name,url: this._url,email: this._email,      }
+        kind : "wick/router/proxy@v1",
+path: this._path,middleware: this._middleware,cors: this._cors,url: this._url,strip_path: this._stripPath,      }
 
     }
 }
@@ -1509,28 +1639,29 @@ name: this._name,url: this._url,email: this._email,      }
 
 
 
-export class StaticRouter implements HasKind {
+export class RestRouter implements HasKind {
  // The path that this router will trigger for. 
       _path : string ;
+ // Additional tools and services to enable. 
+      _tools : Tools| undefined =  undefined;
  // Middleware operations for this router. 
       _middleware : Middleware| undefined =  undefined;
- // The volume to serve static files from. 
-      _volume : string ;
- // Fallback path (relative to volume &#x60;resource&#x60;) for files to serve in case of a 404. Useful for SPA&#x27;s. if volume resource is: /www and fallback: index.html, then a 404 will serve /www/index.html 
-      _fallback : string| undefined =  undefined;
- // Whether or not to serve directory listings when a directory is requested. 
-      _indexes : boolean =false;
+ // Built-in authentication that requests must pass before they reach the router's middleware or operations. 
+      _auth : Auth| undefined =  undefined;
+ // Cross-origin resource sharing (CORS) settings for this router. 
+      _cors : Cors| undefined =  undefined;
+ // The routes to serve and operations that handle them. 
+      _routes : Route[] =  [];
+ // Information about the router to use when generating documentation and other tools. 
+      _info : Info| undefined =  undefined;
     constructor (
 path:
- string,
-volume:
  string,
       ) {
           this._path = path;
-          this._volume = volume;
     }
 
-path(value: string) : StaticRouter {
+path(value: string) : RestRouter {
       this._path = value;
       return this;
     }
@@ -1538,7 +1669,15 @@ path(value: string) : StaticRouter {
       return this._path;
 
     }
-middleware(value: Middleware| undefined) : StaticRouter {
+tools(value: Tools| undefined) : RestRouter {
+      this._tools = value;
+      return this;
+    }
+    getTools() : Tools| undefined {
+      return this._tools;
+
+    }
+middleware(value: Middleware| undefined) : RestRouter {
       this._middleware = value;
       return this;
     }
@@ -1546,39 +1685,47 @@ middleware(value: Middleware| undefined) : StaticRouter {
       return this._middleware;
 
     }
-volume(value: string) : StaticRouter {
-      this._volume = value;
+auth(value: Auth| undefined) : RestRouter {
+      this._auth = value;
       return this;
     }
-    getVolume() : string {
-      return this._volume;
+    getAuth() : Auth| undefined {
+      return this._auth;
 
     }
-fallback(value: string| undefined) : StaticRouter {
-      this._fallback = value;
+cors(value: Cors| undefined) : RestRouter {
+      this._cors = value;
       return this;
     }
-    getFallback() : string| undefined {
-      return this._fallback;
+    getCors() : Cors| undefined {
+      return this._cors;
 
     }
-indexes(value: boolean) : StaticRouter {
-      this._indexes = value;
+routes(value: Route[]) : RestRouter {
+      this._routes = value;
       return this;
     }
-    getIndexes() : boolean {
-      return this._indexes;
+    getRoutes() : Route[] {
+      return this._routes;
+
+    }
+info(value: Info| undefined) : RestRouter {
+      this._info = value;
+      return this;
+    }
+    getInfo() : Info| undefined {
+      return this._info;
 
     }
 
     getKind() : string {
-      return "wick/router/static@v1";
+      return "wick/router/rest@v1";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/router/static@v1",
-path: this._path,middleware: this._middleware,volume: this._volume,fallback: this._fallback,indexes: this._indexes,      }
+        kind : "wick/router/rest@v1",
+path: this._path,tools: this._tools,middleware: this._middleware,auth: this._auth,cors: this._cors,routes: this._routes,info: this._info,      }
 
     }
 }
@@ -1590,66 +1737,125 @@ path: this._path,middleware: this._middleware,volume: this._volume,fallback: thi
 
 
 
-export class RawRouter implements HasKind {
- // The path that this router will trigger for. 
-      _path : string ;
- // Middleware operations for this router. 
-      _middleware : Middleware| undefined =  undefined;
- // The codec to use when encoding/decoding data. 
-      _codec : Codec| undefined =  undefined;
- // The operation that handles HTTP requests. 
+export class Route implements HasKind {
+ // The path to serve this route from. See [URI documentation](/docs/configuration/uri) for more information on specifying query and path parameters. 
+      _subPath : string ;
+ // The operation that will act as the main entrypoint for this route. 
       _operation :string | ComponentOperationExpression ;
+ // The HTTP methods to serve this route for. 
+      _methods : HttpMethod[] =  [];
+ // The unique ID of the route, used for documentation and tooling. 
+      _id : string| undefined =  undefined;
+ // A short description of the route. 
+      _description : string| undefined =  undefined;
+ // A longer description of the route. 
+      _summary : string| undefined =  undefined;
+ // How the operation&#x27;s output is sent back to the client. 
+      _response : ResponseMode = ResponseMode.Buffered;
+ // The maximum size in bytes of a request body. Larger requests are rejected with 413 Payload Too Large. 
+      _maxBodySize : number| undefined =  undefined;
+ // The maximum size in bytes of a single field or file in a multipart/form-data body. 
+      _maxPartSize : number| undefined =  undefined;
+ // Whether to validate path parameters, query parameters, and the request body against the operation&#x27;s inputs. Invalid requests are rejected with 400 Bad Request. 
+      _validate : boolean =false;
     constructor (
-path:
+sub_path:
  string,
 operation:
 string | ComponentOperationExpression,
       ) {
-          this._path = path;
+          this._subPath = sub_path;
           this._operation = operation;
     }
 
-path(value: string) : RawRouter {
-      this._path = value;
+subPath(value: string) : Route {
+      this._subPath = value;
       return this;
     }
-    getPath() : string {
-      return this._path;
+    getSubPath() : string {
+      return this._subPath;
 
     }
-middleware(value: Middleware| undefined) : RawRouter {
-      this._middleware = value;
+operation(value: ComponentOperationExpression) : Route {
+      this._operation = value;
       return this;
     }
-    getMiddleware() : Middleware| undefined {
-      return this._middleware;
+    getOperation() :string | ComponentOperationExpression {
+      return this._operation;
 
     }
-codec(value: Codec| undefined) : RawRouter {
-      this._codec = value;
+methods(value: HttpMethod[]) : Route {
+      this._methods = value;
       return this;
     }
-    getCodec() : Codec| undefined {
-      return this._codec;
+    getMethods() : HttpMethod[] {
+      return this._methods;
 
     }
-operation(value: ComponentOperationExpression) : RawRouter {
-      this._operation = value;
+id(value: string| undefined) : Route {
+      this._id = value;
       return this;
     }
-    getOperation() :string | ComponentOperationExpression {
-      return this._operation;
+    getId() : string| undefined {
+      return this._id;
+
+    }
+description(value: string| undefined) : Route {
+      this._description = value;
+      return this;
+    }
+    getDescription() : string| undefined {
+      return this._description;
+
+    }
+summary(value: string| undefined) : Route {
+      this._summary = value;
+      return this;
+    }
+    getSummary() : string| undefined {
+      return this._summary;
+
+    }
+response(value: ResponseMode) : Route {
+      this._response = value;
+      return this;
+    }
+    getResponse() : ResponseMode {
+      return this._response;
+
+    }
+maxBodySize(value: number| undefined) : Route {
+      this._maxBodySize = value;
+      return this;
+    }
+    getMaxBodySize() : number| undefined {
+      return this._maxBodySize;
+
+    }
+maxPartSize(value: number| undefined) : Route {
+      this._maxPartSize = value;
+      return this;
+    }
+    getMaxPartSize() : number| undefined {
+      return this._maxPartSize;
+
+    }
+validate(value: boolean) : Route {
+      this._validate = value;
+      return this;
+    }
+    getValidate() : boolean {
+      return this._validate;
 
     }
 
     getKind() : string {
-      return "wick/router/raw@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/router/raw@v1",
-path: this._path,middleware: this._middleware,codec: this._codec,operation: this._operation,      }
+sub_path: this._subPath,operation: this._operation,methods: this._methods,id: this._id,description: this._description,summary: this._summary,response: this._response,max_body_size: this._maxBodySize,max_part_size: this._maxPartSize,validate: this._validate,      }
 
     }
 }
@@ -1661,29 +1867,19 @@ path: this._path,middleware: this._middleware,codec: this._codec,operation: this
 
 
 
-export class Middleware implements HasKind {
- // The middleware to apply to requests. 
-      _request : ComponentOperationExpression[] =  [];
- // The middleware to apply to responses. 
-      _response : ComponentOperationExpression[] =  [];
+export class Tools implements HasKind {
+ // Set to true to generate an OpenAPI specification and serve it at *router_path*/openapi.json 
+      _openapi : boolean =false;
     constructor (
       ) {
     }
 
-request(value: ComponentOperationExpression[]) : Middleware {
-      this._request = value;
-      return this;
-    }
-    getRequest() : ComponentOperationExpression[] {
-      return this._request;
-
-    }
-response(value: ComponentOperationExpression[]) : Middleware {
-      this._response = value;
+openapi(value: boolean) : Tools {
+      this._openapi = value;
       return this;
     }
-    getResponse() : ComponentOperationExpression[] {
-      return this._response;
+    getOpenapi() : boolean {
+      return this._openapi;
 
     }
 
@@ -1693,7 +1889,7 @@ response(value: ComponentOperationExpression[]) : Middleware {
 
     toJSON() : any {
       return {
-request: this._request,response: this._response,      }
+openapi: this._openapi,      }
 
     }
 }
@@ -1705,70 +1901,89 @@ request: this._request,response: this._response,      }
 
 
 
-export class TypesConfiguration implements HasKind {
- // The name of this type. 
-      _name : string| undefined =  undefined;
- // Associated metadata for this type. 
-      _metadata : Metadata| undefined =  undefined;
- // Additional types to export and make available to the type. 
-      _types : TypeDefinition[] =  [];
- // A list of operation signatures. 
-      _operations : OperationDefinition[] =  [];
- // Details about the package for this types. 
-      _package : PackageDefinition| undefined =  undefined;
+export class Info implements HasKind {
+ // The title of the API. 
+      _title : string| undefined =  undefined;
+ // A short description of the API. 
+      _description : string| undefined =  undefined;
+ // The terms of service for the API. 
+      _tos : string| undefined =  undefined;
+ // The contact information for the API. 
+      _contact : Contact| undefined =  undefined;
+ // The license information for the API. 
+      _license : License| undefined =  undefined;
+ // The version of the API. 
+      _version : string ="";
+ // The URL to the API&#x27;s terms of service. 
+      _documentation : Documentation| undefined =  undefined;
     constructor (
       ) {
     }
 
-name(value: string| undefined) : TypesConfiguration {
-      this._name = value;
+title(value: string| undefined) : Info {
+      this._title = value;
       return this;
     }
-    getName() : string| undefined {
-      return this._name;
+    getTitle() : string| undefined {
+      return this._title;
 
     }
-metadata(value: Metadata| undefined) : TypesConfiguration {
-      this._metadata = value;
+description(value: string| undefined) : Info {
+      this._description = value;
       return this;
     }
-    getMetadata() : Metadata| undefined {
-      return this._metadata;
+    getDescription() : string| undefined {
+      return this._description;
 
     }
-types(value: TypeDefinition[]) : TypesConfiguration {
-      this._types = value;
+tos(value: string| undefined) : Info {
+      this._tos = value;
       return this;
     }
-    getTypes() : TypeDefinition[] {
-      return this._types;
+    getTos() : string| undefined {
+      return this._tos;
 
     }
-operations(value: OperationDefinition[]) : TypesConfiguration {
-      this._operations = value;
+contact(value: Contact| undefined) : Info {
+      this._contact = value;
       return this;
     }
-    getOperations() : OperationDefinition[] {
-      return this._operations;
+    getContact() : Contact| undefined {
+      return this._contact;
 
     }
-package(value: PackageDefinition| undefined) : TypesConfiguration {
-      this._package = value;
+license(value: License| undefined) : Info {
+      this._license = value;
       return this;
     }
-    getPackage() : PackageDefinition| undefined {
-      return this._package;
+    getLicense() : License| undefined {
+      return this._license;
+
+    }
+version(value: string) : Info {
+      this._version = value;
+      return this;
+    }
+    getVersion() : string {
+      return this._version;
+
+    }
+documentation(value: Documentation| undefined) : Info {
+      this._documentation = value;
+      return this;
+    }
+    getDocumentation() : Documentation| undefined {
+      return this._documentation;
 
     }
 
     getKind() : string {
-      return "wick/types@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/types@v1",
-name: this._name,metadata: this._metadata,types: this._types,operations: this._operations,package: this._package,      }
+title: this._title,description: this._description,tos: this._tos,contact: this._contact,license: this._license,version: this._version,documentation: this._documentation,      }
 
     }
 }
@@ -1780,49 +1995,39 @@ name: this._name,metadata: this._metadata,types: this._types,operations: this._o
 
 
 
-export class TestConfiguration implements HasKind {
- // The name of this component. 
-      _name : string| undefined =  undefined;
- // Configuration used to instantiate this component. 
-      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
- // Unit tests to run against components and operations. 
-      _cases : TestDefinition[] =  [];
+export class Documentation implements HasKind {
+ // The URL to the API&#x27;s documentation. 
+      _url : string| undefined =  undefined;
+ // A short description of the documentation. 
+      _description : string| undefined =  undefined;
     constructor (
       ) {
     }
 
-name(value: string| undefined) : TestConfiguration {
-      this._name = value;
-      return this;
-    }
-    getName() : string| undefined {
-      return this._name;
-
-    }
-with(value:   Record<string,LiquidJsonValue>| undefined) : TestConfiguration {
-      this._with = value;
+url(value: string| undefined) : Documentation {
+      this._url = value;
       return this;
     }
-    getWith() :   Record<string,LiquidJsonValue>| undefined {
-      return this._with;
+    getUrl() : string| undefined {
+      return this._url;
 
     }
-cases(value: TestDefinition[]) : TestConfiguration {
-      this._cases = value;
+description(value: string| undefined) : Documentation {
+      this._description = value;
       return this;
     }
-    getCases() : TestDefinition[] {
-      return this._cases;
+    getDescription() : string| undefined {
+      return this._description;
 
     }
 
     getKind() : string {
-      return "wick/tests@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-name: this._name,with: this._with,cases: this._cases,      }
+url: this._url,description: this._description,      }
 
     }
 }
@@ -1834,40 +2039,42 @@ name: this._name,with: this._with,cases: this._cases,      }
 
 
 
-export class LockdownConfiguration implements HasKind {
- // Associated metadata for this configuration. 
-      _metadata : Metadata| undefined =  undefined;
- // Restrictions to apply to resources before an application or component can be run. 
-      _resources : ResourceRestriction[] =  [];
+export class License implements HasKind {
+ // The name of the license. 
+      _name : string ;
+ // The URL to the license. 
+      _url : string| undefined =  undefined;
     constructor (
+name:
+ string,
       ) {
+          this._name = name;
     }
 
-metadata(value: Metadata| undefined) : LockdownConfiguration {
-      this._metadata = value;
+name(value: string) : License {
+      this._name = value;
       return this;
     }
-    getMetadata() : Metadata| undefined {
-      return this._metadata;
+    getName() : string {
+      return this._name;
 
     }
-resources(value: ResourceRestriction[]) : LockdownConfiguration {
-      this._resources = value;
+url(value: string| undefined) : License {
+      this._url = value;
       return this;
     }
-    getResources() : ResourceRestriction[] {
-      return this._resources;
+    getUrl() : string| undefined {
+      return this._url;
 
     }
 
     getKind() : string {
-      return "wick/lockdown@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/lockdown@v1",
-metadata: this._metadata,resources: this._resources,      }
+name: this._name,url: this._url,      }
 
     }
 }
@@ -1877,53 +2084,51 @@ metadata: this._metadata,resources: this._resources,      }
     
     
 
-    
-    
-    
-export type ResourceRestriction =
-      VolumeRestriction|UrlRestriction|TcpPortRestriction|UdpPortRestriction
-    ;
-    
-
 
 
-export class VolumeRestriction implements HasKind {
- // The components this restriction applies to 
-      _components : string[] =  [];
- // The volumes to allow 
-      _allow : LiquidTemplate ;
+export class Contact implements HasKind {
+ // The name of the contact. 
+      _name : string| undefined =  undefined;
+ // The URL to the contact. 
+      _url : string| undefined =  undefined;
+ // The email address of the contact. 
+      _email : string| undefined =  undefined;
     constructor (
-allow:
- LiquidTemplate,
       ) {
-          this._allow = allow;
     }
 
-components(value: string[]) : VolumeRestriction {
-      this._components = value;
+name(value: string| undefined) : Contact {
+      this._name = value;
       return this;
     }
-    getComponents() : string[] {
-      return this._components;
+    getName() : string| undefined {
+      return this._name;
 
     }
-allow(value: LiquidTemplate) : VolumeRestriction {
-      this._allow = value;
+url(value: string| undefined) : Contact {
+      this._url = value;
       return this;
     }
-    getAllow() : LiquidTemplate {
-      return this._allow;
+    getUrl() : string| undefined {
+      return this._url;
+
+    }
+email(value: string| undefined) : Contact {
+      this._email = value;
+      return this;
+    }
+    getEmail() : string| undefined {
+      return this._email;
 
     }
 
     getKind() : string {
-      return "wick/resource/volume@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/resource/volume@v1",
-components: this._components,allow: this._allow,      }
+name: this._name,url: this._url,email: this._email,      }
 
     }
 }
@@ -1935,43 +2140,3252 @@ components: this._components,allow: this._allow,      }
 
 
 
-export class UrlRestriction implements HasKind {
- // The components this restriction applies to 
-      _components : string[] =  [];
- // The URLs to allow 
-      _allow : LiquidTemplate ;
+export class StaticRouter implements HasKind {
+ // The path that this router will trigger for. 
+      _path : string ;
+ // Middleware operations for this router. 
+      _middleware : Middleware| undefined =  undefined;
+ // Cross-origin resource sharing (CORS) settings for this router. 
+      _cors : Cors| undefined =  undefined;
+ // The volume to serve static files from. 
+      _volume : string ;
+ // Fallback path (relative to volume &#x60;resource&#x60;) for files to serve in case of a 404. Useful for SPA&#x27;s. if volume resource is: /www and fallback: index.html, then a 404 will serve /www/index.html 
+      _fallback : string| undefined =  undefined;
+ // Whether or not to serve directory listings when a directory is requested. 
+      _indexes : boolean =false;
+ // Whether to serve precompressed &#x60;.br&#x60; or &#x60;.gz&#x60; variants of requested files when they exist and the client accepts them. 
+      _precompressed : boolean =false;
     constructor (
-allow:
- LiquidTemplate,
+path:
+ string,
+volume:
+ string,
       ) {
-          this._allow = allow;
+          this._path = path;
+          this._volume = volume;
     }
 
-components(value: string[]) : UrlRestriction {
-      this._components = value;
-      return this;
+path(value: string) : StaticRouter {
+      this._path = value;
+      return this;
+    }
+    getPath() : string {
+      return this._path;
+
+    }
+middleware(value: Middleware| undefined) : StaticRouter {
+      this._middleware = value;
+      return this;
+    }
+    getMiddleware() : Middleware| undefined {
+      return this._middleware;
+
+    }
+cors(value: Cors| undefined) : StaticRouter {
+      this._cors = value;
+      return this;
+    }
+    getCors() : Cors| undefined {
+      return this._cors;
+
+    }
+volume(value: string) : StaticRouter {
+      this._volume = value;
+      return this;
+    }
+    getVolume() : string {
+      return this._volume;
+
+    }
+fallback(value: string| undefined) : StaticRouter {
+      this._fallback = value;
+      return this;
+    }
+    getFallback() : string| undefined {
+      return this._fallback;
+
+    }
+indexes(value: boolean) : StaticRouter {
+      this._indexes = value;
+      return this;
+    }
+    getIndexes() : boolean {
+      return this._indexes;
+
+    }
+precompressed(value: boolean) : StaticRouter {
+      this._precompressed = value;
+      return this;
+    }
+    getPrecompressed() : boolean {
+      return this._precompressed;
+
+    }
+
+    getKind() : string {
+      return "wick/router/static@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/router/static@v1",
+path: this._path,middleware: this._middleware,cors: this._cors,volume: this._volume,fallback: this._fallback,indexes: this._indexes,precompressed: this._precompressed,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class RawRouter implements HasKind {
+ // The path that this router will trigger for. 
+      _path : string ;
+ // Middleware operations for this router. 
+      _middleware : Middleware| undefined =  undefined;
+ // Built-in authentication that requests must pass before they reach the router's middleware or operations. 
+      _auth : Auth| undefined =  undefined;
+ // Cross-origin resource sharing (CORS) settings for this router. 
+      _cors : Cors| undefined =  undefined;
+ // The codec to use when encoding/decoding data. 
+      _codec : Codec| undefined =  undefined;
+ // The operation that handles HTTP requests. 
+      _operation :string | ComponentOperationExpression ;
+    constructor (
+path:
+ string,
+operation:
+string | ComponentOperationExpression,
+      ) {
+          this._path = path;
+          this._operation = operation;
+    }
+
+path(value: string) : RawRouter {
+      this._path = value;
+      return this;
+    }
+    getPath() : string {
+      return this._path;
+
+    }
+middleware(value: Middleware| undefined) : RawRouter {
+      this._middleware = value;
+      return this;
+    }
+    getMiddleware() : Middleware| undefined {
+      return this._middleware;
+
+    }
+auth(value: Auth| undefined) : RawRouter {
+      this._auth = value;
+      return this;
+    }
+    getAuth() : Auth| undefined {
+      return this._auth;
+
+    }
+cors(value: Cors| undefined) : RawRouter {
+      this._cors = value;
+      return this;
+    }
+    getCors() : Cors| undefined {
+      return this._cors;
+
+    }
+codec(value: Codec| undefined) : RawRouter {
+      this._codec = value;
+      return this;
+    }
+    getCodec() : Codec| undefined {
+      return this._codec;
+
+    }
+operation(value: ComponentOperationExpression) : RawRouter {
+      this._operation = value;
+      return this;
+    }
+    getOperation() :string | ComponentOperationExpression {
+      return this._operation;
+
+    }
+
+    getKind() : string {
+      return "wick/router/raw@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/router/raw@v1",
+path: this._path,middleware: this._middleware,auth: this._auth,cors: this._cors,codec: this._codec,operation: this._operation,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class Middleware implements HasKind {
+ // The middleware to apply to requests. 
+      _request : ComponentOperationExpression[] =  [];
+ // The middleware to apply to responses. 
+      _response : ComponentOperationExpression[] =  [];
+    constructor (
+      ) {
+    }
+
+request(value: ComponentOperationExpression[]) : Middleware {
+      this._request = value;
+      return this;
+    }
+    getRequest() : ComponentOperationExpression[] {
+      return this._request;
+
+    }
+response(value: ComponentOperationExpression[]) : Middleware {
+      this._response = value;
+      return this;
+    }
+    getResponse() : ComponentOperationExpression[] {
+      return this._response;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+request: this._request,response: this._response,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class Cors implements HasKind {
+ // The origins allowed to make cross-origin requests. Use * to allow any origin. 
+      _allowedOrigins : string[] ;
+ // The methods allowed in cross-origin requests. Defaults to GET, POST, PUT, PATCH, and DELETE. 
+      _allowedMethods : string[] =  [];
+ // The request headers allowed in cross-origin requests. Defaults to the headers the browser asks for. 
+      _allowedHeaders : string[] =  [];
+ // How long in seconds browsers may cache the result of a preflight request. 
+      _maxAge : number| undefined =  undefined;
+ // Whether browsers may send credentials such as cookies with cross-origin requests. Credentials can't be allowed from any origin (`*`). 
+      _allowCredentials : boolean =false;
+    constructor (
+allowedOrigins:
+ string[],
+      ) {
+          this._allowedOrigins = allowedOrigins;
+    }
+
+allowedOrigins(value: string[]) : Cors {
+      this._allowedOrigins = value;
+      return this;
+    }
+    getAllowedOrigins() : string[] {
+      return this._allowedOrigins;
+
+    }
+allowedMethods(value: string[]) : Cors {
+      this._allowedMethods = value;
+      return this;
+    }
+    getAllowedMethods() : string[] {
+      return this._allowedMethods;
+
+    }
+allowedHeaders(value: string[]) : Cors {
+      this._allowedHeaders = value;
+      return this;
+    }
+    getAllowedHeaders() : string[] {
+      return this._allowedHeaders;
+
+    }
+maxAge(value: number| undefined) : Cors {
+      this._maxAge = value;
+      return this;
+    }
+    getMaxAge() : number| undefined {
+      return this._maxAge;
+
+    }
+allowCredentials(value: boolean) : Cors {
+      this._allowCredentials = value;
+      return this;
+    }
+    getAllowCredentials() : boolean {
+      return this._allowCredentials;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+allowed_origins: this._allowedOrigins,allowed_methods: this._allowedMethods,allowed_headers: this._allowedHeaders,max_age: this._maxAge,allow_credentials: this._allowCredentials,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export type Auth =
+      JwtAuth|ApiKeyAuth|BasicAuth
+    ;
+    
+
+
+
+export class JwtAuth implements HasKind {
+ // The shared secret used to verify token signatures. 
+      _secret : string ;
+ // The issuer that the token's `iss` claim must match. 
+      _issuer : string| undefined =  undefined;
+ // The audience that the token's `aud` claim must contain. 
+      _audience : string| undefined =  undefined;
+    constructor (
+secret:
+ string,
+      ) {
+          this._secret = secret;
+    }
+
+secret(value: string) : JwtAuth {
+      this._secret = value;
+      return this;
+    }
+    getSecret() : string {
+      return this._secret;
+
+    }
+issuer(value: string| undefined) : JwtAuth {
+      this._issuer = value;
+      return this;
+    }
+    getIssuer() : string| undefined {
+      return this._issuer;
+
+    }
+audience(value: string| undefined) : JwtAuth {
+      this._audience = value;
+      return this;
+    }
+    getAudience() : string| undefined {
+      return this._audience;
+
+    }
+
+    getKind() : string {
+      return "wick/auth/jwt@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/auth/jwt@v1",
+secret: this._secret,issuer: this._issuer,audience: this._audience,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class ApiKeyAuth implements HasKind {
+ // The header to read the API key from. 
+      _header : string ="";
+ // The API keys that are allowed access. 
+      _keys : string[] ;
+    constructor (
+keys:
+ string[],
+      ) {
+          this._keys = keys;
+    }
+
+header(value: string) : ApiKeyAuth {
+      this._header = value;
+      return this;
+    }
+    getHeader() : string {
+      return this._header;
+
+    }
+keys(value: string[]) : ApiKeyAuth {
+      this._keys = value;
+      return this;
+    }
+    getKeys() : string[] {
+      return this._keys;
+
+    }
+
+    getKind() : string {
+      return "wick/auth/api-key@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/auth/api-key@v1",
+header: this._header,keys: this._keys,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class BasicAuth implements HasKind {
+ // The realm to report in the WWW-Authenticate header. 
+      _realm : string| undefined =  undefined;
+ // A map of usernames to the passwords that are allowed access. 
+      _users :   Record<string,string> ;
+    constructor (
+users:
+   Record<string,string>,
+      ) {
+          this._users = users;
+    }
+
+realm(value: string| undefined) : BasicAuth {
+      this._realm = value;
+      return this;
+    }
+    getRealm() : string| undefined {
+      return this._realm;
+
+    }
+users(value:   Record<string,string>) : BasicAuth {
+      this._users = value;
+      return this;
+    }
+    getUsers() :   Record<string,string> {
+      return this._users;
+
+    }
+
+    getKind() : string {
+      return "wick/auth/basic@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/auth/basic@v1",
+realm: this._realm,users: this._users,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class TypesConfiguration implements HasKind {
+ // The name of this type. 
+      _name : string| undefined =  undefined;
+ // Associated metadata for this type. 
+      _metadata : Metadata| undefined =  undefined;
+ // Additional types to export and make available to the type. 
+      _types : TypeDefinition[] =  [];
+ // A list of operation signatures. 
+      _operations : OperationDefinition[] =  [];
+ // Details about the package for this types. 
+      _package : PackageDefinition| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+name(value: string| undefined) : TypesConfiguration {
+      this._name = value;
+      return this;
+    }
+    getName() : string| undefined {
+      return this._name;
+
+    }
+metadata(value: Metadata| undefined) : TypesConfiguration {
+      this._metadata = value;
+      return this;
+    }
+    getMetadata() : Metadata| undefined {
+      return this._metadata;
+
+    }
+types(value: TypeDefinition[]) : TypesConfiguration {
+      this._types = value;
+      return this;
+    }
+    getTypes() : TypeDefinition[] {
+      return this._types;
+
+    }
+operations(value: OperationDefinition[]) : TypesConfiguration {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : OperationDefinition[] {
+      return this._operations;
+
+    }
+package(value: PackageDefinition| undefined) : TypesConfiguration {
+      this._package = value;
+      return this;
+    }
+    getPackage() : PackageDefinition| undefined {
+      return this._package;
+
+    }
+
+    getKind() : string {
+      return "wick/types@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/types@v1",
+name: this._name,metadata: this._metadata,types: this._types,operations: this._operations,package: this._package,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class TestConfiguration implements HasKind {
+ // The name of this component. 
+      _name : string| undefined =  undefined;
+ // Configuration used to instantiate this component. 
+      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
+ // Unit tests to run against components and operations. 
+      _cases : TestDefinition[] =  [];
+    constructor (
+      ) {
+    }
+
+name(value: string| undefined) : TestConfiguration {
+      this._name = value;
+      return this;
+    }
+    getName() : string| undefined {
+      return this._name;
+
+    }
+with(value:   Record<string,LiquidJsonValue>| undefined) : TestConfiguration {
+      this._with = value;
+      return this;
+    }
+    getWith() :   Record<string,LiquidJsonValue>| undefined {
+      return this._with;
+
+    }
+cases(value: TestDefinition[]) : TestConfiguration {
+      this._cases = value;
+      return this;
+    }
+    getCases() : TestDefinition[] {
+      return this._cases;
+
+    }
+
+    getKind() : string {
+      return "wick/tests@v1";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,with: this._with,cases: this._cases,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class LockdownConfiguration implements HasKind {
+ // Associated metadata for this configuration. 
+      _metadata : Metadata| undefined =  undefined;
+ // Restrictions to apply to resources before an application or component can be run. 
+      _resources : ResourceRestriction[] =  [];
+    constructor (
+      ) {
+    }
+
+metadata(value: Metadata| undefined) : LockdownConfiguration {
+      this._metadata = value;
+      return this;
+    }
+    getMetadata() : Metadata| undefined {
+      return this._metadata;
+
+    }
+resources(value: ResourceRestriction[]) : LockdownConfiguration {
+      this._resources = value;
+      return this;
+    }
+    getResources() : ResourceRestriction[] {
+      return this._resources;
+
+    }
+
+    getKind() : string {
+      return "wick/lockdown@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/lockdown@v1",
+metadata: this._metadata,resources: this._resources,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+    
+    
+    
+export type ResourceRestriction =
+      VolumeRestriction|UrlRestriction|TcpPortRestriction|UdpPortRestriction|SecretRestriction
+    ;
+    
+
+
+
+export class VolumeRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The volumes to allow 
+      _allow : LiquidTemplate ;
+    constructor (
+allow:
+ LiquidTemplate,
+      ) {
+          this._allow = allow;
+    }
+
+components(value: string[]) : VolumeRestriction {
+      this._components = value;
+      return this;
+    }
+    getComponents() : string[] {
+      return this._components;
+
+    }
+allow(value: LiquidTemplate) : VolumeRestriction {
+      this._allow = value;
+      return this;
+    }
+    getAllow() : LiquidTemplate {
+      return this._allow;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/volume@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/volume@v1",
+components: this._components,allow: this._allow,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class UrlRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The URLs to allow 
+      _allow : LiquidTemplate ;
+    constructor (
+allow:
+ LiquidTemplate,
+      ) {
+          this._allow = allow;
+    }
+
+components(value: string[]) : UrlRestriction {
+      this._components = value;
+      return this;
+    }
+    getComponents() : string[] {
+      return this._components;
+
+    }
+allow(value: LiquidTemplate) : UrlRestriction {
+      this._allow = value;
+      return this;
+    }
+    getAllow() : LiquidTemplate {
+      return this._allow;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/url@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/url@v1",
+components: this._components,allow: this._allow,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class TcpPortRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The address to allow 
+      _address : LiquidTemplate ;
+ // The port to allow 
+      _port : LiquidTemplate ;
+    constructor (
+address:
+ LiquidTemplate,
+port:
+ LiquidTemplate,
+      ) {
+          this._address = address;
+          this._port = port;
+    }
+
+components(value: string[]) : TcpPortRestriction {
+      this._components = value;
+      return this;
+    }
+    getComponents() : string[] {
+      return this._components;
+
+    }
+address(value: LiquidTemplate) : TcpPortRestriction {
+      this._address = value;
+      return this;
+    }
+    getAddress() : LiquidTemplate {
+      return this._address;
+
+    }
+port(value: LiquidTemplate) : TcpPortRestriction {
+      this._port = value;
+      return this;
+    }
+    getPort() : LiquidTemplate {
+      return this._port;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/tcpport@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/tcpport@v1",
+components: this._components,address: this._address,port: this._port,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class UdpPortRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The address to allow 
+      _address : LiquidTemplate ;
+ // The port to allow 
+      _port : LiquidTemplate ;
+    constructor (
+address:
+ LiquidTemplate,
+port:
+ LiquidTemplate,
+      ) {
+          this._address = address;
+          this._port = port;
+    }
+
+components(value: string[]) : UdpPortRestriction {
+      this._components = value;
+      return this;
+    }
+    getComponents() : string[] {
+      return this._components;
+
+    }
+address(value: LiquidTemplate) : UdpPortRestriction {
+      this._address = value;
+      return this;
+    }
+    getAddress() : LiquidTemplate {
+      return this._address;
+
+    }
+port(value: LiquidTemplate) : UdpPortRestriction {
+      this._port = value;
+      return this;
+    }
+    getPort() : LiquidTemplate {
+      return this._port;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/udpport@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/udpport@v1",
+components: this._components,address: this._address,port: this._port,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class SecretRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The provider to allow 
+      _provider : SecretProvider ;
+ // The environment variables or commands to allow 
+      _allow : LiquidTemplate ;
+    constructor (
+provider:
+ SecretProvider,
+allow:
+ LiquidTemplate,
+      ) {
+          this._provider = provider;
+          this._allow = allow;
+    }
+
+components(value: string[]) : SecretRestriction {
+      this._components = value;
+      return this;
+    }
+    getComponents() : string[] {
+      return this._components;
+
+    }
+provider(value: SecretProvider) : SecretRestriction {
+      this._provider = value;
+      return this;
+    }
+    getProvider() : SecretProvider {
+      return this._provider;
+
+    }
+allow(value: LiquidTemplate) : SecretRestriction {
+      this._allow = value;
+      return this;
+    }
+    getAllow() : LiquidTemplate {
+      return this._allow;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/secret@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/secret@v1",
+components: this._components,provider: this._provider,allow: this._allow,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class ComponentConfiguration implements HasKind {
+ // The name of the component. 
+      _name : string| undefined =  undefined;
+ // Associated metadata for this component. 
+      _metadata : Metadata| undefined =  undefined;
+ // Details about the package for this component. 
+      _package : PackageDefinition| undefined =  undefined;
+ // Configuration for when wick hosts this component as a service. 
+      _host : HostConfig| undefined =  undefined;
+ // Resources that the component can access. 
+      _resources : ResourceBinding[] =  [];
+ // Components or types to import into this component&#x27;s scope. 
+      _import : ImportBinding[] =  [];
+ // Additional types to export and make available to the component. 
+      _types : TypeDefinition[] =  [];
+ // Interfaces the component requires to operate. 
+      _requires : InterfaceBinding[] =  [];
+ // Configuration specific to different kinds of components. 
+      _component : ComponentKind ;
+ // Assertions that can be run against the component to validate its behavior. 
+      _tests : TestConfiguration[] =  [];
+    constructor (
+component:
+ ComponentKind,
+      ) {
+          this._component = component;
+    }
+
+name(value: string| undefined) : ComponentConfiguration {
+      this._name = value;
+      return this;
+    }
+    getName() : string| undefined {
+      return this._name;
+
+    }
+metadata(value: Metadata| undefined) : ComponentConfiguration {
+      this._metadata = value;
+      return this;
+    }
+    getMetadata() : Metadata| undefined {
+      return this._metadata;
+
+    }
+package(value: PackageDefinition| undefined) : ComponentConfiguration {
+      this._package = value;
+      return this;
+    }
+    getPackage() : PackageDefinition| undefined {
+      return this._package;
+
+    }
+host(value: HostConfig| undefined) : ComponentConfiguration {
+      this._host = value;
+      return this;
+    }
+    getHost() : HostConfig| undefined {
+      return this._host;
+
+    }
+resources(value: ResourceBinding[]) : ComponentConfiguration {
+      this._resources = value;
+      return this;
+    }
+    getResources() : ResourceBinding[] {
+      return this._resources;
+
+    }
+import(value: ImportBinding[]) : ComponentConfiguration {
+      this._import = value;
+      return this;
+    }
+    getImport() : ImportBinding[] {
+      return this._import;
+
+    }
+types(value: TypeDefinition[]) : ComponentConfiguration {
+      this._types = value;
+      return this;
+    }
+    getTypes() : TypeDefinition[] {
+      return this._types;
+
+    }
+requires(value: InterfaceBinding[]) : ComponentConfiguration {
+      this._requires = value;
+      return this;
+    }
+    getRequires() : InterfaceBinding[] {
+      return this._requires;
+
+    }
+component(value: ComponentKind) : ComponentConfiguration {
+      this._component = value;
+      return this;
+    }
+    getComponent() : ComponentKind {
+      return this._component;
+
+    }
+tests(value: TestConfiguration[]) : ComponentConfiguration {
+      this._tests = value;
+      return this;
+    }
+    getTests() : TestConfiguration[] {
+      return this._tests;
+
+    }
+
+    getKind() : string {
+      return "wick/component@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component@v1",
+name: this._name,metadata: this._metadata,package: this._package,host: this._host,resources: this._resources,import: this._import,types: this._types,requires: this._requires,component: this._component,tests: this._tests,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class InterfaceBinding implements HasKind {
+ // The name of the interface. 
+      _name : string ;
+ // The interface to bind to. 
+      _interface : InterfaceDefinition ;
+    constructor (
+name:
+ string,
+interface_:
+ InterfaceDefinition,
+      ) {
+          this._name = name;
+          this._interface = interface_;
+    }
+
+name(value: string) : InterfaceBinding {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+interface(value: InterfaceDefinition) : InterfaceBinding {
+      this._interface = value;
+      return this;
+    }
+    getInterface() : InterfaceDefinition {
+      return this._interface;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,interface: this._interface,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class InterfaceDefinition implements HasKind {
+ // Types used by the interface&#x27;s operations 
+      _types : TypeDefinition[] =  [];
+ // A list of operations defined by this interface. 
+      _operations : OperationDefinition[] =  [];
+    constructor (
+      ) {
+    }
+
+types(value: TypeDefinition[]) : InterfaceDefinition {
+      this._types = value;
+      return this;
+    }
+    getTypes() : TypeDefinition[] {
+      return this._types;
+
+    }
+operations(value: OperationDefinition[]) : InterfaceDefinition {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : OperationDefinition[] {
+      return this._operations;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+types: this._types,operations: this._operations,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class CompositeComponentConfiguration implements HasKind {
+ // A list of operations exposed by the Composite component. 
+      _operations : CompositeOperationDefinition[] =  [];
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A component or components whose operations you want to inherit from. 
+      _extends : string[] =  [];
+    constructor (
+      ) {
+    }
+
+operations(value: CompositeOperationDefinition[]) : CompositeComponentConfiguration {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : CompositeOperationDefinition[] {
+      return this._operations;
+
+    }
+with(value: Field[]) : CompositeComponentConfiguration {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+extends(value: string[]) : CompositeComponentConfiguration {
+      this._extends = value;
+      return this;
+    }
+    getExtends() : string[] {
+      return this._extends;
+
+    }
+
+    getKind() : string {
+      return "wick/component/composite@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/composite@v1",
+operations: this._operations,with: this._with,extends: this._extends,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class WasmComponentConfiguration implements HasKind {
+ // The path or OCI reference to the WebAssembly module 
+      _ref : string ;
+ // Volumes to expose to the component. 
+      _volumes : ExposedVolume[] =  [];
+ // The default size to allocate to the component&#x27;s send/receive buffer. 
+      _maxPacketSize : number| undefined =  undefined;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations implemented by the WebAssembly module. 
+      _operations : OperationDefinition[] =  [];
+    constructor (
+ref:
+ string,
+      ) {
+          this._ref = ref;
+    }
+
+ref(value: string) : WasmComponentConfiguration {
+      this._ref = value;
+      return this;
+    }
+    getRef() : string {
+      return this._ref;
+
+    }
+volumes(value: ExposedVolume[]) : WasmComponentConfiguration {
+      this._volumes = value;
+      return this;
+    }
+    getVolumes() : ExposedVolume[] {
+      return this._volumes;
+
+    }
+maxPacketSize(value: number| undefined) : WasmComponentConfiguration {
+      this._maxPacketSize = value;
+      return this;
+    }
+    getMaxPacketSize() : number| undefined {
+      return this._maxPacketSize;
+
+    }
+with(value: Field[]) : WasmComponentConfiguration {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+operations(value: OperationDefinition[]) : WasmComponentConfiguration {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : OperationDefinition[] {
+      return this._operations;
+
+    }
+
+    getKind() : string {
+      return "wick/component/wasmrs@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/wasmrs@v1",
+ref: this._ref,volumes: this._volumes,max_packet_size: this._maxPacketSize,with: this._with,operations: this._operations,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class WasmComponentModel implements HasKind {
+ // The path or OCI reference to the WebAssembly module 
+      _ref : string ;
+ // Volumes to expose to the component. 
+      _volumes : ExposedVolume[] =  [];
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations implemented by the WebAssembly module. 
+      _operations : OperationDefinition[] =  [];
+    constructor (
+ref:
+ string,
+      ) {
+          this._ref = ref;
+    }
+
+ref(value: string) : WasmComponentModel {
+      this._ref = value;
+      return this;
+    }
+    getRef() : string {
+      return this._ref;
+
+    }
+volumes(value: ExposedVolume[]) : WasmComponentModel {
+      this._volumes = value;
+      return this;
+    }
+    getVolumes() : ExposedVolume[] {
+      return this._volumes;
+
+    }
+with(value: Field[]) : WasmComponentModel {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+operations(value: OperationDefinition[]) : WasmComponentModel {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : OperationDefinition[] {
+      return this._operations;
+
+    }
+
+    getKind() : string {
+      return "wick/component/wasm@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/wasm@v1",
+ref: this._ref,volumes: this._volumes,with: this._with,operations: this._operations,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class ExposedVolume implements HasKind {
+ // The resource ID of the volume. 
+      _resource : BoundIdentifier ;
+ // The path to map it to in the component. 
+      _path : string ;
+    constructor (
+resource:
+ BoundIdentifier,
+path:
+ string,
+      ) {
+          this._resource = resource;
+          this._path = path;
+    }
+
+resource(value: BoundIdentifier) : ExposedVolume {
+      this._resource = value;
+      return this;
+    }
+    getResource() : BoundIdentifier {
+      return this._resource;
+
+    }
+path(value: string) : ExposedVolume {
+      this._path = value;
+      return this;
+    }
+    getPath() : string {
+      return this._path;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+resource: this._resource,path: this._path,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+    
+    
+    
+export type ComponentKind =
+      WasmComponentConfiguration|WasmComponentModel|CompositeComponentConfiguration|SqlComponent|HttpClientComponent|ArchiveComponent|TextComponent|UnitsComponent|ValidateComponent|GenerateComponent|FlagsComponent|I18nComponent|MetricsComponent|BrokerComponent|CacheComponent|ObjectStoreComponent|FilesystemComponent|DataComponent|CryptoComponent
+    ;
+    
+
+    
+    
+    
+export type ImportDefinition =
+      TypesComponent|ManifestComponent|GrpcUrlComponent|SqlComponent|HttpClientComponent|ArchiveComponent|TextComponent|UnitsComponent|ValidateComponent|GenerateComponent|FlagsComponent|I18nComponent|MetricsComponent|BrokerComponent|CacheComponent|ObjectStoreComponent|FilesystemComponent|DataComponent|CryptoComponent
+    ;
+    
+
+    
+    
+    
+export type ComponentDefinition =
+      GrpcUrlComponent|ManifestComponent|ComponentReference|SqlComponent|HttpClientComponent|ArchiveComponent|TextComponent|UnitsComponent|ValidateComponent|GenerateComponent|FlagsComponent|I18nComponent|MetricsComponent|BrokerComponent|CacheComponent|ObjectStoreComponent|FilesystemComponent|DataComponent|CryptoComponent
+    ;
+    
+
+
+
+export class TypesComponent implements HasKind {
+ // The URL (and optional tag) or local file path to find the types manifest. 
+      _ref : string ;
+ // The types to import from the manifest. 
+      _types : string[] =  [];
+    constructor (
+ref:
+ string,
+      ) {
+          this._ref = ref;
+    }
+
+ref(value: string) : TypesComponent {
+      this._ref = value;
+      return this;
+    }
+    getRef() : string {
+      return this._ref;
+
+    }
+types(value: string[]) : TypesComponent {
+      this._types = value;
+      return this;
+    }
+    getTypes() : string[] {
+      return this._types;
+
+    }
+
+    getKind() : string {
+      return "wick/component/types@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/types@v1",
+ref: this._ref,types: this._types,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class ComponentReference implements HasKind {
+ // The id of the referenced component. 
+      _id : BoundIdentifier ;
+    constructor (
+id:
+ BoundIdentifier,
+      ) {
+          this._id = id;
+    }
+
+id(value: BoundIdentifier) : ComponentReference {
+      this._id = value;
+      return this;
+    }
+    getId() : BoundIdentifier {
+      return this._id;
+
+    }
+
+    getKind() : string {
+      return "wick/component/reference@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/reference@v1",
+id: this._id,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class HostConfig implements HasKind {
+ // Whether or not to allow the &#x60;:latest&#x60; tag on remote artifacts. 
+      _allowLatest : boolean =false;
+ // A list of registries to connect to insecurely (over HTTP vs HTTPS). 
+      _insecureRegistries : string[] =  [];
+ // Configuration for the GRPC server. 
+      _rpc : HttpConfig| undefined =  undefined;
+ // Configuration for the Prometheus metrics endpoint. 
+      _metrics : MetricsConfig| undefined =  undefined;
+ // Configuration for exporting trace spans over OTLP. 
+      _otlp : OtlpConfig| undefined =  undefined;
+ // Rules that decide which imported components the host may instantiate. 
+      _policy : ComponentPolicy| undefined =  undefined;
+ // How the types of connected ports are checked when flows are loaded. 
+      _typeCheck : TypeCheckConfig| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+allowLatest(value: boolean) : HostConfig {
+      this._allowLatest = value;
+      return this;
+    }
+    getAllowLatest() : boolean {
+      return this._allowLatest;
+
+    }
+insecureRegistries(value: string[]) : HostConfig {
+      this._insecureRegistries = value;
+      return this;
+    }
+    getInsecureRegistries() : string[] {
+      return this._insecureRegistries;
+
+    }
+rpc(value: HttpConfig| undefined) : HostConfig {
+      this._rpc = value;
+      return this;
+    }
+    getRpc() : HttpConfig| undefined {
+      return this._rpc;
+
+    }
+metrics(value: MetricsConfig| undefined) : HostConfig {
+      this._metrics = value;
+      return this;
+    }
+    getMetrics() : MetricsConfig| undefined {
+      return this._metrics;
+
+    }
+otlp(value: OtlpConfig| undefined) : HostConfig {
+      this._otlp = value;
+      return this;
+    }
+    getOtlp() : OtlpConfig| undefined {
+      return this._otlp;
+
+    }
+policy(value: ComponentPolicy| undefined) : HostConfig {
+      this._policy = value;
+      return this;
+    }
+    getPolicy() : ComponentPolicy| undefined {
+      return this._policy;
+
+    }
+typeCheck(value: TypeCheckConfig| undefined) : HostConfig {
+      this._typeCheck = value;
+      return this;
+    }
+    getTypeCheck() : TypeCheckConfig| undefined {
+      return this._typeCheck;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+allow_latest: this._allowLatest,insecure_registries: this._insecureRegistries,rpc: this._rpc,metrics: this._metrics,otlp: this._otlp,policy: this._policy,type_check: this._typeCheck,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class MetricsConfig implements HasKind {
+ // Enable/disable the `/metrics` endpoint. 
+      _enabled : boolean =false;
+ // The port to bind to. 
+      _port : number| undefined =  undefined;
+ // The address to bind to. 
+      _address : string| undefined =  undefined;
+ // Rules checked against operation metrics that invoke an operation when they fire. Rules are checked even when the endpoint is disabled. 
+      _alerts : AlertRule[] =  [];
+    constructor (
+      ) {
+    }
+
+enabled(value: boolean) : MetricsConfig {
+      this._enabled = value;
+      return this;
+    }
+    getEnabled() : boolean {
+      return this._enabled;
+
+    }
+port(value: number| undefined) : MetricsConfig {
+      this._port = value;
+      return this;
+    }
+    getPort() : number| undefined {
+      return this._port;
+
+    }
+address(value: string| undefined) : MetricsConfig {
+      this._address = value;
+      return this;
+    }
+    getAddress() : string| undefined {
+      return this._address;
+
+    }
+alerts(value: AlertRule[]) : MetricsConfig {
+      this._alerts = value;
+      return this;
+    }
+    getAlerts() : AlertRule[] {
+      return this._alerts;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+enabled: this._enabled,port: this._port,address: this._address,alerts: this._alerts,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+export class AlertRule implements HasKind {
+ // The name of the rule, passed to the alert operation. 
+      _name : string ;
+ // The operation to watch, e.g. `greet`. Watches every operation when omitted. 
+      _operation : string| undefined =  undefined;
+ // The metric to compare against the threshold. 
+      _metric : AlertMetric ;
+ // The value the metric must exceed for the rule to fire: a percentage for `ErrorRate`, milliseconds for `P99`. 
+      _threshold : number ;
+ // The length (in seconds) of the window the metric is computed over. 
+      _window : number =60;
+ // The operation to invoke when the rule fires, e.g. `notify` or `mailer::send`. It receives the alert as an object on its `alert` input. 
+      _invoke : string ;
+    constructor (
+name:
+ string,
+metric:
+ AlertMetric,
+threshold:
+ number,
+invoke:
+ string,
+      ) {
+          this._name = name;
+          this._metric = metric;
+          this._threshold = threshold;
+          this._invoke = invoke;
+    }
+
+name(value: string) : AlertRule {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+operation(value: string| undefined) : AlertRule {
+      this._operation = value;
+      return this;
+    }
+    getOperation() : string| undefined {
+      return this._operation;
+
+    }
+metric(value: AlertMetric) : AlertRule {
+      this._metric = value;
+      return this;
+    }
+    getMetric() : AlertMetric {
+      return this._metric;
+
+    }
+threshold(value: number) : AlertRule {
+      this._threshold = value;
+      return this;
+    }
+    getThreshold() : number {
+      return this._threshold;
+
+    }
+window(value: number) : AlertRule {
+      this._window = value;
+      return this;
+    }
+    getWindow() : number {
+      return this._window;
+
+    }
+invoke(value: string) : AlertRule {
+      this._invoke = value;
+      return this;
+    }
+    getInvoke() : string {
+      return this._invoke;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,operation: this._operation,metric: this._metric,threshold: this._threshold,window: this._window,invoke: this._invoke,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+export enum AlertMetric {
+ErrorRate = "ErrorRate",P99 = "P99",}
+
+    
+    
+    
+    
+
+
+
+export class ComponentPolicy implements HasKind {
+ // Whether to refuse components no rule matches. Components no rule matches are instantiated when this is false. 
+      _denyByDefault : boolean =false;
+ // The rules to check, in order. 
+      _rules : PolicyRule[] =  [];
+    constructor (
+      ) {
+    }
+
+denyByDefault(value: boolean) : ComponentPolicy {
+      this._denyByDefault = value;
+      return this;
+    }
+    getDenyByDefault() : boolean {
+      return this._denyByDefault;
+
+    }
+rules(value: PolicyRule[]) : ComponentPolicy {
+      this._rules = value;
+      return this;
+    }
+    getRules() : PolicyRule[] {
+      return this._rules;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+deny_by_default: this._denyByDefault,rules: this._rules,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+export class PolicyRule implements HasKind {
+ // Whether to instantiate the components the rule matches. 
+      _action : PolicyAction ;
+ // The OCI registry the component is pulled from, e.g. `registry.candle.dev`. 
+      _registry : string| undefined =  undefined;
+ // The digest of the component&#x27;s artifact, e.g. `sha256:4f6a...`. 
+      _digest : string| undefined =  undefined;
+ // The public key of the account that signed the component&#x27;s WebAssembly module. 
+      _signer : string| undefined =  undefined;
+ // The namespace the component is imported as. A trailing `*` matches any namespace with that prefix. 
+      _namespace : string| undefined =  undefined;
+    constructor (
+action:
+ PolicyAction,
+      ) {
+          this._action = action;
+    }
+
+action(value: PolicyAction) : PolicyRule {
+      this._action = value;
+      return this;
+    }
+    getAction() : PolicyAction {
+      return this._action;
+
+    }
+registry(value: string| undefined) : PolicyRule {
+      this._registry = value;
+      return this;
+    }
+    getRegistry() : string| undefined {
+      return this._registry;
+
+    }
+digest(value: string| undefined) : PolicyRule {
+      this._digest = value;
+      return this;
+    }
+    getDigest() : string| undefined {
+      return this._digest;
+
+    }
+signer(value: string| undefined) : PolicyRule {
+      this._signer = value;
+      return this;
+    }
+    getSigner() : string| undefined {
+      return this._signer;
+
+    }
+namespace(value: string| undefined) : PolicyRule {
+      this._namespace = value;
+      return this;
+    }
+    getNamespace() : string| undefined {
+      return this._namespace;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+action: this._action,registry: this._registry,digest: this._digest,signer: this._signer,namespace: this._namespace,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+export enum PolicyAction {
+Allow = "Allow",Deny = "Deny",}
+
+    
+    
+    
+    
+
+
+
+export class TypeCheckConfig implements HasKind {
+ // Whether connections between ports of incompatible types stop flows from loading. They are logged as warnings when this is false. 
+      _strict : boolean =false;
+ // More conversions to allow between connected ports. Values are sent unchanged, so only allow conversions the input can decode. 
+      _coercions : TypeCoercion[] =  [];
+    constructor (
+      ) {
+    }
+
+strict(value: boolean) : TypeCheckConfig {
+      this._strict = value;
+      return this;
+    }
+    getStrict() : boolean {
+      return this._strict;
+
+    }
+coercions(value: TypeCoercion[]) : TypeCheckConfig {
+      this._coercions = value;
+      return this;
+    }
+    getCoercions() : TypeCoercion[] {
+      return this._coercions;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+strict: this._strict,coercions: this._coercions,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+export class TypeCoercion implements HasKind {
+ // The type of the output. 
+      _from : TypeSignature ;
+ // The type of the input. 
+      _to : TypeSignature ;
+    constructor (
+from:
+ TypeSignature,
+to:
+ TypeSignature,
+      ) {
+          this._from = from;
+          this._to = to;
+    }
+
+from(value: TypeSignature) : TypeCoercion {
+      this._from = value;
+      return this;
+    }
+    getFrom() : TypeSignature {
+      return this._from;
+
+    }
+to(value: TypeSignature) : TypeCoercion {
+      this._to = value;
+      return this;
+    }
+    getTo() : TypeSignature {
+      return this._to;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+from: this._from,to: this._to,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+export class OtlpConfig implements HasKind {
+ // The OTLP/gRPC endpoint of the collector, e.g. http://localhost:4317. 
+      _endpoint : string ;
+    constructor (
+endpoint:
+ string,
+      ) {
+          this._endpoint = endpoint;
+    }
+
+endpoint(value: string) : OtlpConfig {
+      this._endpoint = value;
+      return this;
+    }
+    getEndpoint() : string {
+      return this._endpoint;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+endpoint: this._endpoint,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class HttpConfig implements HasKind {
+ // Enable/disable the server. 
+      _enabled : boolean =false;
+ // The port to bind to. 
+      _port : number| undefined =  undefined;
+ // The address to bind to. 
+      _address : string| undefined =  undefined;
+ // Path to pem file for TLS. 
+      _pem : string| undefined =  undefined;
+ // Path to key file for TLS. 
+      _key : string| undefined =  undefined;
+ // Path to CA file. 
+      _ca : string| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+enabled(value: boolean) : HttpConfig {
+      this._enabled = value;
+      return this;
+    }
+    getEnabled() : boolean {
+      return this._enabled;
+
+    }
+port(value: number| undefined) : HttpConfig {
+      this._port = value;
+      return this;
+    }
+    getPort() : number| undefined {
+      return this._port;
+
+    }
+address(value: string| undefined) : HttpConfig {
+      this._address = value;
+      return this;
+    }
+    getAddress() : string| undefined {
+      return this._address;
+
+    }
+pem(value: string| undefined) : HttpConfig {
+      this._pem = value;
+      return this;
+    }
+    getPem() : string| undefined {
+      return this._pem;
+
+    }
+key(value: string| undefined) : HttpConfig {
+      this._key = value;
+      return this;
+    }
+    getKey() : string| undefined {
+      return this._key;
+
+    }
+ca(value: string| undefined) : HttpConfig {
+      this._ca = value;
+      return this;
+    }
+    getCa() : string| undefined {
+      return this._ca;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+enabled: this._enabled,port: this._port,address: this._address,pem: this._pem,key: this._key,ca: this._ca,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class GrpcUrlComponent implements HasKind {
+ // The GRPC URL to connect to, a &#x60;unix://<path>&#x60; or &#x60;npipe://<name>&#x60; URL for a host on the same machine, or &#x60;dns+srv://<name>&#x60; or &#x60;mdns://<service>&#x60; to balance invocations across the hosts found in the SRV records of a name or advertised over multicast DNS. 
+      _url : string ;
+ // Any configuration necessary for the component. 
+      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
+ // Path to the certificate (PEM) to identify this runtime to the remote host with, for mutual TLS. 
+      _pem : string| undefined =  undefined;
+ // Path to the private key (PEM) for &#x60;pem&#x60;. 
+      _key : string| undefined =  undefined;
+ // Path to the certificate authority (PEM) to verify the remote host with. 
+      _ca : string| undefined =  undefined;
+ // The domain name to verify the remote host&#x27;s certificate against. Defaults to the host in &#x60;url&#x60;. 
+      _domain : string| undefined =  undefined;
+    constructor (
+url:
+ string,
+      ) {
+          this._url = url;
+    }
+
+url(value: string) : GrpcUrlComponent {
+      this._url = value;
+      return this;
+    }
+    getUrl() : string {
+      return this._url;
+
+    }
+with(value:   Record<string,LiquidJsonValue>| undefined) : GrpcUrlComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() :   Record<string,LiquidJsonValue>| undefined {
+      return this._with;
+
+    }
+pem(value: string| undefined) : GrpcUrlComponent {
+      this._pem = value;
+      return this;
+    }
+    getPem() : string| undefined {
+      return this._pem;
+
+    }
+key(value: string| undefined) : GrpcUrlComponent {
+      this._key = value;
+      return this;
+    }
+    getKey() : string| undefined {
+      return this._key;
+
+    }
+ca(value: string| undefined) : GrpcUrlComponent {
+      this._ca = value;
+      return this;
+    }
+    getCa() : string| undefined {
+      return this._ca;
+
+    }
+domain(value: string| undefined) : GrpcUrlComponent {
+      this._domain = value;
+      return this;
+    }
+    getDomain() : string| undefined {
+      return this._domain;
+
+    }
+
+    getKind() : string {
+      return "wick/component/grpc@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/grpc@v1",
+url: this._url,with: this._with,pem: this._pem,key: this._key,ca: this._ca,domain: this._domain,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class ManifestComponent implements HasKind {
+ // The URL (and optional tag) or local file path to find the manifest. 
+      _ref : string ;
+ // Any configuration necessary for the component. 
+      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
+ // External components to provide to the referenced component. 
+      _provide :   Record<string,string> =  {};
+ // If applicable, the default size to allocate to the component&#x27;s send/receive buffer. 
+      _maxPacketSize : number| undefined =  undefined;
+ // Resource limits for the instances of the component, when it&#x27;s a WebAssembly component. 
+      _limits : WasmLimits| undefined =  undefined;
+ // Pool instances of the component, when it&#x27;s a WebAssembly component, so concurrent invocations don&#x27;t wait on one instance. 
+      _pool : WasmPool| undefined =  undefined;
+ // Run the component in a separate, supervised process instead of in the runtime&#x27;s own. 
+      _process : ProcessHost| undefined =  undefined;
+    constructor (
+ref:
+ string,
+      ) {
+          this._ref = ref;
+    }
+
+ref(value: string) : ManifestComponent {
+      this._ref = value;
+      return this;
+    }
+    getRef() : string {
+      return this._ref;
+
+    }
+with(value:   Record<string,LiquidJsonValue>| undefined) : ManifestComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() :   Record<string,LiquidJsonValue>| undefined {
+      return this._with;
+
+    }
+provide(value:   Record<string,string>) : ManifestComponent {
+      this._provide = value;
+      return this;
+    }
+    getProvide() :   Record<string,string> {
+      return this._provide;
+
+    }
+maxPacketSize(value: number| undefined) : ManifestComponent {
+      this._maxPacketSize = value;
+      return this;
+    }
+    getMaxPacketSize() : number| undefined {
+      return this._maxPacketSize;
+
+    }
+limits(value: WasmLimits| undefined) : ManifestComponent {
+      this._limits = value;
+      return this;
+    }
+    getLimits() : WasmLimits| undefined {
+      return this._limits;
+
+    }
+pool(value: WasmPool| undefined) : ManifestComponent {
+      this._pool = value;
+      return this;
+    }
+    getPool() : WasmPool| undefined {
+      return this._pool;
+
+    }
+process(value: ProcessHost| undefined) : ManifestComponent {
+      this._process = value;
+      return this;
+    }
+    getProcess() : ProcessHost| undefined {
+      return this._process;
+
+    }
+
+    getKind() : string {
+      return "wick/component/manifest@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/manifest@v1",
+ref: this._ref,with: this._with,provide: this._provide,max_packet_size: this._maxPacketSize,limits: this._limits,pool: this._pool,process: this._process,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class WasmLimits implements HasKind {
+ // The most linear memory, in bytes, each instance of the component can grow to. 
+      _maxMemory : number| undefined =  undefined;
+ // The most instances of the component that can exist at once. 
+      _maxInstances : number| undefined =  undefined;
+ // How long, in milliseconds, an invocation of the component can run before it&#x27;s cancelled. 
+      _timeout : number| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+maxMemory(value: number| undefined) : WasmLimits {
+      this._maxMemory = value;
+      return this;
+    }
+    getMaxMemory() : number| undefined {
+      return this._maxMemory;
+
+    }
+maxInstances(value: number| undefined) : WasmLimits {
+      this._maxInstances = value;
+      return this;
+    }
+    getMaxInstances() : number| undefined {
+      return this._maxInstances;
+
+    }
+timeout(value: number| undefined) : WasmLimits {
+      this._timeout = value;
+      return this;
+    }
+    getTimeout() : number| undefined {
+      return this._timeout;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+max_memory: this._maxMemory,max_instances: this._maxInstances,timeout: this._timeout,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class WasmPool implements HasKind {
+ // The instances of the component to create when the application starts and keep ready. Defaults to one. 
+      _minInstances : number| undefined =  undefined;
+ // The most instances of the component to pool. Invocations wait for an instance to free up once this many are busy. Defaults to &#x60;min_instances&#x60;. 
+      _maxInstances : number| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+minInstances(value: number| undefined) : WasmPool {
+      this._minInstances = value;
+      return this;
+    }
+    getMinInstances() : number| undefined {
+      return this._minInstances;
+
+    }
+maxInstances(value: number| undefined) : WasmPool {
+      this._maxInstances = value;
+      return this;
+    }
+    getMaxInstances() : number| undefined {
+      return this._maxInstances;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+min_instances: this._minInstances,max_instances: this._maxInstances,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class ProcessHost implements HasKind {
+ // When to restart the process after it exits. 
+      _restart : RestartPolicy = RestartPolicy.OnFailure;
+ // The most times to restart the process. Invocations of the component fail once it has exited more often than this. Defaults to five. 
+      _maxRestarts : number| undefined =  undefined;
+ // How long, in milliseconds, to wait for the process to start serving the component. Defaults to ten seconds. 
+      _startupTimeout : number| undefined =  undefined;
+ // The wick executable to run the component with. Defaults to the executable running the application. 
+      _command : string| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+restart(value: RestartPolicy) : ProcessHost {
+      this._restart = value;
+      return this;
+    }
+    getRestart() : RestartPolicy {
+      return this._restart;
+
+    }
+maxRestarts(value: number| undefined) : ProcessHost {
+      this._maxRestarts = value;
+      return this;
+    }
+    getMaxRestarts() : number| undefined {
+      return this._maxRestarts;
+
+    }
+startupTimeout(value: number| undefined) : ProcessHost {
+      this._startupTimeout = value;
+      return this;
+    }
+    getStartupTimeout() : number| undefined {
+      return this._startupTimeout;
+
+    }
+command(value: string| undefined) : ProcessHost {
+      this._command = value;
+      return this;
+    }
+    getCommand() : string| undefined {
+      return this._command;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+restart: this._restart,max_restarts: this._maxRestarts,startup_timeout: this._startupTimeout,command: this._command,      }
+
+    }
+}
+
+
+
+    
+    
+    
+    
+
+    
+    
+export enum RestartPolicy {
+OnFailure = "OnFailure",Always = "Always",Never = "Never",}
+
+    
+    
+    
+    
+
+
+
+export class CompositeOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ="";
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // Types of the inputs to the operation. 
+      _inputs : Field[] =  [];
+ // Types of the outputs to the operation. 
+      _outputs : Field[] =  [];
+ // A map of IDs to specific operations. 
+      _uses : OperationInstance[] =  [];
+ // A list of connections from operation to operation. 
+      _flow : FlowExpression[] =  [];
+ // Additional &#x60;CompositeOperationDefinition&#x60;s to define as children. 
+      _operations : CompositeOperationDefinition[] =  [];
+ // The relative cost of one invocation of the operation, in whatever units the platform running it charges by. The runtime adds up the costs of the operations each transaction invokes. 
+      _cost : number| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+name(value: string) : CompositeOperationDefinition {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+with(value: Field[]) : CompositeOperationDefinition {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+inputs(value: Field[]) : CompositeOperationDefinition {
+      this._inputs = value;
+      return this;
+    }
+    getInputs() : Field[] {
+      return this._inputs;
+
+    }
+outputs(value: Field[]) : CompositeOperationDefinition {
+      this._outputs = value;
+      return this;
+    }
+    getOutputs() : Field[] {
+      return this._outputs;
+
+    }
+uses(value: OperationInstance[]) : CompositeOperationDefinition {
+      this._uses = value;
+      return this;
+    }
+    getUses() : OperationInstance[] {
+      return this._uses;
+
+    }
+flow(value: FlowExpression[]) : CompositeOperationDefinition {
+      this._flow = value;
+      return this;
+    }
+    getFlow() : FlowExpression[] {
+      return this._flow;
+
+    }
+operations(value: CompositeOperationDefinition[]) : CompositeOperationDefinition {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : CompositeOperationDefinition[] {
+      return this._operations;
+
+    }
+cost(value: number| undefined) : CompositeOperationDefinition {
+      this._cost = value;
+      return this;
+    }
+    getCost() : number| undefined {
+      return this._cost;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,uses: this._uses,flow: this._flow,operations: this._operations,cost: this._cost,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+    
+    
+    
+export type FlowExpression =
+string |      ConnectionDefinition|BlockExpression
+    ;
+    
+
+
+
+export class BlockExpression implements HasKind {
+
+      _expressions : FlowExpression[] ;
+    constructor (
+expressions:
+ FlowExpression[],
+      ) {
+          this._expressions = expressions;
+    }
+
+expressions(value: FlowExpression[]) : BlockExpression {
+      this._expressions = value;
+      return this;
+    }
+    getExpressions() : FlowExpression[] {
+      return this._expressions;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+expressions: this._expressions,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class ConnectionDefinition implements HasKind {
+ // An upstream operation&#x27;s output. 
+      _from : ConnectionTargetDefinition ;
+ // A downstream operation&#x27;s input. 
+      _to : ConnectionTargetDefinition ;
+ // How packets from this and other connections into the same input are merged. Connections into one input must not set different strategies. 
+      _merge : MergeStrategy| undefined =  undefined;
+ // A structured JSON liquid template that reshapes each packet&#x27;s value before it&#x27;s delivered, with the value available as `input`, e.g. `{ name: &quot;{{ input.first_name }}&quot; }`. Signals and errors pass through untouched. 
+      _transform : LiquidJsonValue| undefined =  undefined;
+    constructor (
+from:
+ ConnectionTargetDefinition,
+to:
+ ConnectionTargetDefinition,
+      ) {
+          this._from = from;
+          this._to = to;
+    }
+
+from(value: ConnectionTargetDefinition) : ConnectionDefinition {
+      this._from = value;
+      return this;
+    }
+    getFrom() : ConnectionTargetDefinition {
+      return this._from;
+
+    }
+to(value: ConnectionTargetDefinition) : ConnectionDefinition {
+      this._to = value;
+      return this;
+    }
+    getTo() : ConnectionTargetDefinition {
+      return this._to;
+
+    }
+merge(value: MergeStrategy| undefined) : ConnectionDefinition {
+      this._merge = value;
+      return this;
+    }
+    getMerge() : MergeStrategy| undefined {
+      return this._merge;
+
+    }
+transform(value: LiquidJsonValue| undefined) : ConnectionDefinition {
+      this._transform = value;
+      return this;
+    }
+    getTransform() : LiquidJsonValue| undefined {
+      return this._transform;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+from: this._from,to: this._to,merge: this._merge,transform: this._transform,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class ConnectionTargetDefinition implements HasKind {
+ // The instance ID of the component operation. 
+      _instance : string ;
+ // The operation&#x27;s input or output (depending on to/from). 
+      _port : string| undefined =  undefined;
+ // The default value to provide on this connection in the event of an error. 
+      _data :   Record<string,LiquidJsonValue>| undefined =  undefined;
+    constructor (
+instance:
+ string,
+      ) {
+          this._instance = instance;
+    }
+
+instance(value: string) : ConnectionTargetDefinition {
+      this._instance = value;
+      return this;
+    }
+    getInstance() : string {
+      return this._instance;
+
+    }
+port(value: string| undefined) : ConnectionTargetDefinition {
+      this._port = value;
+      return this;
+    }
+    getPort() : string| undefined {
+      return this._port;
+
+    }
+data(value:   Record<string,LiquidJsonValue>| undefined) : ConnectionTargetDefinition {
+      this._data = value;
+      return this;
+    }
+    getData() :   Record<string,LiquidJsonValue>| undefined {
+      return this._data;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+instance: this._instance,port: this._port,data: this._data,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+    
+    
+export enum MergeStrategy {
+Ordered = "Ordered",RoundRobin = "RoundRobin",Zip = "Zip",Priority = "Priority",}
+
+    
+    
+    
+    
+
+
+
+export class OperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ="";
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // Types of the inputs to the operation. 
+      _inputs : Field[] =  [];
+ // Types of the outputs to the operation. 
+      _outputs : Field[] =  [];
+ // The relative cost of one invocation of the operation, in whatever units the platform running it charges by. The runtime adds up the costs of the operations each transaction invokes. 
+      _cost : number| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+name(value: string) : OperationDefinition {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+with(value: Field[]) : OperationDefinition {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+inputs(value: Field[]) : OperationDefinition {
+      this._inputs = value;
+      return this;
+    }
+    getInputs() : Field[] {
+      return this._inputs;
+
+    }
+outputs(value: Field[]) : OperationDefinition {
+      this._outputs = value;
+      return this;
+    }
+    getOutputs() : Field[] {
+      return this._outputs;
+
+    }
+cost(value: number| undefined) : OperationDefinition {
+      this._cost = value;
+      return this;
+    }
+    getCost() : number| undefined {
+      return this._cost;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,cost: this._cost,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class Field implements HasKind {
+ // The name of the field. 
+      _name : string ;
+ // The type signature of the field. 
+      _type : TypeSignature ;
+ // The description of the field. 
+      _description : string| undefined =  undefined;
+ // The value an input receives when its upstream finishes without sending any data. 
+      _default : any| undefined =  undefined;
+ // The media type of the field&#x27;s data when it isn&#x27;t JSON, like `text/html` or `image/png`. HTTP routers send an output with a media type as the response body with that Content-Type. 
+      _contentType : string| undefined =  undefined;
+    constructor (
+name:
+ string,
+type_:
+ TypeSignature,
+      ) {
+          this._name = name;
+          this._type = type_;
+    }
+
+name(value: string) : Field {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+type(value: TypeSignature) : Field {
+      this._type = value;
+      return this;
+    }
+    getType() : TypeSignature {
+      return this._type;
+
+    }
+description(value: string| undefined) : Field {
+      this._description = value;
+      return this;
+    }
+    getDescription() : string| undefined {
+      return this._description;
+
+    }
+default(value: any| undefined) : Field {
+      this._default = value;
+      return this;
+    }
+    getDefault() : any| undefined {
+      return this._default;
+
+    }
+contentType(value: string| undefined) : Field {
+      this._contentType = value;
+      return this;
+    }
+    getContentType() : string| undefined {
+      return this._contentType;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,type: this._type,description: this._description,default: this._default,content_type: this._contentType,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+    
+    
+    
+export type TypeSignature =
+      string
+;
+    
+
+
+
+export const I8 = "i8";
+
+    
+    
+    
+    
+
+
+
+export const I16 = "i16";
+
+    
+    
+    
+    
+
+
+
+export const I32 = "i32";
+
+    
+    
+    
+    
+
+
+
+export const I64 = "i64";
+
+    
+    
+    
+    
+
+
+
+export const U8 = "u8";
+
+    
+    
+    
+    
+
+
+
+export const U16 = "u16";
+
+    
+    
+    
+    
+
+
+
+export const U32 = "u32";
+
+    
+    
+    
+    
+
+
+
+export const U64 = "u64";
+
+    
+    
+    
+    
+
+
+
+export const F32 = "f32";
+
+    
+    
+    
+    
+
+
+
+export const F64 = "f64";
+
+    
+    
+    
+    
+
+
+
+export const Bool = "i8";
+
+    
+    
+    
+    
+
+
+
+export const StringType = "string";
+
+    
+    
+    
+    
+
+
+
+export const Datetime = "datetime";
+
+    
+    
+    
+    
+
+
+
+export const Bytes = "bytes";
+
+    
+    
+    
+    
+
+
+
+export class Custom implements HasKind {
+ // The name of the custom type. 
+      _name : string ="";
+    constructor (
+      ) {
+    }
+
+name(value: string) : Custom {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class Optional implements HasKind {
+
+      _type : TypeSignature ;
+    constructor (
+type_:
+ TypeSignature,
+      ) {
+          this._type = type_;
+    }
+
+type(value: TypeSignature) : Optional {
+      this._type = value;
+      return this;
+    }
+    getType() : TypeSignature {
+      return this._type;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+type: this._type,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class List implements HasKind {
+
+      _type : TypeSignature ;
+    constructor (
+type_:
+ TypeSignature,
+      ) {
+          this._type = type_;
+    }
+
+type(value: TypeSignature) : List {
+      this._type = value;
+      return this;
+    }
+    getType() : TypeSignature {
+      return this._type;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+type: this._type,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class Map implements HasKind {
+
+      _key : TypeSignature ;
+
+      _value : TypeSignature ;
+    constructor (
+key:
+ TypeSignature,
+value:
+ TypeSignature,
+      ) {
+          this._key = key;
+          this._value = value;
+    }
+
+key(value: TypeSignature) : Map {
+      this._key = value;
+      return this;
+    }
+    getKey() : TypeSignature {
+      return this._key;
+
+    }
+value(value: TypeSignature) : Map {
+      this._value = value;
+      return this;
+    }
+    getValue() : TypeSignature {
+      return this._value;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+key: this._key,value: this._value,      }
+
     }
-    getComponents() : string[] {
-      return this._components;
+}
+
+    
+    
+    
+    
+
+
+
+export const Object = "object";
+
+    
+    
+    
+    
 
+
+
+export class AnonymousStruct implements HasKind {
+ // The fields in this struct. 
+      _fields : Field[] =  [];
+    constructor (
+      ) {
     }
-allow(value: LiquidTemplate) : UrlRestriction {
-      this._allow = value;
+
+fields(value: Field[]) : AnonymousStruct {
+      this._fields = value;
       return this;
     }
-    getAllow() : LiquidTemplate {
-      return this._allow;
+    getFields() : Field[] {
+      return this._fields;
 
     }
 
     getKind() : string {
-      return "wick/resource/url@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/resource/url@v1",
-components: this._components,allow: this._allow,      }
+fields: this._fields,      }
 
     }
 }
@@ -1981,58 +5395,60 @@ components: this._components,allow: this._allow,      }
     
     
 
+    
+    
+    
+export type TypeDefinition =
+      StructSignature|EnumSignature|UnionSignature
+    ;
+    
 
 
-export class TcpPortRestriction implements HasKind {
- // The components this restriction applies to 
-      _components : string[] =  [];
- // The address to allow 
-      _address : LiquidTemplate ;
- // The port to allow 
-      _port : LiquidTemplate ;
+
+export class StructSignature implements HasKind {
+ // The name of the struct. 
+      _name : string ="";
+ // The fields in this struct. 
+      _fields : Field[] =  [];
+ // The description of the struct. 
+      _description : string| undefined =  undefined;
     constructor (
-address:
- LiquidTemplate,
-port:
- LiquidTemplate,
       ) {
-          this._address = address;
-          this._port = port;
     }
 
-components(value: string[]) : TcpPortRestriction {
-      this._components = value;
+name(value: string) : StructSignature {
+      this._name = value;
       return this;
     }
-    getComponents() : string[] {
-      return this._components;
+    getName() : string {
+      return this._name;
 
     }
-address(value: LiquidTemplate) : TcpPortRestriction {
-      this._address = value;
+fields(value: Field[]) : StructSignature {
+      this._fields = value;
       return this;
     }
-    getAddress() : LiquidTemplate {
-      return this._address;
+    getFields() : Field[] {
+      return this._fields;
 
     }
-port(value: LiquidTemplate) : TcpPortRestriction {
-      this._port = value;
+description(value: string| undefined) : StructSignature {
+      this._description = value;
       return this;
     }
-    getPort() : LiquidTemplate {
-      return this._port;
+    getDescription() : string| undefined {
+      return this._description;
 
     }
 
     getKind() : string {
-      return "wick/resource/tcpport@v1";
+      return "wick/type/struct@v1";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/resource/tcpport@v1",
-components: this._components,address: this._address,port: this._port,      }
+        kind : "wick/type/struct@v1",
+name: this._name,fields: this._fields,description: this._description,      }
 
     }
 }
@@ -2044,56 +5460,169 @@ components: this._components,address: this._address,port: this._port,      }
 
 
 
-export class UdpPortRestriction implements HasKind {
- // The components this restriction applies to 
-      _components : string[] =  [];
- // The address to allow 
-      _address : LiquidTemplate ;
- // The port to allow 
-      _port : LiquidTemplate ;
+export class UnionSignature implements HasKind {
+ // The name of the union. 
+      _name : string ="";
+ // The types in the union. 
+      _types : TypeSignature[] =  [];
+ // The description of the union. 
+      _description : string| undefined =  undefined;
     constructor (
-address:
- LiquidTemplate,
-port:
- LiquidTemplate,
       ) {
-          this._address = address;
-          this._port = port;
     }
 
-components(value: string[]) : UdpPortRestriction {
-      this._components = value;
+name(value: string) : UnionSignature {
+      this._name = value;
       return this;
     }
-    getComponents() : string[] {
-      return this._components;
+    getName() : string {
+      return this._name;
 
     }
-address(value: LiquidTemplate) : UdpPortRestriction {
-      this._address = value;
+types(value: TypeSignature[]) : UnionSignature {
+      this._types = value;
       return this;
     }
-    getAddress() : LiquidTemplate {
-      return this._address;
+    getTypes() : TypeSignature[] {
+      return this._types;
 
     }
-port(value: LiquidTemplate) : UdpPortRestriction {
-      this._port = value;
+description(value: string| undefined) : UnionSignature {
+      this._description = value;
+      return this;
+    }
+    getDescription() : string| undefined {
+      return this._description;
+
+    }
+
+    getKind() : string {
+      return "wick/type/union@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/type/union@v1",
+name: this._name,types: this._types,description: this._description,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class EnumSignature implements HasKind {
+ // The name of the enum. 
+      _name : string ="";
+ // The variants in the enum. 
+      _variants : EnumVariant[] =  [];
+ // The description of the enum. 
+      _description : string| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+name(value: string) : EnumSignature {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+variants(value: EnumVariant[]) : EnumSignature {
+      this._variants = value;
+      return this;
+    }
+    getVariants() : EnumVariant[] {
+      return this._variants;
+
+    }
+description(value: string| undefined) : EnumSignature {
+      this._description = value;
+      return this;
+    }
+    getDescription() : string| undefined {
+      return this._description;
+
+    }
+
+    getKind() : string {
+      return "wick/type/enum@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/type/enum@v1",
+name: this._name,variants: this._variants,description: this._description,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class EnumVariant implements HasKind {
+ // The name of the variant. 
+      _name : string ="";
+ // The index of the variant. 
+      _index : number| undefined =  undefined;
+ // The optional value of the variant. 
+      _value : string| undefined =  undefined;
+ // A description of the variant. 
+      _description : string| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+name(value: string) : EnumVariant {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+index(value: number| undefined) : EnumVariant {
+      this._index = value;
+      return this;
+    }
+    getIndex() : number| undefined {
+      return this._index;
+
+    }
+value(value: string| undefined) : EnumVariant {
+      this._value = value;
+      return this;
+    }
+    getValue() : string| undefined {
+      return this._value;
+
+    }
+description(value: string| undefined) : EnumVariant {
+      this._description = value;
       return this;
     }
-    getPort() : LiquidTemplate {
-      return this._port;
+    getDescription() : string| undefined {
+      return this._description;
 
     }
 
     getKind() : string {
-      return "wick/resource/udpport@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/resource/udpport@v1",
-components: this._components,address: this._address,port: this._port,      }
+name: this._name,index: this._index,value: this._value,description: this._description,      }
 
     }
 }
@@ -2105,123 +5634,75 @@ components: this._components,address: this._address,port: this._port,      }
 
 
 
-export class ComponentConfiguration implements HasKind {
- // The name of the component. 
-      _name : string| undefined =  undefined;
- // Associated metadata for this component. 
-      _metadata : Metadata| undefined =  undefined;
- // Details about the package for this component. 
-      _package : PackageDefinition| undefined =  undefined;
- // Configuration for when wick hosts this component as a service. 
-      _host : HostConfig| undefined =  undefined;
- // Resources that the component can access. 
-      _resources : ResourceBinding[] =  [];
- // Components or types to import into this component&#x27;s scope. 
-      _import : ImportBinding[] =  [];
- // Additional types to export and make available to the component. 
-      _types : TypeDefinition[] =  [];
- // Interfaces the component requires to operate. 
-      _requires : InterfaceBinding[] =  [];
- // Configuration specific to different kinds of components. 
-      _component : ComponentKind ;
- // Assertions that can be run against the component to validate its behavior. 
-      _tests : TestConfiguration[] =  [];
+export class OperationInstance implements HasKind {
+ // The name of the binding. 
+      _name : string ;
+ // The operation to bind to. 
+      _operation :string | ComponentOperationExpression ;
+ // Data to associate with the reference, if any. 
+      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
+ // Timeout (in milliseconds) to wait for the operation to complete. Use 0 to wait indefinitely. 
+      _timeout : number| undefined =  undefined;
+ // How to retry the operation when an invocation fails. 
+      _retry : RetryPolicy| undefined =  undefined;
     constructor (
-component:
- ComponentKind,
+name:
+ string,
+operation:
+string | ComponentOperationExpression,
       ) {
-          this._component = component;
+          this._name = name;
+          this._operation = operation;
     }
 
-name(value: string| undefined) : ComponentConfiguration {
+name(value: string) : OperationInstance {
       this._name = value;
       return this;
     }
-    getName() : string| undefined {
+    getName() : string {
       return this._name;
 
     }
-metadata(value: Metadata| undefined) : ComponentConfiguration {
-      this._metadata = value;
-      return this;
-    }
-    getMetadata() : Metadata| undefined {
-      return this._metadata;
-
-    }
-package(value: PackageDefinition| undefined) : ComponentConfiguration {
-      this._package = value;
-      return this;
-    }
-    getPackage() : PackageDefinition| undefined {
-      return this._package;
-
-    }
-host(value: HostConfig| undefined) : ComponentConfiguration {
-      this._host = value;
-      return this;
-    }
-    getHost() : HostConfig| undefined {
-      return this._host;
-
-    }
-resources(value: ResourceBinding[]) : ComponentConfiguration {
-      this._resources = value;
-      return this;
-    }
-    getResources() : ResourceBinding[] {
-      return this._resources;
-
-    }
-import(value: ImportBinding[]) : ComponentConfiguration {
-      this._import = value;
-      return this;
-    }
-    getImport() : ImportBinding[] {
-      return this._import;
-
-    }
-types(value: TypeDefinition[]) : ComponentConfiguration {
-      this._types = value;
+operation(value: ComponentOperationExpression) : OperationInstance {
+      this._operation = value;
       return this;
     }
-    getTypes() : TypeDefinition[] {
-      return this._types;
+    getOperation() :string | ComponentOperationExpression {
+      return this._operation;
 
     }
-requires(value: InterfaceBinding[]) : ComponentConfiguration {
-      this._requires = value;
+with(value:   Record<string,LiquidJsonValue>| undefined) : OperationInstance {
+      this._with = value;
       return this;
     }
-    getRequires() : InterfaceBinding[] {
-      return this._requires;
+    getWith() :   Record<string,LiquidJsonValue>| undefined {
+      return this._with;
 
     }
-component(value: ComponentKind) : ComponentConfiguration {
-      this._component = value;
+timeout(value: number| undefined) : OperationInstance {
+      this._timeout = value;
       return this;
     }
-    getComponent() : ComponentKind {
-      return this._component;
+    getTimeout() : number| undefined {
+      return this._timeout;
 
     }
-tests(value: TestConfiguration[]) : ComponentConfiguration {
-      this._tests = value;
+retry(value: RetryPolicy| undefined) : OperationInstance {
+      this._retry = value;
       return this;
     }
-    getTests() : TestConfiguration[] {
-      return this._tests;
+    getRetry() : RetryPolicy| undefined {
+      return this._retry;
 
     }
 
     getKind() : string {
-      return "wick/component@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component@v1",
-name: this._name,metadata: this._metadata,package: this._package,host: this._host,resources: this._resources,import: this._import,types: this._types,requires: this._requires,component: this._component,tests: this._tests,      }
+name: this._name,operation: this._operation,with: this._with,timeout: this._timeout,retry: this._retry,      }
 
     }
 }
@@ -2233,35 +5714,72 @@ name: this._name,metadata: this._metadata,package: this._package,host: this._hos
 
 
 
-export class InterfaceBinding implements HasKind {
- // The name of the interface. 
-      _name : string ;
- // The interface to bind to. 
-      _interface : InterfaceDefinition ;
+export class TestDefinition implements HasKind {
+ // The name of the test. 
+      _name : string| undefined =  undefined;
+ // The operaton to test. 
+      _operation : string ;
+ // Inherent data to use for the test. 
+      _inherent : InherentData| undefined =  undefined;
+ // The configuration for the operation, if any. 
+      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
+ // The inputs to the test. 
+      _inputs : PacketData[] =  [];
+ // The expected outputs of the operation. 
+      _outputs : TestPacketData[] =  [];
     constructor (
-name:
+operation:
  string,
-interface_:
- InterfaceDefinition,
       ) {
-          this._name = name;
-          this._interface = interface_;
+          this._operation = operation;
     }
 
-name(value: string) : InterfaceBinding {
+name(value: string| undefined) : TestDefinition {
       this._name = value;
       return this;
     }
-    getName() : string {
+    getName() : string| undefined {
       return this._name;
 
     }
-interface(value: InterfaceDefinition) : InterfaceBinding {
-      this._interface = value;
+operation(value: string) : TestDefinition {
+      this._operation = value;
       return this;
     }
-    getInterface() : InterfaceDefinition {
-      return this._interface;
+    getOperation() : string {
+      return this._operation;
+
+    }
+inherent(value: InherentData| undefined) : TestDefinition {
+      this._inherent = value;
+      return this;
+    }
+    getInherent() : InherentData| undefined {
+      return this._inherent;
+
+    }
+with(value:   Record<string,LiquidJsonValue>| undefined) : TestDefinition {
+      this._with = value;
+      return this;
+    }
+    getWith() :   Record<string,LiquidJsonValue>| undefined {
+      return this._with;
+
+    }
+inputs(value: PacketData[]) : TestDefinition {
+      this._inputs = value;
+      return this;
+    }
+    getInputs() : PacketData[] {
+      return this._inputs;
+
+    }
+outputs(value: TestPacketData[]) : TestDefinition {
+      this._outputs = value;
+      return this;
+    }
+    getOutputs() : TestPacketData[] {
+      return this._outputs;
 
     }
 
@@ -2271,7 +5789,7 @@ interface(value: InterfaceDefinition) : InterfaceBinding {
 
     toJSON() : any {
       return {
-name: this._name,interface: this._interface,      }
+name: this._name,operation: this._operation,inherent: this._inherent,with: this._with,inputs: this._inputs,outputs: this._outputs,      }
 
     }
 }
@@ -2283,29 +5801,29 @@ name: this._name,interface: this._interface,      }
 
 
 
-export class InterfaceDefinition implements HasKind {
- // Types used by the interface&#x27;s operations 
-      _types : TypeDefinition[] =  [];
- // A list of operations defined by this interface. 
-      _operations : OperationDefinition[] =  [];
+export class InherentData implements HasKind {
+ // A random seed, i.e. to initialize a random number generator. 
+      _seed : number| undefined =  undefined;
+ // A timestamp. 
+      _timestamp : number| undefined =  undefined;
     constructor (
       ) {
     }
 
-types(value: TypeDefinition[]) : InterfaceDefinition {
-      this._types = value;
+seed(value: number| undefined) : InherentData {
+      this._seed = value;
       return this;
     }
-    getTypes() : TypeDefinition[] {
-      return this._types;
+    getSeed() : number| undefined {
+      return this._seed;
 
     }
-operations(value: OperationDefinition[]) : InterfaceDefinition {
-      this._operations = value;
+timestamp(value: number| undefined) : InherentData {
+      this._timestamp = value;
       return this;
     }
-    getOperations() : OperationDefinition[] {
-      return this._operations;
+    getTimestamp() : number| undefined {
+      return this._timestamp;
 
     }
 
@@ -2315,7 +5833,7 @@ operations(value: OperationDefinition[]) : InterfaceDefinition {
 
     toJSON() : any {
       return {
-types: this._types,operations: this._operations,      }
+seed: this._seed,timestamp: this._timestamp,      }
 
     }
 }
@@ -2325,52 +5843,70 @@ types: this._types,operations: this._operations,      }
     
     
 
+    
+    
+    
+export type PacketData =
+      SuccessPacket|SignalPacket|ErrorPacket
+    ;
+    
+
+    
+    
+    
+export type TestPacketData =
+      SuccessPacket|SignalPacket|PacketAssertionDef|ErrorPacket
+    ;
+    
 
 
-export class CompositeComponentConfiguration implements HasKind {
- // A list of operations exposed by the Composite component. 
-      _operations : CompositeOperationDefinition[] =  [];
- // Configuration necessary to provide when instantiating the component. 
-      _with : Field[] =  [];
- // A component or components whose operations you want to inherit from. 
-      _extends : string[] =  [];
+
+export class SignalPacket implements HasKind {
+ // The name of the input or output this packet is going to or coming from. 
+      _name : string ;
+ // Any flags set on the packet. Deprecated, use &#x27;flag:&#x27; instead 
+      _flags : PacketFlags| undefined =  undefined;
+ // The flag set on the packet. 
+      _flag : PacketFlag| undefined =  undefined;
     constructor (
+name:
+ string,
       ) {
+          this._name = name;
     }
 
-operations(value: CompositeOperationDefinition[]) : CompositeComponentConfiguration {
-      this._operations = value;
+name(value: string) : SignalPacket {
+      this._name = value;
       return this;
     }
-    getOperations() : CompositeOperationDefinition[] {
-      return this._operations;
+    getName() : string {
+      return this._name;
 
     }
-with(value: Field[]) : CompositeComponentConfiguration {
-      this._with = value;
+flags(value: PacketFlags| undefined) : SignalPacket {
+      this._flags = value;
       return this;
     }
-    getWith() : Field[] {
-      return this._with;
+    getFlags() : PacketFlags| undefined {
+      return this._flags;
 
     }
-extends(value: string[]) : CompositeComponentConfiguration {
-      this._extends = value;
+flag(value: PacketFlag| undefined) : SignalPacket {
+      this._flag = value;
       return this;
     }
-    getExtends() : string[] {
-      return this._extends;
+    getFlag() : PacketFlag| undefined {
+      return this._flag;
 
     }
 
     getKind() : string {
-      return "wick/component/composite@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component/composite@v1",
-operations: this._operations,with: this._with,extends: this._extends,      }
+name: this._name,flags: this._flags,flag: this._flag,      }
 
     }
 }
@@ -2382,73 +5918,45 @@ operations: this._operations,with: this._with,extends: this._extends,      }
 
 
 
-export class WasmComponentConfiguration implements HasKind {
- // The path or OCI reference to the WebAssembly module 
-      _ref : string ;
- // Volumes to expose to the component. 
-      _volumes : ExposedVolume[] =  [];
- // The default size to allocate to the component&#x27;s send/receive buffer. 
-      _maxPacketSize : number| undefined =  undefined;
- // Configuration necessary to provide when instantiating the component. 
-      _with : Field[] =  [];
- // A list of operations implemented by the WebAssembly module. 
-      _operations : OperationDefinition[] =  [];
+export class SuccessPacket implements HasKind {
+ // The name of the input or output this packet is going to or coming from. 
+      _name : string ;
+ // The packet payload. 
+      _value : LiquidJsonValue ;
     constructor (
-ref:
+name:
  string,
+value:
+ LiquidJsonValue,
       ) {
-          this._ref = ref;
-    }
-
-ref(value: string) : WasmComponentConfiguration {
-      this._ref = value;
-      return this;
-    }
-    getRef() : string {
-      return this._ref;
-
-    }
-volumes(value: ExposedVolume[]) : WasmComponentConfiguration {
-      this._volumes = value;
-      return this;
-    }
-    getVolumes() : ExposedVolume[] {
-      return this._volumes;
-
-    }
-maxPacketSize(value: number| undefined) : WasmComponentConfiguration {
-      this._maxPacketSize = value;
-      return this;
+          this._name = name;
+          this._value = value;
     }
-    getMaxPacketSize() : number| undefined {
-      return this._maxPacketSize;
 
-    }
-with(value: Field[]) : WasmComponentConfiguration {
-      this._with = value;
+name(value: string) : SuccessPacket {
+      this._name = value;
       return this;
     }
-    getWith() : Field[] {
-      return this._with;
+    getName() : string {
+      return this._name;
 
     }
-operations(value: OperationDefinition[]) : WasmComponentConfiguration {
-      this._operations = value;
+value(value: LiquidJsonValue) : SuccessPacket {
+      this._value = value;
       return this;
     }
-    getOperations() : OperationDefinition[] {
-      return this._operations;
+    getValue() : LiquidJsonValue {
+      return this._value;
 
     }
 
     getKind() : string {
-      return "wick/component/wasmrs@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component/wasmrs@v1",
-ref: this._ref,volumes: this._volumes,max_packet_size: this._maxPacketSize,with: this._with,operations: this._operations,      }
+name: this._name,value: this._value,      }
 
     }
 }
@@ -2460,63 +5968,42 @@ ref: this._ref,volumes: this._volumes,max_packet_size: this._maxPacketSize,with:
 
 
 
-export class WasmComponentModel implements HasKind {
- // The path or OCI reference to the WebAssembly module 
-      _ref : string ;
- // Volumes to expose to the component. 
-      _volumes : ExposedVolume[] =  [];
- // Configuration necessary to provide when instantiating the component. 
-      _with : Field[] =  [];
- // A list of operations implemented by the WebAssembly module. 
-      _operations : OperationDefinition[] =  [];
+export class PacketAssertionDef implements HasKind {
+ // The name of the input or output this packet is going to or coming from. 
+      _name : string ;
+ // An assertion to test against the packet. 
+      _assertions : PacketAssertion[] =  [];
     constructor (
-ref:
+name:
  string,
       ) {
-          this._ref = ref;
-    }
-
-ref(value: string) : WasmComponentModel {
-      this._ref = value;
-      return this;
-    }
-    getRef() : string {
-      return this._ref;
-
-    }
-volumes(value: ExposedVolume[]) : WasmComponentModel {
-      this._volumes = value;
-      return this;
+          this._name = name;
     }
-    getVolumes() : ExposedVolume[] {
-      return this._volumes;
 
-    }
-with(value: Field[]) : WasmComponentModel {
-      this._with = value;
+name(value: string) : PacketAssertionDef {
+      this._name = value;
       return this;
     }
-    getWith() : Field[] {
-      return this._with;
+    getName() : string {
+      return this._name;
 
     }
-operations(value: OperationDefinition[]) : WasmComponentModel {
-      this._operations = value;
+assertions(value: PacketAssertion[]) : PacketAssertionDef {
+      this._assertions = value;
       return this;
     }
-    getOperations() : OperationDefinition[] {
-      return this._operations;
+    getAssertions() : PacketAssertion[] {
+      return this._assertions;
 
     }
 
     getKind() : string {
-      return "wick/component/wasm@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component/wasm@v1",
-ref: this._ref,volumes: this._volumes,with: this._with,operations: this._operations,      }
+name: this._name,assertions: this._assertions,      }
 
     }
 }
@@ -2528,35 +6015,45 @@ ref: this._ref,volumes: this._volumes,with: this._with,operations: this._operati
 
 
 
-export class ExposedVolume implements HasKind {
- // The resource ID of the volume. 
-      _resource : BoundIdentifier ;
- // The path to map it to in the component. 
-      _path : string ;
+export class PacketAssertion implements HasKind {
+ // The optional path to a value in the packet to assert against. 
+      _path : string| undefined =  undefined;
+ // The operation to use when asserting against a packet. 
+      _operator : AssertionOperator ;
+ // A value or object combine with the operator to assert against a packet value. 
+      _value : LiquidJsonValue ;
     constructor (
-resource:
- BoundIdentifier,
-path:
- string,
+operator:
+ AssertionOperator,
+value:
+ LiquidJsonValue,
       ) {
-          this._resource = resource;
-          this._path = path;
+          this._operator = operator;
+          this._value = value;
     }
 
-resource(value: BoundIdentifier) : ExposedVolume {
-      this._resource = value;
+path(value: string| undefined) : PacketAssertion {
+      this._path = value;
       return this;
     }
-    getResource() : BoundIdentifier {
-      return this._resource;
+    getPath() : string| undefined {
+      return this._path;
 
     }
-path(value: string) : ExposedVolume {
-      this._path = value;
+operator(value: AssertionOperator) : PacketAssertion {
+      this._operator = value;
       return this;
     }
-    getPath() : string {
-      return this._path;
+    getOperator() : AssertionOperator {
+      return this._operator;
+
+    }
+value(value: LiquidJsonValue) : PacketAssertion {
+      this._value = value;
+      return this;
+    }
+    getValue() : LiquidJsonValue {
+      return this._value;
 
     }
 
@@ -2566,7 +6063,7 @@ path(value: string) : ExposedVolume {
 
     toJSON() : any {
       return {
-resource: this._resource,path: this._path,      }
+path: this._path,operator: this._operator,value: this._value,      }
 
     }
 }
@@ -2578,105 +6075,72 @@ resource: this._resource,path: this._path,      }
 
     
     
+export enum AssertionOperator {
+Equals = "Equals",LessThan = "LessThan",GreaterThan = "GreaterThan",Regex = "Regex",Contains = "Contains",}
     
-export type ComponentKind =
-      WasmComponentConfiguration|WasmComponentModel|CompositeComponentConfiguration|SqlComponent|HttpClientComponent
-    ;
-    
-
-    
-    
-    
-export type ImportDefinition =
-      TypesComponent|ManifestComponent|SqlComponent|HttpClientComponent
-    ;
-    
-
-    
-    
-    
-export type ComponentDefinition =
-      GrpcUrlComponent|ManifestComponent|ComponentReference|SqlComponent|HttpClientComponent
-    ;
     
 
 
 
-export class TypesComponent implements HasKind {
- // The URL (and optional tag) or local file path to find the types manifest. 
-      _ref : string ;
- // The types to import from the manifest. 
-      _types : string[] =  [];
+export class ErrorPacket implements HasKind {
+ // The name of the input or output this packet is going to or coming from. 
+      _name : string ;
+ // Any flags set on the packet. Deprecated, use &#x27;flag:&#x27; instead 
+      _flags : PacketFlags| undefined =  undefined;
+ // The flag set on the packet. 
+      _flag : PacketFlag| undefined =  undefined;
+ // The error message. 
+      _error : LiquidTemplate ;
     constructor (
-ref:
+name:
  string,
+error:
+ LiquidTemplate,
       ) {
-          this._ref = ref;
-    }
-
-ref(value: string) : TypesComponent {
-      this._ref = value;
-      return this;
+          this._name = name;
+          this._error = error;
     }
-    getRef() : string {
-      return this._ref;
 
-    }
-types(value: string[]) : TypesComponent {
-      this._types = value;
+name(value: string) : ErrorPacket {
+      this._name = value;
       return this;
     }
-    getTypes() : string[] {
-      return this._types;
+    getName() : string {
+      return this._name;
 
     }
-
-    getKind() : string {
-      return "wick/component/types@v1";
+flags(value: PacketFlags| undefined) : ErrorPacket {
+      this._flags = value;
+      return this;
     }
-
-    toJSON() : any {
-      return {
-        kind : "wick/component/types@v1",
-ref: this._ref,types: this._types,      }
+    getFlags() : PacketFlags| undefined {
+      return this._flags;
 
     }
-}
-
-    
-    
-    
-    
-
-
-
-export class ComponentReference implements HasKind {
- // The id of the referenced component. 
-      _id : BoundIdentifier ;
-    constructor (
-id:
- BoundIdentifier,
-      ) {
-          this._id = id;
+flag(value: PacketFlag| undefined) : ErrorPacket {
+      this._flag = value;
+      return this;
     }
+    getFlag() : PacketFlag| undefined {
+      return this._flag;
 
-id(value: BoundIdentifier) : ComponentReference {
-      this._id = value;
+    }
+error(value: LiquidTemplate) : ErrorPacket {
+      this._error = value;
       return this;
     }
-    getId() : BoundIdentifier {
-      return this._id;
+    getError() : LiquidTemplate {
+      return this._error;
 
     }
 
     getKind() : string {
-      return "wick/component/reference@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component/reference@v1",
-id: this._id,      }
+name: this._name,flags: this._flags,flag: this._flag,error: this._error,      }
 
     }
 }
@@ -2688,39 +6152,39 @@ id: this._id,      }
 
 
 
-export class HostConfig implements HasKind {
- // Whether or not to allow the &#x60;:latest&#x60; tag on remote artifacts. 
-      _allowLatest : boolean =false;
- // A list of registries to connect to insecurely (over HTTP vs HTTPS). 
-      _insecureRegistries : string[] =  [];
- // Configuration for the GRPC server. 
-      _rpc : HttpConfig| undefined =  undefined;
+export class PacketFlags implements HasKind {
+ // Indicates the port should be considered closed. 
+      _done : boolean =false;
+ // Indicates the opening of a new substream context within the parent stream. 
+      _open : boolean =false;
+ // Indicates the closing of a substream context within the parent stream. 
+      _close : boolean =false;
     constructor (
       ) {
     }
 
-allowLatest(value: boolean) : HostConfig {
-      this._allowLatest = value;
+done(value: boolean) : PacketFlags {
+      this._done = value;
       return this;
     }
-    getAllowLatest() : boolean {
-      return this._allowLatest;
+    getDone() : boolean {
+      return this._done;
 
     }
-insecureRegistries(value: string[]) : HostConfig {
-      this._insecureRegistries = value;
+open(value: boolean) : PacketFlags {
+      this._open = value;
       return this;
     }
-    getInsecureRegistries() : string[] {
-      return this._insecureRegistries;
+    getOpen() : boolean {
+      return this._open;
 
     }
-rpc(value: HttpConfig| undefined) : HostConfig {
-      this._rpc = value;
+close(value: boolean) : PacketFlags {
+      this._close = value;
       return this;
     }
-    getRpc() : HttpConfig| undefined {
-      return this._rpc;
+    getClose() : boolean {
+      return this._close;
 
     }
 
@@ -2730,7 +6194,7 @@ rpc(value: HttpConfig| undefined) : HostConfig {
 
     toJSON() : any {
       return {
-allow_latest: this._allowLatest,insecure_registries: this._insecureRegistries,rpc: this._rpc,      }
+done: this._done,open: this._open,close: this._close,      }
 
     }
 }
@@ -2740,81 +6204,82 @@ allow_latest: this._allowLatest,insecure_registries: this._insecureRegistries,rp
     
     
 
+    
+    
+export enum PacketFlag {
+Done = "Done",Open = "Open",Close = "Close",}
+    
+    
 
 
-export class HttpConfig implements HasKind {
- // Enable/disable the server. 
-      _enabled : boolean =false;
- // The port to bind to. 
-      _port : number| undefined =  undefined;
- // The address to bind to. 
-      _address : string| undefined =  undefined;
- // Path to pem file for TLS. 
-      _pem : string| undefined =  undefined;
- // Path to key file for TLS. 
-      _key : string| undefined =  undefined;
- // Path to CA file. 
-      _ca : string| undefined =  undefined;
+
+export class SqlComponent implements HasKind {
+ // The connect string URL resource for the database. 
+      _resource : BoundIdentifier ;
+ // Whether or not to use TLS. 
+      _tls : boolean =false;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : SqlQueryKind[] =  [];
+ // Schema changes to apply when the component starts, in order. Each is applied once per database. 
+      _migrations : SqlMigration[] =  [];
     constructor (
+resource:
+ BoundIdentifier,
       ) {
+          this._resource = resource;
     }
 
-enabled(value: boolean) : HttpConfig {
-      this._enabled = value;
-      return this;
-    }
-    getEnabled() : boolean {
-      return this._enabled;
-
-    }
-port(value: number| undefined) : HttpConfig {
-      this._port = value;
+resource(value: BoundIdentifier) : SqlComponent {
+      this._resource = value;
       return this;
     }
-    getPort() : number| undefined {
-      return this._port;
+    getResource() : BoundIdentifier {
+      return this._resource;
 
     }
-address(value: string| undefined) : HttpConfig {
-      this._address = value;
+tls(value: boolean) : SqlComponent {
+      this._tls = value;
       return this;
     }
-    getAddress() : string| undefined {
-      return this._address;
+    getTls() : boolean {
+      return this._tls;
 
     }
-pem(value: string| undefined) : HttpConfig {
-      this._pem = value;
+with(value: Field[]) : SqlComponent {
+      this._with = value;
       return this;
     }
-    getPem() : string| undefined {
-      return this._pem;
+    getWith() : Field[] {
+      return this._with;
 
     }
-key(value: string| undefined) : HttpConfig {
-      this._key = value;
+operations(value: SqlQueryKind[]) : SqlComponent {
+      this._operations = value;
       return this;
     }
-    getKey() : string| undefined {
-      return this._key;
+    getOperations() : SqlQueryKind[] {
+      return this._operations;
 
     }
-ca(value: string| undefined) : HttpConfig {
-      this._ca = value;
+migrations(value: SqlMigration[]) : SqlComponent {
+      this._migrations = value;
       return this;
     }
-    getCa() : string| undefined {
-      return this._ca;
+    getMigrations() : SqlMigration[] {
+      return this._migrations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/sql@v1";
     }
 
     toJSON() : any {
       return {
-enabled: this._enabled,port: this._port,address: this._address,pem: this._pem,key: this._key,ca: this._ca,      }
+        kind : "wick/component/sql@v1",
+resource: this._resource,tls: this._tls,with: this._with,operations: this._operations,migrations: this._migrations,      }
 
     }
 }
@@ -2826,43 +6291,45 @@ enabled: this._enabled,port: this._port,address: this._address,pem: this._pem,ke
 
 
 
-export class GrpcUrlComponent implements HasKind {
- // The GRPC URL to connect to. 
-      _url : string ;
- // Any configuration necessary for the component. 
-      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
+export class SqlMigration implements HasKind {
+ // A unique name for the migration, recorded in the database once it&#x27;s applied. 
+      _name : string ;
+ // The SQL statements to run. 
+      _sql : string ;
     constructor (
-url:
+name:
+ string,
+sql:
  string,
       ) {
-          this._url = url;
+          this._name = name;
+          this._sql = sql;
     }
 
-url(value: string) : GrpcUrlComponent {
-      this._url = value;
+name(value: string) : SqlMigration {
+      this._name = value;
       return this;
     }
-    getUrl() : string {
-      return this._url;
+    getName() : string {
+      return this._name;
 
     }
-with(value:   Record<string,LiquidJsonValue>| undefined) : GrpcUrlComponent {
-      this._with = value;
+sql(value: string) : SqlMigration {
+      this._sql = value;
       return this;
     }
-    getWith() :   Record<string,LiquidJsonValue>| undefined {
-      return this._with;
+    getSql() : string {
+      return this._sql;
 
     }
 
     getKind() : string {
-      return "wick/component/grpc@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component/grpc@v1",
-url: this._url,with: this._with,      }
+name: this._name,sql: this._sql,      }
 
     }
 }
@@ -2872,65 +6339,115 @@ url: this._url,with: this._with,      }
     
     
 
+    
+    
+    
+export type SqlQueryKind =
+      SqlQueryOperationDefinition|SqlExecOperationDefinition
+    ;
+    
 
 
-export class ManifestComponent implements HasKind {
- // The URL (and optional tag) or local file path to find the manifest. 
-      _ref : string ;
- // Any configuration necessary for the component. 
-      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
- // External components to provide to the referenced component. 
-      _provide :   Record<string,string> =  {};
- // If applicable, the default size to allocate to the component&#x27;s send/receive buffer. 
-      _maxPacketSize : number| undefined =  undefined;
+
+export class SqlQueryOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // Types of the inputs to the operation. 
+      _inputs : Field[] =  [];
+ // Types of the outputs to the operation. 
+      _outputs : Field[] =  [];
+ // The query to execute. 
+      _query : string ;
+ // The positional arguments to the query, defined as a list of input names. 
+      _arguments : string[] =  [];
+ // What to do when an error occurs. 
+      _onError : ErrorBehavior| undefined =  undefined;
+ // How the query takes part in a database transaction shared by the operations called in the same flow transaction. 
+      _transaction : SqlTransaction| undefined =  undefined;
     constructor (
-ref:
+name:
+ string,
+query:
  string,
       ) {
-          this._ref = ref;
+          this._name = name;
+          this._query = query;
+    }
+
+name(value: string) : SqlQueryOperationDefinition {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
+
+    }
+with(value: Field[]) : SqlQueryOperationDefinition {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+inputs(value: Field[]) : SqlQueryOperationDefinition {
+      this._inputs = value;
+      return this;
+    }
+    getInputs() : Field[] {
+      return this._inputs;
+
+    }
+outputs(value: Field[]) : SqlQueryOperationDefinition {
+      this._outputs = value;
+      return this;
     }
+    getOutputs() : Field[] {
+      return this._outputs;
 
-ref(value: string) : ManifestComponent {
-      this._ref = value;
+    }
+query(value: string) : SqlQueryOperationDefinition {
+      this._query = value;
       return this;
     }
-    getRef() : string {
-      return this._ref;
+    getQuery() : string {
+      return this._query;
 
     }
-with(value:   Record<string,LiquidJsonValue>| undefined) : ManifestComponent {
-      this._with = value;
+arguments(value: string[]) : SqlQueryOperationDefinition {
+      this._arguments = value;
       return this;
     }
-    getWith() :   Record<string,LiquidJsonValue>| undefined {
-      return this._with;
+    getArguments() : string[] {
+      return this._arguments;
 
     }
-provide(value:   Record<string,string>) : ManifestComponent {
-      this._provide = value;
+onError(value: ErrorBehavior| undefined) : SqlQueryOperationDefinition {
+      this._onError = value;
       return this;
     }
-    getProvide() :   Record<string,string> {
-      return this._provide;
+    getOnError() : ErrorBehavior| undefined {
+      return this._onError;
 
     }
-maxPacketSize(value: number| undefined) : ManifestComponent {
-      this._maxPacketSize = value;
+transaction(value: SqlTransaction| undefined) : SqlQueryOperationDefinition {
+      this._transaction = value;
       return this;
     }
-    getMaxPacketSize() : number| undefined {
-      return this._maxPacketSize;
+    getTransaction() : SqlTransaction| undefined {
+      return this._transaction;
 
     }
 
     getKind() : string {
-      return "wick/component/manifest@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component/manifest@v1",
-ref: this._ref,with: this._with,provide: this._provide,max_packet_size: this._maxPacketSize,      }
+name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,query: this._query,arguments: this._arguments,on_error: this._onError,transaction: this._transaction,      }
 
     }
 }
@@ -2942,26 +6459,36 @@ ref: this._ref,with: this._with,provide: this._provide,max_packet_size: this._ma
 
 
 
-export class CompositeOperationDefinition implements HasKind {
+export class SqlExecOperationDefinition implements HasKind {
  // The name of the operation. 
-      _name : string ="";
+      _name : string ;
  // Any configuration required by the operation. 
       _with : Field[] =  [];
  // Types of the inputs to the operation. 
       _inputs : Field[] =  [];
  // Types of the outputs to the operation. 
       _outputs : Field[] =  [];
- // A map of IDs to specific operations. 
-      _uses : OperationInstance[] =  [];
- // A list of connections from operation to operation. 
-      _flow : FlowExpression[] =  [];
- // Additional &#x60;CompositeOperationDefinition&#x60;s to define as children. 
-      _operations : CompositeOperationDefinition[] =  [];
+ // The query to execute. 
+      _exec : string ;
+ // The positional arguments to the query, defined as a list of input names. 
+      _arguments : string[] =  [];
+ // What to do when an error occurs. 
+      _onError : ErrorBehavior| undefined =  undefined;
+ // How the query takes part in a database transaction shared by the operations called in the same flow transaction. 
+      _transaction : SqlTransaction| undefined =  undefined;
+ // The number of streamed inputs to execute the prepared statement for together in one database transaction. Defaults to 1. 
+      _batch : number| undefined =  undefined;
     constructor (
+name:
+ string,
+exec:
+ string,
       ) {
+          this._name = name;
+          this._exec = exec;
     }
 
-name(value: string) : CompositeOperationDefinition {
+name(value: string) : SqlExecOperationDefinition {
       this._name = value;
       return this;
     }
@@ -2969,7 +6496,7 @@ name(value: string) : CompositeOperationDefinition {
       return this._name;
 
     }
-with(value: Field[]) : CompositeOperationDefinition {
+with(value: Field[]) : SqlExecOperationDefinition {
       this._with = value;
       return this;
     }
@@ -2977,7 +6504,7 @@ with(value: Field[]) : CompositeOperationDefinition {
       return this._with;
 
     }
-inputs(value: Field[]) : CompositeOperationDefinition {
+inputs(value: Field[]) : SqlExecOperationDefinition {
       this._inputs = value;
       return this;
     }
@@ -2985,7 +6512,7 @@ inputs(value: Field[]) : CompositeOperationDefinition {
       return this._inputs;
 
     }
-outputs(value: Field[]) : CompositeOperationDefinition {
+outputs(value: Field[]) : SqlExecOperationDefinition {
       this._outputs = value;
       return this;
     }
@@ -2993,28 +6520,44 @@ outputs(value: Field[]) : CompositeOperationDefinition {
       return this._outputs;
 
     }
-uses(value: OperationInstance[]) : CompositeOperationDefinition {
-      this._uses = value;
+exec(value: string) : SqlExecOperationDefinition {
+      this._exec = value;
       return this;
     }
-    getUses() : OperationInstance[] {
-      return this._uses;
+    getExec() : string {
+      return this._exec;
 
     }
-flow(value: FlowExpression[]) : CompositeOperationDefinition {
-      this._flow = value;
+arguments(value: string[]) : SqlExecOperationDefinition {
+      this._arguments = value;
       return this;
     }
-    getFlow() : FlowExpression[] {
-      return this._flow;
+    getArguments() : string[] {
+      return this._arguments;
 
     }
-operations(value: CompositeOperationDefinition[]) : CompositeOperationDefinition {
-      this._operations = value;
+onError(value: ErrorBehavior| undefined) : SqlExecOperationDefinition {
+      this._onError = value;
       return this;
     }
-    getOperations() : CompositeOperationDefinition[] {
-      return this._operations;
+    getOnError() : ErrorBehavior| undefined {
+      return this._onError;
+
+    }
+transaction(value: SqlTransaction| undefined) : SqlExecOperationDefinition {
+      this._transaction = value;
+      return this;
+    }
+    getTransaction() : SqlTransaction| undefined {
+      return this._transaction;
+
+    }
+batch(value: number| undefined) : SqlExecOperationDefinition {
+      this._batch = value;
+      return this;
+    }
+    getBatch() : number| undefined {
+      return this._batch;
 
     }
 
@@ -3024,7 +6567,7 @@ operations(value: CompositeOperationDefinition[]) : CompositeOperationDefinition
 
     toJSON() : any {
       return {
-name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,uses: this._uses,flow: this._flow,operations: this._operations,      }
+name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,exec: this._exec,arguments: this._arguments,on_error: this._onError,transaction: this._transaction,batch: this._batch,      }
 
     }
 }
@@ -3036,40 +6579,119 @@ name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,us
 
     
     
+export enum SqlTransaction {
+Join = "Join",Begin = "Begin",Commit = "Commit",Rollback = "Rollback",}
     
-export type FlowExpression =
-string |      ConnectionDefinition|BlockExpression
-    ;
     
 
 
 
-export class BlockExpression implements HasKind {
+    
+    
+export enum ErrorBehavior {
+Ignore = "Ignore",Commit = "Commit",Rollback = "Rollback",}
+    
+    
 
-      _expressions : FlowExpression[] ;
+
+
+export class HttpClientComponent implements HasKind {
+ // The URL base to use. 
+      _resource : BoundIdentifier ;
+ // The codec to use when encoding/decoding data. Can be overridden by individual operations. 
+      _codec : Codec| undefined =  undefined;
+ // The proxy HTTP / HTTPS to use. 
+      _proxy : Proxy| undefined =  undefined;
+ // The timeout in seconds 
+      _timeout : number| undefined =  undefined;
+ // The maximum number of redirects to follow. Use 0 to not follow redirects. Defaults to 10. 
+      _redirects : number| undefined =  undefined;
+ // Connection pooling and keep-alive settings for outbound requests. 
+      _pool : ConnectionPool| undefined =  undefined;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : HttpClientOperationDefinition[] =  [];
     constructor (
-expressions:
- FlowExpression[],
+resource:
+ BoundIdentifier,
       ) {
-          this._expressions = expressions;
+          this._resource = resource;
     }
 
-expressions(value: FlowExpression[]) : BlockExpression {
-      this._expressions = value;
+resource(value: BoundIdentifier) : HttpClientComponent {
+      this._resource = value;
       return this;
     }
-    getExpressions() : FlowExpression[] {
-      return this._expressions;
+    getResource() : BoundIdentifier {
+      return this._resource;
+
+    }
+codec(value: Codec| undefined) : HttpClientComponent {
+      this._codec = value;
+      return this;
+    }
+    getCodec() : Codec| undefined {
+      return this._codec;
+
+    }
+proxy(value: Proxy| undefined) : HttpClientComponent {
+      this._proxy = value;
+      return this;
+    }
+    getProxy() : Proxy| undefined {
+      return this._proxy;
+
+    }
+timeout(value: number| undefined) : HttpClientComponent {
+      this._timeout = value;
+      return this;
+    }
+    getTimeout() : number| undefined {
+      return this._timeout;
+
+    }
+redirects(value: number| undefined) : HttpClientComponent {
+      this._redirects = value;
+      return this;
+    }
+    getRedirects() : number| undefined {
+      return this._redirects;
+
+    }
+pool(value: ConnectionPool| undefined) : HttpClientComponent {
+      this._pool = value;
+      return this;
+    }
+    getPool() : ConnectionPool| undefined {
+      return this._pool;
+
+    }
+with(value: Field[]) : HttpClientComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+operations(value: HttpClientOperationDefinition[]) : HttpClientComponent {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : HttpClientOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/http@v1";
     }
 
     toJSON() : any {
       return {
-expressions: this._expressions,      }
+        kind : "wick/component/http@v1",
+resource: this._resource,codec: this._codec,proxy: this._proxy,timeout: this._timeout,redirects: this._redirects,pool: this._pool,with: this._with,operations: this._operations,      }
 
     }
 }
@@ -3081,35 +6703,39 @@ expressions: this._expressions,      }
 
 
 
-export class ConnectionDefinition implements HasKind {
- // An upstream operation&#x27;s output. 
-      _from : ConnectionTargetDefinition ;
- // A downstream operation&#x27;s input. 
-      _to : ConnectionTargetDefinition ;
+export class Proxy implements HasKind {
+ // The URL base to use. http, https are supported. 
+      _resource : string ="";
+ // The username to use when authenticating with the proxy. 
+      _username : string| undefined =  undefined;
+ // The password to use when authenticating with the proxy. 
+      _password : string| undefined =  undefined;
     constructor (
-from:
- ConnectionTargetDefinition,
-to:
- ConnectionTargetDefinition,
       ) {
-          this._from = from;
-          this._to = to;
     }
 
-from(value: ConnectionTargetDefinition) : ConnectionDefinition {
-      this._from = value;
+resource(value: string) : Proxy {
+      this._resource = value;
       return this;
     }
-    getFrom() : ConnectionTargetDefinition {
-      return this._from;
+    getResource() : string {
+      return this._resource;
 
     }
-to(value: ConnectionTargetDefinition) : ConnectionDefinition {
-      this._to = value;
+username(value: string| undefined) : Proxy {
+      this._username = value;
       return this;
     }
-    getTo() : ConnectionTargetDefinition {
-      return this._to;
+    getUsername() : string| undefined {
+      return this._username;
+
+    }
+password(value: string| undefined) : Proxy {
+      this._password = value;
+      return this;
+    }
+    getPassword() : string| undefined {
+      return this._password;
 
     }
 
@@ -3119,7 +6745,7 @@ to(value: ConnectionTargetDefinition) : ConnectionDefinition {
 
     toJSON() : any {
       return {
-from: this._from,to: this._to,      }
+resource: this._resource,username: this._username,password: this._password,      }
 
     }
 }
@@ -3131,42 +6757,49 @@ from: this._from,to: this._to,      }
 
 
 
-export class ConnectionTargetDefinition implements HasKind {
- // The instance ID of the component operation. 
-      _instance : string ;
- // The operation&#x27;s input or output (depending on to/from). 
-      _port : string| undefined =  undefined;
- // The default value to provide on this connection in the event of an error. 
-      _data :   Record<string,LiquidJsonValue>| undefined =  undefined;
+export class ConnectionPool implements HasKind {
+ // The maximum number of idle connections to keep open to each host. 
+      _maxIdlePerHost : number| undefined =  undefined;
+ // How long in seconds an idle connection is kept open before it is closed. 
+      _idleTimeout : number| undefined =  undefined;
+ // The interval in seconds between TCP keep-alive probes on open connections. 
+      _keepAlive : number| undefined =  undefined;
+ // Send every request with HTTP/2 without negotiating it first, so concurrent requests to the same host share one connection. Hosts that only speak HTTP/1.1 will fail. HTTPS hosts that support HTTP/2 negotiate it without this. 
+      _http2PriorKnowledge : boolean =false;
     constructor (
-instance:
- string,
       ) {
-          this._instance = instance;
     }
 
-instance(value: string) : ConnectionTargetDefinition {
-      this._instance = value;
+maxIdlePerHost(value: number| undefined) : ConnectionPool {
+      this._maxIdlePerHost = value;
       return this;
     }
-    getInstance() : string {
-      return this._instance;
+    getMaxIdlePerHost() : number| undefined {
+      return this._maxIdlePerHost;
 
     }
-port(value: string| undefined) : ConnectionTargetDefinition {
-      this._port = value;
+idleTimeout(value: number| undefined) : ConnectionPool {
+      this._idleTimeout = value;
+      return this;
+    }
+    getIdleTimeout() : number| undefined {
+      return this._idleTimeout;
+
+    }
+keepAlive(value: number| undefined) : ConnectionPool {
+      this._keepAlive = value;
       return this;
     }
-    getPort() : string| undefined {
-      return this._port;
+    getKeepAlive() : number| undefined {
+      return this._keepAlive;
 
     }
-data(value:   Record<string,LiquidJsonValue>| undefined) : ConnectionTargetDefinition {
-      this._data = value;
+http2PriorKnowledge(value: boolean) : ConnectionPool {
+      this._http2PriorKnowledge = value;
       return this;
     }
-    getData() :   Record<string,LiquidJsonValue>| undefined {
-      return this._data;
+    getHttp2PriorKnowledge() : boolean {
+      return this._http2PriorKnowledge;
 
     }
 
@@ -3176,7 +6809,7 @@ data(value:   Record<string,LiquidJsonValue>| undefined) : ConnectionTargetDefin
 
     toJSON() : any {
       return {
-instance: this._instance,port: this._port,data: this._data,      }
+max_idle_per_host: this._maxIdlePerHost,idle_timeout: this._idleTimeout,keep_alive: this._keepAlive,http2_prior_knowledge: this._http2PriorKnowledge,      }
 
     }
 }
@@ -3188,20 +6821,44 @@ instance: this._instance,port: this._port,data: this._data,      }
 
 
 
-export class OperationDefinition implements HasKind {
+export class HttpClientOperationDefinition implements HasKind {
  // The name of the operation. 
-      _name : string ="";
+      _name : string ;
  // Any configuration required by the operation. 
       _with : Field[] =  [];
  // Types of the inputs to the operation. 
       _inputs : Field[] =  [];
- // Types of the outputs to the operation. 
-      _outputs : Field[] =  [];
+ // The HTTP method to use. 
+      _method : HttpMethod ;
+ // The codec to use when encoding/decoding data. 
+      _codec : Codec| undefined =  undefined;
+ // Any headers to add to the request. 
+      _headers :   Record<string,string[]>| undefined =  undefined;
+ // The body to send, processed as a structured JSON liquid template. 
+      _body : LiquidJsonValue| undefined =  undefined;
+ // The path to append to our base URL, processed as a liquid template with each input as part of the template data. 
+      _path : string ="";
+ // Timeout (in milliseconds) for the whole request, including reading the response body. 
+      _timeout : number| undefined =  undefined;
+ // The maximum number of redirects to follow, overriding the component's. 
+      _redirects : number| undefined =  undefined;
+ // The proxy HTTP / HTTPS to use, overriding the component's. 
+      _proxy : Proxy| undefined =  undefined;
+ // An input whose packets are sent as the request body as they arrive, in place of `body`. Operations with a streamed body send one request per invocation. 
+      _bodyStream : string| undefined =  undefined;
+ // Send the response body as `bytes` packets as it arrives instead of decoding it with the codec. 
+      _streamResponse : boolean =false;
     constructor (
+name:
+ string,
+method:
+ HttpMethod,
       ) {
+          this._name = name;
+          this._method = method;
     }
 
-name(value: string) : OperationDefinition {
+name(value: string) : HttpClientOperationDefinition {
       this._name = value;
       return this;
     }
@@ -3209,7 +6866,7 @@ name(value: string) : OperationDefinition {
       return this._name;
 
     }
-with(value: Field[]) : OperationDefinition {
+with(value: Field[]) : HttpClientOperationDefinition {
       this._with = value;
       return this;
     }
@@ -3217,7 +6874,7 @@ with(value: Field[]) : OperationDefinition {
       return this._with;
 
     }
-inputs(value: Field[]) : OperationDefinition {
+inputs(value: Field[]) : HttpClientOperationDefinition {
       this._inputs = value;
       return this;
     }
@@ -3225,12 +6882,84 @@ inputs(value: Field[]) : OperationDefinition {
       return this._inputs;
 
     }
-outputs(value: Field[]) : OperationDefinition {
-      this._outputs = value;
+method(value: HttpMethod) : HttpClientOperationDefinition {
+      this._method = value;
       return this;
     }
-    getOutputs() : Field[] {
-      return this._outputs;
+    getMethod() : HttpMethod {
+      return this._method;
+
+    }
+codec(value: Codec| undefined) : HttpClientOperationDefinition {
+      this._codec = value;
+      return this;
+    }
+    getCodec() : Codec| undefined {
+      return this._codec;
+
+    }
+headers(value:   Record<string,string[]>| undefined) : HttpClientOperationDefinition {
+      this._headers = value;
+      return this;
+    }
+    getHeaders() :   Record<string,string[]>| undefined {
+      return this._headers;
+
+    }
+body(value: LiquidJsonValue| undefined) : HttpClientOperationDefinition {
+      this._body = value;
+      return this;
+    }
+    getBody() : LiquidJsonValue| undefined {
+      return this._body;
+
+    }
+path(value: string) : HttpClientOperationDefinition {
+      this._path = value;
+      return this;
+    }
+    getPath() : string {
+      return this._path;
+
+    }
+timeout(value: number| undefined) : HttpClientOperationDefinition {
+      this._timeout = value;
+      return this;
+    }
+    getTimeout() : number| undefined {
+      return this._timeout;
+
+    }
+redirects(value: number| undefined) : HttpClientOperationDefinition {
+      this._redirects = value;
+      return this;
+    }
+    getRedirects() : number| undefined {
+      return this._redirects;
+
+    }
+proxy(value: Proxy| undefined) : HttpClientOperationDefinition {
+      this._proxy = value;
+      return this;
+    }
+    getProxy() : Proxy| undefined {
+      return this._proxy;
+
+    }
+bodyStream(value: string| undefined) : HttpClientOperationDefinition {
+      this._bodyStream = value;
+      return this;
+    }
+    getBodyStream() : string| undefined {
+      return this._bodyStream;
+
+    }
+streamResponse(value: boolean) : HttpClientOperationDefinition {
+      this._streamResponse = value;
+      return this;
+    }
+    getStreamResponse() : boolean {
+      return this._streamResponse;
 
     }
 
@@ -3240,11 +6969,11 @@ outputs(value: Field[]) : OperationDefinition {
 
     toJSON() : any {
       return {
-name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,      }
+name: this._name,with: this._with,inputs: this._inputs,method: this._method,codec: this._codec,headers: this._headers,body: this._body,path: this._path,timeout: this._timeout,redirects: this._redirects,proxy: this._proxy,body_stream: this._bodyStream,stream_response: this._streamResponse,      }
 
     }
 }
-
+    
     
     
     
@@ -3252,55 +6981,70 @@ name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,
 
 
 
-export class Field implements HasKind {
- // The name of the field. 
-      _name : string ;
- // The type signature of the field. 
-      _type : TypeSignature ;
- // The description of the field. 
-      _description : string| undefined =  undefined;
+export class ArchiveComponent implements HasKind {
+ // The format `pack` writes archives in. `unpack` detects the format of its input. 
+      _format : ArchiveFormat = ArchiveFormat.Tar;
+ // The largest uncompressed size in bytes of a single entry. Defaults to 64 MiB. 
+      _maxEntrySize : number| undefined =  undefined;
+ // The largest uncompressed size in bytes of all entries of an archive together. Defaults to 256 MiB. 
+      _maxTotalSize : number| undefined =  undefined;
+ // The largest number of entries an archive may contain. Defaults to 10000. 
+      _maxEntries : number| undefined =  undefined;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
     constructor (
-name:
- string,
-type_:
- TypeSignature,
       ) {
-          this._name = name;
-          this._type = type_;
     }
 
-name(value: string) : Field {
-      this._name = value;
+format(value: ArchiveFormat) : ArchiveComponent {
+      this._format = value;
       return this;
     }
-    getName() : string {
-      return this._name;
+    getFormat() : ArchiveFormat {
+      return this._format;
 
     }
-type(value: TypeSignature) : Field {
-      this._type = value;
+maxEntrySize(value: number| undefined) : ArchiveComponent {
+      this._maxEntrySize = value;
       return this;
     }
-    getType() : TypeSignature {
-      return this._type;
+    getMaxEntrySize() : number| undefined {
+      return this._maxEntrySize;
 
     }
-description(value: string| undefined) : Field {
-      this._description = value;
+maxTotalSize(value: number| undefined) : ArchiveComponent {
+      this._maxTotalSize = value;
       return this;
     }
-    getDescription() : string| undefined {
-      return this._description;
+    getMaxTotalSize() : number| undefined {
+      return this._maxTotalSize;
+
+    }
+maxEntries(value: number| undefined) : ArchiveComponent {
+      this._maxEntries = value;
+      return this;
+    }
+    getMaxEntries() : number| undefined {
+      return this._maxEntries;
+
+    }
+with(value: Field[]) : ArchiveComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/archive@v1";
     }
 
     toJSON() : any {
       return {
-name: this._name,type: this._type,description: this._description,      }
+        kind : "wick/component/archive@v1",
+format: this._format,max_entry_size: this._maxEntrySize,max_total_size: this._maxTotalSize,max_entries: this._maxEntries,with: this._with,      }
 
     }
 }
@@ -3312,16 +7056,9 @@ name: this._name,type: this._type,description: this._description,      }
 
     
     
+export enum ArchiveFormat {
+Tar = "Tar",Zip = "Zip",}
     
-export type TypeSignature =
-      string
-;
-    
-
-
-
-export const I8 = "i8";
-
     
     
     
@@ -3329,98 +7066,194 @@ export const I8 = "i8";
 
 
 
-export const I16 = "i16";
-
-    
-    
-    
-    
-
+export class TextComponent implements HasKind {
+ // The Volume resource `template` operations read template files from. Every file in it can be included in a template as a partial by its path, e.g. `{% include &#x27;partials/header.liquid&#x27; %}`. 
+      _templates : BoundIdentifier| undefined =  undefined;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : TextOperationDefinition[] =  [];
+    constructor (
+      ) {
+    }
 
+templates(value: BoundIdentifier| undefined) : TextComponent {
+      this._templates = value;
+      return this;
+    }
+    getTemplates() : BoundIdentifier| undefined {
+      return this._templates;
 
-export const I32 = "i32";
+    }
+with(value: Field[]) : TextComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
 
-    
-    
-    
-    
+    }
+operations(value: TextOperationDefinition[]) : TextComponent {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : TextOperationDefinition[] {
+      return this._operations;
 
+    }
 
+    getKind() : string {
+      return "wick/component/text@v1";
+    }
 
-export const I64 = "i64";
+    toJSON() : any {
+      return {
+        kind : "wick/component/text@v1",
+templates: this._templates,with: this._with,operations: this._operations,      }
 
+    }
+}
     
     
     
     
-
-
-
-export const U8 = "u8";
-
-    
-    
-    
     
 
 
 
-export const U16 = "u16";
-
-    
-    
-    
-    
-
+export class TextOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ;
+ // What the operation does with its input. 
+      _kind : TextOperationKind ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // Types of the inputs to a `template` operation. Each input is available to the template by name. 
+      _inputs : Field[] =  [];
+ // The regular expression `match`, `replace`, and `captures` operations use. `split` splits on it when set. 
+      _pattern : string| undefined =  undefined;
+ // What `replace` substitutes for each match. It may refer to capture groups as `$1` or `$name`. 
+      _replacement : string| undefined =  undefined;
+ // The string `split` splits on and `join` joins with when no pattern is set. Defaults to a newline. 
+      _separator : string| undefined =  undefined;
+ // The liquid template a `template` operation renders. 
+      _template : string| undefined =  undefined;
+ // The path of a liquid template in the component&#x27;s `templates` volume for a `template` operation to render instead of `template`. 
+      _file : string| undefined =  undefined;
+ // The number of unchanged lines `diff` shows around each change. Defaults to 3. 
+      _context : number| undefined =  undefined;
+    constructor (
+name:
+ string,
+kind:
+ TextOperationKind,
+      ) {
+          this._name = name;
+          this._kind = kind;
+    }
 
+name(value: string) : TextOperationDefinition {
+      this._name = value;
+      return this;
+    }
+    getName() : string {
+      return this._name;
 
-export const U32 = "u32";
+    }
+kind(value: TextOperationKind) : TextOperationDefinition {
+      this._kind = value;
+      return this;
+    }
+    getKind() : TextOperationKind {
+      return this._kind;
 
-    
-    
-    
-    
+    }
+with(value: Field[]) : TextOperationDefinition {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
 
+    }
+inputs(value: Field[]) : TextOperationDefinition {
+      this._inputs = value;
+      return this;
+    }
+    getInputs() : Field[] {
+      return this._inputs;
 
+    }
+pattern(value: string| undefined) : TextOperationDefinition {
+      this._pattern = value;
+      return this;
+    }
+    getPattern() : string| undefined {
+      return this._pattern;
 
-export const U64 = "u64";
+    }
+replacement(value: string| undefined) : TextOperationDefinition {
+      this._replacement = value;
+      return this;
+    }
+    getReplacement() : string| undefined {
+      return this._replacement;
 
-    
-    
-    
-    
+    }
+separator(value: string| undefined) : TextOperationDefinition {
+      this._separator = value;
+      return this;
+    }
+    getSeparator() : string| undefined {
+      return this._separator;
 
+    }
+template(value: string| undefined) : TextOperationDefinition {
+      this._template = value;
+      return this;
+    }
+    getTemplate() : string| undefined {
+      return this._template;
 
+    }
+file(value: string| undefined) : TextOperationDefinition {
+      this._file = value;
+      return this;
+    }
+    getFile() : string| undefined {
+      return this._file;
 
-export const F32 = "f32";
+    }
+context(value: number| undefined) : TextOperationDefinition {
+      this._context = value;
+      return this;
+    }
+    getContext() : number| undefined {
+      return this._context;
 
-    
-    
-    
-    
+    }
 
+    getKind() : string {
+      return "";
+    }
 
+    toJSON() : any {
+      return {
+name: this._name,kind: this._kind,with: this._with,inputs: this._inputs,pattern: this._pattern,replacement: this._replacement,separator: this._separator,template: this._template,file: this._file,context: this._context,      }
 
-export const F64 = "f64";
+    }
+}
 
     
     
     
     
 
-
-
-export const Bool = "i8";
-
-    
     
     
+export enum TextOperationKind {
+Match = "Match",Replace = "Replace",Captures = "Captures",Split = "Split",Join = "Join",Template = "Template",Diff = "Diff",}
     
-
-
-
-export const StringType = "string";
-
     
     
     
@@ -3428,17 +7261,44 @@ export const StringType = "string";
 
 
 
-export const Datetime = "datetime";
+export class UnitsComponent implements HasKind {
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : UnitsOperationDefinition[] =  [];
+    constructor (
+      ) {
+    }
 
-    
-    
-    
-    
+with(value: Field[]) : UnitsComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+operations(value: UnitsOperationDefinition[]) : UnitsComponent {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : UnitsOperationDefinition[] {
+      return this._operations;
 
+    }
 
+    getKind() : string {
+      return "wick/component/units@v1";
+    }
 
-export const Bytes = "bytes";
+    toJSON() : any {
+      return {
+        kind : "wick/component/units@v1",
+with: this._with,operations: this._operations,      }
 
+    }
+}
+    
     
     
     
@@ -3446,14 +7306,36 @@ export const Bytes = "bytes";
 
 
 
-export class Custom implements HasKind {
- // The name of the custom type. 
-      _name : string ="";
+export class UnitsOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ;
+ // What the operation does with its input. 
+      _kind : UnitsOperationKind ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // How results that fall between two minor units (or between two values at `precision`) are rounded. Defaults to `HalfEven`. 
+      _rounding : RoundingMode| undefined =  undefined;
+ // The shares an `allocate` operation splits each amount into, e.g. `[1, 1, 1]` for thirds. 
+      _ratios : number[] =  [];
+ // The ISO 4217 code of the currency a `parse` operation produces. 
+      _currency : string| undefined =  undefined;
+ // The unit a `convert` operation converts from, e.g. `mi`. 
+      _from : string| undefined =  undefined;
+ // The unit a `convert` operation converts to, e.g. `km`. 
+      _to : string| undefined =  undefined;
+ // The number of decimal places `convert` rounds its results to. Defaults to 10. 
+      _precision : number| undefined =  undefined;
     constructor (
+name:
+ string,
+kind:
+ UnitsOperationKind,
       ) {
+          this._name = name;
+          this._kind = kind;
     }
 
-name(value: string) : Custom {
+name(value: string) : UnitsOperationDefinition {
       this._name = value;
       return this;
     }
@@ -3461,6 +7343,70 @@ name(value: string) : Custom {
       return this._name;
 
     }
+kind(value: UnitsOperationKind) : UnitsOperationDefinition {
+      this._kind = value;
+      return this;
+    }
+    getKind() : UnitsOperationKind {
+      return this._kind;
+
+    }
+with(value: Field[]) : UnitsOperationDefinition {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+rounding(value: RoundingMode| undefined) : UnitsOperationDefinition {
+      this._rounding = value;
+      return this;
+    }
+    getRounding() : RoundingMode| undefined {
+      return this._rounding;
+
+    }
+ratios(value: number[]) : UnitsOperationDefinition {
+      this._ratios = value;
+      return this;
+    }
+    getRatios() : number[] {
+      return this._ratios;
+
+    }
+currency(value: string| undefined) : UnitsOperationDefinition {
+      this._currency = value;
+      return this;
+    }
+    getCurrency() : string| undefined {
+      return this._currency;
+
+    }
+from(value: string| undefined) : UnitsOperationDefinition {
+      this._from = value;
+      return this;
+    }
+    getFrom() : string| undefined {
+      return this._from;
+
+    }
+to(value: string| undefined) : UnitsOperationDefinition {
+      this._to = value;
+      return this;
+    }
+    getTo() : string| undefined {
+      return this._to;
+
+    }
+precision(value: number| undefined) : UnitsOperationDefinition {
+      this._precision = value;
+      return this;
+    }
+    getPrecision() : number| undefined {
+      return this._precision;
+
+    }
 
     getKind() : string {
       return "";
@@ -3468,7 +7414,7 @@ name(value: string) : Custom {
 
     toJSON() : any {
       return {
-name: this._name,      }
+name: this._name,kind: this._kind,with: this._with,rounding: this._rounding,ratios: this._ratios,currency: this._currency,from: this._from,to: this._to,precision: this._precision,      }
 
     }
 }
@@ -3478,38 +7424,63 @@ name: this._name,      }
     
     
 
+    
+    
+export enum UnitsOperationKind {
+Add = "Add",Subtract = "Subtract",Multiply = "Multiply",Allocate = "Allocate",Parse = "Parse",Format = "Format",Convert = "Convert",}
+    
+    
+
+    
+    
+export enum RoundingMode {
+HalfEven = "HalfEven",HalfUp = "HalfUp",HalfDown = "HalfDown",Up = "Up",Down = "Down",Ceiling = "Ceiling",Floor = "Floor",}
+    
+    
+    
+    
+    
 
 
-export class Optional implements HasKind {
 
-      _type : TypeSignature ;
+export class ValidateComponent implements HasKind {
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : ValidateOperationDefinition[] =  [];
     constructor (
-type_:
- TypeSignature,
       ) {
-          this._type = type_;
     }
 
-type(value: TypeSignature) : Optional {
-      this._type = value;
+with(value: Field[]) : ValidateComponent {
+      this._with = value;
       return this;
     }
-    getType() : TypeSignature {
-      return this._type;
+    getWith() : Field[] {
+      return this._with;
+
+    }
+operations(value: ValidateOperationDefinition[]) : ValidateComponent {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : ValidateOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/validate@v1";
     }
 
     toJSON() : any {
       return {
-type: this._type,      }
+        kind : "wick/component/validate@v1",
+with: this._with,operations: this._operations,      }
 
     }
 }
-
+    
     
     
     
@@ -3517,22 +7488,65 @@ type: this._type,      }
 
 
 
-export class List implements HasKind {
-
-      _type : TypeSignature ;
+export class ValidateOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ;
+ // What the operation validates. 
+      _kind : ValidateOperationKind ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // The schemes a `url` operation accepts. Defaults to `http` and `https`. 
+      _schemes : string[] =  [];
+ // The calling code, e.g. `1` or `44`, that a `phone` operation assumes for numbers without a leading `+` or `00`. Without it, such numbers are invalid. 
+      _countryCode : string| undefined =  undefined;
     constructor (
-type_:
- TypeSignature,
+name:
+ string,
+kind:
+ ValidateOperationKind,
       ) {
-          this._type = type_;
+          this._name = name;
+          this._kind = kind;
     }
 
-type(value: TypeSignature) : List {
-      this._type = value;
+name(value: string) : ValidateOperationDefinition {
+      this._name = value;
       return this;
     }
-    getType() : TypeSignature {
-      return this._type;
+    getName() : string {
+      return this._name;
+
+    }
+kind(value: ValidateOperationKind) : ValidateOperationDefinition {
+      this._kind = value;
+      return this;
+    }
+    getKind() : ValidateOperationKind {
+      return this._kind;
+
+    }
+with(value: Field[]) : ValidateOperationDefinition {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+schemes(value: string[]) : ValidateOperationDefinition {
+      this._schemes = value;
+      return this;
+    }
+    getSchemes() : string[] {
+      return this._schemes;
+
+    }
+countryCode(value: string| undefined) : ValidateOperationDefinition {
+      this._countryCode = value;
+      return this;
+    }
+    getCountryCode() : string| undefined {
+      return this._countryCode;
 
     }
 
@@ -3542,7 +7556,7 @@ type(value: TypeSignature) : List {
 
     toJSON() : any {
       return {
-type: this._type,      }
+name: this._name,kind: this._kind,with: this._with,schemes: this._schemes,country_code: this._countryCode,      }
 
     }
 }
@@ -3552,87 +7566,87 @@ type: this._type,      }
     
     
 
+    
+    
+export enum ValidateOperationKind {
+Email = "Email",Url = "Url",Phone = "Phone",}
+    
+    
+    
+    
+    
 
 
-export class Map implements HasKind {
-
-      _key : TypeSignature ;
 
-      _value : TypeSignature ;
+export class GenerateComponent implements HasKind {
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : GenerateOperationDefinition[] =  [];
     constructor (
-key:
- TypeSignature,
-value:
- TypeSignature,
       ) {
-          this._key = key;
-          this._value = value;
     }
 
-key(value: TypeSignature) : Map {
-      this._key = value;
+with(value: Field[]) : GenerateComponent {
+      this._with = value;
       return this;
     }
-    getKey() : TypeSignature {
-      return this._key;
+    getWith() : Field[] {
+      return this._with;
 
     }
-value(value: TypeSignature) : Map {
-      this._value = value;
+operations(value: GenerateOperationDefinition[]) : GenerateComponent {
+      this._operations = value;
       return this;
     }
-    getValue() : TypeSignature {
-      return this._value;
+    getOperations() : GenerateOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/generate@v1";
     }
 
     toJSON() : any {
       return {
-key: this._key,value: this._value,      }
+        kind : "wick/component/generate@v1",
+with: this._with,operations: this._operations,      }
 
     }
 }
-
-    
-    
-    
-    
-
-
-
-export const Object = "object";
-
-    
     
     
     
-
-    
     
     
-export type TypeDefinition =
-      StructSignature|EnumSignature|UnionSignature
-    ;
-    
-
-
-
-export class StructSignature implements HasKind {
- // The name of the struct. 
-      _name : string ="";
- // The fields in this struct. 
-      _fields : Field[] =  [];
- // The description of the struct. 
-      _description : string| undefined =  undefined;
+
+
+
+export class GenerateOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // The name of the type, from the manifest&#x27;s `types`, that each record conforms to. 
+      _type : string ;
+ // How many records to generate. Defaults to 10. 
+      _count : number| undefined =  undefined;
+ // The seed for generating records. The same seed always generates the same records. Without it, records differ between invocations. 
+      _seed : number| undefined =  undefined;
+ // How to generate specific fields. Fields not listed here are generated from their type and name, e.g. a string field named `email` gets email addresses. 
+      _fields : GenerateField[] =  [];
     constructor (
+name:
+ string,
+type_:
+ string,
       ) {
+          this._name = name;
+          this._type = type_;
     }
 
-name(value: string) : StructSignature {
+name(value: string) : GenerateOperationDefinition {
       this._name = value;
       return this;
     }
@@ -3640,35 +7654,58 @@ name(value: string) : StructSignature {
       return this._name;
 
     }
-fields(value: Field[]) : StructSignature {
-      this._fields = value;
+with(value: Field[]) : GenerateOperationDefinition {
+      this._with = value;
       return this;
     }
-    getFields() : Field[] {
-      return this._fields;
+    getWith() : Field[] {
+      return this._with;
 
     }
-description(value: string| undefined) : StructSignature {
-      this._description = value;
+type(value: string) : GenerateOperationDefinition {
+      this._type = value;
       return this;
     }
-    getDescription() : string| undefined {
-      return this._description;
+    getType() : string {
+      return this._type;
+
+    }
+count(value: number| undefined) : GenerateOperationDefinition {
+      this._count = value;
+      return this;
+    }
+    getCount() : number| undefined {
+      return this._count;
+
+    }
+seed(value: number| undefined) : GenerateOperationDefinition {
+      this._seed = value;
+      return this;
+    }
+    getSeed() : number| undefined {
+      return this._seed;
+
+    }
+fields(value: GenerateField[]) : GenerateOperationDefinition {
+      this._fields = value;
+      return this;
+    }
+    getFields() : GenerateField[] {
+      return this._fields;
 
     }
 
     getKind() : string {
-      return "wick/type/struct@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/type/struct@v1",
-name: this._name,fields: this._fields,description: this._description,      }
+name: this._name,with: this._with,type: this._type,count: this._count,seed: this._seed,fields: this._fields,      }
 
     }
 }
-
+    
     
     
     
@@ -3676,18 +7713,28 @@ name: this._name,fields: this._fields,description: this._description,      }
 
 
 
-export class UnionSignature implements HasKind {
- // The name of the union. 
-      _name : string ="";
- // The types in the union. 
-      _types : TypeSignature[] =  [];
- // The description of the union. 
-      _description : string| undefined =  undefined;
+export class GenerateField implements HasKind {
+ // The name of the field. 
+      _name : string ;
+ // How the field&#x27;s values are generated. 
+      _strategy : GenerateStrategy ;
+ // The smallest value an `Integer`, `Float`, or `Sequence` field generates. Defaults to 0. 
+      _min : number| undefined =  undefined;
+ // The largest value an `Integer` or `Float` field generates. Defaults to 1000. 
+      _max : number| undefined =  undefined;
+ // The values a `OneOf` field picks from. 
+      _values : any[] =  [];
     constructor (
+name:
+ string,
+strategy:
+ GenerateStrategy,
       ) {
+          this._name = name;
+          this._strategy = strategy;
     }
 
-name(value: string) : UnionSignature {
+name(value: string) : GenerateField {
       this._name = value;
       return this;
     }
@@ -3695,31 +7742,46 @@ name(value: string) : UnionSignature {
       return this._name;
 
     }
-types(value: TypeSignature[]) : UnionSignature {
-      this._types = value;
+strategy(value: GenerateStrategy) : GenerateField {
+      this._strategy = value;
       return this;
     }
-    getTypes() : TypeSignature[] {
-      return this._types;
+    getStrategy() : GenerateStrategy {
+      return this._strategy;
 
     }
-description(value: string| undefined) : UnionSignature {
-      this._description = value;
+min(value: number| undefined) : GenerateField {
+      this._min = value;
       return this;
     }
-    getDescription() : string| undefined {
-      return this._description;
+    getMin() : number| undefined {
+      return this._min;
+
+    }
+max(value: number| undefined) : GenerateField {
+      this._max = value;
+      return this;
+    }
+    getMax() : number| undefined {
+      return this._max;
+
+    }
+values(value: any[]) : GenerateField {
+      this._values = value;
+      return this;
+    }
+    getValues() : any[] {
+      return this._values;
 
     }
 
     getKind() : string {
-      return "wick/type/union@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/type/union@v1",
-name: this._name,types: this._types,description: this._description,      }
+name: this._name,strategy: this._strategy,min: this._min,max: this._max,values: this._values,      }
 
     }
 }
@@ -3729,56 +7791,69 @@ name: this._name,types: this._types,description: this._description,      }
     
     
 
+    
+    
+export enum GenerateStrategy {
+FirstName = "FirstName",LastName = "LastName",FullName = "FullName",Username = "Username",Email = "Email",Phone = "Phone",Company = "Company",City = "City",Country = "Country",Word = "Word",Sentence = "Sentence",Uuid = "Uuid",Integer = "Integer",Float = "Float",Bool = "Bool",Datetime = "Datetime",Sequence = "Sequence",OneOf = "OneOf",}
+    
+    
+    
+    
+    
 
 
-export class EnumSignature implements HasKind {
- // The name of the enum. 
-      _name : string ="";
- // The variants in the enum. 
-      _variants : EnumVariant[] =  [];
- // The description of the enum. 
-      _description : string| undefined =  undefined;
+
+export class FlagsComponent implements HasKind {
+ // The URL resource of the rules file. Only `file://` URLs are supported. 
+      _resource : BoundIdentifier ;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : FlagsOperationDefinition[] =  [];
     constructor (
+resource:
+ BoundIdentifier,
       ) {
+          this._resource = resource;
     }
 
-name(value: string) : EnumSignature {
-      this._name = value;
+resource(value: BoundIdentifier) : FlagsComponent {
+      this._resource = value;
       return this;
     }
-    getName() : string {
-      return this._name;
+    getResource() : BoundIdentifier {
+      return this._resource;
 
     }
-variants(value: EnumVariant[]) : EnumSignature {
-      this._variants = value;
+with(value: Field[]) : FlagsComponent {
+      this._with = value;
       return this;
     }
-    getVariants() : EnumVariant[] {
-      return this._variants;
+    getWith() : Field[] {
+      return this._with;
 
     }
-description(value: string| undefined) : EnumSignature {
-      this._description = value;
+operations(value: FlagsOperationDefinition[]) : FlagsComponent {
+      this._operations = value;
       return this;
     }
-    getDescription() : string| undefined {
-      return this._description;
+    getOperations() : FlagsOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "wick/type/enum@v1";
+      return "wick/component/flags@v1";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/type/enum@v1",
-name: this._name,variants: this._variants,description: this._description,      }
+        kind : "wick/component/flags@v1",
+resource: this._resource,with: this._with,operations: this._operations,      }
 
     }
 }
-
+    
     
     
     
@@ -3786,20 +7861,24 @@ name: this._name,variants: this._variants,description: this._description,      }
 
 
 
-export class EnumVariant implements HasKind {
- // The name of the variant. 
-      _name : string ="";
- // The index of the variant. 
-      _index : number| undefined =  undefined;
- // The optional value of the variant. 
-      _value : string| undefined =  undefined;
- // A description of the variant. 
-      _description : string| undefined =  undefined;
+export class FlagsOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // The name of the flag, from the rules file, that the operation evaluates. 
+      _flag : string ;
     constructor (
+name:
+ string,
+flag:
+ string,
       ) {
+          this._name = name;
+          this._flag = flag;
     }
 
-name(value: string) : EnumVariant {
+name(value: string) : FlagsOperationDefinition {
       this._name = value;
       return this;
     }
@@ -3807,28 +7886,20 @@ name(value: string) : EnumVariant {
       return this._name;
 
     }
-index(value: number| undefined) : EnumVariant {
-      this._index = value;
-      return this;
-    }
-    getIndex() : number| undefined {
-      return this._index;
-
-    }
-value(value: string| undefined) : EnumVariant {
-      this._value = value;
+with(value: Field[]) : FlagsOperationDefinition {
+      this._with = value;
       return this;
     }
-    getValue() : string| undefined {
-      return this._value;
+    getWith() : Field[] {
+      return this._with;
 
     }
-description(value: string| undefined) : EnumVariant {
-      this._description = value;
+flag(value: string) : FlagsOperationDefinition {
+      this._flag = value;
       return this;
     }
-    getDescription() : string| undefined {
-      return this._description;
+    getFlag() : string {
+      return this._flag;
 
     }
 
@@ -3838,11 +7909,11 @@ description(value: string| undefined) : EnumVariant {
 
     toJSON() : any {
       return {
-name: this._name,index: this._index,value: this._value,description: this._description,      }
+name: this._name,with: this._with,flag: this._flag,      }
 
     }
 }
-
+    
     
     
     
@@ -3850,69 +7921,67 @@ name: this._name,index: this._index,value: this._value,description: this._descri
 
 
 
-export class OperationInstance implements HasKind {
- // The name of the binding. 
-      _name : string ;
- // The operation to bind to. 
-      _operation :string | ComponentOperationExpression ;
- // Data to associate with the reference, if any. 
-      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
- // Timeout (in milliseconds) to wait for the operation to complete. Use 0 to wait indefinitely. 
-      _timeout : number| undefined =  undefined;
+export class I18nComponent implements HasKind {
+ // The volume resource holding the catalogs. 
+      _resource : BoundIdentifier ;
+ // The locale to translate to when a message is missing from the requested locale and its language. 
+      _fallback : string| undefined =  undefined;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : I18nOperationDefinition[] =  [];
     constructor (
-name:
- string,
-operation:
-string | ComponentOperationExpression,
+resource:
+ BoundIdentifier,
       ) {
-          this._name = name;
-          this._operation = operation;
+          this._resource = resource;
     }
 
-name(value: string) : OperationInstance {
-      this._name = value;
+resource(value: BoundIdentifier) : I18nComponent {
+      this._resource = value;
       return this;
     }
-    getName() : string {
-      return this._name;
+    getResource() : BoundIdentifier {
+      return this._resource;
 
     }
-operation(value: ComponentOperationExpression) : OperationInstance {
-      this._operation = value;
+fallback(value: string| undefined) : I18nComponent {
+      this._fallback = value;
       return this;
     }
-    getOperation() :string | ComponentOperationExpression {
-      return this._operation;
+    getFallback() : string| undefined {
+      return this._fallback;
 
     }
-with(value:   Record<string,LiquidJsonValue>| undefined) : OperationInstance {
+with(value: Field[]) : I18nComponent {
       this._with = value;
       return this;
     }
-    getWith() :   Record<string,LiquidJsonValue>| undefined {
+    getWith() : Field[] {
       return this._with;
 
     }
-timeout(value: number| undefined) : OperationInstance {
-      this._timeout = value;
+operations(value: I18nOperationDefinition[]) : I18nComponent {
+      this._operations = value;
       return this;
     }
-    getTimeout() : number| undefined {
-      return this._timeout;
+    getOperations() : I18nOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/i18n@v1";
     }
 
     toJSON() : any {
       return {
-name: this._name,operation: this._operation,with: this._with,timeout: this._timeout,      }
+        kind : "wick/component/i18n@v1",
+resource: this._resource,fallback: this._fallback,with: this._with,operations: this._operations,      }
 
     }
 }
-
+    
     
     
     
@@ -3920,74 +7989,34 @@ name: this._name,operation: this._operation,with: this._with,timeout: this._time
 
 
 
-export class TestDefinition implements HasKind {
- // The name of the test. 
-      _name : string| undefined =  undefined;
- // The operaton to test. 
-      _operation : string ;
- // Inherent data to use for the test. 
-      _inherent : InherentData| undefined =  undefined;
- // The configuration for the operation, if any. 
-      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
- // The inputs to the test. 
-      _inputs : PacketData[] =  [];
- // The expected outputs of the operation. 
-      _outputs : TestPacketData[] =  [];
+export class I18nOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
     constructor (
-operation:
+name:
  string,
       ) {
-          this._operation = operation;
-    }
-
-name(value: string| undefined) : TestDefinition {
-      this._name = value;
-      return this;
-    }
-    getName() : string| undefined {
-      return this._name;
-
-    }
-operation(value: string) : TestDefinition {
-      this._operation = value;
-      return this;
+          this._name = name;
     }
-    getOperation() : string {
-      return this._operation;
 
-    }
-inherent(value: InherentData| undefined) : TestDefinition {
-      this._inherent = value;
+name(value: string) : I18nOperationDefinition {
+      this._name = value;
       return this;
     }
-    getInherent() : InherentData| undefined {
-      return this._inherent;
+    getName() : string {
+      return this._name;
 
     }
-with(value:   Record<string,LiquidJsonValue>| undefined) : TestDefinition {
+with(value: Field[]) : I18nOperationDefinition {
       this._with = value;
       return this;
     }
-    getWith() :   Record<string,LiquidJsonValue>| undefined {
+    getWith() : Field[] {
       return this._with;
 
     }
-inputs(value: PacketData[]) : TestDefinition {
-      this._inputs = value;
-      return this;
-    }
-    getInputs() : PacketData[] {
-      return this._inputs;
-
-    }
-outputs(value: TestPacketData[]) : TestDefinition {
-      this._outputs = value;
-      return this;
-    }
-    getOutputs() : TestPacketData[] {
-      return this._outputs;
-
-    }
 
     getKind() : string {
       return "";
@@ -3995,11 +8024,11 @@ outputs(value: TestPacketData[]) : TestDefinition {
 
     toJSON() : any {
       return {
-name: this._name,operation: this._operation,inherent: this._inherent,with: this._with,inputs: this._inputs,outputs: this._outputs,      }
+name: this._name,with: this._with,      }
 
     }
 }
-
+    
     
     
     
@@ -4007,73 +8036,111 @@ name: this._name,operation: this._operation,inherent: this._inherent,with: this.
 
 
 
-export class InherentData implements HasKind {
- // A random seed, i.e. to initialize a random number generator. 
-      _seed : number| undefined =  undefined;
- // A timestamp. 
-      _timestamp : number| undefined =  undefined;
+export class MetricsComponent implements HasKind {
+ // The URL resource to send samples to: an &#x60;http(s)&#x60; remote-write endpoint or a &#x60;udp://host:port&#x60; statsd server. 
+      _resource : BoundIdentifier ;
+ // The protocol to send samples with. 
+      _protocol : MetricsProtocol = MetricsProtocol.RemoteWrite;
+ // Labels added to every sample, e.g. &#x60;job: ingest&#x60;. A sample&#x27;s own labels take precedence. 
+      _labels :   Record<string,string> =  {};
+ // The most samples to send at once. Defaults to 500. 
+      _batchSize : number| undefined =  undefined;
+ // How often, in milliseconds, to send the samples collected so far. Defaults to 1000. 
+      _flushInterval : number| undefined =  undefined;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : MetricsOperationDefinition[] =  [];
     constructor (
+resource:
+ BoundIdentifier,
       ) {
+          this._resource = resource;
     }
 
-seed(value: number| undefined) : InherentData {
-      this._seed = value;
+resource(value: BoundIdentifier) : MetricsComponent {
+      this._resource = value;
       return this;
     }
-    getSeed() : number| undefined {
-      return this._seed;
+    getResource() : BoundIdentifier {
+      return this._resource;
 
     }
-timestamp(value: number| undefined) : InherentData {
-      this._timestamp = value;
+protocol(value: MetricsProtocol) : MetricsComponent {
+      this._protocol = value;
       return this;
     }
-    getTimestamp() : number| undefined {
-      return this._timestamp;
+    getProtocol() : MetricsProtocol {
+      return this._protocol;
+
+    }
+labels(value:   Record<string,string>) : MetricsComponent {
+      this._labels = value;
+      return this;
+    }
+    getLabels() :   Record<string,string> {
+      return this._labels;
+
+    }
+batchSize(value: number| undefined) : MetricsComponent {
+      this._batchSize = value;
+      return this;
+    }
+    getBatchSize() : number| undefined {
+      return this._batchSize;
+
+    }
+flushInterval(value: number| undefined) : MetricsComponent {
+      this._flushInterval = value;
+      return this;
+    }
+    getFlushInterval() : number| undefined {
+      return this._flushInterval;
+
+    }
+with(value: Field[]) : MetricsComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+operations(value: MetricsOperationDefinition[]) : MetricsComponent {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : MetricsOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/metrics@v1";
     }
 
     toJSON() : any {
       return {
-seed: this._seed,timestamp: this._timestamp,      }
+        kind : "wick/component/metrics@v1",
+resource: this._resource,protocol: this._protocol,labels: this._labels,batch_size: this._batchSize,flush_interval: this._flushInterval,with: this._with,operations: this._operations,      }
 
     }
 }
-
-    
-    
-    
-    
-
     
     
     
-export type PacketData =
-      SuccessPacket|SignalPacket|ErrorPacket
-    ;
-    
-
-    
     
     
-export type TestPacketData =
-      SuccessPacket|SignalPacket|PacketAssertionDef|ErrorPacket
-    ;
-    
 
 
 
-export class SignalPacket implements HasKind {
- // The name of the input or output this packet is going to or coming from. 
+export class MetricsOperationDefinition implements HasKind {
+ // The name of the operation. 
       _name : string ;
- // Any flags set on the packet. Deprecated, use &#x27;flag:&#x27; instead 
-      _flags : PacketFlags| undefined =  undefined;
- // The flag set on the packet. 
-      _flag : PacketFlag| undefined =  undefined;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // The kind of metric the operation&#x27;s samples are. 
+      _metric : MetricKind = MetricKind.Gauge;
     constructor (
 name:
  string,
@@ -4081,7 +8148,7 @@ name:
           this._name = name;
     }
 
-name(value: string) : SignalPacket {
+name(value: string) : MetricsOperationDefinition {
       this._name = value;
       return this;
     }
@@ -4089,20 +8156,20 @@ name(value: string) : SignalPacket {
       return this._name;
 
     }
-flags(value: PacketFlags| undefined) : SignalPacket {
-      this._flags = value;
+with(value: Field[]) : MetricsOperationDefinition {
+      this._with = value;
       return this;
     }
-    getFlags() : PacketFlags| undefined {
-      return this._flags;
+    getWith() : Field[] {
+      return this._with;
 
     }
-flag(value: PacketFlag| undefined) : SignalPacket {
-      this._flag = value;
+metric(value: MetricKind) : MetricsOperationDefinition {
+      this._metric = value;
       return this;
     }
-    getFlag() : PacketFlag| undefined {
-      return this._flag;
+    getMetric() : MetricKind {
+      return this._metric;
 
     }
 
@@ -4112,7 +8179,7 @@ flag(value: PacketFlag| undefined) : SignalPacket {
 
     toJSON() : any {
       return {
-name: this._name,flags: this._flags,flag: this._flag,      }
+name: this._name,with: this._with,metric: this._metric,      }
 
     }
 }
@@ -4124,45 +8191,53 @@ name: this._name,flags: this._flags,flag: this._flag,      }
 
 
 
-export class SuccessPacket implements HasKind {
- // The name of the input or output this packet is going to or coming from. 
-      _name : string ;
- // The packet payload. 
-      _value : LiquidJsonValue ;
+export class BrokerComponent implements HasKind {
+ // The Broker resource to publish to. 
+      _resource : BoundIdentifier ;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : BrokerOperationDefinition[] =  [];
     constructor (
-name:
- string,
-value:
- LiquidJsonValue,
+resource:
+ BoundIdentifier,
       ) {
-          this._name = name;
-          this._value = value;
+          this._resource = resource;
     }
 
-name(value: string) : SuccessPacket {
-      this._name = value;
+resource(value: BoundIdentifier) : BrokerComponent {
+      this._resource = value;
       return this;
     }
-    getName() : string {
-      return this._name;
+    getResource() : BoundIdentifier {
+      return this._resource;
 
     }
-value(value: LiquidJsonValue) : SuccessPacket {
-      this._value = value;
+with(value: Field[]) : BrokerComponent {
+      this._with = value;
       return this;
     }
-    getValue() : LiquidJsonValue {
-      return this._value;
+    getWith() : Field[] {
+      return this._with;
+
+    }
+operations(value: BrokerOperationDefinition[]) : BrokerComponent {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : BrokerOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/broker@v1";
     }
 
     toJSON() : any {
       return {
-name: this._name,value: this._value,      }
+        kind : "wick/component/broker@v1",
+resource: this._resource,with: this._with,operations: this._operations,      }
 
     }
 }
@@ -4174,19 +8249,26 @@ name: this._name,value: this._value,      }
 
 
 
-export class PacketAssertionDef implements HasKind {
- // The name of the input or output this packet is going to or coming from. 
+export class BrokerOperationDefinition implements HasKind {
+ // The name of the operation. 
       _name : string ;
- // An assertion to test against the packet. 
-      _assertions : PacketAssertion[] =  [];
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // The topic to publish events to. 
+      _topic : string ;
+ // The fields of the events the operation publishes. Each value is checked against its field&#x27;s type before it&#x27;s published. 
+      _inputs : Field[] =  [];
     constructor (
 name:
+ string,
+topic:
  string,
       ) {
           this._name = name;
+          this._topic = topic;
     }
 
-name(value: string) : PacketAssertionDef {
+name(value: string) : BrokerOperationDefinition {
       this._name = value;
       return this;
     }
@@ -4194,12 +8276,28 @@ name(value: string) : PacketAssertionDef {
       return this._name;
 
     }
-assertions(value: PacketAssertion[]) : PacketAssertionDef {
-      this._assertions = value;
+with(value: Field[]) : BrokerOperationDefinition {
+      this._with = value;
       return this;
     }
-    getAssertions() : PacketAssertion[] {
-      return this._assertions;
+    getWith() : Field[] {
+      return this._with;
+
+    }
+topic(value: string) : BrokerOperationDefinition {
+      this._topic = value;
+      return this;
+    }
+    getTopic() : string {
+      return this._topic;
+
+    }
+inputs(value: Field[]) : BrokerOperationDefinition {
+      this._inputs = value;
+      return this;
+    }
+    getInputs() : Field[] {
+      return this._inputs;
 
     }
 
@@ -4209,7 +8307,7 @@ assertions(value: PacketAssertion[]) : PacketAssertionDef {
 
     toJSON() : any {
       return {
-name: this._name,assertions: this._assertions,      }
+name: this._name,with: this._with,topic: this._topic,inputs: this._inputs,      }
 
     }
 }
@@ -4221,55 +8319,40 @@ name: this._name,assertions: this._assertions,      }
 
 
 
-export class PacketAssertion implements HasKind {
- // The optional path to a value in the packet to assert against. 
-      _path : string| undefined =  undefined;
- // The operation to use when asserting against a packet. 
-      _operator : AssertionOperator ;
- // A value or object combine with the operator to assert against a packet value. 
-      _value : LiquidJsonValue ;
+export class CacheComponent implements HasKind {
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : CacheOperationDefinition[] =  [];
     constructor (
-operator:
- AssertionOperator,
-value:
- LiquidJsonValue,
       ) {
-          this._operator = operator;
-          this._value = value;
-    }
-
-path(value: string| undefined) : PacketAssertion {
-      this._path = value;
-      return this;
     }
-    getPath() : string| undefined {
-      return this._path;
 
-    }
-operator(value: AssertionOperator) : PacketAssertion {
-      this._operator = value;
+with(value: Field[]) : CacheComponent {
+      this._with = value;
       return this;
     }
-    getOperator() : AssertionOperator {
-      return this._operator;
+    getWith() : Field[] {
+      return this._with;
 
     }
-value(value: LiquidJsonValue) : PacketAssertion {
-      this._value = value;
+operations(value: CacheOperationDefinition[]) : CacheComponent {
+      this._operations = value;
       return this;
     }
-    getValue() : LiquidJsonValue {
-      return this._value;
+    getOperations() : CacheOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/cache@v1";
     }
 
     toJSON() : any {
       return {
-path: this._path,operator: this._operator,value: this._value,      }
+        kind : "wick/component/cache@v1",
+with: this._with,operations: this._operations,      }
 
     }
 }
@@ -4279,35 +8362,39 @@ path: this._path,operator: this._operator,value: this._value,      }
     
     
 
-    
-    
-export enum AssertionOperator {
-Equals = "Equals",LessThan = "LessThan",GreaterThan = "GreaterThan",Regex = "Regex",Contains = "Contains",}
-    
-    
-
 
 
-export class ErrorPacket implements HasKind {
- // The name of the input or output this packet is going to or coming from. 
+export class CacheOperationDefinition implements HasKind {
+ // The name of the operation. 
       _name : string ;
- // Any flags set on the packet. Deprecated, use &#x27;flag:&#x27; instead 
-      _flags : PacketFlags| undefined =  undefined;
- // The flag set on the packet. 
-      _flag : PacketFlag| undefined =  undefined;
- // The error message. 
-      _error : LiquidTemplate ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // The inputs of the operation, passed on to the linked operation on a miss. 
+      _inputs : Field[] =  [];
+ // The outputs of the operation, the same as the linked operation&#x27;s. 
+      _outputs : Field[] =  [];
+ // A liquid template rendered with the inputs to make the key outputs are stored under, e.g. `user:{{ id }}`. 
+      _key : string ;
+ // The operation to compute missing outputs with, e.g. `users::lookup`. The component must be in the cache&#x27;s scope: one its manifest requires or, when the cache is imported inline, one imported alongside it. Errors are passed on and never stored. 
+      _compute : string ;
+ // How long (in milliseconds) stored outputs are fresh. 
+      _ttl : number =60000;
+ // How long (in milliseconds) after they expire stored outputs are still sent while they&#x27;re recomputed in the background. 
+      _stale : number =0;
     constructor (
 name:
  string,
-error:
- LiquidTemplate,
+key:
+ string,
+compute:
+ string,
       ) {
           this._name = name;
-          this._error = error;
+          this._key = key;
+          this._compute = compute;
     }
 
-name(value: string) : ErrorPacket {
+name(value: string) : CacheOperationDefinition {
       this._name = value;
       return this;
     }
@@ -4315,82 +8402,60 @@ name(value: string) : ErrorPacket {
       return this._name;
 
     }
-flags(value: PacketFlags| undefined) : ErrorPacket {
-      this._flags = value;
+with(value: Field[]) : CacheOperationDefinition {
+      this._with = value;
       return this;
     }
-    getFlags() : PacketFlags| undefined {
-      return this._flags;
+    getWith() : Field[] {
+      return this._with;
 
     }
-flag(value: PacketFlag| undefined) : ErrorPacket {
-      this._flag = value;
+inputs(value: Field[]) : CacheOperationDefinition {
+      this._inputs = value;
       return this;
     }
-    getFlag() : PacketFlag| undefined {
-      return this._flag;
+    getInputs() : Field[] {
+      return this._inputs;
 
     }
-error(value: LiquidTemplate) : ErrorPacket {
-      this._error = value;
+outputs(value: Field[]) : CacheOperationDefinition {
+      this._outputs = value;
       return this;
     }
-    getError() : LiquidTemplate {
-      return this._error;
-
-    }
+    getOutputs() : Field[] {
+      return this._outputs;
 
-    getKind() : string {
-      return "";
     }
-
-    toJSON() : any {
-      return {
-name: this._name,flags: this._flags,flag: this._flag,error: this._error,      }
-
+key(value: string) : CacheOperationDefinition {
+      this._key = value;
+      return this;
     }
-}
-
-    
-    
-    
-    
-
-
+    getKey() : string {
+      return this._key;
 
-export class PacketFlags implements HasKind {
- // Indicates the port should be considered closed. 
-      _done : boolean =false;
- // Indicates the opening of a new substream context within the parent stream. 
-      _open : boolean =false;
- // Indicates the closing of a substream context within the parent stream. 
-      _close : boolean =false;
-    constructor (
-      ) {
     }
-
-done(value: boolean) : PacketFlags {
-      this._done = value;
+compute(value: string) : CacheOperationDefinition {
+      this._compute = value;
       return this;
     }
-    getDone() : boolean {
-      return this._done;
+    getCompute() : string {
+      return this._compute;
 
     }
-open(value: boolean) : PacketFlags {
-      this._open = value;
+ttl(value: number) : CacheOperationDefinition {
+      this._ttl = value;
       return this;
     }
-    getOpen() : boolean {
-      return this._open;
+    getTtl() : number {
+      return this._ttl;
 
     }
-close(value: boolean) : PacketFlags {
-      this._close = value;
+stale(value: number) : CacheOperationDefinition {
+      this._stale = value;
       return this;
     }
-    getClose() : boolean {
-      return this._close;
+    getStale() : number {
+      return this._stale;
 
     }
 
@@ -4400,11 +8465,12 @@ close(value: boolean) : PacketFlags {
 
     toJSON() : any {
       return {
-done: this._done,open: this._open,close: this._close,      }
+name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,key: this._key,compute: this._compute,ttl: this._ttl,stale: this._stale,      }
 
     }
 }
 
+
     
     
     
@@ -4412,30 +8478,42 @@ done: this._done,open: this._open,close: this._close,      }
 
     
     
-export enum PacketFlag {
-Done = "Done",Open = "Open",Close = "Close",}
-    
-    
-
-
-
-export class SqlComponent implements HasKind {
- // The connect string URL resource for the database. 
+export class ObjectStoreComponent implements HasKind {
+ // The URL resource of the store&#x27;s endpoint, e.g. &#x60;https://s3.us-east-1.amazonaws.com&#x60; or &#x60;http://localhost:9000&#x60;. 
       _resource : BoundIdentifier ;
- // Whether or not to use TLS. 
-      _tls : boolean =false;
+ // The bucket the operations work on. 
+      _bucket : string ;
+ // The region requests are signed for. Defaults to &#x60;us-east-1&#x60;. 
+      _region : string| undefined =  undefined;
+ // The Secret resource holding the access key ID. 
+      _accessKeyId : BoundIdentifier ;
+ // The Secret resource holding the secret access key. 
+      _secretAccessKey : BoundIdentifier ;
+ // The Secret resource holding a session token, for temporary credentials. 
+      _sessionToken : BoundIdentifier| undefined =  undefined;
+ // Address the bucket as the first segment of the path, e.g. &#x60;http://localhost:9000/bucket/key&#x60;, instead of as a subdomain of the endpoint. Most S3-compatible stores need this. 
+      _pathStyle : boolean =false;
  // Configuration necessary to provide when instantiating the component. 
       _with : Field[] =  [];
  // A list of operations to expose on this component. 
-      _operations : SqlQueryKind[] =  [];
+      _operations : ObjectStoreOperationDefinition[] =  [];
     constructor (
 resource:
+ BoundIdentifier,
+bucket:
+ string,
+accessKeyId:
+ BoundIdentifier,
+secretAccessKey:
  BoundIdentifier,
       ) {
           this._resource = resource;
+          this._bucket = bucket;
+          this._accessKeyId = accessKeyId;
+          this._secretAccessKey = secretAccessKey;
     }
 
-resource(value: BoundIdentifier) : SqlComponent {
+resource(value: BoundIdentifier) : ObjectStoreComponent {
       this._resource = value;
       return this;
     }
@@ -4443,15 +8521,55 @@ resource(value: BoundIdentifier) : SqlComponent {
       return this._resource;
 
     }
-tls(value: boolean) : SqlComponent {
-      this._tls = value;
+bucket(value: string) : ObjectStoreComponent {
+      this._bucket = value;
       return this;
     }
-    getTls() : boolean {
-      return this._tls;
+    getBucket() : string {
+      return this._bucket;
 
     }
-with(value: Field[]) : SqlComponent {
+region(value: string| undefined) : ObjectStoreComponent {
+      this._region = value;
+      return this;
+    }
+    getRegion() : string| undefined {
+      return this._region;
+
+    }
+accessKeyId(value: BoundIdentifier) : ObjectStoreComponent {
+      this._accessKeyId = value;
+      return this;
+    }
+    getAccessKeyId() : BoundIdentifier {
+      return this._accessKeyId;
+
+    }
+secretAccessKey(value: BoundIdentifier) : ObjectStoreComponent {
+      this._secretAccessKey = value;
+      return this;
+    }
+    getSecretAccessKey() : BoundIdentifier {
+      return this._secretAccessKey;
+
+    }
+sessionToken(value: BoundIdentifier| undefined) : ObjectStoreComponent {
+      this._sessionToken = value;
+      return this;
+    }
+    getSessionToken() : BoundIdentifier| undefined {
+      return this._sessionToken;
+
+    }
+pathStyle(value: boolean) : ObjectStoreComponent {
+      this._pathStyle = value;
+      return this;
+    }
+    getPathStyle() : boolean {
+      return this._pathStyle;
+
+    }
+with(value: Field[]) : ObjectStoreComponent {
       this._with = value;
       return this;
     }
@@ -4459,23 +8577,23 @@ with(value: Field[]) : SqlComponent {
       return this._with;
 
     }
-operations(value: SqlQueryKind[]) : SqlComponent {
+operations(value: ObjectStoreOperationDefinition[]) : ObjectStoreComponent {
       this._operations = value;
       return this;
     }
-    getOperations() : SqlQueryKind[] {
+    getOperations() : ObjectStoreOperationDefinition[] {
       return this._operations;
 
     }
 
     getKind() : string {
-      return "wick/component/sql@v1";
+      return "wick/component/object-store@v1";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component/sql@v1",
-resource: this._resource,tls: this._tls,with: this._with,operations: this._operations,      }
+        kind : "wick/component/object-store@v1",
+resource: this._resource,bucket: this._bucket,region: this._region,access_key_id: this._accessKeyId,secret_access_key: this._secretAccessKey,session_token: this._sessionToken,path_style: this._pathStyle,with: this._with,operations: this._operations,      }
 
     }
 }
@@ -4484,43 +8602,34 @@ resource: this._resource,tls: this._tls,with: this._with,operations: this._opera
     
     
     
-
-    
-    
-    
-export type SqlQueryKind =
-      SqlQueryOperationDefinition|SqlExecOperationDefinition
-    ;
     
 
 
 
-export class SqlQueryOperationDefinition implements HasKind {
+export class ObjectStoreOperationDefinition implements HasKind {
  // The name of the operation. 
       _name : string ;
  // Any configuration required by the operation. 
       _with : Field[] =  [];
- // Types of the inputs to the operation. 
-      _inputs : Field[] =  [];
- // Types of the outputs to the operation. 
-      _outputs : Field[] =  [];
- // The query to execute. 
-      _query : string ;
- // The positional arguments to the query, defined as a list of input names. 
-      _arguments : string[] =  [];
- // What to do when an error occurs. 
-      _onError : ErrorBehavior| undefined =  undefined;
+ // What the operation does with the objects it&#x27;s given. 
+      _action : ObjectStoreAction ;
+ // For &#x60;presign&#x60;, the method the URL is signed for. Defaults to &#x60;Get&#x60;. 
+      _method : HttpMethod| undefined =  undefined;
+ // For &#x60;presign&#x60;, how long (in seconds) the URL is valid for. Defaults to 3600, at most 604800 (a week). 
+      _expires : number| undefined =  undefined;
+ // For &#x60;list&#x60;, the most objects to ask the store for at a time. Defaults to 1000. 
+      _pageSize : number| undefined =  undefined;
     constructor (
 name:
  string,
-query:
- string,
+action:
+ ObjectStoreAction,
       ) {
           this._name = name;
-          this._query = query;
+          this._action = action;
     }
 
-name(value: string) : SqlQueryOperationDefinition {
+name(value: string) : ObjectStoreOperationDefinition {
       this._name = value;
       return this;
     }
@@ -4528,7 +8637,7 @@ name(value: string) : SqlQueryOperationDefinition {
       return this._name;
 
     }
-with(value: Field[]) : SqlQueryOperationDefinition {
+with(value: Field[]) : ObjectStoreOperationDefinition {
       this._with = value;
       return this;
     }
@@ -4536,54 +8645,117 @@ with(value: Field[]) : SqlQueryOperationDefinition {
       return this._with;
 
     }
-inputs(value: Field[]) : SqlQueryOperationDefinition {
-      this._inputs = value;
+action(value: ObjectStoreAction) : ObjectStoreOperationDefinition {
+      this._action = value;
       return this;
     }
-    getInputs() : Field[] {
-      return this._inputs;
+    getAction() : ObjectStoreAction {
+      return this._action;
 
     }
-outputs(value: Field[]) : SqlQueryOperationDefinition {
-      this._outputs = value;
+method(value: HttpMethod| undefined) : ObjectStoreOperationDefinition {
+      this._method = value;
       return this;
     }
-    getOutputs() : Field[] {
-      return this._outputs;
+    getMethod() : HttpMethod| undefined {
+      return this._method;
 
     }
-query(value: string) : SqlQueryOperationDefinition {
-      this._query = value;
+expires(value: number| undefined) : ObjectStoreOperationDefinition {
+      this._expires = value;
       return this;
     }
-    getQuery() : string {
-      return this._query;
+    getExpires() : number| undefined {
+      return this._expires;
 
     }
-arguments(value: string[]) : SqlQueryOperationDefinition {
-      this._arguments = value;
+pageSize(value: number| undefined) : ObjectStoreOperationDefinition {
+      this._pageSize = value;
       return this;
     }
-    getArguments() : string[] {
-      return this._arguments;
+    getPageSize() : number| undefined {
+      return this._pageSize;
 
     }
-onError(value: ErrorBehavior| undefined) : SqlQueryOperationDefinition {
-      this._onError = value;
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,with: this._with,action: this._action,method: this._method,expires: this._expires,page_size: this._pageSize,      }
+
+    }
+}
+
+
+
+    
+    
+    
+    
+
+    
+    
+export enum ObjectStoreAction {
+Get = "Get",Put = "Put",List = "List",Presign = "Presign",}
+
+    
+    
+    
+    
+    
+
+
+
+export class FilesystemComponent implements HasKind {
+ // The Volume resource the operations work in. 
+      _resource : BoundIdentifier ;
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : FilesystemOperationDefinition[] =  [];
+    constructor (
+resource:
+ BoundIdentifier,
+      ) {
+          this._resource = resource;
+    }
+
+resource(value: BoundIdentifier) : FilesystemComponent {
+      this._resource = value;
       return this;
     }
-    getOnError() : ErrorBehavior| undefined {
-      return this._onError;
+    getResource() : BoundIdentifier {
+      return this._resource;
+
+    }
+with(value: Field[]) : FilesystemComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+operations(value: FilesystemOperationDefinition[]) : FilesystemComponent {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : FilesystemOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/filesystem@v1";
     }
 
     toJSON() : any {
       return {
-name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,query: this._query,arguments: this._arguments,on_error: this._onError,      }
+        kind : "wick/component/filesystem@v1",
+resource: this._resource,with: this._with,operations: this._operations,      }
 
     }
 }
@@ -4592,35 +8764,32 @@ name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,qu
     
     
     
+    
 
 
 
-export class SqlExecOperationDefinition implements HasKind {
+export class FilesystemOperationDefinition implements HasKind {
  // The name of the operation. 
       _name : string ;
  // Any configuration required by the operation. 
       _with : Field[] =  [];
- // Types of the inputs to the operation. 
-      _inputs : Field[] =  [];
- // Types of the outputs to the operation. 
-      _outputs : Field[] =  [];
- // The query to execute. 
-      _exec : string ;
- // The positional arguments to the query, defined as a list of input names. 
-      _arguments : string[] =  [];
- // What to do when an error occurs. 
-      _onError : ErrorBehavior| undefined =  undefined;
+ // What the operation does with the files it&#x27;s given. 
+      _action : FilesystemAction ;
+ // For &#x60;watch&#x60;, whether to watch the files in subdirectories too. 
+      _recursive : boolean =false;
+ // For &#x60;watch&#x60;, how often (in milliseconds) to check for changes. Defaults to 500. 
+      _interval : number| undefined =  undefined;
     constructor (
 name:
  string,
-exec:
- string,
+action:
+ FilesystemAction,
       ) {
           this._name = name;
-          this._exec = exec;
+          this._action = action;
     }
 
-name(value: string) : SqlExecOperationDefinition {
+name(value: string) : FilesystemOperationDefinition {
       this._name = value;
       return this;
     }
@@ -4628,7 +8797,7 @@ name(value: string) : SqlExecOperationDefinition {
       return this._name;
 
     }
-with(value: Field[]) : SqlExecOperationDefinition {
+with(value: Field[]) : FilesystemOperationDefinition {
       this._with = value;
       return this;
     }
@@ -4636,44 +8805,28 @@ with(value: Field[]) : SqlExecOperationDefinition {
       return this._with;
 
     }
-inputs(value: Field[]) : SqlExecOperationDefinition {
-      this._inputs = value;
-      return this;
-    }
-    getInputs() : Field[] {
-      return this._inputs;
-
-    }
-outputs(value: Field[]) : SqlExecOperationDefinition {
-      this._outputs = value;
-      return this;
-    }
-    getOutputs() : Field[] {
-      return this._outputs;
-
-    }
-exec(value: string) : SqlExecOperationDefinition {
-      this._exec = value;
+action(value: FilesystemAction) : FilesystemOperationDefinition {
+      this._action = value;
       return this;
     }
-    getExec() : string {
-      return this._exec;
+    getAction() : FilesystemAction {
+      return this._action;
 
     }
-arguments(value: string[]) : SqlExecOperationDefinition {
-      this._arguments = value;
+recursive(value: boolean) : FilesystemOperationDefinition {
+      this._recursive = value;
       return this;
     }
-    getArguments() : string[] {
-      return this._arguments;
+    getRecursive() : boolean {
+      return this._recursive;
 
-    }
-onError(value: ErrorBehavior| undefined) : SqlExecOperationDefinition {
-      this._onError = value;
+    }
+interval(value: number| undefined) : FilesystemOperationDefinition {
+      this._interval = value;
       return this;
     }
-    getOnError() : ErrorBehavior| undefined {
-      return this._onError;
+    getInterval() : number| undefined {
+      return this._interval;
 
     }
 
@@ -4683,11 +8836,13 @@ onError(value: ErrorBehavior| undefined) : SqlExecOperationDefinition {
 
     toJSON() : any {
       return {
-name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,exec: this._exec,arguments: this._arguments,on_error: this._onError,      }
+name: this._name,with: this._with,action: this._action,recursive: this._recursive,interval: this._interval,      }
 
     }
 }
 
+
+
     
     
     
@@ -4695,66 +8850,106 @@ name: this._name,with: this._with,inputs: this._inputs,outputs: this._outputs,ex
 
     
     
-export enum ErrorBehavior {
-Ignore = "Ignore",Commit = "Commit",Rollback = "Rollback",}
+export enum FilesystemAction {
+Watch = "Watch",}
+
+    
+    
+    
     
     
 
 
 
-export class HttpClientComponent implements HasKind {
- // The URL base to use. 
-      _resource : BoundIdentifier ;
- // The codec to use when encoding/decoding data. Can be overridden by individual operations. 
-      _codec : Codec| undefined =  undefined;
- // The proxy HTTP / HTTPS to use. 
-      _proxy : Proxy| undefined =  undefined;
- // The timeout in seconds 
-      _timeout : number| undefined =  undefined;
+export class DataComponent implements HasKind {
  // Configuration necessary to provide when instantiating the component. 
       _with : Field[] =  [];
  // A list of operations to expose on this component. 
-      _operations : HttpClientOperationDefinition[] =  [];
+      _operations : DataOperationDefinition[] =  [];
     constructor (
-resource:
- BoundIdentifier,
       ) {
-          this._resource = resource;
     }
 
-resource(value: BoundIdentifier) : HttpClientComponent {
-      this._resource = value;
+with(value: Field[]) : DataComponent {
+      this._with = value;
       return this;
     }
-    getResource() : BoundIdentifier {
-      return this._resource;
+    getWith() : Field[] {
+      return this._with;
 
     }
-codec(value: Codec| undefined) : HttpClientComponent {
-      this._codec = value;
+operations(value: DataOperationDefinition[]) : DataComponent {
+      this._operations = value;
       return this;
     }
-    getCodec() : Codec| undefined {
-      return this._codec;
+    getOperations() : DataOperationDefinition[] {
+      return this._operations;
 
     }
-proxy(value: Proxy| undefined) : HttpClientComponent {
-      this._proxy = value;
-      return this;
+
+    getKind() : string {
+      return "wick/component/data@v1";
     }
-    getProxy() : Proxy| undefined {
-      return this._proxy;
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/data@v1",
+with: this._with,operations: this._operations,      }
 
     }
-timeout(value: number| undefined) : HttpClientComponent {
-      this._timeout = value;
+}
+
+    
+    
+    
+    
+    
+
+
+
+export class DataOperationDefinition implements HasKind {
+ // The name of the operation. 
+      _name : string ;
+ // Any configuration required by the operation. 
+      _with : Field[] =  [];
+ // Whether the operation parses data into objects or serializes objects into data. 
+      _action : DataAction ;
+ // The format of the data. 
+      _format : DataFormat ;
+ // For &#x60;csv&#x60;, the character that separates fields. Defaults to &#x60;,&#x60;. 
+      _delimiter : string| undefined =  undefined;
+ // For &#x60;csv&#x60;, the names of the columns of data without a header row. When omitted, parsing reads the columns from the first row and serializing writes a header row from the fields of the first object. 
+      _columns : string[] =  [];
+ // For &#x60;xml&#x60;, the name of the elements records are read from or written as. Defaults to &#x60;item&#x60;. 
+      _element : string| undefined =  undefined;
+ // For serializing &#x60;xml&#x60;, the name of the element records are written inside. Defaults to &#x60;items&#x60;. 
+      _root : string| undefined =  undefined;
+ // Renames fields, from their names in the data (CSV columns, NDJSON keys, or XML attributes and child elements) to their names in the objects. Serializing renames them back. 
+      _mapping :   Record<string,string> =  {};
+ // For parsing &#x60;csv&#x60; and &#x60;xml&#x60;, turn values that read as numbers, booleans, or &#x60;null&#x60; into them instead of keeping every value a string. 
+      _typed : boolean =false;
+    constructor (
+name:
+ string,
+action:
+ DataAction,
+format:
+ DataFormat,
+      ) {
+          this._name = name;
+          this._action = action;
+          this._format = format;
+    }
+
+name(value: string) : DataOperationDefinition {
+      this._name = value;
       return this;
     }
-    getTimeout() : number| undefined {
-      return this._timeout;
+    getName() : string {
+      return this._name;
 
     }
-with(value: Field[]) : HttpClientComponent {
+with(value: Field[]) : DataOperationDefinition {
       this._with = value;
       return this;
     }
@@ -4762,77 +8957,145 @@ with(value: Field[]) : HttpClientComponent {
       return this._with;
 
     }
-operations(value: HttpClientOperationDefinition[]) : HttpClientComponent {
-      this._operations = value;
+action(value: DataAction) : DataOperationDefinition {
+      this._action = value;
       return this;
     }
-    getOperations() : HttpClientOperationDefinition[] {
-      return this._operations;
+    getAction() : DataAction {
+      return this._action;
+
+    }
+format(value: DataFormat) : DataOperationDefinition {
+      this._format = value;
+      return this;
+    }
+    getFormat() : DataFormat {
+      return this._format;
+
+    }
+delimiter(value: string| undefined) : DataOperationDefinition {
+      this._delimiter = value;
+      return this;
+    }
+    getDelimiter() : string| undefined {
+      return this._delimiter;
+
+    }
+columns(value: string[]) : DataOperationDefinition {
+      this._columns = value;
+      return this;
+    }
+    getColumns() : string[] {
+      return this._columns;
+
+    }
+element(value: string| undefined) : DataOperationDefinition {
+      this._element = value;
+      return this;
+    }
+    getElement() : string| undefined {
+      return this._element;
+
+    }
+root(value: string| undefined) : DataOperationDefinition {
+      this._root = value;
+      return this;
+    }
+    getRoot() : string| undefined {
+      return this._root;
+
+    }
+mapping(value:   Record<string,string>) : DataOperationDefinition {
+      this._mapping = value;
+      return this;
+    }
+    getMapping() :   Record<string,string> {
+      return this._mapping;
+
+    }
+typed(value: boolean) : DataOperationDefinition {
+      this._typed = value;
+      return this;
+    }
+    getTyped() : boolean {
+      return this._typed;
 
     }
 
     getKind() : string {
-      return "wick/component/http@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/component/http@v1",
-resource: this._resource,codec: this._codec,proxy: this._proxy,timeout: this._timeout,with: this._with,operations: this._operations,      }
+name: this._name,with: this._with,action: this._action,format: this._format,delimiter: this._delimiter,columns: this._columns,element: this._element,root: this._root,mapping: this._mapping,typed: this._typed,      }
 
     }
 }
 
+
+
     
     
     
     
 
+    
+    
+export enum DataAction {
+Parse = "Parse",Serialize = "Serialize",}
+    
+    
+    
+    
 
+    
+    
+export enum DataFormat {
+Csv = "Csv",Ndjson = "Ndjson",Xml = "Xml",}
 
-export class Proxy implements HasKind {
- // The URL base to use. http, https are supported. 
-      _resource : string ="";
- // The username to use when authenticating with the proxy. 
-      _username : string| undefined =  undefined;
- // The password to use when authenticating with the proxy. 
-      _password : string| undefined =  undefined;
+    
+    
+    
+    
+    
+
+
+
+export class CryptoComponent implements HasKind {
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+ // A list of operations to expose on this component. 
+      _operations : CryptoOperationDefinition[] =  [];
     constructor (
       ) {
     }
 
-resource(value: string) : Proxy {
-      this._resource = value;
-      return this;
-    }
-    getResource() : string {
-      return this._resource;
-
-    }
-username(value: string| undefined) : Proxy {
-      this._username = value;
+with(value: Field[]) : CryptoComponent {
+      this._with = value;
       return this;
     }
-    getUsername() : string| undefined {
-      return this._username;
+    getWith() : Field[] {
+      return this._with;
 
     }
-password(value: string| undefined) : Proxy {
-      this._password = value;
+operations(value: CryptoOperationDefinition[]) : CryptoComponent {
+      this._operations = value;
       return this;
     }
-    getPassword() : string| undefined {
-      return this._password;
+    getOperations() : CryptoOperationDefinition[] {
+      return this._operations;
 
     }
 
     getKind() : string {
-      return "";
+      return "wick/component/crypto@v1";
     }
 
     toJSON() : any {
       return {
-resource: this._resource,username: this._username,password: this._password,      }
+        kind : "wick/component/crypto@v1",
+with: this._with,operations: this._operations,      }
 
     }
 }
@@ -4841,37 +9104,45 @@ resource: this._resource,username: this._username,password: this._password,
     
     
     
+    
 
 
 
-export class HttpClientOperationDefinition implements HasKind {
+export class CryptoOperationDefinition implements HasKind {
  // The name of the operation. 
       _name : string ;
  // Any configuration required by the operation. 
       _with : Field[] =  [];
- // Types of the inputs to the operation. 
-      _inputs : Field[] =  [];
- // The HTTP method to use. 
-      _method : HttpMethod ;
- // The codec to use when encoding/decoding data. 
-      _codec : Codec| undefined =  undefined;
- // Any headers to add to the request. 
-      _headers :   Record<string,string[]>| undefined =  undefined;
- // The body to send, processed as a structured JSON liquid template. 
-      _body : LiquidJsonValue| undefined =  undefined;
- // The path to append to our base URL, processed as a liquid template with each input as part of the template data. 
-      _path : string ="";
+ // What the operation does. 
+      _action : CryptoAction ;
+ // The algorithm the operation uses. &#x60;Hash&#x60; takes a SHA-2 algorithm or &#x60;Blake3&#x60;, &#x60;Hmac&#x60; takes a SHA-2 algorithm, and &#x60;Sign&#x60; takes &#x60;Ed25519&#x60;, &#x60;EcdsaP256&#x60;, or &#x60;EcdsaP384&#x60;. &#x60;Verify&#x60; and the JWT actions take any but &#x60;Blake3&#x60;, with SHA-2 algorithms meaning HMAC. 
+      _algorithm : CryptoAlgorithm ;
+ // The Secret resource holding the key: the shared secret for HMAC, a PKCS#8 private key in PEM to sign and issue tokens, or a public key in PEM to verify signatures and tokens. Every action but &#x60;Hash&#x60; needs one. 
+      _key : BoundIdentifier| undefined =  undefined;
+ // How digests, HMACs, and signatures are encoded, both those the operation outputs and those &#x60;Verify&#x60; takes. Defaults to &#x60;Hex&#x60;. 
+      _encoding : CryptoEncoding| undefined =  undefined;
+ // The &#x60;iss&#x60; claim &#x60;JwtIssue&#x60; adds to tokens and &#x60;JwtVerify&#x60; requires of them. 
+      _issuer : string| undefined =  undefined;
+ // The &#x60;aud&#x60; claim &#x60;JwtIssue&#x60; adds to tokens and &#x60;JwtVerify&#x60; requires of them. 
+      _audience : string| undefined =  undefined;
+ // How many seconds tokens from &#x60;JwtIssue&#x60; are valid for. They don&#x27;t expire when this is omitted. 
+      _expires : number| undefined =  undefined;
+ // How many seconds of difference between clocks &#x60;JwtVerify&#x60; allows when it checks &#x60;exp&#x60; and &#x60;nbf&#x60;. Defaults to 0. 
+      _leeway : number| undefined =  undefined;
     constructor (
 name:
  string,
-method:
- HttpMethod,
+action:
+ CryptoAction,
+algorithm:
+ CryptoAlgorithm,
       ) {
           this._name = name;
-          this._method = method;
+          this._action = action;
+          this._algorithm = algorithm;
     }
 
-name(value: string) : HttpClientOperationDefinition {
+name(value: string) : CryptoOperationDefinition {
       this._name = value;
       return this;
     }
@@ -4879,7 +9150,7 @@ name(value: string) : HttpClientOperationDefinition {
       return this._name;
 
     }
-with(value: Field[]) : HttpClientOperationDefinition {
+with(value: Field[]) : CryptoOperationDefinition {
       this._with = value;
       return this;
     }
@@ -4887,52 +9158,68 @@ with(value: Field[]) : HttpClientOperationDefinition {
       return this._with;
 
     }
-inputs(value: Field[]) : HttpClientOperationDefinition {
-      this._inputs = value;
+action(value: CryptoAction) : CryptoOperationDefinition {
+      this._action = value;
       return this;
     }
-    getInputs() : Field[] {
-      return this._inputs;
+    getAction() : CryptoAction {
+      return this._action;
 
     }
-method(value: HttpMethod) : HttpClientOperationDefinition {
-      this._method = value;
+algorithm(value: CryptoAlgorithm) : CryptoOperationDefinition {
+      this._algorithm = value;
       return this;
     }
-    getMethod() : HttpMethod {
-      return this._method;
+    getAlgorithm() : CryptoAlgorithm {
+      return this._algorithm;
 
     }
-codec(value: Codec| undefined) : HttpClientOperationDefinition {
-      this._codec = value;
+key(value: BoundIdentifier| undefined) : CryptoOperationDefinition {
+      this._key = value;
       return this;
     }
-    getCodec() : Codec| undefined {
-      return this._codec;
+    getKey() : BoundIdentifier| undefined {
+      return this._key;
 
     }
-headers(value:   Record<string,string[]>| undefined) : HttpClientOperationDefinition {
-      this._headers = value;
+encoding(value: CryptoEncoding| undefined) : CryptoOperationDefinition {
+      this._encoding = value;
       return this;
     }
-    getHeaders() :   Record<string,string[]>| undefined {
-      return this._headers;
+    getEncoding() : CryptoEncoding| undefined {
+      return this._encoding;
 
     }
-body(value: LiquidJsonValue| undefined) : HttpClientOperationDefinition {
-      this._body = value;
+issuer(value: string| undefined) : CryptoOperationDefinition {
+      this._issuer = value;
       return this;
     }
-    getBody() : LiquidJsonValue| undefined {
-      return this._body;
+    getIssuer() : string| undefined {
+      return this._issuer;
 
     }
-path(value: string) : HttpClientOperationDefinition {
-      this._path = value;
+audience(value: string| undefined) : CryptoOperationDefinition {
+      this._audience = value;
       return this;
     }
-    getPath() : string {
-      return this._path;
+    getAudience() : string| undefined {
+      return this._audience;
+
+    }
+expires(value: number| undefined) : CryptoOperationDefinition {
+      this._expires = value;
+      return this;
+    }
+    getExpires() : number| undefined {
+      return this._expires;
+
+    }
+leeway(value: number| undefined) : CryptoOperationDefinition {
+      this._leeway = value;
+      return this;
+    }
+    getLeeway() : number| undefined {
+      return this._leeway;
 
     }
 
@@ -4942,13 +9229,40 @@ path(value: string) : HttpClientOperationDefinition {
 
     toJSON() : any {
       return {
-name: this._name,with: this._with,inputs: this._inputs,method: this._method,codec: this._codec,headers: this._headers,body: this._body,path: this._path,      }
+name: this._name,with: this._with,action: this._action,algorithm: this._algorithm,key: this._key,encoding: this._encoding,issuer: this._issuer,audience: this._audience,expires: this._expires,leeway: this._leeway,      }
 
     }
 }
 
+
+
+    
+    
+    
+    
+
+    
+    
+export enum CryptoAction {
+Hash = "Hash",Hmac = "Hmac",Sign = "Sign",Verify = "Verify",JwtIssue = "JwtIssue",JwtVerify = "JwtVerify",}
+    
+    
+    
+    
+
+    
+    
+export enum CryptoAlgorithm {
+Sha256 = "Sha256",Sha384 = "Sha384",Sha512 = "Sha512",Blake3 = "Blake3",Ed25519 = "Ed25519",EcdsaP256 = "EcdsaP256",EcdsaP384 = "EcdsaP384",}
+    
+    
+    
+    
+
     
     
+export enum CryptoEncoding {
+Hex = "Hex",Base64 = "Base64",Base64Url = "Base64Url",}
     
     
 
@@ -4961,6 +9275,34 @@ Json = "Json",Raw = "Raw",FormData = "FormData",Text = "Text",}
 
     
     
+export enum ResponseMode {
+Buffered = "Buffered",Stream = "Stream",Sse = "Sse",}
+    
+    
+
+    
+    
+export enum LogFormat {
+Json = "Json",Csv = "Csv",Regex = "Regex",}
+    
+    
+
+    
+    
+export enum MetricsProtocol {
+RemoteWrite = "RemoteWrite",Statsd = "Statsd",}
+    
+    
+
+    
+    
+export enum MetricKind {
+Gauge = "Gauge",Counter = "Counter",Timing = "Timing",}
+    
+    
+
+    
+    
 export enum HttpMethod {
 Get = "Get",Post = "Post",Put = "Put",Delete = "Delete",}
     
@@ -0,0 +1,394 @@
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use futures::{future, FutureExt};
+use hyper::header::CONTENT_TYPE;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use wick_config::config::AppConfiguration;
+use wick_runtime::metrics::RuntimeMetrics;
+use wick_runtime::{ObservabilityLevel, Runtime};
+
+use crate::error::HostError;
+use crate::Result;
+
+/// Where a trigger is in its lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TriggerStatus {
+  /// The trigger has been loaded but hasn't finished starting.
+  Starting,
+  /// The trigger is waiting for this instance to take over the failover lease.
+  Standby,
+  /// The trigger started successfully.
+  Running,
+  /// The trigger failed to start.
+  Failed {
+    /// Why the trigger failed.
+    error: String,
+  },
+}
+
+/// The status of a single trigger.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct TriggerHealth {
+  /// The kind of trigger, e.g. `wick/trigger/http@v1`.
+  pub kind: String,
+  /// The trigger's current status.
+  pub status: TriggerStatus,
+}
+
+/// The initialization state of an imported component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct ComponentHealth {
+  /// The namespace the component was imported as.
+  pub namespace: String,
+  /// Whether the runtime has initialized the component.
+  pub initialized: bool,
+}
+
+/// A snapshot of an app's health, as served by the admin listener.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct HealthReport {
+  /// Whether every trigger is running and every imported component is initialized.
+  pub ready: bool,
+  /// The status of each trigger, in configuration order.
+  pub triggers: Vec<TriggerHealth>,
+  /// The initialization state of each imported component.
+  pub components: Vec<ComponentHealth>,
+  /// The number of events queued for the interpreter that haven't been processed yet.
+  pub backlog: usize,
+}
+
+impl HealthReport {
+  /// Whether no trigger has failed.
+  #[must_use]
+  pub fn live(&self) -> bool {
+    !self
+      .triggers
+      .iter()
+      .any(|t| matches!(t.status, TriggerStatus::Failed { .. }))
+  }
+}
+
+#[derive(Default)]
+struct HealthInner {
+  triggers: Vec<TriggerHealth>,
+  imports: Vec<String>,
+  runtime: Option<Runtime>,
+}
+
+/// Health information shared between an [crate::AppHost], its triggers, and the admin listener.
+#[derive(Clone, Default)]
+#[must_use]
+pub struct HealthState {
+  inner: Arc<RwLock<HealthInner>>,
+}
+
+impl std::fmt::Debug for HealthState {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("HealthState").field("report", &self.report()).finish()
+  }
+}
+
+impl HealthState {
+  /// Track the triggers and imports of `manifest`, reporting on `runtime`.
+  pub(crate) fn set_app(&self, manifest: &AppConfiguration, runtime: Runtime) {
+    let mut inner = self.inner.write();
+    inner.triggers = manifest
+      .triggers()
+      .iter()
+      .map(|t| TriggerHealth {
+        kind: t.kind().to_string(),
+        status: TriggerStatus::Starting,
+      })
+      .collect();
+    inner.imports = manifest.import().iter().map(|i| i.id().to_owned()).collect();
+    inner.runtime = Some(runtime);
+  }
+
+  /// Switch to the runtime of a reloaded configuration, keeping trigger statuses.
+  pub(crate) fn set_runtime(&self, manifest: &AppConfiguration, runtime: Runtime) {
+    let mut inner = self.inner.write();
+    inner.imports = manifest.import().iter().map(|i| i.id().to_owned()).collect();
+    inner.runtime = Some(runtime);
+  }
+
+  /// Update the status of the trigger at `index`.
+  pub(crate) fn set_trigger(&self, index: usize, status: TriggerStatus) {
+    if let Some(trigger) = self.inner.write().triggers.get_mut(index) {
+      trigger.status = status;
+    }
+  }
+
+  /// Take a snapshot of the app's health.
+  #[must_use]
+  pub fn report(&self) -> HealthReport {
+    let inner = self.inner.read();
+    let initialized = inner.runtime.as_ref().map(Runtime::components).unwrap_or_default();
+    let components: Vec<_> = inner
+      .imports
+      .iter()
+      .map(|ns| ComponentHealth {
+        namespace: ns.clone(),
+        initialized: initialized.contains(ns),
+      })
+      .collect();
+    let ready = inner.runtime.is_some()
+      && inner.triggers.iter().all(|t| t.status == TriggerStatus::Running)
+      && components.iter().all(|c| c.initialized);
+    HealthReport {
+      ready,
+      triggers: inner.triggers.clone(),
+      components,
+      backlog: inner.runtime.as_ref().map_or(0, Runtime::backlog),
+    }
+  }
+}
+
+type Listener = (SocketAddr, JoinHandle<()>);
+
+/// Start serving `/healthz`, `/readyz`, `/metrics`, `/receipts`, and `/observability` on `addr`.
+pub(crate) fn serve(addr: SocketAddr, health: HealthState) -> Result<Listener> {
+  listen(addr, "admin", move |req| {
+    let health = health.clone();
+    async move {
+      if req.uri().path() == "/observability" {
+        observability_response(req).await
+      } else {
+        handle(&req, &health)
+      }
+    }
+  })
+  .map_err(|e| HostError::Admin(format!("{}: {}", addr, e)))
+}
+
+/// Start serving only `/metrics` on `addr`.
+pub(crate) fn serve_metrics(addr: SocketAddr, metrics: RuntimeMetrics) -> Result<Listener> {
+  listen(addr, "metrics", move |req| {
+    future::ready(match req.uri().path() {
+      "/metrics" if req.method() == Method::GET => metrics_response(metrics.render()),
+      "/metrics" => empty(StatusCode::METHOD_NOT_ALLOWED),
+      _ => empty(StatusCode::NOT_FOUND),
+    })
+  })
+  .map_err(|e| HostError::Other(format!("could not start the metrics listener on {}: {}", addr, e)))
+}
+
+fn listen<F, Fut>(addr: SocketAddr, name: &'static str, handler: F) -> std::result::Result<Listener, hyper::Error>
+where
+  F: Fn(Request<Body>) -> Fut + Clone + Send + Sync + 'static,
+  Fut: Future<Output = Response<Body>> + Send + 'static,
+{
+  let builder = Server::try_bind(&addr)?;
+  let make_svc = make_service_fn(move |_| {
+    let handler = handler.clone();
+    future::ok::<_, Infallible>(service_fn(move |req| handler(req).map(Ok::<_, Infallible>)))
+  });
+  let server = builder.serve(make_svc);
+  let addr = server.local_addr();
+  info!(%addr, listener = name, "listener started");
+  let handle = tokio::spawn(async move {
+    if let Err(error) = server.await {
+      error!(%error, listener = name, "listener stopped");
+    }
+  });
+  Ok((addr, handle))
+}
+
+fn handle(req: &Request<Body>, health: &HealthState) -> Response<Body> {
+  if req.method() != Method::GET {
+    return empty(StatusCode::METHOD_NOT_ALLOWED);
+  }
+  if req.uri().path() == "/metrics" {
+    let runtime = health.inner.read().runtime.clone();
+    return runtime.map_or_else(
+      || empty(StatusCode::SERVICE_UNAVAILABLE),
+      |rt| {
+        let mut body = rt.metrics().render();
+        if let Some(receipts) = rt.receipts() {
+          body.push_str(&receipts.render_metrics());
+        }
+        metrics_response(body)
+      },
+    );
+  }
+  if let Some(rest) = req.uri().path().strip_prefix("/receipts") {
+    return receipts_response(rest, health);
+  }
+  let report = health.report();
+  let ok = match req.uri().path() {
+    "/healthz" => report.live(),
+    "/readyz" => report.ready,
+    _ => return empty(StatusCode::NOT_FOUND),
+  };
+  let status = if ok {
+    StatusCode::OK
+  } else {
+    StatusCode::SERVICE_UNAVAILABLE
+  };
+  let body = serde_json::to_vec(&report).unwrap_or_default();
+  Response::builder()
+    .status(status)
+    .header(CONTENT_TYPE, "application/json")
+    .body(Body::from(body))
+    .unwrap()
+}
+
+/// `/receipts` lists the receipts kept, most recent first, and `/receipts/<tx id>` gets one.
+fn receipts_response(rest: &str, health: &HealthState) -> Response<Body> {
+  let runtime = health.inner.read().runtime.clone();
+  let Some(receipts) = runtime.as_ref().and_then(Runtime::receipts) else {
+    return empty(StatusCode::NOT_FOUND);
+  };
+  let body = match rest.trim_start_matches('/') {
+    "" => serde_json::to_vec(&receipts.recent()),
+    tx_id => {
+      let Ok(tx_id) = tx_id.parse::<Uuid>() else {
+        return empty(StatusCode::BAD_REQUEST);
+      };
+      let Some(receipt) = receipts.get(tx_id) else {
+        return empty(StatusCode::NOT_FOUND);
+      };
+      serde_json::to_vec(&receipt)
+    }
+  };
+  Response::builder()
+    .header(CONTENT_TYPE, "application/json")
+    .body(Body::from(body.unwrap_or_default()))
+    .unwrap()
+}
+
+/// The body `/observability` responds with and `PUT /observability` takes.
+#[derive(Debug, Serialize, Deserialize)]
+struct ObservabilityBody {
+  level: ObservabilityLevel,
+}
+
+/// `GET /observability` reports the observability level and `PUT /observability` switches it.
+async fn observability_response(req: Request<Body>) -> Response<Body> {
+  match *req.method() {
+    Method::GET => {}
+    Method::PUT => {
+      let body = hyper::body::to_bytes(req.into_body()).await.unwrap_or_default();
+      let Ok(ObservabilityBody { level }) = serde_json::from_slice(&body) else {
+        return empty(StatusCode::BAD_REQUEST);
+      };
+      let previous = ObservabilityLevel::set(level);
+      info!(%previous, %level, "observability level changed");
+    }
+    _ => return empty(StatusCode::METHOD_NOT_ALLOWED),
+  }
+  let body = ObservabilityBody {
+    level: ObservabilityLevel::current(),
+  };
+  Response::builder()
+    .header(CONTENT_TYPE, "application/json")
+    .body(Body::from(serde_json::to_vec(&body).unwrap_or_default()))
+    .unwrap()
+}
+
+fn metrics_response(body: String) -> Response<Body> {
+  Response::builder()
+    .header(CONTENT_TYPE, "text/plain; version=0.0.4")
+    .body(Body::from(body))
+    .unwrap()
+}
+
+fn empty(status: StatusCode) -> Response<Body> {
+  Response::builder().status(status).body(Body::empty()).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  async fn get(health: &HealthState, path: &str) -> Result<(StatusCode, serde_json::Value)> {
+    let req = Request::builder().uri(path).body(Body::empty())?;
+    let res = handle(&req, health);
+    let status = res.status();
+    let body = hyper::body::to_bytes(res.into_body()).await?;
+    Ok((status, serde_json::from_slice(&body)?))
+  }
+
+  #[tokio::test]
+  async fn test_probes() -> Result<()> {
+    let health = HealthState::default();
+    health.inner.write().triggers = vec![
+      TriggerHealth {
+        kind: "wick/trigger/http@v1".to_owned(),
+        status: TriggerStatus::Running,
+      },
+      TriggerHealth {
+        kind: "wick/trigger/time@v1".to_owned(),
+        status: TriggerStatus::Starting,
+      },
+    ];
+
+    let (status, body) = get(&health, "/healthz").await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["triggers"][1]["status"]["state"], "starting");
+    let (status, body) = get(&health, "/readyz").await?;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["ready"], false);
+
+    health.set_trigger(1, TriggerStatus::Failed {
+      error: "bad schedule".to_owned(),
+    });
+    let (status, body) = get(&health, "/healthz").await?;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["triggers"][1]["status"]["error"], "bad schedule");
+
+    let req = Request::builder().uri("/nope").body(Body::empty())?;
+    assert_eq!(handle(&req, &health).status(), StatusCode::NOT_FOUND);
+    let req = Request::builder().uri("/metrics").body(Body::empty())?;
+    assert_eq!(handle(&req, &health).status(), StatusCode::SERVICE_UNAVAILABLE);
+    let req = Request::builder().uri("/receipts").body(Body::empty())?;
+    assert_eq!(handle(&req, &health).status(), StatusCode::NOT_FOUND);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_observability() -> Result<()> {
+    async fn call(method: Method, body: &str) -> Result<(StatusCode, Option<serde_json::Value>)> {
+      let req = Request::builder()
+        .method(method)
+        .uri("/observability")
+        .body(Body::from(body.to_owned()))?;
+      let res = observability_response(req).await;
+      let status = res.status();
+      let body = hyper::body::to_bytes(res.into_body()).await?;
+      Ok((status, serde_json::from_slice(&body).ok()))
+    }
+    let original = ObservabilityLevel::current();
+
+    let (status, body) = call(Method::PUT, r#"{"level":"ops"}"#).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.unwrap()["level"], "ops");
+    assert_eq!(ObservabilityLevel::current(), ObservabilityLevel::Ops);
+    let (status, body) = call(Method::GET, "").await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.unwrap()["level"], "ops");
+
+    let (status, _) = call(Method::PUT, r#"{"level":"verbose"}"#).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(ObservabilityLevel::current(), ObservabilityLevel::Ops);
+    let (status, _) = call(Method::POST, "").await?;
+    assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+
+    ObservabilityLevel::set(original);
+    Ok(())
+  }
+}
@@ -2,19 +2,28 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::future::{join_all, select};
-use futures::pin_mut;
+use futures::StreamExt;
 use structured_output::StructuredOutput;
 use tokio::task::{JoinError, JoinHandle};
 use tracing::Span;
-use wick_config::config::{AppConfiguration, BoundIdentifier};
+use wick_config::config::{AppConfiguration, BoundIdentifier, ResourceDefinition};
 use wick_config::WickConfiguration;
 use wick_interface_types::ComponentSignature;
-use wick_packet::{Entity, InherentData, Invocation, PacketStream, RuntimeConfig};
+use wick_packet::{Entity, InherentData, Invocation, PacketExt, PacketStream, RuntimeConfig};
+use wick_runtime::checkpoints::Durability;
 use wick_runtime::error::RuntimeError;
+use wick_runtime::failover::{ActiveLease, Coordinator, Failover};
+use wick_runtime::metrics::AlertEvaluator;
+use wick_runtime::receipts::ReceiptLog;
+use wick_runtime::scan::ScanPolicy;
+use wick_runtime::secrets::SecretsBackends;
+use wick_runtime::state::KeyValueStore;
 use wick_runtime::Runtime;
 use wick_trigger::resources::Resource;
 use wick_trigger::{build_trigger_runtime, Trigger};
 
+use crate::admin::{self, HealthState, TriggerStatus};
+use crate::error::HostError;
 use crate::triggers::load_trigger;
 use crate::Result;
 
@@ -27,6 +36,20 @@ pub struct AppHost {
   #[builder(setter(skip))]
   triggers: Option<TriggerState>,
   runtime: Runtime,
+  /// Configuration merged over the `with:` config of the app's imports, keyed by import id. Applied again on reload.
+  #[builder(default)]
+  config_overrides: HashMap<String, RuntimeConfig>,
+  #[builder(setter(skip))]
+  health: HealthState,
+  #[builder(setter(skip))]
+  admin: Option<JoinHandle<()>>,
+  #[builder(setter(skip))]
+  alerts: Option<JoinHandle<()>>,
+  /// Share a lease with other instances of the app and only run triggers while holding it.
+  #[builder(default, setter(strip_option))]
+  failover: Option<Failover>,
+  #[builder(setter(skip))]
+  lease: Option<ActiveLease>,
   #[builder(default = "tracing::Span::current()")]
   span: Span,
 }
@@ -38,35 +61,206 @@ impl std::fmt::Debug for AppHost {
 }
 
 impl AppHost {
-  pub fn start(&mut self) -> Result<()> {
+  pub async fn start(&mut self) -> Result<()> {
     self.span.in_scope(|| debug!("host starting"));
 
-    let resources = self.init_resources()?;
+    let resources = self.init_resources().await?;
+    self.health.set_app(&self.manifest, self.runtime.clone());
+    self.start_admin(&resources)?;
+    self.start_alerts();
+    if let Some(failover) = &self.failover {
+      for index in 0..self.manifest.triggers().len() {
+        self.health.set_trigger(index, TriggerStatus::Standby);
+      }
+      self
+        .span
+        .in_scope(|| info!(node = failover.node(), "waiting for the failover lease"));
+      self.lease = Some(failover.acquire().await?);
+      for index in 0..self.manifest.triggers().len() {
+        self.health.set_trigger(index, TriggerStatus::Starting);
+      }
+    }
+    self.resume_transactions().await?;
     self.start_triggers(resources)?;
 
     Ok(())
   }
 
-  /// Stops a running host.
-  #[allow(clippy::unused_async)]
+  /// Finish the transactions a previous instance of the app left unfinished, if its runtime checkpoints them. Their
+  /// output has no one waiting on it anymore, so it's only logged.
+  async fn resume_transactions(&self) -> Result<()> {
+    for (tx_id, mut stream) in self.runtime.resume().await? {
+      let span = info_span!(parent: &self.span, "resume", %tx_id);
+      span.in_scope(|| info!("resuming unfinished transaction"));
+      tokio::spawn(async move {
+        while let Some(packet) = stream.next().await {
+          match packet {
+            Ok(packet) if packet.is_error() => {
+              span.in_scope(|| warn!(port = packet.port(), error = ?packet.payload(), "resumed transaction failed"));
+            }
+            Err(error) => span.in_scope(|| warn!(%error, "resumed transaction failed")),
+            Ok(_) => {}
+          }
+        }
+        span.in_scope(|| info!("resumed transaction finished"));
+      });
+    }
+    Ok(())
+  }
+
+  /// Stops a running host, giving up the failover lease if it holds it.
   pub async fn stop(self) {
     self.span.in_scope(|| debug!("host stopping"));
+    if let Some(admin) = self.admin {
+      admin.abort();
+    }
+    if let Some(alerts) = self.alerts {
+      alerts.abort();
+    }
+    if let Some(lease) = self.lease {
+      if let Err(error) = lease.release().await {
+        self
+          .span
+          .in_scope(|| warn!(%error, "could not release the failover lease"));
+      }
+    }
+  }
+
+  /// The current health of the app's triggers and components.
+  pub const fn health(&self) -> &HealthState {
+    &self.health
   }
 
-  fn init_resources(&mut self) -> Result<HashMap<BoundIdentifier, Resource>> {
-    let mut resources = HashMap::new();
-    for def in self.manifest.resources() {
-      let resource = Resource::new(def.kind().clone())?;
-      resources.insert(def.binding().clone(), resource);
+  /// Check the alert rules in the app's admin settings against its metrics.
+  fn start_alerts(&mut self) {
+    let Some(rules) = self.manifest.admin().map(|admin| admin.alerts()).filter(|rules| !rules.is_empty()) else {
+      return;
+    };
+    let evaluator = AlertEvaluator::new(rules.to_vec());
+    self.alerts = Some(tokio::spawn(evaluator.watch(self.runtime.clone(), self.span.clone())));
+  }
+
+  fn start_admin(&mut self, resources: &HashMap<BoundIdentifier, Resource>) -> Result<()> {
+    let Some(config) = self.manifest.admin() else {
+      return Ok(());
+    };
+    let addr = match resources.get(config.resource()) {
+      Some(Resource::TcpPort(addr)) => *addr,
+      Some(_) => {
+        return Err(HostError::Admin(format!(
+          "resource '{}' is not a TcpPort",
+          config.resource()
+        )))
+      }
+      None => return Err(HostError::Admin(format!("resource '{}' not found", config.resource()))),
+    };
+    let _guard = self.span.enter();
+    let (_, handle) = admin::serve(addr, self.health.clone())?;
+    self.admin = Some(handle);
+    Ok(())
+  }
+
+  async fn init_resources(&mut self) -> Result<HashMap<BoundIdentifier, Resource>> {
+    init_resources(&self.manifest).await
+  }
+
+  /// Switch a running app over to a new configuration without restarting it.
+  ///
+  /// The new configuration's runtime is built and every trigger validates it before anything is swapped, so a
+  /// failure leaves the running app untouched. Requests already in progress finish on the previous runtime.
+  pub async fn reload(&mut self, manifest: AppConfiguration, seed: Option<u64>) -> Result<()> {
+    self.span.in_scope(|| info!("reloading app"));
+    let Some(state) = self.triggers.as_ref() else {
+      return Err(HostError::NoRuntime);
+    };
+
+    let old_kinds: Vec<_> = self.manifest.triggers().iter().map(|t| t.kind()).collect();
+    let new_kinds: Vec<_> = manifest.triggers().iter().map(|t| t.kind()).collect();
+    if old_kinds != new_kinds {
+      return Err(HostError::Reload(
+        "triggers were added, removed, or reordered; restart the app to apply".to_owned(),
+      ));
+    }
+
+    let runtime = Self::build_runtime_with_overrides(
+      &manifest,
+      seed,
+      &self.config_overrides,
+      self.runtime.receipts().cloned(),
+      self.runtime.coordinator().cloned(),
+      self.runtime.scan().cloned(),
+      Some(self.runtime.state().clone()),
+      // transactions in progress finish on the previous runtime, so the new one doesn't resume them.
+      self.runtime.durability().cloned(),
+      self.span.clone(),
+    )
+    .await?;
+    let resources = Arc::new(init_resources(&manifest).await?);
+
+    let mut commits = Vec::new();
+    for ((trigger, _), config) in state.triggers.iter().zip(manifest.triggers()) {
+      let span = info_span!(parent: &self.span, "trigger:reload", kind=%config.kind());
+      let commit = trigger
+        .prepare_reload(
+          runtime.clone(),
+          manifest.clone(),
+          config.clone(),
+          resources.clone(),
+          span.clone(),
+        )
+        .await?;
+      if commit.is_none() {
+        span.in_scope(|| warn!("trigger can't be reloaded in place, restart the app to apply its changes"));
+      }
+      commits.extend(commit);
+    }
+
+    for commit in commits {
+      commit();
+    }
+    self.health.set_runtime(&manifest, runtime.clone());
+    self.manifest = manifest;
+    self.runtime = runtime;
+    if let Some(alerts) = self.alerts.take() {
+      alerts.abort();
     }
-    Ok(resources)
+    self.start_alerts();
+    self.span.in_scope(|| info!("app reloaded"));
+
+    Ok(())
   }
 
   pub async fn build_runtime(config: &AppConfiguration, seed: Option<u64>, span: Span) -> Result<Runtime> {
-    let rt = build_trigger_runtime(config, span)
+    Self::build_runtime_with_overrides(config, seed, &HashMap::new(), None, None, None, None, None, span).await
+  }
+
+  /// Build the runtime for an app, deep-merging `overrides` over the `with:` config of the imports they're keyed by,
+  /// signing a receipt for each completed transaction into `receipts`, sharing leases with other instances of the
+  /// app through `coordinator`, checking imported components' artifacts with `scan` before they're instantiated,
+  /// keeping components' state in `state`, and checkpointing transactions with `durability`, if given.
+  #[allow(clippy::too_many_arguments)]
+  pub async fn build_runtime_with_overrides(
+    config: &AppConfiguration,
+    seed: Option<u64>,
+    overrides: &HashMap<String, RuntimeConfig>,
+    receipts: Option<ReceiptLog>,
+    coordinator: Option<Coordinator>,
+    scan: Option<ScanPolicy>,
+    state: Option<Arc<dyn KeyValueStore>>,
+    durability: Option<Durability>,
+    span: Span,
+  ) -> Result<Runtime> {
+    let mut builder = build_trigger_runtime(config, span)
       .unwrap()
-      .build(seed.map(seeded_random::Seed::unsafe_new))
-      .await?;
+      .receipts(receipts)
+      .coordinator(coordinator)
+      .scan(scan)
+      .state(state)
+      .durability(durability);
+    for (id, config) in overrides {
+      builder.add_config_override(id.clone(), config.clone());
+    }
+    let rt = builder.build(seed.map(seeded_random::Seed::unsafe_new)).await?;
     Ok(rt)
   }
 
@@ -76,7 +270,7 @@ impl AppHost {
     let resources = Arc::new(resources);
     let mut triggers = TriggerState::new();
 
-    for trigger_config in self.manifest.triggers() {
+    for (index, trigger_config) in self.manifest.triggers().iter().enumerate() {
       self.span.in_scope(|| debug!(?trigger_config, "loading trigger"));
       let config = trigger_config.clone();
       let name = self.manifest.name().to_owned();
@@ -88,15 +282,18 @@ impl AppHost {
       let span = info_span!("trigger", kind=%trigger_config.kind());
       span.follows_from(&self.span);
       let rt = self.runtime.clone();
+      let health = self.health.clone();
 
       let task = tokio::spawn(async move {
         span.in_scope(|| trace!("initializing trigger"));
         match inner.run(name, rt, app_config, config, resources, span.clone()).await {
           Ok(_output) => {
             span.in_scope(|| debug!("trigger initialized"));
+            health.set_trigger(index, TriggerStatus::Running);
           }
           Err(e) => {
             span.in_scope(|| error!("trigger failed to start: {}", e));
+            health.set_trigger(index, TriggerStatus::Failed { error: e.to_string() });
           }
         }
         Ok(())
@@ -131,11 +328,26 @@ impl AppHost {
     self.span.in_scope(|| debug!("all triggers started"));
     let mut all_output = Vec::new();
     for trigger in &triggers {
-      let ctrl_c = async {
+      let lease = &mut self.lease;
+      let handles_signals = trigger.handles_signals();
+      let ctrl_c = async move {
+        if handles_signals {
+          futures::future::pending::<()>().await;
+        }
         let _ = tokio::signal::ctrl_c().await;
       };
-      pin_mut!(ctrl_c);
-      match select(ctrl_c, trigger.wait_for_done()).await {
+      let lost = async move {
+        match lease {
+          Some(lease) => lease.lost().await,
+          None => futures::future::pending().await,
+        }
+      };
+      let interrupted = select(Box::pin(ctrl_c), Box::pin(lost));
+      match select(interrupted, trigger.wait_for_done()).await {
+        futures::future::Either::Left((futures::future::Either::Right(_), _)) => {
+          self.span.in_scope(|| error!("failover lease lost, stopping triggers"));
+          return Err(HostError::LeaseLost);
+        }
         futures::future::Either::Left(_) => {
           self.span.in_scope(|| debug!("ctrl-c received, stopping triggers"));
           break;
@@ -152,6 +364,21 @@ impl AppHost {
   }
 }
 
+/// Create the app's resources. Secrets are read from the backends configured in the environment and kept up to date
+/// while the resources are held.
+async fn init_resources(manifest: &AppConfiguration) -> Result<HashMap<BoundIdentifier, Resource>> {
+  let backends = SecretsBackends::from_env();
+  let mut resources = HashMap::new();
+  for def in manifest.resources() {
+    let resource = match def.kind() {
+      ResourceDefinition::Secret(secret) => Resource::Secret(backends.watch(secret).await?),
+      kind => Resource::new(kind.clone())?,
+    };
+    resources.insert(def.binding().clone(), resource);
+  }
+  Ok(resources)
+}
+
 #[async_trait::async_trait]
 impl crate::Host for AppHost {
   fn namespace(&self) -> &str {
@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use flow_graph_interpreter::error::{InterpreterError, ValidationError};
+use flow_graph_interpreter::graph::GraphError;
+use seeded_random::Seed;
+use serde::Serialize;
+use tracing::Span;
+use wick_config::span::{SourceSpan, SourceText};
+use wick_config::{FetchOptions, WickConfiguration};
+use wick_packet::RuntimeConfig;
+use wick_runtime::error::RuntimeError;
+use wick_runtime::{RuntimeBuilder, ScopeError};
+
+use crate::error::HostError;
+use crate::AppHost;
+
+/// A problem found while checking a configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct Diagnostic {
+  pub message: String,
+  /// The flow the problem was found in, if it was found in one.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub flow: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub location: Option<Location>,
+}
+
+/// Where in a manifest a [Diagnostic] points to. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct Location {
+  pub path: PathBuf,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub line: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub column: Option<usize>,
+  /// The text of the line pointed to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub snippet: Option<String>,
+}
+
+impl Location {
+  fn new(path: &Path, span: Option<&SourceSpan>) -> Self {
+    Self {
+      path: path.to_path_buf(),
+      line: span.map(SourceSpan::line),
+      column: span.map(SourceSpan::column),
+      snippet: span.map(|span| span.snippet().to_owned()),
+    }
+  }
+
+  /// The line pointed to with a caret under the column, when both are known.
+  #[must_use]
+  pub fn excerpt(&self) -> Option<String> {
+    let (line, snippet) = self.line.zip(self.snippet.as_ref())?;
+    let gutter = " ".repeat(line.to_string().len());
+    let caret = self.column.map_or_else(String::new, |column| {
+      format!("{}^", " ".repeat(column.saturating_sub(1)))
+    });
+    Some(format!("{} | {}\n{} | {}", line, snippet, gutter, caret))
+  }
+}
+
+impl std::fmt::Display for Location {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.path.display())?;
+    if let Some(line) = self.line {
+      write!(f, ":{}", line)?;
+      if let Some(column) = self.column {
+        write!(f, ":{}", column)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+impl std::fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some(location) = &self.location {
+      write!(f, "{}: ", location)?;
+    }
+    if let Some(flow) = &self.flow {
+      write!(f, "flow '{}': ", flow)?;
+    }
+    write!(f, "{}", self.message)
+  }
+}
+
+impl Diagnostic {
+  fn new<T: std::fmt::Display>(message: T, flow: Option<&str>, location: Option<Location>) -> Self {
+    Self {
+      message: message.to_string(),
+      flow: flow.map(ToOwned::to_owned),
+      location,
+    }
+  }
+}
+
+/// Load the configuration at `path` and everything it imports, then build its runtime without starting any triggers
+/// and return every problem found along the way.
+///
+/// Building the runtime validates each flow and checks the types of the connections between operations, so a
+/// configuration without diagnostics is one that [AppHost] or [crate::ComponentHost] would start.
+pub async fn check(
+  path: &str,
+  options: FetchOptions,
+  root_config: Option<RuntimeConfig>,
+  env: HashMap<String, String>,
+  seed: Option<u64>,
+  span: Span,
+) -> Vec<Diagnostic> {
+  let tree = match WickConfiguration::fetch_tree(path, root_config, Some(env), options.clone()).await {
+    Ok(tree) => tree,
+    Err(e) => return vec![config_diagnostic(&e)],
+  };
+
+  let result = match tree.element {
+    WickConfiguration::Component(config) => {
+      span.in_scope(|| debug!("checking component"));
+      RuntimeBuilder::from_definition(config)
+        .span(span)
+        .build(seed.map(Seed::unsafe_new))
+        .await
+        .map_err(HostError::from)
+        .map(|_| ())
+    }
+    WickConfiguration::App(mut config) => {
+      span.in_scope(|| debug!("checking application"));
+      config.set_options(options);
+      AppHost::build_runtime(&config, seed, span).await.map(|_| ())
+    }
+    // types, tests, and lockdown configurations have nothing to build, loading them is the whole check.
+    _ => Ok(()),
+  };
+
+  result.map_or_else(|e| host_diagnostics(&e), |()| Vec::new())
+}
+
+fn host_diagnostics(error: &HostError) -> Vec<Diagnostic> {
+  match error {
+    HostError::RuntimeError(e) => match e.as_ref() {
+      RuntimeError::Scope(e) => scope_diagnostics(e),
+      e => vec![Diagnostic::new(e, None, None)],
+    },
+    e => vec![Diagnostic::new(e, None, None)],
+  }
+}
+
+fn scope_diagnostics(error: &ScopeError) -> Vec<Diagnostic> {
+  match error {
+    ScopeError::InterpreterInit(path, e) => {
+      let source = Source::read(path.as_deref());
+      match e.as_ref() {
+        InterpreterError::ValidationError(invalid) => {
+          let mut diagnostics = Vec::new();
+          for op in invalid {
+            for e in op.errors() {
+              let location = source.locate(Some(op.schematic()), &needles(e));
+              diagnostics.push(Diagnostic::new(e, Some(op.schematic()), location));
+            }
+          }
+          diagnostics
+        }
+        InterpreterError::EarlyError(e) => vec![Diagnostic::new(e, None, source.locate(None, &needles(e)))],
+        e => vec![Diagnostic::new(e, None, source.locate(None, &[]))],
+      }
+    }
+    ScopeError::Graph(path, e) => {
+      let source = Source::read(path.as_deref());
+      vec![Diagnostic::new(e, None, source.locate(None, &graph_needles(e)))]
+    }
+    ScopeError::RuntimeInit(path, e) => vec![Diagnostic::new(
+      e,
+      None,
+      Source::read(path.as_deref()).locate(None, &[]),
+    )],
+    ScopeError::Manifest(e) => vec![config_diagnostic(e)],
+    e => vec![Diagnostic::new(e, None, None)],
+  }
+}
+
+fn config_diagnostic(error: &wick_config::Error) -> Diagnostic {
+  match error {
+    wick_config::Error::YamlError(Some(path), message, span) => {
+      Diagnostic::new(message, None, Some(Location::new(path, span.as_ref())))
+    }
+    wick_config::Error::Spanned(e, span) => {
+      Diagnostic::new(e, None, span.path().map(|path| Location::new(path, Some(span))))
+    }
+    e => Diagnostic::new(e, None, None),
+  }
+}
+
+/// What to look for in a manifest to find the line a validation error is about, most specific first.
+fn needles(error: &ValidationError) -> Vec<String> {
+  match error {
+    ValidationError::InvalidPort { port, id, .. }
+    | ValidationError::MissingConnection { port, id, .. }
+    | ValidationError::MissingPort { port, id, .. }
+    | ValidationError::UnknownInput { port, id, .. }
+    | ValidationError::UnknownOutput { port, id, .. }
+    | ValidationError::UnusedOutput { port, id, .. } => vec![format!("{}.{}", id, port), id.clone()],
+    ValidationError::MissingOperation { component, name } => vec![format!("{}::{}", component, name)],
+    ValidationError::MissingComponent(component) | ValidationError::ComponentIdNotFound(component) => {
+      vec![format!("{}::", component)]
+    }
+    ValidationError::MissingFlow { id, flow } => vec![id.clone(), flow.clone()],
+    ValidationError::InvalidDefault { port, .. } => vec![format!("name: {}", port)],
+    // the downstream side of a connection is the one that can't accept what it's sent.
+    ValidationError::TypeMismatch { connection, .. } => connection.rsplit(" -> ").map(ToOwned::to_owned).collect(),
+    _ => Vec::new(),
+  }
+}
+
+fn graph_needles(error: &GraphError) -> Vec<String> {
+  match error {
+    GraphError::MissingOperation {
+      component, operation, ..
+    } => vec![format!("{}::{}", component, operation)],
+    GraphError::NodeNotFound(node) => vec![node.clone()],
+    GraphError::PortInferenceDown { from, .. } => vec![from.clone()],
+    GraphError::PortInferenceUp { to, .. } => vec![to.clone()],
+    GraphError::MergeConflict { port, .. } => vec![port.clone()],
+    GraphError::Config(id, _) | GraphError::CoreOperation(id, _) => vec![id.clone()],
+    _ => Vec::new(),
+  }
+}
+
+/// The text of a manifest, for finding the lines diagnostics point to.
+struct Source<'a> {
+  path: Option<&'a Path>,
+  text: Option<SourceText>,
+}
+
+impl<'a> Source<'a> {
+  fn read(path: Option<&'a Path>) -> Self {
+    Self {
+      path,
+      text: path.and_then(SourceText::read),
+    }
+  }
+
+  /// Find the first of `needles` in the definition of `flow`, or in the whole manifest when there's no flow. Falls back
+  /// to the line the flow is named on, and then to the manifest itself.
+  fn locate(&self, flow: Option<&str>, needles: &[String]) -> Option<Location> {
+    let span = self.text.as_ref().and_then(|text| text.locate(flow, needles));
+    Some(Location::new(self.path?, span.as_ref()))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const MANIFEST: &str = r#"kind: wick/component@v1
+name: test
+component:
+  kind: wick/component/composite@v1
+  operations:
+    - name: "count"
+      flow:
+        - <>.input -> test::count.input
+    - name: greet
+      flow:
+        - <>.input -> COUNT.input
+        - COUNT.output -> GREET.input
+"#;
+
+  fn source(text: &str) -> Source<'static> {
+    Source {
+      path: Some(Path::new("component.wick")),
+      text: Some(SourceText::new(text.to_owned(), Some(PathBuf::from("component.wick")))),
+    }
+  }
+
+  fn at(line: usize, column: usize) -> Option<Location> {
+    Some(Location {
+      path: PathBuf::from("component.wick"),
+      line: Some(line),
+      column: Some(column),
+      snippet: MANIFEST.lines().nth(line - 1).map(ToOwned::to_owned),
+    })
+  }
+
+  #[test]
+  fn test_locate() {
+    let source = source(MANIFEST);
+    let mismatch = ValidationError::TypeMismatch {
+      connection: "COUNT.output -> GREET.input".to_owned(),
+      from: "u32".to_owned(),
+      to: "string".to_owned(),
+    };
+
+    assert_eq!(source.locate(Some("greet"), &needles(&mismatch)), at(12, 27));
+    // `<>.input` appears in both flows, only the one in `greet` is the one it's about.
+    assert_eq!(source.locate(Some("greet"), &["<>.input".to_owned()]), at(11, 11));
+    assert_eq!(source.locate(Some("count"), &[]), at(6, 5));
+    assert_eq!(source.locate(None, &["test::count".to_owned()]), at(8, 23));
+    assert_eq!(
+      source.locate(Some("missing"), &["nothing".to_owned()]),
+      Some(Location {
+        path: PathBuf::from("component.wick"),
+        line: None,
+        column: None,
+        snippet: None,
+      })
+    );
+  }
+
+  #[test]
+  fn test_display() {
+    let diagnostic = Diagnostic::new("Unused sender: A", Some("greet"), at(3, 5));
+    assert_eq!(
+      diagnostic.to_string(),
+      "component.wick:3:5: flow 'greet': Unused sender: A"
+    );
+    assert_eq!(Diagnostic::new("oops", None, None).to_string(), "oops");
+    assert_eq!(
+      at(12, 27).and_then(|l| l.excerpt()).unwrap(),
+      "12 |         - COUNT.output -> GREET.input\n   |                           ^"
+    );
+  }
+}
@@ -1,11 +1,8 @@
-use std::collections::HashMap;
-use std::net::SocketAddr;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 
-use flow_component::SharedComponent;
-use once_cell::sync::Lazy;
-use parking_lot::Mutex;
 use seeded_random::Seed;
+use tokio::task::JoinHandle;
 use tracing::Span;
 use uuid::Uuid;
 use wick_component_cli::options::{Options as HostOptions, ServerOptionsBuilder};
@@ -14,20 +11,12 @@ use wick_config::config::ComponentConfiguration;
 use wick_config::WickConfiguration;
 use wick_interface_types::ComponentSignature;
 use wick_packet::{Entity, Invocation, PacketStream, RuntimeConfig};
-use wick_runtime::{Runtime, RuntimeBuilder, ScopeComponent};
+use wick_runtime::metrics::AlertEvaluator;
+use wick_runtime::{Runtime, RuntimeBuilder};
 
 use crate::error::HostError;
 use crate::{Error, Result};
 
-type ServiceMap = HashMap<Uuid, SharedComponent>;
-static HOST_REGISTRY: Lazy<Mutex<ServiceMap>> = Lazy::new(|| Mutex::new(HashMap::new()));
-
-fn from_registry(id: Uuid) -> SharedComponent {
-  let mut registry = HOST_REGISTRY.lock();
-  let collection = registry.entry(id).or_insert_with(|| Arc::new(ScopeComponent::new(id)));
-  collection.clone()
-}
-
 /// A Wick Host wraps a Wick runtime with server functionality like persistence,.
 #[must_use]
 #[derive(Debug, derive_builder::Builder)]
@@ -41,6 +30,10 @@ pub struct ComponentHost {
   manifest: ComponentConfiguration,
   #[builder(default, setter(strip_option))]
   server_metadata: Option<ServerState>,
+  #[builder(setter(skip))]
+  metrics_server: Option<(SocketAddr, JoinHandle<()>)>,
+  #[builder(setter(skip))]
+  alerts: Option<JoinHandle<()>>,
   #[builder(default = "tracing::Span::current()")]
   span: Span,
 }
@@ -56,6 +49,7 @@ impl ComponentHost {
     self.start_runtime(seed.map(Seed::unsafe_new)).await?;
     self.span.in_scope(|| trace!("starting servers"));
     let state = self.start_servers().await?;
+    self.start_metrics()?;
     self.span.in_scope(|| trace!("host started"));
     self.server_metadata = Some(state);
 
@@ -71,6 +65,12 @@ impl ComponentHost {
       .and_then(|state| state.rpc.as_ref().map(|rpc| rpc.addr))
   }
 
+  /// Get the address the host's metrics endpoint is bound to.
+  #[must_use]
+  pub fn metrics_address(&self) -> Option<SocketAddr> {
+    self.metrics_server.as_ref().map(|(addr, _)| *addr)
+  }
+
   #[must_use]
   pub const fn get_server_info(&self) -> &Option<ServerState> {
     &self.server_metadata
@@ -79,6 +79,12 @@ impl ComponentHost {
   /// Stops a running host.
   pub async fn stop(self) {
     self.span.in_scope(|| debug!("host stopping"));
+    if let Some((_, handle)) = self.metrics_server {
+      handle.abort();
+    }
+    if let Some(handle) = self.alerts {
+      handle.abort();
+    }
     if let Some(runtime) = self.runtime {
       let _ = runtime.shutdown().await;
     }
@@ -94,6 +100,7 @@ impl ComponentHost {
 
   pub async fn start_runtime(&mut self, seed: Option<Seed>) -> Result<()> {
     ensure!(self.runtime.is_none(), crate::Error::AlreadyRunning);
+    self.start_otlp();
 
     let mut rt_builder = RuntimeBuilder::from_definition(self.manifest.clone());
     let span = info_span!(parent: &self.span, "component_host");
@@ -113,8 +120,6 @@ impl ComponentHost {
   }
 
   async fn start_servers(&mut self) -> Result<ServerState> {
-    let nuid = self.get_runtime_uid()?;
-
     let host_config = self.manifest.host().cloned().unwrap_or_default();
 
     #[allow(clippy::manual_map)]
@@ -133,7 +138,7 @@ impl ComponentHost {
       }),
     );
 
-    let collection = from_registry(nuid);
+    let collection = Arc::new(self.get_runtime()?.to_component());
 
     let metadata = tokio::spawn(wick_component_cli::start_server(collection, Some(options)))
       .await
@@ -143,6 +148,40 @@ impl ComponentHost {
     Ok(metadata)
   }
 
+  /// Export spans to the manifest's OTLP collector unless the logger was configured with one already.
+  fn start_otlp(&self) {
+    let Some(config) = self.manifest.host().and_then(|h| h.otlp()) else {
+      return;
+    };
+    let endpoint = config.endpoint();
+    let _guard = self.span.enter();
+    match wick_logger::install_otlp(endpoint) {
+      Ok(true) => info!(endpoint, "exporting traces over OTLP"),
+      Ok(false) => debug!(endpoint, "OTLP export already configured, ignoring manifest"),
+      Err(error) => warn!(%error, "could not start OTLP export"),
+    }
+  }
+
+  fn start_metrics(&mut self) -> Result<()> {
+    let Some(config) = self.manifest.host().and_then(|h| h.metrics()) else {
+      return Ok(());
+    };
+    if !config.alerts().is_empty() {
+      let evaluator = AlertEvaluator::new(config.alerts().to_vec());
+      let runtime = self.get_runtime()?.clone();
+      self.alerts = Some(tokio::spawn(evaluator.watch(runtime, self.span.clone())));
+    }
+    if !config.enabled() {
+      return Ok(());
+    }
+    let runtime = self.get_runtime()?;
+    let address = config.address().copied().unwrap_or(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(IpAddr::V4(address), config.port().unwrap_or(0));
+    let _guard = self.span.enter();
+    self.metrics_server = Some(crate::admin::serve_metrics(addr, runtime.metrics().clone())?);
+    Ok(())
+  }
+
   pub async fn wait_for_sigint(&self) -> Result<()> {
     tokio::signal::ctrl_c().await.unwrap();
     self.span.in_scope(|| debug!("SIGINT received"));
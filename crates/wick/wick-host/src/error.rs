@@ -18,6 +18,21 @@ pub enum HostError {
   #[error(transparent)]
   Resource(#[from] wick_trigger::resources::ResourceError),
 
+  #[error(transparent)]
+  Secrets(#[from] wick_runtime::secrets::SecretsError),
+
+  #[error(transparent)]
+  Failover(#[from] wick_runtime::failover::FailoverError),
+
+  #[error("This instance lost the failover lease, another instance is running the app's triggers")]
+  LeaseLost,
+
+  #[error("Could not start the admin listener: {0}")]
+  Admin(String),
+
+  #[error("Could not reload the app: {0}")]
+  Reload(String),
+
   #[error("General error : {0}")]
   Other(String),
 }
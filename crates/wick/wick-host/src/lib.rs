@@ -120,11 +120,14 @@ pub(crate) mod macros;
 
 pub mod triggers;
 
+pub mod admin;
 mod app_host;
+pub mod check;
 pub mod collection;
 mod component_host;
 mod error;
 mod traits;
+pub mod watch;
 pub use app_host::{AppHost, AppHostBuilder, TriggerState};
 pub use collection::HostComponent;
 pub use component_host::{ComponentHost, ComponentHostBuilder};
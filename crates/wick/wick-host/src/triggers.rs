@@ -7,8 +7,11 @@ use crate::error::HostError;
 
 pub fn load_trigger(name: &TriggerKind) -> Result<Arc<dyn Trigger + Send + Sync>, HostError> {
   match name {
+    TriggerKind::Broker => Ok(Arc::new(wick_trigger_broker::Broker::default())),
     TriggerKind::Cli => Ok(Arc::new(wick_trigger_cli::Cli::default())),
+    TriggerKind::Grpc => Ok(Arc::new(wick_trigger_grpc::Grpc::default())),
     TriggerKind::Http => Ok(Arc::new(wick_trigger_http::Http::default())),
+    TriggerKind::Log => Ok(Arc::new(wick_trigger_log::Log::default())),
     TriggerKind::Time => Ok(Arc::new(wick_trigger_time::Time::default())),
     TriggerKind::WasmCommand => Ok(Arc::new(wick_trigger_wasm_command::WasmTrigger::default())),
   }
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How often watched files are checked for changes unless configured otherwise.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches configuration files for changes by polling their modification times.
+#[derive(Debug)]
+#[must_use]
+pub struct FileWatcher {
+  files: HashMap<PathBuf, Option<SystemTime>>,
+  interval: Duration,
+}
+
+impl FileWatcher {
+  /// Start watching `paths`, comparing against their current modification times.
+  pub fn new(paths: impl IntoIterator<Item = PathBuf>, interval: Duration) -> Self {
+    let files = paths.into_iter().map(|p| (p.clone(), modified(&p))).collect();
+    Self { files, interval }
+  }
+
+  /// Replace the watched files, e.g. after a reload changed the app's imports.
+  pub fn set_paths(&mut self, paths: impl IntoIterator<Item = PathBuf>) {
+    self.files = paths.into_iter().map(|p| (p.clone(), modified(&p))).collect();
+  }
+
+  /// Wait until at least one watched file changes, returning the files that changed.
+  ///
+  /// Saves that touch several files in quick succession are reported together.
+  pub async fn changed(&mut self) -> Vec<PathBuf> {
+    loop {
+      tokio::time::sleep(self.interval).await;
+      let changed = self.poll();
+      if !changed.is_empty() {
+        // let editors finish writing before the caller reads the files.
+        tokio::time::sleep(self.interval).await;
+        let mut changed = changed;
+        changed.extend(self.poll());
+        return changed;
+      }
+    }
+  }
+
+  fn poll(&mut self) -> Vec<PathBuf> {
+    let mut changed = Vec::new();
+    for (path, last) in &mut self.files {
+      let current = modified(path);
+      if current != *last {
+        *last = current;
+        changed.push(path.clone());
+      }
+    }
+    changed
+  }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+  std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_detects_changes() -> Result<()> {
+    let nanos = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?.as_nanos();
+    let dir = std::env::temp_dir().join(format!("wick-watch-{}", nanos));
+    std::fs::create_dir_all(&dir)?;
+    let file = dir.join("app.wick");
+    std::fs::write(&file, "name: one")?;
+
+    let mut watcher = FileWatcher::new(vec![file.clone()], Duration::from_millis(10));
+    assert!(watcher.poll().is_empty());
+
+    // removing the file changes its modification time without depending on the filesystem's mtime resolution.
+    std::fs::remove_file(&file)?;
+    let changed = tokio::time::timeout(Duration::from_secs(5), watcher.changed()).await?;
+    assert_eq!(changed, vec![file]);
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+}
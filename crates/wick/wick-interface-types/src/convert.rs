@@ -0,0 +1,34 @@
+//! Conversions between Wick type definitions and external schema formats.
+
+#[cfg(feature = "json-schema")]
+mod json_schema;
+#[cfg(feature = "protobuf")]
+mod protobuf;
+
+#[cfg(feature = "json-schema")]
+pub use json_schema::{from_json_schema, to_json_schema};
+#[cfg(feature = "protobuf")]
+pub use protobuf::{from_encoded_file_descriptor_set, from_file_descriptor_set, to_file_descriptor};
+
+/// Errors that can occur when converting type definitions to or from another schema format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConvertError {
+  /// The schema document was not in the expected shape.
+  InvalidSchema(String),
+  /// A reference pointed to a definition that does not exist or can not be resolved.
+  UnresolvedReference(String),
+  /// A type has no representation in the target format.
+  Unsupported(String),
+}
+
+impl std::error::Error for ConvertError {}
+impl std::fmt::Display for ConvertError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ConvertError::InvalidSchema(v) => write!(f, "Invalid schema: {}", v),
+      ConvertError::UnresolvedReference(v) => write!(f, "Could not resolve reference {}", v),
+      ConvertError::Unsupported(v) => write!(f, "{} can not be represented in the target format", v),
+    }
+  }
+}
@@ -0,0 +1,437 @@
+//! Conversions between Wick type definitions and JSON Schema documents.
+//!
+//! Named definitions live under `$defs` (or the older `definitions`). Objects with `properties` become structs,
+//! string `enum`s become enums, and `oneOf`/`anyOf` become unions. Definitions that are plain aliases of another
+//! type (e.g. a `string` with a `pattern`) have no Wick equivalent and are inlined wherever they are referenced.
+
+use serde_json::{json, Map, Value};
+
+use super::ConvertError;
+use crate::{EnumDefinition, EnumVariant, Field, StructDefinition, Type, TypeDefinition, UnionDefinition};
+
+const DEFINITION_KEYS: [&str; 2] = ["$defs", "definitions"];
+
+/// Import the named type definitions from a JSON Schema document.
+///
+/// Definitions are read from `$defs` and `definitions`. If the root schema has a `title`, it is imported as a type
+/// of that name as well.
+pub fn from_json_schema(schema: &Value) -> Result<Vec<TypeDefinition>, ConvertError> {
+  let root = schema
+    .as_object()
+    .ok_or_else(|| ConvertError::InvalidSchema("expected the schema to be an object".to_owned()))?;
+
+  let mut types = Vec::new();
+  for key in DEFINITION_KEYS {
+    let Some(definitions) = root.get(key) else {
+      continue;
+    };
+    let definitions = definitions
+      .as_object()
+      .ok_or_else(|| ConvertError::InvalidSchema(format!("expected {} to be an object", key)))?;
+    for (name, definition) in definitions {
+      if let Some(def) = to_definition(schema, name, definition)? {
+        types.push(def);
+      }
+    }
+  }
+  if let Some(title) = root.get("title").and_then(Value::as_str) {
+    if let Some(def) = to_definition(schema, title, schema)? {
+      types.push(def);
+    }
+  }
+  Ok(types)
+}
+
+/// Export type definitions as a JSON Schema document with each type under `$defs`.
+#[must_use]
+pub fn to_json_schema(types: &[TypeDefinition]) -> Value {
+  let definitions: Map<String, Value> = types
+    .iter()
+    .map(|def| (def.name().to_owned(), definition_schema(def)))
+    .collect();
+  json!({
+    "$schema": "https://json-schema.org/draft/2020-12/schema",
+    "$defs": definitions,
+  })
+}
+
+fn description(schema: &Value) -> Option<String> {
+  schema.get("description").and_then(Value::as_str).map(ToOwned::to_owned)
+}
+
+fn variants(schema: &Value) -> Option<&Vec<Value>> {
+  schema
+    .get("oneOf")
+    .or_else(|| schema.get("anyOf"))
+    .and_then(Value::as_array)
+}
+
+fn is_null(schema: &Value) -> bool {
+  schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+/// A `oneOf`/`anyOf` between a single type and `null` is how optional values are usually expressed.
+fn nullable_variant(schema: &Value) -> Option<&Value> {
+  match variants(schema)?.as_slice() {
+    [a, b] if is_null(b) => Some(a),
+    [a, b] if is_null(a) => Some(b),
+    _ => None,
+  }
+}
+
+/// Whether a schema describes something that becomes a named Wick type rather than an alias.
+fn is_named(schema: &Value) -> bool {
+  schema.get("enum").is_some()
+    || schema.get("properties").is_some()
+    || (variants(schema).is_some() && nullable_variant(schema).is_none())
+}
+
+fn to_definition(root: &Value, name: &str, schema: &Value) -> Result<Option<TypeDefinition>, ConvertError> {
+  if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+    let variants = values
+      .iter()
+      .enumerate()
+      .map(|(i, value)| {
+        value
+          .as_str()
+          .map(|value| EnumVariant::new(value, Some(i as u32), Some(value.to_owned()), None))
+          .ok_or_else(|| ConvertError::Unsupported(format!("enum {} with non-string values", name)))
+      })
+      .collect::<Result<_, _>>()?;
+    return Ok(Some(TypeDefinition::Enum(EnumDefinition::new(
+      name,
+      variants,
+      description(schema),
+    ))));
+  }
+  if schema.get("properties").is_some() {
+    return Ok(Some(TypeDefinition::Struct(StructDefinition::new(
+      name,
+      to_fields(root, schema)?,
+      description(schema),
+    ))));
+  }
+  if let (Some(variants), None) = (variants(schema), nullable_variant(schema)) {
+    let types = variants
+      .iter()
+      .map(|variant| to_type(root, variant))
+      .collect::<Result<_, _>>()?;
+    return Ok(Some(TypeDefinition::Union(UnionDefinition::new(
+      name,
+      types,
+      description(schema),
+    ))));
+  }
+  Ok(None)
+}
+
+fn to_fields(root: &Value, schema: &Value) -> Result<Vec<Field>, ConvertError> {
+  let required: Vec<&str> = schema
+    .get("required")
+    .and_then(Value::as_array)
+    .map(|names| names.iter().filter_map(Value::as_str).collect())
+    .unwrap_or_default();
+  let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+    return Ok(Vec::new());
+  };
+  properties
+    .iter()
+    .map(|(name, property)| {
+      let mut ty = to_type(root, property)?;
+      if !required.contains(&name.as_str()) && !matches!(ty, Type::Optional { .. }) {
+        ty = Type::Optional { ty: Box::new(ty) };
+      }
+      Ok(Field::new_with_description(name, ty, description(property)))
+    })
+    .collect()
+}
+
+fn resolve<'a>(root: &'a Value, reference: &str) -> Result<(&'a str, &'a Value), ConvertError> {
+  DEFINITION_KEYS
+    .iter()
+    .find_map(|key| {
+      let name = reference.strip_prefix(&format!("#/{}/", key))?;
+      root.get(key)?.as_object()?.get_key_value(name)
+    })
+    .map(|(name, schema)| (name.as_str(), schema))
+    .ok_or_else(|| ConvertError::UnresolvedReference(reference.to_owned()))
+}
+
+fn to_type(root: &Value, schema: &Value) -> Result<Type, ConvertError> {
+  if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+    let (name, target) = resolve(root, reference)?;
+    return if is_named(target) {
+      Ok(Type::Named(name.to_owned()))
+    } else {
+      to_type(root, target)
+    };
+  }
+  if let Some(variant) = nullable_variant(schema) {
+    return Ok(Type::Optional {
+      ty: Box::new(to_type(root, variant)?),
+    });
+  }
+
+  let (ty, nullable) = match schema.get("type") {
+    Some(Value::String(ty)) => (Some(ty.as_str()), false),
+    Some(Value::Array(types)) => {
+      let types: Vec<&str> = types.iter().filter_map(Value::as_str).collect();
+      let nullable = types.contains(&"null");
+      match types.iter().filter(|ty| **ty != "null").collect::<Vec<_>>().as_slice() {
+        [ty] => (Some(**ty), nullable),
+        _ => (None, nullable),
+      }
+    }
+    _ => (None, false),
+  };
+  let format = schema.get("format").and_then(Value::as_str);
+
+  let ty = match ty {
+    Some("boolean") => Type::Bool,
+    Some("string") => match format {
+      Some("date-time") => Type::Datetime,
+      Some("byte" | "binary") => Type::Bytes,
+      _ => Type::String,
+    },
+    Some("integer") => match format {
+      Some("int8") => Type::I8,
+      Some("int16") => Type::I16,
+      Some("int32") => Type::I32,
+      Some("uint8") => Type::U8,
+      Some("uint16") => Type::U16,
+      Some("uint32") => Type::U32,
+      Some("uint64") => Type::U64,
+      _ => Type::I64,
+    },
+    Some("number") => match format {
+      Some("float") => Type::F32,
+      _ => Type::F64,
+    },
+    Some("array") => Type::List {
+      ty: Box::new(schema.get("items").map_or(Ok(Type::Object), |items| to_type(root, items))?),
+    },
+    Some("object") | None if schema.get("properties").is_some() => Type::AnonymousStruct(to_fields(root, schema)?),
+    Some("object") => match schema.get("additionalProperties") {
+      Some(value @ Value::Object(_)) => Type::Map {
+        key: Box::new(Type::String),
+        value: Box::new(to_type(root, value)?),
+      },
+      _ => Type::Object,
+    },
+    _ => Type::Object,
+  };
+
+  Ok(if nullable {
+    Type::Optional { ty: Box::new(ty) }
+  } else {
+    ty
+  })
+}
+
+fn definition_schema(def: &TypeDefinition) -> Value {
+  let mut schema = match def {
+    TypeDefinition::Struct(def) => object_schema(&def.fields),
+    TypeDefinition::Enum(def) => {
+      let values: Vec<_> = def
+        .variants
+        .iter()
+        .map(|v| v.value.as_ref().unwrap_or(&v.name))
+        .collect();
+      json!({ "type": "string", "enum": values })
+    }
+    TypeDefinition::Union(def) => {
+      let variants: Vec<_> = def.types.iter().map(type_schema).collect();
+      json!({ "oneOf": variants })
+    }
+  };
+  let description = match def {
+    TypeDefinition::Struct(def) => def.description.as_ref(),
+    TypeDefinition::Enum(def) => def.description.as_ref(),
+    TypeDefinition::Union(def) => def.description.as_ref(),
+  };
+  if let (Some(description), Value::Object(schema)) = (description, &mut schema) {
+    schema.insert("description".to_owned(), Value::String(description.clone()));
+  }
+  schema
+}
+
+fn object_schema(fields: &[Field]) -> Value {
+  let properties: Map<String, Value> = fields
+    .iter()
+    .map(|field| {
+      let mut schema = type_schema(&field.ty);
+      if let (Some(description), Value::Object(schema)) = (&field.description, &mut schema) {
+        schema.insert("description".to_owned(), Value::String(description.clone()));
+      }
+      (field.name.clone(), schema)
+    })
+    .collect();
+  let required: Vec<_> = fields
+    .iter()
+    .filter(|field| !matches!(field.ty, Type::Optional { .. }))
+    .map(|field| field.name.as_str())
+    .collect();
+  json!({
+    "type": "object",
+    "properties": properties,
+    "required": required,
+  })
+}
+
+fn type_schema(ty: &Type) -> Value {
+  match ty {
+    Type::I8 => json!({ "type": "integer", "format": "int8" }),
+    Type::I16 => json!({ "type": "integer", "format": "int16" }),
+    Type::I32 => json!({ "type": "integer", "format": "int32" }),
+    Type::I64 => json!({ "type": "integer", "format": "int64" }),
+    Type::U8 => json!({ "type": "integer", "format": "uint8" }),
+    Type::U16 => json!({ "type": "integer", "format": "uint16" }),
+    Type::U32 => json!({ "type": "integer", "format": "uint32" }),
+    Type::U64 => json!({ "type": "integer", "format": "uint64" }),
+    Type::F32 => json!({ "type": "number", "format": "float" }),
+    Type::F64 => json!({ "type": "number", "format": "double" }),
+    Type::Bool => json!({ "type": "boolean" }),
+    Type::String => json!({ "type": "string" }),
+    Type::Datetime => json!({ "type": "string", "format": "date-time" }),
+    Type::Bytes => json!({ "type": "string", "format": "byte" }),
+    Type::Named(name) => json!({ "$ref": format!("#/$defs/{}", name) }),
+    Type::List { ty } => json!({ "type": "array", "items": type_schema(ty) }),
+    Type::Optional { ty } => json!({ "oneOf": [type_schema(ty), { "type": "null" }] }),
+    // JSON object keys are always strings, so the key type can not be expressed.
+    Type::Map { value, .. } => json!({ "type": "object", "additionalProperties": type_schema(value) }),
+    Type::AnonymousStruct(fields) => object_schema(fields),
+    #[allow(deprecated)]
+    Type::Link { .. } | Type::Object => json!({ "type": "object" }),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn optional(ty: Type) -> Type {
+    Type::Optional { ty: Box::new(ty) }
+  }
+
+  #[test]
+  fn test_import() -> Result<()> {
+    let schema = json!({
+      "title": "Order",
+      "type": "object",
+      "required": ["id", "items"],
+      "properties": {
+        "id": { "$ref": "#/$defs/Id" },
+        "items": { "type": "array", "items": { "$ref": "#/$defs/Item" } },
+        "note": { "type": ["string", "null"] },
+        "status": { "$ref": "#/$defs/Status" },
+        "tags": { "type": "object", "additionalProperties": { "type": "string" } }
+      },
+      "$defs": {
+        "Id": { "type": "string", "pattern": "^[a-z0-9]+$" },
+        "Item": {
+          "type": "object",
+          "description": "A line item",
+          "required": ["sku", "quantity"],
+          "properties": {
+            "sku": { "type": "string" },
+            "quantity": { "type": "integer", "format": "uint32" },
+            "added": { "type": "string", "format": "date-time" }
+          }
+        },
+        "Status": { "enum": ["open", "closed"] }
+      }
+    });
+
+    let types = from_json_schema(&schema)?;
+
+    assert_eq!(
+      types,
+      vec![
+        TypeDefinition::Struct(StructDefinition::new(
+          "Item",
+          vec![
+            Field::new("added", optional(Type::Datetime)),
+            Field::new("quantity", Type::U32),
+            Field::new("sku", Type::String),
+          ],
+          Some("A line item".to_owned()),
+        )),
+        TypeDefinition::Enum(EnumDefinition::new(
+          "Status",
+          vec![
+            EnumVariant::new("open", Some(0), Some("open".to_owned()), None),
+            EnumVariant::new("closed", Some(1), Some("closed".to_owned()), None),
+          ],
+          None,
+        )),
+        TypeDefinition::Struct(StructDefinition::new(
+          "Order",
+          vec![
+            Field::new("id", Type::String),
+            Field::new(
+              "items",
+              Type::List {
+                ty: Box::new(Type::Named("Item".to_owned())),
+              },
+            ),
+            Field::new("note", optional(Type::String)),
+            Field::new("status", optional(Type::Named("Status".to_owned()))),
+            Field::new(
+              "tags",
+              optional(Type::Map {
+                key: Box::new(Type::String),
+                value: Box::new(Type::String),
+              }),
+            ),
+          ],
+          None,
+        )),
+      ]
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_unresolved_reference() {
+    let schema = json!({ "$defs": { "A": { "properties": { "b": { "$ref": "#/$defs/B" } } } } });
+    assert_eq!(
+      from_json_schema(&schema),
+      Err(ConvertError::UnresolvedReference("#/$defs/B".to_owned()))
+    );
+  }
+
+  #[test]
+  fn test_roundtrip() -> Result<()> {
+    let types = vec![
+      TypeDefinition::Struct(StructDefinition::new(
+        "Point",
+        vec![
+          Field::new("label", optional(Type::String)),
+          Field::new("x", Type::F64),
+          Field::new(
+            "y",
+            Type::List {
+              ty: Box::new(Type::I32),
+            },
+          ),
+        ],
+        Some("A point".to_owned()),
+      )),
+      TypeDefinition::Union(UnionDefinition::new(
+        "Shape",
+        vec![Type::Named("Point".to_owned()), Type::String],
+        None,
+      )),
+    ];
+
+    let schema = to_json_schema(&types);
+
+    assert_eq!(from_json_schema(&schema)?, types);
+
+    Ok(())
+  }
+}
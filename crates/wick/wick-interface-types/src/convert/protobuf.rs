@@ -0,0 +1,586 @@
+//! Conversions between Wick type definitions and protobuf descriptors.
+//!
+//! Messages become structs, enums become enums, and nested declarations are flattened by joining their names
+//! (`Outer.Inner` becomes `OuterInner`). Singular message fields, `optional` fields and members of a `oneof` track
+//! presence in protobuf and are imported as optional fields. `google.protobuf.Timestamp` maps to `datetime` and
+//! `google.protobuf.Struct` to `object`.
+
+use std::collections::HashMap;
+
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type as ProtoType};
+use prost_types::{
+  DescriptorProto,
+  EnumDescriptorProto,
+  EnumValueDescriptorProto,
+  FieldDescriptorProto,
+  FileDescriptorProto,
+  FileDescriptorSet,
+  MessageOptions,
+  OneofDescriptorProto,
+};
+
+use super::ConvertError;
+use crate::{EnumDefinition, EnumVariant, Field, StructDefinition, Type, TypeDefinition};
+
+const WELL_KNOWN_PACKAGE: &str = "google.protobuf";
+const TIMESTAMP: &str = ".google.protobuf.Timestamp";
+const STRUCT: &str = ".google.protobuf.Struct";
+
+/// Import the messages and enums declared in a set of protobuf file descriptors.
+///
+/// Declarations from the `google.protobuf` package are not imported; the well-known types that have a Wick
+/// equivalent are mapped onto it wherever they are used.
+pub fn from_file_descriptor_set(set: &FileDescriptorSet) -> Result<Vec<TypeDefinition>, ConvertError> {
+  let mut index = Index::default();
+  for file in &set.file {
+    let prefix = file.package.as_ref().map_or_else(String::new, |p| format!(".{}", p));
+    for message in &file.message_type {
+      index.add_message(&prefix, "", message);
+    }
+    for descriptor in &file.enum_type {
+      index.names.insert(
+        format!("{}.{}", prefix, descriptor.name()),
+        descriptor.name().to_owned(),
+      );
+    }
+  }
+
+  let mut types = Vec::new();
+  for file in &set.file {
+    if file.package() == WELL_KNOWN_PACKAGE {
+      continue;
+    }
+    let proto3 = file.syntax() == "proto3";
+    for message in &file.message_type {
+      import_message(&index, proto3, "", message, &mut types)?;
+    }
+    for descriptor in &file.enum_type {
+      types.push(import_enum("", descriptor)?);
+    }
+  }
+  Ok(types)
+}
+
+/// Import the messages and enums from an encoded `FileDescriptorSet`, like the output of `protoc --descriptor_set_out`.
+pub fn from_encoded_file_descriptor_set(bytes: &[u8]) -> Result<Vec<TypeDefinition>, ConvertError> {
+  let set = FileDescriptorSet::decode(bytes).map_err(|e| ConvertError::InvalidSchema(e.to_string()))?;
+  from_file_descriptor_set(&set)
+}
+
+/// Export type definitions as a proto3 file descriptor in the passed package.
+///
+/// Structs become messages and unions become messages with a single `oneof`. Types without a protobuf equivalent,
+/// like nested lists or anonymous structs, produce a [ConvertError::Unsupported] error.
+pub fn to_file_descriptor(types: &[TypeDefinition], package: &str) -> Result<FileDescriptorProto, ConvertError> {
+  let mut file = FileDescriptorProto {
+    name: Some(format!("{}.proto", package.replace('.', "/"))),
+    package: Some(package.to_owned()),
+    syntax: Some("proto3".to_owned()),
+    ..Default::default()
+  };
+  for def in types {
+    match def {
+      TypeDefinition::Struct(def) => {
+        let mut message = DescriptorProto {
+          name: Some(def.name.clone()),
+          ..Default::default()
+        };
+        for (i, field) in def.fields.iter().enumerate() {
+          let descriptor = export_field(types, package, &mut file, &mut message, &field.name, i, &field.ty)?;
+          message.field.push(descriptor);
+        }
+        file.message_type.push(message);
+      }
+      TypeDefinition::Enum(def) => file.enum_type.push(EnumDescriptorProto {
+        name: Some(def.name.clone()),
+        value: def
+          .variants
+          .iter()
+          .enumerate()
+          .map(|(i, variant)| EnumValueDescriptorProto {
+            name: Some(variant.name.clone()),
+            number: Some(variant.index.unwrap_or(i as u32) as i32),
+            options: None,
+          })
+          .collect(),
+        ..Default::default()
+      }),
+      TypeDefinition::Union(def) => {
+        let mut message = DescriptorProto {
+          name: Some(def.name.clone()),
+          oneof_decl: vec![OneofDescriptorProto {
+            name: Some("value".to_owned()),
+            options: None,
+          }],
+          ..Default::default()
+        };
+        for (i, ty) in def.types.iter().enumerate() {
+          let (proto_type, type_name) = export_singular(types, package, &mut file, ty)?;
+          let mut descriptor = field_descriptor(&variant_name(ty), i, proto_type, type_name);
+          descriptor.oneof_index = Some(0);
+          message.field.push(descriptor);
+        }
+        file.message_type.push(message);
+      }
+    }
+  }
+  Ok(file)
+}
+
+#[derive(Default)]
+struct Index<'a> {
+  /// Fully qualified protobuf names mapped to the names of the types they import as.
+  names: HashMap<String, String>,
+  /// The synthetic entry messages protobuf generates for `map<K, V>` fields.
+  map_entries: HashMap<String, &'a DescriptorProto>,
+}
+
+impl<'a> Index<'a> {
+  fn add_message(&mut self, prefix: &str, parent: &str, message: &'a DescriptorProto) {
+    let full_name = format!("{}.{}", prefix, message.name());
+    let name = format!("{}{}", parent, message.name());
+    for nested in &message.nested_type {
+      self.add_message(&full_name, &name, nested);
+    }
+    for descriptor in &message.enum_type {
+      self.names.insert(
+        format!("{}.{}", full_name, descriptor.name()),
+        format!("{}{}", name, descriptor.name()),
+      );
+    }
+    if message.options.as_ref().map_or(false, MessageOptions::map_entry) {
+      self.map_entries.insert(full_name, message);
+    } else {
+      self.names.insert(full_name, name);
+    }
+  }
+}
+
+fn import_message(
+  index: &Index,
+  proto3: bool,
+  parent: &str,
+  message: &DescriptorProto,
+  types: &mut Vec<TypeDefinition>,
+) -> Result<(), ConvertError> {
+  if message.options.as_ref().map_or(false, MessageOptions::map_entry) {
+    return Ok(());
+  }
+  let name = format!("{}{}", parent, message.name());
+  let fields = message
+    .field
+    .iter()
+    .map(|field| Ok(Field::new(field.name(), import_field(index, proto3, field)?)))
+    .collect::<Result<_, ConvertError>>()?;
+  types.push(TypeDefinition::Struct(StructDefinition::new(&name, fields, None)));
+
+  for nested in &message.nested_type {
+    import_message(index, proto3, &name, nested, types)?;
+  }
+  for descriptor in &message.enum_type {
+    types.push(import_enum(&name, descriptor)?);
+  }
+  Ok(())
+}
+
+fn import_enum(parent: &str, descriptor: &EnumDescriptorProto) -> Result<TypeDefinition, ConvertError> {
+  let name = format!("{}{}", parent, descriptor.name());
+  let variants = descriptor
+    .value
+    .iter()
+    .map(|value| {
+      let index = u32::try_from(value.number())
+        .map_err(|_| ConvertError::Unsupported(format!("negative value for {}.{}", name, value.name())))?;
+      Ok(EnumVariant::new(value.name(), Some(index), None, None))
+    })
+    .collect::<Result<_, ConvertError>>()?;
+  Ok(TypeDefinition::Enum(EnumDefinition::new(name, variants, None)))
+}
+
+fn import_field(index: &Index, proto3: bool, field: &FieldDescriptorProto) -> Result<Type, ConvertError> {
+  if field.label() == Label::Repeated {
+    if let Some(entry) = index.map_entries.get(field.type_name()) {
+      let entry_field =
+        |number| {
+          entry.field.iter().find(|f| f.number() == number).ok_or_else(|| {
+            ConvertError::InvalidSchema(format!("map entry {} is missing field {}", entry.name(), number))
+          })
+        };
+      return Ok(Type::Map {
+        key: Box::new(import_singular(index, entry_field(1)?)?),
+        value: Box::new(import_singular(index, entry_field(2)?)?),
+      });
+    }
+    return Ok(Type::List {
+      ty: Box::new(import_singular(index, field)?),
+    });
+  }
+
+  let ty = import_singular(index, field)?;
+  let has_presence = field.proto3_optional()
+    || field.oneof_index.is_some()
+    || field.r#type() == ProtoType::Message
+    || (!proto3 && field.label() == Label::Optional);
+  Ok(if has_presence {
+    Type::Optional { ty: Box::new(ty) }
+  } else {
+    ty
+  })
+}
+
+fn import_singular(index: &Index, field: &FieldDescriptorProto) -> Result<Type, ConvertError> {
+  Ok(match field.r#type() {
+    ProtoType::Double => Type::F64,
+    ProtoType::Float => Type::F32,
+    ProtoType::Int64 | ProtoType::Sint64 | ProtoType::Sfixed64 => Type::I64,
+    ProtoType::Uint64 | ProtoType::Fixed64 => Type::U64,
+    ProtoType::Int32 | ProtoType::Sint32 | ProtoType::Sfixed32 => Type::I32,
+    ProtoType::Uint32 | ProtoType::Fixed32 => Type::U32,
+    ProtoType::Bool => Type::Bool,
+    ProtoType::String => Type::String,
+    ProtoType::Bytes => Type::Bytes,
+    ProtoType::Group => return Err(ConvertError::Unsupported(format!("group field {}", field.name()))),
+    ProtoType::Message | ProtoType::Enum => match field.type_name() {
+      TIMESTAMP => Type::Datetime,
+      STRUCT => Type::Object,
+      name => Type::Named(
+        index
+          .names
+          .get(name)
+          .cloned()
+          .ok_or_else(|| ConvertError::UnresolvedReference(name.to_owned()))?,
+      ),
+    },
+  })
+}
+
+fn field_descriptor(name: &str, i: usize, ty: ProtoType, type_name: Option<String>) -> FieldDescriptorProto {
+  let mut field = FieldDescriptorProto {
+    name: Some(name.to_owned()),
+    number: Some(i as i32 + 1),
+    type_name,
+    ..Default::default()
+  };
+  field.set_type(ty);
+  field.set_label(Label::Optional);
+  field
+}
+
+fn export_field(
+  types: &[TypeDefinition],
+  package: &str,
+  file: &mut FileDescriptorProto,
+  message: &mut DescriptorProto,
+  name: &str,
+  i: usize,
+  ty: &Type,
+) -> Result<FieldDescriptorProto, ConvertError> {
+  match ty {
+    Type::Optional { ty } => {
+      let (proto_type, type_name) = export_singular(types, package, file, ty)?;
+      let mut field = field_descriptor(name, i, proto_type, type_name);
+      // proto3 `optional` fields are represented as a synthetic oneof containing only that field.
+      message.oneof_decl.push(OneofDescriptorProto {
+        name: Some(format!("_{}", name)),
+        options: None,
+      });
+      field.oneof_index = Some(message.oneof_decl.len() as i32 - 1);
+      field.proto3_optional = Some(true);
+      Ok(field)
+    }
+    Type::List { ty } => {
+      let (proto_type, type_name) = export_singular(types, package, file, ty)?;
+      let mut field = field_descriptor(name, i, proto_type, type_name);
+      field.set_label(Label::Repeated);
+      Ok(field)
+    }
+    Type::Map { key, value } => {
+      let (key_type, key_type_name) = export_singular(types, package, file, key)?;
+      let (value_type, value_type_name) = export_singular(types, package, file, value)?;
+      let entry_name = map_entry_name(name);
+      message.nested_type.push(DescriptorProto {
+        name: Some(entry_name.clone()),
+        field: vec![
+          field_descriptor("key", 0, key_type, key_type_name),
+          field_descriptor("value", 1, value_type, value_type_name),
+        ],
+        options: Some(MessageOptions {
+          map_entry: Some(true),
+          ..Default::default()
+        }),
+        ..Default::default()
+      });
+      let type_name = format!(".{}.{}.{}", package, message.name(), entry_name);
+      let mut field = field_descriptor(name, i, ProtoType::Message, Some(type_name));
+      field.set_label(Label::Repeated);
+      Ok(field)
+    }
+    ty => {
+      let (proto_type, type_name) = export_singular(types, package, file, ty)?;
+      Ok(field_descriptor(name, i, proto_type, type_name))
+    }
+  }
+}
+
+fn export_singular(
+  types: &[TypeDefinition],
+  package: &str,
+  file: &mut FileDescriptorProto,
+  ty: &Type,
+) -> Result<(ProtoType, Option<String>), ConvertError> {
+  let mut well_known = |name: &str, dependency: &str| {
+    if !file.dependency.iter().any(|d| d == dependency) {
+      file.dependency.push(dependency.to_owned());
+    }
+    Ok((ProtoType::Message, Some(name.to_owned())))
+  };
+  Ok(match ty {
+    Type::I8 | Type::I16 | Type::I32 => (ProtoType::Int32, None),
+    Type::I64 => (ProtoType::Int64, None),
+    Type::U8 | Type::U16 | Type::U32 => (ProtoType::Uint32, None),
+    Type::U64 => (ProtoType::Uint64, None),
+    Type::F32 => (ProtoType::Float, None),
+    Type::F64 => (ProtoType::Double, None),
+    Type::Bool => (ProtoType::Bool, None),
+    Type::String => (ProtoType::String, None),
+    Type::Bytes => (ProtoType::Bytes, None),
+    Type::Datetime => return well_known(TIMESTAMP, "google/protobuf/timestamp.proto"),
+    Type::Object => return well_known(STRUCT, "google/protobuf/struct.proto"),
+    Type::Named(name) => {
+      let def = types
+        .iter()
+        .find(|def| def.name() == name)
+        .ok_or_else(|| ConvertError::UnresolvedReference(name.clone()))?;
+      let proto_type = match def {
+        TypeDefinition::Enum(_) => ProtoType::Enum,
+        _ => ProtoType::Message,
+      };
+      (proto_type, Some(format!(".{}.{}", package, name)))
+    }
+    ty => return Err(ConvertError::Unsupported(format!("type {}", ty))),
+  })
+}
+
+/// The name protoc gives the synthetic entry message of a map field.
+fn map_entry_name(field: &str) -> String {
+  let mut name: String = field
+    .split('_')
+    .map(|part| {
+      let mut chars = part.chars();
+      chars
+        .next()
+        .map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+    })
+    .collect();
+  name.push_str("Entry");
+  name
+}
+
+fn variant_name(ty: &Type) -> String {
+  match ty {
+    Type::Named(name) => name.to_lowercase(),
+    ty => ty.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn optional(ty: Type) -> Type {
+    Type::Optional { ty: Box::new(ty) }
+  }
+
+  #[test]
+  #[allow(clippy::too_many_lines)]
+  fn test_import() -> Result<()> {
+    let mut note = field_descriptor("note", 2, ProtoType::String, None);
+    note.proto3_optional = Some(true);
+    note.oneof_index = Some(0);
+    let mut items = field_descriptor("items", 1, ProtoType::Message, Some(".shop.Order.Item".to_owned()));
+    items.set_label(Label::Repeated);
+    let mut counts = field_descriptor(
+      "counts",
+      3,
+      ProtoType::Message,
+      Some(".shop.Order.CountsEntry".to_owned()),
+    );
+    counts.set_label(Label::Repeated);
+
+    let file = FileDescriptorProto {
+      name: Some("shop.proto".to_owned()),
+      package: Some("shop".to_owned()),
+      syntax: Some("proto3".to_owned()),
+      message_type: vec![DescriptorProto {
+        name: Some("Order".to_owned()),
+        field: vec![
+          field_descriptor("id", 0, ProtoType::String, None),
+          items,
+          note,
+          counts,
+          field_descriptor("created", 4, ProtoType::Message, Some(TIMESTAMP.to_owned())),
+          field_descriptor("status", 5, ProtoType::Enum, Some(".shop.Status".to_owned())),
+        ],
+        nested_type: vec![
+          DescriptorProto {
+            name: Some("Item".to_owned()),
+            field: vec![
+              field_descriptor("sku", 0, ProtoType::String, None),
+              field_descriptor("quantity", 1, ProtoType::Uint32, None),
+            ],
+            ..Default::default()
+          },
+          DescriptorProto {
+            name: Some("CountsEntry".to_owned()),
+            field: vec![
+              field_descriptor("key", 0, ProtoType::String, None),
+              field_descriptor("value", 1, ProtoType::Int64, None),
+            ],
+            options: Some(MessageOptions {
+              map_entry: Some(true),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+        ],
+        oneof_decl: vec![OneofDescriptorProto {
+          name: Some("_note".to_owned()),
+          options: None,
+        }],
+        ..Default::default()
+      }],
+      enum_type: vec![EnumDescriptorProto {
+        name: Some("Status".to_owned()),
+        value: vec![
+          EnumValueDescriptorProto {
+            name: Some("OPEN".to_owned()),
+            number: Some(0),
+            options: None,
+          },
+          EnumValueDescriptorProto {
+            name: Some("CLOSED".to_owned()),
+            number: Some(1),
+            options: None,
+          },
+        ],
+        ..Default::default()
+      }],
+      ..Default::default()
+    };
+
+    let types = from_file_descriptor_set(&FileDescriptorSet { file: vec![file] })?;
+
+    assert_eq!(
+      types,
+      vec![
+        TypeDefinition::Struct(StructDefinition::new(
+          "Order",
+          vec![
+            Field::new("id", Type::String),
+            Field::new(
+              "items",
+              Type::List {
+                ty: Box::new(Type::Named("OrderItem".to_owned())),
+              },
+            ),
+            Field::new("note", optional(Type::String)),
+            Field::new(
+              "counts",
+              Type::Map {
+                key: Box::new(Type::String),
+                value: Box::new(Type::I64),
+              },
+            ),
+            Field::new("created", optional(Type::Datetime)),
+            Field::new("status", Type::Named("Status".to_owned())),
+          ],
+          None,
+        )),
+        TypeDefinition::Struct(StructDefinition::new(
+          "OrderItem",
+          vec![Field::new("sku", Type::String), Field::new("quantity", Type::U32)],
+          None,
+        )),
+        TypeDefinition::Enum(EnumDefinition::new(
+          "Status",
+          vec![
+            EnumVariant::new("OPEN", Some(0), None, None),
+            EnumVariant::new("CLOSED", Some(1), None, None),
+          ],
+          None,
+        )),
+      ]
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_roundtrip() -> Result<()> {
+    let types = vec![
+      TypeDefinition::Struct(StructDefinition::new(
+        "Reading",
+        vec![
+          Field::new("sensor", Type::String),
+          Field::new("value", optional(Type::F64)),
+          Field::new(
+            "samples",
+            Type::List {
+              ty: Box::new(Type::I64),
+            },
+          ),
+          Field::new(
+            "labels",
+            Type::Map {
+              key: Box::new(Type::String),
+              value: Box::new(Type::String),
+            },
+          ),
+          Field::new("unit", Type::Named("Unit".to_owned())),
+        ],
+        None,
+      )),
+      TypeDefinition::Enum(EnumDefinition::new(
+        "Unit",
+        vec![
+          EnumVariant::new("CELSIUS", Some(0), None, None),
+          EnumVariant::new("FAHRENHEIT", Some(1), None, None),
+        ],
+        None,
+      )),
+    ];
+
+    let file = to_file_descriptor(&types, "sensors")?;
+    assert_eq!(file.message_type[0].nested_type[0].name(), "LabelsEntry");
+
+    assert_eq!(
+      from_file_descriptor_set(&FileDescriptorSet { file: vec![file] })?,
+      types
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_unsupported() {
+    let types = vec![TypeDefinition::Struct(StructDefinition::new(
+      "Grid",
+      vec![Field::new(
+        "cells",
+        Type::List {
+          ty: Box::new(Type::List { ty: Box::new(Type::U8) }),
+        },
+      )],
+      None,
+    ))];
+
+    assert_eq!(
+      to_file_descriptor(&types, "grid"),
+      Err(ConvertError::Unsupported("type u8[]".to_owned()))
+    );
+  }
+}
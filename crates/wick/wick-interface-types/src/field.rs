@@ -29,6 +29,10 @@ pub struct Field {
   /// The description of the field.
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub description: Option<String>,
+
+  /// The media type of the field's data, like `text/html`, when it isn't JSON.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub content_type: Option<String>,
 }
 
 impl Field {
@@ -43,6 +47,7 @@ impl Field {
       #[cfg(feature = "value")]
       default: None,
       required: !matches!(ty, Type::Optional { .. }),
+      content_type: None,
       ty,
     }
   }
@@ -71,6 +76,29 @@ impl Field {
     self.default.as_ref()
   }
 
+  /// Set the value the field takes when none is provided.
+  #[must_use]
+  #[cfg(feature = "value")]
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_default(mut self, default: Option<serde_json::Value>) -> Self {
+    self.default = default;
+    self
+  }
+
+  /// Get the media type of the field's data, if it isn't JSON.
+  #[must_use]
+  pub fn content_type(&self) -> Option<&str> {
+    self.content_type.as_deref()
+  }
+
+  /// Set the media type of the field's data, like `text/html` or `image/png`.
+  #[must_use]
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_content_type(mut self, content_type: Option<String>) -> Self {
+    self.content_type = content_type;
+    self
+  }
+
   /// Get whether the field is required
   #[must_use]
   pub const fn required(&self) -> bool {
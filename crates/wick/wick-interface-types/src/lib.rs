@@ -129,6 +129,9 @@ mod traits;
 
 mod signatures;
 
+#[cfg(any(feature = "json-schema", feature = "protobuf"))]
+pub mod convert;
+
 pub use field::Field;
 #[cfg(feature = "value")]
 pub use field::FieldValue;
@@ -64,12 +64,12 @@ fn map_type(input: &str) -> IResult<&str, Type> {
 }
 
 fn key_type_pair(input: &str) -> IResult<&str, (&str, Type)> {
-  let (i, (key, _, t)) = tuple((identifier, ws(char(':')), valid_type))(input)?;
+  let (i, (key, _, t)) = tuple((identifier, ws(char(':')), optional_type))(input)?;
   Ok((i, (key, t)))
 }
 
 fn map_key_type_pair(input: &str) -> IResult<&str, (&str, Type)> {
-  let (i, (key, _, t)) = tuple((tag("string"), ws(char(':')), valid_type))(input)?;
+  let (i, (key, _, t)) = tuple((tag("string"), ws(char(':')), optional_type))(input)?;
   Ok((i, (key, t)))
 }
 
@@ -81,13 +81,22 @@ fn identifier(input: &str) -> IResult<&str, &str> {
 }
 
 fn list_type(input: &str) -> IResult<&str, Type> {
-  let (i, (t, _)) = pair(typename, ws(square_brackets))(input)?;
+  let (i, (t, _)) = pair(alt((map_type, struct_type, typename)), ws(square_brackets))(input)?;
 
   Ok((i, Type::List { ty: Box::new(t) }))
 }
 
 fn valid_type(input: &str) -> IResult<&str, Type> {
-  alt((map_type, struct_type, list_type, typename))(input)
+  alt((list_type, map_type, struct_type, typename))(input)
+}
+
+fn optional_type(input: &str) -> IResult<&str, Type> {
+  let (i, (t, optional)) = pair(valid_type, opt(tag("?")))(input)?;
+  if optional.is_some() {
+    Ok((i, Type::Optional { ty: Box::new(t) }))
+  } else {
+    Ok((i, t))
+  }
 }
 
 fn typename(input: &str) -> IResult<&str, Type> {
@@ -120,12 +129,7 @@ fn typename(input: &str) -> IResult<&str, Type> {
 }
 
 fn _parse(input: &str) -> IResult<&str, Type> {
-  let (i, (t, optional)) = pair(alt((list_type, map_type, struct_type, typename)), opt(tag("?")))(input)?;
-  if optional.is_some() {
-    Ok((i, Type::Optional { ty: Box::new(t) }))
-  } else {
-    Ok((i, t))
-  }
+  optional_type(input)
 }
 
 #[macro_export]
@@ -327,6 +331,9 @@ mod test {
   #[case("datetime", Type::Datetime)]
   #[case("{string:string}", Type::Map { key: Box::new(Type::String), value: Box::new(Type::String) })]
   #[case("{string:string[]}", Type::Map { key: Box::new(Type::String), value: Box::new(Type::List{ty:Box::new(Type::String)}) })]
+  #[case("{a: string, b: u32?}", Type::AnonymousStruct(vec![Field::new("a", Type::String), Field::new("b", Type::Optional{ty:Box::new(Type::U32)})]))]
+  #[case("{a: string}[]", Type::List{ty:Box::new(Type::AnonymousStruct(vec![Field::new("a", Type::String)]))})]
+  #[case("{a: {b: bool[]}}?", Type::Optional{ty:Box::new(Type::AnonymousStruct(vec![Field::new("a", Type::AnonymousStruct(vec![Field::new("b", Type::List{ty:Box::new(Type::Bool)})]))]))})]
   #[case("object", Type::Object)]
   #[case("myType", Type::Named("myType".to_owned()))]
   #[case("name::myType", Type::Named("name::myType".to_owned()))]
@@ -24,6 +24,10 @@ pub struct OperationSignature {
   #[serde(default)]
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub outputs: Vec<Field>,
+
+  /// The relative cost of one invocation of the operation, if it declares one.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub cost: Option<u64>,
 }
 
 impl PartialEq for OperationSignature {
@@ -41,6 +45,7 @@ impl OperationSignature {
       config,
       inputs,
       outputs,
+      cost: None,
     }
   }
 
@@ -68,6 +73,18 @@ impl OperationSignature {
     &self.outputs
   }
 
+  /// Get the relative cost of one invocation of the operation, if it declares one.
+  #[must_use]
+  pub const fn cost(&self) -> Option<u64> {
+    self.cost
+  }
+
+  /// Set the relative cost of one invocation of the operation.
+  pub const fn with_cost(mut self, cost: Option<u64>) -> Self {
+    self.cost = cost;
+    self
+  }
+
   /// Create a new [OperationSignature] with the passed name.
   pub fn new_named<T: Into<String>>(name: T) -> Self {
     Self {
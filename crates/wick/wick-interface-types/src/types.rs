@@ -5,12 +5,14 @@ use std::error::Error;
 use std::str::FromStr;
 
 mod enum_def;
+mod recursion;
 mod struct_def;
 mod union_def;
 
 use serde::{Deserialize, Serialize};
 
-pub use self::enum_def::{EnumDefinition, EnumVariant};
+pub use self::enum_def::{EnumDefinition, EnumError, EnumVariant};
+pub use self::recursion::{find_infinite_cycle, recursive_fields};
 pub use self::struct_def::StructDefinition;
 pub use self::union_def::UnionDefinition;
 use crate::Field;
@@ -160,7 +162,7 @@ impl Type {
       Type::Map { .. } => TypeId::of::<std::collections::HashMap<Box<dyn std::any::Any>, Box<dyn std::any::Any>>>(),
       Type::Link { .. } => TypeId::of::<serde_json::Value>(),
       Type::Object => TypeId::of::<serde_json::Value>(),
-      Type::AnonymousStruct(_) => unimplemented!(),
+      Type::AnonymousStruct(_) => TypeId::of::<serde_json::Value>(),
     }
   }
 
@@ -228,7 +230,16 @@ impl std::fmt::Display for Type {
       Type::Map { key, value } => write!(f, "{{{}:{}}}", key, value),
       Type::Link { .. } => todo!(),
       Type::Object => f.write_str("object"),
-      Type::AnonymousStruct(_) => todo!(),
+      Type::AnonymousStruct(fields) => {
+        f.write_str("{")?;
+        for (i, field) in fields.iter().enumerate() {
+          if i > 0 {
+            f.write_str(",")?;
+          }
+          write!(f, "{}:{}", field.name, field.ty)?;
+        }
+        f.write_str("}")
+      }
     }
   }
 }
@@ -341,6 +352,17 @@ mod test {
     Ok(())
   }
 
+  #[cfg(feature = "parser")]
+  #[rstest::rstest]
+  #[case("{a:string,b:u32?}")]
+  #[case("{a:string}[]")]
+  #[case("{a:{b:bool[]},c:{string:i64}}?")]
+  fn test_display_roundtrip(#[case] as_str: &str) -> Result<()> {
+    let ty: Type = as_str.parse()?;
+    assert_eq!(ty.to_string(), as_str);
+    Ok(())
+  }
+
   #[cfg(feature = "value")]
   #[rstest::rstest]
   #[case(TS::String, "foo", json!("foo"))]
@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use serde::{Deserialize, Serialize};
 
 use crate::is_false;
@@ -30,6 +32,68 @@ impl EnumDefinition {
       description,
     }
   }
+
+  /// Check that no two variants share a name, an index, or a value.
+  ///
+  /// A variant's value may not match the name of a different variant either, since both are accepted when
+  /// decoding the enum from a string.
+  pub fn validate(&self) -> Result<(), EnumError> {
+    let mut indices = HashSet::new();
+    let mut names: HashMap<&str, &str> = HashMap::new();
+    for variant in &self.variants {
+      if let Some(index) = variant.index {
+        if !indices.insert(index) {
+          return Err(EnumError::DuplicateIndex(index));
+        }
+      }
+      if let Some(other) = names.insert(&variant.name, &variant.name) {
+        return Err(if other == variant.name {
+          EnumError::DuplicateName(variant.name.clone())
+        } else {
+          EnumError::DuplicateValue(variant.name.clone())
+        });
+      }
+      if let Some(value) = variant.value.as_deref().filter(|v| *v != variant.name) {
+        if names.insert(value, &variant.name).is_some() {
+          return Err(EnumError::DuplicateValue(value.to_owned()));
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// The names of the variants, used when reporting a value that doesn't match any of them.
+  #[must_use]
+  pub fn variant_names(&self) -> Vec<&str> {
+    self
+      .variants
+      .iter()
+      .map(|v| v.value.as_deref().unwrap_or(&v.name))
+      .collect()
+  }
+}
+
+/// Conflicts between the variants of an [EnumDefinition].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnumError {
+  /// More than one variant has this name.
+  DuplicateName(String),
+  /// More than one variant has this index.
+  DuplicateIndex(u32),
+  /// More than one variant can be decoded from this value.
+  DuplicateValue(String),
+}
+
+impl std::error::Error for EnumError {}
+impl std::fmt::Display for EnumError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      EnumError::DuplicateName(v) => write!(f, "variant name '{}' is used more than once", v),
+      EnumError::DuplicateIndex(v) => write!(f, "variant index {} is used more than once", v),
+      EnumError::DuplicateValue(v) => write!(f, "value '{}' refers to more than one variant", v),
+    }
+  }
 }
 
 impl PartialEq for EnumDefinition {
@@ -67,3 +131,43 @@ impl EnumVariant {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn variant(name: &str, index: u32, value: Option<&str>) -> EnumVariant {
+    EnumVariant::new(name, Some(index), value.map(ToOwned::to_owned), None)
+  }
+
+  #[test]
+  fn test_validate() {
+    let valid = EnumDefinition::new(
+      "Color",
+      vec![variant("Red", 0, Some("red")), variant("Green", 1, None)],
+      None,
+    );
+    assert_eq!(valid.validate(), Ok(()));
+    assert_eq!(valid.variant_names(), vec!["red", "Green"]);
+
+    let def = EnumDefinition::new("Color", vec![variant("Red", 0, None), variant("Red", 1, None)], None);
+    assert_eq!(def.validate(), Err(EnumError::DuplicateName("Red".to_owned())));
+
+    let def = EnumDefinition::new("Color", vec![variant("Red", 0, None), variant("Green", 0, None)], None);
+    assert_eq!(def.validate(), Err(EnumError::DuplicateIndex(0)));
+
+    let def = EnumDefinition::new(
+      "Color",
+      vec![variant("Red", 0, Some("r")), variant("Green", 1, Some("r"))],
+      None,
+    );
+    assert_eq!(def.validate(), Err(EnumError::DuplicateValue("r".to_owned())));
+
+    let def = EnumDefinition::new(
+      "Color",
+      vec![variant("Red", 0, Some("Green")), variant("Green", 1, None)],
+      None,
+    );
+    assert_eq!(def.validate(), Err(EnumError::DuplicateValue("Green".to_owned())));
+  }
+}
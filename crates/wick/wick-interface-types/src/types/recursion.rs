@@ -0,0 +1,211 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Type, TypeDefinition};
+
+impl Type {
+  /// The named types this type holds by value. Lists and maps keep their elements behind an allocation, so types
+  /// referenced through them are not included.
+  #[must_use]
+  pub fn inline_references(&self) -> Vec<&str> {
+    let mut references = Vec::new();
+    collect_references(self, false, &mut references);
+    references
+  }
+}
+
+fn collect_references<'a>(ty: &'a Type, required_only: bool, references: &mut Vec<&'a str>) {
+  match ty {
+    Type::Named(name) => references.push(name),
+    Type::Optional { ty } if !required_only => collect_references(ty, required_only, references),
+    Type::AnonymousStruct(fields) => {
+      for field in fields {
+        collect_references(&field.ty, required_only, references);
+      }
+    }
+    _ => {}
+  }
+}
+
+fn edges(types: &[TypeDefinition], required_only: bool) -> HashMap<&str, Vec<&str>> {
+  types
+    .iter()
+    .map(|def| {
+      let mut references = Vec::new();
+      match def {
+        TypeDefinition::Struct(def) => {
+          for field in &def.fields {
+            collect_references(&field.ty, required_only, &mut references);
+          }
+        }
+        // Any variant of a union may be chosen, so a union never forces a value to exist.
+        TypeDefinition::Union(def) if !required_only => {
+          for ty in &def.types {
+            collect_references(ty, required_only, &mut references);
+          }
+        }
+        _ => {}
+      }
+      (def.name(), references)
+    })
+    .collect()
+}
+
+fn reaches(edges: &HashMap<&str, Vec<&str>>, from: &str, to: &str) -> bool {
+  let mut seen = HashSet::new();
+  let mut stack = vec![from];
+  while let Some(next) = stack.pop() {
+    if next == to {
+      return true;
+    }
+    if seen.insert(next) {
+      if let Some(references) = edges.get(next) {
+        stack.extend(references.iter().copied());
+      }
+    }
+  }
+  false
+}
+
+/// Find the struct fields that hold, by value, a type which leads back to the struct they belong to.
+///
+/// These fields make a type recursive and need a level of indirection (e.g. a `Box`) in languages that lay values
+/// out inline. Returns `(struct name, field name)` pairs in definition order.
+#[must_use]
+pub fn recursive_fields(types: &[TypeDefinition]) -> Vec<(String, String)> {
+  let edges = edges(types, false);
+  let mut fields = Vec::new();
+  for def in types {
+    let TypeDefinition::Struct(def) = def else {
+      continue;
+    };
+    for field in &def.fields {
+      if field
+        .ty
+        .inline_references()
+        .iter()
+        .any(|r| reaches(&edges, r, &def.name))
+      {
+        fields.push((def.name.clone(), field.name.clone()));
+      }
+    }
+  }
+  fields
+}
+
+/// Find a cycle of required, by-value struct fields.
+///
+/// No value of a type in such a cycle can ever be constructed because each one would have to contain another. At
+/// least one field in every recursive cycle must be optional, a list, or a map. Returns the names of the types in
+/// the cycle, starting and ending with the same type.
+#[must_use]
+pub fn find_infinite_cycle(types: &[TypeDefinition]) -> Option<Vec<String>> {
+  let edges = edges(types, true);
+  for def in types {
+    let mut path = vec![def.name()];
+    if let Some(cycle) = find_path_back(&edges, def.name(), &mut path, &mut HashSet::new()) {
+      return Some(cycle.into_iter().map(ToOwned::to_owned).collect());
+    }
+  }
+  None
+}
+
+fn find_path_back<'a>(
+  edges: &HashMap<&'a str, Vec<&'a str>>,
+  target: &'a str,
+  path: &mut Vec<&'a str>,
+  seen: &mut HashSet<&'a str>,
+) -> Option<Vec<&'a str>> {
+  let current = *path.last()?;
+  for next in edges.get(current).into_iter().flatten().copied() {
+    if next == target {
+      let mut cycle = path.clone();
+      cycle.push(next);
+      return Some(cycle);
+    }
+    if edges.contains_key(next) && seen.insert(next) {
+      path.push(next);
+      if let Some(cycle) = find_path_back(edges, target, path, seen) {
+        return Some(cycle);
+      }
+      path.pop();
+    }
+  }
+  None
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{Field, StructDefinition, UnionDefinition};
+
+  fn named(name: &str) -> Type {
+    Type::Named(name.to_owned())
+  }
+
+  fn optional(ty: Type) -> Type {
+    Type::Optional { ty: Box::new(ty) }
+  }
+
+  fn list(ty: Type) -> Type {
+    Type::List { ty: Box::new(ty) }
+  }
+
+  fn def(name: &str, fields: Vec<Field>) -> TypeDefinition {
+    TypeDefinition::Struct(StructDefinition::new(name, fields, None))
+  }
+
+  #[test]
+  fn test_self_referential() {
+    let types = [def(
+      "Node",
+      vec![
+        Field::new("value", Type::String),
+        Field::new("next", optional(named("Node"))),
+        Field::new("children", list(named("Node"))),
+      ],
+    )];
+    assert_eq!(recursive_fields(&types), vec![("Node".to_owned(), "next".to_owned())]);
+    assert_eq!(find_infinite_cycle(&types), None);
+  }
+
+  #[test]
+  fn test_mutually_recursive() {
+    let types = [
+      def("Tree", vec![Field::new("root", optional(named("Branch")))]),
+      def(
+        "Branch",
+        vec![Field::new("tree", named("Tree")), Field::new("label", Type::String)],
+      ),
+    ];
+    assert_eq!(
+      recursive_fields(&types),
+      vec![
+        ("Tree".to_owned(), "root".to_owned()),
+        ("Branch".to_owned(), "tree".to_owned())
+      ]
+    );
+    assert_eq!(find_infinite_cycle(&types), None);
+  }
+
+  #[test]
+  fn test_through_union() {
+    let types = [
+      def("Expr", vec![Field::new("op", named("Op"))]),
+      TypeDefinition::Union(UnionDefinition::new("Op", vec![Type::I64, named("Expr")], None)),
+    ];
+    assert_eq!(recursive_fields(&types), vec![("Expr".to_owned(), "op".to_owned())]);
+    assert_eq!(find_infinite_cycle(&types), None);
+  }
+
+  #[test]
+  fn test_infinite() {
+    let types = [
+      def("A", vec![Field::new("b", named("B"))]),
+      def("B", vec![Field::new("a", named("A"))]),
+    ];
+    assert_eq!(
+      find_infinite_cycle(&types),
+      Some(vec!["A".to_owned(), "B".to_owned(), "A".to_owned()])
+    );
+  }
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
-use flow_component::SharedComponent;
+use flow_component::{ObservabilityLevel, SharedComponent};
 use parking_lot::RwLock;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
@@ -9,7 +9,7 @@ use tokio_stream::StreamExt;
 use tonic::{Response, Status};
 use wick_packet::PacketStream;
 use wick_rpc::rpc::invocation_service_server::InvocationService;
-use wick_rpc::rpc::{InvocationRequest, ListResponse, Packet, StatsResponse};
+use wick_rpc::rpc::{InvocationRequest, ListResponse, ObservabilityResponse, Packet, StatsResponse};
 use wick_rpc::{rpc, DurationStatistics, Statistics};
 
 /// A GRPC server for implementers of [flow_component::Component].
@@ -72,25 +72,19 @@ impl InvocationServer {
   }
 }
 
-fn convert_invocation_stream(mut streaming: tonic::Streaming<InvocationRequest>) -> PacketStream {
-  let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-  tokio::spawn(async move {
-    while let Some(p) = streaming.next().await {
-      let result = p.map_err(|e| wick_packet::Error::Component(e.to_string())).map(|p| {
-        p.data.map_or_else(
-          || unreachable!(),
-          |p| match p {
-            rpc::invocation_request::Data::Invocation(_) => unreachable!(),
-            rpc::invocation_request::Data::Packet(p) => wick_packet::Packet::from(p),
-          },
-        )
-      });
-
-      let _ = tx.send(result);
-    }
+/// The packets after an invocation's first message, passed through as the client sends them so the component can
+/// produce outputs before its inputs are done.
+fn convert_invocation_stream(streaming: tonic::Streaming<InvocationRequest>) -> PacketStream {
+  let packets = streaming.filter_map(|request| match request.map(|r| r.data) {
+    Ok(Some(rpc::invocation_request::Data::Packet(p))) => Some(Ok(wick_packet::Packet::from(p))),
+    Ok(Some(rpc::invocation_request::Data::Invocation(_))) => Some(Err(wick_packet::Error::Component(
+      "only the first message of an invocation can be an invocation".to_owned(),
+    ))),
+    Ok(None) => None,
+    Err(e) => Some(Err(wick_packet::Error::Component(e.to_string()))),
   });
 
-  wick_packet::PacketStream::new(Box::new(tokio_stream::wrappers::UnboundedReceiverStream::new(rx)))
+  wick_packet::PacketStream::new(Box::new(packets))
 }
 
 #[async_trait::async_trait]
@@ -136,12 +130,11 @@ impl InvocationService for InvocationServer {
       tokio::spawn(async move {
         let mut receiver = result.unwrap();
         while let Some(next) = receiver.next().await {
-          if next.is_err() {
-            todo!("Handle error");
+          let packet = next.unwrap_or_else(|e| wick_packet::Packet::component_error(e.to_string()));
+          if tx.send(Ok(packet.into())).await.is_err() {
+            // the client hung up, nobody is left to send outputs to.
+            break;
           }
-          let next = next.unwrap();
-
-          tx.send(Ok(next.into())).await.unwrap();
         }
       });
       self.record_execution(op_id, JobResult::Success, start.elapsed());
@@ -162,6 +155,21 @@ impl InvocationService for InvocationServer {
       stats: self.stats.read().values().cloned().map(From::from).collect(),
     }))
   }
+
+  async fn observability(
+    &self,
+    request: tonic::Request<rpc::ObservabilityRequest>,
+  ) -> Result<Response<ObservabilityResponse>, Status> {
+    let level = request.into_inner().level;
+    if !level.is_empty() {
+      let level: ObservabilityLevel = level.parse().map_err(|e| Status::invalid_argument(format!("{}", e)))?;
+      let previous = ObservabilityLevel::set(level);
+      debug!(%previous, %level, "observability level changed");
+    }
+    Ok(Response::new(ObservabilityResponse {
+      level: ObservabilityLevel::current().to_string(),
+    }))
+  }
 }
 
 #[cfg(test)]
@@ -152,6 +152,23 @@ pub fn make_rpc_server(
   tokio::spawn(Server::builder().add_service(svc).serve_with_incoming(listener))
 }
 
+/// Build and spawn an RPC server for the passed collection on a Unix domain socket at `path`.
+#[cfg(unix)]
+pub fn make_uds_rpc_server<P: AsRef<std::path::Path>>(
+  path: P,
+  collection: SharedComponent,
+) -> Result<JoinHandle<std::result::Result<(), tonic::transport::Error>>> {
+  let component_service = InvocationServer::new(collection);
+
+  let svc = InvocationServiceServer::new(component_service);
+
+  let listener = tokio_stream::wrappers::UnixListenerStream::new(tokio::net::UnixListener::bind(path)?);
+
+  Ok(tokio::spawn(
+    Server::builder().add_service(svc).serve_with_incoming(listener),
+  ))
+}
+
 /// Create an RPC client.
 pub async fn connect_rpc_client(uri: Uri) -> Result<InvocationServiceClient<Channel>> {
   Ok(InvocationServiceClient::connect(uri).await?)
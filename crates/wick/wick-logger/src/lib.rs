@@ -136,7 +136,10 @@ pub use options::{
 /// The main Logger module.
 mod logger;
 
-pub use crate::logger::{init, init_test, LoggingGuard};
+pub use crate::logger::{init, init_test, install_otlp, LoggingGuard};
+
+/// W3C trace context propagation for spans exported over OTLP.
+pub mod trace_context;
 
 #[macro_use]
 extern crate tracing;
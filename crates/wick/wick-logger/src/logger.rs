@@ -1,10 +1,16 @@
+use std::sync::OnceLock;
+
 use opentelemetry::global;
 use tracing_appender::non_blocking::{NonBlocking, WorkerGuard};
+use tracing_subscriber::filter::{filter_fn, FilterExt};
 use tracing_subscriber::fmt::time::UtcTime;
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::Layer;
 mod otel;
 
+/// The exporter slot used when the logger was initialized without an OTLP endpoint.
+static DEFERRED: OnceLock<otel::DeferredProcessor> = OnceLock::new();
+
 use crate::error::LoggerError;
 use crate::LoggingOptions;
 
@@ -30,6 +36,18 @@ pub fn init_test(opts: &LoggingOptions) -> Option<LoggingGuard> {
   try_init(&opts, Environment::Test).ok()
 }
 
+/// Start exporting spans to the OTLP collector at `endpoint`, e.g. when a manifest configures one after startup.
+///
+/// Returns `false` without changing anything when spans are already being exported or no logger has been initialized.
+pub fn install_otlp(endpoint: &str) -> Result<bool, LoggerError> {
+  let Some(processor) = DEFERRED.get() else {
+    return Ok(false);
+  };
+  processor
+    .install(endpoint)
+    .map_err(|e| LoggerError::InitFailed(e.to_string()))
+}
+
 #[must_use]
 #[derive(Debug)]
 /// Guard that - when dropped - flushes all log messages and drop I/O handles.
@@ -115,9 +133,26 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
 
   let needs_simple_tracer = tokio::runtime::Handle::try_current().is_err() || environment == Environment::Test;
 
-  // Configure a jaeger tracer if we have a configured endpoint.
-  let (otel_layer, tracer_provider) = opts.otlp_endpoint.as_ref().map_or_else(
-    || (None, None),
+  // Configure an OTLP tracer if we have a configured endpoint.
+  let (otel_layer, deferred_layer, tracer_provider) = opts.otlp_endpoint.as_ref().map_or_else(
+    || {
+      // Otherwise keep a disabled layer around so an exporter can be installed later with [install_otlp].
+      let (tracer, provider, processor) = otel::build_deferred();
+      let _ = global::set_tracer_provider(provider.clone());
+      let installed = processor.clone();
+      let _ = DEFERRED.set(processor);
+
+      let layer = Some(
+        tracing_opentelemetry::layer().with_tracer(tracer).with_filter(
+          opts
+            .levels
+            .telemetry
+            .clone()
+            .and(filter_fn(move |_| installed.is_installed())),
+        ),
+      );
+      (None, layer, Some(provider))
+    },
     |otlp_endpoint| {
       let (tracer, provider) = if needs_simple_tracer {
         otel::build_simple(otlp_endpoint).unwrap()
@@ -132,7 +167,7 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
           .with_tracer(tracer)
           .with_filter(opts.levels.telemetry.clone()),
       );
-      (layer, Some(provider))
+      (layer, None, Some(provider))
     },
   );
 
@@ -192,6 +227,7 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
 
   let subscriber = tracing_subscriber::registry()
     .with(otel_layer)
+    .with(deferred_layer)
     .with(test_layer)
     .with(verbose_layer)
     .with(normal_layer);
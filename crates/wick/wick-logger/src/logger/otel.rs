@@ -1,13 +1,15 @@
-use opentelemetry::sdk::trace::{self, Builder, Sampler};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use opentelemetry::sdk::export::trace::SpanData;
+use opentelemetry::sdk::trace::{self, BatchSpanProcessor, Builder, Sampler, Span, SpanProcessor};
 use opentelemetry::sdk::{self, Resource};
-use opentelemetry::trace::{TraceError, TracerProvider};
-use opentelemetry::KeyValue;
+use opentelemetry::trace::{TraceError, TraceResult, TracerProvider};
+use opentelemetry::{Context, KeyValue};
 use opentelemetry_otlp::{SpanExporter, SpanExporterBuilder, WithExportConfig};
 
-fn exporter_builder(endpoint: &str) -> SpanExporter {
-  SpanExporterBuilder::from(opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint))
-    .build_span_exporter()
-    .unwrap()
+fn exporter_builder(endpoint: &str) -> Result<SpanExporter, TraceError> {
+  SpanExporterBuilder::from(opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint)).build_span_exporter()
 }
 
 fn trace_config() -> Option<trace::Config> {
@@ -21,18 +23,28 @@ fn trace_config() -> Option<trace::Config> {
 pub(super) fn build_batch(endpoint: &str) -> Result<(sdk::trace::Tracer, sdk::trace::TracerProvider), TraceError> {
   Ok(build_with_exporter(
     sdk::trace::TracerProvider::builder()
-      .with_batch_exporter(exporter_builder(endpoint), opentelemetry::runtime::Tokio),
+      .with_batch_exporter(exporter_builder(endpoint)?, opentelemetry::runtime::Tokio),
     trace_config(),
   ))
 }
 
 pub(super) fn build_simple(endpoint: &str) -> Result<(sdk::trace::Tracer, sdk::trace::TracerProvider), TraceError> {
   Ok(build_with_exporter(
-    sdk::trace::TracerProvider::builder().with_simple_exporter(exporter_builder(endpoint)),
+    sdk::trace::TracerProvider::builder().with_simple_exporter(exporter_builder(endpoint)?),
     trace_config(),
   ))
 }
 
+/// Build a tracer whose spans are dropped until an exporter is installed in the returned processor.
+pub(super) fn build_deferred() -> (sdk::trace::Tracer, sdk::trace::TracerProvider, DeferredProcessor) {
+  let processor = DeferredProcessor::default();
+  let (tracer, provider) = build_with_exporter(
+    sdk::trace::TracerProvider::builder().with_span_processor(processor.clone()),
+    trace_config(),
+  );
+  (tracer, provider, processor)
+}
+
 pub(super) fn build_with_exporter(
   mut provider_builder: Builder,
   trace_config: Option<sdk::trace::Config>,
@@ -44,3 +56,62 @@ pub(super) fn build_with_exporter(
   let tracer = provider.versioned_tracer("opentelemetry-otlp", Some(env!("CARGO_PKG_VERSION")), None);
   (tracer, provider)
 }
+
+/// A span processor that forwards to an exporter installed after the logger was initialized.
+#[derive(Debug, Clone, Default)]
+pub(super) struct DeferredProcessor {
+  installed: Arc<AtomicBool>,
+  inner: Arc<RwLock<Option<Box<dyn SpanProcessor>>>>,
+}
+
+impl DeferredProcessor {
+  /// Start exporting spans to `endpoint`. Returns `false` if an exporter was already installed.
+  pub(super) fn install(&self, endpoint: &str) -> Result<bool, TraceError> {
+    let mut inner = self
+      .inner
+      .write()
+      .map_err(|e| TraceError::Other(e.to_string().into()))?;
+    if inner.is_some() {
+      return Ok(false);
+    }
+    let processor = BatchSpanProcessor::builder(exporter_builder(endpoint)?, opentelemetry::runtime::Tokio).build();
+    *inner = Some(Box::new(processor));
+    self.installed.store(true, Ordering::Relaxed);
+    Ok(true)
+  }
+
+  /// Whether an exporter has been installed.
+  pub(super) fn is_installed(&self) -> bool {
+    self.installed.load(Ordering::Relaxed)
+  }
+}
+
+impl SpanProcessor for DeferredProcessor {
+  fn on_start(&self, span: &mut Span, cx: &Context) {
+    if let Ok(inner) = self.inner.read() {
+      if let Some(processor) = inner.as_ref() {
+        processor.on_start(span, cx);
+      }
+    }
+  }
+
+  fn on_end(&self, span: SpanData) {
+    if let Ok(inner) = self.inner.read() {
+      if let Some(processor) = inner.as_ref() {
+        processor.on_end(span);
+      }
+    }
+  }
+
+  fn force_flush(&self) -> TraceResult<()> {
+    self.inner.read().map_or(Ok(()), |inner| {
+      inner.as_ref().map_or(Ok(()), |processor| processor.force_flush())
+    })
+  }
+
+  fn shutdown(&mut self) -> TraceResult<()> {
+    self.inner.write().map_or(Ok(()), |mut inner| {
+      inner.take().map_or(Ok(()), |mut processor| processor.shutdown())
+    })
+  }
+}
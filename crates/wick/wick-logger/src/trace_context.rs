@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::trace::TraceContextExt;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// The header that carries the W3C trace context.
+pub const TRACEPARENT: &str = "traceparent";
+
+/// Make `span` a child of the remote span described by a `traceparent` value.
+///
+/// Invalid values are ignored so a malformed header never fails a request.
+pub fn set_parent(span: &Span, traceparent: &str) {
+  let carrier = HashMap::from([(TRACEPARENT.to_owned(), traceparent.to_owned())]);
+  let cx = TraceContextPropagator::new().extract(&carrier);
+  if cx.span().span_context().is_valid() {
+    span.set_parent(cx);
+  }
+}
+
+/// The `traceparent` value that identifies `span` to remote services, if `span` is being exported.
+#[must_use]
+pub fn traceparent(span: &Span) -> Option<String> {
+  let cx = span.context();
+  if !cx.span().span_context().is_valid() {
+    return None;
+  }
+  let mut carrier = HashMap::new();
+  TraceContextPropagator::new().inject_context(&cx, &mut carrier);
+  carrier.remove(TRACEPARENT)
+}
+
+#[cfg(test)]
+mod test {
+  use opentelemetry::sdk::trace::TracerProvider;
+  use opentelemetry::trace::TracerProvider as _;
+  use tracing_subscriber::prelude::*;
+
+  use super::*;
+
+  #[test]
+  fn test_roundtrip() {
+    let provider = TracerProvider::builder().build();
+    let subscriber =
+      tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+    tracing::subscriber::with_default(subscriber, || {
+      let span = tracing::info_span!("request");
+      set_parent(&span, "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01");
+      let child = traceparent(&span).unwrap();
+      assert!(child.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+      assert!(!child.contains("00f067aa0ba902b7"));
+
+      let orphan = tracing::info_span!("orphan");
+      set_parent(&orphan, "not a traceparent");
+      assert!(!traceparent(&orphan)
+        .unwrap()
+        .contains("4bf92f3577b34da6a3ce929d0e0e4736"));
+    });
+  }
+
+  #[test]
+  fn test_disabled() {
+    assert_eq!(traceparent(&Span::none()), None);
+  }
+}
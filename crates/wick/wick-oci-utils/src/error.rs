@@ -73,6 +73,10 @@ pub enum OciError {
   #[error("Failed to pull the package: {0}")]
   PullFailed(String),
 
+  /// Tried to pull a package that isn't cached while offline.
+  #[error("Package '{0}' is not in the cache and fetching is disabled in offline mode")]
+  Offline(String),
+
   /// Tried to pull a layer that didn't include a title.
   #[error("Wick package layers must contain a title")]
   NoTitle,
@@ -93,6 +97,10 @@ pub enum OciError {
   #[error("Invalid layer path '{0}', layer path must contain at least one forward slash.")]
   InvalidLayerPath(PathBuf),
 
+  /// A layer's title is a path outside the package it's in.
+  #[error("Refusing to pull layer '{0}', its path is outside the package")]
+  LayerOutsidePackage(String),
+
   /// Passed cache location could not be found.
   #[error("Invalid cache location '{0}'")]
   InvalidCache(wick_xdg::Error),
@@ -105,6 +113,26 @@ pub enum OciError {
   #[error("Failed to push the package: {0}")]
   PushFailed(String),
 
+  /// A downloaded layer's content didn't match the digest in its manifest.
+  #[error("Digest mismatch: expected {0}, got {1}")]
+  DigestMismatch(String, String),
+
+  /// A blob's digest isn't a sha256 digest, which the blob cache needs to verify it.
+  #[error("Unsupported digest '{0}', expected a sha256 digest")]
+  UnsupportedDigest(String),
+
+  /// A layer in the manifest has a media type Wick doesn't know how to handle.
+  #[error("Unsupported layer media type '{0}'")]
+  UnsupportedMediaType(String),
+
+  /// A pulled package isn't signed by any of the keys it has to be signed by.
+  #[error("Refusing to use '{0}', it is not signed by a trusted key: {1}")]
+  UntrustedArtifact(String, String),
+
+  /// A trusted key is neither an account public key nor a PEM-encoded P-256 public key.
+  #[error("Invalid trusted key '{0}', expected an account public key or a PEM-encoded cosign public key")]
+  InvalidTrustedKey(String),
+
   /// Returned when a pull would overwrite existing files and 'overwrite' is not set.
   #[error("Refusing to overwrite {}. Set 'overwrite' to true to force.", .0.iter().map(|v|v.display().to_string()).collect::<Vec<_>>().join(", "))]
   WouldOverwrite(Vec<PathBuf>),
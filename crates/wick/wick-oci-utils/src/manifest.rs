@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
@@ -7,12 +8,46 @@ use serde::{Deserialize, Serialize};
 pub struct AssetManifest {
   pub(crate) root: PathBuf,
   pub(crate) version: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) digest: Option<String>,
+  /// The digest of each layer, keyed by the path it was written to.
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub(crate) layers: BTreeMap<String, String>,
+  /// The paths of the layers that are WebAssembly modules.
+  #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+  pub(crate) modules: BTreeSet<String>,
 }
 
 impl AssetManifest {
   pub const FILENAME: &str = ".wick-manifest.json";
   pub const fn new(root: PathBuf, version: String) -> Self {
-    Self { root, version }
+    Self {
+      root,
+      version,
+      digest: None,
+      layers: BTreeMap::new(),
+      modules: BTreeSet::new(),
+    }
+  }
+
+  /// Record the digest of the image manifest this asset was pulled from.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_digest(mut self, digest: String) -> Self {
+    self.digest = Some(digest);
+    self
+  }
+
+  /// Record the digest of each layer, keyed by the path it was written to.
+  pub fn with_layers(mut self, layers: BTreeMap<String, String>) -> Self {
+    self.layers = layers;
+    self
+  }
+
+  /// Record which layers are WebAssembly modules, by the path they were written to.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_modules(mut self, modules: BTreeSet<String>) -> Self {
+    self.modules = modules;
+    self
   }
 
   #[must_use]
@@ -24,4 +59,19 @@ impl AssetManifest {
   pub const fn version(&self) -> &String {
     &self.version
   }
+
+  #[must_use]
+  pub const fn digest(&self) -> Option<&String> {
+    self.digest.as_ref()
+  }
+
+  #[must_use]
+  pub const fn layers(&self) -> &BTreeMap<String, String> {
+    &self.layers
+  }
+
+  #[must_use]
+  pub const fn modules(&self) -> &BTreeSet<String> {
+    &self.modules
+  }
 }
@@ -10,6 +10,12 @@ pub enum OnExisting {
   Error,
 }
 
+/// The number of layers pulled at the same time unless configured otherwise.
+pub const DEFAULT_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// The number of bytes the blob cache can hold before blobs are evicted, unless configured otherwise.
+pub const DEFAULT_MAX_CACHE_SIZE: u64 = 1024 * 1024 * 1024;
+
 #[derive(getset::Getters, getset::Setters, Clone, serde::Serialize)]
 #[must_use]
 pub struct OciOptions {
@@ -29,6 +35,26 @@ pub struct OciOptions {
   pub(crate) on_existing: OnExisting,
   #[getset(get = "pub", set = "pub")]
   pub(crate) ignore_manifest: bool,
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) max_concurrent_downloads: usize,
+  /// Check the registry for changes to cached packages, downloading only the layers that changed.
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) refresh: bool,
+  /// Only use packages that are already in the cache, failing rather than contacting a registry.
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) offline: bool,
+  /// Keys pulled packages must be signed by: account public keys that sign the claims embedded in WebAssembly
+  /// modules, or PEM-encoded cosign public keys. Claims only vouch for packages made of nothing but modules, others
+  /// need a cosign signature. When any are set, cached packages are checked against the registry unless offline.
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) trusted_keys: Vec<String>,
+  /// The number of bytes the blob cache can hold, the least recently used blobs that aren't pinned are evicted after
+  /// a pull that exceeds it.
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) max_cache_size: u64,
+  /// The directory the blob cache is kept in, which is shared by every pulled package. `None` disables it.
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) blob_cache_dir: Option<PathBuf>,
 }
 
 impl Default for OciOptions {
@@ -41,8 +67,14 @@ impl Default for OciOptions {
       password: None,
       flatten: false,
       cache_dir: xdg.global().cache().clone(),
+      blob_cache_dir: Some(xdg.global().cache().clone()),
       on_existing: OnExisting::Ignore,
       ignore_manifest: false,
+      max_concurrent_downloads: DEFAULT_CONCURRENT_DOWNLOADS,
+      refresh: false,
+      offline: false,
+      trusted_keys: vec![],
+      max_cache_size: DEFAULT_MAX_CACHE_SIZE,
     }
   }
 }
@@ -64,6 +96,7 @@ impl std::fmt::Debug for OciOptions {
       .field("allow_insecure", &self.allow_insecure)
       .field("username", &self.username)
       .field("password", &self.password.as_ref().map(|_| "********"))
+      .field("trusted_keys", &self.trusted_keys)
       .finish()
   }
 }
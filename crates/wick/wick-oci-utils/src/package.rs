@@ -1,8 +1,14 @@
+mod blobs;
+mod layers;
+mod provenance;
 mod pull;
 mod push;
+mod verify;
 
 use std::path::PathBuf;
 
+pub use blobs::{BlobCache, BlobEntry};
+pub use provenance::{pull_provenance, Provenance, ProvenanceFile};
 pub use pull::*;
 pub use push::*;
 /// Annotation types associated with Wick packages.
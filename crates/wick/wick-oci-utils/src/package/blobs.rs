@@ -0,0 +1,295 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sha2::{Digest, Sha256};
+
+use crate::Error;
+
+/// A content-addressed cache of the blobs pulled packages are made of, kept in `<cache dir>/blobs/sha256/<hex>`.
+///
+/// Blobs are checked against their digest every time they're read, and corrupted ones are removed. Next to each blob
+/// is a `<hex>.used` file recording when it was last used, and a `<hex>.pin` file while it's pinned, which keeps
+/// [BlobCache::gc] from evicting it.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct BlobCache {
+  dir: PathBuf,
+}
+
+/// A blob in a [BlobCache].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BlobEntry {
+  /// The blob's digest, like `sha256:<hex>`.
+  pub digest: String,
+  /// The size of the blob in bytes.
+  pub size: u64,
+  /// Whether the blob is pinned.
+  pub pinned: bool,
+  /// When the blob was last read or written, in milliseconds since the Unix epoch.
+  pub last_used: u64,
+}
+
+impl BlobCache {
+  /// The directory in a cache directory that blobs are kept in.
+  pub const DIRECTORY: &'static str = "blobs";
+
+  /// The blob cache in the cache directory `cache_dir`.
+  pub fn new(cache_dir: &Path) -> Self {
+    Self {
+      dir: cache_dir.join(Self::DIRECTORY).join("sha256"),
+    }
+  }
+
+  /// The path of the blob with `digest`, or `None` if it isn't a sha256 digest.
+  fn path(&self, digest: &str) -> Option<PathBuf> {
+    let hex = digest.strip_prefix("sha256:")?;
+    is_hex_digest(hex).then(|| self.dir.join(hex.to_ascii_lowercase()))
+  }
+
+  /// Read the blob with `digest`, returning `None` if it isn't cached. A blob that doesn't match its digest any more
+  /// is removed and treated as missing.
+  pub async fn get(&self, digest: &str) -> Result<Option<Vec<u8>>, Error> {
+    let Some(path) = self.path(digest) else {
+      return Ok(None);
+    };
+    let data = match tokio::fs::read(&path).await {
+      Ok(data) => data,
+      Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+      Err(e) => return Err(e.into()),
+    };
+    if let Err(e) = check_digest(digest, &data) {
+      warn!(path = %path.display(), error = %e, "cache:blob:corrupted");
+      remove(&path).await?;
+      return Ok(None);
+    }
+    touch(&path).await?;
+    trace!(digest, bytes = data.len(), "cache:blob:hit");
+    Ok(Some(data))
+  }
+
+  /// Store `data` as the blob with `digest`, failing if it doesn't match the digest. Blobs with digests other than
+  /// sha256 digests can't be verified and aren't stored.
+  pub async fn put(&self, digest: &str, data: &[u8]) -> Result<(), Error> {
+    let Some(path) = self.path(digest) else {
+      debug!(digest, "cache:blob:unsupported_digest");
+      return Ok(());
+    };
+    check_digest(digest, data)?;
+    tokio::fs::create_dir_all(&self.dir)
+      .await
+      .map_err(|e| Error::CreateDir(self.dir.clone(), e))?;
+    // written next to the blob first so readers never see a partial blob.
+    let tmp = path.with_extension(format!("tmp-{}", std::process::id()));
+    tokio::fs::write(&tmp, data)
+      .await
+      .map_err(|e| Error::WriteFile(tmp.clone(), e))?;
+    tokio::fs::rename(&tmp, &path).await?;
+    touch(&path).await?;
+    trace!(digest, bytes = data.len(), "cache:blob:write");
+    Ok(())
+  }
+
+  /// Keep the blob with `digest` from being evicted, whether or not it's cached yet.
+  pub async fn pin(&self, digest: &str) -> Result<(), Error> {
+    let path = self
+      .path(digest)
+      .ok_or_else(|| Error::UnsupportedDigest(digest.to_owned()))?;
+    tokio::fs::create_dir_all(&self.dir)
+      .await
+      .map_err(|e| Error::CreateDir(self.dir.clone(), e))?;
+    let pin = path.with_extension("pin");
+    tokio::fs::write(&pin, b"").await.map_err(|e| Error::WriteFile(pin, e))
+  }
+
+  /// Let the blob with `digest` be evicted again.
+  pub async fn unpin(&self, digest: &str) -> Result<(), Error> {
+    let path = self
+      .path(digest)
+      .ok_or_else(|| Error::UnsupportedDigest(digest.to_owned()))?;
+    remove_if_exists(&path.with_extension("pin")).await
+  }
+
+  /// The blobs in the cache.
+  pub async fn entries(&self) -> Result<Vec<BlobEntry>, Error> {
+    let mut dir = match tokio::fs::read_dir(&self.dir).await {
+      Ok(dir) => dir,
+      Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+      Err(e) => return Err(e.into()),
+    };
+    let mut entries = Vec::new();
+    while let Some(entry) = dir.next_entry().await? {
+      let name = entry.file_name();
+      let Some(hex) = name.to_str().filter(|name| is_hex_digest(name)) else {
+        continue;
+      };
+      let path = entry.path();
+      let metadata = entry.metadata().await?;
+      let last_used = tokio::fs::read_to_string(path.with_extension("used"))
+        .await
+        .ok()
+        .and_then(|millis| millis.trim().parse().ok());
+      entries.push(BlobEntry {
+        digest: format!("sha256:{}", hex),
+        size: metadata.len(),
+        pinned: path.with_extension("pin").exists(),
+        last_used: last_used.unwrap_or_else(|| metadata.modified().map_or(0, millis_since_epoch)),
+      });
+    }
+    Ok(entries)
+  }
+
+  /// Evict the least recently used blobs that aren't pinned until the cache holds at most `max_size` bytes, returning
+  /// the digests of the evicted blobs. Pinned blobs are never evicted, even if they alone exceed `max_size`.
+  pub async fn gc(&self, max_size: u64) -> Result<Vec<String>, Error> {
+    let mut entries = self.entries().await?;
+    let mut total: u64 = entries.iter().map(|e| e.size).sum();
+    entries.retain(|e| !e.pinned);
+    entries.sort_by_key(|e| e.last_used);
+
+    let mut evicted = Vec::new();
+    for entry in entries {
+      if total <= max_size {
+        break;
+      }
+      if let Some(path) = self.path(&entry.digest) {
+        remove(&path).await?;
+      }
+      total = total.saturating_sub(entry.size);
+      evicted.push(entry.digest);
+    }
+    if !evicted.is_empty() {
+      debug!(evicted = evicted.len(), bytes = total, "cache:blob:gc");
+    }
+    Ok(evicted)
+  }
+}
+
+/// Whether `hex` is the hex encoding of a sha256 digest.
+fn is_hex_digest(hex: &str) -> bool {
+  hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Check that `data` hashes to the sha256 `digest`.
+fn check_digest(digest: &str, data: &[u8]) -> Result<(), Error> {
+  let expected = digest.strip_prefix("sha256:").unwrap_or(digest);
+  let actual = format!("{:x}", Sha256::digest(data));
+  if actual.eq_ignore_ascii_case(expected) {
+    Ok(())
+  } else {
+    Err(Error::DigestMismatch(digest.to_owned(), format!("sha256:{}", actual)))
+  }
+}
+
+fn millis_since_epoch(time: SystemTime) -> u64 {
+  time
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// Record that the blob at `path` was just used.
+async fn touch(path: &Path) -> Result<(), Error> {
+  let used = path.with_extension("used");
+  let now = millis_since_epoch(SystemTime::now());
+  tokio::fs::write(&used, now.to_string())
+    .await
+    .map_err(|e| Error::WriteFile(used, e))
+}
+
+/// Remove the blob at `path` and the record of its last use.
+async fn remove(path: &Path) -> Result<(), Error> {
+  remove_if_exists(path).await?;
+  remove_if_exists(&path.with_extension("used")).await
+}
+
+async fn remove_if_exists(path: &Path) -> Result<(), Error> {
+  match tokio::fs::remove_file(path).await {
+    Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+    _ => Ok(()),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  fn digest(data: &[u8]) -> String {
+    format!("sha256:{:x}", Sha256::digest(data))
+  }
+
+  fn temp_cache(name: &str) -> Result<(PathBuf, BlobCache)> {
+    let dur = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    let dir = std::env::temp_dir().join(format!("wick-oci-{}-{}", name, dur.as_nanos()));
+    let cache = BlobCache::new(&dir);
+    Ok((dir, cache))
+  }
+
+  #[tokio::test]
+  async fn test_get_put() -> Result<()> {
+    let (dir, cache) = temp_cache("blobs")?;
+    let data = b"layer contents";
+    assert_eq!(cache.get(&digest(data)).await?, None);
+
+    cache.put(&digest(data), data).await?;
+    assert_eq!(cache.get(&digest(data)).await?.as_deref(), Some(&data[..]));
+    assert!(
+      matches!(cache.put(&digest(data), b"other").await, Err(Error::DigestMismatch(..))),
+      "blobs that don't match their digest should be refused"
+    );
+    assert_eq!(cache.get("sha512:abc").await?, None);
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_corrupted() -> Result<()> {
+    let (dir, cache) = temp_cache("blobs-corrupted")?;
+    let data = b"layer contents";
+    cache.put(&digest(data), data).await?;
+
+    let path = cache.path(&digest(data)).unwrap();
+    std::fs::write(&path, b"corrupted")?;
+    assert_eq!(cache.get(&digest(data)).await?, None);
+    assert!(!path.exists(), "corrupted blobs should be removed");
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_gc() -> Result<()> {
+    let (dir, cache) = temp_cache("blobs-gc")?;
+    let blobs: [&[u8]; 3] = [b"oldest blob", b"pinned blob", b"newest blob"];
+    for (i, data) in blobs.iter().enumerate() {
+      cache.put(&digest(data), data).await?;
+      let used = cache.path(&digest(data)).unwrap().with_extension("used");
+      std::fs::write(used, i.to_string())?;
+    }
+    cache.pin(&digest(blobs[1])).await?;
+
+    assert!(
+      cache.gc(100).await?.is_empty(),
+      "nothing should be evicted under the limit"
+    );
+    assert_eq!(cache.gc(22).await?, vec![digest(blobs[0])]);
+    assert_eq!(
+      cache.gc(0).await?,
+      vec![digest(blobs[2])],
+      "pinned blobs should never be evicted"
+    );
+    let entries = cache.entries().await?;
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0].pinned);
+
+    cache.unpin(&digest(blobs[1])).await?;
+    assert_eq!(cache.gc(0).await?, vec![digest(blobs[1])]);
+    assert!(cache.pin("md5:abc").await.is_err(), "only sha256 digests can be pinned");
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,231 @@
+use std::io::{Cursor, Read};
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use flate2::bufread::GzDecoder;
+use futures::{StreamExt, TryStreamExt};
+use oci_distribution::client::ImageLayer;
+use oci_distribution::manifest::OciDescriptor;
+use oci_distribution::{Client, Reference};
+use sha2::{Digest, Sha256};
+use tar::Archive;
+use tokio::io::AsyncWrite;
+
+use super::media_types;
+use crate::{AssetManifest, Error};
+
+/// Download `layers` with at most `concurrency` requests in flight, returning them in manifest order.
+pub(crate) async fn fetch_layers(
+  client: &Client,
+  image: &Reference,
+  layers: &[OciDescriptor],
+  concurrency: usize,
+) -> Result<Vec<ImageLayer>, Error> {
+  // the futures are collected up front, mapping them inside the stream makes the pull future not provably `Send`.
+  let downloads: Vec<_> = layers
+    .iter()
+    .map(|layer| async move {
+      let data = fetch_blob(client, image, layer).await?;
+      Ok::<_, Error>(ImageLayer::new(
+        data,
+        layer.media_type.clone(),
+        layer.annotations.clone(),
+      ))
+    })
+    .collect();
+  futures::stream::iter(downloads)
+    .buffered(concurrency.max(1))
+    .try_collect()
+    .await
+}
+
+/// Download a single blob, verifying its digest as the bytes arrive.
+pub(crate) async fn fetch_blob(
+  client: &Client,
+  image: &Reference,
+  descriptor: &OciDescriptor,
+) -> Result<Vec<u8>, Error> {
+  trace!(digest = %descriptor.digest, size = descriptor.size, "oci:blob:fetch");
+  let mut writer = DigestWriter::with_capacity(usize::try_from(descriptor.size).unwrap_or_default());
+  client
+    .pull_blob(image, &descriptor.digest, &mut writer)
+    .await
+    .map_err(|e| Error::PullFailed(e.to_string()))?;
+  writer.verify(&descriptor.digest)
+}
+
+/// Whether the copy of `layer` left at `path` by a previous pull can be used as is.
+pub(crate) async fn is_current(
+  layer: &OciDescriptor,
+  title: &str,
+  path: &Path,
+  previous: Option<&AssetManifest>,
+) -> bool {
+  if is_archive(&layer.media_type) {
+    // Extracted archives can't be hashed, so trust the digest recorded when they were unpacked.
+    return previous.and_then(|p| p.layers.get(title)) == Some(&layer.digest);
+  }
+  let Some(expected) = layer.digest.strip_prefix("sha256:") else {
+    return false;
+  };
+  tokio::fs::read(path).await.map_or(false, |data| {
+    format!("{:x}", Sha256::digest(data)).eq_ignore_ascii_case(expected)
+  })
+}
+
+/// Extract a compressed tarball layer into `dir`.
+pub(crate) fn unpack(media_type: &str, data: &[u8], dir: &Path) -> Result<(), Error> {
+  let cursor = Cursor::new(data);
+  let decoder: Box<dyn Read> = match media_type {
+    media_types::TARGZ => Box::new(GzDecoder::new(cursor)),
+    media_types::TARZSTD => Box::new(
+      zstd::stream::read::Decoder::new(cursor)
+        .map_err(|e| Error::UntarFile(dir.display().to_string(), e.to_string()))?,
+    ),
+    _ => return Err(Error::UnsupportedMediaType(media_type.to_owned())),
+  };
+  Archive::new(decoder)
+    .unpack(dir)
+    .map_err(|e| Error::UntarFile(dir.display().to_string(), e.to_string()))
+}
+
+/// Whether a layer of this media type is a tarball to extract rather than a single file.
+pub(crate) fn is_archive(media_type: &str) -> bool {
+  media_type == media_types::TARGZ || media_type == media_types::TARZSTD
+}
+
+/// Buffers a blob while hashing it so verification doesn't need a second pass over large layers.
+struct DigestWriter {
+  hasher: Sha256,
+  data: Vec<u8>,
+}
+
+impl DigestWriter {
+  fn with_capacity(capacity: usize) -> Self {
+    Self {
+      hasher: Sha256::new(),
+      data: Vec::with_capacity(capacity),
+    }
+  }
+
+  fn verify(self, expected: &str) -> Result<Vec<u8>, Error> {
+    let Some(expected_hex) = expected.strip_prefix("sha256:") else {
+      // We only compute sha256 digests, anything else is left to the registry to enforce.
+      debug!(digest = expected, "oci:blob:unverified_digest");
+      return Ok(self.data);
+    };
+    let actual = format!("{:x}", self.hasher.finalize());
+    if !actual.eq_ignore_ascii_case(expected_hex) {
+      return Err(Error::DigestMismatch(expected.to_owned(), format!("sha256:{}", actual)));
+    }
+    Ok(self.data)
+  }
+}
+
+impl AsyncWrite for DigestWriter {
+  fn poll_write(self: Pin<&mut Self>, _cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+    let this = self.get_mut();
+    this.hasher.update(buf);
+    this.data.extend_from_slice(buf);
+    Poll::Ready(Ok(buf.len()))
+  }
+
+  fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+    Poll::Ready(Ok(()))
+  }
+
+  fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+    Poll::Ready(Ok(()))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::time::{SystemTime, UNIX_EPOCH};
+
+  use anyhow::Result;
+  use tokio::io::AsyncWriteExt;
+
+  use super::*;
+
+  fn tarball() -> Result<Vec<u8>> {
+    let mut builder = tar::Builder::new(Vec::new());
+    let contents = b"name: test\n";
+    let mut header = tar::Header::new_gnu();
+    header.set_size(contents.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder.append_data(&mut header, "nested/component.yaml", &contents[..])?;
+    Ok(builder.into_inner()?)
+  }
+
+  #[tokio::test]
+  async fn test_digest_writer() -> Result<()> {
+    let data = b"layer contents";
+    let digest = format!("sha256:{:x}", Sha256::digest(data));
+
+    let mut writer = DigestWriter::with_capacity(0);
+    writer.write_all(&data[..7]).await?;
+    writer.write_all(&data[7..]).await?;
+    assert_eq!(writer.verify(&digest)?, data);
+
+    let mut writer = DigestWriter::with_capacity(0);
+    writer.write_all(b"tampered").await?;
+    assert!(matches!(writer.verify(&digest), Err(Error::DigestMismatch(..))));
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_is_current() -> Result<()> {
+    let dur = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    let dir = std::env::temp_dir().join(format!("wick-oci-delta-{}", dur.as_nanos()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("component.wasm");
+    std::fs::write(&path, b"old module")?;
+
+    let mut layer = OciDescriptor {
+      media_type: media_types::WASM.to_owned(),
+      digest: format!("sha256:{:x}", Sha256::digest(b"old module")),
+      ..Default::default()
+    };
+    assert!(is_current(&layer, "component.wasm", &path, None).await);
+    layer.digest = format!("sha256:{:x}", Sha256::digest(b"new module"));
+    assert!(!is_current(&layer, "component.wasm", &path, None).await);
+
+    let archive = OciDescriptor {
+      media_type: media_types::TARZSTD.to_owned(),
+      digest: "sha256:abc".to_owned(),
+      ..Default::default()
+    };
+    let previous = AssetManifest::new("component.yaml".into(), "0.1.0".to_owned())
+      .with_layers([("assets.tar.zst".to_owned(), "sha256:abc".to_owned())].into());
+    assert!(is_current(&archive, "assets.tar.zst", &dir, Some(&previous)).await);
+    assert!(!is_current(&archive, "assets.tar.zst", &dir, None).await);
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_unpack_zstd() -> Result<()> {
+    let dur = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    let dir = std::env::temp_dir().join(format!("wick-oci-zstd-{}", dur.as_nanos()));
+    std::fs::create_dir_all(&dir)?;
+
+    let compressed = zstd::encode_all(Cursor::new(tarball()?), 0)?;
+    assert!(is_archive(media_types::TARZSTD));
+    unpack(media_types::TARZSTD, &compressed, &dir)?;
+    assert_eq!(
+      std::fs::read_to_string(dir.join("nested/component.yaml"))?,
+      "name: test\n"
+    );
+
+    assert!(matches!(
+      unpack(media_types::WASM, &compressed, &dir),
+      Err(Error::UnsupportedMediaType(_))
+    ));
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+}
@@ -16,3 +16,9 @@ pub const WASM: &str = "application/wasm";
 pub const OTHER: &str = "application/octet-stream";
 /// Tarball gzipped media.
 pub const TARGZ: &str = "application/vnd.oci.image.layer.v1.tar+gzip";
+/// Tarball zstd-compressed media.
+pub const TARZSTD: &str = "application/vnd.oci.image.layer.v1.tar+zstd";
+/// Empty OCI config media type, for artifacts that carry no configuration.
+pub const EMPTY: &str = "application/vnd.oci.empty.v1+json";
+/// Wick package provenance media type.
+pub const PROVENANCE: &str = "application/vnd.wick.provenance.v1+json";
@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use oci_distribution::client::{ClientConfig, Config, ImageLayer};
+use oci_distribution::secrets::RegistryAuth;
+use oci_distribution::{Client, Reference};
+
+use super::{layers, media_types, PackageFile};
+use crate::{Error, OciOptions};
+
+/// A record of what a pushed package is made of and who pushed it, attached to the package in its registry.
+///
+/// It's pushed to the tag `sha256-<hex>.att` next to the package, like cosign attestations, so it can be fetched from
+/// the package's digest alone. Its files double as a bill of materials for the package.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Provenance {
+  /// The digest of the package's manifest.
+  pub subject: String,
+  /// The reference the package was pushed to.
+  pub reference: String,
+  /// The tool that built and pushed the package.
+  pub builder: String,
+  /// When the package was pushed, in milliseconds since the Unix epoch.
+  pub created: u64,
+  /// The package's annotations, e.g. its version, authors, and licenses.
+  pub annotations: BTreeMap<String, String>,
+  /// Every file in the package.
+  pub files: Vec<ProvenanceFile>,
+}
+
+/// A file in a package, as recorded in its [Provenance].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub struct ProvenanceFile {
+  /// The file's path in the package.
+  pub path: String,
+  /// The file's digest, as `sha256:<hex>`.
+  pub digest: String,
+  /// The file's media type.
+  pub media_type: String,
+  /// The file's size in bytes.
+  pub size: u64,
+}
+
+impl ProvenanceFile {
+  pub(crate) fn new(file: &PackageFile) -> Self {
+    Self {
+      path: file.package_path().display().to_string(),
+      digest: file.hash().to_owned(),
+      media_type: file.media_type().to_owned(),
+      size: file.contents().len() as u64,
+    }
+  }
+}
+
+impl Provenance {
+  pub(crate) fn new(
+    subject: String,
+    reference: String,
+    annotations: BTreeMap<String, String>,
+    files: Vec<ProvenanceFile>,
+  ) -> Self {
+    Self {
+      subject,
+      reference,
+      builder: format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+      created: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX)),
+      annotations,
+      files,
+    }
+  }
+}
+
+/// The reference the provenance of the package `image` with the manifest `digest` is pushed to.
+fn provenance_reference(image: &Reference, digest: &str) -> Reference {
+  Reference::with_tag(
+    image.registry().to_owned(),
+    image.repository().to_owned(),
+    format!("{}.att", digest.replace(':', "-")),
+  )
+}
+
+/// Push `provenance` for the package `image`.
+pub(crate) async fn push_provenance(
+  client: &mut Client,
+  auth: &RegistryAuth,
+  image: &Reference,
+  provenance: &Provenance,
+) -> Result<(), Error> {
+  let layer = ImageLayer::new(
+    serde_json::to_vec(provenance)?,
+    media_types::PROVENANCE.to_owned(),
+    None,
+  );
+  let config = Config {
+    data: b"{}".to_vec(),
+    media_type: media_types::EMPTY.to_owned(),
+    annotations: None,
+  };
+  let reference = provenance_reference(image, &provenance.subject);
+  client
+    .push(&reference, &[layer], config, auth, None)
+    .await
+    .map_err(|e| Error::PushFailed(format!("could not push the provenance to {}: {}", reference, e)))?;
+  debug!(%reference, "push:provenance");
+  Ok(())
+}
+
+/// Fetch the [Provenance] attached to the package at `reference`, or `None` if it has none.
+pub async fn pull_provenance(reference: &str, options: &OciOptions) -> Result<Option<Provenance>, Error> {
+  let (image, protocol) = crate::utils::parse_reference_and_protocol(reference, &options.allow_insecure)?;
+  let mut client = Client::new(ClientConfig {
+    protocol,
+    ..Default::default()
+  });
+  let auth = options.get_auth();
+  let (_, digest) = client
+    .pull_image_manifest(&image, &auth)
+    .await
+    .map_err(|e| Error::PullFailed(e.to_string()))?;
+
+  let attached = provenance_reference(&image, &digest);
+  let Ok((manifest, _)) = client.pull_image_manifest(&attached, &auth).await else {
+    debug!(%attached, "pull:provenance:none");
+    return Ok(None);
+  };
+  let Some(layer) = manifest.layers.iter().find(|l| l.media_type == media_types::PROVENANCE) else {
+    return Ok(None);
+  };
+  let data = layers::fetch_blob(&client, &attached, layer).await?;
+  let provenance: Provenance = serde_json::from_slice(&data)?;
+  if provenance.subject != digest {
+    return Err(Error::DigestMismatch(digest, provenance.subject));
+  }
+  Ok(Some(provenance))
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_provenance_reference() -> Result<()> {
+    let image: Reference = "registry.candle.dev/common/http:0.1.0".parse()?;
+    let reference = provenance_reference(&image, "sha256:abc");
+    assert_eq!(reference.whole(), "registry.candle.dev/common/http:sha256-abc.att");
+    Ok(())
+  }
+}
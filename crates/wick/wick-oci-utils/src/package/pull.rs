@@ -1,12 +1,11 @@
-use std::io::Cursor;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Component, Path, PathBuf};
 
-use flate2::bufread::GzDecoder;
-use oci_distribution::client::ClientConfig;
+use oci_distribution::client::{ClientConfig, ImageLayer};
+use oci_distribution::manifest::OciDescriptor;
 use oci_distribution::Client;
-use tar::Archive;
 
-use super::{annotations, media_types};
+use super::{annotations, layers, media_types, verify, BlobCache};
 use crate::utils::{create_directory_structure, get_cache_directory};
 use crate::{AssetManifest, Error, OciOptions, OnExisting};
 
@@ -24,6 +23,10 @@ pub struct PullResult {
 
 #[allow(clippy::too_many_lines)]
 /// Pull a Wick package from a registry.
+///
+/// Layers that are already on disk with the same digest are reused rather than downloaded again, so re-pulling a
+/// package only transfers the layers that changed. Blobs are also kept in a [BlobCache] shared by every package, unless
+/// it's disabled, so a blob pulled for one reference isn't downloaded again for another.
 pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, Error> {
   let (image_ref, protocol) = crate::utils::parse_reference_and_protocol(reference, &options.allow_insecure)?;
 
@@ -33,36 +36,48 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
     get_cache_directory(reference, &options.cache_dir)?
   };
 
-  let manifest_file = cache_dir.join(AssetManifest::FILENAME);
+  let blobs = options.blob_cache_dir.as_deref().map(BlobCache::new);
 
-  if !options.ignore_manifest && manifest_file.exists() {
-    debug!(cache_hit = true, "remote asset");
-    let json = tokio::fs::read_to_string(&manifest_file).await?;
+  let manifest_file = cache_dir.join(AssetManifest::FILENAME);
+  let previous = if options.ignore_manifest {
+    None
+  } else {
+    read_asset_manifest(&manifest_file).await?
+  };
 
-    //check if manifest file is valid json, if not then break out of if statement and continue
-    let manifest: Result<AssetManifest, serde_json::Error> = serde_json::from_str(&json);
-    if let Err(e) = manifest {
-      warn!(
-        "Invalid root manifest file at {}, repulling - error was {}",
-        manifest_file.display(),
-        e
-      );
-    } else {
-      //check if manifest.root file exists, if it does then return otherwise continue
-      let root_file = manifest.unwrap().root;
-      let root_filepath = cache_dir.join(&root_file);
-      debug!(file = %root_filepath.display(), "using cache");
-      if root_filepath.exists() {
-        return Ok(PullResult {
-          cached: true,
-          base_dir: cache_dir.clone(),
-          root_path: root_file,
-        });
+  // packages that must be signed are checked against the registry's signatures, unless that's impossible offline.
+  let check_registry = options.refresh || !options.trusted_keys.is_empty();
+  if let Some(previous) = &previous {
+    let root_filepath = cache_dir.join(&previous.root);
+    if root_filepath.exists() && (!check_registry || options.offline) {
+      if !options.trusted_keys.is_empty() {
+        let (modules, other_layers) = cached_contents(previous, &cache_dir).await?;
+        let digest = previous.digest.as_deref();
+        verify::verify(
+          None,
+          &options.get_auth(),
+          &image_ref,
+          digest,
+          &modules,
+          &other_layers,
+          &options.trusted_keys,
+        )
+        .await?;
       }
+      debug!(cache_hit = true, file = %root_filepath.display(), "remote asset");
+      return Ok(PullResult {
+        cached: true,
+        base_dir: cache_dir.clone(),
+        root_path: previous.root.clone(),
+      });
     }
   }
   debug!(cache_hit = false, "remote asset");
 
+  if options.offline {
+    return Err(Error::Offline(reference.to_owned()));
+  }
+
   let client_config = ClientConfig {
     protocol,
     ..Default::default()
@@ -71,7 +86,7 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
   let mut client = Client::new(client_config);
   let auth = options.get_auth();
 
-  let accepted_media_types = vec![
+  let accepted_media_types = [
     media_types::CONFIG,
     media_types::MANIFEST,
     media_types::APPLICATION,
@@ -81,43 +96,159 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
     media_types::WASM,
     media_types::OTHER,
     media_types::TARGZ,
+    media_types::TARZSTD,
   ];
 
-  let result = client.pull(&image_ref, &auth, accepted_media_types).await;
-
-  let image_data = match result {
-    Ok(pull_response) => {
-      debug!(reference = %image_ref.whole(), "image successfully pulled from the registry");
-      pull_response
-    }
-    Err(e) => {
-      return Err(Error::PullFailed(e.to_string()));
+  let (manifest, digest) = client
+    .pull_image_manifest(&image_ref, &auth)
+    .await
+    .map_err(|e| Error::PullFailed(e.to_string()))?;
+
+  if let Some(previous) = &previous {
+    if previous.digest.as_deref() == Some(digest.as_str()) && cache_dir.join(&previous.root).exists() {
+      if !options.trusted_keys.is_empty() {
+        let modules = wasm_modules(&manifest.layers, &[], &cache_dir).await?;
+        verify::verify(
+          Some(&mut client),
+          &auth,
+          &image_ref,
+          Some(&digest),
+          &modules,
+          &other_layers(&manifest.layers),
+          &options.trusted_keys,
+        )
+        .await?;
+      }
+      debug!(%digest, "remote asset unchanged");
+      return Ok(PullResult {
+        cached: true,
+        base_dir: cache_dir.clone(),
+        root_path: previous.root.clone(),
+      });
     }
-  };
+  }
+
+  if let Some(layer) = manifest
+    .layers
+    .iter()
+    .find(|l| !accepted_media_types.contains(&l.media_type.as_str()))
+  {
+    return Err(Error::UnsupportedMediaType(layer.media_type.clone()));
+  }
 
-  let version = match image_data.manifest {
-    Some(manifest) => manifest
+  let version = manifest
+    .annotations
+    .as_ref()
+    .and_then(|v| v.get(annotations::VERSION).cloned())
+    .ok_or(Error::NoVersion())?;
+
+  let mut titles = BTreeMap::new();
+  let mut modules = BTreeSet::new();
+  let mut root_file: Option<String> = None;
+  let mut changed = Vec::new();
+  for layer in &manifest.layers {
+    let layer_title = layer
       .annotations
       .as_ref()
-      .and_then(|v| v.get(annotations::VERSION).cloned())
-      .ok_or(Error::NoVersion())?,
-    None => {
-      return Err(Error::NoManifest);
+      .and_then(|v| v.get(annotations::TITLE).cloned())
+      .ok_or(Error::NoTitle)?;
+    // packages are laid out relative to the directory they're pulled to and can't write anywhere else.
+    if !is_relative_title(&layer_title) {
+      return Err(Error::LayerOutsidePackage(layer_title));
     }
-  };
 
-  create_directory_structure(&cache_dir).await?;
+    // This is only for backwards compatibility for very old packages that don't include root files.
+    if root_file.is_none()
+      && (layer.media_type == media_types::APPLICATION || layer.media_type == media_types::COMPONENT)
+    {
+      root_file = Some(layer_title.clone());
+    }
 
-  let mut root_file: Option<String> = None;
+    if layers::is_current(layer, &layer_title, &cache_dir.join(&layer_title), previous.as_ref()).await {
+      trace!(file = %layer_title, digest = %layer.digest, "cache:reuse");
+    } else {
+      changed.push(layer.clone());
+    }
+    if layer.media_type == media_types::WASM {
+      modules.insert(layer_title.clone());
+    }
+    titles.insert(layer_title, layer.digest.clone());
+  }
+  debug!(
+    total = manifest.layers.len(),
+    changed = changed.len(),
+    "layers to download"
+  );
+
+  // changed layers that are already in the blob cache are read from it rather than downloaded.
+  let mut image_layers = Vec::new();
+  let mut missing = Vec::new();
+  for layer in changed {
+    match cached_blob(blobs.as_ref(), &layer.digest).await {
+      Some(data) => image_layers.push(ImageLayer::new(data, layer.media_type, layer.annotations)),
+      None => missing.push(layer),
+    }
+  }
+  let cached_layers = image_layers.len();
+  let cached_config = cached_blob(blobs.as_ref(), &manifest.config.digest).await;
+  let config_cached = cached_config.is_some();
+
+  // Layers download concurrently and are verified as they stream in, the config blob is fetched alongside them.
+  let (config, downloaded) = futures::future::try_join(
+    async {
+      match cached_config {
+        Some(config) => Ok(config),
+        None => layers::fetch_blob(&client, &image_ref, &manifest.config).await,
+      }
+    },
+    layers::fetch_layers(&client, &image_ref, &missing, options.max_concurrent_downloads),
+  )
+  .await?;
+  debug!(
+    reference = %image_ref.whole(),
+    downloaded = downloaded.len(),
+    cached = cached_layers,
+    "image successfully pulled from the registry"
+  );
+  image_layers.extend(downloaded);
+
+  // nothing is written to the cache until the package is trusted.
+  if !options.trusted_keys.is_empty() {
+    let modules = wasm_modules(&manifest.layers, &image_layers, &cache_dir).await?;
+    verify::verify(
+      Some(&mut client),
+      &auth,
+      &image_ref,
+      Some(&digest),
+      &modules,
+      &other_layers(&manifest.layers),
+      &options.trusted_keys,
+    )
+    .await?;
+  }
+
+  if let Some(blobs) = &blobs {
+    if !config_cached {
+      store_blob(blobs, &manifest.config.digest, &config).await;
+    }
+    for (layer, image_layer) in missing.iter().zip(&image_layers[cached_layers..]) {
+      store_blob(blobs, &layer.digest, &image_layer.data).await;
+    }
+  }
+
+  create_directory_structure(&cache_dir).await?;
 
   let mut would_overwrite: Vec<PathBuf> = Vec::new();
-  for layer in &image_data.layers {
+  for layer in &image_layers {
     let layer_title = layer
       .annotations
       .as_ref()
       .and_then(|v| v.get(annotations::TITLE).cloned())
       .ok_or(Error::NoTitle)?;
     let layer_path = cache_dir.join(&layer_title);
+    if is_stale(previous.as_ref(), &layer_title) {
+      continue;
+    }
 
     // If canonicalize succeeds, the path exists and we would overwrite it.
     if let Ok(path) = layer_path.canonicalize() {
@@ -129,7 +260,7 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
     return Err(Error::WouldOverwrite(would_overwrite));
   }
 
-  for layer in image_data.layers {
+  for layer in image_layers {
     let layer_title = layer
       .annotations
       .and_then(|v| v.get(annotations::TITLE).cloned())
@@ -137,25 +268,15 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
     let layer_path = cache_dir.join(&layer_title);
     let parent_dir = layer_path.parent().ok_or(Error::InvalidLayerPath(layer_path.clone()))?;
 
-    if layer.media_type == media_types::TARGZ {
-      // Create a GzDecoder to uncompress the gzip data
-      let cursor = Cursor::new(&layer.data);
-      let gz_decoder = GzDecoder::new(cursor);
-
-      // Create a tar::Archive to extract the tar data
-      let mut archive = Archive::new(gz_decoder);
-
-      // Extract the tar files to the desired directory
-      archive
-        .unpack(parent_dir)
-        .map_err(|e| Error::UntarFile(parent_dir.to_str().unwrap().to_owned(), e.to_string()))?;
+    if layers::is_archive(&layer.media_type) {
+      layers::unpack(&layer.media_type, &layer.data, parent_dir)?;
     } else {
       // Create any subdirectories if they don't exist
       tokio::fs::create_dir_all(parent_dir)
         .await
         .map_err(|e| Error::CreateDir(parent_dir.to_path_buf(), e))?;
 
-      if layer_path.exists() {
+      if layer_path.exists() && !is_stale(previous.as_ref(), &layer_title) {
         match options.on_existing {
           OnExisting::Ignore => {
             debug!(file = %layer_path.display(), bytes = layer.data.len(), "cache:not_overwriting");
@@ -175,16 +296,8 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
           .map_err(|e| Error::WriteFile(layer_path, e))?;
       }
     }
-
-    // This is only for backwards compatibility for very old packages that don't include root files.
-    if root_file.is_none()
-      && (layer.media_type == media_types::APPLICATION || layer.media_type == media_types::COMPONENT)
-    {
-      root_file = Some(layer_title);
-    }
   }
 
-  let config = image_data.config.data;
   let config_str = std::str::from_utf8(&config);
   let root_file = match config_str {
     Ok(s) => {
@@ -200,11 +313,20 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
   let root_file = root_file.ok_or_else(|| Error::PackageReadFailed("No root file found".to_owned()))?;
 
   if !options.ignore_manifest {
-    let manifest = AssetManifest::new(PathBuf::from(&root_file), version);
+    let manifest = AssetManifest::new(PathBuf::from(&root_file), version)
+      .with_digest(digest)
+      .with_layers(titles)
+      .with_modules(modules);
     let contents = serde_json::to_string(&manifest).unwrap();
     tokio::fs::write(cache_dir.join(AssetManifest::FILENAME), contents).await?;
   }
 
+  if let Some(blobs) = &blobs {
+    if let Err(e) = blobs.gc(options.max_cache_size).await {
+      warn!(error = %e, "cache:blob:gc_failed");
+    }
+  }
+
   debug!(path = root_file, "Root file");
   Ok(PullResult {
     cached: false,
@@ -213,6 +335,119 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
   })
 }
 
+/// Whether a layer's title is a path inside the package, i.e. relative and without `..`.
+fn is_relative_title(title: &str) -> bool {
+  let path = Path::new(title);
+  path.components().next().is_some()
+    && path
+      .components()
+      .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// The WebAssembly modules among a package's `layers`, from the layers just `downloaded` or the copies a previous pull
+/// left in `cache_dir`.
+async fn wasm_modules(
+  layers: &[OciDescriptor],
+  downloaded: &[ImageLayer],
+  cache_dir: &Path,
+) -> Result<Vec<Vec<u8>>, Error> {
+  let mut modules = Vec::new();
+  for layer in layers.iter().filter(|l| l.media_type == media_types::WASM) {
+    let layer_title = title(layer.annotations.as_ref()).ok_or(Error::NoTitle)?;
+    match downloaded
+      .iter()
+      .find(|d| title(d.annotations.as_ref()) == Some(layer_title))
+    {
+      Some(downloaded) => modules.push(downloaded.data.clone()),
+      None => modules.push(tokio::fs::read(cache_dir.join(layer_title)).await?),
+    }
+  }
+  Ok(modules)
+}
+
+/// The titles of a package's `layers` that aren't WebAssembly modules.
+fn other_layers(layers: &[OciDescriptor]) -> Vec<String> {
+  layers
+    .iter()
+    .filter(|l| l.media_type != media_types::WASM)
+    .filter_map(|l| title(l.annotations.as_ref()).cloned())
+    .collect()
+}
+
+/// The title annotation of a layer.
+fn title(annotations: Option<&HashMap<String, String>>) -> Option<&String> {
+  annotations.and_then(|a| a.get(annotations::TITLE))
+}
+
+/// The WebAssembly modules a previous pull left in `cache_dir`, and the titles of its other layers. Layers are told
+/// apart by the media type the pull recorded, like [wasm_modules] does.
+async fn cached_contents(previous: &AssetManifest, cache_dir: &Path) -> Result<(Vec<Vec<u8>>, Vec<String>), Error> {
+  let mut modules = Vec::new();
+  let mut other_layers = Vec::new();
+  for title in previous.layers.keys() {
+    if previous.modules.contains(title) {
+      modules.push(tokio::fs::read(cache_dir.join(title)).await?);
+    } else {
+      other_layers.push(title.clone());
+    }
+  }
+  Ok((modules, other_layers))
+}
+
+/// The blob with `digest` from the blob cache, if there is one and it has the blob. The cache only saves downloads, so
+/// failing to read from it isn't an error.
+async fn cached_blob(blobs: Option<&BlobCache>, digest: &str) -> Option<Vec<u8>> {
+  blobs?.get(digest).await.unwrap_or_else(|e| {
+    warn!(digest, error = %e, "cache:blob:read_failed");
+    None
+  })
+}
+
+/// Keep a downloaded blob in the blob cache. Failing to isn't an error, the blob is downloaded again next time.
+async fn store_blob(blobs: &BlobCache, digest: &str, data: &[u8]) {
+  if let Err(e) = blobs.put(digest, data).await {
+    warn!(digest, error = %e, "cache:blob:write_failed");
+  }
+}
+
+/// Whether a changed layer replaces a file written by a previous pull, which is always overwritten.
+fn is_stale(previous: Option<&AssetManifest>, title: &str) -> bool {
+  previous.map_or(false, |p| p.layers.contains_key(title))
+}
+
+/// Read the manifest left by a previous pull, returning `None` if there isn't a usable one.
+async fn read_asset_manifest(path: &Path) -> Result<Option<AssetManifest>, Error> {
+  if !path.exists() {
+    return Ok(None);
+  }
+  let json = tokio::fs::read_to_string(path).await?;
+  match serde_json::from_str(&json) {
+    Ok(manifest) => Ok(Some(manifest)),
+    Err(e) => {
+      warn!(
+        "Invalid root manifest file at {}, repulling - error was {}",
+        path.display(),
+        e
+      );
+      Ok(None)
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_relative_title() {
+    assert!(is_relative_title("component.yaml"));
+    assert!(is_relative_title("./assets/icon.png"));
+    for title in ["", "/etc/passwd", "../outside.yaml", "assets/../../outside.yaml"] {
+      assert!(!is_relative_title(title), "'{}' should be refused", title);
+    }
+  }
+}
+
 #[cfg(test)]
 mod integration_test {
   use std::time::{SystemTime, UNIX_EPOCH};
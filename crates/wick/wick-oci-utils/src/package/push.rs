@@ -1,10 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use oci_distribution::client::{Client, ClientConfig, ImageLayer, PushResponse};
 use oci_distribution::manifest::{OciDescriptor, OciImageManifest};
 use sha256::digest;
 
 use super::annotations::Annotations;
+use super::provenance::{push_provenance, Provenance, ProvenanceFile};
 use super::{annotations, media_types, PackageFile};
 use crate::{Error, OciOptions};
 /// Push a Wick package to a registry.
@@ -23,6 +24,7 @@ pub async fn push(
 
   let mut image_layer_descriptors: Vec<OciDescriptor> = Vec::new();
   let mut image_layers: Vec<ImageLayer> = Vec::new();
+  let provenance_files: Vec<_> = files.iter().map(ProvenanceFile::new).collect();
 
   for file in files {
     let mut annotations_map: HashMap<String, String> = HashMap::new();
@@ -85,11 +87,26 @@ pub async fn push(
     )
     .await;
 
-  match result {
-    Ok(push_response) => Ok(push_response),
+  let push_response = match result {
+    Ok(push_response) => push_response,
     Err(e) => {
       tracing::error!(manifest = %image_manifest, error = %e, "Push failed");
-      Err(Error::PushFailed(e.to_string()))
+      return Err(Error::PushFailed(e.to_string()));
     }
+  };
+
+  // the package is already pushed, so failing to attach its provenance only warrants a warning.
+  let provenance = match client.pull_image_manifest(&image_ref, &auth).await {
+    Ok((_, digest)) => {
+      let annotations: BTreeMap<_, _> = annotations.inner().clone().into_iter().collect();
+      let provenance = Provenance::new(digest, image_ref.whole(), annotations, provenance_files);
+      push_provenance(&mut client, &auth, &image_ref, &provenance).await
+    }
+    Err(e) => Err(Error::PullFailed(e.to_string())),
+  };
+  if let Err(e) = provenance {
+    warn!(reference = %image_ref, error = %e, "could not attach provenance to the package");
   }
+
+  Ok(push_response)
 }
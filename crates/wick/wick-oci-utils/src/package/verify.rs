@@ -0,0 +1,276 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use oci_distribution::secrets::RegistryAuth;
+use oci_distribution::{Client, Reference};
+use ring::signature::{UnparsedPublicKey, ECDSA_P256_SHA256_ASN1};
+use wick_wascap::WickComponent;
+
+use super::layers;
+use crate::Error;
+
+/// The annotation cosign stores the base64 signature of a signature layer's payload in.
+const COSIGN_SIGNATURE: &str = "dev.cosignproject.cosign/signature";
+
+/// The DER encoding of a P-256 public key's SubjectPublicKeyInfo up to the key itself, an uncompressed point.
+const P256_SPKI_PREFIX: [u8; 26] = [
+  0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce,
+  0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+];
+
+/// A key trusted to sign pulled packages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TrustedKey {
+  /// The public key of an account that signs the claims embedded in WebAssembly modules.
+  Account(String),
+  /// A cosign public key, as the uncompressed point of a P-256 key.
+  Cosign(Vec<u8>),
+}
+
+impl TrustedKey {
+  /// Parse an account public key, like `ACOJJN6WUP4ODD75XEBKKTCCUJJCY5ZKQ56XVKYK4BEJWGVAOOQHZMCW`, or a PEM-encoded
+  /// cosign public key.
+  fn parse(key: &str) -> Result<Self, Error> {
+    let invalid = || Error::InvalidTrustedKey(key.to_owned());
+    let key = key.trim();
+    let Some(pem) = key.strip_prefix("-----BEGIN PUBLIC KEY-----") else {
+      return if key.len() == 56 && key.starts_with('A') {
+        Ok(Self::Account(key.to_owned()))
+      } else {
+        Err(invalid())
+      };
+    };
+    let body: String = pem
+      .trim_end()
+      .strip_suffix("-----END PUBLIC KEY-----")
+      .ok_or_else(invalid)?
+      .split_whitespace()
+      .collect();
+    let der = STANDARD.decode(body).map_err(|_| invalid())?;
+    match der.strip_prefix(&P256_SPKI_PREFIX) {
+      Some(point) if point.len() == 65 => Ok(Self::Cosign(point.to_vec())),
+      _ => Err(invalid()),
+    }
+  }
+}
+
+/// Check that the package `image` was signed by one of `keys`: either it only contains WebAssembly `modules` and every
+/// one embeds valid claims signed by a trusted account, or a cosign signature of its manifest `digest` verifies with a
+/// trusted cosign key. Claims only sign the module they're embedded in, so a package with `other_layers` can only be
+/// trusted through its manifest's signature. Cosign signatures are only checked when a `client` is given to fetch them
+/// with.
+pub(crate) async fn verify(
+  client: Option<&mut Client>,
+  auth: &RegistryAuth,
+  image: &Reference,
+  digest: Option<&str>,
+  modules: &[Vec<u8>],
+  other_layers: &[String],
+  keys: &[String],
+) -> Result<(), Error> {
+  let keys = keys
+    .iter()
+    .map(|k| TrustedKey::parse(k))
+    .collect::<Result<Vec<_>, _>>()?;
+  let accounts: Vec<_> = keys
+    .iter()
+    .filter_map(|k| match k {
+      TrustedKey::Account(account) => Some(account.as_str()),
+      TrustedKey::Cosign(_) => None,
+    })
+    .collect();
+  let cosign: Vec<_> = keys
+    .iter()
+    .filter_map(|k| match k {
+      TrustedKey::Cosign(point) => Some(point.as_slice()),
+      TrustedKey::Account(_) => None,
+    })
+    .collect();
+
+  let mut reasons = Vec::new();
+  if !accounts.is_empty() {
+    if let Some(layer) = other_layers.first() {
+      reasons.push(format!(
+        "its layer {} is not a WebAssembly module, so no module's claims sign it",
+        layer
+      ));
+    } else if modules.is_empty() {
+      reasons.push("it has no WebAssembly modules with embedded claims".to_owned());
+    } else {
+      match modules.iter().try_for_each(|module| check_claims(module, &accounts)) {
+        Ok(()) => return Ok(()),
+        Err(reason) => reasons.push(reason),
+      }
+    }
+  }
+  if !cosign.is_empty() {
+    match (client, digest) {
+      (Some(client), Some(digest)) => match check_cosign(client, auth, image, digest, &cosign).await {
+        Ok(()) => return Ok(()),
+        Err(reason) => reasons.push(reason),
+      },
+      _ => reasons.push("its cosign signatures can't be fetched offline".to_owned()),
+    }
+  }
+  Err(Error::UntrustedArtifact(image.whole(), reasons.join("; ")))
+}
+
+/// Check that `module` embeds valid claims issued by one of `accounts`.
+fn check_claims(module: &[u8], accounts: &[&str]) -> Result<(), String> {
+  let token = wick_wascap::extract_claims(module)
+    .map_err(|e| format!("its claims could not be read: {}", e))?
+    .ok_or_else(|| "a module has no embedded claims".to_owned())?;
+  let issuer = &token.claims.issuer;
+  let validation =
+    wick_wascap::validate_token::<WickComponent>(&token.jwt).map_err(|e| format!("its claims are invalid: {}", e))?;
+  if !validation.signature_valid || validation.expired || validation.cannot_use_yet {
+    return Err(format!("the claims signed by {} are not valid now", issuer));
+  }
+  if !accounts.contains(&issuer.as_str()) {
+    return Err(format!("a module is signed by the untrusted account {}", issuer));
+  }
+  Ok(())
+}
+
+/// Check that a cosign signature of the manifest `digest` of `image` verifies with one of `keys`.
+async fn check_cosign(
+  client: &mut Client,
+  auth: &RegistryAuth,
+  image: &Reference,
+  digest: &str,
+  keys: &[&[u8]],
+) -> Result<(), String> {
+  // cosign pushes the signatures of `sha256:<hex>` to the tag `sha256-<hex>.sig` in the same repository.
+  let signatures = Reference::with_tag(
+    image.registry().to_owned(),
+    image.repository().to_owned(),
+    format!("{}.sig", digest.replace(':', "-")),
+  );
+  let (manifest, _) = client
+    .pull_image_manifest(&signatures, auth)
+    .await
+    .map_err(|e| format!("no cosign signatures were found: {}", e))?;
+  for layer in &manifest.layers {
+    let Some(signature) = layer.annotations.as_ref().and_then(|a| a.get(COSIGN_SIGNATURE)) else {
+      continue;
+    };
+    let Ok(signature) = STANDARD.decode(signature) else {
+      continue;
+    };
+    let payload = layers::fetch_blob(client, &signatures, layer)
+      .await
+      .map_err(|e| e.to_string())?;
+    if signs_digest(&payload, digest) && is_signed(&payload, &signature, keys) {
+      return Ok(());
+    }
+  }
+  Err("none of its cosign signatures is from a trusted key".to_owned())
+}
+
+/// Whether a cosign signature payload is for the manifest `digest`.
+fn signs_digest(payload: &[u8], digest: &str) -> bool {
+  serde_json::from_slice::<serde_json::Value>(payload).map_or(false, |payload| {
+    payload["critical"]["image"]["docker-manifest-digest"] == digest
+  })
+}
+
+/// Whether `signature` is a signature of `payload` by one of the cosign `keys`.
+fn is_signed(payload: &[u8], signature: &[u8], keys: &[&[u8]]) -> bool {
+  keys.iter().any(|key| {
+    UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, key)
+      .verify(payload, signature)
+      .is_ok()
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  const COSIGN_KEY: &str = "-----BEGIN PUBLIC KEY-----
+MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAECSD4IWX1uboIYRpVQtULeQobZ2hL
+PMpMbP+pfFbGxYXBAj9v0UOkKNO+Y4D9u9MX9mUXEuyuOIYybWzQtEJCsA==
+-----END PUBLIC KEY-----";
+
+  #[test]
+  fn test_parse() -> Result<()> {
+    let account = "ACOJJN6WUP4ODD75XEBKKTCCUJJCY5ZKQ56XVKYK4BEJWGVAOOQHZMCW";
+    assert_eq!(TrustedKey::parse(account)?, TrustedKey::Account(account.to_owned()));
+    let TrustedKey::Cosign(point) = TrustedKey::parse(COSIGN_KEY)? else {
+      panic!("expected a cosign key");
+    };
+    assert_eq!(point.len(), 65);
+    assert_eq!(point[0], 0x04, "the point should be uncompressed");
+    for invalid in [
+      "MCOJJN6WUP4ODD75XEBKKTCCUJJCY5ZKQ56XVKYK4BEJWGVAOOQHZMCW",
+      "-----BEGIN PUBLIC KEY-----",
+    ] {
+      assert!(TrustedKey::parse(invalid).is_err(), "'{}' should be invalid", invalid);
+    }
+    Ok(())
+  }
+
+  /// A cosign payload for the digest `sha256:abc`, and its signature by [COSIGN_KEY].
+  const PAYLOAD: &[u8] = br#"{"critical":{"identity":{"docker-reference":"registry.candle.dev/common/http"},"image":{"docker-manifest-digest":"sha256:abc"},"type":"cosign container image signature"},"optional":null}"#;
+  const SIGNATURE: &str =
+    "MEYCIQCiYbiuQtYW6YcAVULACStT8M5wg+RKeNfcBwUqPxulHwIhALs4Jq84vuT9TWPoU5/bb88csY9SQzPAO7DFzLLkNxCj";
+
+  #[test]
+  fn test_cosign_signature() -> Result<()> {
+    assert!(signs_digest(PAYLOAD, "sha256:abc"));
+    assert!(!signs_digest(PAYLOAD, "sha256:def"), "other digests should not match");
+    assert!(!signs_digest(b"not json", "sha256:abc"));
+
+    let TrustedKey::Cosign(key) = TrustedKey::parse(COSIGN_KEY)? else {
+      panic!("expected a cosign key");
+    };
+    let signature = STANDARD.decode(SIGNATURE)?;
+    assert!(is_signed(PAYLOAD, &signature, &[&key]));
+    assert!(
+      !is_signed(b"{}", &signature, &[&key]),
+      "signatures of other payloads should not verify"
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_verify_offline() -> Result<()> {
+    let image: Reference = "registry.candle.dev/common/http:0.1.0".parse()?;
+    let result = verify(
+      None,
+      &RegistryAuth::Anonymous,
+      &image,
+      None,
+      &[],
+      &[],
+      &[COSIGN_KEY.to_owned()],
+    )
+    .await;
+    assert!(
+      matches!(result, Err(Error::UntrustedArtifact(_, _))),
+      "unverifiable packages should be untrusted"
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_verify_unsigned_layers() -> Result<()> {
+    let image: Reference = "registry.candle.dev/common/http:0.1.0".parse()?;
+    let result = verify(
+      None,
+      &RegistryAuth::Anonymous,
+      &image,
+      None,
+      &[],
+      &["component.wick".to_owned()],
+      &["ACOJJN6WUP4ODD75XEBKKTCCUJJCY5ZKQ56XVKYK4BEJWGVAOOQHZMCW".to_owned()],
+    )
+    .await;
+    let Err(Error::UntrustedArtifact(_, reason)) = result else {
+      panic!("packages with layers no account signs should be untrusted, got {:?}", result);
+    };
+    assert!(reason.contains("component.wick"), "{}", reason);
+    Ok(())
+  }
+}
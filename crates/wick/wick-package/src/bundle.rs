@@ -0,0 +1,91 @@
+use std::fs::File;
+use std::io::Cursor;
+use std::path::{Component, Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use tar::{Archive, Builder};
+use tokio::fs;
+use tracing::debug;
+use wick_config::WickConfiguration;
+use wick_oci_utils::package::media_types;
+use wick_oci_utils::OciOptions;
+
+use crate::package::collect_files;
+use crate::Error;
+
+/// The directory in a bundle that the packages it imports from registries are kept in, laid out like wick's cache.
+pub const BUNDLE_CACHE_DIR: &str = ".wick-cache";
+
+/// Write the app or component at `path` into `dir` along with everything it needs to run without a network: the local
+/// files it references and every package it imports from a registry, recursively. Returns the path of the bundled
+/// configuration.
+///
+/// Run the bundle by loading the bundled configuration with [bundle_options].
+pub async fn bundle(path: &Path, dir: &Path, options: &OciOptions) -> Result<PathBuf, Error> {
+  if path.is_dir() {
+    return Err(Error::Directory(path.to_path_buf()));
+  }
+  let config = WickConfiguration::fetch(path, options.clone()).await?.into_inner();
+  let (root, files) = collect_files(path, &config).await?;
+
+  fs::create_dir_all(dir)
+    .await
+    .map_err(|_| Error::DestinationDir(dir.display().to_string()))?;
+  for file in files {
+    let relative = file.package_path();
+    if relative.is_absolute() || relative.components().any(|c| c == Component::ParentDir) {
+      return Err(Error::InvalidFileLocation(relative.display().to_string()));
+    }
+    let dest = dir.join(relative);
+    if file.media_type() == media_types::TARGZ {
+      // the files listed under `package.files`, which are bundled as they are laid out next to the configuration.
+      Archive::new(GzDecoder::new(Cursor::new(file.contents())))
+        .unpack(dir)
+        .map_err(|e| Error::TarFile(dest, e))?;
+      continue;
+    }
+    if let Some(parent) = dest.parent() {
+      fs::create_dir_all(parent)
+        .await
+        .map_err(|_| Error::DestinationDir(parent.display().to_string()))?;
+    }
+    debug!(path = %dest.display(), "bundle:file");
+    fs::write(&dest, file.contents())
+      .await
+      .map_err(|e| Error::WriteFile(dest.clone(), e))?;
+  }
+
+  // fetching the bundled configuration's tree pulls every package it imports into the bundle's cache.
+  let root = dir.join(root);
+  let mut options = bundle_options(dir, options);
+  options.set_offline(false);
+  WickConfiguration::fetch_uninitialized_tree(&root, options).await?;
+  Ok(root)
+}
+
+/// Options that resolve packages only from the bundle in `dir`, failing rather than contacting a registry.
+pub fn bundle_options(dir: &Path, options: &OciOptions) -> OciOptions {
+  let mut options = options.clone();
+  options
+    .set_cache_dir(dir.join(BUNDLE_CACHE_DIR))
+    .set_blob_cache_dir(None)
+    .set_flatten(false)
+    .set_offline(true);
+  options
+}
+
+/// Pack the bundle in `dir` into the single gzipped tarball `archive`, which unpacks into the same bundle.
+pub fn archive_bundle(dir: &Path, archive: &Path) -> Result<(), Error> {
+  let file = File::create(archive).map_err(|e| Error::WriteFile(archive.to_path_buf(), e))?;
+  let mut tar = Builder::new(GzEncoder::new(file, Compression::default()));
+  tar
+    .append_dir_all(".", dir)
+    .map_err(|e| Error::TarFile(dir.to_path_buf(), e))?;
+  tar
+    .into_inner()
+    .and_then(GzEncoder::finish)
+    .map_err(Error::GzipFailed)?;
+  Ok(())
+}
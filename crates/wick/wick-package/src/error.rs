@@ -38,6 +38,10 @@ pub enum Error {
   #[error("Failed to read file '{0}': {1}")]
   ReadFile(PathBuf, #[source] std::io::Error),
 
+  /// Error returned when writing a file
+  #[error("Failed to write file '{}': {1}", .0.display())]
+  WriteFile(PathBuf, #[source] std::io::Error),
+
   /// Error returned when working with tar files
   #[error("Failed to read file '{}': {1}", .0.display())]
   TarFile(PathBuf, #[source] std::io::Error),
@@ -117,9 +117,11 @@
 // Add exceptions here
 #![allow()]
 
+mod bundle;
 mod error;
 mod package;
 mod utils;
 
+pub use bundle::*;
 pub use error::Error;
 pub use package::*;
@@ -43,7 +43,10 @@ fn process_assets(
       }
       seen_assets.insert(asset_path.clone());
 
-      let relative_path = asset_path.strip_prefix(&root_parent_dir).unwrap_or(&asset_path);
+      // packages are pulled into a single directory, so every file has to live under the root configuration's.
+      let relative_path = asset_path
+        .strip_prefix(&root_parent_dir)
+        .map_err(|_| Error::InvalidFileLocation(asset_path.display().to_string()))?;
 
       let options = wick_config::FetchOptions::default();
       let media_type: &str;
@@ -111,6 +114,77 @@ fn process_assets(
   Box::pin(task)
 }
 
+/// Collect the configuration at `path` and the local files it references, returning the name of the root file and
+/// every file with its path relative to the configuration's directory.
+pub(crate) async fn collect_files(
+  path: &Path,
+  config: &WickConfiguration,
+) -> Result<(String, Vec<PackageFile>), Error> {
+  let full_path = path
+    .normalize()
+    .map_err(|e| Error::ReadFile(path.to_path_buf(), e))?
+    .into_path_buf();
+
+  let parent_dir = full_path
+    .parent()
+    .map_or_else(|| PathBuf::from("/"), |v| v.to_path_buf());
+
+  let (media_type, extra_files) = match config {
+    WickConfiguration::App(config) => (media_types::APPLICATION, config.package_files().to_owned()),
+    WickConfiguration::Component(config) => (
+      media_types::COMPONENT,
+      config.package_files().map_or_else(Vec::new, |files| files.to_owned()),
+    ),
+    WickConfiguration::Types(config) => (
+      media_types::TYPES,
+      config.package_files().map_or_else(Vec::new, |files| files.to_owned()),
+    ),
+    _ => return Err(Error::InvalidWickConfig(path.to_string_lossy().to_string())),
+  };
+
+  let assets = config.assets();
+  let mut wick_files: Vec<PackageFile> = Vec::new();
+
+  let root_bytes = fs::read(path)
+    .await
+    .map_err(|e| Error::ReadFile(path.to_path_buf(), e))?;
+  let root_hash = format!("sha256:{}", digest(root_bytes.as_slice()));
+
+  let root_file = PackageFile::new(
+    PathBuf::from(path.file_name().unwrap()),
+    root_hash,
+    media_type.to_owned(),
+    root_bytes.into(),
+  );
+
+  let root_file_path = path.file_name().unwrap().to_string_lossy().to_string();
+  wick_files.push(root_file);
+
+  //if length of extra_files is greater than 0, then we need create a tar of all the files
+  //and add it to the files list.
+  if !extra_files.is_empty() {
+    let gz_bytes = create_tar_gz(extra_files, &parent_dir).await?;
+
+    let tar_hash = format!("sha256:{}", digest(gz_bytes.as_slice()));
+    let tar_file = PackageFile::new(
+      PathBuf::from("extra_files.tar.gz"),
+      tar_hash,
+      media_types::TARGZ.to_owned(),
+      gz_bytes.into(),
+    );
+    wick_files.push(tar_file);
+  }
+
+  //populate wick_files
+  let (_, return_assets) = process_assets(Default::default(), assets, parent_dir.clone(), parent_dir).await?;
+  //merge return assets  vector to wick_files
+  wick_files.extend(return_assets);
+  trace!(files = ?wick_files.iter().map(|f| f.package_path()).collect::<Vec<_>>(),
+    "package files"
+  );
+  Ok((root_file_path, wick_files))
+}
+
 /// Represents a Wick package, including its files and metadata.
 #[derive(Debug, Clone)]
 pub struct WickPackage {
@@ -130,7 +204,6 @@ impl WickPackage {
   ///
   /// The provided path can be a file or directory. If it is a directory, the WickPackage will be created
   /// based on the files within the directory.
-  #[allow(clippy::too_many_lines)]
   pub async fn from_path(basedir: Option<PathBuf>, path: &Path) -> Result<Self, Error> {
     let path = basedir
       .as_ref()
@@ -153,10 +226,6 @@ impl WickPackage {
       .map_err(|e| Error::ReadFile(path.clone(), e))?
       .into_path_buf();
 
-    let parent_dir = full_path
-      .parent()
-      .map_or_else(|| PathBuf::from("/"), |v| v.to_path_buf());
-
     if config.metadata().is_none() {
       return Err(Error::NoMetadata(path.to_string_lossy().to_string()));
     }
@@ -164,78 +233,16 @@ impl WickPackage {
     let annotations = metadata_to_annotations(config.metadata().unwrap());
     let kind = config.kind();
     let name = config.name().ok_or(Error::NoName)?;
-    let media_type = match kind {
-      wick_config::config::ConfigurationKind::App => media_types::APPLICATION,
-      wick_config::config::ConfigurationKind::Component => media_types::COMPONENT,
-      wick_config::config::ConfigurationKind::Types => media_types::TYPES,
-      wick_config::config::ConfigurationKind::Tests => unreachable!(),
-      wick_config::config::ConfigurationKind::Lockdown => unreachable!(),
-    };
     let registry = config.package().and_then(|package| package.registry().cloned());
 
-    let (version, extra_files) = match &config {
-      WickConfiguration::App(config) => {
-        let version = config.version();
-
-        let extra_files = config.package_files().to_owned();
-
-        (version, extra_files)
-      }
-      WickConfiguration::Component(config) => {
-        let version = config.version();
-
-        let extra_files = config.package_files().map_or_else(Vec::new, |files| files.to_owned());
-
-        (version, extra_files)
-      }
-      WickConfiguration::Types(config) => {
-        let version = config.version();
-
-        let extra_files = config.package_files().map_or_else(Vec::new, |files| files.to_owned());
-
-        (version, extra_files)
-      }
+    let version = match &config {
+      WickConfiguration::App(config) => config.version(),
+      WickConfiguration::Component(config) => config.version(),
+      WickConfiguration::Types(config) => config.version(),
       _ => return Err(Error::InvalidWickConfig(path.to_string_lossy().to_string())),
     };
 
-    let assets = config.assets();
-    let mut wick_files: Vec<PackageFile> = Vec::new();
-
-    let root_bytes = fs::read(&path).await.map_err(|e| Error::ReadFile(path.clone(), e))?;
-    let root_hash = format!("sha256:{}", digest(root_bytes.as_slice()));
-
-    let root_file = PackageFile::new(
-      PathBuf::from(path.file_name().unwrap()),
-      root_hash,
-      media_type.to_owned(),
-      root_bytes.into(),
-    );
-
-    let root_file_path = path.file_name().unwrap().to_string_lossy().to_string();
-    wick_files.push(root_file);
-
-    //if length of extra_files is greater than 0, then we need create a tar of all the files
-    //and add it to the files list.
-    if !extra_files.is_empty() {
-      let gz_bytes = create_tar_gz(extra_files, &parent_dir).await?;
-
-      let tar_hash = format!("sha256:{}", digest(gz_bytes.as_slice()));
-      let tar_file = PackageFile::new(
-        PathBuf::from("extra_files.tar.gz"),
-        tar_hash,
-        media_types::TARGZ.to_owned(),
-        gz_bytes.into(),
-      );
-      wick_files.push(tar_file);
-    }
-
-    //populate wick_files
-    let (_, return_assets) = process_assets(Default::default(), assets, parent_dir.clone(), parent_dir.clone()).await?;
-    //merge return assets  vector to wick_files
-    wick_files.extend(return_assets);
-    trace!(files = ?wick_files.iter().map(|f| f.package_path()).collect::<Vec<_>>(),
-      "package files"
-    );
+    let (root_file_path, wick_files) = collect_files(&path, &config).await?;
 
     Ok(Self {
       kind,
@@ -0,0 +1,37 @@
+mod integration_test {
+
+  use std::path::Path;
+
+  use anyhow::Result;
+  use wick_oci_utils::OciOptions;
+  use wick_package::{archive_bundle, bundle, bundle_options, BUNDLE_CACHE_DIR};
+
+  #[test_logger::test(tokio::test)]
+  async fn test_bundle_local_imports() -> Result<()> {
+    let tempdir = std::env::temp_dir().join("test_bundle_local_imports");
+    let _ = tokio::fs::remove_dir_all(&tempdir).await;
+    let dir = tempdir.join("bundle");
+
+    let root = bundle(Path::new("./tests/files/jinja.wick"), &dir, &OciOptions::default()).await?;
+
+    assert_eq!(root, dir.join("jinja.wick"));
+    for file in ["jinja.wick", "include.wick", "test.fake.wasm", "assets/static/bla.txt"] {
+      assert!(dir.join(file).exists(), "{} should be bundled", file);
+    }
+    assert!(
+      !dir.join("extra_files.tar.gz").exists(),
+      "package files should be unpacked"
+    );
+
+    let options = bundle_options(&dir, &OciOptions::default());
+    assert!(options.offline());
+    assert_eq!(options.cache_dir(), &dir.join(BUNDLE_CACHE_DIR));
+
+    let archive = tempdir.join("bundle.tar.gz");
+    archive_bundle(&dir, &archive)?;
+    assert!(archive.metadata()?.len() > 0);
+
+    tokio::fs::remove_dir_all(&tempdir).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,51 @@
+//! Compare `wick_packet::json` against plain `serde_json` on an API-style payload.
+//!
+//! Run with `cargo bench -p wick-packet --features simd-json` to measure the SIMD parser.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use serde_json::{json, Value};
+
+fn payload() -> Vec<u8> {
+  let users: Vec<Value> = (0..200)
+    .map(|i| {
+      json!({
+        "id": i,
+        "name": format!("user-{}", i),
+        "email": format!("user-{}@example.com", i),
+        "active": i % 3 != 0,
+        "score": f64::from(i) * 1.5,
+        "tags": ["alpha", "beta", "gamma"],
+        "address": {"street": "123 Main St", "city": "Springfield", "zip": "12345"},
+      })
+    })
+    .collect();
+  serde_json::to_vec(&json!({ "users": users, "total": 200, "page": 1 })).unwrap()
+}
+
+fn parse(c: &mut Criterion) {
+  let bytes = payload();
+  let mut group = c.benchmark_group("parse");
+  group.throughput(Throughput::Bytes(bytes.len() as u64));
+  group.bench_function("serde_json", |b| {
+    b.iter(|| serde_json::from_slice::<Value>(black_box(&bytes)).unwrap());
+  });
+  group.bench_function("wick_packet::json", |b| {
+    b.iter(|| wick_packet::json::from_slice::<Value>(black_box(&bytes)).unwrap());
+  });
+  group.finish();
+}
+
+fn serialize(c: &mut Criterion) {
+  let value: Value = serde_json::from_slice(&payload()).unwrap();
+  let mut group = c.benchmark_group("serialize");
+  group.bench_function("serde_json", |b| {
+    b.iter(|| serde_json::to_vec(black_box(&value)).unwrap());
+  });
+  group.bench_function("wick_packet::json", |b| {
+    b.iter(|| wick_packet::json::to_vec(black_box(&value)).unwrap());
+  });
+  group.finish();
+}
+
+criterion_group!(benches, parse, serialize);
+criterion_main!(benches);
@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
+
+use bytes::Bytes;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Error;
+
+static GLOBAL: OnceLock<BlobStore> = OnceLock::new();
+
+/// A reference to a blob in a [BlobStore]. It's sent through a flow in place of the blob so large payloads aren't
+/// copied between every operation that handles them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[must_use]
+#[non_exhaustive]
+pub struct BlobRef {
+  /// The hex-encoded blake3 digest of the blob.
+  #[serde(rename = "$blob")]
+  pub digest: String,
+  /// The size of the blob in bytes.
+  pub size: u64,
+}
+
+impl std::fmt::Display for BlobRef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "blob {} ({} bytes)", self.digest, self.size)
+  }
+}
+
+/// A content-addressed store of byte payloads, held for the transactions that put them.
+///
+/// Blobs are stored by their digest, so the same payload put twice is only stored once. A blob is dropped when every
+/// transaction that put it has been released, which the interpreter does when a transaction's last flow finishes.
+/// Operations reach the store through their context's `blobs()`, and WebAssembly guests through link calls to the
+/// runtime's `__blobs` component.
+#[derive(Debug, Default, Clone)]
+#[must_use]
+pub struct BlobStore {
+  inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+  blobs: HashMap<String, Blob>,
+  transactions: HashMap<Uuid, Transaction>,
+}
+
+#[derive(Debug)]
+struct Blob {
+  bytes: Bytes,
+  /// How many transactions hold the blob.
+  holders: usize,
+}
+
+#[derive(Debug, Default)]
+struct Transaction {
+  /// How many scopes are open for the transaction.
+  scopes: usize,
+  blobs: HashSet<String>,
+}
+
+impl BlobStore {
+  /// Create a new, empty [BlobStore].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The store shared by everything in the process.
+  pub fn global() -> &'static Self {
+    GLOBAL.get_or_init(Self::new)
+  }
+
+  /// Store `bytes` for the transaction `tx_id` and return a reference to them.
+  ///
+  /// The transaction must have a [BlobScope] open, so nothing is stored that would never be released.
+  pub fn put(&self, tx_id: Uuid, bytes: impl Into<Bytes>) -> Result<BlobRef, Error> {
+    let bytes = bytes.into();
+    let size = bytes.len() as u64;
+    let digest = blake3::hash(&bytes).to_hex().to_string();
+
+    let mut inner = self.inner.lock();
+    let Inner { blobs, transactions } = &mut *inner;
+    let tx = transactions.get_mut(&tx_id).ok_or(Error::NoBlobScope(tx_id))?;
+    let blob = blobs
+      .entry(digest.clone())
+      .or_insert_with(|| Blob { bytes, holders: 0 });
+    if tx.blobs.insert(digest.clone()) {
+      blob.holders += 1;
+    }
+    Ok(BlobRef { digest, size })
+  }
+
+  /// The bytes `blob` refers to, or [None] if every transaction that put them has been released.
+  #[must_use]
+  pub fn get(&self, blob: &BlobRef) -> Option<Bytes> {
+    self.inner.lock().blobs.get(&blob.digest).map(|b| b.bytes.clone())
+  }
+
+  /// Hold the blobs the transaction `tx_id` puts until every scope opened for it has been dropped.
+  pub fn scope(&self, tx_id: Uuid) -> BlobScope {
+    self.inner.lock().transactions.entry(tx_id).or_default().scopes += 1;
+    BlobScope {
+      store: self.clone(),
+      tx_id,
+    }
+  }
+
+  /// Drop the transaction `tx_id`'s hold on the blobs it put, dropping any blobs no other transaction holds.
+  pub fn release(&self, tx_id: Uuid) {
+    self.inner.lock().release(tx_id);
+  }
+
+  /// The number of blobs in the store.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.inner.lock().blobs.len()
+  }
+
+  /// Whether the store has no blobs.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.inner.lock().blobs.is_empty()
+  }
+}
+
+impl Inner {
+  fn release(&mut self, tx_id: Uuid) {
+    let Some(tx) = self.transactions.remove(&tx_id) else {
+      return;
+    };
+    for digest in tx.blobs {
+      if let Some(blob) = self.blobs.get_mut(&digest) {
+        blob.holders -= 1;
+        if blob.holders == 0 {
+          self.blobs.remove(&digest);
+        }
+      }
+    }
+  }
+}
+
+/// Holds a transaction's blobs in a [BlobStore] until it's dropped, unless another scope for the transaction is still
+/// open.
+#[derive(Debug)]
+#[must_use]
+pub struct BlobScope {
+  store: BlobStore,
+  tx_id: Uuid,
+}
+
+impl BlobScope {
+  /// The transaction the scope holds blobs for.
+  #[must_use]
+  pub const fn tx_id(&self) -> Uuid {
+    self.tx_id
+  }
+}
+
+impl Drop for BlobScope {
+  fn drop(&mut self) {
+    let mut inner = self.store.inner.lock();
+    let Some(tx) = inner.transactions.get_mut(&self.tx_id) else {
+      return;
+    };
+    tx.scopes = tx.scopes.saturating_sub(1);
+    if tx.scopes == 0 {
+      inner.release(self.tx_id);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_put_get() {
+    let store = BlobStore::new();
+    let tx = Uuid::new_v4();
+    let scope = store.scope(tx);
+    let blob = store.put(tx, vec![1_u8, 2, 3]).unwrap();
+    assert_eq!(blob.size, 3);
+    assert_eq!(store.get(&blob).unwrap(), vec![1_u8, 2, 3]);
+    // the same contents are only stored once.
+    assert_eq!(store.put(tx, vec![1_u8, 2, 3]).unwrap(), blob);
+    assert_eq!(store.len(), 1);
+
+    // blobs can't be put once the transaction is done, they'd never be released.
+    drop(scope);
+    assert_eq!(store.put(tx, "late"), Err(Error::NoBlobScope(tx)));
+    assert!(store.put(Uuid::new_v4(), "unknown").is_err());
+    assert!(store.is_empty());
+
+    let json = serde_json::to_value(&blob).unwrap();
+    assert_eq!(json["size"], 3);
+    assert_eq!(serde_json::from_value::<BlobRef>(json).unwrap(), blob);
+  }
+
+  #[test]
+  fn test_scopes() {
+    let store = BlobStore::new();
+    let (tx1, tx2) = (Uuid::new_v4(), Uuid::new_v4());
+    let outer = store.scope(tx1);
+    let inner = store.scope(tx1);
+    let shared = store.put(tx1, "shared").unwrap();
+    let own = store.put(tx1, "own").unwrap();
+
+    let other = store.scope(tx2);
+    let _ = store.put(tx2, "shared").unwrap();
+
+    // blobs are held until the transaction's last scope is dropped.
+    drop(inner);
+    assert_eq!(store.len(), 2);
+    drop(outer);
+    assert!(store.get(&own).is_none());
+    // a blob another transaction put is held until that transaction is done with it too.
+    assert!(store.get(&shared).is_some());
+    drop(other);
+    assert!(store.is_empty());
+  }
+}
@@ -44,6 +44,50 @@ impl RuntimeConfig {
       _ => Err(Error::BadJson(value)),
     }
   }
+
+  /// Merge `other` over this configuration. Objects present in both are merged key by key, any other value in `other`
+  /// replaces the one here.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use serde_json::json;
+  /// use wick_packet::RuntimeConfig;
+  ///
+  /// let base = RuntimeConfig::from([("db", json!({"host": "localhost", "port": 5432})), ("debug", true.into())]);
+  /// let overrides = RuntimeConfig::from([("db", json!({"host": "db.prod"})), ("debug", false.into())]);
+  /// let config = base.merge(overrides);
+  /// assert_eq!(config.get("db"), Some(&json!({"host": "db.prod", "port": 5432})));
+  /// assert_eq!(config.get("debug"), Some(&json!(false)));
+  /// ```
+  #[must_use]
+  pub fn merge(mut self, other: Self) -> Self {
+    for (key, value) in other.0 {
+      match self.0.get_mut(&key) {
+        Some(existing) => merge_value(existing, value),
+        None => {
+          self.0.insert(key, value);
+        }
+      }
+    }
+    self
+  }
+}
+
+fn merge_value(base: &mut Value, other: Value) {
+  match (base, other) {
+    (Value::Object(base), Value::Object(other)) => {
+      for (key, value) in other {
+        match base.get_mut(&key) {
+          Some(existing) => merge_value(existing, value),
+          None => {
+            base.insert(key, value);
+          }
+        }
+      }
+    }
+    (base, other) => *base = other,
+  }
 }
 
 impl IntoIterator for RuntimeConfig {
@@ -15,6 +15,10 @@ pub enum Error {
   #[error("Error deserializing payload '{}': {}",.as_json,.error)]
   Decode { as_json: String, error: String },
 
+  /// Error reading the structure of a messagepack payload.
+  #[error("Malformed messagepack payload: {0}")]
+  MalformedPayload(String),
+
   /// Error converting payload into JSON.
   #[error("Error JSON-ifying payload: {0}")]
   Jsonify(String),
@@ -39,6 +43,15 @@ pub enum Error {
   #[error("Got a Done signal in an unexpected context.")]
   UnexpectedDone,
 
+  /// Thrown when a blob is put for a transaction that isn't running.
+  #[cfg(feature = "blob")]
+  #[error("transaction {0} is not running, so it can't hold blobs")]
+  NoBlobScope(uuid::Uuid),
+
+  /// Thrown when an invocation is made after the deadline of the invocation that made it.
+  #[error("time budget exceeded: {0}")]
+  BudgetExceeded(String),
+
   /// Returned when an operation attempts to retrieve a configuration item that doesn't exist or decoding fails.
   #[error("Could not retrieve configuration item '{0}'")]
   ContextKey(String),
@@ -74,6 +87,16 @@ impl Error {
   pub fn component_error<T: Into<String>>(msg: T) -> Self {
     Self::Component(msg.into())
   }
+
+  /// Whether this error reports an exceeded time budget, here or in a nested invocation.
+  #[must_use]
+  pub fn is_budget_exceeded(&self) -> bool {
+    match self {
+      Self::BudgetExceeded(_) => true,
+      Self::PayloadError(e) => e.is_budget_exceeded(),
+      _ => false,
+    }
+  }
 }
 
 impl From<wasmrs_rx::Error> for Error {
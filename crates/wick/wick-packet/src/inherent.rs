@@ -10,12 +10,73 @@ pub struct InherentData {
   pub seed: u64,
   /// The timestamp to associate with an invocation.
   pub timestamp: u64,
+  /// The time, in milliseconds since the UNIX epoch, by which the invocation and everything it invokes must finish.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub deadline: Option<u64>,
+  /// How many sub-flow calls deep the invocation is.
+  #[serde(default, skip_serializing_if = "is_zero")]
+  pub call_depth: u32,
+  /// The transaction the invocation is part of. WebAssembly guests send it back with their link calls so the host can
+  /// tie what they do to it.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tx_id: Option<String>,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_zero(value: &u32) -> bool {
+  *value == 0
 }
 
 impl InherentData {
   /// Constructor for [InherentData]
   pub const fn new(seed: u64, timestamp: u64) -> Self {
-    Self { seed, timestamp }
+    Self {
+      seed,
+      timestamp,
+      deadline: None,
+      call_depth: 0,
+      tx_id: None,
+    }
+  }
+
+  /// Set the time, in milliseconds since the UNIX epoch, by which the invocation must finish.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_deadline(self, deadline: Option<u64>) -> Self {
+    Self { deadline, ..self }
+  }
+
+  /// Set how many sub-flow calls deep the invocation is.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_call_depth(self, call_depth: u32) -> Self {
+    Self { call_depth, ..self }
+  }
+
+  /// Set the transaction the invocation is part of.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_tx_id(self, tx_id: Option<String>) -> Self {
+    Self { tx_id, ..self }
+  }
+
+  /// The time left until the deadline, or [None] if the invocation has no deadline.
+  #[cfg(not(target_family = "wasm"))]
+  #[must_use]
+  pub fn remaining(&self) -> Option<std::time::Duration> {
+    let now = std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .unwrap_or_default();
+    self
+      .deadline
+      .map(|deadline| std::time::Duration::from_millis(deadline).saturating_sub(now))
+  }
+
+  /// Set the deadline to `remaining` from now.
+  #[cfg(not(target_family = "wasm"))]
+  pub fn with_remaining(self, remaining: std::time::Duration) -> Self {
+    let now = std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .unwrap_or_default();
+    let deadline = u64::try_from(now.saturating_add(remaining).as_millis()).unwrap_or(u64::MAX);
+    self.with_deadline(Some(deadline))
   }
 
   #[cfg(all(feature = "rng", not(target_family = "wasm")))]
@@ -28,6 +89,9 @@ impl InherentData {
         .as_millis()
         .try_into()
         .unwrap(),
+      deadline: self.deadline,
+      call_depth: self.call_depth,
+      tx_id: self.tx_id.clone(),
     }
   }
 
@@ -45,6 +109,9 @@ impl InherentData {
         .as_millis()
         .try_into()
         .unwrap(),
+      deadline: None,
+      call_depth: 0,
+      tx_id: None,
     }
   }
 
@@ -52,13 +119,40 @@ impl InherentData {
   ///
   /// This is not "unsafe" in the Rust sense. It is unsafe because it should
   /// only be used if you are sure you know what you're doing. If you don't know why this is unsafe, don't use it.
-  pub const fn unsafe_clone(&self) -> Self {
+  pub fn unsafe_clone(&self) -> Self {
     Self {
       seed: self.seed,
       timestamp: self.timestamp,
+      deadline: self.deadline,
+      call_depth: self.call_depth,
+      tx_id: self.tx_id.clone(),
     }
   }
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+  use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+  use super::*;
+
+  #[test]
+  fn test_remaining() {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+    let inherent = InherentData::new(0, now);
+    assert_eq!(inherent.remaining(), None);
+
+    let inherent = inherent.with_deadline(Some(now + 60_000));
+    let remaining = inherent.remaining().unwrap();
+    assert!(remaining > Duration::from_secs(50) && remaining <= Duration::from_secs(60));
+    assert_eq!(inherent.unsafe_clone().deadline, Some(now + 60_000));
+    assert_eq!(inherent.with_call_depth(2).unsafe_clone().call_depth, 2);
+
+    let expired = InherentData::new(0, now).with_deadline(Some(now - 1));
+    assert_eq!(expired.remaining(), Some(Duration::ZERO));
+
+    let inherent = InherentData::new(0, now).with_remaining(Duration::from_secs(60));
+    let remaining = inherent.remaining().unwrap();
+    assert!(remaining > Duration::from_secs(50) && remaining <= Duration::from_secs(60));
+  }
+}
@@ -0,0 +1,70 @@
+//! JSON encoding and decoding for payloads that arrive as or leave as JSON text.
+//!
+//! These functions use [serde_json] by default. With the `simd-json` feature enabled they use the SIMD-accelerated
+//! parser from [simd-json](https://docs.rs/simd-json) instead. Errors are always reported as [serde_json::Error] so
+//! callers don't need to care which implementation is active.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Deserialize a value from JSON bytes.
+///
+/// The SIMD parser works in place, so this copies `bytes` when the `simd-json` feature is enabled. Prefer
+/// [from_slice_mut] when you own a buffer that can be overwritten.
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, serde_json::Error> {
+  #[cfg(feature = "simd-json")]
+  {
+    from_slice_mut(&mut bytes.to_vec())
+  }
+  #[cfg(not(feature = "simd-json"))]
+  {
+    serde_json::from_slice(bytes)
+  }
+}
+
+/// Deserialize a value from a JSON buffer that may be modified while parsing.
+pub fn from_slice_mut<T: DeserializeOwned>(bytes: &mut [u8]) -> Result<T, serde_json::Error> {
+  #[cfg(feature = "simd-json")]
+  {
+    simd_json::serde::from_slice(bytes).map_err(<serde_json::Error as serde::de::Error>::custom)
+  }
+  #[cfg(not(feature = "simd-json"))]
+  {
+    serde_json::from_slice(bytes)
+  }
+}
+
+/// Serialize a value into JSON bytes.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+  #[cfg(feature = "simd-json")]
+  {
+    simd_json::serde::to_vec(value).map_err(<serde_json::Error as serde::ser::Error>::custom)
+  }
+  #[cfg(not(feature = "simd-json"))]
+  {
+    serde_json::to_vec(value)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::{json, Value};
+
+  use super::*;
+
+  #[test]
+  fn test_roundtrip() -> Result<()> {
+    let value = json!({"name": "wick", "tags": ["a", "b"], "count": 3, "ratio": 0.5, "nested": {"ok": true}});
+    let bytes = to_vec(&value)?;
+    assert_eq!(from_slice::<Value>(&bytes)?, value);
+    assert_eq!(from_slice_mut::<Value>(&mut bytes.clone())?, value);
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid() -> Result<()> {
+    assert!(from_slice::<Value>(b"{\"unterminated\": ").is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,328 @@
+use serde::de::{DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::Value;
+
+use crate::Error;
+
+/// A view into a messagepack encoded payload that can read individual fields without decoding the whole value.
+///
+/// Fields are located by walking the encoded bytes and skipping over everything that isn't on the requested path,
+/// so only the value that is eventually decoded is allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use]
+pub struct LazyPayload<'a> {
+  bytes: &'a [u8],
+}
+
+impl<'a> LazyPayload<'a> {
+  /// Create a view over messagepack encoded bytes.
+  pub const fn new(bytes: &'a [u8]) -> Self {
+    Self { bytes }
+  }
+
+  /// The encoded bytes of the value this view points to.
+  #[must_use]
+  pub const fn as_bytes(&self) -> &'a [u8] {
+    self.bytes
+  }
+
+  /// Get the value of `key` if this is a map that contains it.
+  pub fn get(&self, key: &str) -> Result<Option<LazyPayload<'a>>, Error> {
+    let (header, mut pos) = read_header(self.bytes, 0)?;
+    let Header::Map(len) = header else {
+      return Ok(None);
+    };
+    for _ in 0..len {
+      let key_start = pos;
+      let (key_header, key_pos) = read_header(self.bytes, pos)?;
+      pos = skip(self.bytes, key_start)?;
+      let value_start = pos;
+      pos = skip(self.bytes, pos)?;
+      if let Header::Str(len) = key_header {
+        if self.bytes.get(key_pos..key_pos + len) == Some(key.as_bytes()) {
+          return Ok(Some(Self::new(&self.bytes[value_start..pos])));
+        }
+      }
+    }
+    Ok(None)
+  }
+
+  /// Get the item at `index` if this is a list that is long enough.
+  pub fn index(&self, index: usize) -> Result<Option<LazyPayload<'a>>, Error> {
+    let (header, mut pos) = read_header(self.bytes, 0)?;
+    let Header::Array(len) = header else {
+      return Ok(None);
+    };
+    if index >= len {
+      return Ok(None);
+    }
+    for _ in 0..index {
+      pos = skip(self.bytes, pos)?;
+    }
+    let end = skip(self.bytes, pos)?;
+    Ok(Some(Self::new(&self.bytes[pos..end])))
+  }
+
+  /// Follow a path of map keys and list indices, returning the value at the end of it.
+  pub fn path<T: AsRef<str>>(&self, path: &[T]) -> Result<Option<LazyPayload<'a>>, Error> {
+    let mut current = *self;
+    for part in path {
+      let part = part.as_ref();
+      let next = match read_header(current.bytes, 0)?.0 {
+        Header::Map(_) => current.get(part)?,
+        Header::Array(_) => match part.parse() {
+          Ok(i) => current.index(i)?,
+          Err(_) => None,
+        },
+        _ => None,
+      };
+      match next {
+        Some(next) => current = next,
+        None => return Ok(None),
+      }
+    }
+    Ok(Some(current))
+  }
+
+  /// Decode the value this view points to.
+  pub fn decode<T: DeserializeOwned>(&self) -> Result<T, Error> {
+    wasmrs_codec::messagepack::deserialize(self.bytes).map_err(|e| Error::Decode {
+      as_json: wasmrs_codec::messagepack::deserialize::<Value>(self.bytes)
+        .map_or_else(|_e| "could not convert".to_owned(), |v| v.to_string()),
+      error: e.to_string(),
+    })
+  }
+
+  /// Decode the value this view points to as a [serde_json::Value].
+  pub fn decode_value(&self) -> Result<Value, Error> {
+    self.decode()
+  }
+}
+
+/// Decode the value at the end of a path of map keys and list indices in a JSON document, skipping over everything
+/// else without allocating it.
+pub fn json_path<T: AsRef<str>>(json: &[u8], path: &[T]) -> Result<Option<Value>, Error> {
+  let mut deserializer = serde_json::Deserializer::from_slice(json);
+  PathSeed(path)
+    .deserialize(&mut deserializer)
+    .map_err(|e| Error::Decode {
+      as_json: String::from_utf8_lossy(json).into_owned(),
+      error: e.to_string(),
+    })
+}
+
+enum Header {
+  /// A value with no nested items that takes up the given number of bytes after its header.
+  Scalar(usize),
+  Str(usize),
+  Array(usize),
+  Map(usize),
+}
+
+fn truncated() -> Error {
+  Error::MalformedPayload("unexpected end of data".to_owned())
+}
+
+fn read_len(bytes: &[u8], pos: usize, width: usize) -> Result<usize, Error> {
+  let slice = bytes.get(pos..pos + width).ok_or_else(truncated)?;
+  Ok(slice.iter().fold(0, |acc, b| (acc << 8) | *b as usize))
+}
+
+/// Read the header of the value at `pos`, returning it and the position its contents start at.
+fn read_header(bytes: &[u8], pos: usize) -> Result<(Header, usize), Error> {
+  let marker = *bytes.get(pos).ok_or_else(truncated)?;
+  let pos = pos + 1;
+  Ok(match marker {
+    0x00..=0x7f | 0xe0..=0xff | 0xc0 | 0xc2 | 0xc3 => (Header::Scalar(0), pos),
+    0x80..=0x8f => (Header::Map((marker & 0x0f) as usize), pos),
+    0x90..=0x9f => (Header::Array((marker & 0x0f) as usize), pos),
+    0xa0..=0xbf => (Header::Str((marker & 0x1f) as usize), pos),
+    0xc4 => (Header::Scalar(read_len(bytes, pos, 1)?), pos + 1),
+    0xc5 => (Header::Scalar(read_len(bytes, pos, 2)?), pos + 2),
+    0xc6 => (Header::Scalar(read_len(bytes, pos, 4)?), pos + 4),
+    // Extension types are followed by a one byte type tag.
+    0xc7 => (Header::Scalar(read_len(bytes, pos, 1)? + 1), pos + 1),
+    0xc8 => (Header::Scalar(read_len(bytes, pos, 2)? + 1), pos + 2),
+    0xc9 => (Header::Scalar(read_len(bytes, pos, 4)? + 1), pos + 4),
+    0xca => (Header::Scalar(4), pos),
+    0xcb => (Header::Scalar(8), pos),
+    0xcc | 0xd0 => (Header::Scalar(1), pos),
+    0xcd | 0xd1 => (Header::Scalar(2), pos),
+    0xce | 0xd2 => (Header::Scalar(4), pos),
+    0xcf | 0xd3 => (Header::Scalar(8), pos),
+    0xd4 => (Header::Scalar(2), pos),
+    0xd5 => (Header::Scalar(3), pos),
+    0xd6 => (Header::Scalar(5), pos),
+    0xd7 => (Header::Scalar(9), pos),
+    0xd8 => (Header::Scalar(17), pos),
+    0xd9 => (Header::Str(read_len(bytes, pos, 1)?), pos + 1),
+    0xda => (Header::Str(read_len(bytes, pos, 2)?), pos + 2),
+    0xdb => (Header::Str(read_len(bytes, pos, 4)?), pos + 4),
+    0xdc => (Header::Array(read_len(bytes, pos, 2)?), pos + 2),
+    0xdd => (Header::Array(read_len(bytes, pos, 4)?), pos + 4),
+    0xde => (Header::Map(read_len(bytes, pos, 2)?), pos + 2),
+    0xdf => (Header::Map(read_len(bytes, pos, 4)?), pos + 4),
+    0xc1 => return Err(Error::MalformedPayload("invalid marker 0xc1".to_owned())),
+  })
+}
+
+/// Skip the value at `pos`, returning the position of the value after it.
+fn skip(bytes: &[u8], mut pos: usize) -> Result<usize, Error> {
+  let mut remaining = 1_usize;
+  while remaining > 0 {
+    remaining -= 1;
+    let (header, next) = read_header(bytes, pos)?;
+    pos = match header {
+      Header::Scalar(len) | Header::Str(len) => next + len,
+      Header::Array(len) => {
+        remaining += len;
+        next
+      }
+      Header::Map(len) => {
+        remaining += len * 2;
+        next
+      }
+    };
+  }
+  if pos > bytes.len() {
+    return Err(truncated());
+  }
+  Ok(pos)
+}
+
+struct PathSeed<'p, T>(&'p [T]);
+
+impl<'de, 'p, T: AsRef<str>> DeserializeSeed<'de> for PathSeed<'p, T> {
+  type Value = Option<Value>;
+
+  fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    match self.0.split_first() {
+      None => serde::Deserialize::deserialize(deserializer).map(Some),
+      Some((part, rest)) => deserializer.deserialize_any(PathVisitor { part, rest }),
+    }
+  }
+}
+
+struct PathVisitor<'p, T> {
+  part: &'p T,
+  rest: &'p [T],
+}
+
+impl<'de, 'p, T: AsRef<str>> Visitor<'de> for PathVisitor<'p, T> {
+  type Value = Option<Value>;
+
+  fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    formatter.write_str("any JSON value")
+  }
+
+  fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+    let mut found = None;
+    while let Some(key) = map.next_key::<std::borrow::Cow<str>>()? {
+      if found.is_none() && key == self.part.as_ref() {
+        found = map.next_value_seed(PathSeed(self.rest))?;
+      } else {
+        map.next_value::<IgnoredAny>()?;
+      }
+    }
+    Ok(found)
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+    let index: Option<usize> = self.part.as_ref().parse().ok();
+    let mut found = None;
+    let mut i = 0;
+    loop {
+      if Some(i) == index {
+        match seq.next_element_seed(PathSeed(self.rest))? {
+          Some(value) => found = value,
+          None => break,
+        }
+      } else if seq.next_element::<IgnoredAny>()?.is_none() {
+        break;
+      }
+      i += 1;
+    }
+    Ok(found)
+  }
+
+  fn visit_bool<E>(self, _v: bool) -> Result<Self::Value, E> {
+    Ok(None)
+  }
+
+  fn visit_i64<E>(self, _v: i64) -> Result<Self::Value, E> {
+    Ok(None)
+  }
+
+  fn visit_u64<E>(self, _v: u64) -> Result<Self::Value, E> {
+    Ok(None)
+  }
+
+  fn visit_f64<E>(self, _v: f64) -> Result<Self::Value, E> {
+    Ok(None)
+  }
+
+  fn visit_str<E>(self, _v: &str) -> Result<Self::Value, E> {
+    Ok(None)
+  }
+
+  fn visit_unit<E>(self) -> Result<Self::Value, E> {
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+  use crate::Packet;
+
+  #[test]
+  fn test_lazy_payload() -> Result<()> {
+    let value = json!({
+      "id": 42,
+      "blob": "x".repeat(300),
+      "items": [{"name": "first", "tags": ["a", "b"]}, {"name": "second", "price": 1.5}],
+      "nested": {"deep": {"value": true}},
+      "empty": null
+    });
+    let packet = Packet::encode("input", value);
+    let lazy = packet.lazy()?;
+
+    assert_eq!(lazy.get("id")?.unwrap().decode::<u32>()?, 42);
+    assert_eq!(
+      lazy.path(&["items", "1", "name"])?.unwrap().decode::<String>()?,
+      "second"
+    );
+    assert_eq!(
+      lazy.path(&["items", "0", "tags", "1"])?.unwrap().decode_value()?,
+      json!("b")
+    );
+    assert_eq!(
+      lazy.path(&["nested", "deep"])?.unwrap().decode_value()?,
+      json!({"value": true})
+    );
+    assert_eq!(lazy.path(&["items", "1", "price"])?.unwrap().decode::<f64>()?, 1.5);
+    assert_eq!(lazy.path(&["empty"])?.unwrap().decode_value()?, Value::Null);
+    assert!(lazy.path(&["items", "2"])?.is_none());
+    assert!(lazy.path(&["id", "nope"])?.is_none());
+    assert!(lazy.get("missing")?.is_none());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_json_path() -> Result<()> {
+    let json = br#"{"skip": {"a": [1, 2, 3]}, "items": [{"name": "first"}, {"name": "second"}]}"#;
+
+    assert_eq!(json_path(json, &["items", "1", "name"])?, Some(json!("second")));
+    assert_eq!(json_path(json, &["skip"])?, Some(json!({"a": [1, 2, 3]})));
+    assert_eq!(json_path(json, &["items", "5"])?, None);
+    assert_eq!(json_path::<&str>(json, &[])?, Some(serde_json::from_slice(json)?));
+
+    Ok(())
+  }
+}
@@ -115,6 +115,8 @@
 #![allow(missing_docs)]
 
 mod b64_bytes;
+#[cfg(feature = "blob")]
+mod blob;
 mod collection_link;
 mod context;
 #[cfg(feature = "datetime")]
@@ -125,6 +127,8 @@ mod inherent;
 mod input;
 #[cfg(feature = "invocation")]
 mod invocation;
+mod lazy;
+mod lineage;
 mod macros;
 mod metadata;
 mod output;
@@ -134,9 +138,12 @@ mod stream_map;
 mod vpacket;
 mod wrapped_type;
 
+pub mod json;
 #[cfg(feature = "validation")]
 pub mod validation;
 
+#[cfg(feature = "blob")]
+pub use blob::{BlobRef, BlobScope, BlobStore};
 pub use collection_link::ComponentReference;
 pub use context::{ContextTransport, InvocationRequest, RuntimeConfig};
 #[cfg(feature = "datetime")]
@@ -147,15 +154,25 @@ pub use inherent::InherentData;
 pub use input::{BinaryInputs, UnaryInputs};
 #[cfg(feature = "invocation")]
 pub use invocation::{Invocation, InvocationData};
+pub use lazy::{json_path, LazyPayload};
+pub use lineage::Lineage;
 pub use metadata::{Flags, WickMetadata, CLOSE_BRACKET, DONE_FLAG, OPEN_BRACKET};
 pub use output::{OutgoingPort, OutputIterator, Port, ValuePort, WasmRsChannel};
-pub use packet::{from_raw_wasmrs, from_wasmrs, packetstream_to_wasmrs, Packet, PacketError, PacketExt, PacketPayload};
+pub use packet::{
+  from_raw_wasmrs,
+  from_wasmrs,
+  packetstream_to_wasmrs,
+  Packet,
+  PacketError,
+  PacketErrorKind,
+  PacketExt,
+  PacketPayload,
+};
 pub use packet_stream::{into_packet, BoxStream, PacketSender, PacketStream};
 pub use stream_map::StreamMap;
 pub use vpacket::VPacket;
 pub use wasmrs::Metadata;
-pub use wasmrs_rx;
-pub use wasmrs_rx::{Flux, FluxChannel, FluxReceiver, Mono, Observable, Observer};
+pub use wasmrs_rx::{self, Flux, FluxChannel, FluxReceiver, Mono, Observable, Observer};
 pub use wrapped_type::TypeWrapper;
 
 #[cfg(feature = "rt-tokio")]
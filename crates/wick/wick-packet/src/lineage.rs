@@ -0,0 +1,27 @@
+/// The minimal lineage a [crate::Packet] carries so its path through a flow can be reconstructed.
+///
+/// Lineage is only tracked in memory and is not transmitted with a packet over the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Lineage {
+  /// The index of the node that emitted the packet.
+  pub node: usize,
+  /// The index of the output port on the node that emitted the packet.
+  pub port: usize,
+  /// An identifier for the hop, unique within a transaction.
+  pub hop: u64,
+}
+
+impl Lineage {
+  /// Create a new [Lineage] for a packet emitted from the given node and port.
+  #[must_use]
+  pub const fn new(node: usize, port: usize, hop: u64) -> Self {
+    Self { node, port, hop }
+  }
+}
+
+impl std::fmt::Display for Lineage {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}.{}#{}", self.node, self.port, self.hop)
+  }
+}
@@ -7,7 +7,17 @@ use wick_interface_types::Type;
 
 use crate::metadata::DONE_FLAG;
 use crate::wrapped_type::coerce;
-use crate::{Base64Bytes, Error, PacketStream, TypeWrapper, WickMetadata, CLOSE_BRACKET, OPEN_BRACKET};
+use crate::{
+  Base64Bytes,
+  Error,
+  LazyPayload,
+  Lineage,
+  PacketStream,
+  TypeWrapper,
+  WickMetadata,
+  CLOSE_BRACKET,
+  OPEN_BRACKET,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[must_use]
@@ -15,6 +25,8 @@ pub struct Packet {
   pub(crate) metadata: Metadata,
   pub(crate) extra: WickMetadata,
   pub payload: PacketPayload,
+  #[serde(skip)]
+  pub(crate) lineage: Option<Lineage>,
 }
 
 impl PartialEq for Packet {
@@ -33,6 +45,8 @@ impl Packet {
   /// The port name that indicates a component-wide fatal error.
   pub const FATAL_ERROR: &str = "<error>";
   pub const NO_INPUT: &str = "<>";
+  /// The port name of the signal a host sends to tell a component to stop work on an invocation.
+  pub const CANCEL: &str = "<cancel>";
 
   /// Create a new packet for the given port with a raw [PacketPayload], wasmRS [Metadata], and [WickMetadata].
   pub const fn new_raw(payload: PacketPayload, wasmrs: Metadata, metadata: WickMetadata) -> Self {
@@ -40,6 +54,7 @@ impl Packet {
       payload,
       metadata: wasmrs,
       extra: metadata,
+      lineage: None,
     }
   }
 
@@ -51,6 +66,7 @@ impl Packet {
       payload,
       metadata: md,
       extra: wmd,
+      lineage: None,
     }
   }
 
@@ -58,6 +74,11 @@ impl Packet {
     Self::encode(Self::NO_INPUT, ())
   }
 
+  /// Create a new cancellation signal for a component's input stream.
+  pub fn cancel() -> Self {
+    Self::new_for_port(Self::CANCEL, PacketPayload::Ok(None), 0)
+  }
+
   /// Create a new fatal error packet for the component.
   pub fn component_error<T: Into<String>>(err: T) -> Self {
     Self::new_for_port(Self::FATAL_ERROR, PacketPayload::fatal_error(err), 0)
@@ -128,6 +149,11 @@ impl Packet {
     self.payload.decode()
   }
 
+  /// Get a [LazyPayload] view of this [Packet]'s data to read individual fields without decoding the whole value.
+  pub fn lazy(&self) -> Result<LazyPayload<'_>, Error> {
+    self.payload.lazy()
+  }
+
   /// Set the port for this packet.
   pub fn to_port<T: Into<String>>(mut self, port: T) -> Self {
     self.extra.port = port.into();
@@ -139,6 +165,16 @@ impl Packet {
     self.extra.port = port.into();
   }
 
+  /// Get the [Lineage] of this packet, if it is being tracked.
+  pub const fn lineage(&self) -> Option<Lineage> {
+    self.lineage
+  }
+
+  /// Set the [Lineage] of this packet.
+  pub fn set_lineage(&mut self, lineage: Lineage) {
+    self.lineage = Some(lineage);
+  }
+
   /// Return `true` if this is an error packet.
   pub const fn is_error(&self) -> bool {
     matches!(self.payload, PacketPayload::Err(_))
@@ -221,6 +257,11 @@ pub trait PacketExt {
     self.port() == Packet::NO_INPUT
   }
 
+  /// Return `true` if this packet tells the component to stop work on the invocation.
+  fn is_cancel(&self) -> bool {
+    self.port() == Packet::CANCEL
+  }
+
   /// Return `true` if this is a fatal, component wide error packet.
   fn is_fatal_error(&self) -> bool {
     self.port() == Packet::FATAL_ERROR
@@ -298,6 +339,15 @@ impl PacketPayload {
     }
   }
 
+  /// Get a [LazyPayload] view of the payload's data.
+  pub fn lazy(&self) -> Result<LazyPayload<'_>, Error> {
+    match self {
+      PacketPayload::Ok(Some(bytes)) => Ok(LazyPayload::new(bytes.as_ref())),
+      PacketPayload::Ok(None) => Err(crate::Error::NoData),
+      PacketPayload::Err(err) => Err(crate::Error::PayloadError(err.clone())),
+    }
+  }
+
   pub fn err<T: Into<String>>(msg: T) -> Self {
     Self::Err(PacketError::new(msg))
   }
@@ -335,14 +385,80 @@ impl PacketPayload {
   }
 }
 
+/// What kind of failure a [PacketError] reports.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PacketErrorKind {
+  /// A failure with no more specific kind.
+  #[default]
+  Other,
+  /// An invocation, or one it made, exceeded the deadline it inherited.
+  BudgetExceeded,
+}
+
+impl PacketErrorKind {
+  #[allow(clippy::trivially_copy_pass_by_ref)]
+  fn is_other(&self) -> bool {
+    *self == Self::Other
+  }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PacketError {
   msg: String,
+  #[serde(default, skip_serializing_if = "PacketErrorKind::is_other")]
+  kind: PacketErrorKind,
 }
 
 impl PacketError {
+  /// The prefix of errors reported when an invocation exceeds the deadline it inherited.
+  pub const BUDGET_EXCEEDED: &'static str = "time budget exceeded";
+
+  /// The message of the terminal error emitted on the outputs of a cancelled transaction.
+  pub const CANCELLED: &'static str = "transaction cancelled";
+
   pub fn new<T: Into<String>>(msg: T) -> Self {
-    Self { msg: msg.into() }
+    Self {
+      msg: msg.into(),
+      kind: PacketErrorKind::Other,
+    }
+  }
+
+  /// Set the kind of failure this error reports.
+  #[must_use]
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_kind(self, kind: PacketErrorKind) -> Self {
+    Self { kind, ..self }
+  }
+
+  /// Create an error reporting that an invocation exceeded its time budget.
+  pub fn budget_exceeded<T: std::fmt::Display>(msg: T) -> Self {
+    Self::new(format!("{}: {}", Self::BUDGET_EXCEEDED, msg)).with_kind(PacketErrorKind::BudgetExceeded)
+  }
+
+  /// The kind of failure this error reports.
+  #[must_use]
+  pub const fn kind(&self) -> PacketErrorKind {
+    self.kind
+  }
+
+  /// Whether this error reports an exceeded time budget, here or in a nested invocation.
+  #[must_use]
+  pub fn is_budget_exceeded(&self) -> bool {
+    self.kind == PacketErrorKind::BudgetExceeded
+  }
+
+  /// Create the error emitted on the outputs of a cancelled transaction.
+  #[must_use]
+  pub fn cancelled() -> Self {
+    Self::new(Self::CANCELLED)
+  }
+
+  /// Whether this error reports that the transaction was cancelled.
+  #[must_use]
+  pub fn is_cancelled(&self) -> bool {
+    self.msg == Self::CANCELLED
   }
 
   #[must_use]
@@ -464,6 +580,7 @@ impl From<Payload> for Packet {
       extra: WickMetadata::decode(ex.unwrap()).unwrap(),
       metadata: value.metadata,
       payload: PacketPayload::Ok(Some(value.data.into())),
+      lineage: None,
     }
   }
 }
@@ -483,7 +600,7 @@ impl From<Packet> for Result<RawPayload, PayloadError> {
 mod test {
   use anyhow::Result;
   use serde_json::Value;
-  use wick_interface_types::Type;
+  use wick_interface_types::{Field, Type};
 
   use super::PacketPayload;
   use crate::{Base64Bytes, Packet};
@@ -516,6 +633,11 @@ mod test {
   #[rstest::rstest]
   #[case("2", Type::String, Value::String("2".into()))]
   #[case(2, Type::String, Value::String("2".into()))]
+  #[case(
+    serde_json::json!({"a": 2, "b": "3"}),
+    Type::AnonymousStruct(vec![Field::new("a", Type::String), Field::new("b", Type::U32)]),
+    serde_json::json!({"a": "2", "b": 3})
+  )]
   fn test_type_wrapper<T>(#[case] value: T, #[case] ty: Type, #[case] expected: Value) -> Result<()>
   where
     T: serde::Serialize + std::fmt::Debug,
@@ -121,7 +121,19 @@ pub(crate) fn coerce(val: Value, ty: &Type) -> Result<Value, Error> {
       _ => coersion_err!(val, ty.clone()),
     },
     Type::Object => val,
-    Type::AnonymousStruct(_) => unimplemented!(),
+    Type::AnonymousStruct(fields) => {
+      let Value::Object(mut obj) = val else {
+        coersion_err!(val, ty.clone())
+      };
+
+      for field in fields {
+        if let Some(v) = obj.get_mut(&field.name) {
+          *v = coerce(v.take(), &field.ty)?;
+        }
+      }
+
+      Value::Object(obj)
+    }
   };
   Ok(val)
 }
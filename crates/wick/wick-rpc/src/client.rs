@@ -2,16 +2,23 @@ use std::error::Error;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use hyper::client::HttpConnector;
 use tokio_stream::{Stream, StreamExt};
-use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity, Uri};
+use tonic::codegen::{BoxFuture, Context, Poll, Service};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity, Uri};
 use tracing::debug;
 use wick_packet::{Invocation, Packet, PacketStream};
 
 use crate::error::RpcClientError;
 use crate::rpc::invocation_service_client::InvocationServiceClient;
-use crate::rpc::{InvocationRequest, ListRequest, StatsRequest, StatsResponse};
+use crate::rpc::{InvocationRequest, ListRequest, ObservabilityRequest, StatsRequest, StatsResponse};
 use crate::{convert_tonic_streaming, generated};
 
+const HAPPY_EYEBALLS_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// How many input packets can wait to be sent before an invocation's inputs are held back.
+const INPUT_BUFFER: usize = 64;
+
 /// Create an RPC client form common configuration
 pub async fn make_rpc_client<T: TryInto<Uri> + Send>(
   address: T,
@@ -56,11 +63,17 @@ pub async fn make_rpc_client<T: TryInto<Uri> + Send>(
     builder = builder.tls_config(tls).map_err(RpcClientError::TlsError)?;
   };
 
+  // resolve through the shared cache and race IPv4/IPv6 addresses when a host has both.
+  let mut connector = HttpConnector::new_with_resolver(cached_resolver::shared());
+  connector.enforce_http(false);
+  connector.set_nodelay(true);
+  connector.set_happy_eyeballs_timeout(Some(HAPPY_EYEBALLS_TIMEOUT));
+
   let result = builder
     .timeout(Duration::from_secs(5))
     .rate_limit(5, Duration::from_secs(1))
     .concurrency_limit(256)
-    .connect()
+    .connect_with_connector(connector)
     .await;
 
   let channel = result.map_err(|e| {
@@ -72,6 +85,94 @@ pub async fn make_rpc_client<T: TryInto<Uri> + Send>(
   Ok(RpcClient::from_channel(InvocationServiceClient::new(channel)))
 }
 
+/// A socket on the local machine that a co-located host serves on, which doesn't expose a port.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LocalSocket {
+  /// A Unix domain socket at this path, from a `unix://<path>` URL.
+  Unix(PathBuf),
+  /// A Windows named pipe with this name, from an `npipe://<name>` URL.
+  NamedPipe(String),
+}
+
+impl LocalSocket {
+  /// The local socket a `unix://` or `npipe://` URL names, or None for any other URL.
+  ///
+  /// Pipe names without a `\\server\pipe\` prefix are taken to be local pipes, so `npipe://wick` and
+  /// `npipe:////./pipe/wick` both name `\\.\pipe\wick`.
+  #[must_use]
+  pub fn parse(url: &str) -> Option<Self> {
+    if let Some(path) = url.strip_prefix("unix://") {
+      return Some(Self::Unix(PathBuf::from(path)));
+    }
+    let name = url.strip_prefix("npipe://")?.replace('/', "\\");
+    if name.starts_with("\\\\") {
+      Some(Self::NamedPipe(name))
+    } else {
+      Some(Self::NamedPipe(format!(
+        "\\\\.\\pipe\\{}",
+        name.trim_start_matches('\\')
+      )))
+    }
+  }
+
+  #[cfg_attr(windows, allow(clippy::unused_async))]
+  async fn connect(self) -> std::io::Result<LocalStream> {
+    match self {
+      #[cfg(unix)]
+      Self::Unix(path) => tokio::net::UnixStream::connect(path).await,
+      #[cfg(windows)]
+      Self::NamedPipe(name) => tokio::net::windows::named_pipe::ClientOptions::new().open(name),
+      socket => Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!("{:?} is not supported on this platform", socket),
+      )),
+    }
+  }
+}
+
+#[cfg(unix)]
+type LocalStream = tokio::net::UnixStream;
+#[cfg(windows)]
+type LocalStream = tokio::net::windows::named_pipe::NamedPipeClient;
+
+/// Connects a channel to a [LocalSocket] whatever URI the channel was built with.
+#[derive(Debug, Clone)]
+struct LocalConnector(LocalSocket);
+
+impl Service<Uri> for LocalConnector {
+  type Response = LocalStream;
+  type Error = std::io::Error;
+  type Future = BoxFuture<Self::Response, Self::Error>;
+
+  fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    Poll::Ready(Ok(()))
+  }
+
+  fn call(&mut self, _uri: Uri) -> Self::Future {
+    Box::pin(self.0.clone().connect())
+  }
+}
+
+/// Create an RPC client for a host serving on a [LocalSocket].
+///
+/// Local sockets are secured by the permissions of the machine they're on, so connections to them don't use TLS.
+pub async fn make_local_rpc_client(socket: LocalSocket) -> Result<RpcClient, RpcClientError> {
+  // the URI is only used for the requests' authority, the connector ignores it.
+  let channel = Endpoint::from_static("http://localhost")
+    .timeout(Duration::from_secs(5))
+    .concurrency_limit(256)
+    .connect_with_connector(LocalConnector(socket))
+    .await
+    .map_err(|e| {
+      e.source().map_or(RpcClientError::UnspecifiedConnectionError, |e| {
+        RpcClientError::ConnectionError(e.to_string())
+      })
+    })?;
+
+  Ok(RpcClient::from_channel(InvocationServiceClient::new(channel)))
+}
+
 #[derive(Debug, Clone)]
 /// [RpcClient] wraps an [InvocationServiceClient] into a more usable package.
 #[must_use]
@@ -106,6 +207,23 @@ impl RpcClient {
     Ok(result.into_inner())
   }
 
+  /// Make a request to the observability RPC method, switching to `level` if one is passed.
+  ///
+  /// Returns the level in effect after the call.
+  pub async fn observability(&mut self, level: Option<&str>) -> Result<String, RpcClientError> {
+    let request = ObservabilityRequest {
+      level: level.unwrap_or_default().to_owned(),
+    };
+    debug!("making observability request");
+    let result = self
+      .inner
+      .observability(request)
+      .await
+      .map_err(RpcClientError::ObservabilityCallFailed)?;
+    debug!("observability result: {:?}", result);
+    Ok(result.into_inner().level)
+  }
+
   /// Make a request to the list RPC method
   pub async fn list(&mut self) -> Result<Vec<wick_interface_types::ComponentSignature>, RpcClientError> {
     let request = ListRequest {};
@@ -142,26 +260,52 @@ impl RpcClient {
   }
 
   /// Send an invoke RPC command with an [Invocation] object.
+  ///
+  /// The invocation's packets are sent as they arrive rather than collected first, so outputs can stream back while
+  /// the invocation is still sending inputs.
   pub async fn invoke(&mut self, invocation: Invocation) -> Result<PacketStream, RpcClientError> {
-    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let (tx, rx) = tokio::sync::mpsc::channel(INPUT_BUFFER);
     let (invocation, mut stream) = invocation.split();
     tx.send(InvocationRequest {
       data: Some(generated::wick::invocation_request::Data::Invocation(invocation.into())),
     })
+    .await
     .map_err(|_e| RpcClientError::UnspecifiedConnectionError)?;
     tokio::spawn(async move {
       while let Some(packet) = stream.next().await {
         let packet = packet.map_or_else(|e| Packet::component_error(e.to_string()), |p| p);
-        tx.send(InvocationRequest {
+        let request = InvocationRequest {
           data: Some(generated::wick::invocation_request::Data::Packet(packet.into())),
-        })
-        .map_err(|_e| RpcClientError::UnspecifiedConnectionError)?;
+        };
+        if tx.send(request).await.is_err() {
+          // the call is over, nobody is left to send inputs to.
+          break;
+        }
       }
-      Ok::<_, RpcClientError>(())
     });
 
-    self
-      .invoke_raw(tokio_stream::wrappers::UnboundedReceiverStream::new(rx))
-      .await
+    self.invoke_raw(tokio_stream::wrappers::ReceiverStream::new(rx)).await
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_parse_local_socket() {
+    assert_eq!(
+      LocalSocket::parse("unix:///run/wick/host.sock"),
+      Some(LocalSocket::Unix(PathBuf::from("/run/wick/host.sock")))
+    );
+    assert_eq!(
+      LocalSocket::parse("npipe://wick"),
+      Some(LocalSocket::NamedPipe(r"\\.\pipe\wick".to_owned()))
+    );
+    assert_eq!(
+      LocalSocket::parse("npipe:////./pipe/wick"),
+      Some(LocalSocket::NamedPipe(r"\\.\pipe\wick".to_owned()))
+    );
+    assert_eq!(LocalSocket::parse("http://127.0.0.1:8090"), None);
   }
 }
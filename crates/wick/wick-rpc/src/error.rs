@@ -126,6 +126,10 @@ pub enum RpcClientError {
   #[error("RPC Stats call failed: {0}")]
   StatsCallFailed(tonic::Status),
 
+  /// An error originating from an Observability RPC call.
+  #[error("RPC Observability call failed: {0}")]
+  ObservabilityCallFailed(tonic::Status),
+
   /// Invalid response from RPC call.
   #[error("RPC response invalid: {0}")]
   ResponseInvalid(String),
@@ -28,6 +28,8 @@ pub struct Invocation {
   pub tx_id: ::prost::alloc::string::String,
   #[prost(message, optional, tag = "6")]
   pub inherent: ::core::option::Option<InherentData>,
+  #[prost(string, tag = "7")]
+  pub traceparent: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -241,6 +243,19 @@ pub struct DurationStatistics {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObservabilityRequest {
+  /// The level to switch to (off, errors, ops, or packets). Leave empty to query the current level.
+  #[prost(string, tag = "1")]
+  pub level: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObservabilityResponse {
+  #[prost(string, tag = "1")]
+  pub level: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StructSignature {
   #[prost(string, tag = "1")]
   pub name: ::prost::alloc::string::String,
@@ -529,6 +544,23 @@ pub mod invocation_service_client {
         .insert(GrpcMethod::new("wick.InvocationService", "Stats"));
       self.inner.unary(req, path, codec).await
     }
+    pub async fn observability(
+      &mut self,
+      request: impl tonic::IntoRequest<super::ObservabilityRequest>,
+    ) -> std::result::Result<tonic::Response<super::ObservabilityResponse>, tonic::Status> {
+      self
+        .inner
+        .ready()
+        .await
+        .map_err(|e| tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into())))?;
+      let codec = tonic::codec::ProstCodec::default();
+      let path = http::uri::PathAndQuery::from_static("/wick.InvocationService/Observability");
+      let mut req = request.into_request();
+      req
+        .extensions_mut()
+        .insert(GrpcMethod::new("wick.InvocationService", "Observability"));
+      self.inner.unary(req, path, codec).await
+    }
   }
 }
 /// Generated server implementations.
@@ -552,6 +584,10 @@ pub mod invocation_service_server {
       &self,
       request: tonic::Request<super::StatsRequest>,
     ) -> std::result::Result<tonic::Response<super::StatsResponse>, tonic::Status>;
+    async fn observability(
+      &self,
+      request: tonic::Request<super::ObservabilityRequest>,
+    ) -> std::result::Result<tonic::Response<super::ObservabilityResponse>, tonic::Status>;
   }
   #[derive(Debug)]
   pub struct InvocationServiceServer<T: InvocationService> {
@@ -714,6 +750,35 @@ pub mod invocation_service_server {
           };
           Box::pin(fut)
         }
+        "/wick.InvocationService/Observability" => {
+          #[allow(non_camel_case_types)]
+          struct ObservabilitySvc<T: InvocationService>(pub Arc<T>);
+          impl<T: InvocationService> tonic::server::UnaryService<super::ObservabilityRequest> for ObservabilitySvc<T> {
+            type Response = super::ObservabilityResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(&mut self, request: tonic::Request<super::ObservabilityRequest>) -> Self::Future {
+              let inner = Arc::clone(&self.0);
+              let fut = async move { (*inner).observability(request).await };
+              Box::pin(fut)
+            }
+          }
+          let accept_compression_encodings = self.accept_compression_encodings;
+          let send_compression_encodings = self.send_compression_encodings;
+          let max_decoding_message_size = self.max_decoding_message_size;
+          let max_encoding_message_size = self.max_encoding_message_size;
+          let inner = self.inner.clone();
+          let fut = async move {
+            let inner = inner.0;
+            let method = ObservabilitySvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = tonic::server::Grpc::new(codec)
+              .apply_compression_config(accept_compression_encodings, send_compression_encodings)
+              .apply_max_message_size_config(max_decoding_message_size, max_encoding_message_size);
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
         _ => Box::pin(async move {
           Ok(
             http::Response::builder()
@@ -117,7 +117,7 @@
 #[cfg(feature = "client")]
 mod client;
 #[cfg(feature = "client")]
-pub use client::{make_rpc_client, RpcClient};
+pub use client::{make_local_rpc_client, make_rpc_client, LocalSocket, RpcClient};
 
 /// Error module.
 pub mod error;
@@ -3,6 +3,7 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use wick_interface_types as wick;
+use wick_logger::trace_context;
 use wick_packet::{Entity, InherentData, Metadata, Packet, PacketExt, WickMetadata};
 
 use crate::error::RpcError;
@@ -167,6 +168,7 @@ impl From<wick_packet::InvocationData> for rpc::Invocation {
         seed: inv.inherent.seed,
         timestamp: inv.inherent.timestamp,
       }),
+      traceparent: trace_context::traceparent(&inv.span).unwrap_or_default(),
     }
   }
 }
@@ -175,6 +177,14 @@ impl TryFrom<rpc::Invocation> for wick_packet::InvocationData {
   type Error = RpcError;
   fn try_from(inv: rpc::Invocation) -> Result<Self> {
     let inherent = inv.inherent.ok_or(RpcError::NoInherentData)?;
+    let span = if inv.traceparent.is_empty() {
+      tracing::Span::current()
+    } else {
+      // continue the caller's trace rather than whatever span happens to be current on this side.
+      let span = tracing::info_span!("rpc:invocation", tx_id = %inv.tx_id);
+      trace_context::set_parent(&span, &inv.traceparent);
+      span
+    };
 
     Ok(Self::new_raw(
       Entity::from_str(&inv.origin).map_err(|_e| RpcError::TypeConversion)?,
@@ -182,7 +192,7 @@ impl TryFrom<rpc::Invocation> for wick_packet::InvocationData {
       uuid::Uuid::from_str(&inv.id).map_err(|e| RpcError::UuidParseError(inv.id, e))?,
       uuid::Uuid::from_str(&inv.tx_id).map_err(|e| RpcError::UuidParseError(inv.tx_id, e))?,
       InherentData::new(inherent.seed, inherent.timestamp),
-      tracing::Span::current(),
+      span,
     ))
   }
 }
@@ -0,0 +1,95 @@
+//! The link target WebAssembly guests put and resolve blobs through.
+//!
+//! Native operations put blobs with their context's `blobs()` handle. Guests make link calls to [LINK_TARGET]
+//! instead, and the blobs they put are held for the transaction the host sent them with the invocation they're
+//! handling.
+
+use flow_component::{Blobs, ComponentError};
+use futures::StreamExt;
+use uuid::Uuid;
+use wick_packet::{Base64Bytes, BlobRef, InherentData, Packet, PacketExt, PacketStream};
+
+/// The component WebAssembly guests link to for blobs. Its `put` operation takes bytes on its `input` port and outputs
+/// a [BlobRef] to send in their place. Its `get` operation takes a [BlobRef] on its `blob` port and outputs the bytes.
+pub const LINK_TARGET: &str = "__blobs";
+
+/// Handle a guest's link call to [LINK_TARGET], made while handling the transaction in `inherent`.
+pub(crate) async fn handle_link(
+  blobs: Blobs,
+  op: &str,
+  mut stream: PacketStream,
+  inherent: &InherentData,
+) -> Result<PacketStream, ComponentError> {
+  let blobs = match inherent.tx_id.as_deref().map(Uuid::parse_str).transpose()? {
+    Some(tx_id) => blobs.bind(tx_id),
+    None => blobs,
+  };
+  let (mut input, mut blob) = (None, None);
+  while let Some(packet) = stream.next().await {
+    let packet = packet?;
+    if !packet.has_data() {
+      continue;
+    }
+    match packet.port() {
+      "input" => input = Some(packet.decode::<Base64Bytes>()?),
+      "blob" => blob = Some(packet.decode::<BlobRef>()?),
+      _ => {}
+    }
+  }
+  let output = match op {
+    "put" => {
+      let input = input.ok_or_else(|| ComponentError::msg("'put' needs bytes on the 'input' port"))?;
+      Packet::encode("output", blobs.put(input)?)
+    }
+    "get" => {
+      let blob = blob.ok_or_else(|| ComponentError::msg("'get' needs a blob on the 'blob' port"))?;
+      Packet::encode("output", Base64Bytes::from(blobs.get(&blob)?))
+    }
+    op => return Err(ComponentError::msg(format!("blobs have no '{}' operation", op))),
+  };
+  Ok(vec![output, Packet::done("output")].into())
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use wick_packet::BlobStore;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_link() -> Result<()> {
+    let store = BlobStore::new();
+    let tx = Uuid::new_v4();
+    let scope = store.scope(tx);
+    let inherent = InherentData::new(0, 0).with_tx_id(Some(tx.to_string()));
+    let call = |op: &'static str, packet: Packet, inherent: InherentData| {
+      let blobs = Blobs::new(store.clone());
+      async move {
+        let output: Vec<_> = handle_link(blobs, op, vec![packet].into(), &inherent).await?.collect().await;
+        Ok::<_, anyhow::Error>(output.into_iter().next().unwrap()?)
+      }
+    };
+
+    let put = Packet::encode("input", Base64Bytes::from(b"payload".to_vec()));
+    let blob: BlobRef = call("put", put.clone(), inherent.unsafe_clone()).await?.decode()?;
+    let bytes: Base64Bytes = call("get", Packet::encode("blob", &blob), InherentData::new(0, 0))
+      .await?
+      .decode()?;
+    assert_eq!(bytes, *b"payload".as_slice());
+
+    assert!(
+      call("put", put.clone(), InherentData::new(0, 0)).await.is_err(),
+      "guests that don't send their transaction can't put blobs"
+    );
+    drop(scope);
+    assert!(
+      call("put", put, inherent).await.is_err(),
+      "finished transactions can't hold blobs"
+    );
+    assert!(call("get", Packet::encode("blob", &blob), InherentData::new(0, 0))
+      .await
+      .is_err());
+    Ok(())
+  }
+}
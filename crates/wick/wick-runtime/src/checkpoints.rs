@@ -0,0 +1,118 @@
+//! Stores for the checkpoints of durable transactions.
+//!
+//! A runtime built with [crate::RuntimeBuilder::durability] checkpoints the transactions its root component runs, so
+//! the ones a crash interrupts can be finished with [crate::Runtime::resume] once it restarts.
+
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use flow_component::{BoxFuture, ComponentError};
+pub use flow_graph_interpreter::{Checkpoint, CheckpointStore, Durability, MemoryCheckpoints};
+use uuid::Uuid;
+
+/// Keeps a JSON file per unfinished transaction in a directory, so checkpoints survive the runtime.
+#[derive(Debug)]
+#[must_use]
+pub struct DirectoryCheckpoints {
+  dir: PathBuf,
+}
+
+impl DirectoryCheckpoints {
+  /// Keep checkpoints in `dir`, which is created when the first one is saved.
+  pub fn new<T: Into<PathBuf>>(dir: T) -> Self {
+    Self { dir: dir.into() }
+  }
+
+  fn path(&self, tx_id: Uuid) -> PathBuf {
+    self.dir.join(format!("{}.json", tx_id))
+  }
+
+  fn error<E: std::fmt::Display>(&self, e: &E) -> ComponentError {
+    ComponentError::msg(format!("{}: {}", self.dir.display(), e))
+  }
+
+  async fn write(&self, checkpoint: &Checkpoint) -> Result<(), ComponentError> {
+    tokio::fs::create_dir_all(&self.dir).await.map_err(|e| self.error(&e))?;
+    let path = self.path(checkpoint.tx_id);
+    let mut tmp = path.clone().into_os_string();
+    tmp.push(".tmp");
+    let json = serde_json::to_vec(checkpoint).map_err(|e| self.error(&e))?;
+    tokio::fs::write(&tmp, json).await.map_err(|e| self.error(&e))?;
+    tokio::fs::rename(&tmp, &path).await.map_err(|e| self.error(&e))
+  }
+
+  async fn read(&self) -> Result<Vec<Checkpoint>, ComponentError> {
+    let mut entries = match tokio::fs::read_dir(&self.dir).await {
+      Ok(entries) => entries,
+      Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+      Err(e) => return Err(self.error(&e)),
+    };
+    let mut checkpoints = Vec::new();
+    while let Some(entry) = entries.next_entry().await.map_err(|e| self.error(&e))? {
+      let path = entry.path();
+      if path.extension().map_or(true, |ext| ext != "json") {
+        continue;
+      }
+      let bytes = tokio::fs::read(&path).await.map_err(|e| self.error(&e))?;
+      match serde_json::from_slice(&bytes) {
+        Ok(checkpoint) => checkpoints.push(checkpoint),
+        Err(error) => warn!(path = %path.display(), %error, "skipping unreadable transaction checkpoint"),
+      }
+    }
+    Ok(checkpoints)
+  }
+}
+
+impl CheckpointStore for DirectoryCheckpoints {
+  fn save<'a>(&'a self, checkpoint: &'a Checkpoint) -> BoxFuture<'a, Result<(), ComponentError>> {
+    Box::pin(self.write(checkpoint))
+  }
+
+  fn remove(&self, tx_id: Uuid) -> BoxFuture<'_, Result<(), ComponentError>> {
+    Box::pin(async move {
+      match tokio::fs::remove_file(self.path(tx_id)).await {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(self.error(&e)),
+        _ => Ok(()),
+      }
+    })
+  }
+
+  fn load(&self) -> BoxFuture<'_, Result<Vec<Checkpoint>, ComponentError>> {
+    Box::pin(self.read())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+  use wick_packet::Packet;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_directory_checkpoints() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("wick-checkpoints-{}", Uuid::new_v4()));
+    let store = DirectoryCheckpoints::new(&dir);
+    assert!(store.load().await?.is_empty());
+
+    let checkpoint: Checkpoint = serde_json::from_value(json!({
+      "tx_id": Uuid::new_v4(),
+      "operation": "test",
+      "seed": 1,
+      "timestamp": 0,
+      "inputs": [Packet::encode("input", "hello"), Packet::done("input")],
+      "completed": {"first": [Packet::encode("output", "hello")]},
+    }))?;
+    store.save(&checkpoint).await?;
+    std::fs::write(dir.join("garbage.json"), "{")?;
+    assert_eq!(store.load().await?, vec![checkpoint.clone()]);
+
+    store.remove(checkpoint.tx_id).await?;
+    store.remove(checkpoint.tx_id).await?;
+    std::fs::remove_file(dir.join("garbage.json"))?;
+    assert!(store.load().await?.is_empty());
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+  }
+}
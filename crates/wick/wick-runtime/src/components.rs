@@ -1,5 +1,8 @@
 pub(crate) mod component_service;
+pub(crate) mod discovery;
 pub(crate) mod error;
+pub(crate) mod process;
+pub(crate) mod remote_component;
 pub(crate) mod scope_component;
 pub(crate) mod validation;
 
@@ -7,16 +10,22 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use flow_component::LocalScope;
+use flow_component::{KeyValue, LocalScope};
 use flow_graph_interpreter::NamespaceHandler;
 use seeded_random::Random;
 use tracing::Instrument;
 use uuid::Uuid;
 use wick_component_wasmrs::component::WasmrsComponent;
 use wick_component_wasmrs::error::LinkError;
-use wick_config::config::components::ManifestComponent;
-use wick_config::config::{Metadata, Permissions, PermissionsBuilder, WasmRsComponent};
-use wick_config::{AssetReference, FetchOptions, Resolver, WickConfiguration};
+use wick_config::config::components::{
+  ComponentConfig,
+  CryptoComponentConfig,
+  GrpcUrlComponent,
+  ManifestComponent,
+  ObjectStoreComponentConfig,
+};
+use wick_config::config::{BoundIdentifier, Metadata, Permissions, PermissionsBuilder, WasmRsComponent};
+use wick_config::{AssetReference, Resolver, WickConfiguration};
 use wick_packet::validation::expect_configuration_matches;
 use wick_packet::{Entity, Invocation, RuntimeConfig};
 
@@ -24,7 +33,11 @@ use self::component_service::NativeComponentService;
 use self::validation::expect_signature_match;
 use crate::dev::prelude::*;
 use crate::dispatch::scope_invoke_async;
-use crate::runtime::scope::{init_child, ChildInit};
+use crate::metrics::RuntimeMetrics;
+use crate::policy::{self, ComponentIdentity};
+use crate::runtime::scope::{init_child, ChildInit, ScopeRegistry};
+use crate::scan::{self, Artifact};
+use crate::secrets::SecretsBackends;
 use crate::BoxFuture;
 
 pub(crate) trait InvocationHandler {
@@ -49,19 +62,27 @@ pub(crate) async fn init_wasmrs_component(
     .span
     .in_scope(|| trace!(namespace = %namespace, ?opts, ?permissions, "registering wasmrs component"));
 
-  let mut options = FetchOptions::default();
-  options
-    .set_allow_latest(opts.allow_latest)
-    .set_allow_insecure(opts.allowed_insecure.clone());
-  let asset = reference.with_options(options);
+  let asset = reference.with_options(opts.fetch_options());
 
   use wick_component_wasmrs::component::ComponentSetupBuilder;
+  use wick_component_wasmrs::limits::ViolationCallback;
 
+  let metrics = opts.metrics.clone();
+  let ns = namespace.clone();
+  let on_violation: ViolationCallback = Arc::new(move |limit| metrics.record_limit(&ns, limit));
   let setup = ComponentSetupBuilder::default()
     .buffer_size(buffer_size)
     .permissions(permissions)
+    .limits(opts.limits)
+    .pool(opts.pool)
+    .pool_stats(opts.pool.map(|_| opts.metrics.pool(&namespace)))
+    .on_violation(Some(on_violation))
     .config(opts.root_config)
-    .callback(Some(make_link_callback(opts.runtime_id)))
+    .callback(Some(make_link_callback(
+      opts.scopes.clone(),
+      opts.runtime_id,
+      opts.kv.clone().bind(&namespace, ""),
+    )))
     .provided(provided)
     .imported(imported)
     .build()
@@ -97,10 +118,18 @@ pub(crate) async fn init_wasmrs_def(
   .await
 }
 
-pub(crate) fn make_link_callback(scope_id: Uuid) -> LocalScope {
+pub(crate) fn make_link_callback(scopes: ScopeRegistry, scope_id: Uuid, kv: KeyValue) -> LocalScope {
+  let state = kv.clone();
   LocalScope::new(Arc::new(move |compref, op, stream, inherent, config, span| {
     let origin_url = compref.get_origin_url();
     let target_id = compref.get_target_id().to_owned();
+    if target_id == crate::state::LINK_TARGET {
+      let kv = state.clone();
+      return Box::pin(async move { crate::state::handle_link(kv, &op, stream, config).await });
+    }
+    if target_id == crate::blobs::LINK_TARGET {
+      return Box::pin(async move { crate::blobs::handle_link(Default::default(), &op, stream, &inherent).await });
+    }
     let invocation = compref.to_invocation(&op, stream, inherent, span);
     invocation.trace(|| {
       debug!(
@@ -111,15 +140,17 @@ pub(crate) fn make_link_callback(scope_id: Uuid) -> LocalScope {
         "link_call"
       );
     });
+    let scopes = scopes.clone();
     Box::pin(async move {
       {
-        let result = scope_invoke_async(scope_id, invocation, config)
+        let result = scope_invoke_async(&scopes, scope_id, invocation, config)
           .await
           .map_err(|e| flow_component::ComponentError::new(LinkError::CallFailure(e.to_string())))?;
         Ok(result)
       }
     })
   }))
+  .with_kv(kv)
 }
 
 pub(crate) async fn init_manifest_component(
@@ -130,29 +161,78 @@ pub(crate) async fn init_manifest_component(
   let span = opts.span.clone();
   span.in_scope(|| trace!(namespace = %id, ?opts, "registering wick component"));
 
-  let mut options = FetchOptions::default();
-
-  options
-    .set_allow_latest(opts.allow_latest)
-    .set_allow_insecure(opts.allowed_insecure.clone());
-  let mut builder = WickConfiguration::fetch(kind.reference().clone(), options)
+  let options = opts.fetch_options();
+  let mut builder = WickConfiguration::fetch(kind.reference().clone(), options.clone())
     .instrument(span.clone())
     .await?;
 
   builder.set_root_config(opts.root_config.clone());
   let manifest = builder.finish()?.try_component_config()?;
 
+  if opts.policy.is_some() || opts.scan.is_some() {
+    let module = match manifest.component() {
+      config::ComponentImplementation::WasmRs(wasm) => Some(wasm.reference()),
+      _ => None,
+    };
+    let identity = ComponentIdentity::fetch(&id, kind.reference(), module, &options)
+      .instrument(span.clone())
+      .await?;
+    if let Some(policy) = &opts.policy {
+      policy::check(policy, &identity)?;
+    }
+    if let Some(scan) = &opts.scan {
+      let artifact =
+        Artifact::new(identity, scan::artifact_path(kind.reference())).with_capabilities(scan::capabilities(&manifest));
+      scan.check(&artifact).instrument(span.clone()).await?;
+    }
+  }
+
+  if let Some(host) = kind.process() {
+    if !kind.provide().is_empty() {
+      return Err(ScopeError::ComponentInit(
+        id,
+        "components can't be provided to a component running in its own process".to_owned(),
+      ));
+    }
+    span.in_scope(|| debug!(namespace = %id, restart = %host.restart(), "running component in its own process"));
+    let component = process::ProcessComponent::start(&id, kind.reference(), host, &opts)
+      .instrument(span.clone())
+      .await?;
+    let service = NativeComponentService::new(Arc::new(component));
+    return Ok(NamespaceHandler::new(id, Box::new(service)));
+  }
+
   let rng = Random::from_seed(opts.rng_seed);
   opts.rng_seed = rng.seed();
 
   let uuid = rng.uuid();
-  let _scope = init_child(uuid, manifest.clone(), id.clone(), opts, kind.max_packet_size()).await?;
+  let scope = init_child(
+    uuid,
+    manifest.clone(),
+    id.clone(),
+    opts,
+    kind.max_packet_size(),
+    kind.limits().copied(),
+    kind.pool().copied(),
+  )
+  .await?;
 
-  let component = Arc::new(scope_component::ScopeComponent::new(uuid));
+  let component = Arc::new(scope_component::ScopeComponent::new(scope));
   let service = NativeComponentService::new(component);
   Ok(NamespaceHandler::new(id, Box::new(service)))
 }
 
+pub(crate) async fn init_remote_component(def: &GrpcUrlComponent, id: String, opts: ChildInit) -> ComponentInitResult {
+  opts
+    .span
+    .in_scope(|| trace!(namespace = %id, url = %def.url(), "registering remote component"));
+  let component = remote_component::RemoteComponent::connect(&id, def, opts.span.clone())
+    .instrument(opts.span.clone())
+    .await?;
+  let service = NativeComponentService::new(Arc::new(component));
+  Ok(NamespaceHandler::new(id, Box::new(service)))
+}
+
 pub(crate) async fn init_impl(
   manifest: &ComponentConfiguration,
   id: String,
@@ -171,7 +251,7 @@ pub(crate) async fn init_impl(
   let rng = Random::from_seed(opts.rng_seed);
   opts.rng_seed = rng.seed();
   let metadata = manifest.metadata();
-  match manifest.component() {
+  let hlc = match manifest.component() {
     config::ComponentImplementation::Wasm(_) => {
       unimplemented!("WASM components are not yet supported in wick releases");
     }
@@ -199,33 +279,39 @@ pub(crate) async fn init_impl(
           &manifest_sig,
         )
       })?;
-      Ok(comp)
+      return Ok(comp);
     }
     config::ComponentImplementation::Composite(_) => {
       // This is handled in the scope initialization.
       unreachable!();
     }
-    config::ComponentImplementation::Sql(c) => {
-      init_hlc_component(
-        id,
-        opts.root_config.clone(),
-        metadata.cloned(),
-        wick_config::config::HighLevelComponent::Sql(c.clone()),
-        manifest.resolver(),
-      )
-      .await
-    }
-    config::ComponentImplementation::HttpClient(c) => {
-      init_hlc_component(
-        id,
-        opts.root_config.clone(),
-        metadata.cloned(),
-        wick_config::config::HighLevelComponent::HttpClient(c.clone()),
-        manifest.resolver(),
-      )
-      .await
-    }
-  }
+    config::ComponentImplementation::Sql(c) => wick_config::config::HighLevelComponent::Sql(c.clone()),
+    config::ComponentImplementation::HttpClient(c) => wick_config::config::HighLevelComponent::HttpClient(c.clone()),
+    config::ComponentImplementation::Archive(c) => wick_config::config::HighLevelComponent::Archive(c.clone()),
+    config::ComponentImplementation::Text(c) => wick_config::config::HighLevelComponent::Text(c.clone()),
+    config::ComponentImplementation::Units(c) => wick_config::config::HighLevelComponent::Units(c.clone()),
+    config::ComponentImplementation::Validate(c) => wick_config::config::HighLevelComponent::Validate(c.clone()),
+    config::ComponentImplementation::Generate(c) => wick_config::config::HighLevelComponent::Generate(c.clone()),
+    config::ComponentImplementation::Flags(c) => wick_config::config::HighLevelComponent::Flags(c.clone()),
+    config::ComponentImplementation::I18n(c) => wick_config::config::HighLevelComponent::I18n(c.clone()),
+    config::ComponentImplementation::Metrics(c) => wick_config::config::HighLevelComponent::Metrics(c.clone()),
+    config::ComponentImplementation::Broker(c) => wick_config::config::HighLevelComponent::Broker(c.clone()),
+    config::ComponentImplementation::Cache(c) => wick_config::config::HighLevelComponent::Cache(c.clone()),
+    config::ComponentImplementation::ObjectStore(c) => wick_config::config::HighLevelComponent::ObjectStore(c.clone()),
+    config::ComponentImplementation::Filesystem(c) => wick_config::config::HighLevelComponent::Filesystem(c.clone()),
+    config::ComponentImplementation::Data(c) => wick_config::config::HighLevelComponent::Data(c.clone()),
+    config::ComponentImplementation::Crypto(c) => wick_config::config::HighLevelComponent::Crypto(c.clone()),
+  };
+  init_hlc_component(
+    id,
+    opts.root_config.clone(),
+    metadata.cloned(),
+    hlc,
+    manifest.resolver(),
+    || manifest.types(),
+    &opts.metrics,
+  )
+  .await
 }
 
 pub(crate) async fn init_hlc_component(
@@ -234,17 +320,134 @@ pub(crate) async fn init_hlc_component(
   metadata: Option<Metadata>,
   component: wick_config::config::HighLevelComponent,
   resolver: Box<Resolver>,
+  types: impl FnOnce() -> std::result::Result<Vec<TypeDefinition>, wick_config::Error> + Send,
+  metrics: &RuntimeMetrics,
 ) -> ComponentInitResult {
   let comp: Box<dyn Component + Send + Sync> = match component {
     config::HighLevelComponent::Sql(comp) => {
       Box::new(wick_sql::SqlComponent::new(comp, root_config, metadata, &resolver).await?)
     }
-    config::HighLevelComponent::HttpClient(comp) => Box::new(wick_http_client::HttpClientComponent::new(
+    config::HighLevelComponent::HttpClient(comp) => Box::new(
+      wick_http_client::HttpClientComponent::new(comp, root_config, metadata, &resolver)?
+        .with_metrics(metrics.http_pool(&id)),
+    ),
+    config::HighLevelComponent::Archive(comp) => {
+      Box::new(wick_archive::ArchiveComponent::new(comp, root_config, metadata)?)
+    }
+    config::HighLevelComponent::Text(comp) => {
+      Box::new(wick_text::TextComponent::new(comp, root_config, metadata, &resolver)?)
+    }
+    config::HighLevelComponent::Units(comp) => Box::new(wick_units::UnitsComponent::new(comp, root_config, metadata)?),
+    config::HighLevelComponent::Validate(comp) => {
+      Box::new(wick_validate::ValidateComponent::new(comp, root_config, metadata)?)
+    }
+    config::HighLevelComponent::Generate(comp) => Box::new(wick_generate::GenerateComponent::new(
+      comp,
+      root_config,
+      metadata,
+      &types()?,
+    )?),
+    config::HighLevelComponent::Flags(comp) => {
+      Box::new(wick_flags::FlagsComponent::new(comp, root_config, metadata, &resolver).await?)
+    }
+    config::HighLevelComponent::I18n(comp) => {
+      Box::new(wick_i18n::I18nComponent::new(comp, root_config, metadata, &resolver)?)
+    }
+    config::HighLevelComponent::Metrics(comp) => {
+      Box::new(wick_metrics::MetricsComponent::new(comp, root_config, metadata, &resolver).await?)
+    }
+    config::HighLevelComponent::Broker(comp) => Box::new(wick_broker::BrokerComponent::new(
+      comp,
+      root_config,
+      metadata,
+      &resolver,
+    )?),
+    config::HighLevelComponent::Cache(comp) => Box::new(wick_cache::CacheComponent::new(comp, root_config, metadata)?),
+    config::HighLevelComponent::ObjectStore(comp) => {
+      let credentials = object_store_credentials(&id, &comp, &resolver).await?;
+      Box::new(wick_object_store::ObjectStoreComponent::new(
+        comp,
+        root_config,
+        metadata,
+        &resolver,
+        credentials,
+      )?)
+    }
+    config::HighLevelComponent::Filesystem(comp) => Box::new(wick_filesystem::FilesystemComponent::new(
       comp,
       root_config,
       metadata,
       &resolver,
     )?),
+    config::HighLevelComponent::Data(comp) => {
+      Box::new(wick_data::DataComponent::new(comp, root_config, metadata, &resolver)?)
+    }
+    config::HighLevelComponent::Crypto(comp) => {
+      let keys = crypto_keys(&id, &comp, &resolver).await?;
+      Box::new(wick_crypto::CryptoComponent::new(comp, root_config, metadata, keys)?)
+    }
   };
   Ok(NamespaceHandler::new(id, comp))
 }
+
+/// Read the credentials of an object store component from its secret resources, from the secrets backends
+/// configured in the environment when they point to one.
+async fn object_store_credentials(
+  id: &str,
+  config: &ObjectStoreComponentConfig,
+  resolver: &Resolver,
+) -> std::result::Result<wick_object_store::Credentials, ScopeError> {
+  let error = |e: String| ScopeError::ComponentInit(id.to_owned(), e);
+  let resource = |binding: &BoundIdentifier| {
+    resolver(binding)
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_secret())
+      .map_err(|e| error(e.to_string()))
+  };
+  let access_key_id = resource(config.access_key_id())?;
+  let secret_access_key = resource(config.secret_access_key())?;
+  let session_token = config.session_token().map(resource).transpose()?;
+
+  let backends = SecretsBackends::from_env();
+  let access_key_id = backends
+    .reveal(&access_key_id)
+    .await
+    .map_err(|e| error(e.to_string()))?;
+  let secret_access_key = backends
+    .reveal(&secret_access_key)
+    .await
+    .map_err(|e| error(e.to_string()))?;
+  let session_token = match session_token {
+    Some(token) => Some(backends.reveal(&token).await.map_err(|e| error(e.to_string()))?),
+    None => None,
+  };
+  Ok(wick_object_store::Credentials::new(
+    access_key_id,
+    secret_access_key,
+    session_token,
+  ))
+}
+
+/// Read the keys of a crypto component's operations from their secret resources, by the bindings they're read
+/// from.
+async fn crypto_keys(
+  id: &str,
+  config: &CryptoComponentConfig,
+  resolver: &Resolver,
+) -> std::result::Result<HashMap<String, config::Secret>, ScopeError> {
+  let error = |e: String| ScopeError::ComponentInit(id.to_owned(), e);
+  let backends = SecretsBackends::from_env();
+  let mut keys = HashMap::new();
+  for binding in config.operations().iter().filter_map(|op| op.key()) {
+    if keys.contains_key(binding.id()) {
+      continue;
+    }
+    let resource = resolver(binding)
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_secret())
+      .map_err(|e| error(e.to_string()))?;
+    let key = backends.reveal(&resource).await.map_err(|e| error(e.to_string()))?;
+    keys.insert(binding.id().to_owned(), key);
+  }
+  Ok(keys)
+}
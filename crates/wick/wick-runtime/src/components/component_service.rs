@@ -1,6 +1,7 @@
 use tracing::Instrument;
 
 use crate::dev::prelude::*;
+use crate::ObservabilityLevel;
 type Result<T> = std::result::Result<T, ComponentError>;
 
 pub(crate) struct NativeComponentService {
@@ -47,7 +48,7 @@ impl InvocationHandler for NativeComponentService {
   ) -> Result<BoxFuture<Result<InvocationResponse>>> {
     let tx_id = invocation.tx_id();
 
-    let span = info_span!(parent:invocation.span(),"runtime:handle");
+    let span = ObservabilityLevel::Ops.span(|| info_span!(parent:invocation.span(),"runtime:handle"));
     let fut = self.handle(invocation, config, Default::default());
 
     let task = async move {
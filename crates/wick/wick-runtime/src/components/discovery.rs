@@ -0,0 +1,445 @@
+mod dns;
+
+use std::collections::HashMap;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Weak;
+use std::time::Duration;
+
+use parking_lot::RwLock;
+use tokio::net::UdpSocket;
+use tokio::time::Instant;
+use wick_rpc::{LocalSocket, RpcClient};
+
+use self::dns::Records;
+use crate::dev::prelude::*;
+
+/// How long to wait for a name server to answer.
+const LOOKUP_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How long to collect answers to a multicast DNS query.
+const MDNS_WINDOW: Duration = Duration::from_secs(1);
+
+/// The multicast DNS group and port.
+const MDNS_ADDR: (Ipv4Addr, u16) = (Ipv4Addr::new(224, 0, 0, 251), 5353);
+
+/// How often to look the hosts up again, to follow hosts joining and leaving.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How often to check that each host is serving, and how long it has to answer.
+const HEALTH_INTERVAL: Duration = Duration::from_secs(5);
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// What a remote component's `url` names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Target {
+  /// A single host.
+  Url(String),
+  /// A single host on this machine, serving on a Unix domain socket or named pipe.
+  Local(LocalSocket),
+  /// The hosts in the SRV records of a name, looked up from the system's name server.
+  DnsSrv(String),
+  /// The hosts advertising a service over multicast DNS.
+  Mdns(String),
+}
+
+impl Target {
+  pub(crate) fn parse(url: &str) -> Self {
+    let name = |name: &str| name.trim_end_matches('/').to_owned();
+    url
+      .strip_prefix("dns+srv://")
+      .map(|n| Self::DnsSrv(name(n)))
+      .or_else(|| url.strip_prefix("mdns://").map(|n| Self::Mdns(name(n))))
+      .or_else(|| LocalSocket::parse(url).map(Self::Local))
+      .unwrap_or_else(|| Self::Url(url.to_owned()))
+  }
+}
+
+/// A host found for a service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Endpoint {
+  pub(crate) addr: SocketAddr,
+  /// The host's name, to verify its certificate against.
+  pub(crate) host: String,
+  pub(crate) priority: u16,
+  pub(crate) weight: u16,
+}
+
+/// How to connect to the hosts of a service.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Connect {
+  pub(crate) pem: Option<PathBuf>,
+  pub(crate) key: Option<PathBuf>,
+  pub(crate) ca: Option<PathBuf>,
+  pub(crate) domain: Option<String>,
+}
+
+impl Connect {
+  async fn client(&self, endpoint: &Endpoint) -> Result<RpcClient, String> {
+    let scheme = if self.pem.is_some() || self.ca.is_some() {
+      "https"
+    } else {
+      "http"
+    };
+    wick_rpc::make_rpc_client(
+      format!("{}://{}", scheme, endpoint.addr),
+      self.pem.clone(),
+      self.key.clone(),
+      self.ca.clone(),
+      Some(self.domain.clone().unwrap_or_else(|| endpoint.host.clone())),
+    )
+    .await
+    .map_err(|e| e.to_string())
+  }
+}
+
+/// The hosts found for `target`.
+pub(crate) async fn resolve(target: &Target) -> io::Result<Vec<Endpoint>> {
+  let records = match target {
+    Target::Url(_) | Target::Local(_) => return Ok(Vec::new()),
+    Target::DnsSrv(name) => lookup_srv(name).await?,
+    Target::Mdns(service) => browse(service).await?,
+  };
+  let mut endpoints = Vec::new();
+  for srv in records.srv {
+    let host = srv.target.trim_end_matches('.').to_owned();
+    let addrs = match records.addrs.get(&host.to_ascii_lowercase()) {
+      Some(addrs) => addrs.iter().map(|ip| SocketAddr::new(*ip, srv.port)).collect(),
+      None => match tokio::net::lookup_host((host.as_str(), srv.port)).await {
+        Ok(addrs) => addrs.collect(),
+        Err(e) => {
+          debug!(%host, error = %e, "could not resolve discovered host");
+          Vec::new()
+        }
+      },
+    };
+    endpoints.extend(addrs.into_iter().map(|addr| Endpoint {
+      addr,
+      host: host.clone(),
+      priority: srv.priority,
+      weight: srv.weight,
+    }));
+  }
+  Ok(endpoints)
+}
+
+fn query_id() -> u16 {
+  let bytes = Uuid::new_v4().into_bytes();
+  u16::from_be_bytes([bytes[0], bytes[1]])
+}
+
+/// The first name server in /etc/resolv.conf.
+fn nameserver() -> io::Result<SocketAddr> {
+  let conf = std::fs::read_to_string("/etc/resolv.conf")?;
+  conf
+    .lines()
+    .filter_map(|line| line.trim().strip_prefix("nameserver"))
+    .find_map(|addr| addr.trim().parse::<IpAddr>().ok())
+    .map(|ip| SocketAddr::new(ip, 53))
+    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no nameserver in /etc/resolv.conf"))
+}
+
+async fn lookup_srv(name: &str) -> io::Result<Records> {
+  let server = nameserver()?;
+  let local: SocketAddr = if server.is_ipv4() {
+    (Ipv4Addr::UNSPECIFIED, 0).into()
+  } else {
+    (Ipv6Addr::UNSPECIFIED, 0).into()
+  };
+  let socket = UdpSocket::bind(local).await?;
+  let id = query_id();
+  socket
+    .send_to(&dns::query(id, name, dns::TYPE_SRV, true), server)
+    .await?;
+
+  let mut buf = vec![0; 4096];
+  let deadline = Instant::now() + LOOKUP_TIMEOUT;
+  loop {
+    let (len, from) = tokio::time::timeout_at(deadline, socket.recv_from(&mut buf))
+      .await
+      .map_err(|_| {
+        io::Error::new(
+          io::ErrorKind::TimedOut,
+          format!("no answer for {} from {}", name, server),
+        )
+      })??;
+    if from != server {
+      continue;
+    }
+    if let Some((response_id, records)) = dns::parse(&buf[..len]) {
+      if response_id == id {
+        return Ok(records);
+      }
+    }
+  }
+}
+
+/// The records of every host that answers a multicast query for `service` within [MDNS_WINDOW].
+async fn browse(service: &str) -> io::Result<Records> {
+  // Querying from a port other than 5353 asks responders to answer us directly.
+  let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+  socket
+    .send_to(&dns::query(query_id(), service, dns::TYPE_PTR, false), MDNS_ADDR)
+    .await?;
+
+  let mut found = Records::default();
+  let mut buf = vec![0; 9000];
+  let deadline = Instant::now() + MDNS_WINDOW;
+  while let Ok(received) = tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)).await {
+    let (len, _) = received?;
+    if let Some((_, records)) = dns::parse(&buf[..len]) {
+      found.srv.extend(records.srv);
+      for (host, addrs) in records.addrs {
+        found.addrs.entry(host).or_default().extend(addrs);
+      }
+    }
+  }
+  Ok(found)
+}
+
+#[derive(Debug)]
+struct Member {
+  endpoint: Endpoint,
+  client: Option<RpcClient>,
+  /// Whether the host answered its last health check. Only set when it has a client.
+  healthy: bool,
+}
+
+/// Spreads invocations across the healthy hosts of a service, by the priority and weight of their SRV records.
+#[derive(Debug)]
+pub(crate) struct Balancer {
+  target: Target,
+  connect: Connect,
+  members: RwLock<Vec<Member>>,
+  next: AtomicUsize,
+  span: Span,
+}
+
+impl Balancer {
+  /// Find the hosts for `target`, connect to them, and keep checking on them until the balancer is dropped.
+  pub(crate) async fn start(target: Target, connect: Connect, span: Span) -> Result<Arc<Self>, String> {
+    let balancer = Arc::new(Self {
+      target,
+      connect,
+      members: RwLock::new(Vec::new()),
+      next: AtomicUsize::new(0),
+      span,
+    });
+    balancer.refresh().await;
+    balancer.check().await;
+    if balancer.members.read().is_empty() {
+      return Err(format!("no hosts found for {:?}", balancer.target));
+    }
+    tokio::spawn(Self::watch(Arc::downgrade(&balancer)));
+    Ok(balancer)
+  }
+
+  /// A client for the next healthy host and the host's address.
+  pub(crate) fn pick(&self) -> Option<(SocketAddr, RpcClient)> {
+    let members = self.members.read();
+    let member = choose(&members, self.next.fetch_add(1, Ordering::Relaxed))?;
+    Some((member.endpoint.addr, member.client.clone()?))
+  }
+
+  /// Take the host at `addr` out of rotation until it passes a health check.
+  pub(crate) fn fail(&self, addr: SocketAddr, error: &str) {
+    if let Some(member) = self.members.write().iter_mut().find(|m| m.endpoint.addr == addr) {
+      if member.healthy {
+        self
+          .span
+          .in_scope(|| warn!(%addr, error, "remote host failed an invocation, taking it out of rotation"));
+      }
+      member.healthy = false;
+    }
+  }
+
+  async fn watch(balancer: Weak<Self>) {
+    let mut refresh = tokio::time::interval(REFRESH_INTERVAL);
+    let mut health = tokio::time::interval(HEALTH_INTERVAL);
+    refresh.tick().await;
+    health.tick().await;
+    loop {
+      let refreshing = tokio::select! {
+        _ = refresh.tick() => true,
+        _ = health.tick() => false,
+      };
+      let Some(balancer) = balancer.upgrade() else {
+        return;
+      };
+      if refreshing {
+        balancer.refresh().await;
+      } else {
+        balancer.check().await;
+      }
+    }
+  }
+
+  /// Look the hosts up again, keeping the clients and health of the ones already known.
+  async fn refresh(&self) {
+    let endpoints = match resolve(&self.target).await {
+      Ok(endpoints) => endpoints,
+      Err(e) => {
+        self
+          .span
+          .in_scope(|| warn!(target = ?self.target, error = %e, "could not look up remote hosts"));
+        return;
+      }
+    };
+    let mut members = self.members.write();
+    let mut known: HashMap<SocketAddr, Member> = members.drain(..).map(|m| (m.endpoint.addr, m)).collect();
+    for endpoint in endpoints {
+      if let Some(mut member) = known.remove(&endpoint.addr) {
+        member.endpoint = endpoint;
+        members.push(member);
+      } else {
+        self
+          .span
+          .in_scope(|| debug!(addr = %endpoint.addr, host = %endpoint.host, "discovered remote host"));
+        members.push(Member {
+          endpoint,
+          client: None,
+          healthy: false,
+        });
+      }
+    }
+    for addr in known.keys() {
+      self.span.in_scope(|| debug!(%addr, "remote host is gone"));
+    }
+  }
+
+  /// Connect to hosts without a client and check that every host is serving.
+  async fn check(&self) {
+    // Collected so the lock isn't held while the checks run.
+    #[allow(clippy::needless_collect)]
+    let members: Vec<_> = self
+      .members
+      .read()
+      .iter()
+      .map(|m| (m.endpoint.clone(), m.client.clone()))
+      .collect();
+    let checks = members.into_iter().map(|(endpoint, client)| async move {
+      let result = tokio::time::timeout(HEALTH_TIMEOUT, async {
+        let mut client = match client {
+          Some(client) => client,
+          None => self.connect.client(&endpoint).await?,
+        };
+        client.list().await.map_err(|e| e.to_string())?;
+        Ok::<_, String>(client)
+      })
+      .await
+      .unwrap_or_else(|_| Err("timed out".to_owned()));
+      (endpoint.addr, result)
+    });
+    let results = futures::future::join_all(checks).await;
+
+    let mut members = self.members.write();
+    for (addr, result) in results {
+      let Some(member) = members.iter_mut().find(|m| m.endpoint.addr == addr) else {
+        continue;
+      };
+      match result {
+        Ok(client) => {
+          if !member.healthy {
+            self.span.in_scope(|| info!(%addr, "remote host is healthy"));
+          }
+          member.client = Some(client);
+          member.healthy = true;
+        }
+        Err(error) => {
+          if member.healthy {
+            self
+              .span
+              .in_scope(|| warn!(%addr, error, "remote host failed its health check"));
+          }
+          member.healthy = false;
+        }
+      }
+    }
+  }
+}
+
+/// The `n`th pick among the healthy members with the best priority, each picked in proportion to its weight.
+fn choose(members: &[Member], n: usize) -> Option<&Member> {
+  let priority = members
+    .iter()
+    .filter(|m| m.healthy)
+    .map(|m| m.endpoint.priority)
+    .min()?;
+  let group: Vec<_> = members
+    .iter()
+    .filter(|m| m.healthy && m.endpoint.priority == priority)
+    .collect();
+  let total: usize = group.iter().map(|m| usize::from(m.endpoint.weight.max(1))).sum();
+  let mut n = n % total;
+  for member in group {
+    let weight = usize::from(member.endpoint.weight.max(1));
+    if n < weight {
+      return Some(member);
+    }
+    n -= weight;
+  }
+  None
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn member(port: u16, priority: u16, weight: u16, healthy: bool) -> Member {
+    Member {
+      endpoint: Endpoint {
+        addr: SocketAddr::new(Ipv4Addr::LOCALHOST.into(), port),
+        host: "localhost".to_owned(),
+        priority,
+        weight,
+      },
+      client: None,
+      healthy,
+    }
+  }
+
+  fn picks(members: &[Member], count: usize) -> Vec<u16> {
+    (0..count)
+      .filter_map(|n| choose(members, n))
+      .map(|m| m.endpoint.addr.port())
+      .collect()
+  }
+
+  #[test]
+  fn test_parse_target() {
+    assert_eq!(
+      Target::parse("dns+srv://_wick._tcp.example.com"),
+      Target::DnsSrv("_wick._tcp.example.com".to_owned())
+    );
+    assert_eq!(
+      Target::parse("mdns://_wick._tcp.local/"),
+      Target::Mdns("_wick._tcp.local".to_owned())
+    );
+    assert_eq!(
+      Target::parse("unix:///run/wick.sock"),
+      Target::Local(LocalSocket::Unix("/run/wick.sock".into()))
+    );
+    assert_eq!(
+      Target::parse("http://127.0.0.1:8090"),
+      Target::Url("http://127.0.0.1:8090".to_owned())
+    );
+  }
+
+  #[test]
+  fn test_choose_by_weight() {
+    let members = vec![member(1, 10, 3, true), member(2, 10, 1, true)];
+    assert_eq!(picks(&members, 8), vec![1, 1, 1, 2, 1, 1, 1, 2]);
+  }
+
+  #[test]
+  fn test_choose_by_priority() {
+    let members = vec![member(1, 20, 1, true), member(2, 10, 1, false), member(3, 10, 0, true)];
+    assert_eq!(picks(&members, 3), vec![3, 3, 3]);
+    let members = vec![member(1, 20, 1, true), member(2, 10, 1, false)];
+    assert_eq!(picks(&members, 2), vec![1, 1]);
+    let members = vec![member(1, 20, 1, false)];
+    assert_eq!(picks(&members, 2), Vec::<u16>::new());
+  }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub(super) const TYPE_A: u16 = 1;
+pub(super) const TYPE_PTR: u16 = 12;
+pub(super) const TYPE_AAAA: u16 = 28;
+pub(super) const TYPE_SRV: u16 = 33;
+
+const CLASS_IN: u16 = 1;
+
+/// The most compression pointers to follow in one name, so a malicious response can't loop forever.
+const MAX_POINTERS: usize = 16;
+
+/// A service instance from an SRV record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Srv {
+  pub(super) priority: u16,
+  pub(super) weight: u16,
+  pub(super) port: u16,
+  pub(super) target: String,
+}
+
+/// The SRV records and addresses in a response, from any of its sections.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct Records {
+  pub(super) srv: Vec<Srv>,
+  pub(super) addrs: HashMap<String, Vec<IpAddr>>,
+}
+
+/// A query with one question for `name`. `recursive` asks a unicast server to resolve it fully.
+pub(super) fn query(id: u16, name: &str, qtype: u16, recursive: bool) -> Vec<u8> {
+  let mut buf = Vec::with_capacity(name.len() + 18);
+  buf.extend_from_slice(&id.to_be_bytes());
+  buf.extend_from_slice(&(if recursive { 0x0100_u16 } else { 0 }).to_be_bytes());
+  buf.extend_from_slice(&[0, 1, 0, 0, 0, 0, 0, 0]);
+  for label in name.trim_end_matches('.').split('.') {
+    buf.push(label.len().min(63) as u8);
+    buf.extend_from_slice(&label.as_bytes()[..label.len().min(63)]);
+  }
+  buf.push(0);
+  buf.extend_from_slice(&qtype.to_be_bytes());
+  buf.extend_from_slice(&CLASS_IN.to_be_bytes());
+  buf
+}
+
+/// The id of a response and the records in it, or None if it's malformed.
+pub(super) fn parse(buf: &[u8]) -> Option<(u16, Records)> {
+  let id = read_u16(buf, 0)?;
+  let questions = read_u16(buf, 4)?;
+  let records = usize::from(read_u16(buf, 6)?) + usize::from(read_u16(buf, 8)?) + usize::from(read_u16(buf, 10)?);
+
+  let mut pos = 12;
+  for _ in 0..questions {
+    pos = read_name(buf, pos)?.1 + 4;
+  }
+
+  let mut found = Records::default();
+  for _ in 0..records {
+    let (name, next) = read_name(buf, pos)?;
+    let rtype = read_u16(buf, next)?;
+    let len = usize::from(read_u16(buf, next + 8)?);
+    let data = next + 10;
+    let rdata = buf.get(data..data + len)?;
+    match rtype {
+      TYPE_SRV => found.srv.push(Srv {
+        priority: read_u16(buf, data)?,
+        weight: read_u16(buf, data + 2)?,
+        port: read_u16(buf, data + 4)?,
+        target: read_name(buf, data + 6)?.0,
+      }),
+      TYPE_A => {
+        let octets: [u8; 4] = rdata.try_into().ok()?;
+        found
+          .addrs
+          .entry(name.to_ascii_lowercase())
+          .or_default()
+          .push(IpAddr::V4(Ipv4Addr::from(octets)));
+      }
+      TYPE_AAAA => {
+        let octets: [u8; 16] = rdata.try_into().ok()?;
+        found
+          .addrs
+          .entry(name.to_ascii_lowercase())
+          .or_default()
+          .push(IpAddr::V6(Ipv6Addr::from(octets)));
+      }
+      _ => {}
+    }
+    pos = data + len;
+  }
+  Some((id, found))
+}
+
+fn read_u16(buf: &[u8], pos: usize) -> Option<u16> {
+  Some(u16::from_be_bytes([*buf.get(pos)?, *buf.get(pos + 1)?]))
+}
+
+/// The dotted name at `pos`, following compression pointers, and the position after it.
+fn read_name(buf: &[u8], mut pos: usize) -> Option<(String, usize)> {
+  let mut labels = Vec::new();
+  let mut end = None;
+  let mut pointers = 0;
+  loop {
+    let len = *buf.get(pos)?;
+    if len == 0 {
+      return Some((labels.join("."), end.unwrap_or(pos + 1)));
+    }
+    if len & 0xC0 == 0xC0 {
+      pointers += 1;
+      if pointers > MAX_POINTERS {
+        return None;
+      }
+      end.get_or_insert(pos + 2);
+      pos = usize::from(read_u16(buf, pos)? & 0x3FFF);
+    } else {
+      let label = buf.get(pos + 1..pos + 1 + usize::from(len))?;
+      labels.push(String::from_utf8_lossy(label).into_owned());
+      pos += 1 + usize::from(len);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  /// A response to `query` with an SRV answer and an A record for its target, using compressed names.
+  fn response(query: &[u8]) -> Vec<u8> {
+    let mut buf = query.to_vec();
+    buf[2] = 0x81;
+    buf[7] = 1;
+    buf[11] = 1;
+    // answer: <question name> SRV 10 5 8090 node1.<question name>
+    buf.extend_from_slice(&[0xC0, 12]);
+    buf.extend_from_slice(&TYPE_SRV.to_be_bytes());
+    buf.extend_from_slice(&[0, 1, 0, 0, 0, 60]);
+    let target = [5, b'n', b'o', b'd', b'e', b'1', 0xC0, 12];
+    buf.extend_from_slice(&(6 + target.len() as u16).to_be_bytes());
+    buf.extend_from_slice(&[0, 10, 0, 5]);
+    buf.extend_from_slice(&8090_u16.to_be_bytes());
+    let target_pos = buf.len();
+    buf.extend_from_slice(&target);
+    // additional: node1.<question name> A 10.0.0.7
+    buf.extend_from_slice(&[0xC0, target_pos as u8]);
+    buf.extend_from_slice(&TYPE_A.to_be_bytes());
+    buf.extend_from_slice(&[0, 1, 0, 0, 0, 60, 0, 4, 10, 0, 0, 7]);
+    buf
+  }
+
+  #[test]
+  fn test_parse_srv() {
+    let query = query(7, "_wick._tcp.example.com", TYPE_SRV, true);
+    let (id, records) = parse(&response(&query)).unwrap();
+    assert_eq!(id, 7);
+    assert_eq!(
+      records.srv,
+      vec![Srv {
+        priority: 10,
+        weight: 5,
+        port: 8090,
+        target: "node1._wick._tcp.example.com".to_owned()
+      }]
+    );
+    assert_eq!(
+      records.addrs.get("node1._wick._tcp.example.com"),
+      Some(&vec![IpAddr::V4(Ipv4Addr::new(10, 0, 0, 7))])
+    );
+  }
+
+  #[test]
+  fn test_parse_malformed() {
+    let query = query(7, "_wick._tcp.example.com", TYPE_SRV, true);
+    let response = response(&query);
+    assert_eq!(parse(&response[..response.len() - 3]), None);
+    let mut looping = query.clone();
+    looping[7] = 1;
+    looping.extend_from_slice(&[0xC0, query.len() as u8, 0xC0, query.len() as u8]);
+    assert_eq!(parse(&looping), None);
+  }
+}
@@ -0,0 +1,376 @@
+use std::net::{Ipv4Addr, TcpListener};
+use std::path::PathBuf;
+use std::process::{ExitStatus, Stdio};
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::{Child, Command};
+use tokio::sync::{oneshot, watch};
+use tokio::time::Instant;
+use wick_config::config::components::{ProcessHost, RestartPolicy};
+use wick_rpc::RpcClient;
+
+use crate::dev::prelude::*;
+use crate::runtime::scope::ChildInit;
+
+/// How many times a process is restarted when `max_restarts` isn't set.
+const DEFAULT_MAX_RESTARTS: u32 = 5;
+
+/// How long a process has to start serving its component when `startup_timeout` isn't set.
+const DEFAULT_STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often to try connecting to a process while it starts.
+const CONNECT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The first and longest waits before restarting a process.
+const MIN_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Whether `policy` restarts a process that exited, successfully or not.
+pub(crate) const fn restarts(policy: RestartPolicy, success: bool) -> bool {
+  match policy {
+    RestartPolicy::OnFailure => !success,
+    RestartPolicy::Always => true,
+    RestartPolicy::Never => false,
+  }
+}
+
+/// How long to wait before the `attempt`th restart, doubling from [MIN_BACKOFF] up to [MAX_BACKOFF].
+pub(crate) fn backoff(attempt: u32) -> Duration {
+  MIN_BACKOFF
+    .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)))
+    .min(MAX_BACKOFF)
+}
+
+/// What invocations of a process-hosted component can expect.
+#[derive(Debug, Clone)]
+enum State {
+  /// The process is starting or restarting.
+  Starting,
+  /// The process is serving the component at this client.
+  Running(RpcClient),
+  /// The process exited and won't be restarted, for this reason.
+  Stopped(String),
+}
+
+/// How to start a component's process.
+#[derive(Debug, Clone)]
+struct Spec {
+  namespace: String,
+  command: PathBuf,
+  reference: String,
+  config: Option<String>,
+  allow_latest: bool,
+  allowed_insecure: Vec<String>,
+  startup_timeout: Duration,
+  span: Span,
+}
+
+/// A component served by a separate `wick serve` process that the runtime supervises, restarting it by its
+/// [RestartPolicy] and routing its output into tracing.
+///
+/// Operation configuration isn't sent over gRPC, so operations of a process-hosted component can only be configured
+/// through the component's own configuration.
+pub(crate) struct ProcessComponent {
+  signature: ComponentSignature,
+  state: watch::Receiver<State>,
+  startup_timeout: Duration,
+  _shutdown: oneshot::Sender<()>,
+}
+
+impl std::fmt::Debug for ProcessComponent {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ProcessComponent")
+      .field("signature", &self.signature)
+      .finish()
+  }
+}
+
+impl ProcessComponent {
+  /// Start the process for the manifest at `reference` and supervise it until the component is dropped.
+  pub(crate) async fn start(
+    namespace: &str,
+    reference: &wick_config::AssetReference,
+    host: &ProcessHost,
+    opts: &ChildInit,
+  ) -> Result<Self, ScopeError> {
+    let init_error = |e: String| ScopeError::ComponentInit(namespace.to_owned(), e);
+    let command = host
+      .command()
+      .map_or_else(std::env::current_exe, |command| Ok(PathBuf::from(command)))
+      .map_err(|e| init_error(e.to_string()))?;
+    let config = opts
+      .root_config
+      .as_ref()
+      .map(serde_json::to_string)
+      .transpose()
+      .map_err(|e| init_error(e.to_string()))?;
+    let spec = Spec {
+      namespace: namespace.to_owned(),
+      command,
+      reference: reference
+        .path()
+        .map_or_else(|_| reference.location().to_owned(), |p| p.to_string_lossy().to_string()),
+      config,
+      allow_latest: opts.allow_latest,
+      allowed_insecure: opts.allowed_insecure.clone(),
+      startup_timeout: host
+        .startup_timeout()
+        .map_or(DEFAULT_STARTUP_TIMEOUT, Duration::from_millis),
+      span: opts.span.clone(),
+    };
+
+    let (child, mut client) = spawn(&spec).await.map_err(init_error)?;
+    let signature = client
+      .list()
+      .await
+      .map_err(|e| init_error(e.to_string()))?
+      .into_iter()
+      .next()
+      .ok_or_else(|| init_error("the process did not report a component".to_owned()))?;
+
+    let (state_tx, state) = watch::channel(State::Running(client));
+    let (shutdown, shutdown_rx) = oneshot::channel();
+    let startup_timeout = spec.startup_timeout;
+    let supervisor = Supervisor {
+      spec,
+      child,
+      policy: *host.restart(),
+      max_restarts: host.max_restarts().unwrap_or(DEFAULT_MAX_RESTARTS),
+      state: state_tx,
+      shutdown: shutdown_rx,
+    };
+    tokio::spawn(supervisor.run());
+
+    Ok(Self {
+      signature,
+      state,
+      startup_timeout,
+      _shutdown: shutdown,
+    })
+  }
+
+  /// The client of the running process, waiting for it when it's restarting.
+  async fn client(mut state: watch::Receiver<State>, timeout: Duration) -> Result<RpcClient, String> {
+    let wait = async {
+      loop {
+        let current = state.borrow_and_update().clone();
+        match current {
+          State::Running(client) => return Ok(client),
+          State::Stopped(reason) => return Err(reason),
+          State::Starting => {}
+        }
+        if state.changed().await.is_err() {
+          return Err("the component's process is no longer supervised".to_owned());
+        }
+      }
+    };
+    tokio::time::timeout(timeout, wait)
+      .await
+      .unwrap_or_else(|_| Err("timed out waiting for the component's process to restart".to_owned()))
+  }
+}
+
+impl Component for ProcessComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> flow_component::BoxFuture<Result<PacketStream, flow_component::ComponentError>> {
+    if config.is_some() {
+      invocation.trace(|| warn!("operation configuration is not sent to components running in their own process"));
+    }
+    let state = self.state.clone();
+    let timeout = self.startup_timeout;
+    Box::pin(async move {
+      let mut client = Self::client(state, timeout)
+        .await
+        .map_err(flow_component::ComponentError::msg)?;
+      client
+        .invoke(invocation)
+        .await
+        .map_err(flow_component::ComponentError::new)
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+/// Restarts a component's process by its policy whenever it exits.
+struct Supervisor {
+  spec: Spec,
+  child: Child,
+  policy: RestartPolicy,
+  max_restarts: u32,
+  state: watch::Sender<State>,
+  shutdown: oneshot::Receiver<()>,
+}
+
+impl Supervisor {
+  /// Supervise the process until the component is dropped or the process is out of restarts.
+  async fn run(mut self) {
+    let spec = &self.spec;
+    let mut attempts = 0;
+    loop {
+      let status = tokio::select! {
+        status = self.child.wait() => status,
+        _ = &mut self.shutdown => {
+          spec.span.in_scope(|| debug!(component = %spec.namespace, "stopping component process"));
+          let _ = self.child.kill().await;
+          return;
+        }
+      };
+      let (success, exit) = match status {
+        Ok(status) => (status.success(), describe(status)),
+        Err(e) => (false, e.to_string()),
+      };
+      self.state.send_replace(State::Starting);
+
+      loop {
+        if !restarts(self.policy, success) {
+          spec.span.in_scope(
+            || info!(component = %spec.namespace, exit = %exit, policy = %self.policy, "component process exited"),
+          );
+          self
+            .state
+            .send_replace(State::Stopped(format!("the component's process {}", exit)));
+          return;
+        }
+        if attempts >= self.max_restarts {
+          spec.span.in_scope(|| {
+            error!(component = %spec.namespace, exit = %exit, restarts = attempts, "component process exited too many times, not restarting");
+          });
+          self.state.send_replace(State::Stopped(format!(
+            "the component's process {} after {} restarts",
+            exit, attempts
+          )));
+          return;
+        }
+        attempts += 1;
+        let delay = backoff(attempts);
+        spec.span.in_scope(|| {
+          warn!(component = %spec.namespace, exit = %exit, restart = attempts, ?delay, "component process exited, restarting");
+        });
+        tokio::select! {
+          _ = tokio::time::sleep(delay) => {}
+          _ = &mut self.shutdown => return,
+        }
+        match spawn(spec).await {
+          Ok((child, client)) => {
+            self.child = child;
+            self.state.send_replace(State::Running(client));
+            break;
+          }
+          Err(e) => {
+            spec
+              .span
+              .in_scope(|| error!(component = %spec.namespace, error = %e, "component process failed to restart"));
+          }
+        }
+      }
+    }
+  }
+}
+
+/// Start a process serving the component and connect to it.
+async fn spawn(spec: &Spec) -> Result<(Child, RpcClient), String> {
+  let port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+    .and_then(|listener| listener.local_addr())
+    .map_err(|e| format!("could not find a free port for the component's process: {}", e))?
+    .port();
+
+  let mut command = Command::new(&spec.command);
+  command
+    .arg("serve")
+    .arg(&spec.reference)
+    .arg("--rpc")
+    .args(["--rpc-address", "127.0.0.1"])
+    .args(["--rpc-port", &port.to_string()]);
+  if let Some(config) = &spec.config {
+    command.args(["--with", config]);
+  }
+  if spec.allow_latest {
+    command.arg("--allow-latest");
+  }
+  for registry in &spec.allowed_insecure {
+    command.args(["--insecure-oci", registry]);
+  }
+  command
+    .stdin(Stdio::null())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .kill_on_drop(true);
+
+  let mut child = command
+    .spawn()
+    .map_err(|e| format!("could not start {}: {}", spec.command.display(), e))?;
+  spec
+    .span
+    .in_scope(|| debug!(component = %spec.namespace, pid = ?child.id(), port, "started component process"));
+  if let Some(stdout) = child.stdout.take() {
+    tokio::spawn(capture(stdout, "stdout", spec.namespace.clone(), spec.span.clone()));
+  }
+  if let Some(stderr) = child.stderr.take() {
+    tokio::spawn(capture(stderr, "stderr", spec.namespace.clone(), spec.span.clone()));
+  }
+
+  let deadline = Instant::now() + spec.startup_timeout;
+  loop {
+    if let Ok(Some(status)) = child.try_wait() {
+      return Err(format!("the component's process {} while starting", describe(status)));
+    }
+    if let Ok(client) = wick_rpc::make_rpc_client(format!("http://127.0.0.1:{}", port), None, None, None, None).await {
+      return Ok((child, client));
+    }
+    if Instant::now() >= deadline {
+      return Err(format!(
+        "the component's process did not start serving within {}ms",
+        spec.startup_timeout.as_millis()
+      ));
+    }
+    tokio::time::sleep(CONNECT_INTERVAL).await;
+  }
+}
+
+/// Log each line of a process's `stream`.
+async fn capture<R: AsyncRead + Unpin + Send>(output: R, stream: &'static str, component: String, span: Span) {
+  let mut lines = BufReader::new(output).lines();
+  while let Ok(Some(line)) = lines.next_line().await {
+    if stream == "stderr" {
+      span.in_scope(|| warn!(component = %component, stream, "{}", line));
+    } else {
+      span.in_scope(|| info!(component = %component, stream, "{}", line));
+    }
+  }
+}
+
+fn describe(status: ExitStatus) -> String {
+  status
+    .code()
+    .map_or_else(|| "was killed".to_owned(), |code| format!("exited with code {}", code))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_restarts() {
+    assert!(restarts(RestartPolicy::OnFailure, false));
+    assert!(!restarts(RestartPolicy::OnFailure, true));
+    assert!(restarts(RestartPolicy::Always, true));
+    assert!(restarts(RestartPolicy::Always, false));
+    assert!(!restarts(RestartPolicy::Never, false));
+  }
+
+  #[test]
+  fn test_backoff() {
+    assert_eq!(backoff(1), Duration::from_millis(100));
+    assert_eq!(backoff(2), Duration::from_millis(200));
+    assert_eq!(backoff(4), Duration::from_millis(800));
+    assert_eq!(backoff(20), MAX_BACKOFF);
+  }
+}
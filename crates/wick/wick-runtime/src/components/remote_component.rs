@@ -0,0 +1,183 @@
+use wick_config::config::components::GrpcUrlComponent;
+use wick_rpc::RpcClient;
+
+use super::discovery::{Balancer, Connect, Target};
+use crate::dev::prelude::*;
+
+/// Where a remote component's invocations go.
+#[derive(Debug, Clone)]
+enum Upstream {
+  /// The one host at the definition's URL.
+  Static(RpcClient),
+  /// Whichever healthy host discovered for the definition's URL is next.
+  Discovered(Arc<Balancer>),
+}
+
+/// A component served by another wick host, whose invocations and packets are forwarded to it over gRPC.
+#[derive(Debug, Clone)]
+pub(crate) struct RemoteComponent {
+  upstream: Upstream,
+  signature: ComponentSignature,
+}
+
+impl RemoteComponent {
+  /// Connect to the host at the definition's `url`, over mutual TLS when it has a certificate and key, to a host on
+  /// this machine through a Unix domain socket or named pipe, or to the hosts discovered for it.
+  pub(crate) async fn connect(namespace: &str, def: &GrpcUrlComponent, span: Span) -> Result<Self, ScopeError> {
+    let init_error = |e: String| ScopeError::ComponentInit(namespace.to_owned(), e);
+    let connect = Connect {
+      pem: def.pem().map(|v| v.path()).transpose()?,
+      key: def.key().map(|v| v.path()).transpose()?,
+      ca: def.ca().map(|v| v.path()).transpose()?,
+      domain: def.domain().cloned(),
+    };
+
+    let (upstream, mut client) = match Target::parse(def.url()) {
+      Target::Url(url) => {
+        let client = wick_rpc::make_rpc_client(url, connect.pem, connect.key, connect.ca, connect.domain)
+          .await
+          .map_err(|e| init_error(format!("could not connect to {}: {}", def.url(), e)))?;
+        (Upstream::Static(client.clone()), client)
+      }
+      Target::Local(socket) => {
+        let client = wick_rpc::make_local_rpc_client(socket)
+          .await
+          .map_err(|e| init_error(format!("could not connect to {}: {}", def.url(), e)))?;
+        (Upstream::Static(client.clone()), client)
+      }
+      target => {
+        let balancer = Balancer::start(target, connect, span).await.map_err(init_error)?;
+        let (_, client) = balancer
+          .pick()
+          .ok_or_else(|| init_error(format!("no healthy hosts found for {}", def.url())))?;
+        (Upstream::Discovered(balancer), client)
+      }
+    };
+    let signature = client
+      .list()
+      .await
+      .map_err(|e| init_error(e.to_string()))?
+      .into_iter()
+      .next()
+      .ok_or_else(|| init_error(format!("{} did not report a component", def.url())))?;
+
+    Ok(Self { upstream, signature })
+  }
+}
+
+impl Component for RemoteComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> flow_component::BoxFuture<Result<PacketStream, flow_component::ComponentError>> {
+    invocation.trace(|| debug!(target = %invocation.target(), "remote:invoke"));
+    let upstream = self.upstream.clone();
+    Box::pin(async move {
+      match upstream {
+        Upstream::Static(mut client) => client
+          .invoke(invocation)
+          .await
+          .map_err(flow_component::ComponentError::new),
+        Upstream::Discovered(balancer) => {
+          let (addr, mut client) = balancer
+            .pick()
+            .ok_or_else(|| flow_component::ComponentError::msg("no healthy hosts for remote component"))?;
+          client.invoke(invocation).await.map_err(|e| {
+            balancer.fail(addr, &e.to_string());
+            flow_component::ComponentError::new(e)
+          })
+        }
+      }
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use futures::StreamExt;
+  use wick_config::config::components::GrpcUrlComponentBuilder;
+  use wick_packet::{packet_stream, Entity, Observer, Packet};
+
+  use super::*;
+  use crate::test::prelude::{assert_eq, *};
+
+  #[test_logger::test(tokio::test)]
+  async fn test_remote_invoke() -> Result<()> {
+    let (scope, _) = init_scope_from_yaml("./manifests/v0/simple.yaml").await?;
+    let socket = wick_invocation_server::bind_new_socket()?;
+    let port = socket.local_addr()?.port();
+    let _server = wick_invocation_server::make_rpc_server(socket, Arc::new(scope.to_component()));
+
+    let def = GrpcUrlComponentBuilder::default()
+      .url(format!("http://127.0.0.1:{}", port))
+      .build()?;
+    let component = RemoteComponent::connect("remote", &def, Span::current()).await?;
+    assert_eq!(component.signature().operations.len(), 1);
+
+    let stream = packet_stream!(("MAIN_IN", "hello"));
+    let invocation = Invocation::test(file!(), Entity::operation("remote", "simple"), stream, None)?;
+    let packets: Vec<_> = component
+      .handle(invocation, None, Default::default())
+      .await?
+      .collect()
+      .await;
+    assert_eq!(packets[0].as_ref().unwrap(), &Packet::encode("MAIN_OUT", "hello"));
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_remote_invoke_bidirectional() -> Result<()> {
+    let (scope, _) = init_scope_from_yaml("./manifests/v0/simple.yaml").await?;
+    let socket = wick_invocation_server::bind_new_socket()?;
+    let port = socket.local_addr()?.port();
+    let _server = wick_invocation_server::make_rpc_server(socket, Arc::new(scope.to_component()));
+
+    let def = GrpcUrlComponentBuilder::default()
+      .url(format!("http://127.0.0.1:{}", port))
+      .build()?;
+    let component = RemoteComponent::connect("remote", &def, Span::current()).await?;
+
+    let (tx, stream) = PacketStream::new_channels();
+    let invocation = Invocation::test(file!(), Entity::operation("remote", "simple"), stream, None)?;
+    let mut outputs = component.handle(invocation, None, Default::default()).await?;
+
+    // outputs arrive while the input stream is still open.
+    tx.send(Packet::encode("MAIN_IN", "hello"))?;
+    let first = tokio::time::timeout(std::time::Duration::from_secs(5), outputs.next()).await?;
+    assert_eq!(first.unwrap()?, Packet::encode("MAIN_OUT", "hello"));
+
+    tx.send(Packet::done("MAIN_IN"))?;
+    tx.complete();
+    Ok(())
+  }
+
+  #[cfg(unix)]
+  #[test_logger::test(tokio::test)]
+  async fn test_remote_invoke_uds() -> Result<()> {
+    let (scope, _) = init_scope_from_yaml("./manifests/v0/simple.yaml").await?;
+    let path = std::env::temp_dir().join(format!("wick-{}.sock", uuid::Uuid::new_v4()));
+    let _server = wick_invocation_server::make_uds_rpc_server(&path, Arc::new(scope.to_component()))?;
+
+    let def = GrpcUrlComponentBuilder::default()
+      .url(format!("unix://{}", path.display()))
+      .build()?;
+    let component = RemoteComponent::connect("remote", &def, Span::current()).await?;
+
+    let stream = packet_stream!(("MAIN_IN", "hello"));
+    let invocation = Invocation::test(file!(), Entity::operation("remote", "simple"), stream, None)?;
+    let packets: Vec<_> = component
+      .handle(invocation, None, Default::default())
+      .await?
+      .collect()
+      .await;
+    assert_eq!(packets[0].as_ref().unwrap(), &Packet::encode("MAIN_OUT", "hello"));
+    std::fs::remove_file(path)?;
+    Ok(())
+  }
+}
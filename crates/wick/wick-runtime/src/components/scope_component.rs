@@ -1,4 +1,3 @@
-use uuid::Uuid;
 use wick_packet::Entity;
 
 use crate::dev::prelude::*;
@@ -8,18 +7,15 @@ struct State {}
 
 #[derive(Clone, Debug)]
 pub struct ScopeComponent {
-  scope_id: Uuid,
+  scope: Scope,
   signature: ComponentSignature,
 }
 
 impl ScopeComponent {
-  #[must_use]
-  pub fn new(scope_id: Uuid) -> Self {
-    let addr = Scope::for_id(&scope_id).unwrap();
+  pub(crate) fn new(scope: Scope) -> Self {
+    let signature = scope.get_signature().unwrap();
 
-    let signature = addr.get_signature().unwrap();
-
-    Self { scope_id, signature }
+    Self { scope, signature }
   }
 }
 
@@ -34,16 +30,14 @@ impl Component for ScopeComponent {
 
     invocation.trace(|| {
       debug!(
-        scope_id = %self.scope_id,
+        scope_id = %self.scope.id(),
         target =  %invocation.target(),
         "scope:invoke",
       );
     });
 
+    let scope = self.scope.clone();
     Box::pin(async move {
-      let scope = Scope::for_id(&self.scope_id)
-        .ok_or_else(|| flow_component::ComponentError::msg(format!("scope '{}' not found", target_url)))?;
-
       let target_component = invocation.target().component_id().to_owned();
       if target_component != scope.namespace() {
         debug!(
@@ -104,9 +98,9 @@ mod tests {
 
   #[test_logger::test(tokio::test)]
   async fn test_request_log() -> Result<()> {
-    let (_, scope_id) = init_scope_from_yaml("./manifests/v0/simple.yaml").await?;
+    let (runtime, _) = init_scope_from_yaml("./manifests/v0/simple.yaml").await?;
 
-    let component = ScopeComponent::new(scope_id);
+    let component = runtime.to_component();
     let user_data = "string to log";
     let result = request_log(&component, user_data).await?;
     print!("Result: {}", result);
@@ -116,8 +110,8 @@ mod tests {
 
   #[test_logger::test(tokio::test)]
   async fn test_list() -> Result<()> {
-    let (_, scope_id) = init_scope_from_yaml("./manifests/v0/simple.yaml").await?;
-    let component = ScopeComponent::new(scope_id);
+    let (runtime, _) = init_scope_from_yaml("./manifests/v0/simple.yaml").await?;
+    let component = runtime.to_component();
     let sig = component.signature();
     println!("operations in scope : {:?}", sig);
     assert_eq!(sig.operations.len(), 1);
@@ -2,6 +2,7 @@ use uuid::Uuid;
 use wick_packet::{Invocation, PacketStream, RuntimeConfig};
 
 use crate::dev::prelude::*;
+use crate::runtime::scope::ScopeRegistry;
 
 #[derive(Debug)]
 #[must_use]
@@ -48,11 +49,12 @@ impl From<ComponentError> for DispatchError {
 }
 
 pub(crate) async fn scope_invoke_async(
+  scopes: &ScopeRegistry,
   scope_id: Uuid,
   invocation: Invocation,
   config: Option<RuntimeConfig>,
 ) -> Result<PacketStream, DispatchError> {
-  let scope = Scope::for_id(&scope_id).ok_or(DispatchError::EntityNotAvailable(scope_id))?;
+  let scope = scopes.get(&scope_id).ok_or(DispatchError::EntityNotAvailable(scope_id))?;
 
   let response = scope.invoke(invocation, config)?.await?;
   match response {
@@ -71,13 +73,13 @@ mod tests {
   use crate::test::prelude::{assert_eq, *};
   #[test_logger::test(tokio::test)]
   async fn invoke_async() -> Result<()> {
-    let (_, nuid) = init_scope_from_yaml("./manifests/v0/echo.yaml").await?;
+    let (runtime, nuid) = init_scope_from_yaml("./manifests/v0/echo.yaml").await?;
 
     let target = Entity::operation("self", "echo");
     let stream = packet_stream![("input", "hello")];
     let invocation = Invocation::test(file!(), target, stream, None)?;
 
-    let packets = scope_invoke_async(nuid, invocation, Default::default()).await?;
+    let packets = scope_invoke_async(&runtime.scopes, nuid, invocation, Default::default()).await?;
     let mut packets: Vec<_> = packets.collect().await;
     debug!("{:?}", packets);
     assert_eq!(packets.len(), 2);
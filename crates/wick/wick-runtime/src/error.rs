@@ -67,6 +67,9 @@ pub enum RuntimeError {
   #[error("{0}")]
   InitializationFailed(String),
 
+  #[error(transparent)]
+  Scope(Box<ScopeError>),
+
   #[error("Could not find scope '{}' via path {}", .1.as_ref().map_or_else(||Entity::LOCAL,|e|e.component_id()), .0.as_ref().map_or_else(String::new,|v|format!("via path {} ",v.join("::"))))]
   ScopeNotFound(Option<Vec<String>>, Option<Entity>),
 
@@ -90,6 +93,15 @@ pub enum RuntimeError {
 
   #[error("Could not render dotviz: {0}")]
   DotViz(flow_graph_interpreter::error::InterpreterError),
+
+  #[error("Could not compute content hash: {0}")]
+  ContentHash(flow_graph_interpreter::error::InterpreterError),
+
+  #[error("Can not share '{0}', no component is imported under that namespace")]
+  ShareNotFound(String),
+
+  #[error("Could not resume unfinished transactions: {0}")]
+  Resume(flow_graph_interpreter::error::InterpreterError),
 }
 
 impl From<Infallible> for RuntimeError {
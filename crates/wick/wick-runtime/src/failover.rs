@@ -0,0 +1,435 @@
+//! Active/passive failover between runtimes that share a lease.
+//!
+//! Every instance of an app builds its runtime, so its components are warm, but only the instance holding the lease
+//! runs triggers. The others poll the lease and take it over when it expires, running their [TakeoverHook]s first so
+//! the work of the failed instance can be replayed.
+//!
+//! A [Coordinator] shares one backend between several leases instead, so instances that all run the app can still
+//! agree on which of them runs work that must happen once, like a cron schedule.
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+mod etcd;
+mod file;
+mod redis;
+pub use etcd::EtcdLease;
+pub use file::FileLease;
+pub use redis::RedisLease;
+
+/// The default time a lease is held for without being renewed.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(15);
+
+/// Errors reading or writing a lease.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum FailoverError {
+  #[error("invalid lease backend '{0}', expected file://<path>, redis://<host>[:<port>]/<key>, or etcd://<host>[:<port>]/<key>")]
+  InvalidBackend(String),
+
+  #[error("could not reach the lease in {backend}: {reason}")]
+  Backend { backend: &'static str, reason: String },
+
+  #[error("takeover hook failed: {0}")]
+  Hook(String),
+}
+
+/// A lease and the node that holds it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Lease {
+  /// The id of the node that holds the lease.
+  pub holder: String,
+  /// When the lease expires unless it's renewed, in milliseconds since the epoch.
+  pub expires_at: u64,
+}
+
+impl Lease {
+  /// A lease for `holder` that expires `ttl` from now.
+  #[must_use]
+  pub fn new<T: Into<String>>(holder: T, ttl: Duration) -> Self {
+    Self {
+      holder: holder.into(),
+      expires_at: now_ms().saturating_add(ttl.as_millis() as u64),
+    }
+  }
+
+  /// Whether the lease has expired.
+  #[must_use]
+  pub fn expired(&self) -> bool {
+    self.expires_at <= now_ms()
+  }
+}
+
+/// A store for a lease that several nodes compete for.
+#[async_trait::async_trait]
+pub trait LeaseBackend: std::fmt::Debug + Send + Sync {
+  /// Take the lease for `holder` for `ttl` if it's free or has expired, or renew it if `holder` already holds it.
+  /// Returns whether `holder` holds the lease.
+  async fn try_acquire(&self, holder: &str, ttl: Duration) -> Result<bool, FailoverError>;
+
+  /// Give up the lease if `holder` holds it.
+  async fn release(&self, holder: &str) -> Result<(), FailoverError>;
+
+  /// The lease, if it's held and hasn't expired.
+  async fn current(&self) -> Result<Option<Lease>, FailoverError>;
+}
+
+/// Open the lease backend `url` points to: `file://<path>`, `redis://[:<password>@]<host>[:<port>]/<key>`, or
+/// `etcd://<host>[:<port>]/<key>`.
+pub fn lease_backend(url: &str) -> Result<Arc<dyn LeaseBackend>, FailoverError> {
+  let invalid = || FailoverError::InvalidBackend(url.to_owned());
+  let (scheme, rest) = url.split_once("://").ok_or_else(invalid)?;
+  if rest.is_empty() {
+    return Err(invalid());
+  }
+  Ok(match scheme {
+    "file" => Arc::new(FileLease::new(rest)),
+    "redis" => Arc::new(RedisLease::from_url(rest).ok_or_else(invalid)?),
+    "etcd" => Arc::new(EtcdLease::from_url(rest).ok_or_else(invalid)?),
+    _ => return Err(invalid()),
+  })
+}
+
+/// Runs when a node takes the lease over from a node whose lease expired, before the new node starts its triggers.
+#[async_trait::async_trait]
+pub trait TakeoverHook: Send + Sync {
+  /// Catch up on the work of the node that held `previous`, e.g. by replaying its journal. An error stops the
+  /// takeover and gives the lease back.
+  async fn on_takeover(&self, previous: &Lease) -> Result<(), FailoverError>;
+}
+
+/// Coordinates which of the nodes sharing a lease is active.
+#[derive(Clone)]
+#[must_use]
+pub struct Failover {
+  backend: Arc<dyn LeaseBackend>,
+  node: String,
+  ttl: Duration,
+  hooks: Vec<Arc<dyn TakeoverHook>>,
+}
+
+impl std::fmt::Debug for Failover {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Failover")
+      .field("backend", &self.backend)
+      .field("node", &self.node)
+      .field("ttl", &self.ttl)
+      .field("hooks", &self.hooks.len())
+      .finish()
+  }
+}
+
+impl Failover {
+  /// Compete for the lease in `backend` as `node`, holding it for `ttl` between renewals.
+  pub fn new<T: Into<String>>(backend: Arc<dyn LeaseBackend>, node: T, ttl: Duration) -> Self {
+    Self {
+      backend,
+      node: node.into(),
+      ttl,
+      hooks: Vec::new(),
+    }
+  }
+
+  /// Run `hook` when this node takes the lease over from a failed node.
+  pub fn add_hook(&mut self, hook: Arc<dyn TakeoverHook>) -> &mut Self {
+    self.hooks.push(hook);
+    self
+  }
+
+  /// The id of this node.
+  #[must_use]
+  pub fn node(&self) -> &str {
+    &self.node
+  }
+
+  /// Wait until this node holds the lease and run the takeover hooks if it was taken over from another node. The
+  /// lease is renewed in the background until the returned [ActiveLease] is released or dropped.
+  pub async fn acquire(&self) -> Result<ActiveLease, FailoverError> {
+    let interval = self.ttl / 3;
+    // the last live lease another node was seen holding, which has expired if this node gets the lease.
+    let mut previous = None;
+    loop {
+      let current = self.backend.current().await?;
+      if self.backend.try_acquire(&self.node, self.ttl).await? {
+        break;
+      }
+      if let Some(lease) = current.filter(|l| l.holder != self.node) {
+        if previous.as_ref().map_or(true, |p: &Lease| p.holder != lease.holder) {
+          info!(node = %self.node, holder = %lease.holder, "standing by while another node holds the lease");
+        }
+        previous = Some(lease);
+      }
+      tokio::time::sleep(interval).await;
+    }
+
+    if let Some(previous) = &previous {
+      warn!(node = %self.node, failed = %previous.holder, "took over the lease from a node that stopped renewing it");
+      for hook in &self.hooks {
+        if let Err(e) = hook.on_takeover(previous).await {
+          let _ = self.backend.release(&self.node).await;
+          return Err(e);
+        }
+      }
+    } else {
+      info!(node = %self.node, "acquired the lease");
+    }
+
+    let (tx, lost) = oneshot::channel();
+    let renewal = tokio::spawn(renew(self.backend.clone(), self.node.clone(), self.ttl, tx));
+    Ok(ActiveLease {
+      backend: self.backend.clone(),
+      node: self.node.clone(),
+      lost: Some(lost),
+      renewal,
+    })
+  }
+}
+
+/// Hands out named leases in a shared backend, so work that must only run on one instance at a time can be spread
+/// across instances that are all active.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Coordinator {
+  url: String,
+  node: String,
+  ttl: Duration,
+}
+
+impl Coordinator {
+  /// Coordinate through the lease backend `url` as `node`, holding leases for `ttl` between renewals.
+  pub fn new<U: Into<String>, T: Into<String>>(url: U, node: T, ttl: Duration) -> Result<Self, FailoverError> {
+    let url = url.into();
+    lease_backend(&url)?;
+    Ok(Self {
+      url,
+      node: node.into(),
+      ttl,
+    })
+  }
+
+  /// The id of this node.
+  #[must_use]
+  pub fn node(&self) -> &str {
+    &self.node
+  }
+
+  /// Compete for the lease named `name`. Its key is the backend's key, or path, followed by `-<name>`.
+  pub fn lease(&self, name: &str) -> Result<Failover, FailoverError> {
+    let backend = lease_backend(&format!("{}-{}", self.url, name))?;
+    Ok(Failover::new(backend, self.node.clone(), self.ttl))
+  }
+}
+
+/// Renew the lease every third of its TTL until it's lost to another node or can't be renewed before it expires.
+async fn renew(backend: Arc<dyn LeaseBackend>, node: String, ttl: Duration, lost: oneshot::Sender<()>) {
+  let mut renewed = tokio::time::Instant::now();
+  loop {
+    tokio::time::sleep(ttl / 3).await;
+    match backend.try_acquire(&node, ttl).await {
+      Ok(true) => renewed = tokio::time::Instant::now(),
+      Ok(false) => {
+        error!(%node, "another node took the lease");
+        break;
+      }
+      Err(error) if renewed.elapsed() >= ttl => {
+        error!(%node, %error, "could not renew the lease before it expired");
+        break;
+      }
+      Err(error) => warn!(%node, %error, "could not renew the lease, retrying"),
+    }
+  }
+  let _ = lost.send(());
+}
+
+/// The lease while this node holds it. Dropping it stops renewing the lease, so it expires and another node takes
+/// over as if this one failed.
+#[derive(Debug)]
+#[must_use]
+pub struct ActiveLease {
+  backend: Arc<dyn LeaseBackend>,
+  node: String,
+  lost: Option<oneshot::Receiver<()>>,
+  renewal: JoinHandle<()>,
+}
+
+impl ActiveLease {
+  /// Resolves when this node no longer holds the lease. The node should stop its triggers, another node may already
+  /// be running them.
+  pub async fn lost(&mut self) {
+    if let Some(lost) = self.lost.as_mut() {
+      let _ = lost.await;
+      self.lost = None;
+    }
+  }
+
+  /// Stop renewing the lease and give it up so another node can take over right away, without running its takeover
+  /// hooks.
+  pub async fn release(self) -> Result<(), FailoverError> {
+    self.renewal.abort();
+    self.backend.release(&self.node).await
+  }
+}
+
+impl Drop for ActiveLease {
+  fn drop(&mut self) {
+    self.renewal.abort();
+  }
+}
+
+fn now_ms() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// Split `[<userinfo>@]<host>[:<port>]/<key>` into its userinfo, `<host>:<port>` address, and key.
+pub(crate) fn split_url(rest: &str, default_port: u16) -> Option<(Option<&str>, String, &str)> {
+  let (authority, key) = rest.split_once('/')?;
+  let (userinfo, host) = authority
+    .rsplit_once('@')
+    .map_or((None, authority), |(userinfo, host)| (Some(userinfo), host));
+  let (host, port) = match host.rsplit_once(':') {
+    Some((host, port)) => (host, port.parse().ok()?),
+    None => (host, default_port),
+  };
+  if host.is_empty() || key.is_empty() {
+    return None;
+  }
+  Some((userinfo, format!("{}:{}", host, port), key))
+}
+
+const fn backend_error(backend: &'static str, reason: String) -> FailoverError {
+  FailoverError::Backend { backend, reason }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  use anyhow::Result;
+  use parking_lot::Mutex;
+
+  use super::*;
+
+  #[test]
+  fn test_lease_backend() {
+    for valid in [
+      "file:///var/run/app.lease",
+      "redis://localhost/app",
+      "redis://:secret@redis:6380/wick:app",
+      "etcd://etcd:2379/wick/app",
+    ] {
+      assert!(lease_backend(valid).is_ok(), "'{}' should be valid", valid);
+    }
+    for invalid in [
+      "/var/run/app.lease",
+      "redis://localhost",
+      "etcd://:2379/app",
+      "s3://bucket/key",
+    ] {
+      assert!(lease_backend(invalid).is_err(), "'{}' should be invalid", invalid);
+    }
+  }
+
+  #[test]
+  fn test_coordinator() -> Result<()> {
+    let coordinator = Coordinator::new("redis://localhost/wick:app", "a", DEFAULT_TTL)?;
+    let lease = coordinator.lease("schedule")?;
+    assert_eq!(lease.node(), "a");
+    assert!(format!("{:?}", lease).contains("wick:app-schedule"), "{:?}", lease);
+    assert!(Coordinator::new("s3://bucket/key", "a", DEFAULT_TTL).is_err());
+    Ok(())
+  }
+
+  #[derive(Default)]
+  struct Recorder(Mutex<Vec<String>>);
+
+  #[async_trait::async_trait]
+  impl TakeoverHook for Recorder {
+    async fn on_takeover(&self, previous: &Lease) -> Result<(), FailoverError> {
+      self.0.lock().push(previous.holder.clone());
+      Ok(())
+    }
+  }
+
+  #[derive(Default)]
+  struct Failing(AtomicUsize);
+
+  #[async_trait::async_trait]
+  impl TakeoverHook for Failing {
+    async fn on_takeover(&self, _previous: &Lease) -> Result<(), FailoverError> {
+      self.0.fetch_add(1, Ordering::SeqCst);
+      Err(FailoverError::Hook("journal unavailable".to_owned()))
+    }
+  }
+
+  #[tokio::test]
+  async fn test_takeover() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("wick-lease-{}", uuid::Uuid::new_v4()));
+    let backend: Arc<dyn LeaseBackend> = Arc::new(FileLease::new(&path));
+    let ttl = Duration::from_millis(300);
+
+    let active = Failover::new(backend.clone(), "a", ttl).acquire().await?;
+    let recorder = Arc::new(Recorder::default());
+    let mut passive = Failover::new(backend.clone(), "b", ttl);
+    passive.add_hook(recorder.clone());
+    let standby = tokio::spawn(async move { passive.acquire().await });
+
+    tokio::time::sleep(ttl * 2).await;
+    assert!(!standby.is_finished(), "the lease should be renewed while it's held");
+    // dropping the lease stops renewing it, as if the node had failed.
+    drop(active);
+    let taken = tokio::time::timeout(ttl * 4, standby).await???;
+    assert_eq!(*recorder.0.lock(), vec!["a".to_owned()]);
+
+    let failing = Arc::new(Failing::default());
+    let mut rival = Failover::new(backend.clone(), "c", ttl);
+    rival.add_hook(failing.clone());
+    let rival = tokio::spawn(async move { rival.acquire().await });
+    tokio::time::sleep(ttl).await;
+    drop(taken);
+    assert!(tokio::time::timeout(ttl * 4, rival).await??.is_err());
+    assert_eq!(failing.0.load(Ordering::SeqCst), 1);
+    assert_eq!(
+      backend.current().await?,
+      None,
+      "a failed takeover should give the lease back"
+    );
+
+    let _ = std::fs::remove_file(path);
+    Ok(())
+  }
+
+  /// A lease another node takes after this node's first renewal.
+  #[derive(Debug, Default)]
+  struct Stolen(AtomicUsize);
+
+  #[async_trait::async_trait]
+  impl LeaseBackend for Stolen {
+    async fn try_acquire(&self, _holder: &str, _ttl: Duration) -> Result<bool, FailoverError> {
+      Ok(self.0.fetch_add(1, Ordering::SeqCst) < 2)
+    }
+
+    async fn release(&self, _holder: &str) -> Result<(), FailoverError> {
+      Ok(())
+    }
+
+    async fn current(&self) -> Result<Option<Lease>, FailoverError> {
+      Ok(None)
+    }
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_lost() -> Result<()> {
+    let mut lease = Failover::new(Arc::new(Stolen::default()), "a", Duration::from_secs(3))
+      .acquire()
+      .await?;
+    tokio::time::timeout(Duration::from_secs(5), lease.lost()).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+
+use super::{backend_error, split_url, FailoverError, Lease, LeaseBackend};
+
+const NAME: &str = "etcd";
+
+/// Keeps a lease in an etcd key attached to an etcd lease, through etcd's JSON gateway. Each node needs its own
+/// [EtcdLease], it remembers the etcd lease it holds.
+#[derive(Debug)]
+#[must_use]
+pub struct EtcdLease {
+  endpoint: String,
+  key: String,
+  client: reqwest::Client,
+  /// The id of the etcd lease this node's key is attached to while it holds the lease.
+  lease: Mutex<Option<String>>,
+}
+
+impl EtcdLease {
+  /// Keep the lease in `key` on the etcd server at `endpoint`, e.g. `http://localhost:2379`.
+  pub fn new<E: Into<String>, K: Into<String>>(endpoint: E, key: K) -> Self {
+    Self {
+      endpoint: endpoint.into().trim_end_matches('/').to_owned(),
+      key: key.into(),
+      client: reqwest::Client::new(),
+      lease: Mutex::new(None),
+    }
+  }
+
+  /// Parse `<host>[:<port>]/<key>`, the part of an `etcd://` URL after the scheme.
+  #[must_use]
+  pub fn from_url(rest: &str) -> Option<Self> {
+    let (_, addr, key) = split_url(rest, 2379)?;
+    Some(Self::new(format!("http://{}", addr), key))
+  }
+
+  /// Call the gateway's `/v3/<path>` endpoint.
+  async fn post(&self, path: &str, body: Value) -> Result<Value, FailoverError> {
+    let error = |reason: String| backend_error(NAME, format!("{}: {}", self.endpoint, reason));
+    let response = self
+      .client
+      .post(format!("{}/v3/{}", self.endpoint, path))
+      .body(body.to_string())
+      .send()
+      .await
+      .map_err(|e| error(e.to_string()))?;
+    if !response.status().is_success() {
+      return Err(error(format!("etcd responded with {}", response.status())));
+    }
+    let body = response.bytes().await.map_err(|e| error(e.to_string()))?;
+    serde_json::from_slice(&body).map_err(|_| error("etcd responded with invalid JSON".to_owned()))
+  }
+
+  /// The holder of the key and the etcd lease it's attached to, if the key exists.
+  async fn range(&self) -> Result<Option<(String, String)>, FailoverError> {
+    let response = self
+      .post("kv/range", json!({ "key": STANDARD.encode(&self.key) }))
+      .await?;
+    Ok(response["kvs"].get(0).and_then(holder))
+  }
+
+  /// The seconds left on an etcd lease, which are zero or less once it's expired.
+  async fn time_to_live(&self, lease: &str) -> Result<i64, FailoverError> {
+    let response = self.post("lease/timetolive", json!({ "ID": lease })).await?;
+    Ok(int(&response["TTL"]).unwrap_or(-1))
+  }
+}
+
+#[async_trait::async_trait]
+impl LeaseBackend for EtcdLease {
+  async fn try_acquire(&self, holder: &str, ttl: Duration) -> Result<bool, FailoverError> {
+    let held = self.lease.lock().clone();
+    if let Some(lease) = held {
+      let response = self.post("lease/keepalive", json!({ "ID": lease })).await?;
+      let alive = int(&response["result"]["TTL"]).map_or(false, |ttl| ttl > 0);
+      if alive && self.range().await?.map_or(false, |(h, l)| h == holder && l == lease) {
+        return Ok(true);
+      }
+      *self.lease.lock() = None;
+    }
+
+    // etcd leases last whole seconds.
+    let seconds = ((ttl.as_millis() + 999) / 1000).max(1);
+    let granted = self.post("lease/grant", json!({ "TTL": seconds.to_string() })).await?;
+    let lease = match &granted["ID"] {
+      Value::String(id) => id.clone(),
+      Value::Number(id) => id.to_string(),
+      _ => return Err(backend_error(NAME, "etcd did not grant a lease".to_owned())),
+    };
+    let key = STANDARD.encode(&self.key);
+    // the key is only created if it doesn't exist, it's deleted when the lease attached to it expires.
+    let response = self
+      .post(
+        "kv/txn",
+        json!({
+          "compare": [{ "key": key, "target": "CREATE", "result": "EQUAL", "create_revision": "0" }],
+          "success": [{ "request_put": { "key": key, "value": STANDARD.encode(holder), "lease": lease } }],
+        }),
+      )
+      .await?;
+    if response["succeeded"].as_bool().unwrap_or(false) {
+      *self.lease.lock() = Some(lease);
+      Ok(true)
+    } else {
+      self.post("lease/revoke", json!({ "ID": lease })).await?;
+      Ok(false)
+    }
+  }
+
+  async fn release(&self, holder: &str) -> Result<(), FailoverError> {
+    let held = self.lease.lock().take();
+    match held {
+      // revoking the etcd lease deletes the key attached to it.
+      Some(lease) if self.range().await?.map_or(false, |(h, _)| h == holder) => {
+        self.post("lease/revoke", json!({ "ID": lease })).await.map(|_| ())
+      }
+      _ => Ok(()),
+    }
+  }
+
+  async fn current(&self) -> Result<Option<Lease>, FailoverError> {
+    let Some((holder, lease)) = self.range().await? else {
+      return Ok(None);
+    };
+    let ttl = self.time_to_live(&lease).await?;
+    Ok((ttl > 0).then(|| Lease::new(holder, Duration::from_secs(ttl as u64))))
+  }
+}
+
+/// The holder in a key-value pair from a range response and the etcd lease the pair is attached to.
+fn holder(kv: &Value) -> Option<(String, String)> {
+  let value = STANDARD.decode(kv["value"].as_str()?).ok()?;
+  let lease = int(&kv["lease"])?;
+  Some((String::from_utf8(value).ok()?, lease.to_string()))
+}
+
+/// An integer the gateway may send as a string, as it does for every 64-bit integer.
+fn int(value: &Value) -> Option<i64> {
+  match value {
+    Value::String(s) => s.parse().ok(),
+    other => other.as_i64(),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_holder() {
+    let kv = json!({ "key": "d2ljay9hcHA=", "value": "bm9kZS1h", "lease": "7587873021513760004" });
+    assert_eq!(
+      holder(&kv),
+      Some(("node-a".to_owned(), "7587873021513760004".to_owned()))
+    );
+    assert_eq!(
+      holder(&json!({ "value": "bm9kZS1h" })),
+      None,
+      "keys without a lease aren't leases"
+    );
+    assert_eq!(int(&json!(15)), Some(15));
+    assert_eq!(int(&json!("-1")), Some(-1));
+  }
+}
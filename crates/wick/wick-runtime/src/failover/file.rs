@@ -0,0 +1,191 @@
+use std::fs::{File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::{backend_error, FailoverError, Lease, LeaseBackend};
+
+const NAME: &str = "file";
+
+/// How often to try again to take a lock another node holds.
+const LOCK_POLL: Duration = Duration::from_millis(20);
+
+/// Keeps a lease as JSON in a file every node can reach, like one on a shared volume. Changes are serialized with an
+/// OS lock on a file next to it, which the OS releases when its holder closes it or dies, so a node that stalls while
+/// holding it keeps it rather than having it taken away.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct FileLease {
+  path: PathBuf,
+}
+
+impl FileLease {
+  /// Keep the lease in the file at `path`.
+  pub fn new<T: Into<PathBuf>>(path: T) -> Self {
+    Self { path: path.into() }
+  }
+
+  /// Hold the lock until the returned guard is dropped.
+  async fn lock(&self) -> Result<LockGuard, FailoverError> {
+    let mut path = self.path.clone().into_os_string();
+    path.push(".lock");
+    let path = PathBuf::from(path);
+    loop {
+      let attempt = {
+        let path = path.clone();
+        tokio::task::spawn_blocking(move || try_lock(&path))
+          .await
+          .map_err(|e| backend_error(NAME, e.to_string()))?
+      };
+      match attempt {
+        Ok(Some(file)) => return Ok(LockGuard(file)),
+        Ok(None) => tokio::time::sleep(LOCK_POLL).await,
+        Err(e) => return Err(backend_error(NAME, format!("{}: {}", path.display(), e))),
+      }
+    }
+  }
+
+  /// The lease in the file, expired or not.
+  async fn read(&self) -> Result<Option<Lease>, FailoverError> {
+    match tokio::fs::read(&self.path).await {
+      Ok(bytes) => serde_json::from_slice(&bytes)
+        .map(Some)
+        .map_err(|e| backend_error(NAME, format!("{}: {}", self.path.display(), e))),
+      Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+      Err(e) => Err(backend_error(NAME, format!("{}: {}", self.path.display(), e))),
+    }
+  }
+
+  /// Replace the lease in the file, writing it next to the file first so readers never see a partial lease.
+  async fn write(&self, lease: &Lease) -> Result<(), FailoverError> {
+    let error = |e: std::io::Error| backend_error(NAME, format!("{}: {}", self.path.display(), e));
+    let mut tmp = self.path.clone().into_os_string();
+    tmp.push(".tmp");
+    let json = serde_json::to_vec(lease).map_err(|e| backend_error(NAME, e.to_string()))?;
+    tokio::fs::write(&tmp, json).await.map_err(error)?;
+    tokio::fs::rename(&tmp, &self.path).await.map_err(error)
+  }
+}
+
+#[async_trait::async_trait]
+impl LeaseBackend for FileLease {
+  async fn try_acquire(&self, holder: &str, ttl: Duration) -> Result<bool, FailoverError> {
+    let _lock = self.lock().await?;
+    match self.read().await? {
+      Some(lease) if !lease.expired() && lease.holder != holder => Ok(false),
+      _ => {
+        self.write(&Lease::new(holder, ttl)).await?;
+        Ok(true)
+      }
+    }
+  }
+
+  async fn release(&self, holder: &str) -> Result<(), FailoverError> {
+    let _lock = self.lock().await?;
+    match self.read().await? {
+      Some(lease) if lease.holder == holder => match tokio::fs::remove_file(&self.path).await {
+        Err(e) if e.kind() != ErrorKind::NotFound => {
+          Err(backend_error(NAME, format!("{}: {}", self.path.display(), e)))
+        }
+        _ => Ok(()),
+      },
+      _ => Ok(()),
+    }
+  }
+
+  async fn current(&self) -> Result<Option<Lease>, FailoverError> {
+    Ok(self.read().await?.filter(|lease| !lease.expired()))
+  }
+}
+
+/// Open the lock file at `path` and take an exclusive lock on it, or return `None` if another handle holds one.
+#[cfg(unix)]
+fn try_lock(path: &Path) -> std::io::Result<Option<File>> {
+  use rustix::fs::{flock, FlockOperation};
+  use rustix::io::Errno;
+
+  let file = OpenOptions::new().write(true).create(true).open(path)?;
+  match flock(&file, FlockOperation::NonBlockingLockExclusive) {
+    Ok(()) => Ok(Some(file)),
+    Err(Errno::WOULDBLOCK) => Ok(None),
+    Err(e) => Err(e.into()),
+  }
+}
+
+/// Open the lock file at `path` without sharing it, which fails while another handle has it open, or return `None` if
+/// another handle does.
+#[cfg(windows)]
+fn try_lock(path: &Path) -> std::io::Result<Option<File>> {
+  use std::os::windows::fs::OpenOptionsExt;
+
+  /// The error opening a file another handle has open without sharing it.
+  const ERROR_SHARING_VIOLATION: i32 = 32;
+
+  match OpenOptions::new().write(true).create(true).share_mode(0).open(path) {
+    Ok(file) => Ok(Some(file)),
+    Err(e) if e.raw_os_error() == Some(ERROR_SHARING_VIOLATION) => Ok(None),
+    Err(e) => Err(e),
+  }
+}
+
+/// Releases the lock when dropped by closing the lock file. The file itself stays, removing it would let another node
+/// lock a new file while this one is still locked.
+struct LockGuard(#[allow(unused)] File);
+
+#[cfg(test)]
+mod test {
+  use std::sync::Arc;
+
+  use anyhow::Result;
+
+  use super::*;
+
+  fn lease_path() -> PathBuf {
+    std::env::temp_dir().join(format!("wick-lease-{}", uuid::Uuid::new_v4()))
+  }
+
+  #[tokio::test]
+  async fn test_lock_is_held_until_dropped() -> Result<()> {
+    let path = lease_path();
+    let lease = FileLease::new(&path);
+    let other = FileLease::new(&path);
+
+    let guard = lease.lock().await?;
+    let waiting = tokio::spawn(async move { other.lock().await.map(|_| ()) });
+    tokio::time::sleep(LOCK_POLL * 10).await;
+    assert!(!waiting.is_finished(), "a held lock should not be taken");
+    drop(guard);
+    tokio::time::timeout(LOCK_POLL * 10, waiting).await???;
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn test_competing_leases() -> Result<()> {
+    let path = lease_path();
+    let ttl = Duration::from_secs(30);
+    for _ in 0..20 {
+      // each node has its own handle on the file, as nodes sharing a volume would.
+      let nodes: Vec<_> = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|holder| (holder, Arc::new(FileLease::new(&path))))
+        .collect();
+      let attempts = nodes.iter().map(|(holder, lease)| {
+        let (holder, lease) = (*holder, lease.clone());
+        tokio::spawn(async move { lease.try_acquire(holder, ttl).await })
+      });
+      let mut winners = Vec::new();
+      for (attempt, (holder, _)) in futures::future::join_all(attempts).await.into_iter().zip(&nodes) {
+        if attempt?? {
+          winners.push(*holder);
+        }
+      }
+      assert_eq!(winners.len(), 1, "exactly one node should win the lease, got {:?}", winners);
+      let current = nodes[0].1.current().await?.unwrap();
+      assert_eq!(current.holder, winners[0]);
+      nodes[0].1.release(winners[0]).await?;
+    }
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(path.with_extension("lock"));
+    Ok(())
+  }
+}
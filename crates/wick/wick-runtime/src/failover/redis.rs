@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use wick_config::config::Secret;
+
+use super::{backend_error, split_url, FailoverError, Lease, LeaseBackend};
+use crate::resp::{self, Reply};
+
+const NAME: &str = "redis";
+
+/// Sets the key to the holder unless another holder has it, expiring it after ARGV[2] milliseconds.
+const ACQUIRE: &str = "local holder = redis.call('get', KEYS[1]) \
+  if holder == false or holder == ARGV[1] then redis.call('set', KEYS[1], ARGV[1], 'PX', ARGV[2]) return 1 end \
+  return 0";
+
+/// Deletes the key if the holder has it.
+const RELEASE: &str = "if redis.call('get', KEYS[1]) == ARGV[1] then return redis.call('del', KEYS[1]) end return 0";
+
+/// Returns the holder and the milliseconds until the key expires.
+const CURRENT: &str = "return {redis.call('get', KEYS[1]), redis.call('pttl', KEYS[1])}";
+
+/// Keeps a lease in a Redis key that expires with it.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct RedisLease {
+  addr: String,
+  password: Option<Secret>,
+  key: String,
+}
+
+impl RedisLease {
+  /// Keep the lease in `key` on the Redis server at `addr`, e.g. `localhost:6379`.
+  pub fn new<A: Into<String>, K: Into<String>>(addr: A, key: K, password: Option<Secret>) -> Self {
+    Self {
+      addr: addr.into(),
+      password,
+      key: key.into(),
+    }
+  }
+
+  /// Parse `[:<password>@]<host>[:<port>]/<key>`, the part of a `redis://` URL after the scheme.
+  #[must_use]
+  pub fn from_url(rest: &str) -> Option<Self> {
+    let (userinfo, addr, key) = split_url(rest, 6379)?;
+    let password = userinfo
+      .and_then(|userinfo| userinfo.split_once(':'))
+      .map(|(_, password)| Secret::new(password));
+    Some(Self::new(addr, key, password))
+  }
+
+  /// Run `script` with the lease's key and `args` on a new connection.
+  async fn eval(&self, script: &str, args: &[&str]) -> Result<Reply, FailoverError> {
+    let error = |e: std::io::Error| backend_error(NAME, format!("{}: {}", self.addr, e));
+    let mut stream = resp::connect(&self.addr, self.password.as_ref()).await.map_err(error)?;
+    let mut command = vec!["EVAL", script, "1", self.key.as_str()];
+    command.extend_from_slice(args);
+    resp::command(&mut stream, &command).await.map_err(error)
+  }
+}
+
+#[async_trait::async_trait]
+impl LeaseBackend for RedisLease {
+  async fn try_acquire(&self, holder: &str, ttl: Duration) -> Result<bool, FailoverError> {
+    let ttl = ttl.as_millis().to_string();
+    Ok(self.eval(ACQUIRE, &[holder, &ttl]).await? == Reply::Integer(1))
+  }
+
+  async fn release(&self, holder: &str) -> Result<(), FailoverError> {
+    self.eval(RELEASE, &[holder]).await.map(|_| ())
+  }
+
+  async fn current(&self) -> Result<Option<Lease>, FailoverError> {
+    match self.eval(CURRENT, &[]).await? {
+      Reply::Array(items) => match items.as_slice() {
+        [Reply::Bulk(Some(holder)), Reply::Integer(ttl)] if *ttl > 0 => Ok(Some(Lease::new(
+          String::from_utf8_lossy(holder),
+          Duration::from_millis(*ttl as u64),
+        ))),
+        _ => Ok(None),
+      },
+      other => Err(backend_error(NAME, format!("unexpected reply {:?}", other))),
+    }
+  }
+}
@@ -118,17 +118,29 @@ extern crate tracing;
 #[cfg(test)]
 pub(crate) mod test;
 
+pub mod blobs;
+pub mod checkpoints;
 mod components;
 pub(crate) mod dev;
 mod dispatch;
 pub mod error;
+pub mod failover;
+pub mod metrics;
+pub mod policy;
+pub mod receipts;
+mod resp;
 mod runtime;
+pub mod scan;
+pub mod secrets;
+pub mod soak;
+pub mod state;
 pub(crate) mod utils;
 
 pub use components::error::ComponentError;
 pub use components::scope_component::ScopeComponent;
+pub use flow_graph_interpreter::ObservabilityLevel;
 pub use runtime::scope::error::ScopeError;
-pub use runtime::{Runtime, RuntimeBuilder, RuntimeConstraint};
+pub use runtime::{Runtime, RuntimeBuilder, RuntimeConstraint, SharedImport};
 
 pub type Error = error::RuntimeError;
 
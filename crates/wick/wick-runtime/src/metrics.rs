@@ -0,0 +1,648 @@
+//! Metrics aggregated from every scope of a [crate::Runtime], rendered in the Prometheus text format.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use flow_graph_interpreter::{Event, Observer, State, TransactionSnapshot, TransactionStatistics};
+use parking_lot::{Mutex, RwLock};
+use wick_component_wasmrs::limits::Limit;
+use wick_component_wasmrs::pool::PoolStats;
+use wick_http_client::{HostPoolStats, PoolMetrics};
+
+mod alerts;
+pub use alerts::{Alert, AlertEvaluator, EVALUATION_INTERVAL};
+
+/// Upper bounds, in seconds, of the operation latency histogram buckets.
+pub const LATENCY_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// The least time between two [TransactionSnapshot]s of the same scope.
+const SNAPSHOT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The cost each operation a scope can invoke declares for one invocation, keyed by namespace and operation name.
+pub(crate) type CostModel = HashMap<(String, String), u64>;
+
+#[derive(Debug, Default, Clone)]
+struct OperationMetrics {
+  buckets: [u64; LATENCY_BUCKETS.len()],
+  sum: f64,
+  count: u64,
+  packets: u64,
+  errors: u64,
+  /// Transactions with at least one error.
+  failed: u64,
+  /// The estimated costs of the transactions, added up.
+  cost: u64,
+}
+
+impl OperationMetrics {
+  fn record(&mut self, stats: &TransactionStatistics, cost: u64) {
+    let secs = stats.duration.as_secs_f64();
+    for (bucket, le) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+      if secs <= le {
+        *bucket += 1;
+      }
+    }
+    self.sum += secs;
+    self.count += 1;
+    self.packets += stats.packets;
+    self.errors += stats.errors;
+    if stats.errors > 0 {
+      self.failed += 1;
+    }
+    self.cost += cost;
+  }
+
+  /// The totals recorded since `earlier` was taken, or all of them if there's nothing earlier.
+  fn since(&self, earlier: Option<&Self>) -> Self {
+    let Some(earlier) = earlier else {
+      return self.clone();
+    };
+    let mut buckets = self.buckets;
+    for (bucket, before) in buckets.iter_mut().zip(earlier.buckets) {
+      *bucket = bucket.saturating_sub(before);
+    }
+    Self {
+      buckets,
+      sum: (self.sum - earlier.sum).max(0.0),
+      count: self.count.saturating_sub(earlier.count),
+      packets: self.packets.saturating_sub(earlier.packets),
+      errors: self.errors.saturating_sub(earlier.errors),
+      failed: self.failed.saturating_sub(earlier.failed),
+      cost: self.cost.saturating_sub(earlier.cost),
+    }
+  }
+}
+
+/// The transactions started by one origin, like a trigger.
+#[derive(Debug, Default, Clone)]
+struct OriginMetrics {
+  transactions: u64,
+  /// The estimated costs of the transactions, added up.
+  cost: u64,
+  /// The measured durations of the transactions, added up.
+  seconds: f64,
+}
+
+#[derive(Debug, Default)]
+struct MetricsInner {
+  /// Keyed by scope namespace and operation name.
+  operations: BTreeMap<(String, String), OperationMetrics>,
+  /// Keyed by the id of the entity that started the transactions.
+  origins: BTreeMap<String, OriginMetrics>,
+  /// Keyed by scope namespace.
+  active: BTreeMap<String, usize>,
+  /// Whether scopes should take [TransactionSnapshot]s.
+  snapshots: bool,
+  /// Keyed by scope namespace.
+  transactions: BTreeMap<String, Vec<TransactionSnapshot>>,
+  /// Times a WebAssembly component exceeded one of its limits, keyed by namespace and limit.
+  limits: BTreeMap<(String, String), u64>,
+  /// Keyed by namespace.
+  pools: BTreeMap<String, Arc<PoolStats>>,
+  /// The connection pool usage of HTTP client components, keyed by namespace.
+  http_pools: BTreeMap<String, PoolMetrics>,
+}
+
+/// Per-operation latency, packet, error, and cost totals plus the number of active transactions for each scope.
+///
+/// A transaction's estimated cost adds up the costs its operations declare for each time the transaction started
+/// them, its actual cost is the time it took. Both are also totaled by the origin that started the transaction, so
+/// the work each trigger causes can be charged back to it.
+///
+/// Clones share the same totals.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct RuntimeMetrics {
+  inner: Arc<RwLock<MetricsInner>>,
+}
+
+impl RuntimeMetrics {
+  /// Add a finished transaction from the scope `namespace` with an estimated `cost` to the totals.
+  pub fn record(&self, namespace: &str, stats: &TransactionStatistics, cost: u64) {
+    let mut inner = self.inner.write();
+    inner
+      .operations
+      .entry((namespace.to_owned(), stats.operation.clone()))
+      .or_default()
+      .record(stats, cost);
+    let origin = inner.origins.entry(stats.origin.clone()).or_default();
+    origin.transactions += 1;
+    origin.cost += cost;
+    origin.seconds += stats.duration.as_secs_f64();
+  }
+
+  /// Set the number of transactions in progress in the scope `namespace`.
+  pub fn set_active(&self, namespace: &str, active: usize) {
+    let mut inner = self.inner.write();
+    if let Some(current) = inner.active.get_mut(namespace) {
+      *current = active;
+      return;
+    }
+    inner.active.insert(namespace.to_owned(), active);
+  }
+
+  /// Count a time the WebAssembly component `namespace` exceeded its `limit`.
+  pub fn record_limit(&self, namespace: &str, limit: Limit) {
+    *self
+      .inner
+      .write()
+      .limits
+      .entry((namespace.to_owned(), limit.to_string()))
+      .or_default() += 1;
+  }
+
+  /// The stats of the instance pool of the WebAssembly component `namespace`, to hand to the pool.
+  #[must_use]
+  pub fn pool(&self, namespace: &str) -> Arc<PoolStats> {
+    self
+      .inner
+      .write()
+      .pools
+      .entry(namespace.to_owned())
+      .or_default()
+      .clone()
+  }
+
+  /// The connection pool usage of the HTTP client component `namespace`, to hand to the component.
+  #[must_use]
+  pub fn http_pool(&self, namespace: &str) -> PoolMetrics {
+    self
+      .inner
+      .write()
+      .http_pools
+      .entry(namespace.to_owned())
+      .or_default()
+      .clone()
+  }
+
+  /// Start taking [TransactionSnapshot]s of every scope's transactions in progress.
+  ///
+  /// Snapshots walk every operation of every transaction, so they are off until something like a soak test asks for
+  /// them.
+  pub fn track_transactions(&self) {
+    self.inner.write().snapshots = true;
+  }
+
+  /// Replace the latest snapshot of the transactions in progress in the scope `namespace`.
+  pub fn set_transactions(&self, namespace: &str, transactions: Vec<TransactionSnapshot>) {
+    self
+      .inner
+      .write()
+      .transactions
+      .insert(namespace.to_owned(), transactions);
+  }
+
+  /// The latest snapshots of the transactions in progress, by scope namespace.
+  ///
+  /// Empty unless [RuntimeMetrics::track_transactions] was called.
+  #[must_use]
+  pub fn transactions(&self) -> BTreeMap<String, Vec<TransactionSnapshot>> {
+    self.inner.read().transactions.clone()
+  }
+
+  /// A copy of every operation's totals, keyed by scope namespace and operation name.
+  fn totals(&self) -> BTreeMap<(String, String), OperationMetrics> {
+    self.inner.read().operations.clone()
+  }
+
+  /// Render every metric in the Prometheus text exposition format.
+  #[must_use]
+  pub fn render(&self) -> String {
+    let inner = self.inner.read();
+    let mut out = String::new();
+
+    inner.render_operations(&mut out);
+    inner.render_transactions(&mut out);
+    inner.render_wasm(&mut out);
+    inner.render_http(&mut out);
+    out
+  }
+
+  /// An interpreter [Observer] that reports the transactions of the scope `namespace`, estimating their cost with
+  /// the `costs` of the operations the scope can invoke.
+  pub(crate) fn observer(&self, namespace: String, costs: CostModel) -> Box<dyn Observer + Send + Sync> {
+    Box::new(MetricsObserver {
+      namespace,
+      metrics: self.clone(),
+      costs,
+      last_snapshot: Mutex::new(None),
+    })
+  }
+}
+
+fn escape(value: &str) -> String {
+  value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// A metric read from the stats of an instance pool: its name, type, help text, and how to read its value.
+type PoolMetric = (&'static str, &'static str, &'static str, fn(&PoolStats) -> u64);
+
+/// A metric read from the usage of a host by an HTTP client component: its name, type, help text, and how to read its
+/// value.
+type HttpPoolMetric = (&'static str, &'static str, &'static str, fn(&HostPoolStats) -> u64);
+
+impl MetricsInner {
+  /// Render the metrics of every operation.
+  fn render_operations(&self, out: &mut String) {
+    out.push_str(
+      "# HELP wick_operation_duration_seconds Time from the start of a transaction until its output finished.\n",
+    );
+    out.push_str("# TYPE wick_operation_duration_seconds histogram\n");
+    for ((ns, op), m) in &self.operations {
+      let labels = format!("namespace=\"{}\",operation=\"{}\"", escape(ns), escape(op));
+      for (count, le) in m.buckets.iter().zip(LATENCY_BUCKETS) {
+        let _ = writeln!(
+          out,
+          "wick_operation_duration_seconds_bucket{{{},le=\"{}\"}} {}",
+          labels, le, count
+        );
+      }
+      let _ = writeln!(
+        out,
+        "wick_operation_duration_seconds_bucket{{{},le=\"+Inf\"}} {}",
+        labels, m.count
+      );
+      let _ = writeln!(out, "wick_operation_duration_seconds_sum{{{}}} {}", labels, m.sum);
+      let _ = writeln!(out, "wick_operation_duration_seconds_count{{{}}} {}", labels, m.count);
+    }
+
+    out.push_str("# HELP wick_operation_packets_total Packets emitted within transactions of an operation.\n");
+    out.push_str("# TYPE wick_operation_packets_total counter\n");
+    for ((ns, op), m) in &self.operations {
+      let _ = writeln!(
+        out,
+        "wick_operation_packets_total{{namespace=\"{}\",operation=\"{}\"}} {}",
+        escape(ns),
+        escape(op),
+        m.packets
+      );
+    }
+
+    out.push_str("# HELP wick_operation_errors_total Errors within transactions of an operation.\n");
+    out.push_str("# TYPE wick_operation_errors_total counter\n");
+    for ((ns, op), m) in &self.operations {
+      let _ = writeln!(
+        out,
+        "wick_operation_errors_total{{namespace=\"{}\",operation=\"{}\"}} {}",
+        escape(ns),
+        escape(op),
+        m.errors
+      );
+    }
+
+    out.push_str(
+      "# HELP wick_operation_cost_total Estimated cost of the transactions of an operation, from the costs its operations declare.\n",
+    );
+    out.push_str("# TYPE wick_operation_cost_total counter\n");
+    for ((ns, op), m) in &self.operations {
+      let _ = writeln!(
+        out,
+        "wick_operation_cost_total{{namespace=\"{}\",operation=\"{}\"}} {}",
+        escape(ns),
+        escape(op),
+        m.cost
+      );
+    }
+  }
+
+  /// Render the metrics of the origins of transactions and the transactions in progress.
+  fn render_transactions(&self, out: &mut String) {
+    out.push_str("# HELP wick_origin_transactions_total Transactions started by an origin, like a trigger.\n");
+    out.push_str("# TYPE wick_origin_transactions_total counter\n");
+    for (origin, m) in &self.origins {
+      let _ = writeln!(
+        out,
+        "wick_origin_transactions_total{{origin=\"{}\"}} {}",
+        escape(origin),
+        m.transactions
+      );
+    }
+
+    out.push_str("# HELP wick_origin_cost_total Estimated cost of the transactions started by an origin.\n");
+    out.push_str("# TYPE wick_origin_cost_total counter\n");
+    for (origin, m) in &self.origins {
+      let _ = writeln!(
+        out,
+        "wick_origin_cost_total{{origin=\"{}\"}} {}",
+        escape(origin),
+        m.cost
+      );
+    }
+
+    out.push_str("# HELP wick_origin_duration_seconds_total Time taken by the transactions started by an origin.\n");
+    out.push_str("# TYPE wick_origin_duration_seconds_total counter\n");
+    for (origin, m) in &self.origins {
+      let _ = writeln!(
+        out,
+        "wick_origin_duration_seconds_total{{origin=\"{}\"}} {}",
+        escape(origin),
+        m.seconds
+      );
+    }
+
+    out.push_str("# HELP wick_active_transactions Transactions in progress.\n");
+    out.push_str("# TYPE wick_active_transactions gauge\n");
+    for (ns, active) in &self.active {
+      let _ = writeln!(
+        out,
+        "wick_active_transactions{{namespace=\"{}\"}} {}",
+        escape(ns),
+        active
+      );
+    }
+  }
+
+  /// Render the limit and instance pool metrics of WebAssembly components.
+  fn render_wasm(&self, out: &mut String) {
+    out.push_str("# HELP wick_wasm_limit_violations_total Times a WebAssembly component exceeded one of its limits.\n");
+    out.push_str("# TYPE wick_wasm_limit_violations_total counter\n");
+    for ((ns, limit), count) in &self.limits {
+      let _ = writeln!(
+        out,
+        "wick_wasm_limit_violations_total{{namespace=\"{}\",limit=\"{}\"}} {}",
+        escape(ns),
+        escape(limit),
+        count
+      );
+    }
+
+    let pool_metrics: [PoolMetric; 6] = [
+      (
+        "wick_wasm_pool_instances",
+        "gauge",
+        "Instances a WebAssembly component's pool has created.",
+        |s| s.instances().into(),
+      ),
+      (
+        "wick_wasm_pool_idle_instances",
+        "gauge",
+        "Instances ready in a WebAssembly component's pool.",
+        |s| s.idle().into(),
+      ),
+      (
+        "wick_wasm_pool_checkouts_total",
+        "counter",
+        "Invocations that checked out an instance of a WebAssembly component.",
+        PoolStats::checkouts,
+      ),
+      (
+        "wick_wasm_pool_cold_starts_total",
+        "counter",
+        "Checkouts that had to create an instance because none were ready.",
+        PoolStats::cold_starts,
+      ),
+      (
+        "wick_wasm_pool_waits_total",
+        "counter",
+        "Checkouts that waited for an instance because the pool was full.",
+        PoolStats::waits,
+      ),
+      (
+        "wick_wasm_pool_retired_total",
+        "counter",
+        "Instances taken out of a pool because an invocation running on them timed out.",
+        PoolStats::retired,
+      ),
+    ];
+    for (name, kind, help, value) in pool_metrics {
+      let _ = writeln!(out, "# HELP {} {}", name, help);
+      let _ = writeln!(out, "# TYPE {} {}", name, kind);
+      for (ns, stats) in &self.pools {
+        let _ = writeln!(out, "{}{{namespace=\"{}\"}} {}", name, escape(ns), value(stats));
+      }
+    }
+  }
+
+  /// Render the connection pool metrics of HTTP client components, per host they sent requests to.
+  fn render_http(&self, out: &mut String) {
+    let hosts: Vec<_> = self
+      .http_pools
+      .iter()
+      .flat_map(|(ns, metrics)| {
+        let hosts: BTreeMap<_, _> = metrics.snapshot().into_iter().collect();
+        hosts.into_iter().map(move |(host, stats)| (ns, host, stats))
+      })
+      .collect();
+    let http_metrics: [HttpPoolMetric; 4] = [
+      (
+        "wick_http_client_requests_total",
+        "counter",
+        "Requests an HTTP client component sent to a host.",
+        |s| s.requests,
+      ),
+      (
+        "wick_http_client_in_flight",
+        "gauge",
+        "Requests to a host whose connection is in use.",
+        |s| s.in_flight,
+      ),
+      (
+        "wick_http_client_peak_in_flight",
+        "gauge",
+        "The most requests to a host that were in flight at the same time.",
+        |s| s.peak_in_flight,
+      ),
+      (
+        "wick_http_client_errors_total",
+        "counter",
+        "Requests to a host that failed before a response was received.",
+        |s| s.errors,
+      ),
+    ];
+    for (name, kind, help, value) in http_metrics {
+      let _ = writeln!(out, "# HELP {} {}", name, help);
+      let _ = writeln!(out, "# TYPE {} {}", name, kind);
+      for (ns, host, stats) in &hosts {
+        let _ = writeln!(
+          out,
+          "{}{{namespace=\"{}\",host=\"{}\"}} {}",
+          name,
+          escape(ns),
+          escape(host),
+          value(stats)
+        );
+      }
+    }
+  }
+}
+
+struct MetricsObserver {
+  namespace: String,
+  metrics: RuntimeMetrics,
+  costs: CostModel,
+  last_snapshot: Mutex<Option<Instant>>,
+}
+
+impl MetricsObserver {
+  /// The estimated cost of a transaction, operations that don't declare a cost are free.
+  fn cost(&self, stats: &TransactionStatistics) -> u64 {
+    stats
+      .operations
+      .iter()
+      .map(|(op, count)| self.costs.get(op).map_or(0, |cost| cost.saturating_mul(*count)))
+      .fold(0, u64::saturating_add)
+  }
+
+  fn snapshot(&self, state: &State) {
+    if !self.metrics.inner.read().snapshots {
+      return;
+    }
+    let mut last = self.last_snapshot.lock();
+    if last.map_or(false, |last| last.elapsed() < SNAPSHOT_INTERVAL) {
+      return;
+    }
+    *last = Some(Instant::now());
+    self
+      .metrics
+      .set_transactions(&self.namespace, state.invocations().snapshot());
+  }
+}
+
+impl Observer for MetricsObserver {
+  fn on_event(&self, _index: usize, _event: &Event) {}
+
+  fn on_after_event(&self, _index: usize, state: &State) {
+    self.metrics.set_active(&self.namespace, state.invocations().len());
+    self.snapshot(state);
+  }
+
+  fn on_idle(&self, state: &State) {
+    self.snapshot(state);
+  }
+
+  fn on_close(&self) {
+    self.metrics.set_active(&self.namespace, 0);
+    self.metrics.inner.write().transactions.remove(&self.namespace);
+  }
+
+  fn on_transaction_done(&self, stats: &TransactionStatistics) {
+    self.metrics.record(&self.namespace, stats, self.cost(stats));
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::time::Duration;
+
+  use anyhow::Result;
+
+  use super::*;
+
+  fn stats(operation: &str, millis: u64, errors: u64) -> TransactionStatistics {
+    let mut stats = TransactionStatistics::new(uuid::Uuid::new_v4(), operation);
+    stats.duration = Duration::from_millis(millis);
+    stats.packets = 3;
+    stats.errors = errors;
+    stats
+  }
+
+  #[test]
+  fn test_render() -> Result<()> {
+    let metrics = RuntimeMetrics::default();
+    metrics.record("app", &stats("greet", 20, 0), 0);
+    metrics.record("app", &stats("greet", 700, 1), 0);
+    metrics.set_active("app", 2);
+
+    let text = metrics.render();
+    let lines: Vec<_> = text.lines().collect();
+    assert!(lines.contains(&r#"wick_operation_duration_seconds_bucket{namespace="app",operation="greet",le="0.01"} 0"#));
+    assert!(
+      lines.contains(&r#"wick_operation_duration_seconds_bucket{namespace="app",operation="greet",le="0.025"} 1"#)
+    );
+    assert!(lines.contains(&r#"wick_operation_duration_seconds_bucket{namespace="app",operation="greet",le="1"} 2"#));
+    assert!(lines.contains(&r#"wick_operation_duration_seconds_bucket{namespace="app",operation="greet",le="+Inf"} 2"#));
+    assert!(lines.contains(&r#"wick_operation_duration_seconds_count{namespace="app",operation="greet"} 2"#));
+    assert!(lines.contains(&r#"wick_operation_packets_total{namespace="app",operation="greet"} 6"#));
+    assert!(lines.contains(&r#"wick_operation_errors_total{namespace="app",operation="greet"} 1"#));
+    assert!(lines.contains(&r#"wick_active_transactions{namespace="app"} 2"#));
+    Ok(())
+  }
+
+  #[test]
+  fn test_limits() {
+    let metrics = RuntimeMetrics::default();
+    metrics.record_limit("wasm", Limit::Timeout);
+    metrics.record_limit("wasm", Limit::Timeout);
+    metrics.record_limit("wasm", Limit::Memory);
+
+    let text = metrics.render();
+    let lines: Vec<_> = text.lines().collect();
+    assert!(lines.contains(&r#"wick_wasm_limit_violations_total{namespace="wasm",limit="timeout"} 2"#));
+    assert!(lines.contains(&r#"wick_wasm_limit_violations_total{namespace="wasm",limit="memory"} 1"#));
+  }
+
+  #[test]
+  fn test_pools() {
+    let metrics = RuntimeMetrics::default();
+    let stats = metrics.pool("wasm");
+    assert!(
+      Arc::ptr_eq(&stats, &metrics.pool("wasm")),
+      "a namespace should keep one pool"
+    );
+
+    let text = metrics.render();
+    let lines: Vec<_> = text.lines().collect();
+    assert!(lines.contains(&"# TYPE wick_wasm_pool_instances gauge"));
+    assert!(lines.contains(&r#"wick_wasm_pool_instances{namespace="wasm"} 0"#));
+    assert!(lines.contains(&r#"wick_wasm_pool_checkouts_total{namespace="wasm"} 0"#));
+  }
+
+  #[test]
+  fn test_http_pools() {
+    let metrics = RuntimeMetrics::default();
+    let pool = metrics.http_pool("api");
+    assert!(pool.snapshot().is_empty(), "nothing has been sent yet");
+
+    let text = metrics.render();
+    let lines: Vec<_> = text.lines().collect();
+    assert!(lines.contains(&"# TYPE wick_http_client_requests_total counter"));
+    assert!(lines.contains(&"# TYPE wick_http_client_in_flight gauge"));
+    assert!(
+      !lines.iter().any(|line| line.starts_with("wick_http_client_requests_total{")),
+      "hosts are only listed once a request was sent to them"
+    );
+  }
+
+  #[test]
+  fn test_costs() -> Result<()> {
+    let metrics = RuntimeMetrics::default();
+    let costs: CostModel = [
+      (("db".to_owned(), "query".to_owned()), 5),
+      (("self".to_owned(), "render".to_owned()), 2),
+    ]
+    .into();
+    let observer = MetricsObserver {
+      namespace: "app".to_owned(),
+      metrics: metrics.clone(),
+      costs,
+      last_snapshot: Mutex::new(None),
+    };
+
+    let mut stats = stats("greet", 1500, 0);
+    stats.origin = "http".to_owned();
+    stats.operations = [
+      (("db".to_owned(), "query".to_owned()), 3),
+      (("self".to_owned(), "render".to_owned()), 1),
+      (("core".to_owned(), "sender".to_owned()), 4),
+    ]
+    .into();
+    assert_eq!(observer.cost(&stats), 17, "undeclared operations should be free");
+    observer.on_transaction_done(&stats);
+    observer.on_transaction_done(&stats);
+
+    let text = metrics.render();
+    let lines: Vec<_> = text.lines().collect();
+    assert!(lines.contains(&r#"wick_operation_cost_total{namespace="app",operation="greet"} 34"#));
+    assert!(lines.contains(&r#"wick_origin_transactions_total{origin="http"} 2"#));
+    assert!(lines.contains(&r#"wick_origin_cost_total{origin="http"} 34"#));
+    assert!(lines.contains(&r#"wick_origin_duration_seconds_total{origin="http"} 3"#));
+    Ok(())
+  }
+
+  #[test]
+  fn test_escape() {
+    assert_eq!(escape(r#"a"b\c"#), r#"a\"b\\c"#);
+  }
+}
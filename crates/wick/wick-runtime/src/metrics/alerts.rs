@@ -0,0 +1,304 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio_stream::StreamExt;
+use tracing::Span;
+use wick_config::config::{AlertMetric, AlertRule};
+use wick_packet::{Entity, InherentData, Invocation, Packet};
+
+use super::{OperationMetrics, LATENCY_BUCKETS};
+use crate::Runtime;
+
+/// How often [AlertEvaluator::watch] checks its rules.
+pub const EVALUATION_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Operation totals keyed by scope namespace and operation name.
+type Totals = BTreeMap<(String, String), OperationMetrics>;
+
+/// A rule that fired, as sent to the rule's alert operation on its `alert` input.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct Alert {
+  /// The name of the rule that fired.
+  pub rule: String,
+  /// The scope of the operation that crossed the threshold.
+  pub namespace: String,
+  /// The operation that crossed the threshold.
+  pub operation: String,
+  /// The metric the rule checks.
+  pub metric: AlertMetric,
+  /// The metric's value over the window. A `p99` beyond the largest latency bucket reports that bucket's bound.
+  pub value: f64,
+  /// The value the metric exceeded.
+  pub threshold: f64,
+  /// The length of the window in seconds.
+  pub window: u64,
+  /// The number of transactions that finished in the window.
+  pub transactions: u64,
+  /// A one-line summary of the alert.
+  pub message: String,
+}
+
+/// Checks [AlertRule]s against the operation totals of a [super::RuntimeMetrics].
+///
+/// Each metric is computed from the change in totals over its rule's window. A rule fires for an operation once when
+/// the metric rises above the threshold and can't fire for it again until the metric drops back to the threshold.
+#[derive(Debug)]
+#[must_use]
+pub struct AlertEvaluator {
+  rules: Vec<AlertRule>,
+  /// Totals from earlier evaluations, oldest first, going back at least the longest window.
+  history: VecDeque<(Instant, Totals)>,
+  /// Rule indexes, namespaces, and operations that are above their threshold.
+  firing: HashSet<(usize, String, String)>,
+}
+
+impl AlertEvaluator {
+  /// Create an evaluator for `rules`.
+  pub fn new(rules: Vec<AlertRule>) -> Self {
+    Self {
+      rules,
+      history: VecDeque::new(),
+      firing: HashSet::new(),
+    }
+  }
+
+  /// Check the rules against `runtime`'s metrics every [EVALUATION_INTERVAL], invoking the alert operation of each
+  /// rule that fires. Runs until the future is dropped.
+  pub async fn watch(mut self, runtime: Runtime, span: Span) {
+    let mut interval = tokio::time::interval(EVALUATION_INTERVAL);
+    loop {
+      interval.tick().await;
+      let totals = runtime.metrics().totals();
+      for (index, alert) in self.evaluate(totals, Instant::now()) {
+        let target = target(self.rules[index].invoke());
+        span.in_scope(|| warn!(rule = alert.rule, %target, message = alert.message, "alert fired"));
+        tokio::spawn(notify(runtime.clone(), target, alert, span.clone()));
+      }
+    }
+  }
+
+  /// Record `totals` as of `now` and return the alerts of rules that started firing, with the index of their rule.
+  fn evaluate(&mut self, totals: Totals, now: Instant) -> Vec<(usize, Alert)> {
+    let mut alerts = Vec::new();
+    for (index, rule) in self.rules.iter().enumerate() {
+      // the totals at the start of the window, or the oldest we have if the window reaches back further.
+      let baseline = now
+        .checked_sub(*rule.window())
+        .and_then(|start| self.history.iter().rev().find(|(at, _)| *at <= start))
+        .or_else(|| self.history.front())
+        .map(|(_, totals)| totals);
+
+      for ((namespace, operation), current) in &totals {
+        if !watches(rule, namespace, operation) {
+          continue;
+        }
+        let key = (namespace.clone(), operation.clone());
+        let delta = current.since(baseline.and_then(|b| b.get(&key)));
+        let Some(value) = measure(*rule.metric(), &delta) else {
+          continue;
+        };
+        let key = (index, key.0, key.1);
+        if value <= rule.threshold() {
+          self.firing.remove(&key);
+        } else if self.firing.insert(key) {
+          alerts.push((index, alert(rule, namespace, operation, value, delta.count)));
+        }
+      }
+    }
+
+    self.history.push_back((now, totals));
+    // keep the newest totals from before the longest window started and everything since.
+    let longest = self.rules.iter().map(|r| *r.window()).max().unwrap_or_default();
+    if let Some(start) = now.checked_sub(longest) {
+      while self.history.len() > 1 && self.history[1].0 <= start {
+        self.history.pop_front();
+      }
+    }
+    alerts
+  }
+}
+
+/// Whether `rule` watches the operation `operation` of the scope `namespace`.
+fn watches(rule: &AlertRule, namespace: &str, operation: &str) -> bool {
+  rule.operation().map_or(true, |watched| {
+    watched == operation || watched.split_once("::") == Some((namespace, operation))
+  })
+}
+
+/// The value of `metric` over the transactions in `delta`, or `None` if there weren't any.
+fn measure(metric: AlertMetric, delta: &OperationMetrics) -> Option<f64> {
+  if delta.count == 0 {
+    return None;
+  }
+  match metric {
+    AlertMetric::ErrorRate => Some(delta.failed as f64 / delta.count as f64 * 100.0),
+    AlertMetric::P99 => {
+      let target = (delta.count as f64 * 0.99).ceil() as u64;
+      let bucket = delta.buckets.iter().position(|count| *count >= target);
+      // beyond the largest bucket the latency is unknown, so it's taken to exceed any threshold.
+      Some(bucket.map_or(f64::INFINITY, |i| LATENCY_BUCKETS[i] * 1000.0))
+    }
+    _ => None,
+  }
+}
+
+fn alert(rule: &AlertRule, namespace: &str, operation: &str, value: f64, transactions: u64) -> Alert {
+  let largest = LATENCY_BUCKETS[LATENCY_BUCKETS.len() - 1] * 1000.0;
+  let (name, shown) = match rule.metric() {
+    AlertMetric::ErrorRate => ("error rate", format!("{:.1}%", value)),
+    _ if value.is_infinite() => ("p99 latency", format!("over {}ms", largest)),
+    _ => ("p99 latency", format!("{}ms", value)),
+  };
+  let window = rule.window().as_secs();
+  Alert {
+    rule: rule.name().to_owned(),
+    namespace: namespace.to_owned(),
+    operation: operation.to_owned(),
+    metric: *rule.metric(),
+    value: value.min(largest),
+    threshold: rule.threshold(),
+    window,
+    transactions,
+    message: format!(
+      "{} of {}::{} was {} over the last {}s, above the threshold of {}",
+      name,
+      namespace,
+      operation,
+      shown,
+      window,
+      rule.threshold()
+    ),
+  }
+}
+
+/// The operation an alert rule invokes, either `component::operation` or an operation of the root component.
+fn target(operation: &str) -> Entity {
+  match operation.rsplit_once("::") {
+    Some((component, operation)) => Entity::operation(component, operation),
+    None => Entity::local(operation),
+  }
+}
+
+async fn notify(runtime: Runtime, target: Entity, alert: Alert, span: Span) {
+  let packets = vec![Packet::encode("alert", &alert), Packet::done("alert")];
+  let invocation = Invocation::new(
+    Entity::server("alerts"),
+    target.clone(),
+    packets,
+    InherentData::unsafe_default(),
+    &span,
+  );
+
+  match runtime.invoke(invocation, None).await {
+    Ok(mut stream) => {
+      while let Some(packet) = stream.next().await {
+        let error = match packet {
+          Ok(packet) => packet.is_error().then(|| packet.unwrap_err().msg().to_owned()),
+          Err(e) => Some(e.to_string()),
+        };
+        if let Some(error) = error {
+          span.in_scope(|| warn!(%target, %error, rule = alert.rule, "alert operation failed"));
+        }
+      }
+    }
+    Err(error) => span.in_scope(|| warn!(%target, %error, rule = alert.rule, "could not invoke alert operation")),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use wick_config::config::AlertRuleBuilder;
+
+  use super::*;
+
+  fn rule(metric: AlertMetric, threshold: f64) -> AlertRule {
+    AlertRuleBuilder::default()
+      .name("slow")
+      .metric(metric)
+      .threshold(threshold)
+      .window(Duration::from_secs(60))
+      .invoke("notify")
+      .build()
+      .unwrap()
+  }
+
+  /// Totals for `greet` in the scope `app` after `count` transactions, `failed` of which had errors, taking `secs`.
+  fn totals(count: u64, failed: u64, secs: f64) -> Totals {
+    let mut metrics = OperationMetrics::default();
+    for _ in 0..count {
+      for (bucket, le) in metrics.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+        if secs <= le {
+          *bucket += 1;
+        }
+      }
+      metrics.count += 1;
+    }
+    metrics.failed = failed;
+    BTreeMap::from([(("app".to_owned(), "greet".to_owned()), metrics)])
+  }
+
+  #[test]
+  fn test_error_rate() {
+    let start = Instant::now();
+    let mut evaluator = AlertEvaluator::new(vec![rule(AlertMetric::ErrorRate, 10.0)]);
+
+    assert!(evaluator.evaluate(totals(100, 5, 0.01), start).is_empty());
+    // 20 of the 100 transactions since the last evaluation failed.
+    let alerts = evaluator.evaluate(totals(200, 25, 0.01), start + Duration::from_secs(60));
+    assert_eq!(alerts.len(), 1);
+    let (index, alert) = &alerts[0];
+    assert_eq!(*index, 0);
+    assert_eq!(alert.value, 20.0);
+    assert_eq!(alert.transactions, 100);
+    assert_eq!(
+      alert.message,
+      "error rate of app::greet was 20.0% over the last 60s, above the threshold of 10"
+    );
+
+    // still firing, so it isn't sent again.
+    assert!(evaluator
+      .evaluate(totals(300, 45, 0.01), start + Duration::from_secs(120))
+      .is_empty());
+    // recovered, then fires again.
+    assert!(evaluator
+      .evaluate(totals(400, 45, 0.01), start + Duration::from_secs(180))
+      .is_empty());
+    assert_eq!(
+      evaluator
+        .evaluate(totals(500, 95, 0.01), start + Duration::from_secs(240))
+        .len(),
+      1
+    );
+  }
+
+  #[test]
+  fn test_p99() {
+    let start = Instant::now();
+    let mut evaluator = AlertEvaluator::new(vec![rule(AlertMetric::P99, 200.0)]);
+
+    assert!(evaluator.evaluate(totals(10, 0, 0.1), start).is_empty());
+    let alerts = evaluator.evaluate(totals(20, 0, 0.4), start + Duration::from_secs(60));
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].1.value, 500.0);
+
+    let mut evaluator = AlertEvaluator::new(vec![rule(AlertMetric::P99, 200.0)]);
+    let alerts = evaluator.evaluate(totals(10, 0, 30.0), start);
+    assert_eq!(alerts[0].1.value, 10000.0);
+    assert!(alerts[0].1.message.contains("over 10000ms"));
+  }
+
+  #[test]
+  fn test_watches() {
+    let mut rule = rule(AlertMetric::ErrorRate, 1.0);
+    assert!(watches(&rule, "app", "greet"));
+    *rule.operation_mut() = Some("greet".to_owned());
+    assert!(watches(&rule, "app", "greet"));
+    *rule.operation_mut() = Some("app::greet".to_owned());
+    assert!(watches(&rule, "app", "greet"));
+    assert!(!watches(&rule, "other", "greet"));
+    assert_eq!(target("mailer::send"), Entity::operation("mailer", "send"));
+    assert_eq!(target("notify"), Entity::local("notify"));
+  }
+}
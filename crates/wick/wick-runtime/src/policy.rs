@@ -0,0 +1,263 @@
+//! Rules that decide which imported components a runtime may instantiate.
+//!
+//! A [ComponentPolicy] is configured in the `host` section of a component's manifest. Components loaded from
+//! artifacts, i.e. WebAssembly modules and wick manifests, are checked before they're instantiated. Built-in components
+//! like `wick/component/sql` aren't checked.
+
+use sha2::{Digest, Sha256};
+use wick_config::config::{ComponentPolicy, PolicyAction, PolicyRule};
+use wick_config::{AssetReference, FetchOptions};
+use wick_wascap::WickComponent;
+
+use crate::ScopeError;
+
+/// What a policy knows about a component before it's instantiated.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct ComponentIdentity {
+  /// The namespace the component is imported as.
+  pub namespace: String,
+  /// The location of the component's artifact.
+  pub reference: String,
+  /// The OCI registry the artifact is pulled from, if it's an OCI reference.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub registry: Option<String>,
+  /// The sha256 digest of the artifact, as `sha256:<hex>`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub digest: Option<String>,
+  /// The public key of the account that signed the component's WebAssembly module.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub signer: Option<String>,
+}
+
+impl ComponentIdentity {
+  /// Identify the component imported as `namespace` from `reference`, before its artifact is read.
+  #[must_use]
+  pub fn new<T: Into<String>, R: Into<String>>(namespace: T, reference: R) -> Self {
+    let reference = reference.into();
+    let registry = wick_oci_utils::parse_reference(&reference)
+      .ok()
+      .map(|r| r.registry().to_owned());
+    Self {
+      namespace: namespace.into(),
+      reference,
+      registry,
+      digest: None,
+      signer: None,
+    }
+  }
+
+  /// Set the digest of the component's artifact from its bytes.
+  #[must_use]
+  pub fn with_artifact(mut self, bytes: &[u8]) -> Self {
+    self.digest = Some(format!("sha256:{:x}", Sha256::digest(bytes)));
+    self
+  }
+
+  /// Set the signer of the component from the claims embedded in its WebAssembly module, if it has any and they're
+  /// validly signed by their issuer.
+  #[must_use]
+  pub fn with_module(mut self, bytes: &[u8]) -> Self {
+    self.signer = wick_wascap::extract_claims(bytes)
+      .ok()
+      .flatten()
+      .filter(|token| {
+        wick_wascap::validate_token::<WickComponent>(&token.jwt)
+          .map_or(false, |v| v.signature_valid && !v.expired && !v.cannot_use_yet)
+      })
+      .map(|token| token.claims.issuer);
+    self
+  }
+
+  /// Read the artifact at `reference` and, if the component is backed by a WebAssembly module, the module at `module`
+  /// to identify the component imported as `namespace`.
+  pub(crate) async fn fetch(
+    namespace: &str,
+    reference: &AssetReference,
+    module: Option<&AssetReference>,
+    options: &FetchOptions,
+  ) -> Result<Self, ScopeError> {
+    let artifact = reference.bytes(options).await?;
+    let mut identity = Self::new(namespace, reference.location()).with_artifact(&artifact);
+    // local files can look like OCI references, e.g. `components/http.wasm`.
+    if reference.exists_outside_cache() {
+      identity.registry = None;
+    }
+    Ok(match module {
+      Some(module) if module.location() == reference.location() => identity.with_module(&artifact),
+      Some(module) => identity.with_module(&module.bytes(options).await?),
+      None => identity,
+    })
+  }
+}
+
+/// A component a [ComponentPolicy] refused to instantiate.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct PolicyViolation {
+  /// The component that was denied.
+  pub component: Box<ComponentIdentity>,
+  /// The index of the rule that denied it, or `None` if no rule matched and the policy denies by default.
+  pub rule: Option<usize>,
+}
+
+impl std::fmt::Display for PolicyViolation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "policy denied component '{}' from {}: ",
+      self.component.namespace, self.component.reference
+    )?;
+    match self.rule {
+      Some(i) => write!(f, "denied by rule {}", i),
+      None => f.write_str("no rule allows it and the policy denies by default"),
+    }
+  }
+}
+
+/// Check `component` against `policy`. The first rule that matches the component decides and components no rule
+/// matches are allowed unless the policy denies by default.
+pub fn check(policy: &ComponentPolicy, component: &ComponentIdentity) -> Result<(), PolicyViolation> {
+  let denied = |rule| PolicyViolation {
+    component: Box::new(component.clone()),
+    rule,
+  };
+  match policy.rules().iter().position(|rule| matches(rule, component)) {
+    Some(i) if *policy.rules()[i].action() == PolicyAction::Allow => Ok(()),
+    Some(i) => Err(denied(Some(i))),
+    None if policy.deny_by_default() => Err(denied(None)),
+    None => Ok(()),
+  }
+}
+
+/// Whether every criterion `rule` sets matches `component`.
+fn matches(rule: &PolicyRule, component: &ComponentIdentity) -> bool {
+  let criterion =
+    |expected: Option<&String>, actual: Option<&str>| expected.map_or(true, |e| Some(e.as_str()) == actual);
+  criterion(rule.registry(), component.registry.as_deref())
+    && criterion(rule.digest(), component.digest.as_deref())
+    && criterion(rule.signer(), component.signer.as_deref())
+    && rule.namespace().map_or(true, |pattern| {
+      pattern.strip_suffix('*').map_or_else(
+        || pattern == &component.namespace,
+        |prefix| component.namespace.starts_with(prefix),
+      )
+    })
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use wick_config::config::{ComponentPolicyBuilder, PolicyRuleBuilder};
+
+  use super::*;
+
+  fn rule(action: PolicyAction) -> PolicyRuleBuilder {
+    let mut builder = PolicyRuleBuilder::default();
+    builder.action(action);
+    builder
+  }
+
+  #[test]
+  fn test_identity() {
+    let component = ComponentIdentity::new("http", "registry.candle.dev/common/http-client:0.1.0");
+    assert_eq!(component.registry.as_deref(), Some("registry.candle.dev"));
+    let component = ComponentIdentity::new("local", "./components/local.wick").with_artifact(b"abc");
+    assert_eq!(component.registry, None, "local paths have no registry");
+    assert_eq!(
+      component.digest.as_deref(),
+      Some("sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+    );
+  }
+
+  #[test]
+  fn test_signer() -> Result<()> {
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use nkeys::KeyPair;
+    use wick_wascap::ClaimsOptions;
+
+    let account = KeyPair::new_account();
+    let signed = wick_wascap::sign_buffer_with_claims(
+      b"\0asm\x01\0\0\0",
+      wick_interface_types::ComponentSignature::new_named("test"),
+      &KeyPair::new_service(),
+      &account,
+      &ClaimsOptions::v1(None, None, None),
+    )?;
+    let component = ComponentIdentity::new("test", "./test.wasm").with_module(&signed);
+    assert_eq!(component.signer, Some(account.public_key()));
+
+    // claim another account issued the module without that account's signature.
+    let jwt = wick_wascap::extract_claims(&signed)?.unwrap().jwt;
+    let segments: Vec<&str> = jwt.split('.').collect();
+    let claims = String::from_utf8(URL_SAFE_NO_PAD.decode(segments[1])?)?;
+    let forged_claims = claims.replace(&account.public_key(), &KeyPair::new_account().public_key());
+    let forged_jwt = format!(
+      "{}.{}.{}",
+      segments[0],
+      URL_SAFE_NO_PAD.encode(forged_claims),
+      segments[2]
+    );
+    assert_eq!(forged_jwt.len(), jwt.len());
+    let position = signed
+      .windows(jwt.len())
+      .position(|window| window == jwt.as_bytes())
+      .unwrap();
+    let mut forged = signed.clone();
+    forged[position..position + jwt.len()].copy_from_slice(forged_jwt.as_bytes());
+
+    let component = ComponentIdentity::new("test", "./test.wasm").with_module(&forged);
+    assert_eq!(component.signer, None, "forged claims should not identify a signer");
+    Ok(())
+  }
+
+  #[test]
+  fn test_check() -> Result<()> {
+    let mut trusted = ComponentIdentity::new("http", "registry.candle.dev/common/http-client:0.1.0");
+    trusted.signer = Some("ACCOUNT".to_owned());
+    let untrusted = ComponentIdentity::new("db", "example.com/db:latest");
+    let internal = ComponentIdentity::new("internal_auth", "./auth.wick");
+
+    let policy = ComponentPolicyBuilder::default()
+      .deny_by_default(true)
+      .rules(vec![
+        rule(PolicyAction::Deny)
+          .namespace("internal_legacy".to_owned())
+          .build()?,
+        rule(PolicyAction::Allow).namespace("internal_*".to_owned()).build()?,
+        rule(PolicyAction::Allow)
+          .registry("registry.candle.dev".to_owned())
+          .signer("ACCOUNT".to_owned())
+          .build()?,
+      ])
+      .build()?;
+
+    assert!(check(&policy, &trusted).is_ok());
+    assert!(check(&policy, &internal).is_ok(), "namespace prefixes should match");
+    assert_eq!(
+      check(&policy, &untrusted),
+      Err(PolicyViolation {
+        component: Box::new(untrusted.clone()),
+        rule: None
+      })
+    );
+    let legacy = ComponentIdentity::new("internal_legacy", "./legacy.wick");
+    assert_eq!(
+      check(&policy, &legacy).map_err(|e| e.rule),
+      Err(Some(0)),
+      "the first matching rule should decide"
+    );
+
+    let mut unsigned = trusted;
+    unsigned.signer = None;
+    assert!(
+      check(&policy, &unsigned).is_err(),
+      "every criterion of a rule should have to match"
+    );
+
+    let permissive = ComponentPolicyBuilder::default().build()?;
+    assert!(check(&permissive, &untrusted).is_ok());
+    Ok(())
+  }
+}
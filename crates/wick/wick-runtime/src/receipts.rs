@@ -0,0 +1,492 @@
+//! Signed receipts of the transactions a [crate::Runtime] completes, for audit trails and settling disputes over what
+//! an app was given and what it produced.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt::Write;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use flow_graph::SCHEMATIC_INPUT;
+use flow_graph_interpreter::{EventListener, TransactionEventKind};
+use nkeys::KeyPair;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use wick_interface_types::ComponentSignature;
+use wick_packet::{Entity, Packet};
+
+/// How many receipts a [ReceiptLog] keeps unless it's created with a different capacity.
+pub const DEFAULT_CAPACITY: usize = 1000;
+
+/// Errors signing or verifying a receipt.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum ReceiptError {
+  #[error("could not sign receipt: {0}")]
+  Sign(String),
+
+  #[error("invalid signer '{0}', expected a public key")]
+  InvalidSigner(String),
+
+  #[error("receipt signature does not match its contents")]
+  InvalidSignature,
+}
+
+/// A summary of a completed transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Receipt {
+  /// The transaction's id.
+  pub tx_id: Uuid,
+  /// The name of the operation the transaction executed.
+  pub operation: String,
+  /// When the transaction started, in milliseconds since the Unix epoch.
+  pub started_at: u64,
+  /// When the transaction finished delivering its output, in milliseconds since the Unix epoch.
+  pub finished_at: u64,
+  /// The hex-encoded sha256 digest of the JSON of each packet the transaction received, one per line, in the order
+  /// they arrived.
+  pub inputs: String,
+  /// The hex-encoded sha256 digest of the JSON of each packet the transaction emitted, one per line, in the order
+  /// they were sent.
+  pub outputs: String,
+  /// The digests of the imported components the transaction invoked, by namespace. See [component_digest].
+  pub components: BTreeMap<String, String>,
+  /// The number of error packets and operation failures within the transaction.
+  pub errors: u64,
+}
+
+impl Receipt {
+  /// The bytes a receipt's signature is computed over.
+  fn canonical(&self) -> Vec<u8> {
+    // fields serialize in declaration order and components are sorted, so this is stable.
+    serde_json::to_vec(self).unwrap_or_default()
+  }
+}
+
+/// A [Receipt] signed with a host's key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SignedReceipt {
+  /// What the transaction did.
+  #[serde(flatten)]
+  pub receipt: Receipt,
+  /// The public key of the host that signed the receipt.
+  pub signer: String,
+  /// The hex-encoded ed25519 signature of the receipt.
+  pub signature: String,
+}
+
+impl SignedReceipt {
+  /// Check that the receipt was signed by its `signer` and hasn't been changed since.
+  pub fn verify(&self) -> Result<(), ReceiptError> {
+    let key = KeyPair::from_public_key(&self.signer).map_err(|_| ReceiptError::InvalidSigner(self.signer.clone()))?;
+    let signature = unhex(&self.signature).ok_or(ReceiptError::InvalidSignature)?;
+    key
+      .verify(&self.receipt.canonical(), &signature)
+      .map_err(|_| ReceiptError::InvalidSignature)
+  }
+}
+
+/// The digest a [Receipt] records for a component: the hex-encoded sha256 digest of its signature's JSON, which
+/// changes with the component's name, version, operations, types, and configuration.
+#[must_use]
+pub fn component_digest(signature: &ComponentSignature) -> String {
+  hex(&Sha256::digest(serde_json::to_vec(signature).unwrap_or_default()))
+}
+
+/// Signs a receipt for every transaction a runtime completes and keeps the most recent ones.
+///
+/// Receipts are kept until the log holds its capacity and newer ones push them out, or until they're older than the
+/// log's max age, if it has one. Old receipts are pruned whenever one is added or the log is read, so nothing needs
+/// to run in the background to clean up after a long-running host.
+///
+/// Clones share the same receipts.
+#[derive(Clone)]
+#[must_use]
+pub struct ReceiptLog {
+  key: Arc<KeyPair>,
+  inner: Arc<Mutex<LogInner>>,
+}
+
+#[derive(Default)]
+struct LogInner {
+  capacity: usize,
+  max_age: Option<Duration>,
+  receipts: VecDeque<SignedReceipt>,
+  pending: HashMap<Uuid, Pending>,
+  /// Receipts pushed out by newer ones.
+  evicted: u64,
+  /// Receipts pruned for being older than the max age.
+  expired: u64,
+}
+
+impl LogInner {
+  /// Drop the receipts older than the max age, which are at the front since receipts are added as transactions
+  /// finish.
+  fn prune(&mut self, now: SystemTime) {
+    let Some(max_age) = self.max_age else {
+      return;
+    };
+    let oldest = millis(now).saturating_sub(u64::try_from(max_age.as_millis()).unwrap_or(u64::MAX));
+    while self.receipts.front().map_or(false, |r| r.receipt.finished_at < oldest) {
+      self.receipts.pop_front();
+      self.expired += 1;
+    }
+  }
+}
+
+/// A transaction that hasn't finished yet.
+struct Pending {
+  operation: String,
+  started_at: u64,
+  inputs: Vec<String>,
+  outputs: Vec<String>,
+  components: BTreeSet<String>,
+}
+
+impl std::fmt::Debug for ReceiptLog {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ReceiptLog")
+      .field("signer", &self.signer())
+      .field("receipts", &self.inner.lock().receipts.len())
+      .finish()
+  }
+}
+
+impl ReceiptLog {
+  /// Sign receipts with `key`, keeping the last [DEFAULT_CAPACITY].
+  pub fn new(key: KeyPair) -> Self {
+    Self::with_capacity(key, DEFAULT_CAPACITY)
+  }
+
+  /// Sign receipts with `key`, keeping the last `capacity`.
+  pub fn with_capacity(key: KeyPair, capacity: usize) -> Self {
+    Self {
+      key: Arc::new(key),
+      inner: Arc::new(Mutex::new(LogInner {
+        capacity: capacity.max(1),
+        ..Default::default()
+      })),
+    }
+  }
+
+  /// Prune receipts of transactions that finished more than `max_age` ago.
+  pub fn with_max_age(self, max_age: Duration) -> Self {
+    self.inner.lock().max_age = Some(max_age);
+    self
+  }
+
+  /// The public key receipts are signed with.
+  #[must_use]
+  pub fn signer(&self) -> String {
+    self.key.public_key()
+  }
+
+  /// The receipt of the transaction `tx_id`, if it finished recently enough to still be kept.
+  #[must_use]
+  pub fn get(&self, tx_id: Uuid) -> Option<SignedReceipt> {
+    let mut inner = self.inner.lock();
+    inner.prune(SystemTime::now());
+    inner.receipts.iter().find(|r| r.receipt.tx_id == tx_id).cloned()
+  }
+
+  /// The receipts kept, most recent first.
+  #[must_use]
+  pub fn recent(&self) -> Vec<SignedReceipt> {
+    let mut inner = self.inner.lock();
+    inner.prune(SystemTime::now());
+    inner.receipts.iter().rev().cloned().collect()
+  }
+
+  /// Render the number of receipts kept and pruned in the Prometheus text exposition format.
+  #[must_use]
+  pub fn render_metrics(&self) -> String {
+    let inner = self.inner.lock();
+    let mut out = String::new();
+    out.push_str("# HELP wick_receipts_retained Transaction receipts kept.\n");
+    out.push_str("# TYPE wick_receipts_retained gauge\n");
+    let _ = writeln!(out, "wick_receipts_retained {}", inner.receipts.len());
+    out.push_str("# HELP wick_receipts_pruned_total Transaction receipts pruned, by why they were pruned.\n");
+    out.push_str("# TYPE wick_receipts_pruned_total counter\n");
+    let _ = writeln!(
+      out,
+      "wick_receipts_pruned_total{{reason=\"capacity\"}} {}",
+      inner.evicted
+    );
+    let _ = writeln!(out, "wick_receipts_pruned_total{{reason=\"age\"}} {}", inner.expired);
+    out
+  }
+
+  /// An interpreter [EventListener] that records the transactions of a scope whose imported components have the
+  /// given digests.
+  pub(crate) fn listener(&self, digests: BTreeMap<String, String>) -> EventListener {
+    let log = self.clone();
+    EventListener::new(move |event| log.record(event.tx_id, event.time, &event.kind, &digests))
+  }
+
+  fn record(&self, tx_id: Uuid, time: SystemTime, kind: &TransactionEventKind, digests: &BTreeMap<String, String>) {
+    let mut inner = self.inner.lock();
+    match kind {
+      TransactionEventKind::Started { operation, .. } => {
+        inner.pending.insert(
+          tx_id,
+          Pending {
+            operation: operation.clone(),
+            started_at: millis(time),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            components: BTreeSet::new(),
+          },
+        );
+      }
+      TransactionEventKind::PacketAccepted { operation, packet, .. } if operation == SCHEMATIC_INPUT => {
+        if let Some(tx) = inner.pending.get_mut(&tx_id) {
+          tx.inputs.push(packet_digest(packet));
+        }
+      }
+      // packets for unconnected inputs were still given to the transaction.
+      TransactionEventKind::DeadLetter { packet, .. } => {
+        if let Some(tx) = inner.pending.get_mut(&tx_id) {
+          tx.inputs.push(packet_digest(packet));
+        }
+      }
+      TransactionEventKind::Output { packet, .. } => {
+        if let Some(tx) = inner.pending.get_mut(&tx_id) {
+          tx.outputs.push(packet_digest(packet));
+        }
+      }
+      TransactionEventKind::OperationStarted { entity, .. } => {
+        if let (Some(tx), Ok(entity)) = (inner.pending.get_mut(&tx_id), entity.parse::<Entity>()) {
+          tx.components.insert(entity.component_id().to_owned());
+        }
+      }
+      // transactions that end without finishing have no receipt.
+      TransactionEventKind::Cancelled { .. } | TransactionEventKind::Abandoned { .. } => {
+        inner.pending.remove(&tx_id);
+      }
+      TransactionEventKind::Finished { errors, .. } => {
+        let Some(tx) = inner.pending.remove(&tx_id) else {
+          return;
+        };
+        let receipt = Receipt {
+          tx_id,
+          operation: tx.operation,
+          started_at: tx.started_at,
+          finished_at: millis(time),
+          inputs: tx.inputs.join("\n"),
+          outputs: tx.outputs.join("\n"),
+          components: tx
+            .components
+            .into_iter()
+            .filter_map(|ns| digests.get(&ns).map(|digest| (ns, digest.clone())))
+            .collect(),
+          errors: *errors,
+        };
+        match self.sign(receipt) {
+          Ok(signed) => {
+            inner.prune(time);
+            if inner.receipts.len() >= inner.capacity {
+              inner.receipts.pop_front();
+              inner.evicted += 1;
+            }
+            inner.receipts.push_back(signed);
+          }
+          Err(error) => warn!(%error, %tx_id, "could not sign transaction receipt"),
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn sign(&self, receipt: Receipt) -> Result<SignedReceipt, ReceiptError> {
+    let signature = self
+      .key
+      .sign(&receipt.canonical())
+      .map_err(|e| ReceiptError::Sign(e.to_string()))?;
+    Ok(SignedReceipt {
+      receipt,
+      signer: self.signer(),
+      signature: hex(&signature),
+    })
+  }
+}
+
+fn packet_digest(packet: &Packet) -> String {
+  hex(&Sha256::digest(serde_json::to_vec(packet).unwrap_or_default()))
+}
+
+fn millis(time: SystemTime) -> u64 {
+  time
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
+
+fn hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn unhex(s: &str) -> Option<Vec<u8>> {
+  if s.len() % 2 != 0 {
+    return None;
+  }
+  (0..s.len())
+    .step_by(2)
+    .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use std::time::Duration;
+
+  use anyhow::Result;
+  use flow_graph::SCHEMATIC_OUTPUT;
+
+  use super::*;
+
+  fn run(log: &ReceiptLog, tx_id: Uuid, input: &str) {
+    let digests = BTreeMap::from([("child".to_owned(), "abc123".to_owned())]);
+    let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let events = [
+      TransactionEventKind::Started {
+        operation: "test".to_owned(),
+        seed: 0,
+        timestamp: 0,
+        config: None,
+      },
+      TransactionEventKind::PacketAccepted {
+        operation: SCHEMATIC_INPUT.to_owned(),
+        port: "input".to_owned(),
+        packet: Packet::encode("input", input),
+      },
+      TransactionEventKind::OperationStarted {
+        operation: "op".to_owned(),
+        entity: Entity::operation("child", "echo").to_string(),
+      },
+      TransactionEventKind::OperationStarted {
+        operation: "sender".to_owned(),
+        entity: Entity::operation("core", "sender").to_string(),
+      },
+      TransactionEventKind::Output {
+        port: SCHEMATIC_OUTPUT.to_owned(),
+        packet: Packet::encode("output", input),
+      },
+      TransactionEventKind::Finished {
+        operation: "test".to_owned(),
+        duration: Duration::from_millis(20),
+        packets: 2,
+        errors: 0,
+      },
+    ];
+    for (i, kind) in events.iter().enumerate() {
+      log.record(tx_id, start + Duration::from_millis(i as u64 * 10), kind, &digests);
+    }
+  }
+
+  #[test]
+  fn test_receipts() -> Result<()> {
+    let log = ReceiptLog::with_capacity(KeyPair::new_server(), 2);
+    let (tx1, tx2, tx3) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    run(&log, tx1, "hello");
+    run(&log, tx2, "hello");
+
+    let first = log.get(tx1).unwrap();
+    assert_eq!(first.receipt.started_at, 1_700_000_000_000);
+    assert_eq!(first.receipt.finished_at, 1_700_000_000_050);
+    assert_eq!(
+      first.receipt.components,
+      BTreeMap::from([("child".to_owned(), "abc123".to_owned())]),
+      "only imported components should be recorded"
+    );
+    assert_eq!(first.signer, log.signer());
+    first.verify()?;
+
+    let second = log.get(tx2).unwrap();
+    assert_eq!(
+      (first.receipt.inputs.as_str(), first.receipt.outputs.as_str()),
+      (second.receipt.inputs.as_str(), second.receipt.outputs.as_str()),
+      "the same packets should have the same digests"
+    );
+
+    let mut tampered = second.clone();
+    tampered.receipt.outputs = first.receipt.inputs.clone();
+    assert!(tampered.verify().is_err(), "a changed receipt should not verify");
+
+    run(&log, tx3, "goodbye");
+    assert!(log.get(tx1).is_none(), "the oldest receipt should have been dropped");
+    let recent = log.recent();
+    assert_eq!(recent[0].receipt.tx_id, tx3, "the newest receipt should be first");
+    assert_ne!(recent[0].receipt.inputs, second.receipt.inputs);
+    Ok(())
+  }
+
+  #[test]
+  fn test_packet_digests() {
+    let log = ReceiptLog::new(KeyPair::new_server());
+    let tx_id = Uuid::new_v4();
+    run(&log, tx_id, "hello");
+    let receipt = log.get(tx_id).unwrap().receipt;
+    assert_eq!(receipt.inputs, packet_digest(&Packet::encode("input", "hello")));
+    assert_eq!(receipt.outputs, packet_digest(&Packet::encode("output", "hello")));
+  }
+
+  #[test]
+  fn test_unfinished() {
+    let log = ReceiptLog::new(KeyPair::new_server());
+    let digests = BTreeMap::new();
+    let started = TransactionEventKind::Started {
+      operation: "test".to_owned(),
+      seed: 0,
+      timestamp: 0,
+      config: None,
+    };
+    let ends = [
+      TransactionEventKind::Cancelled {
+        operation: "test".to_owned(),
+      },
+      TransactionEventKind::Abandoned {
+        operation: "test".to_owned(),
+      },
+    ];
+    for end in ends {
+      let tx_id = Uuid::new_v4();
+      log.record(tx_id, SystemTime::now(), &started, &digests);
+      log.record(tx_id, SystemTime::now(), &end, &digests);
+      assert!(
+        log.inner.lock().pending.is_empty(),
+        "{:?} should end the transaction",
+        end
+      );
+      assert!(log.get(tx_id).is_none());
+    }
+  }
+
+  #[test]
+  fn test_max_age() {
+    let log = ReceiptLog::with_capacity(KeyPair::new_server(), 2).with_max_age(Duration::from_secs(60));
+    run(&log, Uuid::new_v4(), "hello");
+    run(&log, Uuid::new_v4(), "hello");
+    run(&log, Uuid::new_v4(), "hello");
+    let metrics = log.render_metrics();
+    assert!(metrics.contains("wick_receipts_retained 2\n"));
+    assert!(metrics.contains("wick_receipts_pruned_total{reason=\"capacity\"} 1\n"));
+
+    // the receipts finished in 2023, long before now.
+    assert!(
+      log.recent().is_empty(),
+      "receipts older than the max age should be pruned"
+    );
+    let metrics = log.render_metrics();
+    assert!(metrics.contains("wick_receipts_retained 0\n"));
+    assert!(metrics.contains("wick_receipts_pruned_total{reason=\"age\"} 2\n"));
+  }
+
+  #[test]
+  fn test_hex() {
+    assert_eq!(unhex(&hex(&[0, 1, 254, 255])), Some(vec![0, 1, 254, 255]));
+    assert_eq!(unhex("abc"), None);
+    assert_eq!(unhex("zz"), None);
+  }
+}
@@ -0,0 +1,132 @@
+//! The client side of the Redis protocol, as much of it as the runtime's Redis backends need.
+
+use std::io::{Error, ErrorKind};
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use wick_config::config::Secret;
+
+/// A reply from Redis. Nested arrays aren't needed by the runtime's commands and aren't supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Reply {
+  Status(String),
+  Integer(i64),
+  Bulk(Option<Vec<u8>>),
+  Array(Vec<Reply>),
+}
+
+/// Connect to the Redis server at `addr`, authenticating with `password` if given.
+pub(crate) async fn connect(addr: &str, password: Option<&Secret>) -> Result<BufReader<TcpStream>, Error> {
+  let mut stream = BufReader::new(TcpStream::connect(addr).await?);
+  if let Some(password) = password {
+    command(&mut stream, &["AUTH", password.expose()]).await?;
+  }
+  Ok(stream)
+}
+
+/// Send `args` as a command on `stream` and read its reply.
+pub(crate) async fn command(stream: &mut BufReader<TcpStream>, args: &[&str]) -> Result<Reply, Error> {
+  stream.write_all(&encode(args)).await?;
+  read_reply(stream).await
+}
+
+/// Encode a command in the Redis protocol.
+pub(crate) fn encode(args: &[&str]) -> Vec<u8> {
+  let mut buf = format!("*{}\r\n", args.len()).into_bytes();
+  for arg in args {
+    buf.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+    buf.extend_from_slice(arg.as_bytes());
+    buf.extend_from_slice(b"\r\n");
+  }
+  buf
+}
+
+fn invalid(reason: String) -> Error {
+  Error::new(ErrorKind::InvalidData, reason)
+}
+
+async fn read_line<R: AsyncBufRead + Unpin + Send>(reader: &mut R) -> Result<String, Error> {
+  let mut line = String::new();
+  reader.read_line(&mut line).await?;
+  if !line.ends_with("\r\n") {
+    return Err(Error::new(ErrorKind::UnexpectedEof, "connection closed"));
+  }
+  line.truncate(line.len() - 2);
+  Ok(line)
+}
+
+fn integer(s: &str) -> Result<i64, Error> {
+  s.parse().map_err(|_| invalid(format!("invalid integer '{}'", s)))
+}
+
+/// Read a reply that isn't an array.
+async fn read_value<R: AsyncBufRead + Unpin + Send>(reader: &mut R) -> Result<Reply, Error> {
+  let line = read_line(reader).await?;
+  let mut chars = line.chars();
+  let kind = chars.next();
+  let rest = chars.as_str();
+  match kind {
+    Some('+') => Ok(Reply::Status(rest.to_owned())),
+    Some('-') => Err(Error::new(ErrorKind::Other, rest.to_owned())),
+    Some(':') => Ok(Reply::Integer(integer(rest)?)),
+    Some('$') => {
+      let Ok(len) = usize::try_from(integer(rest)?) else {
+        return Ok(Reply::Bulk(None));
+      };
+      let mut buf = vec![0; len + 2];
+      reader.read_exact(&mut buf).await?;
+      buf.truncate(len);
+      Ok(Reply::Bulk(Some(buf)))
+    }
+    _ => Err(invalid(format!("unexpected reply '{}'", line))),
+  }
+}
+
+/// Read a reply, including arrays of values.
+pub(crate) async fn read_reply<R: AsyncBufRead + Unpin + Send>(reader: &mut R) -> Result<Reply, Error> {
+  let buf = reader.fill_buf().await?;
+  if buf.first() != Some(&b'*') {
+    return read_value(reader).await;
+  }
+  let line = read_line(reader).await?;
+  let len = usize::try_from(integer(&line[1..])?).unwrap_or(0);
+  let mut items = Vec::with_capacity(len);
+  for _ in 0..len {
+    items.push(read_value(reader).await?);
+  }
+  Ok(Reply::Array(items))
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_encode() {
+    assert_eq!(
+      encode(&["GET", "lease"]),
+      b"*2\r\n$3\r\nGET\r\n$5\r\nlease\r\n".to_vec()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_read_reply() -> Result<()> {
+    let mut reply: &[u8] = b"*2\r\n$4\r\nnode\r\n:1500\r\n";
+    assert_eq!(
+      read_reply(&mut reply).await?,
+      Reply::Array(vec![Reply::Bulk(Some(b"node".to_vec())), Reply::Integer(1500)])
+    );
+    let mut reply: &[u8] = b"*2\r\n$-1\r\n:-2\r\n";
+    assert_eq!(
+      read_reply(&mut reply).await?,
+      Reply::Array(vec![Reply::Bulk(None), Reply::Integer(-2)])
+    );
+    let mut reply: &[u8] = b"+OK\r\n";
+    assert_eq!(read_reply(&mut reply).await?, Reply::Status("OK".to_owned()));
+    let mut reply: &[u8] = b"-NOSCRIPT no matching script\r\n";
+    assert!(read_reply(&mut reply).await.is_err(), "error replies should fail");
+    Ok(())
+  }
+}
@@ -1,13 +1,21 @@
 use seeded_random::Seed;
 use tracing::Span;
 use uuid::Uuid;
-use wick_config::config::{ComponentConfiguration, ComponentConfigurationBuilder};
+use wick_config::config::components::{WasmLimits, WasmPool};
+use wick_config::config::{ComponentConfiguration, ComponentConfigurationBuilder, ComponentPolicy, TypeCheckConfig};
 use wick_packet::{Entity, RuntimeConfig};
 pub(crate) mod scope;
 
-use scope::{ComponentFactory, ComponentRegistry, ScopeInit};
+use flow_component::{KeyValue, KeyValueStore};
+use flow_graph_interpreter::{ChaosPolicy, Durability, NamespaceHandler};
+use scope::{ComponentFactory, ComponentRegistry, ScopeInit, ScopeRegistry};
 
 use crate::dev::prelude::*;
+use crate::failover::Coordinator;
+use crate::metrics::RuntimeMetrics;
+use crate::receipts::ReceiptLog;
+use crate::scan::ScanPolicy;
+use crate::state::MemoryStore;
 
 type Result<T> = std::result::Result<T, RuntimeError>;
 #[derive(Debug, Clone)]
@@ -15,6 +23,13 @@ type Result<T> = std::result::Result<T, RuntimeError>;
 pub struct Runtime {
   pub uid: Uuid,
   root: Scope,
+  metrics: RuntimeMetrics,
+  receipts: Option<ReceiptLog>,
+  coordinator: Option<Coordinator>,
+  scan: Option<ScanPolicy>,
+  state: Arc<dyn KeyValueStore>,
+  durability: Option<Durability>,
+  pub(crate) scopes: ScopeRegistry,
 }
 
 #[derive(Debug, derive_builder::Builder)]
@@ -44,10 +59,75 @@ pub struct RuntimeInit {
 
   #[builder(default)]
   pub(crate) max_packet_size: Option<u32>,
+
+  /// Resource limits for the instances of the root component, when it's a WebAssembly component.
+  #[builder(default)]
+  pub(crate) limits: Option<WasmLimits>,
+
+  /// Pool instances of the root component, when it's a WebAssembly component.
+  #[builder(default)]
+  pub(crate) pool: Option<WasmPool>,
+
+  /// Inject randomized faults into operation calls, for testing only.
+  #[builder(default)]
+  pub(crate) chaos: Option<ChaosPolicy>,
+
+  /// Configuration merged over the `with:` config of the manifest's imports, keyed by import id.
+  #[builder(default)]
+  pub(crate) config_overrides: HashMap<String, RuntimeConfig>,
+
+  /// Sign a receipt for every transaction the root scope completes.
+  #[builder(default)]
+  pub(crate) receipts: Option<ReceiptLog>,
+
+  /// Rules that decide which imported components may be instantiated, in this scope and every scope below it.
+  #[builder(default)]
+  pub(crate) policy: Option<ComponentPolicy>,
+
+  /// How the types of connected ports are checked when flows are loaded, in this scope and every scope below it.
+  #[builder(default)]
+  pub(crate) type_check: Option<TypeCheckConfig>,
+
+  /// Scans every imported component's artifact before it's instantiated, in this scope and every scope below it.
+  #[builder(default)]
+  pub(crate) scan: Option<ScanPolicy>,
+
+  /// Leases shared with other instances of the app, for triggers whose work must only run on one instance.
+  #[builder(default)]
+  pub(crate) coordinator: Option<Coordinator>,
+
+  /// The store components keep state in through their context, in this scope and every scope below it. Defaults to
+  /// a store in memory.
+  #[builder(default)]
+  pub(crate) state: Option<Arc<dyn KeyValueStore>>,
+
+  #[builder(setter(skip))]
+  pub(crate) kv: Option<KeyValue>,
+
+  /// Checkpoint the transactions the root scope runs so the ones a crash interrupts can be finished with
+  /// [Runtime::resume].
+  #[builder(default)]
+  pub(crate) durability: Option<Durability>,
+
+  #[builder(setter(skip))]
+  pub(crate) metrics: RuntimeMetrics,
+
+  #[builder(setter(skip))]
+  pub(crate) scopes: ScopeRegistry,
 }
 
 impl Runtime {
-  pub(crate) async fn new(seed: Seed, config: RuntimeInit) -> Result<Self> {
+  pub(crate) async fn new(seed: Seed, mut config: RuntimeInit) -> Result<Self> {
+    let metrics = config.metrics.clone();
+    let receipts = config.receipts.clone();
+    let coordinator = config.coordinator.clone();
+    let scan = config.scan.clone();
+    let durability = config.durability.clone();
+    let state = config
+      .state
+      .get_or_insert_with(|| Arc::new(MemoryStore::default()))
+      .clone();
+    let scopes = config.scopes.clone();
     let init = ScopeInit::new(seed, config);
 
     let ns = init.namespace.as_deref().unwrap_or("__local__").to_owned();
@@ -57,9 +137,7 @@ impl Runtime {
     let span = init.span.clone();
 
     let start = std::time::Instant::now();
-    let service = Scope::start(init)
-      .await
-      .map_err(|e| RuntimeError::InitializationFailed(e.to_string()))?;
+    let service = Scope::start(init).await.map_err(|e| RuntimeError::Scope(Box::new(e)))?;
     let end = std::time::Instant::now();
 
     span.in_scope(|| info!(id = ns, duration_ms = %end.duration_since(start).as_millis(), "initialized"));
@@ -67,6 +145,13 @@ impl Runtime {
     Ok(Self {
       uid: service.id(),
       root: service,
+      metrics,
+      receipts,
+      coordinator,
+      scan,
+      state,
+      durability,
+      scopes,
     })
   }
 
@@ -93,7 +178,7 @@ impl Runtime {
       path
     };
     for path in path {
-      if let Some(scope) = Scope::find(Some(last_scope.id()), path) {
+      if let Some(scope) = self.scopes.find(Some(last_scope.id()), path) {
         last_scope = scope.clone();
       } else {
         return None;
@@ -161,9 +246,112 @@ impl Runtime {
     self.root.render_dotviz(op)
   }
 
+  pub fn content_hash(&self, op: &str) -> Result<String> {
+    self.root.content_hash(op)
+  }
+
   pub fn active_config(&self) -> &ComponentConfiguration {
     self.root.active_config()
   }
+
+  /// The namespaces of the components initialized for the root scope, sorted by name.
+  #[must_use]
+  pub fn components(&self) -> Vec<String> {
+    self.root.component_namespaces()
+  }
+
+  /// Latency, packet, and error totals for the operations run by every scope of this runtime.
+  pub const fn metrics(&self) -> &RuntimeMetrics {
+    &self.metrics
+  }
+
+  /// The signed receipts of recently completed transactions, if the runtime was built to keep them.
+  #[must_use]
+  pub const fn receipts(&self) -> Option<&ReceiptLog> {
+    self.receipts.as_ref()
+  }
+
+  /// The leases shared with other instances of the app, if it's coordinating with them.
+  #[must_use]
+  pub const fn coordinator(&self) -> Option<&Coordinator> {
+    self.coordinator.as_ref()
+  }
+
+  /// The scans imported components' artifacts were checked with, if any.
+  #[must_use]
+  pub const fn scan(&self) -> Option<&ScanPolicy> {
+    self.scan.as_ref()
+  }
+
+  /// The store components keep state in through their context.
+  #[must_use]
+  pub const fn state(&self) -> &Arc<dyn KeyValueStore> {
+    &self.state
+  }
+
+  /// Where the transactions the root scope runs are checkpointed, if the runtime was built to make them durable.
+  #[must_use]
+  pub const fn durability(&self) -> Option<&Durability> {
+    self.durability.as_ref()
+  }
+
+  /// Finish the transactions a previous runtime left in the [Runtime::durability] store, returning the id and output
+  /// stream of each.
+  ///
+  /// Operations that had finished emit their recorded output instead of being called again, so only the work that was
+  /// in progress is repeated.
+  pub async fn resume(&self) -> Result<Vec<(Uuid, PacketStream)>> {
+    self.root.resume().await
+  }
+
+  /// The number of events waiting to be processed by the root scope's interpreter.
+  #[must_use]
+  pub fn backlog(&self) -> usize {
+    self.root.backlog()
+  }
+
+  /// Cancel the transaction `tx_id` in every scope of this runtime.
+  ///
+  /// Running operations are aborted and each output of the transaction ends with a
+  /// [wick_packet::PacketError::CANCELLED] error, e.g. to stop work for a client that disconnected.
+  pub fn cancel(&self, tx_id: Uuid) {
+    for scope in self.scopes.all() {
+      scope.cancel(tx_id);
+    }
+  }
+
+  /// Share the component imported under `namespace` so another [Runtime] can import it with
+  /// [RuntimeBuilder::add_shared_component].
+  ///
+  /// Runtimes can't reach each other's components otherwise.
+  pub fn share(&self, namespace: &str) -> Result<SharedImport> {
+    self
+      .root
+      .handler(namespace)
+      .map(|handler| SharedImport { handler })
+      .ok_or_else(|| RuntimeError::ShareNotFound(namespace.to_owned()))
+  }
+
+  /// A [crate::ScopeComponent] that invokes operations on this runtime's root scope.
+  #[must_use]
+  pub fn to_component(&self) -> crate::ScopeComponent {
+    crate::ScopeComponent::new(self.root.clone())
+  }
+}
+
+/// A component one [Runtime] has explicitly shared with others, see [Runtime::share].
+#[derive(Clone)]
+#[must_use]
+pub struct SharedImport {
+  handler: NamespaceHandler,
+}
+
+impl std::fmt::Debug for SharedImport {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SharedImport")
+      .field("namespace", &self.handler.namespace())
+      .finish()
+  }
 }
 
 impl std::fmt::Debug for RuntimeBuilder {
@@ -174,6 +362,15 @@ impl std::fmt::Debug for RuntimeBuilder {
       .field("manifest", &self.manifest)
       .field("namespace", &self.namespace)
       .field("initial_components", &self.initial_components)
+      .field("chaos", &self.chaos)
+      .field("config_overrides", &self.config_overrides)
+      .field("receipts", &self.receipts)
+      .field("policy", &self.policy)
+      .field("type_check", &self.type_check)
+      .field("scan", &self.scan)
+      .field("coordinator", &self.coordinator)
+      .field("state", &self.state)
+      .field("durability", &self.durability)
       .finish()
   }
 }
@@ -210,6 +407,8 @@ impl RuntimeBuilder {
     builder
       .allow_latest(definition.allow_latest())
       .allowed_insecure(definition.insecure_registries().map(|v| v.to_vec()).unwrap_or_default())
+      .policy(definition.policy().cloned())
+      .type_check(definition.type_check().cloned())
       .manifest(definition)
   }
 
@@ -243,6 +442,31 @@ impl RuntimeBuilder {
     self
   }
 
+  /// Deep-merge `config` over the `with:` config of the import `id`, e.g. to point an app at a different backend
+  /// without editing its manifest. Overrides for the same import are merged in the order they're added.
+  pub fn add_config_override<T: Into<String>>(&mut self, id: T, config: RuntimeConfig) -> &mut Self {
+    let mut val = self.config_overrides.take().unwrap_or_default();
+    let id = id.into();
+    let merged = match val.remove(&id) {
+      Some(existing) => existing.merge(config),
+      None => config,
+    };
+    val.insert(id, merged);
+    self.config_overrides.replace(val);
+    self
+  }
+
+  /// Make a component shared by another [Runtime] available under `namespace`.
+  pub fn add_shared_component<T: Into<String>>(&mut self, namespace: T, shared: SharedImport) -> &mut Self {
+    let namespace = namespace.into();
+    self.add_native_component(Box::new(move |_| {
+      Ok(NamespaceHandler::new_from_shared(
+        namespace.clone(),
+        shared.handler.component().clone(),
+      ))
+    }))
+  }
+
   /// Constructs an instance of a Wick [Runtime].
   pub async fn build(self, seed: Option<Seed>) -> Result<Runtime> {
     let span = self.span.unwrap_or_else(tracing::Span::current);
@@ -252,6 +476,18 @@ impl RuntimeBuilder {
       seed.unwrap_or_else(new_seed),
       RuntimeInit {
         max_packet_size: self.max_packet_size.flatten(),
+        limits: self.limits.flatten(),
+        pool: self.pool.flatten(),
+        chaos: self.chaos.flatten(),
+        config_overrides: self.config_overrides.unwrap_or_default(),
+        receipts: self.receipts.flatten(),
+        policy: self.policy.flatten(),
+        type_check: self.type_check.flatten(),
+        scan: self.scan.flatten(),
+        coordinator: self.coordinator.flatten(),
+        state: self.state.flatten(),
+        kv: None,
+        durability: self.durability.flatten(),
         manifest: definition,
         allow_latest: self.allow_latest.unwrap_or_default(),
         allowed_insecure: self.allowed_insecure.unwrap_or_default(),
@@ -259,6 +495,8 @@ impl RuntimeBuilder {
         namespace: self.namespace.unwrap_or_default(),
         constraints: self.constraints.unwrap_or_default(),
         span,
+        metrics: RuntimeMetrics::default(),
+        scopes: ScopeRegistry::default(),
       },
     )
     .await
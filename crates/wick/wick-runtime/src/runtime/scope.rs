@@ -8,7 +8,6 @@ pub(crate) use child_init::{init_child, ChildInit};
 pub(crate) use component_registry::{ComponentFactory, ComponentRegistry};
 use flow_graph_interpreter::{HandlerMap, NamespaceHandler};
 pub(crate) use init::ScopeInit;
-use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use uuid::Uuid;
 use wick_packet::Entity;
@@ -18,7 +17,35 @@ use crate::dev::prelude::*;
 type ServiceMap = HashMap<Uuid, Scope>;
 type Result<T> = std::result::Result<T, ScopeError>;
 
-static SCOPE_REGISTRY: Lazy<Mutex<ServiceMap>> = Lazy::new(|| Mutex::new(HashMap::new()));
+/// The scopes of a single [crate::Runtime].
+///
+/// Each runtime resolves links and nested components against its own registry so components imported by one app
+/// can never be reached from another, even when both were built from the same seed.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ScopeRegistry(Arc<Mutex<ServiceMap>>);
+
+impl ScopeRegistry {
+  fn insert(&self, scope: Scope) {
+    self.0.lock().insert(scope.id(), scope);
+  }
+
+  pub(crate) fn get(&self, id: &Uuid) -> Option<Scope> {
+    self.0.lock().get(id).cloned()
+  }
+
+  pub(crate) fn all(&self) -> Vec<Scope> {
+    self.0.lock().values().cloned().collect()
+  }
+
+  pub(crate) fn find(&self, parent: Option<Uuid>, ns: &str) -> Option<Scope> {
+    self
+      .0
+      .lock()
+      .values()
+      .find(|scope| scope.inner.namespace == ns && scope.inner.parent == parent)
+      .cloned()
+  }
+}
 
 #[must_use]
 #[derive(Debug, Clone)]
@@ -55,17 +82,11 @@ impl Scope {
       }),
     };
 
-    let mut registry = SCOPE_REGISTRY.lock();
-    registry.insert(init.id, scope.clone());
+    init.scopes.insert(scope.clone());
 
     Ok(scope)
   }
 
-  pub(crate) fn for_id(id: &Uuid) -> Option<Self> {
-    let registry = SCOPE_REGISTRY.lock();
-    registry.get(id).cloned()
-  }
-
   pub(crate) fn id(&self) -> Uuid {
     self.inner.id
   }
@@ -83,16 +104,38 @@ impl Scope {
     self.inner.interpreter.render_dotviz(op).map_err(RuntimeError::DotViz)
   }
 
+  pub(crate) fn content_hash(&self, op: &str) -> std::result::Result<String, RuntimeError> {
+    self
+      .inner
+      .interpreter
+      .content_hash(op)
+      .map_err(RuntimeError::ContentHash)
+  }
+
   pub(crate) fn active_config(&self) -> &ComponentConfiguration {
     &self.inner.active_config
   }
 
-  pub(super) fn find(parent: Option<Uuid>, ns: &str) -> Option<Scope> {
-    let registry = SCOPE_REGISTRY.lock();
-    registry
-      .values()
-      .find(|scope| scope.inner.namespace == ns && scope.inner.parent == parent)
-      .cloned()
+  pub(crate) fn backlog(&self) -> usize {
+    self.inner.interpreter.backlog()
+  }
+
+  pub(crate) fn cancel(&self, tx_id: Uuid) {
+    self.inner.interpreter.cancel(tx_id);
+  }
+
+  pub(crate) async fn resume(&self) -> std::result::Result<Vec<(Uuid, PacketStream)>, RuntimeError> {
+    self.inner.interpreter.resume().await.map_err(RuntimeError::Resume)
+  }
+
+  pub(crate) fn component_namespaces(&self) -> Vec<String> {
+    let mut namespaces: Vec<_> = self.inner.interpreter.components().inner().keys().cloned().collect();
+    namespaces.sort();
+    namespaces
+  }
+
+  pub(super) fn handler(&self, ns: &str) -> Option<NamespaceHandler> {
+    self.inner.interpreter.components().get(ns).cloned()
   }
 
   pub(super) fn get_handler_signature(&self, ns: &str) -> Option<&ComponentSignature> {
@@ -112,6 +155,25 @@ impl InvocationHandler for Scope {
   fn get_signature(&self) -> std::result::Result<ComponentSignature, ComponentError> {
     let mut signature = self.inner.interpreter.signature().clone();
     signature.name = Some(self.inner.id.as_hyphenated().to_string());
+    // The interpreter derives operation signatures from the flow graph, so prefer the types the manifest declares for
+    // callers that validate or document data against them. Flows that don't declare their ports keep the derived ones.
+    let declared = self.inner.active_config.component().operation_signatures();
+    for op in &mut signature.operations {
+      if let Some(declared) = declared.iter().find(|d| d.name == op.name) {
+        for (fields, declared) in [(&mut op.inputs, &declared.inputs), (&mut op.outputs, &declared.outputs)] {
+          for field in fields.iter_mut() {
+            if let Some(declared) = declared.iter().find(|d| d.name == field.name) {
+              *field = declared.clone();
+            }
+          }
+        }
+      }
+    }
+    if signature.types.is_empty() {
+      if let Ok(types) = self.inner.active_config.types() {
+        signature.types = types;
+      }
+    }
 
     Ok(signature)
   }
@@ -1,12 +1,17 @@
-use flow_graph_interpreter::HandlerMap;
+use flow_component::KeyValue;
+use flow_graph_interpreter::{ChaosPolicy, HandlerMap};
 use seeded_random::Seed;
 use tracing::Span;
 use uuid::Uuid;
-use wick_config::config::ComponentConfiguration;
+use wick_config::config::components::{WasmLimits, WasmPool};
+use wick_config::config::{ComponentConfiguration, ComponentPolicy, TypeCheckConfig};
+use wick_config::FetchOptions;
 use wick_packet::RuntimeConfig;
 
-use super::{ComponentRegistry, Scope, ScopeInit};
+use super::{ComponentRegistry, Scope, ScopeInit, ScopeRegistry};
+use crate::metrics::RuntimeMetrics;
 use crate::runtime::RuntimeInit;
+use crate::scan::ScanPolicy;
 use crate::{BoxFuture, ScopeError};
 
 #[derive()]
@@ -18,8 +23,17 @@ pub(crate) struct ChildInit {
   pub(crate) root_config: Option<RuntimeConfig>,
   pub(crate) provided: Option<HandlerMap>,
   pub(crate) max_packet_size: Option<u32>,
+  pub(crate) limits: Option<WasmLimits>,
+  pub(crate) pool: Option<WasmPool>,
+  pub(crate) chaos: Option<ChaosPolicy>,
+  pub(crate) policy: Option<ComponentPolicy>,
+  pub(crate) type_check: Option<TypeCheckConfig>,
+  pub(crate) scan: Option<ScanPolicy>,
   #[allow(unused)]
   pub(crate) span: Span,
+  pub(crate) metrics: RuntimeMetrics,
+  pub(crate) kv: KeyValue,
+  pub(crate) scopes: ScopeRegistry,
 }
 
 impl std::fmt::Debug for ChildInit {
@@ -29,6 +43,13 @@ impl std::fmt::Debug for ChildInit {
       .field("runtime_id", &self.runtime_id)
       .field("allow_latest", &self.allow_latest)
       .field("max_packet_size", &self.max_packet_size)
+      .field("limits", &self.limits)
+      .field("pool", &self.pool)
+      .field("chaos", &self.chaos)
+      .field("policy", &self.policy)
+      .field("type_check", &self.type_check)
+      .field("scan", &self.scan)
+      .field("kv", &self.kv)
       .field("allowed_insecure", &self.allowed_insecure)
       .field("root_config", &self.root_config)
       .field("provided", &self.provided.as_ref().map(|p| p.inner().keys()))
@@ -36,12 +57,25 @@ impl std::fmt::Debug for ChildInit {
   }
 }
 
+impl ChildInit {
+  /// The options to fetch the artifacts of the child's components with.
+  pub(crate) fn fetch_options(&self) -> FetchOptions {
+    let mut options = FetchOptions::default();
+    options
+      .set_allow_latest(self.allow_latest)
+      .set_allow_insecure(self.allowed_insecure.clone());
+    options
+  }
+}
+
 pub(crate) fn init_child(
   uid: Uuid,
   manifest: ComponentConfiguration,
   namespace: String,
   opts: ChildInit,
   max_packet_size: Option<u32>,
+  limits: Option<WasmLimits>,
+  pool: Option<WasmPool>,
 ) -> BoxFuture<'static, Result<Scope, ScopeError>> {
   let child_span = info_span!(parent:&opts.span,"scope",id=%namespace);
   let mut components = ComponentRegistry::default();
@@ -65,6 +99,20 @@ pub(crate) fn init_child(
       span: child_span,
       initial_components: components,
       max_packet_size,
+      limits,
+      pool,
+      chaos: opts.chaos,
+      config_overrides: Default::default(),
+      receipts: None,
+      policy: opts.policy,
+      type_check: opts.type_check,
+      scan: opts.scan,
+      coordinator: None,
+      state: None,
+      kv: Some(opts.kv),
+      durability: None,
+      metrics: opts.metrics,
+      scopes: opts.scopes,
     };
 
     let init = ScopeInit::new_with_id(Some(opts.runtime_id), uid, opts.rng_seed, config);
@@ -55,6 +55,12 @@ pub enum ScopeError {
   #[error("constraint not met, {0}")]
   InvalidConstraint(ConstraintFailure),
 
+  #[error(transparent)]
+  PolicyViolation(#[from] crate::policy::PolicyViolation),
+
+  #[error(transparent)]
+  ScanViolation(#[from] crate::scan::ScanViolation),
+
   #[error("Internal error: {0}")]
   InternalError(InternalError),
 
@@ -1,16 +1,22 @@
 use std::path::Path;
 
+use flow_component::{KeyValue, KeyValueStore};
 use flow_graph_interpreter::error::InterpreterError;
-use flow_graph_interpreter::{HandlerMap, Interpreter};
-use wick_config::config::ComponentImplementation;
+use flow_graph_interpreter::{ChaosPolicy, CoercionRules, Durability, HandlerMap, Interpreter, InterpreterOptions};
+use wick_config::config::components::{WasmLimits, WasmPool};
+use wick_config::config::{ComponentImplementation, ComponentPolicy, TypeCheckConfig};
 use wick_packet::Entity;
 
 use super::utils::{assert_constraints, instantiate_import};
-use super::{generate_provides_handlers, ChildInit, ComponentRegistry};
+use super::{generate_provides_handlers, ChildInit, ComponentRegistry, ScopeRegistry};
 use crate::components::validation::expect_signature_match;
 use crate::components::{init_impl, make_link_callback};
 use crate::dev::prelude::*;
+use crate::metrics::{CostModel, RuntimeMetrics};
+use crate::receipts::{component_digest, ReceiptLog};
 use crate::runtime::{RuntimeConstraint, RuntimeInit};
+use crate::scan::ScanPolicy;
+use crate::state::MemoryStore;
 
 fn init_err(source: Option<&Path>) -> impl FnOnce(InterpreterError) -> ScopeError + '_ {
   move |e| ScopeError::InterpreterInit(source.map(Into::into), Box::new(e))
@@ -28,11 +34,24 @@ pub(crate) struct ScopeInit {
   pub(crate) initial_components: ComponentRegistry,
   pub(crate) span: Span,
   pub(crate) max_packet_size: Option<u32>,
+  pub(crate) limits: Option<WasmLimits>,
+  pub(crate) pool: Option<WasmPool>,
+  pub(crate) chaos: Option<ChaosPolicy>,
+  pub(crate) config_overrides: HashMap<String, RuntimeConfig>,
+  pub(crate) receipts: Option<ReceiptLog>,
+  pub(crate) policy: Option<ComponentPolicy>,
+  pub(crate) type_check: Option<TypeCheckConfig>,
+  pub(crate) scan: Option<ScanPolicy>,
+  pub(crate) metrics: RuntimeMetrics,
+  pub(crate) kv: KeyValue,
+  pub(crate) durability: Option<Durability>,
+  pub(crate) scopes: ScopeRegistry,
 }
 
 impl ScopeInit {
   pub(crate) fn new(seed: Seed, config: RuntimeInit) -> Self {
     let rng = Random::from_seed(seed);
+    let kv = scope_kv(config.kv, config.state, config.namespace.as_deref());
     Self {
       parent: None,
       id: rng.uuid(),
@@ -45,11 +64,24 @@ impl ScopeInit {
       initial_components: config.initial_components,
       span: config.span,
       max_packet_size: config.max_packet_size,
+      limits: config.limits,
+      pool: config.pool,
+      chaos: config.chaos,
+      config_overrides: config.config_overrides,
+      receipts: config.receipts,
+      policy: config.policy,
+      type_check: config.type_check,
+      scan: config.scan,
+      kv,
+      durability: config.durability,
+      metrics: config.metrics,
+      scopes: config.scopes,
     }
   }
 
   pub(crate) fn new_with_id(parent: Option<Uuid>, id: Uuid, seed: Seed, config: RuntimeInit) -> Self {
     let rng = Random::from_seed(seed);
+    let kv = scope_kv(config.kv, config.state, config.namespace.as_deref());
     Self {
       parent,
       id,
@@ -62,6 +94,18 @@ impl ScopeInit {
       initial_components: config.initial_components,
       span: config.span,
       max_packet_size: config.max_packet_size,
+      limits: config.limits,
+      pool: config.pool,
+      chaos: config.chaos,
+      config_overrides: config.config_overrides,
+      receipts: config.receipts,
+      policy: config.policy,
+      type_check: config.type_check,
+      scan: config.scan,
+      kv,
+      durability: config.durability,
+      metrics: config.metrics,
+      scopes: config.scopes,
     }
   }
 
@@ -70,6 +114,8 @@ impl ScopeInit {
     root_config: Option<RuntimeConfig>,
     provided: Option<HandlerMap>,
     max_packet_size: Option<u32>,
+    limits: Option<WasmLimits>,
+    pool: Option<WasmPool>,
   ) -> ChildInit {
     ChildInit {
       rng_seed: self.rng.seed(),
@@ -80,6 +126,15 @@ impl ScopeInit {
       provided,
       span: self.span.clone(),
       max_packet_size,
+      limits,
+      pool,
+      chaos: self.chaos.clone(),
+      policy: self.policy.clone(),
+      type_check: self.type_check.clone(),
+      scan: self.scan.clone(),
+      metrics: self.metrics.clone(),
+      kv: self.kv.clone(),
+      scopes: self.scopes.clone(),
     }
   }
 
@@ -115,7 +170,13 @@ impl ScopeInit {
       Some(config.extends())
     } else {
       // Instantiate non-composite component as an exposed, standalone component.
-      let child_init = self.child_init(self.manifest.root_config().cloned(), None, self.max_packet_size);
+      let child_init = self.child_init(
+        self.manifest.root_config().cloned(),
+        None,
+        self.max_packet_size,
+        self.limits,
+        self.pool,
+      );
 
       self
         .span
@@ -151,10 +212,30 @@ impl ScopeInit {
     extends: Option<&[String]>,
     mut components: HandlerMap,
   ) -> Result<HandlerMap, ScopeError> {
+    for id in self.config_overrides.keys() {
+      if !self.manifest.import().iter().any(|i| i.id() == id) {
+        return Err(ScopeError::RuntimeInit(
+          self.manifest.source().map(Into::into),
+          format!("Configuration override for import '{}' but no import has that id", id),
+        ));
+      }
+    }
     for binding in self.manifest.import() {
       let provided = generate_provides_handlers(binding.kind().provide(), &components)?;
-      let component_init = self.child_init(binding.kind().config().cloned(), Some(provided), self.max_packet_size);
-      if let Some(component) = instantiate_import(binding, component_init, self.manifest.resolver()).await? {
+      let config = match (
+        binding.kind().config().cloned(),
+        self.config_overrides.get(binding.id()),
+      ) {
+        (Some(config), Some(overrides)) => Some(config.merge(overrides.clone())),
+        (None, Some(overrides)) => Some(overrides.clone()),
+        (config, None) => config,
+      };
+      let component_init = self.child_init(config, Some(provided), self.max_packet_size, None, None);
+      if let Some(component) = instantiate_import(binding, component_init, self.manifest.resolver(), || {
+        self.manifest.types()
+      })
+      .await?
+      {
         if let Some(extends) = extends {
           if extends.iter().any(|n| n == component.namespace()) {
             self.span.in_scope(|| {
@@ -176,17 +257,73 @@ impl ScopeInit {
       flow_graph_interpreter::graph::from_def(&mut self.manifest, &components)
         .map_err(|e| ScopeError::Graph(self.manifest.source().map(Into::into), Box::new(e)))
     })?;
+    let listener = self.receipts.as_ref().map(|receipts| {
+      let digests = components
+        .component_signatures()
+        .iter()
+        .map(|(ns, signature)| (ns.clone(), component_digest(signature)))
+        .collect();
+      receipts.listener(digests)
+    });
+    let mut costs = cost_model(components.component_signatures().iter());
 
     let mut interpreter = flow_graph_interpreter::Interpreter::new(
       graph,
       Some(self.namespace()),
       Some(components),
-      make_link_callback(self.id),
+      make_link_callback(self.scopes.clone(), self.id, self.kv.clone()),
       self.manifest.root_config(),
+      self.type_check.as_ref().map(coercion_rules),
       &self.span,
     )
     .map_err(init_err(self.manifest.source()))?;
-    interpreter.start(None, None).await;
+    // flows invoke the scope's own operations through the `self` namespace.
+    costs.extend(cost_model([(&"self".to_owned(), interpreter.signature())]));
+    let options = (self.chaos.is_some() || listener.is_some() || self.durability.is_some()).then(|| {
+      let mut options = InterpreterOptions::default();
+      options.chaos = self.chaos.clone();
+      options.event_listener = listener;
+      options.durability = self.durability.clone();
+      options
+    });
+    interpreter
+      .start(options, Some(self.metrics.observer(self.namespace(), costs)))
+      .await;
     Ok(interpreter)
   }
 }
+
+/// The state components in the scope keep: the parent scope's for child scopes, or the app's own, in `state` or
+/// in memory, for the root scope.
+fn scope_kv(kv: Option<KeyValue>, state: Option<Arc<dyn KeyValueStore>>, namespace: Option<&str>) -> KeyValue {
+  kv.unwrap_or_else(|| {
+    let state = state.unwrap_or_else(|| Arc::new(MemoryStore::default()));
+    KeyValue::new(state, namespace.unwrap_or("__local__"))
+  })
+}
+
+/// The costs the operations of each namespace's component declare.
+fn cost_model<'a, T>(signatures: T) -> CostModel
+where
+  T: IntoIterator<Item = (&'a String, &'a ComponentSignature)>,
+{
+  signatures
+    .into_iter()
+    .flat_map(|(ns, signature)| {
+      signature
+        .operations
+        .iter()
+        .filter_map(move |op| Some(((ns.clone(), op.name().to_owned()), op.cost()?)))
+    })
+    .collect()
+}
+
+/// The rules a scope's flows are type checked with: the defaults, plus the conversions the host allows.
+fn coercion_rules(config: &TypeCheckConfig) -> CoercionRules {
+  config
+    .coercions()
+    .iter()
+    .fold(CoercionRules::default().strict(config.strict()), |rules, coercion| {
+      rules.allow(coercion.from().clone(), coercion.to().clone())
+    })
+}
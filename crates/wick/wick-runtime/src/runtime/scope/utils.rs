@@ -4,9 +4,11 @@ use wick_config::Resolver;
 
 use super::error::ConstraintFailure;
 use super::ChildInit;
-use crate::components::{init_hlc_component, init_manifest_component, init_wasmrs_component};
+use crate::components::{init_hlc_component, init_manifest_component, init_remote_component, init_wasmrs_component};
 use crate::dev::prelude::*;
+use crate::policy::{self, ComponentIdentity};
 use crate::runtime::RuntimeConstraint;
+use crate::scan::{self, Artifact};
 
 pub(super) fn assert_constraints(constraints: &[RuntimeConstraint], components: &HandlerMap) -> Result<(), ScopeError> {
   for constraint in constraints {
@@ -47,6 +49,7 @@ pub(crate) async fn instantiate_import(
   binding: &config::Binding<config::ImportDefinition>,
   opts: ChildInit,
   resolver: Box<Resolver>,
+  types: impl FnOnce() -> Result<Vec<TypeDefinition>, wick_config::Error> + Send,
 ) -> Result<Option<NamespaceHandler>, ScopeError> {
   opts.span.in_scope(|| {
     debug!(id = binding.id(), ?opts, "init options");
@@ -58,7 +61,7 @@ pub(crate) async fn instantiate_import(
   let config::ImportDefinition::Component(config) = binding.kind() else {
     return Ok(None);
   };
-  let result = instantiate_imported_component(id, config, opts, resolver).await;
+  let result = instantiate_imported_component(id, config, opts, resolver, types).await;
   let end = std::time::Instant::now();
   span.in_scope(|| {
     info!(id = binding.id(), duration_ms = %end.duration_since(start).as_millis(), "initialized");
@@ -72,26 +75,41 @@ pub(crate) async fn instantiate_imported_component(
   kind: &ComponentDefinition,
   opts: ChildInit,
   resolver: Box<Resolver>,
+  types: impl FnOnce() -> Result<Vec<TypeDefinition>, wick_config::Error> + Send,
 ) -> Result<Option<NamespaceHandler>, ScopeError> {
   match kind {
     #[allow(deprecated)]
-    config::ComponentDefinition::Wasm(def) => Ok(Some(
-      init_wasmrs_component(
-        def.reference(),
-        id,
-        opts,
-        None,
-        None,
-        Default::default(),
-        Default::default(),
-      )
-      .await?,
-    )),
+    config::ComponentDefinition::Wasm(def) => {
+      if opts.policy.is_some() || opts.scan.is_some() {
+        let identity =
+          ComponentIdentity::fetch(&id, def.reference(), Some(def.reference()), &opts.fetch_options()).await?;
+        if let Some(policy) = &opts.policy {
+          policy::check(policy, &identity)?;
+        }
+        if let Some(scan) = &opts.scan {
+          scan
+            .check(&Artifact::new(identity, scan::artifact_path(def.reference())))
+            .await?;
+        }
+      }
+      Ok(Some(
+        init_wasmrs_component(
+          def.reference(),
+          id,
+          opts,
+          None,
+          None,
+          Default::default(),
+          Default::default(),
+        )
+        .await?,
+      ))
+    }
     config::ComponentDefinition::Manifest(def) => Ok(Some(init_manifest_component(def, id, opts).await?)),
     config::ComponentDefinition::Reference(_) => unreachable!(),
-    config::ComponentDefinition::GrpcUrl(_) => todo!(), // CollectionKind::GrpcUrl(v) => initialize_grpc_collection(v, namespace).await,
+    config::ComponentDefinition::GrpcUrl(def) => Ok(Some(init_remote_component(def, id, opts).await?)),
     config::ComponentDefinition::HighLevelComponent(hlc) => {
-      init_hlc_component(id, opts.root_config.clone(), None, hlc.clone(), resolver)
+      init_hlc_component(id, opts.root_config.clone(), None, hlc.clone(), resolver, types, &opts.metrics)
         .await
         .map(Some)
     }
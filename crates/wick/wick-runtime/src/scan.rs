@@ -0,0 +1,369 @@
+//! Scans of the artifacts imported components are loaded from, run after they're fetched and before they're
+//! instantiated so platform operators can gate third-party components.
+//!
+//! A [ScanPolicy] runs every [Scanner] it's given over each artifact and blocks or warns about what they find.
+//! [KnownBadDigests] and [CapabilityLimits] are built in, anything else, like a malware scanner or a vulnerability
+//! database, can be plugged in by implementing [Scanner].
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use normpath::PathExt;
+use wick_config::config::{ComponentConfiguration, ComponentImplementation};
+
+use crate::policy::ComponentIdentity;
+
+/// Something a component asks the host for when it's instantiated.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Capability {
+  /// Access to the host directory `dir`, mounted at `path` in the component.
+  Volume {
+    /// The path the directory is mounted at.
+    path: String,
+    /// The host directory.
+    dir: PathBuf,
+  },
+}
+
+/// The artifact of a component that's about to be instantiated.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct Artifact {
+  /// The component the artifact is for.
+  pub component: ComponentIdentity,
+  /// Where the artifact is on disk, once fetched.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub path: Option<PathBuf>,
+  /// What the component asks the host for.
+  pub capabilities: Vec<Capability>,
+}
+
+impl Artifact {
+  /// The artifact of `component`, fetched to `path`.
+  #[must_use]
+  pub const fn new(component: ComponentIdentity, path: Option<PathBuf>) -> Self {
+    Self {
+      component,
+      path,
+      capabilities: Vec::new(),
+    }
+  }
+
+  /// Set the capabilities the component asks for.
+  #[must_use]
+  pub fn with_capabilities(mut self, capabilities: Vec<Capability>) -> Self {
+    self.capabilities = capabilities;
+    self
+  }
+}
+
+/// Inspects artifacts before they're instantiated, returning a description of each problem found.
+#[async_trait::async_trait]
+pub trait Scanner: std::fmt::Debug + Send + Sync {
+  /// The name findings are reported under.
+  fn name(&self) -> &str;
+
+  /// Scan `artifact`. An error means the artifact couldn't be scanned and is treated like a finding.
+  async fn scan(&self, artifact: &Artifact) -> Result<Vec<String>, String>;
+}
+
+/// Whether a [ScanPolicy] refuses artifacts with findings or only logs them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub enum ScanAction {
+  /// Refuse to instantiate the component.
+  #[default]
+  Block,
+  /// Log the findings and instantiate the component anyway.
+  Warn,
+}
+
+/// A problem a [Scanner] found with an artifact.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct Finding {
+  /// The name of the scanner that found it.
+  pub scanner: String,
+  /// What the scanner found.
+  pub message: String,
+}
+
+impl std::fmt::Display for Finding {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}: {}", self.scanner, self.message)
+  }
+}
+
+/// A component a [ScanPolicy] refused to instantiate.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct ScanViolation {
+  /// The component that was refused.
+  pub component: Box<ComponentIdentity>,
+  /// What the scanners found.
+  pub findings: Vec<Finding>,
+}
+
+impl std::fmt::Display for ScanViolation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "scans refused component '{}' from {}: ",
+      self.component.namespace, self.component.reference
+    )?;
+    let findings: Vec<_> = self.findings.iter().map(ToString::to_string).collect();
+    f.write_str(&findings.join("; "))
+  }
+}
+
+/// The scanners every imported component's artifact is checked by, and what to do about their findings.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct ScanPolicy {
+  scanners: Vec<Arc<dyn Scanner>>,
+  action: ScanAction,
+}
+
+impl ScanPolicy {
+  /// A policy that takes `action` on findings.
+  pub const fn new(action: ScanAction) -> Self {
+    Self {
+      scanners: Vec::new(),
+      action,
+    }
+  }
+
+  /// Add a scanner to run over every artifact.
+  pub fn with_scanner<T: Scanner + 'static>(mut self, scanner: T) -> Self {
+    self.scanners.push(Arc::new(scanner));
+    self
+  }
+
+  /// Run every scanner over `artifact`, failing if any found something and the policy blocks.
+  pub async fn check(&self, artifact: &Artifact) -> Result<(), ScanViolation> {
+    let mut findings = Vec::new();
+    for scanner in &self.scanners {
+      let messages = match scanner.scan(artifact).await {
+        Ok(messages) => messages,
+        Err(e) => vec![format!("scan failed: {}", e)],
+      };
+      findings.extend(messages.into_iter().map(|message| Finding {
+        scanner: scanner.name().to_owned(),
+        message,
+      }));
+    }
+    if findings.is_empty() {
+      return Ok(());
+    }
+    let violation = ScanViolation {
+      component: Box::new(artifact.component.clone()),
+      findings,
+    };
+    match self.action {
+      ScanAction::Block => Err(violation),
+      ScanAction::Warn => {
+        warn!(component = %artifact.component.namespace, "{}", violation);
+        Ok(())
+      }
+    }
+  }
+}
+
+/// Flags artifacts whose digest is on a list of known-bad digests.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct KnownBadDigests {
+  digests: HashSet<String>,
+}
+
+impl KnownBadDigests {
+  /// Flag artifacts with any of `digests`, as `sha256:<hex>`.
+  pub fn new<T: IntoIterator<Item = String>>(digests: T) -> Self {
+    Self {
+      digests: digests.into_iter().map(|d| d.to_ascii_lowercase()).collect(),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl Scanner for KnownBadDigests {
+  fn name(&self) -> &str {
+    "known-bad-digests"
+  }
+
+  async fn scan(&self, artifact: &Artifact) -> Result<Vec<String>, String> {
+    Ok(
+      artifact
+        .component
+        .digest
+        .iter()
+        .filter(|digest| self.digests.contains(&digest.to_ascii_lowercase()))
+        .map(|digest| format!("digest {} is known to be bad", digest))
+        .collect(),
+    )
+  }
+}
+
+/// Flags components that ask for more than operators allow.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct CapabilityLimits {
+  allowed_dirs: Vec<PathBuf>,
+}
+
+impl CapabilityLimits {
+  /// Only allow components to mount host directories inside one of `allowed_dirs`. Both are normalized before
+  /// they're compared, so `..` and symlinks can't lead a volume out of an allowed directory.
+  pub const fn new(allowed_dirs: Vec<PathBuf>) -> Self {
+    Self { allowed_dirs }
+  }
+}
+
+#[async_trait::async_trait]
+impl Scanner for CapabilityLimits {
+  fn name(&self) -> &str {
+    "capability-limits"
+  }
+
+  async fn scan(&self, artifact: &Artifact) -> Result<Vec<String>, String> {
+    Ok(
+      artifact
+        .capabilities
+        .iter()
+        .filter_map(|capability| match capability {
+          Capability::Volume { path, dir } => {
+            let Ok(dir) = dir.normalize() else {
+              return Some(format!("volume {} mounts {}, which can't be resolved", path, dir.display()));
+            };
+            let allowed = self
+              .allowed_dirs
+              .iter()
+              .filter_map(|allowed| allowed.normalize().ok())
+              .any(|allowed| dir.starts_with(allowed));
+            (!allowed).then(|| {
+              format!(
+                "volume {} mounts {}, outside the allowed directories",
+                path,
+                dir.as_path().display()
+              )
+            })
+          }
+        })
+        .collect(),
+    )
+  }
+}
+
+/// The capabilities a component's manifest asks for. Volumes whose resources don't resolve are left out, they fail
+/// when the component is instantiated.
+pub(crate) fn capabilities(manifest: &ComponentConfiguration) -> Vec<Capability> {
+  let ComponentImplementation::WasmRs(wasm) = manifest.component() else {
+    return Vec::new();
+  };
+  let resolver = manifest.resolver();
+  wasm
+    .volumes()
+    .iter()
+    .filter_map(|volume| {
+      let resource = (resolver)(volume.resource()).ok()?.try_resource().ok()?;
+      let dir = resource.try_volume().ok()?.path().ok()?;
+      Some(Capability::Volume {
+        path: volume.path().to_owned(),
+        dir,
+      })
+    })
+    .collect()
+}
+
+/// Where the artifact at `reference` was fetched to.
+pub(crate) fn artifact_path(reference: &wick_config::AssetReference) -> Option<PathBuf> {
+  reference.path().ok().filter(|path| path.exists())
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  fn artifact(digest: &str, dirs: &[&str]) -> Artifact {
+    let mut component = ComponentIdentity::new("http", "registry.candle.dev/common/http-client:0.1.0");
+    component.digest = Some(digest.to_owned());
+    Artifact::new(component, None).with_capabilities(
+      dirs
+        .iter()
+        .map(|dir| Capability::Volume {
+          path: "/data".to_owned(),
+          dir: PathBuf::from(dir),
+        })
+        .collect(),
+    )
+  }
+
+  #[tokio::test]
+  async fn test_scanners() -> Result<()> {
+    let bad = KnownBadDigests::new(vec!["sha256:BAD".to_owned()]);
+    assert_eq!(bad.scan(&artifact("sha256:bad", &[])).await.unwrap().len(), 1);
+    assert!(bad.scan(&artifact("sha256:good", &[])).await.unwrap().is_empty());
+
+    let root = std::env::temp_dir().join("wick_runtime_test_scanners");
+    let data = root.join("srv/data");
+    std::fs::create_dir_all(data.join("app"))?;
+    std::fs::create_dir_all(root.join("etc"))?;
+    let dir = |path: &str| data.join(path).to_string_lossy().into_owned();
+
+    let limits = CapabilityLimits::new(vec![data.clone()]);
+    assert!(limits
+      .scan(&artifact("sha256:good", &[&dir("app")]))
+      .await
+      .unwrap()
+      .is_empty());
+    assert!(limits
+      .scan(&artifact("sha256:good", &[&dir("app/..")]))
+      .await
+      .unwrap()
+      .is_empty());
+    assert_eq!(
+      limits.scan(&artifact("sha256:good", &["/etc"])).await.unwrap().len(),
+      1,
+      "volumes outside the allowed directories should be flagged"
+    );
+    assert_eq!(
+      limits
+        .scan(&artifact("sha256:good", &[&dir("../../etc")]))
+        .await
+        .unwrap()
+        .len(),
+      1,
+      "volumes that climb out of an allowed directory should be flagged"
+    );
+    assert_eq!(
+      limits
+        .scan(&artifact("sha256:good", &[&dir("missing")]))
+        .await
+        .unwrap()
+        .len(),
+      1,
+      "volumes that can't be resolved should be flagged"
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_policy() -> Result<()> {
+    let blocking = ScanPolicy::new(ScanAction::Block).with_scanner(KnownBadDigests::new(vec!["sha256:bad".to_owned()]));
+    assert!(blocking.check(&artifact("sha256:good", &[])).await.is_ok());
+    let violation = blocking.check(&artifact("sha256:bad", &[])).await.unwrap_err();
+    assert_eq!(violation.findings[0].scanner, "known-bad-digests");
+
+    let warning = ScanPolicy::new(ScanAction::Warn).with_scanner(KnownBadDigests::new(vec!["sha256:bad".to_owned()]));
+    assert!(
+      warning.check(&artifact("sha256:bad", &[])).await.is_ok(),
+      "warning policies should let components through"
+    );
+    Ok(())
+  }
+}
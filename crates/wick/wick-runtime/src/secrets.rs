@@ -0,0 +1,375 @@
+//! Secrets read from backends like HashiCorp Vault or AWS Secrets Manager, kept up to date while an app runs.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::RwLock;
+use serde_json::Value;
+use wick_config::config::{Secret, SecretProvider, SecretResource};
+
+mod aws;
+mod vault;
+pub use aws::AwsSecretsManager;
+pub use vault::Vault;
+
+/// The scheme of a reference to a secret in a backend.
+pub const SCHEME: &str = "secret://";
+
+/// How long a command that prints a secret can run before it's killed.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Errors reading a secret. They never include the secret itself.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum SecretsError {
+  #[error("invalid secret reference '{0}', expected secret://<backend>/<path>#<key>")]
+  InvalidReference(String),
+
+  #[error("no secrets backend named '{0}' is configured")]
+  UnknownBackend(String),
+
+  #[error("secret '{0}' has no key '{1}'")]
+  MissingKey(String, String),
+
+  #[error("secret '{0}' holds several values, pick one with #<key>")]
+  AmbiguousKey(String),
+
+  #[error("could not read secret '{path}' from {backend}: {reason}")]
+  Backend {
+    backend: &'static str,
+    path: String,
+    reason: String,
+  },
+
+  #[error(transparent)]
+  Config(#[from] wick_config::Error),
+}
+
+/// A reference to a secret in a backend, written as `secret://<backend>/<path>#<key>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SecretRef {
+  /// The name of the backend the secret is in.
+  pub backend: String,
+  /// The path of the secret in the backend.
+  pub path: String,
+  /// The key of the value to read when the secret holds several.
+  pub key: Option<String>,
+}
+
+impl FromStr for SecretRef {
+  type Err = SecretsError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || SecretsError::InvalidReference(s.to_owned());
+    let rest = s.strip_prefix(SCHEME).ok_or_else(invalid)?;
+    let (backend, rest) = rest.split_once('/').ok_or_else(invalid)?;
+    let (path, key) = rest
+      .split_once('#')
+      .map_or((rest, None), |(path, key)| (path, Some(key)));
+    if backend.is_empty() || path.is_empty() || key.map_or(false, str::is_empty) {
+      return Err(invalid());
+    }
+    Ok(Self {
+      backend: backend.to_owned(),
+      path: path.to_owned(),
+      key: key.map(ToOwned::to_owned),
+    })
+  }
+}
+
+impl std::fmt::Display for SecretRef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}{}/{}", SCHEME, self.backend, self.path)?;
+    if let Some(key) = &self.key {
+      write!(f, "#{}", key)?;
+    }
+    Ok(())
+  }
+}
+
+/// A store of secrets the runtime can read from.
+#[async_trait::async_trait]
+pub trait SecretsBackend: std::fmt::Debug + Send + Sync {
+  /// Read the secret at `path`, or the value of `key` in it when the secret holds several.
+  async fn read(&self, path: &str, key: Option<&str>) -> Result<Secret, SecretsError>;
+}
+
+/// The secrets backends an app's `secret://` references are resolved against, by name.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct SecretsBackends {
+  backends: HashMap<String, Arc<dyn SecretsBackend>>,
+}
+
+impl SecretsBackends {
+  /// Create an empty set of backends.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The built-in backends configured in the environment: `vault` when `VAULT_ADDR` and `VAULT_TOKEN` are set, and
+  /// `aws` when a region and AWS credentials are.
+  pub fn from_env() -> Self {
+    let mut backends = Self::new();
+    if let Some(vault) = Vault::from_env() {
+      backends.register("vault", Arc::new(vault));
+    }
+    if let Some(aws) = AwsSecretsManager::from_env() {
+      backends.register("aws", Arc::new(aws));
+    }
+    backends
+  }
+
+  /// Resolve references to the backend `name` with `backend`, replacing any backend already registered as `name`.
+  pub fn register<T: Into<String>>(&mut self, name: T, backend: Arc<dyn SecretsBackend>) {
+    self.backends.insert(name.into(), backend);
+  }
+
+  /// Read the secret `reference` points to.
+  pub async fn read(&self, reference: &SecretRef) -> Result<Secret, SecretsError> {
+    let backend = self
+      .backends
+      .get(&reference.backend)
+      .ok_or_else(|| SecretsError::UnknownBackend(reference.backend.clone()))?;
+    backend.read(&reference.path, reference.key.as_deref()).await
+  }
+
+  /// Read the secret a resource points to, from a backend or from wherever else its provider reads it.
+  pub async fn reveal(&self, resource: &SecretResource) -> Result<Secret, SecretsError> {
+    let source = || {
+      resource
+        .source()
+        .value()
+        .ok_or_else(|| wick_config::Error::UnrenderedConfiguration(format!("{:?}", resource.source())))
+    };
+    match resource.provider() {
+      SecretProvider::Backend => self.read(&source()?.parse()?).await,
+      SecretProvider::Command => run_command(source()?).await,
+      _ => Ok(resource.reveal()?),
+    }
+  }
+
+  /// Read the secret a resource points to and, if it has a `refresh` interval, read it again on that interval until
+  /// the returned [SharedSecret] is dropped. A failed refresh keeps the last value.
+  pub async fn watch(&self, resource: &SecretResource) -> Result<SharedSecret, SecretsError> {
+    let secret = SharedSecret::new(self.reveal(resource).await?);
+    let Some(refresh) = resource.refresh().filter(|secs| *secs > 0) else {
+      return Ok(secret);
+    };
+
+    let weak = Arc::downgrade(&secret.0);
+    let backends = self.clone();
+    let resource = resource.clone();
+    tokio::spawn(async move {
+      let mut interval = tokio::time::interval(Duration::from_secs(refresh));
+      // the first tick completes immediately and the secret was just read.
+      interval.tick().await;
+      loop {
+        interval.tick().await;
+        let Some(current) = weak.upgrade() else {
+          break;
+        };
+        match backends.reveal(&resource).await {
+          Ok(value) => *current.write() = value,
+          Err(error) => warn!(%error, "could not refresh secret, keeping its last value"),
+        }
+      }
+    });
+    Ok(secret)
+  }
+}
+
+/// A secret that may be read again while an app runs. Clones share the latest value.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct SharedSecret(Arc<RwLock<Secret>>);
+
+impl SharedSecret {
+  /// Share a secret that was just read.
+  pub fn new(secret: Secret) -> Self {
+    Self(Arc::new(RwLock::new(secret)))
+  }
+
+  /// The latest value of the secret.
+  #[must_use]
+  pub fn get(&self) -> Secret {
+    self.0.read().clone()
+  }
+}
+
+impl PartialEq for SharedSecret {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+/// What `command` prints to stdout, trimmed. It's killed if it runs longer than [COMMAND_TIMEOUT].
+async fn run_command(command: &str) -> Result<Secret, SecretsError> {
+  let error = |reason: String| wick_config::Error::SecretUnavailable {
+    provider: SecretProvider::Command,
+    name: command.to_owned(),
+    reason,
+  };
+  let mut args = command.split_whitespace();
+  let program = args.next().ok_or_else(|| error("no command to run".to_owned()))?;
+  let output = tokio::process::Command::new(program)
+    .args(args)
+    .stdin(std::process::Stdio::null())
+    .stderr(std::process::Stdio::null())
+    .kill_on_drop(true)
+    .output();
+  let output = tokio::time::timeout(COMMAND_TIMEOUT, output)
+    .await
+    .map_err(|_| error(format!("command didn't finish within {:?}", COMMAND_TIMEOUT)))?
+    .map_err(|e| error(e.to_string()))?;
+  // stderr isn't reported, it's as likely as stdout to have the secret in it.
+  if !output.status.success() {
+    return Err(error(format!("command exited with {}", output.status)).into());
+  }
+  let value = String::from_utf8(output.stdout).map_err(|_| error("command printed invalid UTF-8".to_owned()))?;
+  Ok(Secret::new(value.trim()))
+}
+
+/// The value of `key` in a secret that holds several, or its only value when no key is given.
+fn pick(path: &str, values: &Value, key: Option<&str>) -> Result<Secret, SecretsError> {
+  let value = match (values, key) {
+    (Value::Object(map), Some(key)) => map
+      .get(key)
+      .ok_or_else(|| SecretsError::MissingKey(path.to_owned(), key.to_owned()))?,
+    (Value::Object(map), None) if map.len() == 1 => map.values().next().unwrap_or(&Value::Null),
+    (Value::Object(_), None) => return Err(SecretsError::AmbiguousKey(path.to_owned())),
+    (_, Some(key)) => return Err(SecretsError::MissingKey(path.to_owned(), key.to_owned())),
+    (value, None) => value,
+  };
+  Ok(Secret::new(match value {
+    Value::String(s) => s.clone(),
+    other => other.to_string(),
+  }))
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::atomic::{AtomicU32, Ordering};
+
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_secret_ref() -> Result<()> {
+    let reference: SecretRef = "secret://vault/secret/data/app#password".parse()?;
+    assert_eq!(
+      reference,
+      SecretRef {
+        backend: "vault".to_owned(),
+        path: "secret/data/app".to_owned(),
+        key: Some("password".to_owned()),
+      }
+    );
+    assert_eq!(reference.to_string(), "secret://vault/secret/data/app#password");
+
+    let reference: SecretRef = "secret://aws/prod/db".parse()?;
+    assert_eq!(reference.key, None, "a reference without a key should have no key");
+
+    for invalid in [
+      "vault/app#key",
+      "secret://vault",
+      "secret:///app",
+      "secret://vault/app#",
+    ] {
+      assert!(invalid.parse::<SecretRef>().is_err(), "'{}' should be invalid", invalid);
+    }
+    Ok(())
+  }
+
+  #[test]
+  fn test_pick() -> Result<()> {
+    let values = json!({"user": "admin", "port": 5432});
+    assert_eq!(pick("db", &values, Some("user"))?.expose(), "admin");
+    assert_eq!(pick("db", &values, Some("port"))?.expose(), "5432");
+    assert!(
+      pick("db", &values, Some("password")).is_err(),
+      "missing keys should fail"
+    );
+    assert!(
+      pick("db", &values, None).is_err(),
+      "a key is needed to pick one of several values"
+    );
+    assert_eq!(pick("db", &json!({"only": "one"}), None)?.expose(), "one");
+    assert_eq!(pick("db", &json!("plain"), None)?.expose(), "plain");
+    Ok(())
+  }
+
+  #[derive(Debug, Default)]
+  struct Counter(AtomicU32);
+
+  #[async_trait::async_trait]
+  impl SecretsBackend for Counter {
+    async fn read(&self, path: &str, _key: Option<&str>) -> Result<Secret, SecretsError> {
+      let n = self.0.fetch_add(1, Ordering::SeqCst) + 1;
+      Ok(Secret::new(format!("{}-{}", path, n)))
+    }
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_watch() -> Result<()> {
+    let mut backends = SecretsBackends::new();
+    backends.register("counter", Arc::new(Counter::default()));
+
+    let resource = SecretResource::new(SecretProvider::Backend, "secret://counter/token");
+    let once = backends.watch(&resource).await?;
+    assert_eq!(once.get().expose(), "token-1");
+
+    let refreshed = backends.watch(&resource.with_refresh(Some(60))).await?;
+    assert_eq!(refreshed.get().expose(), "token-2");
+    tokio::time::sleep(Duration::from_secs(61)).await;
+    assert_eq!(
+      refreshed.get().expose(),
+      "token-3",
+      "the secret should have been read again"
+    );
+    assert_eq!(
+      once.get().expose(),
+      "token-1",
+      "secrets without a refresh should not change"
+    );
+
+    let missing = SecretResource::new(SecretProvider::Backend, "secret://vault/token");
+    assert!(
+      matches!(backends.watch(&missing).await, Err(SecretsError::UnknownBackend(_))),
+      "unregistered backends should fail"
+    );
+    Ok(())
+  }
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn test_command() -> Result<()> {
+    let backends = SecretsBackends::new();
+    let echo = SecretResource::new(SecretProvider::Command, "echo hunter2");
+    assert_eq!(backends.reveal(&echo).await?.expose(), "hunter2");
+
+    let failing = SecretResource::new(SecretProvider::Command, "ls /wick-secrets-test-nonexistent");
+    let error = backends.reveal(&failing).await.unwrap_err().to_string();
+    assert!(error.contains("exited with"), "unexpected error: {}", error);
+    assert!(
+      !error.contains("No such file"),
+      "stderr should not be in the error: {}",
+      error
+    );
+    Ok(())
+  }
+
+  #[cfg(unix)]
+  #[tokio::test(start_paused = true)]
+  async fn test_command_timeout() -> Result<()> {
+    let sleep = SecretResource::new(SecretProvider::Command, "sleep 30");
+    let error = SecretsBackends::new().reveal(&sleep).await.unwrap_err().to_string();
+    assert!(error.contains("didn't finish"), "unexpected error: {}", error);
+    Ok(())
+  }
+}
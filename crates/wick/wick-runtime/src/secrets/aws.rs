@@ -0,0 +1,210 @@
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use wick_config::config::Secret;
+
+use super::{pick, SecretsBackend, SecretsError};
+
+const NAME: &str = "aws";
+const SERVICE: &str = "secretsmanager";
+const TARGET: &str = "secretsmanager.GetSecretValue";
+const CONTENT_TYPE: &str = "application/x-amz-json-1.1";
+
+/// Reads secrets from AWS Secrets Manager. A reference's path is the secret's name or ARN, and its key picks a value
+/// out of a secret stored as a JSON object.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct AwsSecretsManager {
+  region: String,
+  access_key_id: String,
+  secret_access_key: Secret,
+  session_token: Option<Secret>,
+  client: reqwest::Client,
+}
+
+impl AwsSecretsManager {
+  /// Read secrets from `region` with the given credentials.
+  pub fn new<T: Into<String>, K: Into<String>>(
+    region: T,
+    access_key_id: K,
+    secret_access_key: Secret,
+    session_token: Option<Secret>,
+  ) -> Self {
+    Self {
+      region: region.into(),
+      access_key_id: access_key_id.into(),
+      secret_access_key,
+      session_token,
+      client: reqwest::Client::new(),
+    }
+  }
+
+  /// A client for `AWS_REGION` (or `AWS_DEFAULT_REGION`) using `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and
+  /// `AWS_SESSION_TOKEN`, if a region and keys are set.
+  #[must_use]
+  pub fn from_env() -> Option<Self> {
+    let region = std::env::var("AWS_REGION")
+      .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+      .ok()?;
+    let access_key_id = std::env::var("AWS_ACCESS_KEY_ID").ok()?;
+    let secret_access_key = std::env::var("AWS_SECRET_ACCESS_KEY").ok()?;
+    let session_token = std::env::var("AWS_SESSION_TOKEN").ok().map(Secret::new);
+    Some(Self::new(
+      region,
+      access_key_id,
+      Secret::new(secret_access_key),
+      session_token,
+    ))
+  }
+
+  fn host(&self) -> String {
+    format!("{}.{}.amazonaws.com", SERVICE, self.region)
+  }
+
+  /// The headers of a signed `GetSecretValue` request with `body`, sorted by name as Signature Version 4 requires.
+  fn signed_headers(&self, body: &str, now: DateTime<Utc>) -> Vec<(&'static str, String)> {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+
+    let mut headers = vec![
+      ("content-type", CONTENT_TYPE.to_owned()),
+      ("host", self.host()),
+      ("x-amz-date", amz_date.clone()),
+    ];
+    if let Some(token) = &self.session_token {
+      headers.push(("x-amz-security-token", token.expose().to_owned()));
+    }
+    headers.push(("x-amz-target", TARGET.to_owned()));
+
+    let canonical_headers: String = headers.iter().map(|(k, v)| format!("{}:{}\n", k, v)).collect();
+    let signed_headers = headers.iter().map(|(k, _)| *k).collect::<Vec<_>>().join(";");
+    let canonical_request = format!(
+      "POST\n/\n\n{}\n{}\n{}",
+      canonical_headers,
+      signed_headers,
+      hex(&Sha256::digest(body.as_bytes()))
+    );
+
+    let scope = format!("{}/{}/{}/aws4_request", date, self.region, SERVICE);
+    let string_to_sign = format!(
+      "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+      amz_date,
+      scope,
+      hex(&Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = format!("AWS4{}", self.secret_access_key.expose());
+    let key = hmac(key.as_bytes(), date.as_bytes());
+    let key = hmac(&key, self.region.as_bytes());
+    let key = hmac(&key, SERVICE.as_bytes());
+    let key = hmac(&key, b"aws4_request");
+    let signature = hex(&hmac(&key, string_to_sign.as_bytes()));
+
+    headers.push((
+      "authorization",
+      format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        self.access_key_id, scope, signed_headers, signature
+      ),
+    ));
+    headers
+  }
+}
+
+#[async_trait::async_trait]
+impl SecretsBackend for AwsSecretsManager {
+  async fn read(&self, path: &str, key: Option<&str>) -> Result<Secret, SecretsError> {
+    let error = |reason: String| SecretsError::Backend {
+      backend: NAME,
+      path: path.to_owned(),
+      reason,
+    };
+
+    let body = json!({ "SecretId": path }).to_string();
+    let mut request = self.client.post(format!("https://{}/", self.host()));
+    for (name, value) in self.signed_headers(&body, Utc::now()) {
+      request = request.header(name, value);
+    }
+    let response = request.body(body).send().await.map_err(|e| error(e.to_string()))?;
+    if !response.status().is_success() {
+      return Err(error(format!("Secrets Manager responded with {}", response.status())));
+    }
+    let body = response.bytes().await.map_err(|e| error(e.to_string()))?;
+    let body: Value =
+      serde_json::from_slice(&body).map_err(|_| error("Secrets Manager responded with invalid JSON".to_owned()))?;
+    let Some(secret) = body["SecretString"].as_str() else {
+      return Err(error("only secrets stored as strings can be read".to_owned()));
+    };
+
+    match key {
+      Some(key) => {
+        let values: Value =
+          serde_json::from_str(secret).map_err(|_| SecretsError::MissingKey(path.to_owned(), key.to_owned()))?;
+        pick(path, &values, Some(key))
+      }
+      None => Ok(Secret::new(secret)),
+    }
+  }
+}
+
+/// HMAC-SHA256 of `data` with `key`, as defined in RFC 2104.
+fn hmac(key: &[u8], data: &[u8]) -> [u8; 32] {
+  const BLOCK: usize = 64;
+  let mut block = [0_u8; BLOCK];
+  if key.len() > BLOCK {
+    block[..32].copy_from_slice(&Sha256::digest(key));
+  } else {
+    block[..key.len()].copy_from_slice(key);
+  }
+
+  let mut inner = Sha256::new();
+  inner.update(block.map(|b| b ^ 0x36));
+  inner.update(data);
+  let mut outer = Sha256::new();
+  outer.update(block.map(|b| b ^ 0x5c));
+  outer.update(inner.finalize());
+  outer.finalize().into()
+}
+
+fn hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod test {
+  use chrono::TimeZone;
+
+  use super::*;
+
+  #[test]
+  fn test_hmac() {
+    // RFC 4231, test case 2.
+    assert_eq!(
+      hex(&hmac(b"Jefe", b"what do ya want for nothing?")),
+      "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+      "the digest should match the RFC's"
+    );
+  }
+
+  #[test]
+  fn test_signed_headers() {
+    let client = AwsSecretsManager::new("us-east-1", "AKIDEXAMPLE", Secret::new("secret"), None);
+    let now = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+    let headers = client.signed_headers("{}", now);
+    let names: Vec<_> = headers.iter().map(|(name, _)| *name).collect();
+    assert_eq!(
+      names,
+      ["content-type", "host", "x-amz-date", "x-amz-target", "authorization"],
+      "the signed headers should be sorted, followed by the authorization"
+    );
+    let (_, authorization) = &headers[4];
+    assert!(
+      authorization.starts_with(
+        "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20231001/us-east-1/secretsmanager/aws4_request, \
+         SignedHeaders=content-type;host;x-amz-date;x-amz-target, Signature="
+      ),
+      "unexpected authorization: {}",
+      authorization
+    );
+  }
+}
@@ -0,0 +1,70 @@
+use serde_json::Value;
+use wick_config::config::Secret;
+
+use super::{pick, SecretsBackend, SecretsError};
+
+const NAME: &str = "vault";
+
+/// Reads secrets from HashiCorp Vault's HTTP API. A reference's path is the API path after `/v1/`, like
+/// `secret/data/app` for the `app` secret of a KV v2 engine mounted at `secret`.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Vault {
+  addr: String,
+  token: Secret,
+  client: reqwest::Client,
+}
+
+impl Vault {
+  /// Read secrets from the Vault server at `addr`, authenticating with `token`.
+  pub fn new<T: Into<String>>(addr: T, token: Secret) -> Self {
+    Self {
+      addr: addr.into().trim_end_matches('/').to_owned(),
+      token,
+      client: reqwest::Client::new(),
+    }
+  }
+
+  /// A client for the server at `VAULT_ADDR` using `VAULT_TOKEN`, if both are set.
+  #[must_use]
+  pub fn from_env() -> Option<Self> {
+    let addr = std::env::var("VAULT_ADDR").ok()?;
+    let token = std::env::var("VAULT_TOKEN").ok()?;
+    Some(Self::new(addr, Secret::new(token)))
+  }
+}
+
+#[async_trait::async_trait]
+impl SecretsBackend for Vault {
+  async fn read(&self, path: &str, key: Option<&str>) -> Result<Secret, SecretsError> {
+    let error = |reason: String| SecretsError::Backend {
+      backend: NAME,
+      path: path.to_owned(),
+      reason,
+    };
+
+    let response = self
+      .client
+      .get(format!("{}/v1/{}", self.addr, path.trim_start_matches('/')))
+      .header("X-Vault-Token", self.token.expose())
+      .send()
+      .await
+      .map_err(|e| error(e.to_string()))?;
+    // the body isn't reported, Vault echoes request details in its errors.
+    if !response.status().is_success() {
+      return Err(error(format!("Vault responded with {}", response.status())));
+    }
+    let body = response.bytes().await.map_err(|e| error(e.to_string()))?;
+    let body: Value =
+      serde_json::from_slice(&body).map_err(|_| error("Vault responded with invalid JSON".to_owned()))?;
+
+    let data = &body["data"];
+    // KV v2 engines nest the secret's values alongside its metadata.
+    let values = if data["data"].is_object() && data["metadata"].is_object() {
+      &data["data"]
+    } else {
+      data
+    };
+    pick(path, values, key)
+  }
+}
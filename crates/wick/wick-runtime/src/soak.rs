@@ -0,0 +1,397 @@
+//! Leak detection for soak tests, which invoke operations continuously to surface leaks that would otherwise only
+//! show up as an eventual out-of-memory error.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use flow_graph_interpreter::TransactionSnapshot;
+use uuid::Uuid;
+
+use crate::metrics::RuntimeMetrics;
+
+/// One measurement of a runtime during a soak test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SoakSample {
+  /// The time since the soak test started.
+  pub elapsed: Duration,
+  /// The resident memory of the process in bytes, if the platform reports it.
+  pub memory: Option<u64>,
+  /// The transactions in progress, by scope namespace.
+  pub transactions: BTreeMap<String, Vec<TransactionSnapshot>>,
+}
+
+impl SoakSample {
+  /// Create a sample from measurements taken `elapsed` into a soak test.
+  #[must_use]
+  pub fn new(elapsed: Duration, memory: Option<u64>, transactions: BTreeMap<String, Vec<TransactionSnapshot>>) -> Self {
+    Self {
+      elapsed,
+      memory,
+      transactions,
+    }
+  }
+
+  /// Measure this process and the transactions `metrics` has snapshots of.
+  ///
+  /// Call [RuntimeMetrics::track_transactions] before the soak test starts or no transactions will be sampled.
+  #[must_use]
+  pub fn take(elapsed: Duration, metrics: &RuntimeMetrics) -> Self {
+    Self::new(elapsed, resident_memory(), metrics.transactions())
+  }
+
+  /// The number of transactions in progress across every scope.
+  #[must_use]
+  pub fn active(&self) -> usize {
+    self.transactions.values().map(Vec::len).sum()
+  }
+
+  /// The number of packets waiting in port buffers across every scope.
+  #[must_use]
+  pub fn buffered(&self) -> usize {
+    self
+      .transactions
+      .values()
+      .flatten()
+      .map(TransactionSnapshot::buffered)
+      .sum()
+  }
+}
+
+/// The resident memory of this process in bytes, or `None` on platforms other than Linux.
+#[must_use]
+pub fn resident_memory() -> Option<u64> {
+  let status = std::fs::read_to_string("/proc/self/status").ok()?;
+  let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+  let kb: u64 = line
+    .trim_start_matches("VmRSS:")
+    .trim()
+    .trim_end_matches("kB")
+    .trim()
+    .parse()
+    .ok()?;
+  Some(kb * 1024)
+}
+
+/// Something a soak test's samples suggest is leaking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SuspectedLeak {
+  /// The process's resident memory kept growing.
+  Memory {
+    /// The largest measurement early in the test, in bytes.
+    from: u64,
+    /// The smallest measurement late in the test, in bytes.
+    to: u64,
+  },
+  /// The number of transactions in progress for an operation kept growing.
+  Transactions {
+    /// The scope the operation belongs to.
+    namespace: String,
+    /// The operation the transactions execute.
+    operation: String,
+    /// The most transactions in progress early in the test.
+    from: u64,
+    /// The fewest transactions in progress late in the test.
+    to: u64,
+  },
+  /// The packets waiting in the port buffers of an operation instance kept growing.
+  PortBuffers {
+    /// The scope the flow belongs to.
+    namespace: String,
+    /// The flow the instance is part of.
+    operation: String,
+    /// The id of the instance in the flow.
+    instance: String,
+    /// The operation the instance calls.
+    entity: String,
+    /// The most packets buffered early in the test.
+    from: u64,
+    /// The fewest packets buffered late in the test.
+    to: u64,
+  },
+  /// A transaction was still in progress long after it started.
+  Unfinished {
+    /// The scope the operation belongs to.
+    namespace: String,
+    /// The operation the transaction executes.
+    operation: String,
+    /// The transaction's id.
+    id: Uuid,
+    /// The time since the transaction started.
+    age: Duration,
+    /// Whether the transaction had finished its output and was only waiting on operations.
+    done: bool,
+    /// The instances still running or holding packets, as `id (entity)`.
+    pending: Vec<String>,
+  },
+}
+
+impl std::fmt::Display for SuspectedLeak {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SuspectedLeak::Memory { from, to } => write!(
+        f,
+        "resident memory grew from {:.1} MiB to {:.1} MiB",
+        mebibytes(*from),
+        mebibytes(*to)
+      ),
+      SuspectedLeak::Transactions {
+        namespace,
+        operation,
+        from,
+        to,
+      } => write!(
+        f,
+        "transactions in progress for {}::{} grew from {} to {}",
+        namespace, operation, from, to
+      ),
+      SuspectedLeak::PortBuffers {
+        namespace,
+        operation,
+        instance,
+        entity,
+        from,
+        to,
+      } => write!(
+        f,
+        "packets buffered by instance '{}' ({}) in {}::{} grew from {} to {}",
+        instance, entity, namespace, operation, from, to
+      ),
+      SuspectedLeak::Unfinished {
+        namespace,
+        operation,
+        id,
+        age,
+        done,
+        pending,
+      } => {
+        let state = if *done {
+          "its output finished but"
+        } else {
+          "its output never finished and"
+        };
+        write!(
+          f,
+          "transaction {} of {}::{} is {:.1}s old, {} it is waiting on [{}]",
+          id,
+          namespace,
+          operation,
+          age.as_secs_f64(),
+          state,
+          pending.join(", ")
+        )
+      }
+    }
+  }
+}
+
+fn mebibytes(bytes: u64) -> f64 {
+  bytes as f64 / (1024.0 * 1024.0)
+}
+
+/// Finds [SuspectedLeak]s in the samples of a soak test.
+///
+/// A measurement is suspected of leaking when its floor rises: every value in the last quarter of the samples is
+/// larger than every value in the first quarter. Load that comes and goes doesn't raise the floor, but anything that
+/// is never freed does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct LeakDetector {
+  /// How long a transaction may be in progress before it's reported as never finishing.
+  pub unfinished_after: Duration,
+  /// The fraction of samples at the start of the test to ignore while caches and pools warm up.
+  pub warmup: f64,
+}
+
+impl Default for LeakDetector {
+  fn default() -> Self {
+    Self {
+      unfinished_after: Duration::from_secs(30),
+      warmup: 0.25,
+    }
+  }
+}
+
+impl LeakDetector {
+  /// The fewest samples, after warmup, needed to look for growth.
+  pub const MIN_SAMPLES: usize = 4;
+
+  /// Look for leaks in `samples`, which are expected in the order they were taken.
+  #[must_use]
+  pub fn analyze(&self, samples: &[SoakSample]) -> Vec<SuspectedLeak> {
+    let mut leaks = Vec::new();
+    let skip = (samples.len() as f64 * self.warmup.clamp(0.0, 1.0)) as usize;
+    let measured = &samples[skip..];
+
+    if measured.len() >= Self::MIN_SAMPLES {
+      if let Some(memory) = measured.iter().map(|s| s.memory).collect::<Option<Vec<_>>>() {
+        if let Some((from, to)) = growth(&memory) {
+          leaks.push(SuspectedLeak::Memory { from, to });
+        }
+      }
+
+      for ((namespace, operation), series) in series(measured, |tx, counts| {
+        *counts.entry(tx.operation.clone()).or_default() += 1;
+      }) {
+        if let Some((from, to)) = growth(&series) {
+          leaks.push(SuspectedLeak::Transactions {
+            namespace,
+            operation,
+            from,
+            to,
+          });
+        }
+      }
+
+      let mut entities = BTreeMap::new();
+      for ((namespace, key), series) in series(measured, |tx, counts| {
+        for instance in &tx.instances {
+          let key = format!("{}\0{}", tx.operation, instance.id);
+          entities.insert(key.clone(), instance.entity.clone());
+          *counts.entry(key).or_default() += instance.buffered as u64;
+        }
+      }) {
+        if let Some((from, to)) = growth(&series) {
+          let entity = entities.get(&key).cloned().unwrap_or_default();
+          let (operation, instance) = key.split_once('\0').unwrap_or_default();
+          leaks.push(SuspectedLeak::PortBuffers {
+            namespace,
+            operation: operation.to_owned(),
+            instance: instance.to_owned(),
+            entity,
+            from,
+            to,
+          });
+        }
+      }
+    }
+
+    if let Some(last) = samples.last() {
+      for (namespace, transactions) in &last.transactions {
+        for tx in transactions.iter().filter(|tx| tx.age > self.unfinished_after) {
+          leaks.push(SuspectedLeak::Unfinished {
+            namespace: namespace.clone(),
+            operation: tx.operation.clone(),
+            id: tx.id,
+            age: tx.age,
+            done: tx.done,
+            pending: tx
+              .instances
+              .iter()
+              .map(|i| format!("{} ({})", i.id, i.entity))
+              .collect(),
+          });
+        }
+      }
+    }
+
+    leaks
+  }
+}
+
+/// Build a series per scope and key from what `count` tallies for each transaction of each sample.
+fn series<F>(samples: &[SoakSample], mut count: F) -> BTreeMap<(String, String), Vec<u64>>
+where
+  F: FnMut(&TransactionSnapshot, &mut BTreeMap<String, u64>),
+{
+  let mut series: BTreeMap<(String, String), Vec<u64>> = BTreeMap::new();
+  for (index, sample) in samples.iter().enumerate() {
+    for (namespace, transactions) in &sample.transactions {
+      let mut counts = BTreeMap::new();
+      for tx in transactions {
+        count(tx, &mut counts);
+      }
+      for (key, value) in counts {
+        // keys missing from a sample counted zero.
+        let values = series
+          .entry((namespace.clone(), key))
+          .or_insert_with(|| vec![0; samples.len()]);
+        values[index] = value;
+      }
+    }
+  }
+  series
+}
+
+/// The largest value in the first quarter and smallest in the last quarter of `series` if the latter is larger.
+fn growth(series: &[u64]) -> Option<(u64, u64)> {
+  let quarter = (series.len() / 4).max(1);
+  let from = series[..quarter].iter().copied().max()?;
+  let to = series[series.len() - quarter..].iter().copied().min()?;
+  (to > from).then_some((from, to))
+}
+
+#[cfg(test)]
+mod test {
+  use flow_graph_interpreter::InstanceSnapshot;
+
+  use super::*;
+
+  fn tx(operation: &str, age: u64, buffered: usize) -> TransactionSnapshot {
+    let mut tx = TransactionSnapshot::new(Uuid::new_v4(), operation, Duration::from_secs(age));
+    tx.instances
+      .push(InstanceSnapshot::new("merge", "core::merge", true, buffered));
+    tx
+  }
+
+  fn sample(index: u64, memory: u64, transactions: Vec<TransactionSnapshot>) -> SoakSample {
+    SoakSample::new(
+      Duration::from_secs(index),
+      Some(memory),
+      BTreeMap::from([("app".to_owned(), transactions)]),
+    )
+  }
+
+  #[test]
+  fn test_steady_state() {
+    let samples: Vec<_> = (0..12)
+      .map(|i| {
+        sample(
+          i,
+          100 + (i % 3) * 10,
+          vec![tx("greet", 1, (i % 2) as usize); (i % 3) as usize],
+        )
+      })
+      .collect();
+    assert_eq!(LeakDetector::default().analyze(&samples), vec![]);
+  }
+
+  #[test]
+  fn test_growth() {
+    let samples: Vec<_> = (0..12)
+      .map(|i| sample(i, 100 + i * 10, vec![tx("greet", 1, i as usize); i as usize]))
+      .collect();
+    let leaks = LeakDetector::default().analyze(&samples);
+    assert_eq!(
+      leaks,
+      vec![
+        SuspectedLeak::Memory { from: 140, to: 200 },
+        SuspectedLeak::Transactions {
+          namespace: "app".to_owned(),
+          operation: "greet".to_owned(),
+          from: 4,
+          to: 10
+        },
+        SuspectedLeak::PortBuffers {
+          namespace: "app".to_owned(),
+          operation: "greet".to_owned(),
+          instance: "merge".to_owned(),
+          entity: "core::merge".to_owned(),
+          from: 16,
+          to: 100
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_unfinished() {
+    let samples = vec![sample(0, 100, vec![tx("greet", 5, 0), tx("greet", 45, 2)])];
+    let leaks = LeakDetector::default().analyze(&samples);
+    assert_eq!(leaks.len(), 1);
+    assert!(matches!(&leaks[0], SuspectedLeak::Unfinished { age, pending, .. }
+      if *age == Duration::from_secs(45) && pending == &["merge (core::merge)"]));
+    assert!(leaks[0].to_string().contains("app::greet is 45.0s old"));
+  }
+}
@@ -0,0 +1,216 @@
+//! Stores for the state components keep through their context's key-value API.
+//!
+//! Components reach the store with `ctx.kv()`, which scopes their keys to the app, the component, or the transaction
+//! being handled. Every component in a runtime shares the same store, which is kept in memory unless the runtime is
+//! given another one.
+//!
+//! WebAssembly components reach it through the host instead, with link calls to the [LINK_TARGET] component. Guests
+//! don't know which transaction they're handling, so they can only use the app and component scopes.
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flow_component::ComponentError;
+use futures::StreamExt;
+use serde_json::Value;
+use wick_packet::{Packet, PacketExt, PacketStream, RuntimeConfig};
+
+mod file;
+mod memory;
+mod redis;
+pub use file::FileStore;
+pub use flow_component::{KeyValue, KeyValueStore};
+pub use memory::MemoryStore;
+pub use redis::RedisStore;
+
+/// Errors reading or writing component state.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum StateError {
+  #[error(
+    "invalid state store '{0}', expected memory://, file://<path>, or redis://[:<password>@]<host>[:<port>]/<prefix>"
+  )]
+  InvalidBackend(String),
+
+  #[error("could not reach the state in {backend}: {reason}")]
+  Backend { backend: &'static str, reason: String },
+}
+
+/// Open the state store `url` points to: `memory://`, `file://<path>`, or
+/// `redis://[:<password>@]<host>[:<port>]/<prefix>`.
+pub fn state_store(url: &str) -> Result<Arc<dyn KeyValueStore>, StateError> {
+  let invalid = || StateError::InvalidBackend(url.to_owned());
+  let (scheme, rest) = url.split_once("://").ok_or_else(invalid)?;
+  Ok(match scheme {
+    "memory" if rest.is_empty() => Arc::new(MemoryStore::default()),
+    "file" if !rest.is_empty() => Arc::new(FileStore::new(rest)),
+    "redis" => Arc::new(RedisStore::from_url(rest).ok_or_else(invalid)?),
+    _ => return Err(invalid()),
+  })
+}
+
+/// The component WebAssembly guests link to for their state. Its `get`, `set`, and `delete` operations take the key
+/// on their `key` port and, for `set`, the value on their `value` port. Their `scope` config is `component`, the
+/// default, or `app`.
+pub const LINK_TARGET: &str = "__state";
+
+/// Handle a guest's link call to [LINK_TARGET] with `kv`, the handle bound to the guest's component.
+pub(crate) async fn handle_link(
+  kv: KeyValue,
+  op: &str,
+  mut stream: PacketStream,
+  config: Option<RuntimeConfig>,
+) -> Result<PacketStream, ComponentError> {
+  let kv = match config.as_ref().and_then(|c| c.get("scope")).and_then(Value::as_str) {
+    None | Some("component") => kv.component(),
+    Some("app") => kv.app(),
+    Some(scope) => {
+      return Err(ComponentError::msg(format!(
+        "'{}' state is not available to WebAssembly components, use 'app' or 'component'",
+        scope
+      )))
+    }
+  };
+  let (mut key, mut value) = (None, None);
+  while let Some(packet) = stream.next().await {
+    let packet = packet?;
+    if !packet.has_data() {
+      continue;
+    }
+    match packet.port() {
+      "key" => key = Some(packet.decode::<String>()?),
+      "value" => value = Some(packet.decode_value()?),
+      _ => {}
+    }
+  }
+  let key = key.ok_or_else(|| ComponentError::msg("state operations need a key on the 'key' port"))?;
+  let output = match op {
+    "get" => kv.get::<Value>(&key).await?.unwrap_or(Value::Null),
+    "set" => {
+      let value = value.ok_or_else(|| ComponentError::msg("'set' needs a value on the 'value' port"))?;
+      kv.set(&key, &value).await?;
+      Value::Null
+    }
+    "delete" => Value::Bool(kv.delete(&key).await?),
+    op => return Err(ComponentError::msg(format!("state has no '{}' operation", op))),
+  };
+  Ok(vec![Packet::encode("output", output), Packet::done("output")].into())
+}
+
+fn now_ms() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| d.as_millis() as u64)
+}
+
+const fn backend_error(backend: &'static str, reason: String) -> StateError {
+  StateError::Backend { backend, reason }
+}
+
+#[cfg(test)]
+mod test {
+  use std::time::Duration;
+
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_state_store() {
+    for valid in [
+      "memory://",
+      "file:///var/lib/app/state.json",
+      "redis://:secret@redis:6380/app",
+    ] {
+      assert!(state_store(valid).is_ok(), "'{}' should be valid", valid);
+    }
+    for invalid in [
+      "memory://state",
+      "file://",
+      "redis://redis",
+      "state.json",
+      "etcd://etcd/app",
+    ] {
+      assert!(state_store(invalid).is_err(), "'{}' should be invalid", invalid);
+    }
+  }
+
+  /// Exercise a store through the handle components use.
+  async fn exercise(store: Arc<dyn KeyValueStore>) -> Result<()> {
+    let kv = KeyValue::new(store.clone(), "app").bind("counter", "tx");
+    assert_eq!(kv.get::<u32>("count").await?, None);
+    kv.set("count", &1).await?;
+    kv.clone().app().set("count", &2).await?;
+    assert_eq!(kv.get::<u32>("count").await?, Some(1));
+    assert_eq!(kv.clone().app().get::<u32>("count").await?, Some(2));
+    assert_eq!(kv.clone().transaction().get::<u32>("count").await?, None);
+
+    kv.set_with_ttl("session", &json!({"user": "ada"}), Duration::from_millis(50))
+      .await?;
+    assert_eq!(kv.get("session").await?, Some(json!({"user": "ada"})));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(kv.get::<Value>("session").await?, None);
+
+    assert!(kv.delete("count").await?);
+    assert!(!kv.delete("count").await?);
+    assert_eq!(store.get("app/app/count").await?, Some(json!(2)));
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_link() -> Result<()> {
+    let store: Arc<dyn KeyValueStore> = Arc::new(MemoryStore::default());
+    let kv = KeyValue::new(store.clone(), "app").bind("guest", "");
+    let call = |op: &'static str, packets: Vec<Packet>, config: Option<RuntimeConfig>| {
+      let kv = kv.clone();
+      async move {
+        let output: Vec<_> = handle_link(kv, op, packets.into(), config).await?.collect().await;
+        Ok::<_, anyhow::Error>(output.into_iter().next().unwrap()?.decode_value()?)
+      }
+    };
+    let app = || Some(RuntimeConfig::from([("scope", json!("app"))]));
+
+    let set = vec![Packet::encode("key", "count"), Packet::encode("value", 1)];
+    assert_eq!(call("set", set, None).await?, json!(null));
+    let set = vec![Packet::encode("key", "count"), Packet::encode("value", 2)];
+    call("set", set, app()).await?;
+    assert_eq!(call("get", vec![Packet::encode("key", "count")], None).await?, json!(1));
+    assert_eq!(
+      call("get", vec![Packet::encode("key", "count")], app()).await?,
+      json!(2)
+    );
+    assert_eq!(store.get("app/component/guest/count").await?, Some(json!(1)));
+    assert_eq!(
+      call("delete", vec![Packet::encode("key", "count")], None).await?,
+      json!(true)
+    );
+    assert_eq!(
+      call("get", vec![Packet::encode("key", "count")], None).await?,
+      json!(null)
+    );
+
+    let tx = Some(RuntimeConfig::from([("scope", json!("transaction"))]));
+    assert!(
+      handle_link(kv.clone(), "get", vec![Packet::encode("key", "count")].into(), tx)
+        .await
+        .is_err()
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_memory_store() -> Result<()> {
+    exercise(Arc::new(MemoryStore::default())).await
+  }
+
+  #[tokio::test]
+  async fn test_file_store() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("wick-state-{}.json", uuid::Uuid::new_v4()));
+    exercise(Arc::new(FileStore::new(&path))).await?;
+    let reopened = FileStore::new(&path);
+    assert_eq!(reopened.get("app/app/count").await?, Some(json!(2)));
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+  }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use flow_component::{BoxFuture, ComponentError, KeyValueStore, Value};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use super::{backend_error, now_ms, StateError};
+
+const NAME: &str = "file";
+
+/// A value and when it expires, in milliseconds since the epoch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+  value: Value,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  expires_at: Option<u64>,
+}
+
+impl Entry {
+  fn live(&self) -> bool {
+    self.expires_at.map_or(true, |expires_at| expires_at > now_ms())
+  }
+}
+
+/// Keeps state as JSON in a file so it survives restarts. The file is read once and rewritten on every change, so it
+/// suits one instance of an app with modest state; use [super::RedisStore] to share state between instances.
+#[derive(Debug)]
+#[must_use]
+pub struct FileStore {
+  path: PathBuf,
+  entries: Mutex<Option<HashMap<String, Entry>>>,
+}
+
+impl FileStore {
+  /// Keep state in the file at `path`.
+  pub fn new<T: Into<PathBuf>>(path: T) -> Self {
+    Self {
+      path: path.into(),
+      entries: Mutex::new(None),
+    }
+  }
+
+  fn error<E: std::fmt::Display>(&self, e: &E) -> StateError {
+    backend_error(NAME, format!("{}: {}", self.path.display(), e))
+  }
+
+  /// The entries in the file.
+  async fn read(&self) -> Result<HashMap<String, Entry>, StateError> {
+    match tokio::fs::read(&self.path).await {
+      Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| self.error(&e)),
+      Err(e) if e.kind() == ErrorKind::NotFound => Ok(HashMap::new()),
+      Err(e) => Err(self.error(&e)),
+    }
+  }
+
+  /// Replace the file's entries, writing them next to the file first so a crash never leaves it half written.
+  async fn write(&self, entries: &HashMap<String, Entry>) -> Result<(), StateError> {
+    let mut tmp = self.path.clone().into_os_string();
+    tmp.push(".tmp");
+    let json = serde_json::to_vec(entries).map_err(|e| self.error(&e))?;
+    tokio::fs::write(&tmp, json).await.map_err(|e| self.error(&e))?;
+    tokio::fs::rename(&tmp, &self.path).await.map_err(|e| self.error(&e))
+  }
+
+  /// Run `f` on the live entries, rewriting the file if it returns `true`.
+  async fn update<T, F>(&self, f: F) -> Result<T, StateError>
+  where
+    T: Send,
+    F: FnOnce(&mut HashMap<String, Entry>) -> (T, bool) + Send,
+  {
+    let mut cached = self.entries.lock().await;
+    if cached.is_none() {
+      *cached = Some(self.read().await?);
+    }
+    let entries = cached.get_or_insert_with(HashMap::new);
+    let before = entries.len();
+    entries.retain(|_, entry| entry.live());
+    let (result, changed) = f(entries);
+    if changed || entries.len() != before {
+      self.write(entries).await?;
+    }
+    Ok(result)
+  }
+}
+
+impl KeyValueStore for FileStore {
+  fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Value>, ComponentError>> {
+    Box::pin(async move {
+      let value = self
+        .update(|entries| (entries.get(key).map(|entry| entry.value.clone()), false))
+        .await?;
+      Ok(value)
+    })
+  }
+
+  fn set<'a>(&'a self, key: &'a str, value: Value, ttl: Option<Duration>) -> BoxFuture<'a, Result<(), ComponentError>> {
+    Box::pin(async move {
+      let entry = Entry {
+        value,
+        expires_at: ttl.map(|ttl| now_ms().saturating_add(ttl.as_millis() as u64)),
+      };
+      self
+        .update(|entries| {
+          entries.insert(key.to_owned(), entry);
+          ((), true)
+        })
+        .await?;
+      Ok(())
+    })
+  }
+
+  fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool, ComponentError>> {
+    Box::pin(async move {
+      let existed = self
+        .update(|entries| {
+          let existed = entries.remove(key).is_some();
+          (existed, existed)
+        })
+        .await?;
+      Ok(existed)
+    })
+  }
+}
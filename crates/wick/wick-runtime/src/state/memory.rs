@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use flow_component::{BoxFuture, ComponentError, KeyValueStore, Value};
+use parking_lot::Mutex;
+
+/// Keeps state in the runtime's memory. State is lost when the runtime stops and isn't shared with other instances of
+/// the app.
+#[derive(Debug, Default)]
+#[must_use]
+pub struct MemoryStore {
+  entries: Mutex<HashMap<String, (Value, Option<Instant>)>>,
+}
+
+impl MemoryStore {
+  /// The value under `key`, dropping it if it has expired.
+  fn lookup(&self, key: &str) -> Option<Value> {
+    let mut entries = self.entries.lock();
+    let expired = entries
+      .get(key)
+      .and_then(|(_, expires)| *expires)
+      .map_or(false, |expires| expires <= Instant::now());
+    if expired {
+      entries.remove(key);
+      return None;
+    }
+    entries.get(key).map(|(value, _)| value.clone())
+  }
+}
+
+impl KeyValueStore for MemoryStore {
+  fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Value>, ComponentError>> {
+    Box::pin(async move { Ok(self.lookup(key)) })
+  }
+
+  fn set<'a>(&'a self, key: &'a str, value: Value, ttl: Option<Duration>) -> BoxFuture<'a, Result<(), ComponentError>> {
+    Box::pin(async move {
+      let expires = ttl.map(|ttl| Instant::now() + ttl);
+      self.entries.lock().insert(key.to_owned(), (value, expires));
+      Ok(())
+    })
+  }
+
+  fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool, ComponentError>> {
+    Box::pin(async move {
+      let existed = self.lookup(key).is_some();
+      self.entries.lock().remove(key);
+      Ok(existed)
+    })
+  }
+}
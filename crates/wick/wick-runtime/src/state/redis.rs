@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use flow_component::{BoxFuture, ComponentError, KeyValueStore, Value};
+use wick_config::config::Secret;
+
+use super::{backend_error, StateError};
+use crate::failover::split_url;
+use crate::resp::{self, Reply};
+
+const NAME: &str = "redis";
+
+/// Keeps state in Redis, so every instance of an app shares it. Keys are prefixed so several apps can share a server.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct RedisStore {
+  addr: String,
+  password: Option<Secret>,
+  prefix: String,
+}
+
+impl RedisStore {
+  /// Keep state under `prefix` on the Redis server at `addr`, e.g. `localhost:6379`.
+  pub fn new<A: Into<String>, P: Into<String>>(addr: A, prefix: P, password: Option<Secret>) -> Self {
+    Self {
+      addr: addr.into(),
+      password,
+      prefix: prefix.into(),
+    }
+  }
+
+  /// Parse `[:<password>@]<host>[:<port>]/<prefix>`, the part of a `redis://` URL after the scheme.
+  #[must_use]
+  pub fn from_url(rest: &str) -> Option<Self> {
+    let (userinfo, addr, prefix) = split_url(rest, 6379)?;
+    let password = userinfo
+      .and_then(|userinfo| userinfo.split_once(':'))
+      .map(|(_, password)| Secret::new(password));
+    Some(Self::new(addr, prefix, password))
+  }
+
+  /// Run `command` on `key`, followed by `args`, on a new connection.
+  async fn command(&self, command: &str, key: &str, args: &[&str]) -> Result<Reply, StateError> {
+    let error = |e: std::io::Error| backend_error(NAME, format!("{}: {}", self.addr, e));
+    let mut stream = resp::connect(&self.addr, self.password.as_ref()).await.map_err(error)?;
+    let key = format!("{}:{}", self.prefix, key);
+    let mut command = vec![command, key.as_str()];
+    command.extend_from_slice(args);
+    resp::command(&mut stream, &command).await.map_err(error)
+  }
+}
+
+fn unexpected(reply: &Reply) -> StateError {
+  backend_error(NAME, format!("unexpected reply {:?}", reply))
+}
+
+impl KeyValueStore for RedisStore {
+  fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Value>, ComponentError>> {
+    Box::pin(async move {
+      match self.command("GET", key, &[]).await? {
+        Reply::Bulk(Some(json)) => Ok(Some(serde_json::from_slice(&json)?)),
+        Reply::Bulk(None) => Ok(None),
+        other => Err(unexpected(&other).into()),
+      }
+    })
+  }
+
+  fn set<'a>(&'a self, key: &'a str, value: Value, ttl: Option<Duration>) -> BoxFuture<'a, Result<(), ComponentError>> {
+    Box::pin(async move {
+      let json = value.to_string();
+      let ttl = ttl.map(|ttl| ttl.as_millis().max(1).to_string());
+      let mut args = vec![json.as_str()];
+      if let Some(ttl) = &ttl {
+        args.extend(["PX", ttl.as_str()]);
+      }
+      self.command("SET", key, &args).await?;
+      Ok(())
+    })
+  }
+
+  fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool, ComponentError>> {
+    Box::pin(async move {
+      match self.command("DEL", key, &[]).await? {
+        Reply::Integer(deleted) => Ok(deleted > 0),
+        other => Err(unexpected(&other).into()),
+      }
+    })
+  }
+}
@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use futures::StreamExt;
+use seeded_random::Seed;
+use wick_config::WickConfiguration;
+use wick_packet::{packet_stream, Entity, Invocation};
+use wick_runtime::{Runtime, RuntimeBuilder};
+
+type Result<T> = anyhow::Result<T, anyhow::Error>;
+
+async fn builder(path: &str) -> Result<RuntimeBuilder> {
+  let def = WickConfiguration::fetch(Path::new(path), Default::default())
+    .await?
+    .finish()?
+    .try_component_config()?;
+  Ok(RuntimeBuilder::from_definition(def))
+}
+
+async fn run(runtime: &Runtime, input: &str) -> Result<String> {
+  let target = Entity::operation(runtime.namespace(), "run");
+  let invocation = Invocation::test(file!(), target, packet_stream!(("input", input)), None)?;
+  let mut stream = runtime.invoke(invocation, None).await?;
+  let packet = stream.next().await.unwrap()?;
+  Ok(packet.decode()?)
+}
+
+#[test_logger::test(tokio::test)]
+async fn same_seed_runtimes_are_isolated() -> Result<()> {
+  // seeds can't be copied, so make the same one for each runtime.
+  let echo = builder("./tests/manifests/v0/isolated-echo.yaml")
+    .await?
+    .build(Some(Seed::unsafe_new(1)))
+    .await?;
+  let senders = builder("./tests/manifests/v0/isolated-senders.yaml")
+    .await?
+    .build(Some(Seed::unsafe_new(1)))
+    .await?;
+
+  assert_eq!(run(&echo, "hello").await?, "hello");
+  assert_eq!(run(&senders, "hello").await?, "1234512345");
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn shared_components() -> Result<()> {
+  let owner = builder("./tests/manifests/v0/isolated-echo.yaml")
+    .await?
+    .build(None)
+    .await?;
+  assert!(owner.share("missing").is_err());
+
+  let mut consumer = builder("./tests/manifests/v0/shared-import.yaml").await?;
+  consumer.add_shared_component("shared_echo", owner.share("child")?);
+  let consumer = consumer.build(None).await?;
+
+  assert!(consumer.components().contains(&"shared_echo".to_owned()));
+  assert_eq!(run(&consumer, "hello").await?, "hello");
+  Ok(())
+}
@@ -1,9 +1,14 @@
 mod utils;
 use std::collections::HashMap;
 
+use futures::StreamExt;
 use serde_json::json;
 use utils::*;
-use wick_packet::{packet_stream, packets, Packet, RuntimeConfig};
+use wick_config::config::{ComponentPolicyBuilder, PolicyAction, PolicyRuleBuilder};
+use wick_config::WickConfiguration;
+use wick_packet::{packet_stream, packets, Entity, Invocation, Packet, RuntimeConfig};
+use wick_runtime::error::RuntimeError;
+use wick_runtime::{RuntimeBuilder, ScopeError};
 
 type Result<T> = anyhow::Result<T, anyhow::Error>;
 
@@ -114,6 +119,63 @@ async fn test_context_passthrough_base(
   Ok(())
 }
 
+async fn passthrough_builder() -> Result<RuntimeBuilder> {
+  let mut def = WickConfiguration::fetch(
+    "./tests/manifests/v1/component-context-vars-passthrough.yaml",
+    Default::default(),
+  )
+  .await?;
+  def.set_root_config(Some(RuntimeConfig::from([
+    ("required", json!("required field")),
+    ("optional", json!("optional field")),
+  ])));
+  Ok(RuntimeBuilder::from_definition(def.finish()?.try_component_config()?))
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_import_config_override() -> Result<()> {
+  let mut builder = passthrough_builder().await?;
+  builder.add_config_override("child", RuntimeConfig::from([("optional", json!("overridden"))]));
+  let runtime = builder.build(None).await?;
+
+  let target = Entity::operation(runtime.namespace(), "test");
+  let config = RuntimeConfig::from([("required", json!("required field"))]);
+  let invocation = Invocation::test(file!(), target, packets!(("input", "[from input]")), None)?;
+  let packets: Vec<_> = runtime.invoke(invocation, Some(config)).await?.collect().await;
+  assert_eq!(
+    packets[0].as_ref().unwrap().decode_value()?,
+    json!({"left":"[from input]","right":"root_required: required field, root_optional: overridden, required: required field, optional: "})
+  );
+
+  let mut builder = passthrough_builder().await?;
+  builder.add_config_override("missing", RuntimeConfig::default());
+  assert!(
+    builder.build(None).await.is_err(),
+    "overrides for unknown imports should fail"
+  );
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_import_policy() -> Result<()> {
+  let deny_by_default = ComponentPolicyBuilder::default().deny_by_default(true).build()?;
+  let result = passthrough_builder().await?.policy(deny_by_default).build(None).await;
+  assert!(
+    matches!(result, Err(RuntimeError::Scope(e)) if matches!(*e, ScopeError::PolicyViolation(_))),
+    "components no rule allows should be denied"
+  );
+
+  let allow_child = ComponentPolicyBuilder::default()
+    .deny_by_default(true)
+    .rules(vec![PolicyRuleBuilder::default()
+      .action(PolicyAction::Allow)
+      .namespace("child".to_owned())
+      .build()?])
+    .build()?;
+  let _runtime = passthrough_builder().await?.policy(allow_child).build(None).await?;
+  Ok(())
+}
+
 #[test_logger::test(tokio::test)]
 async fn composite_requires() -> Result<()> {
   common_test(
@@ -0,0 +1,233 @@
+pub(crate) mod error;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use parking_lot::Mutex;
+use serde_json::json;
+use structured_output::StructuredOutput;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tracing::Span;
+use wick_broker::Event;
+use wick_config::config::{AppConfiguration, BoundIdentifier, TriggerDefinition};
+use wick_packet::{Entity, InherentData, Invocation, Packet, RuntimeConfig};
+use wick_runtime::Runtime;
+use wick_trigger::resources::{Resource, ResourceKind};
+use wick_trigger::{Error, ErrorKind, Trigger};
+
+use self::error::BrokerError;
+
+async fn invoke_operation(
+  runtime: &Runtime,
+  target: &Entity,
+  config: Option<RuntimeConfig>,
+  event: &Event,
+  span: &Span,
+) -> Result<(), Error> {
+  let packets = event
+    .iter()
+    .flat_map(|(field, value)| [Packet::encode(field, value), Packet::done(field)])
+    .collect::<Vec<_>>();
+  let invocation = Invocation::new(
+    Entity::server("broker_client"),
+    target.clone(),
+    packets,
+    InherentData::unsafe_default(),
+    span,
+  );
+
+  let mut response = runtime.invoke(invocation, config).await?;
+  while let Some(packet) = response.next().await {
+    match packet {
+      Ok(packet) if packet.is_error() => {
+        span.in_scope(|| warn!(error = packet.unwrap_err().msg(), "trigger:broker:response"));
+      }
+      packet => trace!(?packet, "trigger:broker:response"),
+    }
+  }
+  Ok(())
+}
+
+#[derive(Debug)]
+struct BrokerInstance {
+  handle: JoinHandle<()>,
+  stop: oneshot::Sender<()>,
+  running_rx: Option<oneshot::Receiver<()>>,
+}
+
+impl BrokerInstance {
+  async fn shutdown(self) -> Result<(), Error> {
+    debug!("shutting down broker trigger");
+    // the event being sent when the trigger is stopped finishes before the task ends.
+    let _ = self.stop.send(());
+    self.handle.await.map_err(|_| {
+      Error::new_context(
+        "broker",
+        ErrorKind::Shutdown("waiting for the last event to be sent failed".to_owned()),
+      )
+    })
+  }
+}
+
+#[derive(Default)]
+pub struct Broker {
+  instance: Arc<Mutex<Option<BrokerInstance>>>,
+}
+
+impl fmt::Debug for Broker {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Broker")
+  }
+}
+
+#[async_trait]
+impl Trigger for Broker {
+  async fn run(
+    &self,
+    _name: String,
+    runtime: Runtime,
+    _app_config: AppConfiguration,
+    config: TriggerDefinition,
+    resources: Arc<HashMap<BoundIdentifier, Resource>>,
+    span: Span,
+  ) -> Result<StructuredOutput, Error> {
+    span.in_scope(|| debug!(kind = "broker", "trigger:run"));
+    let TriggerDefinition::Broker(config) = config else {
+      panic!("invalid trigger definition, expected Broker configuration");
+    };
+
+    let resource_name = config.resource();
+    let resource = resources
+      .get(resource_name)
+      .ok_or_else(|| Error::new_context("broker", ErrorKind::ResourceNotFound(resource_name.clone())))?;
+    let Resource::Broker(name) = resource else {
+      return Err(Error::new_context(
+        "broker",
+        ErrorKind::InvalidResourceType(ResourceKind::Broker, resource.kind()),
+      ));
+    };
+    let topic = config.topic();
+    // subscribing before returning means nothing published once the trigger is running is missed.
+    let mut events = wick_broker::Broker::named(name).subscribe(topic);
+
+    let component_id = config.operation().component_id().map_err(BrokerError::from)?;
+    let target = Entity::operation(component_id, config.operation().name());
+    let op_config = config.operation().config().and_then(|c| c.value().cloned());
+    let span = info_span!(parent: &span, "trigger:broker", target = %target, topic = %topic);
+
+    let (stop, mut stop_rx) = oneshot::channel();
+    let (running_tx, running_rx) = oneshot::channel();
+    let handle = tokio::spawn(async move {
+      loop {
+        let event = tokio::select! {
+          _ = &mut stop_rx => break,
+          event = events.recv() => event,
+        };
+        match event {
+          // events are sent one at a time so they arrive in the order they were published.
+          Ok(event) => {
+            if let Err(e) = invoke_operation(&runtime, &target, op_config.clone(), &event, &span).await {
+              span.in_scope(|| error!("error invoking operation: {}", e));
+            }
+          }
+          Err(RecvError::Lagged(missed)) => {
+            span.in_scope(|| warn!(missed, "trigger fell behind the topic and missed events"));
+          }
+          Err(RecvError::Closed) => break,
+        }
+      }
+      let _ = running_tx.send(());
+    });
+
+    self.instance.lock().replace(BrokerInstance {
+      handle,
+      stop,
+      running_rx: Some(running_rx),
+    });
+
+    Ok(StructuredOutput::new(
+      format!("Broker trigger subscribed to '{}' on broker '{}'", topic, name),
+      json!({"broker": name, "topic": topic}),
+    ))
+  }
+
+  async fn shutdown_gracefully(self) -> Result<(), Error> {
+    let Some(instance) = self.instance.lock().take() else {
+      return Ok(());
+    };
+    instance.shutdown().await
+  }
+
+  async fn wait_for_done(&self) -> StructuredOutput {
+    let rx = self.instance.lock().as_mut().and_then(|i| i.running_rx.take());
+    let Some(rx) = rx else {
+      error!("broker trigger not running");
+      return StructuredOutput::new(
+        "broker trigger not running",
+        json!({"status": "broker trigger not running"}),
+      );
+    };
+    match rx.await {
+      Ok(_) => {
+        info!("broker trigger finished");
+        StructuredOutput::new("broker trigger finished", json!({"status": "broker trigger finished"}))
+      }
+      Err(e) => {
+        error!(err=%e,"broker trigger failed");
+        let message = format!("broker trigger failed: {}", e);
+        StructuredOutput::new(message.clone(), json!({"status": message}))
+      }
+    }
+  }
+}
+
+impl fmt::Display for Broker {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "Broker Trigger")
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::Value;
+  use wick_trigger::build_trigger_runtime;
+
+  use super::*;
+  use crate::test::load_example;
+
+  #[test_logger::test(tokio::test)]
+  async fn test_broker_example() -> Result<()> {
+    let app_config = load_example("broker/subscriber.wick").await?.try_app_config()?;
+    let rt = build_trigger_runtime(&app_config, Span::current())?.build(None).await?;
+
+    let trigger = Broker::default();
+    let resource = Resource::new(app_config.resources().get(0).as_ref().unwrap().kind().clone())?;
+    let resources = Arc::new([("events".into(), resource)].iter().cloned().collect());
+    let trigger_config = app_config.triggers()[0].clone();
+    let output = trigger
+      .run(
+        "test".to_owned(),
+        rt,
+        app_config,
+        trigger_config,
+        resources,
+        Span::current(),
+      )
+      .await?;
+    assert_eq!(output.json, json!({"broker": "orders", "topic": "placed"}));
+
+    let Value::Object(event) = json!({"id": 7, "item": "widget"}) else {
+      unreachable!()
+    };
+    let delivered = wick_broker::Broker::named("orders").publish("placed", Arc::new(event));
+    assert_eq!(delivered, 1);
+    trigger.shutdown_gracefully().await?;
+    Ok(())
+  }
+}
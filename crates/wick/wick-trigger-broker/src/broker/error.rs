@@ -0,0 +1,18 @@
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum BrokerError {
+  #[error("error in configuration: {0}")]
+  Config(Box<wick_config::Error>),
+}
+
+impl From<BrokerError> for wick_trigger::Error {
+  fn from(value: BrokerError) -> Self {
+    wick_trigger::Error::new_context("broker", wick_trigger::ErrorKind::Trigger(Box::new(value)))
+  }
+}
+
+impl From<wick_config::Error> for BrokerError {
+  fn from(value: wick_config::Error) -> Self {
+    BrokerError::Config(Box::new(value))
+  }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 use std::{env, fmt};
 
 use async_trait::async_trait;
@@ -7,9 +9,22 @@ use futures::stream::StreamExt;
 use parking_lot::Mutex;
 use serde_json::json;
 use structured_output::StructuredOutput;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
 use tracing::{Instrument, Span};
-use wick_config::config::{AppConfiguration, BoundIdentifier, TriggerDefinition};
-use wick_packet::{packet_stream, Entity, InherentData, Invocation, PacketExt};
+use uuid::Uuid;
+use wick_config::config::{AppConfiguration, BoundIdentifier, StdinMode, TriggerDefinition};
+use wick_interface_types::{Field, OperationSignature, Type};
+use wick_packet::{
+  Base64Bytes,
+  Entity,
+  InherentData,
+  Invocation,
+  Observer,
+  Packet,
+  PacketExt,
+  PacketSender,
+  PacketStream,
+};
 use wick_runtime::Runtime;
 use wick_trigger::resources::Resource;
 use wick_trigger::Trigger;
@@ -30,64 +45,432 @@ impl Default for Cli {
   }
 }
 
+/// The port stdin is streamed to.
+const STDIN_PORT: &str = "stdin";
+
+/// The port an interactive operation sends text to write to stdout on.
+const STDOUT_PORT: &str = "stdout";
+
+/// The port an interactive operation sends text to write to stderr on.
+const STDERR_PORT: &str = "stderr";
+
+/// The port an interactive operation asks for a line of stdin on, with the prompt to show.
+const PROMPT_PORT: &str = "prompt";
+
+/// The port each line of stdin an interactive operation asks for is sent to.
+const LINE_PORT: &str = "line";
+
+/// The ports an operation sends its exit code on.
+const EXIT_CODE_PORTS: [&str; 2] = ["exit_code", "code"];
+
+/// How long an operation has to finish after it's sent a cancellation before its transaction is cancelled.
+const CANCEL_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// How much of stdin is sent per packet in [StdinMode::Raw].
+const CHUNK_SIZE: usize = 64 * 1024;
+
 impl Cli {
-  async fn handle(&self, runtime: Runtime, operation: Entity, args: Vec<String>) -> Result<(), wick_trigger::Error> {
+  async fn handle(
+    &self,
+    runtime: Runtime,
+    operation: Entity,
+    args: Vec<String>,
+    stdin: Option<StdinMode>,
+  ) -> Result<(), wick_trigger::Error> {
     let is_interactive = wick_interface_cli::types::Interactive {
       stdin: atty::is(atty::Stream::Stdin),
       stdout: atty::is(atty::Stream::Stdout),
       stderr: atty::is(atty::Stream::Stderr),
     };
 
-    let packet_stream = packet_stream!(("args", args), ("interactive", is_interactive));
+    let signature = operation_signature(&runtime, &operation);
+
+    // the inputs stay open while the operation runs so signals can be forwarded to it.
+    let (inputs, packet_stream) = PacketStream::new_channels();
+    for packet in [
+      Packet::encode("args", args),
+      Packet::done("args"),
+      Packet::encode("interactive", is_interactive),
+      Packet::done("interactive"),
+    ]
+    .into_iter()
+    .chain(close_unfed(signature.as_ref().map_or(&[], |op| op.inputs()), stdin))
+    {
+      let _ = inputs.send(packet);
+    }
+    if let Some(mode @ (StdinMode::Lines | StdinMode::Raw)) = stdin {
+      tokio::spawn(stream_stdin(mode, tokio::io::stdin(), inputs.clone()).instrument(Span::current()));
+    }
     let invocation = Invocation::new(
       Entity::server("cli_channel"),
-      operation,
+      operation.clone(),
       packet_stream,
       InherentData::unsafe_default(),
       &Span::current(),
     );
+    let tx_id = invocation.tx_id();
+
+    let response = runtime.invoke(invocation, Default::default()).await?;
+    let finished = async {
+      match stdin {
+        Some(StdinMode::Interactive) => interact(response, BufReader::new(tokio::io::stdin()), &inputs).await,
+        Some(mode) => write_output(response, mode, &output_types(signature.as_ref())).await,
+        None => wait_for_exit(response).await,
+      }
+    };
+    let (exit, signal) = forward_signals(finished, &runtime, tx_id, &inputs).await;
+    inputs.complete();
+    let output = exit.into_output(signal, stdin.is_some());
+
+    let _ = self.done_tx.lock().take().unwrap().send(output);
+
+    Ok(())
+  }
+}
+
+/// How the operation finished.
+#[derive(Debug, Default)]
+struct Exit {
+  /// The exit code the operation sent.
+  code: Option<u32>,
+  /// Why the operation failed, if it did.
+  error: Option<String>,
+}
+
+impl Exit {
+  /// The trigger's output, with the code the process exits with.
+  ///
+  /// The operation's own exit code wins. Otherwise an operation interrupted by a signal exits with 128 plus the signal
+  /// number, and a failed one exits with 1. Messages are left out when stdout carries the operation's output.
+  fn into_output(self, signal: Option<u32>, quiet: bool) -> StructuredOutput {
+    let code = self.code.or(signal).unwrap_or_else(|| u32::from(self.error.is_some()));
+    let message = if quiet {
+      String::new()
+    } else if let (Some(error), None) = (&self.error, self.code) {
+      format!("CLI Trigger produced error, {}", error)
+    } else if code > 0 {
+      format!("Exit code: {}", code)
+    } else {
+      String::new()
+    };
+    match self.error {
+      Some(error) => StructuredOutput::new(message, json!({ "code": code, "error": error })),
+      None => StructuredOutput::new(message, json!({ "code": code })),
+    }
+  }
+}
 
-    let mut response = runtime.invoke(invocation, Default::default()).await?;
-    let output = loop {
-      if let Some(packet) = response.next().await {
-        trace!(?packet, "trigger:cli:response");
-        match packet {
-          Ok(p) => {
-            if p.port() == "code" && p.has_data() {
-              let code: u32 = p.decode().unwrap();
-              let message = if code > 0 {
-                format!("Exit code: {}", code)
-              } else {
-                String::new()
-              };
-              break StructuredOutput::new(message, json!({ "code": code }));
-            }
-            if p.is_error() {
-              let err = p.unwrap_err();
-              break StructuredOutput::new(
-                format!("CLI Trigger produced error, {}", err.msg()),
-                json!({ "error": err.to_string() }),
-              );
-            }
+/// The exit code in `packet`, if it's one the operation sent on its `exit_code` port, or on `code` as older
+/// operations do.
+fn exit_code(packet: &Packet) -> Option<u32> {
+  if !packet.has_data() || !EXIT_CODE_PORTS.contains(&packet.port()) {
+    return None;
+  }
+  packet.decode().ok()
+}
+
+/// Wait for the operation to send its exit code, ignoring its other output.
+async fn wait_for_exit(mut response: PacketStream) -> Exit {
+  while let Some(packet) = response.next().await {
+    trace!(?packet, "trigger:cli:response");
+    match packet {
+      Ok(p) => {
+        if let Some(code) = exit_code(&p) {
+          return Exit {
+            code: Some(code),
+            error: None,
+          };
+        }
+        if p.is_error() {
+          return Exit {
+            code: None,
+            error: Some(p.unwrap_err().msg().to_owned()),
+          };
+        }
+      }
+      Err(e) => {
+        return Exit {
+          code: None,
+          error: Some(e.to_string()),
+        };
+      }
+    }
+  }
+  Exit {
+    code: None,
+    error: Some("CLI Trigger failed to return an exit code".to_owned()),
+  }
+}
+
+/// Wait for the operation to finish, forwarding SIGINT and SIGTERM to it.
+///
+/// The first signal is sent to the operation as a [Packet::cancel] on its inputs so it can wind down and send an exit
+/// code. If it's still running [CANCEL_GRACE_PERIOD] later, or when a second signal arrives, the transaction is
+/// cancelled. Returns the exit status for the signal, if one arrived.
+async fn forward_signals<F: Future<Output = Exit> + Send>(
+  finished: F,
+  runtime: &Runtime,
+  tx_id: Uuid,
+  inputs: &PacketSender,
+) -> (Exit, Option<u32>) {
+  tokio::pin!(finished);
+  let signal = tokio::select! {
+    exit = &mut finished => return (exit, None),
+    signal = interrupted() => signal,
+  };
+  debug!(signal, "forwarding signal to the operation as a cancellation");
+  let _ = inputs.send(Packet::cancel());
+  tokio::select! {
+    exit = &mut finished => return (exit, Some(signal)),
+    _ = tokio::time::sleep(CANCEL_GRACE_PERIOD) => {}
+    _ = interrupted() => {}
+  }
+  debug!(%tx_id, "operation still running, cancelling its transaction");
+  runtime.cancel(tx_id);
+  (finished.await, Some(signal))
+}
+
+/// Wait for SIGINT or SIGTERM, resolving with the exit status conventional for it: 130 for SIGINT, 143 for SIGTERM.
+async fn interrupted() -> u32 {
+  #[cfg(unix)]
+  if let Ok(mut terminate) = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+    return tokio::select! {
+      _ = tokio::signal::ctrl_c() => 130,
+      _ = terminate.recv() => 143,
+    };
+  }
+  let _ = tokio::signal::ctrl_c().await;
+  130
+}
+
+/// Send stdin to the operation's `stdin` port as it's read, then mark the port done.
+async fn stream_stdin<R: AsyncRead + Unpin + Send>(mode: StdinMode, mut stdin: R, tx: PacketSender) {
+  match mode {
+    StdinMode::Lines => {
+      let mut lines = BufReader::new(stdin).lines();
+      let mut number = 0;
+      loop {
+        let line = match lines.next_line().await {
+          Ok(Some(line)) => line,
+          Ok(None) => break,
+          Err(e) => {
+            let _ = tx.send(Packet::err(STDIN_PORT, format!("could not read stdin: {}", e)));
+            break;
           }
+        };
+        number += 1;
+        if line.trim().is_empty() {
+          continue;
+        }
+        let packet = match serde_json::from_str::<serde_json::Value>(&line) {
+          Ok(value) => Packet::encode(STDIN_PORT, value),
+          Err(e) => Packet::err(STDIN_PORT, format!("invalid JSON on line {} of stdin: {}", number, e)),
+        };
+        if tx.send(packet).is_err() {
+          return;
+        }
+      }
+    }
+    StdinMode::Raw => {
+      let mut buffer = vec![0; CHUNK_SIZE];
+      loop {
+        let read = match stdin.read(&mut buffer).await {
+          Ok(0) => break,
+          Ok(read) => read,
           Err(e) => {
-            break StructuredOutput::new(
-              format!("CLI Trigger produced error, {}", e),
-              json!({ "error": e.to_string() }),
-            );
+            let _ = tx.send(Packet::err(STDIN_PORT, format!("could not read stdin: {}", e)));
+            break;
           }
+        };
+        let chunk = Base64Bytes::new(buffer[..read].to_vec());
+        if tx.send(Packet::encode(STDIN_PORT, chunk)).is_err() {
+          return;
         }
-      } else {
-        break StructuredOutput::new(
-          "CLI Trigger failed to return an exit code",
-          json!({ "error": "CLI Trigger failed to return an exit code" }),
-        );
+      }
+    }
+    // lines are read as the operation prompts for them, see [interact].
+    StdinMode::Interactive => return,
+  }
+  let _ = tx.send(Packet::done(STDIN_PORT));
+}
+
+/// The signature of the operation the trigger runs, if the runtime knows it.
+fn operation_signature(runtime: &Runtime, operation: &Entity) -> Option<OperationSignature> {
+  runtime
+    .deep_signature(None, Some(operation))
+    .ok()
+    .and_then(|signature| signature.get_operation(operation.operation_id()).cloned())
+}
+
+/// Done signals for the operation's inputs nothing is sent to, so it isn't left waiting on them.
+///
+/// `args` and `interactive` are finished as they're sent, and the port stdin is sent to is finished once stdin closes:
+/// `stdin` for [StdinMode::Lines] and [StdinMode::Raw], `line` for [StdinMode::Interactive].
+fn close_unfed(inputs: &[Field], stdin: Option<StdinMode>) -> Vec<Packet> {
+  let fed = match stdin {
+    Some(StdinMode::Lines | StdinMode::Raw) => Some(STDIN_PORT),
+    Some(StdinMode::Interactive) => Some(LINE_PORT),
+    None => None,
+  };
+  inputs
+    .iter()
+    .map(Field::name)
+    .filter(|name| !["args", "interactive"].contains(name) && Some(*name) != fed)
+    .map(Packet::done)
+    .collect()
+}
+
+/// The types of the operation's outputs, so [StdinMode::Raw] can write bytes and strings as-is.
+fn output_types(signature: Option<&OperationSignature>) -> HashMap<String, Type> {
+  signature
+    .map(|op| {
+      op.outputs()
+        .iter()
+        .map(|field| (field.name().to_owned(), field.ty().clone()))
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Write the operation's output to stdout as it arrives and its errors to stderr.
+async fn write_output(mut response: PacketStream, mode: StdinMode, types: &HashMap<String, Type>) -> Exit {
+  let mut stdout = tokio::io::stdout();
+  let mut stderr = tokio::io::stderr();
+  let mut exit = Exit::default();
+  while let Some(packet) = response.next().await {
+    trace!(?packet, "trigger:cli:response");
+    let packet = match packet {
+      Ok(packet) => packet,
+      Err(e) => {
+        let _ = stderr.write_all(format!("{}\n", e).as_bytes()).await;
+        exit.error.get_or_insert(e.to_string());
+        break;
       }
     };
+    if packet.is_error() {
+      let error = packet.unwrap_err().msg().to_owned();
+      let _ = stderr.write_all(format!("{}\n", error).as_bytes()).await;
+      exit.error.get_or_insert(error);
+      continue;
+    }
+    if let Some(code) = exit_code(&packet) {
+      exit.code = Some(code);
+      continue;
+    }
+    if !packet.has_data() || EXIT_CODE_PORTS.contains(&packet.port()) {
+      continue;
+    }
+    match render(&packet, mode, types) {
+      Ok(bytes) => {
+        if stdout.write_all(&bytes).await.is_err() || stdout.flush().await.is_err() {
+          // stdout was closed, e.g. by `head`, so there's nowhere left to write.
+          break;
+        }
+      }
+      Err(e) => {
+        let error = format!("could not write output from port {}: {}", packet.port(), e);
+        let _ = stderr.write_all(format!("{}\n", error).as_bytes()).await;
+        exit.error.get_or_insert(error);
+      }
+    }
+  }
+  exit
+}
 
-    let _ = self.done_tx.lock().take().unwrap().send(output);
+/// Bridge the operation to the terminal for an interactive session.
+///
+/// Text the operation sends on `stdout` and `stderr` is written there as-is. Each `prompt` it sends is written to
+/// stdout before the next line of stdin is read and sent to it on `line`, which is marked done once stdin closes.
+async fn interact<R: AsyncBufRead + Unpin + Send>(mut response: PacketStream, stdin: R, inputs: &PacketSender) -> Exit {
+  let mut lines = stdin.lines();
+  let mut stdout = tokio::io::stdout();
+  let mut stderr = tokio::io::stderr();
+  let mut exit = Exit::default();
+  let mut closed = false;
+  while let Some(packet) = response.next().await {
+    trace!(?packet, "trigger:cli:response");
+    let packet = match packet {
+      Ok(packet) => packet,
+      Err(e) => {
+        let _ = write(&mut stderr, format!("{}\n", e)).await;
+        exit.error.get_or_insert(e.to_string());
+        break;
+      }
+    };
+    if packet.is_error() {
+      let error = packet.unwrap_err().msg().to_owned();
+      let _ = write(&mut stderr, format!("{}\n", error)).await;
+      exit.error.get_or_insert(error);
+      continue;
+    }
+    if let Some(code) = exit_code(&packet) {
+      exit.code = Some(code);
+      continue;
+    }
+    let port = packet.port();
+    if !packet.has_data() || ![STDOUT_PORT, STDERR_PORT, PROMPT_PORT].contains(&port) {
+      continue;
+    }
+    let text = match packet.decode::<String>() {
+      Ok(text) => text,
+      Err(e) => {
+        let error = format!("could not write output from port {}: {}", port, e);
+        let _ = write(&mut stderr, format!("{}\n", error)).await;
+        exit.error.get_or_insert(error);
+        continue;
+      }
+    };
+    match port {
+      STDERR_PORT => {
+        let _ = write(&mut stderr, text).await;
+      }
+      STDOUT_PORT => {
+        let _ = write(&mut stdout, text).await;
+      }
+      _ => {
+        if closed {
+          debug!("ignoring prompt after stdin closed");
+          continue;
+        }
+        let _ = write(&mut stdout, text).await;
+        let packet = match lines.next_line().await {
+          Ok(Some(line)) => Packet::encode(LINE_PORT, line),
+          Ok(None) => {
+            closed = true;
+            Packet::done(LINE_PORT)
+          }
+          Err(e) => {
+            closed = true;
+            let _ = inputs.send(Packet::err(LINE_PORT, format!("could not read stdin: {}", e)));
+            Packet::done(LINE_PORT)
+          }
+        };
+        let _ = inputs.send(packet);
+      }
+    }
+  }
+  exit
+}
 
-    Ok(())
+/// Write `text` and flush it so it's seen right away.
+async fn write<W: AsyncWrite + Unpin + Send, T: AsRef<[u8]> + Send + Sync>(
+  out: &mut W,
+  text: T,
+) -> std::io::Result<()> {
+  out.write_all(text.as_ref()).await?;
+  out.flush().await
+}
+
+/// The bytes to write to stdout for a packet of output.
+fn render(packet: &Packet, mode: StdinMode, types: &HashMap<String, Type>) -> Result<Vec<u8>, wick_packet::Error> {
+  match (mode, types.get(packet.port())) {
+    (StdinMode::Raw, Some(Type::Bytes)) => Ok(packet.decode::<Base64Bytes>()?.as_ref().to_vec()),
+    (StdinMode::Raw, Some(Type::String)) => Ok(packet.decode::<String>()?.into_bytes()),
+    _ => {
+      let mut line = packet.decode_value()?.to_string().into_bytes();
+      line.push(b'\n');
+      Ok(line)
+    }
   }
 }
 
@@ -120,7 +503,10 @@ impl Trigger for Cli {
 
     let target = config.operation().as_entity().unwrap();
 
-    self.handle(runtime, target, args).instrument(span).await?;
+    self
+      .handle(runtime, target, args, config.stdin().copied())
+      .instrument(span)
+      .await?;
 
     Ok(StructuredOutput::default())
   }
@@ -133,6 +519,10 @@ impl Trigger for Cli {
     let rx = self.done_rx.lock().take().unwrap();
     rx.await.unwrap_or_default()
   }
+
+  fn handles_signals(&self) -> bool {
+    true
+  }
 }
 
 impl fmt::Display for Cli {
@@ -140,3 +530,213 @@ impl fmt::Display for Cli {
     write!(f, "Cli Trigger",)
   }
 }
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn test_output_on_success() -> Result<()> {
+    let output = Exit::default().into_output(None, false);
+    assert_eq!(output.lines, "");
+    assert_eq!(output.json, json!({ "code": 0 }));
+
+    let output = Exit {
+      code: Some(3),
+      error: None,
+    }
+    .into_output(None, false);
+    assert_eq!(output.lines, "Exit code: 3");
+    assert_eq!(output.json, json!({ "code": 3 }));
+    Ok(())
+  }
+
+  #[test]
+  fn test_output_on_error() -> Result<()> {
+    let exit = || Exit {
+      code: None,
+      error: Some("boom".to_owned()),
+    };
+    let output = exit().into_output(None, false);
+    assert_eq!(output.lines, "CLI Trigger produced error, boom");
+    assert_eq!(output.json, json!({ "code": 1, "error": "boom" }));
+
+    let output = Exit {
+      code: Some(2),
+      ..exit()
+    }
+    .into_output(None, false);
+    assert_eq!(output.lines, "Exit code: 2");
+    assert_eq!(output.json, json!({ "code": 2, "error": "boom" }));
+    Ok(())
+  }
+
+  #[test]
+  fn test_output_on_signal() -> Result<()> {
+    let output = Exit::default().into_output(Some(130), false);
+    assert_eq!(output.lines, "Exit code: 130");
+    assert_eq!(output.json, json!({ "code": 130 }));
+
+    let output = Exit {
+      code: None,
+      error: Some("cancelled".to_owned()),
+    }
+    .into_output(Some(143), false);
+    assert_eq!(output.json, json!({ "code": 143, "error": "cancelled" }));
+
+    // an operation that winds down and sends its own exit code keeps it.
+    let output = Exit {
+      code: Some(0),
+      error: None,
+    }
+    .into_output(Some(130), false);
+    assert_eq!(output.json, json!({ "code": 0 }));
+    Ok(())
+  }
+
+  #[test]
+  fn test_quiet_output() -> Result<()> {
+    let output = Exit {
+      code: None,
+      error: Some("boom".to_owned()),
+    }
+    .into_output(None, true);
+    assert_eq!(output.lines, "");
+    assert_eq!(output.json, json!({ "code": 1, "error": "boom" }));
+
+    let output = Exit::default().into_output(Some(143), true);
+    assert_eq!(output.lines, "");
+    assert_eq!(output.json, json!({ "code": 143 }));
+    Ok(())
+  }
+
+  #[test]
+  fn test_exit_code() -> Result<()> {
+    assert_eq!(exit_code(&Packet::encode("exit_code", 4)), Some(4));
+    assert_eq!(exit_code(&Packet::encode("code", 5)), Some(5));
+    assert_eq!(exit_code(&Packet::encode("output", 6)), None);
+    assert_eq!(exit_code(&Packet::done("exit_code")), None);
+    assert_eq!(exit_code(&Packet::err("code", "boom")), None);
+    for port in EXIT_CODE_PORTS {
+      assert_eq!(exit_code(&Packet::encode(port, "1")), None, "a string on {}", port);
+      assert_eq!(
+        exit_code(&Packet::encode(port, -1)),
+        None,
+        "a negative number on {}",
+        port
+      );
+      assert_eq!(exit_code(&Packet::encode(port, 1.5)), None, "a fraction on {}", port);
+      assert_eq!(
+        exit_code(&Packet::encode(port, json!({ "code": 1 }))),
+        None,
+        "an object on {}",
+        port
+      );
+    }
+    Ok(())
+  }
+
+  /// Every packet sent on `tx` once `sending` finishes.
+  async fn received<F: Future<Output = ()> + Send>(sending: F, tx: PacketSender, rx: PacketStream) -> Vec<Packet> {
+    sending.await;
+    tx.complete();
+    rx.map(|packet| packet.unwrap()).collect().await
+  }
+
+  #[test]
+  fn test_close_unfed() -> Result<()> {
+    let inputs = ["args", "interactive", "stdin", "line", "config"].map(|name| Field::new(name, Type::String));
+    let closed = |stdin| {
+      close_unfed(&inputs, stdin)
+        .iter()
+        .inspect(|packet| assert!(packet.is_done(), "only done signals should be sent"))
+        .map(|packet| packet.port().to_owned())
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(closed(None), ["stdin", "line", "config"]);
+    assert_eq!(closed(Some(StdinMode::Lines)), ["line", "config"]);
+    assert_eq!(closed(Some(StdinMode::Raw)), ["line", "config"]);
+    assert_eq!(closed(Some(StdinMode::Interactive)), ["stdin", "config"]);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_stdin_lines() -> Result<()> {
+    let stdin: &[u8] = b"{\"a\":1}\n\n  \nnot json\n2";
+    let (tx, rx) = PacketStream::new_channels();
+    let packets = received(stream_stdin(StdinMode::Lines, stdin, tx.clone()), tx, rx).await;
+
+    assert_eq!(packets.len(), 4, "unexpected packets: {:?}", packets);
+    assert_eq!(packets[0].decode_value()?, json!({ "a": 1 }));
+    assert!(packets[1].is_error());
+    assert!(packets[1]
+      .clone()
+      .unwrap_err()
+      .msg()
+      .starts_with("invalid JSON on line 4 of stdin"));
+    assert_eq!(packets[2].decode_value()?, json!(2));
+    assert!(packets[3].is_done());
+    assert!(packets.iter().all(|packet| packet.port() == STDIN_PORT));
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_stdin_raw() -> Result<()> {
+    let stdin = vec![7; CHUNK_SIZE + 10];
+    let (tx, rx) = PacketStream::new_channels();
+    let packets = received(stream_stdin(StdinMode::Raw, stdin.as_slice(), tx.clone()), tx, rx).await;
+
+    assert_eq!(packets.len(), 3, "unexpected packets: {:?}", packets);
+    let mut read = Vec::new();
+    for packet in &packets[..2] {
+      read.extend_from_slice(packet.decode::<Base64Bytes>()?.as_ref());
+    }
+    assert_eq!(read, stdin);
+    assert!(packets[2].is_done());
+    assert!(packets.iter().all(|packet| packet.port() == STDIN_PORT));
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_stdin_interactive() -> Result<()> {
+    let stdin: &[u8] = b"first\nsecond\n";
+    let (tx, rx) = PacketStream::new_channels();
+    // stdin is read as the operation prompts for it, not streamed.
+    let packets = received(stream_stdin(StdinMode::Interactive, stdin, tx.clone()), tx, rx).await;
+    assert!(packets.is_empty(), "unexpected packets: {:?}", packets);
+
+    let (response_tx, response) = PacketStream::new_channels();
+    for packet in [
+      Packet::encode(PROMPT_PORT, ""),
+      Packet::encode(PROMPT_PORT, ""),
+      Packet::encode(PROMPT_PORT, ""),
+      Packet::encode(PROMPT_PORT, ""),
+      Packet::encode("exit_code", 0),
+    ] {
+      response_tx.send(packet)?;
+    }
+    response_tx.complete();
+    let (tx, rx) = PacketStream::new_channels();
+    let mut exit = None;
+    let packets = received(
+      async { exit = Some(interact(response, stdin, &tx).await) },
+      tx.clone(),
+      rx,
+    )
+    .await;
+
+    assert_eq!(exit.unwrap().code, Some(0));
+    assert_eq!(
+      packets,
+      vec![
+        Packet::encode(LINE_PORT, "first"),
+        Packet::encode(LINE_PORT, "second"),
+        Packet::done(LINE_PORT),
+      ]
+    );
+    Ok(())
+  }
+}
@@ -0,0 +1,421 @@
+pub(crate) mod error;
+pub(crate) mod proto;
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::fmt;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use futures::StreamExt;
+use hyper::header::CONTENT_TYPE;
+use hyper::http::HeaderValue;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, HeaderMap, Request, Response, Server};
+use parking_lot::Mutex;
+use serde_json::json;
+use structured_output::StructuredOutput;
+use tokio::task::JoinHandle;
+use tracing::{Instrument, Span};
+use wick_config::config::{AppConfiguration, BoundIdentifier, ComponentOperationExpression, TriggerDefinition};
+use wick_packet::{Entity, InherentData, Invocation, PacketExt};
+use wick_runtime::Runtime;
+use wick_trigger::resources::{Resource, ResourceKind};
+use wick_trigger::{Error, ErrorKind, Trigger};
+
+use self::error::GrpcError;
+use self::proto::{Method, ServiceDescriptor};
+
+const GRPC_CONTENT_TYPE: &str = "application/grpc";
+
+#[derive(Debug)]
+struct Route {
+  component: String,
+  operation: ComponentOperationExpression,
+  method: Method,
+}
+
+#[derive(Debug)]
+struct GrpcService {
+  runtime: Runtime,
+  descriptor: ServiceDescriptor,
+  routes: HashMap<String, Route>,
+  span: Span,
+}
+
+impl GrpcService {
+  fn new(
+    runtime: Runtime,
+    package: Option<String>,
+    name: &str,
+    operations: &[ComponentOperationExpression],
+    span: Span,
+  ) -> Result<Self, GrpcError> {
+    let mut descriptor = ServiceDescriptor::new(package, name);
+    let mut routes = HashMap::new();
+    for operation in operations {
+      let component = operation.component_id()?.to_owned();
+      let signature = runtime
+        .deep_signature(None, Some(&Entity::component(&component)))
+        .map_err(|e| GrpcError::Signature(component.clone(), e.to_string()))?;
+      let op_signature = signature
+        .get_operation(operation.name())
+        .ok_or_else(|| GrpcError::OperationNotFound(component.clone(), operation.name().to_owned()))?;
+      let method = descriptor.add_operation(op_signature, &signature.types)?;
+      span
+        .in_scope(|| info!(method = %method.name, %component, operation = operation.name(), "registering grpc method"));
+      routes.insert(
+        descriptor.path(&method),
+        Route {
+          component,
+          operation: operation.clone(),
+          method,
+        },
+      );
+    }
+    Ok(Self {
+      runtime,
+      descriptor,
+      routes,
+      span,
+    })
+  }
+
+  async fn handle(self: Arc<Self>, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let path = request.uri().path().to_owned();
+    let span = info_span!(parent: &self.span, "grpc:request", %path);
+    let result = self.serve(&path, request).instrument(span.clone()).await;
+    let response = match result {
+      Ok(message) => {
+        let (mut sender, body) = Body::channel();
+        tokio::spawn(async move {
+          let _ = sender.send_data(frame(&message)).await;
+          let _ = sender.send_trailers(status_trailers(0, None)).await;
+        });
+        let mut response = Response::new(body);
+        response
+          .headers_mut()
+          .insert(CONTENT_TYPE, HeaderValue::from_static(GRPC_CONTENT_TYPE));
+        response
+      }
+      Err(e) => {
+        span.in_scope(|| warn!(error = %e, "grpc request failed"));
+        let mut response = Response::new(Body::empty());
+        let headers = response.headers_mut();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(GRPC_CONTENT_TYPE));
+        headers.extend(status_trailers(e.code(), Some(&e.to_string())));
+        response
+      }
+    };
+    Ok(response)
+  }
+
+  async fn serve(&self, path: &str, request: Request<Body>) -> Result<Bytes, GrpcError> {
+    let route = self
+      .routes
+      .get(path)
+      .ok_or_else(|| GrpcError::Unimplemented(path.to_owned()))?;
+
+    let body = hyper::body::to_bytes(request.into_body())
+      .await
+      .map_err(|e| GrpcError::Decode(e.to_string()))?;
+    let message = unframe(body)?;
+    let packets = self.descriptor.decode_request(&route.method, message)?;
+
+    let invocation = Invocation::new(
+      Entity::server("grpc_client"),
+      Entity::operation(&route.component, route.operation.name()),
+      packets,
+      InherentData::unsafe_default(),
+      &Span::current(),
+    );
+    let config = route.operation.config().and_then(|c| c.value().cloned());
+    let mut stream = self
+      .runtime
+      .invoke(invocation, config)
+      .await
+      .map_err(|e| GrpcError::Operation(e.to_string()))?;
+
+    let mut outputs = BTreeMap::new();
+    while let Some(packet) = stream.next().await {
+      let packet = packet.map_err(|e| GrpcError::Operation(e.to_string()))?;
+      if packet.is_error() {
+        return Err(GrpcError::Operation(packet.unwrap_err().msg().to_owned()));
+      }
+      if !packet.has_data() {
+        continue;
+      }
+      let port = packet.port().to_owned();
+      if outputs.insert(port.clone(), packet).is_some() {
+        return Err(GrpcError::MultipleValues(port));
+      }
+    }
+
+    self.descriptor.encode_response(&route.method, &outputs)
+  }
+}
+
+/// Strip the gRPC length-prefixed message framing from a request body.
+fn unframe(mut body: Bytes) -> Result<Bytes, GrpcError> {
+  if body.remaining() < 5 {
+    return Err(GrpcError::Decode(
+      "request body is too short to contain a message".to_owned(),
+    ));
+  }
+  if body.get_u8() != 0 {
+    return Err(GrpcError::Compressed);
+  }
+  let len = body.get_u32() as usize;
+  if len > body.remaining() {
+    return Err(GrpcError::Decode("message length exceeds request body".to_owned()));
+  }
+  Ok(body.split_to(len))
+}
+
+/// Add the gRPC length-prefixed message framing to a response message.
+fn frame(message: &[u8]) -> Bytes {
+  let mut buf = BytesMut::with_capacity(message.len() + 5);
+  buf.put_u8(0);
+  buf.put_u32(message.len() as u32);
+  buf.put_slice(message);
+  buf.freeze()
+}
+
+fn status_trailers(code: u16, message: Option<&str>) -> HeaderMap {
+  let mut trailers = HeaderMap::new();
+  trailers.insert("grpc-status", HeaderValue::from(code));
+  if let Some(message) = message {
+    let encoded = percent_encoding::utf8_percent_encode(message, percent_encoding::NON_ALPHANUMERIC).to_string();
+    if let Ok(value) = HeaderValue::from_str(&encoded) {
+      trailers.insert("grpc-message", value);
+    }
+  }
+  trailers
+}
+
+#[must_use]
+struct GrpcInstance {
+  handle: JoinHandle<()>,
+  shutdown_tx: tokio::sync::oneshot::Sender<()>,
+  running_rx: Option<tokio::sync::oneshot::Receiver<()>>,
+  addr: SocketAddr,
+}
+
+impl GrpcInstance {
+  fn new(service: Arc<GrpcService>, socket: &SocketAddr) -> Result<Self, Error> {
+    let span = service.span.clone();
+    let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+    let (running_tx, running_rx) = tokio::sync::oneshot::channel::<()>();
+    let make_service = make_service_fn(move |_| {
+      let service = service.clone();
+      async move { Ok::<_, Infallible>(service_fn(move |req| service.clone().handle(req))) }
+    });
+    let server = Server::try_bind(socket)
+      .map_err(|e| Error::new_context("grpc", ErrorKind::Startup(e.to_string())))?
+      .http2_only(true)
+      .serve(make_service);
+    let addr = server.local_addr();
+    let handle = tokio::spawn(async move {
+      let _ = server
+        .with_graceful_shutdown(async move {
+          let _ = rx.await;
+          span.in_scope(|| trace!("grpc server shutting down"));
+        })
+        .await;
+      let _ = running_tx.send(());
+    });
+
+    Ok(Self {
+      handle,
+      shutdown_tx: tx,
+      running_rx: Some(running_rx),
+      addr,
+    })
+  }
+
+  async fn shutdown(self) -> Result<(), Error> {
+    debug!("shutting down grpc server");
+    self.shutdown_tx.send(()).map_err(|_| {
+      Error::new_context(
+        "grpc",
+        ErrorKind::Shutdown("could not send shutdown signal; server may have already died".to_owned()),
+      )
+    })?;
+    self.handle.await.map_err(|_| {
+      Error::new_context(
+        "grpc",
+        ErrorKind::Shutdown("waiting for server process to stop after sending shutdown signal failed".to_owned()),
+      )
+    })?;
+    Ok(())
+  }
+}
+
+#[derive(Default)]
+pub struct Grpc {
+  instance: Arc<Mutex<Option<GrpcInstance>>>,
+}
+
+impl fmt::Debug for Grpc {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Grpc")
+  }
+}
+
+#[async_trait]
+impl Trigger for Grpc {
+  async fn run(
+    &self,
+    _name: String,
+    runtime: Runtime,
+    _app_config: AppConfiguration,
+    config: TriggerDefinition,
+    resources: Arc<HashMap<BoundIdentifier, Resource>>,
+    span: Span,
+  ) -> Result<StructuredOutput, Error> {
+    span.in_scope(|| debug!(kind = "grpc", "trigger:run"));
+    let TriggerDefinition::Grpc(config) = config else {
+      panic!("invalid trigger definition, expected Grpc configuration");
+    };
+    let resource_name = config.resource();
+    let resource = resources
+      .get(resource_name)
+      .ok_or_else(|| Error::new_context("grpc", ErrorKind::ResourceNotFound(resource_name.clone())))?;
+    let Resource::TcpPort(socket) = resource else {
+      return Err(Error::new_context(
+        "grpc",
+        ErrorKind::InvalidResourceType(ResourceKind::TcpPort, resource.kind()),
+      ));
+    };
+
+    let span = info_span!(parent: &span, "trigger:grpc", service = config.service());
+    let service = GrpcService::new(
+      runtime,
+      config.package().cloned(),
+      config.service(),
+      config.operations(),
+      span.clone(),
+    )?;
+    let proto = service.descriptor.to_proto();
+    span.in_scope(|| debug!(%proto, "generated grpc service definition"));
+    let service_name = service.descriptor.full_name();
+
+    let instance = GrpcInstance::new(Arc::new(service), socket)?;
+
+    let output = StructuredOutput::new(
+      format!("gRPC server for {} started on {}", service_name, instance.addr),
+      json!({"ip": instance.addr.ip(),"port": instance.addr.port(), "service": service_name, "proto": proto}),
+    );
+
+    span.in_scope(|| info!(address=%instance.addr,"grpc trigger started"));
+
+    self.instance.lock().replace(instance);
+
+    Ok(output)
+  }
+
+  async fn shutdown_gracefully(self) -> Result<(), Error> {
+    let Some(instance) = self.instance.lock().take() else {
+      return Ok(());
+    };
+    instance.shutdown().await
+  }
+
+  async fn wait_for_done(&self) -> StructuredOutput {
+    let rx = self.instance.lock().as_mut().and_then(|i| i.running_rx.take());
+    let Some(rx) = rx else {
+      error!("grpc trigger not running");
+      return StructuredOutput::new(
+        "grpc trigger not running",
+        json!({"status": "grpc trigger not running"}),
+      );
+    };
+    match rx.await {
+      Ok(_) => {
+        info!("grpc trigger finished");
+        StructuredOutput::new("grpc trigger finished", json!({"status": "grpc trigger finished"}))
+      }
+      Err(e) => {
+        error!(err=%e,"grpc trigger failed");
+        let message = format!("grpc trigger failed: {}", e);
+        StructuredOutput::new(message.clone(), json!({"status": message}))
+      }
+    }
+  }
+}
+
+impl fmt::Display for Grpc {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "gRPC Trigger")
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use hyper::body::HttpBody;
+  use hyper::Client;
+  use prost::encoding;
+  use wick_trigger::build_trigger_runtime;
+
+  use super::*;
+  use crate::test::load_example;
+
+  #[test_logger::test(tokio::test)]
+  async fn test_grpc_example() -> Result<()> {
+    std::env::set_var("GRPC_PORT", "9015");
+    let app_config = load_example("grpc/grpc.wick").await?.try_app_config()?;
+    let rt = build_trigger_runtime(&app_config, Span::current())?.build(None).await?;
+
+    let trigger = Grpc::default();
+    let resource = Resource::new(app_config.resources().get(0).as_ref().unwrap().kind().clone())?;
+    let resources = Arc::new([("grpc".into(), resource)].iter().cloned().collect());
+    let trigger_config = app_config.triggers()[0].clone();
+    let output = trigger
+      .run(
+        "test".to_owned(),
+        rt,
+        app_config,
+        trigger_config,
+        resources,
+        Span::current(),
+      )
+      .await?;
+    assert!(output.json["proto"]
+      .as_str()
+      .unwrap()
+      .contains("rpc Echo(EchoRequest) returns (EchoResponse);"));
+
+    // Composite operations report their ports as `object`, which are carried as JSON-encoded strings.
+    let mut message = BytesMut::new();
+    encoding::string::encode(1, &r#""hello""#.to_owned(), &mut message);
+
+    let client = Client::builder().http2_only(true).build_http::<Body>();
+    let request = Request::post("http://127.0.0.1:9015/wick.example.Strings/Echo")
+      .header(CONTENT_TYPE, GRPC_CONTENT_TYPE)
+      .body(Body::from(frame(&message)))?;
+    let mut response = client.request(request).await?;
+    let body = hyper::body::to_bytes(response.body_mut()).await?;
+    let trailers = response.trailers().await?.unwrap();
+    assert_eq!(trailers["grpc-status"], "0");
+
+    let mut body = unframe(body)?;
+    let mut output = String::new();
+    let (tag, wire_type) = encoding::decode_key(&mut body)?;
+    assert_eq!(tag, 1);
+    encoding::string::merge(wire_type, &mut output, &mut body, Default::default())?;
+    assert_eq!(output, r#""hello""#);
+
+    let request = Request::post("http://127.0.0.1:9015/wick.example.Strings/Missing")
+      .header(CONTENT_TYPE, GRPC_CONTENT_TYPE)
+      .body(Body::from(frame(&[])))?;
+    let response = client.request(request).await?;
+    assert_eq!(response.headers()["grpc-status"], "12");
+
+    trigger.shutdown_gracefully().await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,75 @@
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum GrpcError {
+  #[error("error in configuration: {0}")]
+  Config(Box<wick_config::Error>),
+
+  #[error("could not find operation '{1}' on component '{0}'")]
+  OperationNotFound(String, String),
+
+  #[error("could not get the signature for component '{0}': {1}")]
+  Signature(String, String),
+
+  #[error("service already has a method named '{0}'")]
+  DuplicateMethod(String),
+
+  #[error("service already has a message named '{0}'")]
+  DuplicateMessage(String),
+
+  #[error("message '{0}' is not defined")]
+  MissingMessage(String),
+
+  #[error("could not decode request: {0}")]
+  Decode(String),
+
+  #[error("could not encode output '{0}': {1}")]
+  Encode(String, String),
+
+  #[error("method '{0}' is not implemented")]
+  Unimplemented(String),
+
+  #[error("compressed messages are not supported")]
+  Compressed,
+
+  #[error("operation failed: {0}")]
+  Operation(String),
+
+  #[error("operation produced more than one value for '{0}'")]
+  MultipleValues(String),
+}
+
+impl GrpcError {
+  /// The gRPC status code to report to clients for this error.
+  pub(crate) const fn code(&self) -> u16 {
+    match self {
+      GrpcError::Decode(_) => 3,                                 // INVALID_ARGUMENT
+      GrpcError::Operation(_) => 2,                              // UNKNOWN
+      GrpcError::Unimplemented(_) | GrpcError::Compressed => 12, // UNIMPLEMENTED
+      _ => 13,                                                   // INTERNAL
+    }
+  }
+}
+
+impl From<GrpcError> for wick_trigger::Error {
+  fn from(value: GrpcError) -> Self {
+    wick_trigger::Error::new_context("grpc", wick_trigger::ErrorKind::Trigger(Box::new(value)))
+  }
+}
+
+impl From<wick_config::Error> for GrpcError {
+  fn from(value: wick_config::Error) -> Self {
+    GrpcError::Config(Box::new(value))
+  }
+}
+
+impl From<prost::DecodeError> for GrpcError {
+  fn from(value: prost::DecodeError) -> Self {
+    GrpcError::Decode(value.to_string())
+  }
+}
+
+impl From<serde_json::Error> for GrpcError {
+  fn from(value: serde_json::Error) -> Self {
+    GrpcError::Decode(value.to_string())
+  }
+}
@@ -0,0 +1,700 @@
+//! Protobuf descriptors generated from Wick operation signatures.
+//!
+//! Scalar wick types map onto their protobuf counterparts, lists become `repeated` fields and named structs
+//! become nested messages. Types that have no direct protobuf representation (maps, unions, anonymous structs,
+//! `object`, and lists of lists) are carried as JSON-encoded strings.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use heck::{AsPascalCase, AsSnakeCase};
+use prost::encoding::{self, DecodeContext, WireType};
+use serde_json::{Map, Value};
+use wick_interface_types::{Field, OperationSignature, Type, TypeDefinition};
+use wick_packet::{Base64Bytes, Packet};
+
+use super::error::GrpcError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Scalar {
+  Bool,
+  Int32,
+  Int64,
+  Uint32,
+  Uint64,
+  Float,
+  Double,
+  String,
+  Bytes,
+  /// A JSON-encoded string for types without a protobuf equivalent.
+  Json,
+}
+
+impl Scalar {
+  const fn proto_name(self) -> &'static str {
+    match self {
+      Scalar::Bool => "bool",
+      Scalar::Int32 => "int32",
+      Scalar::Int64 => "int64",
+      Scalar::Uint32 => "uint32",
+      Scalar::Uint64 => "uint64",
+      Scalar::Float => "float",
+      Scalar::Double => "double",
+      Scalar::String | Scalar::Json => "string",
+      Scalar::Bytes => "bytes",
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FieldType {
+  Scalar(Scalar),
+  Message(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MessageField {
+  pub(crate) name: String,
+  pub(crate) number: u32,
+  pub(crate) ty: FieldType,
+  pub(crate) repeated: bool,
+  pub(crate) optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Message {
+  pub(crate) name: String,
+  pub(crate) fields: Vec<MessageField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Method {
+  pub(crate) name: String,
+  pub(crate) request: String,
+  pub(crate) response: String,
+}
+
+/// A gRPC service and the messages its methods reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ServiceDescriptor {
+  package: Option<String>,
+  name: String,
+  methods: Vec<Method>,
+  messages: BTreeMap<String, Message>,
+}
+
+impl ServiceDescriptor {
+  pub(crate) fn new(package: Option<String>, name: impl AsRef<str>) -> Self {
+    Self {
+      package,
+      name: AsPascalCase(name.as_ref()).to_string(),
+      methods: Vec::new(),
+      messages: BTreeMap::new(),
+    }
+  }
+
+  /// The fully qualified service name used in request paths.
+  pub(crate) fn full_name(&self) -> String {
+    self
+      .package
+      .as_ref()
+      .map_or_else(|| self.name.clone(), |package| format!("{}.{}", package, self.name))
+  }
+
+  /// The HTTP/2 path clients use to call `method`.
+  pub(crate) fn path(&self, method: &Method) -> String {
+    format!("/{}/{}", self.full_name(), method.name)
+  }
+
+  pub(crate) fn message(&self, name: &str) -> Result<&Message, GrpcError> {
+    self
+      .messages
+      .get(name)
+      .ok_or_else(|| GrpcError::MissingMessage(name.to_owned()))
+  }
+
+  /// Add an RPC method for the passed operation, generating its request and response messages.
+  pub(crate) fn add_operation(
+    &mut self,
+    operation: &OperationSignature,
+    types: &[TypeDefinition],
+  ) -> Result<Method, GrpcError> {
+    let name = AsPascalCase(operation.name()).to_string();
+    if self.methods.iter().any(|m| m.name == name) {
+      return Err(GrpcError::DuplicateMethod(name));
+    }
+    let request = format!("{}Request", name);
+    let response = format!("{}Response", name);
+    self.add_message(&request, operation.inputs(), types)?;
+    self.add_message(&response, operation.outputs(), types)?;
+    let method = Method {
+      name,
+      request,
+      response,
+    };
+    self.methods.push(method.clone());
+    Ok(method)
+  }
+
+  fn add_message(&mut self, name: &str, fields: &[Field], types: &[TypeDefinition]) -> Result<(), GrpcError> {
+    if self.messages.contains_key(name) {
+      return Err(GrpcError::DuplicateMessage(name.to_owned()));
+    }
+    let mut in_progress = HashSet::new();
+    let message = self.build_message(name, fields, types, &mut in_progress);
+    self.messages.insert(name.to_owned(), message);
+    Ok(())
+  }
+
+  fn build_message(
+    &mut self,
+    name: &str,
+    fields: &[Field],
+    types: &[TypeDefinition],
+    in_progress: &mut HashSet<String>,
+  ) -> Message {
+    in_progress.insert(name.to_owned());
+    let fields = fields
+      .iter()
+      .enumerate()
+      .map(|(i, field)| {
+        let (ty, repeated, optional) = self.field_type(&field.ty, types, in_progress);
+        MessageField {
+          name: field.name.clone(),
+          number: i as u32 + 1,
+          ty,
+          repeated,
+          optional,
+        }
+      })
+      .collect();
+    in_progress.remove(name);
+    Message {
+      name: name.to_owned(),
+      fields,
+    }
+  }
+
+  /// Map a wick type to its protobuf type, returning whether it is repeated and whether it is optional.
+  fn field_type(
+    &mut self,
+    ty: &Type,
+    types: &[TypeDefinition],
+    in_progress: &mut HashSet<String>,
+  ) -> (FieldType, bool, bool) {
+    match ty {
+      Type::Optional { ty } => {
+        let (ty, repeated, _) = self.field_type(ty, types, in_progress);
+        (ty, repeated, true)
+      }
+      Type::List { ty } => match self.field_type(ty, types, in_progress) {
+        (ty, false, _) => (ty, true, false),
+        (_, true, _) => (FieldType::Scalar(Scalar::Json), false, false),
+      },
+      Type::Named(name) => (self.named_type(name, types, in_progress), false, false),
+      ty => (FieldType::Scalar(scalar_for(ty)), false, false),
+    }
+  }
+
+  fn named_type(&mut self, name: &str, types: &[TypeDefinition], in_progress: &mut HashSet<String>) -> FieldType {
+    match types.iter().find(|t| t.name() == name) {
+      Some(TypeDefinition::Struct(def)) => {
+        let message_name = AsPascalCase(name.rsplit("::").next().unwrap_or(name)).to_string();
+        if !self.messages.contains_key(&message_name) && !in_progress.contains(&message_name) {
+          let message = self.build_message(&message_name, &def.fields, types, in_progress);
+          self.messages.insert(message_name.clone(), message);
+        }
+        FieldType::Message(message_name)
+      }
+      Some(TypeDefinition::Enum(_)) => FieldType::Scalar(Scalar::String),
+      _ => FieldType::Scalar(Scalar::Json),
+    }
+  }
+
+  /// Render the service as a `.proto` file that clients can generate code from.
+  pub(crate) fn to_proto(&self) -> String {
+    let mut proto = String::from("syntax = \"proto3\";\n\n");
+    if let Some(package) = &self.package {
+      let _ = writeln!(proto, "package {};\n", package);
+    }
+    let _ = writeln!(proto, "service {} {{", self.name);
+    for method in &self.methods {
+      let _ = writeln!(
+        proto,
+        "  rpc {}({}) returns ({});",
+        method.name, method.request, method.response
+      );
+    }
+    proto.push_str("}\n");
+    for message in self.messages.values() {
+      let _ = writeln!(proto, "\nmessage {} {{", message.name);
+      for field in &message.fields {
+        let ty = match &field.ty {
+          FieldType::Scalar(s) => s.proto_name(),
+          FieldType::Message(m) => m.as_str(),
+        };
+        let label = if field.repeated {
+          "repeated "
+        } else if field.optional {
+          "optional "
+        } else {
+          ""
+        };
+        let _ = writeln!(
+          proto,
+          "  {}{} {} = {};",
+          label,
+          ty,
+          AsSnakeCase(&field.name),
+          field.number
+        );
+      }
+      proto.push_str("}\n");
+    }
+    proto
+  }
+
+  /// Decode a request message into one packet per field plus the `done` signals for each port.
+  pub(crate) fn decode_request(&self, method: &Method, mut buf: impl Buf) -> Result<Vec<Packet>, GrpcError> {
+    let message = self.message(&method.request)?;
+    let values = self.decode_fields(message, &mut buf)?;
+    let mut packets = Vec::with_capacity(message.fields.len() * 2);
+    for (field, value) in message.fields.iter().zip(values) {
+      let packet = match value.finish(field, self)? {
+        Decoded::Bytes(bytes) => Packet::encode(&field.name, Base64Bytes::new(bytes)),
+        Decoded::BytesList(list) => {
+          Packet::encode(&field.name, list.into_iter().map(Base64Bytes::new).collect::<Vec<_>>())
+        }
+        Decoded::Json(value) => Packet::encode(&field.name, value),
+      };
+      packets.push(packet);
+    }
+    for field in &message.fields {
+      packets.push(Packet::done(&field.name));
+    }
+    Ok(packets)
+  }
+
+  /// Encode a response message from the first packet received on each output port.
+  pub(crate) fn encode_response(
+    &self,
+    method: &Method,
+    outputs: &BTreeMap<String, Packet>,
+  ) -> Result<Bytes, GrpcError> {
+    let message = self.message(&method.response)?;
+    let mut buf = BytesMut::new();
+    for field in &message.fields {
+      let Some(packet) = outputs.get(&field.name) else {
+        continue;
+      };
+      if field.ty == FieldType::Scalar(Scalar::Bytes) {
+        if field.repeated {
+          let list: Vec<Base64Bytes> = packet
+            .decode()
+            .map_err(|e| GrpcError::Encode(field.name.clone(), e.to_string()))?;
+          let list: Vec<Vec<u8>> = list.into_iter().map(Into::into).collect();
+          encoding::bytes::encode_repeated(field.number, &list, &mut buf);
+        } else {
+          let bytes: Base64Bytes = packet
+            .decode()
+            .map_err(|e| GrpcError::Encode(field.name.clone(), e.to_string()))?;
+          let bytes: Vec<u8> = bytes.into();
+          encoding::bytes::encode(field.number, &bytes, &mut buf);
+        }
+        continue;
+      }
+      let value = packet
+        .decode_value()
+        .map_err(|e| GrpcError::Encode(field.name.clone(), e.to_string()))?;
+      self.encode_field(field, &value, &mut buf)?;
+    }
+    Ok(buf.freeze())
+  }
+
+  fn decode_fields(&self, message: &Message, buf: &mut impl Buf) -> Result<Vec<Accumulator>, GrpcError> {
+    let mut values: Vec<_> = message.fields.iter().map(Accumulator::new).collect();
+    let ctx = DecodeContext::default();
+    while buf.has_remaining() {
+      let (number, wire_type) = encoding::decode_key(buf)?;
+      let Some(index) = message.fields.iter().position(|f| f.number == number) else {
+        encoding::skip_field(wire_type, number, buf, ctx.clone())?;
+        continue;
+      };
+      match &mut values[index] {
+        Accumulator::Bool(v) => encoding::bool::merge_repeated(wire_type, v, buf, ctx.clone())?,
+        Accumulator::Int32(v) => encoding::int32::merge_repeated(wire_type, v, buf, ctx.clone())?,
+        Accumulator::Int64(v) => encoding::int64::merge_repeated(wire_type, v, buf, ctx.clone())?,
+        Accumulator::Uint32(v) => encoding::uint32::merge_repeated(wire_type, v, buf, ctx.clone())?,
+        Accumulator::Uint64(v) => encoding::uint64::merge_repeated(wire_type, v, buf, ctx.clone())?,
+        Accumulator::Float(v) => encoding::float::merge_repeated(wire_type, v, buf, ctx.clone())?,
+        Accumulator::Double(v) => encoding::double::merge_repeated(wire_type, v, buf, ctx.clone())?,
+        Accumulator::String(v) | Accumulator::Json(v) => {
+          encoding::string::merge_repeated(wire_type, v, buf, ctx.clone())?;
+        }
+        Accumulator::Bytes(v) => encoding::bytes::merge_repeated(wire_type, v, buf, ctx.clone())?,
+        Accumulator::Message(v) => {
+          encoding::check_wire_type(WireType::LengthDelimited, wire_type)?;
+          let len = encoding::decode_varint(buf)? as usize;
+          if len > buf.remaining() {
+            return Err(GrpcError::Decode("message length exceeds buffer".to_owned()));
+          }
+          v.push(buf.copy_to_bytes(len));
+        }
+      }
+    }
+    Ok(values)
+  }
+
+  fn decode_object(&self, name: &str, mut buf: Bytes) -> Result<Value, GrpcError> {
+    let message = self.message(name)?;
+    let values = self.decode_fields(message, &mut buf)?;
+    let mut map = Map::new();
+    for (field, value) in message.fields.iter().zip(values) {
+      let value = match value.finish(field, self)? {
+        Decoded::Json(v) => v,
+        Decoded::Bytes(bytes) => serde_json::to_value(Base64Bytes::new(bytes))?,
+        Decoded::BytesList(list) => serde_json::to_value(list.into_iter().map(Base64Bytes::new).collect::<Vec<_>>())?,
+      };
+      map.insert(field.name.clone(), value);
+    }
+    Ok(Value::Object(map))
+  }
+
+  fn encode_field(&self, field: &MessageField, value: &Value, buf: &mut BytesMut) -> Result<(), GrpcError> {
+    if value.is_null() {
+      return Ok(());
+    }
+    if field.repeated {
+      let Value::Array(list) = value else {
+        return Err(GrpcError::Encode(
+          field.name.clone(),
+          format!("expected a list, got {}", value),
+        ));
+      };
+      for item in list {
+        self.encode_value(field, item, buf)?;
+      }
+      Ok(())
+    } else {
+      self.encode_value(field, value, buf)
+    }
+  }
+
+  fn encode_value(&self, field: &MessageField, value: &Value, buf: &mut BytesMut) -> Result<(), GrpcError> {
+    let tag = field.number;
+    let mismatch = || GrpcError::Encode(field.name.clone(), format!("unexpected value {}", value));
+    match &field.ty {
+      FieldType::Message(name) => {
+        let Value::Object(map) = value else {
+          return Err(mismatch());
+        };
+        let message = self.message(name)?;
+        let mut inner = BytesMut::new();
+        for field in &message.fields {
+          if let Some(value) = map.get(&field.name) {
+            if field.ty == FieldType::Scalar(Scalar::Bytes) {
+              encode_json_bytes(field, value, &mut inner)?;
+            } else {
+              self.encode_field(field, value, &mut inner)?;
+            }
+          }
+        }
+        encoding::encode_key(tag, WireType::LengthDelimited, buf);
+        encoding::encode_varint(inner.len() as u64, buf);
+        buf.put(inner);
+      }
+      FieldType::Scalar(scalar) => match scalar {
+        Scalar::Bool => encoding::bool::encode(tag, &value.as_bool().ok_or_else(mismatch)?, buf),
+        Scalar::Int32 => {
+          let v = value.as_i64().ok_or_else(mismatch)?;
+          encoding::int32::encode(tag, &i32::try_from(v).map_err(|_| mismatch())?, buf);
+        }
+        Scalar::Int64 => encoding::int64::encode(tag, &value.as_i64().ok_or_else(mismatch)?, buf),
+        Scalar::Uint32 => {
+          let v = value.as_u64().ok_or_else(mismatch)?;
+          encoding::uint32::encode(tag, &u32::try_from(v).map_err(|_| mismatch())?, buf);
+        }
+        Scalar::Uint64 => encoding::uint64::encode(tag, &value.as_u64().ok_or_else(mismatch)?, buf),
+        Scalar::Float => encoding::float::encode(tag, &(value.as_f64().ok_or_else(mismatch)? as f32), buf),
+        Scalar::Double => encoding::double::encode(tag, &value.as_f64().ok_or_else(mismatch)?, buf),
+        Scalar::String => {
+          let v = value.as_str().map_or_else(|| value.to_string(), ToOwned::to_owned);
+          encoding::string::encode(tag, &v, buf);
+        }
+        Scalar::Json => encoding::string::encode(tag, &value.to_string(), buf),
+        Scalar::Bytes => encode_json_bytes(field, value, buf)?,
+      },
+    }
+    Ok(())
+  }
+}
+
+const fn scalar_for(ty: &Type) -> Scalar {
+  match ty {
+    Type::Bool => Scalar::Bool,
+    Type::I8 | Type::I16 | Type::I32 => Scalar::Int32,
+    Type::I64 => Scalar::Int64,
+    Type::U8 | Type::U16 | Type::U32 => Scalar::Uint32,
+    Type::U64 => Scalar::Uint64,
+    Type::F32 => Scalar::Float,
+    Type::F64 => Scalar::Double,
+    Type::String | Type::Datetime => Scalar::String,
+    Type::Bytes => Scalar::Bytes,
+    _ => Scalar::Json,
+  }
+}
+
+fn encode_json_bytes(field: &MessageField, value: &Value, buf: &mut BytesMut) -> Result<(), GrpcError> {
+  if value.is_null() {
+    return Ok(());
+  }
+  let err = |e: serde_json::Error| GrpcError::Encode(field.name.clone(), e.to_string());
+  if field.repeated {
+    let list: Vec<Base64Bytes> = serde_json::from_value(value.clone()).map_err(err)?;
+    let list: Vec<Vec<u8>> = list.into_iter().map(Into::into).collect();
+    encoding::bytes::encode_repeated(field.number, &list, buf);
+  } else {
+    let bytes: Base64Bytes = serde_json::from_value(value.clone()).map_err(err)?;
+    let bytes: Vec<u8> = bytes.into();
+    encoding::bytes::encode(field.number, &bytes, buf);
+  }
+  Ok(())
+}
+
+/// Field values collected while decoding, before they are converted into packet data.
+enum Accumulator {
+  Bool(Vec<bool>),
+  Int32(Vec<i32>),
+  Int64(Vec<i64>),
+  Uint32(Vec<u32>),
+  Uint64(Vec<u64>),
+  Float(Vec<f32>),
+  Double(Vec<f64>),
+  String(Vec<String>),
+  Json(Vec<String>),
+  Bytes(Vec<Vec<u8>>),
+  Message(Vec<Bytes>),
+}
+
+enum Decoded {
+  Json(Value),
+  Bytes(Vec<u8>),
+  BytesList(Vec<Vec<u8>>),
+}
+
+impl Accumulator {
+  const fn new(field: &MessageField) -> Self {
+    match &field.ty {
+      FieldType::Message(_) => Accumulator::Message(Vec::new()),
+      FieldType::Scalar(s) => match s {
+        Scalar::Bool => Accumulator::Bool(Vec::new()),
+        Scalar::Int32 => Accumulator::Int32(Vec::new()),
+        Scalar::Int64 => Accumulator::Int64(Vec::new()),
+        Scalar::Uint32 => Accumulator::Uint32(Vec::new()),
+        Scalar::Uint64 => Accumulator::Uint64(Vec::new()),
+        Scalar::Float => Accumulator::Float(Vec::new()),
+        Scalar::Double => Accumulator::Double(Vec::new()),
+        Scalar::String => Accumulator::String(Vec::new()),
+        Scalar::Json => Accumulator::Json(Vec::new()),
+        Scalar::Bytes => Accumulator::Bytes(Vec::new()),
+      },
+    }
+  }
+
+  /// Convert the collected values into packet data. Singular fields take the last value on the wire, as
+  /// protobuf requires, and absent optional fields become `null`.
+  fn finish(self, field: &MessageField, service: &ServiceDescriptor) -> Result<Decoded, GrpcError> {
+    fn json<T: Into<Value> + Default>(mut list: Vec<T>, field: &MessageField) -> Value {
+      if field.repeated {
+        Value::Array(list.into_iter().map(Into::into).collect())
+      } else if list.is_empty() && field.optional {
+        Value::Null
+      } else {
+        list.pop().unwrap_or_default().into()
+      }
+    }
+
+    Ok(match self {
+      Accumulator::Bool(v) => Decoded::Json(json(v, field)),
+      Accumulator::Int32(v) => Decoded::Json(json(v, field)),
+      Accumulator::Int64(v) => Decoded::Json(json(v, field)),
+      Accumulator::Uint32(v) => Decoded::Json(json(v, field)),
+      Accumulator::Uint64(v) => Decoded::Json(json(v, field)),
+      Accumulator::Float(v) => Decoded::Json(json(v, field)),
+      Accumulator::Double(v) => Decoded::Json(json(v, field)),
+      Accumulator::String(v) => Decoded::Json(json(v, field)),
+      Accumulator::Json(mut v) => {
+        if field.repeated {
+          Decoded::Json(Value::Array(
+            v.iter().map(|s| serde_json::from_str(s)).collect::<Result<_, _>>()?,
+          ))
+        } else {
+          match v.pop() {
+            Some(json) => Decoded::Json(serde_json::from_str(&json)?),
+            None => Decoded::Json(Value::Null),
+          }
+        }
+      }
+      Accumulator::Bytes(mut v) => {
+        if field.repeated {
+          Decoded::BytesList(v)
+        } else if v.is_empty() && field.optional {
+          Decoded::Json(Value::Null)
+        } else {
+          Decoded::Bytes(v.pop().unwrap_or_default())
+        }
+      }
+      Accumulator::Message(mut v) => {
+        let FieldType::Message(name) = &field.ty else {
+          unreachable!("message accumulators are only created for message fields");
+        };
+        if field.repeated {
+          Decoded::Json(Value::Array(
+            v.into_iter()
+              .map(|b| service.decode_object(name, b))
+              .collect::<Result<_, _>>()?,
+          ))
+        } else {
+          match v.pop() {
+            Some(bytes) => Decoded::Json(service.decode_object(name, bytes)?),
+            None if field.optional => Decoded::Json(Value::Null),
+            None => Decoded::Json(service.decode_object(name, Bytes::new())?),
+          }
+        }
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use wick_interface_types::StructDefinition;
+  use wick_packet::PacketExt;
+
+  use super::*;
+
+  fn service() -> Result<(ServiceDescriptor, Method)> {
+    let types = vec![TypeDefinition::Struct(StructDefinition::new(
+      "point",
+      vec![Field::new("x", Type::I32), Field::new("y", Type::I32)],
+      None,
+    ))];
+    let op = OperationSignature::new_named("get_thing")
+      .add_input("name", Type::String)
+      .add_input("count", Type::U32)
+      .add_input(
+        "tags",
+        Type::List {
+          ty: Box::new(Type::String),
+        },
+      )
+      .add_input("point", Type::Named("point".to_owned()))
+      .add_input("data", Type::Bytes)
+      .add_output(
+        "output",
+        Type::Optional {
+          ty: Box::new(Type::F64),
+        },
+      )
+      .add_output("extra", Type::Object);
+    let mut service = ServiceDescriptor::new(Some("wick.test".to_owned()), "things");
+    let method = service.add_operation(&op, &types)?;
+    Ok((service, method))
+  }
+
+  #[test]
+  fn test_path() -> Result<()> {
+    let (service, method) = service()?;
+    assert_eq!(service.path(&method), "/wick.test.Things/GetThing");
+    Ok(())
+  }
+
+  #[test]
+  fn test_to_proto() -> Result<()> {
+    let (service, _) = service()?;
+    let expected = r#"syntax = "proto3";
+
+package wick.test;
+
+service Things {
+  rpc GetThing(GetThingRequest) returns (GetThingResponse);
+}
+
+message GetThingRequest {
+  string name = 1;
+  uint32 count = 2;
+  repeated string tags = 3;
+  Point point = 4;
+  bytes data = 5;
+}
+
+message GetThingResponse {
+  optional double output = 1;
+  string extra = 2;
+}
+
+message Point {
+  int32 x = 1;
+  int32 y = 2;
+}
+"#;
+    assert_eq!(service.to_proto(), expected);
+    Ok(())
+  }
+
+  #[test]
+  fn test_decode_request() -> Result<()> {
+    let (service, method) = service()?;
+    let mut buf = BytesMut::new();
+    encoding::string::encode(1, &"hello".to_owned(), &mut buf);
+    encoding::uint32::encode(2, &3, &mut buf);
+    encoding::string::encode_repeated(3, &["a".to_owned(), "b".to_owned()], &mut buf);
+    let mut point = BytesMut::new();
+    encoding::int32::encode(1, &-4, &mut point);
+    encoding::int32::encode(2, &7, &mut point);
+    encoding::encode_key(4, WireType::LengthDelimited, &mut buf);
+    encoding::encode_varint(point.len() as u64, &mut buf);
+    buf.put(point);
+    // unknown fields are ignored.
+    encoding::string::encode(99, &"ignored".to_owned(), &mut buf);
+
+    let packets = service.decode_request(&method, buf.freeze())?;
+    let values: Vec<_> = packets
+      .iter()
+      .filter(|p| p.has_data())
+      .map(|p| (p.port().to_owned(), p.decode_value().unwrap()))
+      .collect();
+    assert_eq!(values[0], ("name".to_owned(), json!("hello")));
+    assert_eq!(values[1], ("count".to_owned(), json!(3)));
+    assert_eq!(values[2], ("tags".to_owned(), json!(["a", "b"])));
+    assert_eq!(values[3], ("point".to_owned(), json!({"x": -4, "y": 7})));
+    let data: Base64Bytes = packets[4].decode()?;
+    assert_eq!(data, Base64Bytes::default());
+    assert_eq!(packets.iter().filter(|p| p.is_done()).count(), 5);
+    Ok(())
+  }
+
+  #[test]
+  fn test_encode_response() -> Result<()> {
+    let (service, method) = service()?;
+    let outputs: BTreeMap<_, _> = [
+      ("output".to_owned(), Packet::encode("output", 1.5)),
+      ("extra".to_owned(), Packet::encode("extra", json!({"a": [1, 2]}))),
+    ]
+    .into_iter()
+    .collect();
+    let bytes = service.encode_response(&method, &outputs)?;
+    let mut expected = BytesMut::new();
+    encoding::double::encode(1, &1.5, &mut expected);
+    encoding::string::encode(2, &r#"{"a":[1,2]}"#.to_owned(), &mut expected);
+    assert_eq!(bytes, expected.freeze());
+
+    let decoded = service.decode_object(&method.response, bytes)?;
+    assert_eq!(decoded, json!({"output": 1.5, "extra": {"a": [1, 2]}}));
+    Ok(())
+  }
+}
@@ -15,10 +15,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 pub(crate) use error::HttpError;
 use hyper::{Body, Request, Response, Server};
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 use routers::{HttpRouter, RawRouterHandler, RouterOperation};
 use serde_json::json;
-use service_factory::ServiceFactory;
+use service_factory::{ActiveRoutes, ServiceFactory, SharedRoutes};
 use structured_output::StructuredOutput;
 use tokio::task::JoinHandle;
 use tracing::Span;
@@ -26,7 +26,7 @@ use uuid::Uuid;
 use wick_config::config::{self, AppConfiguration, BoundIdentifier, TriggerDefinition};
 use wick_runtime::Runtime;
 use wick_trigger::resources::{Resource, ResourceKind};
-use wick_trigger::{Error, ErrorKind, Trigger};
+use wick_trigger::{Error, ErrorKind, ReloadCommit, Trigger};
 type BoxFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
 
 trait RawRouter {
@@ -44,19 +44,20 @@ trait RawRouter {
 #[must_use]
 struct HttpInstance {
   handle: JoinHandle<()>,
+  routes: SharedRoutes,
   shutdown_tx: tokio::sync::oneshot::Sender<()>,
   running_rx: Option<tokio::sync::oneshot::Receiver<()>>,
   pub(super) addr: SocketAddr,
 }
 
 impl HttpInstance {
-  async fn new(runtime: Runtime, routers: Vec<HttpRouter>, initiating_span: &Span, socket: &SocketAddr) -> Self {
+  async fn new(routes: SharedRoutes, initiating_span: &Span, socket: &SocketAddr) -> Self {
     let span = info_span!(parent:initiating_span,"http:server", %socket);
 
     span.in_scope(|| trace!(%socket,"http server starting"));
     let (tx, rx) = tokio::sync::oneshot::channel::<()>();
     let (running_tx, running_rx) = tokio::sync::oneshot::channel::<()>();
-    let server = Server::bind(socket).serve(ServiceFactory::new(runtime, routers, span.id()));
+    let server = Server::bind(socket).serve(ServiceFactory::new(routes.clone(), span.id()));
     let shutdown_span = span.clone();
     let handle = tokio::spawn(async move {
       let _ = server
@@ -74,6 +75,7 @@ impl HttpInstance {
 
     Self {
       handle,
+      routes,
       shutdown_tx: tx,
       running_rx: Some(running_rx),
       addr: *socket,
@@ -110,6 +112,51 @@ impl std::fmt::Debug for Http {
   }
 }
 
+fn resolve_socket(
+  config: &config::HttpTriggerConfig,
+  resources: &HashMap<BoundIdentifier, Resource>,
+) -> Result<SocketAddr, Error> {
+  let resource_name = config.resource();
+  let resource = resources
+    .get(resource_name)
+    .ok_or_else(|| Error::new_context("http", ErrorKind::ResourceNotFound(resource_name.clone())))?;
+  match resource {
+    Resource::TcpPort(s) => Ok(*s),
+    _ => Err(Error::new_context(
+      "http",
+      ErrorKind::InvalidResourceType(ResourceKind::TcpPort, resource.kind()),
+    )),
+  }
+}
+
+fn build_routers(
+  runtime: &Runtime,
+  app_config: &AppConfiguration,
+  config: &config::HttpTriggerConfig,
+  resources: Arc<HashMap<BoundIdentifier, Resource>>,
+  span: &Span,
+) -> Result<Vec<HttpRouter>, Error> {
+  span.in_scope(|| {
+    let mut routers = Vec::new();
+    for (i, router) in config.routers().iter().enumerate() {
+      info!(path = router.path(), kind = %router.kind(), "registering http router");
+
+      let router = match router {
+        config::HttpRouterConfig::RawRouter(r) => routers::raw::register_raw_router(i, r)?,
+        config::HttpRouterConfig::StaticRouter(r) => routers::static_::register_static_router(i, resources.clone(), r)?,
+        config::HttpRouterConfig::ProxyRouter(r) => routers::proxy::register_proxy_router(i, resources.clone(), r)?,
+        config::HttpRouterConfig::RestRouter(r) => {
+          routers::rest::register_rest_router(i, resources.clone(), runtime, app_config, r)?
+        }
+      };
+
+      routers.push(router);
+    }
+    debug!(?routers, "http routers");
+    Ok::<_, Error>(routers)
+  })
+}
+
 #[async_trait]
 impl Trigger for Http {
   async fn run(
@@ -125,45 +172,14 @@ impl Trigger for Http {
     let config::TriggerDefinition::Http(config) = config else {
       panic!("invalid trigger definition, expected Http configuraton");
     };
-    let resource_name = config.resource();
-    let resource = resources
-      .get(resource_name)
-      .ok_or_else(|| Error::new_context("http", ErrorKind::ResourceNotFound(resource_name.clone())))?;
-    let socket = match resource {
-      Resource::TcpPort(s) => *s,
-      _ => {
-        return Err(Error::new_context(
-          "http",
-          ErrorKind::InvalidResourceType(ResourceKind::TcpPort, resource.kind()),
-        ))
-      }
-    };
+    let socket = resolve_socket(&config, &resources)?;
 
     let span = info_span!(parent: &span,"trigger:http:routers");
 
-    let routers = span.in_scope(|| {
-      let mut routers = Vec::new();
-      for (i, router) in config.routers().iter().enumerate() {
-        info!(path = router.path(), kind = %router.kind(), "registering http router");
+    let routers = build_routers(&runtime, &app_config, &config, resources, &span)?;
 
-        let router = match router {
-          config::HttpRouterConfig::RawRouter(r) => routers::raw::register_raw_router(i, r)?,
-          config::HttpRouterConfig::StaticRouter(r) => {
-            routers::static_::register_static_router(i, resources.clone(), r)?
-          }
-          config::HttpRouterConfig::ProxyRouter(r) => routers::proxy::register_proxy_router(i, resources.clone(), r)?,
-          config::HttpRouterConfig::RestRouter(r) => {
-            routers::rest::register_rest_router(i, resources.clone(), &app_config, r)?
-          }
-        };
-
-        routers.push(router);
-      }
-      debug!(?routers, "http routers");
-      Ok::<_, Error>(routers)
-    })?;
-
-    let instance = HttpInstance::new(runtime, routers, &span, &socket).await;
+    let routes = Arc::new(RwLock::new(ActiveRoutes::new(runtime, routers)));
+    let instance = HttpInstance::new(routes, &span, &socket).await;
 
     let output = StructuredOutput::new(
       format!("HTTP Server started on {}", instance.addr),
@@ -191,6 +207,37 @@ impl Trigger for Http {
     Ok(())
   }
 
+  async fn prepare_reload(
+    &self,
+    runtime: Runtime,
+    app_config: AppConfiguration,
+    config: TriggerDefinition,
+    resources: Arc<HashMap<BoundIdentifier, Resource>>,
+    span: Span,
+  ) -> Result<Option<ReloadCommit>, Error> {
+    let config::TriggerDefinition::Http(config) = config else {
+      panic!("invalid trigger definition, expected Http configuraton");
+    };
+    let socket = resolve_socket(&config, &resources)?;
+    let routes = self
+      .instance
+      .lock()
+      .as_ref()
+      .filter(|instance| instance.addr == socket)
+      .map(|instance| instance.routes.clone());
+    let Some(routes) = routes else {
+      // Moving to a different address means binding a new server.
+      return Ok(None);
+    };
+
+    let span = info_span!(parent: &span,"trigger:http:reload");
+    let routers = build_routers(&runtime, &app_config, &config, resources, &span)?;
+
+    Ok(Some(Box::new(move || {
+      *routes.write() = ActiveRoutes::new(runtime, routers);
+    })))
+  }
+
   async fn wait_for_done(&self) -> StructuredOutput {
     let rx = if let Some(instance) = self.instance.lock().as_mut() {
       instance.running_rx.take()
@@ -227,6 +274,23 @@ impl fmt::Display for Http {
 
 #[cfg(test)]
 mod test {
+  use anyhow::Result;
+  use tracing::Span;
+  use wick_trigger::build_trigger_runtime;
+
+  use crate::test::load_test_manifest;
+
+  #[test_logger::test(tokio::test)]
+  async fn test_build_runtime_with_imports() -> Result<()> {
+    // Apps with imports don't fetch types up front, so building their runtime must not require them.
+    let app_config = load_test_manifest("app_config/rest-router-errors.wick")
+      .await?
+      .try_app_config()?;
+    let rt = build_trigger_runtime(&app_config, Span::current())?.build(None).await?;
+    rt.shutdown().await?;
+
+    Ok(())
+  }
 
   // "port_limited" tests are grouped together and run on a single thread to prevent port contention
   mod port_limited {
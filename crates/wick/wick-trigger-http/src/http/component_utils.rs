@@ -12,6 +12,7 @@ use tracing::Span;
 use uuid::Uuid;
 use wick_config::config::Codec;
 use wick_interface_http::types::{self as wick_http};
+use wick_interface_types::{Field, Type};
 use wick_packet::{
   packets,
   Base64Bytes,
@@ -332,6 +333,75 @@ fn reset_header(mut builder: Builder, header: HeaderName, value: impl Into<Heade
   builder
 }
 
+/// Encode each packet of an operation's output as soon as it arrives, either as a line of JSON or as a server-sent
+/// event named after the packet's port.
+pub(super) fn stream_to_body(stream: PacketStream, sse: bool) -> Body {
+  let chunks = stream.filter_map(move |packet| async move {
+    let p = match packet {
+      Ok(p) => p,
+      Err(e) => return Some(Err(HttpError::OperationError(e.to_string()))),
+    };
+    if let PacketPayload::Err(e) = p.payload() {
+      let error = serde_json::json!({"port": p.port(), "message": e.msg()});
+      return Some(Ok(encode_chunk("error", error, sse)));
+    }
+    if !p.has_data() {
+      return None;
+    }
+    Some(
+      p.decode_value()
+        .map_err(|e| HttpError::Codec(Codec::Json, e.to_string()))
+        .map(|v| encode_chunk(p.port(), v, sse)),
+    )
+  });
+  Body::wrap_stream(chunks)
+}
+
+fn encode_chunk(port: &str, value: Value, sse: bool) -> Vec<u8> {
+  if sse {
+    to_sse_string_bytes(&wick_http::HttpEvent {
+      event: port.to_owned(),
+      data: value.to_string(),
+      id: String::new(),
+      retry: None,
+    })
+  } else {
+    let mut map = Map::new();
+    map.insert(port.to_owned(), value);
+    let mut line = Value::Object(map).to_string();
+    line.push('\n');
+    line.into()
+  }
+}
+
+/// Collect the data an operation sends on the `output` port into a response body. Bytes and strings are sent as they
+/// are and anything else as JSON.
+pub(super) async fn stream_to_media(mut stream: PacketStream, output: &Field) -> Result<Vec<u8>, HttpError> {
+  let mut body = Vec::new();
+  while let Some(packet) = stream.next().await {
+    let p = packet.map_err(|e| HttpError::OperationError(e.to_string()))?;
+    if let PacketPayload::Err(err) = p.payload() {
+      return Err(HttpError::OutputStream(p.port().to_owned(), err.msg().to_owned()));
+    }
+    if p.port() != output.name() || !p.has_data() {
+      continue;
+    }
+    if matches!(output.ty(), Type::Bytes) {
+      let bytes: Base64Bytes = p.decode().map_err(|e| HttpError::Bytes(e.to_string()))?;
+      body.extend_from_slice(&bytes);
+      continue;
+    }
+    match p
+      .decode_value()
+      .map_err(|e| HttpError::Codec(Codec::Json, e.to_string()))?
+    {
+      Value::String(s) => body.extend_from_slice(s.as_bytes()),
+      value => body.extend(value.to_string().into_bytes()),
+    }
+  }
+  Ok(body)
+}
+
 enum MapVal {
   RawVal(Value),
   RootArray(Vec<Value>),
@@ -393,3 +463,27 @@ pub(super) async fn stream_to_json(stream: PacketStream) -> Result<Value, HttpEr
   );
   Ok(json)
 }
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_stream_to_media() -> Result<()> {
+    let stream = PacketStream::from(vec![
+      Packet::encode("html", "<p>hello</p>"),
+      Packet::encode("status", 200),
+      Packet::encode("html", "<p>world</p>"),
+      Packet::done("html"),
+    ]);
+    let output = Field::new("html", Type::String).with_content_type(Some("text/html".to_owned()));
+    assert_eq!(stream_to_media(stream, &output).await?, b"<p>hello</p><p>world</p>");
+
+    let stream = PacketStream::from(vec![Packet::encode("image", Base64Bytes::new(vec![0_u8, 1, 2]))]);
+    let output = Field::new("image", Type::Bytes);
+    assert_eq!(stream_to_media(stream, &output).await?, [0_u8, 1, 2]);
+    Ok(())
+  }
+}
@@ -25,6 +25,9 @@ pub enum HttpError {
   #[error("Could not decode body as JSON: {0}")]
   InvalidBody(serde_json::Error),
 
+  #[error("Could not read request body: {0}")]
+  ReadBody(String),
+
   #[error("Invalid status code: {0}")]
   InvalidStatusCode(String),
 
@@ -1,20 +1,34 @@
+mod auth;
+mod cors;
+
 use wick_config::config::WickRouter;
 use wick_packet::{Entity, RuntimeConfig};
 
+pub(crate) use self::auth::Authenticator;
+pub(crate) use self::cors::Cors;
 use crate::HttpError;
 
 #[derive(Debug, Clone)]
 pub(crate) struct RouterMiddleware {
   pub(crate) request: Vec<(Entity, Option<RuntimeConfig>)>,
   pub(crate) response: Vec<(Entity, Option<RuntimeConfig>)>,
+  pub(crate) auth: Option<Authenticator>,
+  pub(crate) cors: Option<Cors>,
 }
 
 impl RouterMiddleware {
   pub(crate) fn new(
     request: Vec<(Entity, Option<RuntimeConfig>)>,
     response: Vec<(Entity, Option<RuntimeConfig>)>,
+    auth: Option<Authenticator>,
+    cors: Option<Cors>,
   ) -> Self {
-    Self { request, response }
+    Self {
+      request,
+      response,
+      auth,
+      cors,
+    }
   }
 }
 
@@ -37,6 +51,8 @@ pub(super) fn resolve_middleware_components(router: &impl WickRouter) -> Result<
       ));
     }
   }
-  let middleware = RouterMiddleware::new(request_operations, response_operations);
+  let auth = router.auth().map(Authenticator::new).transpose()?;
+  let cors = router.cors().map(Cors::new);
+  let middleware = RouterMiddleware::new(request_operations, response_operations, auth, cors);
   Ok(middleware)
 }
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use hyper::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+use hyper::{Body, Request, Response, StatusCode};
+use ring::hmac;
+use serde_json::Value;
+use wick_config::config::{AuthConfig, TemplateConfig};
+
+use crate::HttpError;
+
+/// Checks requests against a router's built-in authentication before any other middleware runs.
+#[derive(Debug, Clone)]
+pub(crate) enum Authenticator {
+  Jwt {
+    secret: Vec<u8>,
+    issuer: Option<String>,
+    audience: Option<String>,
+  },
+  ApiKey {
+    header: String,
+    keys: Vec<String>,
+  },
+  Basic {
+    realm: String,
+    users: HashMap<String, String>,
+  },
+}
+
+impl Authenticator {
+  pub(crate) fn new(config: &AuthConfig) -> Result<Self, HttpError> {
+    let auth = match config {
+      AuthConfig::Jwt(jwt) => Self::Jwt {
+        secret: rendered(jwt.secret())?.into_bytes(),
+        issuer: jwt.issuer().cloned(),
+        audience: jwt.audience().cloned(),
+      },
+      AuthConfig::ApiKey(api_key) => Self::ApiKey {
+        header: api_key.header().to_owned(),
+        keys: api_key.keys().iter().map(rendered).collect::<Result<_, _>>()?,
+      },
+      AuthConfig::Basic(basic) => Self::Basic {
+        realm: basic.realm().cloned().unwrap_or_else(|| "wick".to_owned()),
+        users: basic
+          .users()
+          .iter()
+          .map(|(user, password)| Ok((user.clone(), rendered(password)?)))
+          .collect::<Result<_, HttpError>>()?,
+      },
+    };
+    Ok(auth)
+  }
+
+  /// Check a request's credentials, returning a 401 response if they are missing or invalid.
+  pub(crate) fn authenticate<B>(&self, req: &Request<B>) -> Option<Response<Body>> {
+    let result = match self {
+      Self::Jwt {
+        secret,
+        issuer,
+        audience,
+      } => bearer_token(req)
+        .ok_or("missing bearer token")
+        .and_then(|token| verify_jwt(token, secret, issuer.as_deref(), audience.as_deref(), now())),
+      Self::ApiKey { header, keys } => {
+        let key = req.headers().get(header.as_str()).map(|v| v.as_bytes());
+        match key {
+          Some(key) if keys.iter().any(|k| constant_time_eq(k.as_bytes(), key)) => Ok(()),
+          Some(_) => Err("invalid API key"),
+          None => Err("missing API key"),
+        }
+      }
+      Self::Basic { users, .. } => basic_credentials(req)
+        .ok_or("missing or malformed basic credentials")
+        .and_then(|(user, password)| {
+          let valid = users
+            .get(&user)
+            .map_or(false, |expected| constant_time_eq(expected.as_bytes(), password.as_bytes()));
+          if valid {
+            Ok(())
+          } else {
+            Err("invalid basic credentials")
+          }
+        }),
+    };
+
+    match result {
+      Ok(()) => None,
+      Err(reason) => {
+        debug!(reason, "http:auth:rejected");
+        Some(self.unauthorized())
+      }
+    }
+  }
+
+  fn unauthorized(&self) -> Response<Body> {
+    let challenge = match self {
+      Self::Jwt { .. } => "Bearer".to_owned(),
+      Self::ApiKey { header, .. } => format!("ApiKey header=\"{}\"", header),
+      Self::Basic { realm, .. } => format!("Basic realm=\"{}\"", realm),
+    };
+    Response::builder()
+      .status(StatusCode::UNAUTHORIZED)
+      .header(WWW_AUTHENTICATE, challenge)
+      .body(Body::from("Unauthorized"))
+      .unwrap()
+  }
+}
+
+fn rendered(value: &TemplateConfig<String>) -> Result<String, HttpError> {
+  value
+    .value()
+    .cloned()
+    .ok_or_else(|| HttpError::InitializationFailed("authentication configuration was not rendered".to_owned()))
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| d.as_secs())
+}
+
+fn bearer_token<B>(req: &Request<B>) -> Option<&str> {
+  let header = req.headers().get(AUTHORIZATION)?.to_str().ok()?;
+  header.strip_prefix("Bearer ").map(str::trim)
+}
+
+fn basic_credentials<B>(req: &Request<B>) -> Option<(String, String)> {
+  let header = req.headers().get(AUTHORIZATION)?.to_str().ok()?;
+  let encoded = header.strip_prefix("Basic ")?.trim();
+  let decoded = String::from_utf8(STANDARD.decode(encoded).ok()?).ok()?;
+  let (user, password) = decoded.split_once(':')?;
+  Some((user.to_owned(), password.to_owned()))
+}
+
+/// Verify an HMAC-signed JWT and its registered time, issuer, and audience claims.
+fn verify_jwt(
+  token: &str,
+  secret: &[u8],
+  issuer: Option<&str>,
+  audience: Option<&str>,
+  now: u64,
+) -> Result<(), &'static str> {
+  let Some((signed, signature)) = token.rsplit_once('.') else {
+    return Err("malformed token");
+  };
+  let Some((header, claims)) = signed.split_once('.') else {
+    return Err("malformed token");
+  };
+
+  let header = decode_segment(header)?;
+  let algorithm = match header.get("alg").and_then(Value::as_str) {
+    Some("HS256") => hmac::HMAC_SHA256,
+    Some("HS384") => hmac::HMAC_SHA384,
+    Some("HS512") => hmac::HMAC_SHA512,
+    _ => return Err("unsupported token algorithm"),
+  };
+  let signature = URL_SAFE_NO_PAD
+    .decode(signature)
+    .map_err(|_| "malformed token signature")?;
+  hmac::verify(&hmac::Key::new(algorithm, secret), signed.as_bytes(), &signature)
+    .map_err(|_| "invalid token signature")?;
+
+  let claims = decode_segment(claims)?;
+  if let Some(exp) = claims.get("exp") {
+    if exp.as_u64().map_or(true, |exp| exp <= now) {
+      return Err("token has expired");
+    }
+  }
+  if let Some(nbf) = claims.get("nbf") {
+    if nbf.as_u64().map_or(true, |nbf| nbf > now) {
+      return Err("token is not yet valid");
+    }
+  }
+  if let Some(issuer) = issuer {
+    if claims.get("iss").and_then(Value::as_str) != Some(issuer) {
+      return Err("token issuer does not match");
+    }
+  }
+  if let Some(audience) = audience {
+    let valid = match claims.get("aud") {
+      Some(Value::String(aud)) => aud == audience,
+      Some(Value::Array(auds)) => auds.iter().any(|aud| aud.as_str() == Some(audience)),
+      _ => false,
+    };
+    if !valid {
+      return Err("token audience does not match");
+    }
+  }
+  Ok(())
+}
+
+fn decode_segment(segment: &str) -> Result<Value, &'static str> {
+  let bytes = URL_SAFE_NO_PAD.decode(segment).map_err(|_| "malformed token")?;
+  serde_json::from_slice(&bytes).map_err(|_| "malformed token")
+}
+
+/// Compare two byte slices without short-circuiting on the first difference.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  const SECRET: &[u8] = b"secret";
+  const NOW: u64 = 1_700_000_000;
+
+  fn sign(claims: &Value) -> String {
+    let header = URL_SAFE_NO_PAD.encode(json!({"alg": "HS256", "typ": "JWT"}).to_string());
+    let claims = URL_SAFE_NO_PAD.encode(claims.to_string());
+    let signed = format!("{}.{}", header, claims);
+    let tag = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, SECRET), signed.as_bytes());
+    format!("{}.{}", signed, URL_SAFE_NO_PAD.encode(tag.as_ref()))
+  }
+
+  #[test]
+  fn test_jwt_valid() -> Result<()> {
+    let token = sign(&json!({"iss": "wick", "aud": ["api", "web"], "exp": NOW + 60, "nbf": NOW - 60}));
+    assert_eq!(verify_jwt(&token, SECRET, Some("wick"), Some("api"), NOW), Ok(()));
+    assert_eq!(verify_jwt(&token, SECRET, None, None, NOW), Ok(()));
+    Ok(())
+  }
+
+  #[test]
+  fn test_jwt_invalid() -> Result<()> {
+    let token = sign(&json!({"iss": "wick", "aud": "api", "exp": NOW}));
+    let cases = [
+      (verify_jwt(&token, b"wrong", None, None, NOW - 1), "invalid token signature"),
+      (verify_jwt(&token, SECRET, None, None, NOW), "token has expired"),
+      (
+        verify_jwt(&token, SECRET, Some("other"), None, NOW - 1),
+        "token issuer does not match",
+      ),
+      (
+        verify_jwt(&token, SECRET, None, Some("web"), NOW - 1),
+        "token audience does not match",
+      ),
+      (verify_jwt("not.a-token", SECRET, None, None, NOW), "malformed token"),
+    ];
+    for (result, expected) in cases {
+      assert_eq!(result, Err(expected));
+    }
+    Ok(())
+  }
+
+  #[test]
+  fn test_basic_credentials() -> Result<()> {
+    let req = Request::builder()
+      .header(AUTHORIZATION, format!("Basic {}", STANDARD.encode("jane:p:ss")))
+      .body(())?;
+    assert_eq!(basic_credentials(&req), Some(("jane".to_owned(), "p:ss".to_owned())));
+    Ok(())
+  }
+
+  #[test]
+  fn test_api_key() -> Result<()> {
+    let auth = Authenticator::ApiKey {
+      header: "x-api-key".to_owned(),
+      keys: vec!["abc".to_owned()],
+    };
+    let allowed = Request::builder().header("x-api-key", "abc").body(())?;
+    let denied = Request::builder().header("x-api-key", "abd").body(())?;
+    assert!(auth.authenticate(&allowed).is_none());
+    let res = auth.authenticate(&denied).unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    assert!(auth.authenticate(&Request::builder().body(())?).is_some());
+    Ok(())
+  }
+}
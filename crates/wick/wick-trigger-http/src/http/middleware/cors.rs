@@ -0,0 +1,174 @@
+use hyper::header::{
+  HeaderValue,
+  ACCESS_CONTROL_ALLOW_CREDENTIALS,
+  ACCESS_CONTROL_ALLOW_HEADERS,
+  ACCESS_CONTROL_ALLOW_METHODS,
+  ACCESS_CONTROL_ALLOW_ORIGIN,
+  ACCESS_CONTROL_MAX_AGE,
+  ACCESS_CONTROL_REQUEST_HEADERS,
+  ACCESS_CONTROL_REQUEST_METHOD,
+  ORIGIN,
+  VARY,
+};
+use hyper::{Body, HeaderMap, Method, Request, Response, StatusCode};
+use wick_config::config::CorsConfig;
+
+const DEFAULT_METHODS: &str = "GET, POST, PUT, PATCH, DELETE";
+
+/// Answers CORS preflight requests and adds CORS headers to responses for a router.
+#[derive(Debug, Clone)]
+pub(crate) struct Cors {
+  origins: Vec<String>,
+  methods: String,
+  headers: Option<String>,
+  max_age: Option<u64>,
+  credentials: bool,
+}
+
+impl Cors {
+  pub(crate) fn new(config: &CorsConfig) -> Self {
+    let methods = if config.allowed_methods().is_empty() {
+      DEFAULT_METHODS.to_owned()
+    } else {
+      config.allowed_methods().join(", ")
+    };
+    let headers = (!config.allowed_headers().is_empty()).then(|| config.allowed_headers().join(", "));
+    Self {
+      origins: config.allowed_origins().to_vec(),
+      methods,
+      headers,
+      max_age: config.max_age(),
+      credentials: config.allow_credentials(),
+    }
+  }
+
+  /// Answer a preflight request, returning `None` if the request is not a preflight request.
+  pub(crate) fn preflight<B>(&self, req: &Request<B>) -> Option<Response<Body>> {
+    let headers = req.headers();
+    if req.method() != Method::OPTIONS || !headers.contains_key(ACCESS_CONTROL_REQUEST_METHOD) {
+      return None;
+    }
+    let origin = headers.get(ORIGIN)?;
+    let Some(allow_origin) = self.allow_origin(origin) else {
+      debug!(?origin, "http:cors:preflight:rejected");
+      return Some(
+        Response::builder()
+          .status(StatusCode::FORBIDDEN)
+          .body(Body::empty())
+          .unwrap(),
+      );
+    };
+
+    let mut response = Response::builder()
+      .status(StatusCode::NO_CONTENT)
+      .header(ACCESS_CONTROL_ALLOW_METHODS, self.methods.as_str());
+    // without a configured list, allow whatever headers the browser is asking to send.
+    let allow_headers = self
+      .headers
+      .as_deref()
+      .and_then(|h| HeaderValue::from_str(h).ok())
+      .or_else(|| headers.get(ACCESS_CONTROL_REQUEST_HEADERS).cloned());
+    if let Some(allow_headers) = allow_headers {
+      response = response.header(ACCESS_CONTROL_ALLOW_HEADERS, allow_headers);
+    }
+    if let Some(max_age) = self.max_age {
+      response = response.header(ACCESS_CONTROL_MAX_AGE, max_age);
+    }
+    let mut response = response.body(Body::empty()).unwrap();
+    self.insert_origin(response.headers_mut(), allow_origin);
+    Some(response)
+  }
+
+  /// Add CORS headers to the response for a request made from `origin`.
+  pub(crate) fn apply(&self, origin: &HeaderValue, response: &mut Response<Body>) {
+    if let Some(allow_origin) = self.allow_origin(origin) {
+      self.insert_origin(response.headers_mut(), allow_origin);
+    }
+  }
+
+  fn insert_origin(&self, headers: &mut HeaderMap, allow_origin: HeaderValue) {
+    headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, allow_origin);
+    headers.append(VARY, HeaderValue::from_static("Origin"));
+    if self.credentials {
+      headers.insert(ACCESS_CONTROL_ALLOW_CREDENTIALS, HeaderValue::from_static("true"));
+    }
+  }
+
+  /// The value of `Access-Control-Allow-Origin` for `origin`, or `None` if the origin is not allowed.
+  fn allow_origin(&self, origin: &HeaderValue) -> Option<HeaderValue> {
+    // configuration validation keeps credentials from being allowed alongside a wildcard.
+    if self.origins.iter().any(|o| o == "*") {
+      return Some(HeaderValue::from_static("*"));
+    }
+    let origin_str = origin.to_str().ok()?;
+    self
+      .origins
+      .iter()
+      .any(|o| o.eq_ignore_ascii_case(origin_str))
+      .then(|| origin.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use wick_config::config::CorsConfigBuilder;
+
+  use super::*;
+
+  fn preflight_request(origin: &str) -> Result<Request<()>> {
+    Ok(
+      Request::builder()
+        .method(Method::OPTIONS)
+        .header(ORIGIN, origin)
+        .header(ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .header(ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
+        .body(())?,
+    )
+  }
+
+  #[test]
+  fn test_preflight() -> Result<()> {
+    let config = CorsConfigBuilder::default()
+      .allowed_origins(vec!["https://app.example.com".to_owned()])
+      .max_age(Some(600))
+      .build()?;
+    let cors = Cors::new(&config);
+
+    let res = cors.preflight(&preflight_request("https://app.example.com")?).unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    let headers = res.headers();
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_ORIGIN], "https://app.example.com");
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_METHODS], DEFAULT_METHODS);
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_HEADERS], "content-type");
+    assert_eq!(headers[ACCESS_CONTROL_MAX_AGE], "600");
+    assert!(!headers.contains_key(ACCESS_CONTROL_ALLOW_CREDENTIALS));
+
+    let res = cors.preflight(&preflight_request("https://evil.example.com")?).unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    let not_preflight = Request::builder().method(Method::OPTIONS).body(())?;
+    assert!(cors.preflight(&not_preflight).is_none());
+    Ok(())
+  }
+
+  #[test]
+  fn test_apply_wildcard() -> Result<()> {
+    let origin = HeaderValue::from_static("https://app.example.com");
+    let config = CorsConfigBuilder::default()
+      .allowed_origins(vec!["*".to_owned()])
+      .build()?;
+    let mut res = Response::new(Body::empty());
+    Cors::new(&config).apply(&origin, &mut res);
+    assert_eq!(res.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+
+    assert!(!res.headers().contains_key(ACCESS_CONTROL_ALLOW_CREDENTIALS));
+
+    let config = CorsConfigBuilder::default()
+      .allowed_origins(vec!["*".to_owned()])
+      .allow_credentials(true)
+      .build();
+    assert!(config.is_err());
+    Ok(())
+  }
+}
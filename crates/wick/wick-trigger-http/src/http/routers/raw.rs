@@ -7,7 +7,7 @@ use serde_json::Value;
 use tracing::{Instrument, Span};
 use uuid::Uuid;
 use wick_config::config::{Codec, RawRouterConfig, WickRouter};
-use wick_packet::{packets, Base64Bytes, Entity, InherentData, Invocation, Observer, Packet, PacketStream};
+use wick_packet::{json, packets, Base64Bytes, Entity, InherentData, Invocation, Observer, Packet, PacketStream};
 use wick_runtime::Runtime;
 
 use crate::http::component_utils::respond;
@@ -114,12 +114,12 @@ impl RawHandler {
         let bytes: Result<Vec<bytes::Bytes>, _> = body.try_collect().await;
         match bytes {
           Ok(b) => {
-            let bytes = b.concat();
+            let mut bytes = b.concat();
             trace!(?bytes, "http:codec:json:bytes");
             let packet = if bytes.is_empty() {
               Packet::encode("body", None::<Value>)
             } else {
-              serde_json::from_slice::<Option<Value>>(&bytes).map_or_else(
+              json::from_slice_mut::<Option<Value>>(&mut bytes).map_or_else(
                 |e| Packet::err("body", e.to_string()),
                 |value| Packet::encode("body", Some(value)),
               )
@@ -3,7 +3,8 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 use std::task::Poll;
 
-use hyper::body::to_bytes;
+use hyper::body::{Bytes, HttpBody};
+use hyper::header::{CONTENT_LENGTH, CONTENT_TYPE};
 use hyper::service::Service;
 use hyper::{Body, Request, Response, StatusCode};
 use tracing::{Instrument, Span};
@@ -11,21 +12,27 @@ use uuid::Uuid;
 use wick_config::config::{
   AppConfiguration,
   BoundIdentifier,
+  Codec,
   ComponentOperationExpression,
   HttpMethod,
+  ResponseMode,
   RestRouterConfig,
   WickRouter,
 };
-use wick_packet::{Entity, InherentData, Invocation, Packet, PacketExt};
+use wick_interface_types::Field;
+use wick_packet::{json, Entity, InherentData, Invocation, Packet, PacketExt, PacketStream};
 mod error;
+mod multipart;
 mod openapi;
 mod route;
+mod validation;
 
 use wick_runtime::Runtime;
 use wick_trigger::resources::Resource;
 
 use self::error::RestError;
-use crate::http::component_utils::stream_to_json;
+use self::validation::{Location, Validator};
+use crate::http::component_utils::{stream_to_body, stream_to_json, stream_to_media};
 use crate::http::middleware::resolve_middleware_components;
 use crate::http::{BoxFuture, HttpError, HttpRouter, RawRouter, RawRouterHandler};
 
@@ -39,9 +46,10 @@ pub(super) struct RestRouter {
 
 impl RestRouter {
   pub(super) fn new(
+    runtime: &Runtime,
     app_config: &AppConfiguration,
     config: RestRouterConfig,
-    routes: Vec<RestRoute>,
+    mut routes: Vec<RestRoute>,
   ) -> Result<Self, RestError> {
     let title = config
       .info()
@@ -49,8 +57,11 @@ impl RestRouter {
       .unwrap_or_else(|| "Untitled API".to_owned());
 
     debug!(api = %title, path=%config.path(), "router:rest:serving");
-    for route in &routes {
+    for route in &mut routes {
       debug!(route = ?route.route, "router:rest:route");
+      if matches!(route.config.response(), ResponseMode::Buffered) {
+        route.media_output = media_output(runtime, route);
+      }
     }
 
     let oapi = config.tools().map_or(false, |t| t.openapi());
@@ -60,7 +71,7 @@ impl RestRouter {
           path = format!("{}{}", config.path(), OPENAPI_PATH),
           "openapi schema enabled"
         );
-        openapi::generate_openapi(app_config, &config, &routes)
+        openapi::generate_openapi(runtime, app_config, &config, &routes)
       })
       .transpose()?;
 
@@ -171,34 +182,117 @@ impl RestHandler {
       span.in_scope(
         || trace!(route = %uri, path_params=?path_params, query_params=?query_params, "incoming http request"),
       );
+      let signature = route
+        .config
+        .validate()
+        .then(|| {
+          runtime
+            .deep_signature(None, Some(&Entity::component(&route.component)))
+            .map_err(|e| HttpError::OperationError(e.to_string()))
+        })
+        .transpose()?;
+      let mut validator = match &signature {
+        Some(signature) => {
+          let operation = signature.get_operation(route.operation.name()).ok_or_else(|| {
+            HttpError::OperationError(format!(
+              "operation {} not found on component {}",
+              route.operation.name(),
+              route.component
+            ))
+          })?;
+          let mut validator = Validator::new(operation, &signature.types);
+          validator.params(Location::Path, &path_params);
+          validator.params(Location::Query, &query_params);
+          Some(validator)
+        }
+        None => None,
+      };
+
       let mut packets: Vec<_> = path_params
         .iter()
         .chain(query_params.iter())
         .map(|f| Packet::encode(f.name(), f.value()))
         .collect();
 
-      let (_, body) = request.into_parts();
-
-      let body_bytes = to_bytes(body).await.unwrap_or_default();
-      let body = String::from_utf8_lossy(&body_bytes);
-
-      span.in_scope(|| trace!(route = %uri, len=body_bytes.len(), "body"));
-
-      if !matches!(method, HttpMethod::Get) {
-        let payload: Option<serde_json::Value> = if body.trim().is_empty() {
-          None
-        } else {
-          Some(serde_json::from_str(&body).map_err(HttpError::InvalidBody)?)
-        };
-
-        packets.push(Packet::encode("input", payload));
+      let (parts, body) = request.into_parts();
+
+      let max_body_size = route.config.max_body_size();
+      let content_length = parts
+        .headers
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+      if let (Some(max), Some(len)) = (max_body_size, content_length) {
+        if len > max {
+          span.in_scope(|| debug!(route = %uri, len, max, "request body too large"));
+          return Ok(
+            Response::builder()
+              .status(StatusCode::PAYLOAD_TOO_LARGE)
+              .body(Body::empty())
+              .unwrap(),
+          );
+        }
       }
 
-      let mut port_names: Vec<_> = packets.iter().map(|p| p.port().to_owned()).collect();
-      port_names.dedup();
-      for port in port_names {
-        packets.push(Packet::done(port));
-      }
+      let boundary = parts
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| multer::parse_boundary(v).ok());
+
+      let packets: PacketStream = match boundary {
+        Some(boundary) if !matches!(method, HttpMethod::Get) => {
+          span.in_scope(|| trace!(route = %uri, "multipart body"));
+          if let Some(response) = validator.and_then(Validator::finish) {
+            return Ok(response);
+          }
+          multipart::multipart_to_packets(packets, body, boundary, max_body_size, route.config.max_part_size())
+        }
+        _ => {
+          let Some(body_bytes) = read_body(body, max_body_size).await? else {
+            span.in_scope(|| debug!(route = %uri, max = max_body_size, "request body too large"));
+            return Ok(
+              Response::builder()
+                .status(StatusCode::PAYLOAD_TOO_LARGE)
+                .body(Body::empty())
+                .unwrap(),
+            );
+          };
+          let body = String::from_utf8_lossy(&body_bytes);
+
+          span.in_scope(|| trace!(route = %uri, len=body_bytes.len(), "body"));
+
+          let payload: Option<serde_json::Value> = if matches!(method, HttpMethod::Get) || body.trim().is_empty() {
+            None
+          } else {
+            Some(json::from_slice(body.as_bytes()).map_err(HttpError::InvalidBody)?)
+          };
+
+          if let Some(mut validator) = validator.take() {
+            validator.body(payload.as_ref());
+            let mut provided: Vec<_> = packets.iter().map(|p| p.port()).collect();
+            if payload.is_some() {
+              provided.push("input");
+            }
+            validator.required(&provided);
+            if let Some(response) = validator.finish() {
+              span.in_scope(|| debug!(route = %uri, "request failed validation"));
+              return Ok(response);
+            }
+          }
+
+          if !matches!(method, HttpMethod::Get) {
+            packets.push(Packet::encode("input", payload));
+          }
+
+          let mut port_names: Vec<_> = packets.iter().map(|p| p.port().to_owned()).collect();
+          port_names.dedup();
+          for port in port_names {
+            packets.push(Packet::done(port));
+          }
+          packets.into()
+        }
+      };
 
       let invocation = Invocation::new_with_id(
         tx_id,
@@ -215,11 +309,23 @@ impl RestHandler {
         .instrument(span)
         .await
         .map_err(|e| HttpError::OperationError(e.to_string()))?;
-      let json = stream_to_json(stream).await?;
+      let (content_type, body) = match (route.config.response(), &route.media_output) {
+        (ResponseMode::Buffered, Some(output)) => {
+          let bytes = stream_to_media(stream, output).await?;
+          (output.content_type().unwrap_or_default(), Body::from(bytes))
+        }
+        (ResponseMode::Buffered, None) => {
+          let value = stream_to_json(stream).await?;
+          let bytes = json::to_vec(&value).map_err(|e| HttpError::Codec(Codec::Json, e.to_string()))?;
+          ("application/json", Body::from(bytes))
+        }
+        (ResponseMode::Stream, _) => ("application/x-ndjson", stream_to_body(stream, false)),
+        (ResponseMode::Sse, _) => ("text/event-stream", stream_to_body(stream, true)),
+      };
       return Response::builder()
         .status(StatusCode::OK)
-        .header("Content-Type", "application/json")
-        .body(Body::from(json.to_string()))
+        .header("Content-Type", content_type)
+        .body(body)
         .map_err(|e| HttpError::OperationError(e.to_string()));
     }
     Ok(
@@ -250,6 +356,8 @@ pub(crate) struct RestRoute {
   route: route::Route,
   component: String,
   operation: ComponentOperationExpression,
+  /// The output that buffered responses send as their body, for operations that annotate one with a media type.
+  media_output: Option<Field>,
 }
 
 impl RestRoute {
@@ -263,12 +371,58 @@ impl RestRoute {
       route,
       component: component_id,
       operation,
+      media_output: None,
     })
   }
 }
 
+/// The first output of a route's operation with a media type, if it has one.
+fn media_output(runtime: &Runtime, route: &RestRoute) -> Option<Field> {
+  let signature = runtime
+    .deep_signature(None, Some(&Entity::component(&route.component)))
+    .ok()?;
+  let operation = signature.get_operation(route.operation.name())?;
+  operation.outputs.iter().find(|o| o.content_type().is_some()).cloned()
+}
+
+/// Read `body` into memory, stopping with `None` as soon as it grows past `max` bytes.
+async fn read_body(mut body: Body, max: Option<u64>) -> Result<Option<Bytes>, HttpError> {
+  let mut bytes = Vec::new();
+  while let Some(chunk) = body.data().await {
+    let chunk = chunk.map_err(|e| HttpError::ReadBody(e.to_string()))?;
+    if max.map_or(false, |max| (bytes.len() + chunk.len()) as u64 > max) {
+      return Ok(None);
+    }
+    bytes.extend_from_slice(&chunk);
+  }
+  Ok(Some(bytes.into()))
+}
+
 #[cfg(test)]
 mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test_logger::test(tokio::test)]
+  async fn test_read_body() -> Result<()> {
+    assert_eq!(read_body(Body::from("abc"), Some(3)).await?, Some(Bytes::from("abc")));
+    assert_eq!(read_body(Body::from("abcd"), None).await?, Some(Bytes::from("abcd")));
+
+    // a body that never ends should still be rejected once it crosses the limit.
+    let (mut sender, body) = Body::channel();
+    tokio::spawn(async move { while sender.send_data(Bytes::from(vec![0; 64])).await.is_ok() {} });
+    assert_eq!(read_body(body, Some(256)).await?, None);
+
+    let (mut sender, body) = Body::channel();
+    sender.send_data(Bytes::from("abc")).await?;
+    sender.abort();
+    assert!(
+      read_body(body, None).await.is_err(),
+      "read errors should not be swallowed"
+    );
+    Ok(())
+  }
 
   // "port_limited" tests are grouped together and run on a single thread to prevent port contention
   mod port_limited {
@@ -321,12 +475,188 @@ mod test {
       trigger.shutdown_gracefully().await?;
       Ok(())
     }
+
+    #[test_logger::test(tokio::test)]
+    async fn rest_streaming_responses() -> Result<()> {
+      std::env::set_var("HTTP_PORT", PORT);
+      let app_config = load_test_manifest("app_config/rest-router-stream.wick")
+        .await?
+        .try_app_config()?;
+      let rt = build_trigger_runtime(&app_config, Span::current())?.build(None).await?;
+
+      let trigger = Http::default();
+      let resource = Resource::new(app_config.resources().get(0).as_ref().unwrap().kind().clone())?;
+      let resources = Arc::new([("http".into(), resource)].iter().cloned().collect());
+      let trigger_config = app_config.triggers()[0].clone();
+      trigger
+        .run(
+          "test".to_owned(),
+          rt,
+          app_config,
+          trigger_config,
+          resources,
+          Span::current(),
+        )
+        .await?;
+
+      let client = reqwest::Client::new();
+      let res = client
+        .post(format!("http://0.0.0.0:{}/stream", PORT))
+        .body(r#"{"message": "hello"}"#)
+        .send()
+        .await?;
+      assert_eq!(res.headers()["content-type"], "application/x-ndjson");
+      assert_eq!(res.text().await?, "{\"output\":{\"message\":\"hello\"}}\n");
+
+      let res = client
+        .post(format!("http://0.0.0.0:{}/sse", PORT))
+        .body(r#"{"message": "hello"}"#)
+        .send()
+        .await?;
+      assert_eq!(res.headers()["content-type"], "text/event-stream");
+      assert_eq!(res.text().await?, "event: output\ndata: {\"message\":\"hello\"}\n\n");
+
+      let body = "--XYZ\r\nContent-Disposition: form-data; name=\"input\"\r\n\r\nhello\r\n--XYZ--\r\n";
+      let res = client
+        .post(format!("http://0.0.0.0:{}/upload", PORT))
+        .header("Content-Type", "multipart/form-data; boundary=XYZ")
+        .body(body)
+        .send()
+        .await?;
+      assert_eq!(res.text().await?, r#"{"output":"hello"}"#);
+
+      let res = client
+        .post(format!("http://0.0.0.0:{}/upload", PORT))
+        .body(format!(r#"{{"message": "{}"}}"#, "a".repeat(512)))
+        .send()
+        .await?;
+      assert_eq!(res.status(), 413);
+
+      trigger.shutdown_gracefully().await?;
+      Ok(())
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn rest_validation() -> Result<()> {
+      std::env::set_var("HTTP_PORT", PORT);
+      let app_config = load_test_manifest("app_config/rest-router-stream.wick")
+        .await?
+        .try_app_config()?;
+      let rt = build_trigger_runtime(&app_config, Span::current())?.build(None).await?;
+
+      let trigger = Http::default();
+      let resource = Resource::new(app_config.resources().get(0).as_ref().unwrap().kind().clone())?;
+      let resources = Arc::new([("http".into(), resource)].iter().cloned().collect());
+      let trigger_config = app_config.triggers()[0].clone();
+      trigger
+        .run(
+          "test".to_owned(),
+          rt,
+          app_config,
+          trigger_config,
+          resources,
+          Span::current(),
+        )
+        .await?;
+
+      let client = reqwest::Client::new();
+      let res = client
+        .post(format!("http://0.0.0.0:{}/greet", PORT))
+        .body(r#"{"name": "jane", "times": 2}"#)
+        .send()
+        .await?;
+      assert_eq!(res.status(), 200);
+      assert_eq!(res.text().await?, r#"{"output":{"name":"jane","times":2}}"#);
+
+      let res = client
+        .post(format!("http://0.0.0.0:{}/greet", PORT))
+        .body(r#"{"times": -1}"#)
+        .send()
+        .await?;
+      assert_eq!(res.status(), 400);
+      let body: serde_json::Value = serde_json::from_str(&res.text().await?)?;
+      assert_eq!(
+        body,
+        serde_json::json!({
+          "error": "invalid request",
+          "details": [{"location": "body", "field": "input", "message": "input.name: is required"}]
+        })
+      );
+
+      let res = client
+        .post(format!("http://0.0.0.0:{}/greet_extra?extra=yes", PORT))
+        .body(r#"{"name": "jane"}"#)
+        .send()
+        .await?;
+      assert_eq!(res.status(), 400);
+      let body: serde_json::Value = serde_json::from_str(&res.text().await?)?;
+      assert_eq!(body["details"][0]["field"], "extra");
+
+      let res = client.post(format!("http://0.0.0.0:{}/greet", PORT)).send().await?;
+      assert_eq!(res.status(), 400);
+
+      trigger.shutdown_gracefully().await?;
+      Ok(())
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn rest_openapi_schemas() -> Result<()> {
+      std::env::set_var("HTTP_PORT", PORT);
+      let app_config = load_test_manifest("app_config/rest-router-openapi.wick")
+        .await?
+        .try_app_config()?;
+      let rt = build_trigger_runtime(&app_config, Span::current())?.build(None).await?;
+
+      let trigger = Http::default();
+      let resource = Resource::new(app_config.resources().get(0).as_ref().unwrap().kind().clone())?;
+      let resources = Arc::new([("http".into(), resource)].iter().cloned().collect());
+      let trigger_config = app_config.triggers()[0].clone();
+      trigger
+        .run(
+          "test".to_owned(),
+          rt,
+          app_config,
+          trigger_config,
+          resources,
+          Span::current(),
+        )
+        .await?;
+
+      let client = reqwest::Client::new();
+      let res = client
+        .get(format!("http://0.0.0.0:{}/api{}", PORT, OPENAPI_PATH))
+        .send()
+        .await?;
+      assert_eq!(res.status(), 200);
+      let spec: serde_json::Value = serde_json::from_str(&res.text().await?)?;
+
+      let greeting = &spec["components"]["schemas"]["Greeting"];
+      assert_eq!(greeting["type"], "object");
+      assert_eq!(greeting["properties"]["times"]["nullable"], true);
+      assert_eq!(greeting["required"], serde_json::json!(["name"]));
+
+      let greet = &spec["paths"]["/greet"]["post"];
+      let request = &greet["requestBody"]["content"]["application/json"]["schema"];
+      assert_eq!(request["oneOf"][0]["$ref"], "#/components/schemas/Greeting");
+      let response = &greet["responses"]["200"]["content"]["application/json"]["schema"];
+      assert_eq!(
+        response["properties"]["output"]["oneOf"][0]["$ref"],
+        "#/components/schemas/Greeting"
+      );
+
+      let stream = &spec["paths"]["/stream"]["post"]["responses"]["200"]["content"];
+      assert!(stream.get("application/x-ndjson").is_some());
+
+      trigger.shutdown_gracefully().await?;
+      Ok(())
+    }
   }
 }
 
 pub(crate) fn register_rest_router(
   index: usize,
   _resources: Arc<HashMap<BoundIdentifier, Resource>>,
+  runtime: &Runtime,
   app_config: &AppConfiguration,
   router_config: &RestRouterConfig,
 ) -> Result<HttpRouter, HttpError> {
@@ -348,7 +678,7 @@ pub(crate) fn register_rest_router(
     routes.push(route);
   }
 
-  let router = RestRouter::new(app_config, router_config.clone(), routes)
+  let router = RestRouter::new(runtime, app_config, router_config.clone(), routes)
     .map_err(|e| HttpError::InitializationFailed(e.to_string()))?;
   Ok(HttpRouter::Raw(RawRouterHandler {
     path: router_config.path().to_owned(),
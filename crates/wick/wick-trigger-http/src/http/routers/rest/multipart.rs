@@ -0,0 +1,85 @@
+use hyper::Body;
+use multer::{Constraints, Multipart, SizeLimit};
+use wick_packet::{Base64Bytes, Observer, Packet, PacketExt, PacketStream};
+
+/// Turn a multipart/form-data body into input packets.
+///
+/// Form fields are sent as strings on the port with the field's name. Files are streamed to the port with the
+/// field's name as `bytes` packets, one per chunk as it arrives.
+pub(super) fn multipart_to_packets(
+  packets: Vec<Packet>,
+  body: Body,
+  boundary: String,
+  max_body_size: Option<u64>,
+  max_part_size: Option<u64>,
+) -> PacketStream {
+  let (tx, rx) = PacketStream::new_channels();
+  let mut ports: Vec<String> = Vec::new();
+  for packet in packets {
+    if !ports.iter().any(|p| p == packet.port()) {
+      ports.push(packet.port().to_owned());
+    }
+    let _ = tx.send(packet);
+  }
+
+  let mut limit = SizeLimit::new();
+  if let Some(max) = max_body_size {
+    limit = limit.whole_stream(max);
+  }
+  if let Some(max) = max_part_size {
+    limit = limit.per_field(max);
+  }
+  let mut multipart = Multipart::with_constraints(body, boundary, Constraints::new().size_limit(limit));
+
+  tokio::spawn(async move {
+    'fields: loop {
+      let mut field = match multipart.next_field().await {
+        Ok(Some(field)) => field,
+        Ok(None) => break,
+        Err(e) => {
+          let _ = tx.send(Packet::err("input", e.to_string()));
+          break;
+        }
+      };
+      let Some(name) = field.name().map(ToOwned::to_owned) else {
+        trace!("http:multipart:skipping unnamed field");
+        continue;
+      };
+      if !ports.contains(&name) {
+        ports.push(name.clone());
+      }
+
+      if field.file_name().is_some() {
+        loop {
+          match field.chunk().await {
+            Ok(Some(chunk)) => {
+              trace!(field = %name, len = chunk.len(), "http:multipart:chunk");
+              let _ = tx.send(Packet::encode(&name, Base64Bytes::new(chunk)));
+            }
+            Ok(None) => break,
+            Err(e) => {
+              let _ = tx.send(Packet::err(&name, e.to_string()));
+              break 'fields;
+            }
+          }
+        }
+      } else {
+        match field.text().await {
+          Ok(text) => {
+            let _ = tx.send(Packet::encode(&name, text));
+          }
+          Err(e) => {
+            let _ = tx.send(Packet::err(&name, e.to_string()));
+            break;
+          }
+        }
+      }
+    }
+    for port in ports {
+      let _ = tx.send(Packet::done(port));
+    }
+    trace!("http:multipart:done");
+  });
+
+  rx
+}
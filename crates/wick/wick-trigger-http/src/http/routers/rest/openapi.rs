@@ -7,6 +7,7 @@ use openapiv3::{
   Info,
   IntegerType,
   License,
+  MediaType,
   NumberType,
   ObjectType,
   OpenAPI,
@@ -17,20 +18,38 @@ use openapiv3::{
   PathItem,
   Paths,
   ReferenceOr,
+  RequestBody,
+  Response,
   Responses,
   Schema,
   SchemaData,
+  StatusCode,
+  StringFormat,
   StringType,
   VariantOrUnknownOrEmpty,
 };
-use wick_config::config::{AppConfiguration, HttpMethod, WickRouter};
-use wick_interface_types::{EnumDefinition, Field, StructDefinition, Type, TypeDefinition, UnionDefinition};
+use wick_config::config::{AppConfiguration, HttpMethod, ResponseMode, WickRouter};
+use wick_interface_types::{
+  EnumDefinition,
+  Field,
+  OperationSignature,
+  StructDefinition,
+  Type,
+  TypeDefinition,
+  UnionDefinition,
+};
+use wick_packet::Entity;
+use wick_runtime::Runtime;
 
 use super::error::RestError;
 use super::RestRoute;
 
+/// The prefix for references to schemas in the generated spec's `components.schemas`.
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
 // Generates an OpenAPI spec from a RestRouterConfig
 pub(crate) fn generate_openapi(
+  runtime: &Runtime,
   app_config: &AppConfiguration,
   config: &wick_config::config::RestRouterConfig,
   routes: &[RestRoute],
@@ -57,9 +76,11 @@ pub(crate) fn generate_openapi(
     extensions: Default::default(),
   }];
   let mut named_types = HashSet::new();
+  let mut types = Vec::new();
 
   let paths = routes.iter().map(|route| {
-    let path_item = route_to_path_item(route, &mut named_types);
+    let operation = operation_signature(runtime, route, &mut types);
+    let path_item = route_to_path_item(route, operation.as_ref(), &mut named_types);
     let path = route
       .route
       .path_parts
@@ -79,9 +100,9 @@ pub(crate) fn generate_openapi(
   openapi.paths.paths.extend(paths);
 
   let (mut schemas, mut named_types, mut new_named_types) =
-    resolve_named_types(app_config, HashMap::new(), named_types)?;
+    resolve_named_types(app_config, &types, HashMap::new(), named_types)?;
   while !new_named_types.is_empty() {
-    (schemas, named_types, new_named_types) = resolve_named_types(app_config, schemas, named_types)?;
+    (schemas, named_types, new_named_types) = resolve_named_types(app_config, &types, schemas, named_types)?;
   }
 
   if openapi.components.is_none() {
@@ -97,8 +118,30 @@ pub(crate) fn generate_openapi(
 type Seen = HashSet<String>;
 type SchemaMap = HashMap<String, ReferenceOr<Schema>>;
 
+/// Look up the signature of the operation a route targets, collecting the types its component defines.
+fn operation_signature(
+  runtime: &Runtime,
+  route: &RestRoute,
+  types: &mut Vec<TypeDefinition>,
+) -> Option<OperationSignature> {
+  let signature = match runtime.deep_signature(None, Some(&Entity::component(&route.component))) {
+    Ok(signature) => signature,
+    Err(e) => {
+      warn!(component = %route.component, error = %e, "could not find signature for openapi schema");
+      return None;
+    }
+  };
+  for ty in &signature.types {
+    if !types.iter().any(|t| t.name() == ty.name()) {
+      types.push(ty.clone());
+    }
+  }
+  signature.get_operation(route.operation.name()).cloned()
+}
+
 fn resolve_named_types(
   app_config: &AppConfiguration,
+  types: &[TypeDefinition],
   mut schemas: HashMap<String, ReferenceOr<Schema>>,
   mut named: HashSet<String>,
 ) -> Result<(SchemaMap, Seen, Seen), RestError> {
@@ -106,8 +149,11 @@ fn resolve_named_types(
 
   for name in &named {
     if !schemas.contains_key(name) {
-      let ty = app_config
-        .resolve_type(name)
+      let ty = types
+        .iter()
+        .find(|t| t.name() == name)
+        .cloned()
+        .or_else(|| app_config.resolve_type(name))
         .ok_or_else(|| RestError::TypeNotFound(name.clone()))?;
       let schema = typedef_to_schema(&ty, &mut new_named);
       schemas.insert(name.clone(), ReferenceOr::Item(schema));
@@ -119,7 +165,11 @@ fn resolve_named_types(
   Ok((schemas, named, new_named))
 }
 
-fn route_to_path_item(route: &RestRoute, named: &mut HashSet<String>) -> PathItem {
+fn route_to_path_item(
+  route: &RestRoute,
+  operation: Option<&OperationSignature>,
+  named: &mut HashSet<String>,
+) -> PathItem {
   let mut path_item = PathItem {
     summary: route.config.summary().cloned(),
     description: route.config.description().cloned(),
@@ -154,7 +204,7 @@ fn route_to_path_item(route: &RestRoute, named: &mut HashSet<String>) -> PathIte
     external_docs: Default::default(),
     parameters: Default::default(),
     request_body: Default::default(),
-    responses: Responses::default(),
+    responses: operation_responses(route, operation, named),
     deprecated: Default::default(),
     security: Default::default(),
     servers: Default::default(),
@@ -163,6 +213,13 @@ fn route_to_path_item(route: &RestRoute, named: &mut HashSet<String>) -> PathIte
   if route.config.methods().is_empty() || route.config.methods().contains(&HttpMethod::Get) {
     path_item.get = Some(oapi_operation.clone());
   }
+  // Only non-GET requests pass their body to the operation.
+  let oapi_operation = Operation {
+    request_body: operation
+      .and_then(|op| op.inputs.iter().find(|i| i.name() == "input"))
+      .map(|input| ReferenceOr::Item(request_body(input, named))),
+    ..oapi_operation
+  };
   if route.config.methods().contains(&HttpMethod::Post) {
     path_item.post = Some(oapi_operation.clone());
   }
@@ -175,6 +232,82 @@ fn route_to_path_item(route: &RestRoute, named: &mut HashSet<String>) -> PathIte
   path_item
 }
 
+fn request_body(input: &Field, named: &mut HashSet<String>) -> RequestBody {
+  let mut body = RequestBody {
+    description: input.description().map(|s| s.to_owned()),
+    required: !matches!(input.ty(), Type::Optional { .. }) && input.default().is_none(),
+    ..Default::default()
+  };
+  body.content.insert(
+    "application/json".to_owned(),
+    media_type(Some(field_to_schema(input, named))),
+  );
+  body
+}
+
+fn operation_responses(
+  route: &RestRoute,
+  operation: Option<&OperationSignature>,
+  named: &mut HashSet<String>,
+) -> Responses {
+  // Buffered responses are a JSON object keyed by output name, or the data of the output annotated with a media type.
+  // Streamed responses emit one event per packet.
+  let media_output = operation.and_then(|op| op.outputs.iter().find(|o| o.content_type().is_some()));
+  let (content_type, schema) = match (route.config.response(), media_output) {
+    (ResponseMode::Buffered, Some(output)) => (
+      output.content_type().unwrap_or_default(),
+      Some(media_schema(output, named)),
+    ),
+    (ResponseMode::Buffered, None) => (
+      "application/json",
+      operation.map(|op| Schema {
+        schema_data: Default::default(),
+        schema_kind: openapiv3::SchemaKind::Type(openapiv3::Type::Object(outputs_to_object(&op.outputs, named))),
+      }),
+    ),
+    (ResponseMode::Stream, _) => ("application/x-ndjson", None),
+    (ResponseMode::Sse, _) => ("text/event-stream", None),
+  };
+  let mut response = Response {
+    description: "Successful response".to_owned(),
+    ..Default::default()
+  };
+  response.content.insert(content_type.to_owned(), media_type(schema));
+
+  let mut responses = Responses::default();
+  responses
+    .responses
+    .insert(StatusCode::Code(200), ReferenceOr::Item(response));
+  if route.config.validate() {
+    responses.responses.insert(
+      StatusCode::Code(400),
+      ReferenceOr::Item(Response {
+        description: "The request did not match the operation's inputs".to_owned(),
+        ..Default::default()
+      }),
+    );
+  }
+  responses
+}
+
+fn outputs_to_object(outputs: &[Field], named: &mut HashSet<String>) -> ObjectType {
+  let mut obj = ObjectType::default();
+  for output in outputs {
+    obj.properties.insert(
+      output.name().to_owned(),
+      ReferenceOr::Item(Box::new(field_to_schema(output, named))),
+    );
+  }
+  obj
+}
+
+fn media_type(schema: Option<Schema>) -> MediaType {
+  MediaType {
+    schema: schema.map(ReferenceOr::Item),
+    ..Default::default()
+  }
+}
+
 fn convert_contact(contact: Option<&wick_config::config::Contact>) -> Option<Contact> {
   contact.map(|c| Contact {
     name: c.name().cloned(),
@@ -218,6 +351,18 @@ fn field_to_schema(field: &Field, named: &mut HashSet<String>) -> Schema {
   }
 }
 
+/// The schema of an output that's sent as the response body with its own media type. Bytes are documented as binary.
+fn media_schema(field: &Field, named: &mut HashSet<String>) -> Schema {
+  let mut schema = field_to_schema(field, named);
+  if matches!(field.ty(), Type::Bytes) {
+    schema.schema_kind = openapiv3::SchemaKind::Type(openapiv3::Type::String(StringType {
+      format: VariantOrUnknownOrEmpty::Item(StringFormat::Binary),
+      ..Default::default()
+    }));
+  }
+  schema
+}
+
 fn typedef_to_schema(ty: &TypeDefinition, named: &mut HashSet<String>) -> Schema {
   match ty {
     TypeDefinition::Struct(v) => struct_to_schema(v, named),
@@ -227,8 +372,19 @@ fn typedef_to_schema(ty: &TypeDefinition, named: &mut HashSet<String>) -> Schema
 }
 
 fn struct_to_schema(ty: &StructDefinition, named: &mut HashSet<String>) -> Schema {
+  Schema {
+    schema_data: SchemaData {
+      description: ty.description.clone(),
+      nullable: false,
+      ..Default::default()
+    },
+    schema_kind: openapiv3::SchemaKind::Type(openapiv3::Type::Object(fields_to_object(&ty.fields, named))),
+  }
+}
+
+fn fields_to_object(fields: &[Field], named: &mut HashSet<String>) -> ObjectType {
   let mut obj = ObjectType::default();
-  for field in &ty.fields {
+  for field in fields {
     obj.properties.insert(
       field.name().to_owned(),
       ReferenceOr::Item(Box::new(field_to_schema(field, named))),
@@ -237,14 +393,7 @@ fn struct_to_schema(ty: &StructDefinition, named: &mut HashSet<String>) -> Schem
       obj.required.push(field.name().to_owned());
     }
   }
-  Schema {
-    schema_data: SchemaData {
-      description: ty.description.clone(),
-      nullable: false,
-      ..Default::default()
-    },
-    schema_kind: openapiv3::SchemaKind::Type(openapiv3::Type::Object(obj)),
-  }
+  obj
 }
 
 fn enum_to_schema(ty: &EnumDefinition, _named: &mut HashSet<String>) -> Schema {
@@ -344,7 +493,7 @@ fn wick_type_to_schemakind(ty: &Type, named: &mut HashSet<String>) -> openapiv3:
       named.insert(name.clone());
       openapiv3::SchemaKind::OneOf {
         one_of: vec![ReferenceOr::Reference {
-          reference: name.clone(),
+          reference: format!("{}{}", SCHEMA_REF_PREFIX, name),
         }],
       }
     }
@@ -372,7 +521,9 @@ fn wick_type_to_schemakind(ty: &Type, named: &mut HashSet<String>) -> openapiv3:
       min_properties: Default::default(),
       max_properties: Default::default(),
     })),
-    Type::AnonymousStruct(_) => unimplemented!("Anonymous structs are not supported in OpenAPI config"),
+    Type::AnonymousStruct(fields) => {
+      openapiv3::SchemaKind::Type(openapiv3::Type::Object(fields_to_object(fields, named)))
+    }
     #[allow(deprecated)]
     Type::Link { .. } => unreachable!(),
   }
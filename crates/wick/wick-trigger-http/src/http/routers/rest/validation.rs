@@ -0,0 +1,333 @@
+use hyper::{Body, Response, StatusCode};
+use serde_json::Value;
+use wick_interface_types::{Field, FieldValue, OperationSignature, Type, TypeDefinition};
+
+/// The name of the input port that receives the request body.
+const BODY_INPUT: &str = "input";
+
+/// Where in a request an invalid value was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum Location {
+  Path,
+  Query,
+  Body,
+}
+
+/// A single problem found while validating a request.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub(super) struct FieldError {
+  location: Location,
+  field: String,
+  message: String,
+}
+
+/// Validates request data against the inputs of the operation a route targets.
+#[derive(Debug)]
+pub(super) struct Validator<'a> {
+  signature: &'a OperationSignature,
+  types: &'a [TypeDefinition],
+  errors: Vec<FieldError>,
+}
+
+impl<'a> Validator<'a> {
+  pub(super) const fn new(signature: &'a OperationSignature, types: &'a [TypeDefinition]) -> Self {
+    Self {
+      signature,
+      types,
+      errors: Vec::new(),
+    }
+  }
+
+  /// Validate path or query parameters.
+  pub(super) fn params(&mut self, location: Location, params: &[FieldValue]) {
+    for param in params {
+      let name = param.field.name();
+      match self.input(name) {
+        Some(input) => {
+          let ty = input.ty().clone();
+          self.value(location, name, &param.value, &ty);
+        }
+        None => self.error(location, name, "is not an input of the operation"),
+      }
+    }
+  }
+
+  /// Validate the decoded JSON body of a request.
+  pub(super) fn body(&mut self, body: Option<&Value>) {
+    match (self.input(BODY_INPUT), body) {
+      (Some(input), Some(body)) => {
+        let ty = input.ty().clone();
+        self.value(Location::Body, BODY_INPUT, body, &ty);
+      }
+      (None, Some(_)) => self.error(Location::Body, BODY_INPUT, "operation does not accept a request body"),
+      _ => {}
+    }
+  }
+
+  /// Check that every required input was provided, either as a parameter or as the request body.
+  pub(super) fn required(&mut self, provided: &[&str]) {
+    let missing: Vec<_> = self
+      .signature
+      .inputs
+      .iter()
+      .filter(|input| is_required(input) && !provided.contains(&input.name()))
+      .map(|input| input.name().to_owned())
+      .collect();
+    for name in missing {
+      let location = if name == BODY_INPUT {
+        Location::Body
+      } else {
+        Location::Query
+      };
+      self.error(location, &name, "is required");
+    }
+  }
+
+  /// Finish validation, returning a 400 response if any errors were found.
+  pub(super) fn finish(self) -> Option<Response<Body>> {
+    if self.errors.is_empty() {
+      return None;
+    }
+    let body = serde_json::json!({
+      "error": "invalid request",
+      "details": self.errors,
+    });
+    Some(
+      Response::builder()
+        .status(StatusCode::BAD_REQUEST)
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap(),
+    )
+  }
+
+  fn input(&self, name: &str) -> Option<&'a Field> {
+    self.signature.inputs.iter().find(|input| input.name() == name)
+  }
+
+  fn error<T: Into<String>>(&mut self, location: Location, field: &str, message: T) {
+    self.errors.push(FieldError {
+      location,
+      field: field.to_owned(),
+      message: message.into(),
+    });
+  }
+
+  fn value(&mut self, location: Location, field: &str, value: &Value, ty: &Type) {
+    if let Err(message) = check(value, ty, self.types, field) {
+      self.error(location, field, message);
+    }
+  }
+}
+
+const fn is_required(field: &Field) -> bool {
+  (field.required() || !matches!(field.ty(), Type::Optional { .. })) && field.default().is_none()
+}
+
+/// Check `value` against `ty`, returning a message describing the first mismatch found.
+fn check(value: &Value, ty: &Type, types: &[TypeDefinition], path: &str) -> Result<(), String> {
+  let mismatch = || Err(format!("{}: expected {}, got {}", path, ty, kind(value)));
+  match ty {
+    Type::I8 => int(value, i8::MIN.into(), i8::MAX.into()).map_or_else(mismatch, Ok),
+    Type::I16 => int(value, i16::MIN.into(), i16::MAX.into()).map_or_else(mismatch, Ok),
+    Type::I32 => int(value, i32::MIN.into(), i32::MAX.into()).map_or_else(mismatch, Ok),
+    Type::I64 => value.as_i64().map(|_| ()).map_or_else(mismatch, Ok),
+    Type::U8 => uint(value, u8::MAX.into()).map_or_else(mismatch, Ok),
+    Type::U16 => uint(value, u16::MAX.into()).map_or_else(mismatch, Ok),
+    Type::U32 => uint(value, u32::MAX.into()).map_or_else(mismatch, Ok),
+    Type::U64 => value.as_u64().map(|_| ()).map_or_else(mismatch, Ok),
+    Type::F32 | Type::F64 => value.is_number().then_some(()).map_or_else(mismatch, Ok),
+    Type::Bool => value.is_boolean().then_some(()).map_or_else(mismatch, Ok),
+    Type::String => value.is_string().then_some(()).map_or_else(mismatch, Ok),
+    Type::Datetime => (value.is_string() || value.is_number())
+      .then_some(())
+      .map_or_else(mismatch, Ok),
+    Type::Bytes => (value.is_string() || value.is_array())
+      .then_some(())
+      .map_or_else(mismatch, Ok),
+    Type::Optional { ty } => {
+      if value.is_null() {
+        Ok(())
+      } else {
+        check(value, ty, types, path)
+      }
+    }
+    Type::List { ty } => {
+      let Value::Array(items) = value else {
+        return mismatch();
+      };
+      for (i, item) in items.iter().enumerate() {
+        check(item, ty, types, &format!("{}[{}]", path, i))?;
+      }
+      Ok(())
+    }
+    Type::Map { value: ty, .. } => {
+      let Value::Object(map) = value else {
+        return mismatch();
+      };
+      for (key, item) in map {
+        check(item, ty, types, &format!("{}.{}", path, key))?;
+      }
+      Ok(())
+    }
+    Type::AnonymousStruct(fields) => fields_match(value, fields, types, path),
+    Type::Named(name) => {
+      let Some(def) = types.iter().find(|t| t.name() == name) else {
+        // Types we can't resolve are passed through for the operation to handle.
+        return Ok(());
+      };
+      match def {
+        TypeDefinition::Struct(def) => fields_match(value, &def.fields, types, path),
+        TypeDefinition::Enum(def) => {
+          let valid = def.variants.iter().any(|v| match value {
+            Value::String(s) => &v.name == s || v.value.as_ref() == Some(s),
+            Value::Number(n) => v.index.is_some() && n.as_u64() == v.index.map(Into::into),
+            _ => false,
+          });
+          if valid {
+            Ok(())
+          } else {
+            Err(format!(
+              "{}: expected one of {}, got {}",
+              path,
+              def
+                .variants
+                .iter()
+                .map(|v| v.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+              value
+            ))
+          }
+        }
+        TypeDefinition::Union(def) => {
+          if def.types.iter().any(|ty| check(value, ty, types, path).is_ok()) {
+            Ok(())
+          } else {
+            mismatch()
+          }
+        }
+      }
+    }
+    #[allow(deprecated)]
+    Type::Object | Type::Link { .. } => Ok(()),
+  }
+}
+
+/// Check that `value` is an object that has every required field and that each present field is valid.
+fn fields_match(value: &Value, fields: &[Field], types: &[TypeDefinition], path: &str) -> Result<(), String> {
+  let Value::Object(map) = value else {
+    return Err(format!("{}: expected object, got {}", path, kind(value)));
+  };
+  for field in fields {
+    let path = format!("{}.{}", path, field.name());
+    match map.get(field.name()) {
+      Some(item) => check(item, field.ty(), types, &path)?,
+      None if is_required(field) => return Err(format!("{}: is required", path)),
+      None => {}
+    }
+  }
+  Ok(())
+}
+
+fn int(value: &Value, min: i64, max: i64) -> Option<()> {
+  value.as_i64().filter(|v| (min..=max).contains(v)).map(|_| ())
+}
+
+fn uint(value: &Value, max: u64) -> Option<()> {
+  value.as_u64().filter(|v| *v <= max).map(|_| ())
+}
+
+const fn kind(value: &Value) -> &'static str {
+  match value {
+    Value::Null => "null",
+    Value::Bool(_) => "boolean",
+    Value::Number(_) => "number",
+    Value::String(_) => "string",
+    Value::Array(_) => "array",
+    Value::Object(_) => "object",
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+  use wick_interface_types::{EnumDefinition, EnumVariant, StructDefinition};
+
+  use super::*;
+
+  fn types() -> Vec<TypeDefinition> {
+    vec![
+      TypeDefinition::Struct(StructDefinition::new(
+        "User",
+        vec![
+          Field::new("name", Type::String),
+          Field::new("age", Type::Optional { ty: Box::new(Type::U8) }),
+        ],
+        None,
+      )),
+      TypeDefinition::Enum(EnumDefinition::new(
+        "Color",
+        vec![EnumVariant::new("Red", Some(0), None, None)],
+        None,
+      )),
+    ]
+  }
+
+  #[test]
+  fn test_check_valid() -> Result<()> {
+    let cases = [
+      (json!({"name": "jane"}), Type::Named("User".to_owned())),
+      (json!({"name": "jane", "age": 200}), Type::Named("User".to_owned())),
+      (json!([1, 2, 3]), Type::List { ty: Box::new(Type::I8) }),
+      (json!("Red"), Type::Named("Color".to_owned())),
+      (json!(0), Type::Named("Color".to_owned())),
+      (
+        json!(null),
+        Type::Optional {
+          ty: Box::new(Type::String),
+        },
+      ),
+      (json!({"anything": [true]}), Type::Object),
+    ];
+    for (value, ty) in cases {
+      assert_eq!(
+        check(&value, &ty, &types(), "input"),
+        Ok(()),
+        "{} should be a valid {}",
+        value,
+        ty
+      );
+    }
+    Ok(())
+  }
+
+  #[test]
+  fn test_check_invalid() -> Result<()> {
+    let cases = [
+      (json!({}), Type::Named("User".to_owned()), "input.name: is required"),
+      (
+        json!({"name": "jane", "age": 300}),
+        Type::Named("User".to_owned()),
+        "input.age: expected u8, got number",
+      ),
+      (
+        json!([1, "2"]),
+        Type::List { ty: Box::new(Type::I8) },
+        "input[1]: expected i8, got string",
+      ),
+      (
+        json!("Blue"),
+        Type::Named("Color".to_owned()),
+        "input: expected one of Red, got \"Blue\"",
+      ),
+      (json!(-1), Type::U32, "input: expected u32, got number"),
+    ];
+    for (value, ty, expected) in cases {
+      assert_eq!(check(&value, &ty, &types(), "input"), Err(expected.to_owned()));
+    }
+    Ok(())
+  }
+}
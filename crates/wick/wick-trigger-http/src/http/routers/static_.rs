@@ -1,13 +1,26 @@
 use std::collections::HashMap;
+use std::fs::Metadata;
 use std::net::SocketAddr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::task::{Context, Poll};
 mod index_list;
 
+use hyper::header::{
+  HeaderValue,
+  ACCEPT_ENCODING,
+  CACHE_CONTROL,
+  CONTENT_ENCODING,
+  ETAG,
+  IF_MODIFIED_SINCE,
+  IF_NONE_MATCH,
+  LAST_MODIFIED,
+  VARY,
+};
 use hyper::service::Service;
-use hyper::{Body, Method, Request, Response};
+use hyper::{Body, Method, Request, Response, StatusCode};
 use hyper_staticfile::{resolve_path, ResolveResult, ResponseBuilder};
+use tokio::fs::File;
 use tracing::{Instrument, Span};
 use uuid::Uuid;
 use wick_config::config::{BoundIdentifier, StaticRouterConfig, WickRouter};
@@ -26,9 +39,15 @@ pub(super) struct StaticRouter {
 }
 
 impl StaticRouter {
-  pub(super) fn new(root: PathBuf, strip: Option<String>, indexes: bool, fallback: Option<String>) -> Self {
+  pub(super) fn new(
+    root: PathBuf,
+    strip: Option<String>,
+    indexes: bool,
+    fallback: Option<String>,
+    precompressed: bool,
+  ) -> Self {
     debug!(directory = %root.display(), "http:static:serving");
-    let handler = Static::new(root, strip, indexes, fallback);
+    let handler = Static::new(root, strip, indexes, fallback, precompressed);
     Self { handler }
   }
 }
@@ -56,34 +75,149 @@ impl RawRouter for StaticRouter {
   }
 }
 
+/// Encodings we look for precompressed variants of, in order of preference, with their file extensions.
+const PRECOMPRESSED_VARIANTS: [(&str, &str); 2] = [("br", "br"), ("gzip", "gz")];
+
 fn create_response<B>(request: &Request<B>, result: ResolveResult) -> Result<Response<Body>, StaticError>
 where
   B: Send + Sync + 'static,
 {
+  // `If-None-Match` takes precedence over `If-Modified-Since`, so only let the latter through when the former is absent.
+  let mut headers = request.headers().clone();
+  if headers.contains_key(IF_NONE_MATCH) {
+    headers.remove(IF_MODIFIED_SINCE);
+  }
   #[allow(clippy::expect_used)]
-  Ok(
-    ResponseBuilder::new()
-      .request(request)
-      .build(result)
-      .expect("unable to build response"),
-  )
+  let response = ResponseBuilder::new()
+    .request_parts(request.method(), request.uri(), &headers)
+    .build(result)
+    .expect("unable to build response");
+  Ok(check_etag(request, response))
+}
+
+/// Replace a response with `304 Not Modified` if its ETag matches the request's `If-None-Match` header.
+fn check_etag<B>(request: &Request<B>, response: Response<Body>) -> Response<Body> {
+  let (Some(etag), Some(if_none_match)) = (response.headers().get(ETAG), request.headers().get(IF_NONE_MATCH)) else {
+    return response;
+  };
+  let etag = etag.to_str().unwrap_or_default();
+  let matches = if_none_match.to_str().map_or(false, |tags| {
+    tags
+      .split(',')
+      .map(str::trim)
+      .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag.trim_start_matches("W/"))
+  });
+  if !matches {
+    return response;
+  }
+
+  let mut not_modified = Response::new(Body::empty());
+  *not_modified.status_mut() = StatusCode::NOT_MODIFIED;
+  for name in [ETAG, LAST_MODIFIED, CACHE_CONTROL, VARY, CONTENT_ENCODING] {
+    if let Some(value) = response.headers().get(&name) {
+      not_modified.headers_mut().insert(name, value.clone());
+    }
+  }
+  not_modified
+}
+
+fn accepts_encoding<B>(request: &Request<B>, encoding: &str) -> bool {
+  request
+    .headers()
+    .get_all(ACCEPT_ENCODING)
+    .iter()
+    .filter_map(|value| value.to_str().ok())
+    .flat_map(|value| value.split(','))
+    .any(|item| {
+      let mut parts = item.split(';').map(str::trim);
+      let name = parts.next().unwrap_or_default();
+      let quality = parts
+        .find_map(|part| part.strip_prefix("q="))
+        .and_then(|q| q.parse::<f32>().ok())
+        .unwrap_or(1.0);
+      name.eq_ignore_ascii_case(encoding) && quality > 0.0
+    })
+}
+
+/// Find a precompressed variant of the file at `path` that the client accepts.
+async fn find_precompressed<B>(
+  root: &Path,
+  path: &str,
+  request: &Request<B>,
+) -> Result<Option<(&'static str, File, Metadata)>, StaticError>
+where
+  B: Send + Sync + 'static,
+{
+  let path = if path.ends_with('/') {
+    format!("{}index.html", path)
+  } else {
+    path.to_owned()
+  };
+  for (encoding, extension) in PRECOMPRESSED_VARIANTS {
+    if !accepts_encoding(request, encoding) {
+      continue;
+    }
+    if let ResolveResult::Found(file, metadata, _) = resolve_path(root, &format!("{}.{}", path, extension)).await? {
+      return Ok(Some((encoding, file, metadata)));
+    }
+  }
+  Ok(None)
+}
+
+/// Create the response for a file request, serving a precompressed variant in its place when enabled and available.
+async fn create_file_response<B>(
+  root: &Path,
+  path: &str,
+  request: &Request<B>,
+  precompressed: bool,
+  result: ResolveResult,
+) -> Result<Response<Body>, StaticError>
+where
+  B: Send + Sync + 'static,
+{
+  if !precompressed {
+    return create_response(request, result);
+  }
+  let mut response = match (result, find_precompressed(root, path, request).await?) {
+    (ResolveResult::Found(_, _, mime), Some((encoding, file, metadata))) => {
+      let mut response = create_response(request, ResolveResult::Found(file, metadata, mime))?;
+      response
+        .headers_mut()
+        .insert(CONTENT_ENCODING, HeaderValue::from_static(encoding));
+      response
+    }
+    (result, _) => create_response(request, result)?,
+  };
+  response
+    .headers_mut()
+    .insert(VARY, HeaderValue::from_static("accept-encoding"));
+  Ok(response)
 }
+
 #[derive(Clone)]
 struct Static {
   root: PathBuf,
   strip: Option<String>,
   fallback: Option<String>,
   indexes: bool,
+  precompressed: bool,
 }
 
 impl Static {
-  fn new(root: impl Into<PathBuf>, strip: Option<String>, indexes: bool, fallback: Option<String>) -> Self {
+  fn new(
+    root: impl Into<PathBuf>,
+    strip: Option<String>,
+    indexes: bool,
+    fallback: Option<String>,
+    precompressed: bool,
+  ) -> Self {
     let root = root.into();
     Static {
       root,
       strip,
       indexes,
       fallback,
+      precompressed,
     }
   }
 
@@ -97,6 +231,7 @@ impl Static {
       strip,
       indexes,
       fallback,
+      precompressed,
     } = self;
     // Handle only `GET`/`HEAD` and absolute paths.
     match *request.method() {
@@ -120,7 +255,7 @@ impl Static {
     let result = resolve_path(root.clone(), path).await;
 
     match result {
-      Ok(ResolveResult::Found(_, _, _)) => create_response(&request, result?),
+      Ok(ResolveResult::Found(_, _, _)) => create_file_response(&root, path, &request, precompressed, result?).await,
       Ok(ResolveResult::IsDirectory) if indexes => index_list::create(&root, &request).await,
       Ok(ResolveResult::NotFound) if (indexes && request.uri().path() == "/") => {
         index_list::create(&root, &request).await
@@ -128,7 +263,7 @@ impl Static {
       _ => {
         if let Some(fb) = &fallback {
           let fallback_result = resolve_path(root.clone(), fb).await;
-          create_response(&request, fallback_result?)
+          create_file_response(&root, fb, &request, precompressed, fallback_result?).await
         } else {
           create_response(&request, result?)
         }
@@ -173,8 +308,15 @@ pub(crate) fn register_static_router(
 
   let fallback = router_config.fallback().cloned();
   let indexes = router_config.indexes();
+  let precompressed = router_config.precompressed();
 
-  let router = StaticRouter::new(volume, Some(router_config.path().to_owned()), indexes, fallback);
+  let router = StaticRouter::new(
+    volume,
+    Some(router_config.path().to_owned()),
+    indexes,
+    fallback,
+    precompressed,
+  );
 
   Ok(HttpRouter::Raw(RawRouterHandler {
     path: router_config.path().to_owned(),
@@ -182,3 +324,108 @@ pub(crate) fn register_static_router(
     middleware,
   }))
 }
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use hyper::header::{CONTENT_TYPE, RANGE};
+
+  use super::*;
+
+  struct TestDir(PathBuf);
+
+  impl TestDir {
+    fn new() -> Result<Self> {
+      let root = std::env::temp_dir().join(format!("wick-static-{}", Uuid::new_v4()));
+      std::fs::create_dir_all(&root)?;
+      std::fs::write(root.join("index.html"), "<html></html>")?;
+      std::fs::write(root.join("app.js"), "console.log('hello');")?;
+      std::fs::write(root.join("app.js.br"), "brotli")?;
+      std::fs::write(root.join("app.js.gz"), "gzip")?;
+      Ok(Self(root))
+    }
+  }
+
+  impl Drop for TestDir {
+    fn drop(&mut self) {
+      let _ = std::fs::remove_dir_all(&self.0);
+    }
+  }
+
+  async fn serve(dir: &TestDir, path: &str, headers: &[(&str, &str)]) -> Result<Response<Body>> {
+    let handler = Static::new(&dir.0, None, false, Some("index.html".to_owned()), true);
+    let mut request = Request::get(path);
+    for (name, value) in headers {
+      request = request.header(*name, *value);
+    }
+    handler
+      .serve(request.body(())?)
+      .await
+      .map_err(|e| anyhow::anyhow!("{}", e))
+  }
+
+  async fn body(response: Response<Body>) -> Result<String> {
+    let bytes = hyper::body::to_bytes(response.into_body()).await?;
+    Ok(String::from_utf8(bytes.to_vec())?)
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_precompressed() -> Result<()> {
+    let dir = TestDir::new()?;
+
+    let response = serve(&dir, "/app.js", &[("accept-encoding", "gzip, br")]).await?;
+    assert_eq!(response.headers()[CONTENT_ENCODING], "br");
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/javascript");
+    assert_eq!(response.headers()[VARY], "accept-encoding");
+    assert_eq!(body(response).await?, "brotli");
+
+    let response = serve(&dir, "/app.js", &[("accept-encoding", "gzip, br;q=0")]).await?;
+    assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+    assert_eq!(body(response).await?, "gzip");
+
+    let response = serve(&dir, "/app.js", &[]).await?;
+    assert!(response.headers().get(CONTENT_ENCODING).is_none());
+    assert_eq!(body(response).await?, "console.log('hello');");
+
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_etag() -> Result<()> {
+    let dir = TestDir::new()?;
+
+    let response = serve(&dir, "/app.js", &[]).await?;
+    let etag = response.headers()[ETAG].to_str()?.to_owned();
+
+    let response = serve(&dir, "/app.js", &[("if-none-match", &etag)]).await?;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[ETAG], etag.as_str());
+
+    let response = serve(&dir, "/app.js", &[("if-none-match", "\"other\"")]).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_range() -> Result<()> {
+    let dir = TestDir::new()?;
+
+    let response = serve(&dir, "/app.js", &[(RANGE.as_str(), "bytes=0-6")]).await?;
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(body(response).await?, "console");
+
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_fallback() -> Result<()> {
+    let dir = TestDir::new()?;
+
+    let response = serve(&dir, "/some/client/route", &[]).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(response).await?, "<html></html>");
+
+    Ok(())
+  }
+}
@@ -4,13 +4,16 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
+use hyper::header::{HeaderValue, ORIGIN};
 use hyper::http::response::Builder;
 use hyper::server::conn::AddrStream;
 use hyper::service::Service;
 use hyper::{Body, Request, Response, StatusCode};
+use parking_lot::RwLock;
 use tracing::{Id, Span};
 use uuid::Uuid;
 use wick_interface_http::types::RequestMiddlewareResponse;
+use wick_logger::trace_context::{self, TRACEPARENT};
 use wick_packet::Invocation;
 use wick_runtime::Runtime;
 
@@ -19,22 +22,38 @@ use super::conversions::{convert_response, convert_to_wick_response, merge_reque
 use super::error::InternalError;
 use super::{HttpError, HttpRouter, RawRouterHandler};
 
-pub(super) struct ServiceFactory {
+/// The runtime and routers that new requests are dispatched to.
+///
+/// Each request takes its own snapshot, so swapping in a reloaded configuration leaves in-flight requests running
+/// against the routers and runtime they started with.
+#[derive(Debug, Clone)]
+pub(super) struct ActiveRoutes {
   runtime: Runtime,
   routers: Arc<Vec<HttpRouter>>,
-  span: Option<Id>,
 }
 
-impl ServiceFactory {
-  pub(super) fn new(runtime: Runtime, routers: Vec<HttpRouter>, span: Option<Id>) -> Self {
+impl ActiveRoutes {
+  pub(super) fn new(runtime: Runtime, routers: Vec<HttpRouter>) -> Self {
     Self {
       runtime,
       routers: Arc::new(routers),
-      span,
     }
   }
 }
 
+pub(super) type SharedRoutes = Arc<RwLock<ActiveRoutes>>;
+
+pub(super) struct ServiceFactory {
+  routes: SharedRoutes,
+  span: Option<Id>,
+}
+
+impl ServiceFactory {
+  pub(super) fn new(routes: SharedRoutes, span: Option<Id>) -> Self {
+    Self { routes, span }
+  }
+}
+
 impl Service<&AddrStream> for ServiceFactory {
   type Response = ResponseService;
   type Error = hyper::Error;
@@ -45,30 +64,27 @@ impl Service<&AddrStream> for ServiceFactory {
   }
 
   fn call(&mut self, conn: &AddrStream) -> Self::Future {
-    let rt = self.runtime.clone();
-    let routers = self.routers.clone();
+    let routes = self.routes.clone();
 
     let remote_addr = conn.remote_addr();
     let span = self.span.clone();
 
-    let fut = async move { Ok(ResponseService::new(remote_addr, rt, routers, span)) };
+    let fut = async move { Ok(ResponseService::new(remote_addr, routes, span)) };
     Box::pin(fut)
   }
 }
 
 pub(super) struct ResponseService {
   remote_addr: SocketAddr,
-  runtime: Runtime,
-  routers: Arc<Vec<HttpRouter>>,
+  routes: SharedRoutes,
   span: Option<Id>,
 }
 
 impl ResponseService {
-  fn new(remote_addr: SocketAddr, runtime: Runtime, routers: Arc<Vec<HttpRouter>>, span: Option<Id>) -> Self {
+  fn new(remote_addr: SocketAddr, routes: SharedRoutes, span: Option<Id>) -> Self {
     Self {
       remote_addr,
-      runtime,
-      routers,
+      routes,
       span,
     }
   }
@@ -90,6 +106,9 @@ impl Service<Request<Body>> for ResponseService {
     let tx_id = Invocation::new_tx_id();
     let span = info_span!("http:request",%tx_id);
     span.follows_from(self.span.clone());
+    if let Some(traceparent) = req.headers().get(TRACEPARENT).and_then(|v| v.to_str().ok()) {
+      trace_context::set_parent(&span, traceparent);
+    }
 
     span.in_scope(|| {
       info!(
@@ -99,16 +118,15 @@ impl Service<Request<Body>> for ResponseService {
         version = ?req.version(),
       );
     });
-    let rt = self.runtime.clone();
-    let router = self
-      .routers
+    let ActiveRoutes { runtime: rt, routers } = self.routes.read().clone();
+    let router = routers
       .iter()
       .find(|r| req.uri().path().starts_with(r.path()))
       .cloned();
 
     Box::pin(async move {
       let start = chrono::Local::now().format("%d/%b/%Y:%H:%M:%S %z");
-      let response = match router {
+      let mut response = match router {
         Some(h) => match h {
           HttpRouter::Raw(r) => match handle(tx_id, req, r, rt.clone(), remote_addr, &span).await {
             Ok(v) => v,
@@ -143,6 +161,10 @@ impl Service<Request<Body>> for ResponseService {
         });
       };
 
+      if let Some(traceparent) = trace_context::traceparent(&span).and_then(|v| HeaderValue::from_str(&v).ok()) {
+        response.headers_mut().insert(TRACEPARENT, traceparent);
+      }
+
       Ok(response)
     })
   }
@@ -156,9 +178,36 @@ async fn handle(
   remote_addr: SocketAddr,
   span: &Span,
 ) -> Result<Response<Body>, HttpError> {
+  let Some(cors) = &r.middleware.cors else {
+    return route(tx_id, req, &r, runtime, remote_addr, span).await;
+  };
+  // preflight requests carry no credentials, so they are answered before authentication.
+  if let Some(response) = cors.preflight(&req) {
+    return Ok(response);
+  }
+  let origin = req.headers().get(ORIGIN).cloned();
+  let mut response = route(tx_id, req, &r, runtime, remote_addr, span).await?;
+  if let Some(origin) = origin {
+    cors.apply(&origin, &mut response);
+  }
+  Ok(response)
+}
+
+async fn route(
+  tx_id: Uuid,
+  req: Request<Body>,
+  r: &RawRouterHandler,
+  runtime: Runtime,
+  remote_addr: SocketAddr,
+  span: &Span,
+) -> Result<Response<Body>, HttpError> {
+  // requests that fail built-in authentication never reach middleware or the router.
+  if let Some(response) = r.middleware.auth.as_ref().and_then(|auth| auth.authenticate(&req)) {
+    return Ok(response);
+  }
   let pre_span = info_span!(parent: span, "pre-request", service.name="pre-request");
   let (wick_request_object, early_response) =
-    run_request_middleware(tx_id, &req, runtime.clone(), &r, remote_addr, &pre_span).await?;
+    run_request_middleware(tx_id, &req, runtime.clone(), r, remote_addr, &pre_span).await?;
   // if we have an early response, skip the main handler.
   let response = if let Some(response) = early_response {
     response
@@ -170,7 +219,7 @@ async fn handle(
       .await?
   };
   let post_span = info_span!(parent: span, "post-request");
-  run_response_middleware(tx_id, wick_request_object, response, runtime.clone(), &r, &post_span).await
+  run_response_middleware(tx_id, wick_request_object, response, runtime.clone(), r, &post_span).await
 }
 
 async fn run_request_middleware<B>(
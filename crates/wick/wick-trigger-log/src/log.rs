@@ -0,0 +1,448 @@
+pub(crate) mod error;
+mod parser;
+mod tail;
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use structured_output::StructuredOutput;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::Span;
+use wick_config::config::{AppConfiguration, BoundIdentifier, LogSourceConfig, TriggerDefinition};
+use wick_packet::{Entity, InherentData, Invocation, Packet, RuntimeConfig};
+use wick_runtime::Runtime;
+use wick_trigger::resources::{Resource, ResourceKind};
+use wick_trigger::{Error, ErrorKind, Trigger};
+
+use self::error::LogError;
+use self::parser::{Format, Parser};
+use self::tail::{file_id, Event, FileId, Tail};
+
+/// How often the files are checked for new lines.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How many parsed records can wait to be sent before reading stops until they are.
+const BUFFER_SIZE: usize = 128;
+
+async fn invoke_operation(
+  runtime: &Runtime,
+  target: &Entity,
+  config: Option<RuntimeConfig>,
+  record: Value,
+  span: &Span,
+) -> Result<(), Error> {
+  let invocation = Invocation::new(
+    Entity::server("log_client"),
+    target.clone(),
+    vec![Packet::encode("record", record), Packet::done("record")],
+    InherentData::unsafe_default(),
+    span,
+  );
+
+  let mut response = runtime.invoke(invocation, config).await?;
+  while let Some(packet) = response.next().await {
+    match packet {
+      Ok(packet) if packet.is_error() => {
+        span.in_scope(|| warn!(error = packet.unwrap_err().msg(), "trigger:log:response"));
+      }
+      packet => trace!(?packet, "trigger:log:response"),
+    }
+  }
+  Ok(())
+}
+
+/// A source's glob patterns, resolved against its volume, and the format of its files.
+#[derive(Debug)]
+struct Source {
+  patterns: Vec<String>,
+  format: Arc<Format>,
+  from_start: bool,
+}
+
+impl Source {
+  fn new(root: &Path, config: &LogSourceConfig) -> Result<Self, LogError> {
+    let root = glob::Pattern::escape(&root.to_string_lossy());
+    let mut patterns = Vec::new();
+    for path in config.paths() {
+      let relative = Path::new(path);
+      if relative.is_absolute() || relative.components().any(|c| c == Component::ParentDir) {
+        return Err(LogError::InvalidPath(
+          path.clone(),
+          "paths must be relative to the volume and stay inside it".to_owned(),
+        ));
+      }
+      let pattern = format!("{}/{}", root.trim_end_matches('/'), path);
+      glob::Pattern::new(&pattern).map_err(|e| LogError::InvalidPath(path.clone(), e.msg.to_owned()))?;
+      patterns.push(pattern);
+    }
+    Ok(Self {
+      patterns,
+      format: Arc::new(Format::new(config)?),
+      from_start: config.from_start(),
+    })
+  }
+}
+
+#[derive(Debug)]
+struct LogFile {
+  tail: Tail,
+  parser: Parser,
+}
+
+/// Watches the files of every source from its own thread, sending the records parsed from them to be shipped.
+#[derive(Debug)]
+struct Watcher {
+  sources: Vec<Source>,
+  files: BTreeMap<PathBuf, LogFile>,
+  records: mpsc::Sender<Value>,
+  stop: Arc<AtomicBool>,
+  span: Span,
+}
+
+impl Watcher {
+  fn run(mut self) {
+    let mut first = true;
+    while !self.stop.load(Ordering::Relaxed) {
+      if !self.poll(first) {
+        break;
+      }
+      first = false;
+      std::thread::sleep(POLL_INTERVAL);
+    }
+    self.span.in_scope(|| debug!("log trigger stopped watching files"));
+  }
+
+  /// Check every file for new lines, returning false once records can't be sent anymore.
+  ///
+  /// Files that exist when the trigger starts are read from their end unless their source says otherwise. Files found
+  /// afterwards are new and read from their beginning, except for a file that's been renamed by rotation, which is
+  /// followed and read from where it was left off.
+  fn poll(&mut self, first: bool) -> bool {
+    let matched = self.matched();
+
+    let moved: Vec<PathBuf> = self
+      .files
+      .iter()
+      .filter(|(path, file)| !still_at(path, file.tail.id()))
+      .map(|(path, _)| path.clone())
+      .collect();
+    for path in moved {
+      let Some(mut file) = self.files.remove(&path) else {
+        continue;
+      };
+      let renamed = file.tail.id().and_then(|id| {
+        matched
+          .iter()
+          .find(|(path, (_, other))| *other == Some(id) && !self.files.contains_key(*path))
+          .map(|(path, _)| (*path).clone())
+      });
+      if let Some(renamed) = renamed {
+        self
+          .span
+          .in_scope(|| debug!(from = %path.display(), to = %renamed.display(), "following rotated log file"));
+        self.files.insert(renamed, file);
+        continue;
+      }
+      match file.tail.drain() {
+        Ok(events) => {
+          if !send(&self.records, &self.span, &path, &mut file.parser, events) {
+            return false;
+          }
+        }
+        Err(e) => self
+          .span
+          .in_scope(|| warn!(path = %path.display(), error = %e, "could not read rotated log file")),
+      }
+    }
+
+    for (path, (source, _)) in &matched {
+      if self.files.contains_key(path) {
+        continue;
+      }
+      let source = &self.sources[*source];
+      match Tail::open(path, !first || source.from_start) {
+        Ok(tail) => {
+          self
+            .span
+            .in_scope(|| debug!(path = %path.display(), "tailing log file"));
+          let parser = Parser::new(source.format.clone());
+          self.files.insert(path.clone(), LogFile { tail, parser });
+        }
+        Err(e) => self
+          .span
+          .in_scope(|| warn!(path = %path.display(), error = %e, "could not open log file")),
+      }
+    }
+
+    for (path, file) in &mut self.files {
+      match file.tail.read() {
+        Ok(events) => {
+          if !send(&self.records, &self.span, path, &mut file.parser, events) {
+            return false;
+          }
+        }
+        Err(e) => self
+          .span
+          .in_scope(|| warn!(path = %path.display(), error = %e, "could not read log file")),
+      }
+    }
+    true
+  }
+
+  /// Every file matched by a source's patterns, with the source that matched it first and its id.
+  fn matched(&self) -> BTreeMap<PathBuf, (usize, Option<FileId>)> {
+    let mut matched = BTreeMap::new();
+    for (index, source) in self.sources.iter().enumerate() {
+      for pattern in &source.patterns {
+        let Ok(paths) = glob::glob(pattern) else {
+          continue;
+        };
+        for path in paths.flatten() {
+          let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+          };
+          if metadata.is_file() {
+            matched.entry(path).or_insert((index, file_id(&metadata)));
+          }
+        }
+      }
+    }
+    matched
+  }
+}
+
+/// Whether the file with `id` is still at `path`.
+fn still_at(path: &Path, id: Option<FileId>) -> bool {
+  std::fs::metadata(path).map_or(false, |metadata| file_id(&metadata) == id)
+}
+
+/// Parse the lines read from a file and send their records, returning false once they can't be sent anymore.
+fn send(records: &mpsc::Sender<Value>, span: &Span, path: &Path, parser: &mut Parser, events: Vec<Event>) -> bool {
+  for event in events {
+    match event {
+      Event::Restart => parser.reset(),
+      Event::Line(line) => match parser.parse(&line) {
+        Ok(Some(record)) => {
+          if records.blocking_send(record).is_err() {
+            return false;
+          }
+        }
+        Ok(None) => {}
+        Err(e) => span.in_scope(|| warn!(path = %path.display(), error = %e, "skipping log line")),
+      },
+    }
+  }
+  true
+}
+
+#[derive(Debug)]
+struct LogInstance {
+  handle: JoinHandle<()>,
+  stop: Arc<AtomicBool>,
+  running_rx: Option<oneshot::Receiver<()>>,
+}
+
+impl LogInstance {
+  async fn shutdown(self) -> Result<(), Error> {
+    debug!("shutting down log trigger");
+    // the watcher stops at its next poll, and the records it already read are sent before the shipping task ends.
+    self.stop.store(true, Ordering::Relaxed);
+    self.handle.await.map_err(|_| {
+      Error::new_context(
+        "log",
+        ErrorKind::Shutdown("waiting for queued records to be sent failed".to_owned()),
+      )
+    })
+  }
+}
+
+#[derive(Default)]
+pub struct Log {
+  instance: Arc<Mutex<Option<LogInstance>>>,
+}
+
+impl fmt::Debug for Log {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Log")
+  }
+}
+
+#[async_trait]
+impl Trigger for Log {
+  async fn run(
+    &self,
+    _name: String,
+    runtime: Runtime,
+    _app_config: AppConfiguration,
+    config: TriggerDefinition,
+    resources: Arc<HashMap<BoundIdentifier, Resource>>,
+    span: Span,
+  ) -> Result<StructuredOutput, Error> {
+    span.in_scope(|| debug!(kind = "log", "trigger:run"));
+    let TriggerDefinition::Log(config) = config else {
+      panic!("invalid trigger definition, expected Log configuration");
+    };
+
+    let mut sources = Vec::new();
+    for source in config.sources() {
+      let resource_name = source.resource();
+      let resource = resources
+        .get(resource_name)
+        .ok_or_else(|| Error::new_context("log", ErrorKind::ResourceNotFound(resource_name.clone())))?;
+      let Resource::Volume(root) = resource else {
+        return Err(Error::new_context(
+          "log",
+          ErrorKind::InvalidResourceType(ResourceKind::Volume, resource.kind()),
+        ));
+      };
+      sources.push(Source::new(root, source)?);
+    }
+    let patterns: Vec<_> = sources.iter().flat_map(|s| s.patterns.clone()).collect();
+
+    let component_id = config.operation().component_id().map_err(LogError::from)?;
+    let target = Entity::operation(component_id, config.operation().name());
+    let op_config = config.operation().config().and_then(|c| c.value().cloned());
+    let span = info_span!(parent: &span, "trigger:log", target = %target);
+
+    let (tx, mut rx) = mpsc::channel(BUFFER_SIZE);
+    let stop = Arc::new(AtomicBool::new(false));
+    let watcher = Watcher {
+      sources,
+      files: BTreeMap::new(),
+      records: tx,
+      stop: stop.clone(),
+      span: span.clone(),
+    };
+    std::thread::Builder::new()
+      .name("wick-trigger-log".to_owned())
+      .spawn(move || watcher.run())
+      .map_err(|e| LogError::Watch(e.to_string()))?;
+
+    let (running_tx, running_rx) = oneshot::channel();
+    let handle = tokio::spawn(async move {
+      // records are sent one at a time so they arrive in the order they were read.
+      while let Some(record) = rx.recv().await {
+        if let Err(e) = invoke_operation(&runtime, &target, op_config.clone(), record, &span).await {
+          span.in_scope(|| error!("error invoking operation: {}", e));
+        }
+      }
+      let _ = running_tx.send(());
+    });
+
+    self.instance.lock().replace(LogInstance {
+      handle,
+      stop,
+      running_rx: Some(running_rx),
+    });
+
+    Ok(StructuredOutput::new(
+      format!("Log trigger tailing {}", patterns.join(", ")),
+      json!({"paths": patterns}),
+    ))
+  }
+
+  async fn shutdown_gracefully(self) -> Result<(), Error> {
+    let Some(instance) = self.instance.lock().take() else {
+      return Ok(());
+    };
+    instance.shutdown().await
+  }
+
+  async fn wait_for_done(&self) -> StructuredOutput {
+    let rx = self.instance.lock().as_mut().and_then(|i| i.running_rx.take());
+    let Some(rx) = rx else {
+      error!("log trigger not running");
+      return StructuredOutput::new("log trigger not running", json!({"status": "log trigger not running"}));
+    };
+    match rx.await {
+      Ok(_) => {
+        info!("log trigger finished");
+        StructuredOutput::new("log trigger finished", json!({"status": "log trigger finished"}))
+      }
+      Err(e) => {
+        error!(err=%e,"log trigger failed");
+        let message = format!("log trigger failed: {}", e);
+        StructuredOutput::new(message.clone(), json!({"status": message}))
+      }
+    }
+  }
+}
+
+impl fmt::Display for Log {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "Log Trigger")
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use wick_trigger::build_trigger_runtime;
+
+  use super::*;
+  use crate::test::load_example;
+
+  #[test_logger::test(tokio::test)]
+  async fn test_log_example() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("wick-trigger-log-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    std::env::set_var("LOG_DIR", dir.to_string_lossy().to_string());
+
+    let app_config = load_example("log/log.wick").await?.try_app_config()?;
+    let rt = build_trigger_runtime(&app_config, Span::current())?.build(None).await?;
+
+    let trigger = Log::default();
+    let resource = Resource::new(app_config.resources().get(0).as_ref().unwrap().kind().clone())?;
+    let resources = Arc::new([("logs".into(), resource)].iter().cloned().collect());
+    let trigger_config = app_config.triggers()[0].clone();
+    let output = trigger
+      .run(
+        "test".to_owned(),
+        rt,
+        app_config,
+        trigger_config,
+        resources,
+        Span::current(),
+      )
+      .await?;
+    assert!(output.json["paths"][0].as_str().unwrap().ends_with("/*.log"));
+    assert!(output.json["paths"][1].as_str().unwrap().ends_with("/access/*.txt"));
+
+    std::fs::write(dir.join("app.log"), "{\"level\":\"info\",\"msg\":\"started\"}\n")?;
+    tokio::time::sleep(POLL_INTERVAL * 2).await;
+    trigger.shutdown_gracefully().await?;
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_source_paths() -> Result<()> {
+    let config = |path: &str| {
+      wick_config::config::LogSourceConfigBuilder::default()
+        .resource("logs")
+        .paths(vec![path.to_owned()])
+        .build()
+    };
+    let source = Source::new(Path::new("/var/log/[app]/"), &config("*.log")?)?;
+    assert_eq!(source.patterns, vec!["/var/log/[[]app[]]/*.log".to_owned()]);
+    assert!(matches!(
+      Source::new(Path::new("/var/log"), &config("../secrets")?),
+      Err(LogError::InvalidPath(..))
+    ));
+    assert!(matches!(
+      Source::new(Path::new("/var/log"), &config("/etc/passwd")?),
+      Err(LogError::InvalidPath(..))
+    ));
+    Ok(())
+  }
+}
@@ -0,0 +1,48 @@
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum LogError {
+  #[error("error in configuration: {0}")]
+  Config(Box<wick_config::Error>),
+
+  #[error("invalid path pattern '{0}': {1}")]
+  InvalidPath(String, String),
+
+  #[error("the regex format needs a pattern to parse lines with")]
+  MissingPattern,
+
+  #[error("invalid pattern '{0}': {1}")]
+  InvalidPattern(String, String),
+
+  #[error("pattern '{0}' has no named capture groups to make fields from")]
+  NoCaptures(String),
+
+  #[error("CSV delimiter must be a single character, found '{0}'")]
+  InvalidDelimiter(String),
+
+  #[error("could not start watching log files: {0}")]
+  Watch(String),
+
+  #[error("invalid JSON: {0}")]
+  Json(String),
+
+  #[error("expected {0} fields, found {1}")]
+  FieldCount(usize, usize),
+
+  #[error("unterminated quoted field")]
+  UnterminatedQuote,
+
+  #[error("line does not match the pattern")]
+  NoMatch,
+}
+
+impl From<LogError> for wick_trigger::Error {
+  fn from(value: LogError) -> Self {
+    wick_trigger::Error::new_context("log", wick_trigger::ErrorKind::Trigger(Box::new(value)))
+  }
+}
+
+impl From<wick_config::Error> for LogError {
+  fn from(value: wick_config::Error) -> Self {
+    LogError::Config(Box::new(value))
+  }
+}
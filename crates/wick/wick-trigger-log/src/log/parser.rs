@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use regex::Regex;
+use serde_json::{Map, Value};
+use wick_config::config::{LogFormat, LogSourceConfig};
+
+use super::error::LogError;
+
+/// How the lines of a source's files are turned into records.
+#[derive(Debug)]
+pub(crate) enum Format {
+  Json,
+  Csv { delimiter: char, columns: Vec<String> },
+  Regex(Regex),
+}
+
+impl Format {
+  pub(crate) fn new(config: &LogSourceConfig) -> Result<Self, LogError> {
+    match config.format() {
+      LogFormat::Json => Ok(Self::Json),
+      LogFormat::Csv => {
+        let delimiter = match config.delimiter() {
+          Some(delimiter) => {
+            let mut chars = delimiter.chars();
+            match (chars.next(), chars.next()) {
+              (Some(c), None) if c != '"' => c,
+              _ => return Err(LogError::InvalidDelimiter(delimiter.clone())),
+            }
+          }
+          None => ',',
+        };
+        Ok(Self::Csv {
+          delimiter,
+          columns: config.columns().to_vec(),
+        })
+      }
+      LogFormat::Regex => {
+        let pattern = config.pattern().ok_or(LogError::MissingPattern)?;
+        let regex = Regex::new(pattern).map_err(|e| LogError::InvalidPattern(pattern.clone(), e.to_string()))?;
+        if regex.capture_names().flatten().next().is_none() {
+          return Err(LogError::NoCaptures(pattern.clone()));
+        }
+        Ok(Self::Regex(regex))
+      }
+    }
+  }
+}
+
+/// Parses the lines of one file. Files in the CSV format without configured columns are read with their first line as
+/// their header, so each file needs its own parser.
+#[derive(Debug)]
+pub(crate) struct Parser {
+  format: Arc<Format>,
+  header: Option<Vec<String>>,
+}
+
+impl Parser {
+  pub(crate) const fn new(format: Arc<Format>) -> Self {
+    Self { format, header: None }
+  }
+
+  /// Forget the header read from a file, for when the file starts over.
+  pub(crate) fn reset(&mut self) {
+    self.header = None;
+  }
+
+  /// Parse a line into a record. Blank lines and header lines don't make records.
+  pub(crate) fn parse(&mut self, line: &str) -> Result<Option<Value>, LogError> {
+    if line.trim().is_empty() {
+      return Ok(None);
+    }
+    match self.format.as_ref() {
+      Format::Json => serde_json::from_str(line)
+        .map(Some)
+        .map_err(|e| LogError::Json(e.to_string())),
+      Format::Csv { delimiter, columns } => {
+        let fields = split(line, *delimiter)?;
+        let columns = if columns.is_empty() {
+          let Some(header) = &self.header else {
+            self.header = Some(fields);
+            return Ok(None);
+          };
+          header
+        } else {
+          columns
+        };
+        if fields.len() != columns.len() {
+          return Err(LogError::FieldCount(columns.len(), fields.len()));
+        }
+        let record: Map<String, Value> = columns
+          .iter()
+          .cloned()
+          .zip(fields.into_iter().map(Value::String))
+          .collect();
+        Ok(Some(Value::Object(record)))
+      }
+      Format::Regex(regex) => {
+        let captures = regex.captures(line).ok_or(LogError::NoMatch)?;
+        let record: Map<String, Value> = regex
+          .capture_names()
+          .flatten()
+          .map(|name| {
+            let value = captures
+              .name(name)
+              .map_or(Value::Null, |m| Value::String(m.as_str().to_owned()));
+            (name.to_owned(), value)
+          })
+          .collect();
+        Ok(Some(Value::Object(record)))
+      }
+    }
+  }
+}
+
+/// Split a line of CSV into its fields. Fields may be quoted, with quotes inside them doubled, but can't span lines.
+fn split(line: &str, delimiter: char) -> Result<Vec<String>, LogError> {
+  let mut fields = Vec::new();
+  let mut field = String::new();
+  let mut quoted = false;
+  let mut chars = line.chars().peekable();
+  while let Some(c) = chars.next() {
+    if quoted {
+      if c != '"' {
+        field.push(c);
+      } else if chars.peek() == Some(&'"') {
+        chars.next();
+        field.push('"');
+      } else {
+        quoted = false;
+      }
+    } else if c == '"' && field.is_empty() {
+      quoted = true;
+    } else if c == delimiter {
+      fields.push(std::mem::take(&mut field));
+    } else {
+      field.push(c);
+    }
+  }
+  if quoted {
+    return Err(LogError::UnterminatedQuote);
+  }
+  fields.push(field);
+  Ok(fields)
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  use super::*;
+
+  fn parser(format: Format) -> Parser {
+    Parser::new(Arc::new(format))
+  }
+
+  #[test]
+  fn test_json() -> Result<()> {
+    let mut parser = parser(Format::Json);
+    assert_eq!(
+      parser.parse(r#"{"level":"info","msg":"started"}"#)?,
+      Some(json!({"level": "info", "msg": "started"}))
+    );
+    assert_eq!(parser.parse("  ")?, None);
+    assert!(matches!(parser.parse("{oops"), Err(LogError::Json(_))));
+    Ok(())
+  }
+
+  #[test]
+  fn test_csv() -> Result<()> {
+    let mut parser = parser(Format::Csv {
+      delimiter: ',',
+      columns: Vec::new(),
+    });
+    assert_eq!(parser.parse("time,level,msg")?, None);
+    assert_eq!(
+      parser.parse(r#"12:00,warn,"disk at 90%, ""soon"" full""#)?,
+      Some(json!({"time": "12:00", "level": "warn", "msg": "disk at 90%, \"soon\" full"}))
+    );
+    assert!(matches!(parser.parse("12:01,info"), Err(LogError::FieldCount(3, 2))));
+    assert!(matches!(
+      parser.parse(r#"12:02,info,"open"#),
+      Err(LogError::UnterminatedQuote)
+    ));
+
+    // a file that starts over has its header read again.
+    parser.reset();
+    assert_eq!(parser.parse("a,b")?, None);
+    assert_eq!(parser.parse("1,2")?, Some(json!({"a": "1", "b": "2"})));
+
+    let mut parser = self::parser(Format::Csv {
+      delimiter: '\t',
+      columns: vec!["a".to_owned(), "b".to_owned()],
+    });
+    assert_eq!(parser.parse("1\t2")?, Some(json!({"a": "1", "b": "2"})));
+    Ok(())
+  }
+
+  #[test]
+  fn test_regex() -> Result<()> {
+    let mut parser = parser(Format::Regex(Regex::new(
+      r"^(?P<ip>\S+) \[(?P<time>[^\]]+)\] (?P<status>\d+)(?: (?P<bytes>\d+))?$",
+    )?));
+    assert_eq!(
+      parser.parse("10.0.0.1 [17/Oct/2026:10:00:00] 200 512")?,
+      Some(json!({"ip": "10.0.0.1", "time": "17/Oct/2026:10:00:00", "status": "200", "bytes": "512"}))
+    );
+    assert_eq!(
+      parser.parse("10.0.0.1 [17/Oct/2026:10:00:01] 304")?,
+      Some(json!({"ip": "10.0.0.1", "time": "17/Oct/2026:10:00:01", "status": "304", "bytes": null}))
+    );
+    assert!(matches!(parser.parse("garbage"), Err(LogError::NoMatch)));
+    Ok(())
+  }
+}
@@ -0,0 +1,138 @@
+use std::fs::{File, Metadata};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// What identifies a file regardless of its name, so a file can be followed when it's rotated to a new one.
+pub(crate) type FileId = (u64, u64);
+
+#[cfg(unix)]
+pub(crate) fn file_id(metadata: &Metadata) -> Option<FileId> {
+  use std::os::unix::fs::MetadataExt;
+  Some((metadata.dev(), metadata.ino()))
+}
+
+// Without file ids, rotation can only be detected by a file getting shorter.
+#[cfg(not(unix))]
+pub(crate) const fn file_id(_metadata: &Metadata) -> Option<FileId> {
+  None
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Event {
+  Line(String),
+  /// The file was truncated and is being read from its beginning again.
+  Restart,
+}
+
+/// An open log file and how far into it has been read.
+#[derive(Debug)]
+pub(crate) struct Tail {
+  file: File,
+  id: Option<FileId>,
+  offset: u64,
+  /// The end of the file after its last newline, which is held back until the line is complete.
+  partial: Vec<u8>,
+}
+
+impl Tail {
+  /// Open the file at `path`, to read either all of it or only what's written to it from now on.
+  pub(crate) fn open(path: &Path, from_start: bool) -> io::Result<Self> {
+    let mut file = File::open(path)?;
+    let id = file_id(&file.metadata()?);
+    let offset = if from_start { 0 } else { file.seek(SeekFrom::End(0))? };
+    Ok(Self {
+      file,
+      id,
+      offset,
+      partial: Vec::new(),
+    })
+  }
+
+  pub(crate) const fn id(&self) -> Option<FileId> {
+    self.id
+  }
+
+  /// Read the lines written since the last read. A file that's gotten shorter has been truncated and is read again
+  /// from its beginning.
+  pub(crate) fn read(&mut self) -> io::Result<Vec<Event>> {
+    let mut events = Vec::new();
+    if self.file.metadata()?.len() < self.offset {
+      self.file.seek(SeekFrom::Start(0))?;
+      self.offset = 0;
+      self.partial.clear();
+      events.push(Event::Restart);
+    }
+
+    let mut buf = Vec::new();
+    self.offset += self.file.read_to_end(&mut buf)? as u64;
+    self.partial.extend(buf);
+    if let Some(end) = self.partial.iter().rposition(|b| *b == b'\n') {
+      let rest = self.partial.split_off(end + 1);
+      let complete = std::mem::replace(&mut self.partial, rest);
+      events.extend(complete[..end].split(|b| *b == b'\n').map(line));
+    }
+    Ok(events)
+  }
+
+  /// Read the rest of a file that won't be written to anymore, including a last line without a newline.
+  pub(crate) fn drain(mut self) -> io::Result<Vec<Event>> {
+    let mut events = self.read()?;
+    if !self.partial.is_empty() {
+      events.push(line(&self.partial));
+    }
+    Ok(events)
+  }
+}
+
+fn line(bytes: &[u8]) -> Event {
+  let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+  Event::Line(String::from_utf8_lossy(bytes).into_owned())
+}
+
+#[cfg(test)]
+mod test {
+  use std::io::Write;
+
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn lines(lines: &[&str]) -> Vec<Event> {
+    lines.iter().map(|l| Event::Line((*l).to_owned())).collect()
+  }
+
+  fn append(path: &Path, text: &str) -> Result<()> {
+    let mut file = std::fs::OpenOptions::new().append(true).create(true).open(path)?;
+    file.write_all(text.as_bytes())?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_tail() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("wick-trigger-log-tail-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("app.log");
+    std::fs::write(&path, "before\n")?;
+
+    let mut tail = Tail::open(&path, false)?;
+    assert_eq!(tail.read()?, Vec::new());
+    append(&path, "one\r\ntw")?;
+    assert_eq!(tail.read()?, lines(&["one"]));
+    append(&path, "o\n\nthree\n")?;
+    assert_eq!(tail.read()?, lines(&["two", "", "three"]));
+
+    std::fs::write(&path, "new\n")?;
+    let mut expected = vec![Event::Restart];
+    expected.extend(lines(&["new"]));
+    assert_eq!(tail.read()?, expected);
+
+    let mut tail = Tail::open(&path, true)?;
+    append(&path, "last")?;
+    assert_eq!(tail.read()?, lines(&["new"]));
+    assert_eq!(tail.drain()?, lines(&["last"]));
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+}
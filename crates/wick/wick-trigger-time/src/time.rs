@@ -15,7 +15,7 @@ use tokio::time::Duration;
 use tracing::Span;
 use wick_config::config::{self, AppConfiguration, BoundIdentifier, TimeTriggerConfig, TriggerDefinition};
 use wick_packet::{Entity, InherentData, Invocation, Packet};
-use wick_runtime::Runtime;
+use wick_runtime::{failover, Runtime};
 use wick_trigger::resources::Resource;
 use wick_trigger::{Error, Trigger};
 
@@ -54,6 +54,13 @@ async fn create_schedule(
   component_id: String,
   span: Span,
 ) -> Result<tokio::task::JoinHandle<()>, Error> {
+  // when the app runs on several instances, only the one holding the schedule's lease fires it.
+  let failover = runtime
+    .coordinator()
+    .map(|coordinator| coordinator.lease(&format!("schedule-{}.{}", component_id, config.operation().name())))
+    .transpose()
+    .map_err(TimeError::from)?;
+
   // Create a scheduler loop
   let handle = tokio::spawn(async move {
     let operation = Arc::new(config.operation().name().to_owned());
@@ -63,6 +70,8 @@ async fn create_schedule(
 
     let (failure_tx, mut failure_rx) = tokio::sync::mpsc::channel::<()>(1);
 
+    let mut lease = None;
+
     loop {
       if config.schedule().repeat() > 0 && current_count >= config.schedule().repeat() {
         break;
@@ -73,7 +82,16 @@ async fn create_schedule(
         break;
       }
 
-      current_count += 1;
+      if let (Some(failover), None) = (&failover, &lease) {
+        match failover.acquire().await {
+          Ok(acquired) => lease = Some(acquired),
+          Err(e) => {
+            span.in_scope(|| warn!("could not acquire the schedule's lease, retrying: {}", e));
+            tokio::time::sleep(failover::DEFAULT_TTL).await;
+            continue;
+          }
+        }
+      }
 
       // Calculate the next scheduled time based on the current time
       let next = schedule.upcoming(Utc).next().unwrap();
@@ -82,7 +100,23 @@ async fn create_schedule(
       let duration = next.signed_duration_since(Utc::now());
       span.in_scope(|| debug!("duration until next schedule: {:?}", duration));
 
-      tokio::time::sleep(Duration::from_millis(duration.num_milliseconds() as u64)).await;
+      let sleep = tokio::time::sleep(Duration::from_millis(duration.num_milliseconds() as u64));
+      let lost = if let Some(active) = lease.as_mut() {
+        tokio::select! {
+          _ = sleep => false,
+          _ = active.lost() => true,
+        }
+      } else {
+        sleep.await;
+        false
+      };
+      if lost {
+        span.in_scope(|| warn!("lost the schedule's lease, another instance runs it now"));
+        lease = None;
+        continue;
+      }
+
+      current_count += 1;
 
       span.in_scope(|| debug!("done sleeping"));
 
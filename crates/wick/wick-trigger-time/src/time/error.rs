@@ -4,6 +4,9 @@ pub enum TimeError {
   #[error("bad schedule, unable to create schedule from cron expression '{0}'")]
   BadSchedule(String, #[source] cron::error::Error),
 
+  #[error("could not coordinate the schedule with other instances: {0}")]
+  Coordination(#[from] wick_runtime::failover::FailoverError),
+
   #[error("error in configuration: {0}")]
   Config(Box<wick_config::Error>),
 }
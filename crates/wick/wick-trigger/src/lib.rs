@@ -117,4 +117,4 @@ pub mod resources;
 mod trigger;
 
 pub use error::*;
-pub use trigger::{build_trigger_runtime, Trigger, TriggerRuntimeConfig};
+pub use trigger::{build_trigger_runtime, ReloadCommit, Trigger, TriggerRuntimeConfig};
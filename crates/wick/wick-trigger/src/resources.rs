@@ -3,7 +3,8 @@ use std::path::PathBuf;
 use std::str::FromStr;
 
 use url::Url;
-use wick_config::config::{ResourceDefinition, TcpPort, UdpPort, UrlResource, Volume};
+use wick_config::config::{BrokerResource, ResourceDefinition, SecretResource, TcpPort, UdpPort, UrlResource, Volume};
+use wick_runtime::secrets::SharedSecret;
 
 #[derive(thiserror::Error, Debug)]
 #[non_exhaustive]
@@ -12,6 +13,8 @@ pub enum ResourceError {
   InvalidIpAddress(String, String),
   #[error("Invalid path: {0}")]
   InvalidPath(String),
+  #[error("{0}")]
+  Secret(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -22,6 +25,8 @@ pub enum Resource {
   UdpPort(SocketAddr),
   Url(Url),
   Volume(PathBuf),
+  Broker(String),
+  Secret(SharedSecret),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -32,6 +37,8 @@ pub enum ResourceKind {
   UdpPort,
   Url,
   Volume,
+  Broker,
+  Secret,
 }
 
 impl std::fmt::Display for ResourceKind {
@@ -41,6 +48,8 @@ impl std::fmt::Display for ResourceKind {
       Self::UdpPort => write!(f, "UdpPort"),
       Self::Url => write!(f, "Url"),
       Self::Volume => write!(f, "Volume"),
+      Self::Broker => write!(f, "Broker"),
+      Self::Secret => write!(f, "Secret"),
     }
   }
 }
@@ -52,6 +61,8 @@ impl Resource {
       ResourceDefinition::UdpPort(config) => Self::new_udp_port(&config),
       ResourceDefinition::Url(config) => Self::new_url(&config),
       ResourceDefinition::Volume(config) => Self::new_volume(&config),
+      ResourceDefinition::Broker(config) => Self::new_broker(&config),
+      ResourceDefinition::Secret(config) => Self::new_secret(&config),
     }
   }
 
@@ -83,12 +94,26 @@ impl Resource {
     ))
   }
 
+  pub fn new_broker(config: &BrokerResource) -> Result<Self, ResourceError> {
+    Ok(Self::Broker(config.name().value_unchecked().clone()))
+  }
+
+  /// Read a secret once. Secrets from a backend or a command, or that are refreshed while the app runs, are read with
+  /// [wick_runtime::secrets::SecretsBackends::watch] instead.
+  pub fn new_secret(config: &SecretResource) -> Result<Self, ResourceError> {
+    Ok(Self::Secret(SharedSecret::new(
+      config.reveal().map_err(|e| ResourceError::Secret(e.to_string()))?,
+    )))
+  }
+
   pub const fn kind(&self) -> ResourceKind {
     match self {
       Self::TcpPort(_) => ResourceKind::TcpPort,
       Self::UdpPort(_) => ResourceKind::UdpPort,
       Self::Url(_) => ResourceKind::Url,
       Self::Volume(_) => ResourceKind::Volume,
+      Self::Broker(_) => ResourceKind::Broker,
+      Self::Secret(_) => ResourceKind::Secret,
     }
   }
 }
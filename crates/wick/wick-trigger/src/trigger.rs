@@ -27,6 +27,9 @@ pub fn build_trigger_runtime(config: &AppConfiguration, span: Span) -> Result<Ru
   Ok(rt)
 }
 
+/// Switches a trigger over to a configuration validated by [Trigger::prepare_reload].
+pub type ReloadCommit = Box<dyn FnOnce() + Send + Sync>;
+
 #[async_trait]
 pub trait Trigger {
   /// Start executing the trigger.
@@ -43,9 +46,31 @@ pub trait Trigger {
   /// Shutdown a running trigger.
   async fn shutdown_gracefully(self) -> Result<(), Error>;
 
+  /// Prepare a running trigger to switch to a new runtime and configuration.
+  ///
+  /// Implementations validate the new configuration and return a [ReloadCommit] that performs the switch, so a
+  /// host can prepare every trigger before committing any of them. Work already in progress finishes on the old
+  /// runtime. Returns `None` when the trigger can't be updated in place and must be restarted instead.
+  async fn prepare_reload(
+    &self,
+    _runtime: Runtime,
+    _app_config: AppConfiguration,
+    _config: TriggerDefinition,
+    _resources: Arc<HashMap<BoundIdentifier, Resource>>,
+    _span: Span,
+  ) -> Result<Option<ReloadCommit>, Error> {
+    Ok(None)
+  }
+
   /// Wait for the trigger to finish.
   #[must_use = "this returns the output of the trigger"]
   async fn wait_for_done(&self) -> StructuredOutput;
+
+  /// Whether the trigger handles ctrl-c and SIGTERM itself and finishes once it has, rather than the host no longer
+  /// waiting for it when they arrive.
+  fn handles_signals(&self) -> bool {
+    false
+  }
 }
 
 /// Runtime configuration necessary for a trigger to execute.
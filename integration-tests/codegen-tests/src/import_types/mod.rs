@@ -731,6 +731,16 @@ pub mod types {
   }
   #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
   #[allow(clippy::exhaustive_structs)]
+  pub struct LocalStructAnonymous {
+    #[serde(rename = "point")]
+    pub point: types::AnonXY,
+    #[serde(rename = "labels")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<types::AnonNameValue>,
+  }
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
   pub struct LocalStructInner {
     #[serde(rename = "field1")]
     pub field1: String,
@@ -738,6 +748,20 @@ pub mod types {
     pub field2: String,
   }
   #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
+  pub struct LocalTreeNode {
+    #[serde(rename = "value")]
+    pub value: String,
+    #[serde(rename = "parent")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<types::LocalTreeNode>>,
+    #[serde(rename = "children")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<types::LocalTreeNode>,
+  }
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
   ///a weird union
   #[serde(untagged)]
   pub enum LocalUnion {
@@ -748,6 +772,24 @@ pub mod types {
     ///A datetime value.
     Datetime(wick_component::datetime::DateTime),
   }
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
+  pub struct AnonXY {
+    #[serde(rename = "x")]
+    pub x: i32,
+    #[serde(rename = "y")]
+    pub y: i32,
+  }
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
+  pub struct AnonNameValue {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "value")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+  }
   pub mod aaa {
     #[allow(unused)]
     use super::aaa;
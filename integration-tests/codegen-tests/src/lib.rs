@@ -117,6 +117,57 @@ mod test1 {
       Ok(())
     }
 
+    #[test]
+    fn test_anonymous_structs() -> Result<()> {
+      let expected = types::LocalStructAnonymous {
+        point: types::AnonXY { x: 1, y: 2 },
+        labels: vec![types::AnonNameValue {
+          name: "key".to_owned(),
+          value: None,
+        }],
+      };
+
+      let json = serde_json::json!({
+        "point": {"x": 1, "y": 2},
+        "labels": [{"name": "key"}]
+      });
+
+      let actual: types::LocalStructAnonymous = serde_json::from_value(json).unwrap();
+
+      assert_eq!(expected, actual);
+
+      Ok(())
+    }
+
+    #[test]
+    fn test_recursive_structs() -> Result<()> {
+      let expected = types::LocalTreeNode {
+        value: "child".to_owned(),
+        parent: Some(Box::new(types::LocalTreeNode {
+          value: "root".to_owned(),
+          parent: None,
+          children: Vec::new(),
+        })),
+        children: vec![types::LocalTreeNode {
+          value: "leaf".to_owned(),
+          parent: None,
+          children: Vec::new(),
+        }],
+      };
+
+      let json = serde_json::json!({
+        "value": "child",
+        "parent": {"value": "root"},
+        "children": [{"value": "leaf"}]
+      });
+
+      let actual: types::LocalTreeNode = serde_json::from_value(json).unwrap();
+
+      assert_eq!(expected, actual);
+
+      Ok(())
+    }
+
     #[tokio::test]
     async fn test_configgen() -> Result<()> {
       // Don't delete, it tests that local structs are genned correctly.
@@ -1,3 +1,5 @@
+pub(crate) mod bundle;
+pub(crate) mod check;
 pub(crate) mod config;
 pub(crate) mod install;
 pub(crate) mod invoke;
@@ -10,6 +12,7 @@ pub(crate) mod rpc;
 pub(crate) mod run;
 pub(crate) mod serve;
 pub(crate) mod show;
+pub(crate) mod soak;
 pub(crate) mod test;
 pub(crate) mod wasm;
 
@@ -55,10 +58,22 @@ pub(crate) enum CliCommand {
   #[clap(name = "test")]
   Test(test::Options),
 
+  /// Invoke an operation continuously and report suspected leaks.
+  #[clap(name = "soak")]
+  Soak(soak::Options),
+
+  /// Load and validate a configuration without starting it, reporting every problem found.
+  #[clap(name = "check")]
+  Check(check::Options),
+
   /// Create new app and component configurations.
   #[clap(subcommand, name = "new")]
   New(new::SubCommands),
 
+  /// Write an app and everything it imports into a directory that can run it without a network.
+  #[clap(name = "bundle")]
+  Bundle(bundle::Options),
+
   /// Install a wick app to the local system.
   #[clap(name = "install")]
   Install(install::Options),
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Args;
+use structured_output::StructuredOutput;
+use tracing::Instrument;
+
+use crate::utils::reconcile_fetch_options;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  #[clap(flatten)]
+  pub(crate) oci: crate::options::oci::OciOptions,
+
+  /// Directory to write the bundle to.
+  #[clap(long = "output", short = 'o', action, default_value = "bundle")]
+  pub(crate) output: PathBuf,
+
+  /// Also pack the bundle into this single gzipped tarball.
+  #[clap(long = "archive", action)]
+  pub(crate) archive: Option<PathBuf>,
+
+  /// Path or OCI url to the application or component manifest to bundle.
+  #[clap(action)]
+  application: String,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  span.in_scope(|| info!(app = opts.application, output = %opts.output.display(), "bundling"));
+
+  let oci_opts = reconcile_fetch_options(&opts.application, &settings, opts.oci, None);
+  let app_as_path = PathBuf::from(&opts.application);
+  let path = if app_as_path.exists() {
+    app_as_path
+  } else {
+    crate::oci::pull(opts.application.clone(), oci_opts.clone())
+      .instrument(span.clone())
+      .await?
+      .path()
+      .clone()
+  };
+
+  let root = wick_package::bundle(&path, &opts.output, &oci_opts)
+    .instrument(span.clone())
+    .await?;
+  if let Some(archive) = &opts.archive {
+    wick_package::archive_bundle(&opts.output, archive)?;
+  }
+
+  let mut text = format!(
+    "bundled {} into {}, run it with `wick run --bundle {} {}`",
+    opts.application,
+    opts.output.display(),
+    opts.output.display(),
+    root.display()
+  );
+  if let Some(archive) = &opts.archive {
+    text.push_str(&format!("\npacked the bundle into {}", archive.display()));
+  }
+  let json = serde_json::json!({
+    "root": root,
+    "output": opts.output,
+    "archive": opts.archive,
+  });
+
+  Ok(StructuredOutput::new(text, json))
+}
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use clap::Args;
+use serde_json::json;
+use structured_output::StructuredOutput;
+
+use crate::utils::{parse_config_string, reconcile_fetch_options};
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  #[clap(flatten)]
+  pub(crate) oci: crate::options::oci::OciOptions,
+
+  #[clap(flatten)]
+  pub(crate) component: crate::options::component::ComponentOptions,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  span.in_scope(|| debug!(path = %opts.component.path, "checking configuration"));
+  let root_config = parse_config_string(opts.component.with.as_deref())?;
+  let options = reconcile_fetch_options(&opts.component.path, &settings, opts.oci, None);
+  let env = std::env::vars().collect();
+
+  let diagnostics = wick_host::check::check(
+    &opts.component.path,
+    options,
+    root_config,
+    env,
+    opts.component.seed,
+    span,
+  )
+  .await;
+
+  let text = if diagnostics.is_empty() {
+    format!("{}: no problems found", opts.component.path)
+  } else {
+    let mut lines = Vec::new();
+    for diagnostic in &diagnostics {
+      lines.push(format!("error: {}", diagnostic));
+      if let Some(excerpt) = diagnostic
+        .location
+        .as_ref()
+        .and_then(wick_host::check::Location::excerpt)
+      {
+        lines.push(excerpt);
+      }
+    }
+    lines.push(format!("{} problem(s) found", diagnostics.len()));
+    lines.join("\n")
+  };
+
+  Ok(StructuredOutput::new(
+    text,
+    json!({"success": diagnostics.is_empty(), "diagnostics": diagnostics}),
+  ))
+}
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::io::IsTerminal;
 use std::time::SystemTime;
 
 use anyhow::Result;
@@ -6,8 +7,9 @@ use clap::Args;
 use serde_json::json;
 use structured_output::StructuredOutput;
 use wick_component_cli::options::DefaultCliOptions;
-use wick_component_cli::parse_args;
+use wick_component_cli::Query;
 use wick_host::Host;
+use wick_interface_types::OperationSignature;
 use wick_packet::{Entity, InherentData, Invocation, Packet, PacketExt, PacketStream};
 
 use crate::utils::{self, parse_config_string};
@@ -123,54 +125,27 @@ pub(crate) async fn handle(
     )
   });
 
-  if check_stdin {
-    todo!("STDIN support is not yet implemented.");
-    // if atty::is(atty::Stream::Stdin) {
-    //   eprintln!("No input passed, reading from <STDIN>. Pass --no-input to disable.");
-    // }
-    // let reader = io::BufReader::new(io::stdin());
-    // let mut lines = reader.lines();
-
-    // while let Some(line) = lines.next_line().await? {
-    //   debug!("STDIN:'{}'", line);
-    //   let mut payload = TransportMap::from_json_output(&line)?;
-    //   if !opts.raw {
-    //     payload.transpose_output_name();
-    //   }
-
-    //   let stream = host.request(&default_schematic, payload, inherent_data).await?;
-
-    //   utils::print_stream_json(stream, &opts.filter, opts.short, opts.raw).await?;
-    // }
-  } else {
-    let args = parse_args(&opts.args, op_signature).map_err(|e| {
-      anyhow!(
-        "Failed to parse arguments for operation {}: {}",
-        target.operation_id(),
-        e
-      )
-    })?;
-    span.in_scope(|| trace!(args= ?args, "parsed CLI arguments"));
-    let mut packets = Vec::new();
-    let mut seen_ports = HashSet::new();
-    for packet in args {
-      seen_ports.insert(packet.port().to_owned());
-      packets.push(Ok(packet));
-    }
-    for port in seen_ports {
-      packets.push(Ok(Packet::done(port)));
-    }
-    span.in_scope(|| debug!(cli_packets= ?packets, "wick invoke"));
-    let stream = PacketStream::new(futures::stream::iter(packets));
+  let args = parse_inputs(&opts.args, opts.no_input, op_signature, &target, check_stdin)?;
+  span.in_scope(|| trace!(args= ?args, "parsed CLI arguments"));
+  let mut packets = Vec::new();
+  let mut seen_ports = HashSet::new();
+  for packet in args {
+    seen_ports.insert(packet.port().to_owned());
+    packets.push(Ok(packet));
+  }
+  for port in seen_ports {
+    packets.push(Ok(Packet::done(port)));
+  }
+  span.in_scope(|| debug!(cli_packets= ?packets, "wick invoke"));
+  let stream = PacketStream::new(futures::stream::iter(packets));
 
-    span.in_scope(|| info!(operation=%target,path= ?path_parts, "host loaded, invoking operation"));
+  span.in_scope(|| info!(operation=%target,path= ?path_parts, "host loaded, invoking operation"));
 
-    let invocation = Invocation::new(Entity::server(host.namespace()), target, stream, inherent_data, &span);
+  let invocation = Invocation::new(Entity::server(host.namespace()), target, stream, inherent_data, &span);
 
-    let stream = host.invoke_deep(path_parts.as_deref(), invocation, op_config).await?;
+  let stream = host.invoke_deep(path_parts.as_deref(), invocation, op_config).await?;
 
-    utils::print_stream_json(stream, &opts.filter, opts.short, opts.raw).await?;
-  }
+  utils::print_stream_json(stream, &opts.filter, opts.short, opts.raw).await?;
 
   match host {
     wick_host::WickHost::App(_) => {}
@@ -179,3 +154,38 @@ pub(crate) async fn handle(
 
   Ok(StructuredOutput::new("", json!({})))
 }
+
+/// Parse the operation's inputs from the CLI arguments, or from <STDIN> when `from_stdin` is set.
+fn parse_inputs(
+  args: &[String],
+  no_input: bool,
+  signature: &OperationSignature,
+  target: &Entity,
+  from_stdin: bool,
+) -> Result<Vec<Packet>> {
+  let mut query = Query::new(signature);
+  let parsed = if from_stdin {
+    if std::io::stdin().is_terminal() {
+      eprintln!("No input passed, reading from <STDIN>. Pass --no-input to disable.");
+    }
+    query.reader(std::io::stdin().lock())
+  } else {
+    query.args(args)
+  };
+  parsed.map_err(|e| {
+    anyhow!(
+      "Failed to parse arguments for operation {}: {}",
+      target.operation_id(),
+      e
+    )
+  })?;
+  let missing = query.missing_inputs();
+  if !no_input && !missing.is_empty() {
+    return Err(anyhow!(
+      "Operation {} is missing inputs [{}], pass them as name=value or --name value",
+      target.operation_id(),
+      missing.join(", ")
+    ));
+  }
+  Ok(query.packets())
+}
@@ -3,6 +3,7 @@ use wick_config::config;
 
 pub(crate) mod app;
 pub(crate) mod component;
+pub(crate) mod types;
 
 #[derive(Subcommand, Debug, Clone)]
 pub(crate) enum SubCommands {
@@ -13,6 +14,10 @@ pub(crate) enum SubCommands {
   /// Query a collection for a list of its components.
   #[clap(name = "application", alias = "app")]
   App(app::Options),
+
+  /// Create a new types configuration, optionally importing types from a JSON Schema or protobuf descriptor set.
+  #[clap(name = "types")]
+  Types(types::Options),
 }
 
 fn generic_metadata(description: &str) -> config::Metadata {
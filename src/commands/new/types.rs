@@ -0,0 +1,75 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use clap::Args;
+use structured_output::StructuredOutput;
+use wick_config::config::TypesConfigurationBuilder;
+use wick_interface_types::{convert, Field, StructDefinition, Type, TypeDefinition};
+
+use crate::io::File;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  /// Name of the types configuration.
+  #[clap()]
+  name: String,
+
+  /// Import types from a JSON Schema document (.json) or an encoded protobuf descriptor set (.pb, .desc, .binpb).
+  #[clap(long = "from")]
+  from: Option<PathBuf>,
+
+  #[clap(long = "dry-run", action)]
+  dry_run: bool,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  _settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let types = match &opts.from {
+    Some(path) => import_types(path).await?,
+    None => vec![TypeDefinition::Struct(StructDefinition::new(
+      "MyType",
+      vec![Field::new("name", Type::String)],
+      None,
+    ))],
+  };
+
+  let name = crate::commands::new::sanitize_name(&opts.name);
+  let files: Result<Vec<File>> = span.in_scope(|| {
+    info!("initializing wick types: {}", name);
+
+    let config = TypesConfigurationBuilder::default()
+      .name(name.clone())
+      .metadata(Some(crate::commands::new::generic_metadata("New wick types")))
+      .source(None)
+      .types(types)
+      .operations(Vec::new())
+      .build()?;
+
+    let config = wick_config::WickConfiguration::Types(config);
+
+    Ok(vec![File::new(
+      crate::commands::new::wickify_filename(&opts.name),
+      config.into_v1_yaml()?.into(),
+    )])
+  });
+
+  Ok(crate::io::init_files(&files?, opts.dry_run).await?)
+}
+
+async fn import_types(path: &Path) -> Result<Vec<TypeDefinition>> {
+  let bytes = crate::io::read_bytes(path).await?;
+  let types = match path.extension().and_then(|ext| ext.to_str()) {
+    Some("json") => convert::from_json_schema(&serde_json::from_slice(&bytes)?)?,
+    Some("pb" | "desc" | "binpb") => convert::from_encoded_file_descriptor_set(&bytes)?,
+    _ => bail!(
+      "could not determine the schema format of {}, expected a .json, .pb, .desc, or .binpb file",
+      path.display()
+    ),
+  };
+  Ok(types)
+}
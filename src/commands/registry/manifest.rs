@@ -21,6 +21,10 @@ pub(crate) struct Options {
 
   #[clap(flatten)]
   pub(crate) oci_opts: crate::options::oci::OciOptions,
+
+  /// Also show the provenance attached to the package, if any.
+  #[clap(long = "provenance", action)]
+  pub(crate) provenance: bool,
 }
 
 #[allow(clippy::unused_async)]
@@ -60,7 +64,7 @@ pub(crate) async fn handle(
     .collect::<Vec<_>>()
     .join("\n");
 
-  let text = format!(
+  let mut text = format!(
     r#"# {}:
 
 Digest: {}
@@ -82,8 +86,14 @@ Layers:
     manifest_layers,
   );
 
+  let mut json = json!({"manifest":&manifest, "digest":digest});
+  if opts.provenance {
+    let provenance = wick_oci_utils::package::pull_provenance(&opts.reference, &oci_opts).await?;
+    text.push_str(&print_provenance(provenance.as_ref()));
+    json["provenance"] = json!(provenance);
+  }
+
   span.in_scope(|| debug!(%manifest, reference= opts.reference, "pulled manifest"));
-  let json = json!({"manifest":&manifest, "digest":digest});
 
   Ok(StructuredOutput::new(text, json))
 }
@@ -99,6 +109,27 @@ fn print_annotations(annotations: &Option<HashMap<String, String>>, indent: u8)
   String::new()
 }
 
+fn print_provenance(provenance: Option<&wick_oci_utils::package::Provenance>) -> String {
+  let Some(provenance) = provenance else {
+    return "Provenance: none\n".to_owned();
+  };
+  let files = provenance
+    .files
+    .iter()
+    .map(|file| {
+      format!(
+        "    {} ({}, {} bytes): {}",
+        file.path, file.media_type, file.size, file.digest
+      )
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+  format!(
+    "Provenance:\n  Builder: {}\n  Created: {}\n  Reference: {}\n  Files:\n{}\n",
+    provenance.builder, provenance.created, provenance.reference, files
+  )
+}
+
 fn print_oci_descriptor(descriptor: &OciDescriptor, indent: u8) -> String {
   let mut text = vec![format!(
     "{}Media Type: {}",
@@ -4,6 +4,7 @@ use clap::{Args, Subcommand};
 
 pub(crate) mod invoke;
 pub(crate) mod list;
+pub(crate) mod observability;
 pub(crate) mod stats;
 
 #[derive(Subcommand, Debug, Clone)]
@@ -19,6 +20,10 @@ pub(crate) enum SubCommands {
   /// Query a collection for its runtime statistics.
   #[clap(name = "stats")]
   Stats(stats::Options),
+
+  /// Query or change how much tracing detail an app records, through its admin listener.
+  #[clap(name = "observability")]
+  Observability(observability::Options),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -1,10 +1,11 @@
 use std::collections::HashSet;
+use std::io::IsTerminal;
 use std::time::SystemTime;
 
 use anyhow::Result;
 use clap::Args;
 use structured_output::StructuredOutput;
-use wick_component_cli::parse_args;
+use wick_component_cli::Query;
 use wick_packet::{Entity, InherentData, Invocation, Observer, Packet, PacketExt, PacketStream};
 
 use crate::utils;
@@ -87,43 +88,38 @@ pub(crate) async fn handle(
 
   let check_stdin = !opts.no_input && opts.data.is_empty() && opts.args.is_empty();
 
+  let mut query = Query::new(op_sig);
   if check_stdin {
-    todo!();
-    // if atty::is(atty::Stream::Stdin) {
-    //   eprintln!("No input passed, reading from <STDIN>. Pass --no-input to disable.");
-    // }
-    // let reader = BufReader::new(io::stdin());
-    // let mut lines = reader.lines();
-    // while let Some(line) = lines.next_line().await? {
-    //   let stream = client
-    //     .invoke_from_json(origin.clone(), target.clone(), &line, !opts.raw, inherent_data)
-    //     .await?;
-    //   utils::print_stream_json(stream, &opts.filter, opts.short, opts.raw).await?;
-    // }
-  } else {
-    let data = crate::utils::packet_from_kv_json(&opts.data)?;
-
-    let args = parse_args(&opts.args, op_sig)?;
-    let (tx, stream) = PacketStream::new_channels();
-    let mut seen_ports = HashSet::new();
-    for packet in args {
-      seen_ports.insert(packet.port().to_owned());
-      tx.send(packet)?;
-    }
-    for packet in data {
-      seen_ports.insert(packet.port().to_owned());
-      tx.send(packet)?;
-    }
-    for port in seen_ports {
-      tx.send(Packet::done(port))?;
+    if std::io::stdin().is_terminal() {
+      eprintln!("No input passed, reading from <STDIN>. Pass --no-input to disable.");
     }
+    query.reader(std::io::stdin().lock())?;
+  } else {
+    query.args(&opts.args)?;
+  }
 
-    let invocation = Invocation::new(origin, target, stream, inherent_data, &span);
-    span.in_scope(|| trace!("issuing invocation"));
-    let stream = client.invoke(invocation).await?;
-    span.in_scope(|| trace!("server responsed"));
-    utils::print_stream_json(stream, &opts.filter, opts.short, opts.raw).await?;
+  let data = crate::utils::packet_from_kv_json(&opts.data)?;
+
+  let args = query.packets();
+  let (tx, stream) = PacketStream::new_channels();
+  let mut seen_ports = HashSet::new();
+  for packet in args {
+    seen_ports.insert(packet.port().to_owned());
+    tx.send(packet)?;
+  }
+  for packet in data {
+    seen_ports.insert(packet.port().to_owned());
+    tx.send(packet)?;
   }
+  for port in seen_ports {
+    tx.send(Packet::done(port))?;
+  }
+
+  let invocation = Invocation::new(origin, target, stream, inherent_data, &span);
+  span.in_scope(|| trace!("issuing invocation"));
+  let stream = client.invoke(invocation).await?;
+  span.in_scope(|| trace!("server responsed"));
+  utils::print_stream_json(stream, &opts.filter, opts.short, opts.raw).await?;
 
   Ok(StructuredOutput::new("", serde_json::json!({})))
 }
@@ -0,0 +1,50 @@
+use anyhow::{anyhow, Result};
+use clap::Args;
+use serde_json::{json, Value};
+use structured_output::StructuredOutput;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  /// The address of the app's admin listener.
+  #[clap(short, long, default_value = "127.0.0.1", action)]
+  pub(crate) address: String,
+
+  /// The port of the app's admin listener.
+  #[clap(short, long, action)]
+  pub(crate) port: u16,
+
+  /// The level to switch to (off, errors, ops, or packets). Omit to print the current level.
+  #[clap(action)]
+  pub(crate) level: Option<String>,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  _settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let _span = span.enter();
+  let url = format!("http://{}:{}/observability", opts.address, opts.port);
+  let client = reqwest::Client::new();
+  let request = opts.level.as_ref().map_or_else(
+    || client.get(&url),
+    |level| client.put(&url).json(&json!({ "level": level })),
+  );
+  let response = request.send().await?;
+  if !response.status().is_success() {
+    return Err(anyhow!(
+      "{} responded with {}{}",
+      url,
+      response.status(),
+      opts
+        .level
+        .map_or_else(String::new, |level| format!(" (is '{}' a valid level?)", level))
+    ));
+  }
+  let body: Value = response.json().await?;
+  let level = body["level"].as_str().unwrap_or_default().to_owned();
+
+  Ok(StructuredOutput::new(level.clone(), json!({ "level": level })))
+}
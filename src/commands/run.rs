@@ -1,13 +1,28 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use clap::Args;
+use futures::future::join_all;
+use nkeys::{KeyPair, KeyPairType};
 use serde_json::json;
 use structured_output::StructuredOutput;
-use tracing::Instrument;
+use tracing::{Instrument, Span};
+use wick_config::config::AppConfiguration;
 use wick_config::WickConfiguration;
+use wick_host::watch::{FileWatcher, DEFAULT_POLL_INTERVAL};
 use wick_host::{AppHost, AppHostBuilder};
+use wick_oci_utils::OciOptions;
+use wick_packet::RuntimeConfig;
+use wick_runtime::checkpoints::{DirectoryCheckpoints, Durability};
+use wick_runtime::failover::{lease_backend, Coordinator, Failover};
+use wick_runtime::receipts::{ReceiptLog, DEFAULT_CAPACITY};
+use wick_runtime::scan::{CapabilityLimits, KnownBadDigests, ScanAction, ScanPolicy};
+use wick_runtime::state::state_store;
 
+use crate::keys::get_or_create;
 use crate::utils::{fetch_wick_config, fetch_wick_tree, parse_config_string, reconcile_fetch_options};
 
 #[derive(Debug, Clone, Args)]
@@ -28,70 +43,399 @@ pub(crate) struct Options {
   #[clap(long = "dryrun", action)]
   dryrun: bool,
 
+  /// Watch the application and its imported manifests, reloading the app in place when they change.
+  #[clap(long = "watch", action, conflicts_with = "dryrun")]
+  watch: bool,
+
+  /// Run another app in the same process, so the apps can pass events to each other through a shared broker resource.
+  #[clap(long = "with-app", action, conflicts_with = "watch")]
+  with_apps: Vec<String>,
+
+  /// Override an import's configuration without editing the manifest, as `<import id>=<JSON or @file>`. The JSON is
+  /// deep-merged over the import's `with:` config.
+  #[clap(long = "import-with", action)]
+  import_with: Vec<String>,
+
+  /// Sign a receipt for each transaction the app completes, served by its admin listener at `/receipts`.
+  #[clap(long = "receipts", action)]
+  receipts: bool,
+
+  /// The seed of the key to sign receipts with. Defaults to a host key kept with the other keys.
+  #[clap(long = "host-key", env = "WICK_HOST_KEY", action, hide_env_values = true)]
+  host_key: Option<String>,
+
+  /// How many receipts to keep, dropping the oldest ones as new ones are signed.
+  #[clap(long = "receipts-max-count", action, requires = "receipts", default_value_t = DEFAULT_CAPACITY)]
+  receipts_max_count: usize,
+
+  /// Prune receipts of transactions that finished more than this many seconds ago.
+  #[clap(long = "receipts-max-age", action, requires = "receipts")]
+  receipts_max_age: Option<u64>,
+
+  /// Refuse to instantiate imported components whose artifact has this digest, as sha256:<hex>.
+  #[clap(long = "block-digest", action)]
+  block_digests: Vec<String>,
+
+  /// Refuse to instantiate imported components that mount host directories outside of DIR. Pass it more than once
+  /// to allow more directories.
+  #[clap(long = "allow-volume-dir", action)]
+  allowed_volume_dirs: Vec<PathBuf>,
+
+  /// Only log what the scans of imported components find, instantiating the components anyway.
+  #[clap(long = "scan-warn", action)]
+  scan_warn: bool,
+
+  /// Share a lease at URL with other instances of the app and only run triggers while holding it, taking over when
+  /// the instance holding it stops renewing it. URL is file://<path>, redis://<host>[:<port>]/<key>, or
+  /// etcd://<host>[:<port>]/<key>.
+  #[clap(long = "failover", action, conflicts_with = "with_apps")]
+  failover: Option<String>,
+
+  /// Run every trigger on this instance but share leases at URL with other instances of the app, so each time
+  /// trigger's schedule only fires on the instance holding its lease. URL is the same as for --failover.
+  #[clap(long = "coordinate", action, conflicts_with = "failover")]
+  coordinate: Option<String>,
+
+  /// The id this instance holds leases as. Defaults to the host name, or a random id.
+  #[clap(long = "node-id", env = "WICK_NODE_ID", action)]
+  node_id: Option<String>,
+
+  /// How many seconds a lease is held without being renewed before another instance takes over.
+  #[clap(long = "failover-ttl", action, default_value_t = 15)]
+  failover_ttl: u64,
+
+  /// Keep the state components read and write through their context at URL instead of in memory, so it survives
+  /// restarts or is shared between instances of the app. URL is memory://, file://<path>, or
+  /// redis://[:<password>@]<host>[:<port>]/<prefix>. Apps run with --with-apps keep their state in memory.
+  #[clap(long = "state", action)]
+  state: Option<String>,
+
+  /// Checkpoint the app's transactions in DIR so the ones a crash interrupts are finished when the app starts again.
+  /// Apps run with --with-apps aren't checkpointed.
+  #[clap(long = "checkpoints", action)]
+  checkpoints: Option<PathBuf>,
+
   /// Arguments to pass as inputs to a CLI trigger in the application.
   #[clap(last(true), action)]
   args: Vec<String>,
 }
 
-pub(crate) async fn handle(
-  opts: Options,
-  settings: wick_settings::Settings,
-  span: tracing::Span,
-) -> Result<StructuredOutput> {
+pub(crate) async fn handle(opts: Options, settings: wick_settings::Settings, span: Span) -> Result<StructuredOutput> {
   span.in_scope(|| trace!(args = ?opts.args, "rest args"));
   let runtime_config = parse_config_string(opts.component.with.as_deref())?;
-  let options = reconcile_fetch_options(&opts.component.path, &settings, opts.oci, None);
+  let config_overrides = parse_import_overrides(&opts.import_with)?;
+  let options = reconcile_fetch_options(&opts.component.path, &settings, opts.oci.clone(), None);
+
+  let app_config = load_app_config(
+    &opts.component.path,
+    opts.lockdown.as_deref(),
+    &options,
+    runtime_config.clone(),
+    &span,
+  )
+  .await?;
+
+  let receipts = if opts.receipts {
+    Some(receipt_log(&opts, &app_config, &span).await?)
+  } else {
+    None
+  };
+
+  let scan = scan_policy(&opts);
 
-  let config = if let Some(lockdown) = opts.lockdown {
+  let node = opts
+    .node_id
+    .clone()
+    .or_else(|| std::env::var("HOSTNAME").ok())
+    .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+  let ttl = Duration::from_secs(opts.failover_ttl);
+  let coordinator = opts
+    .coordinate
+    .as_ref()
+    .map(|url| Coordinator::new(url.clone(), node.clone(), ttl))
+    .transpose()?;
+
+  let state = opts.state.as_deref().map(state_store).transpose()?;
+  let durability = opts
+    .checkpoints
+    .as_ref()
+    .map(|dir| Durability::new(Arc::new(DirectoryCheckpoints::new(dir))));
+
+  let mut builder = AppHostBuilder::default();
+  builder
+    .manifest(app_config.clone())
+    .runtime(
+      AppHost::build_runtime_with_overrides(
+        &app_config,
+        opts.component.seed,
+        &config_overrides,
+        receipts,
+        coordinator,
+        scan.clone(),
+        state,
+        durability,
+        span.clone(),
+      )
+      .await?,
+    )
+    .config_overrides(config_overrides)
+    .span(span.clone());
+  if let Some(url) = &opts.failover {
+    builder.failover(Failover::new(lease_backend(url)?, node, ttl));
+  }
+  let mut host = builder.build()?;
+
+  let mut other_hosts = Vec::new();
+  for path in &opts.with_apps {
+    other_hosts.push(other_host(path, &opts, &settings, scan.clone(), &span).await?);
+  }
+
+  let output = if opts.watch {
+    host.start().await?;
+    let reload = Reload {
+      path: &opts.component.path,
+      lockdown: opts.lockdown.as_deref(),
+      options: &options,
+      runtime_config,
+      seed: opts.component.seed,
+      span: &span,
+    };
+    watch(&mut host, &reload).instrument(span.clone()).await?;
+    StructuredOutput::new("application stopped", json!({"status":"stopped"}))
+  } else if !opts.dryrun {
+    // the other apps start first to give their triggers a head start on subscribing before this app's publish to them.
+    for other in &mut other_hosts {
+      other.start().await?;
+    }
+    host.start().await?;
+    span.in_scope(|| debug!("waiting on triggers to finish..."));
+
+    let mut waiting = vec![host.wait_for_done()];
+    waiting.extend(other_hosts.iter_mut().map(AppHost::wait_for_done));
+    let mut outputs = Vec::new();
+    for output in join_all(waiting).instrument(span.clone()).await {
+      outputs.extend(output?);
+    }
+    host.stop().await;
+    combine(outputs)
+  } else {
+    info!("application valid but not started because --dryrun set");
+    StructuredOutput::new(
+      "application valid but not started because --dryrun set",
+      json!({"status":"valid"}),
+    )
+  };
+
+  Ok(output)
+}
+
+/// The log to keep signed receipts of completed transactions in.
+async fn receipt_log(opts: &Options, app_config: &AppConfiguration, span: &Span) -> Result<ReceiptLog> {
+  if app_config.admin().is_none() {
+    span.in_scope(|| warn!("receipts are enabled but the app has no admin listener to serve them"));
+  }
+  let key = match &opts.host_key {
+    Some(seed) => KeyPair::from_seed(seed)?,
+    None => get_or_create(Some("host"), None, KeyPairType::Server).await?,
+  };
+  let log = ReceiptLog::with_capacity(key, opts.receipts_max_count);
+  Ok(match opts.receipts_max_age {
+    Some(secs) => log.with_max_age(Duration::from_secs(secs)),
+    None => log,
+  })
+}
+
+/// Build the host of an app run alongside the main one with --with-apps.
+async fn other_host(
+  path: &str,
+  opts: &Options,
+  settings: &wick_settings::Settings,
+  scan: Option<ScanPolicy>,
+  span: &Span,
+) -> Result<AppHost> {
+  let options = reconcile_fetch_options(path, settings, opts.oci.clone(), None);
+  let config = load_app_config(path, opts.lockdown.as_deref(), &options, None, span).await?;
+  let host = AppHostBuilder::default()
+    .manifest(config.clone())
+    .runtime(
+      AppHost::build_runtime_with_overrides(
+        &config,
+        opts.component.seed,
+        &HashMap::new(),
+        None,
+        None,
+        scan,
+        None,
+        None,
+        span.clone(),
+      )
+      .await?,
+    )
+    .span(span.clone())
+    .build()?;
+  Ok(host)
+}
+
+/// Combine the outputs of every trigger into the output of the command.
+fn combine(outputs: Vec<StructuredOutput>) -> StructuredOutput {
+  let mut lines = String::new();
+  let mut json = Vec::new();
+  for output in outputs {
+    if !output.lines.trim().is_empty() {
+      lines.push_str(&output.lines);
+      lines.push('\n');
+    }
+    json.push(output.json);
+  }
+  // a trigger that exits with a nonzero code, like the CLI trigger, makes the process exit with it too.
+  let code = json.iter().find_map(|output| {
+    output
+      .get("code")
+      .and_then(serde_json::Value::as_u64)
+      .filter(|code| *code > 0)
+  });
+  match code {
+    Some(code) => StructuredOutput::new(lines, json!({"output":json,"success":false,"code":code})),
+    None => StructuredOutput::new(lines, json!({"output":json})),
+  }
+}
+
+async fn load_app_config(
+  path: &str,
+  lockdown: Option<&str>,
+  options: &OciOptions,
+  runtime_config: Option<RuntimeConfig>,
+  span: &Span,
+) -> Result<AppConfiguration> {
+  let config = if let Some(lockdown) = lockdown {
     let env: HashMap<String, String> = std::env::vars().collect();
 
-    let mut lockdown_config = WickConfiguration::fetch(&lockdown, options.clone())
+    let mut lockdown_config = WickConfiguration::fetch(lockdown, options.clone())
       .instrument(span.clone())
       .await?;
     lockdown_config.set_env(env.clone());
     let lockdown_config = lockdown_config.finish()?.try_lockdown_config()?;
 
-    let tree = fetch_wick_tree(&opts.component.path, options.clone(), runtime_config, span.clone()).await?;
+    let tree = fetch_wick_tree(path, options.clone(), runtime_config, span.clone()).await?;
     let mut flattened = tree.flatten();
     wick_config::lockdown::assert_restrictions(&flattened, &lockdown_config)?;
 
     flattened.remove(0).as_config().unwrap()
   } else {
-    fetch_wick_config(&opts.component.path, options.clone(), runtime_config, span.clone()).await?
+    fetch_wick_config(path, options.clone(), runtime_config, span.clone()).await?
   };
 
   let mut app_config = config.try_app_config()?;
 
-  app_config.set_options(options);
+  app_config.set_options(options.clone());
+  Ok(app_config)
+}
 
-  let mut host = AppHostBuilder::default()
-    .manifest(app_config.clone())
-    .runtime(AppHost::build_runtime(&app_config, opts.component.seed, span.clone()).await?)
-    .span(span.clone())
-    .build()?;
+/// The scans to check imported components with, if any were asked for.
+fn scan_policy(opts: &Options) -> Option<ScanPolicy> {
+  if opts.block_digests.is_empty() && opts.allowed_volume_dirs.is_empty() {
+    return None;
+  }
+  let action = if opts.scan_warn {
+    ScanAction::Warn
+  } else {
+    ScanAction::Block
+  };
+  let mut policy = ScanPolicy::new(action);
+  if !opts.block_digests.is_empty() {
+    policy = policy.with_scanner(KnownBadDigests::new(opts.block_digests.clone()));
+  }
+  if !opts.allowed_volume_dirs.is_empty() {
+    policy = policy.with_scanner(CapabilityLimits::new(opts.allowed_volume_dirs.clone()));
+  }
+  Some(policy)
+}
 
-  let output = if !opts.dryrun {
-    host.start()?;
-    span.in_scope(|| debug!("waiting on triggers to finish..."));
+/// Parse `--import-with` arguments into configuration for each import, merging repeated ids in order.
+fn parse_import_overrides(args: &[String]) -> Result<HashMap<String, RuntimeConfig>> {
+  let mut overrides: HashMap<String, RuntimeConfig> = HashMap::new();
+  for arg in args {
+    let (id, config) = arg.split_once('=').ok_or_else(|| {
+      anyhow::anyhow!(
+        "invalid import override '{}', expected <import id>=<JSON or @file>",
+        arg
+      )
+    })?;
+    let config = parse_config_string(Some(config))?.unwrap_or_default();
+    let merged = match overrides.remove(id) {
+      Some(existing) => existing.merge(config),
+      None => config,
+    };
+    overrides.insert(id.to_owned(), merged);
+  }
+  Ok(overrides)
+}
 
-    let output = host.wait_for_done().instrument(span.clone()).await?;
-    let mut lines = String::new();
-    let mut json = Vec::new();
-    for output in output {
-      if !output.lines.trim().is_empty() {
-        lines.push_str(&output.lines);
-        lines.push('\n');
-      }
-      json.push(output.json);
-    }
-    StructuredOutput::new(lines, json!({"output":json}))
-  } else {
-    info!("application valid but not started because --dryrun set");
-    StructuredOutput::new(
-      "application valid but not started because --dryrun set",
-      json!({"status":"valid"}),
+/// Everything needed to load the app again after its files change.
+struct Reload<'a> {
+  path: &'a str,
+  lockdown: Option<&'a str>,
+  options: &'a OciOptions,
+  runtime_config: Option<RuntimeConfig>,
+  seed: Option<u64>,
+  span: &'a Span,
+}
+
+impl Reload<'_> {
+  /// The local manifests that make up the app: the app itself and every imported component manifest.
+  async fn files(&self) -> Result<Vec<PathBuf>> {
+    let tree = fetch_wick_tree(
+      self.path,
+      self.options.clone(),
+      self.runtime_config.clone(),
+      self.span.clone(),
     )
-  };
+    .await?;
+    Ok(
+      tree
+        .flatten()
+        .into_iter()
+        .filter_map(|node| node.as_config())
+        .filter_map(|config| config.source().map(Path::to_path_buf))
+        .collect(),
+    )
+  }
 
-  Ok(output)
+  async fn reload(&self, host: &mut AppHost) -> Result<()> {
+    let app_config = load_app_config(
+      self.path,
+      self.lockdown,
+      self.options,
+      self.runtime_config.clone(),
+      self.span,
+    )
+    .await?;
+    host.reload(app_config, self.seed).await?;
+    Ok(())
+  }
+}
+
+async fn watch(host: &mut AppHost, reload: &Reload<'_>) -> Result<()> {
+  let mut watcher = FileWatcher::new(reload.files().await?, DEFAULT_POLL_INTERVAL);
+  info!("watching for changes to the application configuration");
+
+  loop {
+    tokio::select! {
+      _ = tokio::signal::ctrl_c() => {
+        debug!("ctrl-c received, no longer watching");
+        return Ok(());
+      }
+      changed = watcher.changed() => {
+        info!(files = ?changed, "configuration changed, reloading");
+        // a configuration that fails to load or validate leaves the running app as it was.
+        if let Err(e) = reload.reload(host).await {
+          error!(error = %e, "reload failed, still running the previous configuration");
+        }
+        match reload.files().await {
+          Ok(files) => watcher.set_paths(files),
+          Err(e) => warn!(error = %e, "could not refresh the list of watched files"),
+        }
+      }
+    }
+  }
 }
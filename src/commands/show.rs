@@ -1,10 +1,15 @@
 use clap::Subcommand;
 
 pub(crate) mod env;
+pub(crate) mod runtime;
 
 #[derive(Subcommand, Debug, Clone)]
 pub(crate) enum SubCommands {
   /// Show environment details.
   #[clap(name = "env")]
   Env(env::Options),
+
+  /// Show the runtime version, supported manifest formats, and enabled features.
+  #[clap(name = "runtime")]
+  Runtime(runtime::Options),
 }
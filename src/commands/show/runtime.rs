@@ -0,0 +1,33 @@
+use anyhow::Result;
+use clap::Args;
+use structured_output::StructuredOutput;
+
+use crate::utils::runtime_info;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {}
+
+#[allow(clippy::unused_async)]
+pub(crate) async fn handle(
+  _opts: Options,
+  _settings: wick_settings::Settings,
+  _span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let info = runtime_info();
+
+  let features = if info.features.is_empty() {
+    "(none)".to_owned()
+  } else {
+    info.features.join(", ")
+  };
+  let text = format!(
+    "wick {}\nmanifest formats: {}\nfeatures: {}",
+    info.version,
+    info.formats.join(", "),
+    features
+  );
+
+  Ok(StructuredOutput::new(text, serde_json::to_value(&info)?))
+}
@@ -0,0 +1,262 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::Result;
+use clap::Args;
+use futures::StreamExt;
+use serde_json::json;
+use structured_output::StructuredOutput;
+use wick_component_cli::options::DefaultCliOptions;
+use wick_component_cli::parse_args;
+use wick_host::{Host, WickHost};
+use wick_interface_types::OperationSignature;
+use wick_packet::{Entity, InherentData, Invocation, Packet, PacketExt, PacketStream};
+use wick_runtime::metrics::RuntimeMetrics;
+use wick_runtime::soak::{LeakDetector, SoakSample};
+
+use crate::utils::parse_config_string;
+use crate::wick_host::build_host;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  #[clap(flatten)]
+  pub(crate) oci: crate::options::oci::OciOptions,
+
+  #[clap(flatten)]
+  pub(crate) component: crate::options::component::ComponentOptions,
+
+  #[clap(flatten)]
+  pub(crate) operation: crate::options::component::OperationOptions,
+
+  /// How long to keep invoking the operation, in seconds.
+  #[clap(long = "duration", short = 'd', default_value = "300", action)]
+  duration: u64,
+
+  /// How often to sample memory and transactions, in seconds.
+  #[clap(long = "interval", default_value = "5", action)]
+  interval: u64,
+
+  /// The number of invocations to keep in progress at once.
+  #[clap(long = "concurrency", short = 'c', default_value = "1", action)]
+  concurrency: u64,
+
+  /// Report transactions still in progress after this many seconds as never finishing.
+  #[clap(long = "unfinished-after", default_value = "30", action)]
+  unfinished_after: u64,
+
+  /// Arguments to pass as inputs to every invocation.
+  #[clap(last(true), action)]
+  args: Vec<String>,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  if opts.concurrency == 0 || opts.interval == 0 {
+    bail!("--concurrency and --interval must be at least 1");
+  }
+  let root_config = parse_config_string(opts.component.with.as_deref())?;
+  let server_settings = DefaultCliOptions::default();
+
+  let host = build_host(
+    &opts.component.path,
+    opts.oci,
+    root_config,
+    settings,
+    opts.component.seed,
+    Some(server_settings),
+    span.clone(),
+  )
+  .await?;
+
+  let WickHost::Component(host) = host else {
+    bail!("`wick soak` only works on component configurations at this time.");
+  };
+
+  let mut path_parts = opts.operation.operation_name.split("::").collect::<Vec<_>>();
+
+  let (path_parts, target) = if path_parts.len() == 1 {
+    (None, Entity::local(path_parts[0]))
+  } else {
+    let op = path_parts.pop().unwrap();
+    let component = path_parts.pop().unwrap();
+
+    (Some(path_parts), Entity::operation(component, op))
+  };
+
+  let signature = host.get_signature(path_parts.as_deref(), Some(&target))?;
+  let op_signature = signature
+    .get_operation(target.operation_id())
+    .ok_or_else(|| anyhow!("Operation '{}' not found", target.operation_id()))?;
+
+  let op_config = parse_config_string(opts.operation.op_with.as_deref())?;
+
+  let packets = input_packets(&opts.args, op_signature, &target)?;
+
+  let metrics = host.get_runtime()?.metrics().clone();
+  metrics.track_transactions();
+
+  span.in_scope(|| info!(operation = %target, concurrency = opts.concurrency, "starting soak test"));
+
+  let start = Instant::now();
+  let deadline = tokio::time::Instant::from_std(start + Duration::from_secs(opts.duration));
+  let seed = opts.component.seed.unwrap_or_default();
+  let interval = Duration::from_secs(opts.interval);
+  let invocations = AtomicU64::new(0);
+  let errors = AtomicU64::new(0);
+
+  let samples = {
+    // the workers and sampler share everything by reference.
+    let (host, target, path_parts, op_config, packets) = (&host, &target, &path_parts, &op_config, &packets);
+    let (span, metrics, invocations, errors) = (&span, &metrics, &invocations, &errors);
+
+    let worker = move |_| async move {
+      while tokio::time::Instant::now() < deadline {
+        let count = invocations.fetch_add(1, Ordering::Relaxed);
+        let timestamp = SystemTime::now()
+          .duration_since(SystemTime::UNIX_EPOCH)
+          .unwrap()
+          .as_millis()
+          .try_into()
+          .unwrap();
+        let inherent = InherentData::new(seed.wrapping_add(count), timestamp);
+        let stream = PacketStream::new(futures::stream::iter(packets.clone().into_iter().map(Ok)));
+        let invocation = Invocation::new(Entity::server(host.namespace()), target.clone(), stream, inherent, span);
+
+        let call = async {
+          match host
+            .invoke_deep(path_parts.as_deref(), invocation, op_config.clone())
+            .await
+          {
+            Ok(mut stream) => {
+              while let Some(packet) = stream.next().await {
+                if packet.map_or(true, |p| p.is_error()) {
+                  errors.fetch_add(1, Ordering::Relaxed);
+                }
+              }
+            }
+            Err(error) => {
+              errors.fetch_add(1, Ordering::Relaxed);
+              span.in_scope(|| warn!(%error, "soak test invocation failed"));
+            }
+          }
+        };
+        // a transaction that never finishes is left for the leak report instead of holding up the test.
+        if tokio::time::timeout_at(deadline, call).await.is_err() {
+          break;
+        }
+      }
+    };
+
+    let sampler = sample(start, deadline, interval, metrics, invocations, span);
+    let workers = futures::future::join_all((0..opts.concurrency).map(worker));
+    futures::future::join(workers, sampler).await.1
+  };
+
+  let output = report(
+    &samples,
+    Duration::from_secs(opts.unfinished_after),
+    invocations.load(Ordering::Relaxed),
+    errors.load(Ordering::Relaxed),
+    start.elapsed(),
+  );
+
+  host.stop().await;
+
+  Ok(output)
+}
+
+/// Sample memory and transactions every `interval` until `deadline`.
+async fn sample(
+  start: Instant,
+  deadline: tokio::time::Instant,
+  interval: Duration,
+  metrics: &RuntimeMetrics,
+  invocations: &AtomicU64,
+  span: &tracing::Span,
+) -> Vec<SoakSample> {
+  let mut samples = Vec::new();
+  let mut interval = tokio::time::interval(interval);
+  loop {
+    interval.tick().await;
+    let sample = SoakSample::take(start.elapsed(), metrics);
+    span.in_scope(|| {
+      info!(
+        elapsed = sample.elapsed.as_secs(),
+        memory = ?sample.memory,
+        transactions = sample.active(),
+        buffered = sample.buffered(),
+        invocations = invocations.load(Ordering::Relaxed),
+        "soak test sample"
+      );
+    });
+    samples.push(sample);
+    if tokio::time::Instant::now() >= deadline {
+      break samples;
+    }
+  }
+}
+
+/// The packets to send to every invocation: the inputs in `args`, each followed by its port's `done`.
+fn input_packets(args: &[String], signature: &OperationSignature, target: &Entity) -> Result<Vec<Packet>> {
+  let args = parse_args(args, signature).map_err(|e| {
+    anyhow!(
+      "Failed to parse arguments for operation {}: {}",
+      target.operation_id(),
+      e
+    )
+  })?;
+  let mut packets = Vec::new();
+  let mut seen_ports = HashSet::new();
+  for packet in args {
+    seen_ports.insert(packet.port().to_owned());
+    packets.push(packet);
+  }
+  for port in seen_ports {
+    packets.push(Packet::done(port));
+  }
+  Ok(packets)
+}
+
+/// Report the leaks suspected from the `samples` of a soak test.
+fn report(
+  samples: &[SoakSample],
+  unfinished_after: Duration,
+  invocations: u64,
+  errors: u64,
+  elapsed: Duration,
+) -> StructuredOutput {
+  let mut detector = LeakDetector::default();
+  detector.unfinished_after = unfinished_after;
+  let leaks = detector.analyze(samples);
+
+  let mut lines = vec![format!(
+    "{} invocations with {} errors over {}s, {} samples",
+    invocations,
+    errors,
+    elapsed.as_secs(),
+    samples.len()
+  )];
+  if leaks.is_empty() {
+    lines.push("No leaks suspected.".to_owned());
+  } else {
+    lines.push("Suspected leaks:".to_owned());
+    lines.extend(leaks.iter().map(|leak| format!("  - {}", leak)));
+  }
+
+  StructuredOutput::new(
+    lines.join("\n"),
+    json!({
+      "success": leaks.is_empty(),
+      "invocations": invocations,
+      "errors": errors,
+      "samples": samples.len(),
+      "leaks": leaks.iter().map(ToString::to_string).collect::<Vec<_>>(),
+    }),
+  )
+}
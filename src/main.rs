@@ -142,7 +142,12 @@ fn main() {
         if success.as_bool().unwrap() {
           (output.json.clone(), 0)
         } else {
-          (output.json.clone(), 1)
+          let code = output
+            .json
+            .get("code")
+            .and_then(serde_json::Value::as_i64)
+            .map_or(1, |code| code as i32);
+          (output.json.clone(), code)
         }
       } else {
         (serde_json::json!({"success":true,"output":&output.json}), 0)
@@ -242,6 +247,8 @@ async fn async_main(span: Span, cli: Cli, settings: wick_settings::Settings) ->
     CliCommand::Run(cmd) => commands::run::handle(cmd, settings, span).await,
     CliCommand::Invoke(cmd) => commands::invoke::handle(cmd, settings, span).await,
     CliCommand::Test(cmd) => commands::test::handle(cmd, settings, span).await,
+    CliCommand::Soak(cmd) => commands::soak::handle(cmd, settings, span).await,
+    CliCommand::Check(cmd) => commands::check::handle(cmd, settings, span).await,
     CliCommand::Wasm(cmd) => match cmd {
       commands::wasm::SubCommands::Sign(cmd) => commands::wasm::sign::handle(cmd, settings, span).await,
       commands::wasm::SubCommands::Inspect(cmd) => commands::wasm::inspect::handle(cmd, settings, span).await,
@@ -261,9 +268,13 @@ async fn async_main(span: Span, cli: Cli, settings: wick_settings::Settings) ->
       commands::rpc::SubCommands::Invoke(cmd) => commands::rpc::invoke::handle(cmd, settings, span).await,
       commands::rpc::SubCommands::List(cmd) => commands::rpc::list::handle(cmd, settings, span).await,
       commands::rpc::SubCommands::Stats(cmd) => commands::rpc::stats::handle(cmd, settings, span).await,
+      commands::rpc::SubCommands::Observability(cmd) => {
+        commands::rpc::observability::handle(cmd, settings, span).await
+      }
     },
     CliCommand::Query(cmd) => commands::query::handle(cmd, settings, span).await,
     CliCommand::Install(cmd) => commands::install::handle(cmd, settings, span).await,
+    CliCommand::Bundle(cmd) => commands::bundle::handle(cmd, settings, span).await,
     CliCommand::New(cmd) => match cmd {
       new::SubCommands::Component(cmd) => match cmd {
         new::component::SubCommands::Http(cmd) => new::component::http::handle(cmd, settings, span).await,
@@ -272,9 +283,11 @@ async fn async_main(span: Span, cli: Cli, settings: wick_settings::Settings) ->
         new::component::SubCommands::WasmRS(cmd) => new::component::wasmrs::handle(cmd, settings, span).await,
       },
       new::SubCommands::App(cmd) => commands::new::app::handle(cmd, settings, span).await,
+      new::SubCommands::Types(cmd) => commands::new::types::handle(cmd, settings, span).await,
     },
     CliCommand::Show(cmd) => match cmd {
       show::SubCommands::Env(cmd) => commands::show::env::handle(cmd, settings, span).await,
+      show::SubCommands::Runtime(cmd) => commands::show::runtime::handle(cmd, settings, span).await,
     },
     CliCommand::Config(cmd) => match cmd {
       config::SubCommands::Dot(cmd) => commands::config::dot::handle(cmd, settings, span).await,
@@ -1,13 +1,24 @@
+use std::path::PathBuf;
+
 use clap::Args;
 
 impl From<OciOptions> for wick_config::FetchOptions {
   fn from(value: OciOptions) -> Self {
+    let trusted_keys = value.trusted_keys();
     let mut fetch_options = wick_config::FetchOptions::default();
     fetch_options
       .set_allow_latest(value.allow_latest)
       .set_allow_insecure(value.insecure_registries.clone())
       .set_username(value.username)
-      .set_password(value.password);
+      .set_password(value.password)
+      .set_offline(value.offline)
+      .set_trusted_keys(trusted_keys);
+    if let Some(max_cache_size) = value.max_cache_size {
+      fetch_options.set_max_cache_size(max_cache_size);
+    }
+    if let Some(bundle) = &value.bundle {
+      fetch_options = wick_package::bundle_options(bundle, &fetch_options);
+    }
 
     fetch_options
   }
@@ -35,4 +46,37 @@ pub(crate) struct OciOptions {
   /// Force overwriting of files.
   #[clap(long = "force", short = 'f', action)]
   pub(crate) force: bool,
+
+  /// Check the registry for updates to cached artifacts, downloading only the layers that changed.
+  #[clap(long = "refresh", action)]
+  pub(crate) refresh: bool,
+
+  /// Only use artifacts that are already cached, failing instead of contacting a registry.
+  #[clap(long = "offline", action, conflicts_with = "refresh")]
+  pub(crate) offline: bool,
+
+  /// Only run packages signed by this key: an account public key that signs the claims embedded in WebAssembly
+  /// modules, or a PEM-encoded cosign public key or the path to one. Account keys only vouch for packages made of
+  /// nothing but modules.
+  #[clap(long = "trusted-key", env = "WICK_TRUSTED_KEYS", value_delimiter = ',', action)]
+  pub(crate) trusted_keys: Vec<String>,
+
+  /// The number of bytes the cache of downloaded blobs can hold before the least recently used ones are evicted.
+  #[clap(long = "max-cache-size", env = "WICK_MAX_CACHE_SIZE", action)]
+  pub(crate) max_cache_size: Option<u64>,
+
+  /// Resolve imported packages only from a bundle written by `wick bundle`, never contacting a registry.
+  #[clap(long = "bundle", env = "WICK_BUNDLE", action, conflicts_with = "refresh")]
+  pub(crate) bundle: Option<PathBuf>,
+}
+
+impl OciOptions {
+  /// The trusted keys, read from disk when they're given as paths to key files.
+  pub(crate) fn trusted_keys(&self) -> Vec<String> {
+    self
+      .trusted_keys
+      .iter()
+      .map(|key| std::fs::read_to_string(key).unwrap_or_else(|_| key.clone()))
+      .collect()
+  }
 }
@@ -7,6 +7,7 @@ use liquid_json::LiquidJsonValue;
 use serde_json::Value;
 use tracing::{Instrument, Span};
 use wick_component_cli::options::DefaultCliOptions;
+use wick_config::compat::RuntimeInfo;
 use wick_config::config::{ComponentConfiguration, ConfigurationTreeNode, HttpConfigBuilder, LiquidJsonConfig};
 use wick_config::{AssetReference, WickConfiguration};
 use wick_oci_utils::{OciOptions, OnExisting};
@@ -22,6 +23,7 @@ pub(crate) async fn fetch_wick_config(
   let mut builder = WickConfiguration::fetch(path, fetch_opts.clone())
     .instrument(span.clone())
     .await?;
+  runtime_info().check(builder.manifest())?;
 
   builder
     .set_root_config(runtime_config)
@@ -39,10 +41,21 @@ pub(crate) async fn fetch_wick_tree(
   let config = WickConfiguration::fetch_tree(path, runtime_config, Some(env), fetch_opts.clone())
     .instrument(span.clone())
     .await?;
+  runtime_info().check(&config.element)?;
 
   Ok(config)
 }
 
+/// Describe this build of the wick runtime.
+pub(crate) fn runtime_info() -> RuntimeInfo {
+  let features = [
+    ("console", cfg!(feature = "console")),
+    ("mem-profiler", cfg!(feature = "mem-profiler")),
+    ("simd-json", cfg!(feature = "simd-json")),
+  ];
+  RuntimeInfo::new(env!("CARGO_PKG_VERSION")).with_features(features.into_iter().filter_map(|(f, on)| on.then_some(f)))
+}
+
 pub(crate) fn merge_config(
   def: ComponentConfiguration,
   local_cli_opts: &crate::options::oci::OciOptions,
@@ -142,17 +155,24 @@ pub(crate) fn reconcile_fetch_options(
 
   let (username, password) = get_auth_for_scope(configured_creds, opts.username.as_deref(), opts.password.as_deref());
 
+  let trusted_keys = opts.trusted_keys();
   let mut oci_opts = OciOptions::default();
   oci_opts
     .set_allow_insecure(opts.insecure_registries)
     .set_allow_latest(true)
     .set_username(username)
     .set_password(password)
+    .set_refresh(opts.refresh)
+    .set_offline(opts.offline)
+    .set_trusted_keys(trusted_keys)
     .set_on_existing(if opts.force {
       OnExisting::Overwrite
     } else {
       OnExisting::Ignore
     });
+  if let Some(max_cache_size) = opts.max_cache_size {
+    oci_opts.set_max_cache_size(max_cache_size);
+  }
 
   if let Some(output) = output {
     oci_opts.set_cache_dir(output);
@@ -160,6 +180,9 @@ pub(crate) fn reconcile_fetch_options(
     // otherwise, use the global cache.
     oci_opts.set_cache_dir(xdg.global().cache().clone());
   };
+  if let Some(bundle) = &opts.bundle {
+    oci_opts = wick_package::bundle_options(bundle, &oci_opts);
+  }
   oci_opts
 }
 
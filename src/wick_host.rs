@@ -9,7 +9,7 @@ use wick_host::{AppHost, AppHostBuilder, ComponentHostBuilder, WickHost};
 use wick_packet::RuntimeConfig;
 
 use crate::options::oci::OciOptions as WickOciOptions;
-use crate::utils::{get_auth_for_scope, merge_config};
+use crate::utils::{get_auth_for_scope, merge_config, runtime_info};
 
 pub(crate) async fn build_host(
   path: &str,
@@ -31,6 +31,7 @@ pub(crate) async fn build_host(
   fetch_opts.set_cache_dir(env.global().cache().clone());
 
   let mut manifest = WickConfiguration::fetch(path, fetch_opts).await?;
+  runtime_info().check(manifest.manifest())?;
   manifest.set_root_config(root_config);
   let host = match manifest.manifest() {
     WickConfiguration::Component(_) => {
@@ -9,7 +9,9 @@ utils::test_cases!(
     "component-composite.toml",
     "component-sql.toml",
     "component-http.toml",
-    "component-wasm.toml"
+    "component-wasm.toml",
+    "types.toml",
+    "types-json-schema.toml"
   ],
   integration: []
 );
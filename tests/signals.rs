@@ -0,0 +1,67 @@
+#![cfg(unix)]
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+
+/// How long an operation gets to finish after a signal before the CLI trigger cancels it.
+const CANCEL_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Run an app whose operation waits to be cancelled, send it `signal` once it's running, and return its exit code
+/// and the rest of its output.
+async fn interrupt(signal: &str) -> Result<(Option<i32>, Vec<String>)> {
+  let mut child = Command::from(test_bin::get_test_bin("wick"))
+    .args(["run", "tests/run/unit/wait-for-cancel.wick"])
+    .stdin(Stdio::null())
+    .stdout(Stdio::piped())
+    .kill_on_drop(true)
+    .spawn()?;
+  let pid = child.id().ok_or_else(|| anyhow!("wick exited before it was signalled"))?;
+  let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+
+  loop {
+    match lines.next_line().await? {
+      Some(line) if line == "waiting for cancellation" => break,
+      Some(_) => {}
+      None => return Err(anyhow!("wick exited before the operation started: {}", child.wait().await?)),
+    }
+  }
+  // give the trigger a moment to start listening for signals once the operation is running.
+  tokio::time::sleep(Duration::from_millis(500)).await;
+  let status = Command::new("kill")
+    .args([format!("-{}", signal), pid.to_string()])
+    .status()
+    .await?;
+  assert!(status.success(), "could not send SIG{} to wick", signal);
+
+  // finishing within the grace period means the operation stopped itself, rather than being cancelled.
+  let (status, output) = tokio::time::timeout(CANCEL_GRACE_PERIOD - Duration::from_secs(1), async {
+    let mut output = Vec::new();
+    while let Some(line) = lines.next_line().await? {
+      output.push(line);
+    }
+    Ok::<_, anyhow::Error>((child.wait().await?, output))
+  })
+  .await
+  .map_err(|_| anyhow!("wick was still running after SIG{}", signal))??;
+  Ok((status.code(), output))
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_sigint() -> Result<()> {
+  let (code, output) = interrupt("INT").await?;
+  assert_eq!(code, Some(130));
+  assert!(output.contains(&"cancelled".to_owned()), "operation never saw the cancellation: {:?}", output);
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_sigterm() -> Result<()> {
+  let (code, output) = interrupt("TERM").await?;
+  assert_eq!(code, Some(143));
+  assert!(output.contains(&"cancelled".to_owned()), "operation never saw the cancellation: {:?}", output);
+  Ok(())
+}